@@ -0,0 +1,111 @@
+//! Compares `JString` with `String` on the workloads compact strings are meant for: lots of
+//! short ASCII keys. Run with `cargo bench --bench jstring`, and with
+//! `--no-default-features` to see the cost of always using UTF-16.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use java::{JString, JavaString, COMPACT_STRINGS};
+
+const KEYS: usize = 1_000_000;
+
+fn bench<R>(name: &str, iterations: usize, mut f: impl FnMut() -> R) {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let per_iteration = start.elapsed() / iterations as u32;
+    println!("{name:<40} {per_iteration:>12.2?}");
+}
+
+fn heap_bytes_string(keys: &[String]) -> usize {
+    keys.iter().map(|key| key.capacity()).sum()
+}
+
+fn heap_bytes_jstring(keys: &[JString]) -> usize {
+    keys.iter()
+        .map(|key| key.length() * if key.is_latin1() { 1 } else { 2 })
+        .sum()
+}
+
+fn main() {
+    println!("COMPACT_STRINGS = {COMPACT_STRINGS}");
+
+    let strings: Vec<String> = (0..KEYS).map(|i| format!("user:{i:08}")).collect();
+    let jstrings: Vec<JString> = strings.iter().map(|s| JString::from(s.as_str())).collect();
+    println!(
+        "{:<40} {:>12} bytes",
+        "String heap for keys",
+        heap_bytes_string(&strings)
+    );
+    println!(
+        "{:<40} {:>12} bytes",
+        "JString heap for keys",
+        heap_bytes_jstring(&jstrings)
+    );
+
+    bench("String::from (1M keys)", 5, || {
+        strings.iter().map(|s| s.to_string()).collect::<Vec<_>>()
+    });
+    bench("JString::from (1M keys)", 5, || {
+        strings
+            .iter()
+            .map(|s| JString::from(s.as_str()))
+            .collect::<Vec<_>>()
+    });
+
+    bench("String UTF-16 length (1M keys)", 5, || {
+        strings
+            .iter()
+            .map(|s| s.java_chars().count())
+            .sum::<usize>()
+    });
+    bench("JString length (1M keys)", 5, || {
+        jstrings.iter().map(JString::length).sum::<usize>()
+    });
+
+    bench("String char_at(10) (1M keys)", 5, || {
+        strings
+            .iter()
+            .filter_map(|s| s.java_chars().nth(10))
+            .map(|unit| unit as u64)
+            .sum::<u64>()
+    });
+    bench("JString char_at(10) (1M keys)", 5, || {
+        jstrings
+            .iter()
+            .filter_map(|s| s.char_at(10))
+            .map(|unit| unit as u64)
+            .sum::<u64>()
+    });
+
+    bench("JString hash_code (1M keys)", 5, || {
+        jstrings
+            .iter()
+            .map(JString::hash_code)
+            .fold(0, i32::wrapping_add)
+    });
+
+    let wide: Vec<JString> = strings
+        .iter()
+        .map(|s| JString::from(format!("{s}\u{3b1}").as_str()))
+        .collect();
+    bench("JString UTF-16 char_at(10) (1M keys)", 5, || {
+        wide.iter()
+            .filter_map(|s| s.char_at(10))
+            .map(|unit| unit as u64)
+            .sum::<u64>()
+    });
+
+    let mut sorted = strings.clone();
+    bench("String sort (1M keys)", 1, || {
+        sorted.clone_from(&strings);
+        sorted.sort();
+    });
+    let mut sorted = jstrings.clone();
+    bench("JString sort (1M keys)", 1, || {
+        sorted.clone_from(&jstrings);
+        sorted.sort();
+    });
+}
@@ -0,0 +1,593 @@
+//! `java.lang.Character`: Unicode properties of code points, with the same answers as the
+//! JDK the tables were generated from (see `tools/GenCharacterTables.java`).
+//!
+//! Code points are Java `int`s and UTF-16 code units are `u16`s, so unpaired surrogates and
+//! out-of-range values can be asked about just like in Java.
+
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::errors::{IllegalArgumentException, Throwable};
+
+#[rustfmt::skip]
+mod tables;
+
+pub use tables::{UnicodeBlock, UnicodeScript};
+
+/// The Unicode version of the embedded tables.
+pub const UNICODE_VERSION: &str = tables::UNICODE_VERSION;
+
+pub const MIN_RADIX: i32 = 2;
+pub const MAX_RADIX: i32 = 36;
+
+pub const MIN_HIGH_SURROGATE: u16 = 0xD800;
+pub const MAX_HIGH_SURROGATE: u16 = 0xDBFF;
+pub const MIN_LOW_SURROGATE: u16 = 0xDC00;
+pub const MAX_LOW_SURROGATE: u16 = 0xDFFF;
+pub const MIN_SURROGATE: u16 = MIN_HIGH_SURROGATE;
+pub const MAX_SURROGATE: u16 = MAX_LOW_SURROGATE;
+
+pub const MIN_CODE_POINT: i32 = 0;
+pub const MAX_CODE_POINT: i32 = 0x10FFFF;
+pub const MIN_SUPPLEMENTARY_CODE_POINT: i32 = 0x10000;
+
+// General categories, as returned by `get_type`.
+pub const UNASSIGNED: i8 = 0;
+pub const UPPERCASE_LETTER: i8 = 1;
+pub const LOWERCASE_LETTER: i8 = 2;
+pub const TITLECASE_LETTER: i8 = 3;
+pub const MODIFIER_LETTER: i8 = 4;
+pub const OTHER_LETTER: i8 = 5;
+pub const NON_SPACING_MARK: i8 = 6;
+pub const ENCLOSING_MARK: i8 = 7;
+pub const COMBINING_SPACING_MARK: i8 = 8;
+pub const DECIMAL_DIGIT_NUMBER: i8 = 9;
+pub const LETTER_NUMBER: i8 = 10;
+pub const OTHER_NUMBER: i8 = 11;
+pub const SPACE_SEPARATOR: i8 = 12;
+pub const LINE_SEPARATOR: i8 = 13;
+pub const PARAGRAPH_SEPARATOR: i8 = 14;
+pub const CONTROL: i8 = 15;
+pub const FORMAT: i8 = 16;
+pub const PRIVATE_USE: i8 = 18;
+pub const SURROGATE: i8 = 19;
+pub const DASH_PUNCTUATION: i8 = 20;
+pub const START_PUNCTUATION: i8 = 21;
+pub const END_PUNCTUATION: i8 = 22;
+pub const CONNECTOR_PUNCTUATION: i8 = 23;
+pub const OTHER_PUNCTUATION: i8 = 24;
+pub const MATH_SYMBOL: i8 = 25;
+pub const CURRENCY_SYMBOL: i8 = 26;
+pub const MODIFIER_SYMBOL: i8 = 27;
+pub const OTHER_SYMBOL: i8 = 28;
+pub const INITIAL_QUOTE_PUNCTUATION: i8 = 29;
+pub const FINAL_QUOTE_PUNCTUATION: i8 = 30;
+
+fn find<T>(table: &[T], code_point: i32, range: impl Fn(&T) -> (u32, u32)) -> Option<&T> {
+    let cp = code_point as u32;
+    table
+        .binary_search_by(|entry| {
+            let (start, end) = range(entry);
+            if end < cp {
+                std::cmp::Ordering::Less
+            } else if start > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| &table[index])
+}
+
+fn in_ranges(table: &[(u32, u32)], code_point: i32) -> bool {
+    find(table, code_point, |&range| range).is_some()
+}
+
+fn map_case(table: &[(u32, u32)], code_point: i32) -> Option<i32> {
+    find(table, code_point, |&(cp, _)| (cp, cp)).map(|&(_, mapped)| mapped as i32)
+}
+
+fn invalid_code_point(code_point: i32) -> ! {
+    IllegalArgumentException::new(
+        format!("Not a valid Unicode code point: 0x{code_point:X}"),
+        None,
+    )
+    .throw()
+}
+
+/// The general category of a code point, one of the constants in this module.
+pub fn get_type(code_point: i32) -> i8 {
+    find(&tables::CATEGORIES, code_point, |&(start, end, _)| {
+        (start, end)
+    })
+    .map_or(UNASSIGNED, |&(_, _, category)| category)
+}
+
+pub fn is_defined(code_point: i32) -> bool {
+    get_type(code_point) != UNASSIGNED
+}
+
+pub fn is_letter(code_point: i32) -> bool {
+    matches!(
+        get_type(code_point),
+        UPPERCASE_LETTER | LOWERCASE_LETTER | TITLECASE_LETTER | MODIFIER_LETTER | OTHER_LETTER
+    )
+}
+
+pub fn is_digit(code_point: i32) -> bool {
+    get_type(code_point) == DECIMAL_DIGIT_NUMBER
+}
+
+pub fn is_letter_or_digit(code_point: i32) -> bool {
+    is_letter(code_point) || is_digit(code_point)
+}
+
+pub fn is_alphabetic(code_point: i32) -> bool {
+    is_letter(code_point)
+        || get_type(code_point) == LETTER_NUMBER
+        || in_ranges(&tables::OTHER_ALPHABETIC, code_point)
+}
+
+pub fn is_ideographic(code_point: i32) -> bool {
+    in_ranges(&tables::IDEOGRAPHIC, code_point)
+}
+
+pub fn is_upper_case(code_point: i32) -> bool {
+    get_type(code_point) == UPPERCASE_LETTER || in_ranges(&tables::OTHER_UPPERCASE, code_point)
+}
+
+pub fn is_lower_case(code_point: i32) -> bool {
+    get_type(code_point) == LOWERCASE_LETTER || in_ranges(&tables::OTHER_LOWERCASE, code_point)
+}
+
+pub fn is_title_case(code_point: i32) -> bool {
+    get_type(code_point) == TITLECASE_LETTER
+}
+
+pub fn is_mirrored(code_point: i32) -> bool {
+    in_ranges(&tables::MIRRORED, code_point)
+}
+
+pub fn is_iso_control(code_point: i32) -> bool {
+    (0x00..=0x1F).contains(&code_point) || (0x7F..=0x9F).contains(&code_point)
+}
+
+/// Whether the code point is a Unicode space separator (Zs, Zl or Zp), including
+/// non-breaking spaces.
+pub fn is_space_char(code_point: i32) -> bool {
+    matches!(
+        get_type(code_point),
+        SPACE_SEPARATOR | LINE_SEPARATOR | PARAGRAPH_SEPARATOR
+    )
+}
+
+/// Java's notion of whitespace: space separators other than the non-breaking ones, plus
+/// the ASCII control characters tab, line feed, vertical tab, form feed, carriage return
+/// and the four information separators U+001C to U+001F.
+pub fn is_whitespace(code_point: i32) -> bool {
+    match code_point {
+        0x09..=0x0D | 0x1C..=0x1F => true,
+        0x00A0 | 0x2007 | 0x202F => false,
+        _ => is_space_char(code_point),
+    }
+}
+
+/// Characters that may appear in an identifier but are ignored when comparing, such as
+/// non-whitespace ISO controls and format characters.
+pub fn is_identifier_ignorable(code_point: i32) -> bool {
+    matches!(code_point, 0x00..=0x08 | 0x0E..=0x1B | 0x7F..=0x9F) || get_type(code_point) == FORMAT
+}
+
+pub fn is_java_identifier_start(code_point: i32) -> bool {
+    is_letter(code_point)
+        || matches!(
+            get_type(code_point),
+            LETTER_NUMBER | CURRENCY_SYMBOL | CONNECTOR_PUNCTUATION
+        )
+}
+
+pub fn is_java_identifier_part(code_point: i32) -> bool {
+    is_letter(code_point)
+        || matches!(
+            get_type(code_point),
+            CURRENCY_SYMBOL
+                | CONNECTOR_PUNCTUATION
+                | DECIMAL_DIGIT_NUMBER
+                | LETTER_NUMBER
+                | COMBINING_SPACING_MARK
+                | NON_SPACING_MARK
+        )
+        || is_identifier_ignorable(code_point)
+}
+
+/// The numeric value of a code point: its digit or number value, 10 to 35 for Latin
+/// letters, -2 if the value is not a non-negative integer (like ½), and -1 if it has none.
+pub fn get_numeric_value(code_point: i32) -> i32 {
+    find(
+        &tables::NUMERIC_VALUES,
+        code_point,
+        |&(start, end, _, _)| (start, end),
+    )
+    .map_or(-1, |&(start, _, value, step)| {
+        value + (code_point - start as i32) * step
+    })
+}
+
+fn is_latin_letter(code_point: i32) -> bool {
+    matches!(
+        code_point,
+        0x41..=0x5A | 0x61..=0x7A | 0xFF21..=0xFF3A | 0xFF41..=0xFF5A
+    )
+}
+
+/// The value of the code point as a digit in `radix`, or -1 if it is not one.
+///
+/// Decimal digits from any script count, as do ASCII and fullwidth Latin letters.
+pub fn digit(code_point: i32, radix: i32) -> i32 {
+    if !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+        return -1;
+    }
+    let value = if is_digit(code_point) || is_latin_letter(code_point) {
+        get_numeric_value(code_point)
+    } else {
+        -1
+    };
+    if value < radix {
+        value
+    } else {
+        -1
+    }
+}
+
+/// The lowercase character for `digit` in `radix`, or `'\0'` if either is out of range.
+pub fn for_digit(digit: i32, radix: i32) -> char {
+    if digit >= radix || digit < 0 || !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+        '\0'
+    } else {
+        char::from_digit(digit as u32, radix as u32).unwrap()
+    }
+}
+
+/// The simple (single code point) uppercase mapping.
+pub fn to_upper_case(code_point: i32) -> i32 {
+    map_case(&tables::UPPER_CASE, code_point).unwrap_or(code_point)
+}
+
+/// The simple (single code point) lowercase mapping.
+pub fn to_lower_case(code_point: i32) -> i32 {
+    map_case(&tables::LOWER_CASE, code_point).unwrap_or(code_point)
+}
+
+/// The simple titlecase mapping, which is the uppercase mapping except for digraphs like ǆ.
+pub fn to_title_case(code_point: i32) -> i32 {
+    map_case(&tables::TITLE_CASE, code_point).unwrap_or_else(|| to_upper_case(code_point))
+}
+
+/// The full uppercase mapping, which is more than one char for characters like ß; Java's
+/// package-private `Character.toUpperCaseCharArray`.
+pub(crate) fn to_upper_case_char_array(code_point: i32) -> Vec<u16> {
+    match find(&tables::UPPER_CASE_EXPANSIONS, code_point, |&(cp, _)| {
+        (cp, cp)
+    }) {
+        Some(&(_, units)) => units.to_vec(),
+        None => to_chars(to_upper_case(code_point)),
+    }
+}
+
+/// The canonical combining class, 0 for starters; `sun.text.Normalizer.getCombiningClass`.
+pub(crate) fn get_combining_class(code_point: i32) -> u8 {
+    find(
+        &tables::COMBINING_CLASSES,
+        code_point,
+        |&(start, end, _)| (start, end),
+    )
+    .map_or(0, |&(_, _, combining_class)| combining_class)
+}
+
+pub fn is_high_surrogate(ch: u16) -> bool {
+    (MIN_HIGH_SURROGATE..=MAX_HIGH_SURROGATE).contains(&ch)
+}
+
+pub fn is_low_surrogate(ch: u16) -> bool {
+    (MIN_LOW_SURROGATE..=MAX_LOW_SURROGATE).contains(&ch)
+}
+
+pub fn is_surrogate(ch: u16) -> bool {
+    (MIN_SURROGATE..=MAX_SURROGATE).contains(&ch)
+}
+
+pub fn is_surrogate_pair(high: u16, low: u16) -> bool {
+    is_high_surrogate(high) && is_low_surrogate(low)
+}
+
+/// Combines a surrogate pair; the result is meaningless if `is_surrogate_pair` is false.
+pub fn to_code_point(high: u16, low: u16) -> i32 {
+    ((high as i32) << 10)
+        + (low as i32)
+        + (MIN_SUPPLEMENTARY_CODE_POINT
+            - ((MIN_HIGH_SURROGATE as i32) << 10)
+            - MIN_LOW_SURROGATE as i32)
+}
+
+pub fn is_valid_code_point(code_point: i32) -> bool {
+    (MIN_CODE_POINT..=MAX_CODE_POINT).contains(&code_point)
+}
+
+pub fn is_bmp_code_point(code_point: i32) -> bool {
+    (code_point as u32) >> 16 == 0
+}
+
+pub fn is_supplementary_code_point(code_point: i32) -> bool {
+    (MIN_SUPPLEMENTARY_CODE_POINT..=MAX_CODE_POINT).contains(&code_point)
+}
+
+/// The number of UTF-16 code units needed for the code point: 2 if supplementary, else 1.
+pub fn char_count(code_point: i32) -> usize {
+    if code_point >= MIN_SUPPLEMENTARY_CODE_POINT {
+        2
+    } else {
+        1
+    }
+}
+
+/// The leading surrogate of a supplementary code point.
+pub fn high_surrogate(code_point: i32) -> u16 {
+    (((code_point as u32) >> 10) + (MIN_HIGH_SURROGATE as u32 - (0x10000 >> 10))) as u16
+}
+
+/// The trailing surrogate of a supplementary code point.
+pub fn low_surrogate(code_point: i32) -> u16 {
+    ((code_point as u32 & 0x3FF) + MIN_LOW_SURROGATE as u32) as u16
+}
+
+/// The UTF-16 encoding of a code point; throws `IllegalArgumentException` if it is not
+/// a valid one.
+pub fn to_chars(code_point: i32) -> Vec<u16> {
+    let mut chars = vec![0; char_count(code_point)];
+    to_chars_into(code_point, &mut chars, 0);
+    chars
+}
+
+/// Writes the UTF-16 encoding of a code point to `dest` at `dest_index` and returns the
+/// number of units written.
+pub fn to_chars_into(code_point: i32, dest: &mut [u16], dest_index: usize) -> usize {
+    if is_bmp_code_point(code_point) {
+        dest[dest_index] = code_point as u16;
+        1
+    } else if is_valid_code_point(code_point) {
+        dest[dest_index] = high_surrogate(code_point);
+        dest[dest_index + 1] = low_surrogate(code_point);
+        2
+    } else {
+        invalid_code_point(code_point)
+    }
+}
+
+struct Names {
+    words: Vec<&'static str>,
+    entries: Vec<(u32, usize)>,
+}
+
+static NAME_DATA: &[u8] = include_bytes!("names.dat");
+
+fn names() -> &'static Names {
+    static NAMES: OnceLock<Names> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        let data = NAME_DATA;
+        let mut pos = 2;
+        let word_count = u16::from_be_bytes([data[0], data[1]]) as usize;
+        let mut words = Vec::with_capacity(word_count);
+        for _ in 0..word_count {
+            let len = data[pos] as usize;
+            words.push(std::str::from_utf8(&data[pos + 1..pos + 1 + len]).unwrap());
+            pos += 1 + len;
+        }
+
+        let name_count = u32::from_be_bytes(data[pos..pos + 4].try_into().unwrap()) as usize;
+        pos += 4;
+        let mut entries = Vec::with_capacity(name_count);
+        for _ in 0..name_count {
+            let cp = u32::from_be_bytes([0, data[pos], data[pos + 1], data[pos + 2]]);
+            entries.push((cp, pos + 3));
+            let word_count = data[pos + 3];
+            pos += 4;
+            for _ in 0..word_count {
+                pos += if data[pos] & 0x80 == 0 { 1 } else { 2 };
+            }
+        }
+        Names { words, entries }
+    })
+}
+
+/// The Unicode name of a code point, or `None` if it is unassigned.
+///
+/// Like the JDK, characters without an individual name (such as CJK ideographs or private
+/// use characters) are named after their block followed by their hex value.
+pub fn get_name(code_point: i32) -> Option<String> {
+    if !is_valid_code_point(code_point) {
+        invalid_code_point(code_point)
+    }
+
+    let names = names();
+    if let Ok(index) = names
+        .entries
+        .binary_search_by_key(&(code_point as u32), |&(cp, _)| cp)
+    {
+        let mut pos = names.entries[index].1;
+        let word_count = NAME_DATA[pos];
+        pos += 1;
+        let mut name = String::new();
+        for i in 0..word_count {
+            let word = if NAME_DATA[pos] & 0x80 == 0 {
+                pos += 1;
+                NAME_DATA[pos - 1] as usize
+            } else {
+                pos += 2;
+                (u16::from_be_bytes([NAME_DATA[pos - 2], NAME_DATA[pos - 1]]) & 0x7FFF) as usize
+            };
+            if i > 0 {
+                name.push(' ');
+            }
+            name.push_str(names.words[word]);
+        }
+        return Some(name);
+    }
+
+    if !is_defined(code_point) {
+        return None;
+    }
+    Some(match UnicodeBlock::of(code_point) {
+        Some(block) => format!("{} {:X}", block.name().replace('_', " "), code_point),
+        None => format!("{code_point:X}"),
+    })
+}
+
+/// Java-style name lookups ignore case and treat spaces and underscores alike.
+fn name_matches(java_name: &str, name: &str) -> bool {
+    java_name.eq_ignore_ascii_case(&name.replace(' ', "_"))
+        || java_name.replace('_', "").eq_ignore_ascii_case(name)
+}
+
+impl UnicodeBlock {
+    /// The block containing the code point, or `None` if it is in none. Throws
+    /// `IllegalArgumentException` for invalid code points.
+    pub fn of(code_point: i32) -> Option<Self> {
+        if !is_valid_code_point(code_point) {
+            invalid_code_point(code_point)
+        }
+        find(&tables::BLOCKS, code_point, |&(start, end, _)| (start, end))
+            .map(|&(_, _, block)| block)
+    }
+
+    /// Looks a block up by its Java constant name (`"BASIC_LATIN"`) or its Unicode name
+    /// with or without spaces (`"Basic Latin"`, `"BasicLatin"`), ignoring case.
+    pub fn for_name(name: &str) -> Result<Self, IllegalArgumentException> {
+        tables::ALL_BLOCKS
+            .iter()
+            .copied()
+            .find(|block| name_matches(block.name(), name))
+            .ok_or_else(|| {
+                IllegalArgumentException::new(format!("Not a valid block name: {name}"), None)
+            })
+    }
+
+    /// The Java constant name, such as `"BASIC_LATIN"`.
+    pub fn name(self) -> &'static str {
+        tables::BLOCK_NAMES[self as usize]
+    }
+}
+
+impl Display for UnicodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl UnicodeScript {
+    /// The script of the code point; `Unknown` if it is unassigned. Throws
+    /// `IllegalArgumentException` for invalid code points.
+    pub fn of(code_point: i32) -> Self {
+        if !is_valid_code_point(code_point) {
+            invalid_code_point(code_point)
+        }
+        find(&tables::SCRIPTS, code_point, |&(start, end, _)| {
+            (start, end)
+        })
+        .map_or(UnicodeScript::Unknown, |&(_, _, script)| script)
+    }
+
+    /// Looks a script up by its Java constant name (`"OLD_ITALIC"`), ignoring case.
+    pub fn for_name(name: &str) -> Result<Self, IllegalArgumentException> {
+        tables::ALL_SCRIPTS
+            .iter()
+            .copied()
+            .find(|script| name_matches(script.name(), name))
+            .ok_or_else(|| {
+                IllegalArgumentException::new(
+                    format!(
+                        "No enum constant java.lang.Character.UnicodeScript.{}",
+                        name.to_uppercase()
+                    ),
+                    None,
+                )
+            })
+    }
+
+    /// The Java constant name, such as `"OLD_ITALIC"`.
+    pub fn name(self) -> &'static str {
+        tables::SCRIPT_NAMES[self as usize]
+    }
+}
+
+impl Display for UnicodeScript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn properties_match_jdk() {
+        assert_eq!(get_type('A' as i32), UPPERCASE_LETTER);
+        assert_eq!(get_type(0x0378), UNASSIGNED);
+        assert_eq!(get_type(0x1F600), OTHER_SYMBOL);
+        assert!(is_java_identifier_start('$' as i32));
+        assert!(!is_java_identifier_start('1' as i32));
+        assert!(is_java_identifier_part(0x0000));
+        assert!(is_whitespace('\u{1F}' as i32));
+        assert!(!is_whitespace(0x00A0) && is_space_char(0x00A0));
+
+        assert_eq!(digit('7' as i32, 10), 7);
+        assert_eq!(digit('z' as i32, 36), 35);
+        assert_eq!(digit(0x0669, 10), 9);
+        assert_eq!(digit('a' as i32, 10), -1);
+        assert_eq!(for_digit(11, 16), 'b');
+        assert_eq!(get_numeric_value(0x216C), 50);
+        assert_eq!(get_numeric_value(0x00BD), -2);
+        assert_eq!(get_numeric_value(0xFF3A), 35);
+
+        assert_eq!(to_upper_case(0x00DF), 0x00DF);
+        assert_eq!(to_lower_case(0x0130), 'i' as i32);
+        assert_eq!(to_title_case(0x01C6), 0x01C5);
+        assert_eq!(to_title_case(0x01C5), 0x01C5);
+    }
+
+    #[test]
+    fn surrogates() {
+        assert_eq!(to_chars(0x1F600), [0xD83D, 0xDE00]);
+        assert_eq!(to_code_point(0xD83D, 0xDE00), 0x1F600);
+        assert_eq!(char_count(0x1F600), 2);
+        assert!(is_surrogate_pair(0xD83D, 0xDE00));
+        assert_eq!(get_type(0xD800), SURROGATE);
+    }
+
+    #[test]
+    fn names_and_blocks() {
+        assert_eq!(get_name('a' as i32).unwrap(), "LATIN SMALL LETTER A");
+        assert_eq!(get_name(0x1F600).unwrap(), "GRINNING FACE");
+        assert_eq!(get_name(0x4E00).unwrap(), "CJK UNIFIED IDEOGRAPHS 4E00");
+        assert_eq!(get_name(0x0378), None);
+
+        assert_eq!(UnicodeBlock::of('a' as i32), Some(UnicodeBlock::BasicLatin));
+        assert_eq!(
+            UnicodeBlock::for_name("Latin 1 Supplement").unwrap(),
+            UnicodeBlock::Latin1Supplement
+        );
+        assert_eq!(UnicodeScript::of(0x03B1), UnicodeScript::Greek);
+        assert_eq!(UnicodeScript::of(0x0378), UnicodeScript::Unknown);
+        assert_eq!(
+            UnicodeScript::for_name("old_italic").unwrap().name(),
+            "OLD_ITALIC"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a valid Unicode code point: 0x110000")]
+    fn invalid_code_point_name() {
+        get_name(0x110000);
+    }
+}
@@ -0,0 +1,10182 @@
+// Generated by tools/GenCharacterTables.java from JDK 17. Do not edit by hand.
+
+pub(super) const UNICODE_VERSION: &str = "13.0";
+
+pub(super) static CATEGORIES: [(u32, u32, i8); 3199] = [
+    (0x0000, 0x001F, 15),
+    (0x0020, 0x0020, 12),
+    (0x0021, 0x0023, 24),
+    (0x0024, 0x0024, 26),
+    (0x0025, 0x0027, 24),
+    (0x0028, 0x0028, 21),
+    (0x0029, 0x0029, 22),
+    (0x002A, 0x002A, 24),
+    (0x002B, 0x002B, 25),
+    (0x002C, 0x002C, 24),
+    (0x002D, 0x002D, 20),
+    (0x002E, 0x002F, 24),
+    (0x0030, 0x0039, 9),
+    (0x003A, 0x003B, 24),
+    (0x003C, 0x003E, 25),
+    (0x003F, 0x0040, 24),
+    (0x0041, 0x005A, 1),
+    (0x005B, 0x005B, 21),
+    (0x005C, 0x005C, 24),
+    (0x005D, 0x005D, 22),
+    (0x005E, 0x005E, 27),
+    (0x005F, 0x005F, 23),
+    (0x0060, 0x0060, 27),
+    (0x0061, 0x007A, 2),
+    (0x007B, 0x007B, 21),
+    (0x007C, 0x007C, 25),
+    (0x007D, 0x007D, 22),
+    (0x007E, 0x007E, 25),
+    (0x007F, 0x009F, 15),
+    (0x00A0, 0x00A0, 12),
+    (0x00A1, 0x00A1, 24),
+    (0x00A2, 0x00A5, 26),
+    (0x00A6, 0x00A6, 28),
+    (0x00A7, 0x00A7, 24),
+    (0x00A8, 0x00A8, 27),
+    (0x00A9, 0x00A9, 28),
+    (0x00AA, 0x00AA, 5),
+    (0x00AB, 0x00AB, 29),
+    (0x00AC, 0x00AC, 25),
+    (0x00AD, 0x00AD, 16),
+    (0x00AE, 0x00AE, 28),
+    (0x00AF, 0x00AF, 27),
+    (0x00B0, 0x00B0, 28),
+    (0x00B1, 0x00B1, 25),
+    (0x00B2, 0x00B3, 11),
+    (0x00B4, 0x00B4, 27),
+    (0x00B5, 0x00B5, 2),
+    (0x00B6, 0x00B7, 24),
+    (0x00B8, 0x00B8, 27),
+    (0x00B9, 0x00B9, 11),
+    (0x00BA, 0x00BA, 5),
+    (0x00BB, 0x00BB, 30),
+    (0x00BC, 0x00BE, 11),
+    (0x00BF, 0x00BF, 24),
+    (0x00C0, 0x00D6, 1),
+    (0x00D7, 0x00D7, 25),
+    (0x00D8, 0x00DE, 1),
+    (0x00DF, 0x00F6, 2),
+    (0x00F7, 0x00F7, 25),
+    (0x00F8, 0x00FF, 2),
+    (0x0100, 0x0100, 1),
+    (0x0101, 0x0101, 2),
+    (0x0102, 0x0102, 1),
+    (0x0103, 0x0103, 2),
+    (0x0104, 0x0104, 1),
+    (0x0105, 0x0105, 2),
+    (0x0106, 0x0106, 1),
+    (0x0107, 0x0107, 2),
+    (0x0108, 0x0108, 1),
+    (0x0109, 0x0109, 2),
+    (0x010A, 0x010A, 1),
+    (0x010B, 0x010B, 2),
+    (0x010C, 0x010C, 1),
+    (0x010D, 0x010D, 2),
+    (0x010E, 0x010E, 1),
+    (0x010F, 0x010F, 2),
+    (0x0110, 0x0110, 1),
+    (0x0111, 0x0111, 2),
+    (0x0112, 0x0112, 1),
+    (0x0113, 0x0113, 2),
+    (0x0114, 0x0114, 1),
+    (0x0115, 0x0115, 2),
+    (0x0116, 0x0116, 1),
+    (0x0117, 0x0117, 2),
+    (0x0118, 0x0118, 1),
+    (0x0119, 0x0119, 2),
+    (0x011A, 0x011A, 1),
+    (0x011B, 0x011B, 2),
+    (0x011C, 0x011C, 1),
+    (0x011D, 0x011D, 2),
+    (0x011E, 0x011E, 1),
+    (0x011F, 0x011F, 2),
+    (0x0120, 0x0120, 1),
+    (0x0121, 0x0121, 2),
+    (0x0122, 0x0122, 1),
+    (0x0123, 0x0123, 2),
+    (0x0124, 0x0124, 1),
+    (0x0125, 0x0125, 2),
+    (0x0126, 0x0126, 1),
+    (0x0127, 0x0127, 2),
+    (0x0128, 0x0128, 1),
+    (0x0129, 0x0129, 2),
+    (0x012A, 0x012A, 1),
+    (0x012B, 0x012B, 2),
+    (0x012C, 0x012C, 1),
+    (0x012D, 0x012D, 2),
+    (0x012E, 0x012E, 1),
+    (0x012F, 0x012F, 2),
+    (0x0130, 0x0130, 1),
+    (0x0131, 0x0131, 2),
+    (0x0132, 0x0132, 1),
+    (0x0133, 0x0133, 2),
+    (0x0134, 0x0134, 1),
+    (0x0135, 0x0135, 2),
+    (0x0136, 0x0136, 1),
+    (0x0137, 0x0138, 2),
+    (0x0139, 0x0139, 1),
+    (0x013A, 0x013A, 2),
+    (0x013B, 0x013B, 1),
+    (0x013C, 0x013C, 2),
+    (0x013D, 0x013D, 1),
+    (0x013E, 0x013E, 2),
+    (0x013F, 0x013F, 1),
+    (0x0140, 0x0140, 2),
+    (0x0141, 0x0141, 1),
+    (0x0142, 0x0142, 2),
+    (0x0143, 0x0143, 1),
+    (0x0144, 0x0144, 2),
+    (0x0145, 0x0145, 1),
+    (0x0146, 0x0146, 2),
+    (0x0147, 0x0147, 1),
+    (0x0148, 0x0149, 2),
+    (0x014A, 0x014A, 1),
+    (0x014B, 0x014B, 2),
+    (0x014C, 0x014C, 1),
+    (0x014D, 0x014D, 2),
+    (0x014E, 0x014E, 1),
+    (0x014F, 0x014F, 2),
+    (0x0150, 0x0150, 1),
+    (0x0151, 0x0151, 2),
+    (0x0152, 0x0152, 1),
+    (0x0153, 0x0153, 2),
+    (0x0154, 0x0154, 1),
+    (0x0155, 0x0155, 2),
+    (0x0156, 0x0156, 1),
+    (0x0157, 0x0157, 2),
+    (0x0158, 0x0158, 1),
+    (0x0159, 0x0159, 2),
+    (0x015A, 0x015A, 1),
+    (0x015B, 0x015B, 2),
+    (0x015C, 0x015C, 1),
+    (0x015D, 0x015D, 2),
+    (0x015E, 0x015E, 1),
+    (0x015F, 0x015F, 2),
+    (0x0160, 0x0160, 1),
+    (0x0161, 0x0161, 2),
+    (0x0162, 0x0162, 1),
+    (0x0163, 0x0163, 2),
+    (0x0164, 0x0164, 1),
+    (0x0165, 0x0165, 2),
+    (0x0166, 0x0166, 1),
+    (0x0167, 0x0167, 2),
+    (0x0168, 0x0168, 1),
+    (0x0169, 0x0169, 2),
+    (0x016A, 0x016A, 1),
+    (0x016B, 0x016B, 2),
+    (0x016C, 0x016C, 1),
+    (0x016D, 0x016D, 2),
+    (0x016E, 0x016E, 1),
+    (0x016F, 0x016F, 2),
+    (0x0170, 0x0170, 1),
+    (0x0171, 0x0171, 2),
+    (0x0172, 0x0172, 1),
+    (0x0173, 0x0173, 2),
+    (0x0174, 0x0174, 1),
+    (0x0175, 0x0175, 2),
+    (0x0176, 0x0176, 1),
+    (0x0177, 0x0177, 2),
+    (0x0178, 0x0179, 1),
+    (0x017A, 0x017A, 2),
+    (0x017B, 0x017B, 1),
+    (0x017C, 0x017C, 2),
+    (0x017D, 0x017D, 1),
+    (0x017E, 0x0180, 2),
+    (0x0181, 0x0182, 1),
+    (0x0183, 0x0183, 2),
+    (0x0184, 0x0184, 1),
+    (0x0185, 0x0185, 2),
+    (0x0186, 0x0187, 1),
+    (0x0188, 0x0188, 2),
+    (0x0189, 0x018B, 1),
+    (0x018C, 0x018D, 2),
+    (0x018E, 0x0191, 1),
+    (0x0192, 0x0192, 2),
+    (0x0193, 0x0194, 1),
+    (0x0195, 0x0195, 2),
+    (0x0196, 0x0198, 1),
+    (0x0199, 0x019B, 2),
+    (0x019C, 0x019D, 1),
+    (0x019E, 0x019E, 2),
+    (0x019F, 0x01A0, 1),
+    (0x01A1, 0x01A1, 2),
+    (0x01A2, 0x01A2, 1),
+    (0x01A3, 0x01A3, 2),
+    (0x01A4, 0x01A4, 1),
+    (0x01A5, 0x01A5, 2),
+    (0x01A6, 0x01A7, 1),
+    (0x01A8, 0x01A8, 2),
+    (0x01A9, 0x01A9, 1),
+    (0x01AA, 0x01AB, 2),
+    (0x01AC, 0x01AC, 1),
+    (0x01AD, 0x01AD, 2),
+    (0x01AE, 0x01AF, 1),
+    (0x01B0, 0x01B0, 2),
+    (0x01B1, 0x01B3, 1),
+    (0x01B4, 0x01B4, 2),
+    (0x01B5, 0x01B5, 1),
+    (0x01B6, 0x01B6, 2),
+    (0x01B7, 0x01B8, 1),
+    (0x01B9, 0x01BA, 2),
+    (0x01BB, 0x01BB, 5),
+    (0x01BC, 0x01BC, 1),
+    (0x01BD, 0x01BF, 2),
+    (0x01C0, 0x01C3, 5),
+    (0x01C4, 0x01C4, 1),
+    (0x01C5, 0x01C5, 3),
+    (0x01C6, 0x01C6, 2),
+    (0x01C7, 0x01C7, 1),
+    (0x01C8, 0x01C8, 3),
+    (0x01C9, 0x01C9, 2),
+    (0x01CA, 0x01CA, 1),
+    (0x01CB, 0x01CB, 3),
+    (0x01CC, 0x01CC, 2),
+    (0x01CD, 0x01CD, 1),
+    (0x01CE, 0x01CE, 2),
+    (0x01CF, 0x01CF, 1),
+    (0x01D0, 0x01D0, 2),
+    (0x01D1, 0x01D1, 1),
+    (0x01D2, 0x01D2, 2),
+    (0x01D3, 0x01D3, 1),
+    (0x01D4, 0x01D4, 2),
+    (0x01D5, 0x01D5, 1),
+    (0x01D6, 0x01D6, 2),
+    (0x01D7, 0x01D7, 1),
+    (0x01D8, 0x01D8, 2),
+    (0x01D9, 0x01D9, 1),
+    (0x01DA, 0x01DA, 2),
+    (0x01DB, 0x01DB, 1),
+    (0x01DC, 0x01DD, 2),
+    (0x01DE, 0x01DE, 1),
+    (0x01DF, 0x01DF, 2),
+    (0x01E0, 0x01E0, 1),
+    (0x01E1, 0x01E1, 2),
+    (0x01E2, 0x01E2, 1),
+    (0x01E3, 0x01E3, 2),
+    (0x01E4, 0x01E4, 1),
+    (0x01E5, 0x01E5, 2),
+    (0x01E6, 0x01E6, 1),
+    (0x01E7, 0x01E7, 2),
+    (0x01E8, 0x01E8, 1),
+    (0x01E9, 0x01E9, 2),
+    (0x01EA, 0x01EA, 1),
+    (0x01EB, 0x01EB, 2),
+    (0x01EC, 0x01EC, 1),
+    (0x01ED, 0x01ED, 2),
+    (0x01EE, 0x01EE, 1),
+    (0x01EF, 0x01F0, 2),
+    (0x01F1, 0x01F1, 1),
+    (0x01F2, 0x01F2, 3),
+    (0x01F3, 0x01F3, 2),
+    (0x01F4, 0x01F4, 1),
+    (0x01F5, 0x01F5, 2),
+    (0x01F6, 0x01F8, 1),
+    (0x01F9, 0x01F9, 2),
+    (0x01FA, 0x01FA, 1),
+    (0x01FB, 0x01FB, 2),
+    (0x01FC, 0x01FC, 1),
+    (0x01FD, 0x01FD, 2),
+    (0x01FE, 0x01FE, 1),
+    (0x01FF, 0x01FF, 2),
+    (0x0200, 0x0200, 1),
+    (0x0201, 0x0201, 2),
+    (0x0202, 0x0202, 1),
+    (0x0203, 0x0203, 2),
+    (0x0204, 0x0204, 1),
+    (0x0205, 0x0205, 2),
+    (0x0206, 0x0206, 1),
+    (0x0207, 0x0207, 2),
+    (0x0208, 0x0208, 1),
+    (0x0209, 0x0209, 2),
+    (0x020A, 0x020A, 1),
+    (0x020B, 0x020B, 2),
+    (0x020C, 0x020C, 1),
+    (0x020D, 0x020D, 2),
+    (0x020E, 0x020E, 1),
+    (0x020F, 0x020F, 2),
+    (0x0210, 0x0210, 1),
+    (0x0211, 0x0211, 2),
+    (0x0212, 0x0212, 1),
+    (0x0213, 0x0213, 2),
+    (0x0214, 0x0214, 1),
+    (0x0215, 0x0215, 2),
+    (0x0216, 0x0216, 1),
+    (0x0217, 0x0217, 2),
+    (0x0218, 0x0218, 1),
+    (0x0219, 0x0219, 2),
+    (0x021A, 0x021A, 1),
+    (0x021B, 0x021B, 2),
+    (0x021C, 0x021C, 1),
+    (0x021D, 0x021D, 2),
+    (0x021E, 0x021E, 1),
+    (0x021F, 0x021F, 2),
+    (0x0220, 0x0220, 1),
+    (0x0221, 0x0221, 2),
+    (0x0222, 0x0222, 1),
+    (0x0223, 0x0223, 2),
+    (0x0224, 0x0224, 1),
+    (0x0225, 0x0225, 2),
+    (0x0226, 0x0226, 1),
+    (0x0227, 0x0227, 2),
+    (0x0228, 0x0228, 1),
+    (0x0229, 0x0229, 2),
+    (0x022A, 0x022A, 1),
+    (0x022B, 0x022B, 2),
+    (0x022C, 0x022C, 1),
+    (0x022D, 0x022D, 2),
+    (0x022E, 0x022E, 1),
+    (0x022F, 0x022F, 2),
+    (0x0230, 0x0230, 1),
+    (0x0231, 0x0231, 2),
+    (0x0232, 0x0232, 1),
+    (0x0233, 0x0239, 2),
+    (0x023A, 0x023B, 1),
+    (0x023C, 0x023C, 2),
+    (0x023D, 0x023E, 1),
+    (0x023F, 0x0240, 2),
+    (0x0241, 0x0241, 1),
+    (0x0242, 0x0242, 2),
+    (0x0243, 0x0246, 1),
+    (0x0247, 0x0247, 2),
+    (0x0248, 0x0248, 1),
+    (0x0249, 0x0249, 2),
+    (0x024A, 0x024A, 1),
+    (0x024B, 0x024B, 2),
+    (0x024C, 0x024C, 1),
+    (0x024D, 0x024D, 2),
+    (0x024E, 0x024E, 1),
+    (0x024F, 0x0293, 2),
+    (0x0294, 0x0294, 5),
+    (0x0295, 0x02AF, 2),
+    (0x02B0, 0x02C1, 4),
+    (0x02C2, 0x02C5, 27),
+    (0x02C6, 0x02D1, 4),
+    (0x02D2, 0x02DF, 27),
+    (0x02E0, 0x02E4, 4),
+    (0x02E5, 0x02EB, 27),
+    (0x02EC, 0x02EC, 4),
+    (0x02ED, 0x02ED, 27),
+    (0x02EE, 0x02EE, 4),
+    (0x02EF, 0x02FF, 27),
+    (0x0300, 0x036F, 6),
+    (0x0370, 0x0370, 1),
+    (0x0371, 0x0371, 2),
+    (0x0372, 0x0372, 1),
+    (0x0373, 0x0373, 2),
+    (0x0374, 0x0374, 4),
+    (0x0375, 0x0375, 27),
+    (0x0376, 0x0376, 1),
+    (0x0377, 0x0377, 2),
+    (0x037A, 0x037A, 4),
+    (0x037B, 0x037D, 2),
+    (0x037E, 0x037E, 24),
+    (0x037F, 0x037F, 1),
+    (0x0384, 0x0385, 27),
+    (0x0386, 0x0386, 1),
+    (0x0387, 0x0387, 24),
+    (0x0388, 0x038A, 1),
+    (0x038C, 0x038C, 1),
+    (0x038E, 0x038F, 1),
+    (0x0390, 0x0390, 2),
+    (0x0391, 0x03A1, 1),
+    (0x03A3, 0x03AB, 1),
+    (0x03AC, 0x03CE, 2),
+    (0x03CF, 0x03CF, 1),
+    (0x03D0, 0x03D1, 2),
+    (0x03D2, 0x03D4, 1),
+    (0x03D5, 0x03D7, 2),
+    (0x03D8, 0x03D8, 1),
+    (0x03D9, 0x03D9, 2),
+    (0x03DA, 0x03DA, 1),
+    (0x03DB, 0x03DB, 2),
+    (0x03DC, 0x03DC, 1),
+    (0x03DD, 0x03DD, 2),
+    (0x03DE, 0x03DE, 1),
+    (0x03DF, 0x03DF, 2),
+    (0x03E0, 0x03E0, 1),
+    (0x03E1, 0x03E1, 2),
+    (0x03E2, 0x03E2, 1),
+    (0x03E3, 0x03E3, 2),
+    (0x03E4, 0x03E4, 1),
+    (0x03E5, 0x03E5, 2),
+    (0x03E6, 0x03E6, 1),
+    (0x03E7, 0x03E7, 2),
+    (0x03E8, 0x03E8, 1),
+    (0x03E9, 0x03E9, 2),
+    (0x03EA, 0x03EA, 1),
+    (0x03EB, 0x03EB, 2),
+    (0x03EC, 0x03EC, 1),
+    (0x03ED, 0x03ED, 2),
+    (0x03EE, 0x03EE, 1),
+    (0x03EF, 0x03F3, 2),
+    (0x03F4, 0x03F4, 1),
+    (0x03F5, 0x03F5, 2),
+    (0x03F6, 0x03F6, 25),
+    (0x03F7, 0x03F7, 1),
+    (0x03F8, 0x03F8, 2),
+    (0x03F9, 0x03FA, 1),
+    (0x03FB, 0x03FC, 2),
+    (0x03FD, 0x042F, 1),
+    (0x0430, 0x045F, 2),
+    (0x0460, 0x0460, 1),
+    (0x0461, 0x0461, 2),
+    (0x0462, 0x0462, 1),
+    (0x0463, 0x0463, 2),
+    (0x0464, 0x0464, 1),
+    (0x0465, 0x0465, 2),
+    (0x0466, 0x0466, 1),
+    (0x0467, 0x0467, 2),
+    (0x0468, 0x0468, 1),
+    (0x0469, 0x0469, 2),
+    (0x046A, 0x046A, 1),
+    (0x046B, 0x046B, 2),
+    (0x046C, 0x046C, 1),
+    (0x046D, 0x046D, 2),
+    (0x046E, 0x046E, 1),
+    (0x046F, 0x046F, 2),
+    (0x0470, 0x0470, 1),
+    (0x0471, 0x0471, 2),
+    (0x0472, 0x0472, 1),
+    (0x0473, 0x0473, 2),
+    (0x0474, 0x0474, 1),
+    (0x0475, 0x0475, 2),
+    (0x0476, 0x0476, 1),
+    (0x0477, 0x0477, 2),
+    (0x0478, 0x0478, 1),
+    (0x0479, 0x0479, 2),
+    (0x047A, 0x047A, 1),
+    (0x047B, 0x047B, 2),
+    (0x047C, 0x047C, 1),
+    (0x047D, 0x047D, 2),
+    (0x047E, 0x047E, 1),
+    (0x047F, 0x047F, 2),
+    (0x0480, 0x0480, 1),
+    (0x0481, 0x0481, 2),
+    (0x0482, 0x0482, 28),
+    (0x0483, 0x0487, 6),
+    (0x0488, 0x0489, 7),
+    (0x048A, 0x048A, 1),
+    (0x048B, 0x048B, 2),
+    (0x048C, 0x048C, 1),
+    (0x048D, 0x048D, 2),
+    (0x048E, 0x048E, 1),
+    (0x048F, 0x048F, 2),
+    (0x0490, 0x0490, 1),
+    (0x0491, 0x0491, 2),
+    (0x0492, 0x0492, 1),
+    (0x0493, 0x0493, 2),
+    (0x0494, 0x0494, 1),
+    (0x0495, 0x0495, 2),
+    (0x0496, 0x0496, 1),
+    (0x0497, 0x0497, 2),
+    (0x0498, 0x0498, 1),
+    (0x0499, 0x0499, 2),
+    (0x049A, 0x049A, 1),
+    (0x049B, 0x049B, 2),
+    (0x049C, 0x049C, 1),
+    (0x049D, 0x049D, 2),
+    (0x049E, 0x049E, 1),
+    (0x049F, 0x049F, 2),
+    (0x04A0, 0x04A0, 1),
+    (0x04A1, 0x04A1, 2),
+    (0x04A2, 0x04A2, 1),
+    (0x04A3, 0x04A3, 2),
+    (0x04A4, 0x04A4, 1),
+    (0x04A5, 0x04A5, 2),
+    (0x04A6, 0x04A6, 1),
+    (0x04A7, 0x04A7, 2),
+    (0x04A8, 0x04A8, 1),
+    (0x04A9, 0x04A9, 2),
+    (0x04AA, 0x04AA, 1),
+    (0x04AB, 0x04AB, 2),
+    (0x04AC, 0x04AC, 1),
+    (0x04AD, 0x04AD, 2),
+    (0x04AE, 0x04AE, 1),
+    (0x04AF, 0x04AF, 2),
+    (0x04B0, 0x04B0, 1),
+    (0x04B1, 0x04B1, 2),
+    (0x04B2, 0x04B2, 1),
+    (0x04B3, 0x04B3, 2),
+    (0x04B4, 0x04B4, 1),
+    (0x04B5, 0x04B5, 2),
+    (0x04B6, 0x04B6, 1),
+    (0x04B7, 0x04B7, 2),
+    (0x04B8, 0x04B8, 1),
+    (0x04B9, 0x04B9, 2),
+    (0x04BA, 0x04BA, 1),
+    (0x04BB, 0x04BB, 2),
+    (0x04BC, 0x04BC, 1),
+    (0x04BD, 0x04BD, 2),
+    (0x04BE, 0x04BE, 1),
+    (0x04BF, 0x04BF, 2),
+    (0x04C0, 0x04C1, 1),
+    (0x04C2, 0x04C2, 2),
+    (0x04C3, 0x04C3, 1),
+    (0x04C4, 0x04C4, 2),
+    (0x04C5, 0x04C5, 1),
+    (0x04C6, 0x04C6, 2),
+    (0x04C7, 0x04C7, 1),
+    (0x04C8, 0x04C8, 2),
+    (0x04C9, 0x04C9, 1),
+    (0x04CA, 0x04CA, 2),
+    (0x04CB, 0x04CB, 1),
+    (0x04CC, 0x04CC, 2),
+    (0x04CD, 0x04CD, 1),
+    (0x04CE, 0x04CF, 2),
+    (0x04D0, 0x04D0, 1),
+    (0x04D1, 0x04D1, 2),
+    (0x04D2, 0x04D2, 1),
+    (0x04D3, 0x04D3, 2),
+    (0x04D4, 0x04D4, 1),
+    (0x04D5, 0x04D5, 2),
+    (0x04D6, 0x04D6, 1),
+    (0x04D7, 0x04D7, 2),
+    (0x04D8, 0x04D8, 1),
+    (0x04D9, 0x04D9, 2),
+    (0x04DA, 0x04DA, 1),
+    (0x04DB, 0x04DB, 2),
+    (0x04DC, 0x04DC, 1),
+    (0x04DD, 0x04DD, 2),
+    (0x04DE, 0x04DE, 1),
+    (0x04DF, 0x04DF, 2),
+    (0x04E0, 0x04E0, 1),
+    (0x04E1, 0x04E1, 2),
+    (0x04E2, 0x04E2, 1),
+    (0x04E3, 0x04E3, 2),
+    (0x04E4, 0x04E4, 1),
+    (0x04E5, 0x04E5, 2),
+    (0x04E6, 0x04E6, 1),
+    (0x04E7, 0x04E7, 2),
+    (0x04E8, 0x04E8, 1),
+    (0x04E9, 0x04E9, 2),
+    (0x04EA, 0x04EA, 1),
+    (0x04EB, 0x04EB, 2),
+    (0x04EC, 0x04EC, 1),
+    (0x04ED, 0x04ED, 2),
+    (0x04EE, 0x04EE, 1),
+    (0x04EF, 0x04EF, 2),
+    (0x04F0, 0x04F0, 1),
+    (0x04F1, 0x04F1, 2),
+    (0x04F2, 0x04F2, 1),
+    (0x04F3, 0x04F3, 2),
+    (0x04F4, 0x04F4, 1),
+    (0x04F5, 0x04F5, 2),
+    (0x04F6, 0x04F6, 1),
+    (0x04F7, 0x04F7, 2),
+    (0x04F8, 0x04F8, 1),
+    (0x04F9, 0x04F9, 2),
+    (0x04FA, 0x04FA, 1),
+    (0x04FB, 0x04FB, 2),
+    (0x04FC, 0x04FC, 1),
+    (0x04FD, 0x04FD, 2),
+    (0x04FE, 0x04FE, 1),
+    (0x04FF, 0x04FF, 2),
+    (0x0500, 0x0500, 1),
+    (0x0501, 0x0501, 2),
+    (0x0502, 0x0502, 1),
+    (0x0503, 0x0503, 2),
+    (0x0504, 0x0504, 1),
+    (0x0505, 0x0505, 2),
+    (0x0506, 0x0506, 1),
+    (0x0507, 0x0507, 2),
+    (0x0508, 0x0508, 1),
+    (0x0509, 0x0509, 2),
+    (0x050A, 0x050A, 1),
+    (0x050B, 0x050B, 2),
+    (0x050C, 0x050C, 1),
+    (0x050D, 0x050D, 2),
+    (0x050E, 0x050E, 1),
+    (0x050F, 0x050F, 2),
+    (0x0510, 0x0510, 1),
+    (0x0511, 0x0511, 2),
+    (0x0512, 0x0512, 1),
+    (0x0513, 0x0513, 2),
+    (0x0514, 0x0514, 1),
+    (0x0515, 0x0515, 2),
+    (0x0516, 0x0516, 1),
+    (0x0517, 0x0517, 2),
+    (0x0518, 0x0518, 1),
+    (0x0519, 0x0519, 2),
+    (0x051A, 0x051A, 1),
+    (0x051B, 0x051B, 2),
+    (0x051C, 0x051C, 1),
+    (0x051D, 0x051D, 2),
+    (0x051E, 0x051E, 1),
+    (0x051F, 0x051F, 2),
+    (0x0520, 0x0520, 1),
+    (0x0521, 0x0521, 2),
+    (0x0522, 0x0522, 1),
+    (0x0523, 0x0523, 2),
+    (0x0524, 0x0524, 1),
+    (0x0525, 0x0525, 2),
+    (0x0526, 0x0526, 1),
+    (0x0527, 0x0527, 2),
+    (0x0528, 0x0528, 1),
+    (0x0529, 0x0529, 2),
+    (0x052A, 0x052A, 1),
+    (0x052B, 0x052B, 2),
+    (0x052C, 0x052C, 1),
+    (0x052D, 0x052D, 2),
+    (0x052E, 0x052E, 1),
+    (0x052F, 0x052F, 2),
+    (0x0531, 0x0556, 1),
+    (0x0559, 0x0559, 4),
+    (0x055A, 0x055F, 24),
+    (0x0560, 0x0588, 2),
+    (0x0589, 0x0589, 24),
+    (0x058A, 0x058A, 20),
+    (0x058D, 0x058E, 28),
+    (0x058F, 0x058F, 26),
+    (0x0591, 0x05BD, 6),
+    (0x05BE, 0x05BE, 20),
+    (0x05BF, 0x05BF, 6),
+    (0x05C0, 0x05C0, 24),
+    (0x05C1, 0x05C2, 6),
+    (0x05C3, 0x05C3, 24),
+    (0x05C4, 0x05C5, 6),
+    (0x05C6, 0x05C6, 24),
+    (0x05C7, 0x05C7, 6),
+    (0x05D0, 0x05EA, 5),
+    (0x05EF, 0x05F2, 5),
+    (0x05F3, 0x05F4, 24),
+    (0x0600, 0x0605, 16),
+    (0x0606, 0x0608, 25),
+    (0x0609, 0x060A, 24),
+    (0x060B, 0x060B, 26),
+    (0x060C, 0x060D, 24),
+    (0x060E, 0x060F, 28),
+    (0x0610, 0x061A, 6),
+    (0x061B, 0x061B, 24),
+    (0x061C, 0x061C, 16),
+    (0x061E, 0x061F, 24),
+    (0x0620, 0x063F, 5),
+    (0x0640, 0x0640, 4),
+    (0x0641, 0x064A, 5),
+    (0x064B, 0x065F, 6),
+    (0x0660, 0x0669, 9),
+    (0x066A, 0x066D, 24),
+    (0x066E, 0x066F, 5),
+    (0x0670, 0x0670, 6),
+    (0x0671, 0x06D3, 5),
+    (0x06D4, 0x06D4, 24),
+    (0x06D5, 0x06D5, 5),
+    (0x06D6, 0x06DC, 6),
+    (0x06DD, 0x06DD, 16),
+    (0x06DE, 0x06DE, 28),
+    (0x06DF, 0x06E4, 6),
+    (0x06E5, 0x06E6, 4),
+    (0x06E7, 0x06E8, 6),
+    (0x06E9, 0x06E9, 28),
+    (0x06EA, 0x06ED, 6),
+    (0x06EE, 0x06EF, 5),
+    (0x06F0, 0x06F9, 9),
+    (0x06FA, 0x06FC, 5),
+    (0x06FD, 0x06FE, 28),
+    (0x06FF, 0x06FF, 5),
+    (0x0700, 0x070D, 24),
+    (0x070F, 0x070F, 16),
+    (0x0710, 0x0710, 5),
+    (0x0711, 0x0711, 6),
+    (0x0712, 0x072F, 5),
+    (0x0730, 0x074A, 6),
+    (0x074D, 0x07A5, 5),
+    (0x07A6, 0x07B0, 6),
+    (0x07B1, 0x07B1, 5),
+    (0x07C0, 0x07C9, 9),
+    (0x07CA, 0x07EA, 5),
+    (0x07EB, 0x07F3, 6),
+    (0x07F4, 0x07F5, 4),
+    (0x07F6, 0x07F6, 28),
+    (0x07F7, 0x07F9, 24),
+    (0x07FA, 0x07FA, 4),
+    (0x07FD, 0x07FD, 6),
+    (0x07FE, 0x07FF, 26),
+    (0x0800, 0x0815, 5),
+    (0x0816, 0x0819, 6),
+    (0x081A, 0x081A, 4),
+    (0x081B, 0x0823, 6),
+    (0x0824, 0x0824, 4),
+    (0x0825, 0x0827, 6),
+    (0x0828, 0x0828, 4),
+    (0x0829, 0x082D, 6),
+    (0x0830, 0x083E, 24),
+    (0x0840, 0x0858, 5),
+    (0x0859, 0x085B, 6),
+    (0x085E, 0x085E, 24),
+    (0x0860, 0x086A, 5),
+    (0x08A0, 0x08B4, 5),
+    (0x08B6, 0x08C7, 5),
+    (0x08D3, 0x08E1, 6),
+    (0x08E2, 0x08E2, 16),
+    (0x08E3, 0x0902, 6),
+    (0x0903, 0x0903, 8),
+    (0x0904, 0x0939, 5),
+    (0x093A, 0x093A, 6),
+    (0x093B, 0x093B, 8),
+    (0x093C, 0x093C, 6),
+    (0x093D, 0x093D, 5),
+    (0x093E, 0x0940, 8),
+    (0x0941, 0x0948, 6),
+    (0x0949, 0x094C, 8),
+    (0x094D, 0x094D, 6),
+    (0x094E, 0x094F, 8),
+    (0x0950, 0x0950, 5),
+    (0x0951, 0x0957, 6),
+    (0x0958, 0x0961, 5),
+    (0x0962, 0x0963, 6),
+    (0x0964, 0x0965, 24),
+    (0x0966, 0x096F, 9),
+    (0x0970, 0x0970, 24),
+    (0x0971, 0x0971, 4),
+    (0x0972, 0x0980, 5),
+    (0x0981, 0x0981, 6),
+    (0x0982, 0x0983, 8),
+    (0x0985, 0x098C, 5),
+    (0x098F, 0x0990, 5),
+    (0x0993, 0x09A8, 5),
+    (0x09AA, 0x09B0, 5),
+    (0x09B2, 0x09B2, 5),
+    (0x09B6, 0x09B9, 5),
+    (0x09BC, 0x09BC, 6),
+    (0x09BD, 0x09BD, 5),
+    (0x09BE, 0x09C0, 8),
+    (0x09C1, 0x09C4, 6),
+    (0x09C7, 0x09C8, 8),
+    (0x09CB, 0x09CC, 8),
+    (0x09CD, 0x09CD, 6),
+    (0x09CE, 0x09CE, 5),
+    (0x09D7, 0x09D7, 8),
+    (0x09DC, 0x09DD, 5),
+    (0x09DF, 0x09E1, 5),
+    (0x09E2, 0x09E3, 6),
+    (0x09E6, 0x09EF, 9),
+    (0x09F0, 0x09F1, 5),
+    (0x09F2, 0x09F3, 26),
+    (0x09F4, 0x09F9, 11),
+    (0x09FA, 0x09FA, 28),
+    (0x09FB, 0x09FB, 26),
+    (0x09FC, 0x09FC, 5),
+    (0x09FD, 0x09FD, 24),
+    (0x09FE, 0x09FE, 6),
+    (0x0A01, 0x0A02, 6),
+    (0x0A03, 0x0A03, 8),
+    (0x0A05, 0x0A0A, 5),
+    (0x0A0F, 0x0A10, 5),
+    (0x0A13, 0x0A28, 5),
+    (0x0A2A, 0x0A30, 5),
+    (0x0A32, 0x0A33, 5),
+    (0x0A35, 0x0A36, 5),
+    (0x0A38, 0x0A39, 5),
+    (0x0A3C, 0x0A3C, 6),
+    (0x0A3E, 0x0A40, 8),
+    (0x0A41, 0x0A42, 6),
+    (0x0A47, 0x0A48, 6),
+    (0x0A4B, 0x0A4D, 6),
+    (0x0A51, 0x0A51, 6),
+    (0x0A59, 0x0A5C, 5),
+    (0x0A5E, 0x0A5E, 5),
+    (0x0A66, 0x0A6F, 9),
+    (0x0A70, 0x0A71, 6),
+    (0x0A72, 0x0A74, 5),
+    (0x0A75, 0x0A75, 6),
+    (0x0A76, 0x0A76, 24),
+    (0x0A81, 0x0A82, 6),
+    (0x0A83, 0x0A83, 8),
+    (0x0A85, 0x0A8D, 5),
+    (0x0A8F, 0x0A91, 5),
+    (0x0A93, 0x0AA8, 5),
+    (0x0AAA, 0x0AB0, 5),
+    (0x0AB2, 0x0AB3, 5),
+    (0x0AB5, 0x0AB9, 5),
+    (0x0ABC, 0x0ABC, 6),
+    (0x0ABD, 0x0ABD, 5),
+    (0x0ABE, 0x0AC0, 8),
+    (0x0AC1, 0x0AC5, 6),
+    (0x0AC7, 0x0AC8, 6),
+    (0x0AC9, 0x0AC9, 8),
+    (0x0ACB, 0x0ACC, 8),
+    (0x0ACD, 0x0ACD, 6),
+    (0x0AD0, 0x0AD0, 5),
+    (0x0AE0, 0x0AE1, 5),
+    (0x0AE2, 0x0AE3, 6),
+    (0x0AE6, 0x0AEF, 9),
+    (0x0AF0, 0x0AF0, 24),
+    (0x0AF1, 0x0AF1, 26),
+    (0x0AF9, 0x0AF9, 5),
+    (0x0AFA, 0x0AFF, 6),
+    (0x0B01, 0x0B01, 6),
+    (0x0B02, 0x0B03, 8),
+    (0x0B05, 0x0B0C, 5),
+    (0x0B0F, 0x0B10, 5),
+    (0x0B13, 0x0B28, 5),
+    (0x0B2A, 0x0B30, 5),
+    (0x0B32, 0x0B33, 5),
+    (0x0B35, 0x0B39, 5),
+    (0x0B3C, 0x0B3C, 6),
+    (0x0B3D, 0x0B3D, 5),
+    (0x0B3E, 0x0B3E, 8),
+    (0x0B3F, 0x0B3F, 6),
+    (0x0B40, 0x0B40, 8),
+    (0x0B41, 0x0B44, 6),
+    (0x0B47, 0x0B48, 8),
+    (0x0B4B, 0x0B4C, 8),
+    (0x0B4D, 0x0B4D, 6),
+    (0x0B55, 0x0B56, 6),
+    (0x0B57, 0x0B57, 8),
+    (0x0B5C, 0x0B5D, 5),
+    (0x0B5F, 0x0B61, 5),
+    (0x0B62, 0x0B63, 6),
+    (0x0B66, 0x0B6F, 9),
+    (0x0B70, 0x0B70, 28),
+    (0x0B71, 0x0B71, 5),
+    (0x0B72, 0x0B77, 11),
+    (0x0B82, 0x0B82, 6),
+    (0x0B83, 0x0B83, 5),
+    (0x0B85, 0x0B8A, 5),
+    (0x0B8E, 0x0B90, 5),
+    (0x0B92, 0x0B95, 5),
+    (0x0B99, 0x0B9A, 5),
+    (0x0B9C, 0x0B9C, 5),
+    (0x0B9E, 0x0B9F, 5),
+    (0x0BA3, 0x0BA4, 5),
+    (0x0BA8, 0x0BAA, 5),
+    (0x0BAE, 0x0BB9, 5),
+    (0x0BBE, 0x0BBF, 8),
+    (0x0BC0, 0x0BC0, 6),
+    (0x0BC1, 0x0BC2, 8),
+    (0x0BC6, 0x0BC8, 8),
+    (0x0BCA, 0x0BCC, 8),
+    (0x0BCD, 0x0BCD, 6),
+    (0x0BD0, 0x0BD0, 5),
+    (0x0BD7, 0x0BD7, 8),
+    (0x0BE6, 0x0BEF, 9),
+    (0x0BF0, 0x0BF2, 11),
+    (0x0BF3, 0x0BF8, 28),
+    (0x0BF9, 0x0BF9, 26),
+    (0x0BFA, 0x0BFA, 28),
+    (0x0C00, 0x0C00, 6),
+    (0x0C01, 0x0C03, 8),
+    (0x0C04, 0x0C04, 6),
+    (0x0C05, 0x0C0C, 5),
+    (0x0C0E, 0x0C10, 5),
+    (0x0C12, 0x0C28, 5),
+    (0x0C2A, 0x0C39, 5),
+    (0x0C3D, 0x0C3D, 5),
+    (0x0C3E, 0x0C40, 6),
+    (0x0C41, 0x0C44, 8),
+    (0x0C46, 0x0C48, 6),
+    (0x0C4A, 0x0C4D, 6),
+    (0x0C55, 0x0C56, 6),
+    (0x0C58, 0x0C5A, 5),
+    (0x0C60, 0x0C61, 5),
+    (0x0C62, 0x0C63, 6),
+    (0x0C66, 0x0C6F, 9),
+    (0x0C77, 0x0C77, 24),
+    (0x0C78, 0x0C7E, 11),
+    (0x0C7F, 0x0C7F, 28),
+    (0x0C80, 0x0C80, 5),
+    (0x0C81, 0x0C81, 6),
+    (0x0C82, 0x0C83, 8),
+    (0x0C84, 0x0C84, 24),
+    (0x0C85, 0x0C8C, 5),
+    (0x0C8E, 0x0C90, 5),
+    (0x0C92, 0x0CA8, 5),
+    (0x0CAA, 0x0CB3, 5),
+    (0x0CB5, 0x0CB9, 5),
+    (0x0CBC, 0x0CBC, 6),
+    (0x0CBD, 0x0CBD, 5),
+    (0x0CBE, 0x0CBE, 8),
+    (0x0CBF, 0x0CBF, 6),
+    (0x0CC0, 0x0CC4, 8),
+    (0x0CC6, 0x0CC6, 6),
+    (0x0CC7, 0x0CC8, 8),
+    (0x0CCA, 0x0CCB, 8),
+    (0x0CCC, 0x0CCD, 6),
+    (0x0CD5, 0x0CD6, 8),
+    (0x0CDE, 0x0CDE, 5),
+    (0x0CE0, 0x0CE1, 5),
+    (0x0CE2, 0x0CE3, 6),
+    (0x0CE6, 0x0CEF, 9),
+    (0x0CF1, 0x0CF2, 5),
+    (0x0D00, 0x0D01, 6),
+    (0x0D02, 0x0D03, 8),
+    (0x0D04, 0x0D0C, 5),
+    (0x0D0E, 0x0D10, 5),
+    (0x0D12, 0x0D3A, 5),
+    (0x0D3B, 0x0D3C, 6),
+    (0x0D3D, 0x0D3D, 5),
+    (0x0D3E, 0x0D40, 8),
+    (0x0D41, 0x0D44, 6),
+    (0x0D46, 0x0D48, 8),
+    (0x0D4A, 0x0D4C, 8),
+    (0x0D4D, 0x0D4D, 6),
+    (0x0D4E, 0x0D4E, 5),
+    (0x0D4F, 0x0D4F, 28),
+    (0x0D54, 0x0D56, 5),
+    (0x0D57, 0x0D57, 8),
+    (0x0D58, 0x0D5E, 11),
+    (0x0D5F, 0x0D61, 5),
+    (0x0D62, 0x0D63, 6),
+    (0x0D66, 0x0D6F, 9),
+    (0x0D70, 0x0D78, 11),
+    (0x0D79, 0x0D79, 28),
+    (0x0D7A, 0x0D7F, 5),
+    (0x0D81, 0x0D81, 6),
+    (0x0D82, 0x0D83, 8),
+    (0x0D85, 0x0D96, 5),
+    (0x0D9A, 0x0DB1, 5),
+    (0x0DB3, 0x0DBB, 5),
+    (0x0DBD, 0x0DBD, 5),
+    (0x0DC0, 0x0DC6, 5),
+    (0x0DCA, 0x0DCA, 6),
+    (0x0DCF, 0x0DD1, 8),
+    (0x0DD2, 0x0DD4, 6),
+    (0x0DD6, 0x0DD6, 6),
+    (0x0DD8, 0x0DDF, 8),
+    (0x0DE6, 0x0DEF, 9),
+    (0x0DF2, 0x0DF3, 8),
+    (0x0DF4, 0x0DF4, 24),
+    (0x0E01, 0x0E30, 5),
+    (0x0E31, 0x0E31, 6),
+    (0x0E32, 0x0E33, 5),
+    (0x0E34, 0x0E3A, 6),
+    (0x0E3F, 0x0E3F, 26),
+    (0x0E40, 0x0E45, 5),
+    (0x0E46, 0x0E46, 4),
+    (0x0E47, 0x0E4E, 6),
+    (0x0E4F, 0x0E4F, 24),
+    (0x0E50, 0x0E59, 9),
+    (0x0E5A, 0x0E5B, 24),
+    (0x0E81, 0x0E82, 5),
+    (0x0E84, 0x0E84, 5),
+    (0x0E86, 0x0E8A, 5),
+    (0x0E8C, 0x0EA3, 5),
+    (0x0EA5, 0x0EA5, 5),
+    (0x0EA7, 0x0EB0, 5),
+    (0x0EB1, 0x0EB1, 6),
+    (0x0EB2, 0x0EB3, 5),
+    (0x0EB4, 0x0EBC, 6),
+    (0x0EBD, 0x0EBD, 5),
+    (0x0EC0, 0x0EC4, 5),
+    (0x0EC6, 0x0EC6, 4),
+    (0x0EC8, 0x0ECD, 6),
+    (0x0ED0, 0x0ED9, 9),
+    (0x0EDC, 0x0EDF, 5),
+    (0x0F00, 0x0F00, 5),
+    (0x0F01, 0x0F03, 28),
+    (0x0F04, 0x0F12, 24),
+    (0x0F13, 0x0F13, 28),
+    (0x0F14, 0x0F14, 24),
+    (0x0F15, 0x0F17, 28),
+    (0x0F18, 0x0F19, 6),
+    (0x0F1A, 0x0F1F, 28),
+    (0x0F20, 0x0F29, 9),
+    (0x0F2A, 0x0F33, 11),
+    (0x0F34, 0x0F34, 28),
+    (0x0F35, 0x0F35, 6),
+    (0x0F36, 0x0F36, 28),
+    (0x0F37, 0x0F37, 6),
+    (0x0F38, 0x0F38, 28),
+    (0x0F39, 0x0F39, 6),
+    (0x0F3A, 0x0F3A, 21),
+    (0x0F3B, 0x0F3B, 22),
+    (0x0F3C, 0x0F3C, 21),
+    (0x0F3D, 0x0F3D, 22),
+    (0x0F3E, 0x0F3F, 8),
+    (0x0F40, 0x0F47, 5),
+    (0x0F49, 0x0F6C, 5),
+    (0x0F71, 0x0F7E, 6),
+    (0x0F7F, 0x0F7F, 8),
+    (0x0F80, 0x0F84, 6),
+    (0x0F85, 0x0F85, 24),
+    (0x0F86, 0x0F87, 6),
+    (0x0F88, 0x0F8C, 5),
+    (0x0F8D, 0x0F97, 6),
+    (0x0F99, 0x0FBC, 6),
+    (0x0FBE, 0x0FC5, 28),
+    (0x0FC6, 0x0FC6, 6),
+    (0x0FC7, 0x0FCC, 28),
+    (0x0FCE, 0x0FCF, 28),
+    (0x0FD0, 0x0FD4, 24),
+    (0x0FD5, 0x0FD8, 28),
+    (0x0FD9, 0x0FDA, 24),
+    (0x1000, 0x102A, 5),
+    (0x102B, 0x102C, 8),
+    (0x102D, 0x1030, 6),
+    (0x1031, 0x1031, 8),
+    (0x1032, 0x1037, 6),
+    (0x1038, 0x1038, 8),
+    (0x1039, 0x103A, 6),
+    (0x103B, 0x103C, 8),
+    (0x103D, 0x103E, 6),
+    (0x103F, 0x103F, 5),
+    (0x1040, 0x1049, 9),
+    (0x104A, 0x104F, 24),
+    (0x1050, 0x1055, 5),
+    (0x1056, 0x1057, 8),
+    (0x1058, 0x1059, 6),
+    (0x105A, 0x105D, 5),
+    (0x105E, 0x1060, 6),
+    (0x1061, 0x1061, 5),
+    (0x1062, 0x1064, 8),
+    (0x1065, 0x1066, 5),
+    (0x1067, 0x106D, 8),
+    (0x106E, 0x1070, 5),
+    (0x1071, 0x1074, 6),
+    (0x1075, 0x1081, 5),
+    (0x1082, 0x1082, 6),
+    (0x1083, 0x1084, 8),
+    (0x1085, 0x1086, 6),
+    (0x1087, 0x108C, 8),
+    (0x108D, 0x108D, 6),
+    (0x108E, 0x108E, 5),
+    (0x108F, 0x108F, 8),
+    (0x1090, 0x1099, 9),
+    (0x109A, 0x109C, 8),
+    (0x109D, 0x109D, 6),
+    (0x109E, 0x109F, 28),
+    (0x10A0, 0x10C5, 1),
+    (0x10C7, 0x10C7, 1),
+    (0x10CD, 0x10CD, 1),
+    (0x10D0, 0x10FA, 2),
+    (0x10FB, 0x10FB, 24),
+    (0x10FC, 0x10FC, 4),
+    (0x10FD, 0x10FF, 2),
+    (0x1100, 0x1248, 5),
+    (0x124A, 0x124D, 5),
+    (0x1250, 0x1256, 5),
+    (0x1258, 0x1258, 5),
+    (0x125A, 0x125D, 5),
+    (0x1260, 0x1288, 5),
+    (0x128A, 0x128D, 5),
+    (0x1290, 0x12B0, 5),
+    (0x12B2, 0x12B5, 5),
+    (0x12B8, 0x12BE, 5),
+    (0x12C0, 0x12C0, 5),
+    (0x12C2, 0x12C5, 5),
+    (0x12C8, 0x12D6, 5),
+    (0x12D8, 0x1310, 5),
+    (0x1312, 0x1315, 5),
+    (0x1318, 0x135A, 5),
+    (0x135D, 0x135F, 6),
+    (0x1360, 0x1368, 24),
+    (0x1369, 0x137C, 11),
+    (0x1380, 0x138F, 5),
+    (0x1390, 0x1399, 28),
+    (0x13A0, 0x13F5, 1),
+    (0x13F8, 0x13FD, 2),
+    (0x1400, 0x1400, 20),
+    (0x1401, 0x166C, 5),
+    (0x166D, 0x166D, 28),
+    (0x166E, 0x166E, 24),
+    (0x166F, 0x167F, 5),
+    (0x1680, 0x1680, 12),
+    (0x1681, 0x169A, 5),
+    (0x169B, 0x169B, 21),
+    (0x169C, 0x169C, 22),
+    (0x16A0, 0x16EA, 5),
+    (0x16EB, 0x16ED, 24),
+    (0x16EE, 0x16F0, 10),
+    (0x16F1, 0x16F8, 5),
+    (0x1700, 0x170C, 5),
+    (0x170E, 0x1711, 5),
+    (0x1712, 0x1714, 6),
+    (0x1720, 0x1731, 5),
+    (0x1732, 0x1734, 6),
+    (0x1735, 0x1736, 24),
+    (0x1740, 0x1751, 5),
+    (0x1752, 0x1753, 6),
+    (0x1760, 0x176C, 5),
+    (0x176E, 0x1770, 5),
+    (0x1772, 0x1773, 6),
+    (0x1780, 0x17B3, 5),
+    (0x17B4, 0x17B5, 6),
+    (0x17B6, 0x17B6, 8),
+    (0x17B7, 0x17BD, 6),
+    (0x17BE, 0x17C5, 8),
+    (0x17C6, 0x17C6, 6),
+    (0x17C7, 0x17C8, 8),
+    (0x17C9, 0x17D3, 6),
+    (0x17D4, 0x17D6, 24),
+    (0x17D7, 0x17D7, 4),
+    (0x17D8, 0x17DA, 24),
+    (0x17DB, 0x17DB, 26),
+    (0x17DC, 0x17DC, 5),
+    (0x17DD, 0x17DD, 6),
+    (0x17E0, 0x17E9, 9),
+    (0x17F0, 0x17F9, 11),
+    (0x1800, 0x1805, 24),
+    (0x1806, 0x1806, 20),
+    (0x1807, 0x180A, 24),
+    (0x180B, 0x180D, 6),
+    (0x180E, 0x180E, 16),
+    (0x1810, 0x1819, 9),
+    (0x1820, 0x1842, 5),
+    (0x1843, 0x1843, 4),
+    (0x1844, 0x1878, 5),
+    (0x1880, 0x1884, 5),
+    (0x1885, 0x1886, 6),
+    (0x1887, 0x18A8, 5),
+    (0x18A9, 0x18A9, 6),
+    (0x18AA, 0x18AA, 5),
+    (0x18B0, 0x18F5, 5),
+    (0x1900, 0x191E, 5),
+    (0x1920, 0x1922, 6),
+    (0x1923, 0x1926, 8),
+    (0x1927, 0x1928, 6),
+    (0x1929, 0x192B, 8),
+    (0x1930, 0x1931, 8),
+    (0x1932, 0x1932, 6),
+    (0x1933, 0x1938, 8),
+    (0x1939, 0x193B, 6),
+    (0x1940, 0x1940, 28),
+    (0x1944, 0x1945, 24),
+    (0x1946, 0x194F, 9),
+    (0x1950, 0x196D, 5),
+    (0x1970, 0x1974, 5),
+    (0x1980, 0x19AB, 5),
+    (0x19B0, 0x19C9, 5),
+    (0x19D0, 0x19D9, 9),
+    (0x19DA, 0x19DA, 11),
+    (0x19DE, 0x19FF, 28),
+    (0x1A00, 0x1A16, 5),
+    (0x1A17, 0x1A18, 6),
+    (0x1A19, 0x1A1A, 8),
+    (0x1A1B, 0x1A1B, 6),
+    (0x1A1E, 0x1A1F, 24),
+    (0x1A20, 0x1A54, 5),
+    (0x1A55, 0x1A55, 8),
+    (0x1A56, 0x1A56, 6),
+    (0x1A57, 0x1A57, 8),
+    (0x1A58, 0x1A5E, 6),
+    (0x1A60, 0x1A60, 6),
+    (0x1A61, 0x1A61, 8),
+    (0x1A62, 0x1A62, 6),
+    (0x1A63, 0x1A64, 8),
+    (0x1A65, 0x1A6C, 6),
+    (0x1A6D, 0x1A72, 8),
+    (0x1A73, 0x1A7C, 6),
+    (0x1A7F, 0x1A7F, 6),
+    (0x1A80, 0x1A89, 9),
+    (0x1A90, 0x1A99, 9),
+    (0x1AA0, 0x1AA6, 24),
+    (0x1AA7, 0x1AA7, 4),
+    (0x1AA8, 0x1AAD, 24),
+    (0x1AB0, 0x1ABD, 6),
+    (0x1ABE, 0x1ABE, 7),
+    (0x1ABF, 0x1AC0, 6),
+    (0x1B00, 0x1B03, 6),
+    (0x1B04, 0x1B04, 8),
+    (0x1B05, 0x1B33, 5),
+    (0x1B34, 0x1B34, 6),
+    (0x1B35, 0x1B35, 8),
+    (0x1B36, 0x1B3A, 6),
+    (0x1B3B, 0x1B3B, 8),
+    (0x1B3C, 0x1B3C, 6),
+    (0x1B3D, 0x1B41, 8),
+    (0x1B42, 0x1B42, 6),
+    (0x1B43, 0x1B44, 8),
+    (0x1B45, 0x1B4B, 5),
+    (0x1B50, 0x1B59, 9),
+    (0x1B5A, 0x1B60, 24),
+    (0x1B61, 0x1B6A, 28),
+    (0x1B6B, 0x1B73, 6),
+    (0x1B74, 0x1B7C, 28),
+    (0x1B80, 0x1B81, 6),
+    (0x1B82, 0x1B82, 8),
+    (0x1B83, 0x1BA0, 5),
+    (0x1BA1, 0x1BA1, 8),
+    (0x1BA2, 0x1BA5, 6),
+    (0x1BA6, 0x1BA7, 8),
+    (0x1BA8, 0x1BA9, 6),
+    (0x1BAA, 0x1BAA, 8),
+    (0x1BAB, 0x1BAD, 6),
+    (0x1BAE, 0x1BAF, 5),
+    (0x1BB0, 0x1BB9, 9),
+    (0x1BBA, 0x1BE5, 5),
+    (0x1BE6, 0x1BE6, 6),
+    (0x1BE7, 0x1BE7, 8),
+    (0x1BE8, 0x1BE9, 6),
+    (0x1BEA, 0x1BEC, 8),
+    (0x1BED, 0x1BED, 6),
+    (0x1BEE, 0x1BEE, 8),
+    (0x1BEF, 0x1BF1, 6),
+    (0x1BF2, 0x1BF3, 8),
+    (0x1BFC, 0x1BFF, 24),
+    (0x1C00, 0x1C23, 5),
+    (0x1C24, 0x1C2B, 8),
+    (0x1C2C, 0x1C33, 6),
+    (0x1C34, 0x1C35, 8),
+    (0x1C36, 0x1C37, 6),
+    (0x1C3B, 0x1C3F, 24),
+    (0x1C40, 0x1C49, 9),
+    (0x1C4D, 0x1C4F, 5),
+    (0x1C50, 0x1C59, 9),
+    (0x1C5A, 0x1C77, 5),
+    (0x1C78, 0x1C7D, 4),
+    (0x1C7E, 0x1C7F, 24),
+    (0x1C80, 0x1C88, 2),
+    (0x1C90, 0x1CBA, 1),
+    (0x1CBD, 0x1CBF, 1),
+    (0x1CC0, 0x1CC7, 24),
+    (0x1CD0, 0x1CD2, 6),
+    (0x1CD3, 0x1CD3, 24),
+    (0x1CD4, 0x1CE0, 6),
+    (0x1CE1, 0x1CE1, 8),
+    (0x1CE2, 0x1CE8, 6),
+    (0x1CE9, 0x1CEC, 5),
+    (0x1CED, 0x1CED, 6),
+    (0x1CEE, 0x1CF3, 5),
+    (0x1CF4, 0x1CF4, 6),
+    (0x1CF5, 0x1CF6, 5),
+    (0x1CF7, 0x1CF7, 8),
+    (0x1CF8, 0x1CF9, 6),
+    (0x1CFA, 0x1CFA, 5),
+    (0x1D00, 0x1D2B, 2),
+    (0x1D2C, 0x1D6A, 4),
+    (0x1D6B, 0x1D77, 2),
+    (0x1D78, 0x1D78, 4),
+    (0x1D79, 0x1D9A, 2),
+    (0x1D9B, 0x1DBF, 4),
+    (0x1DC0, 0x1DF9, 6),
+    (0x1DFB, 0x1DFF, 6),
+    (0x1E00, 0x1E00, 1),
+    (0x1E01, 0x1E01, 2),
+    (0x1E02, 0x1E02, 1),
+    (0x1E03, 0x1E03, 2),
+    (0x1E04, 0x1E04, 1),
+    (0x1E05, 0x1E05, 2),
+    (0x1E06, 0x1E06, 1),
+    (0x1E07, 0x1E07, 2),
+    (0x1E08, 0x1E08, 1),
+    (0x1E09, 0x1E09, 2),
+    (0x1E0A, 0x1E0A, 1),
+    (0x1E0B, 0x1E0B, 2),
+    (0x1E0C, 0x1E0C, 1),
+    (0x1E0D, 0x1E0D, 2),
+    (0x1E0E, 0x1E0E, 1),
+    (0x1E0F, 0x1E0F, 2),
+    (0x1E10, 0x1E10, 1),
+    (0x1E11, 0x1E11, 2),
+    (0x1E12, 0x1E12, 1),
+    (0x1E13, 0x1E13, 2),
+    (0x1E14, 0x1E14, 1),
+    (0x1E15, 0x1E15, 2),
+    (0x1E16, 0x1E16, 1),
+    (0x1E17, 0x1E17, 2),
+    (0x1E18, 0x1E18, 1),
+    (0x1E19, 0x1E19, 2),
+    (0x1E1A, 0x1E1A, 1),
+    (0x1E1B, 0x1E1B, 2),
+    (0x1E1C, 0x1E1C, 1),
+    (0x1E1D, 0x1E1D, 2),
+    (0x1E1E, 0x1E1E, 1),
+    (0x1E1F, 0x1E1F, 2),
+    (0x1E20, 0x1E20, 1),
+    (0x1E21, 0x1E21, 2),
+    (0x1E22, 0x1E22, 1),
+    (0x1E23, 0x1E23, 2),
+    (0x1E24, 0x1E24, 1),
+    (0x1E25, 0x1E25, 2),
+    (0x1E26, 0x1E26, 1),
+    (0x1E27, 0x1E27, 2),
+    (0x1E28, 0x1E28, 1),
+    (0x1E29, 0x1E29, 2),
+    (0x1E2A, 0x1E2A, 1),
+    (0x1E2B, 0x1E2B, 2),
+    (0x1E2C, 0x1E2C, 1),
+    (0x1E2D, 0x1E2D, 2),
+    (0x1E2E, 0x1E2E, 1),
+    (0x1E2F, 0x1E2F, 2),
+    (0x1E30, 0x1E30, 1),
+    (0x1E31, 0x1E31, 2),
+    (0x1E32, 0x1E32, 1),
+    (0x1E33, 0x1E33, 2),
+    (0x1E34, 0x1E34, 1),
+    (0x1E35, 0x1E35, 2),
+    (0x1E36, 0x1E36, 1),
+    (0x1E37, 0x1E37, 2),
+    (0x1E38, 0x1E38, 1),
+    (0x1E39, 0x1E39, 2),
+    (0x1E3A, 0x1E3A, 1),
+    (0x1E3B, 0x1E3B, 2),
+    (0x1E3C, 0x1E3C, 1),
+    (0x1E3D, 0x1E3D, 2),
+    (0x1E3E, 0x1E3E, 1),
+    (0x1E3F, 0x1E3F, 2),
+    (0x1E40, 0x1E40, 1),
+    (0x1E41, 0x1E41, 2),
+    (0x1E42, 0x1E42, 1),
+    (0x1E43, 0x1E43, 2),
+    (0x1E44, 0x1E44, 1),
+    (0x1E45, 0x1E45, 2),
+    (0x1E46, 0x1E46, 1),
+    (0x1E47, 0x1E47, 2),
+    (0x1E48, 0x1E48, 1),
+    (0x1E49, 0x1E49, 2),
+    (0x1E4A, 0x1E4A, 1),
+    (0x1E4B, 0x1E4B, 2),
+    (0x1E4C, 0x1E4C, 1),
+    (0x1E4D, 0x1E4D, 2),
+    (0x1E4E, 0x1E4E, 1),
+    (0x1E4F, 0x1E4F, 2),
+    (0x1E50, 0x1E50, 1),
+    (0x1E51, 0x1E51, 2),
+    (0x1E52, 0x1E52, 1),
+    (0x1E53, 0x1E53, 2),
+    (0x1E54, 0x1E54, 1),
+    (0x1E55, 0x1E55, 2),
+    (0x1E56, 0x1E56, 1),
+    (0x1E57, 0x1E57, 2),
+    (0x1E58, 0x1E58, 1),
+    (0x1E59, 0x1E59, 2),
+    (0x1E5A, 0x1E5A, 1),
+    (0x1E5B, 0x1E5B, 2),
+    (0x1E5C, 0x1E5C, 1),
+    (0x1E5D, 0x1E5D, 2),
+    (0x1E5E, 0x1E5E, 1),
+    (0x1E5F, 0x1E5F, 2),
+    (0x1E60, 0x1E60, 1),
+    (0x1E61, 0x1E61, 2),
+    (0x1E62, 0x1E62, 1),
+    (0x1E63, 0x1E63, 2),
+    (0x1E64, 0x1E64, 1),
+    (0x1E65, 0x1E65, 2),
+    (0x1E66, 0x1E66, 1),
+    (0x1E67, 0x1E67, 2),
+    (0x1E68, 0x1E68, 1),
+    (0x1E69, 0x1E69, 2),
+    (0x1E6A, 0x1E6A, 1),
+    (0x1E6B, 0x1E6B, 2),
+    (0x1E6C, 0x1E6C, 1),
+    (0x1E6D, 0x1E6D, 2),
+    (0x1E6E, 0x1E6E, 1),
+    (0x1E6F, 0x1E6F, 2),
+    (0x1E70, 0x1E70, 1),
+    (0x1E71, 0x1E71, 2),
+    (0x1E72, 0x1E72, 1),
+    (0x1E73, 0x1E73, 2),
+    (0x1E74, 0x1E74, 1),
+    (0x1E75, 0x1E75, 2),
+    (0x1E76, 0x1E76, 1),
+    (0x1E77, 0x1E77, 2),
+    (0x1E78, 0x1E78, 1),
+    (0x1E79, 0x1E79, 2),
+    (0x1E7A, 0x1E7A, 1),
+    (0x1E7B, 0x1E7B, 2),
+    (0x1E7C, 0x1E7C, 1),
+    (0x1E7D, 0x1E7D, 2),
+    (0x1E7E, 0x1E7E, 1),
+    (0x1E7F, 0x1E7F, 2),
+    (0x1E80, 0x1E80, 1),
+    (0x1E81, 0x1E81, 2),
+    (0x1E82, 0x1E82, 1),
+    (0x1E83, 0x1E83, 2),
+    (0x1E84, 0x1E84, 1),
+    (0x1E85, 0x1E85, 2),
+    (0x1E86, 0x1E86, 1),
+    (0x1E87, 0x1E87, 2),
+    (0x1E88, 0x1E88, 1),
+    (0x1E89, 0x1E89, 2),
+    (0x1E8A, 0x1E8A, 1),
+    (0x1E8B, 0x1E8B, 2),
+    (0x1E8C, 0x1E8C, 1),
+    (0x1E8D, 0x1E8D, 2),
+    (0x1E8E, 0x1E8E, 1),
+    (0x1E8F, 0x1E8F, 2),
+    (0x1E90, 0x1E90, 1),
+    (0x1E91, 0x1E91, 2),
+    (0x1E92, 0x1E92, 1),
+    (0x1E93, 0x1E93, 2),
+    (0x1E94, 0x1E94, 1),
+    (0x1E95, 0x1E9D, 2),
+    (0x1E9E, 0x1E9E, 1),
+    (0x1E9F, 0x1E9F, 2),
+    (0x1EA0, 0x1EA0, 1),
+    (0x1EA1, 0x1EA1, 2),
+    (0x1EA2, 0x1EA2, 1),
+    (0x1EA3, 0x1EA3, 2),
+    (0x1EA4, 0x1EA4, 1),
+    (0x1EA5, 0x1EA5, 2),
+    (0x1EA6, 0x1EA6, 1),
+    (0x1EA7, 0x1EA7, 2),
+    (0x1EA8, 0x1EA8, 1),
+    (0x1EA9, 0x1EA9, 2),
+    (0x1EAA, 0x1EAA, 1),
+    (0x1EAB, 0x1EAB, 2),
+    (0x1EAC, 0x1EAC, 1),
+    (0x1EAD, 0x1EAD, 2),
+    (0x1EAE, 0x1EAE, 1),
+    (0x1EAF, 0x1EAF, 2),
+    (0x1EB0, 0x1EB0, 1),
+    (0x1EB1, 0x1EB1, 2),
+    (0x1EB2, 0x1EB2, 1),
+    (0x1EB3, 0x1EB3, 2),
+    (0x1EB4, 0x1EB4, 1),
+    (0x1EB5, 0x1EB5, 2),
+    (0x1EB6, 0x1EB6, 1),
+    (0x1EB7, 0x1EB7, 2),
+    (0x1EB8, 0x1EB8, 1),
+    (0x1EB9, 0x1EB9, 2),
+    (0x1EBA, 0x1EBA, 1),
+    (0x1EBB, 0x1EBB, 2),
+    (0x1EBC, 0x1EBC, 1),
+    (0x1EBD, 0x1EBD, 2),
+    (0x1EBE, 0x1EBE, 1),
+    (0x1EBF, 0x1EBF, 2),
+    (0x1EC0, 0x1EC0, 1),
+    (0x1EC1, 0x1EC1, 2),
+    (0x1EC2, 0x1EC2, 1),
+    (0x1EC3, 0x1EC3, 2),
+    (0x1EC4, 0x1EC4, 1),
+    (0x1EC5, 0x1EC5, 2),
+    (0x1EC6, 0x1EC6, 1),
+    (0x1EC7, 0x1EC7, 2),
+    (0x1EC8, 0x1EC8, 1),
+    (0x1EC9, 0x1EC9, 2),
+    (0x1ECA, 0x1ECA, 1),
+    (0x1ECB, 0x1ECB, 2),
+    (0x1ECC, 0x1ECC, 1),
+    (0x1ECD, 0x1ECD, 2),
+    (0x1ECE, 0x1ECE, 1),
+    (0x1ECF, 0x1ECF, 2),
+    (0x1ED0, 0x1ED0, 1),
+    (0x1ED1, 0x1ED1, 2),
+    (0x1ED2, 0x1ED2, 1),
+    (0x1ED3, 0x1ED3, 2),
+    (0x1ED4, 0x1ED4, 1),
+    (0x1ED5, 0x1ED5, 2),
+    (0x1ED6, 0x1ED6, 1),
+    (0x1ED7, 0x1ED7, 2),
+    (0x1ED8, 0x1ED8, 1),
+    (0x1ED9, 0x1ED9, 2),
+    (0x1EDA, 0x1EDA, 1),
+    (0x1EDB, 0x1EDB, 2),
+    (0x1EDC, 0x1EDC, 1),
+    (0x1EDD, 0x1EDD, 2),
+    (0x1EDE, 0x1EDE, 1),
+    (0x1EDF, 0x1EDF, 2),
+    (0x1EE0, 0x1EE0, 1),
+    (0x1EE1, 0x1EE1, 2),
+    (0x1EE2, 0x1EE2, 1),
+    (0x1EE3, 0x1EE3, 2),
+    (0x1EE4, 0x1EE4, 1),
+    (0x1EE5, 0x1EE5, 2),
+    (0x1EE6, 0x1EE6, 1),
+    (0x1EE7, 0x1EE7, 2),
+    (0x1EE8, 0x1EE8, 1),
+    (0x1EE9, 0x1EE9, 2),
+    (0x1EEA, 0x1EEA, 1),
+    (0x1EEB, 0x1EEB, 2),
+    (0x1EEC, 0x1EEC, 1),
+    (0x1EED, 0x1EED, 2),
+    (0x1EEE, 0x1EEE, 1),
+    (0x1EEF, 0x1EEF, 2),
+    (0x1EF0, 0x1EF0, 1),
+    (0x1EF1, 0x1EF1, 2),
+    (0x1EF2, 0x1EF2, 1),
+    (0x1EF3, 0x1EF3, 2),
+    (0x1EF4, 0x1EF4, 1),
+    (0x1EF5, 0x1EF5, 2),
+    (0x1EF6, 0x1EF6, 1),
+    (0x1EF7, 0x1EF7, 2),
+    (0x1EF8, 0x1EF8, 1),
+    (0x1EF9, 0x1EF9, 2),
+    (0x1EFA, 0x1EFA, 1),
+    (0x1EFB, 0x1EFB, 2),
+    (0x1EFC, 0x1EFC, 1),
+    (0x1EFD, 0x1EFD, 2),
+    (0x1EFE, 0x1EFE, 1),
+    (0x1EFF, 0x1F07, 2),
+    (0x1F08, 0x1F0F, 1),
+    (0x1F10, 0x1F15, 2),
+    (0x1F18, 0x1F1D, 1),
+    (0x1F20, 0x1F27, 2),
+    (0x1F28, 0x1F2F, 1),
+    (0x1F30, 0x1F37, 2),
+    (0x1F38, 0x1F3F, 1),
+    (0x1F40, 0x1F45, 2),
+    (0x1F48, 0x1F4D, 1),
+    (0x1F50, 0x1F57, 2),
+    (0x1F59, 0x1F59, 1),
+    (0x1F5B, 0x1F5B, 1),
+    (0x1F5D, 0x1F5D, 1),
+    (0x1F5F, 0x1F5F, 1),
+    (0x1F60, 0x1F67, 2),
+    (0x1F68, 0x1F6F, 1),
+    (0x1F70, 0x1F7D, 2),
+    (0x1F80, 0x1F87, 2),
+    (0x1F88, 0x1F8F, 3),
+    (0x1F90, 0x1F97, 2),
+    (0x1F98, 0x1F9F, 3),
+    (0x1FA0, 0x1FA7, 2),
+    (0x1FA8, 0x1FAF, 3),
+    (0x1FB0, 0x1FB4, 2),
+    (0x1FB6, 0x1FB7, 2),
+    (0x1FB8, 0x1FBB, 1),
+    (0x1FBC, 0x1FBC, 3),
+    (0x1FBD, 0x1FBD, 27),
+    (0x1FBE, 0x1FBE, 2),
+    (0x1FBF, 0x1FC1, 27),
+    (0x1FC2, 0x1FC4, 2),
+    (0x1FC6, 0x1FC7, 2),
+    (0x1FC8, 0x1FCB, 1),
+    (0x1FCC, 0x1FCC, 3),
+    (0x1FCD, 0x1FCF, 27),
+    (0x1FD0, 0x1FD3, 2),
+    (0x1FD6, 0x1FD7, 2),
+    (0x1FD8, 0x1FDB, 1),
+    (0x1FDD, 0x1FDF, 27),
+    (0x1FE0, 0x1FE7, 2),
+    (0x1FE8, 0x1FEC, 1),
+    (0x1FED, 0x1FEF, 27),
+    (0x1FF2, 0x1FF4, 2),
+    (0x1FF6, 0x1FF7, 2),
+    (0x1FF8, 0x1FFB, 1),
+    (0x1FFC, 0x1FFC, 3),
+    (0x1FFD, 0x1FFE, 27),
+    (0x2000, 0x200A, 12),
+    (0x200B, 0x200F, 16),
+    (0x2010, 0x2015, 20),
+    (0x2016, 0x2017, 24),
+    (0x2018, 0x2018, 29),
+    (0x2019, 0x2019, 30),
+    (0x201A, 0x201A, 21),
+    (0x201B, 0x201C, 29),
+    (0x201D, 0x201D, 30),
+    (0x201E, 0x201E, 21),
+    (0x201F, 0x201F, 29),
+    (0x2020, 0x2027, 24),
+    (0x2028, 0x2028, 13),
+    (0x2029, 0x2029, 14),
+    (0x202A, 0x202E, 16),
+    (0x202F, 0x202F, 12),
+    (0x2030, 0x2038, 24),
+    (0x2039, 0x2039, 29),
+    (0x203A, 0x203A, 30),
+    (0x203B, 0x203E, 24),
+    (0x203F, 0x2040, 23),
+    (0x2041, 0x2043, 24),
+    (0x2044, 0x2044, 25),
+    (0x2045, 0x2045, 21),
+    (0x2046, 0x2046, 22),
+    (0x2047, 0x2051, 24),
+    (0x2052, 0x2052, 25),
+    (0x2053, 0x2053, 24),
+    (0x2054, 0x2054, 23),
+    (0x2055, 0x205E, 24),
+    (0x205F, 0x205F, 12),
+    (0x2060, 0x2064, 16),
+    (0x2066, 0x206F, 16),
+    (0x2070, 0x2070, 11),
+    (0x2071, 0x2071, 4),
+    (0x2074, 0x2079, 11),
+    (0x207A, 0x207C, 25),
+    (0x207D, 0x207D, 21),
+    (0x207E, 0x207E, 22),
+    (0x207F, 0x207F, 4),
+    (0x2080, 0x2089, 11),
+    (0x208A, 0x208C, 25),
+    (0x208D, 0x208D, 21),
+    (0x208E, 0x208E, 22),
+    (0x2090, 0x209C, 4),
+    (0x20A0, 0x20BF, 26),
+    (0x20D0, 0x20DC, 6),
+    (0x20DD, 0x20E0, 7),
+    (0x20E1, 0x20E1, 6),
+    (0x20E2, 0x20E4, 7),
+    (0x20E5, 0x20F0, 6),
+    (0x2100, 0x2101, 28),
+    (0x2102, 0x2102, 1),
+    (0x2103, 0x2106, 28),
+    (0x2107, 0x2107, 1),
+    (0x2108, 0x2109, 28),
+    (0x210A, 0x210A, 2),
+    (0x210B, 0x210D, 1),
+    (0x210E, 0x210F, 2),
+    (0x2110, 0x2112, 1),
+    (0x2113, 0x2113, 2),
+    (0x2114, 0x2114, 28),
+    (0x2115, 0x2115, 1),
+    (0x2116, 0x2117, 28),
+    (0x2118, 0x2118, 25),
+    (0x2119, 0x211D, 1),
+    (0x211E, 0x2123, 28),
+    (0x2124, 0x2124, 1),
+    (0x2125, 0x2125, 28),
+    (0x2126, 0x2126, 1),
+    (0x2127, 0x2127, 28),
+    (0x2128, 0x2128, 1),
+    (0x2129, 0x2129, 28),
+    (0x212A, 0x212D, 1),
+    (0x212E, 0x212E, 28),
+    (0x212F, 0x212F, 2),
+    (0x2130, 0x2133, 1),
+    (0x2134, 0x2134, 2),
+    (0x2135, 0x2138, 5),
+    (0x2139, 0x2139, 2),
+    (0x213A, 0x213B, 28),
+    (0x213C, 0x213D, 2),
+    (0x213E, 0x213F, 1),
+    (0x2140, 0x2144, 25),
+    (0x2145, 0x2145, 1),
+    (0x2146, 0x2149, 2),
+    (0x214A, 0x214A, 28),
+    (0x214B, 0x214B, 25),
+    (0x214C, 0x214D, 28),
+    (0x214E, 0x214E, 2),
+    (0x214F, 0x214F, 28),
+    (0x2150, 0x215F, 11),
+    (0x2160, 0x2182, 10),
+    (0x2183, 0x2183, 1),
+    (0x2184, 0x2184, 2),
+    (0x2185, 0x2188, 10),
+    (0x2189, 0x2189, 11),
+    (0x218A, 0x218B, 28),
+    (0x2190, 0x2194, 25),
+    (0x2195, 0x2199, 28),
+    (0x219A, 0x219B, 25),
+    (0x219C, 0x219F, 28),
+    (0x21A0, 0x21A0, 25),
+    (0x21A1, 0x21A2, 28),
+    (0x21A3, 0x21A3, 25),
+    (0x21A4, 0x21A5, 28),
+    (0x21A6, 0x21A6, 25),
+    (0x21A7, 0x21AD, 28),
+    (0x21AE, 0x21AE, 25),
+    (0x21AF, 0x21CD, 28),
+    (0x21CE, 0x21CF, 25),
+    (0x21D0, 0x21D1, 28),
+    (0x21D2, 0x21D2, 25),
+    (0x21D3, 0x21D3, 28),
+    (0x21D4, 0x21D4, 25),
+    (0x21D5, 0x21F3, 28),
+    (0x21F4, 0x22FF, 25),
+    (0x2300, 0x2307, 28),
+    (0x2308, 0x2308, 21),
+    (0x2309, 0x2309, 22),
+    (0x230A, 0x230A, 21),
+    (0x230B, 0x230B, 22),
+    (0x230C, 0x231F, 28),
+    (0x2320, 0x2321, 25),
+    (0x2322, 0x2328, 28),
+    (0x2329, 0x2329, 21),
+    (0x232A, 0x232A, 22),
+    (0x232B, 0x237B, 28),
+    (0x237C, 0x237C, 25),
+    (0x237D, 0x239A, 28),
+    (0x239B, 0x23B3, 25),
+    (0x23B4, 0x23DB, 28),
+    (0x23DC, 0x23E1, 25),
+    (0x23E2, 0x2426, 28),
+    (0x2440, 0x244A, 28),
+    (0x2460, 0x249B, 11),
+    (0x249C, 0x24E9, 28),
+    (0x24EA, 0x24FF, 11),
+    (0x2500, 0x25B6, 28),
+    (0x25B7, 0x25B7, 25),
+    (0x25B8, 0x25C0, 28),
+    (0x25C1, 0x25C1, 25),
+    (0x25C2, 0x25F7, 28),
+    (0x25F8, 0x25FF, 25),
+    (0x2600, 0x266E, 28),
+    (0x266F, 0x266F, 25),
+    (0x2670, 0x2767, 28),
+    (0x2768, 0x2768, 21),
+    (0x2769, 0x2769, 22),
+    (0x276A, 0x276A, 21),
+    (0x276B, 0x276B, 22),
+    (0x276C, 0x276C, 21),
+    (0x276D, 0x276D, 22),
+    (0x276E, 0x276E, 21),
+    (0x276F, 0x276F, 22),
+    (0x2770, 0x2770, 21),
+    (0x2771, 0x2771, 22),
+    (0x2772, 0x2772, 21),
+    (0x2773, 0x2773, 22),
+    (0x2774, 0x2774, 21),
+    (0x2775, 0x2775, 22),
+    (0x2776, 0x2793, 11),
+    (0x2794, 0x27BF, 28),
+    (0x27C0, 0x27C4, 25),
+    (0x27C5, 0x27C5, 21),
+    (0x27C6, 0x27C6, 22),
+    (0x27C7, 0x27E5, 25),
+    (0x27E6, 0x27E6, 21),
+    (0x27E7, 0x27E7, 22),
+    (0x27E8, 0x27E8, 21),
+    (0x27E9, 0x27E9, 22),
+    (0x27EA, 0x27EA, 21),
+    (0x27EB, 0x27EB, 22),
+    (0x27EC, 0x27EC, 21),
+    (0x27ED, 0x27ED, 22),
+    (0x27EE, 0x27EE, 21),
+    (0x27EF, 0x27EF, 22),
+    (0x27F0, 0x27FF, 25),
+    (0x2800, 0x28FF, 28),
+    (0x2900, 0x2982, 25),
+    (0x2983, 0x2983, 21),
+    (0x2984, 0x2984, 22),
+    (0x2985, 0x2985, 21),
+    (0x2986, 0x2986, 22),
+    (0x2987, 0x2987, 21),
+    (0x2988, 0x2988, 22),
+    (0x2989, 0x2989, 21),
+    (0x298A, 0x298A, 22),
+    (0x298B, 0x298B, 21),
+    (0x298C, 0x298C, 22),
+    (0x298D, 0x298D, 21),
+    (0x298E, 0x298E, 22),
+    (0x298F, 0x298F, 21),
+    (0x2990, 0x2990, 22),
+    (0x2991, 0x2991, 21),
+    (0x2992, 0x2992, 22),
+    (0x2993, 0x2993, 21),
+    (0x2994, 0x2994, 22),
+    (0x2995, 0x2995, 21),
+    (0x2996, 0x2996, 22),
+    (0x2997, 0x2997, 21),
+    (0x2998, 0x2998, 22),
+    (0x2999, 0x29D7, 25),
+    (0x29D8, 0x29D8, 21),
+    (0x29D9, 0x29D9, 22),
+    (0x29DA, 0x29DA, 21),
+    (0x29DB, 0x29DB, 22),
+    (0x29DC, 0x29FB, 25),
+    (0x29FC, 0x29FC, 21),
+    (0x29FD, 0x29FD, 22),
+    (0x29FE, 0x2AFF, 25),
+    (0x2B00, 0x2B2F, 28),
+    (0x2B30, 0x2B44, 25),
+    (0x2B45, 0x2B46, 28),
+    (0x2B47, 0x2B4C, 25),
+    (0x2B4D, 0x2B73, 28),
+    (0x2B76, 0x2B95, 28),
+    (0x2B97, 0x2BFF, 28),
+    (0x2C00, 0x2C2E, 1),
+    (0x2C30, 0x2C5E, 2),
+    (0x2C60, 0x2C60, 1),
+    (0x2C61, 0x2C61, 2),
+    (0x2C62, 0x2C64, 1),
+    (0x2C65, 0x2C66, 2),
+    (0x2C67, 0x2C67, 1),
+    (0x2C68, 0x2C68, 2),
+    (0x2C69, 0x2C69, 1),
+    (0x2C6A, 0x2C6A, 2),
+    (0x2C6B, 0x2C6B, 1),
+    (0x2C6C, 0x2C6C, 2),
+    (0x2C6D, 0x2C70, 1),
+    (0x2C71, 0x2C71, 2),
+    (0x2C72, 0x2C72, 1),
+    (0x2C73, 0x2C74, 2),
+    (0x2C75, 0x2C75, 1),
+    (0x2C76, 0x2C7B, 2),
+    (0x2C7C, 0x2C7D, 4),
+    (0x2C7E, 0x2C80, 1),
+    (0x2C81, 0x2C81, 2),
+    (0x2C82, 0x2C82, 1),
+    (0x2C83, 0x2C83, 2),
+    (0x2C84, 0x2C84, 1),
+    (0x2C85, 0x2C85, 2),
+    (0x2C86, 0x2C86, 1),
+    (0x2C87, 0x2C87, 2),
+    (0x2C88, 0x2C88, 1),
+    (0x2C89, 0x2C89, 2),
+    (0x2C8A, 0x2C8A, 1),
+    (0x2C8B, 0x2C8B, 2),
+    (0x2C8C, 0x2C8C, 1),
+    (0x2C8D, 0x2C8D, 2),
+    (0x2C8E, 0x2C8E, 1),
+    (0x2C8F, 0x2C8F, 2),
+    (0x2C90, 0x2C90, 1),
+    (0x2C91, 0x2C91, 2),
+    (0x2C92, 0x2C92, 1),
+    (0x2C93, 0x2C93, 2),
+    (0x2C94, 0x2C94, 1),
+    (0x2C95, 0x2C95, 2),
+    (0x2C96, 0x2C96, 1),
+    (0x2C97, 0x2C97, 2),
+    (0x2C98, 0x2C98, 1),
+    (0x2C99, 0x2C99, 2),
+    (0x2C9A, 0x2C9A, 1),
+    (0x2C9B, 0x2C9B, 2),
+    (0x2C9C, 0x2C9C, 1),
+    (0x2C9D, 0x2C9D, 2),
+    (0x2C9E, 0x2C9E, 1),
+    (0x2C9F, 0x2C9F, 2),
+    (0x2CA0, 0x2CA0, 1),
+    (0x2CA1, 0x2CA1, 2),
+    (0x2CA2, 0x2CA2, 1),
+    (0x2CA3, 0x2CA3, 2),
+    (0x2CA4, 0x2CA4, 1),
+    (0x2CA5, 0x2CA5, 2),
+    (0x2CA6, 0x2CA6, 1),
+    (0x2CA7, 0x2CA7, 2),
+    (0x2CA8, 0x2CA8, 1),
+    (0x2CA9, 0x2CA9, 2),
+    (0x2CAA, 0x2CAA, 1),
+    (0x2CAB, 0x2CAB, 2),
+    (0x2CAC, 0x2CAC, 1),
+    (0x2CAD, 0x2CAD, 2),
+    (0x2CAE, 0x2CAE, 1),
+    (0x2CAF, 0x2CAF, 2),
+    (0x2CB0, 0x2CB0, 1),
+    (0x2CB1, 0x2CB1, 2),
+    (0x2CB2, 0x2CB2, 1),
+    (0x2CB3, 0x2CB3, 2),
+    (0x2CB4, 0x2CB4, 1),
+    (0x2CB5, 0x2CB5, 2),
+    (0x2CB6, 0x2CB6, 1),
+    (0x2CB7, 0x2CB7, 2),
+    (0x2CB8, 0x2CB8, 1),
+    (0x2CB9, 0x2CB9, 2),
+    (0x2CBA, 0x2CBA, 1),
+    (0x2CBB, 0x2CBB, 2),
+    (0x2CBC, 0x2CBC, 1),
+    (0x2CBD, 0x2CBD, 2),
+    (0x2CBE, 0x2CBE, 1),
+    (0x2CBF, 0x2CBF, 2),
+    (0x2CC0, 0x2CC0, 1),
+    (0x2CC1, 0x2CC1, 2),
+    (0x2CC2, 0x2CC2, 1),
+    (0x2CC3, 0x2CC3, 2),
+    (0x2CC4, 0x2CC4, 1),
+    (0x2CC5, 0x2CC5, 2),
+    (0x2CC6, 0x2CC6, 1),
+    (0x2CC7, 0x2CC7, 2),
+    (0x2CC8, 0x2CC8, 1),
+    (0x2CC9, 0x2CC9, 2),
+    (0x2CCA, 0x2CCA, 1),
+    (0x2CCB, 0x2CCB, 2),
+    (0x2CCC, 0x2CCC, 1),
+    (0x2CCD, 0x2CCD, 2),
+    (0x2CCE, 0x2CCE, 1),
+    (0x2CCF, 0x2CCF, 2),
+    (0x2CD0, 0x2CD0, 1),
+    (0x2CD1, 0x2CD1, 2),
+    (0x2CD2, 0x2CD2, 1),
+    (0x2CD3, 0x2CD3, 2),
+    (0x2CD4, 0x2CD4, 1),
+    (0x2CD5, 0x2CD5, 2),
+    (0x2CD6, 0x2CD6, 1),
+    (0x2CD7, 0x2CD7, 2),
+    (0x2CD8, 0x2CD8, 1),
+    (0x2CD9, 0x2CD9, 2),
+    (0x2CDA, 0x2CDA, 1),
+    (0x2CDB, 0x2CDB, 2),
+    (0x2CDC, 0x2CDC, 1),
+    (0x2CDD, 0x2CDD, 2),
+    (0x2CDE, 0x2CDE, 1),
+    (0x2CDF, 0x2CDF, 2),
+    (0x2CE0, 0x2CE0, 1),
+    (0x2CE1, 0x2CE1, 2),
+    (0x2CE2, 0x2CE2, 1),
+    (0x2CE3, 0x2CE4, 2),
+    (0x2CE5, 0x2CEA, 28),
+    (0x2CEB, 0x2CEB, 1),
+    (0x2CEC, 0x2CEC, 2),
+    (0x2CED, 0x2CED, 1),
+    (0x2CEE, 0x2CEE, 2),
+    (0x2CEF, 0x2CF1, 6),
+    (0x2CF2, 0x2CF2, 1),
+    (0x2CF3, 0x2CF3, 2),
+    (0x2CF9, 0x2CFC, 24),
+    (0x2CFD, 0x2CFD, 11),
+    (0x2CFE, 0x2CFF, 24),
+    (0x2D00, 0x2D25, 2),
+    (0x2D27, 0x2D27, 2),
+    (0x2D2D, 0x2D2D, 2),
+    (0x2D30, 0x2D67, 5),
+    (0x2D6F, 0x2D6F, 4),
+    (0x2D70, 0x2D70, 24),
+    (0x2D7F, 0x2D7F, 6),
+    (0x2D80, 0x2D96, 5),
+    (0x2DA0, 0x2DA6, 5),
+    (0x2DA8, 0x2DAE, 5),
+    (0x2DB0, 0x2DB6, 5),
+    (0x2DB8, 0x2DBE, 5),
+    (0x2DC0, 0x2DC6, 5),
+    (0x2DC8, 0x2DCE, 5),
+    (0x2DD0, 0x2DD6, 5),
+    (0x2DD8, 0x2DDE, 5),
+    (0x2DE0, 0x2DFF, 6),
+    (0x2E00, 0x2E01, 24),
+    (0x2E02, 0x2E02, 29),
+    (0x2E03, 0x2E03, 30),
+    (0x2E04, 0x2E04, 29),
+    (0x2E05, 0x2E05, 30),
+    (0x2E06, 0x2E08, 24),
+    (0x2E09, 0x2E09, 29),
+    (0x2E0A, 0x2E0A, 30),
+    (0x2E0B, 0x2E0B, 24),
+    (0x2E0C, 0x2E0C, 29),
+    (0x2E0D, 0x2E0D, 30),
+    (0x2E0E, 0x2E16, 24),
+    (0x2E17, 0x2E17, 20),
+    (0x2E18, 0x2E19, 24),
+    (0x2E1A, 0x2E1A, 20),
+    (0x2E1B, 0x2E1B, 24),
+    (0x2E1C, 0x2E1C, 29),
+    (0x2E1D, 0x2E1D, 30),
+    (0x2E1E, 0x2E1F, 24),
+    (0x2E20, 0x2E20, 29),
+    (0x2E21, 0x2E21, 30),
+    (0x2E22, 0x2E22, 21),
+    (0x2E23, 0x2E23, 22),
+    (0x2E24, 0x2E24, 21),
+    (0x2E25, 0x2E25, 22),
+    (0x2E26, 0x2E26, 21),
+    (0x2E27, 0x2E27, 22),
+    (0x2E28, 0x2E28, 21),
+    (0x2E29, 0x2E29, 22),
+    (0x2E2A, 0x2E2E, 24),
+    (0x2E2F, 0x2E2F, 4),
+    (0x2E30, 0x2E39, 24),
+    (0x2E3A, 0x2E3B, 20),
+    (0x2E3C, 0x2E3F, 24),
+    (0x2E40, 0x2E40, 20),
+    (0x2E41, 0x2E41, 24),
+    (0x2E42, 0x2E42, 21),
+    (0x2E43, 0x2E4F, 24),
+    (0x2E50, 0x2E51, 28),
+    (0x2E52, 0x2E52, 24),
+    (0x2E80, 0x2E99, 28),
+    (0x2E9B, 0x2EF3, 28),
+    (0x2F00, 0x2FD5, 28),
+    (0x2FF0, 0x2FFB, 28),
+    (0x3000, 0x3000, 12),
+    (0x3001, 0x3003, 24),
+    (0x3004, 0x3004, 28),
+    (0x3005, 0x3005, 4),
+    (0x3006, 0x3006, 5),
+    (0x3007, 0x3007, 10),
+    (0x3008, 0x3008, 21),
+    (0x3009, 0x3009, 22),
+    (0x300A, 0x300A, 21),
+    (0x300B, 0x300B, 22),
+    (0x300C, 0x300C, 21),
+    (0x300D, 0x300D, 22),
+    (0x300E, 0x300E, 21),
+    (0x300F, 0x300F, 22),
+    (0x3010, 0x3010, 21),
+    (0x3011, 0x3011, 22),
+    (0x3012, 0x3013, 28),
+    (0x3014, 0x3014, 21),
+    (0x3015, 0x3015, 22),
+    (0x3016, 0x3016, 21),
+    (0x3017, 0x3017, 22),
+    (0x3018, 0x3018, 21),
+    (0x3019, 0x3019, 22),
+    (0x301A, 0x301A, 21),
+    (0x301B, 0x301B, 22),
+    (0x301C, 0x301C, 20),
+    (0x301D, 0x301D, 21),
+    (0x301E, 0x301F, 22),
+    (0x3020, 0x3020, 28),
+    (0x3021, 0x3029, 10),
+    (0x302A, 0x302D, 6),
+    (0x302E, 0x302F, 8),
+    (0x3030, 0x3030, 20),
+    (0x3031, 0x3035, 4),
+    (0x3036, 0x3037, 28),
+    (0x3038, 0x303A, 10),
+    (0x303B, 0x303B, 4),
+    (0x303C, 0x303C, 5),
+    (0x303D, 0x303D, 24),
+    (0x303E, 0x303F, 28),
+    (0x3041, 0x3096, 5),
+    (0x3099, 0x309A, 6),
+    (0x309B, 0x309C, 27),
+    (0x309D, 0x309E, 4),
+    (0x309F, 0x309F, 5),
+    (0x30A0, 0x30A0, 20),
+    (0x30A1, 0x30FA, 5),
+    (0x30FB, 0x30FB, 24),
+    (0x30FC, 0x30FE, 4),
+    (0x30FF, 0x30FF, 5),
+    (0x3105, 0x312F, 5),
+    (0x3131, 0x318E, 5),
+    (0x3190, 0x3191, 28),
+    (0x3192, 0x3195, 11),
+    (0x3196, 0x319F, 28),
+    (0x31A0, 0x31BF, 5),
+    (0x31C0, 0x31E3, 28),
+    (0x31F0, 0x31FF, 5),
+    (0x3200, 0x321E, 28),
+    (0x3220, 0x3229, 11),
+    (0x322A, 0x3247, 28),
+    (0x3248, 0x324F, 11),
+    (0x3250, 0x3250, 28),
+    (0x3251, 0x325F, 11),
+    (0x3260, 0x327F, 28),
+    (0x3280, 0x3289, 11),
+    (0x328A, 0x32B0, 28),
+    (0x32B1, 0x32BF, 11),
+    (0x32C0, 0x33FF, 28),
+    (0x3400, 0x4DBF, 5),
+    (0x4DC0, 0x4DFF, 28),
+    (0x4E00, 0x9FFC, 5),
+    (0xA000, 0xA014, 5),
+    (0xA015, 0xA015, 4),
+    (0xA016, 0xA48C, 5),
+    (0xA490, 0xA4C6, 28),
+    (0xA4D0, 0xA4F7, 5),
+    (0xA4F8, 0xA4FD, 4),
+    (0xA4FE, 0xA4FF, 24),
+    (0xA500, 0xA60B, 5),
+    (0xA60C, 0xA60C, 4),
+    (0xA60D, 0xA60F, 24),
+    (0xA610, 0xA61F, 5),
+    (0xA620, 0xA629, 9),
+    (0xA62A, 0xA62B, 5),
+    (0xA640, 0xA640, 1),
+    (0xA641, 0xA641, 2),
+    (0xA642, 0xA642, 1),
+    (0xA643, 0xA643, 2),
+    (0xA644, 0xA644, 1),
+    (0xA645, 0xA645, 2),
+    (0xA646, 0xA646, 1),
+    (0xA647, 0xA647, 2),
+    (0xA648, 0xA648, 1),
+    (0xA649, 0xA649, 2),
+    (0xA64A, 0xA64A, 1),
+    (0xA64B, 0xA64B, 2),
+    (0xA64C, 0xA64C, 1),
+    (0xA64D, 0xA64D, 2),
+    (0xA64E, 0xA64E, 1),
+    (0xA64F, 0xA64F, 2),
+    (0xA650, 0xA650, 1),
+    (0xA651, 0xA651, 2),
+    (0xA652, 0xA652, 1),
+    (0xA653, 0xA653, 2),
+    (0xA654, 0xA654, 1),
+    (0xA655, 0xA655, 2),
+    (0xA656, 0xA656, 1),
+    (0xA657, 0xA657, 2),
+    (0xA658, 0xA658, 1),
+    (0xA659, 0xA659, 2),
+    (0xA65A, 0xA65A, 1),
+    (0xA65B, 0xA65B, 2),
+    (0xA65C, 0xA65C, 1),
+    (0xA65D, 0xA65D, 2),
+    (0xA65E, 0xA65E, 1),
+    (0xA65F, 0xA65F, 2),
+    (0xA660, 0xA660, 1),
+    (0xA661, 0xA661, 2),
+    (0xA662, 0xA662, 1),
+    (0xA663, 0xA663, 2),
+    (0xA664, 0xA664, 1),
+    (0xA665, 0xA665, 2),
+    (0xA666, 0xA666, 1),
+    (0xA667, 0xA667, 2),
+    (0xA668, 0xA668, 1),
+    (0xA669, 0xA669, 2),
+    (0xA66A, 0xA66A, 1),
+    (0xA66B, 0xA66B, 2),
+    (0xA66C, 0xA66C, 1),
+    (0xA66D, 0xA66D, 2),
+    (0xA66E, 0xA66E, 5),
+    (0xA66F, 0xA66F, 6),
+    (0xA670, 0xA672, 7),
+    (0xA673, 0xA673, 24),
+    (0xA674, 0xA67D, 6),
+    (0xA67E, 0xA67E, 24),
+    (0xA67F, 0xA67F, 4),
+    (0xA680, 0xA680, 1),
+    (0xA681, 0xA681, 2),
+    (0xA682, 0xA682, 1),
+    (0xA683, 0xA683, 2),
+    (0xA684, 0xA684, 1),
+    (0xA685, 0xA685, 2),
+    (0xA686, 0xA686, 1),
+    (0xA687, 0xA687, 2),
+    (0xA688, 0xA688, 1),
+    (0xA689, 0xA689, 2),
+    (0xA68A, 0xA68A, 1),
+    (0xA68B, 0xA68B, 2),
+    (0xA68C, 0xA68C, 1),
+    (0xA68D, 0xA68D, 2),
+    (0xA68E, 0xA68E, 1),
+    (0xA68F, 0xA68F, 2),
+    (0xA690, 0xA690, 1),
+    (0xA691, 0xA691, 2),
+    (0xA692, 0xA692, 1),
+    (0xA693, 0xA693, 2),
+    (0xA694, 0xA694, 1),
+    (0xA695, 0xA695, 2),
+    (0xA696, 0xA696, 1),
+    (0xA697, 0xA697, 2),
+    (0xA698, 0xA698, 1),
+    (0xA699, 0xA699, 2),
+    (0xA69A, 0xA69A, 1),
+    (0xA69B, 0xA69B, 2),
+    (0xA69C, 0xA69D, 4),
+    (0xA69E, 0xA69F, 6),
+    (0xA6A0, 0xA6E5, 5),
+    (0xA6E6, 0xA6EF, 10),
+    (0xA6F0, 0xA6F1, 6),
+    (0xA6F2, 0xA6F7, 24),
+    (0xA700, 0xA716, 27),
+    (0xA717, 0xA71F, 4),
+    (0xA720, 0xA721, 27),
+    (0xA722, 0xA722, 1),
+    (0xA723, 0xA723, 2),
+    (0xA724, 0xA724, 1),
+    (0xA725, 0xA725, 2),
+    (0xA726, 0xA726, 1),
+    (0xA727, 0xA727, 2),
+    (0xA728, 0xA728, 1),
+    (0xA729, 0xA729, 2),
+    (0xA72A, 0xA72A, 1),
+    (0xA72B, 0xA72B, 2),
+    (0xA72C, 0xA72C, 1),
+    (0xA72D, 0xA72D, 2),
+    (0xA72E, 0xA72E, 1),
+    (0xA72F, 0xA731, 2),
+    (0xA732, 0xA732, 1),
+    (0xA733, 0xA733, 2),
+    (0xA734, 0xA734, 1),
+    (0xA735, 0xA735, 2),
+    (0xA736, 0xA736, 1),
+    (0xA737, 0xA737, 2),
+    (0xA738, 0xA738, 1),
+    (0xA739, 0xA739, 2),
+    (0xA73A, 0xA73A, 1),
+    (0xA73B, 0xA73B, 2),
+    (0xA73C, 0xA73C, 1),
+    (0xA73D, 0xA73D, 2),
+    (0xA73E, 0xA73E, 1),
+    (0xA73F, 0xA73F, 2),
+    (0xA740, 0xA740, 1),
+    (0xA741, 0xA741, 2),
+    (0xA742, 0xA742, 1),
+    (0xA743, 0xA743, 2),
+    (0xA744, 0xA744, 1),
+    (0xA745, 0xA745, 2),
+    (0xA746, 0xA746, 1),
+    (0xA747, 0xA747, 2),
+    (0xA748, 0xA748, 1),
+    (0xA749, 0xA749, 2),
+    (0xA74A, 0xA74A, 1),
+    (0xA74B, 0xA74B, 2),
+    (0xA74C, 0xA74C, 1),
+    (0xA74D, 0xA74D, 2),
+    (0xA74E, 0xA74E, 1),
+    (0xA74F, 0xA74F, 2),
+    (0xA750, 0xA750, 1),
+    (0xA751, 0xA751, 2),
+    (0xA752, 0xA752, 1),
+    (0xA753, 0xA753, 2),
+    (0xA754, 0xA754, 1),
+    (0xA755, 0xA755, 2),
+    (0xA756, 0xA756, 1),
+    (0xA757, 0xA757, 2),
+    (0xA758, 0xA758, 1),
+    (0xA759, 0xA759, 2),
+    (0xA75A, 0xA75A, 1),
+    (0xA75B, 0xA75B, 2),
+    (0xA75C, 0xA75C, 1),
+    (0xA75D, 0xA75D, 2),
+    (0xA75E, 0xA75E, 1),
+    (0xA75F, 0xA75F, 2),
+    (0xA760, 0xA760, 1),
+    (0xA761, 0xA761, 2),
+    (0xA762, 0xA762, 1),
+    (0xA763, 0xA763, 2),
+    (0xA764, 0xA764, 1),
+    (0xA765, 0xA765, 2),
+    (0xA766, 0xA766, 1),
+    (0xA767, 0xA767, 2),
+    (0xA768, 0xA768, 1),
+    (0xA769, 0xA769, 2),
+    (0xA76A, 0xA76A, 1),
+    (0xA76B, 0xA76B, 2),
+    (0xA76C, 0xA76C, 1),
+    (0xA76D, 0xA76D, 2),
+    (0xA76E, 0xA76E, 1),
+    (0xA76F, 0xA76F, 2),
+    (0xA770, 0xA770, 4),
+    (0xA771, 0xA778, 2),
+    (0xA779, 0xA779, 1),
+    (0xA77A, 0xA77A, 2),
+    (0xA77B, 0xA77B, 1),
+    (0xA77C, 0xA77C, 2),
+    (0xA77D, 0xA77E, 1),
+    (0xA77F, 0xA77F, 2),
+    (0xA780, 0xA780, 1),
+    (0xA781, 0xA781, 2),
+    (0xA782, 0xA782, 1),
+    (0xA783, 0xA783, 2),
+    (0xA784, 0xA784, 1),
+    (0xA785, 0xA785, 2),
+    (0xA786, 0xA786, 1),
+    (0xA787, 0xA787, 2),
+    (0xA788, 0xA788, 4),
+    (0xA789, 0xA78A, 27),
+    (0xA78B, 0xA78B, 1),
+    (0xA78C, 0xA78C, 2),
+    (0xA78D, 0xA78D, 1),
+    (0xA78E, 0xA78E, 2),
+    (0xA78F, 0xA78F, 5),
+    (0xA790, 0xA790, 1),
+    (0xA791, 0xA791, 2),
+    (0xA792, 0xA792, 1),
+    (0xA793, 0xA795, 2),
+    (0xA796, 0xA796, 1),
+    (0xA797, 0xA797, 2),
+    (0xA798, 0xA798, 1),
+    (0xA799, 0xA799, 2),
+    (0xA79A, 0xA79A, 1),
+    (0xA79B, 0xA79B, 2),
+    (0xA79C, 0xA79C, 1),
+    (0xA79D, 0xA79D, 2),
+    (0xA79E, 0xA79E, 1),
+    (0xA79F, 0xA79F, 2),
+    (0xA7A0, 0xA7A0, 1),
+    (0xA7A1, 0xA7A1, 2),
+    (0xA7A2, 0xA7A2, 1),
+    (0xA7A3, 0xA7A3, 2),
+    (0xA7A4, 0xA7A4, 1),
+    (0xA7A5, 0xA7A5, 2),
+    (0xA7A6, 0xA7A6, 1),
+    (0xA7A7, 0xA7A7, 2),
+    (0xA7A8, 0xA7A8, 1),
+    (0xA7A9, 0xA7A9, 2),
+    (0xA7AA, 0xA7AE, 1),
+    (0xA7AF, 0xA7AF, 2),
+    (0xA7B0, 0xA7B4, 1),
+    (0xA7B5, 0xA7B5, 2),
+    (0xA7B6, 0xA7B6, 1),
+    (0xA7B7, 0xA7B7, 2),
+    (0xA7B8, 0xA7B8, 1),
+    (0xA7B9, 0xA7B9, 2),
+    (0xA7BA, 0xA7BA, 1),
+    (0xA7BB, 0xA7BB, 2),
+    (0xA7BC, 0xA7BC, 1),
+    (0xA7BD, 0xA7BD, 2),
+    (0xA7BE, 0xA7BE, 1),
+    (0xA7BF, 0xA7BF, 2),
+    (0xA7C2, 0xA7C2, 1),
+    (0xA7C3, 0xA7C3, 2),
+    (0xA7C4, 0xA7C7, 1),
+    (0xA7C8, 0xA7C8, 2),
+    (0xA7C9, 0xA7C9, 1),
+    (0xA7CA, 0xA7CA, 2),
+    (0xA7F5, 0xA7F5, 1),
+    (0xA7F6, 0xA7F6, 2),
+    (0xA7F7, 0xA7F7, 5),
+    (0xA7F8, 0xA7F9, 4),
+    (0xA7FA, 0xA7FA, 2),
+    (0xA7FB, 0xA801, 5),
+    (0xA802, 0xA802, 6),
+    (0xA803, 0xA805, 5),
+    (0xA806, 0xA806, 6),
+    (0xA807, 0xA80A, 5),
+    (0xA80B, 0xA80B, 6),
+    (0xA80C, 0xA822, 5),
+    (0xA823, 0xA824, 8),
+    (0xA825, 0xA826, 6),
+    (0xA827, 0xA827, 8),
+    (0xA828, 0xA82B, 28),
+    (0xA82C, 0xA82C, 6),
+    (0xA830, 0xA835, 11),
+    (0xA836, 0xA837, 28),
+    (0xA838, 0xA838, 26),
+    (0xA839, 0xA839, 28),
+    (0xA840, 0xA873, 5),
+    (0xA874, 0xA877, 24),
+    (0xA880, 0xA881, 8),
+    (0xA882, 0xA8B3, 5),
+    (0xA8B4, 0xA8C3, 8),
+    (0xA8C4, 0xA8C5, 6),
+    (0xA8CE, 0xA8CF, 24),
+    (0xA8D0, 0xA8D9, 9),
+    (0xA8E0, 0xA8F1, 6),
+    (0xA8F2, 0xA8F7, 5),
+    (0xA8F8, 0xA8FA, 24),
+    (0xA8FB, 0xA8FB, 5),
+    (0xA8FC, 0xA8FC, 24),
+    (0xA8FD, 0xA8FE, 5),
+    (0xA8FF, 0xA8FF, 6),
+    (0xA900, 0xA909, 9),
+    (0xA90A, 0xA925, 5),
+    (0xA926, 0xA92D, 6),
+    (0xA92E, 0xA92F, 24),
+    (0xA930, 0xA946, 5),
+    (0xA947, 0xA951, 6),
+    (0xA952, 0xA953, 8),
+    (0xA95F, 0xA95F, 24),
+    (0xA960, 0xA97C, 5),
+    (0xA980, 0xA982, 6),
+    (0xA983, 0xA983, 8),
+    (0xA984, 0xA9B2, 5),
+    (0xA9B3, 0xA9B3, 6),
+    (0xA9B4, 0xA9B5, 8),
+    (0xA9B6, 0xA9B9, 6),
+    (0xA9BA, 0xA9BB, 8),
+    (0xA9BC, 0xA9BD, 6),
+    (0xA9BE, 0xA9C0, 8),
+    (0xA9C1, 0xA9CD, 24),
+    (0xA9CF, 0xA9CF, 4),
+    (0xA9D0, 0xA9D9, 9),
+    (0xA9DE, 0xA9DF, 24),
+    (0xA9E0, 0xA9E4, 5),
+    (0xA9E5, 0xA9E5, 6),
+    (0xA9E6, 0xA9E6, 4),
+    (0xA9E7, 0xA9EF, 5),
+    (0xA9F0, 0xA9F9, 9),
+    (0xA9FA, 0xA9FE, 5),
+    (0xAA00, 0xAA28, 5),
+    (0xAA29, 0xAA2E, 6),
+    (0xAA2F, 0xAA30, 8),
+    (0xAA31, 0xAA32, 6),
+    (0xAA33, 0xAA34, 8),
+    (0xAA35, 0xAA36, 6),
+    (0xAA40, 0xAA42, 5),
+    (0xAA43, 0xAA43, 6),
+    (0xAA44, 0xAA4B, 5),
+    (0xAA4C, 0xAA4C, 6),
+    (0xAA4D, 0xAA4D, 8),
+    (0xAA50, 0xAA59, 9),
+    (0xAA5C, 0xAA5F, 24),
+    (0xAA60, 0xAA6F, 5),
+    (0xAA70, 0xAA70, 4),
+    (0xAA71, 0xAA76, 5),
+    (0xAA77, 0xAA79, 28),
+    (0xAA7A, 0xAA7A, 5),
+    (0xAA7B, 0xAA7B, 8),
+    (0xAA7C, 0xAA7C, 6),
+    (0xAA7D, 0xAA7D, 8),
+    (0xAA7E, 0xAAAF, 5),
+    (0xAAB0, 0xAAB0, 6),
+    (0xAAB1, 0xAAB1, 5),
+    (0xAAB2, 0xAAB4, 6),
+    (0xAAB5, 0xAAB6, 5),
+    (0xAAB7, 0xAAB8, 6),
+    (0xAAB9, 0xAABD, 5),
+    (0xAABE, 0xAABF, 6),
+    (0xAAC0, 0xAAC0, 5),
+    (0xAAC1, 0xAAC1, 6),
+    (0xAAC2, 0xAAC2, 5),
+    (0xAADB, 0xAADC, 5),
+    (0xAADD, 0xAADD, 4),
+    (0xAADE, 0xAADF, 24),
+    (0xAAE0, 0xAAEA, 5),
+    (0xAAEB, 0xAAEB, 8),
+    (0xAAEC, 0xAAED, 6),
+    (0xAAEE, 0xAAEF, 8),
+    (0xAAF0, 0xAAF1, 24),
+    (0xAAF2, 0xAAF2, 5),
+    (0xAAF3, 0xAAF4, 4),
+    (0xAAF5, 0xAAF5, 8),
+    (0xAAF6, 0xAAF6, 6),
+    (0xAB01, 0xAB06, 5),
+    (0xAB09, 0xAB0E, 5),
+    (0xAB11, 0xAB16, 5),
+    (0xAB20, 0xAB26, 5),
+    (0xAB28, 0xAB2E, 5),
+    (0xAB30, 0xAB5A, 2),
+    (0xAB5B, 0xAB5B, 27),
+    (0xAB5C, 0xAB5F, 4),
+    (0xAB60, 0xAB68, 2),
+    (0xAB69, 0xAB69, 4),
+    (0xAB6A, 0xAB6B, 27),
+    (0xAB70, 0xABBF, 2),
+    (0xABC0, 0xABE2, 5),
+    (0xABE3, 0xABE4, 8),
+    (0xABE5, 0xABE5, 6),
+    (0xABE6, 0xABE7, 8),
+    (0xABE8, 0xABE8, 6),
+    (0xABE9, 0xABEA, 8),
+    (0xABEB, 0xABEB, 24),
+    (0xABEC, 0xABEC, 8),
+    (0xABED, 0xABED, 6),
+    (0xABF0, 0xABF9, 9),
+    (0xAC00, 0xD7A3, 5),
+    (0xD7B0, 0xD7C6, 5),
+    (0xD7CB, 0xD7FB, 5),
+    (0xD800, 0xDFFF, 19),
+    (0xE000, 0xF8FF, 18),
+    (0xF900, 0xFA6D, 5),
+    (0xFA70, 0xFAD9, 5),
+    (0xFB00, 0xFB06, 2),
+    (0xFB13, 0xFB17, 2),
+    (0xFB1D, 0xFB1D, 5),
+    (0xFB1E, 0xFB1E, 6),
+    (0xFB1F, 0xFB28, 5),
+    (0xFB29, 0xFB29, 25),
+    (0xFB2A, 0xFB36, 5),
+    (0xFB38, 0xFB3C, 5),
+    (0xFB3E, 0xFB3E, 5),
+    (0xFB40, 0xFB41, 5),
+    (0xFB43, 0xFB44, 5),
+    (0xFB46, 0xFBB1, 5),
+    (0xFBB2, 0xFBC1, 27),
+    (0xFBD3, 0xFD3D, 5),
+    (0xFD3E, 0xFD3E, 22),
+    (0xFD3F, 0xFD3F, 21),
+    (0xFD50, 0xFD8F, 5),
+    (0xFD92, 0xFDC7, 5),
+    (0xFDF0, 0xFDFB, 5),
+    (0xFDFC, 0xFDFC, 26),
+    (0xFDFD, 0xFDFD, 28),
+    (0xFE00, 0xFE0F, 6),
+    (0xFE10, 0xFE16, 24),
+    (0xFE17, 0xFE17, 21),
+    (0xFE18, 0xFE18, 22),
+    (0xFE19, 0xFE19, 24),
+    (0xFE20, 0xFE2F, 6),
+    (0xFE30, 0xFE30, 24),
+    (0xFE31, 0xFE32, 20),
+    (0xFE33, 0xFE34, 23),
+    (0xFE35, 0xFE35, 21),
+    (0xFE36, 0xFE36, 22),
+    (0xFE37, 0xFE37, 21),
+    (0xFE38, 0xFE38, 22),
+    (0xFE39, 0xFE39, 21),
+    (0xFE3A, 0xFE3A, 22),
+    (0xFE3B, 0xFE3B, 21),
+    (0xFE3C, 0xFE3C, 22),
+    (0xFE3D, 0xFE3D, 21),
+    (0xFE3E, 0xFE3E, 22),
+    (0xFE3F, 0xFE3F, 21),
+    (0xFE40, 0xFE40, 22),
+    (0xFE41, 0xFE41, 21),
+    (0xFE42, 0xFE42, 22),
+    (0xFE43, 0xFE43, 21),
+    (0xFE44, 0xFE44, 22),
+    (0xFE45, 0xFE46, 24),
+    (0xFE47, 0xFE47, 21),
+    (0xFE48, 0xFE48, 22),
+    (0xFE49, 0xFE4C, 24),
+    (0xFE4D, 0xFE4F, 23),
+    (0xFE50, 0xFE52, 24),
+    (0xFE54, 0xFE57, 24),
+    (0xFE58, 0xFE58, 20),
+    (0xFE59, 0xFE59, 21),
+    (0xFE5A, 0xFE5A, 22),
+    (0xFE5B, 0xFE5B, 21),
+    (0xFE5C, 0xFE5C, 22),
+    (0xFE5D, 0xFE5D, 21),
+    (0xFE5E, 0xFE5E, 22),
+    (0xFE5F, 0xFE61, 24),
+    (0xFE62, 0xFE62, 25),
+    (0xFE63, 0xFE63, 20),
+    (0xFE64, 0xFE66, 25),
+    (0xFE68, 0xFE68, 24),
+    (0xFE69, 0xFE69, 26),
+    (0xFE6A, 0xFE6B, 24),
+    (0xFE70, 0xFE74, 5),
+    (0xFE76, 0xFEFC, 5),
+    (0xFEFF, 0xFEFF, 16),
+    (0xFF01, 0xFF03, 24),
+    (0xFF04, 0xFF04, 26),
+    (0xFF05, 0xFF07, 24),
+    (0xFF08, 0xFF08, 21),
+    (0xFF09, 0xFF09, 22),
+    (0xFF0A, 0xFF0A, 24),
+    (0xFF0B, 0xFF0B, 25),
+    (0xFF0C, 0xFF0C, 24),
+    (0xFF0D, 0xFF0D, 20),
+    (0xFF0E, 0xFF0F, 24),
+    (0xFF10, 0xFF19, 9),
+    (0xFF1A, 0xFF1B, 24),
+    (0xFF1C, 0xFF1E, 25),
+    (0xFF1F, 0xFF20, 24),
+    (0xFF21, 0xFF3A, 1),
+    (0xFF3B, 0xFF3B, 21),
+    (0xFF3C, 0xFF3C, 24),
+    (0xFF3D, 0xFF3D, 22),
+    (0xFF3E, 0xFF3E, 27),
+    (0xFF3F, 0xFF3F, 23),
+    (0xFF40, 0xFF40, 27),
+    (0xFF41, 0xFF5A, 2),
+    (0xFF5B, 0xFF5B, 21),
+    (0xFF5C, 0xFF5C, 25),
+    (0xFF5D, 0xFF5D, 22),
+    (0xFF5E, 0xFF5E, 25),
+    (0xFF5F, 0xFF5F, 21),
+    (0xFF60, 0xFF60, 22),
+    (0xFF61, 0xFF61, 24),
+    (0xFF62, 0xFF62, 21),
+    (0xFF63, 0xFF63, 22),
+    (0xFF64, 0xFF65, 24),
+    (0xFF66, 0xFF6F, 5),
+    (0xFF70, 0xFF70, 4),
+    (0xFF71, 0xFF9D, 5),
+    (0xFF9E, 0xFF9F, 4),
+    (0xFFA0, 0xFFBE, 5),
+    (0xFFC2, 0xFFC7, 5),
+    (0xFFCA, 0xFFCF, 5),
+    (0xFFD2, 0xFFD7, 5),
+    (0xFFDA, 0xFFDC, 5),
+    (0xFFE0, 0xFFE1, 26),
+    (0xFFE2, 0xFFE2, 25),
+    (0xFFE3, 0xFFE3, 27),
+    (0xFFE4, 0xFFE4, 28),
+    (0xFFE5, 0xFFE6, 26),
+    (0xFFE8, 0xFFE8, 28),
+    (0xFFE9, 0xFFEC, 25),
+    (0xFFED, 0xFFEE, 28),
+    (0xFFF9, 0xFFFB, 16),
+    (0xFFFC, 0xFFFD, 28),
+    (0x10000, 0x1000B, 5),
+    (0x1000D, 0x10026, 5),
+    (0x10028, 0x1003A, 5),
+    (0x1003C, 0x1003D, 5),
+    (0x1003F, 0x1004D, 5),
+    (0x10050, 0x1005D, 5),
+    (0x10080, 0x100FA, 5),
+    (0x10100, 0x10102, 24),
+    (0x10107, 0x10133, 11),
+    (0x10137, 0x1013F, 28),
+    (0x10140, 0x10174, 10),
+    (0x10175, 0x10178, 11),
+    (0x10179, 0x10189, 28),
+    (0x1018A, 0x1018B, 11),
+    (0x1018C, 0x1018E, 28),
+    (0x10190, 0x1019C, 28),
+    (0x101A0, 0x101A0, 28),
+    (0x101D0, 0x101FC, 28),
+    (0x101FD, 0x101FD, 6),
+    (0x10280, 0x1029C, 5),
+    (0x102A0, 0x102D0, 5),
+    (0x102E0, 0x102E0, 6),
+    (0x102E1, 0x102FB, 11),
+    (0x10300, 0x1031F, 5),
+    (0x10320, 0x10323, 11),
+    (0x1032D, 0x10340, 5),
+    (0x10341, 0x10341, 10),
+    (0x10342, 0x10349, 5),
+    (0x1034A, 0x1034A, 10),
+    (0x10350, 0x10375, 5),
+    (0x10376, 0x1037A, 6),
+    (0x10380, 0x1039D, 5),
+    (0x1039F, 0x1039F, 24),
+    (0x103A0, 0x103C3, 5),
+    (0x103C8, 0x103CF, 5),
+    (0x103D0, 0x103D0, 24),
+    (0x103D1, 0x103D5, 10),
+    (0x10400, 0x10427, 1),
+    (0x10428, 0x1044F, 2),
+    (0x10450, 0x1049D, 5),
+    (0x104A0, 0x104A9, 9),
+    (0x104B0, 0x104D3, 1),
+    (0x104D8, 0x104FB, 2),
+    (0x10500, 0x10527, 5),
+    (0x10530, 0x10563, 5),
+    (0x1056F, 0x1056F, 24),
+    (0x10600, 0x10736, 5),
+    (0x10740, 0x10755, 5),
+    (0x10760, 0x10767, 5),
+    (0x10800, 0x10805, 5),
+    (0x10808, 0x10808, 5),
+    (0x1080A, 0x10835, 5),
+    (0x10837, 0x10838, 5),
+    (0x1083C, 0x1083C, 5),
+    (0x1083F, 0x10855, 5),
+    (0x10857, 0x10857, 24),
+    (0x10858, 0x1085F, 11),
+    (0x10860, 0x10876, 5),
+    (0x10877, 0x10878, 28),
+    (0x10879, 0x1087F, 11),
+    (0x10880, 0x1089E, 5),
+    (0x108A7, 0x108AF, 11),
+    (0x108E0, 0x108F2, 5),
+    (0x108F4, 0x108F5, 5),
+    (0x108FB, 0x108FF, 11),
+    (0x10900, 0x10915, 5),
+    (0x10916, 0x1091B, 11),
+    (0x1091F, 0x1091F, 24),
+    (0x10920, 0x10939, 5),
+    (0x1093F, 0x1093F, 24),
+    (0x10980, 0x109B7, 5),
+    (0x109BC, 0x109BD, 11),
+    (0x109BE, 0x109BF, 5),
+    (0x109C0, 0x109CF, 11),
+    (0x109D2, 0x109FF, 11),
+    (0x10A00, 0x10A00, 5),
+    (0x10A01, 0x10A03, 6),
+    (0x10A05, 0x10A06, 6),
+    (0x10A0C, 0x10A0F, 6),
+    (0x10A10, 0x10A13, 5),
+    (0x10A15, 0x10A17, 5),
+    (0x10A19, 0x10A35, 5),
+    (0x10A38, 0x10A3A, 6),
+    (0x10A3F, 0x10A3F, 6),
+    (0x10A40, 0x10A48, 11),
+    (0x10A50, 0x10A58, 24),
+    (0x10A60, 0x10A7C, 5),
+    (0x10A7D, 0x10A7E, 11),
+    (0x10A7F, 0x10A7F, 24),
+    (0x10A80, 0x10A9C, 5),
+    (0x10A9D, 0x10A9F, 11),
+    (0x10AC0, 0x10AC7, 5),
+    (0x10AC8, 0x10AC8, 28),
+    (0x10AC9, 0x10AE4, 5),
+    (0x10AE5, 0x10AE6, 6),
+    (0x10AEB, 0x10AEF, 11),
+    (0x10AF0, 0x10AF6, 24),
+    (0x10B00, 0x10B35, 5),
+    (0x10B39, 0x10B3F, 24),
+    (0x10B40, 0x10B55, 5),
+    (0x10B58, 0x10B5F, 11),
+    (0x10B60, 0x10B72, 5),
+    (0x10B78, 0x10B7F, 11),
+    (0x10B80, 0x10B91, 5),
+    (0x10B99, 0x10B9C, 24),
+    (0x10BA9, 0x10BAF, 11),
+    (0x10C00, 0x10C48, 5),
+    (0x10C80, 0x10CB2, 1),
+    (0x10CC0, 0x10CF2, 2),
+    (0x10CFA, 0x10CFF, 11),
+    (0x10D00, 0x10D23, 5),
+    (0x10D24, 0x10D27, 6),
+    (0x10D30, 0x10D39, 9),
+    (0x10E60, 0x10E7E, 11),
+    (0x10E80, 0x10EA9, 5),
+    (0x10EAB, 0x10EAC, 6),
+    (0x10EAD, 0x10EAD, 20),
+    (0x10EB0, 0x10EB1, 5),
+    (0x10F00, 0x10F1C, 5),
+    (0x10F1D, 0x10F26, 11),
+    (0x10F27, 0x10F27, 5),
+    (0x10F30, 0x10F45, 5),
+    (0x10F46, 0x10F50, 6),
+    (0x10F51, 0x10F54, 11),
+    (0x10F55, 0x10F59, 24),
+    (0x10FB0, 0x10FC4, 5),
+    (0x10FC5, 0x10FCB, 11),
+    (0x10FE0, 0x10FF6, 5),
+    (0x11000, 0x11000, 8),
+    (0x11001, 0x11001, 6),
+    (0x11002, 0x11002, 8),
+    (0x11003, 0x11037, 5),
+    (0x11038, 0x11046, 6),
+    (0x11047, 0x1104D, 24),
+    (0x11052, 0x11065, 11),
+    (0x11066, 0x1106F, 9),
+    (0x1107F, 0x11081, 6),
+    (0x11082, 0x11082, 8),
+    (0x11083, 0x110AF, 5),
+    (0x110B0, 0x110B2, 8),
+    (0x110B3, 0x110B6, 6),
+    (0x110B7, 0x110B8, 8),
+    (0x110B9, 0x110BA, 6),
+    (0x110BB, 0x110BC, 24),
+    (0x110BD, 0x110BD, 16),
+    (0x110BE, 0x110C1, 24),
+    (0x110CD, 0x110CD, 16),
+    (0x110D0, 0x110E8, 5),
+    (0x110F0, 0x110F9, 9),
+    (0x11100, 0x11102, 6),
+    (0x11103, 0x11126, 5),
+    (0x11127, 0x1112B, 6),
+    (0x1112C, 0x1112C, 8),
+    (0x1112D, 0x11134, 6),
+    (0x11136, 0x1113F, 9),
+    (0x11140, 0x11143, 24),
+    (0x11144, 0x11144, 5),
+    (0x11145, 0x11146, 8),
+    (0x11147, 0x11147, 5),
+    (0x11150, 0x11172, 5),
+    (0x11173, 0x11173, 6),
+    (0x11174, 0x11175, 24),
+    (0x11176, 0x11176, 5),
+    (0x11180, 0x11181, 6),
+    (0x11182, 0x11182, 8),
+    (0x11183, 0x111B2, 5),
+    (0x111B3, 0x111B5, 8),
+    (0x111B6, 0x111BE, 6),
+    (0x111BF, 0x111C0, 8),
+    (0x111C1, 0x111C4, 5),
+    (0x111C5, 0x111C8, 24),
+    (0x111C9, 0x111CC, 6),
+    (0x111CD, 0x111CD, 24),
+    (0x111CE, 0x111CE, 8),
+    (0x111CF, 0x111CF, 6),
+    (0x111D0, 0x111D9, 9),
+    (0x111DA, 0x111DA, 5),
+    (0x111DB, 0x111DB, 24),
+    (0x111DC, 0x111DC, 5),
+    (0x111DD, 0x111DF, 24),
+    (0x111E1, 0x111F4, 11),
+    (0x11200, 0x11211, 5),
+    (0x11213, 0x1122B, 5),
+    (0x1122C, 0x1122E, 8),
+    (0x1122F, 0x11231, 6),
+    (0x11232, 0x11233, 8),
+    (0x11234, 0x11234, 6),
+    (0x11235, 0x11235, 8),
+    (0x11236, 0x11237, 6),
+    (0x11238, 0x1123D, 24),
+    (0x1123E, 0x1123E, 6),
+    (0x11280, 0x11286, 5),
+    (0x11288, 0x11288, 5),
+    (0x1128A, 0x1128D, 5),
+    (0x1128F, 0x1129D, 5),
+    (0x1129F, 0x112A8, 5),
+    (0x112A9, 0x112A9, 24),
+    (0x112B0, 0x112DE, 5),
+    (0x112DF, 0x112DF, 6),
+    (0x112E0, 0x112E2, 8),
+    (0x112E3, 0x112EA, 6),
+    (0x112F0, 0x112F9, 9),
+    (0x11300, 0x11301, 6),
+    (0x11302, 0x11303, 8),
+    (0x11305, 0x1130C, 5),
+    (0x1130F, 0x11310, 5),
+    (0x11313, 0x11328, 5),
+    (0x1132A, 0x11330, 5),
+    (0x11332, 0x11333, 5),
+    (0x11335, 0x11339, 5),
+    (0x1133B, 0x1133C, 6),
+    (0x1133D, 0x1133D, 5),
+    (0x1133E, 0x1133F, 8),
+    (0x11340, 0x11340, 6),
+    (0x11341, 0x11344, 8),
+    (0x11347, 0x11348, 8),
+    (0x1134B, 0x1134D, 8),
+    (0x11350, 0x11350, 5),
+    (0x11357, 0x11357, 8),
+    (0x1135D, 0x11361, 5),
+    (0x11362, 0x11363, 8),
+    (0x11366, 0x1136C, 6),
+    (0x11370, 0x11374, 6),
+    (0x11400, 0x11434, 5),
+    (0x11435, 0x11437, 8),
+    (0x11438, 0x1143F, 6),
+    (0x11440, 0x11441, 8),
+    (0x11442, 0x11444, 6),
+    (0x11445, 0x11445, 8),
+    (0x11446, 0x11446, 6),
+    (0x11447, 0x1144A, 5),
+    (0x1144B, 0x1144F, 24),
+    (0x11450, 0x11459, 9),
+    (0x1145A, 0x1145B, 24),
+    (0x1145D, 0x1145D, 24),
+    (0x1145E, 0x1145E, 6),
+    (0x1145F, 0x11461, 5),
+    (0x11480, 0x114AF, 5),
+    (0x114B0, 0x114B2, 8),
+    (0x114B3, 0x114B8, 6),
+    (0x114B9, 0x114B9, 8),
+    (0x114BA, 0x114BA, 6),
+    (0x114BB, 0x114BE, 8),
+    (0x114BF, 0x114C0, 6),
+    (0x114C1, 0x114C1, 8),
+    (0x114C2, 0x114C3, 6),
+    (0x114C4, 0x114C5, 5),
+    (0x114C6, 0x114C6, 24),
+    (0x114C7, 0x114C7, 5),
+    (0x114D0, 0x114D9, 9),
+    (0x11580, 0x115AE, 5),
+    (0x115AF, 0x115B1, 8),
+    (0x115B2, 0x115B5, 6),
+    (0x115B8, 0x115BB, 8),
+    (0x115BC, 0x115BD, 6),
+    (0x115BE, 0x115BE, 8),
+    (0x115BF, 0x115C0, 6),
+    (0x115C1, 0x115D7, 24),
+    (0x115D8, 0x115DB, 5),
+    (0x115DC, 0x115DD, 6),
+    (0x11600, 0x1162F, 5),
+    (0x11630, 0x11632, 8),
+    (0x11633, 0x1163A, 6),
+    (0x1163B, 0x1163C, 8),
+    (0x1163D, 0x1163D, 6),
+    (0x1163E, 0x1163E, 8),
+    (0x1163F, 0x11640, 6),
+    (0x11641, 0x11643, 24),
+    (0x11644, 0x11644, 5),
+    (0x11650, 0x11659, 9),
+    (0x11660, 0x1166C, 24),
+    (0x11680, 0x116AA, 5),
+    (0x116AB, 0x116AB, 6),
+    (0x116AC, 0x116AC, 8),
+    (0x116AD, 0x116AD, 6),
+    (0x116AE, 0x116AF, 8),
+    (0x116B0, 0x116B5, 6),
+    (0x116B6, 0x116B6, 8),
+    (0x116B7, 0x116B7, 6),
+    (0x116B8, 0x116B8, 5),
+    (0x116C0, 0x116C9, 9),
+    (0x11700, 0x1171A, 5),
+    (0x1171D, 0x1171F, 6),
+    (0x11720, 0x11721, 8),
+    (0x11722, 0x11725, 6),
+    (0x11726, 0x11726, 8),
+    (0x11727, 0x1172B, 6),
+    (0x11730, 0x11739, 9),
+    (0x1173A, 0x1173B, 11),
+    (0x1173C, 0x1173E, 24),
+    (0x1173F, 0x1173F, 28),
+    (0x11800, 0x1182B, 5),
+    (0x1182C, 0x1182E, 8),
+    (0x1182F, 0x11837, 6),
+    (0x11838, 0x11838, 8),
+    (0x11839, 0x1183A, 6),
+    (0x1183B, 0x1183B, 24),
+    (0x118A0, 0x118BF, 1),
+    (0x118C0, 0x118DF, 2),
+    (0x118E0, 0x118E9, 9),
+    (0x118EA, 0x118F2, 11),
+    (0x118FF, 0x11906, 5),
+    (0x11909, 0x11909, 5),
+    (0x1190C, 0x11913, 5),
+    (0x11915, 0x11916, 5),
+    (0x11918, 0x1192F, 5),
+    (0x11930, 0x11935, 8),
+    (0x11937, 0x11938, 8),
+    (0x1193B, 0x1193C, 6),
+    (0x1193D, 0x1193D, 8),
+    (0x1193E, 0x1193E, 6),
+    (0x1193F, 0x1193F, 5),
+    (0x11940, 0x11940, 8),
+    (0x11941, 0x11941, 5),
+    (0x11942, 0x11942, 8),
+    (0x11943, 0x11943, 6),
+    (0x11944, 0x11946, 24),
+    (0x11950, 0x11959, 9),
+    (0x119A0, 0x119A7, 5),
+    (0x119AA, 0x119D0, 5),
+    (0x119D1, 0x119D3, 8),
+    (0x119D4, 0x119D7, 6),
+    (0x119DA, 0x119DB, 6),
+    (0x119DC, 0x119DF, 8),
+    (0x119E0, 0x119E0, 6),
+    (0x119E1, 0x119E1, 5),
+    (0x119E2, 0x119E2, 24),
+    (0x119E3, 0x119E3, 5),
+    (0x119E4, 0x119E4, 8),
+    (0x11A00, 0x11A00, 5),
+    (0x11A01, 0x11A0A, 6),
+    (0x11A0B, 0x11A32, 5),
+    (0x11A33, 0x11A38, 6),
+    (0x11A39, 0x11A39, 8),
+    (0x11A3A, 0x11A3A, 5),
+    (0x11A3B, 0x11A3E, 6),
+    (0x11A3F, 0x11A46, 24),
+    (0x11A47, 0x11A47, 6),
+    (0x11A50, 0x11A50, 5),
+    (0x11A51, 0x11A56, 6),
+    (0x11A57, 0x11A58, 8),
+    (0x11A59, 0x11A5B, 6),
+    (0x11A5C, 0x11A89, 5),
+    (0x11A8A, 0x11A96, 6),
+    (0x11A97, 0x11A97, 8),
+    (0x11A98, 0x11A99, 6),
+    (0x11A9A, 0x11A9C, 24),
+    (0x11A9D, 0x11A9D, 5),
+    (0x11A9E, 0x11AA2, 24),
+    (0x11AC0, 0x11AF8, 5),
+    (0x11C00, 0x11C08, 5),
+    (0x11C0A, 0x11C2E, 5),
+    (0x11C2F, 0x11C2F, 8),
+    (0x11C30, 0x11C36, 6),
+    (0x11C38, 0x11C3D, 6),
+    (0x11C3E, 0x11C3E, 8),
+    (0x11C3F, 0x11C3F, 6),
+    (0x11C40, 0x11C40, 5),
+    (0x11C41, 0x11C45, 24),
+    (0x11C50, 0x11C59, 9),
+    (0x11C5A, 0x11C6C, 11),
+    (0x11C70, 0x11C71, 24),
+    (0x11C72, 0x11C8F, 5),
+    (0x11C92, 0x11CA7, 6),
+    (0x11CA9, 0x11CA9, 8),
+    (0x11CAA, 0x11CB0, 6),
+    (0x11CB1, 0x11CB1, 8),
+    (0x11CB2, 0x11CB3, 6),
+    (0x11CB4, 0x11CB4, 8),
+    (0x11CB5, 0x11CB6, 6),
+    (0x11D00, 0x11D06, 5),
+    (0x11D08, 0x11D09, 5),
+    (0x11D0B, 0x11D30, 5),
+    (0x11D31, 0x11D36, 6),
+    (0x11D3A, 0x11D3A, 6),
+    (0x11D3C, 0x11D3D, 6),
+    (0x11D3F, 0x11D45, 6),
+    (0x11D46, 0x11D46, 5),
+    (0x11D47, 0x11D47, 6),
+    (0x11D50, 0x11D59, 9),
+    (0x11D60, 0x11D65, 5),
+    (0x11D67, 0x11D68, 5),
+    (0x11D6A, 0x11D89, 5),
+    (0x11D8A, 0x11D8E, 8),
+    (0x11D90, 0x11D91, 6),
+    (0x11D93, 0x11D94, 8),
+    (0x11D95, 0x11D95, 6),
+    (0x11D96, 0x11D96, 8),
+    (0x11D97, 0x11D97, 6),
+    (0x11D98, 0x11D98, 5),
+    (0x11DA0, 0x11DA9, 9),
+    (0x11EE0, 0x11EF2, 5),
+    (0x11EF3, 0x11EF4, 6),
+    (0x11EF5, 0x11EF6, 8),
+    (0x11EF7, 0x11EF8, 24),
+    (0x11FB0, 0x11FB0, 5),
+    (0x11FC0, 0x11FD4, 11),
+    (0x11FD5, 0x11FDC, 28),
+    (0x11FDD, 0x11FE0, 26),
+    (0x11FE1, 0x11FF1, 28),
+    (0x11FFF, 0x11FFF, 24),
+    (0x12000, 0x12399, 5),
+    (0x12400, 0x1246E, 10),
+    (0x12470, 0x12474, 24),
+    (0x12480, 0x12543, 5),
+    (0x13000, 0x1342E, 5),
+    (0x13430, 0x13438, 16),
+    (0x14400, 0x14646, 5),
+    (0x16800, 0x16A38, 5),
+    (0x16A40, 0x16A5E, 5),
+    (0x16A60, 0x16A69, 9),
+    (0x16A6E, 0x16A6F, 24),
+    (0x16AD0, 0x16AED, 5),
+    (0x16AF0, 0x16AF4, 6),
+    (0x16AF5, 0x16AF5, 24),
+    (0x16B00, 0x16B2F, 5),
+    (0x16B30, 0x16B36, 6),
+    (0x16B37, 0x16B3B, 24),
+    (0x16B3C, 0x16B3F, 28),
+    (0x16B40, 0x16B43, 4),
+    (0x16B44, 0x16B44, 24),
+    (0x16B45, 0x16B45, 28),
+    (0x16B50, 0x16B59, 9),
+    (0x16B5B, 0x16B61, 11),
+    (0x16B63, 0x16B77, 5),
+    (0x16B7D, 0x16B8F, 5),
+    (0x16E40, 0x16E5F, 1),
+    (0x16E60, 0x16E7F, 2),
+    (0x16E80, 0x16E96, 11),
+    (0x16E97, 0x16E9A, 24),
+    (0x16F00, 0x16F4A, 5),
+    (0x16F4F, 0x16F4F, 6),
+    (0x16F50, 0x16F50, 5),
+    (0x16F51, 0x16F87, 8),
+    (0x16F8F, 0x16F92, 6),
+    (0x16F93, 0x16F9F, 4),
+    (0x16FE0, 0x16FE1, 4),
+    (0x16FE2, 0x16FE2, 24),
+    (0x16FE3, 0x16FE3, 4),
+    (0x16FE4, 0x16FE4, 6),
+    (0x16FF0, 0x16FF1, 8),
+    (0x17000, 0x187F7, 5),
+    (0x18800, 0x18CD5, 5),
+    (0x18D00, 0x18D08, 5),
+    (0x1B000, 0x1B11E, 5),
+    (0x1B150, 0x1B152, 5),
+    (0x1B164, 0x1B167, 5),
+    (0x1B170, 0x1B2FB, 5),
+    (0x1BC00, 0x1BC6A, 5),
+    (0x1BC70, 0x1BC7C, 5),
+    (0x1BC80, 0x1BC88, 5),
+    (0x1BC90, 0x1BC99, 5),
+    (0x1BC9C, 0x1BC9C, 28),
+    (0x1BC9D, 0x1BC9E, 6),
+    (0x1BC9F, 0x1BC9F, 24),
+    (0x1BCA0, 0x1BCA3, 16),
+    (0x1D000, 0x1D0F5, 28),
+    (0x1D100, 0x1D126, 28),
+    (0x1D129, 0x1D164, 28),
+    (0x1D165, 0x1D166, 8),
+    (0x1D167, 0x1D169, 6),
+    (0x1D16A, 0x1D16C, 28),
+    (0x1D16D, 0x1D172, 8),
+    (0x1D173, 0x1D17A, 16),
+    (0x1D17B, 0x1D182, 6),
+    (0x1D183, 0x1D184, 28),
+    (0x1D185, 0x1D18B, 6),
+    (0x1D18C, 0x1D1A9, 28),
+    (0x1D1AA, 0x1D1AD, 6),
+    (0x1D1AE, 0x1D1E8, 28),
+    (0x1D200, 0x1D241, 28),
+    (0x1D242, 0x1D244, 6),
+    (0x1D245, 0x1D245, 28),
+    (0x1D2E0, 0x1D2F3, 11),
+    (0x1D300, 0x1D356, 28),
+    (0x1D360, 0x1D378, 11),
+    (0x1D400, 0x1D419, 1),
+    (0x1D41A, 0x1D433, 2),
+    (0x1D434, 0x1D44D, 1),
+    (0x1D44E, 0x1D454, 2),
+    (0x1D456, 0x1D467, 2),
+    (0x1D468, 0x1D481, 1),
+    (0x1D482, 0x1D49B, 2),
+    (0x1D49C, 0x1D49C, 1),
+    (0x1D49E, 0x1D49F, 1),
+    (0x1D4A2, 0x1D4A2, 1),
+    (0x1D4A5, 0x1D4A6, 1),
+    (0x1D4A9, 0x1D4AC, 1),
+    (0x1D4AE, 0x1D4B5, 1),
+    (0x1D4B6, 0x1D4B9, 2),
+    (0x1D4BB, 0x1D4BB, 2),
+    (0x1D4BD, 0x1D4C3, 2),
+    (0x1D4C5, 0x1D4CF, 2),
+    (0x1D4D0, 0x1D4E9, 1),
+    (0x1D4EA, 0x1D503, 2),
+    (0x1D504, 0x1D505, 1),
+    (0x1D507, 0x1D50A, 1),
+    (0x1D50D, 0x1D514, 1),
+    (0x1D516, 0x1D51C, 1),
+    (0x1D51E, 0x1D537, 2),
+    (0x1D538, 0x1D539, 1),
+    (0x1D53B, 0x1D53E, 1),
+    (0x1D540, 0x1D544, 1),
+    (0x1D546, 0x1D546, 1),
+    (0x1D54A, 0x1D550, 1),
+    (0x1D552, 0x1D56B, 2),
+    (0x1D56C, 0x1D585, 1),
+    (0x1D586, 0x1D59F, 2),
+    (0x1D5A0, 0x1D5B9, 1),
+    (0x1D5BA, 0x1D5D3, 2),
+    (0x1D5D4, 0x1D5ED, 1),
+    (0x1D5EE, 0x1D607, 2),
+    (0x1D608, 0x1D621, 1),
+    (0x1D622, 0x1D63B, 2),
+    (0x1D63C, 0x1D655, 1),
+    (0x1D656, 0x1D66F, 2),
+    (0x1D670, 0x1D689, 1),
+    (0x1D68A, 0x1D6A5, 2),
+    (0x1D6A8, 0x1D6C0, 1),
+    (0x1D6C1, 0x1D6C1, 25),
+    (0x1D6C2, 0x1D6DA, 2),
+    (0x1D6DB, 0x1D6DB, 25),
+    (0x1D6DC, 0x1D6E1, 2),
+    (0x1D6E2, 0x1D6FA, 1),
+    (0x1D6FB, 0x1D6FB, 25),
+    (0x1D6FC, 0x1D714, 2),
+    (0x1D715, 0x1D715, 25),
+    (0x1D716, 0x1D71B, 2),
+    (0x1D71C, 0x1D734, 1),
+    (0x1D735, 0x1D735, 25),
+    (0x1D736, 0x1D74E, 2),
+    (0x1D74F, 0x1D74F, 25),
+    (0x1D750, 0x1D755, 2),
+    (0x1D756, 0x1D76E, 1),
+    (0x1D76F, 0x1D76F, 25),
+    (0x1D770, 0x1D788, 2),
+    (0x1D789, 0x1D789, 25),
+    (0x1D78A, 0x1D78F, 2),
+    (0x1D790, 0x1D7A8, 1),
+    (0x1D7A9, 0x1D7A9, 25),
+    (0x1D7AA, 0x1D7C2, 2),
+    (0x1D7C3, 0x1D7C3, 25),
+    (0x1D7C4, 0x1D7C9, 2),
+    (0x1D7CA, 0x1D7CA, 1),
+    (0x1D7CB, 0x1D7CB, 2),
+    (0x1D7CE, 0x1D7FF, 9),
+    (0x1D800, 0x1D9FF, 28),
+    (0x1DA00, 0x1DA36, 6),
+    (0x1DA37, 0x1DA3A, 28),
+    (0x1DA3B, 0x1DA6C, 6),
+    (0x1DA6D, 0x1DA74, 28),
+    (0x1DA75, 0x1DA75, 6),
+    (0x1DA76, 0x1DA83, 28),
+    (0x1DA84, 0x1DA84, 6),
+    (0x1DA85, 0x1DA86, 28),
+    (0x1DA87, 0x1DA8B, 24),
+    (0x1DA9B, 0x1DA9F, 6),
+    (0x1DAA1, 0x1DAAF, 6),
+    (0x1E000, 0x1E006, 6),
+    (0x1E008, 0x1E018, 6),
+    (0x1E01B, 0x1E021, 6),
+    (0x1E023, 0x1E024, 6),
+    (0x1E026, 0x1E02A, 6),
+    (0x1E100, 0x1E12C, 5),
+    (0x1E130, 0x1E136, 6),
+    (0x1E137, 0x1E13D, 4),
+    (0x1E140, 0x1E149, 9),
+    (0x1E14E, 0x1E14E, 5),
+    (0x1E14F, 0x1E14F, 28),
+    (0x1E2C0, 0x1E2EB, 5),
+    (0x1E2EC, 0x1E2EF, 6),
+    (0x1E2F0, 0x1E2F9, 9),
+    (0x1E2FF, 0x1E2FF, 26),
+    (0x1E800, 0x1E8C4, 5),
+    (0x1E8C7, 0x1E8CF, 11),
+    (0x1E8D0, 0x1E8D6, 6),
+    (0x1E900, 0x1E921, 1),
+    (0x1E922, 0x1E943, 2),
+    (0x1E944, 0x1E94A, 6),
+    (0x1E94B, 0x1E94B, 4),
+    (0x1E950, 0x1E959, 9),
+    (0x1E95E, 0x1E95F, 24),
+    (0x1EC71, 0x1ECAB, 11),
+    (0x1ECAC, 0x1ECAC, 28),
+    (0x1ECAD, 0x1ECAF, 11),
+    (0x1ECB0, 0x1ECB0, 26),
+    (0x1ECB1, 0x1ECB4, 11),
+    (0x1ED01, 0x1ED2D, 11),
+    (0x1ED2E, 0x1ED2E, 28),
+    (0x1ED2F, 0x1ED3D, 11),
+    (0x1EE00, 0x1EE03, 5),
+    (0x1EE05, 0x1EE1F, 5),
+    (0x1EE21, 0x1EE22, 5),
+    (0x1EE24, 0x1EE24, 5),
+    (0x1EE27, 0x1EE27, 5),
+    (0x1EE29, 0x1EE32, 5),
+    (0x1EE34, 0x1EE37, 5),
+    (0x1EE39, 0x1EE39, 5),
+    (0x1EE3B, 0x1EE3B, 5),
+    (0x1EE42, 0x1EE42, 5),
+    (0x1EE47, 0x1EE47, 5),
+    (0x1EE49, 0x1EE49, 5),
+    (0x1EE4B, 0x1EE4B, 5),
+    (0x1EE4D, 0x1EE4F, 5),
+    (0x1EE51, 0x1EE52, 5),
+    (0x1EE54, 0x1EE54, 5),
+    (0x1EE57, 0x1EE57, 5),
+    (0x1EE59, 0x1EE59, 5),
+    (0x1EE5B, 0x1EE5B, 5),
+    (0x1EE5D, 0x1EE5D, 5),
+    (0x1EE5F, 0x1EE5F, 5),
+    (0x1EE61, 0x1EE62, 5),
+    (0x1EE64, 0x1EE64, 5),
+    (0x1EE67, 0x1EE6A, 5),
+    (0x1EE6C, 0x1EE72, 5),
+    (0x1EE74, 0x1EE77, 5),
+    (0x1EE79, 0x1EE7C, 5),
+    (0x1EE7E, 0x1EE7E, 5),
+    (0x1EE80, 0x1EE89, 5),
+    (0x1EE8B, 0x1EE9B, 5),
+    (0x1EEA1, 0x1EEA3, 5),
+    (0x1EEA5, 0x1EEA9, 5),
+    (0x1EEAB, 0x1EEBB, 5),
+    (0x1EEF0, 0x1EEF1, 25),
+    (0x1F000, 0x1F02B, 28),
+    (0x1F030, 0x1F093, 28),
+    (0x1F0A0, 0x1F0AE, 28),
+    (0x1F0B1, 0x1F0BF, 28),
+    (0x1F0C1, 0x1F0CF, 28),
+    (0x1F0D1, 0x1F0F5, 28),
+    (0x1F100, 0x1F10C, 11),
+    (0x1F10D, 0x1F1AD, 28),
+    (0x1F1E6, 0x1F202, 28),
+    (0x1F210, 0x1F23B, 28),
+    (0x1F240, 0x1F248, 28),
+    (0x1F250, 0x1F251, 28),
+    (0x1F260, 0x1F265, 28),
+    (0x1F300, 0x1F3FA, 28),
+    (0x1F3FB, 0x1F3FF, 27),
+    (0x1F400, 0x1F6D7, 28),
+    (0x1F6E0, 0x1F6EC, 28),
+    (0x1F6F0, 0x1F6FC, 28),
+    (0x1F700, 0x1F773, 28),
+    (0x1F780, 0x1F7D8, 28),
+    (0x1F7E0, 0x1F7EB, 28),
+    (0x1F800, 0x1F80B, 28),
+    (0x1F810, 0x1F847, 28),
+    (0x1F850, 0x1F859, 28),
+    (0x1F860, 0x1F887, 28),
+    (0x1F890, 0x1F8AD, 28),
+    (0x1F8B0, 0x1F8B1, 28),
+    (0x1F900, 0x1F978, 28),
+    (0x1F97A, 0x1F9CB, 28),
+    (0x1F9CD, 0x1FA53, 28),
+    (0x1FA60, 0x1FA6D, 28),
+    (0x1FA70, 0x1FA74, 28),
+    (0x1FA78, 0x1FA7A, 28),
+    (0x1FA80, 0x1FA86, 28),
+    (0x1FA90, 0x1FAA8, 28),
+    (0x1FAB0, 0x1FAB6, 28),
+    (0x1FAC0, 0x1FAC2, 28),
+    (0x1FAD0, 0x1FAD6, 28),
+    (0x1FB00, 0x1FB92, 28),
+    (0x1FB94, 0x1FBCA, 28),
+    (0x1FBF0, 0x1FBF9, 9),
+    (0x20000, 0x2A6DD, 5),
+    (0x2A700, 0x2B734, 5),
+    (0x2B740, 0x2B81D, 5),
+    (0x2B820, 0x2CEA1, 5),
+    (0x2CEB0, 0x2EBE0, 5),
+    (0x2F800, 0x2FA1D, 5),
+    (0x30000, 0x3134A, 5),
+    (0xE0001, 0xE0001, 16),
+    (0xE0020, 0xE007F, 16),
+    (0xE0100, 0xE01EF, 6),
+    (0xF0000, 0xFFFFD, 18),
+    (0x100000, 0x10FFFD, 18),
+];
+
+pub(super) static NUMERIC_VALUES: [(u32, u32, i32, i32); 585] = [
+    (0x0030, 0x0039, 0, 1),
+    (0x0041, 0x005A, 10, 1),
+    (0x0061, 0x007A, 10, 1),
+    (0x00B2, 0x00B3, 2, 1),
+    (0x00B9, 0x00B9, 1, 0),
+    (0x00BC, 0x00BE, -2, 0),
+    (0x0660, 0x0669, 0, 1),
+    (0x06F0, 0x06F9, 0, 1),
+    (0x07C0, 0x07C9, 0, 1),
+    (0x0966, 0x096F, 0, 1),
+    (0x09E6, 0x09EF, 0, 1),
+    (0x09F4, 0x09F8, -2, 0),
+    (0x09F9, 0x09F9, 16, 0),
+    (0x0A66, 0x0A6F, 0, 1),
+    (0x0AE6, 0x0AEF, 0, 1),
+    (0x0B66, 0x0B6F, 0, 1),
+    (0x0B72, 0x0B77, -2, 0),
+    (0x0BE6, 0x0BF0, 0, 1),
+    (0x0BF1, 0x0BF1, 100, 0),
+    (0x0BF2, 0x0BF2, 1000, 0),
+    (0x0C66, 0x0C6F, 0, 1),
+    (0x0C78, 0x0C7B, 0, 1),
+    (0x0C7C, 0x0C7E, 1, 1),
+    (0x0CE6, 0x0CEF, 0, 1),
+    (0x0D58, 0x0D5E, -2, 0),
+    (0x0D66, 0x0D70, 0, 1),
+    (0x0D71, 0x0D71, 100, 0),
+    (0x0D72, 0x0D72, 1000, 0),
+    (0x0D73, 0x0D78, -2, 0),
+    (0x0DE6, 0x0DEF, 0, 1),
+    (0x0E50, 0x0E59, 0, 1),
+    (0x0ED0, 0x0ED9, 0, 1),
+    (0x0F20, 0x0F29, 0, 1),
+    (0x0F2A, 0x0F33, -2, 0),
+    (0x1040, 0x1049, 0, 1),
+    (0x1090, 0x1099, 0, 1),
+    (0x1369, 0x1372, 1, 1),
+    (0x1373, 0x1373, 20, 0),
+    (0x1374, 0x1374, 30, 0),
+    (0x1375, 0x1375, 40, 0),
+    (0x1376, 0x1376, 50, 0),
+    (0x1377, 0x1377, 60, 0),
+    (0x1378, 0x1378, 70, 0),
+    (0x1379, 0x1379, 80, 0),
+    (0x137A, 0x137A, 90, 0),
+    (0x137B, 0x137B, 100, 0),
+    (0x137C, 0x137C, 10000, 0),
+    (0x16EE, 0x16F0, 17, 1),
+    (0x17E0, 0x17E9, 0, 1),
+    (0x17F0, 0x17F9, 0, 1),
+    (0x1810, 0x1819, 0, 1),
+    (0x1946, 0x194F, 0, 1),
+    (0x19D0, 0x19D9, 0, 1),
+    (0x19DA, 0x19DA, 1, 0),
+    (0x1A80, 0x1A89, 0, 1),
+    (0x1A90, 0x1A99, 0, 1),
+    (0x1B50, 0x1B59, 0, 1),
+    (0x1BB0, 0x1BB9, 0, 1),
+    (0x1C40, 0x1C49, 0, 1),
+    (0x1C50, 0x1C59, 0, 1),
+    (0x2070, 0x2070, 0, 0),
+    (0x2074, 0x2079, 4, 1),
+    (0x2080, 0x2089, 0, 1),
+    (0x2150, 0x215E, -2, 0),
+    (0x215F, 0x2160, 1, 0),
+    (0x2161, 0x216B, 2, 1),
+    (0x216C, 0x216C, 50, 0),
+    (0x216D, 0x216D, 100, 0),
+    (0x216E, 0x216E, 500, 0),
+    (0x216F, 0x216F, 1000, 0),
+    (0x2170, 0x217B, 1, 1),
+    (0x217C, 0x217C, 50, 0),
+    (0x217D, 0x217D, 100, 0),
+    (0x217E, 0x217E, 500, 0),
+    (0x217F, 0x2180, 1000, 0),
+    (0x2181, 0x2181, 5000, 0),
+    (0x2182, 0x2182, 10000, 0),
+    (0x2185, 0x2185, 6, 0),
+    (0x2186, 0x2186, 50, 0),
+    (0x2187, 0x2187, 50000, 0),
+    (0x2188, 0x2188, 100000, 0),
+    (0x2189, 0x2189, 0, 0),
+    (0x2460, 0x2473, 1, 1),
+    (0x2474, 0x2487, 1, 1),
+    (0x2488, 0x249B, 1, 1),
+    (0x24EA, 0x24EA, 0, 0),
+    (0x24EB, 0x24F4, 11, 1),
+    (0x24F5, 0x24FE, 1, 1),
+    (0x24FF, 0x24FF, 0, 0),
+    (0x2776, 0x277F, 1, 1),
+    (0x2780, 0x2789, 1, 1),
+    (0x278A, 0x2793, 1, 1),
+    (0x2CFD, 0x2CFD, -2, 0),
+    (0x3007, 0x3007, 0, 0),
+    (0x3021, 0x3029, 1, 1),
+    (0x3038, 0x3038, 10, 0),
+    (0x3039, 0x3039, 20, 0),
+    (0x303A, 0x303A, 30, 0),
+    (0x3192, 0x3195, 1, 1),
+    (0x3220, 0x3229, 1, 1),
+    (0x3248, 0x3248, 10, 0),
+    (0x3249, 0x3249, 20, 0),
+    (0x324A, 0x324A, 30, 0),
+    (0x324B, 0x324B, 40, 0),
+    (0x324C, 0x324C, 50, 0),
+    (0x324D, 0x324D, 60, 0),
+    (0x324E, 0x324E, 70, 0),
+    (0x324F, 0x324F, 80, 0),
+    (0x3251, 0x325F, 21, 1),
+    (0x3280, 0x3289, 1, 1),
+    (0x32B1, 0x32BF, 36, 1),
+    (0xA620, 0xA629, 0, 1),
+    (0xA6E6, 0xA6EE, 1, 1),
+    (0xA6EF, 0xA6EF, 0, 0),
+    (0xA830, 0xA835, -2, 0),
+    (0xA8D0, 0xA8D9, 0, 1),
+    (0xA900, 0xA909, 0, 1),
+    (0xA9D0, 0xA9D9, 0, 1),
+    (0xA9F0, 0xA9F9, 0, 1),
+    (0xAA50, 0xAA59, 0, 1),
+    (0xABF0, 0xABF9, 0, 1),
+    (0xF96B, 0xF96B, 3, 0),
+    (0xF973, 0xF973, 10, 0),
+    (0xF978, 0xF978, 2, 0),
+    (0xF9B2, 0xF9B2, 0, 0),
+    (0xF9D1, 0xF9D1, 6, 0),
+    (0xF9D3, 0xF9D3, 6, 0),
+    (0xF9FD, 0xF9FD, 10, 0),
+    (0xFF10, 0xFF19, 0, 1),
+    (0xFF21, 0xFF3A, 10, 1),
+    (0xFF41, 0xFF5A, 10, 1),
+    (0x10107, 0x10110, 1, 1),
+    (0x10111, 0x10111, 20, 0),
+    (0x10112, 0x10112, 30, 0),
+    (0x10113, 0x10113, 40, 0),
+    (0x10114, 0x10114, 50, 0),
+    (0x10115, 0x10115, 60, 0),
+    (0x10116, 0x10116, 70, 0),
+    (0x10117, 0x10117, 80, 0),
+    (0x10118, 0x10118, 90, 0),
+    (0x10119, 0x10119, 100, 0),
+    (0x1011A, 0x1011A, 200, 0),
+    (0x1011B, 0x1011B, 300, 0),
+    (0x1011C, 0x1011C, 400, 0),
+    (0x1011D, 0x1011D, 500, 0),
+    (0x1011E, 0x1011E, 600, 0),
+    (0x1011F, 0x1011F, 700, 0),
+    (0x10120, 0x10120, 800, 0),
+    (0x10121, 0x10121, 900, 0),
+    (0x10122, 0x10122, 1000, 0),
+    (0x10123, 0x10123, 2000, 0),
+    (0x10124, 0x10124, 3000, 0),
+    (0x10125, 0x10125, 4000, 0),
+    (0x10126, 0x10126, 5000, 0),
+    (0x10127, 0x10127, 6000, 0),
+    (0x10128, 0x10128, 7000, 0),
+    (0x10129, 0x10129, 8000, 0),
+    (0x1012A, 0x1012A, 9000, 0),
+    (0x1012B, 0x1012B, 10000, 0),
+    (0x1012C, 0x1012C, 20000, 0),
+    (0x1012D, 0x1012D, 30000, 0),
+    (0x1012E, 0x1012E, 40000, 0),
+    (0x1012F, 0x1012F, 50000, 0),
+    (0x10130, 0x10130, 60000, 0),
+    (0x10131, 0x10131, 70000, 0),
+    (0x10132, 0x10132, 80000, 0),
+    (0x10133, 0x10133, 90000, 0),
+    (0x10140, 0x10141, -2, 0),
+    (0x10142, 0x10142, 1, 0),
+    (0x10143, 0x10143, 5, 0),
+    (0x10144, 0x10144, 50, 0),
+    (0x10145, 0x10145, 500, 0),
+    (0x10146, 0x10146, 5000, 0),
+    (0x10147, 0x10147, 50000, 0),
+    (0x10148, 0x10148, 5, 0),
+    (0x10149, 0x10149, 10, 0),
+    (0x1014A, 0x1014A, 50, 0),
+    (0x1014B, 0x1014B, 100, 0),
+    (0x1014C, 0x1014C, 500, 0),
+    (0x1014D, 0x1014D, 1000, 0),
+    (0x1014E, 0x1014E, 5000, 0),
+    (0x1014F, 0x1014F, 5, 0),
+    (0x10150, 0x10150, 10, 0),
+    (0x10151, 0x10151, 50, 0),
+    (0x10152, 0x10152, 100, 0),
+    (0x10153, 0x10153, 500, 0),
+    (0x10154, 0x10154, 1000, 0),
+    (0x10155, 0x10155, 10000, 0),
+    (0x10156, 0x10156, 50000, 0),
+    (0x10157, 0x10157, 10, 0),
+    (0x10158, 0x1015A, 1, 0),
+    (0x1015B, 0x1015E, 2, 0),
+    (0x1015F, 0x1015F, 5, 0),
+    (0x10160, 0x10164, 10, 0),
+    (0x10165, 0x10165, 30, 0),
+    (0x10166, 0x10169, 50, 0),
+    (0x1016A, 0x1016A, 100, 0),
+    (0x1016B, 0x1016B, 300, 0),
+    (0x1016C, 0x10170, 500, 0),
+    (0x10171, 0x10171, 1000, 0),
+    (0x10172, 0x10172, 5000, 0),
+    (0x10173, 0x10173, 5, 0),
+    (0x10174, 0x10174, 50, 0),
+    (0x10175, 0x10178, -2, 0),
+    (0x1018A, 0x1018A, 0, 0),
+    (0x1018B, 0x1018B, -2, 0),
+    (0x102E1, 0x102EA, 1, 1),
+    (0x102EB, 0x102EB, 20, 0),
+    (0x102EC, 0x102EC, 30, 0),
+    (0x102ED, 0x102ED, 40, 0),
+    (0x102EE, 0x102EE, 50, 0),
+    (0x102EF, 0x102EF, 60, 0),
+    (0x102F0, 0x102F0, 70, 0),
+    (0x102F1, 0x102F1, 80, 0),
+    (0x102F2, 0x102F2, 90, 0),
+    (0x102F3, 0x102F3, 100, 0),
+    (0x102F4, 0x102F4, 200, 0),
+    (0x102F5, 0x102F5, 300, 0),
+    (0x102F6, 0x102F6, 400, 0),
+    (0x102F7, 0x102F7, 500, 0),
+    (0x102F8, 0x102F8, 600, 0),
+    (0x102F9, 0x102F9, 700, 0),
+    (0x102FA, 0x102FA, 800, 0),
+    (0x102FB, 0x102FB, 900, 0),
+    (0x10320, 0x10320, 1, 0),
+    (0x10321, 0x10321, 5, 0),
+    (0x10322, 0x10322, 10, 0),
+    (0x10323, 0x10323, 50, 0),
+    (0x10341, 0x10341, 90, 0),
+    (0x1034A, 0x1034A, 900, 0),
+    (0x103D1, 0x103D2, 1, 1),
+    (0x103D3, 0x103D3, 10, 0),
+    (0x103D4, 0x103D4, 20, 0),
+    (0x103D5, 0x103D5, 100, 0),
+    (0x104A0, 0x104A9, 0, 1),
+    (0x10858, 0x1085A, 1, 1),
+    (0x1085B, 0x1085B, 10, 0),
+    (0x1085C, 0x1085C, 20, 0),
+    (0x1085D, 0x1085D, 100, 0),
+    (0x1085E, 0x1085E, 1000, 0),
+    (0x1085F, 0x1085F, 10000, 0),
+    (0x10879, 0x1087D, 1, 1),
+    (0x1087E, 0x1087E, 10, 0),
+    (0x1087F, 0x1087F, 20, 0),
+    (0x108A7, 0x108AA, 1, 1),
+    (0x108AB, 0x108AC, 4, 1),
+    (0x108AD, 0x108AD, 10, 0),
+    (0x108AE, 0x108AE, 20, 0),
+    (0x108AF, 0x108AF, 100, 0),
+    (0x108FB, 0x108FB, 1, 0),
+    (0x108FC, 0x108FC, 5, 0),
+    (0x108FD, 0x108FD, 10, 0),
+    (0x108FE, 0x108FE, 20, 0),
+    (0x108FF, 0x108FF, 100, 0),
+    (0x10916, 0x10916, 1, 0),
+    (0x10917, 0x10917, 10, 0),
+    (0x10918, 0x10918, 20, 0),
+    (0x10919, 0x10919, 100, 0),
+    (0x1091A, 0x1091B, 2, 1),
+    (0x109BC, 0x109BD, -2, 0),
+    (0x109C0, 0x109C9, 1, 1),
+    (0x109CA, 0x109CA, 20, 0),
+    (0x109CB, 0x109CB, 30, 0),
+    (0x109CC, 0x109CC, 40, 0),
+    (0x109CD, 0x109CD, 50, 0),
+    (0x109CE, 0x109CE, 60, 0),
+    (0x109CF, 0x109CF, 70, 0),
+    (0x109D2, 0x109D2, 100, 0),
+    (0x109D3, 0x109D3, 200, 0),
+    (0x109D4, 0x109D4, 300, 0),
+    (0x109D5, 0x109D5, 400, 0),
+    (0x109D6, 0x109D6, 500, 0),
+    (0x109D7, 0x109D7, 600, 0),
+    (0x109D8, 0x109D8, 700, 0),
+    (0x109D9, 0x109D9, 800, 0),
+    (0x109DA, 0x109DA, 900, 0),
+    (0x109DB, 0x109DB, 1000, 0),
+    (0x109DC, 0x109DC, 2000, 0),
+    (0x109DD, 0x109DD, 3000, 0),
+    (0x109DE, 0x109DE, 4000, 0),
+    (0x109DF, 0x109DF, 5000, 0),
+    (0x109E0, 0x109E0, 6000, 0),
+    (0x109E1, 0x109E1, 7000, 0),
+    (0x109E2, 0x109E2, 8000, 0),
+    (0x109E3, 0x109E3, 9000, 0),
+    (0x109E4, 0x109E4, 10000, 0),
+    (0x109E5, 0x109E5, 20000, 0),
+    (0x109E6, 0x109E6, 30000, 0),
+    (0x109E7, 0x109E7, 40000, 0),
+    (0x109E8, 0x109E8, 50000, 0),
+    (0x109E9, 0x109E9, 60000, 0),
+    (0x109EA, 0x109EA, 70000, 0),
+    (0x109EB, 0x109EB, 80000, 0),
+    (0x109EC, 0x109EC, 90000, 0),
+    (0x109ED, 0x109ED, 100000, 0),
+    (0x109EE, 0x109EE, 200000, 0),
+    (0x109EF, 0x109EF, 300000, 0),
+    (0x109F0, 0x109F0, 400000, 0),
+    (0x109F1, 0x109F1, 500000, 0),
+    (0x109F2, 0x109F2, 600000, 0),
+    (0x109F3, 0x109F3, 700000, 0),
+    (0x109F4, 0x109F4, 800000, 0),
+    (0x109F5, 0x109F5, 900000, 0),
+    (0x109F6, 0x109FF, -2, 0),
+    (0x10A40, 0x10A43, 1, 1),
+    (0x10A44, 0x10A44, 10, 0),
+    (0x10A45, 0x10A45, 20, 0),
+    (0x10A46, 0x10A46, 100, 0),
+    (0x10A47, 0x10A47, 1000, 0),
+    (0x10A48, 0x10A48, -2, 0),
+    (0x10A7D, 0x10A7D, 1, 0),
+    (0x10A7E, 0x10A7E, 50, 0),
+    (0x10A9D, 0x10A9D, 1, 0),
+    (0x10A9E, 0x10A9E, 10, 0),
+    (0x10A9F, 0x10A9F, 20, 0),
+    (0x10AEB, 0x10AEB, 1, 0),
+    (0x10AEC, 0x10AEC, 5, 0),
+    (0x10AED, 0x10AED, 10, 0),
+    (0x10AEE, 0x10AEE, 20, 0),
+    (0x10AEF, 0x10AEF, 100, 0),
+    (0x10B58, 0x10B5B, 1, 1),
+    (0x10B5C, 0x10B5C, 10, 0),
+    (0x10B5D, 0x10B5D, 20, 0),
+    (0x10B5E, 0x10B5E, 100, 0),
+    (0x10B5F, 0x10B5F, 1000, 0),
+    (0x10B78, 0x10B7B, 1, 1),
+    (0x10B7C, 0x10B7C, 10, 0),
+    (0x10B7D, 0x10B7D, 20, 0),
+    (0x10B7E, 0x10B7E, 100, 0),
+    (0x10B7F, 0x10B7F, 1000, 0),
+    (0x10BA9, 0x10BAC, 1, 1),
+    (0x10BAD, 0x10BAD, 10, 0),
+    (0x10BAE, 0x10BAE, 20, 0),
+    (0x10BAF, 0x10BAF, 100, 0),
+    (0x10CFA, 0x10CFA, 1, 0),
+    (0x10CFB, 0x10CFB, 5, 0),
+    (0x10CFC, 0x10CFC, 10, 0),
+    (0x10CFD, 0x10CFD, 50, 0),
+    (0x10CFE, 0x10CFE, 100, 0),
+    (0x10CFF, 0x10CFF, 1000, 0),
+    (0x10D30, 0x10D39, 0, 1),
+    (0x10E60, 0x10E69, 1, 1),
+    (0x10E6A, 0x10E6A, 20, 0),
+    (0x10E6B, 0x10E6B, 30, 0),
+    (0x10E6C, 0x10E6C, 40, 0),
+    (0x10E6D, 0x10E6D, 50, 0),
+    (0x10E6E, 0x10E6E, 60, 0),
+    (0x10E6F, 0x10E6F, 70, 0),
+    (0x10E70, 0x10E70, 80, 0),
+    (0x10E71, 0x10E71, 90, 0),
+    (0x10E72, 0x10E72, 100, 0),
+    (0x10E73, 0x10E73, 200, 0),
+    (0x10E74, 0x10E74, 300, 0),
+    (0x10E75, 0x10E75, 400, 0),
+    (0x10E76, 0x10E76, 500, 0),
+    (0x10E77, 0x10E77, 600, 0),
+    (0x10E78, 0x10E78, 700, 0),
+    (0x10E79, 0x10E79, 800, 0),
+    (0x10E7A, 0x10E7A, 900, 0),
+    (0x10E7B, 0x10E7E, -2, 0),
+    (0x10F1D, 0x10F21, 1, 1),
+    (0x10F22, 0x10F22, 10, 0),
+    (0x10F23, 0x10F23, 20, 0),
+    (0x10F24, 0x10F24, 30, 0),
+    (0x10F25, 0x10F25, 100, 0),
+    (0x10F26, 0x10F26, -2, 0),
+    (0x10F51, 0x10F51, 1, 0),
+    (0x10F52, 0x10F52, 10, 0),
+    (0x10F53, 0x10F53, 20, 0),
+    (0x10F54, 0x10F54, 100, 0),
+    (0x10FC5, 0x10FC8, 1, 1),
+    (0x10FC9, 0x10FC9, 10, 0),
+    (0x10FCA, 0x10FCA, 20, 0),
+    (0x10FCB, 0x10FCB, 100, 0),
+    (0x11052, 0x1105B, 1, 1),
+    (0x1105C, 0x1105C, 20, 0),
+    (0x1105D, 0x1105D, 30, 0),
+    (0x1105E, 0x1105E, 40, 0),
+    (0x1105F, 0x1105F, 50, 0),
+    (0x11060, 0x11060, 60, 0),
+    (0x11061, 0x11061, 70, 0),
+    (0x11062, 0x11062, 80, 0),
+    (0x11063, 0x11063, 90, 0),
+    (0x11064, 0x11064, 100, 0),
+    (0x11065, 0x11065, 1000, 0),
+    (0x11066, 0x1106F, 0, 1),
+    (0x110F0, 0x110F9, 0, 1),
+    (0x11136, 0x1113F, 0, 1),
+    (0x111D0, 0x111D9, 0, 1),
+    (0x111E1, 0x111EA, 1, 1),
+    (0x111EB, 0x111EB, 20, 0),
+    (0x111EC, 0x111EC, 30, 0),
+    (0x111ED, 0x111ED, 40, 0),
+    (0x111EE, 0x111EE, 50, 0),
+    (0x111EF, 0x111EF, 60, 0),
+    (0x111F0, 0x111F0, 70, 0),
+    (0x111F1, 0x111F1, 80, 0),
+    (0x111F2, 0x111F2, 90, 0),
+    (0x111F3, 0x111F3, 100, 0),
+    (0x111F4, 0x111F4, 1000, 0),
+    (0x112F0, 0x112F9, 0, 1),
+    (0x11450, 0x11459, 0, 1),
+    (0x114D0, 0x114D9, 0, 1),
+    (0x11650, 0x11659, 0, 1),
+    (0x116C0, 0x116C9, 0, 1),
+    (0x11730, 0x1173A, 0, 1),
+    (0x1173B, 0x1173B, 20, 0),
+    (0x118E0, 0x118EA, 0, 1),
+    (0x118EB, 0x118EB, 20, 0),
+    (0x118EC, 0x118EC, 30, 0),
+    (0x118ED, 0x118ED, 40, 0),
+    (0x118EE, 0x118EE, 50, 0),
+    (0x118EF, 0x118EF, 60, 0),
+    (0x118F0, 0x118F0, 70, 0),
+    (0x118F1, 0x118F1, 80, 0),
+    (0x118F2, 0x118F2, 90, 0),
+    (0x11950, 0x11959, 0, 1),
+    (0x11C50, 0x11C59, 0, 1),
+    (0x11C5A, 0x11C63, 1, 1),
+    (0x11C64, 0x11C64, 20, 0),
+    (0x11C65, 0x11C65, 30, 0),
+    (0x11C66, 0x11C66, 40, 0),
+    (0x11C67, 0x11C67, 50, 0),
+    (0x11C68, 0x11C68, 60, 0),
+    (0x11C69, 0x11C69, 70, 0),
+    (0x11C6A, 0x11C6A, 80, 0),
+    (0x11C6B, 0x11C6B, 90, 0),
+    (0x11C6C, 0x11C6C, 100, 0),
+    (0x11D50, 0x11D59, 0, 1),
+    (0x11DA0, 0x11DA9, 0, 1),
+    (0x11FC0, 0x11FD4, -2, 0),
+    (0x12400, 0x12407, 2, 1),
+    (0x12408, 0x1240E, 3, 1),
+    (0x1240F, 0x12414, 4, 1),
+    (0x12415, 0x1241D, 1, 1),
+    (0x1241E, 0x12422, 1, 1),
+    (0x12423, 0x12424, 2, 1),
+    (0x12425, 0x1242B, 3, 1),
+    (0x1242C, 0x1242E, 1, 1),
+    (0x1242F, 0x12431, 3, 1),
+    (0x12432, 0x12432, 216000, 0),
+    (0x12433, 0x12433, 432000, 0),
+    (0x12434, 0x12436, 1, 1),
+    (0x12437, 0x12439, 3, 1),
+    (0x1243A, 0x1243B, 3, 0),
+    (0x1243C, 0x1243F, 4, 0),
+    (0x12440, 0x12441, 6, 1),
+    (0x12442, 0x12443, 7, 0),
+    (0x12444, 0x12445, 8, 0),
+    (0x12446, 0x12449, 9, 0),
+    (0x1244A, 0x1244E, 2, 1),
+    (0x1244F, 0x12452, 1, 1),
+    (0x12453, 0x12454, 4, 1),
+    (0x12455, 0x12455, 5, 0),
+    (0x12456, 0x12457, 2, 1),
+    (0x12458, 0x12459, 1, 1),
+    (0x1245A, 0x12466, -2, 0),
+    (0x12467, 0x12467, 40, 0),
+    (0x12468, 0x12468, 50, 0),
+    (0x12469, 0x1246E, 4, 1),
+    (0x16A60, 0x16A69, 0, 1),
+    (0x16B50, 0x16B59, 0, 1),
+    (0x16B5B, 0x16B5B, 10, 0),
+    (0x16B5C, 0x16B5C, 100, 0),
+    (0x16B5D, 0x16B5D, 10000, 0),
+    (0x16B5E, 0x16B5E, 1000000, 0),
+    (0x16B5F, 0x16B5F, 100000000, 0),
+    (0x16B60, 0x16B61, -2, 0),
+    (0x16E80, 0x16E93, 0, 1),
+    (0x16E94, 0x16E96, 1, 1),
+    (0x1D2E0, 0x1D2F3, 0, 1),
+    (0x1D360, 0x1D369, 1, 1),
+    (0x1D36A, 0x1D36A, 20, 0),
+    (0x1D36B, 0x1D36B, 30, 0),
+    (0x1D36C, 0x1D36C, 40, 0),
+    (0x1D36D, 0x1D36D, 50, 0),
+    (0x1D36E, 0x1D36E, 60, 0),
+    (0x1D36F, 0x1D36F, 70, 0),
+    (0x1D370, 0x1D370, 80, 0),
+    (0x1D371, 0x1D371, 90, 0),
+    (0x1D372, 0x1D376, 1, 1),
+    (0x1D377, 0x1D377, 1, 0),
+    (0x1D378, 0x1D378, 5, 0),
+    (0x1D7CE, 0x1D7D7, 0, 1),
+    (0x1D7D8, 0x1D7E1, 0, 1),
+    (0x1D7E2, 0x1D7EB, 0, 1),
+    (0x1D7EC, 0x1D7F5, 0, 1),
+    (0x1D7F6, 0x1D7FF, 0, 1),
+    (0x1E140, 0x1E149, 0, 1),
+    (0x1E2F0, 0x1E2F9, 0, 1),
+    (0x1E8C7, 0x1E8CF, 1, 1),
+    (0x1E950, 0x1E959, 0, 1),
+    (0x1EC71, 0x1EC7A, 1, 1),
+    (0x1EC7B, 0x1EC7B, 20, 0),
+    (0x1EC7C, 0x1EC7C, 30, 0),
+    (0x1EC7D, 0x1EC7D, 40, 0),
+    (0x1EC7E, 0x1EC7E, 50, 0),
+    (0x1EC7F, 0x1EC7F, 60, 0),
+    (0x1EC80, 0x1EC80, 70, 0),
+    (0x1EC81, 0x1EC81, 80, 0),
+    (0x1EC82, 0x1EC82, 90, 0),
+    (0x1EC83, 0x1EC83, 100, 0),
+    (0x1EC84, 0x1EC84, 200, 0),
+    (0x1EC85, 0x1EC85, 300, 0),
+    (0x1EC86, 0x1EC86, 400, 0),
+    (0x1EC87, 0x1EC87, 500, 0),
+    (0x1EC88, 0x1EC88, 600, 0),
+    (0x1EC89, 0x1EC89, 700, 0),
+    (0x1EC8A, 0x1EC8A, 800, 0),
+    (0x1EC8B, 0x1EC8B, 900, 0),
+    (0x1EC8C, 0x1EC8C, 1000, 0),
+    (0x1EC8D, 0x1EC8D, 2000, 0),
+    (0x1EC8E, 0x1EC8E, 3000, 0),
+    (0x1EC8F, 0x1EC8F, 4000, 0),
+    (0x1EC90, 0x1EC90, 5000, 0),
+    (0x1EC91, 0x1EC91, 6000, 0),
+    (0x1EC92, 0x1EC92, 7000, 0),
+    (0x1EC93, 0x1EC93, 8000, 0),
+    (0x1EC94, 0x1EC94, 9000, 0),
+    (0x1EC95, 0x1EC95, 10000, 0),
+    (0x1EC96, 0x1EC96, 20000, 0),
+    (0x1EC97, 0x1EC97, 30000, 0),
+    (0x1EC98, 0x1EC98, 40000, 0),
+    (0x1EC99, 0x1EC99, 50000, 0),
+    (0x1EC9A, 0x1EC9A, 60000, 0),
+    (0x1EC9B, 0x1EC9B, 70000, 0),
+    (0x1EC9C, 0x1EC9C, 80000, 0),
+    (0x1EC9D, 0x1EC9D, 90000, 0),
+    (0x1EC9E, 0x1EC9E, 100000, 0),
+    (0x1EC9F, 0x1EC9F, 200000, 0),
+    (0x1ECA0, 0x1ECA0, 100000, 0),
+    (0x1ECA1, 0x1ECA1, 10000000, 0),
+    (0x1ECA2, 0x1ECA2, 20000000, 0),
+    (0x1ECA3, 0x1ECAB, 1, 1),
+    (0x1ECAD, 0x1ECAF, -2, 0),
+    (0x1ECB1, 0x1ECB2, 1, 1),
+    (0x1ECB3, 0x1ECB3, 10000, 0),
+    (0x1ECB4, 0x1ECB4, 100000, 0),
+    (0x1ED01, 0x1ED0A, 1, 1),
+    (0x1ED0B, 0x1ED0B, 20, 0),
+    (0x1ED0C, 0x1ED0C, 30, 0),
+    (0x1ED0D, 0x1ED0D, 40, 0),
+    (0x1ED0E, 0x1ED0E, 50, 0),
+    (0x1ED0F, 0x1ED0F, 60, 0),
+    (0x1ED10, 0x1ED10, 70, 0),
+    (0x1ED11, 0x1ED11, 80, 0),
+    (0x1ED12, 0x1ED12, 90, 0),
+    (0x1ED13, 0x1ED13, 100, 0),
+    (0x1ED14, 0x1ED14, 200, 0),
+    (0x1ED15, 0x1ED15, 300, 0),
+    (0x1ED16, 0x1ED16, 400, 0),
+    (0x1ED17, 0x1ED17, 500, 0),
+    (0x1ED18, 0x1ED18, 600, 0),
+    (0x1ED19, 0x1ED19, 700, 0),
+    (0x1ED1A, 0x1ED1A, 800, 0),
+    (0x1ED1B, 0x1ED1B, 900, 0),
+    (0x1ED1C, 0x1ED1C, 1000, 0),
+    (0x1ED1D, 0x1ED1D, 2000, 0),
+    (0x1ED1E, 0x1ED1E, 3000, 0),
+    (0x1ED1F, 0x1ED1F, 4000, 0),
+    (0x1ED20, 0x1ED20, 5000, 0),
+    (0x1ED21, 0x1ED21, 6000, 0),
+    (0x1ED22, 0x1ED22, 7000, 0),
+    (0x1ED23, 0x1ED23, 8000, 0),
+    (0x1ED24, 0x1ED24, 9000, 0),
+    (0x1ED25, 0x1ED25, 10000, 0),
+    (0x1ED26, 0x1ED26, 20000, 0),
+    (0x1ED27, 0x1ED27, 30000, 0),
+    (0x1ED28, 0x1ED28, 40000, 0),
+    (0x1ED29, 0x1ED29, 50000, 0),
+    (0x1ED2A, 0x1ED2A, 60000, 0),
+    (0x1ED2B, 0x1ED2B, 70000, 0),
+    (0x1ED2C, 0x1ED2C, 80000, 0),
+    (0x1ED2D, 0x1ED2D, 90000, 0),
+    (0x1ED2F, 0x1ED37, 2, 1),
+    (0x1ED38, 0x1ED38, 400, 0),
+    (0x1ED39, 0x1ED39, 600, 0),
+    (0x1ED3A, 0x1ED3A, 2000, 0),
+    (0x1ED3B, 0x1ED3B, 10000, 0),
+    (0x1ED3C, 0x1ED3D, -2, 0),
+    (0x1F100, 0x1F101, 0, 0),
+    (0x1F102, 0x1F10A, 1, 1),
+    (0x1F10B, 0x1F10C, 0, 0),
+    (0x1FBF0, 0x1FBF9, 0, 1),
+    (0x2F890, 0x2F890, 9, 0),
+];
+
+pub(super) static OTHER_UPPERCASE: [(u32, u32); 5] = [
+    (0x2160, 0x216F),
+    (0x24B6, 0x24CF),
+    (0x1F130, 0x1F149),
+    (0x1F150, 0x1F169),
+    (0x1F170, 0x1F189),
+];
+
+pub(super) static OTHER_LOWERCASE: [(u32, u32); 20] = [
+    (0x00AA, 0x00AA),
+    (0x00BA, 0x00BA),
+    (0x02B0, 0x02B8),
+    (0x02C0, 0x02C1),
+    (0x02E0, 0x02E4),
+    (0x0345, 0x0345),
+    (0x037A, 0x037A),
+    (0x1D2C, 0x1D6A),
+    (0x1D78, 0x1D78),
+    (0x1D9B, 0x1DBF),
+    (0x2071, 0x2071),
+    (0x207F, 0x207F),
+    (0x2090, 0x209C),
+    (0x2170, 0x217F),
+    (0x24D0, 0x24E9),
+    (0x2C7C, 0x2C7D),
+    (0xA69C, 0xA69D),
+    (0xA770, 0xA770),
+    (0xA7F8, 0xA7F9),
+    (0xAB5C, 0xAB5F),
+];
+
+pub(super) static OTHER_ALPHABETIC: [(u32, u32); 230] = [
+    (0x0345, 0x0345),
+    (0x05B0, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0610, 0x061A),
+    (0x064B, 0x0657),
+    (0x0659, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x06E1, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06ED, 0x06ED),
+    (0x0711, 0x0711),
+    (0x0730, 0x073F),
+    (0x07A6, 0x07B0),
+    (0x0816, 0x0817),
+    (0x081B, 0x0823),
+    (0x0825, 0x0827),
+    (0x0829, 0x082C),
+    (0x08D4, 0x08DF),
+    (0x08E3, 0x08E9),
+    (0x08F0, 0x0903),
+    (0x093A, 0x093B),
+    (0x093E, 0x094C),
+    (0x094E, 0x094F),
+    (0x0955, 0x0957),
+    (0x0962, 0x0963),
+    (0x0981, 0x0983),
+    (0x09BE, 0x09C4),
+    (0x09C7, 0x09C8),
+    (0x09CB, 0x09CC),
+    (0x09D7, 0x09D7),
+    (0x09E2, 0x09E3),
+    (0x0A01, 0x0A03),
+    (0x0A3E, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4C),
+    (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71),
+    (0x0A75, 0x0A75),
+    (0x0A81, 0x0A83),
+    (0x0ABE, 0x0AC5),
+    (0x0AC7, 0x0AC9),
+    (0x0ACB, 0x0ACC),
+    (0x0AE2, 0x0AE3),
+    (0x0AFA, 0x0AFC),
+    (0x0B01, 0x0B03),
+    (0x0B3E, 0x0B44),
+    (0x0B47, 0x0B48),
+    (0x0B4B, 0x0B4C),
+    (0x0B56, 0x0B57),
+    (0x0B62, 0x0B63),
+    (0x0B82, 0x0B82),
+    (0x0BBE, 0x0BC2),
+    (0x0BC6, 0x0BC8),
+    (0x0BCA, 0x0BCC),
+    (0x0BD7, 0x0BD7),
+    (0x0C00, 0x0C03),
+    (0x0C3E, 0x0C44),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4C),
+    (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63),
+    (0x0C81, 0x0C83),
+    (0x0CBE, 0x0CC4),
+    (0x0CC6, 0x0CC8),
+    (0x0CCA, 0x0CCC),
+    (0x0CD5, 0x0CD6),
+    (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D03),
+    (0x0D3E, 0x0D44),
+    (0x0D46, 0x0D48),
+    (0x0D4A, 0x0D4C),
+    (0x0D57, 0x0D57),
+    (0x0D62, 0x0D63),
+    (0x0D81, 0x0D83),
+    (0x0DCF, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0DD8, 0x0DDF),
+    (0x0DF2, 0x0DF3),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E4D, 0x0E4D),
+    (0x0EB1, 0x0EB1),
+    (0x0EB4, 0x0EB9),
+    (0x0EBB, 0x0EBC),
+    (0x0ECD, 0x0ECD),
+    (0x0F71, 0x0F81),
+    (0x0F8D, 0x0F97),
+    (0x0F99, 0x0FBC),
+    (0x102B, 0x1036),
+    (0x1038, 0x1038),
+    (0x103B, 0x103E),
+    (0x1056, 0x1059),
+    (0x105E, 0x1060),
+    (0x1062, 0x1064),
+    (0x1067, 0x106D),
+    (0x1071, 0x1074),
+    (0x1082, 0x108D),
+    (0x108F, 0x108F),
+    (0x109A, 0x109D),
+    (0x1712, 0x1713),
+    (0x1732, 0x1733),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B6, 0x17C8),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x192B),
+    (0x1930, 0x1938),
+    (0x1A17, 0x1A1B),
+    (0x1A55, 0x1A5E),
+    (0x1A61, 0x1A74),
+    (0x1ABF, 0x1AC0),
+    (0x1B00, 0x1B04),
+    (0x1B35, 0x1B43),
+    (0x1B80, 0x1B82),
+    (0x1BA1, 0x1BA9),
+    (0x1BAC, 0x1BAD),
+    (0x1BE7, 0x1BF1),
+    (0x1C24, 0x1C36),
+    (0x1DE7, 0x1DF4),
+    (0x24B6, 0x24E9),
+    (0x2DE0, 0x2DFF),
+    (0xA674, 0xA67B),
+    (0xA69E, 0xA69F),
+    (0xA802, 0xA802),
+    (0xA80B, 0xA80B),
+    (0xA823, 0xA827),
+    (0xA880, 0xA881),
+    (0xA8B4, 0xA8C3),
+    (0xA8C5, 0xA8C5),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92A),
+    (0xA947, 0xA952),
+    (0xA980, 0xA983),
+    (0xA9B4, 0xA9BF),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4D),
+    (0xAA7B, 0xAA7D),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABE),
+    (0xAAEB, 0xAAEF),
+    (0xAAF5, 0xAAF5),
+    (0xABE3, 0xABEA),
+    (0xFB1E, 0xFB1E),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC),
+    (0x11000, 0x11002),
+    (0x11038, 0x11045),
+    (0x11082, 0x11082),
+    (0x110B0, 0x110B8),
+    (0x11100, 0x11102),
+    (0x11127, 0x11132),
+    (0x11145, 0x11146),
+    (0x11180, 0x11182),
+    (0x111B3, 0x111BF),
+    (0x111CE, 0x111CF),
+    (0x1122C, 0x11234),
+    (0x11237, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x112DF, 0x112E8),
+    (0x11300, 0x11303),
+    (0x1133E, 0x11344),
+    (0x11347, 0x11348),
+    (0x1134B, 0x1134C),
+    (0x11357, 0x11357),
+    (0x11362, 0x11363),
+    (0x11435, 0x11441),
+    (0x11443, 0x11445),
+    (0x114B0, 0x114C1),
+    (0x115AF, 0x115B5),
+    (0x115B8, 0x115BE),
+    (0x115DC, 0x115DD),
+    (0x11630, 0x1163E),
+    (0x11640, 0x11640),
+    (0x116AB, 0x116B5),
+    (0x1171D, 0x1172A),
+    (0x1182C, 0x11838),
+    (0x11930, 0x11935),
+    (0x11937, 0x11938),
+    (0x1193B, 0x1193C),
+    (0x11940, 0x11940),
+    (0x11942, 0x11942),
+    (0x119D1, 0x119D7),
+    (0x119DA, 0x119DF),
+    (0x119E4, 0x119E4),
+    (0x11A01, 0x11A0A),
+    (0x11A35, 0x11A39),
+    (0x11A3B, 0x11A3E),
+    (0x11A51, 0x11A5B),
+    (0x11A8A, 0x11A97),
+    (0x11C2F, 0x11C36),
+    (0x11C38, 0x11C3E),
+    (0x11C92, 0x11CA7),
+    (0x11CA9, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D41),
+    (0x11D43, 0x11D43),
+    (0x11D47, 0x11D47),
+    (0x11D8A, 0x11D8E),
+    (0x11D90, 0x11D91),
+    (0x11D93, 0x11D96),
+    (0x11EF3, 0x11EF6),
+    (0x16F4F, 0x16F4F),
+    (0x16F51, 0x16F87),
+    (0x16F8F, 0x16F92),
+    (0x16FF0, 0x16FF1),
+    (0x1BC9E, 0x1BC9E),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E947, 0x1E947),
+    (0x1F130, 0x1F149),
+    (0x1F150, 0x1F169),
+    (0x1F170, 0x1F189),
+];
+
+pub(super) static IDEOGRAPHIC: [(u32, u32); 19] = [
+    (0x3006, 0x3007),
+    (0x3021, 0x3029),
+    (0x3038, 0x303A),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFC),
+    (0xF900, 0xFA6D),
+    (0xFA70, 0xFAD9),
+    (0x16FE4, 0x16FE4),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1B170, 0x1B2FB),
+    (0x20000, 0x2A6DD),
+    (0x2A700, 0x2B734),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x2F800, 0x2FA1D),
+    (0x30000, 0x3134A),
+];
+
+pub(super) static MIRRORED: [(u32, u32); 113] = [
+    (0x0028, 0x0029),
+    (0x003C, 0x003C),
+    (0x003E, 0x003E),
+    (0x005B, 0x005B),
+    (0x005D, 0x005D),
+    (0x007B, 0x007B),
+    (0x007D, 0x007D),
+    (0x00AB, 0x00AB),
+    (0x00BB, 0x00BB),
+    (0x0F3A, 0x0F3D),
+    (0x169B, 0x169C),
+    (0x2039, 0x203A),
+    (0x2045, 0x2046),
+    (0x207D, 0x207E),
+    (0x208D, 0x208E),
+    (0x2140, 0x2140),
+    (0x2201, 0x2204),
+    (0x2208, 0x220D),
+    (0x2211, 0x2211),
+    (0x2215, 0x2216),
+    (0x221A, 0x221D),
+    (0x221F, 0x2222),
+    (0x2224, 0x2224),
+    (0x2226, 0x2226),
+    (0x222B, 0x2233),
+    (0x2239, 0x2239),
+    (0x223B, 0x224C),
+    (0x2252, 0x2255),
+    (0x225F, 0x2260),
+    (0x2262, 0x2262),
+    (0x2264, 0x226B),
+    (0x226E, 0x228C),
+    (0x228F, 0x2292),
+    (0x2298, 0x2298),
+    (0x22A2, 0x22A3),
+    (0x22A6, 0x22B8),
+    (0x22BE, 0x22BF),
+    (0x22C9, 0x22CD),
+    (0x22D0, 0x22D1),
+    (0x22D6, 0x22ED),
+    (0x22F0, 0x22FF),
+    (0x2308, 0x230B),
+    (0x2320, 0x2321),
+    (0x2329, 0x232A),
+    (0x2768, 0x2775),
+    (0x27C0, 0x27C0),
+    (0x27C3, 0x27C6),
+    (0x27C8, 0x27C9),
+    (0x27CB, 0x27CD),
+    (0x27D3, 0x27D6),
+    (0x27DC, 0x27DE),
+    (0x27E2, 0x27EF),
+    (0x2983, 0x2998),
+    (0x299B, 0x29A0),
+    (0x29A2, 0x29AF),
+    (0x29B8, 0x29B8),
+    (0x29C0, 0x29C5),
+    (0x29C9, 0x29C9),
+    (0x29CE, 0x29D2),
+    (0x29D4, 0x29D5),
+    (0x29D8, 0x29DC),
+    (0x29E1, 0x29E1),
+    (0x29E3, 0x29E5),
+    (0x29E8, 0x29E9),
+    (0x29F4, 0x29F9),
+    (0x29FC, 0x29FD),
+    (0x2A0A, 0x2A1C),
+    (0x2A1E, 0x2A21),
+    (0x2A24, 0x2A24),
+    (0x2A26, 0x2A26),
+    (0x2A29, 0x2A29),
+    (0x2A2B, 0x2A2E),
+    (0x2A34, 0x2A35),
+    (0x2A3C, 0x2A3E),
+    (0x2A57, 0x2A58),
+    (0x2A64, 0x2A65),
+    (0x2A6A, 0x2A6D),
+    (0x2A6F, 0x2A70),
+    (0x2A73, 0x2A74),
+    (0x2A79, 0x2AA3),
+    (0x2AA6, 0x2AAD),
+    (0x2AAF, 0x2AD6),
+    (0x2ADC, 0x2ADC),
+    (0x2ADE, 0x2ADE),
+    (0x2AE2, 0x2AE6),
+    (0x2AEC, 0x2AEE),
+    (0x2AF3, 0x2AF3),
+    (0x2AF7, 0x2AFB),
+    (0x2AFD, 0x2AFD),
+    (0x2BFE, 0x2BFE),
+    (0x2E02, 0x2E05),
+    (0x2E09, 0x2E0A),
+    (0x2E0C, 0x2E0D),
+    (0x2E1C, 0x2E1D),
+    (0x2E20, 0x2E29),
+    (0x3008, 0x3011),
+    (0x3014, 0x301B),
+    (0xFE59, 0xFE5E),
+    (0xFE64, 0xFE65),
+    (0xFF08, 0xFF09),
+    (0xFF1C, 0xFF1C),
+    (0xFF1E, 0xFF1E),
+    (0xFF3B, 0xFF3B),
+    (0xFF3D, 0xFF3D),
+    (0xFF5B, 0xFF5B),
+    (0xFF5D, 0xFF5D),
+    (0xFF5F, 0xFF60),
+    (0xFF62, 0xFF63),
+    (0x1D6DB, 0x1D6DB),
+    (0x1D715, 0x1D715),
+    (0x1D74F, 0x1D74F),
+    (0x1D789, 0x1D789),
+    (0x1D7C3, 0x1D7C3),
+];
+
+pub(super) static UPPER_CASE: [(u32, u32); 1410] = [
+    (0x0061, 0x0041),
+    (0x0062, 0x0042),
+    (0x0063, 0x0043),
+    (0x0064, 0x0044),
+    (0x0065, 0x0045),
+    (0x0066, 0x0046),
+    (0x0067, 0x0047),
+    (0x0068, 0x0048),
+    (0x0069, 0x0049),
+    (0x006A, 0x004A),
+    (0x006B, 0x004B),
+    (0x006C, 0x004C),
+    (0x006D, 0x004D),
+    (0x006E, 0x004E),
+    (0x006F, 0x004F),
+    (0x0070, 0x0050),
+    (0x0071, 0x0051),
+    (0x0072, 0x0052),
+    (0x0073, 0x0053),
+    (0x0074, 0x0054),
+    (0x0075, 0x0055),
+    (0x0076, 0x0056),
+    (0x0077, 0x0057),
+    (0x0078, 0x0058),
+    (0x0079, 0x0059),
+    (0x007A, 0x005A),
+    (0x00B5, 0x039C),
+    (0x00E0, 0x00C0),
+    (0x00E1, 0x00C1),
+    (0x00E2, 0x00C2),
+    (0x00E3, 0x00C3),
+    (0x00E4, 0x00C4),
+    (0x00E5, 0x00C5),
+    (0x00E6, 0x00C6),
+    (0x00E7, 0x00C7),
+    (0x00E8, 0x00C8),
+    (0x00E9, 0x00C9),
+    (0x00EA, 0x00CA),
+    (0x00EB, 0x00CB),
+    (0x00EC, 0x00CC),
+    (0x00ED, 0x00CD),
+    (0x00EE, 0x00CE),
+    (0x00EF, 0x00CF),
+    (0x00F0, 0x00D0),
+    (0x00F1, 0x00D1),
+    (0x00F2, 0x00D2),
+    (0x00F3, 0x00D3),
+    (0x00F4, 0x00D4),
+    (0x00F5, 0x00D5),
+    (0x00F6, 0x00D6),
+    (0x00F8, 0x00D8),
+    (0x00F9, 0x00D9),
+    (0x00FA, 0x00DA),
+    (0x00FB, 0x00DB),
+    (0x00FC, 0x00DC),
+    (0x00FD, 0x00DD),
+    (0x00FE, 0x00DE),
+    (0x00FF, 0x0178),
+    (0x0101, 0x0100),
+    (0x0103, 0x0102),
+    (0x0105, 0x0104),
+    (0x0107, 0x0106),
+    (0x0109, 0x0108),
+    (0x010B, 0x010A),
+    (0x010D, 0x010C),
+    (0x010F, 0x010E),
+    (0x0111, 0x0110),
+    (0x0113, 0x0112),
+    (0x0115, 0x0114),
+    (0x0117, 0x0116),
+    (0x0119, 0x0118),
+    (0x011B, 0x011A),
+    (0x011D, 0x011C),
+    (0x011F, 0x011E),
+    (0x0121, 0x0120),
+    (0x0123, 0x0122),
+    (0x0125, 0x0124),
+    (0x0127, 0x0126),
+    (0x0129, 0x0128),
+    (0x012B, 0x012A),
+    (0x012D, 0x012C),
+    (0x012F, 0x012E),
+    (0x0131, 0x0049),
+    (0x0133, 0x0132),
+    (0x0135, 0x0134),
+    (0x0137, 0x0136),
+    (0x013A, 0x0139),
+    (0x013C, 0x013B),
+    (0x013E, 0x013D),
+    (0x0140, 0x013F),
+    (0x0142, 0x0141),
+    (0x0144, 0x0143),
+    (0x0146, 0x0145),
+    (0x0148, 0x0147),
+    (0x014B, 0x014A),
+    (0x014D, 0x014C),
+    (0x014F, 0x014E),
+    (0x0151, 0x0150),
+    (0x0153, 0x0152),
+    (0x0155, 0x0154),
+    (0x0157, 0x0156),
+    (0x0159, 0x0158),
+    (0x015B, 0x015A),
+    (0x015D, 0x015C),
+    (0x015F, 0x015E),
+    (0x0161, 0x0160),
+    (0x0163, 0x0162),
+    (0x0165, 0x0164),
+    (0x0167, 0x0166),
+    (0x0169, 0x0168),
+    (0x016B, 0x016A),
+    (0x016D, 0x016C),
+    (0x016F, 0x016E),
+    (0x0171, 0x0170),
+    (0x0173, 0x0172),
+    (0x0175, 0x0174),
+    (0x0177, 0x0176),
+    (0x017A, 0x0179),
+    (0x017C, 0x017B),
+    (0x017E, 0x017D),
+    (0x017F, 0x0053),
+    (0x0180, 0x0243),
+    (0x0183, 0x0182),
+    (0x0185, 0x0184),
+    (0x0188, 0x0187),
+    (0x018C, 0x018B),
+    (0x0192, 0x0191),
+    (0x0195, 0x01F6),
+    (0x0199, 0x0198),
+    (0x019A, 0x023D),
+    (0x019E, 0x0220),
+    (0x01A1, 0x01A0),
+    (0x01A3, 0x01A2),
+    (0x01A5, 0x01A4),
+    (0x01A8, 0x01A7),
+    (0x01AD, 0x01AC),
+    (0x01B0, 0x01AF),
+    (0x01B4, 0x01B3),
+    (0x01B6, 0x01B5),
+    (0x01B9, 0x01B8),
+    (0x01BD, 0x01BC),
+    (0x01BF, 0x01F7),
+    (0x01C5, 0x01C4),
+    (0x01C6, 0x01C4),
+    (0x01C8, 0x01C7),
+    (0x01C9, 0x01C7),
+    (0x01CB, 0x01CA),
+    (0x01CC, 0x01CA),
+    (0x01CE, 0x01CD),
+    (0x01D0, 0x01CF),
+    (0x01D2, 0x01D1),
+    (0x01D4, 0x01D3),
+    (0x01D6, 0x01D5),
+    (0x01D8, 0x01D7),
+    (0x01DA, 0x01D9),
+    (0x01DC, 0x01DB),
+    (0x01DD, 0x018E),
+    (0x01DF, 0x01DE),
+    (0x01E1, 0x01E0),
+    (0x01E3, 0x01E2),
+    (0x01E5, 0x01E4),
+    (0x01E7, 0x01E6),
+    (0x01E9, 0x01E8),
+    (0x01EB, 0x01EA),
+    (0x01ED, 0x01EC),
+    (0x01EF, 0x01EE),
+    (0x01F2, 0x01F1),
+    (0x01F3, 0x01F1),
+    (0x01F5, 0x01F4),
+    (0x01F9, 0x01F8),
+    (0x01FB, 0x01FA),
+    (0x01FD, 0x01FC),
+    (0x01FF, 0x01FE),
+    (0x0201, 0x0200),
+    (0x0203, 0x0202),
+    (0x0205, 0x0204),
+    (0x0207, 0x0206),
+    (0x0209, 0x0208),
+    (0x020B, 0x020A),
+    (0x020D, 0x020C),
+    (0x020F, 0x020E),
+    (0x0211, 0x0210),
+    (0x0213, 0x0212),
+    (0x0215, 0x0214),
+    (0x0217, 0x0216),
+    (0x0219, 0x0218),
+    (0x021B, 0x021A),
+    (0x021D, 0x021C),
+    (0x021F, 0x021E),
+    (0x0223, 0x0222),
+    (0x0225, 0x0224),
+    (0x0227, 0x0226),
+    (0x0229, 0x0228),
+    (0x022B, 0x022A),
+    (0x022D, 0x022C),
+    (0x022F, 0x022E),
+    (0x0231, 0x0230),
+    (0x0233, 0x0232),
+    (0x023C, 0x023B),
+    (0x023F, 0x2C7E),
+    (0x0240, 0x2C7F),
+    (0x0242, 0x0241),
+    (0x0247, 0x0246),
+    (0x0249, 0x0248),
+    (0x024B, 0x024A),
+    (0x024D, 0x024C),
+    (0x024F, 0x024E),
+    (0x0250, 0x2C6F),
+    (0x0251, 0x2C6D),
+    (0x0252, 0x2C70),
+    (0x0253, 0x0181),
+    (0x0254, 0x0186),
+    (0x0256, 0x0189),
+    (0x0257, 0x018A),
+    (0x0259, 0x018F),
+    (0x025B, 0x0190),
+    (0x025C, 0xA7AB),
+    (0x0260, 0x0193),
+    (0x0261, 0xA7AC),
+    (0x0263, 0x0194),
+    (0x0265, 0xA78D),
+    (0x0266, 0xA7AA),
+    (0x0268, 0x0197),
+    (0x0269, 0x0196),
+    (0x026A, 0xA7AE),
+    (0x026B, 0x2C62),
+    (0x026C, 0xA7AD),
+    (0x026F, 0x019C),
+    (0x0271, 0x2C6E),
+    (0x0272, 0x019D),
+    (0x0275, 0x019F),
+    (0x027D, 0x2C64),
+    (0x0280, 0x01A6),
+    (0x0282, 0xA7C5),
+    (0x0283, 0x01A9),
+    (0x0287, 0xA7B1),
+    (0x0288, 0x01AE),
+    (0x0289, 0x0244),
+    (0x028A, 0x01B1),
+    (0x028B, 0x01B2),
+    (0x028C, 0x0245),
+    (0x0292, 0x01B7),
+    (0x029D, 0xA7B2),
+    (0x029E, 0xA7B0),
+    (0x0345, 0x0399),
+    (0x0371, 0x0370),
+    (0x0373, 0x0372),
+    (0x0377, 0x0376),
+    (0x037B, 0x03FD),
+    (0x037C, 0x03FE),
+    (0x037D, 0x03FF),
+    (0x03AC, 0x0386),
+    (0x03AD, 0x0388),
+    (0x03AE, 0x0389),
+    (0x03AF, 0x038A),
+    (0x03B1, 0x0391),
+    (0x03B2, 0x0392),
+    (0x03B3, 0x0393),
+    (0x03B4, 0x0394),
+    (0x03B5, 0x0395),
+    (0x03B6, 0x0396),
+    (0x03B7, 0x0397),
+    (0x03B8, 0x0398),
+    (0x03B9, 0x0399),
+    (0x03BA, 0x039A),
+    (0x03BB, 0x039B),
+    (0x03BC, 0x039C),
+    (0x03BD, 0x039D),
+    (0x03BE, 0x039E),
+    (0x03BF, 0x039F),
+    (0x03C0, 0x03A0),
+    (0x03C1, 0x03A1),
+    (0x03C2, 0x03A3),
+    (0x03C3, 0x03A3),
+    (0x03C4, 0x03A4),
+    (0x03C5, 0x03A5),
+    (0x03C6, 0x03A6),
+    (0x03C7, 0x03A7),
+    (0x03C8, 0x03A8),
+    (0x03C9, 0x03A9),
+    (0x03CA, 0x03AA),
+    (0x03CB, 0x03AB),
+    (0x03CC, 0x038C),
+    (0x03CD, 0x038E),
+    (0x03CE, 0x038F),
+    (0x03D0, 0x0392),
+    (0x03D1, 0x0398),
+    (0x03D5, 0x03A6),
+    (0x03D6, 0x03A0),
+    (0x03D7, 0x03CF),
+    (0x03D9, 0x03D8),
+    (0x03DB, 0x03DA),
+    (0x03DD, 0x03DC),
+    (0x03DF, 0x03DE),
+    (0x03E1, 0x03E0),
+    (0x03E3, 0x03E2),
+    (0x03E5, 0x03E4),
+    (0x03E7, 0x03E6),
+    (0x03E9, 0x03E8),
+    (0x03EB, 0x03EA),
+    (0x03ED, 0x03EC),
+    (0x03EF, 0x03EE),
+    (0x03F0, 0x039A),
+    (0x03F1, 0x03A1),
+    (0x03F2, 0x03F9),
+    (0x03F3, 0x037F),
+    (0x03F5, 0x0395),
+    (0x03F8, 0x03F7),
+    (0x03FB, 0x03FA),
+    (0x0430, 0x0410),
+    (0x0431, 0x0411),
+    (0x0432, 0x0412),
+    (0x0433, 0x0413),
+    (0x0434, 0x0414),
+    (0x0435, 0x0415),
+    (0x0436, 0x0416),
+    (0x0437, 0x0417),
+    (0x0438, 0x0418),
+    (0x0439, 0x0419),
+    (0x043A, 0x041A),
+    (0x043B, 0x041B),
+    (0x043C, 0x041C),
+    (0x043D, 0x041D),
+    (0x043E, 0x041E),
+    (0x043F, 0x041F),
+    (0x0440, 0x0420),
+    (0x0441, 0x0421),
+    (0x0442, 0x0422),
+    (0x0443, 0x0423),
+    (0x0444, 0x0424),
+    (0x0445, 0x0425),
+    (0x0446, 0x0426),
+    (0x0447, 0x0427),
+    (0x0448, 0x0428),
+    (0x0449, 0x0429),
+    (0x044A, 0x042A),
+    (0x044B, 0x042B),
+    (0x044C, 0x042C),
+    (0x044D, 0x042D),
+    (0x044E, 0x042E),
+    (0x044F, 0x042F),
+    (0x0450, 0x0400),
+    (0x0451, 0x0401),
+    (0x0452, 0x0402),
+    (0x0453, 0x0403),
+    (0x0454, 0x0404),
+    (0x0455, 0x0405),
+    (0x0456, 0x0406),
+    (0x0457, 0x0407),
+    (0x0458, 0x0408),
+    (0x0459, 0x0409),
+    (0x045A, 0x040A),
+    (0x045B, 0x040B),
+    (0x045C, 0x040C),
+    (0x045D, 0x040D),
+    (0x045E, 0x040E),
+    (0x045F, 0x040F),
+    (0x0461, 0x0460),
+    (0x0463, 0x0462),
+    (0x0465, 0x0464),
+    (0x0467, 0x0466),
+    (0x0469, 0x0468),
+    (0x046B, 0x046A),
+    (0x046D, 0x046C),
+    (0x046F, 0x046E),
+    (0x0471, 0x0470),
+    (0x0473, 0x0472),
+    (0x0475, 0x0474),
+    (0x0477, 0x0476),
+    (0x0479, 0x0478),
+    (0x047B, 0x047A),
+    (0x047D, 0x047C),
+    (0x047F, 0x047E),
+    (0x0481, 0x0480),
+    (0x048B, 0x048A),
+    (0x048D, 0x048C),
+    (0x048F, 0x048E),
+    (0x0491, 0x0490),
+    (0x0493, 0x0492),
+    (0x0495, 0x0494),
+    (0x0497, 0x0496),
+    (0x0499, 0x0498),
+    (0x049B, 0x049A),
+    (0x049D, 0x049C),
+    (0x049F, 0x049E),
+    (0x04A1, 0x04A0),
+    (0x04A3, 0x04A2),
+    (0x04A5, 0x04A4),
+    (0x04A7, 0x04A6),
+    (0x04A9, 0x04A8),
+    (0x04AB, 0x04AA),
+    (0x04AD, 0x04AC),
+    (0x04AF, 0x04AE),
+    (0x04B1, 0x04B0),
+    (0x04B3, 0x04B2),
+    (0x04B5, 0x04B4),
+    (0x04B7, 0x04B6),
+    (0x04B9, 0x04B8),
+    (0x04BB, 0x04BA),
+    (0x04BD, 0x04BC),
+    (0x04BF, 0x04BE),
+    (0x04C2, 0x04C1),
+    (0x04C4, 0x04C3),
+    (0x04C6, 0x04C5),
+    (0x04C8, 0x04C7),
+    (0x04CA, 0x04C9),
+    (0x04CC, 0x04CB),
+    (0x04CE, 0x04CD),
+    (0x04CF, 0x04C0),
+    (0x04D1, 0x04D0),
+    (0x04D3, 0x04D2),
+    (0x04D5, 0x04D4),
+    (0x04D7, 0x04D6),
+    (0x04D9, 0x04D8),
+    (0x04DB, 0x04DA),
+    (0x04DD, 0x04DC),
+    (0x04DF, 0x04DE),
+    (0x04E1, 0x04E0),
+    (0x04E3, 0x04E2),
+    (0x04E5, 0x04E4),
+    (0x04E7, 0x04E6),
+    (0x04E9, 0x04E8),
+    (0x04EB, 0x04EA),
+    (0x04ED, 0x04EC),
+    (0x04EF, 0x04EE),
+    (0x04F1, 0x04F0),
+    (0x04F3, 0x04F2),
+    (0x04F5, 0x04F4),
+    (0x04F7, 0x04F6),
+    (0x04F9, 0x04F8),
+    (0x04FB, 0x04FA),
+    (0x04FD, 0x04FC),
+    (0x04FF, 0x04FE),
+    (0x0501, 0x0500),
+    (0x0503, 0x0502),
+    (0x0505, 0x0504),
+    (0x0507, 0x0506),
+    (0x0509, 0x0508),
+    (0x050B, 0x050A),
+    (0x050D, 0x050C),
+    (0x050F, 0x050E),
+    (0x0511, 0x0510),
+    (0x0513, 0x0512),
+    (0x0515, 0x0514),
+    (0x0517, 0x0516),
+    (0x0519, 0x0518),
+    (0x051B, 0x051A),
+    (0x051D, 0x051C),
+    (0x051F, 0x051E),
+    (0x0521, 0x0520),
+    (0x0523, 0x0522),
+    (0x0525, 0x0524),
+    (0x0527, 0x0526),
+    (0x0529, 0x0528),
+    (0x052B, 0x052A),
+    (0x052D, 0x052C),
+    (0x052F, 0x052E),
+    (0x0561, 0x0531),
+    (0x0562, 0x0532),
+    (0x0563, 0x0533),
+    (0x0564, 0x0534),
+    (0x0565, 0x0535),
+    (0x0566, 0x0536),
+    (0x0567, 0x0537),
+    (0x0568, 0x0538),
+    (0x0569, 0x0539),
+    (0x056A, 0x053A),
+    (0x056B, 0x053B),
+    (0x056C, 0x053C),
+    (0x056D, 0x053D),
+    (0x056E, 0x053E),
+    (0x056F, 0x053F),
+    (0x0570, 0x0540),
+    (0x0571, 0x0541),
+    (0x0572, 0x0542),
+    (0x0573, 0x0543),
+    (0x0574, 0x0544),
+    (0x0575, 0x0545),
+    (0x0576, 0x0546),
+    (0x0577, 0x0547),
+    (0x0578, 0x0548),
+    (0x0579, 0x0549),
+    (0x057A, 0x054A),
+    (0x057B, 0x054B),
+    (0x057C, 0x054C),
+    (0x057D, 0x054D),
+    (0x057E, 0x054E),
+    (0x057F, 0x054F),
+    (0x0580, 0x0550),
+    (0x0581, 0x0551),
+    (0x0582, 0x0552),
+    (0x0583, 0x0553),
+    (0x0584, 0x0554),
+    (0x0585, 0x0555),
+    (0x0586, 0x0556),
+    (0x10D0, 0x1C90),
+    (0x10D1, 0x1C91),
+    (0x10D2, 0x1C92),
+    (0x10D3, 0x1C93),
+    (0x10D4, 0x1C94),
+    (0x10D5, 0x1C95),
+    (0x10D6, 0x1C96),
+    (0x10D7, 0x1C97),
+    (0x10D8, 0x1C98),
+    (0x10D9, 0x1C99),
+    (0x10DA, 0x1C9A),
+    (0x10DB, 0x1C9B),
+    (0x10DC, 0x1C9C),
+    (0x10DD, 0x1C9D),
+    (0x10DE, 0x1C9E),
+    (0x10DF, 0x1C9F),
+    (0x10E0, 0x1CA0),
+    (0x10E1, 0x1CA1),
+    (0x10E2, 0x1CA2),
+    (0x10E3, 0x1CA3),
+    (0x10E4, 0x1CA4),
+    (0x10E5, 0x1CA5),
+    (0x10E6, 0x1CA6),
+    (0x10E7, 0x1CA7),
+    (0x10E8, 0x1CA8),
+    (0x10E9, 0x1CA9),
+    (0x10EA, 0x1CAA),
+    (0x10EB, 0x1CAB),
+    (0x10EC, 0x1CAC),
+    (0x10ED, 0x1CAD),
+    (0x10EE, 0x1CAE),
+    (0x10EF, 0x1CAF),
+    (0x10F0, 0x1CB0),
+    (0x10F1, 0x1CB1),
+    (0x10F2, 0x1CB2),
+    (0x10F3, 0x1CB3),
+    (0x10F4, 0x1CB4),
+    (0x10F5, 0x1CB5),
+    (0x10F6, 0x1CB6),
+    (0x10F7, 0x1CB7),
+    (0x10F8, 0x1CB8),
+    (0x10F9, 0x1CB9),
+    (0x10FA, 0x1CBA),
+    (0x10FD, 0x1CBD),
+    (0x10FE, 0x1CBE),
+    (0x10FF, 0x1CBF),
+    (0x13F8, 0x13F0),
+    (0x13F9, 0x13F1),
+    (0x13FA, 0x13F2),
+    (0x13FB, 0x13F3),
+    (0x13FC, 0x13F4),
+    (0x13FD, 0x13F5),
+    (0x1C80, 0x0412),
+    (0x1C81, 0x0414),
+    (0x1C82, 0x041E),
+    (0x1C83, 0x0421),
+    (0x1C84, 0x0422),
+    (0x1C85, 0x0422),
+    (0x1C86, 0x042A),
+    (0x1C87, 0x0462),
+    (0x1C88, 0xA64A),
+    (0x1D79, 0xA77D),
+    (0x1D7D, 0x2C63),
+    (0x1D8E, 0xA7C6),
+    (0x1E01, 0x1E00),
+    (0x1E03, 0x1E02),
+    (0x1E05, 0x1E04),
+    (0x1E07, 0x1E06),
+    (0x1E09, 0x1E08),
+    (0x1E0B, 0x1E0A),
+    (0x1E0D, 0x1E0C),
+    (0x1E0F, 0x1E0E),
+    (0x1E11, 0x1E10),
+    (0x1E13, 0x1E12),
+    (0x1E15, 0x1E14),
+    (0x1E17, 0x1E16),
+    (0x1E19, 0x1E18),
+    (0x1E1B, 0x1E1A),
+    (0x1E1D, 0x1E1C),
+    (0x1E1F, 0x1E1E),
+    (0x1E21, 0x1E20),
+    (0x1E23, 0x1E22),
+    (0x1E25, 0x1E24),
+    (0x1E27, 0x1E26),
+    (0x1E29, 0x1E28),
+    (0x1E2B, 0x1E2A),
+    (0x1E2D, 0x1E2C),
+    (0x1E2F, 0x1E2E),
+    (0x1E31, 0x1E30),
+    (0x1E33, 0x1E32),
+    (0x1E35, 0x1E34),
+    (0x1E37, 0x1E36),
+    (0x1E39, 0x1E38),
+    (0x1E3B, 0x1E3A),
+    (0x1E3D, 0x1E3C),
+    (0x1E3F, 0x1E3E),
+    (0x1E41, 0x1E40),
+    (0x1E43, 0x1E42),
+    (0x1E45, 0x1E44),
+    (0x1E47, 0x1E46),
+    (0x1E49, 0x1E48),
+    (0x1E4B, 0x1E4A),
+    (0x1E4D, 0x1E4C),
+    (0x1E4F, 0x1E4E),
+    (0x1E51, 0x1E50),
+    (0x1E53, 0x1E52),
+    (0x1E55, 0x1E54),
+    (0x1E57, 0x1E56),
+    (0x1E59, 0x1E58),
+    (0x1E5B, 0x1E5A),
+    (0x1E5D, 0x1E5C),
+    (0x1E5F, 0x1E5E),
+    (0x1E61, 0x1E60),
+    (0x1E63, 0x1E62),
+    (0x1E65, 0x1E64),
+    (0x1E67, 0x1E66),
+    (0x1E69, 0x1E68),
+    (0x1E6B, 0x1E6A),
+    (0x1E6D, 0x1E6C),
+    (0x1E6F, 0x1E6E),
+    (0x1E71, 0x1E70),
+    (0x1E73, 0x1E72),
+    (0x1E75, 0x1E74),
+    (0x1E77, 0x1E76),
+    (0x1E79, 0x1E78),
+    (0x1E7B, 0x1E7A),
+    (0x1E7D, 0x1E7C),
+    (0x1E7F, 0x1E7E),
+    (0x1E81, 0x1E80),
+    (0x1E83, 0x1E82),
+    (0x1E85, 0x1E84),
+    (0x1E87, 0x1E86),
+    (0x1E89, 0x1E88),
+    (0x1E8B, 0x1E8A),
+    (0x1E8D, 0x1E8C),
+    (0x1E8F, 0x1E8E),
+    (0x1E91, 0x1E90),
+    (0x1E93, 0x1E92),
+    (0x1E95, 0x1E94),
+    (0x1E9B, 0x1E60),
+    (0x1EA1, 0x1EA0),
+    (0x1EA3, 0x1EA2),
+    (0x1EA5, 0x1EA4),
+    (0x1EA7, 0x1EA6),
+    (0x1EA9, 0x1EA8),
+    (0x1EAB, 0x1EAA),
+    (0x1EAD, 0x1EAC),
+    (0x1EAF, 0x1EAE),
+    (0x1EB1, 0x1EB0),
+    (0x1EB3, 0x1EB2),
+    (0x1EB5, 0x1EB4),
+    (0x1EB7, 0x1EB6),
+    (0x1EB9, 0x1EB8),
+    (0x1EBB, 0x1EBA),
+    (0x1EBD, 0x1EBC),
+    (0x1EBF, 0x1EBE),
+    (0x1EC1, 0x1EC0),
+    (0x1EC3, 0x1EC2),
+    (0x1EC5, 0x1EC4),
+    (0x1EC7, 0x1EC6),
+    (0x1EC9, 0x1EC8),
+    (0x1ECB, 0x1ECA),
+    (0x1ECD, 0x1ECC),
+    (0x1ECF, 0x1ECE),
+    (0x1ED1, 0x1ED0),
+    (0x1ED3, 0x1ED2),
+    (0x1ED5, 0x1ED4),
+    (0x1ED7, 0x1ED6),
+    (0x1ED9, 0x1ED8),
+    (0x1EDB, 0x1EDA),
+    (0x1EDD, 0x1EDC),
+    (0x1EDF, 0x1EDE),
+    (0x1EE1, 0x1EE0),
+    (0x1EE3, 0x1EE2),
+    (0x1EE5, 0x1EE4),
+    (0x1EE7, 0x1EE6),
+    (0x1EE9, 0x1EE8),
+    (0x1EEB, 0x1EEA),
+    (0x1EED, 0x1EEC),
+    (0x1EEF, 0x1EEE),
+    (0x1EF1, 0x1EF0),
+    (0x1EF3, 0x1EF2),
+    (0x1EF5, 0x1EF4),
+    (0x1EF7, 0x1EF6),
+    (0x1EF9, 0x1EF8),
+    (0x1EFB, 0x1EFA),
+    (0x1EFD, 0x1EFC),
+    (0x1EFF, 0x1EFE),
+    (0x1F00, 0x1F08),
+    (0x1F01, 0x1F09),
+    (0x1F02, 0x1F0A),
+    (0x1F03, 0x1F0B),
+    (0x1F04, 0x1F0C),
+    (0x1F05, 0x1F0D),
+    (0x1F06, 0x1F0E),
+    (0x1F07, 0x1F0F),
+    (0x1F10, 0x1F18),
+    (0x1F11, 0x1F19),
+    (0x1F12, 0x1F1A),
+    (0x1F13, 0x1F1B),
+    (0x1F14, 0x1F1C),
+    (0x1F15, 0x1F1D),
+    (0x1F20, 0x1F28),
+    (0x1F21, 0x1F29),
+    (0x1F22, 0x1F2A),
+    (0x1F23, 0x1F2B),
+    (0x1F24, 0x1F2C),
+    (0x1F25, 0x1F2D),
+    (0x1F26, 0x1F2E),
+    (0x1F27, 0x1F2F),
+    (0x1F30, 0x1F38),
+    (0x1F31, 0x1F39),
+    (0x1F32, 0x1F3A),
+    (0x1F33, 0x1F3B),
+    (0x1F34, 0x1F3C),
+    (0x1F35, 0x1F3D),
+    (0x1F36, 0x1F3E),
+    (0x1F37, 0x1F3F),
+    (0x1F40, 0x1F48),
+    (0x1F41, 0x1F49),
+    (0x1F42, 0x1F4A),
+    (0x1F43, 0x1F4B),
+    (0x1F44, 0x1F4C),
+    (0x1F45, 0x1F4D),
+    (0x1F51, 0x1F59),
+    (0x1F53, 0x1F5B),
+    (0x1F55, 0x1F5D),
+    (0x1F57, 0x1F5F),
+    (0x1F60, 0x1F68),
+    (0x1F61, 0x1F69),
+    (0x1F62, 0x1F6A),
+    (0x1F63, 0x1F6B),
+    (0x1F64, 0x1F6C),
+    (0x1F65, 0x1F6D),
+    (0x1F66, 0x1F6E),
+    (0x1F67, 0x1F6F),
+    (0x1F70, 0x1FBA),
+    (0x1F71, 0x1FBB),
+    (0x1F72, 0x1FC8),
+    (0x1F73, 0x1FC9),
+    (0x1F74, 0x1FCA),
+    (0x1F75, 0x1FCB),
+    (0x1F76, 0x1FDA),
+    (0x1F77, 0x1FDB),
+    (0x1F78, 0x1FF8),
+    (0x1F79, 0x1FF9),
+    (0x1F7A, 0x1FEA),
+    (0x1F7B, 0x1FEB),
+    (0x1F7C, 0x1FFA),
+    (0x1F7D, 0x1FFB),
+    (0x1F80, 0x1F88),
+    (0x1F81, 0x1F89),
+    (0x1F82, 0x1F8A),
+    (0x1F83, 0x1F8B),
+    (0x1F84, 0x1F8C),
+    (0x1F85, 0x1F8D),
+    (0x1F86, 0x1F8E),
+    (0x1F87, 0x1F8F),
+    (0x1F90, 0x1F98),
+    (0x1F91, 0x1F99),
+    (0x1F92, 0x1F9A),
+    (0x1F93, 0x1F9B),
+    (0x1F94, 0x1F9C),
+    (0x1F95, 0x1F9D),
+    (0x1F96, 0x1F9E),
+    (0x1F97, 0x1F9F),
+    (0x1FA0, 0x1FA8),
+    (0x1FA1, 0x1FA9),
+    (0x1FA2, 0x1FAA),
+    (0x1FA3, 0x1FAB),
+    (0x1FA4, 0x1FAC),
+    (0x1FA5, 0x1FAD),
+    (0x1FA6, 0x1FAE),
+    (0x1FA7, 0x1FAF),
+    (0x1FB0, 0x1FB8),
+    (0x1FB1, 0x1FB9),
+    (0x1FB3, 0x1FBC),
+    (0x1FBE, 0x0399),
+    (0x1FC3, 0x1FCC),
+    (0x1FD0, 0x1FD8),
+    (0x1FD1, 0x1FD9),
+    (0x1FE0, 0x1FE8),
+    (0x1FE1, 0x1FE9),
+    (0x1FE5, 0x1FEC),
+    (0x1FF3, 0x1FFC),
+    (0x214E, 0x2132),
+    (0x2170, 0x2160),
+    (0x2171, 0x2161),
+    (0x2172, 0x2162),
+    (0x2173, 0x2163),
+    (0x2174, 0x2164),
+    (0x2175, 0x2165),
+    (0x2176, 0x2166),
+    (0x2177, 0x2167),
+    (0x2178, 0x2168),
+    (0x2179, 0x2169),
+    (0x217A, 0x216A),
+    (0x217B, 0x216B),
+    (0x217C, 0x216C),
+    (0x217D, 0x216D),
+    (0x217E, 0x216E),
+    (0x217F, 0x216F),
+    (0x2184, 0x2183),
+    (0x24D0, 0x24B6),
+    (0x24D1, 0x24B7),
+    (0x24D2, 0x24B8),
+    (0x24D3, 0x24B9),
+    (0x24D4, 0x24BA),
+    (0x24D5, 0x24BB),
+    (0x24D6, 0x24BC),
+    (0x24D7, 0x24BD),
+    (0x24D8, 0x24BE),
+    (0x24D9, 0x24BF),
+    (0x24DA, 0x24C0),
+    (0x24DB, 0x24C1),
+    (0x24DC, 0x24C2),
+    (0x24DD, 0x24C3),
+    (0x24DE, 0x24C4),
+    (0x24DF, 0x24C5),
+    (0x24E0, 0x24C6),
+    (0x24E1, 0x24C7),
+    (0x24E2, 0x24C8),
+    (0x24E3, 0x24C9),
+    (0x24E4, 0x24CA),
+    (0x24E5, 0x24CB),
+    (0x24E6, 0x24CC),
+    (0x24E7, 0x24CD),
+    (0x24E8, 0x24CE),
+    (0x24E9, 0x24CF),
+    (0x2C30, 0x2C00),
+    (0x2C31, 0x2C01),
+    (0x2C32, 0x2C02),
+    (0x2C33, 0x2C03),
+    (0x2C34, 0x2C04),
+    (0x2C35, 0x2C05),
+    (0x2C36, 0x2C06),
+    (0x2C37, 0x2C07),
+    (0x2C38, 0x2C08),
+    (0x2C39, 0x2C09),
+    (0x2C3A, 0x2C0A),
+    (0x2C3B, 0x2C0B),
+    (0x2C3C, 0x2C0C),
+    (0x2C3D, 0x2C0D),
+    (0x2C3E, 0x2C0E),
+    (0x2C3F, 0x2C0F),
+    (0x2C40, 0x2C10),
+    (0x2C41, 0x2C11),
+    (0x2C42, 0x2C12),
+    (0x2C43, 0x2C13),
+    (0x2C44, 0x2C14),
+    (0x2C45, 0x2C15),
+    (0x2C46, 0x2C16),
+    (0x2C47, 0x2C17),
+    (0x2C48, 0x2C18),
+    (0x2C49, 0x2C19),
+    (0x2C4A, 0x2C1A),
+    (0x2C4B, 0x2C1B),
+    (0x2C4C, 0x2C1C),
+    (0x2C4D, 0x2C1D),
+    (0x2C4E, 0x2C1E),
+    (0x2C4F, 0x2C1F),
+    (0x2C50, 0x2C20),
+    (0x2C51, 0x2C21),
+    (0x2C52, 0x2C22),
+    (0x2C53, 0x2C23),
+    (0x2C54, 0x2C24),
+    (0x2C55, 0x2C25),
+    (0x2C56, 0x2C26),
+    (0x2C57, 0x2C27),
+    (0x2C58, 0x2C28),
+    (0x2C59, 0x2C29),
+    (0x2C5A, 0x2C2A),
+    (0x2C5B, 0x2C2B),
+    (0x2C5C, 0x2C2C),
+    (0x2C5D, 0x2C2D),
+    (0x2C5E, 0x2C2E),
+    (0x2C61, 0x2C60),
+    (0x2C65, 0x023A),
+    (0x2C66, 0x023E),
+    (0x2C68, 0x2C67),
+    (0x2C6A, 0x2C69),
+    (0x2C6C, 0x2C6B),
+    (0x2C73, 0x2C72),
+    (0x2C76, 0x2C75),
+    (0x2C81, 0x2C80),
+    (0x2C83, 0x2C82),
+    (0x2C85, 0x2C84),
+    (0x2C87, 0x2C86),
+    (0x2C89, 0x2C88),
+    (0x2C8B, 0x2C8A),
+    (0x2C8D, 0x2C8C),
+    (0x2C8F, 0x2C8E),
+    (0x2C91, 0x2C90),
+    (0x2C93, 0x2C92),
+    (0x2C95, 0x2C94),
+    (0x2C97, 0x2C96),
+    (0x2C99, 0x2C98),
+    (0x2C9B, 0x2C9A),
+    (0x2C9D, 0x2C9C),
+    (0x2C9F, 0x2C9E),
+    (0x2CA1, 0x2CA0),
+    (0x2CA3, 0x2CA2),
+    (0x2CA5, 0x2CA4),
+    (0x2CA7, 0x2CA6),
+    (0x2CA9, 0x2CA8),
+    (0x2CAB, 0x2CAA),
+    (0x2CAD, 0x2CAC),
+    (0x2CAF, 0x2CAE),
+    (0x2CB1, 0x2CB0),
+    (0x2CB3, 0x2CB2),
+    (0x2CB5, 0x2CB4),
+    (0x2CB7, 0x2CB6),
+    (0x2CB9, 0x2CB8),
+    (0x2CBB, 0x2CBA),
+    (0x2CBD, 0x2CBC),
+    (0x2CBF, 0x2CBE),
+    (0x2CC1, 0x2CC0),
+    (0x2CC3, 0x2CC2),
+    (0x2CC5, 0x2CC4),
+    (0x2CC7, 0x2CC6),
+    (0x2CC9, 0x2CC8),
+    (0x2CCB, 0x2CCA),
+    (0x2CCD, 0x2CCC),
+    (0x2CCF, 0x2CCE),
+    (0x2CD1, 0x2CD0),
+    (0x2CD3, 0x2CD2),
+    (0x2CD5, 0x2CD4),
+    (0x2CD7, 0x2CD6),
+    (0x2CD9, 0x2CD8),
+    (0x2CDB, 0x2CDA),
+    (0x2CDD, 0x2CDC),
+    (0x2CDF, 0x2CDE),
+    (0x2CE1, 0x2CE0),
+    (0x2CE3, 0x2CE2),
+    (0x2CEC, 0x2CEB),
+    (0x2CEE, 0x2CED),
+    (0x2CF3, 0x2CF2),
+    (0x2D00, 0x10A0),
+    (0x2D01, 0x10A1),
+    (0x2D02, 0x10A2),
+    (0x2D03, 0x10A3),
+    (0x2D04, 0x10A4),
+    (0x2D05, 0x10A5),
+    (0x2D06, 0x10A6),
+    (0x2D07, 0x10A7),
+    (0x2D08, 0x10A8),
+    (0x2D09, 0x10A9),
+    (0x2D0A, 0x10AA),
+    (0x2D0B, 0x10AB),
+    (0x2D0C, 0x10AC),
+    (0x2D0D, 0x10AD),
+    (0x2D0E, 0x10AE),
+    (0x2D0F, 0x10AF),
+    (0x2D10, 0x10B0),
+    (0x2D11, 0x10B1),
+    (0x2D12, 0x10B2),
+    (0x2D13, 0x10B3),
+    (0x2D14, 0x10B4),
+    (0x2D15, 0x10B5),
+    (0x2D16, 0x10B6),
+    (0x2D17, 0x10B7),
+    (0x2D18, 0x10B8),
+    (0x2D19, 0x10B9),
+    (0x2D1A, 0x10BA),
+    (0x2D1B, 0x10BB),
+    (0x2D1C, 0x10BC),
+    (0x2D1D, 0x10BD),
+    (0x2D1E, 0x10BE),
+    (0x2D1F, 0x10BF),
+    (0x2D20, 0x10C0),
+    (0x2D21, 0x10C1),
+    (0x2D22, 0x10C2),
+    (0x2D23, 0x10C3),
+    (0x2D24, 0x10C4),
+    (0x2D25, 0x10C5),
+    (0x2D27, 0x10C7),
+    (0x2D2D, 0x10CD),
+    (0xA641, 0xA640),
+    (0xA643, 0xA642),
+    (0xA645, 0xA644),
+    (0xA647, 0xA646),
+    (0xA649, 0xA648),
+    (0xA64B, 0xA64A),
+    (0xA64D, 0xA64C),
+    (0xA64F, 0xA64E),
+    (0xA651, 0xA650),
+    (0xA653, 0xA652),
+    (0xA655, 0xA654),
+    (0xA657, 0xA656),
+    (0xA659, 0xA658),
+    (0xA65B, 0xA65A),
+    (0xA65D, 0xA65C),
+    (0xA65F, 0xA65E),
+    (0xA661, 0xA660),
+    (0xA663, 0xA662),
+    (0xA665, 0xA664),
+    (0xA667, 0xA666),
+    (0xA669, 0xA668),
+    (0xA66B, 0xA66A),
+    (0xA66D, 0xA66C),
+    (0xA681, 0xA680),
+    (0xA683, 0xA682),
+    (0xA685, 0xA684),
+    (0xA687, 0xA686),
+    (0xA689, 0xA688),
+    (0xA68B, 0xA68A),
+    (0xA68D, 0xA68C),
+    (0xA68F, 0xA68E),
+    (0xA691, 0xA690),
+    (0xA693, 0xA692),
+    (0xA695, 0xA694),
+    (0xA697, 0xA696),
+    (0xA699, 0xA698),
+    (0xA69B, 0xA69A),
+    (0xA723, 0xA722),
+    (0xA725, 0xA724),
+    (0xA727, 0xA726),
+    (0xA729, 0xA728),
+    (0xA72B, 0xA72A),
+    (0xA72D, 0xA72C),
+    (0xA72F, 0xA72E),
+    (0xA733, 0xA732),
+    (0xA735, 0xA734),
+    (0xA737, 0xA736),
+    (0xA739, 0xA738),
+    (0xA73B, 0xA73A),
+    (0xA73D, 0xA73C),
+    (0xA73F, 0xA73E),
+    (0xA741, 0xA740),
+    (0xA743, 0xA742),
+    (0xA745, 0xA744),
+    (0xA747, 0xA746),
+    (0xA749, 0xA748),
+    (0xA74B, 0xA74A),
+    (0xA74D, 0xA74C),
+    (0xA74F, 0xA74E),
+    (0xA751, 0xA750),
+    (0xA753, 0xA752),
+    (0xA755, 0xA754),
+    (0xA757, 0xA756),
+    (0xA759, 0xA758),
+    (0xA75B, 0xA75A),
+    (0xA75D, 0xA75C),
+    (0xA75F, 0xA75E),
+    (0xA761, 0xA760),
+    (0xA763, 0xA762),
+    (0xA765, 0xA764),
+    (0xA767, 0xA766),
+    (0xA769, 0xA768),
+    (0xA76B, 0xA76A),
+    (0xA76D, 0xA76C),
+    (0xA76F, 0xA76E),
+    (0xA77A, 0xA779),
+    (0xA77C, 0xA77B),
+    (0xA77F, 0xA77E),
+    (0xA781, 0xA780),
+    (0xA783, 0xA782),
+    (0xA785, 0xA784),
+    (0xA787, 0xA786),
+    (0xA78C, 0xA78B),
+    (0xA791, 0xA790),
+    (0xA793, 0xA792),
+    (0xA794, 0xA7C4),
+    (0xA797, 0xA796),
+    (0xA799, 0xA798),
+    (0xA79B, 0xA79A),
+    (0xA79D, 0xA79C),
+    (0xA79F, 0xA79E),
+    (0xA7A1, 0xA7A0),
+    (0xA7A3, 0xA7A2),
+    (0xA7A5, 0xA7A4),
+    (0xA7A7, 0xA7A6),
+    (0xA7A9, 0xA7A8),
+    (0xA7B5, 0xA7B4),
+    (0xA7B7, 0xA7B6),
+    (0xA7B9, 0xA7B8),
+    (0xA7BB, 0xA7BA),
+    (0xA7BD, 0xA7BC),
+    (0xA7BF, 0xA7BE),
+    (0xA7C3, 0xA7C2),
+    (0xA7C8, 0xA7C7),
+    (0xA7CA, 0xA7C9),
+    (0xA7F6, 0xA7F5),
+    (0xAB53, 0xA7B3),
+    (0xAB70, 0x13A0),
+    (0xAB71, 0x13A1),
+    (0xAB72, 0x13A2),
+    (0xAB73, 0x13A3),
+    (0xAB74, 0x13A4),
+    (0xAB75, 0x13A5),
+    (0xAB76, 0x13A6),
+    (0xAB77, 0x13A7),
+    (0xAB78, 0x13A8),
+    (0xAB79, 0x13A9),
+    (0xAB7A, 0x13AA),
+    (0xAB7B, 0x13AB),
+    (0xAB7C, 0x13AC),
+    (0xAB7D, 0x13AD),
+    (0xAB7E, 0x13AE),
+    (0xAB7F, 0x13AF),
+    (0xAB80, 0x13B0),
+    (0xAB81, 0x13B1),
+    (0xAB82, 0x13B2),
+    (0xAB83, 0x13B3),
+    (0xAB84, 0x13B4),
+    (0xAB85, 0x13B5),
+    (0xAB86, 0x13B6),
+    (0xAB87, 0x13B7),
+    (0xAB88, 0x13B8),
+    (0xAB89, 0x13B9),
+    (0xAB8A, 0x13BA),
+    (0xAB8B, 0x13BB),
+    (0xAB8C, 0x13BC),
+    (0xAB8D, 0x13BD),
+    (0xAB8E, 0x13BE),
+    (0xAB8F, 0x13BF),
+    (0xAB90, 0x13C0),
+    (0xAB91, 0x13C1),
+    (0xAB92, 0x13C2),
+    (0xAB93, 0x13C3),
+    (0xAB94, 0x13C4),
+    (0xAB95, 0x13C5),
+    (0xAB96, 0x13C6),
+    (0xAB97, 0x13C7),
+    (0xAB98, 0x13C8),
+    (0xAB99, 0x13C9),
+    (0xAB9A, 0x13CA),
+    (0xAB9B, 0x13CB),
+    (0xAB9C, 0x13CC),
+    (0xAB9D, 0x13CD),
+    (0xAB9E, 0x13CE),
+    (0xAB9F, 0x13CF),
+    (0xABA0, 0x13D0),
+    (0xABA1, 0x13D1),
+    (0xABA2, 0x13D2),
+    (0xABA3, 0x13D3),
+    (0xABA4, 0x13D4),
+    (0xABA5, 0x13D5),
+    (0xABA6, 0x13D6),
+    (0xABA7, 0x13D7),
+    (0xABA8, 0x13D8),
+    (0xABA9, 0x13D9),
+    (0xABAA, 0x13DA),
+    (0xABAB, 0x13DB),
+    (0xABAC, 0x13DC),
+    (0xABAD, 0x13DD),
+    (0xABAE, 0x13DE),
+    (0xABAF, 0x13DF),
+    (0xABB0, 0x13E0),
+    (0xABB1, 0x13E1),
+    (0xABB2, 0x13E2),
+    (0xABB3, 0x13E3),
+    (0xABB4, 0x13E4),
+    (0xABB5, 0x13E5),
+    (0xABB6, 0x13E6),
+    (0xABB7, 0x13E7),
+    (0xABB8, 0x13E8),
+    (0xABB9, 0x13E9),
+    (0xABBA, 0x13EA),
+    (0xABBB, 0x13EB),
+    (0xABBC, 0x13EC),
+    (0xABBD, 0x13ED),
+    (0xABBE, 0x13EE),
+    (0xABBF, 0x13EF),
+    (0xFF41, 0xFF21),
+    (0xFF42, 0xFF22),
+    (0xFF43, 0xFF23),
+    (0xFF44, 0xFF24),
+    (0xFF45, 0xFF25),
+    (0xFF46, 0xFF26),
+    (0xFF47, 0xFF27),
+    (0xFF48, 0xFF28),
+    (0xFF49, 0xFF29),
+    (0xFF4A, 0xFF2A),
+    (0xFF4B, 0xFF2B),
+    (0xFF4C, 0xFF2C),
+    (0xFF4D, 0xFF2D),
+    (0xFF4E, 0xFF2E),
+    (0xFF4F, 0xFF2F),
+    (0xFF50, 0xFF30),
+    (0xFF51, 0xFF31),
+    (0xFF52, 0xFF32),
+    (0xFF53, 0xFF33),
+    (0xFF54, 0xFF34),
+    (0xFF55, 0xFF35),
+    (0xFF56, 0xFF36),
+    (0xFF57, 0xFF37),
+    (0xFF58, 0xFF38),
+    (0xFF59, 0xFF39),
+    (0xFF5A, 0xFF3A),
+    (0x10428, 0x10400),
+    (0x10429, 0x10401),
+    (0x1042A, 0x10402),
+    (0x1042B, 0x10403),
+    (0x1042C, 0x10404),
+    (0x1042D, 0x10405),
+    (0x1042E, 0x10406),
+    (0x1042F, 0x10407),
+    (0x10430, 0x10408),
+    (0x10431, 0x10409),
+    (0x10432, 0x1040A),
+    (0x10433, 0x1040B),
+    (0x10434, 0x1040C),
+    (0x10435, 0x1040D),
+    (0x10436, 0x1040E),
+    (0x10437, 0x1040F),
+    (0x10438, 0x10410),
+    (0x10439, 0x10411),
+    (0x1043A, 0x10412),
+    (0x1043B, 0x10413),
+    (0x1043C, 0x10414),
+    (0x1043D, 0x10415),
+    (0x1043E, 0x10416),
+    (0x1043F, 0x10417),
+    (0x10440, 0x10418),
+    (0x10441, 0x10419),
+    (0x10442, 0x1041A),
+    (0x10443, 0x1041B),
+    (0x10444, 0x1041C),
+    (0x10445, 0x1041D),
+    (0x10446, 0x1041E),
+    (0x10447, 0x1041F),
+    (0x10448, 0x10420),
+    (0x10449, 0x10421),
+    (0x1044A, 0x10422),
+    (0x1044B, 0x10423),
+    (0x1044C, 0x10424),
+    (0x1044D, 0x10425),
+    (0x1044E, 0x10426),
+    (0x1044F, 0x10427),
+    (0x104D8, 0x104B0),
+    (0x104D9, 0x104B1),
+    (0x104DA, 0x104B2),
+    (0x104DB, 0x104B3),
+    (0x104DC, 0x104B4),
+    (0x104DD, 0x104B5),
+    (0x104DE, 0x104B6),
+    (0x104DF, 0x104B7),
+    (0x104E0, 0x104B8),
+    (0x104E1, 0x104B9),
+    (0x104E2, 0x104BA),
+    (0x104E3, 0x104BB),
+    (0x104E4, 0x104BC),
+    (0x104E5, 0x104BD),
+    (0x104E6, 0x104BE),
+    (0x104E7, 0x104BF),
+    (0x104E8, 0x104C0),
+    (0x104E9, 0x104C1),
+    (0x104EA, 0x104C2),
+    (0x104EB, 0x104C3),
+    (0x104EC, 0x104C4),
+    (0x104ED, 0x104C5),
+    (0x104EE, 0x104C6),
+    (0x104EF, 0x104C7),
+    (0x104F0, 0x104C8),
+    (0x104F1, 0x104C9),
+    (0x104F2, 0x104CA),
+    (0x104F3, 0x104CB),
+    (0x104F4, 0x104CC),
+    (0x104F5, 0x104CD),
+    (0x104F6, 0x104CE),
+    (0x104F7, 0x104CF),
+    (0x104F8, 0x104D0),
+    (0x104F9, 0x104D1),
+    (0x104FA, 0x104D2),
+    (0x104FB, 0x104D3),
+    (0x10CC0, 0x10C80),
+    (0x10CC1, 0x10C81),
+    (0x10CC2, 0x10C82),
+    (0x10CC3, 0x10C83),
+    (0x10CC4, 0x10C84),
+    (0x10CC5, 0x10C85),
+    (0x10CC6, 0x10C86),
+    (0x10CC7, 0x10C87),
+    (0x10CC8, 0x10C88),
+    (0x10CC9, 0x10C89),
+    (0x10CCA, 0x10C8A),
+    (0x10CCB, 0x10C8B),
+    (0x10CCC, 0x10C8C),
+    (0x10CCD, 0x10C8D),
+    (0x10CCE, 0x10C8E),
+    (0x10CCF, 0x10C8F),
+    (0x10CD0, 0x10C90),
+    (0x10CD1, 0x10C91),
+    (0x10CD2, 0x10C92),
+    (0x10CD3, 0x10C93),
+    (0x10CD4, 0x10C94),
+    (0x10CD5, 0x10C95),
+    (0x10CD6, 0x10C96),
+    (0x10CD7, 0x10C97),
+    (0x10CD8, 0x10C98),
+    (0x10CD9, 0x10C99),
+    (0x10CDA, 0x10C9A),
+    (0x10CDB, 0x10C9B),
+    (0x10CDC, 0x10C9C),
+    (0x10CDD, 0x10C9D),
+    (0x10CDE, 0x10C9E),
+    (0x10CDF, 0x10C9F),
+    (0x10CE0, 0x10CA0),
+    (0x10CE1, 0x10CA1),
+    (0x10CE2, 0x10CA2),
+    (0x10CE3, 0x10CA3),
+    (0x10CE4, 0x10CA4),
+    (0x10CE5, 0x10CA5),
+    (0x10CE6, 0x10CA6),
+    (0x10CE7, 0x10CA7),
+    (0x10CE8, 0x10CA8),
+    (0x10CE9, 0x10CA9),
+    (0x10CEA, 0x10CAA),
+    (0x10CEB, 0x10CAB),
+    (0x10CEC, 0x10CAC),
+    (0x10CED, 0x10CAD),
+    (0x10CEE, 0x10CAE),
+    (0x10CEF, 0x10CAF),
+    (0x10CF0, 0x10CB0),
+    (0x10CF1, 0x10CB1),
+    (0x10CF2, 0x10CB2),
+    (0x118C0, 0x118A0),
+    (0x118C1, 0x118A1),
+    (0x118C2, 0x118A2),
+    (0x118C3, 0x118A3),
+    (0x118C4, 0x118A4),
+    (0x118C5, 0x118A5),
+    (0x118C6, 0x118A6),
+    (0x118C7, 0x118A7),
+    (0x118C8, 0x118A8),
+    (0x118C9, 0x118A9),
+    (0x118CA, 0x118AA),
+    (0x118CB, 0x118AB),
+    (0x118CC, 0x118AC),
+    (0x118CD, 0x118AD),
+    (0x118CE, 0x118AE),
+    (0x118CF, 0x118AF),
+    (0x118D0, 0x118B0),
+    (0x118D1, 0x118B1),
+    (0x118D2, 0x118B2),
+    (0x118D3, 0x118B3),
+    (0x118D4, 0x118B4),
+    (0x118D5, 0x118B5),
+    (0x118D6, 0x118B6),
+    (0x118D7, 0x118B7),
+    (0x118D8, 0x118B8),
+    (0x118D9, 0x118B9),
+    (0x118DA, 0x118BA),
+    (0x118DB, 0x118BB),
+    (0x118DC, 0x118BC),
+    (0x118DD, 0x118BD),
+    (0x118DE, 0x118BE),
+    (0x118DF, 0x118BF),
+    (0x16E60, 0x16E40),
+    (0x16E61, 0x16E41),
+    (0x16E62, 0x16E42),
+    (0x16E63, 0x16E43),
+    (0x16E64, 0x16E44),
+    (0x16E65, 0x16E45),
+    (0x16E66, 0x16E46),
+    (0x16E67, 0x16E47),
+    (0x16E68, 0x16E48),
+    (0x16E69, 0x16E49),
+    (0x16E6A, 0x16E4A),
+    (0x16E6B, 0x16E4B),
+    (0x16E6C, 0x16E4C),
+    (0x16E6D, 0x16E4D),
+    (0x16E6E, 0x16E4E),
+    (0x16E6F, 0x16E4F),
+    (0x16E70, 0x16E50),
+    (0x16E71, 0x16E51),
+    (0x16E72, 0x16E52),
+    (0x16E73, 0x16E53),
+    (0x16E74, 0x16E54),
+    (0x16E75, 0x16E55),
+    (0x16E76, 0x16E56),
+    (0x16E77, 0x16E57),
+    (0x16E78, 0x16E58),
+    (0x16E79, 0x16E59),
+    (0x16E7A, 0x16E5A),
+    (0x16E7B, 0x16E5B),
+    (0x16E7C, 0x16E5C),
+    (0x16E7D, 0x16E5D),
+    (0x16E7E, 0x16E5E),
+    (0x16E7F, 0x16E5F),
+    (0x1E922, 0x1E900),
+    (0x1E923, 0x1E901),
+    (0x1E924, 0x1E902),
+    (0x1E925, 0x1E903),
+    (0x1E926, 0x1E904),
+    (0x1E927, 0x1E905),
+    (0x1E928, 0x1E906),
+    (0x1E929, 0x1E907),
+    (0x1E92A, 0x1E908),
+    (0x1E92B, 0x1E909),
+    (0x1E92C, 0x1E90A),
+    (0x1E92D, 0x1E90B),
+    (0x1E92E, 0x1E90C),
+    (0x1E92F, 0x1E90D),
+    (0x1E930, 0x1E90E),
+    (0x1E931, 0x1E90F),
+    (0x1E932, 0x1E910),
+    (0x1E933, 0x1E911),
+    (0x1E934, 0x1E912),
+    (0x1E935, 0x1E913),
+    (0x1E936, 0x1E914),
+    (0x1E937, 0x1E915),
+    (0x1E938, 0x1E916),
+    (0x1E939, 0x1E917),
+    (0x1E93A, 0x1E918),
+    (0x1E93B, 0x1E919),
+    (0x1E93C, 0x1E91A),
+    (0x1E93D, 0x1E91B),
+    (0x1E93E, 0x1E91C),
+    (0x1E93F, 0x1E91D),
+    (0x1E940, 0x1E91E),
+    (0x1E941, 0x1E91F),
+    (0x1E942, 0x1E920),
+    (0x1E943, 0x1E921),
+];
+
+pub(super) static LOWER_CASE: [(u32, u32); 1393] = [
+    (0x0041, 0x0061),
+    (0x0042, 0x0062),
+    (0x0043, 0x0063),
+    (0x0044, 0x0064),
+    (0x0045, 0x0065),
+    (0x0046, 0x0066),
+    (0x0047, 0x0067),
+    (0x0048, 0x0068),
+    (0x0049, 0x0069),
+    (0x004A, 0x006A),
+    (0x004B, 0x006B),
+    (0x004C, 0x006C),
+    (0x004D, 0x006D),
+    (0x004E, 0x006E),
+    (0x004F, 0x006F),
+    (0x0050, 0x0070),
+    (0x0051, 0x0071),
+    (0x0052, 0x0072),
+    (0x0053, 0x0073),
+    (0x0054, 0x0074),
+    (0x0055, 0x0075),
+    (0x0056, 0x0076),
+    (0x0057, 0x0077),
+    (0x0058, 0x0078),
+    (0x0059, 0x0079),
+    (0x005A, 0x007A),
+    (0x00C0, 0x00E0),
+    (0x00C1, 0x00E1),
+    (0x00C2, 0x00E2),
+    (0x00C3, 0x00E3),
+    (0x00C4, 0x00E4),
+    (0x00C5, 0x00E5),
+    (0x00C6, 0x00E6),
+    (0x00C7, 0x00E7),
+    (0x00C8, 0x00E8),
+    (0x00C9, 0x00E9),
+    (0x00CA, 0x00EA),
+    (0x00CB, 0x00EB),
+    (0x00CC, 0x00EC),
+    (0x00CD, 0x00ED),
+    (0x00CE, 0x00EE),
+    (0x00CF, 0x00EF),
+    (0x00D0, 0x00F0),
+    (0x00D1, 0x00F1),
+    (0x00D2, 0x00F2),
+    (0x00D3, 0x00F3),
+    (0x00D4, 0x00F4),
+    (0x00D5, 0x00F5),
+    (0x00D6, 0x00F6),
+    (0x00D8, 0x00F8),
+    (0x00D9, 0x00F9),
+    (0x00DA, 0x00FA),
+    (0x00DB, 0x00FB),
+    (0x00DC, 0x00FC),
+    (0x00DD, 0x00FD),
+    (0x00DE, 0x00FE),
+    (0x0100, 0x0101),
+    (0x0102, 0x0103),
+    (0x0104, 0x0105),
+    (0x0106, 0x0107),
+    (0x0108, 0x0109),
+    (0x010A, 0x010B),
+    (0x010C, 0x010D),
+    (0x010E, 0x010F),
+    (0x0110, 0x0111),
+    (0x0112, 0x0113),
+    (0x0114, 0x0115),
+    (0x0116, 0x0117),
+    (0x0118, 0x0119),
+    (0x011A, 0x011B),
+    (0x011C, 0x011D),
+    (0x011E, 0x011F),
+    (0x0120, 0x0121),
+    (0x0122, 0x0123),
+    (0x0124, 0x0125),
+    (0x0126, 0x0127),
+    (0x0128, 0x0129),
+    (0x012A, 0x012B),
+    (0x012C, 0x012D),
+    (0x012E, 0x012F),
+    (0x0130, 0x0069),
+    (0x0132, 0x0133),
+    (0x0134, 0x0135),
+    (0x0136, 0x0137),
+    (0x0139, 0x013A),
+    (0x013B, 0x013C),
+    (0x013D, 0x013E),
+    (0x013F, 0x0140),
+    (0x0141, 0x0142),
+    (0x0143, 0x0144),
+    (0x0145, 0x0146),
+    (0x0147, 0x0148),
+    (0x014A, 0x014B),
+    (0x014C, 0x014D),
+    (0x014E, 0x014F),
+    (0x0150, 0x0151),
+    (0x0152, 0x0153),
+    (0x0154, 0x0155),
+    (0x0156, 0x0157),
+    (0x0158, 0x0159),
+    (0x015A, 0x015B),
+    (0x015C, 0x015D),
+    (0x015E, 0x015F),
+    (0x0160, 0x0161),
+    (0x0162, 0x0163),
+    (0x0164, 0x0165),
+    (0x0166, 0x0167),
+    (0x0168, 0x0169),
+    (0x016A, 0x016B),
+    (0x016C, 0x016D),
+    (0x016E, 0x016F),
+    (0x0170, 0x0171),
+    (0x0172, 0x0173),
+    (0x0174, 0x0175),
+    (0x0176, 0x0177),
+    (0x0178, 0x00FF),
+    (0x0179, 0x017A),
+    (0x017B, 0x017C),
+    (0x017D, 0x017E),
+    (0x0181, 0x0253),
+    (0x0182, 0x0183),
+    (0x0184, 0x0185),
+    (0x0186, 0x0254),
+    (0x0187, 0x0188),
+    (0x0189, 0x0256),
+    (0x018A, 0x0257),
+    (0x018B, 0x018C),
+    (0x018E, 0x01DD),
+    (0x018F, 0x0259),
+    (0x0190, 0x025B),
+    (0x0191, 0x0192),
+    (0x0193, 0x0260),
+    (0x0194, 0x0263),
+    (0x0196, 0x0269),
+    (0x0197, 0x0268),
+    (0x0198, 0x0199),
+    (0x019C, 0x026F),
+    (0x019D, 0x0272),
+    (0x019F, 0x0275),
+    (0x01A0, 0x01A1),
+    (0x01A2, 0x01A3),
+    (0x01A4, 0x01A5),
+    (0x01A6, 0x0280),
+    (0x01A7, 0x01A8),
+    (0x01A9, 0x0283),
+    (0x01AC, 0x01AD),
+    (0x01AE, 0x0288),
+    (0x01AF, 0x01B0),
+    (0x01B1, 0x028A),
+    (0x01B2, 0x028B),
+    (0x01B3, 0x01B4),
+    (0x01B5, 0x01B6),
+    (0x01B7, 0x0292),
+    (0x01B8, 0x01B9),
+    (0x01BC, 0x01BD),
+    (0x01C4, 0x01C6),
+    (0x01C5, 0x01C6),
+    (0x01C7, 0x01C9),
+    (0x01C8, 0x01C9),
+    (0x01CA, 0x01CC),
+    (0x01CB, 0x01CC),
+    (0x01CD, 0x01CE),
+    (0x01CF, 0x01D0),
+    (0x01D1, 0x01D2),
+    (0x01D3, 0x01D4),
+    (0x01D5, 0x01D6),
+    (0x01D7, 0x01D8),
+    (0x01D9, 0x01DA),
+    (0x01DB, 0x01DC),
+    (0x01DE, 0x01DF),
+    (0x01E0, 0x01E1),
+    (0x01E2, 0x01E3),
+    (0x01E4, 0x01E5),
+    (0x01E6, 0x01E7),
+    (0x01E8, 0x01E9),
+    (0x01EA, 0x01EB),
+    (0x01EC, 0x01ED),
+    (0x01EE, 0x01EF),
+    (0x01F1, 0x01F3),
+    (0x01F2, 0x01F3),
+    (0x01F4, 0x01F5),
+    (0x01F6, 0x0195),
+    (0x01F7, 0x01BF),
+    (0x01F8, 0x01F9),
+    (0x01FA, 0x01FB),
+    (0x01FC, 0x01FD),
+    (0x01FE, 0x01FF),
+    (0x0200, 0x0201),
+    (0x0202, 0x0203),
+    (0x0204, 0x0205),
+    (0x0206, 0x0207),
+    (0x0208, 0x0209),
+    (0x020A, 0x020B),
+    (0x020C, 0x020D),
+    (0x020E, 0x020F),
+    (0x0210, 0x0211),
+    (0x0212, 0x0213),
+    (0x0214, 0x0215),
+    (0x0216, 0x0217),
+    (0x0218, 0x0219),
+    (0x021A, 0x021B),
+    (0x021C, 0x021D),
+    (0x021E, 0x021F),
+    (0x0220, 0x019E),
+    (0x0222, 0x0223),
+    (0x0224, 0x0225),
+    (0x0226, 0x0227),
+    (0x0228, 0x0229),
+    (0x022A, 0x022B),
+    (0x022C, 0x022D),
+    (0x022E, 0x022F),
+    (0x0230, 0x0231),
+    (0x0232, 0x0233),
+    (0x023A, 0x2C65),
+    (0x023B, 0x023C),
+    (0x023D, 0x019A),
+    (0x023E, 0x2C66),
+    (0x0241, 0x0242),
+    (0x0243, 0x0180),
+    (0x0244, 0x0289),
+    (0x0245, 0x028C),
+    (0x0246, 0x0247),
+    (0x0248, 0x0249),
+    (0x024A, 0x024B),
+    (0x024C, 0x024D),
+    (0x024E, 0x024F),
+    (0x0370, 0x0371),
+    (0x0372, 0x0373),
+    (0x0376, 0x0377),
+    (0x037F, 0x03F3),
+    (0x0386, 0x03AC),
+    (0x0388, 0x03AD),
+    (0x0389, 0x03AE),
+    (0x038A, 0x03AF),
+    (0x038C, 0x03CC),
+    (0x038E, 0x03CD),
+    (0x038F, 0x03CE),
+    (0x0391, 0x03B1),
+    (0x0392, 0x03B2),
+    (0x0393, 0x03B3),
+    (0x0394, 0x03B4),
+    (0x0395, 0x03B5),
+    (0x0396, 0x03B6),
+    (0x0397, 0x03B7),
+    (0x0398, 0x03B8),
+    (0x0399, 0x03B9),
+    (0x039A, 0x03BA),
+    (0x039B, 0x03BB),
+    (0x039C, 0x03BC),
+    (0x039D, 0x03BD),
+    (0x039E, 0x03BE),
+    (0x039F, 0x03BF),
+    (0x03A0, 0x03C0),
+    (0x03A1, 0x03C1),
+    (0x03A3, 0x03C3),
+    (0x03A4, 0x03C4),
+    (0x03A5, 0x03C5),
+    (0x03A6, 0x03C6),
+    (0x03A7, 0x03C7),
+    (0x03A8, 0x03C8),
+    (0x03A9, 0x03C9),
+    (0x03AA, 0x03CA),
+    (0x03AB, 0x03CB),
+    (0x03CF, 0x03D7),
+    (0x03D8, 0x03D9),
+    (0x03DA, 0x03DB),
+    (0x03DC, 0x03DD),
+    (0x03DE, 0x03DF),
+    (0x03E0, 0x03E1),
+    (0x03E2, 0x03E3),
+    (0x03E4, 0x03E5),
+    (0x03E6, 0x03E7),
+    (0x03E8, 0x03E9),
+    (0x03EA, 0x03EB),
+    (0x03EC, 0x03ED),
+    (0x03EE, 0x03EF),
+    (0x03F4, 0x03B8),
+    (0x03F7, 0x03F8),
+    (0x03F9, 0x03F2),
+    (0x03FA, 0x03FB),
+    (0x03FD, 0x037B),
+    (0x03FE, 0x037C),
+    (0x03FF, 0x037D),
+    (0x0400, 0x0450),
+    (0x0401, 0x0451),
+    (0x0402, 0x0452),
+    (0x0403, 0x0453),
+    (0x0404, 0x0454),
+    (0x0405, 0x0455),
+    (0x0406, 0x0456),
+    (0x0407, 0x0457),
+    (0x0408, 0x0458),
+    (0x0409, 0x0459),
+    (0x040A, 0x045A),
+    (0x040B, 0x045B),
+    (0x040C, 0x045C),
+    (0x040D, 0x045D),
+    (0x040E, 0x045E),
+    (0x040F, 0x045F),
+    (0x0410, 0x0430),
+    (0x0411, 0x0431),
+    (0x0412, 0x0432),
+    (0x0413, 0x0433),
+    (0x0414, 0x0434),
+    (0x0415, 0x0435),
+    (0x0416, 0x0436),
+    (0x0417, 0x0437),
+    (0x0418, 0x0438),
+    (0x0419, 0x0439),
+    (0x041A, 0x043A),
+    (0x041B, 0x043B),
+    (0x041C, 0x043C),
+    (0x041D, 0x043D),
+    (0x041E, 0x043E),
+    (0x041F, 0x043F),
+    (0x0420, 0x0440),
+    (0x0421, 0x0441),
+    (0x0422, 0x0442),
+    (0x0423, 0x0443),
+    (0x0424, 0x0444),
+    (0x0425, 0x0445),
+    (0x0426, 0x0446),
+    (0x0427, 0x0447),
+    (0x0428, 0x0448),
+    (0x0429, 0x0449),
+    (0x042A, 0x044A),
+    (0x042B, 0x044B),
+    (0x042C, 0x044C),
+    (0x042D, 0x044D),
+    (0x042E, 0x044E),
+    (0x042F, 0x044F),
+    (0x0460, 0x0461),
+    (0x0462, 0x0463),
+    (0x0464, 0x0465),
+    (0x0466, 0x0467),
+    (0x0468, 0x0469),
+    (0x046A, 0x046B),
+    (0x046C, 0x046D),
+    (0x046E, 0x046F),
+    (0x0470, 0x0471),
+    (0x0472, 0x0473),
+    (0x0474, 0x0475),
+    (0x0476, 0x0477),
+    (0x0478, 0x0479),
+    (0x047A, 0x047B),
+    (0x047C, 0x047D),
+    (0x047E, 0x047F),
+    (0x0480, 0x0481),
+    (0x048A, 0x048B),
+    (0x048C, 0x048D),
+    (0x048E, 0x048F),
+    (0x0490, 0x0491),
+    (0x0492, 0x0493),
+    (0x0494, 0x0495),
+    (0x0496, 0x0497),
+    (0x0498, 0x0499),
+    (0x049A, 0x049B),
+    (0x049C, 0x049D),
+    (0x049E, 0x049F),
+    (0x04A0, 0x04A1),
+    (0x04A2, 0x04A3),
+    (0x04A4, 0x04A5),
+    (0x04A6, 0x04A7),
+    (0x04A8, 0x04A9),
+    (0x04AA, 0x04AB),
+    (0x04AC, 0x04AD),
+    (0x04AE, 0x04AF),
+    (0x04B0, 0x04B1),
+    (0x04B2, 0x04B3),
+    (0x04B4, 0x04B5),
+    (0x04B6, 0x04B7),
+    (0x04B8, 0x04B9),
+    (0x04BA, 0x04BB),
+    (0x04BC, 0x04BD),
+    (0x04BE, 0x04BF),
+    (0x04C0, 0x04CF),
+    (0x04C1, 0x04C2),
+    (0x04C3, 0x04C4),
+    (0x04C5, 0x04C6),
+    (0x04C7, 0x04C8),
+    (0x04C9, 0x04CA),
+    (0x04CB, 0x04CC),
+    (0x04CD, 0x04CE),
+    (0x04D0, 0x04D1),
+    (0x04D2, 0x04D3),
+    (0x04D4, 0x04D5),
+    (0x04D6, 0x04D7),
+    (0x04D8, 0x04D9),
+    (0x04DA, 0x04DB),
+    (0x04DC, 0x04DD),
+    (0x04DE, 0x04DF),
+    (0x04E0, 0x04E1),
+    (0x04E2, 0x04E3),
+    (0x04E4, 0x04E5),
+    (0x04E6, 0x04E7),
+    (0x04E8, 0x04E9),
+    (0x04EA, 0x04EB),
+    (0x04EC, 0x04ED),
+    (0x04EE, 0x04EF),
+    (0x04F0, 0x04F1),
+    (0x04F2, 0x04F3),
+    (0x04F4, 0x04F5),
+    (0x04F6, 0x04F7),
+    (0x04F8, 0x04F9),
+    (0x04FA, 0x04FB),
+    (0x04FC, 0x04FD),
+    (0x04FE, 0x04FF),
+    (0x0500, 0x0501),
+    (0x0502, 0x0503),
+    (0x0504, 0x0505),
+    (0x0506, 0x0507),
+    (0x0508, 0x0509),
+    (0x050A, 0x050B),
+    (0x050C, 0x050D),
+    (0x050E, 0x050F),
+    (0x0510, 0x0511),
+    (0x0512, 0x0513),
+    (0x0514, 0x0515),
+    (0x0516, 0x0517),
+    (0x0518, 0x0519),
+    (0x051A, 0x051B),
+    (0x051C, 0x051D),
+    (0x051E, 0x051F),
+    (0x0520, 0x0521),
+    (0x0522, 0x0523),
+    (0x0524, 0x0525),
+    (0x0526, 0x0527),
+    (0x0528, 0x0529),
+    (0x052A, 0x052B),
+    (0x052C, 0x052D),
+    (0x052E, 0x052F),
+    (0x0531, 0x0561),
+    (0x0532, 0x0562),
+    (0x0533, 0x0563),
+    (0x0534, 0x0564),
+    (0x0535, 0x0565),
+    (0x0536, 0x0566),
+    (0x0537, 0x0567),
+    (0x0538, 0x0568),
+    (0x0539, 0x0569),
+    (0x053A, 0x056A),
+    (0x053B, 0x056B),
+    (0x053C, 0x056C),
+    (0x053D, 0x056D),
+    (0x053E, 0x056E),
+    (0x053F, 0x056F),
+    (0x0540, 0x0570),
+    (0x0541, 0x0571),
+    (0x0542, 0x0572),
+    (0x0543, 0x0573),
+    (0x0544, 0x0574),
+    (0x0545, 0x0575),
+    (0x0546, 0x0576),
+    (0x0547, 0x0577),
+    (0x0548, 0x0578),
+    (0x0549, 0x0579),
+    (0x054A, 0x057A),
+    (0x054B, 0x057B),
+    (0x054C, 0x057C),
+    (0x054D, 0x057D),
+    (0x054E, 0x057E),
+    (0x054F, 0x057F),
+    (0x0550, 0x0580),
+    (0x0551, 0x0581),
+    (0x0552, 0x0582),
+    (0x0553, 0x0583),
+    (0x0554, 0x0584),
+    (0x0555, 0x0585),
+    (0x0556, 0x0586),
+    (0x10A0, 0x2D00),
+    (0x10A1, 0x2D01),
+    (0x10A2, 0x2D02),
+    (0x10A3, 0x2D03),
+    (0x10A4, 0x2D04),
+    (0x10A5, 0x2D05),
+    (0x10A6, 0x2D06),
+    (0x10A7, 0x2D07),
+    (0x10A8, 0x2D08),
+    (0x10A9, 0x2D09),
+    (0x10AA, 0x2D0A),
+    (0x10AB, 0x2D0B),
+    (0x10AC, 0x2D0C),
+    (0x10AD, 0x2D0D),
+    (0x10AE, 0x2D0E),
+    (0x10AF, 0x2D0F),
+    (0x10B0, 0x2D10),
+    (0x10B1, 0x2D11),
+    (0x10B2, 0x2D12),
+    (0x10B3, 0x2D13),
+    (0x10B4, 0x2D14),
+    (0x10B5, 0x2D15),
+    (0x10B6, 0x2D16),
+    (0x10B7, 0x2D17),
+    (0x10B8, 0x2D18),
+    (0x10B9, 0x2D19),
+    (0x10BA, 0x2D1A),
+    (0x10BB, 0x2D1B),
+    (0x10BC, 0x2D1C),
+    (0x10BD, 0x2D1D),
+    (0x10BE, 0x2D1E),
+    (0x10BF, 0x2D1F),
+    (0x10C0, 0x2D20),
+    (0x10C1, 0x2D21),
+    (0x10C2, 0x2D22),
+    (0x10C3, 0x2D23),
+    (0x10C4, 0x2D24),
+    (0x10C5, 0x2D25),
+    (0x10C7, 0x2D27),
+    (0x10CD, 0x2D2D),
+    (0x13A0, 0xAB70),
+    (0x13A1, 0xAB71),
+    (0x13A2, 0xAB72),
+    (0x13A3, 0xAB73),
+    (0x13A4, 0xAB74),
+    (0x13A5, 0xAB75),
+    (0x13A6, 0xAB76),
+    (0x13A7, 0xAB77),
+    (0x13A8, 0xAB78),
+    (0x13A9, 0xAB79),
+    (0x13AA, 0xAB7A),
+    (0x13AB, 0xAB7B),
+    (0x13AC, 0xAB7C),
+    (0x13AD, 0xAB7D),
+    (0x13AE, 0xAB7E),
+    (0x13AF, 0xAB7F),
+    (0x13B0, 0xAB80),
+    (0x13B1, 0xAB81),
+    (0x13B2, 0xAB82),
+    (0x13B3, 0xAB83),
+    (0x13B4, 0xAB84),
+    (0x13B5, 0xAB85),
+    (0x13B6, 0xAB86),
+    (0x13B7, 0xAB87),
+    (0x13B8, 0xAB88),
+    (0x13B9, 0xAB89),
+    (0x13BA, 0xAB8A),
+    (0x13BB, 0xAB8B),
+    (0x13BC, 0xAB8C),
+    (0x13BD, 0xAB8D),
+    (0x13BE, 0xAB8E),
+    (0x13BF, 0xAB8F),
+    (0x13C0, 0xAB90),
+    (0x13C1, 0xAB91),
+    (0x13C2, 0xAB92),
+    (0x13C3, 0xAB93),
+    (0x13C4, 0xAB94),
+    (0x13C5, 0xAB95),
+    (0x13C6, 0xAB96),
+    (0x13C7, 0xAB97),
+    (0x13C8, 0xAB98),
+    (0x13C9, 0xAB99),
+    (0x13CA, 0xAB9A),
+    (0x13CB, 0xAB9B),
+    (0x13CC, 0xAB9C),
+    (0x13CD, 0xAB9D),
+    (0x13CE, 0xAB9E),
+    (0x13CF, 0xAB9F),
+    (0x13D0, 0xABA0),
+    (0x13D1, 0xABA1),
+    (0x13D2, 0xABA2),
+    (0x13D3, 0xABA3),
+    (0x13D4, 0xABA4),
+    (0x13D5, 0xABA5),
+    (0x13D6, 0xABA6),
+    (0x13D7, 0xABA7),
+    (0x13D8, 0xABA8),
+    (0x13D9, 0xABA9),
+    (0x13DA, 0xABAA),
+    (0x13DB, 0xABAB),
+    (0x13DC, 0xABAC),
+    (0x13DD, 0xABAD),
+    (0x13DE, 0xABAE),
+    (0x13DF, 0xABAF),
+    (0x13E0, 0xABB0),
+    (0x13E1, 0xABB1),
+    (0x13E2, 0xABB2),
+    (0x13E3, 0xABB3),
+    (0x13E4, 0xABB4),
+    (0x13E5, 0xABB5),
+    (0x13E6, 0xABB6),
+    (0x13E7, 0xABB7),
+    (0x13E8, 0xABB8),
+    (0x13E9, 0xABB9),
+    (0x13EA, 0xABBA),
+    (0x13EB, 0xABBB),
+    (0x13EC, 0xABBC),
+    (0x13ED, 0xABBD),
+    (0x13EE, 0xABBE),
+    (0x13EF, 0xABBF),
+    (0x13F0, 0x13F8),
+    (0x13F1, 0x13F9),
+    (0x13F2, 0x13FA),
+    (0x13F3, 0x13FB),
+    (0x13F4, 0x13FC),
+    (0x13F5, 0x13FD),
+    (0x1C90, 0x10D0),
+    (0x1C91, 0x10D1),
+    (0x1C92, 0x10D2),
+    (0x1C93, 0x10D3),
+    (0x1C94, 0x10D4),
+    (0x1C95, 0x10D5),
+    (0x1C96, 0x10D6),
+    (0x1C97, 0x10D7),
+    (0x1C98, 0x10D8),
+    (0x1C99, 0x10D9),
+    (0x1C9A, 0x10DA),
+    (0x1C9B, 0x10DB),
+    (0x1C9C, 0x10DC),
+    (0x1C9D, 0x10DD),
+    (0x1C9E, 0x10DE),
+    (0x1C9F, 0x10DF),
+    (0x1CA0, 0x10E0),
+    (0x1CA1, 0x10E1),
+    (0x1CA2, 0x10E2),
+    (0x1CA3, 0x10E3),
+    (0x1CA4, 0x10E4),
+    (0x1CA5, 0x10E5),
+    (0x1CA6, 0x10E6),
+    (0x1CA7, 0x10E7),
+    (0x1CA8, 0x10E8),
+    (0x1CA9, 0x10E9),
+    (0x1CAA, 0x10EA),
+    (0x1CAB, 0x10EB),
+    (0x1CAC, 0x10EC),
+    (0x1CAD, 0x10ED),
+    (0x1CAE, 0x10EE),
+    (0x1CAF, 0x10EF),
+    (0x1CB0, 0x10F0),
+    (0x1CB1, 0x10F1),
+    (0x1CB2, 0x10F2),
+    (0x1CB3, 0x10F3),
+    (0x1CB4, 0x10F4),
+    (0x1CB5, 0x10F5),
+    (0x1CB6, 0x10F6),
+    (0x1CB7, 0x10F7),
+    (0x1CB8, 0x10F8),
+    (0x1CB9, 0x10F9),
+    (0x1CBA, 0x10FA),
+    (0x1CBD, 0x10FD),
+    (0x1CBE, 0x10FE),
+    (0x1CBF, 0x10FF),
+    (0x1E00, 0x1E01),
+    (0x1E02, 0x1E03),
+    (0x1E04, 0x1E05),
+    (0x1E06, 0x1E07),
+    (0x1E08, 0x1E09),
+    (0x1E0A, 0x1E0B),
+    (0x1E0C, 0x1E0D),
+    (0x1E0E, 0x1E0F),
+    (0x1E10, 0x1E11),
+    (0x1E12, 0x1E13),
+    (0x1E14, 0x1E15),
+    (0x1E16, 0x1E17),
+    (0x1E18, 0x1E19),
+    (0x1E1A, 0x1E1B),
+    (0x1E1C, 0x1E1D),
+    (0x1E1E, 0x1E1F),
+    (0x1E20, 0x1E21),
+    (0x1E22, 0x1E23),
+    (0x1E24, 0x1E25),
+    (0x1E26, 0x1E27),
+    (0x1E28, 0x1E29),
+    (0x1E2A, 0x1E2B),
+    (0x1E2C, 0x1E2D),
+    (0x1E2E, 0x1E2F),
+    (0x1E30, 0x1E31),
+    (0x1E32, 0x1E33),
+    (0x1E34, 0x1E35),
+    (0x1E36, 0x1E37),
+    (0x1E38, 0x1E39),
+    (0x1E3A, 0x1E3B),
+    (0x1E3C, 0x1E3D),
+    (0x1E3E, 0x1E3F),
+    (0x1E40, 0x1E41),
+    (0x1E42, 0x1E43),
+    (0x1E44, 0x1E45),
+    (0x1E46, 0x1E47),
+    (0x1E48, 0x1E49),
+    (0x1E4A, 0x1E4B),
+    (0x1E4C, 0x1E4D),
+    (0x1E4E, 0x1E4F),
+    (0x1E50, 0x1E51),
+    (0x1E52, 0x1E53),
+    (0x1E54, 0x1E55),
+    (0x1E56, 0x1E57),
+    (0x1E58, 0x1E59),
+    (0x1E5A, 0x1E5B),
+    (0x1E5C, 0x1E5D),
+    (0x1E5E, 0x1E5F),
+    (0x1E60, 0x1E61),
+    (0x1E62, 0x1E63),
+    (0x1E64, 0x1E65),
+    (0x1E66, 0x1E67),
+    (0x1E68, 0x1E69),
+    (0x1E6A, 0x1E6B),
+    (0x1E6C, 0x1E6D),
+    (0x1E6E, 0x1E6F),
+    (0x1E70, 0x1E71),
+    (0x1E72, 0x1E73),
+    (0x1E74, 0x1E75),
+    (0x1E76, 0x1E77),
+    (0x1E78, 0x1E79),
+    (0x1E7A, 0x1E7B),
+    (0x1E7C, 0x1E7D),
+    (0x1E7E, 0x1E7F),
+    (0x1E80, 0x1E81),
+    (0x1E82, 0x1E83),
+    (0x1E84, 0x1E85),
+    (0x1E86, 0x1E87),
+    (0x1E88, 0x1E89),
+    (0x1E8A, 0x1E8B),
+    (0x1E8C, 0x1E8D),
+    (0x1E8E, 0x1E8F),
+    (0x1E90, 0x1E91),
+    (0x1E92, 0x1E93),
+    (0x1E94, 0x1E95),
+    (0x1E9E, 0x00DF),
+    (0x1EA0, 0x1EA1),
+    (0x1EA2, 0x1EA3),
+    (0x1EA4, 0x1EA5),
+    (0x1EA6, 0x1EA7),
+    (0x1EA8, 0x1EA9),
+    (0x1EAA, 0x1EAB),
+    (0x1EAC, 0x1EAD),
+    (0x1EAE, 0x1EAF),
+    (0x1EB0, 0x1EB1),
+    (0x1EB2, 0x1EB3),
+    (0x1EB4, 0x1EB5),
+    (0x1EB6, 0x1EB7),
+    (0x1EB8, 0x1EB9),
+    (0x1EBA, 0x1EBB),
+    (0x1EBC, 0x1EBD),
+    (0x1EBE, 0x1EBF),
+    (0x1EC0, 0x1EC1),
+    (0x1EC2, 0x1EC3),
+    (0x1EC4, 0x1EC5),
+    (0x1EC6, 0x1EC7),
+    (0x1EC8, 0x1EC9),
+    (0x1ECA, 0x1ECB),
+    (0x1ECC, 0x1ECD),
+    (0x1ECE, 0x1ECF),
+    (0x1ED0, 0x1ED1),
+    (0x1ED2, 0x1ED3),
+    (0x1ED4, 0x1ED5),
+    (0x1ED6, 0x1ED7),
+    (0x1ED8, 0x1ED9),
+    (0x1EDA, 0x1EDB),
+    (0x1EDC, 0x1EDD),
+    (0x1EDE, 0x1EDF),
+    (0x1EE0, 0x1EE1),
+    (0x1EE2, 0x1EE3),
+    (0x1EE4, 0x1EE5),
+    (0x1EE6, 0x1EE7),
+    (0x1EE8, 0x1EE9),
+    (0x1EEA, 0x1EEB),
+    (0x1EEC, 0x1EED),
+    (0x1EEE, 0x1EEF),
+    (0x1EF0, 0x1EF1),
+    (0x1EF2, 0x1EF3),
+    (0x1EF4, 0x1EF5),
+    (0x1EF6, 0x1EF7),
+    (0x1EF8, 0x1EF9),
+    (0x1EFA, 0x1EFB),
+    (0x1EFC, 0x1EFD),
+    (0x1EFE, 0x1EFF),
+    (0x1F08, 0x1F00),
+    (0x1F09, 0x1F01),
+    (0x1F0A, 0x1F02),
+    (0x1F0B, 0x1F03),
+    (0x1F0C, 0x1F04),
+    (0x1F0D, 0x1F05),
+    (0x1F0E, 0x1F06),
+    (0x1F0F, 0x1F07),
+    (0x1F18, 0x1F10),
+    (0x1F19, 0x1F11),
+    (0x1F1A, 0x1F12),
+    (0x1F1B, 0x1F13),
+    (0x1F1C, 0x1F14),
+    (0x1F1D, 0x1F15),
+    (0x1F28, 0x1F20),
+    (0x1F29, 0x1F21),
+    (0x1F2A, 0x1F22),
+    (0x1F2B, 0x1F23),
+    (0x1F2C, 0x1F24),
+    (0x1F2D, 0x1F25),
+    (0x1F2E, 0x1F26),
+    (0x1F2F, 0x1F27),
+    (0x1F38, 0x1F30),
+    (0x1F39, 0x1F31),
+    (0x1F3A, 0x1F32),
+    (0x1F3B, 0x1F33),
+    (0x1F3C, 0x1F34),
+    (0x1F3D, 0x1F35),
+    (0x1F3E, 0x1F36),
+    (0x1F3F, 0x1F37),
+    (0x1F48, 0x1F40),
+    (0x1F49, 0x1F41),
+    (0x1F4A, 0x1F42),
+    (0x1F4B, 0x1F43),
+    (0x1F4C, 0x1F44),
+    (0x1F4D, 0x1F45),
+    (0x1F59, 0x1F51),
+    (0x1F5B, 0x1F53),
+    (0x1F5D, 0x1F55),
+    (0x1F5F, 0x1F57),
+    (0x1F68, 0x1F60),
+    (0x1F69, 0x1F61),
+    (0x1F6A, 0x1F62),
+    (0x1F6B, 0x1F63),
+    (0x1F6C, 0x1F64),
+    (0x1F6D, 0x1F65),
+    (0x1F6E, 0x1F66),
+    (0x1F6F, 0x1F67),
+    (0x1F88, 0x1F80),
+    (0x1F89, 0x1F81),
+    (0x1F8A, 0x1F82),
+    (0x1F8B, 0x1F83),
+    (0x1F8C, 0x1F84),
+    (0x1F8D, 0x1F85),
+    (0x1F8E, 0x1F86),
+    (0x1F8F, 0x1F87),
+    (0x1F98, 0x1F90),
+    (0x1F99, 0x1F91),
+    (0x1F9A, 0x1F92),
+    (0x1F9B, 0x1F93),
+    (0x1F9C, 0x1F94),
+    (0x1F9D, 0x1F95),
+    (0x1F9E, 0x1F96),
+    (0x1F9F, 0x1F97),
+    (0x1FA8, 0x1FA0),
+    (0x1FA9, 0x1FA1),
+    (0x1FAA, 0x1FA2),
+    (0x1FAB, 0x1FA3),
+    (0x1FAC, 0x1FA4),
+    (0x1FAD, 0x1FA5),
+    (0x1FAE, 0x1FA6),
+    (0x1FAF, 0x1FA7),
+    (0x1FB8, 0x1FB0),
+    (0x1FB9, 0x1FB1),
+    (0x1FBA, 0x1F70),
+    (0x1FBB, 0x1F71),
+    (0x1FBC, 0x1FB3),
+    (0x1FC8, 0x1F72),
+    (0x1FC9, 0x1F73),
+    (0x1FCA, 0x1F74),
+    (0x1FCB, 0x1F75),
+    (0x1FCC, 0x1FC3),
+    (0x1FD8, 0x1FD0),
+    (0x1FD9, 0x1FD1),
+    (0x1FDA, 0x1F76),
+    (0x1FDB, 0x1F77),
+    (0x1FE8, 0x1FE0),
+    (0x1FE9, 0x1FE1),
+    (0x1FEA, 0x1F7A),
+    (0x1FEB, 0x1F7B),
+    (0x1FEC, 0x1FE5),
+    (0x1FF8, 0x1F78),
+    (0x1FF9, 0x1F79),
+    (0x1FFA, 0x1F7C),
+    (0x1FFB, 0x1F7D),
+    (0x1FFC, 0x1FF3),
+    (0x2126, 0x03C9),
+    (0x212A, 0x006B),
+    (0x212B, 0x00E5),
+    (0x2132, 0x214E),
+    (0x2160, 0x2170),
+    (0x2161, 0x2171),
+    (0x2162, 0x2172),
+    (0x2163, 0x2173),
+    (0x2164, 0x2174),
+    (0x2165, 0x2175),
+    (0x2166, 0x2176),
+    (0x2167, 0x2177),
+    (0x2168, 0x2178),
+    (0x2169, 0x2179),
+    (0x216A, 0x217A),
+    (0x216B, 0x217B),
+    (0x216C, 0x217C),
+    (0x216D, 0x217D),
+    (0x216E, 0x217E),
+    (0x216F, 0x217F),
+    (0x2183, 0x2184),
+    (0x24B6, 0x24D0),
+    (0x24B7, 0x24D1),
+    (0x24B8, 0x24D2),
+    (0x24B9, 0x24D3),
+    (0x24BA, 0x24D4),
+    (0x24BB, 0x24D5),
+    (0x24BC, 0x24D6),
+    (0x24BD, 0x24D7),
+    (0x24BE, 0x24D8),
+    (0x24BF, 0x24D9),
+    (0x24C0, 0x24DA),
+    (0x24C1, 0x24DB),
+    (0x24C2, 0x24DC),
+    (0x24C3, 0x24DD),
+    (0x24C4, 0x24DE),
+    (0x24C5, 0x24DF),
+    (0x24C6, 0x24E0),
+    (0x24C7, 0x24E1),
+    (0x24C8, 0x24E2),
+    (0x24C9, 0x24E3),
+    (0x24CA, 0x24E4),
+    (0x24CB, 0x24E5),
+    (0x24CC, 0x24E6),
+    (0x24CD, 0x24E7),
+    (0x24CE, 0x24E8),
+    (0x24CF, 0x24E9),
+    (0x2C00, 0x2C30),
+    (0x2C01, 0x2C31),
+    (0x2C02, 0x2C32),
+    (0x2C03, 0x2C33),
+    (0x2C04, 0x2C34),
+    (0x2C05, 0x2C35),
+    (0x2C06, 0x2C36),
+    (0x2C07, 0x2C37),
+    (0x2C08, 0x2C38),
+    (0x2C09, 0x2C39),
+    (0x2C0A, 0x2C3A),
+    (0x2C0B, 0x2C3B),
+    (0x2C0C, 0x2C3C),
+    (0x2C0D, 0x2C3D),
+    (0x2C0E, 0x2C3E),
+    (0x2C0F, 0x2C3F),
+    (0x2C10, 0x2C40),
+    (0x2C11, 0x2C41),
+    (0x2C12, 0x2C42),
+    (0x2C13, 0x2C43),
+    (0x2C14, 0x2C44),
+    (0x2C15, 0x2C45),
+    (0x2C16, 0x2C46),
+    (0x2C17, 0x2C47),
+    (0x2C18, 0x2C48),
+    (0x2C19, 0x2C49),
+    (0x2C1A, 0x2C4A),
+    (0x2C1B, 0x2C4B),
+    (0x2C1C, 0x2C4C),
+    (0x2C1D, 0x2C4D),
+    (0x2C1E, 0x2C4E),
+    (0x2C1F, 0x2C4F),
+    (0x2C20, 0x2C50),
+    (0x2C21, 0x2C51),
+    (0x2C22, 0x2C52),
+    (0x2C23, 0x2C53),
+    (0x2C24, 0x2C54),
+    (0x2C25, 0x2C55),
+    (0x2C26, 0x2C56),
+    (0x2C27, 0x2C57),
+    (0x2C28, 0x2C58),
+    (0x2C29, 0x2C59),
+    (0x2C2A, 0x2C5A),
+    (0x2C2B, 0x2C5B),
+    (0x2C2C, 0x2C5C),
+    (0x2C2D, 0x2C5D),
+    (0x2C2E, 0x2C5E),
+    (0x2C60, 0x2C61),
+    (0x2C62, 0x026B),
+    (0x2C63, 0x1D7D),
+    (0x2C64, 0x027D),
+    (0x2C67, 0x2C68),
+    (0x2C69, 0x2C6A),
+    (0x2C6B, 0x2C6C),
+    (0x2C6D, 0x0251),
+    (0x2C6E, 0x0271),
+    (0x2C6F, 0x0250),
+    (0x2C70, 0x0252),
+    (0x2C72, 0x2C73),
+    (0x2C75, 0x2C76),
+    (0x2C7E, 0x023F),
+    (0x2C7F, 0x0240),
+    (0x2C80, 0x2C81),
+    (0x2C82, 0x2C83),
+    (0x2C84, 0x2C85),
+    (0x2C86, 0x2C87),
+    (0x2C88, 0x2C89),
+    (0x2C8A, 0x2C8B),
+    (0x2C8C, 0x2C8D),
+    (0x2C8E, 0x2C8F),
+    (0x2C90, 0x2C91),
+    (0x2C92, 0x2C93),
+    (0x2C94, 0x2C95),
+    (0x2C96, 0x2C97),
+    (0x2C98, 0x2C99),
+    (0x2C9A, 0x2C9B),
+    (0x2C9C, 0x2C9D),
+    (0x2C9E, 0x2C9F),
+    (0x2CA0, 0x2CA1),
+    (0x2CA2, 0x2CA3),
+    (0x2CA4, 0x2CA5),
+    (0x2CA6, 0x2CA7),
+    (0x2CA8, 0x2CA9),
+    (0x2CAA, 0x2CAB),
+    (0x2CAC, 0x2CAD),
+    (0x2CAE, 0x2CAF),
+    (0x2CB0, 0x2CB1),
+    (0x2CB2, 0x2CB3),
+    (0x2CB4, 0x2CB5),
+    (0x2CB6, 0x2CB7),
+    (0x2CB8, 0x2CB9),
+    (0x2CBA, 0x2CBB),
+    (0x2CBC, 0x2CBD),
+    (0x2CBE, 0x2CBF),
+    (0x2CC0, 0x2CC1),
+    (0x2CC2, 0x2CC3),
+    (0x2CC4, 0x2CC5),
+    (0x2CC6, 0x2CC7),
+    (0x2CC8, 0x2CC9),
+    (0x2CCA, 0x2CCB),
+    (0x2CCC, 0x2CCD),
+    (0x2CCE, 0x2CCF),
+    (0x2CD0, 0x2CD1),
+    (0x2CD2, 0x2CD3),
+    (0x2CD4, 0x2CD5),
+    (0x2CD6, 0x2CD7),
+    (0x2CD8, 0x2CD9),
+    (0x2CDA, 0x2CDB),
+    (0x2CDC, 0x2CDD),
+    (0x2CDE, 0x2CDF),
+    (0x2CE0, 0x2CE1),
+    (0x2CE2, 0x2CE3),
+    (0x2CEB, 0x2CEC),
+    (0x2CED, 0x2CEE),
+    (0x2CF2, 0x2CF3),
+    (0xA640, 0xA641),
+    (0xA642, 0xA643),
+    (0xA644, 0xA645),
+    (0xA646, 0xA647),
+    (0xA648, 0xA649),
+    (0xA64A, 0xA64B),
+    (0xA64C, 0xA64D),
+    (0xA64E, 0xA64F),
+    (0xA650, 0xA651),
+    (0xA652, 0xA653),
+    (0xA654, 0xA655),
+    (0xA656, 0xA657),
+    (0xA658, 0xA659),
+    (0xA65A, 0xA65B),
+    (0xA65C, 0xA65D),
+    (0xA65E, 0xA65F),
+    (0xA660, 0xA661),
+    (0xA662, 0xA663),
+    (0xA664, 0xA665),
+    (0xA666, 0xA667),
+    (0xA668, 0xA669),
+    (0xA66A, 0xA66B),
+    (0xA66C, 0xA66D),
+    (0xA680, 0xA681),
+    (0xA682, 0xA683),
+    (0xA684, 0xA685),
+    (0xA686, 0xA687),
+    (0xA688, 0xA689),
+    (0xA68A, 0xA68B),
+    (0xA68C, 0xA68D),
+    (0xA68E, 0xA68F),
+    (0xA690, 0xA691),
+    (0xA692, 0xA693),
+    (0xA694, 0xA695),
+    (0xA696, 0xA697),
+    (0xA698, 0xA699),
+    (0xA69A, 0xA69B),
+    (0xA722, 0xA723),
+    (0xA724, 0xA725),
+    (0xA726, 0xA727),
+    (0xA728, 0xA729),
+    (0xA72A, 0xA72B),
+    (0xA72C, 0xA72D),
+    (0xA72E, 0xA72F),
+    (0xA732, 0xA733),
+    (0xA734, 0xA735),
+    (0xA736, 0xA737),
+    (0xA738, 0xA739),
+    (0xA73A, 0xA73B),
+    (0xA73C, 0xA73D),
+    (0xA73E, 0xA73F),
+    (0xA740, 0xA741),
+    (0xA742, 0xA743),
+    (0xA744, 0xA745),
+    (0xA746, 0xA747),
+    (0xA748, 0xA749),
+    (0xA74A, 0xA74B),
+    (0xA74C, 0xA74D),
+    (0xA74E, 0xA74F),
+    (0xA750, 0xA751),
+    (0xA752, 0xA753),
+    (0xA754, 0xA755),
+    (0xA756, 0xA757),
+    (0xA758, 0xA759),
+    (0xA75A, 0xA75B),
+    (0xA75C, 0xA75D),
+    (0xA75E, 0xA75F),
+    (0xA760, 0xA761),
+    (0xA762, 0xA763),
+    (0xA764, 0xA765),
+    (0xA766, 0xA767),
+    (0xA768, 0xA769),
+    (0xA76A, 0xA76B),
+    (0xA76C, 0xA76D),
+    (0xA76E, 0xA76F),
+    (0xA779, 0xA77A),
+    (0xA77B, 0xA77C),
+    (0xA77D, 0x1D79),
+    (0xA77E, 0xA77F),
+    (0xA780, 0xA781),
+    (0xA782, 0xA783),
+    (0xA784, 0xA785),
+    (0xA786, 0xA787),
+    (0xA78B, 0xA78C),
+    (0xA78D, 0x0265),
+    (0xA790, 0xA791),
+    (0xA792, 0xA793),
+    (0xA796, 0xA797),
+    (0xA798, 0xA799),
+    (0xA79A, 0xA79B),
+    (0xA79C, 0xA79D),
+    (0xA79E, 0xA79F),
+    (0xA7A0, 0xA7A1),
+    (0xA7A2, 0xA7A3),
+    (0xA7A4, 0xA7A5),
+    (0xA7A6, 0xA7A7),
+    (0xA7A8, 0xA7A9),
+    (0xA7AA, 0x0266),
+    (0xA7AB, 0x025C),
+    (0xA7AC, 0x0261),
+    (0xA7AD, 0x026C),
+    (0xA7AE, 0x026A),
+    (0xA7B0, 0x029E),
+    (0xA7B1, 0x0287),
+    (0xA7B2, 0x029D),
+    (0xA7B3, 0xAB53),
+    (0xA7B4, 0xA7B5),
+    (0xA7B6, 0xA7B7),
+    (0xA7B8, 0xA7B9),
+    (0xA7BA, 0xA7BB),
+    (0xA7BC, 0xA7BD),
+    (0xA7BE, 0xA7BF),
+    (0xA7C2, 0xA7C3),
+    (0xA7C4, 0xA794),
+    (0xA7C5, 0x0282),
+    (0xA7C6, 0x1D8E),
+    (0xA7C7, 0xA7C8),
+    (0xA7C9, 0xA7CA),
+    (0xA7F5, 0xA7F6),
+    (0xFF21, 0xFF41),
+    (0xFF22, 0xFF42),
+    (0xFF23, 0xFF43),
+    (0xFF24, 0xFF44),
+    (0xFF25, 0xFF45),
+    (0xFF26, 0xFF46),
+    (0xFF27, 0xFF47),
+    (0xFF28, 0xFF48),
+    (0xFF29, 0xFF49),
+    (0xFF2A, 0xFF4A),
+    (0xFF2B, 0xFF4B),
+    (0xFF2C, 0xFF4C),
+    (0xFF2D, 0xFF4D),
+    (0xFF2E, 0xFF4E),
+    (0xFF2F, 0xFF4F),
+    (0xFF30, 0xFF50),
+    (0xFF31, 0xFF51),
+    (0xFF32, 0xFF52),
+    (0xFF33, 0xFF53),
+    (0xFF34, 0xFF54),
+    (0xFF35, 0xFF55),
+    (0xFF36, 0xFF56),
+    (0xFF37, 0xFF57),
+    (0xFF38, 0xFF58),
+    (0xFF39, 0xFF59),
+    (0xFF3A, 0xFF5A),
+    (0x10400, 0x10428),
+    (0x10401, 0x10429),
+    (0x10402, 0x1042A),
+    (0x10403, 0x1042B),
+    (0x10404, 0x1042C),
+    (0x10405, 0x1042D),
+    (0x10406, 0x1042E),
+    (0x10407, 0x1042F),
+    (0x10408, 0x10430),
+    (0x10409, 0x10431),
+    (0x1040A, 0x10432),
+    (0x1040B, 0x10433),
+    (0x1040C, 0x10434),
+    (0x1040D, 0x10435),
+    (0x1040E, 0x10436),
+    (0x1040F, 0x10437),
+    (0x10410, 0x10438),
+    (0x10411, 0x10439),
+    (0x10412, 0x1043A),
+    (0x10413, 0x1043B),
+    (0x10414, 0x1043C),
+    (0x10415, 0x1043D),
+    (0x10416, 0x1043E),
+    (0x10417, 0x1043F),
+    (0x10418, 0x10440),
+    (0x10419, 0x10441),
+    (0x1041A, 0x10442),
+    (0x1041B, 0x10443),
+    (0x1041C, 0x10444),
+    (0x1041D, 0x10445),
+    (0x1041E, 0x10446),
+    (0x1041F, 0x10447),
+    (0x10420, 0x10448),
+    (0x10421, 0x10449),
+    (0x10422, 0x1044A),
+    (0x10423, 0x1044B),
+    (0x10424, 0x1044C),
+    (0x10425, 0x1044D),
+    (0x10426, 0x1044E),
+    (0x10427, 0x1044F),
+    (0x104B0, 0x104D8),
+    (0x104B1, 0x104D9),
+    (0x104B2, 0x104DA),
+    (0x104B3, 0x104DB),
+    (0x104B4, 0x104DC),
+    (0x104B5, 0x104DD),
+    (0x104B6, 0x104DE),
+    (0x104B7, 0x104DF),
+    (0x104B8, 0x104E0),
+    (0x104B9, 0x104E1),
+    (0x104BA, 0x104E2),
+    (0x104BB, 0x104E3),
+    (0x104BC, 0x104E4),
+    (0x104BD, 0x104E5),
+    (0x104BE, 0x104E6),
+    (0x104BF, 0x104E7),
+    (0x104C0, 0x104E8),
+    (0x104C1, 0x104E9),
+    (0x104C2, 0x104EA),
+    (0x104C3, 0x104EB),
+    (0x104C4, 0x104EC),
+    (0x104C5, 0x104ED),
+    (0x104C6, 0x104EE),
+    (0x104C7, 0x104EF),
+    (0x104C8, 0x104F0),
+    (0x104C9, 0x104F1),
+    (0x104CA, 0x104F2),
+    (0x104CB, 0x104F3),
+    (0x104CC, 0x104F4),
+    (0x104CD, 0x104F5),
+    (0x104CE, 0x104F6),
+    (0x104CF, 0x104F7),
+    (0x104D0, 0x104F8),
+    (0x104D1, 0x104F9),
+    (0x104D2, 0x104FA),
+    (0x104D3, 0x104FB),
+    (0x10C80, 0x10CC0),
+    (0x10C81, 0x10CC1),
+    (0x10C82, 0x10CC2),
+    (0x10C83, 0x10CC3),
+    (0x10C84, 0x10CC4),
+    (0x10C85, 0x10CC5),
+    (0x10C86, 0x10CC6),
+    (0x10C87, 0x10CC7),
+    (0x10C88, 0x10CC8),
+    (0x10C89, 0x10CC9),
+    (0x10C8A, 0x10CCA),
+    (0x10C8B, 0x10CCB),
+    (0x10C8C, 0x10CCC),
+    (0x10C8D, 0x10CCD),
+    (0x10C8E, 0x10CCE),
+    (0x10C8F, 0x10CCF),
+    (0x10C90, 0x10CD0),
+    (0x10C91, 0x10CD1),
+    (0x10C92, 0x10CD2),
+    (0x10C93, 0x10CD3),
+    (0x10C94, 0x10CD4),
+    (0x10C95, 0x10CD5),
+    (0x10C96, 0x10CD6),
+    (0x10C97, 0x10CD7),
+    (0x10C98, 0x10CD8),
+    (0x10C99, 0x10CD9),
+    (0x10C9A, 0x10CDA),
+    (0x10C9B, 0x10CDB),
+    (0x10C9C, 0x10CDC),
+    (0x10C9D, 0x10CDD),
+    (0x10C9E, 0x10CDE),
+    (0x10C9F, 0x10CDF),
+    (0x10CA0, 0x10CE0),
+    (0x10CA1, 0x10CE1),
+    (0x10CA2, 0x10CE2),
+    (0x10CA3, 0x10CE3),
+    (0x10CA4, 0x10CE4),
+    (0x10CA5, 0x10CE5),
+    (0x10CA6, 0x10CE6),
+    (0x10CA7, 0x10CE7),
+    (0x10CA8, 0x10CE8),
+    (0x10CA9, 0x10CE9),
+    (0x10CAA, 0x10CEA),
+    (0x10CAB, 0x10CEB),
+    (0x10CAC, 0x10CEC),
+    (0x10CAD, 0x10CED),
+    (0x10CAE, 0x10CEE),
+    (0x10CAF, 0x10CEF),
+    (0x10CB0, 0x10CF0),
+    (0x10CB1, 0x10CF1),
+    (0x10CB2, 0x10CF2),
+    (0x118A0, 0x118C0),
+    (0x118A1, 0x118C1),
+    (0x118A2, 0x118C2),
+    (0x118A3, 0x118C3),
+    (0x118A4, 0x118C4),
+    (0x118A5, 0x118C5),
+    (0x118A6, 0x118C6),
+    (0x118A7, 0x118C7),
+    (0x118A8, 0x118C8),
+    (0x118A9, 0x118C9),
+    (0x118AA, 0x118CA),
+    (0x118AB, 0x118CB),
+    (0x118AC, 0x118CC),
+    (0x118AD, 0x118CD),
+    (0x118AE, 0x118CE),
+    (0x118AF, 0x118CF),
+    (0x118B0, 0x118D0),
+    (0x118B1, 0x118D1),
+    (0x118B2, 0x118D2),
+    (0x118B3, 0x118D3),
+    (0x118B4, 0x118D4),
+    (0x118B5, 0x118D5),
+    (0x118B6, 0x118D6),
+    (0x118B7, 0x118D7),
+    (0x118B8, 0x118D8),
+    (0x118B9, 0x118D9),
+    (0x118BA, 0x118DA),
+    (0x118BB, 0x118DB),
+    (0x118BC, 0x118DC),
+    (0x118BD, 0x118DD),
+    (0x118BE, 0x118DE),
+    (0x118BF, 0x118DF),
+    (0x16E40, 0x16E60),
+    (0x16E41, 0x16E61),
+    (0x16E42, 0x16E62),
+    (0x16E43, 0x16E63),
+    (0x16E44, 0x16E64),
+    (0x16E45, 0x16E65),
+    (0x16E46, 0x16E66),
+    (0x16E47, 0x16E67),
+    (0x16E48, 0x16E68),
+    (0x16E49, 0x16E69),
+    (0x16E4A, 0x16E6A),
+    (0x16E4B, 0x16E6B),
+    (0x16E4C, 0x16E6C),
+    (0x16E4D, 0x16E6D),
+    (0x16E4E, 0x16E6E),
+    (0x16E4F, 0x16E6F),
+    (0x16E50, 0x16E70),
+    (0x16E51, 0x16E71),
+    (0x16E52, 0x16E72),
+    (0x16E53, 0x16E73),
+    (0x16E54, 0x16E74),
+    (0x16E55, 0x16E75),
+    (0x16E56, 0x16E76),
+    (0x16E57, 0x16E77),
+    (0x16E58, 0x16E78),
+    (0x16E59, 0x16E79),
+    (0x16E5A, 0x16E7A),
+    (0x16E5B, 0x16E7B),
+    (0x16E5C, 0x16E7C),
+    (0x16E5D, 0x16E7D),
+    (0x16E5E, 0x16E7E),
+    (0x16E5F, 0x16E7F),
+    (0x1E900, 0x1E922),
+    (0x1E901, 0x1E923),
+    (0x1E902, 0x1E924),
+    (0x1E903, 0x1E925),
+    (0x1E904, 0x1E926),
+    (0x1E905, 0x1E927),
+    (0x1E906, 0x1E928),
+    (0x1E907, 0x1E929),
+    (0x1E908, 0x1E92A),
+    (0x1E909, 0x1E92B),
+    (0x1E90A, 0x1E92C),
+    (0x1E90B, 0x1E92D),
+    (0x1E90C, 0x1E92E),
+    (0x1E90D, 0x1E92F),
+    (0x1E90E, 0x1E930),
+    (0x1E90F, 0x1E931),
+    (0x1E910, 0x1E932),
+    (0x1E911, 0x1E933),
+    (0x1E912, 0x1E934),
+    (0x1E913, 0x1E935),
+    (0x1E914, 0x1E936),
+    (0x1E915, 0x1E937),
+    (0x1E916, 0x1E938),
+    (0x1E917, 0x1E939),
+    (0x1E918, 0x1E93A),
+    (0x1E919, 0x1E93B),
+    (0x1E91A, 0x1E93C),
+    (0x1E91B, 0x1E93D),
+    (0x1E91C, 0x1E93E),
+    (0x1E91D, 0x1E93F),
+    (0x1E91E, 0x1E940),
+    (0x1E91F, 0x1E941),
+    (0x1E920, 0x1E942),
+    (0x1E921, 0x1E943),
+];
+
+pub(super) static TITLE_CASE: [(u32, u32); 58] = [
+    (0x01C4, 0x01C5),
+    (0x01C5, 0x01C5),
+    (0x01C6, 0x01C5),
+    (0x01C7, 0x01C8),
+    (0x01C8, 0x01C8),
+    (0x01C9, 0x01C8),
+    (0x01CA, 0x01CB),
+    (0x01CB, 0x01CB),
+    (0x01CC, 0x01CB),
+    (0x01F1, 0x01F2),
+    (0x01F2, 0x01F2),
+    (0x01F3, 0x01F2),
+    (0x10D0, 0x10D0),
+    (0x10D1, 0x10D1),
+    (0x10D2, 0x10D2),
+    (0x10D3, 0x10D3),
+    (0x10D4, 0x10D4),
+    (0x10D5, 0x10D5),
+    (0x10D6, 0x10D6),
+    (0x10D7, 0x10D7),
+    (0x10D8, 0x10D8),
+    (0x10D9, 0x10D9),
+    (0x10DA, 0x10DA),
+    (0x10DB, 0x10DB),
+    (0x10DC, 0x10DC),
+    (0x10DD, 0x10DD),
+    (0x10DE, 0x10DE),
+    (0x10DF, 0x10DF),
+    (0x10E0, 0x10E0),
+    (0x10E1, 0x10E1),
+    (0x10E2, 0x10E2),
+    (0x10E3, 0x10E3),
+    (0x10E4, 0x10E4),
+    (0x10E5, 0x10E5),
+    (0x10E6, 0x10E6),
+    (0x10E7, 0x10E7),
+    (0x10E8, 0x10E8),
+    (0x10E9, 0x10E9),
+    (0x10EA, 0x10EA),
+    (0x10EB, 0x10EB),
+    (0x10EC, 0x10EC),
+    (0x10ED, 0x10ED),
+    (0x10EE, 0x10EE),
+    (0x10EF, 0x10EF),
+    (0x10F0, 0x10F0),
+    (0x10F1, 0x10F1),
+    (0x10F2, 0x10F2),
+    (0x10F3, 0x10F3),
+    (0x10F4, 0x10F4),
+    (0x10F5, 0x10F5),
+    (0x10F6, 0x10F6),
+    (0x10F7, 0x10F7),
+    (0x10F8, 0x10F8),
+    (0x10F9, 0x10F9),
+    (0x10FA, 0x10FA),
+    (0x10FD, 0x10FD),
+    (0x10FE, 0x10FE),
+    (0x10FF, 0x10FF),
+];
+
+pub(super) static UPPER_CASE_EXPANSIONS: [(u32, &[u16]); 102] = [
+    (0x00DF, &[0x0053, 0x0053]),
+    (0x0149, &[0x02BC, 0x004E]),
+    (0x01F0, &[0x004A, 0x030C]),
+    (0x0390, &[0x0399, 0x0308, 0x0301]),
+    (0x03B0, &[0x03A5, 0x0308, 0x0301]),
+    (0x0587, &[0x0535, 0x0552]),
+    (0x1E96, &[0x0048, 0x0331]),
+    (0x1E97, &[0x0054, 0x0308]),
+    (0x1E98, &[0x0057, 0x030A]),
+    (0x1E99, &[0x0059, 0x030A]),
+    (0x1E9A, &[0x0041, 0x02BE]),
+    (0x1F50, &[0x03A5, 0x0313]),
+    (0x1F52, &[0x03A5, 0x0313, 0x0300]),
+    (0x1F54, &[0x03A5, 0x0313, 0x0301]),
+    (0x1F56, &[0x03A5, 0x0313, 0x0342]),
+    (0x1F80, &[0x1F08, 0x0399]),
+    (0x1F81, &[0x1F09, 0x0399]),
+    (0x1F82, &[0x1F0A, 0x0399]),
+    (0x1F83, &[0x1F0B, 0x0399]),
+    (0x1F84, &[0x1F0C, 0x0399]),
+    (0x1F85, &[0x1F0D, 0x0399]),
+    (0x1F86, &[0x1F0E, 0x0399]),
+    (0x1F87, &[0x1F0F, 0x0399]),
+    (0x1F88, &[0x1F08, 0x0399]),
+    (0x1F89, &[0x1F09, 0x0399]),
+    (0x1F8A, &[0x1F0A, 0x0399]),
+    (0x1F8B, &[0x1F0B, 0x0399]),
+    (0x1F8C, &[0x1F0C, 0x0399]),
+    (0x1F8D, &[0x1F0D, 0x0399]),
+    (0x1F8E, &[0x1F0E, 0x0399]),
+    (0x1F8F, &[0x1F0F, 0x0399]),
+    (0x1F90, &[0x1F28, 0x0399]),
+    (0x1F91, &[0x1F29, 0x0399]),
+    (0x1F92, &[0x1F2A, 0x0399]),
+    (0x1F93, &[0x1F2B, 0x0399]),
+    (0x1F94, &[0x1F2C, 0x0399]),
+    (0x1F95, &[0x1F2D, 0x0399]),
+    (0x1F96, &[0x1F2E, 0x0399]),
+    (0x1F97, &[0x1F2F, 0x0399]),
+    (0x1F98, &[0x1F28, 0x0399]),
+    (0x1F99, &[0x1F29, 0x0399]),
+    (0x1F9A, &[0x1F2A, 0x0399]),
+    (0x1F9B, &[0x1F2B, 0x0399]),
+    (0x1F9C, &[0x1F2C, 0x0399]),
+    (0x1F9D, &[0x1F2D, 0x0399]),
+    (0x1F9E, &[0x1F2E, 0x0399]),
+    (0x1F9F, &[0x1F2F, 0x0399]),
+    (0x1FA0, &[0x1F68, 0x0399]),
+    (0x1FA1, &[0x1F69, 0x0399]),
+    (0x1FA2, &[0x1F6A, 0x0399]),
+    (0x1FA3, &[0x1F6B, 0x0399]),
+    (0x1FA4, &[0x1F6C, 0x0399]),
+    (0x1FA5, &[0x1F6D, 0x0399]),
+    (0x1FA6, &[0x1F6E, 0x0399]),
+    (0x1FA7, &[0x1F6F, 0x0399]),
+    (0x1FA8, &[0x1F68, 0x0399]),
+    (0x1FA9, &[0x1F69, 0x0399]),
+    (0x1FAA, &[0x1F6A, 0x0399]),
+    (0x1FAB, &[0x1F6B, 0x0399]),
+    (0x1FAC, &[0x1F6C, 0x0399]),
+    (0x1FAD, &[0x1F6D, 0x0399]),
+    (0x1FAE, &[0x1F6E, 0x0399]),
+    (0x1FAF, &[0x1F6F, 0x0399]),
+    (0x1FB2, &[0x1FBA, 0x0399]),
+    (0x1FB3, &[0x0391, 0x0399]),
+    (0x1FB4, &[0x0386, 0x0399]),
+    (0x1FB6, &[0x0391, 0x0342]),
+    (0x1FB7, &[0x0391, 0x0342, 0x0399]),
+    (0x1FBC, &[0x0391, 0x0399]),
+    (0x1FC2, &[0x1FCA, 0x0399]),
+    (0x1FC3, &[0x0397, 0x0399]),
+    (0x1FC4, &[0x0389, 0x0399]),
+    (0x1FC6, &[0x0397, 0x0342]),
+    (0x1FC7, &[0x0397, 0x0342, 0x0399]),
+    (0x1FCC, &[0x0397, 0x0399]),
+    (0x1FD2, &[0x0399, 0x0308, 0x0300]),
+    (0x1FD3, &[0x0399, 0x0308, 0x0301]),
+    (0x1FD6, &[0x0399, 0x0342]),
+    (0x1FD7, &[0x0399, 0x0308, 0x0342]),
+    (0x1FE2, &[0x03A5, 0x0308, 0x0300]),
+    (0x1FE3, &[0x03A5, 0x0308, 0x0301]),
+    (0x1FE4, &[0x03A1, 0x0313]),
+    (0x1FE6, &[0x03A5, 0x0342]),
+    (0x1FE7, &[0x03A5, 0x0308, 0x0342]),
+    (0x1FF2, &[0x1FFA, 0x0399]),
+    (0x1FF3, &[0x03A9, 0x0399]),
+    (0x1FF4, &[0x038F, 0x0399]),
+    (0x1FF6, &[0x03A9, 0x0342]),
+    (0x1FF7, &[0x03A9, 0x0342, 0x0399]),
+    (0x1FFC, &[0x03A9, 0x0399]),
+    (0xFB00, &[0x0046, 0x0046]),
+    (0xFB01, &[0x0046, 0x0049]),
+    (0xFB02, &[0x0046, 0x004C]),
+    (0xFB03, &[0x0046, 0x0046, 0x0049]),
+    (0xFB04, &[0x0046, 0x0046, 0x004C]),
+    (0xFB05, &[0x0053, 0x0054]),
+    (0xFB06, &[0x0053, 0x0054]),
+    (0xFB13, &[0x0544, 0x0546]),
+    (0xFB14, &[0x0544, 0x0535]),
+    (0xFB15, &[0x0544, 0x053B]),
+    (0xFB16, &[0x054E, 0x0546]),
+    (0xFB17, &[0x0544, 0x053D]),
+];
+
+pub(super) static COMBINING_CLASSES: [(u32, u32, u8); 365] = [
+    (0x0300, 0x0314, 230),
+    (0x0315, 0x0315, 232),
+    (0x0316, 0x0319, 220),
+    (0x031A, 0x031A, 232),
+    (0x031B, 0x031B, 216),
+    (0x031C, 0x0320, 220),
+    (0x0321, 0x0322, 202),
+    (0x0323, 0x0326, 220),
+    (0x0327, 0x0328, 202),
+    (0x0329, 0x0333, 220),
+    (0x0334, 0x0338, 1),
+    (0x0339, 0x033C, 220),
+    (0x033D, 0x0344, 230),
+    (0x0345, 0x0345, 240),
+    (0x0346, 0x0346, 230),
+    (0x0347, 0x0349, 220),
+    (0x034A, 0x034C, 230),
+    (0x034D, 0x034E, 220),
+    (0x0350, 0x0352, 230),
+    (0x0353, 0x0356, 220),
+    (0x0357, 0x0357, 230),
+    (0x0358, 0x0358, 232),
+    (0x0359, 0x035A, 220),
+    (0x035B, 0x035B, 230),
+    (0x035C, 0x035C, 233),
+    (0x035D, 0x035E, 234),
+    (0x035F, 0x035F, 233),
+    (0x0360, 0x0361, 234),
+    (0x0362, 0x0362, 233),
+    (0x0363, 0x036F, 230),
+    (0x0483, 0x0487, 230),
+    (0x0591, 0x0591, 220),
+    (0x0592, 0x0595, 230),
+    (0x0596, 0x0596, 220),
+    (0x0597, 0x0599, 230),
+    (0x059A, 0x059A, 222),
+    (0x059B, 0x059B, 220),
+    (0x059C, 0x05A1, 230),
+    (0x05A2, 0x05A7, 220),
+    (0x05A8, 0x05A9, 230),
+    (0x05AA, 0x05AA, 220),
+    (0x05AB, 0x05AC, 230),
+    (0x05AD, 0x05AD, 222),
+    (0x05AE, 0x05AE, 228),
+    (0x05AF, 0x05AF, 230),
+    (0x05B0, 0x05B0, 10),
+    (0x05B1, 0x05B1, 11),
+    (0x05B2, 0x05B2, 12),
+    (0x05B3, 0x05B3, 13),
+    (0x05B4, 0x05B4, 14),
+    (0x05B5, 0x05B5, 15),
+    (0x05B6, 0x05B6, 16),
+    (0x05B7, 0x05B7, 17),
+    (0x05B8, 0x05B8, 18),
+    (0x05B9, 0x05BA, 19),
+    (0x05BB, 0x05BB, 20),
+    (0x05BC, 0x05BC, 21),
+    (0x05BD, 0x05BD, 22),
+    (0x05BF, 0x05BF, 23),
+    (0x05C1, 0x05C1, 24),
+    (0x05C2, 0x05C2, 25),
+    (0x05C4, 0x05C4, 230),
+    (0x05C5, 0x05C5, 220),
+    (0x05C7, 0x05C7, 18),
+    (0x0610, 0x0617, 230),
+    (0x0618, 0x0618, 30),
+    (0x0619, 0x0619, 31),
+    (0x061A, 0x061A, 32),
+    (0x064B, 0x064B, 27),
+    (0x064C, 0x064C, 28),
+    (0x064D, 0x064D, 29),
+    (0x064E, 0x064E, 30),
+    (0x064F, 0x064F, 31),
+    (0x0650, 0x0650, 32),
+    (0x0651, 0x0651, 33),
+    (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230),
+    (0x0655, 0x0656, 220),
+    (0x0657, 0x065B, 230),
+    (0x065C, 0x065C, 220),
+    (0x065D, 0x065E, 230),
+    (0x065F, 0x065F, 220),
+    (0x0670, 0x0670, 35),
+    (0x06D6, 0x06DC, 230),
+    (0x06DF, 0x06E2, 230),
+    (0x06E3, 0x06E3, 220),
+    (0x06E4, 0x06E4, 230),
+    (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220),
+    (0x06EB, 0x06EC, 230),
+    (0x06ED, 0x06ED, 220),
+    (0x0711, 0x0711, 36),
+    (0x0730, 0x0730, 230),
+    (0x0731, 0x0731, 220),
+    (0x0732, 0x0733, 230),
+    (0x0734, 0x0734, 220),
+    (0x0735, 0x0736, 230),
+    (0x0737, 0x0739, 220),
+    (0x073A, 0x073A, 230),
+    (0x073B, 0x073C, 220),
+    (0x073D, 0x073D, 230),
+    (0x073E, 0x073E, 220),
+    (0x073F, 0x0741, 230),
+    (0x0742, 0x0742, 220),
+    (0x0743, 0x0743, 230),
+    (0x0744, 0x0744, 220),
+    (0x0745, 0x0745, 230),
+    (0x0746, 0x0746, 220),
+    (0x0747, 0x0747, 230),
+    (0x0748, 0x0748, 220),
+    (0x0749, 0x074A, 230),
+    (0x07EB, 0x07F1, 230),
+    (0x07F2, 0x07F2, 220),
+    (0x07F3, 0x07F3, 230),
+    (0x07FD, 0x07FD, 220),
+    (0x0816, 0x0819, 230),
+    (0x081B, 0x0823, 230),
+    (0x0825, 0x0827, 230),
+    (0x0829, 0x082D, 230),
+    (0x0859, 0x085B, 220),
+    (0x08D3, 0x08D3, 220),
+    (0x08D4, 0x08E1, 230),
+    (0x08E3, 0x08E3, 220),
+    (0x08E4, 0x08E5, 230),
+    (0x08E6, 0x08E6, 220),
+    (0x08E7, 0x08E8, 230),
+    (0x08E9, 0x08E9, 220),
+    (0x08EA, 0x08EC, 230),
+    (0x08ED, 0x08EF, 220),
+    (0x08F0, 0x08F0, 27),
+    (0x08F1, 0x08F1, 28),
+    (0x08F2, 0x08F2, 29),
+    (0x08F3, 0x08F5, 230),
+    (0x08F6, 0x08F6, 220),
+    (0x08F7, 0x08F8, 230),
+    (0x08F9, 0x08FA, 220),
+    (0x08FB, 0x08FF, 230),
+    (0x093C, 0x093C, 7),
+    (0x094D, 0x094D, 9),
+    (0x0951, 0x0951, 230),
+    (0x0952, 0x0952, 220),
+    (0x0953, 0x0954, 230),
+    (0x09BC, 0x09BC, 7),
+    (0x09CD, 0x09CD, 9),
+    (0x09FE, 0x09FE, 230),
+    (0x0A3C, 0x0A3C, 7),
+    (0x0A4D, 0x0A4D, 9),
+    (0x0ABC, 0x0ABC, 7),
+    (0x0ACD, 0x0ACD, 9),
+    (0x0B3C, 0x0B3C, 7),
+    (0x0B4D, 0x0B4D, 9),
+    (0x0BCD, 0x0BCD, 9),
+    (0x0C4D, 0x0C4D, 9),
+    (0x0C55, 0x0C55, 84),
+    (0x0C56, 0x0C56, 91),
+    (0x0CBC, 0x0CBC, 7),
+    (0x0CCD, 0x0CCD, 9),
+    (0x0D3B, 0x0D3C, 9),
+    (0x0D4D, 0x0D4D, 9),
+    (0x0DCA, 0x0DCA, 9),
+    (0x0E38, 0x0E39, 103),
+    (0x0E3A, 0x0E3A, 9),
+    (0x0E48, 0x0E4B, 107),
+    (0x0EB8, 0x0EB9, 118),
+    (0x0EBA, 0x0EBA, 9),
+    (0x0EC8, 0x0ECB, 122),
+    (0x0F18, 0x0F19, 220),
+    (0x0F35, 0x0F35, 220),
+    (0x0F37, 0x0F37, 220),
+    (0x0F39, 0x0F39, 216),
+    (0x0F71, 0x0F71, 129),
+    (0x0F72, 0x0F72, 130),
+    (0x0F74, 0x0F74, 132),
+    (0x0F7A, 0x0F7D, 130),
+    (0x0F80, 0x0F80, 130),
+    (0x0F82, 0x0F83, 230),
+    (0x0F84, 0x0F84, 9),
+    (0x0F86, 0x0F87, 230),
+    (0x0FC6, 0x0FC6, 220),
+    (0x1037, 0x1037, 7),
+    (0x1039, 0x103A, 9),
+    (0x108D, 0x108D, 220),
+    (0x135D, 0x135F, 230),
+    (0x1714, 0x1714, 9),
+    (0x1734, 0x1734, 9),
+    (0x17D2, 0x17D2, 9),
+    (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228),
+    (0x1939, 0x1939, 222),
+    (0x193A, 0x193A, 230),
+    (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230),
+    (0x1A18, 0x1A18, 220),
+    (0x1A60, 0x1A60, 9),
+    (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220),
+    (0x1AB0, 0x1AB4, 230),
+    (0x1AB5, 0x1ABA, 220),
+    (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220),
+    (0x1ABF, 0x1AC0, 220),
+    (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9),
+    (0x1B6B, 0x1B6B, 230),
+    (0x1B6C, 0x1B6C, 220),
+    (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BF2, 0x1BF3, 9),
+    (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230),
+    (0x1CD4, 0x1CD4, 1),
+    (0x1CD5, 0x1CD9, 220),
+    (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220),
+    (0x1CE0, 0x1CE0, 230),
+    (0x1CE2, 0x1CE8, 1),
+    (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230),
+    (0x1CF8, 0x1CF9, 230),
+    (0x1DC0, 0x1DC1, 230),
+    (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230),
+    (0x1DCA, 0x1DCA, 220),
+    (0x1DCB, 0x1DCC, 230),
+    (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214),
+    (0x1DCF, 0x1DCF, 220),
+    (0x1DD0, 0x1DD0, 202),
+    (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232),
+    (0x1DF7, 0x1DF8, 228),
+    (0x1DF9, 0x1DF9, 220),
+    (0x1DFB, 0x1DFB, 230),
+    (0x1DFC, 0x1DFC, 233),
+    (0x1DFD, 0x1DFD, 220),
+    (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220),
+    (0x20D0, 0x20D1, 230),
+    (0x20D2, 0x20D3, 1),
+    (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1),
+    (0x20DB, 0x20DC, 230),
+    (0x20E1, 0x20E1, 230),
+    (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230),
+    (0x20E8, 0x20E8, 220),
+    (0x20E9, 0x20E9, 230),
+    (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220),
+    (0x20F0, 0x20F0, 230),
+    (0x2CEF, 0x2CF1, 230),
+    (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230),
+    (0x302A, 0x302A, 218),
+    (0x302B, 0x302B, 228),
+    (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222),
+    (0x302E, 0x302F, 224),
+    (0x3099, 0x309A, 8),
+    (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230),
+    (0xA69E, 0xA69F, 230),
+    (0xA6F0, 0xA6F1, 230),
+    (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9),
+    (0xA8C4, 0xA8C4, 9),
+    (0xA8E0, 0xA8F1, 230),
+    (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9C0, 0xA9C0, 9),
+    (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230),
+    (0xAAB4, 0xAAB4, 220),
+    (0xAAB7, 0xAAB8, 230),
+    (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230),
+    (0xAAF6, 0xAAF6, 9),
+    (0xABED, 0xABED, 9),
+    (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230),
+    (0xFE27, 0xFE2D, 220),
+    (0xFE2E, 0xFE2F, 230),
+    (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220),
+    (0x10376, 0x1037A, 230),
+    (0x10A0D, 0x10A0D, 220),
+    (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230),
+    (0x10A39, 0x10A39, 1),
+    (0x10A3A, 0x10A3A, 220),
+    (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230),
+    (0x10AE6, 0x10AE6, 220),
+    (0x10D24, 0x10D27, 230),
+    (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220),
+    (0x10F48, 0x10F4A, 230),
+    (0x10F4B, 0x10F4B, 220),
+    (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220),
+    (0x11046, 0x11046, 9),
+    (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9),
+    (0x110BA, 0x110BA, 7),
+    (0x11100, 0x11102, 230),
+    (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7),
+    (0x111C0, 0x111C0, 9),
+    (0x111CA, 0x111CA, 7),
+    (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7),
+    (0x112E9, 0x112E9, 7),
+    (0x112EA, 0x112EA, 9),
+    (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9),
+    (0x11366, 0x1136C, 230),
+    (0x11370, 0x11374, 230),
+    (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7),
+    (0x1145E, 0x1145E, 230),
+    (0x114C2, 0x114C2, 9),
+    (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9),
+    (0x115C0, 0x115C0, 7),
+    (0x1163F, 0x1163F, 9),
+    (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7),
+    (0x1172B, 0x1172B, 9),
+    (0x11839, 0x11839, 9),
+    (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9),
+    (0x11943, 0x11943, 7),
+    (0x119E0, 0x119E0, 9),
+    (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9),
+    (0x11A99, 0x11A99, 9),
+    (0x11C3F, 0x11C3F, 9),
+    (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9),
+    (0x11D97, 0x11D97, 9),
+    (0x16AF0, 0x16AF4, 1),
+    (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6),
+    (0x1BC9E, 0x1BC9E, 1),
+    (0x1D165, 0x1D166, 216),
+    (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226),
+    (0x1D16E, 0x1D172, 216),
+    (0x1D17B, 0x1D182, 220),
+    (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220),
+    (0x1D1AA, 0x1D1AD, 230),
+    (0x1D242, 0x1D244, 230),
+    (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230),
+    (0x1E01B, 0x1E021, 230),
+    (0x1E023, 0x1E024, 230),
+    (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230),
+    (0x1E2EC, 0x1E2EF, 230),
+    (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230),
+    (0x1E94A, 0x1E94A, 7),
+];
+
+/// A Unicode block, in code point order; `java.lang.Character.UnicodeBlock`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UnicodeBlock {
+    BasicLatin,
+    Latin1Supplement,
+    LatinExtendedA,
+    LatinExtendedB,
+    IpaExtensions,
+    SpacingModifierLetters,
+    CombiningDiacriticalMarks,
+    Greek,
+    Cyrillic,
+    CyrillicSupplementary,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Syriac,
+    ArabicSupplement,
+    Thaana,
+    Nko,
+    Samaritan,
+    Mandaic,
+    SyriacSupplement,
+    ArabicExtendedA,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhala,
+    Thai,
+    Lao,
+    Tibetan,
+    Myanmar,
+    Georgian,
+    HangulJamo,
+    Ethiopic,
+    EthiopicSupplement,
+    Cherokee,
+    UnifiedCanadianAboriginalSyllabics,
+    Ogham,
+    Runic,
+    Tagalog,
+    Hanunoo,
+    Buhid,
+    Tagbanwa,
+    Khmer,
+    Mongolian,
+    UnifiedCanadianAboriginalSyllabicsExtended,
+    Limbu,
+    TaiLe,
+    NewTaiLue,
+    KhmerSymbols,
+    Buginese,
+    TaiTham,
+    CombiningDiacriticalMarksExtended,
+    Balinese,
+    Sundanese,
+    Batak,
+    Lepcha,
+    OlChiki,
+    CyrillicExtendedC,
+    GeorgianExtended,
+    SundaneseSupplement,
+    VedicExtensions,
+    PhoneticExtensions,
+    PhoneticExtensionsSupplement,
+    CombiningDiacriticalMarksSupplement,
+    LatinExtendedAdditional,
+    GreekExtended,
+    GeneralPunctuation,
+    SuperscriptsAndSubscripts,
+    CurrencySymbols,
+    CombiningMarksForSymbols,
+    LetterlikeSymbols,
+    NumberForms,
+    Arrows,
+    MathematicalOperators,
+    MiscellaneousTechnical,
+    ControlPictures,
+    OpticalCharacterRecognition,
+    EnclosedAlphanumerics,
+    BoxDrawing,
+    BlockElements,
+    GeometricShapes,
+    MiscellaneousSymbols,
+    Dingbats,
+    MiscellaneousMathematicalSymbolsA,
+    SupplementalArrowsA,
+    BraillePatterns,
+    SupplementalArrowsB,
+    MiscellaneousMathematicalSymbolsB,
+    SupplementalMathematicalOperators,
+    MiscellaneousSymbolsAndArrows,
+    Glagolitic,
+    LatinExtendedC,
+    Coptic,
+    GeorgianSupplement,
+    Tifinagh,
+    EthiopicExtended,
+    CyrillicExtendedA,
+    SupplementalPunctuation,
+    CjkRadicalsSupplement,
+    KangxiRadicals,
+    IdeographicDescriptionCharacters,
+    CjkSymbolsAndPunctuation,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    HangulCompatibilityJamo,
+    Kanbun,
+    BopomofoExtended,
+    CjkStrokes,
+    KatakanaPhoneticExtensions,
+    EnclosedCjkLettersAndMonths,
+    CjkCompatibility,
+    CjkUnifiedIdeographsExtensionA,
+    YijingHexagramSymbols,
+    CjkUnifiedIdeographs,
+    YiSyllables,
+    YiRadicals,
+    Lisu,
+    Vai,
+    CyrillicExtendedB,
+    Bamum,
+    ModifierToneLetters,
+    LatinExtendedD,
+    SylotiNagri,
+    CommonIndicNumberForms,
+    PhagsPa,
+    Saurashtra,
+    DevanagariExtended,
+    KayahLi,
+    Rejang,
+    HangulJamoExtendedA,
+    Javanese,
+    MyanmarExtendedB,
+    Cham,
+    MyanmarExtendedA,
+    TaiViet,
+    MeeteiMayekExtensions,
+    EthiopicExtendedA,
+    LatinExtendedE,
+    CherokeeSupplement,
+    MeeteiMayek,
+    HangulSyllables,
+    HangulJamoExtendedB,
+    HighSurrogates,
+    HighPrivateUseSurrogates,
+    LowSurrogates,
+    PrivateUseArea,
+    CjkCompatibilityIdeographs,
+    AlphabeticPresentationForms,
+    ArabicPresentationFormsA,
+    VariationSelectors,
+    VerticalForms,
+    CombiningHalfMarks,
+    CjkCompatibilityForms,
+    SmallFormVariants,
+    ArabicPresentationFormsB,
+    HalfwidthAndFullwidthForms,
+    Specials,
+    LinearBSyllabary,
+    LinearBIdeograms,
+    AegeanNumbers,
+    AncientGreekNumbers,
+    AncientSymbols,
+    PhaistosDisc,
+    Lycian,
+    Carian,
+    CopticEpactNumbers,
+    OldItalic,
+    Gothic,
+    OldPermic,
+    Ugaritic,
+    OldPersian,
+    Deseret,
+    Shavian,
+    Osmanya,
+    Osage,
+    Elbasan,
+    CaucasianAlbanian,
+    LinearA,
+    CypriotSyllabary,
+    ImperialAramaic,
+    Palmyrene,
+    Nabataean,
+    Hatran,
+    Phoenician,
+    Lydian,
+    MeroiticHieroglyphs,
+    MeroiticCursive,
+    Kharoshthi,
+    OldSouthArabian,
+    OldNorthArabian,
+    Manichaean,
+    Avestan,
+    InscriptionalParthian,
+    InscriptionalPahlavi,
+    PsalterPahlavi,
+    OldTurkic,
+    OldHungarian,
+    HanifiRohingya,
+    RumiNumeralSymbols,
+    Yezidi,
+    OldSogdian,
+    Sogdian,
+    Chorasmian,
+    Elymaic,
+    Brahmi,
+    Kaithi,
+    SoraSompeng,
+    Chakma,
+    Mahajani,
+    Sharada,
+    SinhalaArchaicNumbers,
+    Khojki,
+    Multani,
+    Khudawadi,
+    Grantha,
+    Newa,
+    Tirhuta,
+    Siddham,
+    Modi,
+    MongolianSupplement,
+    Takri,
+    Ahom,
+    Dogra,
+    WarangCiti,
+    DivesAkuru,
+    Nandinagari,
+    ZanabazarSquare,
+    Soyombo,
+    PauCinHau,
+    Bhaiksuki,
+    Marchen,
+    MasaramGondi,
+    GunjalaGondi,
+    Makasar,
+    LisuSupplement,
+    TamilSupplement,
+    Cuneiform,
+    CuneiformNumbersAndPunctuation,
+    EarlyDynasticCuneiform,
+    EgyptianHieroglyphs,
+    EgyptianHieroglyphFormatControls,
+    AnatolianHieroglyphs,
+    BamumSupplement,
+    Mro,
+    BassaVah,
+    PahawhHmong,
+    Medefaidrin,
+    Miao,
+    IdeographicSymbolsAndPunctuation,
+    Tangut,
+    TangutComponents,
+    KhitanSmallScript,
+    TangutSupplement,
+    KanaSupplement,
+    KanaExtendedA,
+    SmallKanaExtension,
+    Nushu,
+    Duployan,
+    ShorthandFormatControls,
+    ByzantineMusicalSymbols,
+    MusicalSymbols,
+    AncientGreekMusicalNotation,
+    MayanNumerals,
+    TaiXuanJingSymbols,
+    CountingRodNumerals,
+    MathematicalAlphanumericSymbols,
+    SuttonSignwriting,
+    GlagoliticSupplement,
+    NyiakengPuachueHmong,
+    Wancho,
+    MendeKikakui,
+    Adlam,
+    IndicSiyaqNumbers,
+    OttomanSiyaqNumbers,
+    ArabicMathematicalAlphabeticSymbols,
+    MahjongTiles,
+    DominoTiles,
+    PlayingCards,
+    EnclosedAlphanumericSupplement,
+    EnclosedIdeographicSupplement,
+    MiscellaneousSymbolsAndPictographs,
+    Emoticons,
+    OrnamentalDingbats,
+    TransportAndMapSymbols,
+    AlchemicalSymbols,
+    GeometricShapesExtended,
+    SupplementalArrowsC,
+    SupplementalSymbolsAndPictographs,
+    ChessSymbols,
+    SymbolsAndPictographsExtendedA,
+    SymbolsForLegacyComputing,
+    CjkUnifiedIdeographsExtensionB,
+    CjkUnifiedIdeographsExtensionC,
+    CjkUnifiedIdeographsExtensionD,
+    CjkUnifiedIdeographsExtensionE,
+    CjkUnifiedIdeographsExtensionF,
+    CjkCompatibilityIdeographsSupplement,
+    CjkUnifiedIdeographsExtensionG,
+    Tags,
+    VariationSelectorsSupplement,
+    SupplementaryPrivateUseAreaA,
+    SupplementaryPrivateUseAreaB,
+}
+
+pub(super) static ALL_BLOCKS: [UnicodeBlock; 308] = [
+    UnicodeBlock::BasicLatin,
+    UnicodeBlock::Latin1Supplement,
+    UnicodeBlock::LatinExtendedA,
+    UnicodeBlock::LatinExtendedB,
+    UnicodeBlock::IpaExtensions,
+    UnicodeBlock::SpacingModifierLetters,
+    UnicodeBlock::CombiningDiacriticalMarks,
+    UnicodeBlock::Greek,
+    UnicodeBlock::Cyrillic,
+    UnicodeBlock::CyrillicSupplementary,
+    UnicodeBlock::Armenian,
+    UnicodeBlock::Hebrew,
+    UnicodeBlock::Arabic,
+    UnicodeBlock::Syriac,
+    UnicodeBlock::ArabicSupplement,
+    UnicodeBlock::Thaana,
+    UnicodeBlock::Nko,
+    UnicodeBlock::Samaritan,
+    UnicodeBlock::Mandaic,
+    UnicodeBlock::SyriacSupplement,
+    UnicodeBlock::ArabicExtendedA,
+    UnicodeBlock::Devanagari,
+    UnicodeBlock::Bengali,
+    UnicodeBlock::Gurmukhi,
+    UnicodeBlock::Gujarati,
+    UnicodeBlock::Oriya,
+    UnicodeBlock::Tamil,
+    UnicodeBlock::Telugu,
+    UnicodeBlock::Kannada,
+    UnicodeBlock::Malayalam,
+    UnicodeBlock::Sinhala,
+    UnicodeBlock::Thai,
+    UnicodeBlock::Lao,
+    UnicodeBlock::Tibetan,
+    UnicodeBlock::Myanmar,
+    UnicodeBlock::Georgian,
+    UnicodeBlock::HangulJamo,
+    UnicodeBlock::Ethiopic,
+    UnicodeBlock::EthiopicSupplement,
+    UnicodeBlock::Cherokee,
+    UnicodeBlock::UnifiedCanadianAboriginalSyllabics,
+    UnicodeBlock::Ogham,
+    UnicodeBlock::Runic,
+    UnicodeBlock::Tagalog,
+    UnicodeBlock::Hanunoo,
+    UnicodeBlock::Buhid,
+    UnicodeBlock::Tagbanwa,
+    UnicodeBlock::Khmer,
+    UnicodeBlock::Mongolian,
+    UnicodeBlock::UnifiedCanadianAboriginalSyllabicsExtended,
+    UnicodeBlock::Limbu,
+    UnicodeBlock::TaiLe,
+    UnicodeBlock::NewTaiLue,
+    UnicodeBlock::KhmerSymbols,
+    UnicodeBlock::Buginese,
+    UnicodeBlock::TaiTham,
+    UnicodeBlock::CombiningDiacriticalMarksExtended,
+    UnicodeBlock::Balinese,
+    UnicodeBlock::Sundanese,
+    UnicodeBlock::Batak,
+    UnicodeBlock::Lepcha,
+    UnicodeBlock::OlChiki,
+    UnicodeBlock::CyrillicExtendedC,
+    UnicodeBlock::GeorgianExtended,
+    UnicodeBlock::SundaneseSupplement,
+    UnicodeBlock::VedicExtensions,
+    UnicodeBlock::PhoneticExtensions,
+    UnicodeBlock::PhoneticExtensionsSupplement,
+    UnicodeBlock::CombiningDiacriticalMarksSupplement,
+    UnicodeBlock::LatinExtendedAdditional,
+    UnicodeBlock::GreekExtended,
+    UnicodeBlock::GeneralPunctuation,
+    UnicodeBlock::SuperscriptsAndSubscripts,
+    UnicodeBlock::CurrencySymbols,
+    UnicodeBlock::CombiningMarksForSymbols,
+    UnicodeBlock::LetterlikeSymbols,
+    UnicodeBlock::NumberForms,
+    UnicodeBlock::Arrows,
+    UnicodeBlock::MathematicalOperators,
+    UnicodeBlock::MiscellaneousTechnical,
+    UnicodeBlock::ControlPictures,
+    UnicodeBlock::OpticalCharacterRecognition,
+    UnicodeBlock::EnclosedAlphanumerics,
+    UnicodeBlock::BoxDrawing,
+    UnicodeBlock::BlockElements,
+    UnicodeBlock::GeometricShapes,
+    UnicodeBlock::MiscellaneousSymbols,
+    UnicodeBlock::Dingbats,
+    UnicodeBlock::MiscellaneousMathematicalSymbolsA,
+    UnicodeBlock::SupplementalArrowsA,
+    UnicodeBlock::BraillePatterns,
+    UnicodeBlock::SupplementalArrowsB,
+    UnicodeBlock::MiscellaneousMathematicalSymbolsB,
+    UnicodeBlock::SupplementalMathematicalOperators,
+    UnicodeBlock::MiscellaneousSymbolsAndArrows,
+    UnicodeBlock::Glagolitic,
+    UnicodeBlock::LatinExtendedC,
+    UnicodeBlock::Coptic,
+    UnicodeBlock::GeorgianSupplement,
+    UnicodeBlock::Tifinagh,
+    UnicodeBlock::EthiopicExtended,
+    UnicodeBlock::CyrillicExtendedA,
+    UnicodeBlock::SupplementalPunctuation,
+    UnicodeBlock::CjkRadicalsSupplement,
+    UnicodeBlock::KangxiRadicals,
+    UnicodeBlock::IdeographicDescriptionCharacters,
+    UnicodeBlock::CjkSymbolsAndPunctuation,
+    UnicodeBlock::Hiragana,
+    UnicodeBlock::Katakana,
+    UnicodeBlock::Bopomofo,
+    UnicodeBlock::HangulCompatibilityJamo,
+    UnicodeBlock::Kanbun,
+    UnicodeBlock::BopomofoExtended,
+    UnicodeBlock::CjkStrokes,
+    UnicodeBlock::KatakanaPhoneticExtensions,
+    UnicodeBlock::EnclosedCjkLettersAndMonths,
+    UnicodeBlock::CjkCompatibility,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionA,
+    UnicodeBlock::YijingHexagramSymbols,
+    UnicodeBlock::CjkUnifiedIdeographs,
+    UnicodeBlock::YiSyllables,
+    UnicodeBlock::YiRadicals,
+    UnicodeBlock::Lisu,
+    UnicodeBlock::Vai,
+    UnicodeBlock::CyrillicExtendedB,
+    UnicodeBlock::Bamum,
+    UnicodeBlock::ModifierToneLetters,
+    UnicodeBlock::LatinExtendedD,
+    UnicodeBlock::SylotiNagri,
+    UnicodeBlock::CommonIndicNumberForms,
+    UnicodeBlock::PhagsPa,
+    UnicodeBlock::Saurashtra,
+    UnicodeBlock::DevanagariExtended,
+    UnicodeBlock::KayahLi,
+    UnicodeBlock::Rejang,
+    UnicodeBlock::HangulJamoExtendedA,
+    UnicodeBlock::Javanese,
+    UnicodeBlock::MyanmarExtendedB,
+    UnicodeBlock::Cham,
+    UnicodeBlock::MyanmarExtendedA,
+    UnicodeBlock::TaiViet,
+    UnicodeBlock::MeeteiMayekExtensions,
+    UnicodeBlock::EthiopicExtendedA,
+    UnicodeBlock::LatinExtendedE,
+    UnicodeBlock::CherokeeSupplement,
+    UnicodeBlock::MeeteiMayek,
+    UnicodeBlock::HangulSyllables,
+    UnicodeBlock::HangulJamoExtendedB,
+    UnicodeBlock::HighSurrogates,
+    UnicodeBlock::HighPrivateUseSurrogates,
+    UnicodeBlock::LowSurrogates,
+    UnicodeBlock::PrivateUseArea,
+    UnicodeBlock::CjkCompatibilityIdeographs,
+    UnicodeBlock::AlphabeticPresentationForms,
+    UnicodeBlock::ArabicPresentationFormsA,
+    UnicodeBlock::VariationSelectors,
+    UnicodeBlock::VerticalForms,
+    UnicodeBlock::CombiningHalfMarks,
+    UnicodeBlock::CjkCompatibilityForms,
+    UnicodeBlock::SmallFormVariants,
+    UnicodeBlock::ArabicPresentationFormsB,
+    UnicodeBlock::HalfwidthAndFullwidthForms,
+    UnicodeBlock::Specials,
+    UnicodeBlock::LinearBSyllabary,
+    UnicodeBlock::LinearBIdeograms,
+    UnicodeBlock::AegeanNumbers,
+    UnicodeBlock::AncientGreekNumbers,
+    UnicodeBlock::AncientSymbols,
+    UnicodeBlock::PhaistosDisc,
+    UnicodeBlock::Lycian,
+    UnicodeBlock::Carian,
+    UnicodeBlock::CopticEpactNumbers,
+    UnicodeBlock::OldItalic,
+    UnicodeBlock::Gothic,
+    UnicodeBlock::OldPermic,
+    UnicodeBlock::Ugaritic,
+    UnicodeBlock::OldPersian,
+    UnicodeBlock::Deseret,
+    UnicodeBlock::Shavian,
+    UnicodeBlock::Osmanya,
+    UnicodeBlock::Osage,
+    UnicodeBlock::Elbasan,
+    UnicodeBlock::CaucasianAlbanian,
+    UnicodeBlock::LinearA,
+    UnicodeBlock::CypriotSyllabary,
+    UnicodeBlock::ImperialAramaic,
+    UnicodeBlock::Palmyrene,
+    UnicodeBlock::Nabataean,
+    UnicodeBlock::Hatran,
+    UnicodeBlock::Phoenician,
+    UnicodeBlock::Lydian,
+    UnicodeBlock::MeroiticHieroglyphs,
+    UnicodeBlock::MeroiticCursive,
+    UnicodeBlock::Kharoshthi,
+    UnicodeBlock::OldSouthArabian,
+    UnicodeBlock::OldNorthArabian,
+    UnicodeBlock::Manichaean,
+    UnicodeBlock::Avestan,
+    UnicodeBlock::InscriptionalParthian,
+    UnicodeBlock::InscriptionalPahlavi,
+    UnicodeBlock::PsalterPahlavi,
+    UnicodeBlock::OldTurkic,
+    UnicodeBlock::OldHungarian,
+    UnicodeBlock::HanifiRohingya,
+    UnicodeBlock::RumiNumeralSymbols,
+    UnicodeBlock::Yezidi,
+    UnicodeBlock::OldSogdian,
+    UnicodeBlock::Sogdian,
+    UnicodeBlock::Chorasmian,
+    UnicodeBlock::Elymaic,
+    UnicodeBlock::Brahmi,
+    UnicodeBlock::Kaithi,
+    UnicodeBlock::SoraSompeng,
+    UnicodeBlock::Chakma,
+    UnicodeBlock::Mahajani,
+    UnicodeBlock::Sharada,
+    UnicodeBlock::SinhalaArchaicNumbers,
+    UnicodeBlock::Khojki,
+    UnicodeBlock::Multani,
+    UnicodeBlock::Khudawadi,
+    UnicodeBlock::Grantha,
+    UnicodeBlock::Newa,
+    UnicodeBlock::Tirhuta,
+    UnicodeBlock::Siddham,
+    UnicodeBlock::Modi,
+    UnicodeBlock::MongolianSupplement,
+    UnicodeBlock::Takri,
+    UnicodeBlock::Ahom,
+    UnicodeBlock::Dogra,
+    UnicodeBlock::WarangCiti,
+    UnicodeBlock::DivesAkuru,
+    UnicodeBlock::Nandinagari,
+    UnicodeBlock::ZanabazarSquare,
+    UnicodeBlock::Soyombo,
+    UnicodeBlock::PauCinHau,
+    UnicodeBlock::Bhaiksuki,
+    UnicodeBlock::Marchen,
+    UnicodeBlock::MasaramGondi,
+    UnicodeBlock::GunjalaGondi,
+    UnicodeBlock::Makasar,
+    UnicodeBlock::LisuSupplement,
+    UnicodeBlock::TamilSupplement,
+    UnicodeBlock::Cuneiform,
+    UnicodeBlock::CuneiformNumbersAndPunctuation,
+    UnicodeBlock::EarlyDynasticCuneiform,
+    UnicodeBlock::EgyptianHieroglyphs,
+    UnicodeBlock::EgyptianHieroglyphFormatControls,
+    UnicodeBlock::AnatolianHieroglyphs,
+    UnicodeBlock::BamumSupplement,
+    UnicodeBlock::Mro,
+    UnicodeBlock::BassaVah,
+    UnicodeBlock::PahawhHmong,
+    UnicodeBlock::Medefaidrin,
+    UnicodeBlock::Miao,
+    UnicodeBlock::IdeographicSymbolsAndPunctuation,
+    UnicodeBlock::Tangut,
+    UnicodeBlock::TangutComponents,
+    UnicodeBlock::KhitanSmallScript,
+    UnicodeBlock::TangutSupplement,
+    UnicodeBlock::KanaSupplement,
+    UnicodeBlock::KanaExtendedA,
+    UnicodeBlock::SmallKanaExtension,
+    UnicodeBlock::Nushu,
+    UnicodeBlock::Duployan,
+    UnicodeBlock::ShorthandFormatControls,
+    UnicodeBlock::ByzantineMusicalSymbols,
+    UnicodeBlock::MusicalSymbols,
+    UnicodeBlock::AncientGreekMusicalNotation,
+    UnicodeBlock::MayanNumerals,
+    UnicodeBlock::TaiXuanJingSymbols,
+    UnicodeBlock::CountingRodNumerals,
+    UnicodeBlock::MathematicalAlphanumericSymbols,
+    UnicodeBlock::SuttonSignwriting,
+    UnicodeBlock::GlagoliticSupplement,
+    UnicodeBlock::NyiakengPuachueHmong,
+    UnicodeBlock::Wancho,
+    UnicodeBlock::MendeKikakui,
+    UnicodeBlock::Adlam,
+    UnicodeBlock::IndicSiyaqNumbers,
+    UnicodeBlock::OttomanSiyaqNumbers,
+    UnicodeBlock::ArabicMathematicalAlphabeticSymbols,
+    UnicodeBlock::MahjongTiles,
+    UnicodeBlock::DominoTiles,
+    UnicodeBlock::PlayingCards,
+    UnicodeBlock::EnclosedAlphanumericSupplement,
+    UnicodeBlock::EnclosedIdeographicSupplement,
+    UnicodeBlock::MiscellaneousSymbolsAndPictographs,
+    UnicodeBlock::Emoticons,
+    UnicodeBlock::OrnamentalDingbats,
+    UnicodeBlock::TransportAndMapSymbols,
+    UnicodeBlock::AlchemicalSymbols,
+    UnicodeBlock::GeometricShapesExtended,
+    UnicodeBlock::SupplementalArrowsC,
+    UnicodeBlock::SupplementalSymbolsAndPictographs,
+    UnicodeBlock::ChessSymbols,
+    UnicodeBlock::SymbolsAndPictographsExtendedA,
+    UnicodeBlock::SymbolsForLegacyComputing,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionB,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionC,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionD,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionE,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionF,
+    UnicodeBlock::CjkCompatibilityIdeographsSupplement,
+    UnicodeBlock::CjkUnifiedIdeographsExtensionG,
+    UnicodeBlock::Tags,
+    UnicodeBlock::VariationSelectorsSupplement,
+    UnicodeBlock::SupplementaryPrivateUseAreaA,
+    UnicodeBlock::SupplementaryPrivateUseAreaB,
+];
+
+pub(super) static BLOCK_NAMES: [&str; 308] = [
+    "BASIC_LATIN",
+    "LATIN_1_SUPPLEMENT",
+    "LATIN_EXTENDED_A",
+    "LATIN_EXTENDED_B",
+    "IPA_EXTENSIONS",
+    "SPACING_MODIFIER_LETTERS",
+    "COMBINING_DIACRITICAL_MARKS",
+    "GREEK",
+    "CYRILLIC",
+    "CYRILLIC_SUPPLEMENTARY",
+    "ARMENIAN",
+    "HEBREW",
+    "ARABIC",
+    "SYRIAC",
+    "ARABIC_SUPPLEMENT",
+    "THAANA",
+    "NKO",
+    "SAMARITAN",
+    "MANDAIC",
+    "SYRIAC_SUPPLEMENT",
+    "ARABIC_EXTENDED_A",
+    "DEVANAGARI",
+    "BENGALI",
+    "GURMUKHI",
+    "GUJARATI",
+    "ORIYA",
+    "TAMIL",
+    "TELUGU",
+    "KANNADA",
+    "MALAYALAM",
+    "SINHALA",
+    "THAI",
+    "LAO",
+    "TIBETAN",
+    "MYANMAR",
+    "GEORGIAN",
+    "HANGUL_JAMO",
+    "ETHIOPIC",
+    "ETHIOPIC_SUPPLEMENT",
+    "CHEROKEE",
+    "UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS",
+    "OGHAM",
+    "RUNIC",
+    "TAGALOG",
+    "HANUNOO",
+    "BUHID",
+    "TAGBANWA",
+    "KHMER",
+    "MONGOLIAN",
+    "UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS_EXTENDED",
+    "LIMBU",
+    "TAI_LE",
+    "NEW_TAI_LUE",
+    "KHMER_SYMBOLS",
+    "BUGINESE",
+    "TAI_THAM",
+    "COMBINING_DIACRITICAL_MARKS_EXTENDED",
+    "BALINESE",
+    "SUNDANESE",
+    "BATAK",
+    "LEPCHA",
+    "OL_CHIKI",
+    "CYRILLIC_EXTENDED_C",
+    "GEORGIAN_EXTENDED",
+    "SUNDANESE_SUPPLEMENT",
+    "VEDIC_EXTENSIONS",
+    "PHONETIC_EXTENSIONS",
+    "PHONETIC_EXTENSIONS_SUPPLEMENT",
+    "COMBINING_DIACRITICAL_MARKS_SUPPLEMENT",
+    "LATIN_EXTENDED_ADDITIONAL",
+    "GREEK_EXTENDED",
+    "GENERAL_PUNCTUATION",
+    "SUPERSCRIPTS_AND_SUBSCRIPTS",
+    "CURRENCY_SYMBOLS",
+    "COMBINING_MARKS_FOR_SYMBOLS",
+    "LETTERLIKE_SYMBOLS",
+    "NUMBER_FORMS",
+    "ARROWS",
+    "MATHEMATICAL_OPERATORS",
+    "MISCELLANEOUS_TECHNICAL",
+    "CONTROL_PICTURES",
+    "OPTICAL_CHARACTER_RECOGNITION",
+    "ENCLOSED_ALPHANUMERICS",
+    "BOX_DRAWING",
+    "BLOCK_ELEMENTS",
+    "GEOMETRIC_SHAPES",
+    "MISCELLANEOUS_SYMBOLS",
+    "DINGBATS",
+    "MISCELLANEOUS_MATHEMATICAL_SYMBOLS_A",
+    "SUPPLEMENTAL_ARROWS_A",
+    "BRAILLE_PATTERNS",
+    "SUPPLEMENTAL_ARROWS_B",
+    "MISCELLANEOUS_MATHEMATICAL_SYMBOLS_B",
+    "SUPPLEMENTAL_MATHEMATICAL_OPERATORS",
+    "MISCELLANEOUS_SYMBOLS_AND_ARROWS",
+    "GLAGOLITIC",
+    "LATIN_EXTENDED_C",
+    "COPTIC",
+    "GEORGIAN_SUPPLEMENT",
+    "TIFINAGH",
+    "ETHIOPIC_EXTENDED",
+    "CYRILLIC_EXTENDED_A",
+    "SUPPLEMENTAL_PUNCTUATION",
+    "CJK_RADICALS_SUPPLEMENT",
+    "KANGXI_RADICALS",
+    "IDEOGRAPHIC_DESCRIPTION_CHARACTERS",
+    "CJK_SYMBOLS_AND_PUNCTUATION",
+    "HIRAGANA",
+    "KATAKANA",
+    "BOPOMOFO",
+    "HANGUL_COMPATIBILITY_JAMO",
+    "KANBUN",
+    "BOPOMOFO_EXTENDED",
+    "CJK_STROKES",
+    "KATAKANA_PHONETIC_EXTENSIONS",
+    "ENCLOSED_CJK_LETTERS_AND_MONTHS",
+    "CJK_COMPATIBILITY",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_A",
+    "YIJING_HEXAGRAM_SYMBOLS",
+    "CJK_UNIFIED_IDEOGRAPHS",
+    "YI_SYLLABLES",
+    "YI_RADICALS",
+    "LISU",
+    "VAI",
+    "CYRILLIC_EXTENDED_B",
+    "BAMUM",
+    "MODIFIER_TONE_LETTERS",
+    "LATIN_EXTENDED_D",
+    "SYLOTI_NAGRI",
+    "COMMON_INDIC_NUMBER_FORMS",
+    "PHAGS_PA",
+    "SAURASHTRA",
+    "DEVANAGARI_EXTENDED",
+    "KAYAH_LI",
+    "REJANG",
+    "HANGUL_JAMO_EXTENDED_A",
+    "JAVANESE",
+    "MYANMAR_EXTENDED_B",
+    "CHAM",
+    "MYANMAR_EXTENDED_A",
+    "TAI_VIET",
+    "MEETEI_MAYEK_EXTENSIONS",
+    "ETHIOPIC_EXTENDED_A",
+    "LATIN_EXTENDED_E",
+    "CHEROKEE_SUPPLEMENT",
+    "MEETEI_MAYEK",
+    "HANGUL_SYLLABLES",
+    "HANGUL_JAMO_EXTENDED_B",
+    "HIGH_SURROGATES",
+    "HIGH_PRIVATE_USE_SURROGATES",
+    "LOW_SURROGATES",
+    "PRIVATE_USE_AREA",
+    "CJK_COMPATIBILITY_IDEOGRAPHS",
+    "ALPHABETIC_PRESENTATION_FORMS",
+    "ARABIC_PRESENTATION_FORMS_A",
+    "VARIATION_SELECTORS",
+    "VERTICAL_FORMS",
+    "COMBINING_HALF_MARKS",
+    "CJK_COMPATIBILITY_FORMS",
+    "SMALL_FORM_VARIANTS",
+    "ARABIC_PRESENTATION_FORMS_B",
+    "HALFWIDTH_AND_FULLWIDTH_FORMS",
+    "SPECIALS",
+    "LINEAR_B_SYLLABARY",
+    "LINEAR_B_IDEOGRAMS",
+    "AEGEAN_NUMBERS",
+    "ANCIENT_GREEK_NUMBERS",
+    "ANCIENT_SYMBOLS",
+    "PHAISTOS_DISC",
+    "LYCIAN",
+    "CARIAN",
+    "COPTIC_EPACT_NUMBERS",
+    "OLD_ITALIC",
+    "GOTHIC",
+    "OLD_PERMIC",
+    "UGARITIC",
+    "OLD_PERSIAN",
+    "DESERET",
+    "SHAVIAN",
+    "OSMANYA",
+    "OSAGE",
+    "ELBASAN",
+    "CAUCASIAN_ALBANIAN",
+    "LINEAR_A",
+    "CYPRIOT_SYLLABARY",
+    "IMPERIAL_ARAMAIC",
+    "PALMYRENE",
+    "NABATAEAN",
+    "HATRAN",
+    "PHOENICIAN",
+    "LYDIAN",
+    "MEROITIC_HIEROGLYPHS",
+    "MEROITIC_CURSIVE",
+    "KHAROSHTHI",
+    "OLD_SOUTH_ARABIAN",
+    "OLD_NORTH_ARABIAN",
+    "MANICHAEAN",
+    "AVESTAN",
+    "INSCRIPTIONAL_PARTHIAN",
+    "INSCRIPTIONAL_PAHLAVI",
+    "PSALTER_PAHLAVI",
+    "OLD_TURKIC",
+    "OLD_HUNGARIAN",
+    "HANIFI_ROHINGYA",
+    "RUMI_NUMERAL_SYMBOLS",
+    "YEZIDI",
+    "OLD_SOGDIAN",
+    "SOGDIAN",
+    "CHORASMIAN",
+    "ELYMAIC",
+    "BRAHMI",
+    "KAITHI",
+    "SORA_SOMPENG",
+    "CHAKMA",
+    "MAHAJANI",
+    "SHARADA",
+    "SINHALA_ARCHAIC_NUMBERS",
+    "KHOJKI",
+    "MULTANI",
+    "KHUDAWADI",
+    "GRANTHA",
+    "NEWA",
+    "TIRHUTA",
+    "SIDDHAM",
+    "MODI",
+    "MONGOLIAN_SUPPLEMENT",
+    "TAKRI",
+    "AHOM",
+    "DOGRA",
+    "WARANG_CITI",
+    "DIVES_AKURU",
+    "NANDINAGARI",
+    "ZANABAZAR_SQUARE",
+    "SOYOMBO",
+    "PAU_CIN_HAU",
+    "BHAIKSUKI",
+    "MARCHEN",
+    "MASARAM_GONDI",
+    "GUNJALA_GONDI",
+    "MAKASAR",
+    "LISU_SUPPLEMENT",
+    "TAMIL_SUPPLEMENT",
+    "CUNEIFORM",
+    "CUNEIFORM_NUMBERS_AND_PUNCTUATION",
+    "EARLY_DYNASTIC_CUNEIFORM",
+    "EGYPTIAN_HIEROGLYPHS",
+    "EGYPTIAN_HIEROGLYPH_FORMAT_CONTROLS",
+    "ANATOLIAN_HIEROGLYPHS",
+    "BAMUM_SUPPLEMENT",
+    "MRO",
+    "BASSA_VAH",
+    "PAHAWH_HMONG",
+    "MEDEFAIDRIN",
+    "MIAO",
+    "IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION",
+    "TANGUT",
+    "TANGUT_COMPONENTS",
+    "KHITAN_SMALL_SCRIPT",
+    "TANGUT_SUPPLEMENT",
+    "KANA_SUPPLEMENT",
+    "KANA_EXTENDED_A",
+    "SMALL_KANA_EXTENSION",
+    "NUSHU",
+    "DUPLOYAN",
+    "SHORTHAND_FORMAT_CONTROLS",
+    "BYZANTINE_MUSICAL_SYMBOLS",
+    "MUSICAL_SYMBOLS",
+    "ANCIENT_GREEK_MUSICAL_NOTATION",
+    "MAYAN_NUMERALS",
+    "TAI_XUAN_JING_SYMBOLS",
+    "COUNTING_ROD_NUMERALS",
+    "MATHEMATICAL_ALPHANUMERIC_SYMBOLS",
+    "SUTTON_SIGNWRITING",
+    "GLAGOLITIC_SUPPLEMENT",
+    "NYIAKENG_PUACHUE_HMONG",
+    "WANCHO",
+    "MENDE_KIKAKUI",
+    "ADLAM",
+    "INDIC_SIYAQ_NUMBERS",
+    "OTTOMAN_SIYAQ_NUMBERS",
+    "ARABIC_MATHEMATICAL_ALPHABETIC_SYMBOLS",
+    "MAHJONG_TILES",
+    "DOMINO_TILES",
+    "PLAYING_CARDS",
+    "ENCLOSED_ALPHANUMERIC_SUPPLEMENT",
+    "ENCLOSED_IDEOGRAPHIC_SUPPLEMENT",
+    "MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS",
+    "EMOTICONS",
+    "ORNAMENTAL_DINGBATS",
+    "TRANSPORT_AND_MAP_SYMBOLS",
+    "ALCHEMICAL_SYMBOLS",
+    "GEOMETRIC_SHAPES_EXTENDED",
+    "SUPPLEMENTAL_ARROWS_C",
+    "SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS",
+    "CHESS_SYMBOLS",
+    "SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A",
+    "SYMBOLS_FOR_LEGACY_COMPUTING",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_B",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_C",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_D",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_E",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_F",
+    "CJK_COMPATIBILITY_IDEOGRAPHS_SUPPLEMENT",
+    "CJK_UNIFIED_IDEOGRAPHS_EXTENSION_G",
+    "TAGS",
+    "VARIATION_SELECTORS_SUPPLEMENT",
+    "SUPPLEMENTARY_PRIVATE_USE_AREA_A",
+    "SUPPLEMENTARY_PRIVATE_USE_AREA_B",
+];
+
+pub(super) static BLOCKS: [(u32, u32, UnicodeBlock); 308] = [
+    (0x0000, 0x007F, UnicodeBlock::BasicLatin),
+    (0x0080, 0x00FF, UnicodeBlock::Latin1Supplement),
+    (0x0100, 0x017F, UnicodeBlock::LatinExtendedA),
+    (0x0180, 0x024F, UnicodeBlock::LatinExtendedB),
+    (0x0250, 0x02AF, UnicodeBlock::IpaExtensions),
+    (0x02B0, 0x02FF, UnicodeBlock::SpacingModifierLetters),
+    (0x0300, 0x036F, UnicodeBlock::CombiningDiacriticalMarks),
+    (0x0370, 0x03FF, UnicodeBlock::Greek),
+    (0x0400, 0x04FF, UnicodeBlock::Cyrillic),
+    (0x0500, 0x052F, UnicodeBlock::CyrillicSupplementary),
+    (0x0530, 0x058F, UnicodeBlock::Armenian),
+    (0x0590, 0x05FF, UnicodeBlock::Hebrew),
+    (0x0600, 0x06FF, UnicodeBlock::Arabic),
+    (0x0700, 0x074F, UnicodeBlock::Syriac),
+    (0x0750, 0x077F, UnicodeBlock::ArabicSupplement),
+    (0x0780, 0x07BF, UnicodeBlock::Thaana),
+    (0x07C0, 0x07FF, UnicodeBlock::Nko),
+    (0x0800, 0x083F, UnicodeBlock::Samaritan),
+    (0x0840, 0x085F, UnicodeBlock::Mandaic),
+    (0x0860, 0x086F, UnicodeBlock::SyriacSupplement),
+    (0x08A0, 0x08FF, UnicodeBlock::ArabicExtendedA),
+    (0x0900, 0x097F, UnicodeBlock::Devanagari),
+    (0x0980, 0x09FF, UnicodeBlock::Bengali),
+    (0x0A00, 0x0A7F, UnicodeBlock::Gurmukhi),
+    (0x0A80, 0x0AFF, UnicodeBlock::Gujarati),
+    (0x0B00, 0x0B7F, UnicodeBlock::Oriya),
+    (0x0B80, 0x0BFF, UnicodeBlock::Tamil),
+    (0x0C00, 0x0C7F, UnicodeBlock::Telugu),
+    (0x0C80, 0x0CFF, UnicodeBlock::Kannada),
+    (0x0D00, 0x0D7F, UnicodeBlock::Malayalam),
+    (0x0D80, 0x0DFF, UnicodeBlock::Sinhala),
+    (0x0E00, 0x0E7F, UnicodeBlock::Thai),
+    (0x0E80, 0x0EFF, UnicodeBlock::Lao),
+    (0x0F00, 0x0FFF, UnicodeBlock::Tibetan),
+    (0x1000, 0x109F, UnicodeBlock::Myanmar),
+    (0x10A0, 0x10FF, UnicodeBlock::Georgian),
+    (0x1100, 0x11FF, UnicodeBlock::HangulJamo),
+    (0x1200, 0x137F, UnicodeBlock::Ethiopic),
+    (0x1380, 0x139F, UnicodeBlock::EthiopicSupplement),
+    (0x13A0, 0x13FF, UnicodeBlock::Cherokee),
+    (0x1400, 0x167F, UnicodeBlock::UnifiedCanadianAboriginalSyllabics),
+    (0x1680, 0x169F, UnicodeBlock::Ogham),
+    (0x16A0, 0x16FF, UnicodeBlock::Runic),
+    (0x1700, 0x171F, UnicodeBlock::Tagalog),
+    (0x1720, 0x173F, UnicodeBlock::Hanunoo),
+    (0x1740, 0x175F, UnicodeBlock::Buhid),
+    (0x1760, 0x177F, UnicodeBlock::Tagbanwa),
+    (0x1780, 0x17FF, UnicodeBlock::Khmer),
+    (0x1800, 0x18AF, UnicodeBlock::Mongolian),
+    (0x18B0, 0x18FF, UnicodeBlock::UnifiedCanadianAboriginalSyllabicsExtended),
+    (0x1900, 0x194F, UnicodeBlock::Limbu),
+    (0x1950, 0x197F, UnicodeBlock::TaiLe),
+    (0x1980, 0x19DF, UnicodeBlock::NewTaiLue),
+    (0x19E0, 0x19FF, UnicodeBlock::KhmerSymbols),
+    (0x1A00, 0x1A1F, UnicodeBlock::Buginese),
+    (0x1A20, 0x1AAF, UnicodeBlock::TaiTham),
+    (0x1AB0, 0x1AFF, UnicodeBlock::CombiningDiacriticalMarksExtended),
+    (0x1B00, 0x1B7F, UnicodeBlock::Balinese),
+    (0x1B80, 0x1BBF, UnicodeBlock::Sundanese),
+    (0x1BC0, 0x1BFF, UnicodeBlock::Batak),
+    (0x1C00, 0x1C4F, UnicodeBlock::Lepcha),
+    (0x1C50, 0x1C7F, UnicodeBlock::OlChiki),
+    (0x1C80, 0x1C8F, UnicodeBlock::CyrillicExtendedC),
+    (0x1C90, 0x1CBF, UnicodeBlock::GeorgianExtended),
+    (0x1CC0, 0x1CCF, UnicodeBlock::SundaneseSupplement),
+    (0x1CD0, 0x1CFF, UnicodeBlock::VedicExtensions),
+    (0x1D00, 0x1D7F, UnicodeBlock::PhoneticExtensions),
+    (0x1D80, 0x1DBF, UnicodeBlock::PhoneticExtensionsSupplement),
+    (0x1DC0, 0x1DFF, UnicodeBlock::CombiningDiacriticalMarksSupplement),
+    (0x1E00, 0x1EFF, UnicodeBlock::LatinExtendedAdditional),
+    (0x1F00, 0x1FFF, UnicodeBlock::GreekExtended),
+    (0x2000, 0x206F, UnicodeBlock::GeneralPunctuation),
+    (0x2070, 0x209F, UnicodeBlock::SuperscriptsAndSubscripts),
+    (0x20A0, 0x20CF, UnicodeBlock::CurrencySymbols),
+    (0x20D0, 0x20FF, UnicodeBlock::CombiningMarksForSymbols),
+    (0x2100, 0x214F, UnicodeBlock::LetterlikeSymbols),
+    (0x2150, 0x218F, UnicodeBlock::NumberForms),
+    (0x2190, 0x21FF, UnicodeBlock::Arrows),
+    (0x2200, 0x22FF, UnicodeBlock::MathematicalOperators),
+    (0x2300, 0x23FF, UnicodeBlock::MiscellaneousTechnical),
+    (0x2400, 0x243F, UnicodeBlock::ControlPictures),
+    (0x2440, 0x245F, UnicodeBlock::OpticalCharacterRecognition),
+    (0x2460, 0x24FF, UnicodeBlock::EnclosedAlphanumerics),
+    (0x2500, 0x257F, UnicodeBlock::BoxDrawing),
+    (0x2580, 0x259F, UnicodeBlock::BlockElements),
+    (0x25A0, 0x25FF, UnicodeBlock::GeometricShapes),
+    (0x2600, 0x26FF, UnicodeBlock::MiscellaneousSymbols),
+    (0x2700, 0x27BF, UnicodeBlock::Dingbats),
+    (0x27C0, 0x27EF, UnicodeBlock::MiscellaneousMathematicalSymbolsA),
+    (0x27F0, 0x27FF, UnicodeBlock::SupplementalArrowsA),
+    (0x2800, 0x28FF, UnicodeBlock::BraillePatterns),
+    (0x2900, 0x297F, UnicodeBlock::SupplementalArrowsB),
+    (0x2980, 0x29FF, UnicodeBlock::MiscellaneousMathematicalSymbolsB),
+    (0x2A00, 0x2AFF, UnicodeBlock::SupplementalMathematicalOperators),
+    (0x2B00, 0x2BFF, UnicodeBlock::MiscellaneousSymbolsAndArrows),
+    (0x2C00, 0x2C5F, UnicodeBlock::Glagolitic),
+    (0x2C60, 0x2C7F, UnicodeBlock::LatinExtendedC),
+    (0x2C80, 0x2CFF, UnicodeBlock::Coptic),
+    (0x2D00, 0x2D2F, UnicodeBlock::GeorgianSupplement),
+    (0x2D30, 0x2D7F, UnicodeBlock::Tifinagh),
+    (0x2D80, 0x2DDF, UnicodeBlock::EthiopicExtended),
+    (0x2DE0, 0x2DFF, UnicodeBlock::CyrillicExtendedA),
+    (0x2E00, 0x2E7F, UnicodeBlock::SupplementalPunctuation),
+    (0x2E80, 0x2EFF, UnicodeBlock::CjkRadicalsSupplement),
+    (0x2F00, 0x2FDF, UnicodeBlock::KangxiRadicals),
+    (0x2FF0, 0x2FFF, UnicodeBlock::IdeographicDescriptionCharacters),
+    (0x3000, 0x303F, UnicodeBlock::CjkSymbolsAndPunctuation),
+    (0x3040, 0x309F, UnicodeBlock::Hiragana),
+    (0x30A0, 0x30FF, UnicodeBlock::Katakana),
+    (0x3100, 0x312F, UnicodeBlock::Bopomofo),
+    (0x3130, 0x318F, UnicodeBlock::HangulCompatibilityJamo),
+    (0x3190, 0x319F, UnicodeBlock::Kanbun),
+    (0x31A0, 0x31BF, UnicodeBlock::BopomofoExtended),
+    (0x31C0, 0x31EF, UnicodeBlock::CjkStrokes),
+    (0x31F0, 0x31FF, UnicodeBlock::KatakanaPhoneticExtensions),
+    (0x3200, 0x32FF, UnicodeBlock::EnclosedCjkLettersAndMonths),
+    (0x3300, 0x33FF, UnicodeBlock::CjkCompatibility),
+    (0x3400, 0x4DBF, UnicodeBlock::CjkUnifiedIdeographsExtensionA),
+    (0x4DC0, 0x4DFF, UnicodeBlock::YijingHexagramSymbols),
+    (0x4E00, 0x9FFF, UnicodeBlock::CjkUnifiedIdeographs),
+    (0xA000, 0xA48F, UnicodeBlock::YiSyllables),
+    (0xA490, 0xA4CF, UnicodeBlock::YiRadicals),
+    (0xA4D0, 0xA4FF, UnicodeBlock::Lisu),
+    (0xA500, 0xA63F, UnicodeBlock::Vai),
+    (0xA640, 0xA69F, UnicodeBlock::CyrillicExtendedB),
+    (0xA6A0, 0xA6FF, UnicodeBlock::Bamum),
+    (0xA700, 0xA71F, UnicodeBlock::ModifierToneLetters),
+    (0xA720, 0xA7FF, UnicodeBlock::LatinExtendedD),
+    (0xA800, 0xA82F, UnicodeBlock::SylotiNagri),
+    (0xA830, 0xA83F, UnicodeBlock::CommonIndicNumberForms),
+    (0xA840, 0xA87F, UnicodeBlock::PhagsPa),
+    (0xA880, 0xA8DF, UnicodeBlock::Saurashtra),
+    (0xA8E0, 0xA8FF, UnicodeBlock::DevanagariExtended),
+    (0xA900, 0xA92F, UnicodeBlock::KayahLi),
+    (0xA930, 0xA95F, UnicodeBlock::Rejang),
+    (0xA960, 0xA97F, UnicodeBlock::HangulJamoExtendedA),
+    (0xA980, 0xA9DF, UnicodeBlock::Javanese),
+    (0xA9E0, 0xA9FF, UnicodeBlock::MyanmarExtendedB),
+    (0xAA00, 0xAA5F, UnicodeBlock::Cham),
+    (0xAA60, 0xAA7F, UnicodeBlock::MyanmarExtendedA),
+    (0xAA80, 0xAADF, UnicodeBlock::TaiViet),
+    (0xAAE0, 0xAAFF, UnicodeBlock::MeeteiMayekExtensions),
+    (0xAB00, 0xAB2F, UnicodeBlock::EthiopicExtendedA),
+    (0xAB30, 0xAB6F, UnicodeBlock::LatinExtendedE),
+    (0xAB70, 0xABBF, UnicodeBlock::CherokeeSupplement),
+    (0xABC0, 0xABFF, UnicodeBlock::MeeteiMayek),
+    (0xAC00, 0xD7AF, UnicodeBlock::HangulSyllables),
+    (0xD7B0, 0xD7FF, UnicodeBlock::HangulJamoExtendedB),
+    (0xD800, 0xDB7F, UnicodeBlock::HighSurrogates),
+    (0xDB80, 0xDBFF, UnicodeBlock::HighPrivateUseSurrogates),
+    (0xDC00, 0xDFFF, UnicodeBlock::LowSurrogates),
+    (0xE000, 0xF8FF, UnicodeBlock::PrivateUseArea),
+    (0xF900, 0xFAFF, UnicodeBlock::CjkCompatibilityIdeographs),
+    (0xFB00, 0xFB4F, UnicodeBlock::AlphabeticPresentationForms),
+    (0xFB50, 0xFDFF, UnicodeBlock::ArabicPresentationFormsA),
+    (0xFE00, 0xFE0F, UnicodeBlock::VariationSelectors),
+    (0xFE10, 0xFE1F, UnicodeBlock::VerticalForms),
+    (0xFE20, 0xFE2F, UnicodeBlock::CombiningHalfMarks),
+    (0xFE30, 0xFE4F, UnicodeBlock::CjkCompatibilityForms),
+    (0xFE50, 0xFE6F, UnicodeBlock::SmallFormVariants),
+    (0xFE70, 0xFEFF, UnicodeBlock::ArabicPresentationFormsB),
+    (0xFF00, 0xFFEF, UnicodeBlock::HalfwidthAndFullwidthForms),
+    (0xFFF0, 0xFFFF, UnicodeBlock::Specials),
+    (0x10000, 0x1007F, UnicodeBlock::LinearBSyllabary),
+    (0x10080, 0x100FF, UnicodeBlock::LinearBIdeograms),
+    (0x10100, 0x1013F, UnicodeBlock::AegeanNumbers),
+    (0x10140, 0x1018F, UnicodeBlock::AncientGreekNumbers),
+    (0x10190, 0x101CF, UnicodeBlock::AncientSymbols),
+    (0x101D0, 0x101FF, UnicodeBlock::PhaistosDisc),
+    (0x10280, 0x1029F, UnicodeBlock::Lycian),
+    (0x102A0, 0x102DF, UnicodeBlock::Carian),
+    (0x102E0, 0x102FF, UnicodeBlock::CopticEpactNumbers),
+    (0x10300, 0x1032F, UnicodeBlock::OldItalic),
+    (0x10330, 0x1034F, UnicodeBlock::Gothic),
+    (0x10350, 0x1037F, UnicodeBlock::OldPermic),
+    (0x10380, 0x1039F, UnicodeBlock::Ugaritic),
+    (0x103A0, 0x103DF, UnicodeBlock::OldPersian),
+    (0x10400, 0x1044F, UnicodeBlock::Deseret),
+    (0x10450, 0x1047F, UnicodeBlock::Shavian),
+    (0x10480, 0x104AF, UnicodeBlock::Osmanya),
+    (0x104B0, 0x104FF, UnicodeBlock::Osage),
+    (0x10500, 0x1052F, UnicodeBlock::Elbasan),
+    (0x10530, 0x1056F, UnicodeBlock::CaucasianAlbanian),
+    (0x10600, 0x1077F, UnicodeBlock::LinearA),
+    (0x10800, 0x1083F, UnicodeBlock::CypriotSyllabary),
+    (0x10840, 0x1085F, UnicodeBlock::ImperialAramaic),
+    (0x10860, 0x1087F, UnicodeBlock::Palmyrene),
+    (0x10880, 0x108AF, UnicodeBlock::Nabataean),
+    (0x108E0, 0x108FF, UnicodeBlock::Hatran),
+    (0x10900, 0x1091F, UnicodeBlock::Phoenician),
+    (0x10920, 0x1093F, UnicodeBlock::Lydian),
+    (0x10980, 0x1099F, UnicodeBlock::MeroiticHieroglyphs),
+    (0x109A0, 0x109FF, UnicodeBlock::MeroiticCursive),
+    (0x10A00, 0x10A5F, UnicodeBlock::Kharoshthi),
+    (0x10A60, 0x10A7F, UnicodeBlock::OldSouthArabian),
+    (0x10A80, 0x10A9F, UnicodeBlock::OldNorthArabian),
+    (0x10AC0, 0x10AFF, UnicodeBlock::Manichaean),
+    (0x10B00, 0x10B3F, UnicodeBlock::Avestan),
+    (0x10B40, 0x10B5F, UnicodeBlock::InscriptionalParthian),
+    (0x10B60, 0x10B7F, UnicodeBlock::InscriptionalPahlavi),
+    (0x10B80, 0x10BAF, UnicodeBlock::PsalterPahlavi),
+    (0x10C00, 0x10C4F, UnicodeBlock::OldTurkic),
+    (0x10C80, 0x10CFF, UnicodeBlock::OldHungarian),
+    (0x10D00, 0x10D3F, UnicodeBlock::HanifiRohingya),
+    (0x10E60, 0x10E7F, UnicodeBlock::RumiNumeralSymbols),
+    (0x10E80, 0x10EBF, UnicodeBlock::Yezidi),
+    (0x10F00, 0x10F2F, UnicodeBlock::OldSogdian),
+    (0x10F30, 0x10F6F, UnicodeBlock::Sogdian),
+    (0x10FB0, 0x10FDF, UnicodeBlock::Chorasmian),
+    (0x10FE0, 0x10FFF, UnicodeBlock::Elymaic),
+    (0x11000, 0x1107F, UnicodeBlock::Brahmi),
+    (0x11080, 0x110CF, UnicodeBlock::Kaithi),
+    (0x110D0, 0x110FF, UnicodeBlock::SoraSompeng),
+    (0x11100, 0x1114F, UnicodeBlock::Chakma),
+    (0x11150, 0x1117F, UnicodeBlock::Mahajani),
+    (0x11180, 0x111DF, UnicodeBlock::Sharada),
+    (0x111E0, 0x111FF, UnicodeBlock::SinhalaArchaicNumbers),
+    (0x11200, 0x1124F, UnicodeBlock::Khojki),
+    (0x11280, 0x112AF, UnicodeBlock::Multani),
+    (0x112B0, 0x112FF, UnicodeBlock::Khudawadi),
+    (0x11300, 0x1137F, UnicodeBlock::Grantha),
+    (0x11400, 0x1147F, UnicodeBlock::Newa),
+    (0x11480, 0x114DF, UnicodeBlock::Tirhuta),
+    (0x11580, 0x115FF, UnicodeBlock::Siddham),
+    (0x11600, 0x1165F, UnicodeBlock::Modi),
+    (0x11660, 0x1167F, UnicodeBlock::MongolianSupplement),
+    (0x11680, 0x116CF, UnicodeBlock::Takri),
+    (0x11700, 0x1173F, UnicodeBlock::Ahom),
+    (0x11800, 0x1184F, UnicodeBlock::Dogra),
+    (0x118A0, 0x118FF, UnicodeBlock::WarangCiti),
+    (0x11900, 0x1195F, UnicodeBlock::DivesAkuru),
+    (0x119A0, 0x119FF, UnicodeBlock::Nandinagari),
+    (0x11A00, 0x11A4F, UnicodeBlock::ZanabazarSquare),
+    (0x11A50, 0x11AAF, UnicodeBlock::Soyombo),
+    (0x11AC0, 0x11AFF, UnicodeBlock::PauCinHau),
+    (0x11C00, 0x11C6F, UnicodeBlock::Bhaiksuki),
+    (0x11C70, 0x11CBF, UnicodeBlock::Marchen),
+    (0x11D00, 0x11D5F, UnicodeBlock::MasaramGondi),
+    (0x11D60, 0x11DAF, UnicodeBlock::GunjalaGondi),
+    (0x11EE0, 0x11EFF, UnicodeBlock::Makasar),
+    (0x11FB0, 0x11FBF, UnicodeBlock::LisuSupplement),
+    (0x11FC0, 0x11FFF, UnicodeBlock::TamilSupplement),
+    (0x12000, 0x123FF, UnicodeBlock::Cuneiform),
+    (0x12400, 0x1247F, UnicodeBlock::CuneiformNumbersAndPunctuation),
+    (0x12480, 0x1254F, UnicodeBlock::EarlyDynasticCuneiform),
+    (0x13000, 0x1342F, UnicodeBlock::EgyptianHieroglyphs),
+    (0x13430, 0x1343F, UnicodeBlock::EgyptianHieroglyphFormatControls),
+    (0x14400, 0x1467F, UnicodeBlock::AnatolianHieroglyphs),
+    (0x16800, 0x16A3F, UnicodeBlock::BamumSupplement),
+    (0x16A40, 0x16A6F, UnicodeBlock::Mro),
+    (0x16AD0, 0x16AFF, UnicodeBlock::BassaVah),
+    (0x16B00, 0x16B8F, UnicodeBlock::PahawhHmong),
+    (0x16E40, 0x16E9F, UnicodeBlock::Medefaidrin),
+    (0x16F00, 0x16F9F, UnicodeBlock::Miao),
+    (0x16FE0, 0x16FFF, UnicodeBlock::IdeographicSymbolsAndPunctuation),
+    (0x17000, 0x187FF, UnicodeBlock::Tangut),
+    (0x18800, 0x18AFF, UnicodeBlock::TangutComponents),
+    (0x18B00, 0x18CFF, UnicodeBlock::KhitanSmallScript),
+    (0x18D00, 0x18D8F, UnicodeBlock::TangutSupplement),
+    (0x1B000, 0x1B0FF, UnicodeBlock::KanaSupplement),
+    (0x1B100, 0x1B12F, UnicodeBlock::KanaExtendedA),
+    (0x1B130, 0x1B16F, UnicodeBlock::SmallKanaExtension),
+    (0x1B170, 0x1B2FF, UnicodeBlock::Nushu),
+    (0x1BC00, 0x1BC9F, UnicodeBlock::Duployan),
+    (0x1BCA0, 0x1BCAF, UnicodeBlock::ShorthandFormatControls),
+    (0x1D000, 0x1D0FF, UnicodeBlock::ByzantineMusicalSymbols),
+    (0x1D100, 0x1D1FF, UnicodeBlock::MusicalSymbols),
+    (0x1D200, 0x1D24F, UnicodeBlock::AncientGreekMusicalNotation),
+    (0x1D2E0, 0x1D2FF, UnicodeBlock::MayanNumerals),
+    (0x1D300, 0x1D35F, UnicodeBlock::TaiXuanJingSymbols),
+    (0x1D360, 0x1D37F, UnicodeBlock::CountingRodNumerals),
+    (0x1D400, 0x1D7FF, UnicodeBlock::MathematicalAlphanumericSymbols),
+    (0x1D800, 0x1DAAF, UnicodeBlock::SuttonSignwriting),
+    (0x1E000, 0x1E02F, UnicodeBlock::GlagoliticSupplement),
+    (0x1E100, 0x1E14F, UnicodeBlock::NyiakengPuachueHmong),
+    (0x1E2C0, 0x1E2FF, UnicodeBlock::Wancho),
+    (0x1E800, 0x1E8DF, UnicodeBlock::MendeKikakui),
+    (0x1E900, 0x1E95F, UnicodeBlock::Adlam),
+    (0x1EC70, 0x1ECBF, UnicodeBlock::IndicSiyaqNumbers),
+    (0x1ED00, 0x1ED4F, UnicodeBlock::OttomanSiyaqNumbers),
+    (0x1EE00, 0x1EEFF, UnicodeBlock::ArabicMathematicalAlphabeticSymbols),
+    (0x1F000, 0x1F02F, UnicodeBlock::MahjongTiles),
+    (0x1F030, 0x1F09F, UnicodeBlock::DominoTiles),
+    (0x1F0A0, 0x1F0FF, UnicodeBlock::PlayingCards),
+    (0x1F100, 0x1F1FF, UnicodeBlock::EnclosedAlphanumericSupplement),
+    (0x1F200, 0x1F2FF, UnicodeBlock::EnclosedIdeographicSupplement),
+    (0x1F300, 0x1F5FF, UnicodeBlock::MiscellaneousSymbolsAndPictographs),
+    (0x1F600, 0x1F64F, UnicodeBlock::Emoticons),
+    (0x1F650, 0x1F67F, UnicodeBlock::OrnamentalDingbats),
+    (0x1F680, 0x1F6FF, UnicodeBlock::TransportAndMapSymbols),
+    (0x1F700, 0x1F77F, UnicodeBlock::AlchemicalSymbols),
+    (0x1F780, 0x1F7FF, UnicodeBlock::GeometricShapesExtended),
+    (0x1F800, 0x1F8FF, UnicodeBlock::SupplementalArrowsC),
+    (0x1F900, 0x1F9FF, UnicodeBlock::SupplementalSymbolsAndPictographs),
+    (0x1FA00, 0x1FA6F, UnicodeBlock::ChessSymbols),
+    (0x1FA70, 0x1FAFF, UnicodeBlock::SymbolsAndPictographsExtendedA),
+    (0x1FB00, 0x1FBFF, UnicodeBlock::SymbolsForLegacyComputing),
+    (0x20000, 0x2A6DF, UnicodeBlock::CjkUnifiedIdeographsExtensionB),
+    (0x2A700, 0x2B73F, UnicodeBlock::CjkUnifiedIdeographsExtensionC),
+    (0x2B740, 0x2B81F, UnicodeBlock::CjkUnifiedIdeographsExtensionD),
+    (0x2B820, 0x2CEAF, UnicodeBlock::CjkUnifiedIdeographsExtensionE),
+    (0x2CEB0, 0x2EBEF, UnicodeBlock::CjkUnifiedIdeographsExtensionF),
+    (0x2F800, 0x2FA1F, UnicodeBlock::CjkCompatibilityIdeographsSupplement),
+    (0x30000, 0x3134F, UnicodeBlock::CjkUnifiedIdeographsExtensionG),
+    (0xE0000, 0xE007F, UnicodeBlock::Tags),
+    (0xE0100, 0xE01EF, UnicodeBlock::VariationSelectorsSupplement),
+    (0xF0000, 0xFFFFF, UnicodeBlock::SupplementaryPrivateUseAreaA),
+    (0x100000, 0x10FFFF, UnicodeBlock::SupplementaryPrivateUseAreaB),
+];
+
+/// A Unicode script; `java.lang.Character.UnicodeScript`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UnicodeScript {
+    Common,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Syriac,
+    Thaana,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhala,
+    Thai,
+    Lao,
+    Tibetan,
+    Myanmar,
+    Georgian,
+    Hangul,
+    Ethiopic,
+    Cherokee,
+    CanadianAboriginal,
+    Ogham,
+    Runic,
+    Khmer,
+    Mongolian,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Han,
+    Yi,
+    OldItalic,
+    Gothic,
+    Deseret,
+    Inherited,
+    Tagalog,
+    Hanunoo,
+    Buhid,
+    Tagbanwa,
+    Limbu,
+    TaiLe,
+    LinearB,
+    Ugaritic,
+    Shavian,
+    Osmanya,
+    Cypriot,
+    Braille,
+    Buginese,
+    Coptic,
+    NewTaiLue,
+    Glagolitic,
+    Tifinagh,
+    SylotiNagri,
+    OldPersian,
+    Kharoshthi,
+    Balinese,
+    Cuneiform,
+    Phoenician,
+    PhagsPa,
+    Nko,
+    Sundanese,
+    Batak,
+    Lepcha,
+    OlChiki,
+    Vai,
+    Saurashtra,
+    KayahLi,
+    Rejang,
+    Lycian,
+    Carian,
+    Lydian,
+    Cham,
+    TaiTham,
+    TaiViet,
+    Avestan,
+    EgyptianHieroglyphs,
+    Samaritan,
+    Mandaic,
+    Lisu,
+    Bamum,
+    Javanese,
+    MeeteiMayek,
+    ImperialAramaic,
+    OldSouthArabian,
+    InscriptionalParthian,
+    InscriptionalPahlavi,
+    OldTurkic,
+    Brahmi,
+    Kaithi,
+    MeroiticHieroglyphs,
+    MeroiticCursive,
+    SoraSompeng,
+    Chakma,
+    Sharada,
+    Takri,
+    Miao,
+    CaucasianAlbanian,
+    BassaVah,
+    Duployan,
+    Elbasan,
+    Grantha,
+    PahawhHmong,
+    Khojki,
+    LinearA,
+    Mahajani,
+    Manichaean,
+    MendeKikakui,
+    Modi,
+    Mro,
+    OldNorthArabian,
+    Nabataean,
+    Palmyrene,
+    PauCinHau,
+    OldPermic,
+    PsalterPahlavi,
+    Siddham,
+    Khudawadi,
+    Tirhuta,
+    WarangCiti,
+    Ahom,
+    AnatolianHieroglyphs,
+    Hatran,
+    Multani,
+    OldHungarian,
+    Signwriting,
+    Adlam,
+    Bhaiksuki,
+    Marchen,
+    Newa,
+    Osage,
+    Tangut,
+    MasaramGondi,
+    Nushu,
+    Soyombo,
+    ZanabazarSquare,
+    HanifiRohingya,
+    OldSogdian,
+    Sogdian,
+    Dogra,
+    GunjalaGondi,
+    Makasar,
+    Medefaidrin,
+    Elymaic,
+    Nandinagari,
+    NyiakengPuachueHmong,
+    Wancho,
+    Yezidi,
+    Chorasmian,
+    DivesAkuru,
+    KhitanSmallScript,
+    Unknown,
+}
+
+pub(super) static ALL_SCRIPTS: [UnicodeScript; 157] = [
+    UnicodeScript::Common,
+    UnicodeScript::Latin,
+    UnicodeScript::Greek,
+    UnicodeScript::Cyrillic,
+    UnicodeScript::Armenian,
+    UnicodeScript::Hebrew,
+    UnicodeScript::Arabic,
+    UnicodeScript::Syriac,
+    UnicodeScript::Thaana,
+    UnicodeScript::Devanagari,
+    UnicodeScript::Bengali,
+    UnicodeScript::Gurmukhi,
+    UnicodeScript::Gujarati,
+    UnicodeScript::Oriya,
+    UnicodeScript::Tamil,
+    UnicodeScript::Telugu,
+    UnicodeScript::Kannada,
+    UnicodeScript::Malayalam,
+    UnicodeScript::Sinhala,
+    UnicodeScript::Thai,
+    UnicodeScript::Lao,
+    UnicodeScript::Tibetan,
+    UnicodeScript::Myanmar,
+    UnicodeScript::Georgian,
+    UnicodeScript::Hangul,
+    UnicodeScript::Ethiopic,
+    UnicodeScript::Cherokee,
+    UnicodeScript::CanadianAboriginal,
+    UnicodeScript::Ogham,
+    UnicodeScript::Runic,
+    UnicodeScript::Khmer,
+    UnicodeScript::Mongolian,
+    UnicodeScript::Hiragana,
+    UnicodeScript::Katakana,
+    UnicodeScript::Bopomofo,
+    UnicodeScript::Han,
+    UnicodeScript::Yi,
+    UnicodeScript::OldItalic,
+    UnicodeScript::Gothic,
+    UnicodeScript::Deseret,
+    UnicodeScript::Inherited,
+    UnicodeScript::Tagalog,
+    UnicodeScript::Hanunoo,
+    UnicodeScript::Buhid,
+    UnicodeScript::Tagbanwa,
+    UnicodeScript::Limbu,
+    UnicodeScript::TaiLe,
+    UnicodeScript::LinearB,
+    UnicodeScript::Ugaritic,
+    UnicodeScript::Shavian,
+    UnicodeScript::Osmanya,
+    UnicodeScript::Cypriot,
+    UnicodeScript::Braille,
+    UnicodeScript::Buginese,
+    UnicodeScript::Coptic,
+    UnicodeScript::NewTaiLue,
+    UnicodeScript::Glagolitic,
+    UnicodeScript::Tifinagh,
+    UnicodeScript::SylotiNagri,
+    UnicodeScript::OldPersian,
+    UnicodeScript::Kharoshthi,
+    UnicodeScript::Balinese,
+    UnicodeScript::Cuneiform,
+    UnicodeScript::Phoenician,
+    UnicodeScript::PhagsPa,
+    UnicodeScript::Nko,
+    UnicodeScript::Sundanese,
+    UnicodeScript::Batak,
+    UnicodeScript::Lepcha,
+    UnicodeScript::OlChiki,
+    UnicodeScript::Vai,
+    UnicodeScript::Saurashtra,
+    UnicodeScript::KayahLi,
+    UnicodeScript::Rejang,
+    UnicodeScript::Lycian,
+    UnicodeScript::Carian,
+    UnicodeScript::Lydian,
+    UnicodeScript::Cham,
+    UnicodeScript::TaiTham,
+    UnicodeScript::TaiViet,
+    UnicodeScript::Avestan,
+    UnicodeScript::EgyptianHieroglyphs,
+    UnicodeScript::Samaritan,
+    UnicodeScript::Mandaic,
+    UnicodeScript::Lisu,
+    UnicodeScript::Bamum,
+    UnicodeScript::Javanese,
+    UnicodeScript::MeeteiMayek,
+    UnicodeScript::ImperialAramaic,
+    UnicodeScript::OldSouthArabian,
+    UnicodeScript::InscriptionalParthian,
+    UnicodeScript::InscriptionalPahlavi,
+    UnicodeScript::OldTurkic,
+    UnicodeScript::Brahmi,
+    UnicodeScript::Kaithi,
+    UnicodeScript::MeroiticHieroglyphs,
+    UnicodeScript::MeroiticCursive,
+    UnicodeScript::SoraSompeng,
+    UnicodeScript::Chakma,
+    UnicodeScript::Sharada,
+    UnicodeScript::Takri,
+    UnicodeScript::Miao,
+    UnicodeScript::CaucasianAlbanian,
+    UnicodeScript::BassaVah,
+    UnicodeScript::Duployan,
+    UnicodeScript::Elbasan,
+    UnicodeScript::Grantha,
+    UnicodeScript::PahawhHmong,
+    UnicodeScript::Khojki,
+    UnicodeScript::LinearA,
+    UnicodeScript::Mahajani,
+    UnicodeScript::Manichaean,
+    UnicodeScript::MendeKikakui,
+    UnicodeScript::Modi,
+    UnicodeScript::Mro,
+    UnicodeScript::OldNorthArabian,
+    UnicodeScript::Nabataean,
+    UnicodeScript::Palmyrene,
+    UnicodeScript::PauCinHau,
+    UnicodeScript::OldPermic,
+    UnicodeScript::PsalterPahlavi,
+    UnicodeScript::Siddham,
+    UnicodeScript::Khudawadi,
+    UnicodeScript::Tirhuta,
+    UnicodeScript::WarangCiti,
+    UnicodeScript::Ahom,
+    UnicodeScript::AnatolianHieroglyphs,
+    UnicodeScript::Hatran,
+    UnicodeScript::Multani,
+    UnicodeScript::OldHungarian,
+    UnicodeScript::Signwriting,
+    UnicodeScript::Adlam,
+    UnicodeScript::Bhaiksuki,
+    UnicodeScript::Marchen,
+    UnicodeScript::Newa,
+    UnicodeScript::Osage,
+    UnicodeScript::Tangut,
+    UnicodeScript::MasaramGondi,
+    UnicodeScript::Nushu,
+    UnicodeScript::Soyombo,
+    UnicodeScript::ZanabazarSquare,
+    UnicodeScript::HanifiRohingya,
+    UnicodeScript::OldSogdian,
+    UnicodeScript::Sogdian,
+    UnicodeScript::Dogra,
+    UnicodeScript::GunjalaGondi,
+    UnicodeScript::Makasar,
+    UnicodeScript::Medefaidrin,
+    UnicodeScript::Elymaic,
+    UnicodeScript::Nandinagari,
+    UnicodeScript::NyiakengPuachueHmong,
+    UnicodeScript::Wancho,
+    UnicodeScript::Yezidi,
+    UnicodeScript::Chorasmian,
+    UnicodeScript::DivesAkuru,
+    UnicodeScript::KhitanSmallScript,
+    UnicodeScript::Unknown,
+];
+
+pub(super) static SCRIPT_NAMES: [&str; 157] = [
+    "COMMON",
+    "LATIN",
+    "GREEK",
+    "CYRILLIC",
+    "ARMENIAN",
+    "HEBREW",
+    "ARABIC",
+    "SYRIAC",
+    "THAANA",
+    "DEVANAGARI",
+    "BENGALI",
+    "GURMUKHI",
+    "GUJARATI",
+    "ORIYA",
+    "TAMIL",
+    "TELUGU",
+    "KANNADA",
+    "MALAYALAM",
+    "SINHALA",
+    "THAI",
+    "LAO",
+    "TIBETAN",
+    "MYANMAR",
+    "GEORGIAN",
+    "HANGUL",
+    "ETHIOPIC",
+    "CHEROKEE",
+    "CANADIAN_ABORIGINAL",
+    "OGHAM",
+    "RUNIC",
+    "KHMER",
+    "MONGOLIAN",
+    "HIRAGANA",
+    "KATAKANA",
+    "BOPOMOFO",
+    "HAN",
+    "YI",
+    "OLD_ITALIC",
+    "GOTHIC",
+    "DESERET",
+    "INHERITED",
+    "TAGALOG",
+    "HANUNOO",
+    "BUHID",
+    "TAGBANWA",
+    "LIMBU",
+    "TAI_LE",
+    "LINEAR_B",
+    "UGARITIC",
+    "SHAVIAN",
+    "OSMANYA",
+    "CYPRIOT",
+    "BRAILLE",
+    "BUGINESE",
+    "COPTIC",
+    "NEW_TAI_LUE",
+    "GLAGOLITIC",
+    "TIFINAGH",
+    "SYLOTI_NAGRI",
+    "OLD_PERSIAN",
+    "KHAROSHTHI",
+    "BALINESE",
+    "CUNEIFORM",
+    "PHOENICIAN",
+    "PHAGS_PA",
+    "NKO",
+    "SUNDANESE",
+    "BATAK",
+    "LEPCHA",
+    "OL_CHIKI",
+    "VAI",
+    "SAURASHTRA",
+    "KAYAH_LI",
+    "REJANG",
+    "LYCIAN",
+    "CARIAN",
+    "LYDIAN",
+    "CHAM",
+    "TAI_THAM",
+    "TAI_VIET",
+    "AVESTAN",
+    "EGYPTIAN_HIEROGLYPHS",
+    "SAMARITAN",
+    "MANDAIC",
+    "LISU",
+    "BAMUM",
+    "JAVANESE",
+    "MEETEI_MAYEK",
+    "IMPERIAL_ARAMAIC",
+    "OLD_SOUTH_ARABIAN",
+    "INSCRIPTIONAL_PARTHIAN",
+    "INSCRIPTIONAL_PAHLAVI",
+    "OLD_TURKIC",
+    "BRAHMI",
+    "KAITHI",
+    "MEROITIC_HIEROGLYPHS",
+    "MEROITIC_CURSIVE",
+    "SORA_SOMPENG",
+    "CHAKMA",
+    "SHARADA",
+    "TAKRI",
+    "MIAO",
+    "CAUCASIAN_ALBANIAN",
+    "BASSA_VAH",
+    "DUPLOYAN",
+    "ELBASAN",
+    "GRANTHA",
+    "PAHAWH_HMONG",
+    "KHOJKI",
+    "LINEAR_A",
+    "MAHAJANI",
+    "MANICHAEAN",
+    "MENDE_KIKAKUI",
+    "MODI",
+    "MRO",
+    "OLD_NORTH_ARABIAN",
+    "NABATAEAN",
+    "PALMYRENE",
+    "PAU_CIN_HAU",
+    "OLD_PERMIC",
+    "PSALTER_PAHLAVI",
+    "SIDDHAM",
+    "KHUDAWADI",
+    "TIRHUTA",
+    "WARANG_CITI",
+    "AHOM",
+    "ANATOLIAN_HIEROGLYPHS",
+    "HATRAN",
+    "MULTANI",
+    "OLD_HUNGARIAN",
+    "SIGNWRITING",
+    "ADLAM",
+    "BHAIKSUKI",
+    "MARCHEN",
+    "NEWA",
+    "OSAGE",
+    "TANGUT",
+    "MASARAM_GONDI",
+    "NUSHU",
+    "SOYOMBO",
+    "ZANABAZAR_SQUARE",
+    "HANIFI_ROHINGYA",
+    "OLD_SOGDIAN",
+    "SOGDIAN",
+    "DOGRA",
+    "GUNJALA_GONDI",
+    "MAKASAR",
+    "MEDEFAIDRIN",
+    "ELYMAIC",
+    "NANDINAGARI",
+    "NYIAKENG_PUACHUE_HMONG",
+    "WANCHO",
+    "YEZIDI",
+    "CHORASMIAN",
+    "DIVES_AKURU",
+    "KHITAN_SMALL_SCRIPT",
+    "UNKNOWN",
+];
+
+pub(super) static SCRIPTS: [(u32, u32, UnicodeScript); 912] = [
+    (0x0000, 0x0040, UnicodeScript::Common),
+    (0x0041, 0x005A, UnicodeScript::Latin),
+    (0x005B, 0x0060, UnicodeScript::Common),
+    (0x0061, 0x007A, UnicodeScript::Latin),
+    (0x007B, 0x00A9, UnicodeScript::Common),
+    (0x00AA, 0x00AA, UnicodeScript::Latin),
+    (0x00AB, 0x00B9, UnicodeScript::Common),
+    (0x00BA, 0x00BA, UnicodeScript::Latin),
+    (0x00BB, 0x00BF, UnicodeScript::Common),
+    (0x00C0, 0x00D6, UnicodeScript::Latin),
+    (0x00D7, 0x00D7, UnicodeScript::Common),
+    (0x00D8, 0x00F6, UnicodeScript::Latin),
+    (0x00F7, 0x00F7, UnicodeScript::Common),
+    (0x00F8, 0x02B8, UnicodeScript::Latin),
+    (0x02B9, 0x02DF, UnicodeScript::Common),
+    (0x02E0, 0x02E4, UnicodeScript::Latin),
+    (0x02E5, 0x02E9, UnicodeScript::Common),
+    (0x02EA, 0x02EB, UnicodeScript::Bopomofo),
+    (0x02EC, 0x02FF, UnicodeScript::Common),
+    (0x0300, 0x036F, UnicodeScript::Inherited),
+    (0x0370, 0x0373, UnicodeScript::Greek),
+    (0x0374, 0x0374, UnicodeScript::Common),
+    (0x0375, 0x0377, UnicodeScript::Greek),
+    (0x037A, 0x037D, UnicodeScript::Greek),
+    (0x037E, 0x037E, UnicodeScript::Common),
+    (0x037F, 0x037F, UnicodeScript::Greek),
+    (0x0384, 0x0384, UnicodeScript::Greek),
+    (0x0385, 0x0385, UnicodeScript::Common),
+    (0x0386, 0x0386, UnicodeScript::Greek),
+    (0x0387, 0x0387, UnicodeScript::Common),
+    (0x0388, 0x038A, UnicodeScript::Greek),
+    (0x038C, 0x038C, UnicodeScript::Greek),
+    (0x038E, 0x03A1, UnicodeScript::Greek),
+    (0x03A3, 0x03E1, UnicodeScript::Greek),
+    (0x03E2, 0x03EF, UnicodeScript::Coptic),
+    (0x03F0, 0x03FF, UnicodeScript::Greek),
+    (0x0400, 0x0484, UnicodeScript::Cyrillic),
+    (0x0485, 0x0486, UnicodeScript::Inherited),
+    (0x0487, 0x052F, UnicodeScript::Cyrillic),
+    (0x0531, 0x0556, UnicodeScript::Armenian),
+    (0x0559, 0x058A, UnicodeScript::Armenian),
+    (0x058D, 0x058F, UnicodeScript::Armenian),
+    (0x0591, 0x05C7, UnicodeScript::Hebrew),
+    (0x05D0, 0x05EA, UnicodeScript::Hebrew),
+    (0x05EF, 0x05F4, UnicodeScript::Hebrew),
+    (0x0600, 0x0604, UnicodeScript::Arabic),
+    (0x0605, 0x0605, UnicodeScript::Common),
+    (0x0606, 0x060B, UnicodeScript::Arabic),
+    (0x060C, 0x060C, UnicodeScript::Common),
+    (0x060D, 0x061A, UnicodeScript::Arabic),
+    (0x061B, 0x061B, UnicodeScript::Common),
+    (0x061C, 0x061C, UnicodeScript::Arabic),
+    (0x061E, 0x061E, UnicodeScript::Arabic),
+    (0x061F, 0x061F, UnicodeScript::Common),
+    (0x0620, 0x063F, UnicodeScript::Arabic),
+    (0x0640, 0x0640, UnicodeScript::Common),
+    (0x0641, 0x064A, UnicodeScript::Arabic),
+    (0x064B, 0x0655, UnicodeScript::Inherited),
+    (0x0656, 0x066F, UnicodeScript::Arabic),
+    (0x0670, 0x0670, UnicodeScript::Inherited),
+    (0x0671, 0x06DC, UnicodeScript::Arabic),
+    (0x06DD, 0x06DD, UnicodeScript::Common),
+    (0x06DE, 0x06FF, UnicodeScript::Arabic),
+    (0x0700, 0x070D, UnicodeScript::Syriac),
+    (0x070F, 0x074A, UnicodeScript::Syriac),
+    (0x074D, 0x074F, UnicodeScript::Syriac),
+    (0x0750, 0x077F, UnicodeScript::Arabic),
+    (0x0780, 0x07B1, UnicodeScript::Thaana),
+    (0x07C0, 0x07FA, UnicodeScript::Nko),
+    (0x07FD, 0x07FF, UnicodeScript::Nko),
+    (0x0800, 0x082D, UnicodeScript::Samaritan),
+    (0x0830, 0x083E, UnicodeScript::Samaritan),
+    (0x0840, 0x085B, UnicodeScript::Mandaic),
+    (0x085E, 0x085E, UnicodeScript::Mandaic),
+    (0x0860, 0x086A, UnicodeScript::Syriac),
+    (0x08A0, 0x08B4, UnicodeScript::Arabic),
+    (0x08B6, 0x08C7, UnicodeScript::Arabic),
+    (0x08D3, 0x08E1, UnicodeScript::Arabic),
+    (0x08E2, 0x08E2, UnicodeScript::Common),
+    (0x08E3, 0x08FF, UnicodeScript::Arabic),
+    (0x0900, 0x0950, UnicodeScript::Devanagari),
+    (0x0951, 0x0954, UnicodeScript::Inherited),
+    (0x0955, 0x0963, UnicodeScript::Devanagari),
+    (0x0964, 0x0965, UnicodeScript::Common),
+    (0x0966, 0x097F, UnicodeScript::Devanagari),
+    (0x0980, 0x0983, UnicodeScript::Bengali),
+    (0x0985, 0x098C, UnicodeScript::Bengali),
+    (0x098F, 0x0990, UnicodeScript::Bengali),
+    (0x0993, 0x09A8, UnicodeScript::Bengali),
+    (0x09AA, 0x09B0, UnicodeScript::Bengali),
+    (0x09B2, 0x09B2, UnicodeScript::Bengali),
+    (0x09B6, 0x09B9, UnicodeScript::Bengali),
+    (0x09BC, 0x09C4, UnicodeScript::Bengali),
+    (0x09C7, 0x09C8, UnicodeScript::Bengali),
+    (0x09CB, 0x09CE, UnicodeScript::Bengali),
+    (0x09D7, 0x09D7, UnicodeScript::Bengali),
+    (0x09DC, 0x09DD, UnicodeScript::Bengali),
+    (0x09DF, 0x09E3, UnicodeScript::Bengali),
+    (0x09E6, 0x09FE, UnicodeScript::Bengali),
+    (0x0A01, 0x0A03, UnicodeScript::Gurmukhi),
+    (0x0A05, 0x0A0A, UnicodeScript::Gurmukhi),
+    (0x0A0F, 0x0A10, UnicodeScript::Gurmukhi),
+    (0x0A13, 0x0A28, UnicodeScript::Gurmukhi),
+    (0x0A2A, 0x0A30, UnicodeScript::Gurmukhi),
+    (0x0A32, 0x0A33, UnicodeScript::Gurmukhi),
+    (0x0A35, 0x0A36, UnicodeScript::Gurmukhi),
+    (0x0A38, 0x0A39, UnicodeScript::Gurmukhi),
+    (0x0A3C, 0x0A3C, UnicodeScript::Gurmukhi),
+    (0x0A3E, 0x0A42, UnicodeScript::Gurmukhi),
+    (0x0A47, 0x0A48, UnicodeScript::Gurmukhi),
+    (0x0A4B, 0x0A4D, UnicodeScript::Gurmukhi),
+    (0x0A51, 0x0A51, UnicodeScript::Gurmukhi),
+    (0x0A59, 0x0A5C, UnicodeScript::Gurmukhi),
+    (0x0A5E, 0x0A5E, UnicodeScript::Gurmukhi),
+    (0x0A66, 0x0A76, UnicodeScript::Gurmukhi),
+    (0x0A81, 0x0A83, UnicodeScript::Gujarati),
+    (0x0A85, 0x0A8D, UnicodeScript::Gujarati),
+    (0x0A8F, 0x0A91, UnicodeScript::Gujarati),
+    (0x0A93, 0x0AA8, UnicodeScript::Gujarati),
+    (0x0AAA, 0x0AB0, UnicodeScript::Gujarati),
+    (0x0AB2, 0x0AB3, UnicodeScript::Gujarati),
+    (0x0AB5, 0x0AB9, UnicodeScript::Gujarati),
+    (0x0ABC, 0x0AC5, UnicodeScript::Gujarati),
+    (0x0AC7, 0x0AC9, UnicodeScript::Gujarati),
+    (0x0ACB, 0x0ACD, UnicodeScript::Gujarati),
+    (0x0AD0, 0x0AD0, UnicodeScript::Gujarati),
+    (0x0AE0, 0x0AE3, UnicodeScript::Gujarati),
+    (0x0AE6, 0x0AF1, UnicodeScript::Gujarati),
+    (0x0AF9, 0x0AFF, UnicodeScript::Gujarati),
+    (0x0B01, 0x0B03, UnicodeScript::Oriya),
+    (0x0B05, 0x0B0C, UnicodeScript::Oriya),
+    (0x0B0F, 0x0B10, UnicodeScript::Oriya),
+    (0x0B13, 0x0B28, UnicodeScript::Oriya),
+    (0x0B2A, 0x0B30, UnicodeScript::Oriya),
+    (0x0B32, 0x0B33, UnicodeScript::Oriya),
+    (0x0B35, 0x0B39, UnicodeScript::Oriya),
+    (0x0B3C, 0x0B44, UnicodeScript::Oriya),
+    (0x0B47, 0x0B48, UnicodeScript::Oriya),
+    (0x0B4B, 0x0B4D, UnicodeScript::Oriya),
+    (0x0B55, 0x0B57, UnicodeScript::Oriya),
+    (0x0B5C, 0x0B5D, UnicodeScript::Oriya),
+    (0x0B5F, 0x0B63, UnicodeScript::Oriya),
+    (0x0B66, 0x0B77, UnicodeScript::Oriya),
+    (0x0B82, 0x0B83, UnicodeScript::Tamil),
+    (0x0B85, 0x0B8A, UnicodeScript::Tamil),
+    (0x0B8E, 0x0B90, UnicodeScript::Tamil),
+    (0x0B92, 0x0B95, UnicodeScript::Tamil),
+    (0x0B99, 0x0B9A, UnicodeScript::Tamil),
+    (0x0B9C, 0x0B9C, UnicodeScript::Tamil),
+    (0x0B9E, 0x0B9F, UnicodeScript::Tamil),
+    (0x0BA3, 0x0BA4, UnicodeScript::Tamil),
+    (0x0BA8, 0x0BAA, UnicodeScript::Tamil),
+    (0x0BAE, 0x0BB9, UnicodeScript::Tamil),
+    (0x0BBE, 0x0BC2, UnicodeScript::Tamil),
+    (0x0BC6, 0x0BC8, UnicodeScript::Tamil),
+    (0x0BCA, 0x0BCD, UnicodeScript::Tamil),
+    (0x0BD0, 0x0BD0, UnicodeScript::Tamil),
+    (0x0BD7, 0x0BD7, UnicodeScript::Tamil),
+    (0x0BE6, 0x0BFA, UnicodeScript::Tamil),
+    (0x0C00, 0x0C0C, UnicodeScript::Telugu),
+    (0x0C0E, 0x0C10, UnicodeScript::Telugu),
+    (0x0C12, 0x0C28, UnicodeScript::Telugu),
+    (0x0C2A, 0x0C39, UnicodeScript::Telugu),
+    (0x0C3D, 0x0C44, UnicodeScript::Telugu),
+    (0x0C46, 0x0C48, UnicodeScript::Telugu),
+    (0x0C4A, 0x0C4D, UnicodeScript::Telugu),
+    (0x0C55, 0x0C56, UnicodeScript::Telugu),
+    (0x0C58, 0x0C5A, UnicodeScript::Telugu),
+    (0x0C60, 0x0C63, UnicodeScript::Telugu),
+    (0x0C66, 0x0C6F, UnicodeScript::Telugu),
+    (0x0C77, 0x0C7F, UnicodeScript::Telugu),
+    (0x0C80, 0x0C8C, UnicodeScript::Kannada),
+    (0x0C8E, 0x0C90, UnicodeScript::Kannada),
+    (0x0C92, 0x0CA8, UnicodeScript::Kannada),
+    (0x0CAA, 0x0CB3, UnicodeScript::Kannada),
+    (0x0CB5, 0x0CB9, UnicodeScript::Kannada),
+    (0x0CBC, 0x0CC4, UnicodeScript::Kannada),
+    (0x0CC6, 0x0CC8, UnicodeScript::Kannada),
+    (0x0CCA, 0x0CCD, UnicodeScript::Kannada),
+    (0x0CD5, 0x0CD6, UnicodeScript::Kannada),
+    (0x0CDE, 0x0CDE, UnicodeScript::Kannada),
+    (0x0CE0, 0x0CE3, UnicodeScript::Kannada),
+    (0x0CE6, 0x0CEF, UnicodeScript::Kannada),
+    (0x0CF1, 0x0CF2, UnicodeScript::Kannada),
+    (0x0D00, 0x0D0C, UnicodeScript::Malayalam),
+    (0x0D0E, 0x0D10, UnicodeScript::Malayalam),
+    (0x0D12, 0x0D44, UnicodeScript::Malayalam),
+    (0x0D46, 0x0D48, UnicodeScript::Malayalam),
+    (0x0D4A, 0x0D4F, UnicodeScript::Malayalam),
+    (0x0D54, 0x0D63, UnicodeScript::Malayalam),
+    (0x0D66, 0x0D7F, UnicodeScript::Malayalam),
+    (0x0D81, 0x0D83, UnicodeScript::Sinhala),
+    (0x0D85, 0x0D96, UnicodeScript::Sinhala),
+    (0x0D9A, 0x0DB1, UnicodeScript::Sinhala),
+    (0x0DB3, 0x0DBB, UnicodeScript::Sinhala),
+    (0x0DBD, 0x0DBD, UnicodeScript::Sinhala),
+    (0x0DC0, 0x0DC6, UnicodeScript::Sinhala),
+    (0x0DCA, 0x0DCA, UnicodeScript::Sinhala),
+    (0x0DCF, 0x0DD4, UnicodeScript::Sinhala),
+    (0x0DD6, 0x0DD6, UnicodeScript::Sinhala),
+    (0x0DD8, 0x0DDF, UnicodeScript::Sinhala),
+    (0x0DE6, 0x0DEF, UnicodeScript::Sinhala),
+    (0x0DF2, 0x0DF4, UnicodeScript::Sinhala),
+    (0x0E01, 0x0E3A, UnicodeScript::Thai),
+    (0x0E3F, 0x0E3F, UnicodeScript::Common),
+    (0x0E40, 0x0E5B, UnicodeScript::Thai),
+    (0x0E81, 0x0E82, UnicodeScript::Lao),
+    (0x0E84, 0x0E84, UnicodeScript::Lao),
+    (0x0E86, 0x0E8A, UnicodeScript::Lao),
+    (0x0E8C, 0x0EA3, UnicodeScript::Lao),
+    (0x0EA5, 0x0EA5, UnicodeScript::Lao),
+    (0x0EA7, 0x0EBD, UnicodeScript::Lao),
+    (0x0EC0, 0x0EC4, UnicodeScript::Lao),
+    (0x0EC6, 0x0EC6, UnicodeScript::Lao),
+    (0x0EC8, 0x0ECD, UnicodeScript::Lao),
+    (0x0ED0, 0x0ED9, UnicodeScript::Lao),
+    (0x0EDC, 0x0EDF, UnicodeScript::Lao),
+    (0x0F00, 0x0F47, UnicodeScript::Tibetan),
+    (0x0F49, 0x0F6C, UnicodeScript::Tibetan),
+    (0x0F71, 0x0F97, UnicodeScript::Tibetan),
+    (0x0F99, 0x0FBC, UnicodeScript::Tibetan),
+    (0x0FBE, 0x0FCC, UnicodeScript::Tibetan),
+    (0x0FCE, 0x0FD4, UnicodeScript::Tibetan),
+    (0x0FD5, 0x0FD8, UnicodeScript::Common),
+    (0x0FD9, 0x0FDA, UnicodeScript::Tibetan),
+    (0x1000, 0x109F, UnicodeScript::Myanmar),
+    (0x10A0, 0x10C5, UnicodeScript::Georgian),
+    (0x10C7, 0x10C7, UnicodeScript::Georgian),
+    (0x10CD, 0x10CD, UnicodeScript::Georgian),
+    (0x10D0, 0x10FA, UnicodeScript::Georgian),
+    (0x10FB, 0x10FB, UnicodeScript::Common),
+    (0x10FC, 0x10FF, UnicodeScript::Georgian),
+    (0x1100, 0x11FF, UnicodeScript::Hangul),
+    (0x1200, 0x1248, UnicodeScript::Ethiopic),
+    (0x124A, 0x124D, UnicodeScript::Ethiopic),
+    (0x1250, 0x1256, UnicodeScript::Ethiopic),
+    (0x1258, 0x1258, UnicodeScript::Ethiopic),
+    (0x125A, 0x125D, UnicodeScript::Ethiopic),
+    (0x1260, 0x1288, UnicodeScript::Ethiopic),
+    (0x128A, 0x128D, UnicodeScript::Ethiopic),
+    (0x1290, 0x12B0, UnicodeScript::Ethiopic),
+    (0x12B2, 0x12B5, UnicodeScript::Ethiopic),
+    (0x12B8, 0x12BE, UnicodeScript::Ethiopic),
+    (0x12C0, 0x12C0, UnicodeScript::Ethiopic),
+    (0x12C2, 0x12C5, UnicodeScript::Ethiopic),
+    (0x12C8, 0x12D6, UnicodeScript::Ethiopic),
+    (0x12D8, 0x1310, UnicodeScript::Ethiopic),
+    (0x1312, 0x1315, UnicodeScript::Ethiopic),
+    (0x1318, 0x135A, UnicodeScript::Ethiopic),
+    (0x135D, 0x137C, UnicodeScript::Ethiopic),
+    (0x1380, 0x1399, UnicodeScript::Ethiopic),
+    (0x13A0, 0x13F5, UnicodeScript::Cherokee),
+    (0x13F8, 0x13FD, UnicodeScript::Cherokee),
+    (0x1400, 0x167F, UnicodeScript::CanadianAboriginal),
+    (0x1680, 0x169C, UnicodeScript::Ogham),
+    (0x16A0, 0x16EA, UnicodeScript::Runic),
+    (0x16EB, 0x16ED, UnicodeScript::Common),
+    (0x16EE, 0x16F8, UnicodeScript::Runic),
+    (0x1700, 0x170C, UnicodeScript::Tagalog),
+    (0x170E, 0x1714, UnicodeScript::Tagalog),
+    (0x1720, 0x1734, UnicodeScript::Hanunoo),
+    (0x1735, 0x1736, UnicodeScript::Common),
+    (0x1740, 0x1753, UnicodeScript::Buhid),
+    (0x1760, 0x176C, UnicodeScript::Tagbanwa),
+    (0x176E, 0x1770, UnicodeScript::Tagbanwa),
+    (0x1772, 0x1773, UnicodeScript::Tagbanwa),
+    (0x1780, 0x17DD, UnicodeScript::Khmer),
+    (0x17E0, 0x17E9, UnicodeScript::Khmer),
+    (0x17F0, 0x17F9, UnicodeScript::Khmer),
+    (0x1800, 0x1801, UnicodeScript::Mongolian),
+    (0x1802, 0x1803, UnicodeScript::Common),
+    (0x1804, 0x1804, UnicodeScript::Mongolian),
+    (0x1805, 0x1805, UnicodeScript::Common),
+    (0x1806, 0x180E, UnicodeScript::Mongolian),
+    (0x1810, 0x1819, UnicodeScript::Mongolian),
+    (0x1820, 0x1878, UnicodeScript::Mongolian),
+    (0x1880, 0x18AA, UnicodeScript::Mongolian),
+    (0x18B0, 0x18F5, UnicodeScript::CanadianAboriginal),
+    (0x1900, 0x191E, UnicodeScript::Limbu),
+    (0x1920, 0x192B, UnicodeScript::Limbu),
+    (0x1930, 0x193B, UnicodeScript::Limbu),
+    (0x1940, 0x1940, UnicodeScript::Limbu),
+    (0x1944, 0x194F, UnicodeScript::Limbu),
+    (0x1950, 0x196D, UnicodeScript::TaiLe),
+    (0x1970, 0x1974, UnicodeScript::TaiLe),
+    (0x1980, 0x19AB, UnicodeScript::NewTaiLue),
+    (0x19B0, 0x19C9, UnicodeScript::NewTaiLue),
+    (0x19D0, 0x19DA, UnicodeScript::NewTaiLue),
+    (0x19DE, 0x19DF, UnicodeScript::NewTaiLue),
+    (0x19E0, 0x19FF, UnicodeScript::Khmer),
+    (0x1A00, 0x1A1B, UnicodeScript::Buginese),
+    (0x1A1E, 0x1A1F, UnicodeScript::Buginese),
+    (0x1A20, 0x1A5E, UnicodeScript::TaiTham),
+    (0x1A60, 0x1A7C, UnicodeScript::TaiTham),
+    (0x1A7F, 0x1A89, UnicodeScript::TaiTham),
+    (0x1A90, 0x1A99, UnicodeScript::TaiTham),
+    (0x1AA0, 0x1AAD, UnicodeScript::TaiTham),
+    (0x1AB0, 0x1AC0, UnicodeScript::Inherited),
+    (0x1B00, 0x1B4B, UnicodeScript::Balinese),
+    (0x1B50, 0x1B7C, UnicodeScript::Balinese),
+    (0x1B80, 0x1BBF, UnicodeScript::Sundanese),
+    (0x1BC0, 0x1BF3, UnicodeScript::Batak),
+    (0x1BFC, 0x1BFF, UnicodeScript::Batak),
+    (0x1C00, 0x1C37, UnicodeScript::Lepcha),
+    (0x1C3B, 0x1C49, UnicodeScript::Lepcha),
+    (0x1C4D, 0x1C4F, UnicodeScript::Lepcha),
+    (0x1C50, 0x1C7F, UnicodeScript::OlChiki),
+    (0x1C80, 0x1C88, UnicodeScript::Cyrillic),
+    (0x1C90, 0x1CBA, UnicodeScript::Georgian),
+    (0x1CBD, 0x1CBF, UnicodeScript::Georgian),
+    (0x1CC0, 0x1CC7, UnicodeScript::Sundanese),
+    (0x1CD0, 0x1CD2, UnicodeScript::Inherited),
+    (0x1CD3, 0x1CD3, UnicodeScript::Common),
+    (0x1CD4, 0x1CE0, UnicodeScript::Inherited),
+    (0x1CE1, 0x1CE1, UnicodeScript::Common),
+    (0x1CE2, 0x1CE8, UnicodeScript::Inherited),
+    (0x1CE9, 0x1CEC, UnicodeScript::Common),
+    (0x1CED, 0x1CED, UnicodeScript::Inherited),
+    (0x1CEE, 0x1CF3, UnicodeScript::Common),
+    (0x1CF4, 0x1CF4, UnicodeScript::Inherited),
+    (0x1CF5, 0x1CF7, UnicodeScript::Common),
+    (0x1CF8, 0x1CF9, UnicodeScript::Inherited),
+    (0x1CFA, 0x1CFA, UnicodeScript::Common),
+    (0x1D00, 0x1D25, UnicodeScript::Latin),
+    (0x1D26, 0x1D2A, UnicodeScript::Greek),
+    (0x1D2B, 0x1D2B, UnicodeScript::Cyrillic),
+    (0x1D2C, 0x1D5C, UnicodeScript::Latin),
+    (0x1D5D, 0x1D61, UnicodeScript::Greek),
+    (0x1D62, 0x1D65, UnicodeScript::Latin),
+    (0x1D66, 0x1D6A, UnicodeScript::Greek),
+    (0x1D6B, 0x1D77, UnicodeScript::Latin),
+    (0x1D78, 0x1D78, UnicodeScript::Cyrillic),
+    (0x1D79, 0x1DBE, UnicodeScript::Latin),
+    (0x1DBF, 0x1DBF, UnicodeScript::Greek),
+    (0x1DC0, 0x1DF9, UnicodeScript::Inherited),
+    (0x1DFB, 0x1DFF, UnicodeScript::Inherited),
+    (0x1E00, 0x1EFF, UnicodeScript::Latin),
+    (0x1F00, 0x1F15, UnicodeScript::Greek),
+    (0x1F18, 0x1F1D, UnicodeScript::Greek),
+    (0x1F20, 0x1F45, UnicodeScript::Greek),
+    (0x1F48, 0x1F4D, UnicodeScript::Greek),
+    (0x1F50, 0x1F57, UnicodeScript::Greek),
+    (0x1F59, 0x1F59, UnicodeScript::Greek),
+    (0x1F5B, 0x1F5B, UnicodeScript::Greek),
+    (0x1F5D, 0x1F5D, UnicodeScript::Greek),
+    (0x1F5F, 0x1F7D, UnicodeScript::Greek),
+    (0x1F80, 0x1FB4, UnicodeScript::Greek),
+    (0x1FB6, 0x1FC4, UnicodeScript::Greek),
+    (0x1FC6, 0x1FD3, UnicodeScript::Greek),
+    (0x1FD6, 0x1FDB, UnicodeScript::Greek),
+    (0x1FDD, 0x1FEF, UnicodeScript::Greek),
+    (0x1FF2, 0x1FF4, UnicodeScript::Greek),
+    (0x1FF6, 0x1FFE, UnicodeScript::Greek),
+    (0x2000, 0x200B, UnicodeScript::Common),
+    (0x200C, 0x200D, UnicodeScript::Inherited),
+    (0x200E, 0x2064, UnicodeScript::Common),
+    (0x2066, 0x2070, UnicodeScript::Common),
+    (0x2071, 0x2071, UnicodeScript::Latin),
+    (0x2074, 0x207E, UnicodeScript::Common),
+    (0x207F, 0x207F, UnicodeScript::Latin),
+    (0x2080, 0x208E, UnicodeScript::Common),
+    (0x2090, 0x209C, UnicodeScript::Latin),
+    (0x20A0, 0x20BF, UnicodeScript::Common),
+    (0x20D0, 0x20F0, UnicodeScript::Inherited),
+    (0x2100, 0x2125, UnicodeScript::Common),
+    (0x2126, 0x2126, UnicodeScript::Greek),
+    (0x2127, 0x2129, UnicodeScript::Common),
+    (0x212A, 0x212B, UnicodeScript::Latin),
+    (0x212C, 0x2131, UnicodeScript::Common),
+    (0x2132, 0x2132, UnicodeScript::Latin),
+    (0x2133, 0x214D, UnicodeScript::Common),
+    (0x214E, 0x214E, UnicodeScript::Latin),
+    (0x214F, 0x215F, UnicodeScript::Common),
+    (0x2160, 0x2188, UnicodeScript::Latin),
+    (0x2189, 0x218B, UnicodeScript::Common),
+    (0x2190, 0x2426, UnicodeScript::Common),
+    (0x2440, 0x244A, UnicodeScript::Common),
+    (0x2460, 0x27FF, UnicodeScript::Common),
+    (0x2800, 0x28FF, UnicodeScript::Braille),
+    (0x2900, 0x2B73, UnicodeScript::Common),
+    (0x2B76, 0x2B95, UnicodeScript::Common),
+    (0x2B97, 0x2BFF, UnicodeScript::Common),
+    (0x2C00, 0x2C2E, UnicodeScript::Glagolitic),
+    (0x2C30, 0x2C5E, UnicodeScript::Glagolitic),
+    (0x2C60, 0x2C7F, UnicodeScript::Latin),
+    (0x2C80, 0x2CF3, UnicodeScript::Coptic),
+    (0x2CF9, 0x2CFF, UnicodeScript::Coptic),
+    (0x2D00, 0x2D25, UnicodeScript::Georgian),
+    (0x2D27, 0x2D27, UnicodeScript::Georgian),
+    (0x2D2D, 0x2D2D, UnicodeScript::Georgian),
+    (0x2D30, 0x2D67, UnicodeScript::Tifinagh),
+    (0x2D6F, 0x2D70, UnicodeScript::Tifinagh),
+    (0x2D7F, 0x2D7F, UnicodeScript::Tifinagh),
+    (0x2D80, 0x2D96, UnicodeScript::Ethiopic),
+    (0x2DA0, 0x2DA6, UnicodeScript::Ethiopic),
+    (0x2DA8, 0x2DAE, UnicodeScript::Ethiopic),
+    (0x2DB0, 0x2DB6, UnicodeScript::Ethiopic),
+    (0x2DB8, 0x2DBE, UnicodeScript::Ethiopic),
+    (0x2DC0, 0x2DC6, UnicodeScript::Ethiopic),
+    (0x2DC8, 0x2DCE, UnicodeScript::Ethiopic),
+    (0x2DD0, 0x2DD6, UnicodeScript::Ethiopic),
+    (0x2DD8, 0x2DDE, UnicodeScript::Ethiopic),
+    (0x2DE0, 0x2DFF, UnicodeScript::Cyrillic),
+    (0x2E00, 0x2E52, UnicodeScript::Common),
+    (0x2E80, 0x2E99, UnicodeScript::Han),
+    (0x2E9B, 0x2EF3, UnicodeScript::Han),
+    (0x2F00, 0x2FD5, UnicodeScript::Han),
+    (0x2FF0, 0x2FFB, UnicodeScript::Common),
+    (0x3000, 0x3004, UnicodeScript::Common),
+    (0x3005, 0x3005, UnicodeScript::Han),
+    (0x3006, 0x3006, UnicodeScript::Common),
+    (0x3007, 0x3007, UnicodeScript::Han),
+    (0x3008, 0x3020, UnicodeScript::Common),
+    (0x3021, 0x3029, UnicodeScript::Han),
+    (0x302A, 0x302D, UnicodeScript::Inherited),
+    (0x302E, 0x302F, UnicodeScript::Hangul),
+    (0x3030, 0x3037, UnicodeScript::Common),
+    (0x3038, 0x303B, UnicodeScript::Han),
+    (0x303C, 0x303F, UnicodeScript::Common),
+    (0x3041, 0x3096, UnicodeScript::Hiragana),
+    (0x3099, 0x309A, UnicodeScript::Inherited),
+    (0x309B, 0x309C, UnicodeScript::Common),
+    (0x309D, 0x309F, UnicodeScript::Hiragana),
+    (0x30A0, 0x30A0, UnicodeScript::Common),
+    (0x30A1, 0x30FA, UnicodeScript::Katakana),
+    (0x30FB, 0x30FC, UnicodeScript::Common),
+    (0x30FD, 0x30FF, UnicodeScript::Katakana),
+    (0x3105, 0x312F, UnicodeScript::Bopomofo),
+    (0x3131, 0x318E, UnicodeScript::Hangul),
+    (0x3190, 0x319F, UnicodeScript::Common),
+    (0x31A0, 0x31BF, UnicodeScript::Bopomofo),
+    (0x31C0, 0x31E3, UnicodeScript::Common),
+    (0x31F0, 0x31FF, UnicodeScript::Katakana),
+    (0x3200, 0x321E, UnicodeScript::Hangul),
+    (0x3220, 0x325F, UnicodeScript::Common),
+    (0x3260, 0x327E, UnicodeScript::Hangul),
+    (0x327F, 0x32CF, UnicodeScript::Common),
+    (0x32D0, 0x32FE, UnicodeScript::Katakana),
+    (0x32FF, 0x32FF, UnicodeScript::Common),
+    (0x3300, 0x3357, UnicodeScript::Katakana),
+    (0x3358, 0x33FF, UnicodeScript::Common),
+    (0x3400, 0x4DBF, UnicodeScript::Han),
+    (0x4DC0, 0x4DFF, UnicodeScript::Common),
+    (0x4E00, 0x9FFC, UnicodeScript::Han),
+    (0xA000, 0xA48C, UnicodeScript::Yi),
+    (0xA490, 0xA4C6, UnicodeScript::Yi),
+    (0xA4D0, 0xA4FF, UnicodeScript::Lisu),
+    (0xA500, 0xA62B, UnicodeScript::Vai),
+    (0xA640, 0xA69F, UnicodeScript::Cyrillic),
+    (0xA6A0, 0xA6F7, UnicodeScript::Bamum),
+    (0xA700, 0xA721, UnicodeScript::Common),
+    (0xA722, 0xA787, UnicodeScript::Latin),
+    (0xA788, 0xA78A, UnicodeScript::Common),
+    (0xA78B, 0xA7BF, UnicodeScript::Latin),
+    (0xA7C2, 0xA7CA, UnicodeScript::Latin),
+    (0xA7F5, 0xA7FF, UnicodeScript::Latin),
+    (0xA800, 0xA82C, UnicodeScript::SylotiNagri),
+    (0xA830, 0xA839, UnicodeScript::Common),
+    (0xA840, 0xA877, UnicodeScript::PhagsPa),
+    (0xA880, 0xA8C5, UnicodeScript::Saurashtra),
+    (0xA8CE, 0xA8D9, UnicodeScript::Saurashtra),
+    (0xA8E0, 0xA8FF, UnicodeScript::Devanagari),
+    (0xA900, 0xA92D, UnicodeScript::KayahLi),
+    (0xA92E, 0xA92E, UnicodeScript::Common),
+    (0xA92F, 0xA92F, UnicodeScript::KayahLi),
+    (0xA930, 0xA953, UnicodeScript::Rejang),
+    (0xA95F, 0xA95F, UnicodeScript::Rejang),
+    (0xA960, 0xA97C, UnicodeScript::Hangul),
+    (0xA980, 0xA9CD, UnicodeScript::Javanese),
+    (0xA9CF, 0xA9CF, UnicodeScript::Common),
+    (0xA9D0, 0xA9D9, UnicodeScript::Javanese),
+    (0xA9DE, 0xA9DF, UnicodeScript::Javanese),
+    (0xA9E0, 0xA9FE, UnicodeScript::Myanmar),
+    (0xAA00, 0xAA36, UnicodeScript::Cham),
+    (0xAA40, 0xAA4D, UnicodeScript::Cham),
+    (0xAA50, 0xAA59, UnicodeScript::Cham),
+    (0xAA5C, 0xAA5F, UnicodeScript::Cham),
+    (0xAA60, 0xAA7F, UnicodeScript::Myanmar),
+    (0xAA80, 0xAAC2, UnicodeScript::TaiViet),
+    (0xAADB, 0xAADF, UnicodeScript::TaiViet),
+    (0xAAE0, 0xAAF6, UnicodeScript::MeeteiMayek),
+    (0xAB01, 0xAB06, UnicodeScript::Ethiopic),
+    (0xAB09, 0xAB0E, UnicodeScript::Ethiopic),
+    (0xAB11, 0xAB16, UnicodeScript::Ethiopic),
+    (0xAB20, 0xAB26, UnicodeScript::Ethiopic),
+    (0xAB28, 0xAB2E, UnicodeScript::Ethiopic),
+    (0xAB30, 0xAB5A, UnicodeScript::Latin),
+    (0xAB5B, 0xAB5B, UnicodeScript::Common),
+    (0xAB5C, 0xAB64, UnicodeScript::Latin),
+    (0xAB65, 0xAB65, UnicodeScript::Greek),
+    (0xAB66, 0xAB69, UnicodeScript::Latin),
+    (0xAB6A, 0xAB6B, UnicodeScript::Common),
+    (0xAB70, 0xABBF, UnicodeScript::Cherokee),
+    (0xABC0, 0xABED, UnicodeScript::MeeteiMayek),
+    (0xABF0, 0xABF9, UnicodeScript::MeeteiMayek),
+    (0xAC00, 0xD7A3, UnicodeScript::Hangul),
+    (0xD7B0, 0xD7C6, UnicodeScript::Hangul),
+    (0xD7CB, 0xD7FB, UnicodeScript::Hangul),
+    (0xF900, 0xFA6D, UnicodeScript::Han),
+    (0xFA70, 0xFAD9, UnicodeScript::Han),
+    (0xFB00, 0xFB06, UnicodeScript::Latin),
+    (0xFB13, 0xFB17, UnicodeScript::Armenian),
+    (0xFB1D, 0xFB36, UnicodeScript::Hebrew),
+    (0xFB38, 0xFB3C, UnicodeScript::Hebrew),
+    (0xFB3E, 0xFB3E, UnicodeScript::Hebrew),
+    (0xFB40, 0xFB41, UnicodeScript::Hebrew),
+    (0xFB43, 0xFB44, UnicodeScript::Hebrew),
+    (0xFB46, 0xFB4F, UnicodeScript::Hebrew),
+    (0xFB50, 0xFBC1, UnicodeScript::Arabic),
+    (0xFBD3, 0xFD3D, UnicodeScript::Arabic),
+    (0xFD3E, 0xFD3F, UnicodeScript::Common),
+    (0xFD50, 0xFD8F, UnicodeScript::Arabic),
+    (0xFD92, 0xFDC7, UnicodeScript::Arabic),
+    (0xFDF0, 0xFDFD, UnicodeScript::Arabic),
+    (0xFE00, 0xFE0F, UnicodeScript::Inherited),
+    (0xFE10, 0xFE19, UnicodeScript::Common),
+    (0xFE20, 0xFE2D, UnicodeScript::Inherited),
+    (0xFE2E, 0xFE2F, UnicodeScript::Cyrillic),
+    (0xFE30, 0xFE52, UnicodeScript::Common),
+    (0xFE54, 0xFE66, UnicodeScript::Common),
+    (0xFE68, 0xFE6B, UnicodeScript::Common),
+    (0xFE70, 0xFE74, UnicodeScript::Arabic),
+    (0xFE76, 0xFEFC, UnicodeScript::Arabic),
+    (0xFEFF, 0xFEFF, UnicodeScript::Common),
+    (0xFF01, 0xFF20, UnicodeScript::Common),
+    (0xFF21, 0xFF3A, UnicodeScript::Latin),
+    (0xFF3B, 0xFF40, UnicodeScript::Common),
+    (0xFF41, 0xFF5A, UnicodeScript::Latin),
+    (0xFF5B, 0xFF65, UnicodeScript::Common),
+    (0xFF66, 0xFF6F, UnicodeScript::Katakana),
+    (0xFF70, 0xFF70, UnicodeScript::Common),
+    (0xFF71, 0xFF9D, UnicodeScript::Katakana),
+    (0xFF9E, 0xFF9F, UnicodeScript::Common),
+    (0xFFA0, 0xFFBE, UnicodeScript::Hangul),
+    (0xFFC2, 0xFFC7, UnicodeScript::Hangul),
+    (0xFFCA, 0xFFCF, UnicodeScript::Hangul),
+    (0xFFD2, 0xFFD7, UnicodeScript::Hangul),
+    (0xFFDA, 0xFFDC, UnicodeScript::Hangul),
+    (0xFFE0, 0xFFE6, UnicodeScript::Common),
+    (0xFFE8, 0xFFEE, UnicodeScript::Common),
+    (0xFFF9, 0xFFFD, UnicodeScript::Common),
+    (0x10000, 0x1000B, UnicodeScript::LinearB),
+    (0x1000D, 0x10026, UnicodeScript::LinearB),
+    (0x10028, 0x1003A, UnicodeScript::LinearB),
+    (0x1003C, 0x1003D, UnicodeScript::LinearB),
+    (0x1003F, 0x1004D, UnicodeScript::LinearB),
+    (0x10050, 0x1005D, UnicodeScript::LinearB),
+    (0x10080, 0x100FA, UnicodeScript::LinearB),
+    (0x10100, 0x10102, UnicodeScript::Common),
+    (0x10107, 0x10133, UnicodeScript::Common),
+    (0x10137, 0x1013F, UnicodeScript::Common),
+    (0x10140, 0x1018E, UnicodeScript::Greek),
+    (0x10190, 0x1019C, UnicodeScript::Common),
+    (0x101A0, 0x101A0, UnicodeScript::Greek),
+    (0x101D0, 0x101FC, UnicodeScript::Common),
+    (0x101FD, 0x101FD, UnicodeScript::Inherited),
+    (0x10280, 0x1029C, UnicodeScript::Lycian),
+    (0x102A0, 0x102D0, UnicodeScript::Carian),
+    (0x102E0, 0x102E0, UnicodeScript::Inherited),
+    (0x102E1, 0x102FB, UnicodeScript::Common),
+    (0x10300, 0x10323, UnicodeScript::OldItalic),
+    (0x1032D, 0x1032F, UnicodeScript::OldItalic),
+    (0x10330, 0x1034A, UnicodeScript::Gothic),
+    (0x10350, 0x1037A, UnicodeScript::OldPermic),
+    (0x10380, 0x1039D, UnicodeScript::Ugaritic),
+    (0x1039F, 0x1039F, UnicodeScript::Ugaritic),
+    (0x103A0, 0x103C3, UnicodeScript::OldPersian),
+    (0x103C8, 0x103D5, UnicodeScript::OldPersian),
+    (0x10400, 0x1044F, UnicodeScript::Deseret),
+    (0x10450, 0x1047F, UnicodeScript::Shavian),
+    (0x10480, 0x1049D, UnicodeScript::Osmanya),
+    (0x104A0, 0x104A9, UnicodeScript::Osmanya),
+    (0x104B0, 0x104D3, UnicodeScript::Osage),
+    (0x104D8, 0x104FB, UnicodeScript::Osage),
+    (0x10500, 0x10527, UnicodeScript::Elbasan),
+    (0x10530, 0x10563, UnicodeScript::CaucasianAlbanian),
+    (0x1056F, 0x1056F, UnicodeScript::CaucasianAlbanian),
+    (0x10600, 0x10736, UnicodeScript::LinearA),
+    (0x10740, 0x10755, UnicodeScript::LinearA),
+    (0x10760, 0x10767, UnicodeScript::LinearA),
+    (0x10800, 0x10805, UnicodeScript::Cypriot),
+    (0x10808, 0x10808, UnicodeScript::Cypriot),
+    (0x1080A, 0x10835, UnicodeScript::Cypriot),
+    (0x10837, 0x10838, UnicodeScript::Cypriot),
+    (0x1083C, 0x1083C, UnicodeScript::Cypriot),
+    (0x1083F, 0x1083F, UnicodeScript::Cypriot),
+    (0x10840, 0x10855, UnicodeScript::ImperialAramaic),
+    (0x10857, 0x1085F, UnicodeScript::ImperialAramaic),
+    (0x10860, 0x1087F, UnicodeScript::Palmyrene),
+    (0x10880, 0x1089E, UnicodeScript::Nabataean),
+    (0x108A7, 0x108AF, UnicodeScript::Nabataean),
+    (0x108E0, 0x108F2, UnicodeScript::Hatran),
+    (0x108F4, 0x108F5, UnicodeScript::Hatran),
+    (0x108FB, 0x108FF, UnicodeScript::Hatran),
+    (0x10900, 0x1091B, UnicodeScript::Phoenician),
+    (0x1091F, 0x1091F, UnicodeScript::Phoenician),
+    (0x10920, 0x10939, UnicodeScript::Lydian),
+    (0x1093F, 0x1093F, UnicodeScript::Lydian),
+    (0x10980, 0x1099F, UnicodeScript::MeroiticHieroglyphs),
+    (0x109A0, 0x109B7, UnicodeScript::MeroiticCursive),
+    (0x109BC, 0x109CF, UnicodeScript::MeroiticCursive),
+    (0x109D2, 0x109FF, UnicodeScript::MeroiticCursive),
+    (0x10A00, 0x10A03, UnicodeScript::Kharoshthi),
+    (0x10A05, 0x10A06, UnicodeScript::Kharoshthi),
+    (0x10A0C, 0x10A13, UnicodeScript::Kharoshthi),
+    (0x10A15, 0x10A17, UnicodeScript::Kharoshthi),
+    (0x10A19, 0x10A35, UnicodeScript::Kharoshthi),
+    (0x10A38, 0x10A3A, UnicodeScript::Kharoshthi),
+    (0x10A3F, 0x10A48, UnicodeScript::Kharoshthi),
+    (0x10A50, 0x10A58, UnicodeScript::Kharoshthi),
+    (0x10A60, 0x10A7F, UnicodeScript::OldSouthArabian),
+    (0x10A80, 0x10A9F, UnicodeScript::OldNorthArabian),
+    (0x10AC0, 0x10AE6, UnicodeScript::Manichaean),
+    (0x10AEB, 0x10AF6, UnicodeScript::Manichaean),
+    (0x10B00, 0x10B35, UnicodeScript::Avestan),
+    (0x10B39, 0x10B3F, UnicodeScript::Avestan),
+    (0x10B40, 0x10B55, UnicodeScript::InscriptionalParthian),
+    (0x10B58, 0x10B5F, UnicodeScript::InscriptionalParthian),
+    (0x10B60, 0x10B72, UnicodeScript::InscriptionalPahlavi),
+    (0x10B78, 0x10B7F, UnicodeScript::InscriptionalPahlavi),
+    (0x10B80, 0x10B91, UnicodeScript::PsalterPahlavi),
+    (0x10B99, 0x10B9C, UnicodeScript::PsalterPahlavi),
+    (0x10BA9, 0x10BAF, UnicodeScript::PsalterPahlavi),
+    (0x10C00, 0x10C48, UnicodeScript::OldTurkic),
+    (0x10C80, 0x10CB2, UnicodeScript::OldHungarian),
+    (0x10CC0, 0x10CF2, UnicodeScript::OldHungarian),
+    (0x10CFA, 0x10CFF, UnicodeScript::OldHungarian),
+    (0x10D00, 0x10D27, UnicodeScript::HanifiRohingya),
+    (0x10D30, 0x10D39, UnicodeScript::HanifiRohingya),
+    (0x10E60, 0x10E7E, UnicodeScript::Arabic),
+    (0x10E80, 0x10EA9, UnicodeScript::Yezidi),
+    (0x10EAB, 0x10EAD, UnicodeScript::Yezidi),
+    (0x10EB0, 0x10EB1, UnicodeScript::Yezidi),
+    (0x10F00, 0x10F27, UnicodeScript::OldSogdian),
+    (0x10F30, 0x10F59, UnicodeScript::Sogdian),
+    (0x10FB0, 0x10FCB, UnicodeScript::Chorasmian),
+    (0x10FE0, 0x10FF6, UnicodeScript::Elymaic),
+    (0x11000, 0x1104D, UnicodeScript::Brahmi),
+    (0x11052, 0x1106F, UnicodeScript::Brahmi),
+    (0x1107F, 0x1107F, UnicodeScript::Brahmi),
+    (0x11080, 0x110C1, UnicodeScript::Kaithi),
+    (0x110CD, 0x110CD, UnicodeScript::Kaithi),
+    (0x110D0, 0x110E8, UnicodeScript::SoraSompeng),
+    (0x110F0, 0x110F9, UnicodeScript::SoraSompeng),
+    (0x11100, 0x11134, UnicodeScript::Chakma),
+    (0x11136, 0x11147, UnicodeScript::Chakma),
+    (0x11150, 0x11176, UnicodeScript::Mahajani),
+    (0x11180, 0x111DF, UnicodeScript::Sharada),
+    (0x111E1, 0x111F4, UnicodeScript::Sinhala),
+    (0x11200, 0x11211, UnicodeScript::Khojki),
+    (0x11213, 0x1123E, UnicodeScript::Khojki),
+    (0x11280, 0x11286, UnicodeScript::Multani),
+    (0x11288, 0x11288, UnicodeScript::Multani),
+    (0x1128A, 0x1128D, UnicodeScript::Multani),
+    (0x1128F, 0x1129D, UnicodeScript::Multani),
+    (0x1129F, 0x112A9, UnicodeScript::Multani),
+    (0x112B0, 0x112EA, UnicodeScript::Khudawadi),
+    (0x112F0, 0x112F9, UnicodeScript::Khudawadi),
+    (0x11300, 0x11303, UnicodeScript::Grantha),
+    (0x11305, 0x1130C, UnicodeScript::Grantha),
+    (0x1130F, 0x11310, UnicodeScript::Grantha),
+    (0x11313, 0x11328, UnicodeScript::Grantha),
+    (0x1132A, 0x11330, UnicodeScript::Grantha),
+    (0x11332, 0x11333, UnicodeScript::Grantha),
+    (0x11335, 0x11339, UnicodeScript::Grantha),
+    (0x1133B, 0x1133B, UnicodeScript::Inherited),
+    (0x1133C, 0x11344, UnicodeScript::Grantha),
+    (0x11347, 0x11348, UnicodeScript::Grantha),
+    (0x1134B, 0x1134D, UnicodeScript::Grantha),
+    (0x11350, 0x11350, UnicodeScript::Grantha),
+    (0x11357, 0x11357, UnicodeScript::Grantha),
+    (0x1135D, 0x11363, UnicodeScript::Grantha),
+    (0x11366, 0x1136C, UnicodeScript::Grantha),
+    (0x11370, 0x11374, UnicodeScript::Grantha),
+    (0x11400, 0x1145B, UnicodeScript::Newa),
+    (0x1145D, 0x11461, UnicodeScript::Newa),
+    (0x11480, 0x114C7, UnicodeScript::Tirhuta),
+    (0x114D0, 0x114D9, UnicodeScript::Tirhuta),
+    (0x11580, 0x115B5, UnicodeScript::Siddham),
+    (0x115B8, 0x115DD, UnicodeScript::Siddham),
+    (0x11600, 0x11644, UnicodeScript::Modi),
+    (0x11650, 0x11659, UnicodeScript::Modi),
+    (0x11660, 0x1166C, UnicodeScript::Mongolian),
+    (0x11680, 0x116B8, UnicodeScript::Takri),
+    (0x116C0, 0x116C9, UnicodeScript::Takri),
+    (0x11700, 0x1171A, UnicodeScript::Ahom),
+    (0x1171D, 0x1172B, UnicodeScript::Ahom),
+    (0x11730, 0x1173F, UnicodeScript::Ahom),
+    (0x11800, 0x1183B, UnicodeScript::Dogra),
+    (0x118A0, 0x118F2, UnicodeScript::WarangCiti),
+    (0x118FF, 0x118FF, UnicodeScript::WarangCiti),
+    (0x11900, 0x11906, UnicodeScript::DivesAkuru),
+    (0x11909, 0x11909, UnicodeScript::DivesAkuru),
+    (0x1190C, 0x11913, UnicodeScript::DivesAkuru),
+    (0x11915, 0x11916, UnicodeScript::DivesAkuru),
+    (0x11918, 0x11935, UnicodeScript::DivesAkuru),
+    (0x11937, 0x11938, UnicodeScript::DivesAkuru),
+    (0x1193B, 0x11946, UnicodeScript::DivesAkuru),
+    (0x11950, 0x11959, UnicodeScript::DivesAkuru),
+    (0x119A0, 0x119A7, UnicodeScript::Nandinagari),
+    (0x119AA, 0x119D7, UnicodeScript::Nandinagari),
+    (0x119DA, 0x119E4, UnicodeScript::Nandinagari),
+    (0x11A00, 0x11A47, UnicodeScript::ZanabazarSquare),
+    (0x11A50, 0x11AA2, UnicodeScript::Soyombo),
+    (0x11AC0, 0x11AF8, UnicodeScript::PauCinHau),
+    (0x11C00, 0x11C08, UnicodeScript::Bhaiksuki),
+    (0x11C0A, 0x11C36, UnicodeScript::Bhaiksuki),
+    (0x11C38, 0x11C45, UnicodeScript::Bhaiksuki),
+    (0x11C50, 0x11C6C, UnicodeScript::Bhaiksuki),
+    (0x11C70, 0x11C8F, UnicodeScript::Marchen),
+    (0x11C92, 0x11CA7, UnicodeScript::Marchen),
+    (0x11CA9, 0x11CB6, UnicodeScript::Marchen),
+    (0x11D00, 0x11D06, UnicodeScript::MasaramGondi),
+    (0x11D08, 0x11D09, UnicodeScript::MasaramGondi),
+    (0x11D0B, 0x11D36, UnicodeScript::MasaramGondi),
+    (0x11D3A, 0x11D3A, UnicodeScript::MasaramGondi),
+    (0x11D3C, 0x11D3D, UnicodeScript::MasaramGondi),
+    (0x11D3F, 0x11D47, UnicodeScript::MasaramGondi),
+    (0x11D50, 0x11D59, UnicodeScript::MasaramGondi),
+    (0x11D60, 0x11D65, UnicodeScript::GunjalaGondi),
+    (0x11D67, 0x11D68, UnicodeScript::GunjalaGondi),
+    (0x11D6A, 0x11D8E, UnicodeScript::GunjalaGondi),
+    (0x11D90, 0x11D91, UnicodeScript::GunjalaGondi),
+    (0x11D93, 0x11D98, UnicodeScript::GunjalaGondi),
+    (0x11DA0, 0x11DA9, UnicodeScript::GunjalaGondi),
+    (0x11EE0, 0x11EF8, UnicodeScript::Makasar),
+    (0x11FB0, 0x11FB0, UnicodeScript::Lisu),
+    (0x11FC0, 0x11FF1, UnicodeScript::Tamil),
+    (0x11FFF, 0x11FFF, UnicodeScript::Tamil),
+    (0x12000, 0x12399, UnicodeScript::Cuneiform),
+    (0x12400, 0x1246E, UnicodeScript::Cuneiform),
+    (0x12470, 0x12474, UnicodeScript::Cuneiform),
+    (0x12480, 0x12543, UnicodeScript::Cuneiform),
+    (0x13000, 0x1342E, UnicodeScript::EgyptianHieroglyphs),
+    (0x13430, 0x13438, UnicodeScript::EgyptianHieroglyphs),
+    (0x14400, 0x14646, UnicodeScript::AnatolianHieroglyphs),
+    (0x16800, 0x16A38, UnicodeScript::Bamum),
+    (0x16A40, 0x16A5E, UnicodeScript::Mro),
+    (0x16A60, 0x16A69, UnicodeScript::Mro),
+    (0x16A6E, 0x16A6F, UnicodeScript::Mro),
+    (0x16AD0, 0x16AED, UnicodeScript::BassaVah),
+    (0x16AF0, 0x16AF5, UnicodeScript::BassaVah),
+    (0x16B00, 0x16B45, UnicodeScript::PahawhHmong),
+    (0x16B50, 0x16B59, UnicodeScript::PahawhHmong),
+    (0x16B5B, 0x16B61, UnicodeScript::PahawhHmong),
+    (0x16B63, 0x16B77, UnicodeScript::PahawhHmong),
+    (0x16B7D, 0x16B8F, UnicodeScript::PahawhHmong),
+    (0x16E40, 0x16E9A, UnicodeScript::Medefaidrin),
+    (0x16F00, 0x16F4A, UnicodeScript::Miao),
+    (0x16F4F, 0x16F87, UnicodeScript::Miao),
+    (0x16F8F, 0x16F9F, UnicodeScript::Miao),
+    (0x16FE0, 0x16FE0, UnicodeScript::Tangut),
+    (0x16FE1, 0x16FE1, UnicodeScript::Nushu),
+    (0x16FE2, 0x16FE3, UnicodeScript::Common),
+    (0x16FE4, 0x16FE4, UnicodeScript::KhitanSmallScript),
+    (0x16FF0, 0x16FF1, UnicodeScript::Han),
+    (0x17000, 0x187F7, UnicodeScript::Tangut),
+    (0x18800, 0x18AFF, UnicodeScript::Tangut),
+    (0x18B00, 0x18CD5, UnicodeScript::KhitanSmallScript),
+    (0x18D00, 0x18D08, UnicodeScript::Tangut),
+    (0x1B000, 0x1B000, UnicodeScript::Katakana),
+    (0x1B001, 0x1B11E, UnicodeScript::Hiragana),
+    (0x1B150, 0x1B152, UnicodeScript::Hiragana),
+    (0x1B164, 0x1B167, UnicodeScript::Katakana),
+    (0x1B170, 0x1B2FB, UnicodeScript::Nushu),
+    (0x1BC00, 0x1BC6A, UnicodeScript::Duployan),
+    (0x1BC70, 0x1BC7C, UnicodeScript::Duployan),
+    (0x1BC80, 0x1BC88, UnicodeScript::Duployan),
+    (0x1BC90, 0x1BC99, UnicodeScript::Duployan),
+    (0x1BC9C, 0x1BC9F, UnicodeScript::Duployan),
+    (0x1BCA0, 0x1BCA3, UnicodeScript::Common),
+    (0x1D000, 0x1D0F5, UnicodeScript::Common),
+    (0x1D100, 0x1D126, UnicodeScript::Common),
+    (0x1D129, 0x1D166, UnicodeScript::Common),
+    (0x1D167, 0x1D169, UnicodeScript::Inherited),
+    (0x1D16A, 0x1D17A, UnicodeScript::Common),
+    (0x1D17B, 0x1D182, UnicodeScript::Inherited),
+    (0x1D183, 0x1D184, UnicodeScript::Common),
+    (0x1D185, 0x1D18B, UnicodeScript::Inherited),
+    (0x1D18C, 0x1D1A9, UnicodeScript::Common),
+    (0x1D1AA, 0x1D1AD, UnicodeScript::Inherited),
+    (0x1D1AE, 0x1D1E8, UnicodeScript::Common),
+    (0x1D200, 0x1D245, UnicodeScript::Greek),
+    (0x1D2E0, 0x1D2F3, UnicodeScript::Common),
+    (0x1D300, 0x1D356, UnicodeScript::Common),
+    (0x1D360, 0x1D378, UnicodeScript::Common),
+    (0x1D400, 0x1D454, UnicodeScript::Common),
+    (0x1D456, 0x1D49C, UnicodeScript::Common),
+    (0x1D49E, 0x1D49F, UnicodeScript::Common),
+    (0x1D4A2, 0x1D4A2, UnicodeScript::Common),
+    (0x1D4A5, 0x1D4A6, UnicodeScript::Common),
+    (0x1D4A9, 0x1D4AC, UnicodeScript::Common),
+    (0x1D4AE, 0x1D4B9, UnicodeScript::Common),
+    (0x1D4BB, 0x1D4BB, UnicodeScript::Common),
+    (0x1D4BD, 0x1D4C3, UnicodeScript::Common),
+    (0x1D4C5, 0x1D505, UnicodeScript::Common),
+    (0x1D507, 0x1D50A, UnicodeScript::Common),
+    (0x1D50D, 0x1D514, UnicodeScript::Common),
+    (0x1D516, 0x1D51C, UnicodeScript::Common),
+    (0x1D51E, 0x1D539, UnicodeScript::Common),
+    (0x1D53B, 0x1D53E, UnicodeScript::Common),
+    (0x1D540, 0x1D544, UnicodeScript::Common),
+    (0x1D546, 0x1D546, UnicodeScript::Common),
+    (0x1D54A, 0x1D550, UnicodeScript::Common),
+    (0x1D552, 0x1D6A5, UnicodeScript::Common),
+    (0x1D6A8, 0x1D7CB, UnicodeScript::Common),
+    (0x1D7CE, 0x1D7FF, UnicodeScript::Common),
+    (0x1D800, 0x1DA8B, UnicodeScript::Signwriting),
+    (0x1DA9B, 0x1DA9F, UnicodeScript::Signwriting),
+    (0x1DAA1, 0x1DAAF, UnicodeScript::Signwriting),
+    (0x1E000, 0x1E006, UnicodeScript::Glagolitic),
+    (0x1E008, 0x1E018, UnicodeScript::Glagolitic),
+    (0x1E01B, 0x1E021, UnicodeScript::Glagolitic),
+    (0x1E023, 0x1E024, UnicodeScript::Glagolitic),
+    (0x1E026, 0x1E02A, UnicodeScript::Glagolitic),
+    (0x1E100, 0x1E12C, UnicodeScript::NyiakengPuachueHmong),
+    (0x1E130, 0x1E13D, UnicodeScript::NyiakengPuachueHmong),
+    (0x1E140, 0x1E149, UnicodeScript::NyiakengPuachueHmong),
+    (0x1E14E, 0x1E14F, UnicodeScript::NyiakengPuachueHmong),
+    (0x1E2C0, 0x1E2F9, UnicodeScript::Wancho),
+    (0x1E2FF, 0x1E2FF, UnicodeScript::Wancho),
+    (0x1E800, 0x1E8C4, UnicodeScript::MendeKikakui),
+    (0x1E8C7, 0x1E8D6, UnicodeScript::MendeKikakui),
+    (0x1E900, 0x1E94B, UnicodeScript::Adlam),
+    (0x1E950, 0x1E959, UnicodeScript::Adlam),
+    (0x1E95E, 0x1E95F, UnicodeScript::Adlam),
+    (0x1EC71, 0x1ECB4, UnicodeScript::Common),
+    (0x1ED01, 0x1ED3D, UnicodeScript::Common),
+    (0x1EE00, 0x1EE03, UnicodeScript::Arabic),
+    (0x1EE05, 0x1EE1F, UnicodeScript::Arabic),
+    (0x1EE21, 0x1EE22, UnicodeScript::Arabic),
+    (0x1EE24, 0x1EE24, UnicodeScript::Arabic),
+    (0x1EE27, 0x1EE27, UnicodeScript::Arabic),
+    (0x1EE29, 0x1EE32, UnicodeScript::Arabic),
+    (0x1EE34, 0x1EE37, UnicodeScript::Arabic),
+    (0x1EE39, 0x1EE39, UnicodeScript::Arabic),
+    (0x1EE3B, 0x1EE3B, UnicodeScript::Arabic),
+    (0x1EE42, 0x1EE42, UnicodeScript::Arabic),
+    (0x1EE47, 0x1EE47, UnicodeScript::Arabic),
+    (0x1EE49, 0x1EE49, UnicodeScript::Arabic),
+    (0x1EE4B, 0x1EE4B, UnicodeScript::Arabic),
+    (0x1EE4D, 0x1EE4F, UnicodeScript::Arabic),
+    (0x1EE51, 0x1EE52, UnicodeScript::Arabic),
+    (0x1EE54, 0x1EE54, UnicodeScript::Arabic),
+    (0x1EE57, 0x1EE57, UnicodeScript::Arabic),
+    (0x1EE59, 0x1EE59, UnicodeScript::Arabic),
+    (0x1EE5B, 0x1EE5B, UnicodeScript::Arabic),
+    (0x1EE5D, 0x1EE5D, UnicodeScript::Arabic),
+    (0x1EE5F, 0x1EE5F, UnicodeScript::Arabic),
+    (0x1EE61, 0x1EE62, UnicodeScript::Arabic),
+    (0x1EE64, 0x1EE64, UnicodeScript::Arabic),
+    (0x1EE67, 0x1EE6A, UnicodeScript::Arabic),
+    (0x1EE6C, 0x1EE72, UnicodeScript::Arabic),
+    (0x1EE74, 0x1EE77, UnicodeScript::Arabic),
+    (0x1EE79, 0x1EE7C, UnicodeScript::Arabic),
+    (0x1EE7E, 0x1EE7E, UnicodeScript::Arabic),
+    (0x1EE80, 0x1EE89, UnicodeScript::Arabic),
+    (0x1EE8B, 0x1EE9B, UnicodeScript::Arabic),
+    (0x1EEA1, 0x1EEA3, UnicodeScript::Arabic),
+    (0x1EEA5, 0x1EEA9, UnicodeScript::Arabic),
+    (0x1EEAB, 0x1EEBB, UnicodeScript::Arabic),
+    (0x1EEF0, 0x1EEF1, UnicodeScript::Arabic),
+    (0x1F000, 0x1F02B, UnicodeScript::Common),
+    (0x1F030, 0x1F093, UnicodeScript::Common),
+    (0x1F0A0, 0x1F0AE, UnicodeScript::Common),
+    (0x1F0B1, 0x1F0BF, UnicodeScript::Common),
+    (0x1F0C1, 0x1F0CF, UnicodeScript::Common),
+    (0x1F0D1, 0x1F0F5, UnicodeScript::Common),
+    (0x1F100, 0x1F1AD, UnicodeScript::Common),
+    (0x1F1E6, 0x1F1FF, UnicodeScript::Common),
+    (0x1F200, 0x1F200, UnicodeScript::Hiragana),
+    (0x1F201, 0x1F202, UnicodeScript::Common),
+    (0x1F210, 0x1F23B, UnicodeScript::Common),
+    (0x1F240, 0x1F248, UnicodeScript::Common),
+    (0x1F250, 0x1F251, UnicodeScript::Common),
+    (0x1F260, 0x1F265, UnicodeScript::Common),
+    (0x1F300, 0x1F6D7, UnicodeScript::Common),
+    (0x1F6E0, 0x1F6EC, UnicodeScript::Common),
+    (0x1F6F0, 0x1F6FC, UnicodeScript::Common),
+    (0x1F700, 0x1F773, UnicodeScript::Common),
+    (0x1F780, 0x1F7D8, UnicodeScript::Common),
+    (0x1F7E0, 0x1F7EB, UnicodeScript::Common),
+    (0x1F800, 0x1F80B, UnicodeScript::Common),
+    (0x1F810, 0x1F847, UnicodeScript::Common),
+    (0x1F850, 0x1F859, UnicodeScript::Common),
+    (0x1F860, 0x1F887, UnicodeScript::Common),
+    (0x1F890, 0x1F8AD, UnicodeScript::Common),
+    (0x1F8B0, 0x1F8B1, UnicodeScript::Common),
+    (0x1F900, 0x1F978, UnicodeScript::Common),
+    (0x1F97A, 0x1F9CB, UnicodeScript::Common),
+    (0x1F9CD, 0x1FA53, UnicodeScript::Common),
+    (0x1FA60, 0x1FA6D, UnicodeScript::Common),
+    (0x1FA70, 0x1FA74, UnicodeScript::Common),
+    (0x1FA78, 0x1FA7A, UnicodeScript::Common),
+    (0x1FA80, 0x1FA86, UnicodeScript::Common),
+    (0x1FA90, 0x1FAA8, UnicodeScript::Common),
+    (0x1FAB0, 0x1FAB6, UnicodeScript::Common),
+    (0x1FAC0, 0x1FAC2, UnicodeScript::Common),
+    (0x1FAD0, 0x1FAD6, UnicodeScript::Common),
+    (0x1FB00, 0x1FB92, UnicodeScript::Common),
+    (0x1FB94, 0x1FBCA, UnicodeScript::Common),
+    (0x1FBF0, 0x1FBF9, UnicodeScript::Common),
+    (0x20000, 0x2A6DD, UnicodeScript::Han),
+    (0x2A700, 0x2B734, UnicodeScript::Han),
+    (0x2B740, 0x2B81D, UnicodeScript::Han),
+    (0x2B820, 0x2CEA1, UnicodeScript::Han),
+    (0x2CEB0, 0x2EBE0, UnicodeScript::Han),
+    (0x2F800, 0x2FA1D, UnicodeScript::Han),
+    (0x30000, 0x3134A, UnicodeScript::Han),
+    (0xE0001, 0xE0001, UnicodeScript::Common),
+    (0xE0020, 0xE007F, UnicodeScript::Common),
+    (0xE0100, 0xE01EF, UnicodeScript::Inherited),
+];
+
@@ -42,6 +42,7 @@ macro_rules! define_error {
         }
     };
     ($name: ident, $($arg: ident # $type: ty),+) => {
+        #[derive(Debug, Clone, PartialEq)]
         pub struct $name {
             message: String,
             $($arg: $type),+
@@ -316,6 +317,13 @@ pub mod net {
 }
 
 pub mod nio {
+    pub mod charset {
+        use super::super::*;
+
+        define_error!(IllegalCharsetNameException);
+        define_error!(UnsupportedCharsetException);
+    }
+
     pub mod file {
         use super::super::*;
 
@@ -533,10 +541,119 @@ pub mod time {
     // TODO: DateTimeParseException
 }
 
-// TODO: java.util
+pub mod util {
+    pub mod regex {
+        use std::fmt::Display;
+
+        use crate::errors::Throwable;
+
+        /// A syntax error in a regular expression, with the UTF-16 index it was found near
+        /// (-1 if unknown).
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct PatternSyntaxException {
+            description: String,
+            pattern: String,
+            index: i32,
+        }
+
+        impl PatternSyntaxException {
+            pub fn new(description: String, pattern: String, index: i32) -> Self {
+                Self {
+                    description,
+                    pattern,
+                    index,
+                }
+            }
+
+            pub fn get_description(&self) -> &str {
+                &self.description
+            }
+
+            pub fn get_index(&self) -> i32 {
+                self.index
+            }
+
+            pub fn get_pattern(&self) -> &str {
+                &self.pattern
+            }
+        }
+
+        impl Display for PatternSyntaxException {
+            /// Java's multi-line message: the description, the pattern, and a caret under
+            /// the error index when it falls inside the pattern.
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.description)?;
+                if self.index >= 0 {
+                    write!(f, " near index {}", self.index)?;
+                }
+                write!(f, "\n{}", self.pattern)?;
+                if self.index >= 0 && (self.index as usize) < self.pattern.encode_utf16().count() {
+                    write!(f, "\n{}^", " ".repeat(self.index as usize))?;
+                }
+                Ok(())
+            }
+        }
+
+        impl Throwable for PatternSyntaxException {
+            fn message(&self) -> String {
+                self.to_string()
+            }
+            fn cause<T: Throwable>(&self) -> Option<&T> {
+                None
+            }
+
+            fn throw(&self) -> ! {
+                panic!("{}", self)
+            }
+        }
+    }
+
+    pub mod concurrent {
+        use std::fmt::Display;
+
+        use crate::errors::Throwable;
+
+        define_error!(RejectedExecutionException);
+    }
+
+    use std::fmt::Display;
+
+    use crate::errors::Throwable;
+
+    // The `IllegalFormatException`s of `Formatter`, with Java's messages.
+    define_error!(DuplicateFormatFlagsException);
+    define_error!(FormatFlagsConversionMismatchException);
+    define_error!(IllegalFormatArgumentIndexException);
+    define_error!(IllegalFormatCodePointException);
+    define_error!(IllegalFormatConversionException);
+    define_error!(IllegalFormatFlagsException);
+    define_error!(IllegalFormatPrecisionException);
+    define_error!(IllegalFormatWidthException);
+    define_error!(MissingFormatArgumentException);
+    define_error!(MissingFormatWidthException);
+    define_error!(UnknownFormatConversionException);
+
+    define_error!(ConcurrentModificationException);
+    define_error!(EmptyStackException);
+    define_error!(FormatterClosedException);
+    define_error!(IllformedLocaleException, error_index # i32);
+    define_error!(InputMismatchException);
+    define_error!(InvalidPropertiesFormatException);
+    define_error!(MissingResourceException);
+    define_error!(NoSuchElementException);
+    define_error!(TooManyListenersException);
+    define_error!(UnknownFormatFlagsException);
+}
 
 define_error!(ParseException, error_offset # i32);
 
+impl ParseException {
+    /// Where parsing failed. The `java.text` formats give it as a byte offset into the text.
+    pub fn get_error_offset(&self) -> i32 {
+        self.error_offset
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::errors::AbstractMethodError;
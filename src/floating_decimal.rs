@@ -0,0 +1,712 @@
+//! A port of the JDK's `FloatingDecimal` and `FormattedFloatingDecimal`: the decimal digits
+//! that `Double.toString` and `Formatter` give a floating-point value. They are not always
+//! the shortest that round-trip: whole numbers below 2^63 get all of their significant
+//! digits, and ties between two shortest candidates are settled by the JDK's own rules.
+
+use std::cmp::Ordering;
+
+const EXP_SHIFT: i32 = 52;
+const FRACT_HOB: u64 = 1 << EXP_SHIFT;
+const SIGNIF_BIT_MASK: u64 = FRACT_HOB - 1;
+const EXP_BIAS: i32 = 1023;
+const MAX_SMALL_BIN_EXP: i32 = 62;
+const MIN_SMALL_BIN_EXP: i32 = -(63 / 3);
+
+/// The number of bits of 5^i, except that of 5^0 is taken as 0.
+const N_5_BITS: [i32; 27] = [
+    0, 3, 5, 7, 10, 12, 14, 17, 19, 21, 24, 26, 28, 31, 33, 35, 38, 40, 42, 45, 47, 49, 52, 54, 56,
+    59, 61,
+];
+
+/// The number of decimal digits that are below the precision of a whole number times 2^i.
+const INSIGNIFICANT_DIGITS: [i32; 64] = [
+    0, 0, 0, 0, 1, 1, 1, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 8, 8, 8, 9, 9,
+    9, 9, 10, 10, 10, 11, 11, 11, 12, 12, 12, 12, 13, 13, 13, 14, 14, 14, 15, 15, 15, 15, 16, 16,
+    16, 17, 17, 17, 18, 18, 18, 19,
+];
+
+/// The decimal digits of a finite value: `0.d1d2d3... * 10^decimal_exponent`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FloatingDecimal {
+    pub(crate) negative: bool,
+    /// ASCII digits, possibly with trailing zeros.
+    pub(crate) digits: Vec<u8>,
+    pub(crate) decimal_exponent: i32,
+    /// Whether the last digit was rounded up, as `digitsRoundedUp` tells.
+    pub(crate) rounded_up: bool,
+    /// Whether the digits are the exact value, as `decimalDigitsExact` tells. Those of
+    /// whole numbers taken through a long are never marked exact.
+    pub(crate) exact: bool,
+}
+
+impl FloatingDecimal {
+    /// The digits of a finite double. `compatible` is `Double.toString`'s mode, which may
+    /// stop after one digit; `Formatter` always develops at least two.
+    pub(crate) fn of_double(value: f64, compatible: bool) -> Self {
+        let bits = value.to_bits();
+        let negative = value.is_sign_negative();
+        let mut fract_bits = bits & SIGNIF_BIT_MASK;
+        let mut bin_exp = ((bits >> EXP_SHIFT) & 0x7FF) as i32;
+        let significant_bits;
+        if bin_exp == 0 {
+            if fract_bits == 0 {
+                return Self::zero(negative);
+            }
+            let leading_zeros = fract_bits.leading_zeros() as i32;
+            let shift = leading_zeros - (63 - EXP_SHIFT);
+            fract_bits <<= shift;
+            bin_exp = 1 - shift;
+            significant_bits = 64 - leading_zeros;
+        } else {
+            fract_bits |= FRACT_HOB;
+            significant_bits = EXP_SHIFT + 1;
+        }
+        dtoa(
+            negative,
+            bin_exp - EXP_BIAS,
+            fract_bits,
+            significant_bits,
+            compatible,
+        )
+    }
+
+    /// The digits of a finite float, as `Float.toString` gives them.
+    pub(crate) fn of_float(value: f32) -> Self {
+        const SINGLE_EXP_SHIFT: i32 = 23;
+        let bits = value.to_bits();
+        let negative = value.is_sign_negative();
+        let mut fract_bits = bits & ((1 << SINGLE_EXP_SHIFT) - 1);
+        let mut bin_exp = ((bits >> SINGLE_EXP_SHIFT) & 0xFF) as i32;
+        let significant_bits;
+        if bin_exp == 0 {
+            if fract_bits == 0 {
+                return Self::zero(negative);
+            }
+            let leading_zeros = fract_bits.leading_zeros() as i32;
+            let shift = leading_zeros - (31 - SINGLE_EXP_SHIFT);
+            fract_bits <<= shift;
+            bin_exp = 1 - shift;
+            significant_bits = 32 - leading_zeros;
+        } else {
+            fract_bits |= 1 << SINGLE_EXP_SHIFT;
+            significant_bits = SINGLE_EXP_SHIFT + 1;
+        }
+        dtoa(
+            negative,
+            bin_exp - 127,
+            (fract_bits as u64) << (EXP_SHIFT - SINGLE_EXP_SHIFT),
+            significant_bits,
+            true,
+        )
+    }
+
+    fn zero(negative: bool) -> Self {
+        Self {
+            negative,
+            digits: vec![b'0'],
+            decimal_exponent: 0,
+            rounded_up: false,
+            exact: false,
+        }
+    }
+
+    /// `Double.toString`'s layout of the digits: plain from 10^-3 up to 10^7, else
+    /// computerized scientific notation such as `1.0E10`.
+    pub(crate) fn to_java_string(&self) -> String {
+        let mut result = String::new();
+        if self.negative {
+            result.push('-');
+        }
+        let digits = std::str::from_utf8(&self.digits).unwrap();
+        let exponent = self.decimal_exponent;
+        let count = digits.len() as i32;
+        if exponent > 0 && exponent < 8 {
+            let integer = count.min(exponent) as usize;
+            result.push_str(&digits[..integer]);
+            if (integer as i32) < exponent {
+                result.extend(std::iter::repeat_n(
+                    '0',
+                    (exponent - integer as i32) as usize,
+                ));
+                result.push_str(".0");
+            } else {
+                result.push('.');
+                match &digits[integer..] {
+                    "" => result.push('0'),
+                    fraction => result.push_str(fraction),
+                }
+            }
+        } else if exponent <= 0 && exponent > -3 {
+            result.push_str("0.");
+            result.extend(std::iter::repeat_n('0', -exponent as usize));
+            result.push_str(digits);
+        } else {
+            result.push_str(&digits[..1]);
+            result.push('.');
+            match &digits[1..] {
+                "" => result.push('0'),
+                fraction => result.push_str(fraction),
+            }
+            result.push('E');
+            result.push_str(&(exponent - 1).to_string());
+        }
+        result
+    }
+}
+
+/// Java's `Double.toString`.
+pub(crate) fn double_to_string(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        FloatingDecimal::of_double(value, true).to_java_string()
+    }
+}
+
+/// Java's `Float.toString`.
+pub(crate) fn float_to_string(value: f32) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        FloatingDecimal::of_float(value).to_java_string()
+    }
+}
+
+/// `String.valueOf` of a value that `Display` writes: `Float.toString` or `Double.toString`
+/// for a float (or a reference to one), whose shortest digits Rust's `Display` gives back
+/// exactly, and its `Display` for anything else.
+pub(crate) fn display_to_string<T: std::fmt::Display + ?Sized>(value: &T) -> String {
+    let shown = value.to_string();
+    match std::any::type_name::<T>().trim_start_matches('&') {
+        "f32" => float_to_string(shown.parse().unwrap()),
+        "f64" => double_to_string(shown.parse().unwrap()),
+        _ => shown,
+    }
+}
+
+/// `BinaryToASCIIBuffer.dtoa`: the digits of `fract_bits * 2^(bin_exp - 52)`, where the
+/// high bit of `fract_bits` is bit 52 and `significant_bits` of it are significant.
+fn dtoa(
+    negative: bool,
+    bin_exp: i32,
+    mut fract_bits: u64,
+    significant_bits: i32,
+    compatible: bool,
+) -> FloatingDecimal {
+    let tail_zeros = fract_bits.trailing_zeros() as i32;
+    // The number of significant bits to the right of the binary point.
+    let fract_bit_count = EXP_SHIFT + 1 - tail_zeros;
+    let tiny_bits = (fract_bit_count - bin_exp - 1).max(0);
+    // Whole numbers that fit a long are converted exactly, less their insignificant digits.
+    if (MIN_SMALL_BIN_EXP..=MAX_SMALL_BIN_EXP).contains(&bin_exp)
+        && tiny_bits < N_5_BITS.len() as i32
+        && fract_bit_count + N_5_BITS[tiny_bits as usize] < 64
+        && tiny_bits == 0
+    {
+        let insignificant = if bin_exp > significant_bits {
+            insignificant_digits_for_pow2(bin_exp - significant_bits - 1)
+        } else {
+            0
+        };
+        if bin_exp >= EXP_SHIFT {
+            fract_bits <<= bin_exp - EXP_SHIFT;
+        } else {
+            fract_bits >>= EXP_SHIFT - bin_exp;
+        }
+        return develop_long_digits(negative, fract_bits, insignificant);
+    }
+
+    // The value is B / S * 10^dec_exp with 1 <= B / S < 10, and M is half an ulp scaled
+    // like B. Digits are taken off B / S until the remainder is within M of either end.
+    let mut dec_exp = estimate_dec_exp(fract_bits, bin_exp);
+    let b5 = (-dec_exp).max(0);
+    let mut b2 = b5 + tiny_bits + bin_exp;
+    let s5 = dec_exp.max(0);
+    let mut s2 = s5 + tiny_bits;
+    let m5 = b5;
+    let mut m2 = b2 - significant_bits;
+
+    fract_bits >>= tail_zeros;
+    b2 -= fract_bit_count - 1;
+    let common2 = b2.min(s2);
+    b2 -= common2;
+    s2 -= common2;
+    m2 -= common2;
+    // Below a power of two the next smaller value is only half as far away.
+    if fract_bit_count == 1 {
+        m2 -= 1;
+    }
+    if m2 < 0 {
+        b2 -= m2;
+        s2 -= m2;
+        m2 = 0;
+    }
+
+    let bits_of_5 = |power: i32| N_5_BITS.get(power as usize).copied().unwrap_or(power * 3);
+    let b_bits = fract_bit_count + b2 + bits_of_5(b5);
+    let ten_s_bits = s2 + 1 + bits_of_5(s5 + 1);
+
+    let mut digits = Vec::new();
+    let low;
+    let high;
+    let low_digit_difference: Ordering;
+    let exact;
+    if b_bits < 64 && ten_s_bits < 64 {
+        // The JDK works in ints when they are wide enough, else in longs, and lets the
+        // arithmetic on M overflow; its digits depend on that.
+        let narrow = |value: i64| {
+            if b_bits < 32 && ten_s_bits < 32 {
+                value as i32 as i64
+            } else {
+                value
+            }
+        };
+        let mut b = ((fract_bits * 5u64.pow(b5 as u32)) << b2) as i64;
+        let s = (5u64.pow(s5 as u32) << s2) as i64;
+        let mut m = (5u64.pow(m5 as u32) << m2) as i64;
+        let tens = s * 10;
+
+        let q = b / s;
+        b = 10 * (b % s);
+        m = narrow(m.wrapping_mul(10));
+        let mut is_low = b < m;
+        let mut is_high = narrow(b.wrapping_add(m)) > tens;
+        if q == 0 && !is_high {
+            dec_exp -= 1;
+        } else {
+            digits.push(b'0' + q as u8);
+        }
+        // Scientific notation needs a digit after the point.
+        if !compatible || !(-3..8).contains(&dec_exp) {
+            is_low = false;
+            is_high = false;
+        }
+        while !is_low && !is_high {
+            let q = b / s;
+            b = 10 * (b % s);
+            m = narrow(m.wrapping_mul(10));
+            if m > 0 {
+                is_low = b < m;
+                is_high = narrow(b.wrapping_add(m)) > tens;
+            } else {
+                is_low = true;
+                is_high = true;
+            }
+            digits.push(b'0' + q as u8);
+        }
+        low_digit_difference = narrow((b << 1).wrapping_sub(tens)).cmp(&0);
+        exact = b == 0;
+        low = is_low;
+        high = is_high;
+    } else {
+        let s = BigUint::pow52(s5, s2);
+        let mut b = BigUint::from(fract_bits).mul_pow52(b5, b2);
+        let mut m = BigUint::pow52(m5 + 1, m2 + 1);
+        let ten_s = BigUint::pow52(s5 + 1, s2 + 1);
+
+        let q = b.quo_rem_iteration(&s);
+        let mut is_low = b < m;
+        let mut is_high = b.add(&m) >= ten_s;
+        if q == 0 && !is_high {
+            dec_exp -= 1;
+        } else {
+            digits.push(b'0' + q);
+        }
+        if !compatible || !(-3..8).contains(&dec_exp) {
+            is_low = false;
+            is_high = false;
+        }
+        while !is_low && !is_high {
+            let q = b.quo_rem_iteration(&s);
+            m = m.mul_small(10);
+            is_low = b < m;
+            is_high = b.add(&m) >= ten_s;
+            digits.push(b'0' + q);
+        }
+        low_digit_difference = if is_high && is_low {
+            b.mul_small(2).cmp(&ten_s)
+        } else {
+            Ordering::Equal
+        };
+        exact = b.0.is_empty();
+        low = is_low;
+        high = is_high;
+    }
+
+    let mut decimal = FloatingDecimal {
+        negative,
+        digits,
+        decimal_exponent: dec_exp + 1,
+        rounded_up: false,
+        exact,
+    };
+    // The last digit is rounded by the stopping condition, a tie to an even digit.
+    if high {
+        let round_up = if low {
+            match low_digit_difference {
+                Ordering::Equal => decimal.digits.last().is_some_and(|digit| digit & 1 != 0),
+                Ordering::Greater => true,
+                Ordering::Less => false,
+            }
+        } else {
+            true
+        };
+        if round_up {
+            roundup(&mut decimal);
+        }
+    }
+    decimal
+}
+
+fn insignificant_digits_for_pow2(p2: i32) -> i32 {
+    if p2 > 1 && p2 < INSIGNIFICANT_DIGITS.len() as i32 {
+        INSIGNIFICANT_DIGITS[p2 as usize]
+    } else {
+        0
+    }
+}
+
+/// The digits of a whole number, rounding away `insignificant` low digits and dropping
+/// trailing zeros.
+fn develop_long_digits(negative: bool, mut value: u64, insignificant: i32) -> FloatingDecimal {
+    let mut exponent = 0;
+    if insignificant != 0 {
+        let pow10 = 10u64.pow(insignificant as u32);
+        let residue = value % pow10;
+        value /= pow10;
+        exponent += insignificant;
+        if residue >= pow10 >> 1 {
+            value += 1;
+        }
+    }
+    while value.is_multiple_of(10) {
+        value /= 10;
+        exponent += 1;
+    }
+    let digits = value.to_string().into_bytes();
+    FloatingDecimal {
+        negative,
+        decimal_exponent: exponent + digits.len() as i32,
+        digits,
+        rounded_up: false,
+        exact: false,
+    }
+}
+
+/// `floor(log10(d))`, estimated from the binary exponent and fraction. The constants are
+/// the JDK's, which `LOG10_2` is not exactly.
+#[allow(clippy::approx_constant)]
+fn estimate_dec_exp(fract_bits: u64, bin_exp: i32) -> i32 {
+    let d2 = f64::from_bits(0x3FF0_0000_0000_0000 | (fract_bits & SIGNIF_BIT_MASK));
+    let d = (d2 - 1.5) * 0.289529654 + 0.176091259 + bin_exp as f64 * 0.301029995663981;
+    d.floor() as i32
+}
+
+fn roundup(decimal: &mut FloatingDecimal) {
+    decimal.rounded_up = true;
+    let digits = &mut decimal.digits;
+    let mut i = digits.len() - 1;
+    while digits[i] == b'9' && i > 0 {
+        digits[i] = b'0';
+        i -= 1;
+    }
+    if digits[i] == b'9' {
+        // Carry out of the first digit: a 1 followed by zeros.
+        decimal.decimal_exponent += 1;
+        digits[0] = b'1';
+    } else {
+        digits[i] += 1;
+    }
+}
+
+/// The layouts of `FormattedFloatingDecimal`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Form {
+    Scientific,
+    DecimalFloat,
+    General,
+}
+
+/// `FormattedFloatingDecimal`: the mantissa and, in scientific notation, the signed
+/// exponent of a non-negative finite value, rounded half up on its decimal digits to the
+/// precision, along with the rounded decimal exponent less one.
+pub(crate) fn formatted(
+    value: f64,
+    mut precision: i32,
+    form: Form,
+) -> (String, Option<String>, i32) {
+    let decimal = FloatingDecimal::of_double(value, false);
+    let mut digits = decimal.digits;
+    let dec_exp = decimal.decimal_exponent;
+    match form {
+        Form::DecimalFloat => {
+            let exp = apply_precision(dec_exp, &mut digits, dec_exp + precision);
+            (fill_decimal(precision, &digits, exp), None, exp - 1)
+        }
+        Form::Scientific => {
+            let exp = apply_precision(dec_exp, &mut digits, precision + 1);
+            let (mantissa, exponent) = fill_scientific(precision, &digits, exp);
+            (mantissa, Some(exponent), exp - 1)
+        }
+        Form::General => {
+            let exp = apply_precision(dec_exp, &mut digits, precision);
+            if exp - 1 < -4 || exp > precision {
+                precision -= 1;
+                let (mantissa, exponent) = fill_scientific(precision, &digits, exp);
+                (mantissa, Some(exponent), exp - 1)
+            } else {
+                precision -= exp;
+                (fill_decimal(precision, &digits, exp), None, exp - 1)
+            }
+        }
+    }
+}
+
+/// Rounds the digits half up to `precision` digits, returning the new decimal exponent.
+fn apply_precision(dec_exp: i32, digits: &mut [u8], precision: i32) -> i32 {
+    if precision >= digits.len() as i32 || precision < 0 {
+        return dec_exp;
+    }
+    let precision = precision as usize;
+    if precision == 0 {
+        // Only a 0 or a 1 is left.
+        return if digits[0] >= b'5' {
+            digits.fill(b'0');
+            digits[0] = b'1';
+            dec_exp + 1
+        } else {
+            digits.fill(b'0');
+            dec_exp
+        };
+    }
+    if digits[precision] >= b'5' {
+        let mut i = precision - 1;
+        while digits[i] == b'9' && i > 0 {
+            i -= 1;
+        }
+        if digits[i] == b'9' {
+            digits.fill(b'0');
+            digits[0] = b'1';
+            return dec_exp + 1;
+        }
+        digits[i] += 1;
+        digits[i + 1..].fill(b'0');
+    } else {
+        digits[precision..].fill(b'0');
+    }
+    dec_exp
+}
+
+fn fill_decimal(precision: i32, digits: &[u8], exp: i32) -> String {
+    let digits = std::str::from_utf8(digits).unwrap();
+    let count = digits.len() as i32;
+    let mut mantissa = String::new();
+    if exp > 0 {
+        if count < exp {
+            mantissa.push_str(digits);
+            mantissa.extend(std::iter::repeat_n('0', (exp - count) as usize));
+        } else {
+            let fraction = (count - exp).min(precision);
+            mantissa.push_str(&digits[..exp as usize]);
+            if fraction > 0 {
+                mantissa.push('.');
+                mantissa.push_str(&digits[exp as usize..(exp + fraction) as usize]);
+            }
+        }
+    } else {
+        let zeros = (-exp).min(precision).max(0);
+        let shown = count.min(precision + exp).max(0);
+        if zeros > 0 || shown > 0 {
+            mantissa.push_str("0.");
+            mantissa.extend(std::iter::repeat_n('0', zeros as usize));
+            mantissa.push_str(&digits[..shown as usize]);
+        } else {
+            mantissa.push('0');
+        }
+    }
+    mantissa
+}
+
+fn fill_scientific(precision: i32, digits: &[u8], exp: i32) -> (String, String) {
+    let digits = std::str::from_utf8(digits).unwrap();
+    let fraction = (digits.len() as i32 - 1).min(precision).max(0) as usize;
+    let mut mantissa = digits[..1].to_string();
+    if fraction > 0 {
+        mantissa.push('.');
+        mantissa.push_str(&digits[1..1 + fraction]);
+    }
+    let exponent = if exp <= 0 {
+        format!("-{:02}", -exp + 1)
+    } else {
+        format!("+{:02}", exp - 1)
+    };
+    (mantissa, exponent)
+}
+
+/// Just enough of an unsigned big integer for `dtoa`, as little-endian 32-bit words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BigUint(Vec<u32>);
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self(vec![value as u32, (value >> 32) as u32]).normalized()
+    }
+}
+
+impl BigUint {
+    /// 5^p5 * 2^p2.
+    fn pow52(p5: i32, p2: i32) -> Self {
+        Self::from(1).mul_pow52(p5, p2)
+    }
+
+    fn mul_pow52(mut self, p5: i32, p2: i32) -> Self {
+        for _ in 0..p5 {
+            self = self.mul_small(5);
+        }
+        self.shl(p2 as usize)
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    fn mul_small(&self, factor: u32) -> Self {
+        let mut carry = 0u64;
+        let mut words: Vec<u32> = self
+            .0
+            .iter()
+            .map(|&word| {
+                let product = word as u64 * factor as u64 + carry;
+                carry = product >> 32;
+                product as u32
+            })
+            .collect();
+        if carry != 0 {
+            words.push(carry as u32);
+        }
+        Self(words).normalized()
+    }
+
+    fn shl(self, bits: usize) -> Self {
+        let (words, bits) = (bits / 32, bits % 32);
+        let mut result = vec![0; words];
+        let mut carry = 0u32;
+        for word in self.0 {
+            if bits == 0 {
+                result.push(word);
+            } else {
+                result.push(word << bits | carry);
+                carry = word >> (32 - bits);
+            }
+        }
+        if carry != 0 {
+            result.push(carry);
+        }
+        Self(result).normalized()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let mut carry = 0u64;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let sum =
+                *self.0.get(i).unwrap_or(&0) as u64 + *other.0.get(i).unwrap_or(&0) as u64 + carry;
+            result.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry != 0 {
+            result.push(carry as u32);
+        }
+        Self(result).normalized()
+    }
+
+    /// Subtracts a value that is not greater.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut borrow = 0i64;
+        for i in 0..self.0.len() {
+            let difference = self.0[i] as i64 - *other.0.get(i).unwrap_or(&0) as i64 - borrow;
+            self.0[i] = difference as u32;
+            borrow = (difference < 0) as i64;
+        }
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    /// `FDBigInteger.quoRemIteration`: the single-digit quotient by `divisor`, leaving ten
+    /// times the remainder.
+    fn quo_rem_iteration(&mut self, divisor: &Self) -> u8 {
+        let mut quotient = 0;
+        while *self >= *divisor {
+            self.sub_assign(divisor);
+            quotient += 1;
+        }
+        *self = self.mul_small(10);
+        quotient
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string_like_jdk() {
+        let cases = [
+            (0.0, "0.0"),
+            (-0.0, "-0.0"),
+            (1.0, "1.0"),
+            (0.1, "0.1"),
+            (100.0, "100.0"),
+            (1e7, "1.0E7"),
+            (1.0e-3, "0.001"),
+            (1.0e-4, "1.0E-4"),
+            (f64::MAX, "1.7976931348623157E308"),
+            (f64::from_bits(1), "4.9E-324"),
+            (2e23, "1.9999999999999998E23"),
+            (7.886_145_034_478_063e18, "7.8861450344780626E18"),
+            (8.339840063750242e14, "8.339840063750242E14"),
+            (123456789.0, "1.23456789E8"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(double_to_string(value), expected);
+        }
+        assert_eq!(float_to_string(0.1), "0.1");
+        assert_eq!(float_to_string(1.0e10), "1.0E10");
+        assert_eq!(float_to_string(f32::from_bits(1)), "1.4E-45");
+    }
+
+    #[test]
+    fn formats_like_jdk() {
+        assert_eq!(formatted(0.125, 2, Form::DecimalFloat).0, "0.13");
+        assert_eq!(formatted(1.005, 2, Form::DecimalFloat).0, "1.01");
+        let (mantissa, exponent, _) = formatted(12345.678, 6, Form::Scientific);
+        assert_eq!(
+            (mantissa.as_str(), exponent.as_deref()),
+            ("1.234568", Some("+04"))
+        );
+        assert_eq!(formatted(12345.678, 6, Form::General).0, "12345.7");
+    }
+}
@@ -0,0 +1,317 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::time::{Instant, LocalDate, LocalDateTime, LocalTime, ZonedDateTime};
+use crate::util::{Calendar, Date, UUID};
+use crate::{InternedStr, JString};
+
+/// Java's `hashCode`, which `HashMap` and `HashSet` use to place their elements. To iterate
+/// in the JDK's order, a key type has to hash exactly like the Java class it stands for;
+/// the implementations here are those of the boxed primitives, `String`, the `java.time`
+/// and `java.util` classes, and `null` for `None`.
+pub trait HashCode {
+    fn hash_code(&self) -> i32;
+
+    /// Java's `compareTo` for a class that is `Comparable` to itself, or `None` for one that
+    /// isn't. `HashMap` orders the keys of a bin that has been turned into a tree by their
+    /// hashes, and keys with equal hashes by this.
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        let _ = other;
+        None
+    }
+
+    /// Whether this is Java's `null`, which only `None` is. The immutable collections of
+    /// `List.of` and friends reject it.
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    /// Java's `equals` for a type whose `equals` isn't `==`, or `None` for one whose is.
+    /// Floats are equal like `Double.equals` has them, by their `doubleToLongBits`: NaN
+    /// equals itself, and `0.0` doesn't equal `-0.0`.
+    fn java_equals(&self, other: &Self) -> Option<bool> {
+        let _ = other;
+        None
+    }
+}
+
+fn long_hash(value: i64) -> i32 {
+    (value ^ (value >> 32)) as i32
+}
+
+macro_rules! integer_hash_code {
+    ($($type: ty => $hash: expr),*) => {
+        $(
+            impl HashCode for $type {
+                fn hash_code(&self) -> i32 {
+                    #[allow(clippy::redundant_closure_call)]
+                    ($hash)(*self)
+                }
+
+                fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+        )*
+    };
+}
+
+integer_hash_code! {
+    i8 => |value: i8| value as i32,
+    i16 => |value: i16| value as i32,
+    i32 => |value: i32| value,
+    i64 => long_hash,
+    char => |value: char| value as i32
+}
+
+impl HashCode for bool {
+    fn hash_code(&self) -> i32 {
+        if *self {
+            1231
+        } else {
+            1237
+        }
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Java's `Float.floatToIntBits`, which collapses the NaNs into one.
+fn float_to_int_bits(value: f32) -> i32 {
+    if value.is_nan() {
+        0x7fc0_0000
+    } else {
+        value.to_bits() as i32
+    }
+}
+
+/// Java's `Double.doubleToLongBits`, which collapses the NaNs into one.
+fn double_to_long_bits(value: f64) -> i64 {
+    if value.is_nan() {
+        0x7ff8_0000_0000_0000
+    } else {
+        value.to_bits() as i64
+    }
+}
+
+/// `Float.compare` and `Double.compare`: `-0.0` is less than `0.0`, and NaN is equal to
+/// itself and greater than everything else.
+fn compare_floats<T: PartialOrd>(a: T, b: T, a_bits: i64, b_bits: i64) -> Ordering {
+    if a < b {
+        Ordering::Less
+    } else if a > b {
+        Ordering::Greater
+    } else {
+        a_bits.cmp(&b_bits)
+    }
+}
+
+impl HashCode for f32 {
+    fn hash_code(&self) -> i32 {
+        float_to_int_bits(*self)
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(compare_floats(
+            *self,
+            *other,
+            float_to_int_bits(*self) as i64,
+            float_to_int_bits(*other) as i64,
+        ))
+    }
+
+    fn java_equals(&self, other: &Self) -> Option<bool> {
+        Some(float_to_int_bits(*self) == float_to_int_bits(*other))
+    }
+}
+
+impl HashCode for f64 {
+    fn hash_code(&self) -> i32 {
+        long_hash(double_to_long_bits(*self))
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(compare_floats(
+            *self,
+            *other,
+            double_to_long_bits(*self),
+            double_to_long_bits(*other),
+        ))
+    }
+
+    fn java_equals(&self, other: &Self) -> Option<bool> {
+        Some(double_to_long_bits(*self) == double_to_long_bits(*other))
+    }
+}
+
+/// Like `String.compareTo`, by UTF-16 code unit.
+fn compare_utf16(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+impl HashCode for str {
+    fn hash_code(&self) -> i32 {
+        self.encode_utf16().fold(0i32, |hash, unit| {
+            hash.wrapping_mul(31).wrapping_add(unit as i32)
+        })
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(compare_utf16(self, other))
+    }
+}
+
+impl HashCode for String {
+    fn hash_code(&self) -> i32 {
+        self.as_str().hash_code()
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(compare_utf16(self, other))
+    }
+}
+
+impl HashCode for InternedStr {
+    fn hash_code(&self) -> i32 {
+        self.as_str().hash_code()
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(compare_utf16(self, other))
+    }
+}
+
+impl HashCode for JString {
+    fn hash_code(&self) -> i32 {
+        JString::hash_code(self)
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        Some(self.compare_to(other).cmp(&0))
+    }
+}
+
+/// `None` is Java's `null`, whose hash is 0 and which is never `Comparable`.
+impl<T: HashCode> HashCode for Option<T> {
+    fn hash_code(&self) -> i32 {
+        self.as_ref().map_or(0, T::hash_code)
+    }
+
+    fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Some(a), Some(b)) => a.comparable_order(b),
+            _ => None,
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_none()
+    }
+
+    fn java_equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Some(a), Some(b)) => a.java_equals(b),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! pointer_hash_code {
+    ($($type: ty),*) => {
+        $(
+            impl<T: HashCode + ?Sized> HashCode for $type {
+                fn hash_code(&self) -> i32 {
+                    (**self).hash_code()
+                }
+
+                fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+                    (**self).comparable_order(other)
+                }
+
+                fn is_null(&self) -> bool {
+                    (**self).is_null()
+                }
+
+                fn java_equals(&self, other: &Self) -> Option<bool> {
+                    (**self).java_equals(other)
+                }
+            }
+        )*
+    };
+}
+
+pointer_hash_code!(&T, Box<T>, Rc<T>, Arc<T>);
+
+macro_rules! comparable_hash_code {
+    ($($type: ty),*) => {
+        $(
+            impl HashCode for $type {
+                fn hash_code(&self) -> i32 {
+                    <$type>::hash_code(self)
+                }
+
+                fn comparable_order(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+        )*
+    };
+}
+
+comparable_hash_code!(Date, UUID, Instant, LocalTime);
+
+// `LocalDate`, `LocalDateTime` and `ZonedDateTime` are only `Comparable` to their `Chrono`
+// interfaces, and `GregorianCalendar` doesn't declare `Comparable` itself, so `HashMap`
+// doesn't compare them.
+macro_rules! hash_code {
+    ($($type: ty),*) => {
+        $(
+            impl HashCode for $type {
+                fn hash_code(&self) -> i32 {
+                    <$type>::hash_code(self)
+                }
+            }
+        )*
+    };
+}
+
+hash_code!(LocalDate, LocalDateTime, ZonedDateTime, Calendar);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boxed_primitives() {
+        assert_eq!(7.hash_code(), 7);
+        assert_eq!((-1i64).hash_code(), 0);
+        assert_eq!((1i64 << 32).hash_code(), 1);
+        assert_eq!(true.hash_code(), 1231);
+        assert_eq!('a'.hash_code(), 97);
+        assert_eq!(1.5f32.hash_code(), 1069547520);
+        assert_eq!(1.5f64.hash_code(), 1073217536);
+        assert_eq!(f64::NAN.hash_code(), 2146959360);
+        assert_eq!((-0.0f64).comparable_order(&0.0), Some(Ordering::Less));
+        assert_eq!(
+            f64::NAN.comparable_order(&f64::INFINITY),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(None::<i32>.hash_code(), 0);
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!("".hash_code(), 0);
+        assert_eq!("hello".hash_code(), 99162322);
+        assert_eq!("Aa".hash_code(), "BB".hash_code());
+        assert_eq!("\u{1F600}".hash_code(), 1772899);
+        assert_eq!(
+            "\u{1F600}".comparable_order("\u{FFFF}"),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Rc::new(String::from("hello")).hash_code(), 99162322);
+    }
+}
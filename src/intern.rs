@@ -0,0 +1,280 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+/// A string from a `StringTable`. Two handles from the same table are equal exactly when
+/// their contents are, and comparing or hashing them only looks at the pointer, like
+/// comparing interned Java strings with `==`.
+#[derive(Clone)]
+pub struct InternedStr(Arc<str>);
+
+impl InternedStr {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for InternedStr {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for InternedStr {}
+
+impl Hash for InternedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as *const u8).hash(state)
+    }
+}
+
+impl Deref for InternedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for InternedStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Debug for InternedStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+/// How long a `StringTable` keeps its strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InternMode {
+    /// Strings stay in the table for as long as the table exists.
+    Permanent,
+    /// Strings are dropped once the last `InternedStr` for them is, like Java's string table
+    /// under garbage collection. Dropped entries are collected whenever the table has grown
+    /// by `collect_threshold` entries, so it holds at most about twice the live strings
+    /// plus the threshold.
+    Weak { collect_threshold: usize },
+}
+
+impl Default for InternMode {
+    fn default() -> Self {
+        InternMode::Weak {
+            collect_threshold: 1 << 16,
+        }
+    }
+}
+
+/// Counters describing a `StringTable`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InternStats {
+    /// Entries currently in the table, including dropped ones that are not collected yet.
+    pub entries: usize,
+    /// Calls to `intern`.
+    pub lookups: u64,
+    /// Calls to `intern` that found the string already in the table.
+    pub hits: u64,
+    /// Dropped entries removed so far.
+    pub collected: u64,
+}
+
+enum Entry {
+    Strong(Arc<str>),
+    Weak(Weak<str>),
+}
+
+impl Entry {
+    fn get(&self) -> Option<Arc<str>> {
+        match self {
+            Entry::Strong(s) => Some(s.clone()),
+            Entry::Weak(s) => s.upgrade(),
+        }
+    }
+
+    fn is_live(&self) -> bool {
+        match self {
+            Entry::Strong(_) => true,
+            Entry::Weak(s) => s.strong_count() > 0,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Shard {
+    buckets: HashMap<u64, Vec<Entry>>,
+    entries: usize,
+    next_collection: usize,
+}
+
+impl Shard {
+    fn collect(&mut self) -> usize {
+        let before = self.entries;
+        self.buckets.retain(|_, entries| {
+            entries.retain(Entry::is_live);
+            !entries.is_empty()
+        });
+        self.entries = self.buckets.values().map(Vec::len).sum();
+        before - self.entries
+    }
+}
+
+const SHARDS: usize = 16;
+
+/// A thread-safe pool of unique strings, Java's string table.
+///
+/// The table is split into independently locked shards so that interning from many threads
+/// at once does not contend on a single lock.
+pub struct StringTable {
+    mode: InternMode,
+    hasher: RandomState,
+    shards: [Mutex<Shard>; SHARDS],
+    lookups: AtomicU64,
+    hits: AtomicU64,
+    collected: AtomicU64,
+}
+
+static GLOBAL: OnceLock<StringTable> = OnceLock::new();
+
+impl StringTable {
+    pub fn new(mode: InternMode) -> Self {
+        Self {
+            mode,
+            hasher: RandomState::new(),
+            shards: Default::default(),
+            lookups: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            collected: AtomicU64::new(0),
+        }
+    }
+
+    /// The table behind `JavaString::intern`, created with the default (weak) mode unless
+    /// `configure_global` was called first.
+    pub fn global() -> &'static StringTable {
+        GLOBAL.get_or_init(|| StringTable::new(InternMode::default()))
+    }
+
+    /// Sets the mode of the global table. Returns `false`, changing nothing, if the table
+    /// was already in use.
+    pub fn configure_global(mode: InternMode) -> bool {
+        GLOBAL.set(StringTable::new(mode)).is_ok()
+    }
+
+    pub fn mode(&self) -> InternMode {
+        self.mode
+    }
+
+    /// Returns the table's handle for `s`, adding it if it is not there yet.
+    pub fn intern(&self, s: &str) -> InternedStr {
+        self.lookups.fetch_add(1, Ordering::Relaxed);
+        let hash = self.hasher.hash_one(s);
+        let mut shard = self.shards[hash as usize % SHARDS].lock().unwrap();
+
+        let bucket = shard.buckets.entry(hash).or_default();
+        if let Some(existing) = bucket
+            .iter()
+            .filter_map(Entry::get)
+            .find(|existing| &**existing == s)
+        {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return InternedStr(existing);
+        }
+
+        let interned: Arc<str> = Arc::from(s);
+        bucket.push(match self.mode {
+            InternMode::Permanent => Entry::Strong(interned.clone()),
+            InternMode::Weak { .. } => Entry::Weak(Arc::downgrade(&interned)),
+        });
+        shard.entries += 1;
+
+        if let InternMode::Weak { collect_threshold } = self.mode {
+            if shard.entries >= shard.next_collection {
+                let collected = shard.collect();
+                self.collected
+                    .fetch_add(collected as u64, Ordering::Relaxed);
+                shard.next_collection = shard.entries * 2 + collect_threshold / SHARDS;
+            }
+        }
+
+        InternedStr(interned)
+    }
+
+    /// Removes the entries of dropped strings now, returning how many there were. Only weak
+    /// tables ever have any.
+    pub fn collect(&self) -> usize {
+        let collected = self
+            .shards
+            .iter()
+            .map(|shard| shard.lock().unwrap().collect())
+            .sum();
+        self.collected
+            .fetch_add(collected as u64, Ordering::Relaxed);
+        collected
+    }
+
+    pub fn stats(&self) -> InternStats {
+        InternStats {
+            entries: self
+                .shards
+                .iter()
+                .map(|shard| shard.lock().unwrap().entries)
+                .sum(),
+            lookups: self.lookups.load(Ordering::Relaxed),
+            hits: self.hits.load(Ordering::Relaxed),
+            collected: self.collected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for StringTable {
+    fn default() -> Self {
+        Self::new(InternMode::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let table = StringTable::new(InternMode::Permanent);
+        let a = table.intern("key");
+        let b = table.intern(&String::from("key"));
+        assert_eq!(a, b);
+        assert_ne!(a, table.intern("other"));
+        assert_eq!(&*a, "key");
+
+        drop((a, b));
+        assert_eq!(table.collect(), 0);
+        let stats = table.stats();
+        assert_eq!((stats.entries, stats.lookups, stats.hits), (2, 3, 1));
+    }
+
+    #[test]
+    fn weak_entries_are_collected() {
+        let table = StringTable::new(InternMode::Weak {
+            collect_threshold: usize::MAX,
+        });
+        let kept = table.intern("kept");
+        for i in 0..100 {
+            table.intern(&format!("temporary {i}"));
+        }
+        assert_eq!(table.stats().entries, 101);
+        assert_eq!(table.collect(), 100);
+        assert_eq!(table.intern("kept"), kept);
+        assert_eq!(table.stats().entries, 1);
+    }
+}
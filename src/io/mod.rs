@@ -0,0 +1,5 @@
+//! `java.io`: Java-style output streams over Rust writers.
+
+mod print_stream;
+
+pub use print_stream::PrintStream;
@@ -0,0 +1,155 @@
+use std::io::{self, Stderr, Stdout, Write};
+
+use crate::nio::charset::Charset;
+use crate::util::{FormatArgument, Formatter, Locale};
+
+/// `java.io.PrintStream`: prints values as Java's `String.valueOf` does, and formatted text
+/// with `printf`, encoded in a charset. Like Java, it never returns I/O errors, but
+/// remembers that one happened for `check_error`.
+///
+/// With automatic flushing, the writer is flushed after `println` and whenever printed
+/// text contains a newline.
+#[derive(Debug)]
+pub struct PrintStream<W: Write> {
+    out: W,
+    auto_flush: bool,
+    charset: Charset,
+    error: bool,
+}
+
+impl PrintStream<Stdout> {
+    /// Java's `System.out`.
+    pub fn stdout() -> Self {
+        Self::with_charset(io::stdout(), true, Charset::default_charset())
+    }
+}
+
+impl PrintStream<Stderr> {
+    /// Java's `System.err`.
+    pub fn stderr() -> Self {
+        Self::with_charset(io::stderr(), true, Charset::default_charset())
+    }
+}
+
+impl<W: Write> PrintStream<W> {
+    /// A stream in the default charset, without automatic flushing.
+    pub fn new(out: W) -> Self {
+        Self::with_charset(out, false, Charset::default_charset())
+    }
+
+    pub fn with_charset(out: W, auto_flush: bool, charset: Charset) -> Self {
+        Self {
+            out,
+            auto_flush,
+            charset,
+            error: false,
+        }
+    }
+
+    /// Prints a value as Java would print the boxed value, so `1.0` prints as `1.0` and
+    /// `None` as `null`.
+    pub fn print<T: Into<FormatArgument>>(&mut self, x: T) {
+        let s = x.into().to_java_string();
+        self.write_text(&s);
+    }
+
+    /// Prints a value, then a line separator.
+    pub fn println<T: Into<FormatArgument>>(&mut self, x: T) {
+        let mut s = x.into().to_java_string();
+        s.push('\n');
+        self.write_bytes(&self.charset.encode(&s));
+        if self.auto_flush {
+            self.flush();
+        }
+    }
+
+    /// Prints the arguments formatted by `format` in the default locale, see `Formatter`.
+    /// Throws the `IllegalFormatException`s of `Formatter`, in which case nothing of the
+    /// format is printed.
+    pub fn printf(&mut self, format: &str, args: &[FormatArgument]) -> &mut Self {
+        self.printf_with_locale(&Locale::get_default(), format, args)
+    }
+
+    pub fn printf_with_locale(
+        &mut self,
+        locale: &Locale,
+        format: &str,
+        args: &[FormatArgument],
+    ) -> &mut Self {
+        let mut formatter = Formatter::with_locale(locale);
+        formatter.format(format, args);
+        self.write_text(formatter.out());
+        self
+    }
+
+    /// The same as `printf`.
+    pub fn format(&mut self, format: &str, args: &[FormatArgument]) -> &mut Self {
+        self.printf(format, args)
+    }
+
+    pub fn format_with_locale(
+        &mut self,
+        locale: &Locale,
+        format: &str,
+        args: &[FormatArgument],
+    ) -> &mut Self {
+        self.printf_with_locale(locale, format, args)
+    }
+
+    /// Writes bytes as they are, flushing with automatic flushing.
+    pub fn write(&mut self, buf: &[u8]) {
+        self.write_bytes(buf);
+        if self.auto_flush {
+            self.flush();
+        }
+    }
+
+    pub fn flush(&mut self) {
+        if self.out.flush().is_err() {
+            self.error = true;
+        }
+    }
+
+    /// Flushes the stream and returns whether it ever failed to write or flush.
+    pub fn check_error(&mut self) -> bool {
+        self.flush();
+        self.error
+    }
+
+    /// Flushes the stream and returns the writer.
+    pub fn into_inner(mut self) -> W {
+        self.flush();
+        self.out
+    }
+
+    fn write_text(&mut self, s: &str) {
+        self.write_bytes(&self.charset.encode(s));
+        if self.auto_flush && s.contains('\n') {
+            self.flush();
+        }
+    }
+
+    fn write_bytes(&mut self, buf: &[u8]) {
+        if self.out.write_all(buf).is_err() {
+            self.error = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nio::charset::StandardCharsets;
+
+    #[test]
+    fn prints_like_java() {
+        let mut out = PrintStream::with_charset(Vec::new(), false, StandardCharsets::UTF_8);
+        out.print(1.0);
+        out.print(' ');
+        out.println(None::<i32>);
+        out.printf_with_locale(&Locale::GERMANY, "%.2f|%5s%n", &[1.5.into(), "ä".into()])
+            .printf("%x", &[255.into()]);
+        assert!(!out.check_error());
+        assert_eq!(out.into_inner(), "1.0 null\n1,50|    ä\nff".as_bytes());
+    }
+}
@@ -0,0 +1,224 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+use crate::character::{is_high_surrogate, is_low_surrogate, to_code_point};
+
+/// Whether strings that only contain Latin-1 characters are stored one byte per character,
+/// HotSpot's `-XX:+CompactStrings`. Controlled by the `compact-strings` feature, which is on
+/// by default.
+pub const COMPACT_STRINGS: bool = cfg!(feature = "compact-strings");
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Value {
+    Latin1(Box<[u8]>),
+    Utf16(Box<[u16]>),
+}
+
+/// An immutable Java string, stored the way HotSpot stores `java.lang.String`: as Latin-1
+/// bytes when every character fits in one byte, and as UTF-16 code units otherwise.
+///
+/// Both forms give O(1) `length` and `char_at` in UTF-16 units, and a `JString` can hold
+/// unpaired surrogates just like a Java string. Which form is used only depends on the
+/// content (and `COMPACT_STRINGS`), so equal strings always have equal representations.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct JString {
+    value: Value,
+}
+
+impl JString {
+    pub fn new() -> Self {
+        Self::from_latin1(&[])
+    }
+
+    /// A string of Latin-1 characters, one per byte.
+    pub fn from_latin1(bytes: &[u8]) -> Self {
+        let value = if COMPACT_STRINGS {
+            Value::Latin1(bytes.into())
+        } else {
+            Value::Utf16(bytes.iter().map(|&byte| byte as u16).collect())
+        };
+        Self { value }
+    }
+
+    /// A string of UTF-16 code units, which may include unpaired surrogates.
+    pub fn from_utf16(units: &[u16]) -> Self {
+        if COMPACT_STRINGS && units.iter().all(|&unit| unit <= 0xFF) {
+            Self {
+                value: Value::Latin1(units.iter().map(|&unit| unit as u8).collect()),
+            }
+        } else {
+            Self {
+                value: Value::Utf16(units.into()),
+            }
+        }
+    }
+
+    /// Whether the string is stored one byte per character.
+    pub fn is_latin1(&self) -> bool {
+        matches!(self.value, Value::Latin1(_))
+    }
+
+    /// The length in UTF-16 code units.
+    pub fn length(&self) -> usize {
+        match &self.value {
+            Value::Latin1(bytes) => bytes.len(),
+            Value::Utf16(units) => units.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
+    /// The UTF-16 code unit at `index`.
+    pub fn char_at(&self, index: usize) -> Option<u16> {
+        match &self.value {
+            Value::Latin1(bytes) => bytes.get(index).map(|&byte| byte as u16),
+            Value::Utf16(units) => units.get(index).copied(),
+        }
+    }
+
+    /// The code point at UTF-16 index `index`, combining a surrogate pair that starts there.
+    pub fn code_point_at(&self, index: usize) -> Option<i32> {
+        let unit = self.char_at(index)?;
+        if is_high_surrogate(unit) {
+            if let Some(low) = self.char_at(index + 1).filter(|&low| is_low_surrogate(low)) {
+                return Some(to_code_point(unit, low));
+            }
+        }
+        Some(unit as i32)
+    }
+
+    /// The UTF-16 code units of the string.
+    pub fn chars(&self) -> impl Iterator<Item = u16> + '_ {
+        (0..self.length()).map(|index| self.char_at(index).unwrap())
+    }
+
+    /// The code points of the string; unpaired surrogates are returned as is.
+    pub fn code_points(&self) -> impl Iterator<Item = i32> + '_ {
+        let mut index = 0;
+        std::iter::from_fn(move || {
+            let code_point = self.code_point_at(index)?;
+            index += if code_point >= 0x10000 { 2 } else { 1 };
+            Some(code_point)
+        })
+    }
+
+    /// Java's `String.hashCode`: `s[0]*31^(n-1) + s[1]*31^(n-2) + ... + s[n-1]`.
+    pub fn hash_code(&self) -> i32 {
+        self.chars().fold(0i32, |hash, unit| {
+            hash.wrapping_mul(31).wrapping_add(unit as i32)
+        })
+    }
+
+    /// Compares by UTF-16 code unit like Java's `String.compareTo`.
+    pub fn compare_to(&self, other: &Self) -> i32 {
+        if let (Value::Latin1(a), Value::Latin1(b)) = (&self.value, &other.value) {
+            return a
+                .iter()
+                .zip(b.iter())
+                .find(|(x, y)| x != y)
+                .map_or(a.len() as i32 - b.len() as i32, |(&x, &y)| {
+                    x as i32 - y as i32
+                });
+        }
+        self.chars()
+            .zip(other.chars())
+            .find(|(x, y)| x != y)
+            .map_or(self.length() as i32 - other.length() as i32, |(x, y)| {
+                x as i32 - y as i32
+            })
+    }
+}
+
+impl Default for JString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for JString {
+    fn from(s: &str) -> Self {
+        if s.is_ascii() {
+            Self::from_latin1(s.as_bytes())
+        } else {
+            Self::from_utf16(&s.encode_utf16().collect::<Vec<u16>>())
+        }
+    }
+}
+
+impl From<String> for JString {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl PartialOrd for JString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.value, &other.value) {
+            (Value::Latin1(a), Value::Latin1(b)) => a.cmp(b),
+            (Value::Utf16(a), Value::Utf16(b)) => a.cmp(b),
+            _ => self.compare_to(other).cmp(&0),
+        }
+    }
+}
+
+impl Display for JString {
+    /// Unpaired surrogates are written as U+FFFD, since they cannot be represented in UTF-8.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Value::Latin1(bytes) => {
+                let s: String = bytes.iter().map(|&byte| byte as char).collect();
+                write!(f, "{s}")
+            }
+            Value::Utf16(units) => write!(f, "{}", String::from_utf16_lossy(units)),
+        }
+    }
+}
+
+impl Debug for JString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn representation() {
+        let ascii = JString::from("key-42");
+        let latin1 = JString::from("caf\u{e9}");
+        let wide = JString::from("a\u{1F600}");
+        assert_eq!(ascii.is_latin1(), COMPACT_STRINGS);
+        assert_eq!(latin1.is_latin1(), COMPACT_STRINGS);
+        assert!(!wide.is_latin1());
+
+        assert_eq!(latin1.length(), 4);
+        assert_eq!(latin1.char_at(3), Some(0xE9));
+        assert_eq!(wide.length(), 3);
+        assert_eq!(wide.char_at(2), Some(0xDE00));
+        assert_eq!(
+            wide.code_points().collect::<Vec<_>>(),
+            ['a' as i32, 0x1F600]
+        );
+
+        let lone = JString::from_utf16(&[0xD800, 'x' as u16]);
+        assert_eq!(lone.code_point_at(0), Some(0xD800));
+        assert_eq!(lone.to_string(), "\u{FFFD}x");
+
+        assert_eq!(JString::from_utf16(&[0x68, 0x69]), JString::from("hi"));
+        assert_eq!(JString::from("hello").hash_code(), 99162322);
+        assert_eq!(
+            JString::from("\u{1F600}").compare_to(&JString::from("\u{FFFF}")),
+            -10178
+        );
+    }
+}
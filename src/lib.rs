@@ -1,22 +1,67 @@
+pub mod character;
 pub mod errors;
-
-pub trait JavaString {
-    fn value_of<T>(x: T) -> Self
-    where
-        T: ToString;
-    fn transform<R>(&self, function: fn(String) -> R) -> R;
-    fn indent(&self, amount: usize) -> Self;
+mod floating_decimal;
+mod hash_code;
+pub mod intern;
+pub mod io;
+mod jstring;
+pub mod math;
+pub mod nio;
+mod special_casing;
+pub mod text;
+pub mod time;
+pub mod util;
+
+pub use hash_code::HashCode;
+pub use intern::InternedStr;
+pub use jstring::{JString, COMPACT_STRINGS};
+
+use character::{is_high_surrogate, is_low_surrogate, is_surrogate, to_code_point};
+use errors::{StringIndexOutOfBoundsException, Throwable};
+use nio::charset::Charset;
+use util::regex::Pattern;
+use util::stream::Stream;
+use util::{Comparator, FormatArgument, Formatter, Locale, StringJoiner};
+
+/// The read-only half of Java's `String` API, for any string type that can be viewed as a
+/// `str`: `String`, `&str`, `Cow<str>`, `Box<str>`, `Rc<str>`, `Arc<str>` and so on. None of
+/// these methods copy the string unless their result is itself owned.
+pub trait JavaString: AsRef<str> {
     fn is_blank(&self) -> bool;
     fn split_with_delimiters<T>(&self, regex: T) -> Vec<&str>
     where
         T: FnMut(char) -> bool;
-    fn replace_all<T>(&self, regex: T, replacement: &Self) -> Self
-    where
-        T: FnMut(char) -> bool;
-    fn replace_first<T: FnMut(char) -> bool>(&self, regex: T, replacement: &Self) -> Self;
-    fn concat(&self, other: &Self) -> Self;
-    fn subsequence(&self, begin_index: usize, end_index: usize) -> Vec<char>;
-    fn substring(&self, begin_index: usize, end_index: usize) -> String;
+
+    /// Java's `split(regex, limit)`, with the same rules as `Pattern::split`: a positive
+    /// limit caps the number of parts, zero drops trailing empty parts and a negative limit
+    /// keeps them. Like Java, a regex that is a single literal character (or an escaped
+    /// non-alphanumeric one) skips the regex engine. Throws `PatternSyntaxException` for an
+    /// invalid regex.
+    ///
+    /// Named so as not to hide `str::split`.
+    fn split_regex(&self, regex: &str, limit: i32) -> Vec<&str>;
+
+    /// Java's `lines()`: the lines of the string, each ended by `\n`, `\r`, `\r\n` or the
+    /// end of the string, without their terminators. The last line is only there if it
+    /// isn't empty.
+    ///
+    /// Named so as not to hide `str::lines`, which also splits on `\n` and `\r\n` but not
+    /// on a lone `\r`.
+    fn java_lines(&self) -> Stream<'_, &str> {
+        let mut rest = self.as_ref();
+        Stream::of(std::iter::from_fn(move || {
+            if rest.is_empty() {
+                return None;
+            }
+            let (line, next) = match rest.find(['\n', '\r']) {
+                Some(end) if rest[end..].starts_with("\r\n") => (&rest[..end], end + 2),
+                Some(end) => (&rest[..end], end + 1),
+                None => (rest, rest.len()),
+            };
+            rest = &rest[next..];
+            Some(line)
+        }))
+    }
     fn last_index_from<T: FnMut(char) -> bool>(&self, ch: T, from_index: usize) -> Option<usize>;
     fn last_index_of<T: FnMut(char) -> bool>(&self, ch: T) -> Option<usize>;
     fn index_of_between<T: FnMut(char) -> bool>(
@@ -27,113 +72,328 @@ pub trait JavaString {
     ) -> Option<usize>;
     fn index_of<T: FnMut(char) -> bool>(&self, ch: T) -> Option<usize>;
     fn hash_code(&self) -> u32;
+
+    /// The canonical copy of this string from the global `StringTable`; interned strings
+    /// with equal contents compare equal by pointer.
+    fn intern(&self) -> InternedStr;
     fn region_matches(
         &self,
         toffset: usize,
-        other: &Self,
+        other: &str,
         ooffset: usize,
         len: usize,
         ignore_case: bool,
     ) -> bool;
-    fn compare_to_ignore_case(&self, other: &Self) -> i8;
+    /// Compares like `compare_to` after folding each character with `to_upper_case` and
+    /// then `to_lower_case`, exactly as Java does.
+    ///
+    /// As in the JDK, surrogate pairs are folded as whole code points only when both strings
+    /// need UTF-16 storage; with `COMPACT_STRINGS` a Latin-1 string is compared against the
+    /// other string's individual code units.
+    fn compare_to_ignore_case(&self, other: &str) -> i32 {
+        let a: Vec<u16> = self.java_chars().collect();
+        let b: Vec<u16> = other.encode_utf16().collect();
+        compare_ignore_case(&a, &b, Self::COMPACT_STRINGS)
+    }
+
     const COMPACT_STRINGS: bool;
 
+    /// The length in UTF-8 bytes, not Java's UTF-16 length, which is
+    /// `java_chars().count()`.
     fn length(&self) -> usize;
+
+    /// The `index`th Rust `char`. The methods on code points and `get_chars` take UTF-16
+    /// indices instead, as Java does.
     fn char_at(&self, index: usize) -> Option<char>;
 
-    fn code_point_at(&self, index: usize) -> u8 {
-        self.char_at(index).unwrap_or('\0') as u8
+    /// The UTF-16 code units of the string, like Java's `String.chars()`. Named so as not
+    /// to hide `str::chars`.
+    fn java_chars(&self) -> impl Iterator<Item = u16> + '_;
+
+    /// The code points of the string, like Java's `String.codePoints()`. A surrogate that is
+    /// not part of a valid pair is returned as is.
+    fn code_points(&self) -> impl Iterator<Item = i32> + '_ {
+        let mut units = self.java_chars().peekable();
+        std::iter::from_fn(move || {
+            let unit = units.next()?;
+            if is_high_surrogate(unit) {
+                if let Some(low) = units.next_if(|&low| is_low_surrogate(low)) {
+                    return Some(to_code_point(unit, low));
+                }
+            }
+            Some(unit as i32)
+        })
     }
 
-    // TODO: code_point_before
-    fn code_point_count(&self, begin_index: usize, end_index: usize) -> Option<usize>;
-    // TODO: offset_by_code_points
-    // TODO: get_chars
-    fn get_chars(&self, src_begin: usize, src_end: usize, dest: &mut Vec<char>, dest_begin: usize);
+    /// The code point starting at UTF-16 index `index`. If `index` is the second half of a
+    /// surrogate pair, that trailing surrogate is returned, as in Java.
+    fn code_point_at(&self, index: usize) -> Option<i32> {
+        let mut units = self.java_chars().skip(index);
+        let unit = units.next()?;
+        if is_high_surrogate(unit) {
+            if let Some(low) = units.next().filter(|&low| is_low_surrogate(low)) {
+                return Some(to_code_point(unit, low));
+            }
+        }
+        Some(unit as i32)
+    }
 
-    fn equals(&self, other: &Self) -> bool
-    where
-        Self: Eq,
-    {
-        self == other
+    /// The code point ending just before UTF-16 index `index`.
+    fn code_point_before(&self, index: usize) -> Option<i32> {
+        if index == 0 {
+            return None;
+        }
+        let units: Vec<u16> = self.java_chars().take(index).collect();
+        if units.len() != index {
+            return None;
+        }
+        let unit = units[index - 1];
+        if is_low_surrogate(unit) && index > 1 && is_high_surrogate(units[index - 2]) {
+            return Some(to_code_point(units[index - 2], unit));
+        }
+        Some(unit as i32)
     }
 
-    fn equals_ignore_case(&self, other: &Self) -> bool;
-    fn compare_to(&self, other: &Self) -> i8;
-}
+    /// The number of code points between the UTF-16 indices `begin_index` and `end_index`,
+    /// where a surrogate pair counts once and a lone surrogate counts as one code point.
+    fn code_point_count(&self, begin_index: usize, end_index: usize) -> Option<usize> {
+        if begin_index > end_index {
+            return None;
+        }
+        let units: Vec<u16> = self.java_chars().take(end_index).collect();
+        if units.len() != end_index {
+            return None;
+        }
+
+        let mut count = end_index - begin_index;
+        let mut i = begin_index;
+        while i < end_index {
+            if is_high_surrogate(units[i]) && i + 1 < end_index && is_low_surrogate(units[i + 1]) {
+                count -= 1;
+                i += 1;
+            }
+            i += 1;
+        }
+        Some(count)
+    }
 
-impl JavaString for String {
-    const COMPACT_STRINGS: bool = true;
+    /// The UTF-16 index `code_point_offset` code points away from `index`, or `None` if that
+    /// runs off either end of the string.
+    fn offset_by_code_points(&self, index: usize, code_point_offset: isize) -> Option<usize> {
+        let units: Vec<u16> = self.java_chars().collect();
+        let length = units.len();
+        if index > length {
+            return None;
+        }
 
-    fn length(&self) -> usize {
-        self.len()
+        let mut x = index;
+        if code_point_offset >= 0 {
+            let mut i = 0;
+            while x < length && i < code_point_offset {
+                x += 1;
+                if is_high_surrogate(units[x - 1]) && x < length && is_low_surrogate(units[x]) {
+                    x += 1;
+                }
+                i += 1;
+            }
+            (i == code_point_offset).then_some(x)
+        } else {
+            let mut i = code_point_offset;
+            while x > 0 && i < 0 {
+                x -= 1;
+                if is_low_surrogate(units[x]) && x > 0 && is_high_surrogate(units[x - 1]) {
+                    x -= 1;
+                }
+                i += 1;
+            }
+            (i == 0).then_some(x)
+        }
     }
 
-    fn char_at(&self, index: usize) -> Option<char> {
-        self.chars().nth(index)
+    /// Copies the UTF-16 code units `src_begin..src_end` into `dest` starting at
+    /// `dest_begin`. Throws `StringIndexOutOfBoundsException` if either range is out of
+    /// bounds, as Java does, before anything is copied.
+    fn get_chars(&self, src_begin: usize, src_end: usize, dest: &mut [u16], dest_begin: usize);
+
+    fn equals(&self, other: &str) -> bool {
+        self.as_ref() == other
     }
 
-    fn code_point_count(&self, begin_index: usize, end_index: usize) -> Option<usize> {
-        if begin_index.max(end_index) > self.length() {
-            None
-        } else {
-            Some(end_index - begin_index)
+    /// Whether the strings have the same length and `compare_to_ignore_case` finds them
+    /// equal, which is Java's rule; unlike comparing `to_lowercase` copies it never treats
+    /// strings of different lengths, like "ß" and "SS", as equal.
+    fn equals_ignore_case(&self, other: &str) -> bool;
+
+    /// Compares lexicographically by UTF-16 code unit, like Java: the difference of the first
+    /// differing units, or else the difference in length.
+    ///
+    /// This is not the order of `Ord for String`, which compares UTF-8 bytes and so sorts
+    /// supplementary characters after U+E000 to U+FFFF instead of before them.
+    fn compare_to(&self, other: &str) -> i32 {
+        let a: Vec<u16> = self.java_chars().collect();
+        let b: Vec<u16> = other.encode_utf16().collect();
+        a.iter()
+            .zip(&b)
+            .find(|(x, y)| x != y)
+            .map_or(a.len() as i32 - b.len() as i32, |(&x, &y)| {
+                x as i32 - y as i32
+            })
+    }
+}
+
+fn compare_code_point_ci(a: i32, b: i32) -> i32 {
+    let (a, b) = (character::to_upper_case(a), character::to_upper_case(b));
+    if a != b {
+        let (a, b) = (character::to_lower_case(a), character::to_lower_case(b));
+        if a != b {
+            return a - b;
         }
     }
+    0
+}
 
-    fn get_chars(
-        &self,
-        _src_begin: usize,
-        _src_end: usize,
-        _dest: &mut Vec<char>,
-        _dest_begin: usize,
-    ) {
-        todo!();
-        //dest[dest_begin..] = self.chars().collect::<Vec<char>>()[src_begin..src_end];
+/// `compareToIgnoreCase` over UTF-16 code units, as described on `compare_to_ignore_case`.
+fn compare_ignore_case(a: &[u16], b: &[u16], compact_strings: bool) -> i32 {
+    let is_latin1 = |units: &[u16]| units.iter().all(|&unit| unit <= 0xFF);
+    if compact_strings && (is_latin1(a) || is_latin1(b)) {
+        compare_units_ci(a, b)
+    } else {
+        compare_code_points_ci(a, b)
     }
+}
+
+/// `compareToIgnoreCase` for strings with at least one Latin-1 side, folding unit by unit.
+fn compare_units_ci(a: &[u16], b: &[u16]) -> i32 {
+    a.iter()
+        .zip(b)
+        .map(|(&x, &y)| compare_code_point_ci(x as i32, y as i32))
+        .find(|&difference| difference != 0)
+        .unwrap_or(a.len() as i32 - b.len() as i32)
+}
 
-    fn equals_ignore_case(&self, other: &Self) -> bool {
-        self.to_lowercase() == other.to_lowercase()
+/// The code point including the unit at `index`: negated if it is a pair starting there,
+/// positive if it is a pair ending there, and the unit itself otherwise.
+fn code_point_including(units: &[u16], index: usize) -> i32 {
+    let unit = units[index];
+    if !is_surrogate(unit) {
+        unit as i32
+    } else if is_low_surrogate(unit) {
+        match index.checked_sub(1).map(|before| units[before]) {
+            Some(high) if is_high_surrogate(high) => to_code_point(high, unit),
+            _ => unit as i32,
+        }
+    } else {
+        match units.get(index + 1) {
+            Some(&low) if is_low_surrogate(low) => -to_code_point(unit, low),
+            _ => unit as i32,
+        }
     }
+}
 
-    fn compare_to(&self, other: &Self) -> i8 {
-        match self.cmp(other) {
-            std::cmp::Ordering::Less => -1,
-            std::cmp::Ordering::Equal => 0,
-            std::cmp::Ordering::Greater => 1,
+/// `compareToIgnoreCase` for two UTF-16 strings, folding surrogate pairs as code points.
+fn compare_code_points_ci(a: &[u16], b: &[u16]) -> i32 {
+    let (mut k1, mut k2) = (0, 0);
+    while k1 < a.len() && k2 < b.len() {
+        if a[k1] != b[k2] && compare_code_point_ci(a[k1] as i32, b[k2] as i32) != 0 {
+            let mut cp1 = code_point_including(a, k1);
+            if cp1 < 0 {
+                k1 += 1;
+                cp1 = -cp1;
+            }
+            let mut cp2 = code_point_including(b, k2);
+            if cp2 < 0 {
+                k2 += 1;
+                cp2 = -cp2;
+            }
+            let difference = compare_code_point_ci(cp1, cp2);
+            if difference != 0 {
+                return difference;
+            }
         }
+        k1 += 1;
+        k2 += 1;
+    }
+    a.len() as i32 - b.len() as i32
+}
+
+/// Orders strings like `compare_to_ignore_case`; Java's `String.CASE_INSENSITIVE_ORDER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaseInsensitiveComparator;
+
+pub const CASE_INSENSITIVE_ORDER: CaseInsensitiveComparator = CaseInsensitiveComparator;
+
+impl<S: JavaString + ?Sized> Comparator<S> for CaseInsensitiveComparator {
+    fn compare(&self, a: &S, b: &S) -> i32 {
+        a.compare_to_ignore_case(b.as_ref())
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> JavaString for S {
+    const COMPACT_STRINGS: bool = COMPACT_STRINGS;
+
+    fn length(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.as_ref().chars().nth(index)
+    }
+
+    fn java_chars(&self) -> impl Iterator<Item = u16> + '_ {
+        self.as_ref().encode_utf16()
     }
 
-    fn compare_to_ignore_case(&self, other: &Self) -> i8 {
-        match self.to_lowercase().cmp(&other.to_lowercase()) {
-            std::cmp::Ordering::Less => -1,
-            std::cmp::Ordering::Equal => 0,
-            std::cmp::Ordering::Greater => 1,
+    fn get_chars(&self, src_begin: usize, src_end: usize, dest: &mut [u16], dest_begin: usize) {
+        let length = self.java_chars().count();
+        if src_begin > src_end || src_end > length {
+            StringIndexOutOfBoundsException::new(
+                format!("begin {src_begin}, end {src_end}, length {length}"),
+                src_begin as i32,
+            )
+            .throw();
+        }
+        let count = src_end - src_begin;
+        check_offset_count(dest_begin, count, dest.len());
+        for (dest, unit) in dest[dest_begin..dest_begin + count]
+            .iter_mut()
+            .zip(self.java_chars().skip(src_begin))
+        {
+            *dest = unit;
         }
     }
 
+    fn equals_ignore_case(&self, other: &str) -> bool {
+        let a: Vec<u16> = self.java_chars().collect();
+        let b: Vec<u16> = other.encode_utf16().collect();
+        a.len() == b.len() && compare_ignore_case(&a, &b, Self::COMPACT_STRINGS) == 0
+    }
+
     fn region_matches(
         &self,
         toffset: usize,
-        other: &Self,
+        other: &str,
         ooffset: usize,
         len: usize,
         ignore_case: bool,
     ) -> bool {
+        let this = self.as_ref();
         if ignore_case {
-            self.to_lowercase()[toffset..(toffset + len)]
-                == other[ooffset..(ooffset + len)].to_lowercase()
+            this[toffset..(toffset + len)].equals_ignore_case(&other[ooffset..(ooffset + len)])
         } else {
-            self[toffset..(toffset + len)] == other[ooffset..(ooffset + len)]
+            this[toffset..(toffset + len)] == other[ooffset..(ooffset + len)]
         }
     }
 
     fn hash_code(&self) -> u32 {
-        todo!()
+        HashCode::hash_code(self.as_ref()) as u32
+    }
+
+    fn intern(&self) -> InternedStr {
+        intern::StringTable::global().intern(self.as_ref())
     }
 
     fn index_of<T: FnMut(char) -> bool>(&self, ch: T) -> Option<usize> {
-        self.find(ch)
+        self.as_ref().find(ch)
     }
 
     fn index_of_between<T: FnMut(char) -> bool>(
@@ -142,51 +402,208 @@ impl JavaString for String {
         begin_index: usize,
         end_index: usize,
     ) -> Option<usize> {
-        self[begin_index..end_index].find(ch)
+        self.as_ref()[begin_index..end_index].find(ch)
     }
 
     fn last_index_of<T: FnMut(char) -> bool>(&self, ch: T) -> Option<usize> {
-        self.rfind(ch)
+        self.as_ref().rfind(ch)
     }
 
     fn last_index_from<T: FnMut(char) -> bool>(&self, ch: T, from_index: usize) -> Option<usize> {
-        self[from_index..].rfind(ch)
+        self.as_ref()[from_index..].rfind(ch)
     }
 
-    fn substring(&self, begin_index: usize, end_index: usize) -> String {
-        self[begin_index..end_index].to_string()
+    fn split_with_delimiters<T: FnMut(char) -> bool>(&self, regex: T) -> Vec<&str> {
+        self.as_ref().split_inclusive(regex).collect()
     }
 
-    fn subsequence(&self, begin_index: usize, end_index: usize) -> Vec<char> {
-        self[begin_index..end_index].chars().collect()
+    fn split_regex(&self, regex: &str, limit: i32) -> Vec<&str> {
+        match split_fastpath_char(regex) {
+            Some(ch) => split_on_char(self.as_ref(), ch, limit),
+            None => match Pattern::compile(regex) {
+                Ok(pattern) => pattern.split(self.as_ref(), limit),
+                Err(e) => e.throw(),
+            },
+        }
     }
 
-    fn concat(&self, other: &Self) -> Self {
-        let mut return_value = self.clone();
-        return_value.push_str(other);
-        return_value
+    fn is_blank(&self) -> bool {
+        self.as_ref().trim().is_empty()
+    }
+}
+
+/// Java's `checkBoundsOffCount` for a `count`-long range at `offset` in an array of
+/// `length` elements.
+fn check_offset_count(offset: usize, count: usize, length: usize) {
+    if offset.checked_add(count).is_none_or(|end| end > length) {
+        StringIndexOutOfBoundsException::new(
+            format!("offset {offset}, count {count}, length {length}"),
+            offset as i32,
+        )
+        .throw();
+    }
+}
+
+/// The character `String.split` looks for directly: a single BMP character that is not a
+/// regex metacharacter, or a backslash followed by one that is not an ASCII letter or digit.
+fn split_fastpath_char(regex: &str) -> Option<char> {
+    let mut chars = regex.chars();
+    let ch = match (chars.next(), chars.next(), chars.next()) {
+        (Some(ch), None, _) if !".$|()[{^?*+\\".contains(ch) => ch,
+        (Some('\\'), Some(ch), None) if !ch.is_ascii_alphanumeric() => ch,
+        _ => return None,
+    };
+    (ch.len_utf16() == 1).then_some(ch)
+}
+
+fn split_on_char(s: &str, ch: char, limit: i32) -> Vec<&str> {
+    let limited = limit > 0;
+    let mut parts = Vec::new();
+    let mut off = 0;
+    while let Some(next) = s[off..].find(ch).map(|i| off + i) {
+        if !limited || parts.len() < limit as usize - 1 {
+            parts.push(&s[off..next]);
+            off = next + ch.len_utf8();
+        } else {
+            parts.push(&s[off..]);
+            off = s.len();
+            break;
+        }
     }
+    if off == 0 {
+        return vec![s];
+    }
+    if !limited || parts.len() < limit as usize {
+        parts.push(&s[off..]);
+    }
+    if limit == 0 {
+        while parts.last().is_some_and(|part| part.is_empty()) {
+            parts.pop();
+        }
+    }
+    parts
+}
+
+/// The half of Java's `String` API that produces new strings. The results are always owned
+/// `String`s, whatever the type of the receiver.
+pub trait JavaStringOwned: JavaString {
+    fn value_of<T>(x: T) -> String
+    where
+        T: ToString;
 
-    fn replace_first<T: FnMut(char) -> bool>(&self, regex: T, replacement: &Self) -> Self {
-        self.replacen(regex, replacement, 1)
+    /// Java's `String.valueOf(char[], offset, count)`: the string of `count` UTF-16 code
+    /// units from `data`, starting at `offset`. Unpaired surrogates, which a `String`
+    /// cannot hold, become U+FFFD. Throws `StringIndexOutOfBoundsException` if the range
+    /// is out of bounds.
+    fn value_of_chars(data: &[u16], offset: usize, count: usize) -> String;
+
+    /// The same as `value_of_chars`.
+    fn copy_value_of(data: &[u16], offset: usize, count: usize) -> String {
+        Self::value_of_chars(data, offset, count)
     }
 
-    fn replace_all<T: FnMut(char) -> bool>(&self, regex: T, replacement: &Self) -> Self {
-        self.replace(regex, replacement)
+    /// Java's `String.join`: the elements separated by `delimiter`.
+    fn join<I>(delimiter: &str, elements: I) -> String
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut joiner = StringJoiner::new(delimiter);
+        for element in elements {
+            joiner.add(element.as_ref());
+        }
+        joiner.to_string()
     }
 
-    fn split_with_delimiters<T: FnMut(char) -> bool>(&self, regex: T) -> Vec<&str> {
-        self.split_inclusive(regex).collect()
+    /// Java's `String.format`: the arguments formatted by `format` in the default locale,
+    /// see `Formatter`.
+    fn format(format: &str, args: &[FormatArgument]) -> String {
+        Self::format_with_locale(&Locale::get_default(), format, args)
     }
 
-    fn is_blank(&self) -> bool {
-        self.trim().is_empty()
+    fn format_with_locale(locale: &Locale, format: &str, args: &[FormatArgument]) -> String {
+        Formatter::with_locale(locale)
+            .format(format, args)
+            .to_string()
+    }
+
+    /// Java's `formatted`: the same as `format` with the string as the format.
+    fn formatted(&self, args: &[FormatArgument]) -> String {
+        Self::format(self.as_ref(), args)
+    }
+
+    /// Java's `new String(bytes, charset)`: decodes `bytes`, replacing malformed input
+    /// with U+FFFD.
+    fn from_bytes(bytes: &[u8], charset: &Charset) -> String {
+        charset.decode(bytes)
     }
 
-    fn indent(&self, amount: usize) -> Self {
+    /// Java's `getBytes(charset)`: encodes the string, replacing characters the charset
+    /// cannot represent with `?`.
+    fn get_bytes(&self, charset: &Charset) -> Vec<u8> {
+        charset.encode(self.as_ref())
+    }
+
+    /// The string's UTF-16 code units, like Java's `toCharArray`.
+    fn to_char_array(&self) -> Vec<u16> {
+        self.java_chars().collect()
+    }
+    fn transform<R>(&self, function: fn(String) -> R) -> R;
+    fn indent(&self, amount: usize) -> String;
+    fn replace_all<T>(&self, regex: T, replacement: &str) -> String
+    where
+        T: FnMut(char) -> bool;
+    fn replace_first<T: FnMut(char) -> bool>(&self, regex: T, replacement: &str) -> String;
+    fn concat(&self, other: &str) -> String;
+    fn subsequence(&self, begin_index: usize, end_index: usize) -> Vec<char>;
+    fn substring(&self, begin_index: usize, end_index: usize) -> String;
+
+    /// Java's `toUpperCase(Locale)`: the full uppercase mapping, so "ß" becomes "SS", with
+    /// the Turkish and Azeri dotted i and the Lithuanian dot above handled by language.
+    fn to_upper_case(&self, locale: &Locale) -> String;
+
+    /// Java's `toLowerCase(Locale)`, including a final sigma as "ς", "İ" as "i̇" outside
+    /// Turkish and Azeri, and Lithuanian's retained dot above.
+    fn to_lower_case(&self, locale: &Locale) -> String;
+}
+
+impl<S: AsRef<str> + ?Sized> JavaStringOwned for S {
+    fn to_upper_case(&self, locale: &Locale) -> String {
+        let units: Vec<u16> = self.java_chars().collect();
+        special_casing::to_upper_case(&units, locale)
+    }
+
+    fn to_lower_case(&self, locale: &Locale) -> String {
+        let units: Vec<u16> = self.java_chars().collect();
+        special_casing::to_lower_case(&units, locale)
+    }
+
+    fn substring(&self, begin_index: usize, end_index: usize) -> String {
+        self.as_ref()[begin_index..end_index].to_string()
+    }
+
+    fn subsequence(&self, begin_index: usize, end_index: usize) -> Vec<char> {
+        self.as_ref()[begin_index..end_index].chars().collect()
+    }
+
+    fn concat(&self, other: &str) -> String {
+        let mut return_value = self.as_ref().to_string();
+        return_value.push_str(other);
+        return_value
+    }
+
+    fn replace_first<T: FnMut(char) -> bool>(&self, regex: T, replacement: &str) -> String {
+        self.as_ref().replacen(regex, replacement, 1)
+    }
+
+    fn replace_all<T: FnMut(char) -> bool>(&self, regex: T, replacement: &str) -> String {
+        self.as_ref().replace(regex, replacement)
+    }
+
+    fn indent(&self, amount: usize) -> String {
         let mut return_value = String::new();
 
-        for line in self.lines() {
+        for line in self.as_ref().lines() {
             return_value.push_str(&" ".repeat(amount));
             return_value.push_str(line);
             return_value.push('\n');
@@ -195,14 +612,290 @@ impl JavaString for String {
         return_value
     }
 
-    // TODO: strip_indent(&self) -> Self
-    // TODO: translate_escapes(&self) -> Self
+    // TODO: strip_indent(&self) -> String
+    // TODO: translate_escapes(&self) -> String
 
     fn transform<R>(&self, function: fn(String) -> R) -> R {
-        function(self.clone())
+        function(self.as_ref().to_string())
     }
 
-    fn value_of<T: ToString>(x: T) -> Self {
+    fn value_of<T: ToString>(x: T) -> String {
         x.to_string()
     }
+
+    fn value_of_chars(data: &[u16], offset: usize, count: usize) -> String {
+        check_offset_count(offset, count, data.len());
+        String::from_utf16_lossy(&data[offset..offset + count])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nio::charset::StandardCharsets;
+    use crate::util::{Comparator, Locale};
+    use crate::{JavaString, JavaStringOwned, CASE_INSENSITIVE_ORDER};
+
+    #[test]
+    fn format() {
+        let args = ["x".into(), 3.into()];
+        assert_eq!(
+            String::format_with_locale(&Locale::US, "%s=%03d", &args),
+            "x=003"
+        );
+        assert_eq!("%s=%<S".formatted(&args), "x=X");
+    }
+
+    #[test]
+    fn code_points() {
+        // 'a', U+1F600 (a surrogate pair), 'b'
+        let s = "a\u{1F600}b".to_string();
+        assert_eq!(s.java_chars().count(), 4);
+        assert_eq!(s.chars().count(), 3);
+        assert_eq!(
+            s.code_points().collect::<Vec<_>>(),
+            ['a' as i32, 0x1F600, 'b' as i32]
+        );
+        assert_eq!(s.code_point_at(1), Some(0x1F600));
+        assert_eq!(s.code_point_at(2), Some(0xDE00));
+        assert_eq!(s.code_point_at(4), None);
+        assert_eq!(s.code_point_before(3), Some(0x1F600));
+        assert_eq!(s.code_point_before(2), Some(0xD83D));
+        assert_eq!(s.code_point_count(0, 4), Some(3));
+        assert_eq!(s.code_point_count(0, 2), Some(2));
+        assert_eq!(s.code_point_count(0, 5), None);
+        assert_eq!(s.offset_by_code_points(0, 2), Some(3));
+        assert_eq!(s.offset_by_code_points(4, -2), Some(1));
+        assert_eq!(s.offset_by_code_points(0, 4), None);
+    }
+
+    #[test]
+    fn comparisons_match_jdk() {
+        for (a, b, compare_to, ignore_case) in [
+            ("\u{10400}", "\u{10428}", -40, 0),
+            ("a\u{10400}x", "A\u{10428}y", 32, -1),
+            // Latin-1 strings compare unit by unit with compact strings.
+            (
+                "\u{10400}",
+                "a",
+                55200,
+                if crate::COMPACT_STRINGS { 55200 } else { 66503 },
+            ),
+            ("\u{FB03}", "FFI", 64189, 64157),
+            ("\u{1C5}", "\u{1C6}", -1, 0),
+            ("\u{131}", "I", 232, 0),
+            ("Hello", "hello world", -32, -6),
+            ("\u{1F600}", "\u{FFFF}", -10178, 62977),
+        ] {
+            let (a, b) = (a.to_string(), b.to_string());
+            assert_eq!(a.compare_to(&b), compare_to, "{a} vs {b}");
+            assert_eq!(a.compare_to_ignore_case(&b), ignore_case, "{a} vs {b}");
+        }
+
+        let mut words: Vec<String> = ["b", "C", "a"].map(String::from).to_vec();
+        words.sort_by(CASE_INSENSITIVE_ORDER.as_fn());
+        assert_eq!(words, ["a", "b", "C"]);
+    }
+
+    #[test]
+    fn borrowed_and_shared_strings() {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        let text = "key = value";
+        let cow: Cow<str> = Cow::Borrowed(text);
+        let boxed: Box<str> = text.into();
+        let rc: Rc<str> = text.into();
+        let arc: Arc<str> = text.into();
+
+        assert_eq!(text.index_of(|c| c == '='), Some(4));
+        assert_eq!(cow.last_index_of(|c| c == 'e'), Some(10));
+        assert!(boxed.region_matches(6, "VALUE", 0, 5, true));
+        assert!(!rc.is_blank() && " \t".is_blank());
+        assert_eq!(arc.compare_to(text), 0);
+        assert_eq!(text.substring(0, 3), "key");
+        assert_eq!(rc.replace_all(char::is_whitespace, ""), "key=value");
+        assert_eq!(String::value_of(42), "42");
+    }
+
+    #[test]
+    fn case_mapping_matches_jdk() {
+        let turkish = Locale::for_language_tag("tr");
+        let lithuanian = Locale::with_country("lt", "LT");
+        assert_eq!("straße".to_upper_case(&Locale::ROOT), "STRASSE");
+        assert_eq!("istanbul".to_upper_case(&turkish), "İSTANBUL");
+        assert_eq!("ISTANBUL".to_lower_case(&turkish), "ıstanbul");
+        assert_eq!("I\u{307}x".to_lower_case(&turkish), "ix");
+        assert_eq!("İ".to_lower_case(&Locale::ENGLISH), "i\u{307}");
+        assert_eq!("ΟΔΟΣ ΟΔΟΣ.".to_lower_case(&Locale::ROOT), "οδος οδος.");
+        assert_eq!("Σ".to_lower_case(&Locale::ROOT), "σ");
+        assert_eq!("A'Σ".to_lower_case(&Locale::ROOT), "a'ς");
+        assert_eq!("I\u{10428}Σ".to_lower_case(&Locale::ROOT), "i\u{10428}σ");
+        assert_eq!("\u{10428}Σ".to_lower_case(&Locale::ROOT), "\u{10428}ς");
+        assert_eq!("AΣ\u{20000}b".to_lower_case(&Locale::ROOT), "aς\u{20000}b");
+        assert_eq!("I\u{301}".to_lower_case(&lithuanian), "i\u{307}\u{301}");
+        assert_eq!("i\u{307}".to_upper_case(&lithuanian), "I");
+        assert_eq!("\u{1F88}".to_upper_case(&Locale::ROOT), "\u{1F08}\u{399}");
+
+        assert!(!"straße".equals_ignore_case("STRASSE"));
+        assert!("ß".equals_ignore_case("\u{1E9E}"));
+        assert!("\u{10400}".equals_ignore_case("\u{10428}"));
+        assert!("\u{1C5}".equals_ignore_case("\u{1C6}"));
+    }
+
+    #[test]
+    fn intern() {
+        let a = String::from("interned").intern();
+        let b = format!("inter{}", "ned").intern();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn split_matches_jdk() {
+        for (input, regex, limit, expected) in [
+            ("boo:and:foo", ":", 0, &["boo", "and", "foo"][..]),
+            ("boo:and:foo", ":", -1, &["boo", "and", "foo"][..]),
+            ("boo:and:foo", ":", 1, &["boo:and:foo"][..]),
+            ("boo:and:foo", ":", 2, &["boo", "and:foo"][..]),
+            ("boo:and:foo", "o", 0, &["b", "", ":and:f"][..]),
+            ("boo:and:foo", "o", -1, &["b", "", ":and:f", "", ""][..]),
+            ("boo:and:foo", "o", 1, &["boo:and:foo"][..]),
+            ("boo:and:foo", "o", 2, &["b", "o:and:foo"][..]),
+            ("abc", "", 0, &["a", "b", "c"][..]),
+            ("abc", "", -1, &["a", "b", "c", ""][..]),
+            ("abc", "", 1, &["abc"][..]),
+            ("abc", "", 2, &["a", "bc"][..]),
+            (",", ",", 0, &[][..]),
+            (",", ",", -1, &["", ""][..]),
+            (",", ",", 1, &[","][..]),
+            (",", ",", 2, &["", ""][..]),
+            ("", ",", 0, &[""][..]),
+            ("", ",", -1, &[""][..]),
+            ("", ",", 1, &[""][..]),
+            ("", ",", 2, &[""][..]),
+            ("a,b,,c,,", ",", 0, &["a", "b", "", "c"][..]),
+            ("a,b,,c,,", ",", -1, &["a", "b", "", "c", "", ""][..]),
+            ("a,b,,c,,", ",", 1, &["a,b,,c,,"][..]),
+            ("a,b,,c,,", ",", 2, &["a", "b,,c,,"][..]),
+            (",a,,b", ",", 0, &["", "a", "", "b"][..]),
+            (",a,,b", ",", -1, &["", "a", "", "b"][..]),
+            (",a,,b", ",", 1, &[",a,,b"][..]),
+            (",a,,b", ",", 2, &["", "a,,b"][..]),
+            ("a.b.c", "\\.", 0, &["a", "b", "c"][..]),
+            ("a.b.c", "\\.", -1, &["a", "b", "c"][..]),
+            ("a.b.c", "\\.", 1, &["a.b.c"][..]),
+            ("a.b.c", "\\.", 2, &["a", "b.c"][..]),
+            ("a|b|c", "\\|", 0, &["a", "b", "c"][..]),
+            ("a|b|c", "\\|", -1, &["a", "b", "c"][..]),
+            ("a|b|c", "\\|", 1, &["a|b|c"][..]),
+            ("a|b|c", "\\|", 2, &["a", "b|c"][..]),
+            ("a1b22c333", "\\d*", 0, &["a", "", "b", "", "c"][..]),
+            (
+                "a1b22c333",
+                "\\d*",
+                -1,
+                &["a", "", "b", "", "c", "", ""][..],
+            ),
+            ("a1b22c333", "\\d*", 1, &["a1b22c333"][..]),
+            ("a1b22c333", "\\d*", 2, &["a", "b22c333"][..]),
+            ("a1b22c333", "\\d+", 0, &["a", "b", "c"][..]),
+            ("a1b22c333", "\\d+", -1, &["a", "b", "c", ""][..]),
+            ("a1b22c333", "\\d+", 1, &["a1b22c333"][..]),
+            ("a1b22c333", "\\d+", 2, &["a", "b22c333"][..]),
+            ("aaa", "a*?", 0, &["a", "a", "a"][..]),
+            ("aaa", "a*?", -1, &["a", "a", "a", ""][..]),
+            ("aaa", "a*?", 1, &["aaa"][..]),
+            ("aaa", "a*?", 2, &["a", "aa"][..]),
+            ("Hello World", "(?=[A-Z])", 0, &["Hello ", "World"][..]),
+            ("Hello World", "(?=[A-Z])", -1, &["Hello ", "World"][..]),
+            ("Hello World", "(?=[A-Z])", 1, &["Hello World"][..]),
+            ("Hello World", "(?=[A-Z])", 2, &["Hello ", "World"][..]),
+            ("a b  c", "\\b", 0, &["a", " ", "b", "  ", "c"][..]),
+            ("a b  c", "\\b", -1, &["a", " ", "b", "  ", "c", ""][..]),
+            ("a b  c", "\\b", 1, &["a b  c"][..]),
+            ("a b  c", "\\b", 2, &["a", " b  c"][..]),
+            ("a b  c", "\\s+", 0, &["a", "b", "c"][..]),
+            ("a b  c", "\\s+", -1, &["a", "b", "c"][..]),
+            ("a b  c", "\\s+", 1, &["a b  c"][..]),
+            ("a b  c", "\\s+", 2, &["a", "b  c"][..]),
+            ("aXbXc", "X", 0, &["a", "b", "c"][..]),
+            ("aXbXc", "X", -1, &["a", "b", "c"][..]),
+            ("aXbXc", "X", 1, &["aXbXc"][..]),
+            ("aXbXc", "X", 2, &["a", "bXc"][..]),
+            ("tab	sep", "	", 0, &["tab", "sep"][..]),
+            ("tab	sep", "	", -1, &["tab", "sep"][..]),
+            ("tab	sep", "	", 1, &["tab	sep"][..]),
+            ("tab	sep", "	", 2, &["tab", "sep"][..]),
+            ("日本語", "本", 0, &["日", "語"][..]),
+            ("日本語", "本", -1, &["日", "語"][..]),
+            ("日本語", "本", 1, &["日本語"][..]),
+            ("日本語", "本", 2, &["日", "語"][..]),
+            ("a😀b😀", "😀", 0, &["a", "b"][..]),
+            ("a😀b😀", "😀", -1, &["a", "b", ""][..]),
+            ("a😀b😀", "😀", 1, &["a😀b😀"][..]),
+            ("a😀b😀", "😀", 2, &["a", "b😀"][..]),
+            ("x--y", "-", 0, &["x", "", "y"][..]),
+            ("x--y", "-", -1, &["x", "", "y"][..]),
+            ("x--y", "-", 1, &["x--y"][..]),
+            ("x--y", "-", 2, &["x", "-y"][..]),
+            ("a::b", "::", 0, &["a", "b"][..]),
+            ("a::b", "::", -1, &["a", "b"][..]),
+            ("a::b", "::", 1, &["a::b"][..]),
+            ("a::b", "::", 2, &["a", "b"][..]),
+        ] {
+            assert_eq!(
+                input.split_regex(regex, limit),
+                expected,
+                "{input:?}.split({regex:?}, {limit})"
+            );
+        }
+        assert_eq!(String::from("a1b2").split_regex("\\d", 0), ["a", "b"]);
+        // `str`'s own methods are still there on owned strings.
+        let owned = String::from("a b\rc");
+        assert_eq!(owned.split(' ').collect::<Vec<_>>(), ["a", "b\rc"]);
+        assert_eq!(owned.lines().count(), 1);
+        assert_eq!(owned.java_lines().to_array(), ["a b", "c"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unclosed group near index 2")]
+    fn split_invalid_regex() {
+        "a(b".split_regex("a(", 0);
+    }
+
+    #[test]
+    fn char_arrays_and_bytes() {
+        let mut dest = [0u16; 5];
+        "h€llo".get_chars(1, 3, &mut dest, 2);
+        assert_eq!(dest, [0, 0, 0x20AC, 'l' as u16, 0]);
+        assert_eq!("a😀".to_char_array(), [0x61, 0xD83D, 0xDE00]);
+        assert_eq!(String::value_of_chars(&[0x61, 0x62, 0x63], 1, 2), "bc");
+        assert_eq!(String::copy_value_of(&[0xD83D, 0x61], 0, 2), "\u{FFFD}a");
+
+        assert_eq!(String::join("-", ["a", "b", "c"]), "a-b-c");
+        assert_eq!(String::join(",", Vec::<String>::new()), "");
+
+        let utf16 = StandardCharsets::UTF_16;
+        assert_eq!("Aé".get_bytes(&utf16), [0xFE, 0xFF, 0, 0x41, 0, 0xE9]);
+        assert_eq!(String::from_bytes(&"Aé".get_bytes(&utf16), &utf16), "Aé");
+        assert_eq!("Aé€".get_bytes(&StandardCharsets::ISO_8859_1), b"A\xE9?");
+    }
+
+    #[test]
+    #[should_panic(expected = "begin 3, end 2, length 5")]
+    fn get_chars_bad_source_range() {
+        "hello".get_chars(3, 2, &mut [0; 5], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset 3, count 3, length 5")]
+    fn get_chars_bad_destination_range() {
+        "hello".get_chars(0, 3, &mut [0; 5], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "offset 1, count 5, length 3")]
+    fn value_of_chars_out_of_bounds() {
+        String::value_of_chars(&[0x61, 0x62, 0x63], 1, 5);
+    }
 }
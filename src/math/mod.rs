@@ -0,0 +1,25 @@
+//! `java.math`: the rounding modes shared by decimal arithmetic and number formatting.
+
+/// `java.math.RoundingMode`: which way to go when digits are discarded from a number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Away from zero, whenever a discarded digit is non-zero.
+    Up,
+    /// Towards zero; plain truncation.
+    Down,
+    /// Towards positive infinity.
+    Ceiling,
+    /// Towards negative infinity.
+    Floor,
+    /// To the nearest neighbour, or away from zero if both are equally near.
+    HalfUp,
+    /// To the nearest neighbour, or towards zero if both are equally near.
+    HalfDown,
+    /// To the nearest neighbour, or to the even one if both are equally near. This is the
+    /// default of number formats.
+    #[default]
+    HalfEven,
+    /// Asserts that nothing needs rounding; discarding a non-zero digit is an
+    /// `ArithmeticException`.
+    Unnecessary,
+}
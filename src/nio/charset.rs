@@ -0,0 +1,398 @@
+//! `java.nio.charset`: the standard charsets, with the JDK's replacement behaviour for
+//! characters that cannot be encoded and bytes that cannot be decoded.
+
+use std::fmt::Display;
+
+use crate::errors::nio::charset::{IllegalCharsetNameException, UnsupportedCharsetException};
+use crate::errors::Throwable;
+
+const REPLACEMENT: char = '\u{FFFD}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Encoding {
+    UsAscii,
+    Iso8859_1,
+    Utf8,
+    Utf16,
+    Utf16Be,
+    Utf16Le,
+}
+
+/// A named character encoding. Only the charsets every Java platform must support are
+/// available; see `StandardCharsets`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Charset(Encoding);
+
+/// The charsets of `java.nio.charset.StandardCharsets`.
+pub struct StandardCharsets;
+
+impl StandardCharsets {
+    pub const US_ASCII: Charset = Charset(Encoding::UsAscii);
+    pub const ISO_8859_1: Charset = Charset(Encoding::Iso8859_1);
+    pub const UTF_8: Charset = Charset(Encoding::Utf8);
+    /// Decodes either byte order according to a leading byte-order mark, defaulting to
+    /// big-endian, and encodes big-endian with a byte-order mark.
+    pub const UTF_16: Charset = Charset(Encoding::Utf16);
+    pub const UTF_16BE: Charset = Charset(Encoding::Utf16Be);
+    pub const UTF_16LE: Charset = Charset(Encoding::Utf16Le);
+}
+
+const ALL: [(Charset, &str, &[&str]); 6] = [
+    (
+        StandardCharsets::US_ASCII,
+        "US-ASCII",
+        &[
+            "iso-ir-6",
+            "ANSI_X3.4-1986",
+            "ISO_646.irv:1991",
+            "ASCII",
+            "ISO646-US",
+            "us",
+            "IBM367",
+            "cp367",
+            "csASCII",
+            "default",
+            "646",
+            "iso_646.irv:1983",
+            "ANSI_X3.4-1968",
+            "ascii7",
+        ],
+    ),
+    (
+        StandardCharsets::ISO_8859_1,
+        "ISO-8859-1",
+        &[
+            "iso-ir-100",
+            "ISO_8859-1",
+            "latin1",
+            "l1",
+            "IBM819",
+            "cp819",
+            "csISOLatin1",
+            "819",
+            "IBM-819",
+            "ISO8859_1",
+            "ISO_8859-1:1987",
+            "ISO_8859_1",
+            "8859_1",
+            "ISO8859-1",
+        ],
+    ),
+    (
+        StandardCharsets::UTF_8,
+        "UTF-8",
+        &["UTF8", "unicode-1-1-utf-8"],
+    ),
+    (
+        StandardCharsets::UTF_16,
+        "UTF-16",
+        &["UTF_16", "utf16", "unicode", "UnicodeBig"],
+    ),
+    (
+        StandardCharsets::UTF_16BE,
+        "UTF-16BE",
+        &[
+            "UTF_16BE",
+            "ISO-10646-UCS-2",
+            "X-UTF-16BE",
+            "UnicodeBigUnmarked",
+        ],
+    ),
+    (
+        StandardCharsets::UTF_16LE,
+        "UTF-16LE",
+        &["UTF_16LE", "X-UTF-16LE", "UnicodeLittleUnmarked"],
+    ),
+];
+
+/// Java's rule for charset names: a letter or digit followed by letters, digits and
+/// `-+:_.`.
+fn is_legal_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|ch| ch.is_ascii_alphanumeric())
+        && chars.all(|ch| ch.is_ascii_alphanumeric() || "-+:_.".contains(ch))
+}
+
+impl Charset {
+    /// Looks a charset up by its canonical name or one of the JDK's aliases, ignoring case.
+    /// Throws `IllegalCharsetNameException` if `name` is not a legal charset name at all.
+    pub fn for_name(name: &str) -> Result<Self, UnsupportedCharsetException> {
+        if !is_legal_name(name) {
+            IllegalCharsetNameException::new(name.to_string()).throw();
+        }
+        ALL.iter()
+            .find(|(_, canonical, aliases)| {
+                canonical.eq_ignore_ascii_case(name)
+                    || aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name))
+            })
+            .map(|&(charset, _, _)| charset)
+            .ok_or_else(|| UnsupportedCharsetException::new(name.to_string()))
+    }
+
+    /// UTF-8, the default on every platform since JDK 18.
+    pub fn default_charset() -> Self {
+        StandardCharsets::UTF_8
+    }
+
+    /// The canonical name, such as `"UTF-8"`.
+    pub fn name(&self) -> &'static str {
+        ALL.iter()
+            .find(|(charset, _, _)| charset == self)
+            .map(|(_, name, _)| *name)
+            .unwrap()
+    }
+
+    pub fn aliases(&self) -> &'static [&'static str] {
+        ALL.iter()
+            .find(|(charset, _, _)| charset == self)
+            .map(|(_, _, aliases)| *aliases)
+            .unwrap()
+    }
+
+    /// Encodes `s`, replacing characters the charset cannot represent with `?`.
+    pub fn encode(&self, s: &str) -> Vec<u8> {
+        let limited = |max: char| {
+            s.chars()
+                .map(|ch| if ch <= max { ch as u8 } else { b'?' })
+                .collect()
+        };
+        match self.0 {
+            Encoding::UsAscii => limited('\x7F'),
+            Encoding::Iso8859_1 => limited('\u{FF}'),
+            Encoding::Utf8 => s.as_bytes().to_vec(),
+            Encoding::Utf16 => [0xFE, 0xFF]
+                .into_iter()
+                .chain(s.encode_utf16().flat_map(u16::to_be_bytes))
+                .collect(),
+            Encoding::Utf16Be => s.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+            Encoding::Utf16Le => s.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+        }
+    }
+
+    /// Decodes `bytes`, replacing malformed input with U+FFFD exactly where the JDK's
+    /// decoders would.
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match self.0 {
+            Encoding::UsAscii => bytes
+                .iter()
+                .map(|&b| if b < 0x80 { b as char } else { REPLACEMENT })
+                .collect(),
+            Encoding::Iso8859_1 => bytes.iter().map(|&b| b as char).collect(),
+            Encoding::Utf8 => decode_utf8(bytes),
+            Encoding::Utf16 => match bytes {
+                [0xFE, 0xFF, rest @ ..] => decode_utf16(rest, false),
+                [0xFF, 0xFE, rest @ ..] => decode_utf16(rest, true),
+                _ => decode_utf16(bytes, false),
+            },
+            Encoding::Utf16Be => decode_utf16(bytes, false),
+            Encoding::Utf16Le => decode_utf16(bytes, true),
+        }
+    }
+}
+
+impl Display for Charset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn is_continuation(b: u8) -> bool {
+    b & 0xC0 == 0x80
+}
+
+/// How many bytes of a malformed sequence starting at `bytes[0]` one replacement covers,
+/// following `sun.nio.cs.UTF_8`; `None` means the input ends inside a sequence that might
+/// still have been valid.
+fn malformed_length(bytes: &[u8]) -> Option<usize> {
+    let b1 = bytes[0];
+    let b2 = bytes.get(1).copied();
+    match b1 {
+        0xC2..=0xDF => b2.map(|_| 1),
+        0xE0..=0xEF => {
+            let bad_second = |b2: u8| (b1 == 0xE0 && b2 & 0xE0 == 0x80) || !is_continuation(b2);
+            match (b2, bytes.get(2)) {
+                (Some(b2), _) if bad_second(b2) => Some(1),
+                (Some(_), Some(_)) => Some(2),
+                _ => None,
+            }
+        }
+        0xF0..=0xF4 => {
+            let bad_second = |b2: u8| {
+                (b1 == 0xF0 && !(0x90..=0xBF).contains(&b2))
+                    || (b1 == 0xF4 && b2 & 0xF0 != 0x80)
+                    || !is_continuation(b2)
+            };
+            match (b2, bytes.get(2), bytes.get(3)) {
+                (Some(b2), _, _) if bad_second(b2) => Some(1),
+                (Some(_), Some(&b3), _) if !is_continuation(b3) => Some(2),
+                (Some(_), Some(_), Some(_)) => Some(3),
+                _ => None,
+            }
+        }
+        _ => Some(1),
+    }
+}
+
+fn decode_utf8(bytes: &[u8]) -> String {
+    let mut decoded = String::with_capacity(bytes.len());
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                decoded.push_str(valid);
+                break;
+            }
+            Err(e) => {
+                let (valid, invalid) = rest.split_at(e.valid_up_to());
+                decoded.push_str(std::str::from_utf8(valid).unwrap());
+                decoded.push(REPLACEMENT);
+                // An encoded surrogate is well-formed enough for Java to replace it whole.
+                let surrogate =
+                    matches!(invalid, [0xED, 0xA0..=0xBF, b3, ..] if is_continuation(*b3));
+                let length = if surrogate {
+                    3
+                } else {
+                    malformed_length(invalid).unwrap_or(invalid.len())
+                };
+                rest = &invalid[length..];
+            }
+        }
+    }
+    decoded
+}
+
+/// Decodes UTF-16 like `sun.nio.cs.UnicodeDecoder`: a high surrogate followed by anything
+/// but a low surrogate is replaced together with that unit, and a trailing odd byte or
+/// unpaired high surrogate by one replacement.
+fn decode_utf16(bytes: &[u8], little_endian: bool) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            let pair = [pair[0], pair[1]];
+            if little_endian {
+                u16::from_le_bytes(pair)
+            } else {
+                u16::from_be_bytes(pair)
+            }
+        })
+        .collect();
+    let mut decoded = String::with_capacity(units.len());
+    let mut i = 0;
+    while i < units.len() {
+        let unit = units[i];
+        match unit {
+            0xD800..=0xDBFF => match units.get(i + 1) {
+                Some(&low @ 0xDC00..=0xDFFF) => {
+                    let code_point =
+                        0x10000 + ((unit as u32 - 0xD800) << 10) + (low as u32 - 0xDC00);
+                    decoded.push(char::from_u32(code_point).unwrap());
+                    i += 2;
+                }
+                Some(_) => {
+                    decoded.push(REPLACEMENT);
+                    i += 2;
+                }
+                None => {
+                    decoded.push(REPLACEMENT);
+                    return decoded;
+                }
+            },
+            0xDC00..=0xDFFF => {
+                decoded.push(REPLACEMENT);
+                i += 1;
+            }
+            _ => {
+                decoded.push(char::from_u32(unit as u32).unwrap());
+                i += 1;
+            }
+        }
+    }
+    if bytes.len() % 2 == 1 {
+        decoded.push(REPLACEMENT);
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Charset, StandardCharsets};
+
+    #[test]
+    fn round_trips_match_jdk() {
+        let s = "Aé€😀";
+        for (charset, bytes, decoded) in [
+            (StandardCharsets::US_ASCII, &b"A???"[..], "A???"),
+            (StandardCharsets::ISO_8859_1, &b"A\xE9??"[..], "Aé??"),
+            (StandardCharsets::UTF_8, s.as_bytes(), s),
+            (
+                StandardCharsets::UTF_16,
+                &[
+                    0xFE, 0xFF, 0, 0x41, 0, 0xE9, 0x20, 0xAC, 0xD8, 0x3D, 0xDE, 0,
+                ][..],
+                s,
+            ),
+            (
+                StandardCharsets::UTF_16BE,
+                &[0, 0x41, 0, 0xE9, 0x20, 0xAC, 0xD8, 0x3D, 0xDE, 0][..],
+                s,
+            ),
+            (
+                StandardCharsets::UTF_16LE,
+                &[0x41, 0, 0xE9, 0, 0xAC, 0x20, 0x3D, 0xD8, 0, 0xDE][..],
+                s,
+            ),
+        ] {
+            assert_eq!(charset.encode(s), bytes, "{charset}");
+            assert_eq!(charset.decode(bytes), decoded, "{charset}");
+        }
+    }
+
+    #[test]
+    fn malformed_input_matches_jdk() {
+        let utf8 = StandardCharsets::UTF_8;
+        assert_eq!(utf8.decode(b"\xFFa"), "\u{FFFD}a");
+        assert_eq!(utf8.decode(b"\xE2\x82a"), "\u{FFFD}a");
+        assert_eq!(utf8.decode(b"\xF0\x9F\x98"), "\u{FFFD}");
+        assert_eq!(utf8.decode(b"\xC0\x80"), "\u{FFFD}\u{FFFD}");
+        assert_eq!(utf8.decode(b"\xED\xA0\x80"), "\u{FFFD}");
+        assert_eq!(utf8.decode(b"\xF4\x90\x80\x80"), "\u{FFFD}".repeat(4));
+        assert_eq!(
+            StandardCharsets::US_ASCII.decode(b"\xE2\x82a"),
+            "\u{FFFD}\u{FFFD}a"
+        );
+
+        let utf16 = StandardCharsets::UTF_16;
+        assert_eq!(utf16.decode(&[0xFF, 0xFE, 0x61, 0]), "a");
+        assert_eq!(utf16.decode(&[0, 0x61, 0]), "a\u{FFFD}");
+        assert_eq!(utf16.decode(&[0xD8, 0, 0, 0x61]), "\u{FFFD}");
+        assert_eq!(utf16.decode(&[0xFE, 0xFF]), "");
+        assert_eq!(
+            StandardCharsets::UTF_16BE.decode(&[0xFE, 0xFF, 0, 0x61]),
+            "\u{FEFF}a"
+        );
+        assert_eq!(
+            StandardCharsets::UTF_16LE.decode(&[0xD8, 0, 0, 0x61]),
+            "\u{D8}\u{6100}"
+        );
+    }
+
+    #[test]
+    fn names_match_jdk() {
+        for (name, canonical) in [
+            ("utf8", "UTF-8"),
+            ("latin1", "ISO-8859-1"),
+            ("646", "US-ASCII"),
+            ("utf-16le", "UTF-16LE"),
+            ("UnicodeBig", "UTF-16"),
+        ] {
+            assert_eq!(Charset::for_name(name).unwrap().name(), canonical);
+        }
+        assert_eq!(Charset::for_name("UTF_8").unwrap_err().to_string(), "UTF_8");
+    }
+
+    #[test]
+    #[should_panic(expected = "bad name!")]
+    fn illegal_charset_name() {
+        let _ = Charset::for_name("bad name!");
+    }
+}
@@ -0,0 +1,341 @@
+//! Java's `java.lang.ConditionalSpecialCasing`: the context- and language-sensitive case
+//! mappings of SpecialCasing.txt used by `to_upper_case` and `to_lower_case` on strings.
+
+use crate::character::{self, get_combining_class, is_high_surrogate, is_low_surrogate};
+use crate::util::Locale;
+
+const COMBINING_CLASS_ABOVE: u8 = 230;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Condition {
+    Always,
+    FinalCased,
+    AfterSoftDotted,
+    MoreAbove,
+    AfterI,
+    NotBeforeDot,
+}
+
+struct Entry {
+    code_point: i32,
+    lower: &'static [u16],
+    upper: &'static [u16],
+    language: Option<&'static str>,
+    condition: Condition,
+}
+
+const fn entry(
+    code_point: i32,
+    lower: &'static [u16],
+    upper: &'static [u16],
+    language: Option<&'static str>,
+    condition: Condition,
+) -> Entry {
+    Entry {
+        code_point,
+        lower,
+        upper,
+        language,
+        condition,
+    }
+}
+
+#[rustfmt::skip]
+static ENTRIES: [Entry; 17] = [
+    // Final sigma
+    entry(0x03A3, &[0x03C2], &[0x03A3], None, Condition::FinalCased),
+    entry(0x0130, &[0x0069, 0x0307], &[0x0130], None, Condition::Always),
+    // Lithuanian
+    entry(0x0307, &[0x0307], &[], Some("lt"), Condition::AfterSoftDotted),
+    entry(0x0049, &[0x0069, 0x0307], &[0x0049], Some("lt"), Condition::MoreAbove),
+    entry(0x004A, &[0x006A, 0x0307], &[0x004A], Some("lt"), Condition::MoreAbove),
+    entry(0x012E, &[0x012F, 0x0307], &[0x012E], Some("lt"), Condition::MoreAbove),
+    entry(0x00CC, &[0x0069, 0x0307, 0x0300], &[0x00CC], Some("lt"), Condition::Always),
+    entry(0x00CD, &[0x0069, 0x0307, 0x0301], &[0x00CD], Some("lt"), Condition::Always),
+    entry(0x0128, &[0x0069, 0x0307, 0x0303], &[0x0128], Some("lt"), Condition::Always),
+    // Turkish and Azeri
+    entry(0x0130, &[0x0069], &[0x0130], Some("tr"), Condition::Always),
+    entry(0x0130, &[0x0069], &[0x0130], Some("az"), Condition::Always),
+    entry(0x0307, &[], &[0x0307], Some("tr"), Condition::AfterI),
+    entry(0x0307, &[], &[0x0307], Some("az"), Condition::AfterI),
+    entry(0x0049, &[0x0131], &[0x0049], Some("tr"), Condition::NotBeforeDot),
+    entry(0x0049, &[0x0131], &[0x0049], Some("az"), Condition::NotBeforeDot),
+    entry(0x0069, &[0x0069], &[0x0130], Some("tr"), Condition::Always),
+    entry(0x0069, &[0x0069], &[0x0130], Some("az"), Condition::Always),
+];
+
+fn code_point_at(units: &[u16], index: usize) -> i32 {
+    let unit = units[index];
+    match units.get(index + 1) {
+        Some(&low) if is_high_surrogate(unit) && is_low_surrogate(low) => {
+            character::to_code_point(unit, low)
+        }
+        _ => unit as i32,
+    }
+}
+
+fn code_point_before(units: &[u16], index: usize) -> i32 {
+    let unit = units[index - 1];
+    if is_low_surrogate(unit) && index > 1 && is_high_surrogate(units[index - 2]) {
+        character::to_code_point(units[index - 2], unit)
+    } else {
+        unit as i32
+    }
+}
+
+/// Whether a character counts as cased for the final sigma rule, including the
+/// Other_Lowercase and Other_Uppercase ranges listed in the JDK.
+fn is_cased(ch: i32) -> bool {
+    matches!(
+        character::get_type(ch),
+        character::LOWERCASE_LETTER | character::UPPERCASE_LETTER | character::TITLECASE_LETTER
+    ) || matches!(
+        ch,
+        0x02B0..=0x02B8
+            | 0x02C0..=0x02C1
+            | 0x02E0..=0x02E4
+            | 0x0345
+            | 0x037A
+            | 0x1D2C..=0x1D61
+            | 0x2160..=0x217F
+            | 0x24B6..=0x24E9
+    )
+}
+
+fn is_soft_dotted(ch: i32) -> bool {
+    matches!(
+        ch,
+        0x0069 | 0x006A | 0x012F | 0x0268 | 0x0456 | 0x0458 | 0x1E2D | 0x1ECB
+    )
+}
+
+/// Marks and format characters, which the word break rules attach to whatever precedes them.
+fn is_ignorable(ch: i32) -> bool {
+    ch != 0x00AD
+        && matches!(
+            character::get_type(ch),
+            character::NON_SPACING_MARK | character::ENCLOSING_MARK | character::FORMAT
+        )
+}
+
+/// Letters other than the kanji, katakana and hiragana that the JDK's rules break apart.
+fn is_word_letter(ch: i32) -> bool {
+    let is_cjk = matches!(
+        ch,
+        0x3005 | 0x3041..=0x309E | 0x30A1..=0x30FE | 0x4E00..=0x9FA5 | 0xF900..=0xFA2D
+    );
+    (character::is_letter(ch) || character::get_type(ch) == character::COMBINING_SPACING_MARK)
+        && !is_cjk
+}
+
+fn is_number(ch: i32) -> bool {
+    matches!(
+        character::get_type(ch),
+        character::DECIMAL_DIGIT_NUMBER | character::LETTER_NUMBER | character::OTHER_NUMBER
+    )
+}
+
+/// Punctuation that does not break a word when it has letters on both sides.
+fn is_mid_word(ch: i32) -> bool {
+    character::get_type(ch) == character::DASH_PUNCTUATION
+        || matches!(ch, 0x0022 | 0x0027 | 0x002E | 0x005F | 0x00AD | 0x2027)
+}
+
+/// Punctuation that does not break a number when it has digits on both sides.
+fn is_mid_number(ch: i32) -> bool {
+    matches!(ch, 0x0022 | 0x0027 | 0x002C | 0x002E | 0x066B)
+}
+
+/// The last character before `index` that is not ignorable, and the index it starts at.
+fn base_before(units: &[u16], mut index: usize) -> Option<(i32, usize)> {
+    while index > 0 {
+        let ch = code_point_before(units, index);
+        index -= character::char_count(ch);
+        if !is_ignorable(ch) {
+            return Some((ch, index));
+        }
+    }
+    None
+}
+
+/// The first character at or after `index` that is not a format character; a mark here
+/// follows punctuation, so it is not skipped.
+fn base_at(units: &[u16], mut index: usize) -> Option<i32> {
+    while index < units.len() {
+        let ch = code_point_at(units, index);
+        if character::get_type(ch) != character::FORMAT {
+            return Some(ch);
+        }
+        index += character::char_count(ch);
+    }
+    None
+}
+
+/// Whether a word `BreakIterator` would put a boundary at `index`, following the JDK's
+/// word rules closely enough for the final sigma condition: letters and numbers run
+/// together, and some punctuation joins letters to letters or digits to digits.
+fn is_word_boundary(units: &[u16], index: usize) -> bool {
+    if index == 0 || index >= units.len() {
+        return true;
+    }
+    // The JDK asks its iterator for the boundary following `index - 1`, which for a
+    // surrogate pair ending at `index` starts between the halves. Read on their own they
+    // join nothing, so the iterator stops after the pair, unless the pair starts the text
+    // and it reads forward from the start instead.
+    if index > 2 && is_low_surrogate(units[index - 1]) && is_high_surrogate(units[index - 2]) {
+        return true;
+    }
+    let after = code_point_at(units, index);
+    if is_ignorable(after) {
+        return false;
+    }
+    let Some((before, before_index)) = base_before(units, index) else {
+        return true;
+    };
+    let is_word_part = |ch| is_word_letter(ch) || is_number(ch);
+    if is_word_part(before) && is_word_part(after) {
+        return false;
+    }
+    for (is_mid, is_part) in [
+        (
+            is_mid_word as fn(i32) -> bool,
+            is_word_letter as fn(i32) -> bool,
+        ),
+        (is_mid_number, is_number),
+    ] {
+        if is_mid(after) && is_part(before) {
+            let next = index + character::char_count(after);
+            if base_at(units, next).is_some_and(is_part) {
+                return false;
+            }
+        }
+        // Marks attach to the punctuation, which then no longer joins anything.
+        let only_format_between = base_at(units, before_index + character::char_count(before))
+            .is_some_and(|ch| ch == after);
+        if is_mid(before)
+            && is_part(after)
+            && only_format_between
+            && base_before(units, before_index).is_some_and(|(ch, _)| is_part(ch))
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Whether the sigma at `index` ends a word that has a cased letter before it and none
+/// after it, with words found as Java's word `BreakIterator` would.
+fn is_final_cased(units: &[u16], index: usize) -> bool {
+    let mut i = index;
+    while !is_word_boundary(units, i) {
+        let ch = code_point_before(units, i);
+        if is_cased(ch) {
+            let mut i = index + character::char_count(code_point_at(units, index));
+            while i < units.len() && !is_word_boundary(units, i) {
+                let ch = code_point_at(units, i);
+                if is_cased(ch) {
+                    return false;
+                }
+                i += character::char_count(ch);
+            }
+            return true;
+        }
+        i -= character::char_count(ch);
+    }
+    false
+}
+
+/// Looks back from `index`, through combining marks that are neither starters nor above
+/// the base, for a character matching `found`.
+fn is_after(units: &[u16], index: usize, found: impl Fn(i32) -> bool) -> bool {
+    let mut i = index;
+    while i > 0 {
+        let ch = code_point_before(units, i);
+        if found(ch) {
+            return true;
+        }
+        let combining_class = get_combining_class(ch);
+        if combining_class == 0 || combining_class == COMBINING_CLASS_ABOVE {
+            return false;
+        }
+        i -= character::char_count(ch);
+    }
+    false
+}
+
+/// Looks ahead from the character at `index` for a combining mark matching `found`,
+/// stopping at the next starter.
+fn is_before(units: &[u16], index: usize, found: impl Fn(i32, u8) -> bool) -> bool {
+    let mut i = index + character::char_count(code_point_at(units, index));
+    while i < units.len() {
+        let ch = code_point_at(units, i);
+        let combining_class = get_combining_class(ch);
+        if found(ch, combining_class) {
+            return true;
+        }
+        if combining_class == 0 || combining_class == COMBINING_CLASS_ABOVE {
+            return false;
+        }
+        i += character::char_count(ch);
+    }
+    false
+}
+
+fn is_condition_met(units: &[u16], index: usize, condition: Condition) -> bool {
+    match condition {
+        Condition::Always => true,
+        Condition::FinalCased => is_final_cased(units, index),
+        Condition::AfterSoftDotted => is_after(units, index, is_soft_dotted),
+        Condition::MoreAbove => is_before(units, index, |_, class| class == COMBINING_CLASS_ABOVE),
+        Condition::AfterI => is_after(units, index, |ch| ch == 'I' as i32),
+        Condition::NotBeforeDot => !is_before(units, index, |ch, _| ch == 0x0307),
+    }
+}
+
+/// The special mapping of the character at `index`, if one applies. Entries for the
+/// locale's language take precedence over those for every language.
+fn look_up(units: &[u16], index: usize, language: &str, lower: bool) -> Option<&'static [u16]> {
+    let code_point = code_point_at(units, index);
+    let mut result = None;
+    for entry in ENTRIES
+        .iter()
+        .filter(|entry| entry.code_point == code_point)
+    {
+        if entry
+            .language
+            .is_none_or(|entry_language| entry_language == language)
+            && is_condition_met(units, index, entry.condition)
+        {
+            result = Some(if lower { entry.lower } else { entry.upper });
+            if entry.language.is_some() {
+                break;
+            }
+        }
+    }
+    result
+}
+
+fn map(units: &[u16], locale: &Locale, lower: bool) -> String {
+    let mut mapped = Vec::with_capacity(units.len());
+    let mut i = 0;
+    while i < units.len() {
+        let code_point = code_point_at(units, i);
+        match look_up(units, i, locale.get_language(), lower) {
+            Some(special) => mapped.extend_from_slice(special),
+            None if lower => {
+                mapped.extend(character::to_chars(character::to_lower_case(code_point)))
+            }
+            None => mapped.extend(character::to_upper_case_char_array(code_point)),
+        }
+        i += character::char_count(code_point);
+    }
+    String::from_utf16_lossy(&mapped)
+}
+
+pub(crate) fn to_lower_case(units: &[u16], locale: &Locale) -> String {
+    map(units, locale, true)
+}
+
+pub(crate) fn to_upper_case(units: &[u16], locale: &Locale) -> String {
+    map(units, locale, false)
+}
@@ -0,0 +1,416 @@
+use crate::errors::{IllegalArgumentException, Throwable};
+use crate::util::Locale;
+
+use super::tables;
+
+/// The state machines of one kind of `sun.text.RuleBasedBreakIterator`: each row of a
+/// state table holds the next state for every character category.
+pub(super) struct Rules {
+    pub(super) categories: &'static [(u32, u32, i8)],
+    pub(super) num_categories: usize,
+    pub(super) state_table: &'static [i16],
+    pub(super) backwards_state_table: &'static [i16],
+    pub(super) end_states: &'static [bool],
+    pub(super) lookahead_states: &'static [bool],
+}
+
+/// A category for characters that never change the state.
+const IGNORE: i8 = -1;
+const START_STATE: usize = 1;
+const STOP_STATE: usize = 0;
+/// `CharacterIterator.DONE`, which Java also gets for a U+FFFF in the text and so stops
+/// there like it does at the end.
+const DONE: u32 = 0xFFFF;
+
+impl Rules {
+    fn category(&self, c: u32) -> i8 {
+        let index = self.categories.partition_point(|&(_, end, _)| end < c);
+        self.categories[index].2
+    }
+
+    fn next_state(table: &[i16], state: usize, category: i8, num_categories: usize) -> usize {
+        table[state * num_categories + category as usize] as usize
+    }
+}
+
+/// `java.text.BreakIterator`: finds character, word, line or sentence boundaries in a
+/// text, with the JDK's rules.
+///
+/// Boundaries are byte offsets into the text, so the pieces between them can be sliced
+/// out directly. Offsets passed in must be on `char` boundaries.
+#[derive(Clone)]
+pub struct BreakIterator<'t> {
+    rules: &'static Rules,
+    text: &'t str,
+    /// The current position.
+    index: usize,
+    /// The boundary found by the last `following` or `previous`, which the next one can
+    /// resume from instead of scanning backwards.
+    cached_last_known_break: Option<usize>,
+}
+
+impl std::fmt::Debug for BreakIterator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BreakIterator")
+            .field("text", &self.text)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl BreakIterator<'static> {
+    fn new(rules: &'static Rules) -> Self {
+        Self {
+            rules,
+            text: "",
+            index: 0,
+            cached_last_known_break: None,
+        }
+    }
+
+    /// Finds the boundaries between user-perceived characters: a base character and its
+    /// combining marks, a Hangul syllable or a surrogate pair stay together.
+    ///
+    /// The JDK uses the same rules for every locale; the locale is accepted for parity with
+    /// `BreakIterator.getCharacterInstance(Locale)`.
+    pub fn get_character_instance(_locale: &Locale) -> Self {
+        Self::new(&tables::CHARACTER)
+    }
+
+    /// Finds the boundaries of words, with punctuation and each run of whitespace between
+    /// them as pieces of their own.
+    ///
+    /// The JDK uses the same rules for every locale except Thai, whose dictionary-based
+    /// iterator is not available here; Thai text gets the default rules.
+    pub fn get_word_instance(_locale: &Locale) -> Self {
+        Self::new(&tables::WORD)
+    }
+
+    /// Finds the places where a line may be wrapped.
+    ///
+    /// The JDK uses the same rules for every locale except Thai, whose dictionary-based
+    /// iterator is not available here; Thai text gets the default rules.
+    pub fn get_line_instance(_locale: &Locale) -> Self {
+        Self::new(&tables::LINE)
+    }
+
+    /// Finds the boundaries of sentences.
+    ///
+    /// The JDK uses the same rules for every locale; the locale is accepted for parity with
+    /// `BreakIterator.getSentenceInstance(Locale)`.
+    pub fn get_sentence_instance(_locale: &Locale) -> Self {
+        Self::new(&tables::SENTENCE)
+    }
+}
+
+impl<'t> BreakIterator<'t> {
+    /// Sets the text to analyze and moves to its start.
+    pub fn set_text(&mut self, new_text: &'t str) {
+        self.text = new_text;
+        self.index = 0;
+        self.cached_last_known_break = None;
+    }
+
+    pub fn get_text(&self) -> &'t str {
+        self.text
+    }
+
+    /// Moves to the start of the text, which is always a boundary.
+    pub fn first(&mut self) -> usize {
+        self.index = 0;
+        self.index
+    }
+
+    /// Moves to the end of the text, which is always a boundary.
+    pub fn last(&mut self) -> usize {
+        self.index = self.text.len();
+        self.index
+    }
+
+    /// The boundary last returned.
+    pub fn current(&self) -> usize {
+        self.index
+    }
+
+    /// Moves to the next boundary, or returns `None` at the end of the text.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<usize> {
+        self.handle_next()
+    }
+
+    /// Moves `n` boundaries forward, or backward if `n` is negative, and returns the last
+    /// one reached, or `None` if it ran off either end.
+    pub fn next_by(&mut self, mut n: i32) -> Option<usize> {
+        let mut result = Some(self.current());
+        while n > 0 {
+            result = self.handle_next();
+            n -= 1;
+        }
+        while n < 0 {
+            result = self.previous();
+            n += 1;
+        }
+        result
+    }
+
+    /// Moves to the previous boundary, or returns `None` at the start of the text.
+    pub fn previous(&mut self) -> Option<usize> {
+        if self.current() == 0 {
+            return None;
+        }
+
+        let old_offset = self.current();
+        let mut last_result = match self.cached_last_known_break {
+            Some(cached) if cached < old_offset => {
+                self.index = cached;
+                cached
+            }
+            _ => {
+                self.get_previous();
+                self.handle_previous()
+            }
+        };
+        let mut result = Some(last_result);
+        while let Some(offset) = result.filter(|&offset| offset < old_offset) {
+            last_result = offset;
+            result = self.handle_next();
+        }
+        self.index = last_result;
+        self.cached_last_known_break = Some(last_result);
+        Some(last_result)
+    }
+
+    /// Moves to the first boundary after `offset`, or returns `None` if there is none.
+    /// Throws `IllegalArgumentException` if `offset` is outside the text.
+    pub fn following(&mut self, offset: usize) -> Option<usize> {
+        self.check_offset(offset);
+        self.index = offset;
+        if offset == 0 {
+            self.cached_last_known_break = self.handle_next();
+            return self.cached_last_known_break;
+        }
+
+        let mut result = match self.cached_last_known_break {
+            Some(cached) if cached < offset => {
+                self.index = cached;
+                Some(cached)
+            }
+            _ => Some(self.handle_previous()),
+        };
+        while result.is_some_and(|boundary| boundary <= offset) {
+            result = self.handle_next();
+        }
+        self.cached_last_known_break = result;
+        result
+    }
+
+    /// Moves to the last boundary before `offset`, or returns `None` if there is none.
+    /// Throws `IllegalArgumentException` if `offset` is outside the text.
+    pub fn preceding(&mut self, offset: usize) -> Option<usize> {
+        self.check_offset(offset);
+        self.index = offset;
+        self.previous()
+    }
+
+    /// Whether `offset` is a boundary; also moves to the first boundary after the
+    /// character before it. Throws `IllegalArgumentException` if `offset` is outside the
+    /// text.
+    ///
+    /// Java starts from the UTF-16 unit before `offset`, which lands inside a
+    /// supplementary character that precedes it; this starts from the whole character.
+    pub fn is_boundary(&mut self, offset: usize) -> bool {
+        self.check_offset(offset);
+        if offset == 0 {
+            return true;
+        }
+        let before = self.text[..offset]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(index, _)| index);
+        self.following(before) == Some(offset)
+    }
+
+    fn check_offset(&self, offset: usize) {
+        if offset > self.text.len() || !self.text.is_char_boundary(offset) {
+            IllegalArgumentException::new("offset out of bounds".to_string(), None).throw();
+        }
+    }
+
+    /// The character at the current position, or `DONE` at the end.
+    fn get_current(&self) -> u32 {
+        self.text[self.index..]
+            .chars()
+            .next()
+            .map_or(DONE, u32::from)
+    }
+
+    /// Moves to the next character and returns it, or returns `DONE` without moving if
+    /// there is none.
+    fn get_next(&mut self) -> u32 {
+        let next = self.get_next_index();
+        if self.index == self.text.len() || next >= self.text.len() {
+            return DONE;
+        }
+        self.index = next;
+        self.get_current()
+    }
+
+    fn get_next_index(&self) -> usize {
+        self.text[self.index..]
+            .chars()
+            .next()
+            .map_or(self.index, |ch| self.index + ch.len_utf8())
+    }
+
+    /// Moves to the previous character and returns it, or returns `DONE` without moving if
+    /// there is none.
+    fn get_previous(&mut self) -> u32 {
+        match self.text[..self.index].chars().next_back() {
+            Some(ch) => {
+                self.index -= ch.len_utf8();
+                ch as u32
+            }
+            None => DONE,
+        }
+    }
+
+    /// Runs the forward state machine from the current position to the next boundary.
+    fn handle_next(&mut self) -> Option<usize> {
+        if self.index == self.text.len() {
+            return None;
+        }
+
+        let rules = self.rules;
+        let mut result = self.get_next_index();
+        let mut lookahead_result = 0;
+        let mut state = START_STATE;
+        let mut c = self.get_current();
+        while c != DONE && state != STOP_STATE {
+            let category = rules.category(c);
+            if category != IGNORE {
+                state = Rules::next_state(rules.state_table, state, category, rules.num_categories);
+            }
+            if rules.lookahead_states[state] {
+                if rules.end_states[state] {
+                    result = lookahead_result;
+                } else {
+                    lookahead_result = self.get_next_index();
+                }
+            } else if rules.end_states[state] {
+                result = self.get_next_index();
+            }
+            c = self.get_next();
+        }
+
+        if c == DONE && lookahead_result == self.text.len() {
+            result = lookahead_result;
+        }
+        self.index = result;
+        Some(result)
+    }
+
+    /// Runs the backward state machine from the current position to a position from which
+    /// `handle_next` is certain to find the correct boundaries.
+    fn handle_previous(&mut self) -> usize {
+        let rules = self.rules;
+        let mut state = START_STATE;
+        let mut category = 0;
+        let mut last_category = 0;
+        let mut c = self.get_current();
+        while c != DONE && state != STOP_STATE {
+            last_category = category;
+            category = rules.category(c);
+            if category != IGNORE {
+                state = Rules::next_state(
+                    rules.backwards_state_table,
+                    state,
+                    category,
+                    rules.num_categories,
+                );
+            }
+            c = self.get_previous();
+        }
+
+        if c != DONE {
+            if last_category != IGNORE {
+                self.get_next();
+                self.get_next();
+            } else {
+                self.get_next();
+            }
+        }
+        self.index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BreakIterator;
+    use crate::util::Locale;
+
+    fn pieces<'t>(mut iterator: BreakIterator<'t>, text: &'t str) -> Vec<&'t str> {
+        iterator.set_text(text);
+        let mut start = iterator.first();
+        let mut pieces = Vec::new();
+        while let Some(end) = iterator.next() {
+            pieces.push(&text[start..end]);
+            start = end;
+        }
+        pieces
+    }
+
+    #[test]
+    fn breaks_like_jdk() {
+        let text = "Hello, world! It's 3.14 o'clock. Mr. Smith left.";
+        assert_eq!(
+            pieces(BreakIterator::get_word_instance(&Locale::US), text),
+            [
+                "Hello", ",", " ", "world", "!", " ", "It's", " ", "3.14", " ", "o'clock", ".",
+                " ", "Mr", ".", " ", "Smith", " ", "left", "."
+            ]
+        );
+        assert_eq!(
+            pieces(BreakIterator::get_sentence_instance(&Locale::US), text),
+            [
+                "Hello, world! ",
+                "It's 3.14 o'clock. ",
+                "Mr. ",
+                "Smith left."
+            ]
+        );
+        assert_eq!(
+            pieces(BreakIterator::get_line_instance(&Locale::US), text),
+            [
+                "Hello, ",
+                "world! ",
+                "It's ",
+                "3.14 ",
+                "o'clock. ",
+                "Mr. ",
+                "Smith ",
+                "left."
+            ]
+        );
+        assert_eq!(
+            pieces(
+                BreakIterator::get_character_instance(&Locale::US),
+                "e\u{301}한😀!"
+            ),
+            ["e\u{301}", "한", "😀", "!"]
+        );
+    }
+
+    #[test]
+    fn moves_between_boundaries() {
+        let mut iterator = BreakIterator::get_word_instance(&Locale::US);
+        iterator.set_text("one two");
+        assert_eq!(iterator.last(), 7);
+        assert_eq!(iterator.previous(), Some(4));
+        assert_eq!(iterator.following(1), Some(3));
+        assert_eq!(iterator.preceding(3), Some(0));
+        assert_eq!(iterator.previous(), None);
+        assert_eq!(iterator.next_by(2), Some(4));
+        assert!(iterator.is_boundary(3));
+        assert!(!iterator.is_boundary(5));
+    }
+}
@@ -0,0 +1,412 @@
+use crate::errors::{
+    ArrayIndexOutOfBoundsException, IllegalArgumentException, NumberFormatException,
+    ParseException, Throwable,
+};
+
+use super::ParsePosition;
+
+/// `java.text.ChoiceFormat`: picks a string by the range a number falls in, as in
+/// `0#no files|1#one file|1<{0} files`.
+///
+/// Each limit starts a range that runs up to the next one: `n#` includes `n` and `n<`
+/// excludes it. Numbers below the first limit get the first string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChoiceFormat {
+    choice_limits: Vec<f64>,
+    choice_formats: Vec<String>,
+}
+
+impl ChoiceFormat {
+    /// A format for a pattern of `limit#string` or `limit<string` choices separated by `|`,
+    /// where a limit is a number, `∞` or `-∞`, and `≤` can stand for `#`. Quotes escape
+    /// these characters in strings, and `''` is a quote.
+    ///
+    /// Panics with `IllegalArgumentException` if a choice has no limit or the limits are
+    /// not ascending, and `NumberFormatException` if a limit is not a number.
+    pub fn new(pattern: &str) -> Self {
+        let mut format = Self {
+            choice_limits: Vec::new(),
+            choice_formats: Vec::new(),
+        };
+        format.apply_pattern(pattern);
+        format
+    }
+
+    /// A format choosing `formats[i]` for numbers from `limits[i]`, which should be
+    /// ascending. Panics with `IllegalArgumentException` if the lengths differ.
+    pub fn with_limits<S: AsRef<str>>(limits: &[f64], formats: &[S]) -> Self {
+        let mut format = Self {
+            choice_limits: Vec::new(),
+            choice_formats: Vec::new(),
+        };
+        format.set_choices(limits, formats);
+        format
+    }
+
+    pub fn apply_pattern(&mut self, pattern: &str) {
+        match parse_choices(pattern) {
+            Ok((limits, formats)) => {
+                self.choice_limits = limits;
+                self.choice_formats = formats;
+            }
+            Err(PatternError::IllegalArgument(message)) => {
+                IllegalArgumentException::new(message.to_string(), None).throw()
+            }
+            Err(PatternError::NumberFormat(message)) => NumberFormatException::new(message).throw(),
+        }
+    }
+
+    /// A format for the pattern, or `None` where `new` would panic.
+    pub(super) fn checked(pattern: &str) -> Option<Self> {
+        parse_choices(pattern)
+            .ok()
+            .map(|(choice_limits, choice_formats)| Self {
+                choice_limits,
+                choice_formats,
+            })
+    }
+
+    /// The pattern of the choices, with each limit written as whichever of `n#` and `m<`
+    /// is closer to an integer.
+    pub fn to_pattern(&self) -> String {
+        let mut result = String::new();
+        for (i, (&limit, text)) in self
+            .choice_limits
+            .iter()
+            .zip(&self.choice_formats)
+            .enumerate()
+        {
+            if i != 0 {
+                result.push('|');
+            }
+            let less = Self::previous_double(limit);
+            let try_less_or_equal = ieee_remainder(limit).abs();
+            let try_less = ieee_remainder(less).abs();
+            if try_less_or_equal < try_less {
+                result.push_str(&double_to_string(limit));
+                result.push('#');
+            } else {
+                if limit == f64::INFINITY {
+                    result.push('∞');
+                } else if limit == f64::NEG_INFINITY {
+                    result.push_str("-∞");
+                } else {
+                    result.push_str(&double_to_string(less));
+                }
+                result.push('<');
+            }
+            let need_quote = text.contains(['<', '#', '≤', '|']);
+            if need_quote {
+                result.push('\'');
+            }
+            result.push_str(&text.replace('\'', "''"));
+            if need_quote {
+                result.push('\'');
+            }
+        }
+        result
+    }
+
+    /// Replaces the choices. Panics with `IllegalArgumentException` if the lengths differ.
+    pub fn set_choices<S: AsRef<str>>(&mut self, limits: &[f64], formats: &[S]) {
+        if limits.len() != formats.len() {
+            IllegalArgumentException::new(
+                "Array and limit arrays must be of the same length.".to_string(),
+                None,
+            )
+            .throw();
+        }
+        self.choice_limits = limits.to_vec();
+        self.choice_formats = formats
+            .iter()
+            .map(|format| format.as_ref().to_string())
+            .collect();
+    }
+
+    pub fn get_limits(&self) -> &[f64] {
+        &self.choice_limits
+    }
+
+    pub fn get_formats(&self) -> &[String] {
+        &self.choice_formats
+    }
+
+    /// The string of the last limit not above the number, or the first string if the
+    /// number is below every limit or NaN. Panics with `ArrayIndexOutOfBoundsException` if
+    /// there are no choices.
+    pub fn format<N: Into<f64>>(&self, number: N) -> String {
+        let number = number.into();
+        let index = self
+            .choice_limits
+            .iter()
+            .position(|&limit| number < limit || number.is_nan())
+            .unwrap_or(self.choice_limits.len());
+        let index = index.saturating_sub(1);
+        match self.choice_formats.get(index) {
+            Some(format) => format.clone(),
+            None => ArrayIndexOutOfBoundsException::new(
+                format!("Index {index} out of bounds for length 0"),
+                index as i32,
+            )
+            .throw(),
+        }
+    }
+
+    /// Parses the longest of the strings at the start of the text into its limit.
+    pub fn parse(&self, source: &str) -> Result<f64, ParseException> {
+        let mut pos = ParsePosition::new(0);
+        match self.parse_from(source, &mut pos) {
+            Some(number) => Ok(number),
+            None => Err(ParseException::new(
+                format!("Unparseable number: \"{source}\""),
+                pos.get_error_index().unwrap_or(0) as i32,
+            )),
+        }
+    }
+
+    /// Parses the longest of the strings at the position into its limit, advancing the
+    /// position past it, or setting its error index and returning `None` if none match.
+    pub fn parse_from(&self, text: &str, status: &mut ParsePosition) -> Option<f64> {
+        let start = status.get_index();
+        let mut furthest = start;
+        let mut best_number = f64::NAN;
+        let rest = text.get(start..).unwrap_or_default();
+        for (format, &limit) in self.choice_formats.iter().zip(&self.choice_limits) {
+            if rest.starts_with(format.as_str()) && start + format.len() > furthest {
+                furthest = start + format.len();
+                best_number = limit;
+                if furthest == text.len() {
+                    break;
+                }
+            }
+        }
+        status.set_index(furthest);
+        if furthest == start {
+            status.set_error_index(Some(furthest));
+            return None;
+        }
+        Some(best_number)
+    }
+
+    /// The least double greater than `d`, for turning `n<` into an inclusive limit.
+    pub fn next_double(d: f64) -> f64 {
+        d.next_up()
+    }
+
+    /// The greatest double less than `d`.
+    pub fn previous_double(d: f64) -> f64 {
+        d.next_down()
+    }
+}
+
+/// Why a choice pattern is invalid, as the exception `ChoiceFormat` would throw.
+enum PatternError {
+    IllegalArgument(&'static str),
+    NumberFormat(String),
+}
+
+/// The limits and strings of a choice pattern.
+fn parse_choices(pattern: &str) -> Result<(Vec<f64>, Vec<String>), PatternError> {
+    let mut segments = [String::new(), String::new()];
+    let mut limits = Vec::new();
+    let mut formats = Vec::new();
+    let mut part = 0;
+    let mut start_value = 0.0;
+    let mut old_start_value = f64::NAN;
+    let mut in_quote = false;
+
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.next_if_eq(&'\'').is_some() {
+                segments[part].push(c);
+            } else {
+                in_quote = !in_quote;
+            }
+        } else if in_quote {
+            segments[part].push(c);
+        } else if c == '<' || c == '#' || c == '≤' {
+            if segments[0].is_empty() {
+                return Err(PatternError::IllegalArgument(
+                    "Each interval must contain a number before a format",
+                ));
+            }
+            start_value = match segments[0].as_str() {
+                "∞" => f64::INFINITY,
+                "-∞" => f64::NEG_INFINITY,
+                limit => parse_double(limit).map_err(PatternError::NumberFormat)?,
+            };
+            if c == '<' && start_value.is_finite() {
+                start_value = ChoiceFormat::next_double(start_value);
+            }
+            if start_value <= old_start_value {
+                return Err(PatternError::IllegalArgument(
+                    "Incorrect order of intervals, must be in ascending order",
+                ));
+            }
+            segments[0].clear();
+            part = 1;
+        } else if c == '|' {
+            limits.push(start_value);
+            formats.push(std::mem::take(&mut segments[1]));
+            old_start_value = start_value;
+            part = 0;
+        } else {
+            segments[part].push(c);
+        }
+    }
+    if part == 1 {
+        limits.push(start_value);
+        formats.push(std::mem::take(&mut segments[1]));
+    }
+    Ok((limits, formats))
+}
+
+/// `Math.IEEEremainder(d, 1.0)`: the distance of `d` from the nearest integer, ties going
+/// to the even one.
+fn ieee_remainder(d: f64) -> f64 {
+    d - d.round_ties_even()
+}
+
+/// `Double.parseDouble`, which allows surrounding whitespace and a type suffix, or the
+/// message of its `NumberFormatException` if the text is not a number.
+fn parse_double(text: &str) -> Result<f64, String> {
+    let trimmed = text.trim_matches(|c| c <= ' ');
+    let number = trimmed
+        .strip_suffix(['d', 'D', 'f', 'F'])
+        .unwrap_or(trimmed);
+    let unsigned = number.strip_prefix(['+', '-']).unwrap_or(number);
+    let valid = matches!(unsigned, "Infinity" | "NaN")
+        || (unsigned
+            .bytes()
+            .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'+' | b'-'))
+            && unsigned.bytes().any(|b| b.is_ascii_digit()));
+    let parsed = match unsigned {
+        "Infinity" => Some(if number.starts_with('-') {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        }),
+        "NaN" => Some(f64::NAN),
+        _ => number.parse().ok(),
+    };
+    match parsed.filter(|_| valid) {
+        Some(number) => Ok(number),
+        None if trimmed.is_empty() => Err("empty String".to_string()),
+        None => Err(format!("For input string: \"{text}\"")),
+    }
+}
+
+/// `Double.toString`: the shortest digits that round-trip, plain from 10^-3 up to 10^7 and
+/// in computerized scientific notation like `1.0E10` outside that.
+pub(super) fn double_to_string(d: f64) -> String {
+    if d.is_nan() {
+        return "NaN".to_string();
+    }
+    if d.is_infinite() {
+        return if d > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    }
+    if d == 0.0 {
+        return if d.is_sign_negative() { "-0.0" } else { "0.0" }.to_string();
+    }
+    let scientific = format!("{:e}", d.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i32 = exponent.parse().unwrap_or(0);
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    let mut result = String::new();
+    if d < 0.0 {
+        result.push('-');
+    }
+    if (-3..7).contains(&exponent) {
+        if exponent < 0 {
+            result.push_str("0.");
+            result.extend(std::iter::repeat_n('0', (-exponent - 1) as usize));
+            result.push_str(&digits);
+        } else {
+            let integer_digits = exponent as usize + 1;
+            if digits.len() <= integer_digits {
+                result.push_str(&digits);
+                result.extend(std::iter::repeat_n('0', integer_digits - digits.len()));
+                result.push_str(".0");
+            } else {
+                result.push_str(&digits[..integer_digits]);
+                result.push('.');
+                result.push_str(&digits[integer_digits..]);
+            }
+        }
+    } else {
+        result.push_str(&digits[..1]);
+        result.push('.');
+        result.push_str(if digits.len() > 1 { &digits[1..] } else { "0" });
+        result.push('E');
+        result.push_str(&exponent.to_string());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_by_range() {
+        let format = ChoiceFormat::new("-1#is negative| 0#is zero or fraction | 1#is one |1.0<is 1+ |2#is two |2<is more than 2.");
+        assert_eq!(format.format(f64::NEG_INFINITY), "is negative");
+        assert_eq!(format.format(-1.0), "is negative");
+        assert_eq!(format.format(0.5), "is zero or fraction ");
+        assert_eq!(format.format(1.0), "is one ");
+        assert_eq!(format.format(1.5), "is 1+ ");
+        assert_eq!(format.format(2.0), "is two ");
+        assert_eq!(format.format(2.1), "is more than 2.");
+        assert_eq!(format.format(f64::NAN), "is negative");
+        assert_eq!(format.format(f64::INFINITY), "is more than 2.");
+    }
+
+    #[test]
+    fn pattern_round_trip_like_jdk() {
+        let format = ChoiceFormat::new("0#no files|1#one file|1<{0,number,integer} files");
+        assert_eq!(
+            format.get_limits(),
+            &[0.0, 1.0, ChoiceFormat::next_double(1.0)]
+        );
+        assert_eq!(
+            format.to_pattern(),
+            "0.0#no files|1.0#one file|1.0<{0,number,integer} files"
+        );
+        assert_eq!(
+            ChoiceFormat::new("-∞<neg|0.001≤small|1e7#'#big|'|∞≤inf").to_pattern(),
+            "-∞<neg|9.999999999999998E-4<small|1.0E7#'#big|'|∞<inf"
+        );
+        assert_eq!(ChoiceFormat::new("0#it''s").to_pattern(), "0.0#it''s");
+        assert_eq!(ChoiceFormat::new("").to_pattern(), "");
+    }
+
+    #[test]
+    fn parses_longest_match() {
+        let format = ChoiceFormat::with_limits(&[1.0, 2.0, 3.0], &["one", "one two", "three"]);
+        assert_eq!(format.parse("one two three"), Ok(2.0));
+        let mut pos = ParsePosition::new(8);
+        assert_eq!(format.parse_from("one two three", &mut pos), Some(3.0));
+        assert_eq!(pos.get_index(), 13);
+        assert_eq!(format.parse("four").unwrap_err().get_error_offset(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incorrect order of intervals")]
+    fn rejects_descending_limits() {
+        ChoiceFormat::new("2#two|1#one");
+    }
+
+    #[test]
+    fn doubles_like_jdk() {
+        assert_eq!(double_to_string(1.0), "1.0");
+        assert_eq!(double_to_string(-0.001), "-0.001");
+        assert_eq!(double_to_string(0.0001), "1.0E-4");
+        assert_eq!(double_to_string(1234567.0), "1234567.0");
+        assert_eq!(double_to_string(12345678.9), "1.23456789E7");
+        assert_eq!(
+            double_to_string(ChoiceFormat::next_double(1.0)),
+            "1.0000000000000002"
+        );
+    }
+}
@@ -0,0 +1,741 @@
+use std::sync::OnceLock;
+
+use crate::errors::{IllegalArgumentException, Throwable};
+use crate::util::{Comparator, Locale};
+use crate::JavaString;
+
+use super::locale_data::candidate_tags;
+use super::normalizer::{self, decompose, Form};
+
+/// The JDK's built collation tables (`java.text.RBCollationTables`) for one set of rules.
+struct Table {
+    french_sec: bool,
+    se_asian_swapping: bool,
+    max_sec_order: i16,
+    max_ter_order: i16,
+    /// Runs of consecutive code points as (first, last, order of first, step), sorted.
+    runs: Vec<(u32, u32, i32, i32)>,
+    /// For each contracting character, the sequences starting with it as (UTF-16 units,
+    /// order, whether it applies going forward). The first entry is the character alone.
+    contractions: Vec<Vec<(Vec<u16>, i32, bool)>>,
+    expansions: Vec<Vec<i32>>,
+}
+
+struct Collations {
+    tables: Vec<Table>,
+    /// Language tags with their table, sorted by tag.
+    locales: Vec<(&'static str, usize)>,
+}
+
+static COLLATION_DATA: &[u8] = include_bytes!("collation.dat");
+
+/// Reads collation.dat; see tools/GenTextTables.java for the layout.
+struct Reader {
+    pos: usize,
+}
+
+impl Reader {
+    fn u8(&mut self) -> u8 {
+        self.pos += 1;
+        COLLATION_DATA[self.pos - 1]
+    }
+
+    fn u16(&mut self) -> u16 {
+        u16::from_be_bytes([self.u8(), self.u8()])
+    }
+
+    fn i32(&mut self) -> i32 {
+        i32::from_be_bytes([self.u8(), self.u8(), self.u8(), self.u8()])
+    }
+
+    fn varint(&mut self) -> u32 {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.u8();
+            value |= ((byte & 0x7F) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return value;
+            }
+            shift += 7;
+        }
+    }
+
+    fn signed_varint(&mut self) -> i32 {
+        let value = self.varint();
+        (value >> 1) as i32 ^ -((value & 1) as i32)
+    }
+
+    fn table(&mut self) -> Table {
+        let flags = self.u8();
+        let max_sec_order = self.u16() as i16;
+        let max_ter_order = self.u16() as i16;
+
+        let run_count = self.u16() as usize;
+        let mut runs = Vec::with_capacity(run_count);
+        let (mut last, mut order) = (u32::MAX, 0i32);
+        for _ in 0..run_count {
+            let first = last.wrapping_add(1) + self.varint();
+            let length = self.varint();
+            let first_order = order.wrapping_add(self.signed_varint());
+            let step = if length > 1 { self.signed_varint() } else { 0 };
+            last = first + length - 1;
+            order = first_order.wrapping_add(step.wrapping_mul(length as i32 - 1));
+            runs.push((first, last, first_order, step));
+        }
+
+        let contractions = (0..self.u16())
+            .map(|_| {
+                (0..self.u8())
+                    .map(|_| {
+                        let length = self.u8();
+                        let name = (0..length).map(|_| self.u16()).collect();
+                        (name, self.i32(), self.u8() != 0)
+                    })
+                    .collect()
+            })
+            .collect();
+        let expansions = (0..self.u16())
+            .map(|_| (0..self.u8()).map(|_| self.i32()).collect())
+            .collect();
+
+        Table {
+            french_sec: flags & 1 != 0,
+            se_asian_swapping: flags & 2 != 0,
+            max_sec_order,
+            max_ter_order,
+            runs,
+            contractions,
+            expansions,
+        }
+    }
+}
+
+fn collations() -> &'static Collations {
+    static COLLATIONS: OnceLock<Collations> = OnceLock::new();
+    COLLATIONS.get_or_init(|| {
+        let mut reader = Reader { pos: 0 };
+        let tables = (0..reader.u8()).map(|_| reader.table()).collect();
+        let locales = (0..reader.u8())
+            .map(|_| {
+                let length = reader.u8() as usize;
+                let tag = &COLLATION_DATA[reader.pos..reader.pos + length];
+                reader.pos += length;
+                (std::str::from_utf8(tag).unwrap(), reader.u8() as usize)
+            })
+            .collect();
+        Collations { tables, locales }
+    })
+}
+
+const UNMAPPED: i32 = -1;
+const UNMAPPED_CHAR_VALUE: i32 = 0x7FFF0000;
+const EXPAND_CHAR_INDEX: i32 = 0x7E000000;
+const CONTRACT_CHAR_INDEX: i32 = 0x7F000000;
+
+impl Table {
+    /// The order of a code point, an index into the contractions or expansions, or
+    /// `UNMAPPED`. Like the JDK, only the low 18 bits of the code point are looked at.
+    fn unicode_order(&self, code_point: i32) -> i32 {
+        let key = code_point as u32 & 0x3FFFF;
+        let index = self.runs.partition_point(|&(_, last, _, _)| last < key);
+        match self.runs.get(index) {
+            Some(&(first, _, order, step)) if first <= key => {
+                order.wrapping_add(step.wrapping_mul((key - first) as i32))
+            }
+            _ => UNMAPPED,
+        }
+    }
+
+    fn contraction(&self, code_point: i32) -> &[(Vec<u16>, i32, bool)] {
+        &self.contractions[(self.unicode_order(code_point) - CONTRACT_CHAR_INDEX) as usize]
+    }
+}
+
+fn primary_order(order: i32) -> i32 {
+    ((order as u32) >> 16) as i32
+}
+
+fn secondary_order(order: i32) -> i32 {
+    (order & 0xFF00) >> 8
+}
+
+fn tertiary_order(order: i32) -> i32 {
+    order & 0xFF
+}
+
+fn is_thai_pre_vowel(ch: i32) -> bool {
+    (0x0E40..=0x0E44).contains(&ch)
+}
+
+fn is_thai_base_consonant(ch: i32) -> bool {
+    (0x0E01..=0x0E2E).contains(&ch)
+}
+
+fn is_lao_pre_vowel(ch: i32) -> bool {
+    (0x0EC0..=0x0EC4).contains(&ch)
+}
+
+fn is_lao_base_consonant(ch: i32) -> bool {
+    (0x0E81..=0x0EAE).contains(&ch)
+}
+
+/// `java.text.CollationElementIterator`: the collation elements of a string, one order
+/// at a time.
+struct CollationElements<'c> {
+    table: &'c Table,
+    strength: i32,
+    text: Vec<char>,
+    pos: usize,
+    /// The rest of an expansion being returned.
+    buffer: Option<Vec<i32>>,
+    exp_index: usize,
+    /// An unmapped character to return after `UNMAPPED_CHAR_VALUE`.
+    swap_order: i32,
+}
+
+const NULL_ORDER: i32 = -1;
+
+impl<'c> CollationElements<'c> {
+    fn new(collator: &'c Collator, source: &str) -> Self {
+        let text = match collator.decomposition {
+            Collator::CANONICAL_DECOMPOSITION => decompose(source, false),
+            Collator::FULL_DECOMPOSITION => decompose(source, true),
+            _ => source.chars().collect(),
+        };
+        Self {
+            table: collator.table,
+            strength: collator.strength,
+            text,
+            pos: 0,
+            buffer: None,
+            exp_index: 0,
+            swap_order: 0,
+        }
+    }
+
+    fn next_char(&mut self) -> i32 {
+        match self.text.get(self.pos) {
+            Some(&ch) => {
+                self.pos += 1;
+                ch as i32
+            }
+            None => NULL_ORDER,
+        }
+    }
+
+    fn strength_order(&self, order: i32) -> i32 {
+        match self.strength {
+            Collator::PRIMARY => order & 0xFFFF0000u32 as i32,
+            Collator::SECONDARY => order & 0xFFFFFF00u32 as i32,
+            _ => order,
+        }
+    }
+
+    fn next(&mut self) -> i32 {
+        if let Some(buffer) = &self.buffer {
+            if self.exp_index < buffer.len() {
+                self.exp_index += 1;
+                return self.strength_order(buffer[self.exp_index - 1]);
+            }
+            self.buffer = None;
+            self.exp_index = 0;
+        } else if self.swap_order != 0 {
+            if self.swap_order >= 0x10000 {
+                let units = crate::character::to_chars(self.swap_order);
+                self.swap_order = units[1] as i32;
+                return (units[0] as i32) << 16;
+            }
+            let order = self.swap_order << 16;
+            self.swap_order = 0;
+            return order;
+        }
+
+        let ch = self.next_char();
+        if ch == NULL_ORDER {
+            return NULL_ORDER;
+        }
+        let mut value = self.table.unicode_order(ch);
+        if value == UNMAPPED {
+            self.swap_order = ch;
+            return UNMAPPED_CHAR_VALUE;
+        }
+        if value >= CONTRACT_CHAR_INDEX {
+            value = self.next_contract_char(ch);
+        }
+        if value >= EXPAND_CHAR_INDEX {
+            let buffer = self.table.expansions[(value - EXPAND_CHAR_INDEX) as usize].clone();
+            value = buffer[0];
+            self.buffer = Some(buffer);
+            self.exp_index = 1;
+        }
+
+        if self.table.se_asian_swapping {
+            let pairs = [
+                (
+                    is_thai_pre_vowel as fn(i32) -> bool,
+                    is_thai_base_consonant as fn(i32) -> bool,
+                ),
+                (is_lao_pre_vowel, is_lao_base_consonant),
+            ];
+            for (is_pre_vowel, is_base_consonant) in pairs {
+                if is_pre_vowel(ch) {
+                    let consonant = self.next_char();
+                    if is_base_consonant(consonant) {
+                        let buffer = self.make_reordered_buffer(consonant, value);
+                        value = buffer[0];
+                        self.buffer = Some(buffer);
+                        self.exp_index = 1;
+                    } else if consonant != NULL_ORDER {
+                        self.pos -= 1;
+                    }
+                }
+            }
+        }
+        self.strength_order(value)
+    }
+
+    /// The orders of a Thai or Lao consonant followed by those of the vowel before it.
+    fn make_reordered_buffer(&mut self, consonant: i32, last_value: i32) -> Vec<i32> {
+        let mut order = self.table.unicode_order(consonant);
+        if order >= CONTRACT_CHAR_INDEX {
+            order = self.next_contract_char(consonant);
+        }
+        let mut buffer = if order >= EXPAND_CHAR_INDEX {
+            self.table.expansions[(order - EXPAND_CHAR_INDEX) as usize].clone()
+        } else {
+            vec![order]
+        };
+        match self.buffer.take() {
+            Some(last_expansion) => buffer.extend(last_expansion),
+            None => buffer.push(last_value),
+        }
+        buffer
+    }
+
+    /// The order of the longest contraction starting with `ch`, consuming the rest of it.
+    fn next_contract_char(&mut self, ch: i32) -> i32 {
+        let list = self.table.contraction(ch);
+        let mut order = list[0].1;
+        let mut max_length = list.last().unwrap().0.len() as i32;
+
+        let mut fragment = Vec::new();
+        let mut pos = self.pos - 1;
+        while max_length > 0 && pos < self.text.len() {
+            let start = fragment.len();
+            fragment.extend(self.text[pos].encode_utf16(&mut [0; 2]).iter());
+            max_length -= (fragment.len() - start) as i32;
+            pos += 1;
+        }
+
+        let mut best = 1;
+        for (name, value, forward) in list[1..].iter().rev() {
+            if *forward && fragment.starts_with(name) && name.len() > best {
+                best = name.len();
+                order = *value;
+            }
+        }
+        let mut best = best as i32;
+        while best > 1 {
+            let c = self.next_char();
+            best -= if c >= 0x10000 { 2 } else { 1 };
+        }
+        order
+    }
+}
+
+/// `java.text.Collator`: locale-sensitive string comparison, with the JDK's collation
+/// rules for each locale.
+///
+/// The strength picks which differences count: `PRIMARY` only sees different base
+/// letters, `SECONDARY` also accents, `TERTIARY` (the default) also case and
+/// `IDENTICAL` everything else.
+#[derive(Clone)]
+pub struct Collator {
+    table: &'static Table,
+    strength: i32,
+    decomposition: i32,
+}
+
+impl std::fmt::Debug for Collator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Collator")
+            .field("strength", &self.strength)
+            .field("decomposition", &self.decomposition)
+            .finish()
+    }
+}
+
+impl PartialEq for Collator {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.table, other.table)
+            && self.strength == other.strength
+            && self.decomposition == other.decomposition
+    }
+}
+
+impl Eq for Collator {}
+
+impl Collator {
+    pub const PRIMARY: i32 = 0;
+    pub const SECONDARY: i32 = 1;
+    pub const TERTIARY: i32 = 2;
+    pub const IDENTICAL: i32 = 3;
+
+    pub const NO_DECOMPOSITION: i32 = 0;
+    pub const CANONICAL_DECOMPOSITION: i32 = 1;
+    pub const FULL_DECOMPOSITION: i32 = 2;
+
+    /// The collator for a locale, falling back from the most specific of its language,
+    /// script and country to the root rules like the JDK does.
+    pub fn get_instance(locale: &Locale) -> Self {
+        let collations = collations();
+        let table = candidate_tags(locale)
+            .iter()
+            .find_map(|tag| {
+                collations
+                    .locales
+                    .binary_search_by_key(&tag.as_str(), |&(tag, _)| tag)
+                    .ok()
+            })
+            .map_or(0, |index| collations.locales[index].1);
+        Self {
+            table: &collations.tables[table],
+            strength: Self::TERTIARY,
+            decomposition: Self::NO_DECOMPOSITION,
+        }
+    }
+
+    pub fn get_strength(&self) -> i32 {
+        self.strength
+    }
+
+    /// Throws `IllegalArgumentException` if `new_strength` is not one of the strengths.
+    pub fn set_strength(&mut self, new_strength: i32) {
+        if !(Self::PRIMARY..=Self::IDENTICAL).contains(&new_strength) {
+            IllegalArgumentException::new("Incorrect comparison level.".to_string(), None).throw();
+        }
+        self.strength = new_strength;
+    }
+
+    pub fn get_decomposition(&self) -> i32 {
+        self.decomposition
+    }
+
+    /// Sets how strings are normalized before comparing: not at all (the default), to NFD
+    /// or to NFKD. Throws `IllegalArgumentException` for any other mode.
+    pub fn set_decomposition(&mut self, decomposition_mode: i32) {
+        if !(Self::NO_DECOMPOSITION..=Self::FULL_DECOMPOSITION).contains(&decomposition_mode) {
+            IllegalArgumentException::new("Wrong decomposition mode.".to_string(), None).throw();
+        }
+        self.decomposition = decomposition_mode;
+    }
+
+    /// Compares two strings by the collation rules: negative, zero or positive like
+    /// `compare_to`.
+    pub fn compare(&self, source: &str, target: &str) -> i32 {
+        let check_sec_ter_initial = self.strength >= Self::SECONDARY;
+        let mut check_sec_ter = check_sec_ter_initial;
+        let mut check_tertiary = self.strength >= Self::TERTIARY;
+        let french_sec = self.table.french_sec;
+
+        let mut source_cursor = CollationElements::new(self, source);
+        let mut target_cursor = CollationElements::new(self, target);
+        let (mut s_order, mut t_order) = (0, 0);
+        let (mut get_s, mut get_t) = (true, true);
+        let mut result = 0;
+        loop {
+            if get_s {
+                s_order = source_cursor.next();
+            } else {
+                get_s = true;
+            }
+            if get_t {
+                t_order = target_cursor.next();
+            } else {
+                get_t = true;
+            }
+            if s_order == NULL_ORDER || t_order == NULL_ORDER {
+                break;
+            }
+
+            let (p_s, p_t) = (primary_order(s_order), primary_order(t_order));
+            if s_order == t_order {
+                // French secondaries count from the last accent, so a later one that
+                // differs overrides an earlier tertiary difference.
+                if french_sec && p_s != 0 && !check_sec_ter {
+                    check_sec_ter = check_sec_ter_initial;
+                    check_tertiary = false;
+                }
+                continue;
+            }
+
+            if p_s != p_t {
+                if s_order == 0 {
+                    get_t = false;
+                    continue;
+                }
+                if t_order == 0 {
+                    get_s = false;
+                    continue;
+                }
+                if p_s == 0 {
+                    // Only the source has an ignorable (accent) here.
+                    if check_sec_ter {
+                        result = 1;
+                        check_sec_ter = false;
+                    }
+                    get_t = false;
+                } else if p_t == 0 {
+                    if check_sec_ter {
+                        result = -1;
+                        check_sec_ter = false;
+                    }
+                    get_s = false;
+                } else {
+                    return if p_s < p_t { -1 } else { 1 };
+                }
+            } else if check_sec_ter {
+                let (sec_s, sec_t) = (secondary_order(s_order), secondary_order(t_order));
+                if sec_s != sec_t {
+                    result = if sec_s < sec_t { -1 } else { 1 };
+                    check_sec_ter = false;
+                } else if check_tertiary {
+                    let (ter_s, ter_t) = (tertiary_order(s_order), tertiary_order(t_order));
+                    if ter_s != ter_t {
+                        result = if ter_s < ter_t { -1 } else { 1 };
+                        check_tertiary = false;
+                    }
+                }
+            }
+        }
+
+        // Whatever is left over only matters if it is not ignorable.
+        let rest = if s_order != NULL_ORDER {
+            Some((s_order, &mut source_cursor, 1))
+        } else if t_order != NULL_ORDER {
+            Some((t_order, &mut target_cursor, -1))
+        } else {
+            None
+        };
+        if let Some((mut order, cursor, sign)) = rest {
+            while order != NULL_ORDER {
+                if primary_order(order) != 0 {
+                    return sign;
+                }
+                if secondary_order(order) != 0 && check_sec_ter {
+                    result = sign;
+                    check_sec_ter = false;
+                }
+                order = cursor.next();
+            }
+        }
+
+        if result == 0 && self.strength == Self::IDENTICAL {
+            return match self.decomposition {
+                Self::CANONICAL_DECOMPOSITION => normalizer::normalize(source, Form::NFD)
+                    .compare_to(&normalizer::normalize(target, Form::NFD)),
+                Self::FULL_DECOMPOSITION => normalizer::normalize(source, Form::NFKD)
+                    .compare_to(&normalizer::normalize(target, Form::NFKD)),
+                _ => source.compare_to(target),
+            };
+        }
+        result
+    }
+
+    /// Whether the strings compare equal by the collation rules.
+    pub fn equals(&self, source: &str, target: &str) -> bool {
+        self.compare(source, target) == 0
+    }
+
+    /// The string's sort key: comparing keys gives the same result as `compare` on the
+    /// strings, which is faster when each string is compared many times.
+    pub fn get_collation_key(&self, source: &str) -> CollationKey {
+        let check_sec_ter = self.strength >= Self::SECONDARY;
+        let check_tertiary = self.strength >= Self::TERTIARY;
+        let table = self.table;
+        let (mut primary, mut secondary, mut tertiary) = (Vec::new(), Vec::new(), Vec::new());
+        let mut sec_order_start = 0;
+
+        let mut cursor = CollationElements::new(self, source);
+        loop {
+            let order = cursor.next();
+            if order == NULL_ORDER {
+                break;
+            }
+            let sec_order = secondary_order(order) as u16;
+            let ter_order = tertiary_order(order) as u16;
+            if order & 0xFFFF0000u32 as i32 != 0 {
+                primary.push((primary_order(order) as u16).wrapping_add(1));
+                if check_sec_ter {
+                    // French secondaries sort from the end of each word.
+                    if table.french_sec && sec_order_start < secondary.len() {
+                        secondary[sec_order_start..].reverse();
+                    }
+                    secondary.push(sec_order + 1);
+                    sec_order_start = secondary.len();
+                }
+                if check_tertiary {
+                    tertiary.push(ter_order + 1);
+                }
+            } else {
+                if check_sec_ter && sec_order != 0 {
+                    secondary.push(sec_order.wrapping_add(table.max_sec_order as u16) + 1);
+                }
+                if check_tertiary && ter_order != 0 {
+                    tertiary.push(ter_order.wrapping_add(table.max_ter_order as u16) + 1);
+                }
+            }
+        }
+        if table.french_sec {
+            if sec_order_start < secondary.len() {
+                secondary[sec_order_start..].reverse();
+            }
+            secondary.reverse();
+        }
+
+        let mut key = primary;
+        key.push(0);
+        key.extend(secondary);
+        key.push(0);
+        key.extend(tertiary);
+        if self.strength == Self::IDENTICAL {
+            key.push(0);
+            match self.decomposition {
+                Self::CANONICAL_DECOMPOSITION => {
+                    key.extend(normalizer::normalize(source, Form::NFD).encode_utf16())
+                }
+                Self::FULL_DECOMPOSITION => {
+                    key.extend(normalizer::normalize(source, Form::NFKD).encode_utf16())
+                }
+                _ => key.extend(source.encode_utf16()),
+            }
+        }
+        CollationKey {
+            source: source.to_string(),
+            key,
+        }
+    }
+}
+
+impl<S: AsRef<str> + ?Sized> Comparator<S> for Collator {
+    fn compare(&self, a: &S, b: &S) -> i32 {
+        Collator::compare(self, a.as_ref(), b.as_ref())
+    }
+}
+
+/// `java.text.CollationKey`: a string's sort key under one collator. Keys from different
+/// collators are not comparable. Keys are equal when their strings compare equal, whether
+/// or not the strings themselves are.
+#[derive(Debug, Clone)]
+pub struct CollationKey {
+    source: String,
+    key: Vec<u16>,
+}
+
+impl CollationKey {
+    pub fn get_source_string(&self) -> &str {
+        &self.source
+    }
+
+    /// -1, 0 or 1 as this key sorts before, with or after `target`.
+    pub fn compare_to(&self, target: &CollationKey) -> i32 {
+        match self.key.cmp(&target.key) {
+            std::cmp::Ordering::Less => -1,
+            std::cmp::Ordering::Equal => 0,
+            std::cmp::Ordering::Greater => 1,
+        }
+    }
+
+    /// The key as big-endian UTF-16 code units.
+    pub fn to_byte_array(&self) -> Vec<u8> {
+        self.key
+            .iter()
+            .flat_map(|unit| unit.to_be_bytes())
+            .collect()
+    }
+}
+
+impl PartialEq for CollationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for CollationKey {}
+
+impl std::hash::Hash for CollationKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+impl PartialOrd for CollationKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CollationKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Collator;
+    use crate::util::{Comparator, Locale};
+
+    #[test]
+    fn sorts_like_jdk() {
+        let words = [
+            "zebra", "Äpfel", "apple", "Apple", "öl", "côte", "coté", "cote", "中文", "日本",
+        ];
+        let sorted = |tag: &str| {
+            let collator = Collator::get_instance(&Locale::for_language_tag(tag));
+            let mut sorted = words;
+            sorted.sort_by(|a, b| collator.ordering(*a, *b));
+            sorted
+        };
+        assert_eq!(
+            sorted("en-US"),
+            [
+                "Äpfel", "apple", "Apple", "cote", "coté", "côte", "öl", "zebra", "中文", "日本"
+            ]
+        );
+        assert_eq!(
+            sorted("sv"),
+            [
+                "apple", "Apple", "cote", "coté", "côte", "zebra", "Äpfel", "öl", "中文", "日本"
+            ]
+        );
+        assert_eq!(
+            sorted("fr-FR"),
+            [
+                "Äpfel", "apple", "Apple", "cote", "côte", "coté", "öl", "zebra", "中文", "日本"
+            ]
+        );
+        assert_eq!(
+            sorted("zh-CN"),
+            [
+                "Äpfel", "apple", "Apple", "cote", "coté", "côte", "öl", "zebra", "日本", "中文"
+            ]
+        );
+    }
+
+    #[test]
+    fn compares_by_strength() {
+        let mut collator = Collator::get_instance(&Locale::US);
+        assert_eq!(collator.compare("a", "A"), -1);
+        assert_eq!(collator.compare("abc", "abd"), -1);
+        assert_eq!(
+            collator.get_collation_key("Ab").to_byte_array(),
+            [0x00, 0x53, 0x00, 0x54, 0, 0, 0x00, 0x01, 0x00, 0x01, 0, 0, 0x00, 0x02, 0x00, 0x01]
+        );
+
+        collator.set_strength(Collator::PRIMARY);
+        assert_eq!(collator.compare("a", "Á"), 0);
+        assert!(collator.equals("résumé", "RESUME"));
+    }
+}
@@ -0,0 +1,1194 @@
+use std::sync::OnceLock;
+
+use crate::character;
+use crate::errors::{ArithmeticException, IllegalArgumentException, ParseException, Throwable};
+use crate::floating_decimal::FloatingDecimal;
+use crate::math::RoundingMode;
+use crate::util::regex::{Matcher, Pattern};
+use crate::util::Locale;
+use crate::JavaString;
+
+use super::decimal_format::{DigitList, DOUBLE_FRACTION_DIGITS, DOUBLE_INTEGER_DIGITS};
+use super::locale_data;
+use super::{DecimalFormat, DecimalFormatSymbols, Number, ParsePosition};
+
+/// `NumberFormat.Style`: how long the words of a compact number are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// "1K".
+    Short,
+    /// "1 thousand".
+    Long,
+}
+
+/// The plural categories in the iteration order of the JDK's rule map, which settles which
+/// category wins when several rules match. The empty key is that of empty rules.
+const CATEGORY_ORDER: [&str; 7] = ["zero", "", "other", "one", "few", "many", "two"];
+
+macro_rules! regex {
+    ($name: ident, $regex: expr) => {
+        fn $name() -> &'static Pattern {
+            static PATTERN: OnceLock<Pattern> = OnceLock::new();
+            PATTERN.get_or_init(|| Pattern::compile($regex).unwrap())
+        }
+    };
+}
+
+regex!(plurals, r"^\{(?<plurals>.*)}$");
+regex!(
+    count_pattern,
+    r"(zero|one|two|few|many|other):((' '|[^ ])+)[ ]*"
+);
+regex!(
+    plural_rules_pattern,
+    r"(zero|one|two|few|many):\s*([niftvwe])\s*(([/%])\s*(\d+))*\s*(!?=)\s*((\d+)\.\.(\d+)|\d+)\s*(,\s*((\d+)\.\.(\d+)|\d+))*(\s*(and|or)\s*([niftvwe])\s*(([/%])\s*(\d+))*\s*(!?=)\s*((\d+)\.\.(\d+)|\d+)\s*(,\s*((\d+)\.\.(\d+)|\d+))*)*"
+);
+regex!(digits_pattern, r"\p{Nd}+");
+regex!(
+    expr_pattern,
+    r"(?<op>[niftvwe])\s*((?<div>[/%])\s*(?<val>\d+))*"
+);
+regex!(relation_pattern, r"(?<rel>!?=)");
+regex!(
+    value_range_pattern,
+    r"(?<start>\d+)\.\.(?<end>\d+)|(?<value>\d+)"
+);
+
+/// One part of a compact pattern by plural category; the JDK's `Patterns`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Patterns(Vec<(String, String)>);
+
+impl Patterns {
+    fn put(&mut self, count: &str, pattern: String) {
+        match self.0.iter_mut().find(|(key, _)| key == count) {
+            Some(entry) => entry.1 = pattern,
+            None => self.0.push((count.to_string(), pattern)),
+        }
+    }
+
+    /// The pattern of the category, else of "other", else empty.
+    fn get(&self, category: &str) -> &str {
+        let find = |count: &str| {
+            self.0
+                .iter()
+                .find(|(key, _)| key == count)
+                .map(|(_, pattern)| pattern.as_str())
+        };
+        find(category).or_else(|| find("other")).unwrap_or_default()
+    }
+}
+
+/// `java.text.CompactNumberFormat`: formats numbers in a short form such as "1K" or
+/// "1 thousand", by a pattern for each power of ten, with plural forms chosen by the
+/// locale's CLDR plural rules.
+///
+/// A compact pattern is a `DecimalFormat`-style pattern whose zeros both stand for the
+/// number and give its scale: at index 3, "0K" formats thousands and "00K" tens of
+/// thousands. Patterns with plural forms are written as `{one:0' 'thousand other:0'
+/// 'thousands}`. Numbers below every pattern, or matched by an empty one, are formatted
+/// with the decimal pattern instead. Parse positions are byte offsets into the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactNumberFormat {
+    symbols: DecimalFormatSymbols,
+    decimal_pattern: String,
+    compact_patterns: Vec<String>,
+    plural_rules: String,
+    /// Formats the scaled numbers of the compact patterns, with this format's digit limits,
+    /// grouping and rounding.
+    decimal_format: DecimalFormat,
+    /// Formats the numbers no compact pattern applies to.
+    default_decimal_format: DecimalFormat,
+    /// The affix patterns and placeholder zeros of each compact pattern.
+    positive_prefix_patterns: Vec<Patterns>,
+    negative_prefix_patterns: Vec<Patterns>,
+    positive_suffix_patterns: Vec<Patterns>,
+    negative_suffix_patterns: Vec<Patterns>,
+    placeholder_patterns: Vec<Patterns>,
+    /// What the numbers of each compact pattern are divided by. Like the JDK's, a compact
+    /// pattern that sets no plural form adds none, which shifts the ones after it.
+    divisors: Vec<u128>,
+    /// The plural rules by category, in `CATEGORY_ORDER`.
+    rules: Vec<(String, String)>,
+}
+
+impl CompactNumberFormat {
+    /// A format with no plural rules, so that only the "other" forms of the compact
+    /// patterns are used. Panics with `IllegalArgumentException` if a pattern is malformed.
+    pub fn new(
+        decimal_pattern: &str,
+        symbols: DecimalFormatSymbols,
+        compact_patterns: &[&str],
+    ) -> Self {
+        Self::with_plural_rules(decimal_pattern, symbols, compact_patterns, "")
+    }
+
+    /// A format whose plural forms are chosen by CLDR plural rules such as
+    /// `one:i = 1 and v = 0`, separated by semicolons. Panics with
+    /// `IllegalArgumentException` if a pattern or the rules are malformed.
+    pub fn with_plural_rules(
+        decimal_pattern: &str,
+        symbols: DecimalFormatSymbols,
+        compact_patterns: &[&str],
+        plural_rules: &str,
+    ) -> Self {
+        let decimal_format = DecimalFormat::with_symbols("0", symbols.clone());
+        let mut default_decimal_format =
+            DecimalFormat::with_symbols(decimal_pattern, symbols.clone());
+        default_decimal_format.set_maximum_fraction_digits(0);
+        let mut format = Self {
+            symbols,
+            decimal_pattern: decimal_pattern.to_string(),
+            compact_patterns: compact_patterns.iter().map(|p| p.to_string()).collect(),
+            plural_rules: plural_rules.to_string(),
+            decimal_format,
+            default_decimal_format,
+            positive_prefix_patterns: Vec::new(),
+            negative_prefix_patterns: Vec::new(),
+            positive_suffix_patterns: Vec::new(),
+            negative_suffix_patterns: Vec::new(),
+            placeholder_patterns: Vec::new(),
+            divisors: Vec::new(),
+            rules: Vec::new(),
+        };
+        // The digit limits of the "0" pattern, capped to those of a double.
+        format.set_maximum_integer_digits(format.decimal_format.get_maximum_integer_digits());
+        format.set_minimum_integer_digits(format.decimal_format.get_minimum_integer_digits());
+        format.set_maximum_fraction_digits(format.decimal_format.get_maximum_fraction_digits());
+        format.set_minimum_fraction_digits(format.decimal_format.get_minimum_fraction_digits());
+        format.process_compact_patterns();
+        format.rules = build_plural_rules(plural_rules);
+        format
+    }
+
+    /// `NumberFormat.getCompactNumberInstance`: the locale's compact number format.
+    pub fn get_compact_number_instance(locale: &Locale, style: Style) -> Self {
+        let data = locale_data::number_data(locale);
+        let compact_patterns = &data.compact_patterns[match style {
+            Style::Short => 0,
+            Style::Long => 1,
+        }];
+        Self::with_plural_rules(
+            data.compact_decimal_pattern,
+            DecimalFormatSymbols::get_instance(locale),
+            compact_patterns,
+            data.plural_rules,
+        )
+    }
+
+    pub fn get_maximum_integer_digits(&self) -> i32 {
+        self.decimal_format.get_maximum_integer_digits()
+    }
+
+    /// At most 309, the most integer digits a double can have.
+    pub fn set_maximum_integer_digits(&mut self, digits: i32) {
+        self.decimal_format
+            .set_maximum_integer_digits(digits.min(DOUBLE_INTEGER_DIGITS));
+    }
+
+    pub fn get_minimum_integer_digits(&self) -> i32 {
+        self.decimal_format.get_minimum_integer_digits()
+    }
+
+    /// At most 309, the most integer digits a double can have.
+    pub fn set_minimum_integer_digits(&mut self, digits: i32) {
+        self.decimal_format
+            .set_minimum_integer_digits(digits.min(DOUBLE_INTEGER_DIGITS));
+    }
+
+    pub fn get_maximum_fraction_digits(&self) -> i32 {
+        self.decimal_format.get_maximum_fraction_digits()
+    }
+
+    /// At most 340, the most fraction digits a double can have.
+    pub fn set_maximum_fraction_digits(&mut self, digits: i32) {
+        self.decimal_format
+            .set_maximum_fraction_digits(digits.min(DOUBLE_FRACTION_DIGITS));
+    }
+
+    pub fn get_minimum_fraction_digits(&self) -> i32 {
+        self.decimal_format.get_minimum_fraction_digits()
+    }
+
+    /// At most 340, the most fraction digits a double can have.
+    pub fn set_minimum_fraction_digits(&mut self, digits: i32) {
+        self.decimal_format
+            .set_minimum_fraction_digits(digits.min(DOUBLE_FRACTION_DIGITS));
+    }
+
+    pub fn get_rounding_mode(&self) -> RoundingMode {
+        self.decimal_format.get_rounding_mode()
+    }
+
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.decimal_format.set_rounding_mode(rounding_mode);
+    }
+
+    pub fn get_grouping_size(&self) -> i32 {
+        self.decimal_format.get_grouping_size()
+    }
+
+    /// Panics with `IllegalArgumentException` if the size is negative or above 127.
+    pub fn set_grouping_size(&mut self, size: i32) {
+        if !(0..=i8::MAX as i32).contains(&size) {
+            IllegalArgumentException::new(
+                "The value passed is negative or larger than 127".to_string(),
+                None,
+            )
+            .throw();
+        }
+        self.decimal_format.set_grouping_size(size);
+    }
+
+    /// Whether the scaled numbers are grouped; unlike `DecimalFormat`, off by default.
+    pub fn is_grouping_used(&self) -> bool {
+        self.decimal_format.is_grouping_used()
+    }
+
+    pub fn set_grouping_used(&mut self, used: bool) {
+        self.decimal_format.set_grouping_used(used);
+    }
+
+    pub fn is_parse_integer_only(&self) -> bool {
+        self.decimal_format.is_parse_integer_only()
+    }
+
+    pub fn set_parse_integer_only(&mut self, integer_only: bool) {
+        self.decimal_format.set_parse_integer_only(integer_only);
+    }
+
+    /// Parses each compact pattern into its affixes and placeholder by plural category.
+    fn process_compact_patterns(&mut self) {
+        for index in 0..self.compact_patterns.len() {
+            self.positive_prefix_patterns.push(Patterns::default());
+            self.negative_prefix_patterns.push(Patterns::default());
+            self.positive_suffix_patterns.push(Patterns::default());
+            self.negative_suffix_patterns.push(Patterns::default());
+            self.placeholder_patterns.push(Patterns::default());
+
+            let pattern = self.compact_patterns[index].clone();
+            let mut matcher = plurals().matcher(&pattern);
+            if matcher.matches() {
+                let plurals = matcher.group_named("plurals").unwrap_or_default();
+                let mut counts = count_pattern().matcher(plurals);
+                while counts.find() {
+                    let (count, pattern) = (counts.group(1), counts.group(2));
+                    self.apply_pattern(count.unwrap(), pattern.unwrap(), index);
+                }
+            } else {
+                self.apply_pattern("other", &pattern, index);
+            }
+        }
+    }
+
+    /// Parses the compact pattern of one plural category. Like a `DecimalFormat` pattern,
+    /// it has an optional negative subpattern, which only contributes its affixes.
+    fn apply_pattern(&mut self, count: &str, pattern: &str, index: usize) {
+        let malformed = |message: String| -> ! {
+            IllegalArgumentException::new(format!("{message} in pattern \"{pattern}\""), None)
+                .throw()
+        };
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut start = 0;
+        let mut got_negative = false;
+        let mut positive_prefix = String::new();
+        let mut positive_suffix = String::new();
+        let mut negative_prefix = String::new();
+        let mut negative_suffix = String::new();
+        let mut zeros = String::new();
+
+        for j in (0..=1).rev() {
+            if start >= chars.len() {
+                break;
+            }
+            let mut prefix = String::new();
+            let mut suffix = String::new();
+            let mut in_quote = false;
+            // 0 is the prefix, 1 the placeholder zeros and 2 the suffix.
+            let mut phase = 0;
+            let mut in_suffix = false;
+
+            let mut i = start;
+            while i < chars.len() {
+                let ch = chars[i];
+                let affix = if in_suffix { &mut suffix } else { &mut prefix };
+                if phase == 1 {
+                    if j == 1 && ch == '0' {
+                        zeros.push('0');
+                    } else if j == 1 {
+                        phase = 2;
+                        in_suffix = true;
+                        continue;
+                    } else {
+                        // The negative subpattern's zeros are skipped.
+                        while i < chars.len() && chars[i] == '0' {
+                            i += 1;
+                        }
+                        if i < chars.len() {
+                            phase = 2;
+                            in_suffix = true;
+                            continue;
+                        }
+                    }
+                } else if in_quote {
+                    if ch == '\'' {
+                        if chars.get(i + 1) == Some(&'\'') {
+                            i += 1;
+                            affix.push_str("''");
+                        } else {
+                            in_quote = false;
+                        }
+                    } else {
+                        affix.push(ch);
+                    }
+                } else {
+                    match ch {
+                        '0' => {
+                            phase = 1;
+                            continue;
+                        }
+                        '\'' => {
+                            if chars.get(i + 1) == Some(&'\'') {
+                                i += 1;
+                                affix.push_str("''");
+                            } else {
+                                in_quote = true;
+                            }
+                        }
+                        ';' => {
+                            if phase == 0 || j == 0 {
+                                malformed(format!("Unquoted special character '{ch}'"));
+                            }
+                            start = i + 1;
+                            break;
+                        }
+                        // The minus sign is quoted to be expanded to the locale's.
+                        '-' => affix.push_str("'-"),
+                        '#' | '%' | ',' | '.' | '\u{A4}' | '\u{2030}' => {
+                            malformed(format!("Unquoted special character '{ch}'"))
+                        }
+                        _ => affix.push(ch),
+                    }
+                }
+                i += 1;
+            }
+            if in_quote {
+                malformed("Invalid single quote".to_string());
+            }
+
+            if j == 1 {
+                positive_prefix = prefix;
+                positive_suffix = suffix;
+                negative_prefix = positive_prefix.clone();
+                negative_suffix = positive_suffix.clone();
+            } else {
+                negative_prefix = prefix;
+                negative_suffix = suffix;
+                got_negative = true;
+            }
+            // Without a distinct negative subpattern, negatives get a minus sign.
+            if !got_negative
+                || (negative_prefix == positive_prefix && negative_suffix == positive_suffix)
+            {
+                negative_suffix = positive_suffix.clone();
+                negative_prefix = format!("'-{positive_prefix}");
+            }
+        }
+
+        // A pattern of only zeros, or none, means the decimal pattern is used.
+        let has_affixes = !positive_prefix.is_empty() || !positive_suffix.is_empty();
+        if !has_affixes {
+            (positive_prefix, negative_prefix) = (String::new(), String::new());
+            (positive_suffix, negative_suffix) = (String::new(), String::new());
+            zeros.clear();
+        }
+        self.positive_prefix_patterns[index].put(count, positive_prefix);
+        self.negative_prefix_patterns[index].put(count, negative_prefix);
+        self.positive_suffix_patterns[index].put(count, positive_suffix);
+        self.negative_suffix_patterns[index].put(count, negative_suffix);
+        if self.divisors.len() <= index {
+            let divisor = if has_affixes {
+                self.compute_divisor(zeros.len(), index)
+            } else {
+                1
+            };
+            self.divisors.push(divisor);
+        }
+        self.placeholder_patterns[index].put(count, zeros);
+    }
+
+    /// The divisor of the compact pattern at the index with that many placeholder zeros:
+    /// "00K" at index 3 divides by 100.
+    fn compute_divisor(&self, zeros: usize, index: usize) -> u128 {
+        let divisor = 10u128.checked_pow(index as u32).unwrap_or(u128::MAX);
+        if zeros == 0 {
+            return divisor;
+        }
+        let scale = 10u128.pow(zeros as u32 - 1);
+        if divisor < scale {
+            IllegalArgumentException::new(
+                format!(
+                    "Invalid Pattern [{}]: min integer digits specified exceeds the limit for \
+                     the index {index}",
+                    self.compact_patterns[index]
+                ),
+                None,
+            )
+            .throw();
+        }
+        divisor / scale
+    }
+
+    /// Formats a number, like `NumberFormat.format`.
+    pub fn format<N: Into<Number>>(&self, number: N) -> String {
+        match number.into() {
+            Number::Long(value) => self.format_long(value),
+            Number::Double(value) => self.format_double(value),
+        }
+    }
+
+    fn format_double(&self, number: f64) -> String {
+        let mut result = String::new();
+        if number.is_nan() {
+            result.push_str(self.symbols.get_nan());
+            return result;
+        }
+        let is_negative = number < 0.0 || (number == 0.0 && number.is_sign_negative());
+        if number.is_infinite() {
+            let format = &self.decimal_format;
+            let (prefix, suffix) = if is_negative {
+                (format.get_negative_prefix(), format.get_negative_suffix())
+            } else {
+                (format.get_positive_prefix(), format.get_positive_suffix())
+            };
+            result.push_str(prefix);
+            result.push_str(self.symbols.get_infinity());
+            result.push_str(suffix);
+            return result;
+        }
+
+        let number = number.abs();
+        // The pattern is chosen by the number as rounded to the minimum fraction digits.
+        let rounded =
+            self.decimal_format
+                .round(number, is_negative, self.get_minimum_fraction_digits());
+        let Some(index) = self.select_compact_pattern(rounded as i64) else {
+            self.default_decimal_format
+                .double_subformat(&mut result, number, is_negative);
+            return result;
+        };
+        let divisor = self.divisors[index] as i64;
+        let category = self.plural_category(self.integer_part(number, divisor as f64) as f64);
+        let (prefix, suffix) = self.affix_patterns(is_negative, index, category);
+        if prefix.is_empty() && suffix.is_empty() {
+            self.default_decimal_format
+                .double_subformat(&mut result, number, is_negative);
+            return result;
+        }
+        result.push_str(&self.expand_affix(prefix));
+        // Without placeholder zeros, a pattern such as "thousand" stands for the number.
+        if !self.placeholder_patterns[index].get(category).is_empty() {
+            self.decimal_format.subformat_digits(
+                &mut result,
+                Number::Double(rounded / divisor as f64),
+                is_negative,
+                self.digit_limits(),
+            );
+            result.push_str(&self.expand_affix(suffix));
+        }
+        result
+    }
+
+    fn format_long(&self, number: i64) -> String {
+        let mut result = String::new();
+        let is_negative = number < 0;
+        let magnitude = number.unsigned_abs();
+        // Like the JDK, which formats Long.MIN_VALUE as a BigInteger, the pattern is chosen
+        // by the powers of ten below the number rather than its logarithm.
+        let index = if number == i64::MIN {
+            (!self.compact_patterns.is_empty()).then(|| 18.min(self.compact_patterns.len() - 1))
+        } else {
+            self.select_compact_pattern(magnitude as i64)
+        };
+        let Some(index) = index else {
+            return self.default_decimal_format.format(number);
+        };
+        let divisor = self.divisors[index];
+        let category =
+            self.plural_category(self.integer_part(magnitude as f64, divisor as f64) as f64);
+        let (prefix, suffix) = self.affix_patterns(is_negative, index, category);
+        if prefix.is_empty() && suffix.is_empty() {
+            return self.default_decimal_format.format(number);
+        }
+        result.push_str(&self.expand_affix(prefix));
+        if !self.placeholder_patterns[index].get(category).is_empty() {
+            let magnitude = magnitude as u128;
+            let scaled = if magnitude.is_multiple_of(divisor) {
+                // Only Long.MIN_VALUE over 1 does not fit, and wraps back to its magnitude.
+                Number::Long((magnitude / divisor) as i64)
+            } else if number == i64::MIN {
+                Number::Long(divide(magnitude, divisor, self.get_rounding_mode()) as i64)
+            } else {
+                Number::Double(magnitude as f64 / divisor as f64)
+            };
+            self.decimal_format.subformat_digits(
+                &mut result,
+                scaled,
+                is_negative,
+                self.digit_limits(),
+            );
+            result.push_str(&self.expand_affix(suffix));
+        }
+        result
+    }
+
+    fn digit_limits(&self) -> (i32, i32, i32, i32) {
+        (
+            self.get_maximum_integer_digits(),
+            self.get_minimum_integer_digits(),
+            self.get_maximum_fraction_digits(),
+            self.get_minimum_fraction_digits(),
+        )
+    }
+
+    /// The index of the compact pattern for a non-negative number: that of its power of
+    /// ten, at most the last.
+    fn select_compact_pattern(&self, number: i64) -> Option<usize> {
+        if self.compact_patterns.is_empty() {
+            return None;
+        }
+        let index = if number <= 1 {
+            0
+        } else {
+            (number as f64).log10() as usize
+        };
+        Some(index.min(self.compact_patterns.len() - 1))
+    }
+
+    /// The prefix and suffix patterns of a compact pattern.
+    fn affix_patterns(&self, is_negative: bool, index: usize, category: &str) -> (&str, &str) {
+        if is_negative {
+            (
+                self.negative_prefix_patterns[index].get(category),
+                self.negative_suffix_patterns[index].get(category),
+            )
+        } else {
+            (
+                self.positive_prefix_patterns[index].get(category),
+                self.positive_suffix_patterns[index].get(category),
+            )
+        }
+    }
+
+    /// Expands an affix pattern: a quoted minus becomes the locale's minus sign and any
+    /// other quoted character itself.
+    fn expand_affix(&self, pattern: &str) -> String {
+        let mut result = String::new();
+        let mut chars = pattern.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\'' => match chars.next() {
+                    Some('-') => result.push_str(&self.symbols.minus_sign_text),
+                    Some(ch) => result.push(ch),
+                    None => {}
+                },
+                _ => result.push(ch),
+            }
+        }
+        result
+    }
+
+    /// The integer part of the number over the divisor, which picks its plural form. Like
+    /// the JDK, the quotient is rounded by the rounding mode to the scale of the number's
+    /// shortest decimal representation, then truncated to an int.
+    fn integer_part(&self, number: f64, divisor: f64) -> i32 {
+        let (dividend, dividend_scale) = big_decimal(number);
+        let (divisor, divisor_scale) = big_decimal(divisor);
+        let (numerator, denominator) = if divisor_scale >= 0 {
+            (dividend * 10u128.pow(divisor_scale as u32), divisor)
+        } else {
+            (dividend, divisor * 10u128.pow(divisor_scale.unsigned_abs()))
+        };
+        let quotient = divide(numerator, denominator, self.get_rounding_mode());
+        // `BigDecimal.intValue`: the low 32 bits of the integer part.
+        if dividend_scale >= 0 {
+            match 10u128.checked_pow(dividend_scale as u32) {
+                Some(scale) => (quotient / scale) as u32 as i32,
+                None => 0,
+            }
+        } else {
+            (quotient as u32).wrapping_mul(10u32.wrapping_pow(dividend_scale.unsigned_abs())) as i32
+        }
+    }
+
+    /// The plural category of a number: that of the first rule it matches, or "other".
+    fn plural_category(&self, number: f64) -> &str {
+        self.rules
+            .iter()
+            .find(|(_, rule)| match_plural_rule(rule, number))
+            .map_or("other", |(category, _)| category)
+    }
+
+    /// Parses a number from the start of the text, like `NumberFormat.parse(String)`.
+    pub fn parse(&self, source: &str) -> Result<Number, ParseException> {
+        let mut pos = ParsePosition::new(0);
+        match self.parse_from(source, &mut pos) {
+            Some(number) if pos.get_index() != 0 => Ok(number),
+            _ => Err(ParseException::new(
+                format!("Unparseable number: \"{source}\""),
+                pos.get_error_index().unwrap_or(0) as i32,
+            )),
+        }
+    }
+
+    /// Parses a compact number at the position, advancing it past the number, or setting
+    /// its error index and returning `None` on failure. The affixes select the compact
+    /// pattern whose divisor the number is multiplied by, so "1K" parses as 1000; an affix
+    /// without placeholder zeros parses as the divisor alone. The result is a `Long` when
+    /// it is an integer that fits one.
+    pub fn parse_from(&self, text: &str, pos: &mut ParsePosition) -> Option<Number> {
+        let start = pos.get_index();
+        let Some(rest) = text.get(start..) else {
+            pos.set_error_index(Some(start));
+            return None;
+        };
+        let nan = self.symbols.get_nan();
+        if rest.starts_with(nan) {
+            pos.set_index(start + nan.len());
+            return Some(Number::Double(f64::NAN));
+        }
+
+        let default_positive_prefix = self.default_decimal_format.get_positive_prefix();
+        let default_negative_prefix = self.default_decimal_format.get_negative_prefix();
+        // The plural forms are chosen by the number's digits, before its affixes are known.
+        let number = self.parse_number_part(text, start);
+        let category = self.plural_category(number as i32 as f64);
+        let mut got_positive = false;
+        let mut got_negative = false;
+        let mut positive_index = None;
+        let mut negative_index = None;
+        let mut matched_positive_prefix = String::new();
+        let mut matched_negative_prefix = String::new();
+        for index in 0..self.compact_patterns.len() {
+            let positive_prefix =
+                self.expand_affix(self.positive_prefix_patterns[index].get(category));
+            let negative_prefix =
+                self.expand_affix(self.negative_prefix_patterns[index].get(category));
+            if match_affix(
+                rest,
+                &positive_prefix,
+                default_positive_prefix,
+                &matched_positive_prefix,
+            ) {
+                positive_index = Some(index);
+                matched_positive_prefix = positive_prefix;
+                got_positive = true;
+            }
+            if match_affix(
+                rest,
+                &negative_prefix,
+                default_negative_prefix,
+                &matched_negative_prefix,
+            ) {
+                negative_index = Some(index);
+                matched_negative_prefix = negative_prefix;
+                got_negative = true;
+            }
+        }
+        if !got_positive && !got_negative {
+            if rest.starts_with(default_positive_prefix) {
+                matched_positive_prefix = default_positive_prefix.to_string();
+                got_positive = true;
+            }
+            if rest.starts_with(default_negative_prefix) {
+                matched_negative_prefix = default_negative_prefix.to_string();
+                got_negative = true;
+            }
+        }
+        // When both match, the longer one wins.
+        if got_positive && got_negative {
+            if matched_positive_prefix.len() > matched_negative_prefix.len() {
+                got_negative = false;
+            } else if matched_positive_prefix.len() < matched_negative_prefix.len() {
+                got_positive = false;
+            }
+        }
+
+        let mut position = start;
+        let mut multiplier = Number::Long(1);
+        if got_positive || got_negative {
+            let (prefix, index) = if got_positive {
+                (&matched_positive_prefix, positive_index)
+            } else {
+                (&matched_negative_prefix, negative_index)
+            };
+            position += prefix.len();
+            if let Some(index) = index {
+                multiplier = divisor_number(self.divisors[index]);
+                if self.placeholder_patterns[index]
+                    .get(self.plural_category(number))
+                    .is_empty()
+                {
+                    pos.set_index(position);
+                    return Some(multiplier);
+                }
+            }
+        }
+
+        let mut digits = DigitList::default();
+        let Some((end, is_infinite)) =
+            self.decimal_format
+                .subparse_number(text, position, &mut digits, false, false)
+        else {
+            pos.set_index(start);
+            pos.set_error_index(Some(start));
+            return None;
+        };
+        position = end;
+        // The fraction of an integer-only parse is skipped. The JDK throws if the number
+        // ends the text here; that is taken as there being no fraction.
+        if self.is_parse_integer_only()
+            && text[position..].starts_with(self.symbols.get_decimal_separator())
+        {
+            position += self.symbols.get_decimal_separator().len_utf8();
+            let zero = self.symbols.get_zero_digit() as i32;
+            while let Some(ch) = text[position..].chars().next() {
+                let is_digit = (0..=9).contains(&(ch as i32 - zero))
+                    || (0..=9).contains(&character::digit(ch as i32, 10));
+                if !is_digit {
+                    break;
+                }
+                position += ch.len_utf8();
+            }
+        }
+        pos.set_index(position);
+
+        let matched_prefix = if got_positive {
+            &matched_positive_prefix
+        } else {
+            &matched_negative_prefix
+        };
+        let (suffix_multiplier, is_positive) = self.compute_parse_multiplier(
+            text,
+            pos,
+            matched_prefix,
+            got_positive,
+            got_negative,
+            number,
+        )?;
+        if suffix_multiplier.long_value() != 1 {
+            multiplier = suffix_multiplier;
+        }
+        if is_infinite {
+            return Some(Number::Double(if is_positive {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }));
+        }
+        Some(
+            if digits.fits_into_long(is_positive, self.is_parse_integer_only()) {
+                let value = digits.get_long();
+                generate_parse_result(Number::Long(value), value < 0, is_positive, multiplier)
+            } else {
+                generate_parse_result(
+                    Number::Double(digits.get_double()),
+                    false,
+                    is_positive,
+                    multiplier,
+                )
+            },
+        )
+    }
+
+    /// The number that picks the plural forms of a parse: infinity, NaN, the first run of
+    /// digits after the position, or 1 if there are none.
+    fn parse_number_part(&self, text: &str, position: usize) -> f64 {
+        let rest = &text[position..];
+        if rest.starts_with(self.symbols.get_infinity()) {
+            return f64::INFINITY;
+        }
+        if rest.starts_with(self.symbols.get_nan()) {
+            return f64::NAN;
+        }
+        let mut matcher = digits_pattern().matcher(text);
+        if !matcher.find_from(position) {
+            return 1.0;
+        }
+        let digits: String = matcher
+            .group(0)
+            .unwrap_or_default()
+            .chars()
+            .map(|ch| character::get_numeric_value(ch as i32).to_string())
+            .collect();
+        digits.parse().unwrap_or(f64::NAN)
+    }
+
+    /// Matches the suffix after a parsed number against those of the compact patterns
+    /// whose prefix was matched, returning the divisor of the pattern found, or 1, and
+    /// whether the number is positive. Sets the error index and returns `None` if neither
+    /// or both of a positive and a negative suffix match.
+    fn compute_parse_multiplier(
+        &self,
+        text: &str,
+        pos: &mut ParsePosition,
+        matched_prefix: &str,
+        got_positive_prefix: bool,
+        got_negative_prefix: bool,
+        number: f64,
+    ) -> Option<(Number, bool)> {
+        let position = pos.get_index();
+        let rest = &text[position..];
+        let category = self.plural_category(number as i32 as f64);
+        let default = &self.default_decimal_format;
+        let mut got_positive = false;
+        let mut got_negative = false;
+        let mut positive_index = None;
+        let mut negative_index = None;
+        let mut matched_positive_suffix = String::new();
+        let mut matched_negative_suffix = String::new();
+        for index in 0..self.compact_patterns.len() {
+            let positive_prefix =
+                self.expand_affix(self.positive_prefix_patterns[index].get(category));
+            let negative_prefix =
+                self.expand_affix(self.negative_prefix_patterns[index].get(category));
+            let positive_suffix =
+                self.expand_affix(self.positive_suffix_patterns[index].get(category));
+            let negative_suffix =
+                self.expand_affix(self.negative_suffix_patterns[index].get(category));
+            if (positive_prefix == matched_prefix
+                || matched_prefix == default.get_positive_prefix())
+                && match_affix(
+                    rest,
+                    &positive_suffix,
+                    default.get_positive_suffix(),
+                    &matched_positive_suffix,
+                )
+            {
+                positive_index = Some(index);
+                matched_positive_suffix = positive_suffix;
+                got_positive = true;
+            }
+            if (negative_prefix == matched_prefix
+                || matched_prefix == default.get_negative_prefix())
+                && match_affix(
+                    rest,
+                    &negative_suffix,
+                    default.get_negative_suffix(),
+                    &matched_negative_suffix,
+                )
+            {
+                negative_index = Some(index);
+                matched_negative_suffix = negative_suffix;
+                got_negative = true;
+            }
+        }
+        if !got_positive && !got_negative {
+            if rest.starts_with(default.get_positive_suffix()) {
+                matched_positive_suffix = default.get_positive_suffix().to_string();
+                got_positive = true;
+            }
+            if rest.starts_with(default.get_negative_suffix()) {
+                matched_negative_suffix = default.get_negative_suffix().to_string();
+                got_negative = true;
+            }
+        }
+        // The longer suffix wins, and a tie is settled by the prefix.
+        if got_positive && got_negative {
+            if matched_positive_suffix.len() > matched_negative_suffix.len() {
+                got_negative = false;
+            } else if matched_positive_suffix.len() < matched_negative_suffix.len() {
+                got_positive = false;
+            } else {
+                got_positive = got_positive_prefix;
+                got_negative = got_negative_prefix;
+            }
+        }
+        if got_positive == got_negative {
+            pos.set_error_index(Some(position));
+            return None;
+        }
+
+        let (suffix, index) = if got_positive {
+            (matched_positive_suffix, positive_index)
+        } else {
+            (matched_negative_suffix, negative_index)
+        };
+        pos.set_index(position + suffix.len());
+        let multiplier = index.map_or(Number::Long(1), |index| {
+            divisor_number(self.divisors[index])
+        });
+        Some((multiplier, got_positive))
+    }
+}
+
+/// Like the JDK's `matchAffix`: whether the text starts with a non-empty compact affix that
+/// is not the decimal pattern's and is longer than the one matched so far.
+fn match_affix(text: &str, affix: &str, default_affix: &str, matched_affix: &str) -> bool {
+    !affix.is_empty()
+        && affix != default_affix
+        && matched_affix.len() < affix.len()
+        && text.starts_with(affix)
+}
+
+/// A divisor as the `Number` a parsed number is multiplied by.
+fn divisor_number(divisor: u128) -> Number {
+    match i64::try_from(divisor) {
+        Ok(divisor) => Number::Long(divisor),
+        Err(_) => Number::Double(divisor as f64),
+    }
+}
+
+/// The parsed number times the multiplier, with the sign applied: a `Long` if it is an
+/// integer that fits one. The digits of Long.MIN_VALUE already carry their sign.
+fn generate_parse_result(
+    number: Number,
+    got_long_minimum: bool,
+    is_positive: bool,
+    multiplier: Number,
+) -> Number {
+    let negate = !is_positive && !got_long_minimum;
+    match (number, multiplier) {
+        (Number::Double(value), _) => {
+            let mut value = value * multiplier.double_value();
+            if negate {
+                value = -value;
+            }
+            if multiplier.long_value() == 1 {
+                return Number::Double(value);
+            }
+            let long = value as i64;
+            if value != long as f64 || (value == 0.0 && value.is_sign_negative()) {
+                Number::Double(value)
+            } else {
+                Number::Long(long)
+            }
+        }
+        (Number::Long(value), Number::Long(1)) => {
+            Number::Long(if negate { value.wrapping_neg() } else { value })
+        }
+        (Number::Long(value), Number::Long(multiplier)) if !got_long_minimum => {
+            match value.checked_mul(multiplier) {
+                Some(product) => Number::Long(if negate {
+                    product.wrapping_neg()
+                } else {
+                    product
+                }),
+                None => {
+                    let product = value as f64 * multiplier as f64;
+                    Number::Double(if negate { -product } else { product })
+                }
+            }
+        }
+        (Number::Long(value), multiplier) => {
+            let product = value as f64 * multiplier.double_value();
+            Number::Double(if negate { -product } else { product })
+        }
+    }
+}
+
+/// The unscaled value and scale of `BigDecimal.valueOf` of a non-negative double, which
+/// takes the digits and notation of `Double.toString`: "1000.0" is 10000 with scale 1 and
+/// "1.0E7" is 10 with scale -6.
+fn big_decimal(value: f64) -> (u128, i32) {
+    if value == 0.0 {
+        return (0, 1);
+    }
+    let decimal = FloatingDecimal::of_double(value, true);
+    let unscaled: u128 = std::str::from_utf8(&decimal.digits)
+        .unwrap()
+        .parse()
+        .unwrap();
+    let exponent = decimal.decimal_exponent - 1;
+    let significant_fraction = decimal.digits.len() as i32 - 1;
+    // `Double.toString` shows at least one fraction digit, and uses plain notation from
+    // 10^-3 up to 10^7.
+    let (scale, shown_fraction) = if (1e-3..1e7).contains(&value) {
+        let fraction = (significant_fraction - exponent).max(1);
+        (fraction, fraction + exponent)
+    } else {
+        let fraction = significant_fraction.max(1);
+        (fraction - exponent, fraction)
+    };
+    let padding = (shown_fraction - significant_fraction) as u32;
+    (unscaled * 10u128.pow(padding), scale)
+}
+
+/// A non-negative quotient rounded to an integer by the rounding mode, like
+/// `BigDecimal.divide`. Panics with `ArithmeticException` if it needs rounding in
+/// `RoundingMode::Unnecessary`.
+fn divide(numerator: u128, denominator: u128, rounding_mode: RoundingMode) -> u128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient;
+    }
+    let half = (remainder * 2).cmp(&denominator);
+    let round_up = match rounding_mode {
+        RoundingMode::Up | RoundingMode::Ceiling => true,
+        RoundingMode::Down | RoundingMode::Floor => false,
+        RoundingMode::HalfUp => half.is_ge(),
+        RoundingMode::HalfDown => half.is_gt(),
+        RoundingMode::HalfEven => half.is_gt() || (half.is_eq() && quotient % 2 == 1),
+        RoundingMode::Unnecessary => {
+            ArithmeticException::new("Rounding necessary".to_string()).throw()
+        }
+    };
+    quotient + round_up as u128
+}
+
+/// Parses plural rules into (category, rule) pairs in `CATEGORY_ORDER`. Panics with
+/// `IllegalArgumentException` if the rules are too long, malformed or name a category twice.
+fn build_plural_rules(plural_rules: &str) -> Vec<(String, String)> {
+    if plural_rules.chars().count() > 2048 {
+        IllegalArgumentException::new("plural rules is too long (> 2,048)".to_string(), None)
+            .throw();
+    }
+    let mut rules: Vec<(String, String)> = Vec::new();
+    for rule in plural_rules.split_regex(";", 0) {
+        let rule = rule.trim();
+        if !rule.is_empty() && rule != "other:" && !plural_rules_pattern().matcher(rule).matches() {
+            IllegalArgumentException::new(format!("Invalid plural rules syntax: {rule}"), None)
+                .throw();
+        }
+        let (category, rule) = rule.split_once(':').unwrap_or((rule, ""));
+        if let Some((_, existing)) = rules.iter().find(|(key, _)| key == category) {
+            let message = format!(
+                "Duplicate key {category} (attempted merging values {existing} and {rule})"
+            );
+            IllegalArgumentException::new(
+                format!("java.lang.IllegalStateException: {message}"),
+                None,
+            )
+            .throw();
+        }
+        rules.push((category.to_string(), rule.to_string()));
+    }
+    rules.sort_by_key(|(category, _)| CATEGORY_ORDER.iter().position(|c| c == category));
+    rules
+}
+
+/// Whether a number matches a rule: any of its `or` conditions, each a conjunction of
+/// relations such as `n % 10 = 2..4`.
+fn match_plural_rule(rule: &str, number: f64) -> bool {
+    rule.split_regex("or", 0).iter().any(|condition| {
+        condition
+            .split_regex("and", 0)
+            .iter()
+            .all(|relation| relation_check(relation, number))
+    })
+}
+
+fn relation_check(relation: &str, number: f64) -> bool {
+    let mut expr = expr_pattern().matcher(relation);
+    if !expr.find() {
+        return false;
+    }
+    let operand = eval_operand(&expr, number);
+    let mut rel = relation_pattern().matcher(relation);
+    if !rel.find_from(expr.end()) {
+        return false;
+    }
+    let values = relation[rel.end()..].split_regex(",", 0);
+    let matches = values
+        .iter()
+        .any(|value| value_or_range_matches(value, operand));
+    if rel.group_named("rel") == Some("!=") {
+        !matches
+    } else {
+        matches
+    }
+}
+
+/// The operand of a relation. Only the number and its integer part are supported, as the
+/// JDK formats whole numbers; the other operands are 0.
+fn eval_operand(expr: &Matcher, number: f64) -> f64 {
+    if number == f64::INFINITY {
+        return number;
+    }
+    let mut operand = match expr.group_named("op") {
+        Some("n" | "i") => number,
+        _ => 0.0,
+    };
+    if let (Some(div), Some(value)) = (expr.group_named("div"), expr.group_named("val")) {
+        let value: f64 = value.parse().unwrap();
+        operand = if div == "%" {
+            operand % value
+        } else {
+            operand / value
+        };
+    }
+    operand
+}
+
+fn value_or_range_matches(value: &str, operand: f64) -> bool {
+    let mut matcher = value_range_pattern().matcher(value);
+    if !matcher.find() {
+        return false;
+    }
+    let parse = |name: &str| -> f64 { matcher.group_named(name).unwrap().parse().unwrap() };
+    match matcher.group_named("value") {
+        Some(value) => operand == value.parse::<f64>().unwrap(),
+        None => parse("start") <= operand && operand <= parse("end"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_like_jdk() {
+        let short = CompactNumberFormat::get_compact_number_instance(&Locale::US, Style::Short);
+        let long = CompactNumberFormat::get_compact_number_instance(&Locale::US, Style::Long);
+        assert_eq!(short.format(1234), "1K");
+        assert_eq!(short.format(-1_500_000), "-2M");
+        assert_eq!(short.format(999), "999");
+        assert_eq!(long.format(1234), "1 thousand");
+        assert_eq!(long.format(2_000_000_000), "2 billion");
+        assert_eq!(short.format(f64::NAN), "NaN");
+
+        let mut format = short.clone();
+        format.set_maximum_fraction_digits(2);
+        format.set_rounding_mode(RoundingMode::Down);
+        assert_eq!(format.format(1299), "1.29K");
+        assert_eq!(format.format(1234.5678), "1.23K");
+
+        let custom = CompactNumberFormat::with_plural_rules(
+            "#,##0",
+            DecimalFormatSymbols::get_instance(&Locale::US),
+            &["", "", "", "{one:0' 'grand other:0' 'grands}"],
+            "one:i = 1",
+        );
+        assert_eq!(custom.format(1000), "1 grand");
+        assert_eq!(custom.format(5000), "5 grands");
+
+        // The plural form follows the digits of `Double.toString`, not the shortest ones.
+        let russian = CompactNumberFormat::get_compact_number_instance(
+            &Locale::for_language_tag("ru-RU"),
+            Style::Long,
+        );
+        assert_eq!(russian.format(247444056417849153i64), "247444 триллиона");
+        assert_eq!(russian.format(69931428285529292i64), "69931 триллион");
+    }
+
+    #[test]
+    fn parses_like_jdk() {
+        let short = CompactNumberFormat::get_compact_number_instance(&Locale::US, Style::Short);
+        assert_eq!(short.parse("1K"), Ok(Number::Long(1000)));
+        assert_eq!(short.parse("-1.5M"), Ok(Number::Long(-1_500_000)));
+        assert_eq!(short.parse("12"), Ok(Number::Long(12)));
+        let error = short.parse("K").unwrap_err();
+        assert_eq!(error.get_error_offset(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid plural rules syntax: one: x = 1")]
+    fn rejects_malformed_plural_rules() {
+        CompactNumberFormat::with_plural_rules(
+            "#",
+            DecimalFormatSymbols::get_instance(&Locale::US),
+            &["", "0K"],
+            "one: x = 1",
+        );
+    }
+}
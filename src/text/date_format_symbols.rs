@@ -0,0 +1,69 @@
+use crate::util::Locale;
+
+use super::locale_data::{self, LONG_FORMAT, SHORT_FORMAT};
+
+/// `java.text.DateFormatSymbols`: the names of months, week days and AM/PM a locale
+/// formats dates with. Like Java's, the arrays are indexed by `Calendar` field value, so
+/// months have an empty 13th entry and week days an empty entry before Sunday.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateFormatSymbols {
+    months: Vec<String>,
+    short_months: Vec<String>,
+    weekdays: Vec<String>,
+    short_weekdays: Vec<String>,
+    am_pm_strings: Vec<String>,
+}
+
+impl DateFormatSymbols {
+    /// The symbols of the default locale.
+    pub fn new() -> Self {
+        Self::get_instance(&Locale::get_default())
+    }
+
+    pub fn get_instance(locale: &Locale) -> Self {
+        let data = locale_data::date_data(locale);
+        let names = |names: &[Option<&str>]| -> Vec<String> {
+            names
+                .iter()
+                .map(|name| name.unwrap_or_default().to_string())
+                .collect()
+        };
+        let with_empty = |mut names: Vec<String>, at: usize| {
+            names.insert(at, String::new());
+            names
+        };
+        Self {
+            months: with_empty(names(&data.months[LONG_FORMAT]), 12),
+            short_months: with_empty(names(&data.months[SHORT_FORMAT]), 12),
+            weekdays: with_empty(names(&data.weekdays[LONG_FORMAT]), 0),
+            short_weekdays: with_empty(names(&data.weekdays[SHORT_FORMAT]), 0),
+            am_pm_strings: names(&data.am_pm[SHORT_FORMAT]),
+        }
+    }
+
+    pub fn get_months(&self) -> &[String] {
+        &self.months
+    }
+
+    pub fn get_short_months(&self) -> &[String] {
+        &self.short_months
+    }
+
+    pub fn get_weekdays(&self) -> &[String] {
+        &self.weekdays
+    }
+
+    pub fn get_short_weekdays(&self) -> &[String] {
+        &self.short_weekdays
+    }
+
+    pub fn get_am_pm_strings(&self) -> &[String] {
+        &self.am_pm_strings
+    }
+}
+
+impl Default for DateFormatSymbols {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,1881 @@
+use crate::character;
+use crate::errors::{ArithmeticException, IllegalArgumentException, ParseException, Throwable};
+use crate::floating_decimal::FloatingDecimal;
+use crate::math::RoundingMode;
+use crate::util::Locale;
+
+use super::locale_data::{self, NumberData};
+use super::ParsePosition;
+
+/// A value formatted or parsed by a number format: Java's `Long` or `Double`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Long(i64),
+    Double(f64),
+}
+
+impl Number {
+    pub fn int_value(&self) -> i32 {
+        match *self {
+            Number::Long(value) => value as i32,
+            Number::Double(value) => value as i32,
+        }
+    }
+
+    pub fn long_value(&self) -> i64 {
+        match *self {
+            Number::Long(value) => value,
+            Number::Double(value) => value as i64,
+        }
+    }
+
+    pub fn double_value(&self) -> f64 {
+        match *self {
+            Number::Long(value) => value as f64,
+            Number::Double(value) => value,
+        }
+    }
+}
+
+macro_rules! number_from {
+    ($variant: ident, $($type: ty),+) => {
+        $(
+            impl From<$type> for Number {
+                fn from(value: $type) -> Self {
+                    Number::$variant(value.into())
+                }
+            }
+        )+
+    };
+}
+
+number_from!(Long, i8, i16, i32, i64, u8, u16, u32);
+number_from!(Double, f32, f64);
+
+/// `java.text.DecimalFormatSymbols`: the characters and strings a locale formats numbers
+/// with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecimalFormatSymbols {
+    locale: Locale,
+    zero_digit: char,
+    grouping_separator: char,
+    decimal_separator: char,
+    per_mill: char,
+    percent: char,
+    digit: char,
+    pattern_separator: char,
+    infinity: String,
+    nan: String,
+    minus_sign: char,
+    currency_symbol: String,
+    intl_currency_symbol: String,
+    monetary_separator: char,
+    exponent_separator: String,
+    per_mill_text: String,
+    percent_text: String,
+    pub(super) minus_sign_text: String,
+    monetary_grouping_separator: char,
+    currency_fraction_digits: i32,
+}
+
+impl DecimalFormatSymbols {
+    /// The symbols of the default locale.
+    pub fn new() -> Self {
+        Self::get_instance(&Locale::get_default())
+    }
+
+    pub fn get_instance(locale: &Locale) -> Self {
+        let data: &NumberData = locale_data::number_data(locale);
+        Self {
+            locale: locale.clone(),
+            zero_digit: data.zero_digit,
+            grouping_separator: data.grouping_separator,
+            decimal_separator: data.decimal_separator,
+            per_mill: data.per_mill,
+            percent: data.percent,
+            digit: data.digit,
+            pattern_separator: data.pattern_separator,
+            infinity: data.infinity.to_string(),
+            nan: data.nan.to_string(),
+            minus_sign: data.minus_sign,
+            currency_symbol: data.currency_symbol.to_string(),
+            intl_currency_symbol: data.intl_currency_symbol.to_string(),
+            monetary_separator: data.monetary_separator,
+            exponent_separator: data.exponent_separator.to_string(),
+            per_mill_text: data.per_mill_text.to_string(),
+            percent_text: data.percent_text.to_string(),
+            minus_sign_text: data.minus_sign_text.to_string(),
+            monetary_grouping_separator: data.monetary_grouping_separator,
+            currency_fraction_digits: data.currency_fraction_digits,
+        }
+    }
+
+    pub fn get_locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn get_zero_digit(&self) -> char {
+        self.zero_digit
+    }
+
+    pub fn get_grouping_separator(&self) -> char {
+        self.grouping_separator
+    }
+
+    pub fn get_decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    pub fn get_per_mill(&self) -> char {
+        self.per_mill
+    }
+
+    pub fn get_percent(&self) -> char {
+        self.percent
+    }
+
+    pub fn get_digit(&self) -> char {
+        self.digit
+    }
+
+    pub fn get_pattern_separator(&self) -> char {
+        self.pattern_separator
+    }
+
+    pub fn get_infinity(&self) -> &str {
+        &self.infinity
+    }
+
+    pub fn get_nan(&self) -> &str {
+        &self.nan
+    }
+
+    pub fn get_minus_sign(&self) -> char {
+        self.minus_sign
+    }
+
+    pub fn get_currency_symbol(&self) -> &str {
+        &self.currency_symbol
+    }
+
+    pub fn get_international_currency_symbol(&self) -> &str {
+        &self.intl_currency_symbol
+    }
+
+    pub fn get_monetary_decimal_separator(&self) -> char {
+        self.monetary_separator
+    }
+
+    pub fn get_exponent_separator(&self) -> &str {
+        &self.exponent_separator
+    }
+
+    pub fn get_monetary_grouping_separator(&self) -> char {
+        self.monetary_grouping_separator
+    }
+
+    pub fn set_zero_digit(&mut self, zero_digit: char) {
+        self.zero_digit = zero_digit;
+    }
+
+    pub fn set_grouping_separator(&mut self, grouping_separator: char) {
+        self.grouping_separator = grouping_separator;
+    }
+
+    pub fn set_decimal_separator(&mut self, decimal_separator: char) {
+        self.decimal_separator = decimal_separator;
+    }
+
+    /// Also replaces the per-mille text that formats use.
+    pub fn set_per_mill(&mut self, per_mill: char) {
+        self.per_mill = per_mill;
+        self.per_mill_text = per_mill.to_string();
+    }
+
+    /// Also replaces the percent text that formats use.
+    pub fn set_percent(&mut self, percent: char) {
+        self.percent = percent;
+        self.percent_text = percent.to_string();
+    }
+
+    pub fn set_digit(&mut self, digit: char) {
+        self.digit = digit;
+    }
+
+    pub fn set_pattern_separator(&mut self, pattern_separator: char) {
+        self.pattern_separator = pattern_separator;
+    }
+
+    pub fn set_infinity(&mut self, infinity: &str) {
+        self.infinity = infinity.to_string();
+    }
+
+    pub fn set_nan(&mut self, nan: &str) {
+        self.nan = nan.to_string();
+    }
+
+    /// Also replaces the minus sign text that formats use.
+    pub fn set_minus_sign(&mut self, minus_sign: char) {
+        self.minus_sign = minus_sign;
+        self.minus_sign_text = minus_sign.to_string();
+    }
+
+    pub fn set_currency_symbol(&mut self, currency_symbol: &str) {
+        self.currency_symbol = currency_symbol.to_string();
+    }
+
+    /// Unlike the JDK, this does not look the currency up to replace the local symbol too.
+    pub fn set_international_currency_symbol(&mut self, intl_currency_symbol: &str) {
+        self.intl_currency_symbol = intl_currency_symbol.to_string();
+    }
+
+    pub fn set_monetary_decimal_separator(&mut self, monetary_separator: char) {
+        self.monetary_separator = monetary_separator;
+    }
+
+    pub fn set_exponent_separator(&mut self, exponent_separator: &str) {
+        self.exponent_separator = exponent_separator.to_string();
+    }
+
+    pub fn set_monetary_grouping_separator(&mut self, monetary_grouping_separator: char) {
+        self.monetary_grouping_separator = monetary_grouping_separator;
+    }
+}
+
+impl Default for DecimalFormatSymbols {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const PATTERN_ZERO_DIGIT: char = '0';
+const PATTERN_GROUPING_SEPARATOR: char = ',';
+const PATTERN_DECIMAL_SEPARATOR: char = '.';
+const PATTERN_PER_MILLE: char = '\u{2030}';
+const PATTERN_PERCENT: char = '%';
+const PATTERN_DIGIT: char = '#';
+const PATTERN_SEPARATOR: char = ';';
+const PATTERN_EXPONENT: &str = "E";
+const PATTERN_MINUS: char = '-';
+const CURRENCY_SIGN: char = '\u{00A4}';
+const QUOTE: char = '\'';
+
+/// The most digits a double can need on either side of the decimal point.
+pub(super) const DOUBLE_INTEGER_DIGITS: i32 = 309;
+pub(super) const DOUBLE_FRACTION_DIGITS: i32 = 340;
+
+/// The number styles of a locale's `NumberData` patterns.
+pub(super) const NUMBER_STYLE: usize = 0;
+pub(super) const INTEGER_STYLE: usize = 1;
+pub(super) const PERCENT_STYLE: usize = 2;
+pub(super) const CURRENCY_STYLE: usize = 3;
+
+/// `java.text.DecimalFormat`: formats and parses decimal numbers by a pattern such as
+/// `#,##0.00;(#,##0.00)`, with the symbols of a locale.
+///
+/// Doubles are rounded from their shortest decimal representation, half-even unless another
+/// `RoundingMode` is set, exactly like the JDK's, which also settles ties by whether that
+/// representation was rounded. Parse positions are byte offsets into the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecimalFormat {
+    symbols: DecimalFormatSymbols,
+    positive_prefix: String,
+    positive_suffix: String,
+    negative_prefix: String,
+    negative_suffix: String,
+    /// The affixes as given by the pattern, with each special character quoted to be
+    /// expanded to its symbol.
+    pos_prefix_pattern: String,
+    pos_suffix_pattern: String,
+    neg_prefix_pattern: String,
+    neg_suffix_pattern: String,
+    multiplier: i32,
+    grouping_size: i32,
+    grouping_used: bool,
+    decimal_separator_always_shown: bool,
+    parse_integer_only: bool,
+    is_currency_format: bool,
+    use_exponential_notation: bool,
+    min_exponent_digits: i32,
+    maximum_integer_digits: i32,
+    minimum_integer_digits: i32,
+    maximum_fraction_digits: i32,
+    minimum_fraction_digits: i32,
+    rounding_mode: RoundingMode,
+}
+
+impl DecimalFormat {
+    /// A format for the pattern with the symbols of the default locale. Panics with
+    /// `IllegalArgumentException` if the pattern is malformed.
+    pub fn new(pattern: &str) -> Self {
+        Self::with_symbols(pattern, DecimalFormatSymbols::new())
+    }
+
+    pub fn with_symbols(pattern: &str, symbols: DecimalFormatSymbols) -> Self {
+        let mut format = Self {
+            symbols,
+            positive_prefix: String::new(),
+            positive_suffix: String::new(),
+            negative_prefix: String::new(),
+            negative_suffix: String::new(),
+            pos_prefix_pattern: String::new(),
+            pos_suffix_pattern: String::new(),
+            neg_prefix_pattern: String::new(),
+            neg_suffix_pattern: String::new(),
+            multiplier: 1,
+            grouping_size: 3,
+            grouping_used: true,
+            decimal_separator_always_shown: false,
+            parse_integer_only: false,
+            is_currency_format: false,
+            use_exponential_notation: false,
+            min_exponent_digits: 0,
+            maximum_integer_digits: i32::MAX,
+            minimum_integer_digits: 1,
+            maximum_fraction_digits: 3,
+            minimum_fraction_digits: 0,
+            rounding_mode: RoundingMode::HalfEven,
+        };
+        format.apply_pattern(pattern);
+        format
+    }
+
+    /// `NumberFormat.getInstance`: the locale's general-purpose number format.
+    pub fn get_instance(locale: &Locale) -> Self {
+        Self::for_style(locale, NUMBER_STYLE)
+    }
+
+    /// `NumberFormat.getNumberInstance`, the same as `get_instance`.
+    pub fn get_number_instance(locale: &Locale) -> Self {
+        Self::for_style(locale, NUMBER_STYLE)
+    }
+
+    /// `NumberFormat.getIntegerInstance`: the locale's number format rounded to integers,
+    /// which also parses only the integer part.
+    pub fn get_integer_instance(locale: &Locale) -> Self {
+        Self::for_style(locale, INTEGER_STYLE)
+    }
+
+    /// `NumberFormat.getPercentInstance`.
+    pub fn get_percent_instance(locale: &Locale) -> Self {
+        Self::for_style(locale, PERCENT_STYLE)
+    }
+
+    /// `NumberFormat.getCurrencyInstance`: the locale's currency format, with the fraction
+    /// digits of its currency.
+    pub fn get_currency_instance(locale: &Locale) -> Self {
+        Self::for_style(locale, CURRENCY_STYLE)
+    }
+
+    /// A locale's format for one of the number styles.
+    pub(super) fn for_style(locale: &Locale, style: usize) -> Self {
+        let symbols = DecimalFormatSymbols::get_instance(locale);
+        let mut format =
+            Self::with_symbols(locale_data::number_data(locale).patterns[style], symbols);
+        if style == INTEGER_STYLE {
+            format.set_maximum_fraction_digits(0);
+            format.set_decimal_separator_always_shown(false);
+            format.set_parse_integer_only(true);
+        }
+        format
+    }
+
+    pub fn get_decimal_format_symbols(&self) -> &DecimalFormatSymbols {
+        &self.symbols
+    }
+
+    pub fn set_decimal_format_symbols(&mut self, symbols: DecimalFormatSymbols) {
+        self.symbols = symbols;
+        self.expand_affixes();
+    }
+
+    pub fn get_positive_prefix(&self) -> &str {
+        &self.positive_prefix
+    }
+
+    pub fn get_positive_suffix(&self) -> &str {
+        &self.positive_suffix
+    }
+
+    pub fn get_negative_prefix(&self) -> &str {
+        &self.negative_prefix
+    }
+
+    pub fn get_negative_suffix(&self) -> &str {
+        &self.negative_suffix
+    }
+
+    pub fn get_multiplier(&self) -> i32 {
+        self.multiplier
+    }
+
+    pub fn set_multiplier(&mut self, multiplier: i32) {
+        self.multiplier = multiplier;
+    }
+
+    pub fn get_grouping_size(&self) -> i32 {
+        self.grouping_size
+    }
+
+    /// Panics with `IllegalArgumentException` if the size is negative or above 127.
+    pub fn set_grouping_size(&mut self, size: i32) {
+        if size < 0 {
+            IllegalArgumentException::new(format!("newValue is negative: {size}"), None).throw();
+        }
+        if size > i8::MAX as i32 {
+            IllegalArgumentException::new(
+                format!("newValue exceeds MAXIMUM_GROUPING_DIGITS: {size}"),
+                None,
+            )
+            .throw();
+        }
+        self.grouping_size = size;
+    }
+
+    pub fn is_grouping_used(&self) -> bool {
+        self.grouping_used
+    }
+
+    pub fn set_grouping_used(&mut self, used: bool) {
+        self.grouping_used = used;
+    }
+
+    pub fn is_decimal_separator_always_shown(&self) -> bool {
+        self.decimal_separator_always_shown
+    }
+
+    pub fn set_decimal_separator_always_shown(&mut self, shown: bool) {
+        self.decimal_separator_always_shown = shown;
+    }
+
+    pub fn is_parse_integer_only(&self) -> bool {
+        self.parse_integer_only
+    }
+
+    pub fn set_parse_integer_only(&mut self, integer_only: bool) {
+        self.parse_integer_only = integer_only;
+    }
+
+    pub fn get_maximum_integer_digits(&self) -> i32 {
+        self.maximum_integer_digits
+    }
+
+    pub fn set_maximum_integer_digits(&mut self, digits: i32) {
+        self.maximum_integer_digits = digits.max(0);
+        self.minimum_integer_digits = self.minimum_integer_digits.min(self.maximum_integer_digits);
+    }
+
+    pub fn get_minimum_integer_digits(&self) -> i32 {
+        self.minimum_integer_digits
+    }
+
+    pub fn set_minimum_integer_digits(&mut self, digits: i32) {
+        self.minimum_integer_digits = digits.max(0);
+        self.maximum_integer_digits = self.maximum_integer_digits.max(self.minimum_integer_digits);
+    }
+
+    pub fn get_maximum_fraction_digits(&self) -> i32 {
+        self.maximum_fraction_digits
+    }
+
+    pub fn set_maximum_fraction_digits(&mut self, digits: i32) {
+        self.maximum_fraction_digits = digits.max(0);
+        self.minimum_fraction_digits = self
+            .minimum_fraction_digits
+            .min(self.maximum_fraction_digits);
+    }
+
+    pub fn get_minimum_fraction_digits(&self) -> i32 {
+        self.minimum_fraction_digits
+    }
+
+    pub fn set_minimum_fraction_digits(&mut self, digits: i32) {
+        self.minimum_fraction_digits = digits.max(0);
+        self.maximum_fraction_digits = self
+            .maximum_fraction_digits
+            .max(self.minimum_fraction_digits);
+    }
+
+    pub fn get_rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    pub fn set_rounding_mode(&mut self, rounding_mode: RoundingMode) {
+        self.rounding_mode = rounding_mode;
+    }
+
+    /// Replaces the pattern, like `DecimalFormat.applyPattern`. Panics with
+    /// `IllegalArgumentException` if it is malformed.
+    pub fn apply_pattern(&mut self, pattern: &str) {
+        let malformed = |message: &str| -> ! {
+            IllegalArgumentException::new(format!("{message} in pattern \"{pattern}\""), None)
+                .throw()
+        };
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut got_negative = false;
+        self.decimal_separator_always_shown = false;
+        self.is_currency_format = false;
+        self.use_exponential_notation = false;
+
+        let mut start = 0;
+        for j in (0..=1).rev() {
+            if start >= chars.len() {
+                break;
+            }
+            let mut in_quote = false;
+            let mut prefix = String::new();
+            let mut suffix = String::new();
+            let mut decimal_pos: i32 = -1;
+            let mut multiplier = 1;
+            let (mut digit_left_count, mut zero_digit_count, mut digit_right_count) = (0, 0, 0);
+            let mut grouping_count: i32 = -1;
+
+            // Phase 0 is the prefix, 1 the digits and separators, and 2 the suffix. Percent,
+            // per mille and currency signs are only recognized in the affixes, and digit
+            // characters must be quoted there.
+            let mut phase = 0;
+            let mut in_suffix = false;
+
+            let mut pos = start;
+            while pos < chars.len() {
+                let ch = chars[pos];
+                let affix = if in_suffix { &mut suffix } else { &mut prefix };
+                if phase != 1 {
+                    if in_quote {
+                        // Either the closing quote or two quotes, a literal one.
+                        if ch == QUOTE {
+                            if chars.get(pos + 1) == Some(&QUOTE) {
+                                pos += 1;
+                                affix.push_str("''");
+                            } else {
+                                in_quote = false;
+                            }
+                            pos += 1;
+                            continue;
+                        }
+                    } else if ch == PATTERN_DIGIT
+                        || ch == PATTERN_ZERO_DIGIT
+                        || ch == PATTERN_GROUPING_SEPARATOR
+                        || ch == PATTERN_DECIMAL_SEPARATOR
+                    {
+                        phase = 1;
+                        continue;
+                    } else if ch == CURRENCY_SIGN {
+                        let doubled = chars.get(pos + 1) == Some(&CURRENCY_SIGN);
+                        if doubled {
+                            pos += 1;
+                        }
+                        self.is_currency_format = true;
+                        affix.push_str(if doubled {
+                            "'\u{00A4}\u{00A4}"
+                        } else {
+                            "'\u{00A4}"
+                        });
+                        pos += 1;
+                        continue;
+                    } else if ch == QUOTE {
+                        // Either the opening quote or two quotes, a literal one.
+                        if chars.get(pos + 1) == Some(&QUOTE) {
+                            pos += 1;
+                            affix.push_str("''");
+                        } else {
+                            in_quote = true;
+                        }
+                        pos += 1;
+                        continue;
+                    } else if ch == PATTERN_SEPARATOR {
+                        // Only allowed after the digits of the positive pattern.
+                        if phase == 0 || j == 0 {
+                            IllegalArgumentException::new(
+                                format!(
+                                    "Unquoted special character '{ch}' in pattern \"{pattern}\""
+                                ),
+                                None,
+                            )
+                            .throw();
+                        }
+                        start = pos + 1;
+                        break;
+                    } else if ch == PATTERN_PERCENT || ch == PATTERN_PER_MILLE {
+                        if multiplier != 1 {
+                            malformed("Too many percent/per mille characters");
+                        }
+                        multiplier = if ch == PATTERN_PERCENT { 100 } else { 1000 };
+                        affix.push(QUOTE);
+                        affix.push(ch);
+                        pos += 1;
+                        continue;
+                    } else if ch == PATTERN_MINUS {
+                        affix.push_str("'-");
+                        pos += 1;
+                        continue;
+                    }
+                    affix.push(ch);
+                    pos += 1;
+                    continue;
+                }
+
+                // The negative subpattern only gives the negative affixes, so its digits
+                // are skipped.
+                if j == 0 {
+                    while pos < chars.len() {
+                        let ch = chars[pos];
+                        if ch == PATTERN_DIGIT
+                            || ch == PATTERN_ZERO_DIGIT
+                            || ch == PATTERN_GROUPING_SEPARATOR
+                            || ch == PATTERN_DECIMAL_SEPARATOR
+                        {
+                            pos += 1;
+                        } else if starts_with_at(&chars, pos, PATTERN_EXPONENT) {
+                            pos += PATTERN_EXPONENT.len();
+                        } else {
+                            phase = 2;
+                            in_suffix = true;
+                            break;
+                        }
+                    }
+                    continue;
+                }
+
+                if ch == PATTERN_DIGIT {
+                    if zero_digit_count > 0 {
+                        digit_right_count += 1;
+                    } else {
+                        digit_left_count += 1;
+                    }
+                    if grouping_count >= 0 && decimal_pos < 0 {
+                        grouping_count += 1;
+                    }
+                } else if ch == PATTERN_ZERO_DIGIT {
+                    if digit_right_count > 0 {
+                        IllegalArgumentException::new(
+                            format!("Unexpected '0' in pattern \"{pattern}\""),
+                            None,
+                        )
+                        .throw();
+                    }
+                    zero_digit_count += 1;
+                    if grouping_count >= 0 && decimal_pos < 0 {
+                        grouping_count += 1;
+                    }
+                } else if ch == PATTERN_GROUPING_SEPARATOR {
+                    grouping_count = 0;
+                } else if ch == PATTERN_DECIMAL_SEPARATOR {
+                    if decimal_pos >= 0 {
+                        malformed("Multiple decimal separators");
+                    }
+                    decimal_pos = digit_left_count + zero_digit_count + digit_right_count;
+                } else if starts_with_at(&chars, pos, PATTERN_EXPONENT) {
+                    if self.use_exponential_notation {
+                        malformed("Multiple exponential symbols");
+                    }
+                    self.use_exponential_notation = true;
+                    self.min_exponent_digits = 0;
+                    pos += PATTERN_EXPONENT.len();
+                    while chars.get(pos) == Some(&PATTERN_ZERO_DIGIT) {
+                        self.min_exponent_digits += 1;
+                        pos += 1;
+                    }
+                    if digit_left_count + zero_digit_count < 1 || self.min_exponent_digits < 1 {
+                        IllegalArgumentException::new(
+                            format!("Malformed exponential pattern \"{pattern}\""),
+                            None,
+                        )
+                        .throw();
+                    }
+                    phase = 2;
+                    in_suffix = true;
+                    continue;
+                } else {
+                    phase = 2;
+                    in_suffix = true;
+                    continue;
+                }
+                pos += 1;
+            }
+            if pos >= chars.len() {
+                start = chars.len();
+            }
+
+            // Patterns without a '0' are legal, but interpreted: "##.###" as "#0.###" and
+            // ".###" as ".0##".
+            if zero_digit_count == 0 && digit_left_count > 0 && decimal_pos >= 0 {
+                let n = if decimal_pos == 0 { 1 } else { decimal_pos };
+                digit_right_count = digit_left_count - n;
+                digit_left_count = n - 1;
+                zero_digit_count = 1;
+            }
+
+            if (decimal_pos < 0 && digit_right_count > 0)
+                || (decimal_pos >= 0
+                    && (decimal_pos < digit_left_count
+                        || decimal_pos > digit_left_count + zero_digit_count))
+                || grouping_count == 0
+                || in_quote
+            {
+                IllegalArgumentException::new(format!("Malformed pattern \"{pattern}\""), None)
+                    .throw();
+            }
+
+            if j == 1 {
+                self.pos_prefix_pattern = prefix;
+                self.pos_suffix_pattern = suffix;
+                self.neg_prefix_pattern = self.pos_prefix_pattern.clone();
+                self.neg_suffix_pattern = self.pos_suffix_pattern.clone();
+                let digit_total_count = digit_left_count + zero_digit_count + digit_right_count;
+                let effective_decimal_pos = if decimal_pos >= 0 {
+                    decimal_pos
+                } else {
+                    digit_total_count
+                };
+                self.set_minimum_integer_digits(effective_decimal_pos - digit_left_count);
+                self.set_maximum_integer_digits(if self.use_exponential_notation {
+                    digit_left_count + self.minimum_integer_digits
+                } else {
+                    i32::MAX
+                });
+                self.set_maximum_fraction_digits(if decimal_pos >= 0 {
+                    digit_total_count - decimal_pos
+                } else {
+                    0
+                });
+                self.set_minimum_fraction_digits(if decimal_pos >= 0 {
+                    digit_left_count + zero_digit_count - decimal_pos
+                } else {
+                    0
+                });
+                self.grouping_used = grouping_count > 0;
+                self.grouping_size = grouping_count.max(0);
+                self.multiplier = multiplier;
+                self.decimal_separator_always_shown =
+                    decimal_pos == 0 || decimal_pos == digit_total_count;
+            } else {
+                self.neg_prefix_pattern = prefix;
+                self.neg_suffix_pattern = suffix;
+                got_negative = true;
+            }
+        }
+
+        if pattern.is_empty() {
+            self.pos_prefix_pattern.clear();
+            self.pos_suffix_pattern.clear();
+            self.set_minimum_integer_digits(0);
+            self.set_maximum_integer_digits(i32::MAX);
+            self.set_minimum_fraction_digits(0);
+            self.set_maximum_fraction_digits(i32::MAX);
+        }
+
+        // Without a distinct negative subpattern, negative numbers get a minus sign in
+        // front of the positive prefix.
+        if !got_negative
+            || (self.neg_prefix_pattern == self.pos_prefix_pattern
+                && self.neg_suffix_pattern == self.pos_suffix_pattern)
+        {
+            self.neg_suffix_pattern = self.pos_suffix_pattern.clone();
+            self.neg_prefix_pattern = format!("'-{}", self.pos_prefix_pattern);
+        }
+
+        self.expand_affixes();
+    }
+
+    fn expand_affixes(&mut self) {
+        self.positive_prefix = self.expand_affix(&self.pos_prefix_pattern);
+        self.positive_suffix = self.expand_affix(&self.pos_suffix_pattern);
+        self.negative_prefix = self.expand_affix(&self.neg_prefix_pattern);
+        self.negative_suffix = self.expand_affix(&self.neg_suffix_pattern);
+    }
+
+    /// Replaces the quoted special characters of an affix pattern by their symbols.
+    fn expand_affix(&self, pattern: &str) -> String {
+        let mut buffer = String::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == QUOTE {
+                let Some(c) = chars.next() else { break };
+                match c {
+                    CURRENCY_SIGN => {
+                        if chars.next_if_eq(&CURRENCY_SIGN).is_some() {
+                            buffer.push_str(&self.symbols.intl_currency_symbol);
+                        } else {
+                            buffer.push_str(&self.symbols.currency_symbol);
+                        }
+                    }
+                    PATTERN_PERCENT => buffer.push_str(&self.symbols.percent_text),
+                    PATTERN_PER_MILLE => buffer.push_str(&self.symbols.per_mill_text),
+                    PATTERN_MINUS => buffer.push_str(&self.symbols.minus_sign_text),
+                    _ => buffer.push(c),
+                }
+            } else {
+                buffer.push(c);
+            }
+        }
+        buffer
+    }
+
+    /// The pattern of the format, like `DecimalFormat.toPattern`.
+    pub fn to_pattern(&self) -> String {
+        let mut result = String::new();
+        for j in (0..=1).rev() {
+            append_affix_pattern(
+                &mut result,
+                if j == 1 {
+                    &self.pos_prefix_pattern
+                } else {
+                    &self.neg_prefix_pattern
+                },
+            );
+            let digit_count = if self.use_exponential_notation {
+                self.maximum_integer_digits
+            } else {
+                self.grouping_size.max(self.minimum_integer_digits) + 1
+            };
+            for i in (1..=digit_count).rev() {
+                if i != digit_count
+                    && self.grouping_used
+                    && self.grouping_size != 0
+                    && i % self.grouping_size == 0
+                {
+                    result.push(PATTERN_GROUPING_SEPARATOR);
+                }
+                result.push(if i <= self.minimum_integer_digits {
+                    PATTERN_ZERO_DIGIT
+                } else {
+                    PATTERN_DIGIT
+                });
+            }
+            let maximum_fraction_digits = self.maximum_fraction_digits.min(DOUBLE_FRACTION_DIGITS);
+            if maximum_fraction_digits > 0 || self.decimal_separator_always_shown {
+                result.push(PATTERN_DECIMAL_SEPARATOR);
+            }
+            for i in 0..maximum_fraction_digits {
+                result.push(if i < self.minimum_fraction_digits {
+                    PATTERN_ZERO_DIGIT
+                } else {
+                    PATTERN_DIGIT
+                });
+            }
+            if self.use_exponential_notation {
+                result.push_str(PATTERN_EXPONENT);
+                for _ in 0..self.min_exponent_digits {
+                    result.push(PATTERN_ZERO_DIGIT);
+                }
+            }
+            if j == 1 {
+                append_affix_pattern(&mut result, &self.pos_suffix_pattern);
+                if self.neg_suffix_pattern == self.pos_suffix_pattern
+                    && self.neg_prefix_pattern == format!("'-{}", self.pos_prefix_pattern)
+                {
+                    break;
+                }
+                result.push(PATTERN_SEPARATOR);
+            } else {
+                append_affix_pattern(&mut result, &self.neg_suffix_pattern);
+            }
+        }
+        result
+    }
+
+    /// Formats a number. Integers are formatted exactly and doubles half-even from their
+    /// shortest representation.
+    pub fn format<N: Into<Number>>(&self, number: N) -> String {
+        match number.into() {
+            Number::Long(value) => self.format_long(value),
+            Number::Double(value) => self.format_double(value),
+        }
+    }
+
+    fn format_double(&self, number: f64) -> String {
+        let mut result = String::new();
+        if number.is_nan() || (number.is_infinite() && self.multiplier == 0) {
+            result.push_str(&self.symbols.nan);
+            return result;
+        }
+        let is_negative =
+            (number < 0.0 || (number == 0.0 && number.is_sign_negative())) ^ (self.multiplier < 0);
+        let mut number = number;
+        if self.multiplier != 1 {
+            number *= self.multiplier as f64;
+        }
+        if number.is_infinite() {
+            let (prefix, suffix) = self.affixes(is_negative);
+            result.push_str(prefix);
+            result.push_str(&self.symbols.infinity);
+            result.push_str(suffix);
+            return result;
+        }
+        if is_negative {
+            number = -number;
+        }
+        self.double_subformat(&mut result, number, is_negative);
+        result
+    }
+
+    /// Appends a finite, non-negative double with its affixes, ignoring the multiplier.
+    pub(super) fn double_subformat(&self, result: &mut String, number: f64, is_negative: bool) {
+        let limits = self.digit_limits(DOUBLE_INTEGER_DIGITS, DOUBLE_FRACTION_DIGITS);
+        let (max_int, _, max_fra, _) = limits;
+        let mut digits = self.digit_list();
+        digits.set_double(
+            is_negative,
+            number,
+            if self.use_exponential_notation {
+                max_int.saturating_add(max_fra)
+            } else {
+                max_fra
+            },
+            !self.use_exponential_notation,
+        );
+        self.subformat(result, &mut digits, is_negative, false, limits);
+    }
+
+    /// Appends the digits of a non-negative number without affixes, within the given
+    /// maximum and minimum integer and fraction digits. An integer is shown exactly and a
+    /// double rounded to the maximum fraction digits.
+    pub(super) fn subformat_digits(
+        &self,
+        result: &mut String,
+        number: Number,
+        is_negative: bool,
+        limits: (i32, i32, i32, i32),
+    ) {
+        let mut digits = self.digit_list();
+        let is_integer = match number {
+            Number::Long(value) => {
+                digits.set_long(is_negative, value.unsigned_abs().into(), 0);
+                true
+            }
+            Number::Double(value) => {
+                digits.set_double(is_negative, value, limits.2, true);
+                false
+            }
+        };
+        self.subformat_number(result, &mut digits, is_integer, limits);
+    }
+
+    /// A non-negative double rounded to the fraction digits by the rounding mode.
+    pub(super) fn round(&self, number: f64, is_negative: bool, fraction_digits: i32) -> f64 {
+        let mut digits = self.digit_list();
+        digits.set_double(is_negative, number, fraction_digits, true);
+        digits.get_double()
+    }
+
+    fn digit_list(&self) -> DigitList {
+        DigitList {
+            rounding_mode: self.rounding_mode,
+            ..DigitList::default()
+        }
+    }
+
+    fn format_long(&self, number: i64) -> String {
+        let mut result = String::new();
+        // Multiplying in 128 bits stands in for the JDK's fallback to BigInteger.
+        let number = number as i128 * self.multiplier as i128;
+        let is_negative = number < 0;
+        let big = number.unsigned_abs() > i64::MAX as u128;
+        let (max_int, min_int, max_fra, min_fra) = if big {
+            self.digit_limits(i32::MAX, i32::MAX)
+        } else {
+            self.digit_limits(DOUBLE_INTEGER_DIGITS, DOUBLE_FRACTION_DIGITS)
+        };
+        let mut digits = self.digit_list();
+        digits.set_long(
+            is_negative,
+            number.unsigned_abs(),
+            if self.use_exponential_notation {
+                max_int.saturating_add(max_fra)
+            } else {
+                0
+            },
+        );
+        self.subformat(
+            &mut result,
+            &mut digits,
+            is_negative,
+            true,
+            (max_int, min_int, max_fra, min_fra),
+        );
+        result
+    }
+
+    /// The maximum and minimum integer and fraction digits, capped for the type formatted.
+    fn digit_limits(&self, integer_cap: i32, fraction_cap: i32) -> (i32, i32, i32, i32) {
+        (
+            self.maximum_integer_digits.min(integer_cap),
+            self.minimum_integer_digits.min(integer_cap),
+            self.maximum_fraction_digits.min(fraction_cap),
+            self.minimum_fraction_digits.min(fraction_cap),
+        )
+    }
+
+    fn affixes(&self, is_negative: bool) -> (&str, &str) {
+        if is_negative {
+            (&self.negative_prefix, &self.negative_suffix)
+        } else {
+            (&self.positive_prefix, &self.positive_suffix)
+        }
+    }
+
+    fn subformat(
+        &self,
+        result: &mut String,
+        digit_list: &mut DigitList,
+        is_negative: bool,
+        is_integer: bool,
+        limits: (i32, i32, i32, i32),
+    ) {
+        let (prefix, suffix) = self.affixes(is_negative);
+        result.push_str(prefix);
+        self.subformat_number(result, digit_list, is_integer, limits);
+        result.push_str(suffix);
+    }
+
+    fn subformat_number(
+        &self,
+        result: &mut String,
+        digit_list: &mut DigitList,
+        is_integer: bool,
+        (max_int_digits, min_int_digits, max_fra_digits, min_fra_digits): (i32, i32, i32, i32),
+    ) {
+        let symbols = &self.symbols;
+        let (grouping, decimal) = if self.is_currency_format {
+            (
+                symbols.monetary_grouping_separator,
+                symbols.monetary_separator,
+            )
+        } else {
+            (symbols.grouping_separator, symbols.decimal_separator)
+        };
+        let zero = symbols.zero_digit;
+        let digit = |digit: u8| char::from_u32(zero as u32 + (digit - b'0') as u32).unwrap_or(zero);
+
+        // Zero keeps its sign, so that -0.0 formats as "-0".
+        if digit_list.is_zero() {
+            digit_list.decimal_at = 0;
+        }
+
+        if self.use_exponential_notation {
+            // Minimum integer digits are met by adjusting the exponent, e.g. 0.01234 with 3
+            // is "123.4E-4". Maximum integer digits above the minimum and above 1 give the
+            // exponent's multiple for engineering notation, e.g. 0.01234 as "12.34E-3".
+            let mut exponent = digit_list.decimal_at;
+            let repeat = max_int_digits;
+            let mut minimum_integer_digits = min_int_digits;
+            if repeat > 1 && repeat > min_int_digits {
+                exponent = if exponent >= 1 {
+                    ((exponent - 1) / repeat) * repeat
+                } else {
+                    ((exponent - repeat) / repeat) * repeat
+                };
+                minimum_integer_digits = 1;
+            } else {
+                exponent -= minimum_integer_digits;
+            }
+
+            let mut minimum_digits = min_int_digits
+                .checked_add(min_fra_digits)
+                .unwrap_or(i32::MAX);
+            let integer_digits = if digit_list.is_zero() {
+                minimum_integer_digits
+            } else {
+                digit_list.decimal_at - exponent
+            };
+            minimum_digits = minimum_digits.max(integer_digits);
+            let total_digits = (digit_list.count() as i32).max(minimum_digits);
+            for i in 0..total_digits {
+                if i == integer_digits {
+                    result.push(decimal);
+                }
+                result.push(match digit_list.digits.get(i as usize) {
+                    Some(&d) => digit(d),
+                    None => zero,
+                });
+            }
+            if self.decimal_separator_always_shown && total_digits == integer_digits {
+                result.push(decimal);
+            }
+
+            result.push_str(&symbols.exponent_separator);
+            // The exponent of zero is forced to zero only now, after it has given the
+            // integer digits.
+            if digit_list.is_zero() {
+                exponent = 0;
+            }
+            if exponent < 0 {
+                result.push_str(&symbols.minus_sign_text);
+            }
+            let exponent_digits = exponent.unsigned_abs().to_string();
+            for _ in exponent_digits.len() as i32..self.min_exponent_digits {
+                result.push(zero);
+            }
+            result.extend(exponent_digits.bytes().map(digit));
+        } else {
+            let mut count = min_int_digits;
+            let mut digit_index = 0usize;
+            if digit_list.decimal_at > 0 && count < digit_list.decimal_at {
+                count = digit_list.decimal_at;
+            }
+            // With fewer maximum integer digits than the number has, the least significant
+            // ones are shown: 1997 with 2 is "97".
+            if count > max_int_digits {
+                count = max_int_digits;
+                digit_index = (digit_list.decimal_at - count) as usize;
+            }
+            let size_before_integer_part = result.len();
+            for i in (0..count).rev() {
+                if i < digit_list.decimal_at && digit_index < digit_list.count() {
+                    result.push(digit(digit_list.digits[digit_index]));
+                    digit_index += 1;
+                } else {
+                    result.push(zero);
+                }
+                if self.grouping_used
+                    && i > 0
+                    && self.grouping_size != 0
+                    && i % self.grouping_size == 0
+                {
+                    result.push(grouping);
+                }
+            }
+
+            let fraction_present =
+                min_fra_digits > 0 || (!is_integer && digit_index < digit_list.count());
+            // Without any digits the output could not be parsed back, so show a zero.
+            if !fraction_present && result.len() == size_before_integer_part {
+                result.push(zero);
+            }
+            if self.decimal_separator_always_shown || fraction_present {
+                result.push(decimal);
+            }
+            for i in 0..max_fra_digits {
+                if i >= min_fra_digits && (is_integer || digit_index >= digit_list.count()) {
+                    break;
+                }
+                // Zeros between the decimal point and the first significant digit.
+                if -1 - i > digit_list.decimal_at - 1 {
+                    result.push(zero);
+                    continue;
+                }
+                if !is_integer && digit_index < digit_list.count() {
+                    result.push(digit(digit_list.digits[digit_index]));
+                    digit_index += 1;
+                } else {
+                    result.push(zero);
+                }
+            }
+        }
+    }
+
+    /// Parses a number from the start of the text, like `NumberFormat.parse(String)`.
+    pub fn parse(&self, source: &str) -> Result<Number, ParseException> {
+        let mut pos = ParsePosition::new(0);
+        match self.parse_from(source, &mut pos) {
+            Some(number) if pos.get_index() != 0 => Ok(number),
+            _ => Err(ParseException::new(
+                format!("Unparseable number: \"{source}\""),
+                pos.get_error_index().unwrap_or(0) as i32,
+            )),
+        }
+    }
+
+    /// Parses a number at the position, advancing it past the number, or setting its error
+    /// index and returning `None` on failure. Like the JDK, the result is a `Long` when it is
+    /// an integer that fits one, unless it is -0.
+    pub fn parse_from(&self, text: &str, pos: &mut ParsePosition) -> Option<Number> {
+        let Some(rest) = text.get(pos.get_index()..) else {
+            pos.set_error_index(Some(pos.get_index()));
+            return None;
+        };
+        let nan = &self.symbols.nan;
+        if rest.starts_with(nan.as_str()) {
+            pos.set_index(pos.get_index() + nan.len());
+            return Some(Number::Double(f64::NAN));
+        }
+
+        let mut digits = DigitList::default();
+        let (is_positive, is_infinite) = self.subparse(
+            text,
+            pos,
+            &self.positive_prefix,
+            &self.negative_prefix,
+            &mut digits,
+            false,
+        )?;
+        if is_infinite {
+            return Some(Number::Double(if is_positive == (self.multiplier >= 0) {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            }));
+        }
+        if self.multiplier == 0 {
+            return Some(Number::Double(if digits.is_zero() {
+                f64::NAN
+            } else if !is_positive {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            }));
+        }
+
+        let mut got_double = true;
+        let mut got_long_minimum = false;
+        let mut double_result = 0.0;
+        let mut long_result = 0i64;
+        if digits.fits_into_long(is_positive, self.parse_integer_only) {
+            got_double = false;
+            long_result = digits.get_long();
+            got_long_minimum = long_result < 0;
+        } else {
+            double_result = digits.get_double();
+        }
+
+        if self.multiplier != 1 {
+            if got_double {
+                double_result /= self.multiplier as f64;
+            } else if long_result % self.multiplier as i64 == 0 {
+                long_result /= self.multiplier as i64;
+            } else {
+                double_result = long_result as f64 / self.multiplier as f64;
+                got_double = true;
+            }
+        }
+        if !is_positive && !got_long_minimum {
+            double_result = -double_result;
+            long_result = long_result.wrapping_neg();
+        }
+        // Dividing by the multiplier may have left an integer that fits a long.
+        if self.multiplier != 1 && got_double {
+            long_result = double_result as i64;
+            got_double = (double_result != long_result as f64
+                || (double_result == 0.0 && double_result.is_sign_negative()))
+                && !self.parse_integer_only;
+        }
+        Some(if got_double {
+            Number::Double(double_result)
+        } else {
+            Number::Long(long_result)
+        })
+    }
+
+    /// Parses the affixes and digits of a number into `digits`, returning whether it is
+    /// positive and whether it is infinite.
+    fn subparse(
+        &self,
+        text: &str,
+        parse_position: &mut ParsePosition,
+        positive_prefix: &str,
+        negative_prefix: &str,
+        digits: &mut DigitList,
+        is_exponent: bool,
+    ) -> Option<(bool, bool)> {
+        let start = parse_position.get_index();
+        let rest = &text[start..];
+        let mut got_positive = rest.starts_with(positive_prefix);
+        let mut got_negative = rest.starts_with(negative_prefix);
+        // When both match, the longer one wins.
+        if got_positive && got_negative {
+            if positive_prefix.len() > negative_prefix.len() {
+                got_negative = false;
+            } else if positive_prefix.len() < negative_prefix.len() {
+                got_positive = false;
+            }
+        }
+        let mut position = if got_positive {
+            start + positive_prefix.len()
+        } else if got_negative {
+            start + negative_prefix.len()
+        } else {
+            parse_position.set_error_index(Some(start));
+            return None;
+        };
+
+        let (end, is_infinite) =
+            match self.subparse_number(text, position, digits, true, is_exponent) {
+                Some(parsed) => parsed,
+                None => {
+                    parse_position.set_index(start);
+                    parse_position.set_error_index(Some(start));
+                    return None;
+                }
+            };
+        position = end;
+
+        if !is_exponent {
+            let rest = &text[position..];
+            if got_positive {
+                got_positive = rest.starts_with(self.positive_suffix.as_str());
+            }
+            if got_negative {
+                got_negative = rest.starts_with(self.negative_suffix.as_str());
+            }
+            if got_positive && got_negative {
+                if self.positive_suffix.len() > self.negative_suffix.len() {
+                    got_negative = false;
+                } else if self.positive_suffix.len() < self.negative_suffix.len() {
+                    got_positive = false;
+                }
+            }
+            if got_positive == got_negative {
+                parse_position.set_error_index(Some(position));
+                return None;
+            }
+            parse_position.set_index(
+                position
+                    + if got_positive {
+                        self.positive_suffix.len()
+                    } else {
+                        self.negative_suffix.len()
+                    },
+            );
+        } else {
+            parse_position.set_index(position);
+        }
+        if parse_position.get_index() == start {
+            parse_position.set_error_index(Some(position));
+            return None;
+        }
+        Some((got_positive, is_infinite))
+    }
+
+    /// Parses the digits, separators and, if `check_exponent`, the exponent of a number,
+    /// returning where they end and whether the number is infinite, or `None` if there are
+    /// no digits.
+    pub(super) fn subparse_number(
+        &self,
+        text: &str,
+        mut position: usize,
+        digits: &mut DigitList,
+        check_exponent: bool,
+        is_exponent: bool,
+    ) -> Option<(usize, bool)> {
+        let symbols = &self.symbols;
+        if !is_exponent && text[position..].starts_with(symbols.infinity.as_str()) {
+            return Some((position + symbols.infinity.len(), true));
+        }
+
+        // Only significant digits go into the list; leading zeros move the decimal point.
+        digits.digits.clear();
+        digits.decimal_at = 0;
+        let zero = symbols.zero_digit;
+        let (decimal, grouping) = if self.is_currency_format {
+            (
+                symbols.monetary_separator,
+                symbols.monetary_grouping_separator,
+            )
+        } else {
+            (symbols.decimal_separator, symbols.grouping_separator)
+        };
+        let mut saw_decimal = false;
+        let mut saw_digit = false;
+        let mut exponent = 0;
+        let mut digit_count = 0;
+        // A grouping separator only counts if a digit follows it.
+        let mut backup = None;
+
+        while let Some(ch) = text[position..].chars().next() {
+            // The locale's digits, or any Unicode decimal digits.
+            let mut digit = ch as i32 - zero as i32;
+            if !(0..=9).contains(&digit) {
+                digit = character::digit(ch as i32, 10);
+            }
+            if digit == 0 {
+                backup = None;
+                saw_digit = true;
+                if digits.count() == 0 {
+                    // Leading zeros of the integer part are dropped, and those of the
+                    // fraction move the decimal point.
+                    if saw_decimal {
+                        digits.decimal_at -= 1;
+                    }
+                } else {
+                    digit_count += 1;
+                    if !saw_decimal {
+                        digits.decimal_at += 1;
+                    }
+                    digits.digits.push(b'0');
+                }
+            } else if (1..=9).contains(&digit) {
+                saw_digit = true;
+                digit_count += 1;
+                if !saw_decimal {
+                    digits.decimal_at += 1;
+                }
+                digits.digits.push(b'0' + digit as u8);
+                backup = None;
+            } else if !is_exponent && ch == decimal {
+                if self.parse_integer_only || saw_decimal {
+                    break;
+                }
+                saw_decimal = true;
+            } else if !is_exponent && ch == grouping && self.grouping_used {
+                if saw_decimal {
+                    break;
+                }
+                backup = Some(position);
+            } else if check_exponent
+                && !is_exponent
+                && text[position..].starts_with(symbols.exponent_separator.as_str())
+            {
+                // The exponent is parsed like a number of its own, and ends the number
+                // whether or not there is one.
+                let mut pos = ParsePosition::new(position + symbols.exponent_separator.len());
+                let mut exponent_digits = DigitList::default();
+                if let Some((is_positive, _)) = self.subparse(
+                    text,
+                    &mut pos,
+                    "",
+                    &symbols.minus_sign_text,
+                    &mut exponent_digits,
+                    true,
+                ) {
+                    if exponent_digits.fits_into_long(is_positive, true) {
+                        position = pos.get_index();
+                        exponent = exponent_digits.get_long() as i32;
+                        if !is_positive {
+                            exponent = exponent.wrapping_neg();
+                        }
+                    }
+                }
+                break;
+            } else {
+                break;
+            }
+            position += ch.len_utf8();
+        }
+
+        if let Some(backup) = backup {
+            position = backup;
+        }
+        if !saw_decimal {
+            digits.decimal_at = digit_count;
+        }
+        digits.decimal_at = digits.decimal_at.wrapping_add(exponent);
+        if !saw_digit && digit_count == 0 {
+            return None;
+        }
+        Some((position, false))
+    }
+}
+
+fn starts_with_at(chars: &[char], pos: usize, prefix: &str) -> bool {
+    prefix
+        .chars()
+        .enumerate()
+        .all(|(i, c)| chars.get(pos + i) == Some(&c))
+}
+
+/// Appends an affix pattern to a pattern, quoting the characters that would otherwise be
+/// special there.
+fn append_affix_pattern(buffer: &mut String, affix_pattern: &str) {
+    let mut pos = 0;
+    while pos < affix_pattern.len() {
+        let Some(quote) = affix_pattern[pos..].find(QUOTE).map(|i| pos + i) else {
+            append_affix(buffer, &affix_pattern[pos..]);
+            break;
+        };
+        if quote > pos {
+            append_affix(buffer, &affix_pattern[pos..quote]);
+        }
+        let mut rest = affix_pattern[quote + 1..].chars();
+        let Some(c) = rest.next() else { break };
+        pos = quote + 1 + c.len_utf8();
+        if c == QUOTE {
+            buffer.push(c);
+        } else if c == CURRENCY_SIGN && rest.next() == Some(CURRENCY_SIGN) {
+            pos += CURRENCY_SIGN.len_utf8();
+            buffer.push(c);
+        }
+        buffer.push(c);
+    }
+}
+
+fn append_affix(buffer: &mut String, affix: &str) {
+    let need_quote = affix.contains([
+        PATTERN_ZERO_DIGIT,
+        PATTERN_GROUPING_SEPARATOR,
+        PATTERN_DECIMAL_SEPARATOR,
+        PATTERN_PERCENT,
+        PATTERN_PER_MILLE,
+        PATTERN_DIGIT,
+        PATTERN_SEPARATOR,
+        PATTERN_MINUS,
+        CURRENCY_SIGN,
+    ]);
+    if need_quote {
+        buffer.push(QUOTE);
+    }
+    buffer.push_str(&affix.replace(QUOTE, "''"));
+    if need_quote {
+        buffer.push(QUOTE);
+    }
+}
+
+/// The JDK's `DigitList`: the significant digits of a number as ASCII, and where its decimal
+/// point is, so 0.0123 is "123" at -1 and 1200 is "12" at 4.
+#[derive(Debug, Default)]
+pub(super) struct DigitList {
+    digits: Vec<u8>,
+    decimal_at: i32,
+    rounding_mode: RoundingMode,
+}
+
+/// The digits of `Long.MIN_VALUE` without its sign.
+const LONG_MIN_REP: &[u8] = b"9223372036854775808";
+
+impl DigitList {
+    fn count(&self) -> usize {
+        self.digits.len()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.iter().all(|&digit| digit == b'0')
+    }
+
+    /// Sets the list to a non-negative double, rounded to `maximum_digits` fraction digits if
+    /// `fixed_point`, or significant digits otherwise.
+    fn set_double(
+        &mut self,
+        is_negative: bool,
+        source: f64,
+        maximum_digits: i32,
+        fixed_point: bool,
+    ) {
+        // The digits of Java's `Double.toString`, and whether the last of them was
+        // rounded up or they are the exact value, which settle ties.
+        let decimal = FloatingDecimal::of_double(source, true);
+        let rounded_up = decimal.rounded_up;
+        let value_exact_as_decimal = decimal.exact;
+
+        self.digits = decimal.digits;
+        if self.is_zero() {
+            self.digits.clear();
+            self.decimal_at = 0;
+        } else {
+            self.decimal_at = decimal.decimal_exponent;
+        }
+
+        if fixed_point {
+            // Values too small for the fraction digits round to nothing or to a single
+            // digit in the last place.
+            if -self.decimal_at > maximum_digits {
+                self.digits.clear();
+                return;
+            } else if -self.decimal_at == maximum_digits {
+                if self.should_round_up(0, is_negative, rounded_up, value_exact_as_decimal) {
+                    self.digits = vec![b'1'];
+                    self.decimal_at += 1;
+                } else {
+                    self.digits.clear();
+                }
+                return;
+            }
+        }
+        self.trim_trailing_zeros();
+        self.round(
+            if fixed_point {
+                maximum_digits.saturating_add(self.decimal_at)
+            } else {
+                maximum_digits
+            },
+            is_negative,
+            rounded_up,
+            value_exact_as_decimal,
+        );
+    }
+
+    /// Sets the list to an integer, rounded to `maximum_digits` significant digits if that
+    /// is positive.
+    fn set_long(&mut self, is_negative: bool, source: u128, maximum_digits: i32) {
+        if source == 0 {
+            self.digits.clear();
+            self.decimal_at = 0;
+        } else {
+            self.digits = source.to_string().into_bytes();
+            self.decimal_at = self.digits.len() as i32;
+            self.trim_trailing_zeros();
+        }
+        if maximum_digits > 0 {
+            self.round(maximum_digits, is_negative, false, true);
+        }
+    }
+
+    fn trim_trailing_zeros(&mut self) {
+        while self.digits.len() > 1 && self.digits.last() == Some(&b'0') {
+            self.digits.pop();
+        }
+    }
+
+    fn round(
+        &mut self,
+        maximum_digits: i32,
+        is_negative: bool,
+        already_rounded: bool,
+        value_exact_as_decimal: bool,
+    ) {
+        if maximum_digits < 0 || maximum_digits as usize >= self.count() {
+            return;
+        }
+        let mut maximum = maximum_digits as usize;
+        if self.should_round_up(
+            maximum,
+            is_negative,
+            already_rounded,
+            value_exact_as_decimal,
+        ) {
+            // Carry from the last kept digit, all the way to a new leading 1 for 99..9.
+            loop {
+                if maximum == 0 {
+                    self.digits[0] = b'1';
+                    self.decimal_at += 1;
+                    break;
+                }
+                maximum -= 1;
+                self.digits[maximum] += 1;
+                if self.digits[maximum] <= b'9' {
+                    break;
+                }
+            }
+            maximum += 1;
+        }
+        self.digits.truncate(maximum);
+        self.trim_trailing_zeros();
+    }
+
+    /// Whether dropping the digits from `maximum_digits` on rounds the rest up, by the
+    /// rounding mode. A last digit of 5 is only a tie if the digits are exact; otherwise the
+    /// value is above it unless the digits were themselves rounded up. Panics with
+    /// `ArithmeticException` if digits would be lost in `RoundingMode::Unnecessary`.
+    fn should_round_up(
+        &self,
+        maximum_digits: usize,
+        is_negative: bool,
+        already_rounded: bool,
+        value_exact_as_decimal: bool,
+    ) -> bool {
+        if maximum_digits >= self.count() {
+            return false;
+        }
+        let digits = &self.digits;
+        let discards_non_zero = digits[maximum_digits..].iter().any(|&digit| digit != b'0');
+        let is_last = maximum_digits == self.count() - 1;
+        match self.rounding_mode {
+            RoundingMode::Up => discards_non_zero,
+            RoundingMode::Down => false,
+            RoundingMode::Ceiling => discards_non_zero && !is_negative,
+            RoundingMode::Floor => discards_non_zero && is_negative,
+            RoundingMode::HalfUp | RoundingMode::HalfDown => match digits[maximum_digits] {
+                b'6'..=b'9' => true,
+                b'5' if is_last => {
+                    if value_exact_as_decimal {
+                        self.rounding_mode == RoundingMode::HalfUp
+                    } else {
+                        !already_rounded
+                    }
+                }
+                b'5' => true,
+                _ => false,
+            },
+            RoundingMode::HalfEven => match digits[maximum_digits] {
+                b'6'..=b'9' => true,
+                b'5' if is_last => {
+                    if value_exact_as_decimal {
+                        maximum_digits > 0 && !(digits[maximum_digits - 1] - b'0').is_multiple_of(2)
+                    } else {
+                        !already_rounded
+                    }
+                }
+                b'5' => digits[maximum_digits + 1..]
+                    .iter()
+                    .any(|&digit| digit != b'0'),
+                _ => false,
+            },
+            RoundingMode::Unnecessary => {
+                if discards_non_zero {
+                    ArithmeticException::new(
+                        "Rounding needed with the rounding mode being set to \
+                         RoundingMode.UNNECESSARY"
+                            .to_string(),
+                    )
+                    .throw();
+                }
+                false
+            }
+        }
+    }
+
+    /// Whether the parsed value is an integer that fits a long. Negative zero only does
+    /// when `ignore_negative_zero`.
+    pub(super) fn fits_into_long(&mut self, is_positive: bool, ignore_negative_zero: bool) -> bool {
+        while self.digits.last() == Some(&b'0') {
+            self.digits.pop();
+        }
+        if self.digits.is_empty() {
+            return is_positive || ignore_negative_zero;
+        }
+        let max_count = LONG_MIN_REP.len() as i32;
+        if self.decimal_at < self.count() as i32 || self.decimal_at > max_count {
+            return false;
+        }
+        if self.decimal_at < max_count {
+            return true;
+        }
+        for (&digit, &max) in self.digits.iter().zip(LONG_MIN_REP) {
+            if digit != max {
+                return digit < max;
+            }
+        }
+        if (self.count() as i32) < self.decimal_at {
+            return true;
+        }
+        // The digits are those of Long.MIN_VALUE, which only fits negated.
+        !is_positive
+    }
+
+    pub(super) fn get_long(&self) -> i64 {
+        if self.digits.is_empty() {
+            return 0;
+        }
+        let mut digits = self.digits.clone();
+        digits.resize(self.decimal_at as usize, b'0');
+        if digits == LONG_MIN_REP {
+            return i64::MIN;
+        }
+        std::str::from_utf8(&digits).unwrap().parse().unwrap()
+    }
+
+    pub(super) fn get_double(&self) -> f64 {
+        if self.digits.is_empty() {
+            return 0.0;
+        }
+        format!(
+            "0.{}E{}",
+            std::str::from_utf8(&self.digits).unwrap(),
+            self.decimal_at
+        )
+        .parse()
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(pattern: &str) -> DecimalFormat {
+        DecimalFormat::with_symbols(pattern, DecimalFormatSymbols::get_instance(&Locale::US))
+    }
+
+    #[test]
+    fn formats_like_jdk() {
+        let cases = [
+            ("#,##0.00", 1234567.891, "1,234,567.89", "#,##0.00"),
+            ("0.###E0", 12345.0, "1.235E4", "0.###E0"),
+            ("00.00%", 0.4567, "45.67%", "#00.00%"),
+            (
+                "#,##0.00;(#,##0.00)",
+                -1234.5,
+                "(1,234.50)",
+                "#,##0.00;(#,##0.00)",
+            ),
+            ("¤#,##0.00", -3.5, "-$3.50", "¤#,##0.00"),
+            ("'#'#", 42.0, "#42", "'#'#"),
+            ("#.##", 0.125, "0.12", "#0.##"),
+            ("#.##", 0.135, "0.14", "#0.##"),
+            ("##0.#####E0", 123456.0, "123.456E3", "##0.#####E0"),
+            ("#,##0.###", f64::NAN, "NaN", "#,##0.###"),
+        ];
+        for (pattern, number, expected, to_pattern) in cases {
+            let format = us(pattern);
+            assert_eq!(format.format(number), expected, "{pattern}");
+            assert_eq!(format.to_pattern(), to_pattern);
+        }
+
+        let format = DecimalFormat::with_symbols(
+            "#,##0.00",
+            DecimalFormatSymbols::get_instance(&Locale::GERMANY),
+        );
+        assert_eq!(format.format(1234567.891), "1.234.567,89");
+        assert_eq!(format.format(i64::MIN), "-9.223.372.036.854.775.808,00");
+    }
+
+    #[test]
+    fn formats_the_digits_of_double_to_string() {
+        let cases = [
+            ("#,##0", 1.9136501335079107e18, "1,913,650,133,507,910,660"),
+            ("#,##0", 5.393228097415586e17, "539,322,809,741,558,590"),
+            ("0.###E0", f64::from_bits(1), "4.9E-324"),
+            ("0.00E00", f64::from_bits(1), "4.90E-324"),
+        ];
+        for (pattern, number, expected) in cases {
+            assert_eq!(us(pattern).format(number), expected, "{pattern}");
+        }
+    }
+
+    #[test]
+    fn parses_like_jdk() {
+        let format = us("#,##0.###");
+        assert_eq!(format.parse("-1,234.5xyz"), Ok(Number::Double(-1234.5)));
+        assert_eq!(format.parse("1,234"), Ok(Number::Long(1234)));
+        let error = format.parse("abc").unwrap_err();
+        assert_eq!(error.message(), "Unparseable number: \"abc\"");
+        assert_eq!(error.get_error_offset(), 0);
+        assert_eq!(us("#%").parse("50%"), Ok(Number::Double(0.5)));
+    }
+
+    #[test]
+    fn rounds_by_rounding_mode() {
+        let cases = [
+            (RoundingMode::Up, ["0.3", "-0.3", "0.3", "-0.3"]),
+            (RoundingMode::Down, ["0.2", "-0.2", "0.2", "-0.2"]),
+            (RoundingMode::Ceiling, ["0.3", "-0.2", "0.3", "-0.2"]),
+            (RoundingMode::Floor, ["0.2", "-0.3", "0.2", "-0.3"]),
+            (RoundingMode::HalfUp, ["0.3", "-0.3", "0.3", "-0.2"]),
+            (RoundingMode::HalfDown, ["0.2", "-0.2", "0.3", "-0.2"]),
+            (RoundingMode::HalfEven, ["0.2", "-0.2", "0.3", "-0.2"]),
+        ];
+        let mut format = us("0.0");
+        for (rounding_mode, expected) in cases {
+            format.set_rounding_mode(rounding_mode);
+            let formatted = [0.25, -0.25, 0.26, -0.21].map(|number| format.format(number));
+            assert_eq!(formatted, expected, "{rounding_mode:?}");
+        }
+        format.set_rounding_mode(RoundingMode::Unnecessary);
+        assert_eq!(format.format(0.5), "0.5");
+    }
+
+    #[test]
+    #[should_panic(expected = "Rounding needed with the rounding mode being set")]
+    fn unnecessary_rounding_panics() {
+        let mut format = us("0.0");
+        format.set_rounding_mode(RoundingMode::Unnecessary);
+        format.format(0.25);
+    }
+
+    #[test]
+    fn number_format_factories() {
+        assert_eq!(
+            DecimalFormat::get_currency_instance(&Locale::US).format(3.5),
+            "$3.50"
+        );
+        assert_eq!(
+            DecimalFormat::get_percent_instance(&Locale::US).format(0.256),
+            "26%"
+        );
+        assert_eq!(
+            DecimalFormat::get_integer_instance(&Locale::US).format(2.5),
+            "2"
+        );
+        assert_eq!(
+            DecimalFormat::get_instance(&Locale::GERMANY).format(1234.5678),
+            "1.234,568"
+        );
+        assert_eq!(
+            DecimalFormat::get_currency_instance(&Locale::GERMANY).format(-3.5),
+            "-3,50\u{A0}\u{20AC}"
+        );
+        assert_eq!(
+            DecimalFormat::get_currency_instance(&Locale::JAPAN).format(1234.5),
+            "\u{FFE5}1,234"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::OnceLock;
+
+use crate::util::Locale;
+
+static LOCALE_DATA: &[u8] = include_bytes!("locales.dat");
+
+/// The calendar names, week rules and date-time patterns of a locale.
+#[derive(Debug)]
+pub(super) struct DateData {
+    pub(super) buddhist: bool,
+    pub(super) first_day_of_week: i32,
+    pub(super) minimal_days_in_first_week: i32,
+    /// Display names by style, in `NAME_STYLES` order, then by calendar field value. Week
+    /// days start at Sunday.
+    pub(super) eras: [[Option<&'static str>; 2]; 4],
+    pub(super) months: [[Option<&'static str>; 12]; 4],
+    pub(super) weekdays: [[Option<&'static str>; 7]; 4],
+    pub(super) am_pm: [[Option<&'static str>; 2]; 4],
+    /// Patterns by style, from `FULL` to `SHORT`.
+    pub(super) date_patterns: [&'static str; 4],
+    pub(super) time_patterns: [&'static str; 4],
+    /// Patterns by date style, then by time style.
+    pub(super) date_time_patterns: [[&'static str; 4]; 4],
+}
+
+/// Name styles of `DateData`.
+pub(super) const SHORT_FORMAT: usize = 0;
+pub(super) const LONG_FORMAT: usize = 1;
+pub(super) const SHORT_STANDALONE: usize = 2;
+pub(super) const LONG_STANDALONE: usize = 3;
+
+/// The `DecimalFormatSymbols` and number patterns of a locale.
+#[derive(Debug)]
+pub(super) struct NumberData {
+    /// The default fraction digits of the currency, or -1 if it is unknown.
+    pub(super) currency_fraction_digits: i32,
+    pub(super) zero_digit: char,
+    pub(super) grouping_separator: char,
+    pub(super) decimal_separator: char,
+    pub(super) per_mill: char,
+    pub(super) percent: char,
+    pub(super) digit: char,
+    pub(super) pattern_separator: char,
+    pub(super) infinity: &'static str,
+    pub(super) nan: &'static str,
+    pub(super) minus_sign: char,
+    pub(super) currency_symbol: &'static str,
+    pub(super) intl_currency_symbol: &'static str,
+    pub(super) monetary_separator: char,
+    pub(super) exponent_separator: &'static str,
+    pub(super) per_mill_text: &'static str,
+    pub(super) percent_text: &'static str,
+    pub(super) minus_sign_text: &'static str,
+    pub(super) monetary_grouping_separator: char,
+    /// The number, integer, percent and currency patterns.
+    pub(super) patterns: [&'static str; 4],
+    /// The pattern of compact numbers that no compact pattern applies to.
+    pub(super) compact_decimal_pattern: &'static str,
+    pub(super) plural_rules: &'static str,
+    /// The short and long compact patterns, by the power of ten they start at.
+    pub(super) compact_patterns: [Vec<&'static str>; 2],
+}
+
+/// A time zone's short standard, long standard, short daylight and long daylight names.
+pub(super) type ZoneNames = [&'static str; 4];
+
+struct LocaleData {
+    dates: Vec<DateData>,
+    numbers: Vec<NumberData>,
+    /// The short standard and daylight names of the zones whose short names in a locale
+    /// aren't the English ones, by zone index.
+    zone_names: Vec<Vec<(usize, [&'static str; 2])>>,
+    /// Language tags with their date, number and zone name records, sorted by tag.
+    locales: Vec<(&'static str, usize, usize, usize)>,
+    /// Time zone IDs with their English names, sorted by ID.
+    zones: Vec<(&'static str, ZoneNames)>,
+}
+
+/// Reads locales.dat; see tools/GenTextTables.java for the layout.
+struct Reader {
+    pos: usize,
+    strings: Vec<&'static str>,
+}
+
+impl Reader {
+    fn u8(&mut self) -> u8 {
+        self.pos += 1;
+        LOCALE_DATA[self.pos - 1]
+    }
+
+    fn u16(&mut self) -> u16 {
+        u16::from_be_bytes([self.u8(), self.u8()])
+    }
+
+    fn name(&mut self) -> Option<&'static str> {
+        match self.u16() {
+            0xFFFF => None,
+            index => Some(self.strings[index as usize]),
+        }
+    }
+
+    fn string(&mut self) -> &'static str {
+        self.name().unwrap_or_default()
+    }
+
+    fn char(&mut self) -> char {
+        self.string().chars().next().unwrap_or_default()
+    }
+
+    fn names<const N: usize>(&mut self) -> [[Option<&'static str>; N]; 4] {
+        [(); 4].map(|_| [(); N].map(|_| self.name()))
+    }
+
+    fn date(&mut self) -> DateData {
+        DateData {
+            buddhist: self.u8() & 1 != 0,
+            first_day_of_week: self.u8() as i32,
+            minimal_days_in_first_week: self.u8() as i32,
+            eras: self.names(),
+            months: self.names(),
+            weekdays: self.names(),
+            am_pm: self.names(),
+            date_patterns: [(); 4].map(|_| self.string()),
+            time_patterns: [(); 4].map(|_| self.string()),
+            date_time_patterns: [(); 4].map(|_| [(); 4].map(|_| self.string())),
+        }
+    }
+
+    fn number(&mut self) -> NumberData {
+        NumberData {
+            currency_fraction_digits: self.u8() as i8 as i32,
+            zero_digit: self.char(),
+            grouping_separator: self.char(),
+            decimal_separator: self.char(),
+            per_mill: self.char(),
+            percent: self.char(),
+            digit: self.char(),
+            pattern_separator: self.char(),
+            infinity: self.string(),
+            nan: self.string(),
+            minus_sign: self.char(),
+            currency_symbol: self.string(),
+            intl_currency_symbol: self.string(),
+            monetary_separator: self.char(),
+            exponent_separator: self.string(),
+            per_mill_text: self.string(),
+            percent_text: self.string(),
+            minus_sign_text: self.string(),
+            monetary_grouping_separator: self.char(),
+            patterns: [(); 4].map(|_| self.string()),
+            compact_decimal_pattern: self.string(),
+            plural_rules: self.string(),
+            compact_patterns: [(); 2].map(|_| (0..self.u16()).map(|_| self.string()).collect()),
+        }
+    }
+}
+
+fn locale_data() -> &'static LocaleData {
+    static DATA: OnceLock<LocaleData> = OnceLock::new();
+    DATA.get_or_init(|| {
+        let mut reader = Reader {
+            pos: 0,
+            strings: Vec::new(),
+        };
+        for _ in 0..reader.u16() {
+            let length = reader.u16() as usize;
+            let string = &LOCALE_DATA[reader.pos..reader.pos + length];
+            reader.pos += length;
+            reader.strings.push(std::str::from_utf8(string).unwrap());
+        }
+        let dates = (0..reader.u16()).map(|_| reader.date()).collect();
+        let numbers = (0..reader.u16()).map(|_| reader.number()).collect();
+        let zone_names = (0..reader.u16())
+            .map(|_| {
+                (0..reader.u16())
+                    .map(|_| (reader.u16() as usize, [(); 2].map(|_| reader.string())))
+                    .collect()
+            })
+            .collect();
+        let locales = (0..reader.u16())
+            .map(|_| {
+                (
+                    reader.string(),
+                    reader.u16() as usize,
+                    reader.u16() as usize,
+                    reader.u16() as usize,
+                )
+            })
+            .collect();
+        let zones = (0..reader.u16())
+            .map(|_| (reader.string(), [(); 4].map(|_| reader.string())))
+            .collect();
+        LocaleData {
+            dates,
+            numbers,
+            zone_names,
+            locales,
+            zones,
+        }
+    })
+}
+
+/// The entry of the most specific of a locale's candidate tags, or of the root locale.
+fn lookup(locale: &Locale) -> (&'static str, usize, usize, usize) {
+    let data = locale_data();
+    let root = data
+        .locales
+        .binary_search_by_key(&"und", |&(tag, ..)| tag)
+        .unwrap_or(0);
+    let index = candidate_tags(locale)
+        .iter()
+        .find_map(|tag| {
+            data.locales
+                .binary_search_by_key(&tag.as_str(), |&(tag, ..)| tag)
+                .ok()
+        })
+        .unwrap_or(root);
+    data.locales[index]
+}
+
+pub(super) fn date_data(locale: &Locale) -> &'static DateData {
+    &locale_data().dates[lookup(locale).1]
+}
+
+pub(super) fn number_data(locale: &Locale) -> &'static NumberData {
+    &locale_data().numbers[lookup(locale).2]
+}
+
+/// A time zone's names in a locale: its short names are the locale's, like those of
+/// `TimeZone.getDisplayName`, and its long names the English ones.
+pub(super) fn zone_names(id: &str, locale: &Locale) -> Option<ZoneNames> {
+    let zones = &locale_data().zones;
+    let index = zones.binary_search_by_key(&id, |&(id, _)| id).ok()?;
+    Some(localized_zone_names(index, locale_zone_names(locale)))
+}
+
+/// Every time zone ID with names, with its names in a locale.
+pub(super) fn zone_ids(locale: &Locale) -> impl Iterator<Item = (&'static str, ZoneNames)> {
+    let localized = locale_zone_names(locale);
+    let zones = &locale_data().zones;
+    (0..zones.len()).map(move |index| (zones[index].0, localized_zone_names(index, localized)))
+}
+
+fn locale_zone_names(locale: &Locale) -> &'static [(usize, [&'static str; 2])] {
+    &locale_data().zone_names[lookup(locale).3]
+}
+
+fn localized_zone_names(index: usize, localized: &[(usize, [&'static str; 2])]) -> ZoneNames {
+    let mut names = locale_data().zones[index].1;
+    if let Ok(entry) = localized.binary_search_by_key(&index, |&(index, _)| index) {
+        [names[0], names[2]] = localized[entry].1;
+    }
+    names
+}
+
+/// The language tags to look a locale's data up by, most specific first. Like the JDK, a
+/// Chinese locale without a script gets the one its country uses, and one without a
+/// country gets the country its script is used in.
+pub(super) fn candidate_tags(locale: &Locale) -> Vec<String> {
+    let language = locale.get_language();
+    let mut script = locale.get_script().to_string();
+    let mut country = locale.get_country().to_string();
+    if language == "zh" {
+        if script.is_empty() && !country.is_empty() {
+            script = match country.as_str() {
+                "TW" | "HK" | "MO" => "Hant",
+                _ => "Hans",
+            }
+            .to_string();
+        } else if !script.is_empty() && country.is_empty() {
+            match script.as_str() {
+                "Hans" => country = "CN".to_string(),
+                "Hant" => country = "TW".to_string(),
+                _ => {}
+            }
+        }
+    }
+
+    let mut tags = Vec::new();
+    if !script.is_empty() {
+        if !country.is_empty() {
+            tags.push(format!("{language}-{script}-{country}"));
+        }
+        tags.push(format!("{language}-{script}"));
+    }
+    if !country.is_empty() {
+        tags.push(format!("{language}-{country}"));
+    }
+    tags.push(language.to_string());
+    // Like `ResourceBundle.Control`, Nynorsk and Bokmål fall back to Norwegian.
+    if language == "nn" || language == "nb" {
+        tags.push("no".to_string());
+    }
+    tags
+}
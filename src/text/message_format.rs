@@ -0,0 +1,677 @@
+use crate::errors::{IllegalArgumentException, ParseException, Throwable};
+use crate::util::{Date, Locale};
+
+use super::decimal_format::{CURRENCY_STYLE, INTEGER_STYLE, NUMBER_STYLE, PERCENT_STYLE};
+use super::{
+    ChoiceFormat, DecimalFormat, DecimalFormatSymbols, Number, ParsePosition, SimpleDateFormat,
+};
+
+const SEG_RAW: usize = 0;
+const SEG_INDEX: usize = 1;
+const SEG_TYPE: usize = 2;
+const SEG_MODIFIER: usize = 3;
+
+const TYPE_KEYWORDS: [&str; 5] = ["", "number", "date", "time", "choice"];
+const NUMBER_MODIFIER_KEYWORDS: [&str; 4] = ["", "currency", "percent", "integer"];
+const DATE_TIME_MODIFIER_KEYWORDS: [&str; 5] = ["", "short", "medium", "long", "full"];
+const DATE_TIME_MODIFIERS: [i32; 5] = [
+    SimpleDateFormat::DEFAULT,
+    SimpleDateFormat::SHORT,
+    SimpleDateFormat::MEDIUM,
+    SimpleDateFormat::LONG,
+    SimpleDateFormat::FULL,
+];
+
+/// An argument of a `MessageFormat`, standing in for Java's `Object`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    /// Java's `null`, formatted as `null`.
+    Null,
+    String(String),
+    Number(Number),
+    Date(Date),
+}
+
+impl From<&str> for Argument {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for Argument {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Date> for Argument {
+    fn from(value: Date) -> Self {
+        Self::Date(value)
+    }
+}
+
+impl<T: Into<Number>> From<T> for Argument {
+    fn from(value: T) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+/// The format of an argument given by its type and style in the pattern. The number and
+/// date formats are boxed, being much larger than a choice format.
+#[derive(Debug, Clone, PartialEq)]
+enum SubFormat {
+    Number(Box<DecimalFormat>),
+    Date(Box<SimpleDateFormat>),
+    Choice(ChoiceFormat),
+}
+
+impl SubFormat {
+    fn format(&self, argument: &Argument) -> String {
+        match (self, argument) {
+            (Self::Number(format), Argument::Number(number)) => format.format(*number),
+            (Self::Choice(format), Argument::Number(number)) => {
+                format.format(number.double_value())
+            }
+            (Self::Date(format), Argument::Date(date)) => format.format(date),
+            (Self::Date(format), Argument::Number(number)) => {
+                format.format(&Date::with_time(number.long_value()))
+            }
+            (Self::Date(_), _) => IllegalArgumentException::new(
+                "Cannot format given Object as a Date".to_string(),
+                None,
+            )
+            .throw(),
+            _ => IllegalArgumentException::new(
+                "Cannot format given Object as a Number".to_string(),
+                None,
+            )
+            .throw(),
+        }
+    }
+
+    fn parse_from(&self, source: &str, pos: &mut ParsePosition) -> Argument {
+        let argument = match self {
+            Self::Number(format) => format.parse_from(source, pos).map(Argument::Number),
+            Self::Date(format) => format.parse_from(source, pos).map(Argument::Date),
+            Self::Choice(format) => format.parse_from(source, pos).map(Argument::from),
+        };
+        argument.unwrap_or(Argument::Null)
+    }
+}
+
+/// An argument's place in the pattern.
+#[derive(Debug, Clone, PartialEq)]
+struct Placeholder {
+    /// Where the argument goes, as a byte offset in the pattern's literal text.
+    offset: usize,
+    argument_number: usize,
+    format: Option<SubFormat>,
+}
+
+/// `java.text.MessageFormat`: fills numbered arguments into a pattern like
+/// `{0} has {1,number,integer} {1,choice,0#files|1#file|1<files}`.
+///
+/// An argument is `{index}` or `{index,type}` or `{index,type,style}`. The types are:
+/// - `number`, with the styles `integer`, `currency`, `percent` or a `DecimalFormat`
+///   pattern.
+/// - `date` and `time`, with the styles `short`, `medium`, `long`, `full` or a
+///   `SimpleDateFormat` pattern.
+/// - `choice`, with a `ChoiceFormat` pattern, whose result is formatted again as a message
+///   if it has a `{`.
+///
+/// Outside arguments, quotes escape text, so `'{0}'` is literal and `''` is a quote.
+/// Inside a type or style, quotes are kept for the sub-format to interpret.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageFormat {
+    pattern: String,
+    placeholders: Vec<Placeholder>,
+    locale: Locale,
+}
+
+impl MessageFormat {
+    /// A format for the pattern in the default locale. Panics with
+    /// `IllegalArgumentException` if the pattern is invalid.
+    pub fn new(pattern: &str) -> Self {
+        Self::with_locale(pattern, &Locale::get_default())
+    }
+
+    pub fn with_locale(pattern: &str, locale: &Locale) -> Self {
+        let mut format = Self {
+            pattern: String::new(),
+            placeholders: Vec::new(),
+            locale: locale.clone(),
+        };
+        format.apply_pattern(pattern);
+        format
+    }
+
+    /// Formats the arguments into a pattern in the default locale, like the static
+    /// `MessageFormat.format`.
+    pub fn format_pattern(pattern: &str, arguments: &[Argument]) -> String {
+        Self::new(pattern).format(arguments)
+    }
+
+    pub fn get_locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Sets the locale for the sub-formats of patterns applied from now on.
+    pub fn set_locale(&mut self, locale: &Locale) {
+        self.locale = locale.clone();
+    }
+
+    /// Replaces the pattern. Panics with `IllegalArgumentException` if it has an unmatched
+    /// brace, an argument index that is not a non-negative integer, an unknown type or an
+    /// invalid sub-format pattern.
+    pub fn apply_pattern(&mut self, pattern: &str) {
+        let mut segments: [Option<String>; 4] = [Some(String::new()), None, None, None];
+        let mut part = SEG_RAW;
+        let mut in_quote = false;
+        let mut brace_stack = 0;
+        self.placeholders.clear();
+
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            let segment = segments[part].get_or_insert_with(String::new);
+            if part == SEG_RAW {
+                if c == '\'' {
+                    if chars.next_if_eq(&'\'').is_some() {
+                        segment.push(c);
+                    } else {
+                        in_quote = !in_quote;
+                    }
+                } else if c == '{' && !in_quote {
+                    part = SEG_INDEX;
+                    segments[SEG_INDEX].get_or_insert_with(String::new);
+                } else {
+                    segment.push(c);
+                }
+            } else if in_quote {
+                segment.push(c);
+                if c == '\'' {
+                    in_quote = false;
+                }
+            } else {
+                match c {
+                    ',' if part < SEG_MODIFIER => {
+                        part += 1;
+                        segments[part].get_or_insert_with(String::new);
+                    }
+                    '{' => {
+                        brace_stack += 1;
+                        segment.push(c);
+                    }
+                    '}' if brace_stack == 0 => {
+                        part = SEG_RAW;
+                        self.make_format(&segments);
+                        segments[SEG_INDEX] = None;
+                        segments[SEG_TYPE] = None;
+                        segments[SEG_MODIFIER] = None;
+                    }
+                    '}' => {
+                        brace_stack -= 1;
+                        segment.push(c);
+                    }
+                    // Leading spaces of the type are skipped.
+                    ' ' if part == SEG_TYPE && segment.is_empty() => {}
+                    _ => {
+                        if c == '\'' {
+                            in_quote = true;
+                        }
+                        segment.push(c);
+                    }
+                }
+            }
+        }
+        if brace_stack == 0 && part != SEG_RAW {
+            self.placeholders.clear();
+            IllegalArgumentException::new("Unmatched braces in the pattern.".to_string(), None)
+                .throw();
+        }
+        self.pattern = segments[SEG_RAW].take().unwrap_or_default();
+    }
+
+    fn make_format(&mut self, segments: &[Option<String>; 4]) {
+        let segment = |part: usize| segments[part].as_deref().unwrap_or_default();
+        let Ok(argument_number) = segment(SEG_INDEX).parse::<i32>() else {
+            IllegalArgumentException::new(
+                format!("can't parse argument number: {}", segment(SEG_INDEX)),
+                None,
+            )
+            .throw();
+        };
+        if argument_number < 0 {
+            IllegalArgumentException::new(
+                format!("negative argument number: {argument_number}"),
+                None,
+            )
+            .throw();
+        }
+
+        let locale = &self.locale;
+        let modifier = segment(SEG_MODIFIER);
+        let format = match find_keyword(segment(SEG_TYPE), &TYPE_KEYWORDS) {
+            // An empty type, as in "{0,}", is no type.
+            Some(0) => None,
+            Some(1) => Some(SubFormat::Number(Box::new(
+                match find_keyword(modifier, &NUMBER_MODIFIER_KEYWORDS) {
+                    Some(0) => DecimalFormat::for_style(locale, NUMBER_STYLE),
+                    Some(1) => DecimalFormat::for_style(locale, CURRENCY_STYLE),
+                    Some(2) => DecimalFormat::for_style(locale, PERCENT_STYLE),
+                    Some(3) => DecimalFormat::for_style(locale, INTEGER_STYLE),
+                    _ => DecimalFormat::with_symbols(
+                        modifier,
+                        DecimalFormatSymbols::get_instance(locale),
+                    ),
+                },
+            ))),
+            Some(kind @ (2 | 3)) => Some(SubFormat::Date(Box::new(
+                match find_keyword(modifier, &DATE_TIME_MODIFIER_KEYWORDS) {
+                    Some(index) if kind == 2 => {
+                        SimpleDateFormat::get_date_instance(DATE_TIME_MODIFIERS[index], locale)
+                    }
+                    Some(index) => {
+                        SimpleDateFormat::get_time_instance(DATE_TIME_MODIFIERS[index], locale)
+                    }
+                    None => SimpleDateFormat::with_locale(modifier, locale),
+                },
+            ))),
+            Some(_) => match ChoiceFormat::checked(modifier) {
+                Some(format) => Some(SubFormat::Choice(format)),
+                None => IllegalArgumentException::new(
+                    format!("Choice Pattern incorrect: {modifier}"),
+                    None,
+                )
+                .throw(),
+            },
+            None => IllegalArgumentException::new(
+                format!("unknown format type: {}", segment(SEG_TYPE)),
+                None,
+            )
+            .throw(),
+        };
+        self.placeholders.push(Placeholder {
+            offset: segment(SEG_RAW).len(),
+            argument_number: argument_number as usize,
+            format,
+        });
+    }
+
+    /// The pattern, with the sub-formats written as the keywords they were made from where
+    /// they equal the locale's standard ones.
+    pub fn to_pattern(&self) -> String {
+        let locale = &self.locale;
+        let mut last_offset = 0;
+        let mut result = String::new();
+        for placeholder in &self.placeholders {
+            copy_and_fix_quotes(&self.pattern[last_offset..placeholder.offset], &mut result);
+            last_offset = placeholder.offset;
+            result.push('{');
+            result.push_str(&placeholder.argument_number.to_string());
+            match &placeholder.format {
+                None => {}
+                Some(SubFormat::Number(format)) => {
+                    let styles = [NUMBER_STYLE, CURRENCY_STYLE, PERCENT_STYLE, INTEGER_STYLE];
+                    let keywords = [
+                        ",number",
+                        ",number,currency",
+                        ",number,percent",
+                        ",number,integer",
+                    ];
+                    match styles
+                        .iter()
+                        .position(|&style| **format == DecimalFormat::for_style(locale, style))
+                    {
+                        Some(index) => result.push_str(keywords[index]),
+                        None => {
+                            result.push_str(",number,");
+                            result.push_str(&format.to_pattern());
+                        }
+                    }
+                }
+                Some(SubFormat::Date(format)) => {
+                    let mut keyword = None;
+                    for (index, &style) in DATE_TIME_MODIFIERS.iter().enumerate() {
+                        if **format == SimpleDateFormat::get_date_instance(style, locale) {
+                            keyword = Some((",date", index));
+                            break;
+                        }
+                        if **format == SimpleDateFormat::get_time_instance(style, locale) {
+                            keyword = Some((",time", index));
+                            break;
+                        }
+                    }
+                    match keyword {
+                        Some((kind, index)) => {
+                            result.push_str(kind);
+                            if index != 0 {
+                                result.push(',');
+                                result.push_str(DATE_TIME_MODIFIER_KEYWORDS[index]);
+                            }
+                        }
+                        None => {
+                            result.push_str(",date,");
+                            result.push_str(format.to_pattern());
+                        }
+                    }
+                }
+                Some(SubFormat::Choice(format)) => {
+                    result.push_str(",choice,");
+                    result.push_str(&format.to_pattern());
+                }
+            }
+            result.push('}');
+        }
+        copy_and_fix_quotes(&self.pattern[last_offset..], &mut result);
+        result
+    }
+
+    /// Formats the arguments into the pattern. An argument index past the end of the
+    /// arguments is left as `{index}`. Panics with `IllegalArgumentException` if an argument
+    /// does not suit its sub-format, like a string for a number.
+    pub fn format(&self, arguments: &[Argument]) -> String {
+        let mut result = String::new();
+        let mut last_offset = 0;
+        for placeholder in &self.placeholders {
+            result.push_str(&self.pattern[last_offset..placeholder.offset]);
+            last_offset = placeholder.offset;
+            let Some(argument) = arguments.get(placeholder.argument_number) else {
+                result.push('{');
+                result.push_str(&placeholder.argument_number.to_string());
+                result.push('}');
+                continue;
+            };
+            match (argument, &placeholder.format) {
+                (Argument::Null, _) => result.push_str("null"),
+                (_, Some(format @ SubFormat::Choice(_))) => {
+                    // A choice with an argument in it is a message of its own.
+                    let choice = format.format(argument);
+                    if choice.contains('{') {
+                        result
+                            .push_str(&Self::with_locale(&choice, &self.locale).format(arguments));
+                    } else {
+                        result.push_str(&choice);
+                    }
+                }
+                (_, Some(format)) => result.push_str(&format.format(argument)),
+                (Argument::Number(number), None) => result.push_str(
+                    &DecimalFormat::for_style(&self.locale, NUMBER_STYLE).format(*number),
+                ),
+                (Argument::Date(date), None) => result.push_str(
+                    &SimpleDateFormat::get_date_time_instance(
+                        SimpleDateFormat::SHORT,
+                        SimpleDateFormat::SHORT,
+                        &self.locale,
+                    )
+                    .format(date),
+                ),
+                (Argument::String(string), None) => result.push_str(string),
+            }
+        }
+        result.push_str(&self.pattern[last_offset..]);
+        result
+    }
+
+    /// Parses a message from the start of the text into its arguments, like
+    /// `MessageFormat.parse(String)`.
+    pub fn parse(&self, source: &str) -> Result<Vec<Argument>, ParseException> {
+        let mut pos = ParsePosition::new(0);
+        match self.parse_from(source, &mut pos) {
+            Some(arguments) if pos.get_index() != 0 => Ok(arguments),
+            _ => Err(ParseException::new(
+                "MessageFormat parse error!".to_string(),
+                pos.get_error_index().unwrap_or(0) as i32,
+            )),
+        }
+    }
+
+    /// Parses a message at the position into its arguments, advancing the position past
+    /// it, or setting its error index and returning `None` on failure. Arguments missing
+    /// from the pattern are `Null`.
+    ///
+    /// An argument without a sub-format takes the text up to the next literal text of the
+    /// pattern, or all of it at the end. Arguments parsed as `{index}` are left `Null`.
+    pub fn parse_from(&self, source: &str, pos: &mut ParsePosition) -> Option<Vec<Argument>> {
+        let argument_count = self
+            .placeholders
+            .iter()
+            .map(|placeholder| placeholder.argument_number + 1)
+            .max()
+            .unwrap_or(0);
+        let mut result = vec![Argument::Null; argument_count];
+        let mut pattern_offset = 0;
+        let mut source_offset = pos.get_index();
+
+        for (i, placeholder) in self.placeholders.iter().enumerate() {
+            // The text before the argument.
+            let literal = &self.pattern[pattern_offset..placeholder.offset];
+            if !source
+                .get(source_offset..)
+                .is_some_and(|rest| rest.starts_with(literal))
+            {
+                pos.set_error_index(Some(source_offset));
+                return None;
+            }
+            source_offset += literal.len();
+            pattern_offset = placeholder.offset;
+
+            match &placeholder.format {
+                None => {
+                    let end = self
+                        .placeholders
+                        .get(i + 1)
+                        .map_or(self.pattern.len(), |next| next.offset);
+                    let next = if pattern_offset >= end {
+                        Some(source.len())
+                    } else {
+                        source[source_offset..]
+                            .find(&self.pattern[pattern_offset..end])
+                            .map(|index| source_offset + index)
+                    };
+                    let Some(next) = next else {
+                        pos.set_error_index(Some(source_offset));
+                        return None;
+                    };
+                    let value = &source[source_offset..next];
+                    if value != format!("{{{}}}", placeholder.argument_number) {
+                        result[placeholder.argument_number] = Argument::String(value.to_string());
+                    }
+                    source_offset = next;
+                }
+                Some(format) => {
+                    let mut temp_status = ParsePosition::new(source_offset);
+                    result[placeholder.argument_number] =
+                        format.parse_from(source, &mut temp_status);
+                    if temp_status.get_index() == source_offset {
+                        pos.set_error_index(Some(source_offset));
+                        return None;
+                    }
+                    source_offset = temp_status.get_index();
+                }
+            }
+        }
+
+        let literal = &self.pattern[pattern_offset..];
+        if !source
+            .get(source_offset..)
+            .is_some_and(|rest| rest.starts_with(literal))
+        {
+            pos.set_error_index(Some(source_offset));
+            return None;
+        }
+        pos.set_index(source_offset + literal.len());
+        Some(result)
+    }
+}
+
+/// The index of a keyword in the list, as is or trimmed and in lower case.
+fn find_keyword(s: &str, list: &[&str]) -> Option<usize> {
+    list.iter().position(|&keyword| keyword == s).or_else(|| {
+        let lower = s.trim_matches(|c| c <= ' ').to_lowercase();
+        list.iter().position(|&keyword| keyword == lower)
+    })
+}
+
+/// Appends literal text, quoting braces and doubling quotes.
+fn copy_and_fix_quotes(source: &str, target: &mut String) {
+    let mut quoted = false;
+    for c in source.chars() {
+        if c == '{' {
+            if !quoted {
+                target.push('\'');
+                quoted = true;
+            }
+            target.push(c);
+        } else if c == '\'' {
+            target.push_str("''");
+        } else {
+            if quoted {
+                target.push('\'');
+                quoted = false;
+            }
+            target.push(c);
+        }
+    }
+    if quoted {
+        target.push('\'');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(pattern: &str) -> MessageFormat {
+        MessageFormat::with_locale(pattern, &Locale::US)
+    }
+
+    #[test]
+    fn formats_error_catalog_messages() {
+        let format = us("{0} has {1,number,integer} {1,choice,0#files|1#file|1<files}");
+        assert_eq!(
+            format.format(&["disk".into(), 0.into()]),
+            "disk has 0 files"
+        );
+        assert_eq!(format.format(&["disk".into(), 1.into()]), "disk has 1 file");
+        assert_eq!(
+            format.format(&["disk".into(), 1234.into()]),
+            "disk has 1,234 files"
+        );
+        assert_eq!(format.format(&["disk".into()]), "disk has {1} {1}");
+        assert_eq!(
+            format.format(&[Argument::Null, 2.5.into()]),
+            "null has 2 files"
+        );
+    }
+
+    #[test]
+    fn formats_sub_formats_like_jdk() {
+        let date = Date::with_time(1_700_000_000_000);
+        let format = us(
+            "{0,date} {0,time} {1,number} {1,number,integer} {1,number,percent} {1,number,currency}",
+        );
+        let expected = format!(
+            "{} {} 1,234.568 1,235 123,457% $1,234.57",
+            SimpleDateFormat::get_date_instance(SimpleDateFormat::DEFAULT, &Locale::US)
+                .format(&date),
+            SimpleDateFormat::get_time_instance(SimpleDateFormat::DEFAULT, &Locale::US)
+                .format(&date),
+        );
+        assert_eq!(format.format(&[date.into(), 1234.5678.into()]), expected);
+
+        let format = us("{0,number,#.##} {0,number,0.000E0} {1,date,yyyy-MM-dd'T'HH} {2}");
+        let hour = SimpleDateFormat::with_locale("yyyy-MM-dd'T'HH", &Locale::US).format(&date);
+        assert_eq!(
+            format.format(&[std::f64::consts::PI.into(), date.into(), 7.into()]),
+            format!("3.14 3.142E0 {hour} 7")
+        );
+    }
+
+    #[test]
+    fn quotes_like_jdk() {
+        assert_eq!(us("it''s '{0}' {0}").format(&["x".into()]), "it's {0} x");
+        assert_eq!(us("'{'{0}'}'").format(&["x".into()]), "{x}");
+        // The choice unquotes its strings before they are formatted again.
+        assert_eq!(
+            us("{0,choice,0#'{1}'|1#{1} and '{2}'}").format(&[0.into(), "a".into()]),
+            "a"
+        );
+        assert_eq!(
+            us("{0,choice,0#'{1}'|1#{1} and '{2}'}").format(&[1.into(), "a".into()]),
+            "a and {2}"
+        );
+        assert_eq!(us("{0,choice,0#no|1#a '' b}").format(&[1.into()]), "a ' b");
+    }
+
+    #[test]
+    fn nested_choice_formats_as_message() {
+        let format = us("{0,choice,0#no files|1#one file|1<{0,number,integer} files}");
+        assert_eq!(format.format(&[0.into()]), "no files");
+        assert_eq!(format.format(&[1.into()]), "one file");
+        assert_eq!(format.format(&[12345.into()]), "12,345 files");
+    }
+
+    #[test]
+    fn to_pattern_like_jdk() {
+        assert_eq!(
+            us("{0} has {1,number,integer} {1,choice,0#files|1#file|1<files}").to_pattern(),
+            "{0} has {1,number,integer} {1,choice,0.0#files|1.0#file|1.0<files}"
+        );
+        assert_eq!(
+            us("{0,date,short}{1,time,full}{2,date}{3,number,#.#}{4, NUMBER , Percent}{5,}")
+                .to_pattern(),
+            "{0,date,short}{1,time,full}{2,date}{3,number,#0.#}{4,number,percent}{5}"
+        );
+        assert_eq!(
+            us("'{a}' it''s {0,date,HH:mm}").to_pattern(),
+            "'{'a} it''s {0,date,HH:mm}"
+        );
+    }
+
+    #[test]
+    fn rejects_bad_patterns() {
+        let message = |pattern: &'static str| {
+            let result = std::panic::catch_unwind(|| us(pattern));
+            *result.unwrap_err().downcast::<String>().unwrap()
+        };
+        assert_eq!(message("{0"), "Unmatched braces in the pattern.");
+        assert_eq!(message("{x}"), "can't parse argument number: x");
+        assert_eq!(message("{-1}"), "negative argument number: -1");
+        assert_eq!(message("{0,money}"), "unknown format type: money");
+        assert_eq!(message("{0,choice,x#a}"), "Choice Pattern incorrect: x#a");
+        assert_eq!(
+            message("{0,choice,1#a|0#b}"),
+            "Choice Pattern incorrect: 1#a|0#b"
+        );
+        // Like the JDK, unclosed nested braces are not an error.
+        assert_eq!(us("{0,choice,0#{").to_pattern(), "");
+    }
+
+    #[test]
+    fn parses_arguments() {
+        let format = us("{0} has {1,number,integer} {1,choice,0#files|1#file|1<files}");
+        // The choice is parsed last, and its first string matching wins.
+        assert_eq!(
+            format.parse("disk has 3 files"),
+            Ok(vec!["disk".into(), Argument::Number(Number::Double(0.0))])
+        );
+        let format = us("{1}, {0,number}!");
+        assert_eq!(
+            format.parse("b, 1,234.5!"),
+            Ok(vec![Argument::Number(Number::Double(1234.5)), "b".into()])
+        );
+        assert_eq!(format.parse("{1}, 7!"), Ok(vec![7.into(), Argument::Null]));
+
+        let error = format.parse("b; 7!").unwrap_err();
+        assert_eq!(error.message(), "MessageFormat parse error!");
+        assert_eq!(error.get_error_offset(), 0);
+        assert_eq!(format.parse("b, x!").unwrap_err().get_error_offset(), 3);
+
+        let mut pos = ParsePosition::new(4);
+        assert_eq!(
+            format.parse_from("xxx a, 2! yyy", &mut pos),
+            Some(vec![2.into(), "a".into()])
+        );
+        assert_eq!(pos.get_index(), 9);
+    }
+}
@@ -0,0 +1,29 @@
+//! `java.text`: Unicode normalization, locale-sensitive collation, text boundary
+//! analysis and number, date and message formatting, built on data generated from the JDK
+//! by tools/GenTextTables.java so that results match Java's exactly.
+
+mod break_iterator;
+mod choice_format;
+mod collator;
+mod compact_number_format;
+mod date_format_symbols;
+mod decimal_format;
+mod locale_data;
+mod message_format;
+pub mod normalizer;
+mod parse_position;
+mod simple_date_format;
+#[rustfmt::skip]
+mod tables;
+
+pub use break_iterator::BreakIterator;
+pub use choice_format::ChoiceFormat;
+pub use collator::{CollationKey, Collator};
+pub use compact_number_format::{CompactNumberFormat, Style};
+pub use date_format_symbols::DateFormatSymbols;
+pub use decimal_format::{DecimalFormat, DecimalFormatSymbols, Number};
+pub use message_format::{Argument, MessageFormat};
+pub use normalizer::Form;
+pub use parse_position::ParsePosition;
+pub use simple_date_format::SimpleDateFormat;
+pub(crate) use simple_date_format::{calendar_fields, calendar_rules, zone_display_name};
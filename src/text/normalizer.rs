@@ -0,0 +1,175 @@
+//! `java.text.Normalizer`: the four Unicode normalization forms of UAX #15.
+
+use crate::character::get_combining_class;
+
+use super::tables;
+
+/// A Unicode normalization form; `java.text.Normalizer.Form`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Form {
+    /// Canonical decomposition.
+    NFD,
+    /// Canonical decomposition, followed by canonical composition.
+    NFC,
+    /// Compatibility decomposition.
+    NFKD,
+    /// Compatibility decomposition, followed by canonical composition.
+    NFKC,
+}
+
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Normalizes `src` to the given form.
+pub fn normalize(src: &str, form: Form) -> String {
+    let decomposed = decompose(src, matches!(form, Form::NFKD | Form::NFKC));
+    match form {
+        Form::NFD | Form::NFKD => decomposed.into_iter().collect(),
+        Form::NFC | Form::NFKC => compose(decomposed),
+    }
+}
+
+/// Whether `src` is already in the given form.
+pub fn is_normalized(src: &str, form: Form) -> bool {
+    normalize(src, form) == src
+}
+
+fn combining_class(ch: char) -> u8 {
+    get_combining_class(ch as i32)
+}
+
+fn decomposition(table: &[(u32, u16, u8)], ch: char) -> Option<&'static [u32]> {
+    let index = table
+        .binary_search_by_key(&(ch as u32), |&(cp, _, _)| cp)
+        .ok()?;
+    let (_, offset, length) = table[index];
+    let offset = offset as usize;
+    Some(&tables::DECOMPOSITION_DATA[offset..offset + length as usize])
+}
+
+/// The full decomposition of `src` in canonical order.
+pub(super) fn decompose(src: &str, compatibility: bool) -> Vec<char> {
+    let mut chars = Vec::with_capacity(src.len());
+    for ch in src.chars() {
+        let s = (ch as u32).wrapping_sub(HANGUL_S_BASE);
+        if s < HANGUL_S_COUNT {
+            chars.push(char::from_u32(HANGUL_L_BASE + s / HANGUL_N_COUNT).unwrap());
+            chars
+                .push(char::from_u32(HANGUL_V_BASE + s % HANGUL_N_COUNT / HANGUL_T_COUNT).unwrap());
+            if !s.is_multiple_of(HANGUL_T_COUNT) {
+                chars.push(char::from_u32(HANGUL_T_BASE + s % HANGUL_T_COUNT).unwrap());
+            }
+            continue;
+        }
+
+        let full = compatibility
+            .then(|| decomposition(&tables::COMPATIBILITY_DECOMPOSITIONS, ch))
+            .flatten()
+            .or_else(|| decomposition(&tables::CANONICAL_DECOMPOSITIONS, ch));
+        match full {
+            Some(full) => chars.extend(full.iter().map(|&cp| char::from_u32(cp).unwrap())),
+            None => chars.push(ch),
+        }
+    }
+
+    // Canonical ordering: a stable sort of each run of non-starters by combining class.
+    let mut start = 0;
+    while start < chars.len() {
+        if combining_class(chars[start]) == 0 {
+            start += 1;
+            continue;
+        }
+        let end = chars[start..]
+            .iter()
+            .position(|&ch| combining_class(ch) == 0)
+            .map_or(chars.len(), |length| start + length);
+        chars[start..end].sort_by_key(|&ch| combining_class(ch));
+        start = end;
+    }
+    chars
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first, second) = (first as u32, second as u32);
+    let l = first.wrapping_sub(HANGUL_L_BASE);
+    let v = second.wrapping_sub(HANGUL_V_BASE);
+    if l < HANGUL_L_COUNT && v < HANGUL_V_COUNT {
+        return char::from_u32(HANGUL_S_BASE + (l * HANGUL_V_COUNT + v) * HANGUL_T_COUNT);
+    }
+    let s = first.wrapping_sub(HANGUL_S_BASE);
+    let t = second.wrapping_sub(HANGUL_T_BASE);
+    if s < HANGUL_S_COUNT && s % HANGUL_T_COUNT == 0 && t > 0 && t < HANGUL_T_COUNT {
+        return char::from_u32(first + t);
+    }
+
+    tables::COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()
+        .map(|index| char::from_u32(tables::COMPOSITIONS[index].2).unwrap())
+}
+
+/// Canonical composition of a decomposed string: each character joins the last starter
+/// unless a character in between blocks it.
+fn compose(chars: Vec<char>) -> String {
+    let mut result: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    let mut last_class = 0;
+    for ch in chars {
+        let class = combining_class(ch);
+        if let Some(index) = starter {
+            let adjacent = index == result.len() - 1;
+            if adjacent || (last_class != 0 && last_class < class) {
+                if let Some(composite) = compose_pair(result[index], ch) {
+                    result[index] = composite;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+        }
+        last_class = class;
+        result.push(ch);
+    }
+    result.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_normalized, normalize, Form};
+
+    #[test]
+    fn normalizes_like_jdk() {
+        let cases = [
+            ("Åström", "A\u{30A}stro\u{308}m", "Åström"),
+            ("ﬁ café", "ﬁ cafe\u{301}", "ﬁ café"),
+            (
+                "한국어",
+                "\u{1112}\u{1161}\u{11AB}\u{1100}\u{116E}\u{11A8}\u{110B}\u{1165}",
+                "한국어",
+            ),
+            ("q\u{307}\u{323}", "q\u{323}\u{307}", "q\u{323}\u{307}"),
+            ("\u{1E0B}\u{323}", "d\u{323}\u{307}", "\u{1E0D}\u{307}"),
+            ("\u{212B}\u{2126}", "A\u{30A}\u{3A9}", "Å\u{3A9}"),
+            ("a\u{301}\u{301}", "a\u{301}\u{301}", "á\u{301}"),
+        ];
+        for (src, nfd, nfc) in cases {
+            assert_eq!(normalize(src, Form::NFD), nfd, "NFD of {src:?}");
+            assert_eq!(normalize(src, Form::NFC), nfc, "NFC of {src:?}");
+        }
+
+        assert_eq!(normalize("ﬁ ①²", Form::NFKD), "fi 12");
+        assert_eq!(normalize("ｶﾞ ẛ̣", Form::NFKC), "ガ ṩ");
+        assert_eq!(normalize("ｶﾞ", Form::NFKD), "カ\u{3099}");
+        assert!(is_normalized("café", Form::NFC));
+        assert!(!is_normalized("café", Form::NFD));
+        assert!(!is_normalized("ﬁ", Form::NFKC));
+    }
+}
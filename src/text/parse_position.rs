@@ -0,0 +1,33 @@
+/// `java.text.ParsePosition`: where parsing starts, advanced past what was parsed, and where
+/// it failed if it did. Indices are byte offsets into the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsePosition {
+    index: usize,
+    error_index: Option<usize>,
+}
+
+impl ParsePosition {
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            error_index: None,
+        }
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn set_index(&mut self, index: usize) {
+        self.index = index;
+    }
+
+    /// Where parsing failed, or `None` (Java's -1) if it has not.
+    pub fn get_error_index(&self) -> Option<usize> {
+        self.error_index
+    }
+
+    pub fn set_error_index(&mut self, error_index: Option<usize>) {
+        self.error_index = error_index;
+    }
+}
@@ -0,0 +1,1518 @@
+use crate::character;
+use crate::errors::{IllegalArgumentException, ParseException, Throwable};
+use crate::util::random::current_time_millis;
+use crate::util::{custom_id, Date, Locale, TimeZone};
+
+use super::decimal_format::{DecimalFormat, INTEGER_STYLE};
+use super::locale_data::{
+    self, DateData, LONG_FORMAT, LONG_STANDALONE, SHORT_FORMAT, SHORT_STANDALONE,
+};
+use super::ParsePosition;
+
+/// The pattern letters, in the order of the JDK's `DateFormatSymbols.patternChars`.
+const PATTERN_CHARS: &str = "GyMdkHmsSEDFwWahKzZYuXL";
+
+const PATTERN_ERA: usize = 0;
+const PATTERN_YEAR: usize = 1;
+const PATTERN_MONTH: usize = 2;
+const PATTERN_DAY_OF_MONTH: usize = 3;
+const PATTERN_HOUR_OF_DAY1: usize = 4;
+const PATTERN_HOUR_OF_DAY0: usize = 5;
+const PATTERN_MINUTE: usize = 6;
+const PATTERN_SECOND: usize = 7;
+const PATTERN_MILLISECOND: usize = 8;
+const PATTERN_DAY_OF_WEEK: usize = 9;
+const PATTERN_DAY_OF_YEAR: usize = 10;
+const PATTERN_DAY_OF_WEEK_IN_MONTH: usize = 11;
+const PATTERN_WEEK_OF_YEAR: usize = 12;
+const PATTERN_WEEK_OF_MONTH: usize = 13;
+const PATTERN_AM_PM: usize = 14;
+const PATTERN_HOUR1: usize = 15;
+const PATTERN_HOUR0: usize = 16;
+const PATTERN_ZONE_NAME: usize = 17;
+const PATTERN_ZONE_VALUE: usize = 18;
+const PATTERN_WEEK_YEAR: usize = 19;
+const PATTERN_ISO_DAY_OF_WEEK: usize = 20;
+const PATTERN_ISO_ZONE: usize = 21;
+const PATTERN_MONTH_STANDALONE: usize = 22;
+
+/// `java.util.Calendar` field numbers, with the pseudo fields of the JDK's `CalendarBuilder`.
+const ERA: usize = 0;
+const YEAR: usize = 1;
+const MONTH: usize = 2;
+const WEEK_OF_YEAR: usize = 3;
+const WEEK_OF_MONTH: usize = 4;
+const DAY_OF_MONTH: usize = 5;
+const DAY_OF_YEAR: usize = 6;
+const DAY_OF_WEEK: usize = 7;
+const DAY_OF_WEEK_IN_MONTH: usize = 8;
+const AM_PM: usize = 9;
+const HOUR: usize = 10;
+const HOUR_OF_DAY: usize = 11;
+const MINUTE: usize = 12;
+const SECOND: usize = 13;
+const MILLISECOND: usize = 14;
+const ZONE_OFFSET: usize = 15;
+const DST_OFFSET: usize = 16;
+const WEEK_YEAR: usize = 17;
+const ISO_DAY_OF_WEEK: usize = 18;
+const FIELD_COUNT: usize = 19;
+
+const PATTERN_INDEX_TO_CALENDAR_FIELD: [usize; 23] = [
+    ERA,
+    YEAR,
+    MONTH,
+    DAY_OF_MONTH,
+    HOUR_OF_DAY,
+    HOUR_OF_DAY,
+    MINUTE,
+    SECOND,
+    MILLISECOND,
+    DAY_OF_WEEK,
+    DAY_OF_YEAR,
+    DAY_OF_WEEK_IN_MONTH,
+    WEEK_OF_YEAR,
+    WEEK_OF_MONTH,
+    AM_PM,
+    HOUR,
+    HOUR,
+    ZONE_OFFSET,
+    ZONE_OFFSET,
+    WEEK_YEAR,
+    ISO_DAY_OF_WEEK,
+    ZONE_OFFSET,
+    MONTH,
+];
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+/// The Julian-to-Gregorian cutover of `GregorianCalendar`, 1582-10-15, in days since the
+/// epoch.
+const GREGORIAN_CUTOVER_DAY: i64 = -141_427;
+const BUDDHIST_YEAR_OFFSET: i32 = 543;
+const GMT: &str = "GMT";
+
+/// A compiled pattern: runs of a pattern letter, and literal text.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Field { index: usize, count: usize },
+    Literal(String),
+}
+
+/// `java.text.SimpleDateFormat`: formats and parses dates by a pattern such as
+/// `yyyy-MM-dd'T'HH:mm:ss`, with a locale's names and digits and a time zone.
+///
+/// Dates use the locale's calendar like the JDK's do: Gregorian, switching to Julian
+/// before 1582-10-15, or Buddhist for Thai. Time zone names are always the English ones.
+/// Parse positions are byte offsets into the text.
+#[derive(Debug, Clone)]
+pub struct SimpleDateFormat {
+    pattern: String,
+    compiled: Vec<Token>,
+    force_standalone_form: bool,
+    locale: Locale,
+    data: &'static DateData,
+    number_format: DecimalFormat,
+    zone: TimeZone,
+    lenient: bool,
+    default_century_start: i64,
+    default_century_start_year: i32,
+}
+
+impl SimpleDateFormat {
+    pub const FULL: i32 = 0;
+    pub const LONG: i32 = 1;
+    pub const MEDIUM: i32 = 2;
+    pub const SHORT: i32 = 3;
+    pub const DEFAULT: i32 = Self::MEDIUM;
+
+    /// A format for the pattern in the default locale. Panics with
+    /// `IllegalArgumentException` if the pattern has an unknown letter or an unterminated
+    /// quote.
+    pub fn new(pattern: &str) -> Self {
+        Self::with_locale(pattern, &Locale::get_default())
+    }
+
+    pub fn with_locale(pattern: &str, locale: &Locale) -> Self {
+        let (compiled, force_standalone_form) = compile(pattern);
+        let mut number_format = DecimalFormat::for_style(locale, INTEGER_STYLE);
+        number_format.set_grouping_used(false);
+        let mut format = Self {
+            pattern: pattern.to_string(),
+            compiled,
+            force_standalone_form,
+            locale: locale.clone(),
+            data: locale_data::date_data(locale),
+            number_format,
+            zone: TimeZone::get_default(),
+            lenient: true,
+            default_century_start: 0,
+            default_century_start_year: 0,
+        };
+        // Two-digit years are read as within 80 years before and 20 years after now.
+        let now = format.fields(current_time_millis());
+        let fixed = fixed_from_date(
+            now.gregorian_year - 80,
+            now.month as u32 + 1,
+            now.day_of_month as u32,
+        );
+        let start = fixed * MILLIS_PER_DAY + now.millis_of_day - now.zone_offset as i64;
+        format.set_2digit_year_start(&Date::with_time(start));
+        format
+    }
+
+    /// A locale's format for dates in one of the styles `FULL` to `SHORT`.
+    pub fn get_date_instance(style: i32, locale: &Locale) -> Self {
+        let style = check_style(style, "date");
+        Self::with_locale(locale_data::date_data(locale).date_patterns[style], locale)
+    }
+
+    /// A locale's format for times in one of the styles `FULL` to `SHORT`.
+    pub fn get_time_instance(style: i32, locale: &Locale) -> Self {
+        let style = check_style(style, "time");
+        Self::with_locale(locale_data::date_data(locale).time_patterns[style], locale)
+    }
+
+    /// A locale's format for dates and times in the given styles.
+    pub fn get_date_time_instance(date_style: i32, time_style: i32, locale: &Locale) -> Self {
+        let date_style = check_style(date_style, "date");
+        let time_style = check_style(time_style, "time");
+        Self::with_locale(
+            locale_data::date_data(locale).date_time_patterns[date_style][time_style],
+            locale,
+        )
+    }
+
+    pub fn to_pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Replaces the pattern. Panics with `IllegalArgumentException` if it is invalid.
+    pub fn apply_pattern(&mut self, pattern: &str) {
+        let (compiled, force_standalone_form) = compile(pattern);
+        self.pattern = pattern.to_string();
+        self.compiled = compiled;
+        self.force_standalone_form = force_standalone_form;
+    }
+
+    pub fn get_locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn get_time_zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    pub fn set_time_zone(&mut self, zone: TimeZone) {
+        self.zone = zone;
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Whether parsing accepts out of range fields like February 30, rolling them over.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    pub fn get_2digit_year_start(&self) -> Date {
+        Date::with_time(self.default_century_start)
+    }
+
+    /// Parses two-digit years as within the 100 years from this date.
+    pub fn set_2digit_year_start(&mut self, start_date: &Date) {
+        self.default_century_start = start_date.get_time();
+        self.default_century_start_year = self.fields(start_date.get_time()).year;
+    }
+
+    /// The calendar fields of a time in the format's zone and calendar.
+    fn fields(&self, time: i64) -> Fields {
+        fields(time, &self.zone, self.data)
+    }
+
+    /// Formats a date.
+    pub fn format(&self, date: &Date) -> String {
+        let fields = self.fields(date.get_time());
+        let mut buffer = String::new();
+        for token in &self.compiled {
+            match token {
+                Token::Literal(text) => buffer.push_str(text),
+                &Token::Field { index, count } => {
+                    self.sub_format(&mut buffer, &fields, index, count)
+                }
+            }
+        }
+        buffer
+    }
+
+    fn sub_format(&self, buffer: &mut String, fields: &Fields, index: usize, count: usize) {
+        let data = self.data;
+        let style = if count >= 4 {
+            LONG_FORMAT
+        } else {
+            SHORT_FORMAT
+        };
+        let standalone = if count >= 4 {
+            LONG_STANDALONE
+        } else {
+            SHORT_STANDALONE
+        };
+        let month = fields.month as usize;
+        let max_int_count = usize::MAX;
+        match index {
+            PATTERN_ERA => {
+                buffer.push_str(data.eras[style][fields.era as usize].unwrap_or_default())
+            }
+            PATTERN_YEAR | PATTERN_WEEK_YEAR => {
+                let value = if index == PATTERN_YEAR {
+                    fields.year
+                } else {
+                    fields.week_year
+                };
+                if count != 2 {
+                    self.zero_padding_number(buffer, value, count, max_int_count);
+                } else {
+                    self.zero_padding_number(buffer, value, 2, 2);
+                }
+            }
+            PATTERN_MONTH | PATTERN_MONTH_STANDALONE => {
+                let name = if count < 3 {
+                    None
+                } else if index == PATTERN_MONTH_STANDALONE || self.force_standalone_form {
+                    data.months[standalone][month].or(data.months[style][month])
+                } else {
+                    data.months[style][month]
+                };
+                match name {
+                    Some(name) => buffer.push_str(name),
+                    None => {
+                        self.zero_padding_number(buffer, fields.month + 1, count, max_int_count)
+                    }
+                }
+            }
+            PATTERN_HOUR_OF_DAY1 => {
+                let hour = if fields.hour_of_day == 0 {
+                    24
+                } else {
+                    fields.hour_of_day
+                };
+                self.zero_padding_number(buffer, hour, count, max_int_count);
+            }
+            PATTERN_DAY_OF_WEEK => {
+                let day = fields.day_of_week as usize - 1;
+                buffer.push_str(data.weekdays[style][day].unwrap_or_default());
+            }
+            PATTERN_AM_PM => {
+                let am_pm = (fields.hour_of_day >= 12) as usize;
+                buffer.push_str(data.am_pm[style][am_pm].unwrap_or_default());
+            }
+            PATTERN_HOUR1 => {
+                let hour = if fields.hour_of_day % 12 == 0 {
+                    12
+                } else {
+                    fields.hour_of_day % 12
+                };
+                self.zero_padding_number(buffer, hour, count, max_int_count);
+            }
+            PATTERN_ZONE_NAME => buffer.push_str(&zone_display_name(
+                &self.zone,
+                fields.daylight,
+                count >= 4,
+                &self.locale,
+            )),
+            PATTERN_ZONE_VALUE => {
+                // RFC 822, like -0800.
+                let minutes = fields.zone_offset / 60_000;
+                buffer.push(if minutes >= 0 { '+' } else { '-' });
+                let minutes = minutes.abs();
+                buffer.push_str(&format!("{:04}", minutes / 60 * 100 + minutes % 60));
+            }
+            PATTERN_ISO_ZONE => {
+                // ISO 8601, like Z, -08, -0800 or -08:00.
+                if fields.zone_offset == 0 {
+                    buffer.push('Z');
+                    return;
+                }
+                let minutes = fields.zone_offset / 60_000;
+                buffer.push(if minutes >= 0 { '+' } else { '-' });
+                let minutes = minutes.abs();
+                buffer.push_str(&format!("{:02}", minutes / 60));
+                if count == 1 {
+                    return;
+                }
+                if count == 3 {
+                    buffer.push(':');
+                }
+                buffer.push_str(&format!("{:02}", minutes % 60));
+            }
+            _ => {
+                let value = match index {
+                    PATTERN_DAY_OF_MONTH => fields.day_of_month,
+                    PATTERN_HOUR_OF_DAY0 => fields.hour_of_day,
+                    PATTERN_MINUTE => fields.minute,
+                    PATTERN_SECOND => fields.second,
+                    PATTERN_MILLISECOND => fields.millisecond,
+                    PATTERN_DAY_OF_YEAR => fields.day_of_year,
+                    PATTERN_DAY_OF_WEEK_IN_MONTH => (fields.day_of_month - 1) / 7 + 1,
+                    PATTERN_WEEK_OF_YEAR => fields.week_of_year,
+                    PATTERN_WEEK_OF_MONTH => fields.week_of_month,
+                    PATTERN_HOUR0 => fields.hour_of_day % 12,
+                    // PATTERN_ISO_DAY_OF_WEEK, from Monday as 1.
+                    _ => (fields.day_of_week + 5) % 7 + 1,
+                };
+                self.zero_padding_number(buffer, value, count, max_int_count);
+            }
+        }
+    }
+
+    /// Formats a number in the locale's digits, with at least `min_digits` and at most the
+    /// last `max_digits` of them.
+    fn zero_padding_number(
+        &self,
+        buffer: &mut String,
+        value: i32,
+        min_digits: usize,
+        max_digits: usize,
+    ) {
+        if value < 0 {
+            let mut format = self.number_format.clone();
+            format.set_minimum_integer_digits(min_digits.min(i32::MAX as usize) as i32);
+            format.set_maximum_integer_digits(max_digits.min(i32::MAX as usize) as i32);
+            buffer.push_str(&format.format(value));
+            return;
+        }
+        let zero = self
+            .number_format
+            .get_decimal_format_symbols()
+            .get_zero_digit();
+        let digits = value.to_string();
+        let digits = &digits[digits.len().saturating_sub(max_digits)..];
+        for _ in digits.len()..min_digits {
+            buffer.push(zero);
+        }
+        buffer.extend(
+            digits
+                .bytes()
+                .map(|digit| char::from_u32(zero as u32 + (digit - b'0') as u32).unwrap_or(zero)),
+        );
+    }
+
+    /// Parses a date from the start of the text, like `DateFormat.parse(String)`.
+    pub fn parse(&self, source: &str) -> Result<Date, ParseException> {
+        let mut pos = ParsePosition::new(0);
+        match self.parse_from(source, &mut pos) {
+            Some(date) if pos.get_index() != 0 => Ok(date),
+            _ => Err(ParseException::new(
+                format!("Unparseable date: \"{source}\""),
+                pos.get_error_index().unwrap_or(0) as i32,
+            )),
+        }
+    }
+
+    /// Parses a date at the position, advancing it past the date, or setting its error
+    /// index and returning `None` on failure. Fields missing from the pattern default to
+    /// 1970-01-01 00:00:00.000 in the format's time zone.
+    pub fn parse_from(&self, text: &str, pos: &mut ParsePosition) -> Option<Date> {
+        let old_start = pos.get_index();
+        let mut start = old_start;
+        if text.get(start..).is_none() {
+            pos.set_error_index(Some(start));
+            return None;
+        }
+        let mut builder = CalendarBuilder::default();
+        let mut ambiguous_year = false;
+
+        for (i, token) in self.compiled.iter().enumerate() {
+            match token {
+                Token::Literal(literal) => {
+                    for expected in literal.chars() {
+                        if !text[start..].starts_with(expected) {
+                            pos.set_index(old_start);
+                            pos.set_error_index(Some(start));
+                            return None;
+                        }
+                        start += expected.len_utf8();
+                    }
+                }
+                &Token::Field { index, count } => {
+                    // Fields without delimiters between them, like "yyyyMMdd", have to be
+                    // parsed by the number of pattern letters.
+                    let obey_count = matches!(
+                        self.compiled.get(i + 1),
+                        Some(&Token::Field { index, count }) if should_obey_count(index, count)
+                    );
+                    match self.sub_parse(
+                        text,
+                        start,
+                        index,
+                        count,
+                        obey_count,
+                        &mut ambiguous_year,
+                        pos,
+                        &mut builder,
+                    ) {
+                        Some(end) => start = end,
+                        None => {
+                            pos.set_index(old_start);
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        pos.set_index(start);
+        let mut date = self.establish(&builder);
+        // A two-digit year equal to that of the century start is in the century after it
+        // if the date would otherwise be before the start.
+        if ambiguous_year {
+            if let Some(time) = date {
+                if time < self.default_century_start {
+                    builder.add_year(100);
+                    date = self.establish(&builder);
+                }
+            }
+        }
+        match date {
+            Some(time) => Some(Date::with_time(time)),
+            None => {
+                pos.set_error_index(Some(start));
+                pos.set_index(old_start);
+                None
+            }
+        }
+    }
+
+    /// Parses one field at `start`, returning where it ends.
+    #[allow(clippy::too_many_arguments)]
+    fn sub_parse(
+        &self,
+        text: &str,
+        start: usize,
+        pattern_char_index: usize,
+        count: usize,
+        obey_count: bool,
+        ambiguous_year: &mut bool,
+        orig_pos: &mut ParsePosition,
+        builder: &mut CalendarBuilder,
+    ) -> Option<usize> {
+        let field = PATTERN_INDEX_TO_CALENDAR_FIELD[pattern_char_index];
+        let mut pos = ParsePosition::new(start);
+        // Skip spaces and tabs, failing at the end of the text.
+        loop {
+            match text[pos.get_index()..].chars().next() {
+                None => {
+                    orig_pos.set_error_index(Some(start));
+                    return None;
+                }
+                Some(c @ (' ' | '\t')) => pos.set_index(pos.get_index() + c.len_utf8()),
+                Some(_) => break,
+            }
+        }
+        let actual_start = pos.get_index();
+
+        let result = 'parsing: {
+            let mut value = 0;
+            // A few numeric fields need their value adjusted.
+            if matches!(
+                pattern_char_index,
+                PATTERN_HOUR_OF_DAY1 | PATTERN_HOUR1 | PATTERN_YEAR | PATTERN_WEEK_YEAR
+            ) || (matches!(pattern_char_index, PATTERN_MONTH | PATTERN_MONTH_STANDALONE)
+                && count <= 2)
+            {
+                match self.parse_number(text, start, count, obey_count, &mut pos) {
+                    Some(number) => value = number,
+                    None => break 'parsing None,
+                }
+            }
+
+            match pattern_char_index {
+                PATTERN_ERA => {
+                    let names = names_map(
+                        &self.data.eras,
+                        &[SHORT_FORMAT, SHORT_STANDALONE, LONG_FORMAT, LONG_STANDALONE],
+                    );
+                    break 'parsing match_string(text, start, field, &names, builder);
+                }
+                PATTERN_YEAR | PATTERN_WEEK_YEAR => {
+                    // Two digits for a pattern of one or two letters are a year within the
+                    // century from the two-digit year start. Other years are literal.
+                    let mut digits = text[actual_start..pos.get_index()].chars();
+                    if count <= 2
+                        && digits.clone().count() == 2
+                        && digits.all(|c| character::is_digit(c as i32))
+                    {
+                        let ambiguous_two_digit_year = self.default_century_start_year % 100;
+                        *ambiguous_year = value == ambiguous_two_digit_year;
+                        value += (self.default_century_start_year / 100) * 100
+                            + if value < ambiguous_two_digit_year {
+                                100
+                            } else {
+                                0
+                            };
+                    }
+                    builder.set(field, value);
+                    Some(pos.get_index())
+                }
+                PATTERN_MONTH | PATTERN_MONTH_STANDALONE => {
+                    if count <= 2 {
+                        builder.set(MONTH, value - 1);
+                        break 'parsing Some(pos.get_index());
+                    }
+                    let styles: &[usize] = if pattern_char_index == PATTERN_MONTH_STANDALONE
+                        || self.force_standalone_form
+                    {
+                        &[SHORT_STANDALONE, LONG_STANDALONE]
+                    } else {
+                        &[SHORT_FORMAT, LONG_FORMAT]
+                    };
+                    let names = names_map(&self.data.months, styles);
+                    match_string(text, start, field, &names, builder)
+                }
+                PATTERN_HOUR_OF_DAY1 => {
+                    if !self.lenient && !(1..=24).contains(&value) {
+                        break 'parsing None;
+                    }
+                    builder.set(HOUR_OF_DAY, if value == 24 { 0 } else { value });
+                    Some(pos.get_index())
+                }
+                PATTERN_DAY_OF_WEEK => {
+                    for style in [LONG_FORMAT, SHORT_FORMAT] {
+                        let names = names_map(&self.data.weekdays, &[style]);
+                        // Week days are numbered from Sunday as 1.
+                        let names: Vec<(&str, i32)> = names
+                            .into_iter()
+                            .map(|(name, value)| (name, value + 1))
+                            .collect();
+                        if let Some(end) = match_string(text, start, field, &names, builder) {
+                            return Some(end);
+                        }
+                    }
+                    None
+                }
+                PATTERN_AM_PM => {
+                    let names = names_map(
+                        &self.data.am_pm,
+                        &[SHORT_FORMAT, SHORT_STANDALONE, LONG_FORMAT, LONG_STANDALONE],
+                    );
+                    match_string(text, start, field, &names, builder)
+                }
+                PATTERN_HOUR1 => {
+                    if !self.lenient && !(1..=12).contains(&value) {
+                        break 'parsing None;
+                    }
+                    builder.set(HOUR, if value == 12 { 0 } else { value });
+                    Some(pos.get_index())
+                }
+                PATTERN_ZONE_NAME | PATTERN_ZONE_VALUE => {
+                    let index = pos.get_index();
+                    let sign = match text[index..].chars().next() {
+                        Some('+') => 1,
+                        Some('-') => -1,
+                        _ => 0,
+                    };
+                    if sign != 0 {
+                        // RFC 822, like -0800.
+                        match sub_parse_numeric_zone(text, index + 1, sign, 0, false, builder) {
+                            Ok(end) => return Some(end),
+                            Err(end) => pos.set_index(end),
+                        }
+                        break 'parsing None;
+                    }
+                    let rest = &text[start..];
+                    if rest.len() >= GMT.len()
+                        && rest.as_bytes()[..GMT.len()].eq_ignore_ascii_case(GMT.as_bytes())
+                    {
+                        // A custom zone, like GMT or GMT-08:00.
+                        let index = start + GMT.len();
+                        pos.set_index(index);
+                        let sign = match text[index..].chars().next() {
+                            Some('+') => 1,
+                            Some('-') => -1,
+                            _ => 0,
+                        };
+                        if sign == 0 {
+                            builder.set(ZONE_OFFSET, 0);
+                            builder.set(DST_OFFSET, 0);
+                            return Some(index);
+                        }
+                        match sub_parse_numeric_zone(text, index + 1, sign, 0, true, builder) {
+                            Ok(end) => return Some(end),
+                            Err(end) => pos.set_index(end),
+                        }
+                        break 'parsing None;
+                    }
+                    match self.sub_parse_zone_string(text, index, builder) {
+                        Some(end) => Some(end),
+                        None => {
+                            pos.set_index(index);
+                            None
+                        }
+                    }
+                }
+                PATTERN_ISO_ZONE => {
+                    let index = pos.get_index();
+                    let sign = match text[index..].chars().next() {
+                        Some('Z') => {
+                            builder.set(ZONE_OFFSET, 0);
+                            builder.set(DST_OFFSET, 0);
+                            return Some(index + 1);
+                        }
+                        Some('+') => 1,
+                        Some('-') => -1,
+                        Some(c) => {
+                            pos.set_index(index + c.len_utf8());
+                            break 'parsing None;
+                        }
+                        None => break 'parsing None,
+                    };
+                    match sub_parse_numeric_zone(text, index + 1, sign, count, count == 3, builder)
+                    {
+                        Ok(end) => Some(end),
+                        Err(end) => {
+                            pos.set_index(end);
+                            None
+                        }
+                    }
+                }
+                _ => {
+                    // The other fields are plain numbers.
+                    let value = self.parse_number(text, start, count, obey_count, &mut pos);
+                    value.map(|value| {
+                        builder.set(field, value);
+                        pos.get_index()
+                    })
+                }
+            }
+        };
+        if result.is_none() {
+            orig_pos.set_error_index(Some(pos.get_index()));
+        }
+        result
+    }
+
+    /// Parses an integer in the locale's digits at `pos`, only up to `count` characters
+    /// from `start` if `obey_count`.
+    fn parse_number(
+        &self,
+        text: &str,
+        start: usize,
+        count: usize,
+        obey_count: bool,
+        pos: &mut ParsePosition,
+    ) -> Option<i32> {
+        let text = if obey_count {
+            let end = text[start..]
+                .char_indices()
+                .nth(count)
+                .map_or(text.len(), |(i, _)| start + i);
+            if text[start..end].chars().count() < count {
+                return None;
+            }
+            &text[..end]
+        } else {
+            text
+        };
+        self.number_format
+            .parse_from(text, pos)
+            .map(|number| number.int_value())
+    }
+
+    /// Matches a time zone name at `start`: one of the format's zone, then the default
+    /// zone, then any zone.
+    fn sub_parse_zone_string(
+        &self,
+        text: &str,
+        start: usize,
+        builder: &mut CalendarBuilder,
+    ) -> Option<usize> {
+        let default_zone = TimeZone::get_default();
+        let ids = [self.zone.get_id(), default_zone.get_id()];
+        let mut matched = ids.iter().find_map(|&id| {
+            let names = locale_data::zone_names(id, &self.locale)?;
+            match_zone_string(text, start, &names).map(|index| (id.to_string(), names, index))
+        });
+        if matched.is_none() {
+            matched = locale_data::zone_ids(&self.locale).find_map(|(id, names)| {
+                match_zone_string(text, start, &names).map(|index| (id.to_string(), names, index))
+            });
+        }
+        let (id, names, index) = matched?;
+        let zone = TimeZone::get_time_zone(&id);
+        let daylight = index >= 2;
+        // A name shared by standard and daylight time leaves it to the zone which applies.
+        let same_name = !daylight && names[index].eq_ignore_ascii_case(names[index + 2]);
+        let dst_amount = if daylight { zone.get_dst_savings() } else { 0 };
+        builder.zone = Some(zone);
+        if !(same_name || (daylight && dst_amount == 0)) {
+            builder.set(DST_OFFSET, dst_amount);
+        }
+        Some(start + names[index].len())
+    }
+
+    /// Computes the time of the parsed fields, or `None` if a field is out of range and
+    /// the format is not lenient.
+    fn establish(&self, builder: &CalendarBuilder) -> Option<i64> {
+        let get = |field: usize, default: i32| builder.get(field).unwrap_or(default);
+        if !self.lenient && !builder.in_range() {
+            return None;
+        }
+
+        let mut year =
+            builder
+                .get(YEAR)
+                .or(builder.get(WEEK_YEAR))
+                .unwrap_or(if self.data.buddhist {
+                    1970 + BUDDHIST_YEAR_OFFSET
+                } else {
+                    1970
+                }) as i64;
+        if self.data.buddhist {
+            year -= BUDDHIST_YEAR_OFFSET as i64;
+        }
+        if get(ERA, 1) == 0 {
+            year = 1 - year;
+        }
+        let first_day_of_week = self.data.first_day_of_week;
+        let day_of_week = builder
+            .get(DAY_OF_WEEK)
+            .or(builder.get(ISO_DAY_OF_WEEK).map(|day| day % 7 + 1));
+        // The first day of a week counted from `first`, the start of a month or year.
+        let week_start = |first: i64, week: i32| {
+            let mut first_week = day_of_week_on_or_before(first + 6, first_day_of_week);
+            if first_week - first >= self.data.minimal_days_in_first_week as i64 {
+                first_week -= 7;
+            }
+            if let Some(day_of_week) = day_of_week.filter(|&day| day != first_day_of_week) {
+                first_week = day_of_week_on_or_before(first_week + 6, day_of_week);
+            }
+            first_week + 7 * (week as i64 - 1)
+        };
+
+        let fixed = match builder.select_date_fields() {
+            DateFields::Month(by) => {
+                let month = get(MONTH, 0) as i64;
+                let year = year + month.div_euclid(12);
+                let month = month.rem_euclid(12) as u32 + 1;
+                let month1 = fixed_from_date(year, month, 1);
+                match by {
+                    MonthDay::Day => month1 + get(DAY_OF_MONTH, 1) as i64 - 1,
+                    MonthDay::Week => week_start(month1, get(WEEK_OF_MONTH, 1)),
+                    MonthDay::DayOfWeek => {
+                        let day_of_week = day_of_week.unwrap_or(first_day_of_week);
+                        match get(DAY_OF_WEEK_IN_MONTH, 1) {
+                            week if week > 0 => {
+                                day_of_week_on_or_before(month1 + 7 * week as i64 - 1, day_of_week)
+                            }
+                            week => {
+                                let next_month1 = match month {
+                                    12 => fixed_from_date(year + 1, 1, 1),
+                                    _ => fixed_from_date(year, month + 1, 1),
+                                };
+                                let last = next_month1 - 1 + 7 * (week as i64 + 1);
+                                day_of_week_on_or_before(last, day_of_week)
+                            }
+                        }
+                    }
+                }
+            }
+            DateFields::DayOfYear => fixed_from_date(year, 1, 1) + get(DAY_OF_YEAR, 1) as i64 - 1,
+            DateFields::WeekOfYear => week_start(fixed_from_date(year, 1, 1), get(WEEK_OF_YEAR, 1)),
+        };
+
+        let hour = if builder.stamp(HOUR_OF_DAY) >= builder.stamp(HOUR).max(builder.stamp(AM_PM)) {
+            get(HOUR_OF_DAY, 0) as i64
+        } else {
+            get(HOUR, 0) as i64 + 12 * get(AM_PM, 0) as i64
+        };
+        let millis_of_day = ((hour * 60 + get(MINUTE, 0) as i64) * 60 + get(SECOND, 0) as i64)
+            * 1000
+            + get(MILLISECOND, 0) as i64;
+        let local = fixed * MILLIS_PER_DAY + millis_of_day;
+
+        let zone = builder.zone.as_ref().unwrap_or(&self.zone);
+        let offset = match (builder.get(ZONE_OFFSET), builder.get(DST_OFFSET)) {
+            (Some(zone_offset), dst_offset) => zone_offset + dst_offset.unwrap_or(0),
+            (None, Some(dst_offset)) => {
+                // The zone's standard offset at the time, with the parsed daylight saving.
+                let offset = zone.get_offset_by_wall(local);
+                let standard = if zone.in_daylight_time(local - offset as i64) {
+                    offset - zone.get_dst_savings()
+                } else {
+                    offset
+                };
+                standard + dst_offset
+            }
+            (None, None) => zone.get_offset_by_wall(local),
+        };
+        Some(local - offset as i64)
+    }
+}
+
+/// Like `DateFormat.equals`: the same pattern, names, week rules, number format, time zone
+/// and leniency.
+impl PartialEq for SimpleDateFormat {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+            && std::ptr::eq(self.data, other.data)
+            && self.number_format == other.number_format
+            && self.zone == other.zone
+            && self.lenient == other.lenient
+    }
+}
+
+fn check_style(style: i32, kind: &str) -> usize {
+    if !(SimpleDateFormat::FULL..=SimpleDateFormat::SHORT).contains(&style) {
+        IllegalArgumentException::new(format!("Illegal {kind} style {style}"), None).throw();
+    }
+    style as usize
+}
+
+/// Compiles a pattern into its tokens, and whether it is a lone month field, whose names
+/// are then the standalone ones.
+fn compile(pattern: &str) -> (Vec<Token>, bool) {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut field: Option<(usize, usize)> = None;
+    let mut in_quote = false;
+
+    fn end_field(tokens: &mut Vec<Token>, field: &mut Option<(usize, usize)>) {
+        if let Some((index, count)) = field.take() {
+            if index == PATTERN_ISO_ZONE && count >= 4 {
+                IllegalArgumentException::new(
+                    format!("invalid ISO 8601 format: length={count}"),
+                    None,
+                )
+                .throw();
+            }
+            tokens.push(Token::Field { index, count });
+        }
+    }
+
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            // Two quotes are a quote, in or out of quoted text.
+            if chars.next_if_eq(&'\'').is_some() {
+                end_field(&mut tokens, &mut field);
+                literal.push('\'');
+            } else {
+                in_quote = !in_quote;
+            }
+            continue;
+        }
+        if in_quote || !c.is_ascii_alphabetic() {
+            end_field(&mut tokens, &mut field);
+            literal.push(c);
+            continue;
+        }
+        let Some(index) = PATTERN_CHARS.find(c) else {
+            IllegalArgumentException::new(format!("Illegal pattern character '{c}'"), None).throw();
+        };
+        match &mut field {
+            Some((last, count)) if *last == index => *count += 1,
+            _ => {
+                end_field(&mut tokens, &mut field);
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                field = Some((index, 1));
+            }
+        }
+    }
+    if in_quote {
+        IllegalArgumentException::new("Unterminated quote".to_string(), None).throw();
+    }
+    end_field(&mut tokens, &mut field);
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    let fields: Vec<_> = tokens
+        .iter()
+        .filter_map(|token| match token {
+            Token::Field { index, .. } => Some(*index),
+            Token::Literal(_) => None,
+        })
+        .collect();
+    let force_standalone_form = fields == [PATTERN_MONTH];
+    (tokens, force_standalone_form)
+}
+
+fn should_obey_count(index: usize, count: usize) -> bool {
+    match index {
+        PATTERN_MONTH | PATTERN_MONTH_STANDALONE => count <= 2,
+        PATTERN_YEAR
+        | PATTERN_DAY_OF_MONTH
+        | PATTERN_HOUR_OF_DAY1
+        | PATTERN_HOUR_OF_DAY0
+        | PATTERN_MINUTE
+        | PATTERN_SECOND
+        | PATTERN_MILLISECOND
+        | PATTERN_DAY_OF_YEAR
+        | PATTERN_DAY_OF_WEEK_IN_MONTH
+        | PATTERN_WEEK_OF_YEAR
+        | PATTERN_WEEK_OF_MONTH
+        | PATTERN_HOUR1
+        | PATTERN_HOUR0
+        | PATTERN_WEEK_YEAR
+        | PATTERN_ISO_DAY_OF_WEEK => true,
+        _ => false,
+    }
+}
+
+/// The names of a field in the given styles, with their values.
+fn names_map<const N: usize>(
+    names: &[[Option<&'static str>; N]; 4],
+    styles: &[usize],
+) -> Vec<(&'static str, i32)> {
+    styles
+        .iter()
+        .flat_map(|&style| {
+            names[style].iter().enumerate().filter_map(|(value, name)| {
+                Some((name.filter(|name| !name.is_empty())?, value as i32))
+            })
+        })
+        .collect()
+}
+
+/// Sets the field to the value of the longest name at `start`, ignoring case.
+fn match_string(
+    text: &str,
+    start: usize,
+    field: usize,
+    names: &[(&str, i32)],
+    builder: &mut CalendarBuilder,
+) -> Option<usize> {
+    let mut best: Option<(&str, i32)> = None;
+    for &(name, value) in names {
+        if best.is_none_or(|(best, _)| name.len() > best.len())
+            && region_matches_ignore_case(text, start, name)
+        {
+            best = Some((name, value));
+        }
+    }
+    let (name, value) = best?;
+    builder.set(field, value);
+    Some(start + region_length(text, start, name))
+}
+
+/// The index among the zone's names, in the JDK's order of long standard, short standard,
+/// long daylight and short daylight, of the first one at `start`. Indices 2 and 3 are
+/// daylight names.
+fn match_zone_string(text: &str, start: usize, names: &locale_data::ZoneNames) -> Option<usize> {
+    let [short_std, long_std, short_dst, long_dst] = *names;
+    [long_std, short_std, long_dst, short_dst]
+        .iter()
+        .position(|name| region_matches_ignore_case(text, start, name))
+        .map(|position| [1, 0, 3, 2][position])
+}
+
+/// Whether the text at `start` matches `name`, ignoring case like `String.regionMatches`.
+fn region_matches_ignore_case(text: &str, start: usize, name: &str) -> bool {
+    let mut text = text[start..].chars();
+    name.chars().all(|expected| {
+        text.next().is_some_and(|actual| {
+            actual == expected
+                || actual.to_uppercase().eq(expected.to_uppercase())
+                || actual.to_lowercase().eq(expected.to_lowercase())
+        })
+    })
+}
+
+/// The length in the text of a name that matched at `start` ignoring case.
+fn region_length(text: &str, start: usize, name: &str) -> usize {
+    text[start..]
+        .chars()
+        .take(name.chars().count())
+        .map(char::len_utf8)
+        .sum()
+}
+
+/// Parses `hh[[:]mm]` at `start` into the zone offset, returning where it ends, or where it
+/// failed as the error.
+fn sub_parse_numeric_zone(
+    text: &str,
+    start: usize,
+    sign: i32,
+    count: usize,
+    colon: bool,
+    builder: &mut CalendarBuilder,
+) -> Result<usize, usize> {
+    let bytes = text.as_bytes();
+    let mut index = start;
+    let digit = |byte: Option<&u8>| {
+        byte.filter(|byte| byte.is_ascii_digit())
+            .map(|byte| (byte - b'0') as i32)
+    };
+
+    // Like the JDK, every character read advances the index, even past the end of the text.
+    let result = (|| {
+        index += 1;
+        let mut hours = digit(bytes.get(index - 1))?;
+        index += 1;
+        match digit(bytes.get(index - 1)) {
+            Some(second) => hours = hours * 10 + second,
+            // Without a colon, or in ISO 8601, hours need two digits.
+            None if count > 0 || !colon => return None,
+            None => index -= 1,
+        }
+        if hours > 23 {
+            return None;
+        }
+        let mut minutes = 0;
+        if count != 1 {
+            index += 1;
+            if colon {
+                if bytes.get(index - 1) != Some(&b':') {
+                    return None;
+                }
+                index += 1;
+            }
+            minutes = digit(bytes.get(index - 1))?;
+            index += 1;
+            minutes = minutes * 10 + digit(bytes.get(index - 1))?;
+            if minutes > 59 {
+                return None;
+            }
+        }
+        Some((hours * 60 + minutes) * 60_000 * sign)
+    })();
+    match result {
+        Some(offset) => {
+            builder.set(ZONE_OFFSET, offset);
+            builder.set(DST_OFFSET, 0);
+            Ok(index)
+        }
+        None => Err(index - 1),
+    }
+}
+
+/// `TimeZone.getDisplayName` in a locale, or the zone's offset as a custom ID like
+/// `GMT+05:30` if it has no names. Long names are always the English ones.
+pub(crate) fn zone_display_name(
+    zone: &TimeZone,
+    daylight: bool,
+    long: bool,
+    locale: &Locale,
+) -> String {
+    match locale_data::zone_names(zone.get_id(), locale) {
+        Some(names) => names[daylight as usize * 2 + long as usize].to_string(),
+        None => {
+            let offset = zone.get_raw_offset() + if daylight { zone.get_dst_savings() } else { 0 };
+            custom_id(offset / 1000)
+        }
+    }
+}
+
+/// The calendar fields of a time in a zone, in the calendar of a locale's data.
+fn fields(time: i64, zone: &TimeZone, data: &DateData) -> Fields {
+    let zone_offset = zone.get_offset(time);
+    let local = time + zone_offset as i64;
+    let fixed = local.div_euclid(MILLIS_PER_DAY);
+    let millis_of_day = local.rem_euclid(MILLIS_PER_DAY);
+    let (year, month, day_of_month) = date_from_fixed(fixed);
+    let jan1 = fixed_from_date(year, 1, 1);
+    let month1 = fixed - day_of_month as i64 + 1;
+
+    let mut week_of_year = week_number(data, jan1, fixed);
+    let mut week_year = year as i32;
+    if week_of_year == 0 {
+        // The last week of the previous year.
+        week_of_year = week_number(data, fixed_from_date(year - 1, 1, 1), jan1 - 1);
+        week_year -= 1;
+    } else if week_of_year >= 52 {
+        let next_jan1 = fixed_from_date(year + 1, 1, 1);
+        let next_jan1st = day_of_week_on_or_before(next_jan1 + 6, data.first_day_of_week);
+        if next_jan1st - next_jan1 >= data.minimal_days_in_first_week as i64
+            && fixed >= next_jan1st - 7
+        {
+            week_of_year = 1;
+            week_year += 1;
+        }
+    }
+
+    let (era, mut year_of_era) = if year > 0 {
+        (1, year as i32)
+    } else {
+        (0, (1 - year) as i32)
+    };
+    if data.buddhist {
+        year_of_era += BUDDHIST_YEAR_OFFSET;
+        week_year += BUDDHIST_YEAR_OFFSET;
+    }
+    let hour_of_day = (millis_of_day / 3_600_000) as i32;
+    Fields {
+        era,
+        year: year_of_era,
+        gregorian_year: year,
+        month: month as i32 - 1,
+        day_of_month: day_of_month as i32,
+        day_of_year: (fixed - jan1 + 1) as i32,
+        day_of_week: (fixed + 4).rem_euclid(7) as i32 + 1,
+        week_of_year,
+        week_of_month: week_number(data, month1, fixed),
+        week_year,
+        hour_of_day,
+        minute: (millis_of_day / 60_000 % 60) as i32,
+        second: (millis_of_day / 1000 % 60) as i32,
+        millisecond: (millis_of_day % 1000) as i32,
+        millis_of_day,
+        zone_offset,
+        daylight: zone.in_daylight_time(time),
+    }
+}
+
+/// The `java.util.Calendar` field values of a time in a zone, in the calendar of a locale,
+/// indexed by field number from `ERA` to `DST_OFFSET`. The daylight saving amount is the
+/// zone's current one, or an hour if the zone no longer observes it.
+pub(crate) fn calendar_fields(time: i64, zone: &TimeZone, locale: &Locale) -> [i32; 17] {
+    let fields = fields(time, zone, locale_data::date_data(locale));
+    let dst_offset = match (fields.daylight, zone.get_dst_savings()) {
+        (false, _) => 0,
+        (true, 0) => 3_600_000,
+        (true, savings) => savings,
+    };
+    let mut values = [0; 17];
+    values[ERA] = fields.era;
+    values[YEAR] = fields.year;
+    values[MONTH] = fields.month;
+    values[WEEK_OF_YEAR] = fields.week_of_year;
+    values[WEEK_OF_MONTH] = fields.week_of_month;
+    values[DAY_OF_MONTH] = fields.day_of_month;
+    values[DAY_OF_YEAR] = fields.day_of_year;
+    values[DAY_OF_WEEK] = fields.day_of_week;
+    values[DAY_OF_WEEK_IN_MONTH] = (fields.day_of_month - 1) / 7 + 1;
+    values[AM_PM] = (fields.hour_of_day >= 12) as i32;
+    values[HOUR] = fields.hour_of_day % 12;
+    values[HOUR_OF_DAY] = fields.hour_of_day;
+    values[MINUTE] = fields.minute;
+    values[SECOND] = fields.second;
+    values[MILLISECOND] = fields.millisecond;
+    values[ZONE_OFFSET] = fields.zone_offset - dst_offset;
+    values[DST_OFFSET] = dst_offset;
+    values
+}
+
+/// Whether a locale's calendar is Buddhist, with its first day of the week, from 1 for
+/// Sunday, and the minimal days in the first week of the year.
+pub(crate) fn calendar_rules(locale: &Locale) -> (bool, i32, i32) {
+    let data = locale_data::date_data(locale);
+    (
+        data.buddhist,
+        data.first_day_of_week,
+        data.minimal_days_in_first_week,
+    )
+}
+
+/// `GregorianCalendar.getWeekNumber`: the week of `fixed` in the period starting on
+/// `first`, by the locale's first day of week and minimal days in the first week.
+fn week_number(data: &DateData, first: i64, fixed: i64) -> i32 {
+    let mut first_week = day_of_week_on_or_before(first + 6, data.first_day_of_week);
+    if first_week - first >= data.minimal_days_in_first_week as i64 {
+        first_week -= 7;
+    }
+    (fixed - first_week).div_euclid(7) as i32 + 1
+}
+
+/// The fields set while parsing, each with the order it was set in.
+#[derive(Debug, Default)]
+struct CalendarBuilder {
+    fields: [Option<(i32, u32)>; FIELD_COUNT],
+    next_stamp: u32,
+    /// The zone of a parsed zone name.
+    zone: Option<TimeZone>,
+}
+
+impl CalendarBuilder {
+    fn set(&mut self, field: usize, value: i32) {
+        self.next_stamp += 1;
+        self.fields[field] = Some((value, self.next_stamp));
+    }
+
+    fn get(&self, field: usize) -> Option<i32> {
+        self.fields[field].map(|(value, _)| value)
+    }
+
+    fn stamp(&self, field: usize) -> u32 {
+        self.fields[field].map_or(0, |(_, stamp)| stamp)
+    }
+
+    /// Like `Calendar.selectFields`, which of the set fields determine the date: the most
+    /// recently set of the day of month, day of year, or a week with its day of week.
+    fn select_date_fields(&self) -> DateFields {
+        let aggregate = |a: u32, b: u32| if a == 0 || b == 0 { 0 } else { a.max(b) };
+        let day_of_week = self.stamp(DAY_OF_WEEK).max(self.stamp(ISO_DAY_OF_WEEK));
+        let mut day_of_month = self.stamp(DAY_OF_MONTH);
+        let mut week_of_month = aggregate(self.stamp(WEEK_OF_MONTH), day_of_week);
+        let mut day_of_week_in_month = aggregate(self.stamp(DAY_OF_WEEK_IN_MONTH), day_of_week);
+        let day_of_year = self.stamp(DAY_OF_YEAR);
+        let mut week_of_year = aggregate(self.stamp(WEEK_OF_YEAR), day_of_week);
+
+        let mut best = day_of_month
+            .max(week_of_month)
+            .max(day_of_week_in_month)
+            .max(day_of_year)
+            .max(week_of_year);
+        if best == 0 {
+            week_of_month = self.stamp(WEEK_OF_MONTH);
+            day_of_week_in_month = self.stamp(DAY_OF_WEEK_IN_MONTH).max(day_of_week);
+            week_of_year = self.stamp(WEEK_OF_YEAR);
+            best = week_of_month.max(day_of_week_in_month).max(week_of_year);
+            if best == 0 {
+                day_of_month = self.stamp(MONTH);
+                best = day_of_month;
+            }
+        }
+
+        if best == day_of_month {
+            DateFields::Month(MonthDay::Day)
+        } else if (best == week_of_month || best == day_of_week_in_month)
+            && self
+                .stamp(WEEK_OF_MONTH)
+                .max(self.stamp(DAY_OF_WEEK_IN_MONTH))
+                >= self.stamp(WEEK_OF_YEAR)
+        {
+            let by_week = if week_of_month == day_of_week_in_month {
+                self.stamp(WEEK_OF_MONTH) >= self.stamp(DAY_OF_WEEK_IN_MONTH)
+            } else {
+                best == week_of_month
+            };
+            DateFields::Month(if by_week {
+                MonthDay::Week
+            } else {
+                MonthDay::DayOfWeek
+            })
+        } else if best == day_of_year {
+            DateFields::DayOfYear
+        } else {
+            DateFields::WeekOfYear
+        }
+    }
+
+    fn add_year(&mut self, years: i32) {
+        for field in [YEAR, WEEK_YEAR] {
+            if let Some((value, _)) = &mut self.fields[field] {
+                *value += years;
+            }
+        }
+    }
+
+    /// Whether the set fields are within the ranges of a non-lenient calendar.
+    fn in_range(&self) -> bool {
+        let ranges: [(usize, i32, i32); 14] = [
+            (ERA, 0, 1),
+            (YEAR, 1, i32::MAX),
+            (MONTH, 0, 11),
+            (WEEK_OF_YEAR, 1, 53),
+            (WEEK_OF_MONTH, 0, 6),
+            (DAY_OF_MONTH, 1, 31),
+            (DAY_OF_YEAR, 1, 366),
+            (DAY_OF_WEEK, 1, 7),
+            (DAY_OF_WEEK_IN_MONTH, -6, 6),
+            (AM_PM, 0, 1),
+            (HOUR, 0, 11),
+            (HOUR_OF_DAY, 0, 23),
+            (MINUTE, 0, 59),
+            (SECOND, 0, 59),
+        ];
+        let in_ranges = ranges.iter().all(|&(field, min, max)| {
+            self.get(field)
+                .is_none_or(|value| (min..=max).contains(&value))
+        });
+        let in_month = match (self.get(YEAR), self.get(MONTH), self.get(DAY_OF_MONTH)) {
+            (year, month, Some(day)) => {
+                let year = year.unwrap_or(1970) as i64;
+                let month = month.unwrap_or(0) as u32 + 1;
+                let next = if month == 12 {
+                    fixed_from_date(year + 1, 1, 1)
+                } else {
+                    fixed_from_date(year, month + 1, 1)
+                };
+                day as i64 <= next - fixed_from_date(year, month, 1)
+            }
+            _ => true,
+        };
+        in_ranges
+            && in_month
+            && self
+                .get(MILLISECOND)
+                .is_none_or(|value| (0..=999).contains(&value))
+    }
+}
+
+/// The fields a parsed date is taken from.
+enum DateFields {
+    Month(MonthDay),
+    DayOfYear,
+    WeekOfYear,
+}
+
+/// The fields a parsed date is taken from within its month: the day of the month, the
+/// week of the month, or the day of the week in the month.
+enum MonthDay {
+    Day,
+    Week,
+    DayOfWeek,
+}
+
+/// The calendar fields of a time.
+#[derive(Debug)]
+struct Fields {
+    era: i32,
+    /// The year of the era, in the locale's calendar.
+    year: i32,
+    gregorian_year: i64,
+    /// From 0 for January.
+    month: i32,
+    day_of_month: i32,
+    day_of_year: i32,
+    /// From 1 for Sunday.
+    day_of_week: i32,
+    week_of_year: i32,
+    week_of_month: i32,
+    week_year: i32,
+    hour_of_day: i32,
+    minute: i32,
+    second: i32,
+    millisecond: i32,
+    millis_of_day: i64,
+    zone_offset: i32,
+    daylight: bool,
+}
+
+/// The date of a day since the epoch, Julian before the Gregorian cutover.
+fn date_from_fixed(fixed: i64) -> (i64, u32, u32) {
+    if fixed >= GREGORIAN_CUTOVER_DAY {
+        let days = fixed + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+        (year, month, day)
+    } else {
+        // Julian, by way of the Julian day number.
+        let c = fixed + 2_440_588 + 32_082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2).div_euclid(153);
+        let day = (e - (153 * m + 2).div_euclid(5) + 1) as u32;
+        let month = (m + 3 - 12 * (m / 10)) as u32;
+        (d - 4800 + m / 10, month, day)
+    }
+}
+
+/// Days since the epoch of a date, Julian if it falls before the Gregorian cutover.
+fn fixed_from_date(year: i64, month: u32, day: u32) -> i64 {
+    let gregorian = {
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = month as i64;
+        let day_of_year =
+            (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+        era * 146_097 + year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year
+            - 719_468
+    };
+    if gregorian >= GREGORIAN_CUTOVER_DAY {
+        return gregorian;
+    }
+    let a = (14 - month as i64) / 12;
+    let y = year + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    day as i64 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32_083 - 2_440_588
+}
+
+/// The day since the epoch on or before `fixed` that falls on a day of the week, from 1 for
+/// Sunday.
+fn day_of_week_on_or_before(fixed: i64, day_of_week: i32) -> i64 {
+    fixed - (fixed + 4 - (day_of_week as i64 - 1)).rem_euclid(7)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_york(pattern: &str, locale: &Locale) -> SimpleDateFormat {
+        let mut format = SimpleDateFormat::with_locale(pattern, locale);
+        format.set_time_zone(TimeZone::get_time_zone("America/New_York"));
+        format
+    }
+
+    #[test]
+    fn formats_like_jdk() {
+        let date = Date::with_time(1_700_000_000_000);
+        let cases = [
+            ("yyyy-MM-dd'T'HH:mm:ss.SSSZ", "2023-11-14T17:13:20.000-0500"),
+            (
+                "EEEE, MMMM d, yyyy h:mm a z",
+                "Tuesday, November 14, 2023 5:13 PM EST",
+            ),
+            (
+                "EEE MMM dd HH:mm:ss zzzz yyyy",
+                "Tue Nov 14 17:13:20 Eastern Standard Time 2023",
+            ),
+            ("G yy D w W F u k K XXX", "AD 23 318 46 3 2 2 17 5 -05:00"),
+            ("''hh 'o''clock' a", "'05 o'clock PM"),
+        ];
+        for (pattern, expected) in cases {
+            assert_eq!(
+                new_york(pattern, &Locale::US).format(&date),
+                expected,
+                "{pattern}"
+            );
+        }
+        assert_eq!(
+            new_york("d. MMMM yyyy HH:mm", &Locale::GERMANY).format(&date),
+            "14. November 2023 17:13"
+        );
+        let mut format = SimpleDateFormat::get_date_time_instance(
+            SimpleDateFormat::FULL,
+            SimpleDateFormat::SHORT,
+            &Locale::FRANCE,
+        );
+        format.set_time_zone(TimeZone::get_time_zone("America/New_York"));
+        assert_eq!(format.format(&date), "mardi 14 novembre 2023 17:13");
+    }
+
+    #[test]
+    fn parses_like_jdk() {
+        let mut format = new_york("yyyy-MM-dd HH:mm z", &Locale::US);
+        let time = |text| format.parse(text).map(|date| date.get_time());
+        assert_eq!(time("2023-07-04 12:30 PDT"), Ok(1_688_499_000_000));
+        assert_eq!(time("2023-07-04 12:30 EDT"), Ok(1_688_488_200_000));
+        let error = format.parse("2023-07-04 1x:30").unwrap_err();
+        assert_eq!(error.message(), "Unparseable date: \"2023-07-04 1x:30\"");
+        assert_eq!(error.get_error_offset(), 12);
+
+        format.set_lenient(false);
+        let error = format.parse("2023-02-30 10:00 EST").unwrap_err();
+        assert_eq!(error.get_error_offset(), 20);
+
+        let germany = new_york("yyyy-MM-dd HH:mm z", &Locale::GERMANY);
+        let date = germany.parse("2021-01-07 01:00 MEZ").unwrap();
+        assert_eq!(date.get_time(), 1_609_977_600_000);
+
+        let mut format = new_york("MM/dd/yy", &Locale::US);
+        format.set_2digit_year_start(&Date::with_time(0));
+        assert_eq!(
+            format.parse("01/02/03").unwrap().get_time(),
+            1_041_483_600_000
+        );
+        assert_eq!(
+            format.parse("01/02/69").unwrap().get_time(),
+            3_124_328_400_000
+        );
+
+        let mut format = SimpleDateFormat::with_locale("HH:mm", &Locale::US);
+        format.set_time_zone(TimeZone::get_time_zone("UTC"));
+        let mut pos = ParsePosition::new(3);
+        let date = format.parse_from("at 10:15 sharp", &mut pos);
+        assert_eq!(date.map(|date| date.get_time()), Some(36_900_000));
+        assert_eq!(pos.get_index(), 8);
+    }
+}
@@ -0,0 +1,15551 @@
+// Generated by tools/GenTextTables.java from JDK 17. Do not edit by hand.
+
+use super::break_iterator::Rules;
+
+pub(super) static CANONICAL_DECOMPOSITIONS: [(u32, u16, u8); 2061] = [
+    (0x00C0, 24, 2),
+    (0x00C1, 26, 2),
+    (0x00C2, 28, 2),
+    (0x00C3, 30, 2),
+    (0x00C4, 32, 2),
+    (0x00C5, 34, 2),
+    (0x00C7, 36, 2),
+    (0x00C8, 38, 2),
+    (0x00C9, 40, 2),
+    (0x00CA, 42, 2),
+    (0x00CB, 44, 2),
+    (0x00CC, 46, 2),
+    (0x00CD, 48, 2),
+    (0x00CE, 50, 2),
+    (0x00CF, 52, 2),
+    (0x00D1, 54, 2),
+    (0x00D2, 56, 2),
+    (0x00D3, 58, 2),
+    (0x00D4, 60, 2),
+    (0x00D5, 62, 2),
+    (0x00D6, 64, 2),
+    (0x00D9, 66, 2),
+    (0x00DA, 68, 2),
+    (0x00DB, 70, 2),
+    (0x00DC, 72, 2),
+    (0x00DD, 74, 2),
+    (0x00E0, 76, 2),
+    (0x00E1, 78, 2),
+    (0x00E2, 80, 2),
+    (0x00E3, 82, 2),
+    (0x00E4, 84, 2),
+    (0x00E5, 86, 2),
+    (0x00E7, 88, 2),
+    (0x00E8, 90, 2),
+    (0x00E9, 92, 2),
+    (0x00EA, 94, 2),
+    (0x00EB, 96, 2),
+    (0x00EC, 98, 2),
+    (0x00ED, 100, 2),
+    (0x00EE, 102, 2),
+    (0x00EF, 104, 2),
+    (0x00F1, 106, 2),
+    (0x00F2, 108, 2),
+    (0x00F3, 110, 2),
+    (0x00F4, 112, 2),
+    (0x00F5, 114, 2),
+    (0x00F6, 116, 2),
+    (0x00F9, 118, 2),
+    (0x00FA, 120, 2),
+    (0x00FB, 122, 2),
+    (0x00FC, 124, 2),
+    (0x00FD, 126, 2),
+    (0x00FF, 128, 2),
+    (0x0100, 130, 2),
+    (0x0101, 132, 2),
+    (0x0102, 134, 2),
+    (0x0103, 136, 2),
+    (0x0104, 138, 2),
+    (0x0105, 140, 2),
+    (0x0106, 142, 2),
+    (0x0107, 144, 2),
+    (0x0108, 146, 2),
+    (0x0109, 148, 2),
+    (0x010A, 150, 2),
+    (0x010B, 152, 2),
+    (0x010C, 154, 2),
+    (0x010D, 156, 2),
+    (0x010E, 158, 2),
+    (0x010F, 160, 2),
+    (0x0112, 162, 2),
+    (0x0113, 164, 2),
+    (0x0114, 166, 2),
+    (0x0115, 168, 2),
+    (0x0116, 170, 2),
+    (0x0117, 172, 2),
+    (0x0118, 174, 2),
+    (0x0119, 176, 2),
+    (0x011A, 178, 2),
+    (0x011B, 180, 2),
+    (0x011C, 182, 2),
+    (0x011D, 184, 2),
+    (0x011E, 186, 2),
+    (0x011F, 188, 2),
+    (0x0120, 190, 2),
+    (0x0121, 192, 2),
+    (0x0122, 194, 2),
+    (0x0123, 196, 2),
+    (0x0124, 198, 2),
+    (0x0125, 200, 2),
+    (0x0128, 202, 2),
+    (0x0129, 204, 2),
+    (0x012A, 206, 2),
+    (0x012B, 208, 2),
+    (0x012C, 210, 2),
+    (0x012D, 212, 2),
+    (0x012E, 214, 2),
+    (0x012F, 216, 2),
+    (0x0130, 218, 2),
+    (0x0134, 224, 2),
+    (0x0135, 226, 2),
+    (0x0136, 228, 2),
+    (0x0137, 230, 2),
+    (0x0139, 232, 2),
+    (0x013A, 234, 2),
+    (0x013B, 236, 2),
+    (0x013C, 238, 2),
+    (0x013D, 240, 2),
+    (0x013E, 242, 2),
+    (0x0143, 248, 2),
+    (0x0144, 250, 2),
+    (0x0145, 252, 2),
+    (0x0146, 254, 2),
+    (0x0147, 256, 2),
+    (0x0148, 258, 2),
+    (0x014C, 262, 2),
+    (0x014D, 264, 2),
+    (0x014E, 266, 2),
+    (0x014F, 268, 2),
+    (0x0150, 270, 2),
+    (0x0151, 272, 2),
+    (0x0154, 274, 2),
+    (0x0155, 276, 2),
+    (0x0156, 278, 2),
+    (0x0157, 280, 2),
+    (0x0158, 282, 2),
+    (0x0159, 284, 2),
+    (0x015A, 286, 2),
+    (0x015B, 288, 2),
+    (0x015C, 290, 2),
+    (0x015D, 292, 2),
+    (0x015E, 294, 2),
+    (0x015F, 296, 2),
+    (0x0160, 298, 2),
+    (0x0161, 300, 2),
+    (0x0162, 302, 2),
+    (0x0163, 304, 2),
+    (0x0164, 306, 2),
+    (0x0165, 308, 2),
+    (0x0168, 310, 2),
+    (0x0169, 312, 2),
+    (0x016A, 314, 2),
+    (0x016B, 316, 2),
+    (0x016C, 318, 2),
+    (0x016D, 320, 2),
+    (0x016E, 322, 2),
+    (0x016F, 324, 2),
+    (0x0170, 326, 2),
+    (0x0171, 328, 2),
+    (0x0172, 330, 2),
+    (0x0173, 332, 2),
+    (0x0174, 334, 2),
+    (0x0175, 336, 2),
+    (0x0176, 338, 2),
+    (0x0177, 340, 2),
+    (0x0178, 342, 2),
+    (0x0179, 344, 2),
+    (0x017A, 346, 2),
+    (0x017B, 348, 2),
+    (0x017C, 350, 2),
+    (0x017D, 352, 2),
+    (0x017E, 354, 2),
+    (0x01A0, 357, 2),
+    (0x01A1, 359, 2),
+    (0x01AF, 361, 2),
+    (0x01B0, 363, 2),
+    (0x01CD, 386, 2),
+    (0x01CE, 388, 2),
+    (0x01CF, 390, 2),
+    (0x01D0, 392, 2),
+    (0x01D1, 394, 2),
+    (0x01D2, 396, 2),
+    (0x01D3, 398, 2),
+    (0x01D4, 400, 2),
+    (0x01D5, 402, 3),
+    (0x01D6, 405, 3),
+    (0x01D7, 408, 3),
+    (0x01D8, 411, 3),
+    (0x01D9, 414, 3),
+    (0x01DA, 417, 3),
+    (0x01DB, 420, 3),
+    (0x01DC, 423, 3),
+    (0x01DE, 426, 3),
+    (0x01DF, 429, 3),
+    (0x01E0, 432, 3),
+    (0x01E1, 435, 3),
+    (0x01E2, 438, 2),
+    (0x01E3, 440, 2),
+    (0x01E6, 442, 2),
+    (0x01E7, 444, 2),
+    (0x01E8, 446, 2),
+    (0x01E9, 448, 2),
+    (0x01EA, 450, 2),
+    (0x01EB, 452, 2),
+    (0x01EC, 454, 3),
+    (0x01ED, 457, 3),
+    (0x01EE, 460, 2),
+    (0x01EF, 462, 2),
+    (0x01F0, 464, 2),
+    (0x01F4, 472, 2),
+    (0x01F5, 474, 2),
+    (0x01F8, 476, 2),
+    (0x01F9, 478, 2),
+    (0x01FA, 480, 3),
+    (0x01FB, 483, 3),
+    (0x01FC, 486, 2),
+    (0x01FD, 488, 2),
+    (0x01FE, 490, 2),
+    (0x01FF, 492, 2),
+    (0x0200, 494, 2),
+    (0x0201, 496, 2),
+    (0x0202, 498, 2),
+    (0x0203, 500, 2),
+    (0x0204, 502, 2),
+    (0x0205, 504, 2),
+    (0x0206, 506, 2),
+    (0x0207, 508, 2),
+    (0x0208, 510, 2),
+    (0x0209, 512, 2),
+    (0x020A, 514, 2),
+    (0x020B, 516, 2),
+    (0x020C, 518, 2),
+    (0x020D, 520, 2),
+    (0x020E, 522, 2),
+    (0x020F, 524, 2),
+    (0x0210, 526, 2),
+    (0x0211, 528, 2),
+    (0x0212, 530, 2),
+    (0x0213, 532, 2),
+    (0x0214, 534, 2),
+    (0x0215, 536, 2),
+    (0x0216, 538, 2),
+    (0x0217, 540, 2),
+    (0x0218, 542, 2),
+    (0x0219, 544, 2),
+    (0x021A, 546, 2),
+    (0x021B, 548, 2),
+    (0x021E, 550, 2),
+    (0x021F, 552, 2),
+    (0x0226, 554, 2),
+    (0x0227, 556, 2),
+    (0x0228, 558, 2),
+    (0x0229, 560, 2),
+    (0x022A, 562, 3),
+    (0x022B, 565, 3),
+    (0x022C, 568, 3),
+    (0x022D, 571, 3),
+    (0x022E, 574, 2),
+    (0x022F, 576, 2),
+    (0x0230, 578, 3),
+    (0x0231, 581, 3),
+    (0x0232, 584, 2),
+    (0x0233, 586, 2),
+    (0x0340, 614, 1),
+    (0x0341, 615, 1),
+    (0x0343, 616, 1),
+    (0x0344, 617, 2),
+    (0x0374, 619, 1),
+    (0x037E, 622, 1),
+    (0x0385, 625, 2),
+    (0x0386, 630, 2),
+    (0x0387, 632, 1),
+    (0x0388, 633, 2),
+    (0x0389, 635, 2),
+    (0x038A, 637, 2),
+    (0x038C, 639, 2),
+    (0x038E, 641, 2),
+    (0x038F, 643, 2),
+    (0x0390, 645, 3),
+    (0x03AA, 648, 2),
+    (0x03AB, 650, 2),
+    (0x03AC, 652, 2),
+    (0x03AD, 654, 2),
+    (0x03AE, 656, 2),
+    (0x03AF, 658, 2),
+    (0x03B0, 660, 3),
+    (0x03CA, 663, 2),
+    (0x03CB, 665, 2),
+    (0x03CC, 667, 2),
+    (0x03CD, 669, 2),
+    (0x03CE, 671, 2),
+    (0x03D3, 676, 2),
+    (0x03D4, 680, 2),
+    (0x0400, 692, 2),
+    (0x0401, 694, 2),
+    (0x0403, 696, 2),
+    (0x0407, 698, 2),
+    (0x040C, 700, 2),
+    (0x040D, 702, 2),
+    (0x040E, 704, 2),
+    (0x0419, 706, 2),
+    (0x0439, 708, 2),
+    (0x0450, 710, 2),
+    (0x0451, 712, 2),
+    (0x0453, 714, 2),
+    (0x0457, 716, 2),
+    (0x045C, 718, 2),
+    (0x045D, 720, 2),
+    (0x045E, 722, 2),
+    (0x0476, 724, 2),
+    (0x0477, 726, 2),
+    (0x04C1, 728, 2),
+    (0x04C2, 730, 2),
+    (0x04D0, 732, 2),
+    (0x04D1, 734, 2),
+    (0x04D2, 736, 2),
+    (0x04D3, 738, 2),
+    (0x04D6, 740, 2),
+    (0x04D7, 742, 2),
+    (0x04DA, 744, 2),
+    (0x04DB, 746, 2),
+    (0x04DC, 748, 2),
+    (0x04DD, 750, 2),
+    (0x04DE, 752, 2),
+    (0x04DF, 754, 2),
+    (0x04E2, 756, 2),
+    (0x04E3, 758, 2),
+    (0x04E4, 760, 2),
+    (0x04E5, 762, 2),
+    (0x04E6, 764, 2),
+    (0x04E7, 766, 2),
+    (0x04EA, 768, 2),
+    (0x04EB, 770, 2),
+    (0x04EC, 772, 2),
+    (0x04ED, 774, 2),
+    (0x04EE, 776, 2),
+    (0x04EF, 778, 2),
+    (0x04F0, 780, 2),
+    (0x04F1, 782, 2),
+    (0x04F2, 784, 2),
+    (0x04F3, 786, 2),
+    (0x04F4, 788, 2),
+    (0x04F5, 790, 2),
+    (0x04F8, 792, 2),
+    (0x04F9, 794, 2),
+    (0x0622, 798, 2),
+    (0x0623, 800, 2),
+    (0x0624, 802, 2),
+    (0x0625, 804, 2),
+    (0x0626, 806, 2),
+    (0x06C0, 816, 2),
+    (0x06C2, 818, 2),
+    (0x06D3, 820, 2),
+    (0x0929, 822, 2),
+    (0x0931, 824, 2),
+    (0x0934, 826, 2),
+    (0x0958, 828, 2),
+    (0x0959, 830, 2),
+    (0x095A, 832, 2),
+    (0x095B, 834, 2),
+    (0x095C, 836, 2),
+    (0x095D, 838, 2),
+    (0x095E, 840, 2),
+    (0x095F, 842, 2),
+    (0x09CB, 844, 2),
+    (0x09CC, 846, 2),
+    (0x09DC, 848, 2),
+    (0x09DD, 850, 2),
+    (0x09DF, 852, 2),
+    (0x0A33, 854, 2),
+    (0x0A36, 856, 2),
+    (0x0A59, 858, 2),
+    (0x0A5A, 860, 2),
+    (0x0A5B, 862, 2),
+    (0x0A5E, 864, 2),
+    (0x0B48, 866, 2),
+    (0x0B4B, 868, 2),
+    (0x0B4C, 870, 2),
+    (0x0B5C, 872, 2),
+    (0x0B5D, 874, 2),
+    (0x0B94, 876, 2),
+    (0x0BCA, 878, 2),
+    (0x0BCB, 880, 2),
+    (0x0BCC, 882, 2),
+    (0x0C48, 884, 2),
+    (0x0CC0, 886, 2),
+    (0x0CC7, 888, 2),
+    (0x0CC8, 890, 2),
+    (0x0CCA, 892, 2),
+    (0x0CCB, 894, 3),
+    (0x0D4A, 897, 2),
+    (0x0D4B, 899, 2),
+    (0x0D4C, 901, 2),
+    (0x0DDA, 903, 2),
+    (0x0DDC, 905, 2),
+    (0x0DDD, 907, 3),
+    (0x0DDE, 910, 2),
+    (0x0F43, 921, 2),
+    (0x0F4D, 923, 2),
+    (0x0F52, 925, 2),
+    (0x0F57, 927, 2),
+    (0x0F5C, 929, 2),
+    (0x0F69, 931, 2),
+    (0x0F73, 933, 2),
+    (0x0F75, 935, 2),
+    (0x0F76, 937, 2),
+    (0x0F78, 942, 2),
+    (0x0F81, 947, 2),
+    (0x0F93, 949, 2),
+    (0x0F9D, 951, 2),
+    (0x0FA2, 953, 2),
+    (0x0FA7, 955, 2),
+    (0x0FAC, 957, 2),
+    (0x0FB9, 959, 2),
+    (0x1026, 961, 2),
+    (0x1B06, 964, 2),
+    (0x1B08, 966, 2),
+    (0x1B0A, 968, 2),
+    (0x1B0C, 970, 2),
+    (0x1B0E, 972, 2),
+    (0x1B12, 974, 2),
+    (0x1B3B, 976, 2),
+    (0x1B3D, 978, 2),
+    (0x1B40, 980, 2),
+    (0x1B41, 982, 2),
+    (0x1B43, 984, 2),
+    (0x1E00, 1084, 2),
+    (0x1E01, 1086, 2),
+    (0x1E02, 1088, 2),
+    (0x1E03, 1090, 2),
+    (0x1E04, 1092, 2),
+    (0x1E05, 1094, 2),
+    (0x1E06, 1096, 2),
+    (0x1E07, 1098, 2),
+    (0x1E08, 1100, 3),
+    (0x1E09, 1103, 3),
+    (0x1E0A, 1106, 2),
+    (0x1E0B, 1108, 2),
+    (0x1E0C, 1110, 2),
+    (0x1E0D, 1112, 2),
+    (0x1E0E, 1114, 2),
+    (0x1E0F, 1116, 2),
+    (0x1E10, 1118, 2),
+    (0x1E11, 1120, 2),
+    (0x1E12, 1122, 2),
+    (0x1E13, 1124, 2),
+    (0x1E14, 1126, 3),
+    (0x1E15, 1129, 3),
+    (0x1E16, 1132, 3),
+    (0x1E17, 1135, 3),
+    (0x1E18, 1138, 2),
+    (0x1E19, 1140, 2),
+    (0x1E1A, 1142, 2),
+    (0x1E1B, 1144, 2),
+    (0x1E1C, 1146, 3),
+    (0x1E1D, 1149, 3),
+    (0x1E1E, 1152, 2),
+    (0x1E1F, 1154, 2),
+    (0x1E20, 1156, 2),
+    (0x1E21, 1158, 2),
+    (0x1E22, 1160, 2),
+    (0x1E23, 1162, 2),
+    (0x1E24, 1164, 2),
+    (0x1E25, 1166, 2),
+    (0x1E26, 1168, 2),
+    (0x1E27, 1170, 2),
+    (0x1E28, 1172, 2),
+    (0x1E29, 1174, 2),
+    (0x1E2A, 1176, 2),
+    (0x1E2B, 1178, 2),
+    (0x1E2C, 1180, 2),
+    (0x1E2D, 1182, 2),
+    (0x1E2E, 1184, 3),
+    (0x1E2F, 1187, 3),
+    (0x1E30, 1190, 2),
+    (0x1E31, 1192, 2),
+    (0x1E32, 1194, 2),
+    (0x1E33, 1196, 2),
+    (0x1E34, 1198, 2),
+    (0x1E35, 1200, 2),
+    (0x1E36, 1202, 2),
+    (0x1E37, 1204, 2),
+    (0x1E38, 1206, 3),
+    (0x1E39, 1209, 3),
+    (0x1E3A, 1212, 2),
+    (0x1E3B, 1214, 2),
+    (0x1E3C, 1216, 2),
+    (0x1E3D, 1218, 2),
+    (0x1E3E, 1220, 2),
+    (0x1E3F, 1222, 2),
+    (0x1E40, 1224, 2),
+    (0x1E41, 1226, 2),
+    (0x1E42, 1228, 2),
+    (0x1E43, 1230, 2),
+    (0x1E44, 1232, 2),
+    (0x1E45, 1234, 2),
+    (0x1E46, 1236, 2),
+    (0x1E47, 1238, 2),
+    (0x1E48, 1240, 2),
+    (0x1E49, 1242, 2),
+    (0x1E4A, 1244, 2),
+    (0x1E4B, 1246, 2),
+    (0x1E4C, 1248, 3),
+    (0x1E4D, 1251, 3),
+    (0x1E4E, 1254, 3),
+    (0x1E4F, 1257, 3),
+    (0x1E50, 1260, 3),
+    (0x1E51, 1263, 3),
+    (0x1E52, 1266, 3),
+    (0x1E53, 1269, 3),
+    (0x1E54, 1272, 2),
+    (0x1E55, 1274, 2),
+    (0x1E56, 1276, 2),
+    (0x1E57, 1278, 2),
+    (0x1E58, 1280, 2),
+    (0x1E59, 1282, 2),
+    (0x1E5A, 1284, 2),
+    (0x1E5B, 1286, 2),
+    (0x1E5C, 1288, 3),
+    (0x1E5D, 1291, 3),
+    (0x1E5E, 1294, 2),
+    (0x1E5F, 1296, 2),
+    (0x1E60, 1298, 2),
+    (0x1E61, 1300, 2),
+    (0x1E62, 1302, 2),
+    (0x1E63, 1304, 2),
+    (0x1E64, 1306, 3),
+    (0x1E65, 1309, 3),
+    (0x1E66, 1312, 3),
+    (0x1E67, 1315, 3),
+    (0x1E68, 1318, 3),
+    (0x1E69, 1321, 3),
+    (0x1E6A, 1324, 2),
+    (0x1E6B, 1326, 2),
+    (0x1E6C, 1328, 2),
+    (0x1E6D, 1330, 2),
+    (0x1E6E, 1332, 2),
+    (0x1E6F, 1334, 2),
+    (0x1E70, 1336, 2),
+    (0x1E71, 1338, 2),
+    (0x1E72, 1340, 2),
+    (0x1E73, 1342, 2),
+    (0x1E74, 1344, 2),
+    (0x1E75, 1346, 2),
+    (0x1E76, 1348, 2),
+    (0x1E77, 1350, 2),
+    (0x1E78, 1352, 3),
+    (0x1E79, 1355, 3),
+    (0x1E7A, 1358, 3),
+    (0x1E7B, 1361, 3),
+    (0x1E7C, 1364, 2),
+    (0x1E7D, 1366, 2),
+    (0x1E7E, 1368, 2),
+    (0x1E7F, 1370, 2),
+    (0x1E80, 1372, 2),
+    (0x1E81, 1374, 2),
+    (0x1E82, 1376, 2),
+    (0x1E83, 1378, 2),
+    (0x1E84, 1380, 2),
+    (0x1E85, 1382, 2),
+    (0x1E86, 1384, 2),
+    (0x1E87, 1386, 2),
+    (0x1E88, 1388, 2),
+    (0x1E89, 1390, 2),
+    (0x1E8A, 1392, 2),
+    (0x1E8B, 1394, 2),
+    (0x1E8C, 1396, 2),
+    (0x1E8D, 1398, 2),
+    (0x1E8E, 1400, 2),
+    (0x1E8F, 1402, 2),
+    (0x1E90, 1404, 2),
+    (0x1E91, 1406, 2),
+    (0x1E92, 1408, 2),
+    (0x1E93, 1410, 2),
+    (0x1E94, 1412, 2),
+    (0x1E95, 1414, 2),
+    (0x1E96, 1416, 2),
+    (0x1E97, 1418, 2),
+    (0x1E98, 1420, 2),
+    (0x1E99, 1422, 2),
+    (0x1E9B, 1426, 2),
+    (0x1EA0, 1430, 2),
+    (0x1EA1, 1432, 2),
+    (0x1EA2, 1434, 2),
+    (0x1EA3, 1436, 2),
+    (0x1EA4, 1438, 3),
+    (0x1EA5, 1441, 3),
+    (0x1EA6, 1444, 3),
+    (0x1EA7, 1447, 3),
+    (0x1EA8, 1450, 3),
+    (0x1EA9, 1453, 3),
+    (0x1EAA, 1456, 3),
+    (0x1EAB, 1459, 3),
+    (0x1EAC, 1462, 3),
+    (0x1EAD, 1465, 3),
+    (0x1EAE, 1468, 3),
+    (0x1EAF, 1471, 3),
+    (0x1EB0, 1474, 3),
+    (0x1EB1, 1477, 3),
+    (0x1EB2, 1480, 3),
+    (0x1EB3, 1483, 3),
+    (0x1EB4, 1486, 3),
+    (0x1EB5, 1489, 3),
+    (0x1EB6, 1492, 3),
+    (0x1EB7, 1495, 3),
+    (0x1EB8, 1498, 2),
+    (0x1EB9, 1500, 2),
+    (0x1EBA, 1502, 2),
+    (0x1EBB, 1504, 2),
+    (0x1EBC, 1506, 2),
+    (0x1EBD, 1508, 2),
+    (0x1EBE, 1510, 3),
+    (0x1EBF, 1513, 3),
+    (0x1EC0, 1516, 3),
+    (0x1EC1, 1519, 3),
+    (0x1EC2, 1522, 3),
+    (0x1EC3, 1525, 3),
+    (0x1EC4, 1528, 3),
+    (0x1EC5, 1531, 3),
+    (0x1EC6, 1534, 3),
+    (0x1EC7, 1537, 3),
+    (0x1EC8, 1540, 2),
+    (0x1EC9, 1542, 2),
+    (0x1ECA, 1544, 2),
+    (0x1ECB, 1546, 2),
+    (0x1ECC, 1548, 2),
+    (0x1ECD, 1550, 2),
+    (0x1ECE, 1552, 2),
+    (0x1ECF, 1554, 2),
+    (0x1ED0, 1556, 3),
+    (0x1ED1, 1559, 3),
+    (0x1ED2, 1562, 3),
+    (0x1ED3, 1565, 3),
+    (0x1ED4, 1568, 3),
+    (0x1ED5, 1571, 3),
+    (0x1ED6, 1574, 3),
+    (0x1ED7, 1577, 3),
+    (0x1ED8, 1580, 3),
+    (0x1ED9, 1583, 3),
+    (0x1EDA, 1586, 3),
+    (0x1EDB, 1589, 3),
+    (0x1EDC, 1592, 3),
+    (0x1EDD, 1595, 3),
+    (0x1EDE, 1598, 3),
+    (0x1EDF, 1601, 3),
+    (0x1EE0, 1604, 3),
+    (0x1EE1, 1607, 3),
+    (0x1EE2, 1610, 3),
+    (0x1EE3, 1613, 3),
+    (0x1EE4, 1616, 2),
+    (0x1EE5, 1618, 2),
+    (0x1EE6, 1620, 2),
+    (0x1EE7, 1622, 2),
+    (0x1EE8, 1624, 3),
+    (0x1EE9, 1627, 3),
+    (0x1EEA, 1630, 3),
+    (0x1EEB, 1633, 3),
+    (0x1EEC, 1636, 3),
+    (0x1EED, 1639, 3),
+    (0x1EEE, 1642, 3),
+    (0x1EEF, 1645, 3),
+    (0x1EF0, 1648, 3),
+    (0x1EF1, 1651, 3),
+    (0x1EF2, 1654, 2),
+    (0x1EF3, 1656, 2),
+    (0x1EF4, 1658, 2),
+    (0x1EF5, 1660, 2),
+    (0x1EF6, 1662, 2),
+    (0x1EF7, 1664, 2),
+    (0x1EF8, 1666, 2),
+    (0x1EF9, 1668, 2),
+    (0x1F00, 1670, 2),
+    (0x1F01, 1672, 2),
+    (0x1F02, 1674, 3),
+    (0x1F03, 1677, 3),
+    (0x1F04, 1680, 3),
+    (0x1F05, 1683, 3),
+    (0x1F06, 1686, 3),
+    (0x1F07, 1689, 3),
+    (0x1F08, 1692, 2),
+    (0x1F09, 1694, 2),
+    (0x1F0A, 1696, 3),
+    (0x1F0B, 1699, 3),
+    (0x1F0C, 1702, 3),
+    (0x1F0D, 1705, 3),
+    (0x1F0E, 1708, 3),
+    (0x1F0F, 1711, 3),
+    (0x1F10, 1714, 2),
+    (0x1F11, 1716, 2),
+    (0x1F12, 1718, 3),
+    (0x1F13, 1721, 3),
+    (0x1F14, 1724, 3),
+    (0x1F15, 1727, 3),
+    (0x1F18, 1730, 2),
+    (0x1F19, 1732, 2),
+    (0x1F1A, 1734, 3),
+    (0x1F1B, 1737, 3),
+    (0x1F1C, 1740, 3),
+    (0x1F1D, 1743, 3),
+    (0x1F20, 1746, 2),
+    (0x1F21, 1748, 2),
+    (0x1F22, 1750, 3),
+    (0x1F23, 1753, 3),
+    (0x1F24, 1756, 3),
+    (0x1F25, 1759, 3),
+    (0x1F26, 1762, 3),
+    (0x1F27, 1765, 3),
+    (0x1F28, 1768, 2),
+    (0x1F29, 1770, 2),
+    (0x1F2A, 1772, 3),
+    (0x1F2B, 1775, 3),
+    (0x1F2C, 1778, 3),
+    (0x1F2D, 1781, 3),
+    (0x1F2E, 1784, 3),
+    (0x1F2F, 1787, 3),
+    (0x1F30, 1790, 2),
+    (0x1F31, 1792, 2),
+    (0x1F32, 1794, 3),
+    (0x1F33, 1797, 3),
+    (0x1F34, 1800, 3),
+    (0x1F35, 1803, 3),
+    (0x1F36, 1806, 3),
+    (0x1F37, 1809, 3),
+    (0x1F38, 1812, 2),
+    (0x1F39, 1814, 2),
+    (0x1F3A, 1816, 3),
+    (0x1F3B, 1819, 3),
+    (0x1F3C, 1822, 3),
+    (0x1F3D, 1825, 3),
+    (0x1F3E, 1828, 3),
+    (0x1F3F, 1831, 3),
+    (0x1F40, 1834, 2),
+    (0x1F41, 1836, 2),
+    (0x1F42, 1838, 3),
+    (0x1F43, 1841, 3),
+    (0x1F44, 1844, 3),
+    (0x1F45, 1847, 3),
+    (0x1F48, 1850, 2),
+    (0x1F49, 1852, 2),
+    (0x1F4A, 1854, 3),
+    (0x1F4B, 1857, 3),
+    (0x1F4C, 1860, 3),
+    (0x1F4D, 1863, 3),
+    (0x1F50, 1866, 2),
+    (0x1F51, 1868, 2),
+    (0x1F52, 1870, 3),
+    (0x1F53, 1873, 3),
+    (0x1F54, 1876, 3),
+    (0x1F55, 1879, 3),
+    (0x1F56, 1882, 3),
+    (0x1F57, 1885, 3),
+    (0x1F59, 1888, 2),
+    (0x1F5B, 1890, 3),
+    (0x1F5D, 1893, 3),
+    (0x1F5F, 1896, 3),
+    (0x1F60, 1899, 2),
+    (0x1F61, 1901, 2),
+    (0x1F62, 1903, 3),
+    (0x1F63, 1906, 3),
+    (0x1F64, 1909, 3),
+    (0x1F65, 1912, 3),
+    (0x1F66, 1915, 3),
+    (0x1F67, 1918, 3),
+    (0x1F68, 1921, 2),
+    (0x1F69, 1923, 2),
+    (0x1F6A, 1925, 3),
+    (0x1F6B, 1928, 3),
+    (0x1F6C, 1931, 3),
+    (0x1F6D, 1934, 3),
+    (0x1F6E, 1937, 3),
+    (0x1F6F, 1940, 3),
+    (0x1F70, 1943, 2),
+    (0x1F71, 1945, 2),
+    (0x1F72, 1947, 2),
+    (0x1F73, 1949, 2),
+    (0x1F74, 1951, 2),
+    (0x1F75, 1953, 2),
+    (0x1F76, 1955, 2),
+    (0x1F77, 1957, 2),
+    (0x1F78, 1959, 2),
+    (0x1F79, 1961, 2),
+    (0x1F7A, 1963, 2),
+    (0x1F7B, 1965, 2),
+    (0x1F7C, 1967, 2),
+    (0x1F7D, 1969, 2),
+    (0x1F80, 1971, 3),
+    (0x1F81, 1974, 3),
+    (0x1F82, 1977, 4),
+    (0x1F83, 1981, 4),
+    (0x1F84, 1985, 4),
+    (0x1F85, 1989, 4),
+    (0x1F86, 1993, 4),
+    (0x1F87, 1997, 4),
+    (0x1F88, 2001, 3),
+    (0x1F89, 2004, 3),
+    (0x1F8A, 2007, 4),
+    (0x1F8B, 2011, 4),
+    (0x1F8C, 2015, 4),
+    (0x1F8D, 2019, 4),
+    (0x1F8E, 2023, 4),
+    (0x1F8F, 2027, 4),
+    (0x1F90, 2031, 3),
+    (0x1F91, 2034, 3),
+    (0x1F92, 2037, 4),
+    (0x1F93, 2041, 4),
+    (0x1F94, 2045, 4),
+    (0x1F95, 2049, 4),
+    (0x1F96, 2053, 4),
+    (0x1F97, 2057, 4),
+    (0x1F98, 2061, 3),
+    (0x1F99, 2064, 3),
+    (0x1F9A, 2067, 4),
+    (0x1F9B, 2071, 4),
+    (0x1F9C, 2075, 4),
+    (0x1F9D, 2079, 4),
+    (0x1F9E, 2083, 4),
+    (0x1F9F, 2087, 4),
+    (0x1FA0, 2091, 3),
+    (0x1FA1, 2094, 3),
+    (0x1FA2, 2097, 4),
+    (0x1FA3, 2101, 4),
+    (0x1FA4, 2105, 4),
+    (0x1FA5, 2109, 4),
+    (0x1FA6, 2113, 4),
+    (0x1FA7, 2117, 4),
+    (0x1FA8, 2121, 3),
+    (0x1FA9, 2124, 3),
+    (0x1FAA, 2127, 4),
+    (0x1FAB, 2131, 4),
+    (0x1FAC, 2135, 4),
+    (0x1FAD, 2139, 4),
+    (0x1FAE, 2143, 4),
+    (0x1FAF, 2147, 4),
+    (0x1FB0, 2151, 2),
+    (0x1FB1, 2153, 2),
+    (0x1FB2, 2155, 3),
+    (0x1FB3, 2158, 2),
+    (0x1FB4, 2160, 3),
+    (0x1FB6, 2163, 2),
+    (0x1FB7, 2165, 3),
+    (0x1FB8, 2168, 2),
+    (0x1FB9, 2170, 2),
+    (0x1FBA, 2172, 2),
+    (0x1FBB, 2174, 2),
+    (0x1FBC, 2176, 2),
+    (0x1FBE, 2180, 1),
+    (0x1FC1, 2185, 2),
+    (0x1FC2, 2190, 3),
+    (0x1FC3, 2193, 2),
+    (0x1FC4, 2195, 3),
+    (0x1FC6, 2198, 2),
+    (0x1FC7, 2200, 3),
+    (0x1FC8, 2203, 2),
+    (0x1FC9, 2205, 2),
+    (0x1FCA, 2207, 2),
+    (0x1FCB, 2209, 2),
+    (0x1FCC, 2211, 2),
+    (0x1FCD, 2213, 2),
+    (0x1FCE, 2218, 2),
+    (0x1FCF, 2223, 2),
+    (0x1FD0, 2228, 2),
+    (0x1FD1, 2230, 2),
+    (0x1FD2, 2232, 3),
+    (0x1FD3, 2235, 3),
+    (0x1FD6, 2238, 2),
+    (0x1FD7, 2240, 3),
+    (0x1FD8, 2243, 2),
+    (0x1FD9, 2245, 2),
+    (0x1FDA, 2247, 2),
+    (0x1FDB, 2249, 2),
+    (0x1FDD, 2251, 2),
+    (0x1FDE, 2256, 2),
+    (0x1FDF, 2261, 2),
+    (0x1FE0, 2266, 2),
+    (0x1FE1, 2268, 2),
+    (0x1FE2, 2270, 3),
+    (0x1FE3, 2273, 3),
+    (0x1FE4, 2276, 2),
+    (0x1FE5, 2278, 2),
+    (0x1FE6, 2280, 2),
+    (0x1FE7, 2282, 3),
+    (0x1FE8, 2285, 2),
+    (0x1FE9, 2287, 2),
+    (0x1FEA, 2289, 2),
+    (0x1FEB, 2291, 2),
+    (0x1FEC, 2293, 2),
+    (0x1FED, 2295, 2),
+    (0x1FEE, 2300, 2),
+    (0x1FEF, 2305, 1),
+    (0x1FF2, 2306, 3),
+    (0x1FF3, 2309, 2),
+    (0x1FF4, 2311, 3),
+    (0x1FF6, 2314, 2),
+    (0x1FF7, 2316, 3),
+    (0x1FF8, 2319, 2),
+    (0x1FF9, 2321, 2),
+    (0x1FFA, 2323, 2),
+    (0x1FFB, 2325, 2),
+    (0x1FFC, 2327, 2),
+    (0x1FFD, 2329, 1),
+    (0x2000, 2334, 1),
+    (0x2001, 2336, 1),
+    (0x2126, 2470, 1),
+    (0x212A, 2472, 1),
+    (0x212B, 2473, 2),
+    (0x219A, 2611, 2),
+    (0x219B, 2613, 2),
+    (0x21AE, 2615, 2),
+    (0x21CD, 2617, 2),
+    (0x21CE, 2619, 2),
+    (0x21CF, 2621, 2),
+    (0x2204, 2623, 2),
+    (0x2209, 2625, 2),
+    (0x220C, 2627, 2),
+    (0x2224, 2629, 2),
+    (0x2226, 2631, 2),
+    (0x2241, 2643, 2),
+    (0x2244, 2645, 2),
+    (0x2247, 2647, 2),
+    (0x2249, 2649, 2),
+    (0x2260, 2651, 2),
+    (0x2262, 2653, 2),
+    (0x226D, 2655, 2),
+    (0x226E, 2657, 2),
+    (0x226F, 2659, 2),
+    (0x2270, 2661, 2),
+    (0x2271, 2663, 2),
+    (0x2274, 2665, 2),
+    (0x2275, 2667, 2),
+    (0x2278, 2669, 2),
+    (0x2279, 2671, 2),
+    (0x2280, 2673, 2),
+    (0x2281, 2675, 2),
+    (0x2284, 2677, 2),
+    (0x2285, 2679, 2),
+    (0x2288, 2681, 2),
+    (0x2289, 2683, 2),
+    (0x22AC, 2685, 2),
+    (0x22AD, 2687, 2),
+    (0x22AE, 2689, 2),
+    (0x22AF, 2691, 2),
+    (0x22E0, 2693, 2),
+    (0x22E1, 2695, 2),
+    (0x22E2, 2697, 2),
+    (0x22E3, 2699, 2),
+    (0x22EA, 2701, 2),
+    (0x22EB, 2703, 2),
+    (0x22EC, 2705, 2),
+    (0x22ED, 2707, 2),
+    (0x2329, 2709, 1),
+    (0x232A, 2710, 1),
+    (0x2ADC, 3007, 2),
+    (0x304C, 3233, 2),
+    (0x304E, 3235, 2),
+    (0x3050, 3237, 2),
+    (0x3052, 3239, 2),
+    (0x3054, 3241, 2),
+    (0x3056, 3243, 2),
+    (0x3058, 3245, 2),
+    (0x305A, 3247, 2),
+    (0x305C, 3249, 2),
+    (0x305E, 3251, 2),
+    (0x3060, 3253, 2),
+    (0x3062, 3255, 2),
+    (0x3065, 3257, 2),
+    (0x3067, 3259, 2),
+    (0x3069, 3261, 2),
+    (0x3070, 3263, 2),
+    (0x3071, 3265, 2),
+    (0x3073, 3267, 2),
+    (0x3074, 3269, 2),
+    (0x3076, 3271, 2),
+    (0x3077, 3273, 2),
+    (0x3079, 3275, 2),
+    (0x307A, 3277, 2),
+    (0x307C, 3279, 2),
+    (0x307D, 3281, 2),
+    (0x3094, 3283, 2),
+    (0x309E, 3289, 2),
+    (0x30AC, 3293, 2),
+    (0x30AE, 3295, 2),
+    (0x30B0, 3297, 2),
+    (0x30B2, 3299, 2),
+    (0x30B4, 3301, 2),
+    (0x30B6, 3303, 2),
+    (0x30B8, 3305, 2),
+    (0x30BA, 3307, 2),
+    (0x30BC, 3309, 2),
+    (0x30BE, 3311, 2),
+    (0x30C0, 3313, 2),
+    (0x30C2, 3315, 2),
+    (0x30C5, 3317, 2),
+    (0x30C7, 3319, 2),
+    (0x30C9, 3321, 2),
+    (0x30D0, 3323, 2),
+    (0x30D1, 3325, 2),
+    (0x30D3, 3327, 2),
+    (0x30D4, 3329, 2),
+    (0x30D6, 3331, 2),
+    (0x30D7, 3333, 2),
+    (0x30D9, 3335, 2),
+    (0x30DA, 3337, 2),
+    (0x30DC, 3339, 2),
+    (0x30DD, 3341, 2),
+    (0x30F4, 3343, 2),
+    (0x30F7, 3345, 2),
+    (0x30F8, 3347, 2),
+    (0x30F9, 3349, 2),
+    (0x30FA, 3351, 2),
+    (0x30FE, 3353, 2),
+    (0xF900, 4721, 1),
+    (0xF901, 4722, 1),
+    (0xF902, 4723, 1),
+    (0xF903, 4724, 1),
+    (0xF904, 4725, 1),
+    (0xF905, 4726, 1),
+    (0xF906, 4727, 1),
+    (0xF907, 4728, 1),
+    (0xF908, 4729, 1),
+    (0xF909, 4730, 1),
+    (0xF90A, 4731, 1),
+    (0xF90B, 4732, 1),
+    (0xF90C, 4733, 1),
+    (0xF90D, 4734, 1),
+    (0xF90E, 4735, 1),
+    (0xF90F, 4736, 1),
+    (0xF910, 4737, 1),
+    (0xF911, 4738, 1),
+    (0xF912, 4739, 1),
+    (0xF913, 4740, 1),
+    (0xF914, 4741, 1),
+    (0xF915, 4742, 1),
+    (0xF916, 4743, 1),
+    (0xF917, 4744, 1),
+    (0xF918, 4745, 1),
+    (0xF919, 4746, 1),
+    (0xF91A, 4747, 1),
+    (0xF91B, 4748, 1),
+    (0xF91C, 4749, 1),
+    (0xF91D, 4750, 1),
+    (0xF91E, 4751, 1),
+    (0xF91F, 4752, 1),
+    (0xF920, 4753, 1),
+    (0xF921, 4754, 1),
+    (0xF922, 4755, 1),
+    (0xF923, 4756, 1),
+    (0xF924, 4757, 1),
+    (0xF925, 4758, 1),
+    (0xF926, 4759, 1),
+    (0xF927, 4760, 1),
+    (0xF928, 4761, 1),
+    (0xF929, 4762, 1),
+    (0xF92A, 4763, 1),
+    (0xF92B, 4764, 1),
+    (0xF92C, 4765, 1),
+    (0xF92D, 4766, 1),
+    (0xF92E, 4767, 1),
+    (0xF92F, 4768, 1),
+    (0xF930, 4769, 1),
+    (0xF931, 4770, 1),
+    (0xF932, 4771, 1),
+    (0xF933, 4772, 1),
+    (0xF934, 4773, 1),
+    (0xF935, 4774, 1),
+    (0xF936, 4775, 1),
+    (0xF937, 4776, 1),
+    (0xF938, 4777, 1),
+    (0xF939, 4778, 1),
+    (0xF93A, 4779, 1),
+    (0xF93B, 4780, 1),
+    (0xF93C, 4781, 1),
+    (0xF93D, 4782, 1),
+    (0xF93E, 4783, 1),
+    (0xF93F, 4784, 1),
+    (0xF940, 4785, 1),
+    (0xF941, 4786, 1),
+    (0xF942, 4787, 1),
+    (0xF943, 4788, 1),
+    (0xF944, 4789, 1),
+    (0xF945, 4790, 1),
+    (0xF946, 4791, 1),
+    (0xF947, 4792, 1),
+    (0xF948, 4793, 1),
+    (0xF949, 4794, 1),
+    (0xF94A, 4795, 1),
+    (0xF94B, 4796, 1),
+    (0xF94C, 4797, 1),
+    (0xF94D, 4798, 1),
+    (0xF94E, 4799, 1),
+    (0xF94F, 4800, 1),
+    (0xF950, 4801, 1),
+    (0xF951, 4802, 1),
+    (0xF952, 4803, 1),
+    (0xF953, 4804, 1),
+    (0xF954, 4805, 1),
+    (0xF955, 4806, 1),
+    (0xF956, 4807, 1),
+    (0xF957, 4808, 1),
+    (0xF958, 4809, 1),
+    (0xF959, 4810, 1),
+    (0xF95A, 4811, 1),
+    (0xF95B, 4812, 1),
+    (0xF95C, 4813, 1),
+    (0xF95D, 4814, 1),
+    (0xF95E, 4815, 1),
+    (0xF95F, 4816, 1),
+    (0xF960, 4817, 1),
+    (0xF961, 4818, 1),
+    (0xF962, 4819, 1),
+    (0xF963, 4820, 1),
+    (0xF964, 4821, 1),
+    (0xF965, 4822, 1),
+    (0xF966, 4823, 1),
+    (0xF967, 4824, 1),
+    (0xF968, 4825, 1),
+    (0xF969, 4826, 1),
+    (0xF96A, 4827, 1),
+    (0xF96B, 4828, 1),
+    (0xF96C, 4829, 1),
+    (0xF96D, 4830, 1),
+    (0xF96E, 4831, 1),
+    (0xF96F, 4832, 1),
+    (0xF970, 4833, 1),
+    (0xF971, 4834, 1),
+    (0xF972, 4835, 1),
+    (0xF973, 4836, 1),
+    (0xF974, 4837, 1),
+    (0xF975, 4838, 1),
+    (0xF976, 4839, 1),
+    (0xF977, 4840, 1),
+    (0xF978, 4841, 1),
+    (0xF979, 4842, 1),
+    (0xF97A, 4843, 1),
+    (0xF97B, 4844, 1),
+    (0xF97C, 4845, 1),
+    (0xF97D, 4846, 1),
+    (0xF97E, 4847, 1),
+    (0xF97F, 4848, 1),
+    (0xF980, 4849, 1),
+    (0xF981, 4850, 1),
+    (0xF982, 4851, 1),
+    (0xF983, 4852, 1),
+    (0xF984, 4853, 1),
+    (0xF985, 4854, 1),
+    (0xF986, 4855, 1),
+    (0xF987, 4856, 1),
+    (0xF988, 4857, 1),
+    (0xF989, 4858, 1),
+    (0xF98A, 4859, 1),
+    (0xF98B, 4860, 1),
+    (0xF98C, 4861, 1),
+    (0xF98D, 4862, 1),
+    (0xF98E, 4863, 1),
+    (0xF98F, 4864, 1),
+    (0xF990, 4865, 1),
+    (0xF991, 4866, 1),
+    (0xF992, 4867, 1),
+    (0xF993, 4868, 1),
+    (0xF994, 4869, 1),
+    (0xF995, 4870, 1),
+    (0xF996, 4871, 1),
+    (0xF997, 4872, 1),
+    (0xF998, 4873, 1),
+    (0xF999, 4874, 1),
+    (0xF99A, 4875, 1),
+    (0xF99B, 4876, 1),
+    (0xF99C, 4877, 1),
+    (0xF99D, 4878, 1),
+    (0xF99E, 4879, 1),
+    (0xF99F, 4880, 1),
+    (0xF9A0, 4881, 1),
+    (0xF9A1, 4882, 1),
+    (0xF9A2, 4883, 1),
+    (0xF9A3, 4884, 1),
+    (0xF9A4, 4885, 1),
+    (0xF9A5, 4886, 1),
+    (0xF9A6, 4887, 1),
+    (0xF9A7, 4888, 1),
+    (0xF9A8, 4889, 1),
+    (0xF9A9, 4890, 1),
+    (0xF9AA, 4891, 1),
+    (0xF9AB, 4892, 1),
+    (0xF9AC, 4893, 1),
+    (0xF9AD, 4894, 1),
+    (0xF9AE, 4895, 1),
+    (0xF9AF, 4896, 1),
+    (0xF9B0, 4897, 1),
+    (0xF9B1, 4898, 1),
+    (0xF9B2, 4899, 1),
+    (0xF9B3, 4900, 1),
+    (0xF9B4, 4901, 1),
+    (0xF9B5, 4902, 1),
+    (0xF9B6, 4903, 1),
+    (0xF9B7, 4904, 1),
+    (0xF9B8, 4905, 1),
+    (0xF9B9, 4906, 1),
+    (0xF9BA, 4907, 1),
+    (0xF9BB, 4908, 1),
+    (0xF9BC, 4909, 1),
+    (0xF9BD, 4910, 1),
+    (0xF9BE, 4911, 1),
+    (0xF9BF, 4912, 1),
+    (0xF9C0, 4913, 1),
+    (0xF9C1, 4914, 1),
+    (0xF9C2, 4915, 1),
+    (0xF9C3, 4916, 1),
+    (0xF9C4, 4917, 1),
+    (0xF9C5, 4918, 1),
+    (0xF9C6, 4919, 1),
+    (0xF9C7, 4920, 1),
+    (0xF9C8, 4921, 1),
+    (0xF9C9, 4922, 1),
+    (0xF9CA, 4923, 1),
+    (0xF9CB, 4924, 1),
+    (0xF9CC, 4925, 1),
+    (0xF9CD, 4926, 1),
+    (0xF9CE, 4927, 1),
+    (0xF9CF, 4928, 1),
+    (0xF9D0, 4929, 1),
+    (0xF9D1, 4930, 1),
+    (0xF9D2, 4931, 1),
+    (0xF9D3, 4932, 1),
+    (0xF9D4, 4933, 1),
+    (0xF9D5, 4934, 1),
+    (0xF9D6, 4935, 1),
+    (0xF9D7, 4936, 1),
+    (0xF9D8, 4937, 1),
+    (0xF9D9, 4938, 1),
+    (0xF9DA, 4939, 1),
+    (0xF9DB, 4940, 1),
+    (0xF9DC, 4941, 1),
+    (0xF9DD, 4942, 1),
+    (0xF9DE, 4943, 1),
+    (0xF9DF, 4944, 1),
+    (0xF9E0, 4945, 1),
+    (0xF9E1, 4946, 1),
+    (0xF9E2, 4947, 1),
+    (0xF9E3, 4948, 1),
+    (0xF9E4, 4949, 1),
+    (0xF9E5, 4950, 1),
+    (0xF9E6, 4951, 1),
+    (0xF9E7, 4952, 1),
+    (0xF9E8, 4953, 1),
+    (0xF9E9, 4954, 1),
+    (0xF9EA, 4955, 1),
+    (0xF9EB, 4956, 1),
+    (0xF9EC, 4957, 1),
+    (0xF9ED, 4958, 1),
+    (0xF9EE, 4959, 1),
+    (0xF9EF, 4960, 1),
+    (0xF9F0, 4961, 1),
+    (0xF9F1, 4962, 1),
+    (0xF9F2, 4963, 1),
+    (0xF9F3, 4964, 1),
+    (0xF9F4, 4965, 1),
+    (0xF9F5, 4966, 1),
+    (0xF9F6, 4967, 1),
+    (0xF9F7, 4968, 1),
+    (0xF9F8, 4969, 1),
+    (0xF9F9, 4970, 1),
+    (0xF9FA, 4971, 1),
+    (0xF9FB, 4972, 1),
+    (0xF9FC, 4973, 1),
+    (0xF9FD, 4974, 1),
+    (0xF9FE, 4975, 1),
+    (0xF9FF, 4976, 1),
+    (0xFA00, 4977, 1),
+    (0xFA01, 4978, 1),
+    (0xFA02, 4979, 1),
+    (0xFA03, 4980, 1),
+    (0xFA04, 4981, 1),
+    (0xFA05, 4982, 1),
+    (0xFA06, 4983, 1),
+    (0xFA07, 4984, 1),
+    (0xFA08, 4985, 1),
+    (0xFA09, 4986, 1),
+    (0xFA0A, 4987, 1),
+    (0xFA0B, 4988, 1),
+    (0xFA0C, 4989, 1),
+    (0xFA0D, 4990, 1),
+    (0xFA10, 4991, 1),
+    (0xFA12, 4992, 1),
+    (0xFA15, 4993, 1),
+    (0xFA16, 4994, 1),
+    (0xFA17, 4995, 1),
+    (0xFA18, 4996, 1),
+    (0xFA19, 4997, 1),
+    (0xFA1A, 4998, 1),
+    (0xFA1B, 4999, 1),
+    (0xFA1C, 5000, 1),
+    (0xFA1D, 5001, 1),
+    (0xFA1E, 5002, 1),
+    (0xFA20, 5003, 1),
+    (0xFA22, 5004, 1),
+    (0xFA25, 5005, 1),
+    (0xFA26, 5006, 1),
+    (0xFA2A, 5007, 1),
+    (0xFA2B, 5008, 1),
+    (0xFA2C, 5009, 1),
+    (0xFA2D, 5010, 1),
+    (0xFA2E, 5011, 1),
+    (0xFA2F, 5012, 1),
+    (0xFA30, 5013, 1),
+    (0xFA31, 5014, 1),
+    (0xFA32, 5015, 1),
+    (0xFA33, 5016, 1),
+    (0xFA34, 5017, 1),
+    (0xFA35, 5018, 1),
+    (0xFA36, 5019, 1),
+    (0xFA37, 5020, 1),
+    (0xFA38, 5021, 1),
+    (0xFA39, 5022, 1),
+    (0xFA3A, 5023, 1),
+    (0xFA3B, 5024, 1),
+    (0xFA3C, 5025, 1),
+    (0xFA3D, 5026, 1),
+    (0xFA3E, 5027, 1),
+    (0xFA3F, 5028, 1),
+    (0xFA40, 5029, 1),
+    (0xFA41, 5030, 1),
+    (0xFA42, 5031, 1),
+    (0xFA43, 5032, 1),
+    (0xFA44, 5033, 1),
+    (0xFA45, 5034, 1),
+    (0xFA46, 5035, 1),
+    (0xFA47, 5036, 1),
+    (0xFA48, 5037, 1),
+    (0xFA49, 5038, 1),
+    (0xFA4A, 5039, 1),
+    (0xFA4B, 5040, 1),
+    (0xFA4C, 5041, 1),
+    (0xFA4D, 5042, 1),
+    (0xFA4E, 5043, 1),
+    (0xFA4F, 5044, 1),
+    (0xFA50, 5045, 1),
+    (0xFA51, 5046, 1),
+    (0xFA52, 5047, 1),
+    (0xFA53, 5048, 1),
+    (0xFA54, 5049, 1),
+    (0xFA55, 5050, 1),
+    (0xFA56, 5051, 1),
+    (0xFA57, 5052, 1),
+    (0xFA58, 5053, 1),
+    (0xFA59, 5054, 1),
+    (0xFA5A, 5055, 1),
+    (0xFA5B, 5056, 1),
+    (0xFA5C, 5057, 1),
+    (0xFA5D, 5058, 1),
+    (0xFA5E, 5059, 1),
+    (0xFA5F, 5060, 1),
+    (0xFA60, 5061, 1),
+    (0xFA61, 5062, 1),
+    (0xFA62, 5063, 1),
+    (0xFA63, 5064, 1),
+    (0xFA64, 5065, 1),
+    (0xFA65, 5066, 1),
+    (0xFA66, 5067, 1),
+    (0xFA67, 5068, 1),
+    (0xFA68, 5069, 1),
+    (0xFA69, 5070, 1),
+    (0xFA6A, 5071, 1),
+    (0xFA6B, 5072, 1),
+    (0xFA6C, 5073, 1),
+    (0xFA6D, 5074, 1),
+    (0xFA70, 5075, 1),
+    (0xFA71, 5076, 1),
+    (0xFA72, 5077, 1),
+    (0xFA73, 5078, 1),
+    (0xFA74, 5079, 1),
+    (0xFA75, 5080, 1),
+    (0xFA76, 5081, 1),
+    (0xFA77, 5082, 1),
+    (0xFA78, 5083, 1),
+    (0xFA79, 5084, 1),
+    (0xFA7A, 5085, 1),
+    (0xFA7B, 5086, 1),
+    (0xFA7C, 5087, 1),
+    (0xFA7D, 5088, 1),
+    (0xFA7E, 5089, 1),
+    (0xFA7F, 5090, 1),
+    (0xFA80, 5091, 1),
+    (0xFA81, 5092, 1),
+    (0xFA82, 5093, 1),
+    (0xFA83, 5094, 1),
+    (0xFA84, 5095, 1),
+    (0xFA85, 5096, 1),
+    (0xFA86, 5097, 1),
+    (0xFA87, 5098, 1),
+    (0xFA88, 5099, 1),
+    (0xFA89, 5100, 1),
+    (0xFA8A, 5101, 1),
+    (0xFA8B, 5102, 1),
+    (0xFA8C, 5103, 1),
+    (0xFA8D, 5104, 1),
+    (0xFA8E, 5105, 1),
+    (0xFA8F, 5106, 1),
+    (0xFA90, 5107, 1),
+    (0xFA91, 5108, 1),
+    (0xFA92, 5109, 1),
+    (0xFA93, 5110, 1),
+    (0xFA94, 5111, 1),
+    (0xFA95, 5112, 1),
+    (0xFA96, 5113, 1),
+    (0xFA97, 5114, 1),
+    (0xFA98, 5115, 1),
+    (0xFA99, 5116, 1),
+    (0xFA9A, 5117, 1),
+    (0xFA9B, 5118, 1),
+    (0xFA9C, 5119, 1),
+    (0xFA9D, 5120, 1),
+    (0xFA9E, 5121, 1),
+    (0xFA9F, 5122, 1),
+    (0xFAA0, 5123, 1),
+    (0xFAA1, 5124, 1),
+    (0xFAA2, 5125, 1),
+    (0xFAA3, 5126, 1),
+    (0xFAA4, 5127, 1),
+    (0xFAA5, 5128, 1),
+    (0xFAA6, 5129, 1),
+    (0xFAA7, 5130, 1),
+    (0xFAA8, 5131, 1),
+    (0xFAA9, 5132, 1),
+    (0xFAAA, 5133, 1),
+    (0xFAAB, 5134, 1),
+    (0xFAAC, 5135, 1),
+    (0xFAAD, 5136, 1),
+    (0xFAAE, 5137, 1),
+    (0xFAAF, 5138, 1),
+    (0xFAB0, 5139, 1),
+    (0xFAB1, 5140, 1),
+    (0xFAB2, 5141, 1),
+    (0xFAB3, 5142, 1),
+    (0xFAB4, 5143, 1),
+    (0xFAB5, 5144, 1),
+    (0xFAB6, 5145, 1),
+    (0xFAB7, 5146, 1),
+    (0xFAB8, 5147, 1),
+    (0xFAB9, 5148, 1),
+    (0xFABA, 5149, 1),
+    (0xFABB, 5150, 1),
+    (0xFABC, 5151, 1),
+    (0xFABD, 5152, 1),
+    (0xFABE, 5153, 1),
+    (0xFABF, 5154, 1),
+    (0xFAC0, 5155, 1),
+    (0xFAC1, 5156, 1),
+    (0xFAC2, 5157, 1),
+    (0xFAC3, 5158, 1),
+    (0xFAC4, 5159, 1),
+    (0xFAC5, 5160, 1),
+    (0xFAC6, 5161, 1),
+    (0xFAC7, 5162, 1),
+    (0xFAC8, 5163, 1),
+    (0xFAC9, 5164, 1),
+    (0xFACA, 5165, 1),
+    (0xFACB, 5166, 1),
+    (0xFACC, 5167, 1),
+    (0xFACD, 5168, 1),
+    (0xFACE, 5169, 1),
+    (0xFACF, 5170, 1),
+    (0xFAD0, 5171, 1),
+    (0xFAD1, 5172, 1),
+    (0xFAD2, 5173, 1),
+    (0xFAD3, 5174, 1),
+    (0xFAD4, 5175, 1),
+    (0xFAD5, 5176, 1),
+    (0xFAD6, 5177, 1),
+    (0xFAD7, 5178, 1),
+    (0xFAD8, 5179, 1),
+    (0xFAD9, 5180, 1),
+    (0xFB1D, 5207, 2),
+    (0xFB1F, 5209, 2),
+    (0xFB2A, 5221, 2),
+    (0xFB2B, 5223, 2),
+    (0xFB2C, 5225, 3),
+    (0xFB2D, 5228, 3),
+    (0xFB2E, 5231, 2),
+    (0xFB2F, 5233, 2),
+    (0xFB30, 5235, 2),
+    (0xFB31, 5237, 2),
+    (0xFB32, 5239, 2),
+    (0xFB33, 5241, 2),
+    (0xFB34, 5243, 2),
+    (0xFB35, 5245, 2),
+    (0xFB36, 5247, 2),
+    (0xFB38, 5249, 2),
+    (0xFB39, 5251, 2),
+    (0xFB3A, 5253, 2),
+    (0xFB3B, 5255, 2),
+    (0xFB3C, 5257, 2),
+    (0xFB3E, 5259, 2),
+    (0xFB40, 5261, 2),
+    (0xFB41, 5263, 2),
+    (0xFB43, 5265, 2),
+    (0xFB44, 5267, 2),
+    (0xFB46, 5269, 2),
+    (0xFB47, 5271, 2),
+    (0xFB48, 5273, 2),
+    (0xFB49, 5275, 2),
+    (0xFB4A, 5277, 2),
+    (0xFB4B, 5279, 2),
+    (0xFB4C, 5281, 2),
+    (0xFB4D, 5283, 2),
+    (0xFB4E, 5285, 2),
+    (0x1109A, 7035, 2),
+    (0x1109C, 7037, 2),
+    (0x110AB, 7039, 2),
+    (0x1112E, 7041, 2),
+    (0x1112F, 7043, 2),
+    (0x1134B, 7045, 2),
+    (0x1134C, 7047, 2),
+    (0x114BB, 7049, 2),
+    (0x114BC, 7051, 2),
+    (0x114BE, 7053, 2),
+    (0x115BA, 7055, 2),
+    (0x115BB, 7057, 2),
+    (0x11938, 7059, 2),
+    (0x1D15E, 7061, 2),
+    (0x1D15F, 7063, 2),
+    (0x1D160, 7065, 3),
+    (0x1D161, 7068, 3),
+    (0x1D162, 7071, 3),
+    (0x1D163, 7074, 3),
+    (0x1D164, 7077, 3),
+    (0x1D1BB, 7080, 2),
+    (0x1D1BC, 7082, 2),
+    (0x1D1BD, 7084, 3),
+    (0x1D1BE, 7087, 3),
+    (0x1D1BF, 7090, 3),
+    (0x1D1C0, 7093, 3),
+    (0x2F800, 8478, 1),
+    (0x2F801, 8479, 1),
+    (0x2F802, 8480, 1),
+    (0x2F803, 8481, 1),
+    (0x2F804, 8482, 1),
+    (0x2F805, 8483, 1),
+    (0x2F806, 8484, 1),
+    (0x2F807, 8485, 1),
+    (0x2F808, 8486, 1),
+    (0x2F809, 8487, 1),
+    (0x2F80A, 8488, 1),
+    (0x2F80B, 8489, 1),
+    (0x2F80C, 8490, 1),
+    (0x2F80D, 8491, 1),
+    (0x2F80E, 8492, 1),
+    (0x2F80F, 8493, 1),
+    (0x2F810, 8494, 1),
+    (0x2F811, 8495, 1),
+    (0x2F812, 8496, 1),
+    (0x2F813, 8497, 1),
+    (0x2F814, 8498, 1),
+    (0x2F815, 8499, 1),
+    (0x2F816, 8500, 1),
+    (0x2F817, 8501, 1),
+    (0x2F818, 8502, 1),
+    (0x2F819, 8503, 1),
+    (0x2F81A, 8504, 1),
+    (0x2F81B, 8505, 1),
+    (0x2F81C, 8506, 1),
+    (0x2F81D, 8507, 1),
+    (0x2F81E, 8508, 1),
+    (0x2F81F, 8509, 1),
+    (0x2F820, 8510, 1),
+    (0x2F821, 8511, 1),
+    (0x2F822, 8512, 1),
+    (0x2F823, 8513, 1),
+    (0x2F824, 8514, 1),
+    (0x2F825, 8515, 1),
+    (0x2F826, 8516, 1),
+    (0x2F827, 8517, 1),
+    (0x2F828, 8518, 1),
+    (0x2F829, 8519, 1),
+    (0x2F82A, 8520, 1),
+    (0x2F82B, 8521, 1),
+    (0x2F82C, 8522, 1),
+    (0x2F82D, 8523, 1),
+    (0x2F82E, 8524, 1),
+    (0x2F82F, 8525, 1),
+    (0x2F830, 8526, 1),
+    (0x2F831, 8527, 1),
+    (0x2F832, 8528, 1),
+    (0x2F833, 8529, 1),
+    (0x2F834, 8530, 1),
+    (0x2F835, 8531, 1),
+    (0x2F836, 8532, 1),
+    (0x2F837, 8533, 1),
+    (0x2F838, 8534, 1),
+    (0x2F839, 8535, 1),
+    (0x2F83A, 8536, 1),
+    (0x2F83B, 8537, 1),
+    (0x2F83C, 8538, 1),
+    (0x2F83D, 8539, 1),
+    (0x2F83E, 8540, 1),
+    (0x2F83F, 8541, 1),
+    (0x2F840, 8542, 1),
+    (0x2F841, 8543, 1),
+    (0x2F842, 8544, 1),
+    (0x2F843, 8545, 1),
+    (0x2F844, 8546, 1),
+    (0x2F845, 8547, 1),
+    (0x2F846, 8548, 1),
+    (0x2F847, 8549, 1),
+    (0x2F848, 8550, 1),
+    (0x2F849, 8551, 1),
+    (0x2F84A, 8552, 1),
+    (0x2F84B, 8553, 1),
+    (0x2F84C, 8554, 1),
+    (0x2F84D, 8555, 1),
+    (0x2F84E, 8556, 1),
+    (0x2F84F, 8557, 1),
+    (0x2F850, 8558, 1),
+    (0x2F851, 8559, 1),
+    (0x2F852, 8560, 1),
+    (0x2F853, 8561, 1),
+    (0x2F854, 8562, 1),
+    (0x2F855, 8563, 1),
+    (0x2F856, 8564, 1),
+    (0x2F857, 8565, 1),
+    (0x2F858, 8566, 1),
+    (0x2F859, 8567, 1),
+    (0x2F85A, 8568, 1),
+    (0x2F85B, 8569, 1),
+    (0x2F85C, 8570, 1),
+    (0x2F85D, 8571, 1),
+    (0x2F85E, 8572, 1),
+    (0x2F85F, 8573, 1),
+    (0x2F860, 8574, 1),
+    (0x2F861, 8575, 1),
+    (0x2F862, 8576, 1),
+    (0x2F863, 8577, 1),
+    (0x2F864, 8578, 1),
+    (0x2F865, 8579, 1),
+    (0x2F866, 8580, 1),
+    (0x2F867, 8581, 1),
+    (0x2F868, 8582, 1),
+    (0x2F869, 8583, 1),
+    (0x2F86A, 8584, 1),
+    (0x2F86B, 8585, 1),
+    (0x2F86C, 8586, 1),
+    (0x2F86D, 8587, 1),
+    (0x2F86E, 8588, 1),
+    (0x2F86F, 8589, 1),
+    (0x2F870, 8590, 1),
+    (0x2F871, 8591, 1),
+    (0x2F872, 8592, 1),
+    (0x2F873, 8593, 1),
+    (0x2F874, 8594, 1),
+    (0x2F875, 8595, 1),
+    (0x2F876, 8596, 1),
+    (0x2F877, 8597, 1),
+    (0x2F878, 8598, 1),
+    (0x2F879, 8599, 1),
+    (0x2F87A, 8600, 1),
+    (0x2F87B, 8601, 1),
+    (0x2F87C, 8602, 1),
+    (0x2F87D, 8603, 1),
+    (0x2F87E, 8604, 1),
+    (0x2F87F, 8605, 1),
+    (0x2F880, 8606, 1),
+    (0x2F881, 8607, 1),
+    (0x2F882, 8608, 1),
+    (0x2F883, 8609, 1),
+    (0x2F884, 8610, 1),
+    (0x2F885, 8611, 1),
+    (0x2F886, 8612, 1),
+    (0x2F887, 8613, 1),
+    (0x2F888, 8614, 1),
+    (0x2F889, 8615, 1),
+    (0x2F88A, 8616, 1),
+    (0x2F88B, 8617, 1),
+    (0x2F88C, 8618, 1),
+    (0x2F88D, 8619, 1),
+    (0x2F88E, 8620, 1),
+    (0x2F88F, 8621, 1),
+    (0x2F890, 8622, 1),
+    (0x2F891, 8623, 1),
+    (0x2F892, 8624, 1),
+    (0x2F893, 8625, 1),
+    (0x2F894, 8626, 1),
+    (0x2F895, 8627, 1),
+    (0x2F896, 8628, 1),
+    (0x2F897, 8629, 1),
+    (0x2F898, 8630, 1),
+    (0x2F899, 8631, 1),
+    (0x2F89A, 8632, 1),
+    (0x2F89B, 8633, 1),
+    (0x2F89C, 8634, 1),
+    (0x2F89D, 8635, 1),
+    (0x2F89E, 8636, 1),
+    (0x2F89F, 8637, 1),
+    (0x2F8A0, 8638, 1),
+    (0x2F8A1, 8639, 1),
+    (0x2F8A2, 8640, 1),
+    (0x2F8A3, 8641, 1),
+    (0x2F8A4, 8642, 1),
+    (0x2F8A5, 8643, 1),
+    (0x2F8A6, 8644, 1),
+    (0x2F8A7, 8645, 1),
+    (0x2F8A8, 8646, 1),
+    (0x2F8A9, 8647, 1),
+    (0x2F8AA, 8648, 1),
+    (0x2F8AB, 8649, 1),
+    (0x2F8AC, 8650, 1),
+    (0x2F8AD, 8651, 1),
+    (0x2F8AE, 8652, 1),
+    (0x2F8AF, 8653, 1),
+    (0x2F8B0, 8654, 1),
+    (0x2F8B1, 8655, 1),
+    (0x2F8B2, 8656, 1),
+    (0x2F8B3, 8657, 1),
+    (0x2F8B4, 8658, 1),
+    (0x2F8B5, 8659, 1),
+    (0x2F8B6, 8660, 1),
+    (0x2F8B7, 8661, 1),
+    (0x2F8B8, 8662, 1),
+    (0x2F8B9, 8663, 1),
+    (0x2F8BA, 8664, 1),
+    (0x2F8BB, 8665, 1),
+    (0x2F8BC, 8666, 1),
+    (0x2F8BD, 8667, 1),
+    (0x2F8BE, 8668, 1),
+    (0x2F8BF, 8669, 1),
+    (0x2F8C0, 8670, 1),
+    (0x2F8C1, 8671, 1),
+    (0x2F8C2, 8672, 1),
+    (0x2F8C3, 8673, 1),
+    (0x2F8C4, 8674, 1),
+    (0x2F8C5, 8675, 1),
+    (0x2F8C6, 8676, 1),
+    (0x2F8C7, 8677, 1),
+    (0x2F8C8, 8678, 1),
+    (0x2F8C9, 8679, 1),
+    (0x2F8CA, 8680, 1),
+    (0x2F8CB, 8681, 1),
+    (0x2F8CC, 8682, 1),
+    (0x2F8CD, 8683, 1),
+    (0x2F8CE, 8684, 1),
+    (0x2F8CF, 8685, 1),
+    (0x2F8D0, 8686, 1),
+    (0x2F8D1, 8687, 1),
+    (0x2F8D2, 8688, 1),
+    (0x2F8D3, 8689, 1),
+    (0x2F8D4, 8690, 1),
+    (0x2F8D5, 8691, 1),
+    (0x2F8D6, 8692, 1),
+    (0x2F8D7, 8693, 1),
+    (0x2F8D8, 8694, 1),
+    (0x2F8D9, 8695, 1),
+    (0x2F8DA, 8696, 1),
+    (0x2F8DB, 8697, 1),
+    (0x2F8DC, 8698, 1),
+    (0x2F8DD, 8699, 1),
+    (0x2F8DE, 8700, 1),
+    (0x2F8DF, 8701, 1),
+    (0x2F8E0, 8702, 1),
+    (0x2F8E1, 8703, 1),
+    (0x2F8E2, 8704, 1),
+    (0x2F8E3, 8705, 1),
+    (0x2F8E4, 8706, 1),
+    (0x2F8E5, 8707, 1),
+    (0x2F8E6, 8708, 1),
+    (0x2F8E7, 8709, 1),
+    (0x2F8E8, 8710, 1),
+    (0x2F8E9, 8711, 1),
+    (0x2F8EA, 8712, 1),
+    (0x2F8EB, 8713, 1),
+    (0x2F8EC, 8714, 1),
+    (0x2F8ED, 8715, 1),
+    (0x2F8EE, 8716, 1),
+    (0x2F8EF, 8717, 1),
+    (0x2F8F0, 8718, 1),
+    (0x2F8F1, 8719, 1),
+    (0x2F8F2, 8720, 1),
+    (0x2F8F3, 8721, 1),
+    (0x2F8F4, 8722, 1),
+    (0x2F8F5, 8723, 1),
+    (0x2F8F6, 8724, 1),
+    (0x2F8F7, 8725, 1),
+    (0x2F8F8, 8726, 1),
+    (0x2F8F9, 8727, 1),
+    (0x2F8FA, 8728, 1),
+    (0x2F8FB, 8729, 1),
+    (0x2F8FC, 8730, 1),
+    (0x2F8FD, 8731, 1),
+    (0x2F8FE, 8732, 1),
+    (0x2F8FF, 8733, 1),
+    (0x2F900, 8734, 1),
+    (0x2F901, 8735, 1),
+    (0x2F902, 8736, 1),
+    (0x2F903, 8737, 1),
+    (0x2F904, 8738, 1),
+    (0x2F905, 8739, 1),
+    (0x2F906, 8740, 1),
+    (0x2F907, 8741, 1),
+    (0x2F908, 8742, 1),
+    (0x2F909, 8743, 1),
+    (0x2F90A, 8744, 1),
+    (0x2F90B, 8745, 1),
+    (0x2F90C, 8746, 1),
+    (0x2F90D, 8747, 1),
+    (0x2F90E, 8748, 1),
+    (0x2F90F, 8749, 1),
+    (0x2F910, 8750, 1),
+    (0x2F911, 8751, 1),
+    (0x2F912, 8752, 1),
+    (0x2F913, 8753, 1),
+    (0x2F914, 8754, 1),
+    (0x2F915, 8755, 1),
+    (0x2F916, 8756, 1),
+    (0x2F917, 8757, 1),
+    (0x2F918, 8758, 1),
+    (0x2F919, 8759, 1),
+    (0x2F91A, 8760, 1),
+    (0x2F91B, 8761, 1),
+    (0x2F91C, 8762, 1),
+    (0x2F91D, 8763, 1),
+    (0x2F91E, 8764, 1),
+    (0x2F91F, 8765, 1),
+    (0x2F920, 8766, 1),
+    (0x2F921, 8767, 1),
+    (0x2F922, 8768, 1),
+    (0x2F923, 8769, 1),
+    (0x2F924, 8770, 1),
+    (0x2F925, 8771, 1),
+    (0x2F926, 8772, 1),
+    (0x2F927, 8773, 1),
+    (0x2F928, 8774, 1),
+    (0x2F929, 8775, 1),
+    (0x2F92A, 8776, 1),
+    (0x2F92B, 8777, 1),
+    (0x2F92C, 8778, 1),
+    (0x2F92D, 8779, 1),
+    (0x2F92E, 8780, 1),
+    (0x2F92F, 8781, 1),
+    (0x2F930, 8782, 1),
+    (0x2F931, 8783, 1),
+    (0x2F932, 8784, 1),
+    (0x2F933, 8785, 1),
+    (0x2F934, 8786, 1),
+    (0x2F935, 8787, 1),
+    (0x2F936, 8788, 1),
+    (0x2F937, 8789, 1),
+    (0x2F938, 8790, 1),
+    (0x2F939, 8791, 1),
+    (0x2F93A, 8792, 1),
+    (0x2F93B, 8793, 1),
+    (0x2F93C, 8794, 1),
+    (0x2F93D, 8795, 1),
+    (0x2F93E, 8796, 1),
+    (0x2F93F, 8797, 1),
+    (0x2F940, 8798, 1),
+    (0x2F941, 8799, 1),
+    (0x2F942, 8800, 1),
+    (0x2F943, 8801, 1),
+    (0x2F944, 8802, 1),
+    (0x2F945, 8803, 1),
+    (0x2F946, 8804, 1),
+    (0x2F947, 8805, 1),
+    (0x2F948, 8806, 1),
+    (0x2F949, 8807, 1),
+    (0x2F94A, 8808, 1),
+    (0x2F94B, 8809, 1),
+    (0x2F94C, 8810, 1),
+    (0x2F94D, 8811, 1),
+    (0x2F94E, 8812, 1),
+    (0x2F94F, 8813, 1),
+    (0x2F950, 8814, 1),
+    (0x2F951, 8815, 1),
+    (0x2F952, 8816, 1),
+    (0x2F953, 8817, 1),
+    (0x2F954, 8818, 1),
+    (0x2F955, 8819, 1),
+    (0x2F956, 8820, 1),
+    (0x2F957, 8821, 1),
+    (0x2F958, 8822, 1),
+    (0x2F959, 8823, 1),
+    (0x2F95A, 8824, 1),
+    (0x2F95B, 8825, 1),
+    (0x2F95C, 8826, 1),
+    (0x2F95D, 8827, 1),
+    (0x2F95E, 8828, 1),
+    (0x2F95F, 8829, 1),
+    (0x2F960, 8830, 1),
+    (0x2F961, 8831, 1),
+    (0x2F962, 8832, 1),
+    (0x2F963, 8833, 1),
+    (0x2F964, 8834, 1),
+    (0x2F965, 8835, 1),
+    (0x2F966, 8836, 1),
+    (0x2F967, 8837, 1),
+    (0x2F968, 8838, 1),
+    (0x2F969, 8839, 1),
+    (0x2F96A, 8840, 1),
+    (0x2F96B, 8841, 1),
+    (0x2F96C, 8842, 1),
+    (0x2F96D, 8843, 1),
+    (0x2F96E, 8844, 1),
+    (0x2F96F, 8845, 1),
+    (0x2F970, 8846, 1),
+    (0x2F971, 8847, 1),
+    (0x2F972, 8848, 1),
+    (0x2F973, 8849, 1),
+    (0x2F974, 8850, 1),
+    (0x2F975, 8851, 1),
+    (0x2F976, 8852, 1),
+    (0x2F977, 8853, 1),
+    (0x2F978, 8854, 1),
+    (0x2F979, 8855, 1),
+    (0x2F97A, 8856, 1),
+    (0x2F97B, 8857, 1),
+    (0x2F97C, 8858, 1),
+    (0x2F97D, 8859, 1),
+    (0x2F97E, 8860, 1),
+    (0x2F97F, 8861, 1),
+    (0x2F980, 8862, 1),
+    (0x2F981, 8863, 1),
+    (0x2F982, 8864, 1),
+    (0x2F983, 8865, 1),
+    (0x2F984, 8866, 1),
+    (0x2F985, 8867, 1),
+    (0x2F986, 8868, 1),
+    (0x2F987, 8869, 1),
+    (0x2F988, 8870, 1),
+    (0x2F989, 8871, 1),
+    (0x2F98A, 8872, 1),
+    (0x2F98B, 8873, 1),
+    (0x2F98C, 8874, 1),
+    (0x2F98D, 8875, 1),
+    (0x2F98E, 8876, 1),
+    (0x2F98F, 8877, 1),
+    (0x2F990, 8878, 1),
+    (0x2F991, 8879, 1),
+    (0x2F992, 8880, 1),
+    (0x2F993, 8881, 1),
+    (0x2F994, 8882, 1),
+    (0x2F995, 8883, 1),
+    (0x2F996, 8884, 1),
+    (0x2F997, 8885, 1),
+    (0x2F998, 8886, 1),
+    (0x2F999, 8887, 1),
+    (0x2F99A, 8888, 1),
+    (0x2F99B, 8889, 1),
+    (0x2F99C, 8890, 1),
+    (0x2F99D, 8891, 1),
+    (0x2F99E, 8892, 1),
+    (0x2F99F, 8893, 1),
+    (0x2F9A0, 8894, 1),
+    (0x2F9A1, 8895, 1),
+    (0x2F9A2, 8896, 1),
+    (0x2F9A3, 8897, 1),
+    (0x2F9A4, 8898, 1),
+    (0x2F9A5, 8899, 1),
+    (0x2F9A6, 8900, 1),
+    (0x2F9A7, 8901, 1),
+    (0x2F9A8, 8902, 1),
+    (0x2F9A9, 8903, 1),
+    (0x2F9AA, 8904, 1),
+    (0x2F9AB, 8905, 1),
+    (0x2F9AC, 8906, 1),
+    (0x2F9AD, 8907, 1),
+    (0x2F9AE, 8908, 1),
+    (0x2F9AF, 8909, 1),
+    (0x2F9B0, 8910, 1),
+    (0x2F9B1, 8911, 1),
+    (0x2F9B2, 8912, 1),
+    (0x2F9B3, 8913, 1),
+    (0x2F9B4, 8914, 1),
+    (0x2F9B5, 8915, 1),
+    (0x2F9B6, 8916, 1),
+    (0x2F9B7, 8917, 1),
+    (0x2F9B8, 8918, 1),
+    (0x2F9B9, 8919, 1),
+    (0x2F9BA, 8920, 1),
+    (0x2F9BB, 8921, 1),
+    (0x2F9BC, 8922, 1),
+    (0x2F9BD, 8923, 1),
+    (0x2F9BE, 8924, 1),
+    (0x2F9BF, 8925, 1),
+    (0x2F9C0, 8926, 1),
+    (0x2F9C1, 8927, 1),
+    (0x2F9C2, 8928, 1),
+    (0x2F9C3, 8929, 1),
+    (0x2F9C4, 8930, 1),
+    (0x2F9C5, 8931, 1),
+    (0x2F9C6, 8932, 1),
+    (0x2F9C7, 8933, 1),
+    (0x2F9C8, 8934, 1),
+    (0x2F9C9, 8935, 1),
+    (0x2F9CA, 8936, 1),
+    (0x2F9CB, 8937, 1),
+    (0x2F9CC, 8938, 1),
+    (0x2F9CD, 8939, 1),
+    (0x2F9CE, 8940, 1),
+    (0x2F9CF, 8941, 1),
+    (0x2F9D0, 8942, 1),
+    (0x2F9D1, 8943, 1),
+    (0x2F9D2, 8944, 1),
+    (0x2F9D3, 8945, 1),
+    (0x2F9D4, 8946, 1),
+    (0x2F9D5, 8947, 1),
+    (0x2F9D6, 8948, 1),
+    (0x2F9D7, 8949, 1),
+    (0x2F9D8, 8950, 1),
+    (0x2F9D9, 8951, 1),
+    (0x2F9DA, 8952, 1),
+    (0x2F9DB, 8953, 1),
+    (0x2F9DC, 8954, 1),
+    (0x2F9DD, 8955, 1),
+    (0x2F9DE, 8956, 1),
+    (0x2F9DF, 8957, 1),
+    (0x2F9E0, 8958, 1),
+    (0x2F9E1, 8959, 1),
+    (0x2F9E2, 8960, 1),
+    (0x2F9E3, 8961, 1),
+    (0x2F9E4, 8962, 1),
+    (0x2F9E5, 8963, 1),
+    (0x2F9E6, 8964, 1),
+    (0x2F9E7, 8965, 1),
+    (0x2F9E8, 8966, 1),
+    (0x2F9E9, 8967, 1),
+    (0x2F9EA, 8968, 1),
+    (0x2F9EB, 8969, 1),
+    (0x2F9EC, 8970, 1),
+    (0x2F9ED, 8971, 1),
+    (0x2F9EE, 8972, 1),
+    (0x2F9EF, 8973, 1),
+    (0x2F9F0, 8974, 1),
+    (0x2F9F1, 8975, 1),
+    (0x2F9F2, 8976, 1),
+    (0x2F9F3, 8977, 1),
+    (0x2F9F4, 8978, 1),
+    (0x2F9F5, 8979, 1),
+    (0x2F9F6, 8980, 1),
+    (0x2F9F7, 8981, 1),
+    (0x2F9F8, 8982, 1),
+    (0x2F9F9, 8983, 1),
+    (0x2F9FA, 8984, 1),
+    (0x2F9FB, 8985, 1),
+    (0x2F9FC, 8986, 1),
+    (0x2F9FD, 8987, 1),
+    (0x2F9FE, 8988, 1),
+    (0x2F9FF, 8989, 1),
+    (0x2FA00, 8990, 1),
+    (0x2FA01, 8991, 1),
+    (0x2FA02, 8992, 1),
+    (0x2FA03, 8993, 1),
+    (0x2FA04, 8994, 1),
+    (0x2FA05, 8995, 1),
+    (0x2FA06, 8996, 1),
+    (0x2FA07, 8997, 1),
+    (0x2FA08, 8998, 1),
+    (0x2FA09, 8999, 1),
+    (0x2FA0A, 9000, 1),
+    (0x2FA0B, 9001, 1),
+    (0x2FA0C, 9002, 1),
+    (0x2FA0D, 9003, 1),
+    (0x2FA0E, 9004, 1),
+    (0x2FA0F, 9005, 1),
+    (0x2FA10, 9006, 1),
+    (0x2FA11, 9007, 1),
+    (0x2FA12, 9008, 1),
+    (0x2FA13, 9009, 1),
+    (0x2FA14, 9010, 1),
+    (0x2FA15, 9011, 1),
+    (0x2FA16, 9012, 1),
+    (0x2FA17, 9013, 1),
+    (0x2FA18, 9014, 1),
+    (0x2FA19, 9015, 1),
+    (0x2FA1A, 9016, 1),
+    (0x2FA1B, 9017, 1),
+    (0x2FA1C, 9018, 1),
+    (0x2FA1D, 9019, 1),
+];
+
+pub(super) static COMPATIBILITY_DECOMPOSITIONS: [(u32, u16, u8); 3691] = [
+    (0x00A0, 0, 1),
+    (0x00A8, 1, 2),
+    (0x00AA, 3, 1),
+    (0x00AF, 4, 2),
+    (0x00B2, 6, 1),
+    (0x00B3, 7, 1),
+    (0x00B4, 8, 2),
+    (0x00B5, 10, 1),
+    (0x00B8, 11, 2),
+    (0x00B9, 13, 1),
+    (0x00BA, 14, 1),
+    (0x00BC, 15, 3),
+    (0x00BD, 18, 3),
+    (0x00BE, 21, 3),
+    (0x0132, 220, 2),
+    (0x0133, 222, 2),
+    (0x013F, 244, 2),
+    (0x0140, 246, 2),
+    (0x0149, 260, 2),
+    (0x017F, 356, 1),
+    (0x01C4, 365, 3),
+    (0x01C5, 368, 3),
+    (0x01C6, 371, 3),
+    (0x01C7, 374, 2),
+    (0x01C8, 376, 2),
+    (0x01C9, 378, 2),
+    (0x01CA, 380, 2),
+    (0x01CB, 382, 2),
+    (0x01CC, 384, 2),
+    (0x01F1, 466, 2),
+    (0x01F2, 468, 2),
+    (0x01F3, 470, 2),
+    (0x02B0, 588, 1),
+    (0x02B1, 589, 1),
+    (0x02B2, 590, 1),
+    (0x02B3, 591, 1),
+    (0x02B4, 592, 1),
+    (0x02B5, 593, 1),
+    (0x02B6, 594, 1),
+    (0x02B7, 595, 1),
+    (0x02B8, 596, 1),
+    (0x02D8, 597, 2),
+    (0x02D9, 599, 2),
+    (0x02DA, 601, 2),
+    (0x02DB, 603, 2),
+    (0x02DC, 605, 2),
+    (0x02DD, 607, 2),
+    (0x02E0, 609, 1),
+    (0x02E1, 610, 1),
+    (0x02E2, 611, 1),
+    (0x02E3, 612, 1),
+    (0x02E4, 613, 1),
+    (0x037A, 620, 2),
+    (0x0384, 623, 2),
+    (0x0385, 627, 3),
+    (0x03D0, 673, 1),
+    (0x03D1, 674, 1),
+    (0x03D2, 675, 1),
+    (0x03D3, 678, 2),
+    (0x03D4, 682, 2),
+    (0x03D5, 684, 1),
+    (0x03D6, 685, 1),
+    (0x03F0, 686, 1),
+    (0x03F1, 687, 1),
+    (0x03F2, 688, 1),
+    (0x03F4, 689, 1),
+    (0x03F5, 690, 1),
+    (0x03F9, 691, 1),
+    (0x0587, 796, 2),
+    (0x0675, 808, 2),
+    (0x0676, 810, 2),
+    (0x0677, 812, 2),
+    (0x0678, 814, 2),
+    (0x0E33, 912, 2),
+    (0x0EB3, 914, 2),
+    (0x0EDC, 916, 2),
+    (0x0EDD, 918, 2),
+    (0x0F0C, 920, 1),
+    (0x0F77, 939, 3),
+    (0x0F79, 944, 3),
+    (0x10FC, 963, 1),
+    (0x1D2C, 986, 1),
+    (0x1D2D, 987, 1),
+    (0x1D2E, 988, 1),
+    (0x1D30, 989, 1),
+    (0x1D31, 990, 1),
+    (0x1D32, 991, 1),
+    (0x1D33, 992, 1),
+    (0x1D34, 993, 1),
+    (0x1D35, 994, 1),
+    (0x1D36, 995, 1),
+    (0x1D37, 996, 1),
+    (0x1D38, 997, 1),
+    (0x1D39, 998, 1),
+    (0x1D3A, 999, 1),
+    (0x1D3C, 1000, 1),
+    (0x1D3D, 1001, 1),
+    (0x1D3E, 1002, 1),
+    (0x1D3F, 1003, 1),
+    (0x1D40, 1004, 1),
+    (0x1D41, 1005, 1),
+    (0x1D42, 1006, 1),
+    (0x1D43, 1007, 1),
+    (0x1D44, 1008, 1),
+    (0x1D45, 1009, 1),
+    (0x1D46, 1010, 1),
+    (0x1D47, 1011, 1),
+    (0x1D48, 1012, 1),
+    (0x1D49, 1013, 1),
+    (0x1D4A, 1014, 1),
+    (0x1D4B, 1015, 1),
+    (0x1D4C, 1016, 1),
+    (0x1D4D, 1017, 1),
+    (0x1D4F, 1018, 1),
+    (0x1D50, 1019, 1),
+    (0x1D51, 1020, 1),
+    (0x1D52, 1021, 1),
+    (0x1D53, 1022, 1),
+    (0x1D54, 1023, 1),
+    (0x1D55, 1024, 1),
+    (0x1D56, 1025, 1),
+    (0x1D57, 1026, 1),
+    (0x1D58, 1027, 1),
+    (0x1D59, 1028, 1),
+    (0x1D5A, 1029, 1),
+    (0x1D5B, 1030, 1),
+    (0x1D5C, 1031, 1),
+    (0x1D5D, 1032, 1),
+    (0x1D5E, 1033, 1),
+    (0x1D5F, 1034, 1),
+    (0x1D60, 1035, 1),
+    (0x1D61, 1036, 1),
+    (0x1D62, 1037, 1),
+    (0x1D63, 1038, 1),
+    (0x1D64, 1039, 1),
+    (0x1D65, 1040, 1),
+    (0x1D66, 1041, 1),
+    (0x1D67, 1042, 1),
+    (0x1D68, 1043, 1),
+    (0x1D69, 1044, 1),
+    (0x1D6A, 1045, 1),
+    (0x1D78, 1046, 1),
+    (0x1D9B, 1047, 1),
+    (0x1D9C, 1048, 1),
+    (0x1D9D, 1049, 1),
+    (0x1D9E, 1050, 1),
+    (0x1D9F, 1051, 1),
+    (0x1DA0, 1052, 1),
+    (0x1DA1, 1053, 1),
+    (0x1DA2, 1054, 1),
+    (0x1DA3, 1055, 1),
+    (0x1DA4, 1056, 1),
+    (0x1DA5, 1057, 1),
+    (0x1DA6, 1058, 1),
+    (0x1DA7, 1059, 1),
+    (0x1DA8, 1060, 1),
+    (0x1DA9, 1061, 1),
+    (0x1DAA, 1062, 1),
+    (0x1DAB, 1063, 1),
+    (0x1DAC, 1064, 1),
+    (0x1DAD, 1065, 1),
+    (0x1DAE, 1066, 1),
+    (0x1DAF, 1067, 1),
+    (0x1DB0, 1068, 1),
+    (0x1DB1, 1069, 1),
+    (0x1DB2, 1070, 1),
+    (0x1DB3, 1071, 1),
+    (0x1DB4, 1072, 1),
+    (0x1DB5, 1073, 1),
+    (0x1DB6, 1074, 1),
+    (0x1DB7, 1075, 1),
+    (0x1DB8, 1076, 1),
+    (0x1DB9, 1077, 1),
+    (0x1DBA, 1078, 1),
+    (0x1DBB, 1079, 1),
+    (0x1DBC, 1080, 1),
+    (0x1DBD, 1081, 1),
+    (0x1DBE, 1082, 1),
+    (0x1DBF, 1083, 1),
+    (0x1E9A, 1424, 2),
+    (0x1E9B, 1428, 2),
+    (0x1FBD, 2178, 2),
+    (0x1FBF, 2181, 2),
+    (0x1FC0, 2183, 2),
+    (0x1FC1, 2187, 3),
+    (0x1FCD, 2215, 3),
+    (0x1FCE, 2220, 3),
+    (0x1FCF, 2225, 3),
+    (0x1FDD, 2253, 3),
+    (0x1FDE, 2258, 3),
+    (0x1FDF, 2263, 3),
+    (0x1FED, 2297, 3),
+    (0x1FEE, 2302, 3),
+    (0x1FFD, 2330, 2),
+    (0x1FFE, 2332, 2),
+    (0x2000, 2335, 1),
+    (0x2001, 2337, 1),
+    (0x2002, 2338, 1),
+    (0x2003, 2339, 1),
+    (0x2004, 2340, 1),
+    (0x2005, 2341, 1),
+    (0x2006, 2342, 1),
+    (0x2007, 2343, 1),
+    (0x2008, 2344, 1),
+    (0x2009, 2345, 1),
+    (0x200A, 2346, 1),
+    (0x2011, 2347, 1),
+    (0x2017, 2348, 2),
+    (0x2024, 2350, 1),
+    (0x2025, 2351, 2),
+    (0x2026, 2353, 3),
+    (0x202F, 2356, 1),
+    (0x2033, 2357, 2),
+    (0x2034, 2359, 3),
+    (0x2036, 2362, 2),
+    (0x2037, 2364, 3),
+    (0x203C, 2367, 2),
+    (0x203E, 2369, 2),
+    (0x2047, 2371, 2),
+    (0x2048, 2373, 2),
+    (0x2049, 2375, 2),
+    (0x2057, 2377, 4),
+    (0x205F, 2381, 1),
+    (0x2070, 2382, 1),
+    (0x2071, 2383, 1),
+    (0x2074, 2384, 1),
+    (0x2075, 2385, 1),
+    (0x2076, 2386, 1),
+    (0x2077, 2387, 1),
+    (0x2078, 2388, 1),
+    (0x2079, 2389, 1),
+    (0x207A, 2390, 1),
+    (0x207B, 2391, 1),
+    (0x207C, 2392, 1),
+    (0x207D, 2393, 1),
+    (0x207E, 2394, 1),
+    (0x207F, 2395, 1),
+    (0x2080, 2396, 1),
+    (0x2081, 2397, 1),
+    (0x2082, 2398, 1),
+    (0x2083, 2399, 1),
+    (0x2084, 2400, 1),
+    (0x2085, 2401, 1),
+    (0x2086, 2402, 1),
+    (0x2087, 2403, 1),
+    (0x2088, 2404, 1),
+    (0x2089, 2405, 1),
+    (0x208A, 2406, 1),
+    (0x208B, 2407, 1),
+    (0x208C, 2408, 1),
+    (0x208D, 2409, 1),
+    (0x208E, 2410, 1),
+    (0x2090, 2411, 1),
+    (0x2091, 2412, 1),
+    (0x2092, 2413, 1),
+    (0x2093, 2414, 1),
+    (0x2094, 2415, 1),
+    (0x2095, 2416, 1),
+    (0x2096, 2417, 1),
+    (0x2097, 2418, 1),
+    (0x2098, 2419, 1),
+    (0x2099, 2420, 1),
+    (0x209A, 2421, 1),
+    (0x209B, 2422, 1),
+    (0x209C, 2423, 1),
+    (0x20A8, 2424, 2),
+    (0x2100, 2426, 3),
+    (0x2101, 2429, 3),
+    (0x2102, 2432, 1),
+    (0x2103, 2433, 2),
+    (0x2105, 2435, 3),
+    (0x2106, 2438, 3),
+    (0x2107, 2441, 1),
+    (0x2109, 2442, 2),
+    (0x210A, 2444, 1),
+    (0x210B, 2445, 1),
+    (0x210C, 2446, 1),
+    (0x210D, 2447, 1),
+    (0x210E, 2448, 1),
+    (0x210F, 2449, 1),
+    (0x2110, 2450, 1),
+    (0x2111, 2451, 1),
+    (0x2112, 2452, 1),
+    (0x2113, 2453, 1),
+    (0x2115, 2454, 1),
+    (0x2116, 2455, 2),
+    (0x2119, 2457, 1),
+    (0x211A, 2458, 1),
+    (0x211B, 2459, 1),
+    (0x211C, 2460, 1),
+    (0x211D, 2461, 1),
+    (0x2120, 2462, 2),
+    (0x2121, 2464, 3),
+    (0x2122, 2467, 2),
+    (0x2124, 2469, 1),
+    (0x2128, 2471, 1),
+    (0x212C, 2475, 1),
+    (0x212D, 2476, 1),
+    (0x212F, 2477, 1),
+    (0x2130, 2478, 1),
+    (0x2131, 2479, 1),
+    (0x2133, 2480, 1),
+    (0x2134, 2481, 1),
+    (0x2135, 2482, 1),
+    (0x2136, 2483, 1),
+    (0x2137, 2484, 1),
+    (0x2138, 2485, 1),
+    (0x2139, 2486, 1),
+    (0x213B, 2487, 3),
+    (0x213C, 2490, 1),
+    (0x213D, 2491, 1),
+    (0x213E, 2492, 1),
+    (0x213F, 2493, 1),
+    (0x2140, 2494, 1),
+    (0x2145, 2495, 1),
+    (0x2146, 2496, 1),
+    (0x2147, 2497, 1),
+    (0x2148, 2498, 1),
+    (0x2149, 2499, 1),
+    (0x2150, 2500, 3),
+    (0x2151, 2503, 3),
+    (0x2152, 2506, 4),
+    (0x2153, 2510, 3),
+    (0x2154, 2513, 3),
+    (0x2155, 2516, 3),
+    (0x2156, 2519, 3),
+    (0x2157, 2522, 3),
+    (0x2158, 2525, 3),
+    (0x2159, 2528, 3),
+    (0x215A, 2531, 3),
+    (0x215B, 2534, 3),
+    (0x215C, 2537, 3),
+    (0x215D, 2540, 3),
+    (0x215E, 2543, 3),
+    (0x215F, 2546, 2),
+    (0x2160, 2548, 1),
+    (0x2161, 2549, 2),
+    (0x2162, 2551, 3),
+    (0x2163, 2554, 2),
+    (0x2164, 2556, 1),
+    (0x2165, 2557, 2),
+    (0x2166, 2559, 3),
+    (0x2167, 2562, 4),
+    (0x2168, 2566, 2),
+    (0x2169, 2568, 1),
+    (0x216A, 2569, 2),
+    (0x216B, 2571, 3),
+    (0x216C, 2574, 1),
+    (0x216D, 2575, 1),
+    (0x216E, 2576, 1),
+    (0x216F, 2577, 1),
+    (0x2170, 2578, 1),
+    (0x2171, 2579, 2),
+    (0x2172, 2581, 3),
+    (0x2173, 2584, 2),
+    (0x2174, 2586, 1),
+    (0x2175, 2587, 2),
+    (0x2176, 2589, 3),
+    (0x2177, 2592, 4),
+    (0x2178, 2596, 2),
+    (0x2179, 2598, 1),
+    (0x217A, 2599, 2),
+    (0x217B, 2601, 3),
+    (0x217C, 2604, 1),
+    (0x217D, 2605, 1),
+    (0x217E, 2606, 1),
+    (0x217F, 2607, 1),
+    (0x2189, 2608, 3),
+    (0x222C, 2633, 2),
+    (0x222D, 2635, 3),
+    (0x222F, 2638, 2),
+    (0x2230, 2640, 3),
+    (0x2460, 2711, 1),
+    (0x2461, 2712, 1),
+    (0x2462, 2713, 1),
+    (0x2463, 2714, 1),
+    (0x2464, 2715, 1),
+    (0x2465, 2716, 1),
+    (0x2466, 2717, 1),
+    (0x2467, 2718, 1),
+    (0x2468, 2719, 1),
+    (0x2469, 2720, 2),
+    (0x246A, 2722, 2),
+    (0x246B, 2724, 2),
+    (0x246C, 2726, 2),
+    (0x246D, 2728, 2),
+    (0x246E, 2730, 2),
+    (0x246F, 2732, 2),
+    (0x2470, 2734, 2),
+    (0x2471, 2736, 2),
+    (0x2472, 2738, 2),
+    (0x2473, 2740, 2),
+    (0x2474, 2742, 3),
+    (0x2475, 2745, 3),
+    (0x2476, 2748, 3),
+    (0x2477, 2751, 3),
+    (0x2478, 2754, 3),
+    (0x2479, 2757, 3),
+    (0x247A, 2760, 3),
+    (0x247B, 2763, 3),
+    (0x247C, 2766, 3),
+    (0x247D, 2769, 4),
+    (0x247E, 2773, 4),
+    (0x247F, 2777, 4),
+    (0x2480, 2781, 4),
+    (0x2481, 2785, 4),
+    (0x2482, 2789, 4),
+    (0x2483, 2793, 4),
+    (0x2484, 2797, 4),
+    (0x2485, 2801, 4),
+    (0x2486, 2805, 4),
+    (0x2487, 2809, 4),
+    (0x2488, 2813, 2),
+    (0x2489, 2815, 2),
+    (0x248A, 2817, 2),
+    (0x248B, 2819, 2),
+    (0x248C, 2821, 2),
+    (0x248D, 2823, 2),
+    (0x248E, 2825, 2),
+    (0x248F, 2827, 2),
+    (0x2490, 2829, 2),
+    (0x2491, 2831, 3),
+    (0x2492, 2834, 3),
+    (0x2493, 2837, 3),
+    (0x2494, 2840, 3),
+    (0x2495, 2843, 3),
+    (0x2496, 2846, 3),
+    (0x2497, 2849, 3),
+    (0x2498, 2852, 3),
+    (0x2499, 2855, 3),
+    (0x249A, 2858, 3),
+    (0x249B, 2861, 3),
+    (0x249C, 2864, 3),
+    (0x249D, 2867, 3),
+    (0x249E, 2870, 3),
+    (0x249F, 2873, 3),
+    (0x24A0, 2876, 3),
+    (0x24A1, 2879, 3),
+    (0x24A2, 2882, 3),
+    (0x24A3, 2885, 3),
+    (0x24A4, 2888, 3),
+    (0x24A5, 2891, 3),
+    (0x24A6, 2894, 3),
+    (0x24A7, 2897, 3),
+    (0x24A8, 2900, 3),
+    (0x24A9, 2903, 3),
+    (0x24AA, 2906, 3),
+    (0x24AB, 2909, 3),
+    (0x24AC, 2912, 3),
+    (0x24AD, 2915, 3),
+    (0x24AE, 2918, 3),
+    (0x24AF, 2921, 3),
+    (0x24B0, 2924, 3),
+    (0x24B1, 2927, 3),
+    (0x24B2, 2930, 3),
+    (0x24B3, 2933, 3),
+    (0x24B4, 2936, 3),
+    (0x24B5, 2939, 3),
+    (0x24B6, 2942, 1),
+    (0x24B7, 2943, 1),
+    (0x24B8, 2944, 1),
+    (0x24B9, 2945, 1),
+    (0x24BA, 2946, 1),
+    (0x24BB, 2947, 1),
+    (0x24BC, 2948, 1),
+    (0x24BD, 2949, 1),
+    (0x24BE, 2950, 1),
+    (0x24BF, 2951, 1),
+    (0x24C0, 2952, 1),
+    (0x24C1, 2953, 1),
+    (0x24C2, 2954, 1),
+    (0x24C3, 2955, 1),
+    (0x24C4, 2956, 1),
+    (0x24C5, 2957, 1),
+    (0x24C6, 2958, 1),
+    (0x24C7, 2959, 1),
+    (0x24C8, 2960, 1),
+    (0x24C9, 2961, 1),
+    (0x24CA, 2962, 1),
+    (0x24CB, 2963, 1),
+    (0x24CC, 2964, 1),
+    (0x24CD, 2965, 1),
+    (0x24CE, 2966, 1),
+    (0x24CF, 2967, 1),
+    (0x24D0, 2968, 1),
+    (0x24D1, 2969, 1),
+    (0x24D2, 2970, 1),
+    (0x24D3, 2971, 1),
+    (0x24D4, 2972, 1),
+    (0x24D5, 2973, 1),
+    (0x24D6, 2974, 1),
+    (0x24D7, 2975, 1),
+    (0x24D8, 2976, 1),
+    (0x24D9, 2977, 1),
+    (0x24DA, 2978, 1),
+    (0x24DB, 2979, 1),
+    (0x24DC, 2980, 1),
+    (0x24DD, 2981, 1),
+    (0x24DE, 2982, 1),
+    (0x24DF, 2983, 1),
+    (0x24E0, 2984, 1),
+    (0x24E1, 2985, 1),
+    (0x24E2, 2986, 1),
+    (0x24E3, 2987, 1),
+    (0x24E4, 2988, 1),
+    (0x24E5, 2989, 1),
+    (0x24E6, 2990, 1),
+    (0x24E7, 2991, 1),
+    (0x24E8, 2992, 1),
+    (0x24E9, 2993, 1),
+    (0x24EA, 2994, 1),
+    (0x2A0C, 2995, 4),
+    (0x2A74, 2999, 3),
+    (0x2A75, 3002, 2),
+    (0x2A76, 3004, 3),
+    (0x2C7C, 3009, 1),
+    (0x2C7D, 3010, 1),
+    (0x2D6F, 3011, 1),
+    (0x2E9F, 3012, 1),
+    (0x2EF3, 3013, 1),
+    (0x2F00, 3014, 1),
+    (0x2F01, 3015, 1),
+    (0x2F02, 3016, 1),
+    (0x2F03, 3017, 1),
+    (0x2F04, 3018, 1),
+    (0x2F05, 3019, 1),
+    (0x2F06, 3020, 1),
+    (0x2F07, 3021, 1),
+    (0x2F08, 3022, 1),
+    (0x2F09, 3023, 1),
+    (0x2F0A, 3024, 1),
+    (0x2F0B, 3025, 1),
+    (0x2F0C, 3026, 1),
+    (0x2F0D, 3027, 1),
+    (0x2F0E, 3028, 1),
+    (0x2F0F, 3029, 1),
+    (0x2F10, 3030, 1),
+    (0x2F11, 3031, 1),
+    (0x2F12, 3032, 1),
+    (0x2F13, 3033, 1),
+    (0x2F14, 3034, 1),
+    (0x2F15, 3035, 1),
+    (0x2F16, 3036, 1),
+    (0x2F17, 3037, 1),
+    (0x2F18, 3038, 1),
+    (0x2F19, 3039, 1),
+    (0x2F1A, 3040, 1),
+    (0x2F1B, 3041, 1),
+    (0x2F1C, 3042, 1),
+    (0x2F1D, 3043, 1),
+    (0x2F1E, 3044, 1),
+    (0x2F1F, 3045, 1),
+    (0x2F20, 3046, 1),
+    (0x2F21, 3047, 1),
+    (0x2F22, 3048, 1),
+    (0x2F23, 3049, 1),
+    (0x2F24, 3050, 1),
+    (0x2F25, 3051, 1),
+    (0x2F26, 3052, 1),
+    (0x2F27, 3053, 1),
+    (0x2F28, 3054, 1),
+    (0x2F29, 3055, 1),
+    (0x2F2A, 3056, 1),
+    (0x2F2B, 3057, 1),
+    (0x2F2C, 3058, 1),
+    (0x2F2D, 3059, 1),
+    (0x2F2E, 3060, 1),
+    (0x2F2F, 3061, 1),
+    (0x2F30, 3062, 1),
+    (0x2F31, 3063, 1),
+    (0x2F32, 3064, 1),
+    (0x2F33, 3065, 1),
+    (0x2F34, 3066, 1),
+    (0x2F35, 3067, 1),
+    (0x2F36, 3068, 1),
+    (0x2F37, 3069, 1),
+    (0x2F38, 3070, 1),
+    (0x2F39, 3071, 1),
+    (0x2F3A, 3072, 1),
+    (0x2F3B, 3073, 1),
+    (0x2F3C, 3074, 1),
+    (0x2F3D, 3075, 1),
+    (0x2F3E, 3076, 1),
+    (0x2F3F, 3077, 1),
+    (0x2F40, 3078, 1),
+    (0x2F41, 3079, 1),
+    (0x2F42, 3080, 1),
+    (0x2F43, 3081, 1),
+    (0x2F44, 3082, 1),
+    (0x2F45, 3083, 1),
+    (0x2F46, 3084, 1),
+    (0x2F47, 3085, 1),
+    (0x2F48, 3086, 1),
+    (0x2F49, 3087, 1),
+    (0x2F4A, 3088, 1),
+    (0x2F4B, 3089, 1),
+    (0x2F4C, 3090, 1),
+    (0x2F4D, 3091, 1),
+    (0x2F4E, 3092, 1),
+    (0x2F4F, 3093, 1),
+    (0x2F50, 3094, 1),
+    (0x2F51, 3095, 1),
+    (0x2F52, 3096, 1),
+    (0x2F53, 3097, 1),
+    (0x2F54, 3098, 1),
+    (0x2F55, 3099, 1),
+    (0x2F56, 3100, 1),
+    (0x2F57, 3101, 1),
+    (0x2F58, 3102, 1),
+    (0x2F59, 3103, 1),
+    (0x2F5A, 3104, 1),
+    (0x2F5B, 3105, 1),
+    (0x2F5C, 3106, 1),
+    (0x2F5D, 3107, 1),
+    (0x2F5E, 3108, 1),
+    (0x2F5F, 3109, 1),
+    (0x2F60, 3110, 1),
+    (0x2F61, 3111, 1),
+    (0x2F62, 3112, 1),
+    (0x2F63, 3113, 1),
+    (0x2F64, 3114, 1),
+    (0x2F65, 3115, 1),
+    (0x2F66, 3116, 1),
+    (0x2F67, 3117, 1),
+    (0x2F68, 3118, 1),
+    (0x2F69, 3119, 1),
+    (0x2F6A, 3120, 1),
+    (0x2F6B, 3121, 1),
+    (0x2F6C, 3122, 1),
+    (0x2F6D, 3123, 1),
+    (0x2F6E, 3124, 1),
+    (0x2F6F, 3125, 1),
+    (0x2F70, 3126, 1),
+    (0x2F71, 3127, 1),
+    (0x2F72, 3128, 1),
+    (0x2F73, 3129, 1),
+    (0x2F74, 3130, 1),
+    (0x2F75, 3131, 1),
+    (0x2F76, 3132, 1),
+    (0x2F77, 3133, 1),
+    (0x2F78, 3134, 1),
+    (0x2F79, 3135, 1),
+    (0x2F7A, 3136, 1),
+    (0x2F7B, 3137, 1),
+    (0x2F7C, 3138, 1),
+    (0x2F7D, 3139, 1),
+    (0x2F7E, 3140, 1),
+    (0x2F7F, 3141, 1),
+    (0x2F80, 3142, 1),
+    (0x2F81, 3143, 1),
+    (0x2F82, 3144, 1),
+    (0x2F83, 3145, 1),
+    (0x2F84, 3146, 1),
+    (0x2F85, 3147, 1),
+    (0x2F86, 3148, 1),
+    (0x2F87, 3149, 1),
+    (0x2F88, 3150, 1),
+    (0x2F89, 3151, 1),
+    (0x2F8A, 3152, 1),
+    (0x2F8B, 3153, 1),
+    (0x2F8C, 3154, 1),
+    (0x2F8D, 3155, 1),
+    (0x2F8E, 3156, 1),
+    (0x2F8F, 3157, 1),
+    (0x2F90, 3158, 1),
+    (0x2F91, 3159, 1),
+    (0x2F92, 3160, 1),
+    (0x2F93, 3161, 1),
+    (0x2F94, 3162, 1),
+    (0x2F95, 3163, 1),
+    (0x2F96, 3164, 1),
+    (0x2F97, 3165, 1),
+    (0x2F98, 3166, 1),
+    (0x2F99, 3167, 1),
+    (0x2F9A, 3168, 1),
+    (0x2F9B, 3169, 1),
+    (0x2F9C, 3170, 1),
+    (0x2F9D, 3171, 1),
+    (0x2F9E, 3172, 1),
+    (0x2F9F, 3173, 1),
+    (0x2FA0, 3174, 1),
+    (0x2FA1, 3175, 1),
+    (0x2FA2, 3176, 1),
+    (0x2FA3, 3177, 1),
+    (0x2FA4, 3178, 1),
+    (0x2FA5, 3179, 1),
+    (0x2FA6, 3180, 1),
+    (0x2FA7, 3181, 1),
+    (0x2FA8, 3182, 1),
+    (0x2FA9, 3183, 1),
+    (0x2FAA, 3184, 1),
+    (0x2FAB, 3185, 1),
+    (0x2FAC, 3186, 1),
+    (0x2FAD, 3187, 1),
+    (0x2FAE, 3188, 1),
+    (0x2FAF, 3189, 1),
+    (0x2FB0, 3190, 1),
+    (0x2FB1, 3191, 1),
+    (0x2FB2, 3192, 1),
+    (0x2FB3, 3193, 1),
+    (0x2FB4, 3194, 1),
+    (0x2FB5, 3195, 1),
+    (0x2FB6, 3196, 1),
+    (0x2FB7, 3197, 1),
+    (0x2FB8, 3198, 1),
+    (0x2FB9, 3199, 1),
+    (0x2FBA, 3200, 1),
+    (0x2FBB, 3201, 1),
+    (0x2FBC, 3202, 1),
+    (0x2FBD, 3203, 1),
+    (0x2FBE, 3204, 1),
+    (0x2FBF, 3205, 1),
+    (0x2FC0, 3206, 1),
+    (0x2FC1, 3207, 1),
+    (0x2FC2, 3208, 1),
+    (0x2FC3, 3209, 1),
+    (0x2FC4, 3210, 1),
+    (0x2FC5, 3211, 1),
+    (0x2FC6, 3212, 1),
+    (0x2FC7, 3213, 1),
+    (0x2FC8, 3214, 1),
+    (0x2FC9, 3215, 1),
+    (0x2FCA, 3216, 1),
+    (0x2FCB, 3217, 1),
+    (0x2FCC, 3218, 1),
+    (0x2FCD, 3219, 1),
+    (0x2FCE, 3220, 1),
+    (0x2FCF, 3221, 1),
+    (0x2FD0, 3222, 1),
+    (0x2FD1, 3223, 1),
+    (0x2FD2, 3224, 1),
+    (0x2FD3, 3225, 1),
+    (0x2FD4, 3226, 1),
+    (0x2FD5, 3227, 1),
+    (0x3000, 3228, 1),
+    (0x3036, 3229, 1),
+    (0x3038, 3230, 1),
+    (0x3039, 3231, 1),
+    (0x303A, 3232, 1),
+    (0x309B, 3285, 2),
+    (0x309C, 3287, 2),
+    (0x309F, 3291, 2),
+    (0x30FF, 3355, 2),
+    (0x3131, 3357, 1),
+    (0x3132, 3358, 1),
+    (0x3133, 3359, 1),
+    (0x3134, 3360, 1),
+    (0x3135, 3361, 1),
+    (0x3136, 3362, 1),
+    (0x3137, 3363, 1),
+    (0x3138, 3364, 1),
+    (0x3139, 3365, 1),
+    (0x313A, 3366, 1),
+    (0x313B, 3367, 1),
+    (0x313C, 3368, 1),
+    (0x313D, 3369, 1),
+    (0x313E, 3370, 1),
+    (0x313F, 3371, 1),
+    (0x3140, 3372, 1),
+    (0x3141, 3373, 1),
+    (0x3142, 3374, 1),
+    (0x3143, 3375, 1),
+    (0x3144, 3376, 1),
+    (0x3145, 3377, 1),
+    (0x3146, 3378, 1),
+    (0x3147, 3379, 1),
+    (0x3148, 3380, 1),
+    (0x3149, 3381, 1),
+    (0x314A, 3382, 1),
+    (0x314B, 3383, 1),
+    (0x314C, 3384, 1),
+    (0x314D, 3385, 1),
+    (0x314E, 3386, 1),
+    (0x314F, 3387, 1),
+    (0x3150, 3388, 1),
+    (0x3151, 3389, 1),
+    (0x3152, 3390, 1),
+    (0x3153, 3391, 1),
+    (0x3154, 3392, 1),
+    (0x3155, 3393, 1),
+    (0x3156, 3394, 1),
+    (0x3157, 3395, 1),
+    (0x3158, 3396, 1),
+    (0x3159, 3397, 1),
+    (0x315A, 3398, 1),
+    (0x315B, 3399, 1),
+    (0x315C, 3400, 1),
+    (0x315D, 3401, 1),
+    (0x315E, 3402, 1),
+    (0x315F, 3403, 1),
+    (0x3160, 3404, 1),
+    (0x3161, 3405, 1),
+    (0x3162, 3406, 1),
+    (0x3163, 3407, 1),
+    (0x3164, 3408, 1),
+    (0x3165, 3409, 1),
+    (0x3166, 3410, 1),
+    (0x3167, 3411, 1),
+    (0x3168, 3412, 1),
+    (0x3169, 3413, 1),
+    (0x316A, 3414, 1),
+    (0x316B, 3415, 1),
+    (0x316C, 3416, 1),
+    (0x316D, 3417, 1),
+    (0x316E, 3418, 1),
+    (0x316F, 3419, 1),
+    (0x3170, 3420, 1),
+    (0x3171, 3421, 1),
+    (0x3172, 3422, 1),
+    (0x3173, 3423, 1),
+    (0x3174, 3424, 1),
+    (0x3175, 3425, 1),
+    (0x3176, 3426, 1),
+    (0x3177, 3427, 1),
+    (0x3178, 3428, 1),
+    (0x3179, 3429, 1),
+    (0x317A, 3430, 1),
+    (0x317B, 3431, 1),
+    (0x317C, 3432, 1),
+    (0x317D, 3433, 1),
+    (0x317E, 3434, 1),
+    (0x317F, 3435, 1),
+    (0x3180, 3436, 1),
+    (0x3181, 3437, 1),
+    (0x3182, 3438, 1),
+    (0x3183, 3439, 1),
+    (0x3184, 3440, 1),
+    (0x3185, 3441, 1),
+    (0x3186, 3442, 1),
+    (0x3187, 3443, 1),
+    (0x3188, 3444, 1),
+    (0x3189, 3445, 1),
+    (0x318A, 3446, 1),
+    (0x318B, 3447, 1),
+    (0x318C, 3448, 1),
+    (0x318D, 3449, 1),
+    (0x318E, 3450, 1),
+    (0x3192, 3451, 1),
+    (0x3193, 3452, 1),
+    (0x3194, 3453, 1),
+    (0x3195, 3454, 1),
+    (0x3196, 3455, 1),
+    (0x3197, 3456, 1),
+    (0x3198, 3457, 1),
+    (0x3199, 3458, 1),
+    (0x319A, 3459, 1),
+    (0x319B, 3460, 1),
+    (0x319C, 3461, 1),
+    (0x319D, 3462, 1),
+    (0x319E, 3463, 1),
+    (0x319F, 3464, 1),
+    (0x3200, 3465, 3),
+    (0x3201, 3468, 3),
+    (0x3202, 3471, 3),
+    (0x3203, 3474, 3),
+    (0x3204, 3477, 3),
+    (0x3205, 3480, 3),
+    (0x3206, 3483, 3),
+    (0x3207, 3486, 3),
+    (0x3208, 3489, 3),
+    (0x3209, 3492, 3),
+    (0x320A, 3495, 3),
+    (0x320B, 3498, 3),
+    (0x320C, 3501, 3),
+    (0x320D, 3504, 3),
+    (0x320E, 3507, 4),
+    (0x320F, 3511, 4),
+    (0x3210, 3515, 4),
+    (0x3211, 3519, 4),
+    (0x3212, 3523, 4),
+    (0x3213, 3527, 4),
+    (0x3214, 3531, 4),
+    (0x3215, 3535, 4),
+    (0x3216, 3539, 4),
+    (0x3217, 3543, 4),
+    (0x3218, 3547, 4),
+    (0x3219, 3551, 4),
+    (0x321A, 3555, 4),
+    (0x321B, 3559, 4),
+    (0x321C, 3563, 4),
+    (0x321D, 3567, 7),
+    (0x321E, 3574, 6),
+    (0x3220, 3580, 3),
+    (0x3221, 3583, 3),
+    (0x3222, 3586, 3),
+    (0x3223, 3589, 3),
+    (0x3224, 3592, 3),
+    (0x3225, 3595, 3),
+    (0x3226, 3598, 3),
+    (0x3227, 3601, 3),
+    (0x3228, 3604, 3),
+    (0x3229, 3607, 3),
+    (0x322A, 3610, 3),
+    (0x322B, 3613, 3),
+    (0x322C, 3616, 3),
+    (0x322D, 3619, 3),
+    (0x322E, 3622, 3),
+    (0x322F, 3625, 3),
+    (0x3230, 3628, 3),
+    (0x3231, 3631, 3),
+    (0x3232, 3634, 3),
+    (0x3233, 3637, 3),
+    (0x3234, 3640, 3),
+    (0x3235, 3643, 3),
+    (0x3236, 3646, 3),
+    (0x3237, 3649, 3),
+    (0x3238, 3652, 3),
+    (0x3239, 3655, 3),
+    (0x323A, 3658, 3),
+    (0x323B, 3661, 3),
+    (0x323C, 3664, 3),
+    (0x323D, 3667, 3),
+    (0x323E, 3670, 3),
+    (0x323F, 3673, 3),
+    (0x3240, 3676, 3),
+    (0x3241, 3679, 3),
+    (0x3242, 3682, 3),
+    (0x3243, 3685, 3),
+    (0x3244, 3688, 1),
+    (0x3245, 3689, 1),
+    (0x3246, 3690, 1),
+    (0x3247, 3691, 1),
+    (0x3250, 3692, 3),
+    (0x3251, 3695, 2),
+    (0x3252, 3697, 2),
+    (0x3253, 3699, 2),
+    (0x3254, 3701, 2),
+    (0x3255, 3703, 2),
+    (0x3256, 3705, 2),
+    (0x3257, 3707, 2),
+    (0x3258, 3709, 2),
+    (0x3259, 3711, 2),
+    (0x325A, 3713, 2),
+    (0x325B, 3715, 2),
+    (0x325C, 3717, 2),
+    (0x325D, 3719, 2),
+    (0x325E, 3721, 2),
+    (0x325F, 3723, 2),
+    (0x3260, 3725, 1),
+    (0x3261, 3726, 1),
+    (0x3262, 3727, 1),
+    (0x3263, 3728, 1),
+    (0x3264, 3729, 1),
+    (0x3265, 3730, 1),
+    (0x3266, 3731, 1),
+    (0x3267, 3732, 1),
+    (0x3268, 3733, 1),
+    (0x3269, 3734, 1),
+    (0x326A, 3735, 1),
+    (0x326B, 3736, 1),
+    (0x326C, 3737, 1),
+    (0x326D, 3738, 1),
+    (0x326E, 3739, 2),
+    (0x326F, 3741, 2),
+    (0x3270, 3743, 2),
+    (0x3271, 3745, 2),
+    (0x3272, 3747, 2),
+    (0x3273, 3749, 2),
+    (0x3274, 3751, 2),
+    (0x3275, 3753, 2),
+    (0x3276, 3755, 2),
+    (0x3277, 3757, 2),
+    (0x3278, 3759, 2),
+    (0x3279, 3761, 2),
+    (0x327A, 3763, 2),
+    (0x327B, 3765, 2),
+    (0x327C, 3767, 5),
+    (0x327D, 3772, 4),
+    (0x327E, 3776, 2),
+    (0x3280, 3778, 1),
+    (0x3281, 3779, 1),
+    (0x3282, 3780, 1),
+    (0x3283, 3781, 1),
+    (0x3284, 3782, 1),
+    (0x3285, 3783, 1),
+    (0x3286, 3784, 1),
+    (0x3287, 3785, 1),
+    (0x3288, 3786, 1),
+    (0x3289, 3787, 1),
+    (0x328A, 3788, 1),
+    (0x328B, 3789, 1),
+    (0x328C, 3790, 1),
+    (0x328D, 3791, 1),
+    (0x328E, 3792, 1),
+    (0x328F, 3793, 1),
+    (0x3290, 3794, 1),
+    (0x3291, 3795, 1),
+    (0x3292, 3796, 1),
+    (0x3293, 3797, 1),
+    (0x3294, 3798, 1),
+    (0x3295, 3799, 1),
+    (0x3296, 3800, 1),
+    (0x3297, 3801, 1),
+    (0x3298, 3802, 1),
+    (0x3299, 3803, 1),
+    (0x329A, 3804, 1),
+    (0x329B, 3805, 1),
+    (0x329C, 3806, 1),
+    (0x329D, 3807, 1),
+    (0x329E, 3808, 1),
+    (0x329F, 3809, 1),
+    (0x32A0, 3810, 1),
+    (0x32A1, 3811, 1),
+    (0x32A2, 3812, 1),
+    (0x32A3, 3813, 1),
+    (0x32A4, 3814, 1),
+    (0x32A5, 3815, 1),
+    (0x32A6, 3816, 1),
+    (0x32A7, 3817, 1),
+    (0x32A8, 3818, 1),
+    (0x32A9, 3819, 1),
+    (0x32AA, 3820, 1),
+    (0x32AB, 3821, 1),
+    (0x32AC, 3822, 1),
+    (0x32AD, 3823, 1),
+    (0x32AE, 3824, 1),
+    (0x32AF, 3825, 1),
+    (0x32B0, 3826, 1),
+    (0x32B1, 3827, 2),
+    (0x32B2, 3829, 2),
+    (0x32B3, 3831, 2),
+    (0x32B4, 3833, 2),
+    (0x32B5, 3835, 2),
+    (0x32B6, 3837, 2),
+    (0x32B7, 3839, 2),
+    (0x32B8, 3841, 2),
+    (0x32B9, 3843, 2),
+    (0x32BA, 3845, 2),
+    (0x32BB, 3847, 2),
+    (0x32BC, 3849, 2),
+    (0x32BD, 3851, 2),
+    (0x32BE, 3853, 2),
+    (0x32BF, 3855, 2),
+    (0x32C0, 3857, 2),
+    (0x32C1, 3859, 2),
+    (0x32C2, 3861, 2),
+    (0x32C3, 3863, 2),
+    (0x32C4, 3865, 2),
+    (0x32C5, 3867, 2),
+    (0x32C6, 3869, 2),
+    (0x32C7, 3871, 2),
+    (0x32C8, 3873, 2),
+    (0x32C9, 3875, 3),
+    (0x32CA, 3878, 3),
+    (0x32CB, 3881, 3),
+    (0x32CC, 3884, 2),
+    (0x32CD, 3886, 3),
+    (0x32CE, 3889, 2),
+    (0x32CF, 3891, 3),
+    (0x32D0, 3894, 1),
+    (0x32D1, 3895, 1),
+    (0x32D2, 3896, 1),
+    (0x32D3, 3897, 1),
+    (0x32D4, 3898, 1),
+    (0x32D5, 3899, 1),
+    (0x32D6, 3900, 1),
+    (0x32D7, 3901, 1),
+    (0x32D8, 3902, 1),
+    (0x32D9, 3903, 1),
+    (0x32DA, 3904, 1),
+    (0x32DB, 3905, 1),
+    (0x32DC, 3906, 1),
+    (0x32DD, 3907, 1),
+    (0x32DE, 3908, 1),
+    (0x32DF, 3909, 1),
+    (0x32E0, 3910, 1),
+    (0x32E1, 3911, 1),
+    (0x32E2, 3912, 1),
+    (0x32E3, 3913, 1),
+    (0x32E4, 3914, 1),
+    (0x32E5, 3915, 1),
+    (0x32E6, 3916, 1),
+    (0x32E7, 3917, 1),
+    (0x32E8, 3918, 1),
+    (0x32E9, 3919, 1),
+    (0x32EA, 3920, 1),
+    (0x32EB, 3921, 1),
+    (0x32EC, 3922, 1),
+    (0x32ED, 3923, 1),
+    (0x32EE, 3924, 1),
+    (0x32EF, 3925, 1),
+    (0x32F0, 3926, 1),
+    (0x32F1, 3927, 1),
+    (0x32F2, 3928, 1),
+    (0x32F3, 3929, 1),
+    (0x32F4, 3930, 1),
+    (0x32F5, 3931, 1),
+    (0x32F6, 3932, 1),
+    (0x32F7, 3933, 1),
+    (0x32F8, 3934, 1),
+    (0x32F9, 3935, 1),
+    (0x32FA, 3936, 1),
+    (0x32FB, 3937, 1),
+    (0x32FC, 3938, 1),
+    (0x32FD, 3939, 1),
+    (0x32FE, 3940, 1),
+    (0x32FF, 3941, 2),
+    (0x3300, 3943, 5),
+    (0x3301, 3948, 4),
+    (0x3302, 3952, 5),
+    (0x3303, 3957, 3),
+    (0x3304, 3960, 5),
+    (0x3305, 3965, 3),
+    (0x3306, 3968, 3),
+    (0x3307, 3971, 6),
+    (0x3308, 3977, 4),
+    (0x3309, 3981, 3),
+    (0x330A, 3984, 3),
+    (0x330B, 3987, 3),
+    (0x330C, 3990, 4),
+    (0x330D, 3994, 4),
+    (0x330E, 3998, 4),
+    (0x330F, 4002, 4),
+    (0x3310, 4006, 4),
+    (0x3311, 4010, 4),
+    (0x3312, 4014, 4),
+    (0x3313, 4018, 6),
+    (0x3314, 4024, 2),
+    (0x3315, 4026, 6),
+    (0x3316, 4032, 6),
+    (0x3317, 4038, 5),
+    (0x3318, 4043, 4),
+    (0x3319, 4047, 6),
+    (0x331A, 4053, 6),
+    (0x331B, 4059, 4),
+    (0x331C, 4063, 3),
+    (0x331D, 4066, 3),
+    (0x331E, 4069, 4),
+    (0x331F, 4073, 4),
+    (0x3320, 4077, 5),
+    (0x3321, 4082, 5),
+    (0x3322, 4087, 3),
+    (0x3323, 4090, 3),
+    (0x3324, 4093, 4),
+    (0x3325, 4097, 3),
+    (0x3326, 4100, 3),
+    (0x3327, 4103, 2),
+    (0x3328, 4105, 2),
+    (0x3329, 4107, 3),
+    (0x332A, 4110, 3),
+    (0x332B, 4113, 6),
+    (0x332C, 4119, 4),
+    (0x332D, 4123, 5),
+    (0x332E, 4128, 6),
+    (0x332F, 4134, 4),
+    (0x3330, 4138, 3),
+    (0x3331, 4141, 3),
+    (0x3332, 4144, 6),
+    (0x3333, 4150, 4),
+    (0x3334, 4154, 6),
+    (0x3335, 4160, 3),
+    (0x3336, 4163, 5),
+    (0x3337, 4168, 3),
+    (0x3338, 4171, 4),
+    (0x3339, 4175, 3),
+    (0x333A, 4178, 4),
+    (0x333B, 4182, 5),
+    (0x333C, 4187, 4),
+    (0x333D, 4191, 5),
+    (0x333E, 4196, 4),
+    (0x333F, 4200, 2),
+    (0x3340, 4202, 5),
+    (0x3341, 4207, 3),
+    (0x3342, 4210, 3),
+    (0x3343, 4213, 4),
+    (0x3344, 4217, 3),
+    (0x3345, 4220, 3),
+    (0x3346, 4223, 3),
+    (0x3347, 4226, 5),
+    (0x3348, 4231, 4),
+    (0x3349, 4235, 2),
+    (0x334A, 4237, 6),
+    (0x334B, 4243, 3),
+    (0x334C, 4246, 5),
+    (0x334D, 4251, 4),
+    (0x334E, 4255, 4),
+    (0x334F, 4259, 3),
+    (0x3350, 4262, 3),
+    (0x3351, 4265, 4),
+    (0x3352, 4269, 2),
+    (0x3353, 4271, 4),
+    (0x3354, 4275, 5),
+    (0x3355, 4280, 2),
+    (0x3356, 4282, 6),
+    (0x3357, 4288, 3),
+    (0x3358, 4291, 2),
+    (0x3359, 4293, 2),
+    (0x335A, 4295, 2),
+    (0x335B, 4297, 2),
+    (0x335C, 4299, 2),
+    (0x335D, 4301, 2),
+    (0x335E, 4303, 2),
+    (0x335F, 4305, 2),
+    (0x3360, 4307, 2),
+    (0x3361, 4309, 2),
+    (0x3362, 4311, 3),
+    (0x3363, 4314, 3),
+    (0x3364, 4317, 3),
+    (0x3365, 4320, 3),
+    (0x3366, 4323, 3),
+    (0x3367, 4326, 3),
+    (0x3368, 4329, 3),
+    (0x3369, 4332, 3),
+    (0x336A, 4335, 3),
+    (0x336B, 4338, 3),
+    (0x336C, 4341, 3),
+    (0x336D, 4344, 3),
+    (0x336E, 4347, 3),
+    (0x336F, 4350, 3),
+    (0x3370, 4353, 3),
+    (0x3371, 4356, 3),
+    (0x3372, 4359, 2),
+    (0x3373, 4361, 2),
+    (0x3374, 4363, 3),
+    (0x3375, 4366, 2),
+    (0x3376, 4368, 2),
+    (0x3377, 4370, 2),
+    (0x3378, 4372, 3),
+    (0x3379, 4375, 3),
+    (0x337A, 4378, 2),
+    (0x337B, 4380, 2),
+    (0x337C, 4382, 2),
+    (0x337D, 4384, 2),
+    (0x337E, 4386, 2),
+    (0x337F, 4388, 4),
+    (0x3380, 4392, 2),
+    (0x3381, 4394, 2),
+    (0x3382, 4396, 2),
+    (0x3383, 4398, 2),
+    (0x3384, 4400, 2),
+    (0x3385, 4402, 2),
+    (0x3386, 4404, 2),
+    (0x3387, 4406, 2),
+    (0x3388, 4408, 3),
+    (0x3389, 4411, 4),
+    (0x338A, 4415, 2),
+    (0x338B, 4417, 2),
+    (0x338C, 4419, 2),
+    (0x338D, 4421, 2),
+    (0x338E, 4423, 2),
+    (0x338F, 4425, 2),
+    (0x3390, 4427, 2),
+    (0x3391, 4429, 3),
+    (0x3392, 4432, 3),
+    (0x3393, 4435, 3),
+    (0x3394, 4438, 3),
+    (0x3395, 4441, 2),
+    (0x3396, 4443, 2),
+    (0x3397, 4445, 2),
+    (0x3398, 4447, 2),
+    (0x3399, 4449, 2),
+    (0x339A, 4451, 2),
+    (0x339B, 4453, 2),
+    (0x339C, 4455, 2),
+    (0x339D, 4457, 2),
+    (0x339E, 4459, 2),
+    (0x339F, 4461, 3),
+    (0x33A0, 4464, 3),
+    (0x33A1, 4467, 2),
+    (0x33A2, 4469, 3),
+    (0x33A3, 4472, 3),
+    (0x33A4, 4475, 3),
+    (0x33A5, 4478, 2),
+    (0x33A6, 4480, 3),
+    (0x33A7, 4483, 3),
+    (0x33A8, 4486, 4),
+    (0x33A9, 4490, 2),
+    (0x33AA, 4492, 3),
+    (0x33AB, 4495, 3),
+    (0x33AC, 4498, 3),
+    (0x33AD, 4501, 3),
+    (0x33AE, 4504, 5),
+    (0x33AF, 4509, 6),
+    (0x33B0, 4515, 2),
+    (0x33B1, 4517, 2),
+    (0x33B2, 4519, 2),
+    (0x33B3, 4521, 2),
+    (0x33B4, 4523, 2),
+    (0x33B5, 4525, 2),
+    (0x33B6, 4527, 2),
+    (0x33B7, 4529, 2),
+    (0x33B8, 4531, 2),
+    (0x33B9, 4533, 2),
+    (0x33BA, 4535, 2),
+    (0x33BB, 4537, 2),
+    (0x33BC, 4539, 2),
+    (0x33BD, 4541, 2),
+    (0x33BE, 4543, 2),
+    (0x33BF, 4545, 2),
+    (0x33C0, 4547, 2),
+    (0x33C1, 4549, 2),
+    (0x33C2, 4551, 4),
+    (0x33C3, 4555, 2),
+    (0x33C4, 4557, 2),
+    (0x33C5, 4559, 2),
+    (0x33C6, 4561, 4),
+    (0x33C7, 4565, 3),
+    (0x33C8, 4568, 2),
+    (0x33C9, 4570, 2),
+    (0x33CA, 4572, 2),
+    (0x33CB, 4574, 2),
+    (0x33CC, 4576, 2),
+    (0x33CD, 4578, 2),
+    (0x33CE, 4580, 2),
+    (0x33CF, 4582, 2),
+    (0x33D0, 4584, 2),
+    (0x33D1, 4586, 2),
+    (0x33D2, 4588, 3),
+    (0x33D3, 4591, 2),
+    (0x33D4, 4593, 2),
+    (0x33D5, 4595, 3),
+    (0x33D6, 4598, 3),
+    (0x33D7, 4601, 2),
+    (0x33D8, 4603, 4),
+    (0x33D9, 4607, 3),
+    (0x33DA, 4610, 2),
+    (0x33DB, 4612, 2),
+    (0x33DC, 4614, 2),
+    (0x33DD, 4616, 2),
+    (0x33DE, 4618, 3),
+    (0x33DF, 4621, 3),
+    (0x33E0, 4624, 2),
+    (0x33E1, 4626, 2),
+    (0x33E2, 4628, 2),
+    (0x33E3, 4630, 2),
+    (0x33E4, 4632, 2),
+    (0x33E5, 4634, 2),
+    (0x33E6, 4636, 2),
+    (0x33E7, 4638, 2),
+    (0x33E8, 4640, 2),
+    (0x33E9, 4642, 3),
+    (0x33EA, 4645, 3),
+    (0x33EB, 4648, 3),
+    (0x33EC, 4651, 3),
+    (0x33ED, 4654, 3),
+    (0x33EE, 4657, 3),
+    (0x33EF, 4660, 3),
+    (0x33F0, 4663, 3),
+    (0x33F1, 4666, 3),
+    (0x33F2, 4669, 3),
+    (0x33F3, 4672, 3),
+    (0x33F4, 4675, 3),
+    (0x33F5, 4678, 3),
+    (0x33F6, 4681, 3),
+    (0x33F7, 4684, 3),
+    (0x33F8, 4687, 3),
+    (0x33F9, 4690, 3),
+    (0x33FA, 4693, 3),
+    (0x33FB, 4696, 3),
+    (0x33FC, 4699, 3),
+    (0x33FD, 4702, 3),
+    (0x33FE, 4705, 3),
+    (0x33FF, 4708, 3),
+    (0xA69C, 4711, 1),
+    (0xA69D, 4712, 1),
+    (0xA770, 4713, 1),
+    (0xA7F8, 4714, 1),
+    (0xA7F9, 4715, 1),
+    (0xAB5C, 4716, 1),
+    (0xAB5D, 4717, 1),
+    (0xAB5E, 4718, 1),
+    (0xAB5F, 4719, 1),
+    (0xAB69, 4720, 1),
+    (0xFB00, 5181, 2),
+    (0xFB01, 5183, 2),
+    (0xFB02, 5185, 2),
+    (0xFB03, 5187, 3),
+    (0xFB04, 5190, 3),
+    (0xFB05, 5193, 2),
+    (0xFB06, 5195, 2),
+    (0xFB13, 5197, 2),
+    (0xFB14, 5199, 2),
+    (0xFB15, 5201, 2),
+    (0xFB16, 5203, 2),
+    (0xFB17, 5205, 2),
+    (0xFB20, 5211, 1),
+    (0xFB21, 5212, 1),
+    (0xFB22, 5213, 1),
+    (0xFB23, 5214, 1),
+    (0xFB24, 5215, 1),
+    (0xFB25, 5216, 1),
+    (0xFB26, 5217, 1),
+    (0xFB27, 5218, 1),
+    (0xFB28, 5219, 1),
+    (0xFB29, 5220, 1),
+    (0xFB4F, 5287, 2),
+    (0xFB50, 5289, 1),
+    (0xFB51, 5290, 1),
+    (0xFB52, 5291, 1),
+    (0xFB53, 5292, 1),
+    (0xFB54, 5293, 1),
+    (0xFB55, 5294, 1),
+    (0xFB56, 5295, 1),
+    (0xFB57, 5296, 1),
+    (0xFB58, 5297, 1),
+    (0xFB59, 5298, 1),
+    (0xFB5A, 5299, 1),
+    (0xFB5B, 5300, 1),
+    (0xFB5C, 5301, 1),
+    (0xFB5D, 5302, 1),
+    (0xFB5E, 5303, 1),
+    (0xFB5F, 5304, 1),
+    (0xFB60, 5305, 1),
+    (0xFB61, 5306, 1),
+    (0xFB62, 5307, 1),
+    (0xFB63, 5308, 1),
+    (0xFB64, 5309, 1),
+    (0xFB65, 5310, 1),
+    (0xFB66, 5311, 1),
+    (0xFB67, 5312, 1),
+    (0xFB68, 5313, 1),
+    (0xFB69, 5314, 1),
+    (0xFB6A, 5315, 1),
+    (0xFB6B, 5316, 1),
+    (0xFB6C, 5317, 1),
+    (0xFB6D, 5318, 1),
+    (0xFB6E, 5319, 1),
+    (0xFB6F, 5320, 1),
+    (0xFB70, 5321, 1),
+    (0xFB71, 5322, 1),
+    (0xFB72, 5323, 1),
+    (0xFB73, 5324, 1),
+    (0xFB74, 5325, 1),
+    (0xFB75, 5326, 1),
+    (0xFB76, 5327, 1),
+    (0xFB77, 5328, 1),
+    (0xFB78, 5329, 1),
+    (0xFB79, 5330, 1),
+    (0xFB7A, 5331, 1),
+    (0xFB7B, 5332, 1),
+    (0xFB7C, 5333, 1),
+    (0xFB7D, 5334, 1),
+    (0xFB7E, 5335, 1),
+    (0xFB7F, 5336, 1),
+    (0xFB80, 5337, 1),
+    (0xFB81, 5338, 1),
+    (0xFB82, 5339, 1),
+    (0xFB83, 5340, 1),
+    (0xFB84, 5341, 1),
+    (0xFB85, 5342, 1),
+    (0xFB86, 5343, 1),
+    (0xFB87, 5344, 1),
+    (0xFB88, 5345, 1),
+    (0xFB89, 5346, 1),
+    (0xFB8A, 5347, 1),
+    (0xFB8B, 5348, 1),
+    (0xFB8C, 5349, 1),
+    (0xFB8D, 5350, 1),
+    (0xFB8E, 5351, 1),
+    (0xFB8F, 5352, 1),
+    (0xFB90, 5353, 1),
+    (0xFB91, 5354, 1),
+    (0xFB92, 5355, 1),
+    (0xFB93, 5356, 1),
+    (0xFB94, 5357, 1),
+    (0xFB95, 5358, 1),
+    (0xFB96, 5359, 1),
+    (0xFB97, 5360, 1),
+    (0xFB98, 5361, 1),
+    (0xFB99, 5362, 1),
+    (0xFB9A, 5363, 1),
+    (0xFB9B, 5364, 1),
+    (0xFB9C, 5365, 1),
+    (0xFB9D, 5366, 1),
+    (0xFB9E, 5367, 1),
+    (0xFB9F, 5368, 1),
+    (0xFBA0, 5369, 1),
+    (0xFBA1, 5370, 1),
+    (0xFBA2, 5371, 1),
+    (0xFBA3, 5372, 1),
+    (0xFBA4, 5373, 2),
+    (0xFBA5, 5375, 2),
+    (0xFBA6, 5377, 1),
+    (0xFBA7, 5378, 1),
+    (0xFBA8, 5379, 1),
+    (0xFBA9, 5380, 1),
+    (0xFBAA, 5381, 1),
+    (0xFBAB, 5382, 1),
+    (0xFBAC, 5383, 1),
+    (0xFBAD, 5384, 1),
+    (0xFBAE, 5385, 1),
+    (0xFBAF, 5386, 1),
+    (0xFBB0, 5387, 2),
+    (0xFBB1, 5389, 2),
+    (0xFBD3, 5391, 1),
+    (0xFBD4, 5392, 1),
+    (0xFBD5, 5393, 1),
+    (0xFBD6, 5394, 1),
+    (0xFBD7, 5395, 1),
+    (0xFBD8, 5396, 1),
+    (0xFBD9, 5397, 1),
+    (0xFBDA, 5398, 1),
+    (0xFBDB, 5399, 1),
+    (0xFBDC, 5400, 1),
+    (0xFBDD, 5401, 2),
+    (0xFBDE, 5403, 1),
+    (0xFBDF, 5404, 1),
+    (0xFBE0, 5405, 1),
+    (0xFBE1, 5406, 1),
+    (0xFBE2, 5407, 1),
+    (0xFBE3, 5408, 1),
+    (0xFBE4, 5409, 1),
+    (0xFBE5, 5410, 1),
+    (0xFBE6, 5411, 1),
+    (0xFBE7, 5412, 1),
+    (0xFBE8, 5413, 1),
+    (0xFBE9, 5414, 1),
+    (0xFBEA, 5415, 3),
+    (0xFBEB, 5418, 3),
+    (0xFBEC, 5421, 3),
+    (0xFBED, 5424, 3),
+    (0xFBEE, 5427, 3),
+    (0xFBEF, 5430, 3),
+    (0xFBF0, 5433, 3),
+    (0xFBF1, 5436, 3),
+    (0xFBF2, 5439, 3),
+    (0xFBF3, 5442, 3),
+    (0xFBF4, 5445, 3),
+    (0xFBF5, 5448, 3),
+    (0xFBF6, 5451, 3),
+    (0xFBF7, 5454, 3),
+    (0xFBF8, 5457, 3),
+    (0xFBF9, 5460, 3),
+    (0xFBFA, 5463, 3),
+    (0xFBFB, 5466, 3),
+    (0xFBFC, 5469, 1),
+    (0xFBFD, 5470, 1),
+    (0xFBFE, 5471, 1),
+    (0xFBFF, 5472, 1),
+    (0xFC00, 5473, 3),
+    (0xFC01, 5476, 3),
+    (0xFC02, 5479, 3),
+    (0xFC03, 5482, 3),
+    (0xFC04, 5485, 3),
+    (0xFC05, 5488, 2),
+    (0xFC06, 5490, 2),
+    (0xFC07, 5492, 2),
+    (0xFC08, 5494, 2),
+    (0xFC09, 5496, 2),
+    (0xFC0A, 5498, 2),
+    (0xFC0B, 5500, 2),
+    (0xFC0C, 5502, 2),
+    (0xFC0D, 5504, 2),
+    (0xFC0E, 5506, 2),
+    (0xFC0F, 5508, 2),
+    (0xFC10, 5510, 2),
+    (0xFC11, 5512, 2),
+    (0xFC12, 5514, 2),
+    (0xFC13, 5516, 2),
+    (0xFC14, 5518, 2),
+    (0xFC15, 5520, 2),
+    (0xFC16, 5522, 2),
+    (0xFC17, 5524, 2),
+    (0xFC18, 5526, 2),
+    (0xFC19, 5528, 2),
+    (0xFC1A, 5530, 2),
+    (0xFC1B, 5532, 2),
+    (0xFC1C, 5534, 2),
+    (0xFC1D, 5536, 2),
+    (0xFC1E, 5538, 2),
+    (0xFC1F, 5540, 2),
+    (0xFC20, 5542, 2),
+    (0xFC21, 5544, 2),
+    (0xFC22, 5546, 2),
+    (0xFC23, 5548, 2),
+    (0xFC24, 5550, 2),
+    (0xFC25, 5552, 2),
+    (0xFC26, 5554, 2),
+    (0xFC27, 5556, 2),
+    (0xFC28, 5558, 2),
+    (0xFC29, 5560, 2),
+    (0xFC2A, 5562, 2),
+    (0xFC2B, 5564, 2),
+    (0xFC2C, 5566, 2),
+    (0xFC2D, 5568, 2),
+    (0xFC2E, 5570, 2),
+    (0xFC2F, 5572, 2),
+    (0xFC30, 5574, 2),
+    (0xFC31, 5576, 2),
+    (0xFC32, 5578, 2),
+    (0xFC33, 5580, 2),
+    (0xFC34, 5582, 2),
+    (0xFC35, 5584, 2),
+    (0xFC36, 5586, 2),
+    (0xFC37, 5588, 2),
+    (0xFC38, 5590, 2),
+    (0xFC39, 5592, 2),
+    (0xFC3A, 5594, 2),
+    (0xFC3B, 5596, 2),
+    (0xFC3C, 5598, 2),
+    (0xFC3D, 5600, 2),
+    (0xFC3E, 5602, 2),
+    (0xFC3F, 5604, 2),
+    (0xFC40, 5606, 2),
+    (0xFC41, 5608, 2),
+    (0xFC42, 5610, 2),
+    (0xFC43, 5612, 2),
+    (0xFC44, 5614, 2),
+    (0xFC45, 5616, 2),
+    (0xFC46, 5618, 2),
+    (0xFC47, 5620, 2),
+    (0xFC48, 5622, 2),
+    (0xFC49, 5624, 2),
+    (0xFC4A, 5626, 2),
+    (0xFC4B, 5628, 2),
+    (0xFC4C, 5630, 2),
+    (0xFC4D, 5632, 2),
+    (0xFC4E, 5634, 2),
+    (0xFC4F, 5636, 2),
+    (0xFC50, 5638, 2),
+    (0xFC51, 5640, 2),
+    (0xFC52, 5642, 2),
+    (0xFC53, 5644, 2),
+    (0xFC54, 5646, 2),
+    (0xFC55, 5648, 2),
+    (0xFC56, 5650, 2),
+    (0xFC57, 5652, 2),
+    (0xFC58, 5654, 2),
+    (0xFC59, 5656, 2),
+    (0xFC5A, 5658, 2),
+    (0xFC5B, 5660, 2),
+    (0xFC5C, 5662, 2),
+    (0xFC5D, 5664, 2),
+    (0xFC5E, 5666, 3),
+    (0xFC5F, 5669, 3),
+    (0xFC60, 5672, 3),
+    (0xFC61, 5675, 3),
+    (0xFC62, 5678, 3),
+    (0xFC63, 5681, 3),
+    (0xFC64, 5684, 3),
+    (0xFC65, 5687, 3),
+    (0xFC66, 5690, 3),
+    (0xFC67, 5693, 3),
+    (0xFC68, 5696, 3),
+    (0xFC69, 5699, 3),
+    (0xFC6A, 5702, 2),
+    (0xFC6B, 5704, 2),
+    (0xFC6C, 5706, 2),
+    (0xFC6D, 5708, 2),
+    (0xFC6E, 5710, 2),
+    (0xFC6F, 5712, 2),
+    (0xFC70, 5714, 2),
+    (0xFC71, 5716, 2),
+    (0xFC72, 5718, 2),
+    (0xFC73, 5720, 2),
+    (0xFC74, 5722, 2),
+    (0xFC75, 5724, 2),
+    (0xFC76, 5726, 2),
+    (0xFC77, 5728, 2),
+    (0xFC78, 5730, 2),
+    (0xFC79, 5732, 2),
+    (0xFC7A, 5734, 2),
+    (0xFC7B, 5736, 2),
+    (0xFC7C, 5738, 2),
+    (0xFC7D, 5740, 2),
+    (0xFC7E, 5742, 2),
+    (0xFC7F, 5744, 2),
+    (0xFC80, 5746, 2),
+    (0xFC81, 5748, 2),
+    (0xFC82, 5750, 2),
+    (0xFC83, 5752, 2),
+    (0xFC84, 5754, 2),
+    (0xFC85, 5756, 2),
+    (0xFC86, 5758, 2),
+    (0xFC87, 5760, 2),
+    (0xFC88, 5762, 2),
+    (0xFC89, 5764, 2),
+    (0xFC8A, 5766, 2),
+    (0xFC8B, 5768, 2),
+    (0xFC8C, 5770, 2),
+    (0xFC8D, 5772, 2),
+    (0xFC8E, 5774, 2),
+    (0xFC8F, 5776, 2),
+    (0xFC90, 5778, 2),
+    (0xFC91, 5780, 2),
+    (0xFC92, 5782, 2),
+    (0xFC93, 5784, 2),
+    (0xFC94, 5786, 2),
+    (0xFC95, 5788, 2),
+    (0xFC96, 5790, 2),
+    (0xFC97, 5792, 3),
+    (0xFC98, 5795, 3),
+    (0xFC99, 5798, 3),
+    (0xFC9A, 5801, 3),
+    (0xFC9B, 5804, 3),
+    (0xFC9C, 5807, 2),
+    (0xFC9D, 5809, 2),
+    (0xFC9E, 5811, 2),
+    (0xFC9F, 5813, 2),
+    (0xFCA0, 5815, 2),
+    (0xFCA1, 5817, 2),
+    (0xFCA2, 5819, 2),
+    (0xFCA3, 5821, 2),
+    (0xFCA4, 5823, 2),
+    (0xFCA5, 5825, 2),
+    (0xFCA6, 5827, 2),
+    (0xFCA7, 5829, 2),
+    (0xFCA8, 5831, 2),
+    (0xFCA9, 5833, 2),
+    (0xFCAA, 5835, 2),
+    (0xFCAB, 5837, 2),
+    (0xFCAC, 5839, 2),
+    (0xFCAD, 5841, 2),
+    (0xFCAE, 5843, 2),
+    (0xFCAF, 5845, 2),
+    (0xFCB0, 5847, 2),
+    (0xFCB1, 5849, 2),
+    (0xFCB2, 5851, 2),
+    (0xFCB3, 5853, 2),
+    (0xFCB4, 5855, 2),
+    (0xFCB5, 5857, 2),
+    (0xFCB6, 5859, 2),
+    (0xFCB7, 5861, 2),
+    (0xFCB8, 5863, 2),
+    (0xFCB9, 5865, 2),
+    (0xFCBA, 5867, 2),
+    (0xFCBB, 5869, 2),
+    (0xFCBC, 5871, 2),
+    (0xFCBD, 5873, 2),
+    (0xFCBE, 5875, 2),
+    (0xFCBF, 5877, 2),
+    (0xFCC0, 5879, 2),
+    (0xFCC1, 5881, 2),
+    (0xFCC2, 5883, 2),
+    (0xFCC3, 5885, 2),
+    (0xFCC4, 5887, 2),
+    (0xFCC5, 5889, 2),
+    (0xFCC6, 5891, 2),
+    (0xFCC7, 5893, 2),
+    (0xFCC8, 5895, 2),
+    (0xFCC9, 5897, 2),
+    (0xFCCA, 5899, 2),
+    (0xFCCB, 5901, 2),
+    (0xFCCC, 5903, 2),
+    (0xFCCD, 5905, 2),
+    (0xFCCE, 5907, 2),
+    (0xFCCF, 5909, 2),
+    (0xFCD0, 5911, 2),
+    (0xFCD1, 5913, 2),
+    (0xFCD2, 5915, 2),
+    (0xFCD3, 5917, 2),
+    (0xFCD4, 5919, 2),
+    (0xFCD5, 5921, 2),
+    (0xFCD6, 5923, 2),
+    (0xFCD7, 5925, 2),
+    (0xFCD8, 5927, 2),
+    (0xFCD9, 5929, 2),
+    (0xFCDA, 5931, 2),
+    (0xFCDB, 5933, 2),
+    (0xFCDC, 5935, 2),
+    (0xFCDD, 5937, 2),
+    (0xFCDE, 5939, 2),
+    (0xFCDF, 5941, 3),
+    (0xFCE0, 5944, 3),
+    (0xFCE1, 5947, 2),
+    (0xFCE2, 5949, 2),
+    (0xFCE3, 5951, 2),
+    (0xFCE4, 5953, 2),
+    (0xFCE5, 5955, 2),
+    (0xFCE6, 5957, 2),
+    (0xFCE7, 5959, 2),
+    (0xFCE8, 5961, 2),
+    (0xFCE9, 5963, 2),
+    (0xFCEA, 5965, 2),
+    (0xFCEB, 5967, 2),
+    (0xFCEC, 5969, 2),
+    (0xFCED, 5971, 2),
+    (0xFCEE, 5973, 2),
+    (0xFCEF, 5975, 2),
+    (0xFCF0, 5977, 2),
+    (0xFCF1, 5979, 2),
+    (0xFCF2, 5981, 3),
+    (0xFCF3, 5984, 3),
+    (0xFCF4, 5987, 3),
+    (0xFCF5, 5990, 2),
+    (0xFCF6, 5992, 2),
+    (0xFCF7, 5994, 2),
+    (0xFCF8, 5996, 2),
+    (0xFCF9, 5998, 2),
+    (0xFCFA, 6000, 2),
+    (0xFCFB, 6002, 2),
+    (0xFCFC, 6004, 2),
+    (0xFCFD, 6006, 2),
+    (0xFCFE, 6008, 2),
+    (0xFCFF, 6010, 2),
+    (0xFD00, 6012, 2),
+    (0xFD01, 6014, 2),
+    (0xFD02, 6016, 2),
+    (0xFD03, 6018, 2),
+    (0xFD04, 6020, 2),
+    (0xFD05, 6022, 2),
+    (0xFD06, 6024, 2),
+    (0xFD07, 6026, 2),
+    (0xFD08, 6028, 2),
+    (0xFD09, 6030, 2),
+    (0xFD0A, 6032, 2),
+    (0xFD0B, 6034, 2),
+    (0xFD0C, 6036, 2),
+    (0xFD0D, 6038, 2),
+    (0xFD0E, 6040, 2),
+    (0xFD0F, 6042, 2),
+    (0xFD10, 6044, 2),
+    (0xFD11, 6046, 2),
+    (0xFD12, 6048, 2),
+    (0xFD13, 6050, 2),
+    (0xFD14, 6052, 2),
+    (0xFD15, 6054, 2),
+    (0xFD16, 6056, 2),
+    (0xFD17, 6058, 2),
+    (0xFD18, 6060, 2),
+    (0xFD19, 6062, 2),
+    (0xFD1A, 6064, 2),
+    (0xFD1B, 6066, 2),
+    (0xFD1C, 6068, 2),
+    (0xFD1D, 6070, 2),
+    (0xFD1E, 6072, 2),
+    (0xFD1F, 6074, 2),
+    (0xFD20, 6076, 2),
+    (0xFD21, 6078, 2),
+    (0xFD22, 6080, 2),
+    (0xFD23, 6082, 2),
+    (0xFD24, 6084, 2),
+    (0xFD25, 6086, 2),
+    (0xFD26, 6088, 2),
+    (0xFD27, 6090, 2),
+    (0xFD28, 6092, 2),
+    (0xFD29, 6094, 2),
+    (0xFD2A, 6096, 2),
+    (0xFD2B, 6098, 2),
+    (0xFD2C, 6100, 2),
+    (0xFD2D, 6102, 2),
+    (0xFD2E, 6104, 2),
+    (0xFD2F, 6106, 2),
+    (0xFD30, 6108, 2),
+    (0xFD31, 6110, 2),
+    (0xFD32, 6112, 2),
+    (0xFD33, 6114, 2),
+    (0xFD34, 6116, 2),
+    (0xFD35, 6118, 2),
+    (0xFD36, 6120, 2),
+    (0xFD37, 6122, 2),
+    (0xFD38, 6124, 2),
+    (0xFD39, 6126, 2),
+    (0xFD3A, 6128, 2),
+    (0xFD3B, 6130, 2),
+    (0xFD3C, 6132, 2),
+    (0xFD3D, 6134, 2),
+    (0xFD50, 6136, 3),
+    (0xFD51, 6139, 3),
+    (0xFD52, 6142, 3),
+    (0xFD53, 6145, 3),
+    (0xFD54, 6148, 3),
+    (0xFD55, 6151, 3),
+    (0xFD56, 6154, 3),
+    (0xFD57, 6157, 3),
+    (0xFD58, 6160, 3),
+    (0xFD59, 6163, 3),
+    (0xFD5A, 6166, 3),
+    (0xFD5B, 6169, 3),
+    (0xFD5C, 6172, 3),
+    (0xFD5D, 6175, 3),
+    (0xFD5E, 6178, 3),
+    (0xFD5F, 6181, 3),
+    (0xFD60, 6184, 3),
+    (0xFD61, 6187, 3),
+    (0xFD62, 6190, 3),
+    (0xFD63, 6193, 3),
+    (0xFD64, 6196, 3),
+    (0xFD65, 6199, 3),
+    (0xFD66, 6202, 3),
+    (0xFD67, 6205, 3),
+    (0xFD68, 6208, 3),
+    (0xFD69, 6211, 3),
+    (0xFD6A, 6214, 3),
+    (0xFD6B, 6217, 3),
+    (0xFD6C, 6220, 3),
+    (0xFD6D, 6223, 3),
+    (0xFD6E, 6226, 3),
+    (0xFD6F, 6229, 3),
+    (0xFD70, 6232, 3),
+    (0xFD71, 6235, 3),
+    (0xFD72, 6238, 3),
+    (0xFD73, 6241, 3),
+    (0xFD74, 6244, 3),
+    (0xFD75, 6247, 3),
+    (0xFD76, 6250, 3),
+    (0xFD77, 6253, 3),
+    (0xFD78, 6256, 3),
+    (0xFD79, 6259, 3),
+    (0xFD7A, 6262, 3),
+    (0xFD7B, 6265, 3),
+    (0xFD7C, 6268, 3),
+    (0xFD7D, 6271, 3),
+    (0xFD7E, 6274, 3),
+    (0xFD7F, 6277, 3),
+    (0xFD80, 6280, 3),
+    (0xFD81, 6283, 3),
+    (0xFD82, 6286, 3),
+    (0xFD83, 6289, 3),
+    (0xFD84, 6292, 3),
+    (0xFD85, 6295, 3),
+    (0xFD86, 6298, 3),
+    (0xFD87, 6301, 3),
+    (0xFD88, 6304, 3),
+    (0xFD89, 6307, 3),
+    (0xFD8A, 6310, 3),
+    (0xFD8B, 6313, 3),
+    (0xFD8C, 6316, 3),
+    (0xFD8D, 6319, 3),
+    (0xFD8E, 6322, 3),
+    (0xFD8F, 6325, 3),
+    (0xFD92, 6328, 3),
+    (0xFD93, 6331, 3),
+    (0xFD94, 6334, 3),
+    (0xFD95, 6337, 3),
+    (0xFD96, 6340, 3),
+    (0xFD97, 6343, 3),
+    (0xFD98, 6346, 3),
+    (0xFD99, 6349, 3),
+    (0xFD9A, 6352, 3),
+    (0xFD9B, 6355, 3),
+    (0xFD9C, 6358, 3),
+    (0xFD9D, 6361, 3),
+    (0xFD9E, 6364, 3),
+    (0xFD9F, 6367, 3),
+    (0xFDA0, 6370, 3),
+    (0xFDA1, 6373, 3),
+    (0xFDA2, 6376, 3),
+    (0xFDA3, 6379, 3),
+    (0xFDA4, 6382, 3),
+    (0xFDA5, 6385, 3),
+    (0xFDA6, 6388, 3),
+    (0xFDA7, 6391, 3),
+    (0xFDA8, 6394, 3),
+    (0xFDA9, 6397, 3),
+    (0xFDAA, 6400, 3),
+    (0xFDAB, 6403, 3),
+    (0xFDAC, 6406, 3),
+    (0xFDAD, 6409, 3),
+    (0xFDAE, 6412, 3),
+    (0xFDAF, 6415, 3),
+    (0xFDB0, 6418, 3),
+    (0xFDB1, 6421, 3),
+    (0xFDB2, 6424, 3),
+    (0xFDB3, 6427, 3),
+    (0xFDB4, 6430, 3),
+    (0xFDB5, 6433, 3),
+    (0xFDB6, 6436, 3),
+    (0xFDB7, 6439, 3),
+    (0xFDB8, 6442, 3),
+    (0xFDB9, 6445, 3),
+    (0xFDBA, 6448, 3),
+    (0xFDBB, 6451, 3),
+    (0xFDBC, 6454, 3),
+    (0xFDBD, 6457, 3),
+    (0xFDBE, 6460, 3),
+    (0xFDBF, 6463, 3),
+    (0xFDC0, 6466, 3),
+    (0xFDC1, 6469, 3),
+    (0xFDC2, 6472, 3),
+    (0xFDC3, 6475, 3),
+    (0xFDC4, 6478, 3),
+    (0xFDC5, 6481, 3),
+    (0xFDC6, 6484, 3),
+    (0xFDC7, 6487, 3),
+    (0xFDF0, 6490, 3),
+    (0xFDF1, 6493, 3),
+    (0xFDF2, 6496, 4),
+    (0xFDF3, 6500, 4),
+    (0xFDF4, 6504, 4),
+    (0xFDF5, 6508, 4),
+    (0xFDF6, 6512, 4),
+    (0xFDF7, 6516, 4),
+    (0xFDF8, 6520, 4),
+    (0xFDF9, 6524, 3),
+    (0xFDFA, 6527, 18),
+    (0xFDFB, 6545, 8),
+    (0xFDFC, 6553, 4),
+    (0xFE10, 6557, 1),
+    (0xFE11, 6558, 1),
+    (0xFE12, 6559, 1),
+    (0xFE13, 6560, 1),
+    (0xFE14, 6561, 1),
+    (0xFE15, 6562, 1),
+    (0xFE16, 6563, 1),
+    (0xFE17, 6564, 1),
+    (0xFE18, 6565, 1),
+    (0xFE19, 6566, 3),
+    (0xFE30, 6569, 2),
+    (0xFE31, 6571, 1),
+    (0xFE32, 6572, 1),
+    (0xFE33, 6573, 1),
+    (0xFE34, 6574, 1),
+    (0xFE35, 6575, 1),
+    (0xFE36, 6576, 1),
+    (0xFE37, 6577, 1),
+    (0xFE38, 6578, 1),
+    (0xFE39, 6579, 1),
+    (0xFE3A, 6580, 1),
+    (0xFE3B, 6581, 1),
+    (0xFE3C, 6582, 1),
+    (0xFE3D, 6583, 1),
+    (0xFE3E, 6584, 1),
+    (0xFE3F, 6585, 1),
+    (0xFE40, 6586, 1),
+    (0xFE41, 6587, 1),
+    (0xFE42, 6588, 1),
+    (0xFE43, 6589, 1),
+    (0xFE44, 6590, 1),
+    (0xFE47, 6591, 1),
+    (0xFE48, 6592, 1),
+    (0xFE49, 6593, 2),
+    (0xFE4A, 6595, 2),
+    (0xFE4B, 6597, 2),
+    (0xFE4C, 6599, 2),
+    (0xFE4D, 6601, 1),
+    (0xFE4E, 6602, 1),
+    (0xFE4F, 6603, 1),
+    (0xFE50, 6604, 1),
+    (0xFE51, 6605, 1),
+    (0xFE52, 6606, 1),
+    (0xFE54, 6607, 1),
+    (0xFE55, 6608, 1),
+    (0xFE56, 6609, 1),
+    (0xFE57, 6610, 1),
+    (0xFE58, 6611, 1),
+    (0xFE59, 6612, 1),
+    (0xFE5A, 6613, 1),
+    (0xFE5B, 6614, 1),
+    (0xFE5C, 6615, 1),
+    (0xFE5D, 6616, 1),
+    (0xFE5E, 6617, 1),
+    (0xFE5F, 6618, 1),
+    (0xFE60, 6619, 1),
+    (0xFE61, 6620, 1),
+    (0xFE62, 6621, 1),
+    (0xFE63, 6622, 1),
+    (0xFE64, 6623, 1),
+    (0xFE65, 6624, 1),
+    (0xFE66, 6625, 1),
+    (0xFE68, 6626, 1),
+    (0xFE69, 6627, 1),
+    (0xFE6A, 6628, 1),
+    (0xFE6B, 6629, 1),
+    (0xFE70, 6630, 2),
+    (0xFE71, 6632, 2),
+    (0xFE72, 6634, 2),
+    (0xFE74, 6636, 2),
+    (0xFE76, 6638, 2),
+    (0xFE77, 6640, 2),
+    (0xFE78, 6642, 2),
+    (0xFE79, 6644, 2),
+    (0xFE7A, 6646, 2),
+    (0xFE7B, 6648, 2),
+    (0xFE7C, 6650, 2),
+    (0xFE7D, 6652, 2),
+    (0xFE7E, 6654, 2),
+    (0xFE7F, 6656, 2),
+    (0xFE80, 6658, 1),
+    (0xFE81, 6659, 2),
+    (0xFE82, 6661, 2),
+    (0xFE83, 6663, 2),
+    (0xFE84, 6665, 2),
+    (0xFE85, 6667, 2),
+    (0xFE86, 6669, 2),
+    (0xFE87, 6671, 2),
+    (0xFE88, 6673, 2),
+    (0xFE89, 6675, 2),
+    (0xFE8A, 6677, 2),
+    (0xFE8B, 6679, 2),
+    (0xFE8C, 6681, 2),
+    (0xFE8D, 6683, 1),
+    (0xFE8E, 6684, 1),
+    (0xFE8F, 6685, 1),
+    (0xFE90, 6686, 1),
+    (0xFE91, 6687, 1),
+    (0xFE92, 6688, 1),
+    (0xFE93, 6689, 1),
+    (0xFE94, 6690, 1),
+    (0xFE95, 6691, 1),
+    (0xFE96, 6692, 1),
+    (0xFE97, 6693, 1),
+    (0xFE98, 6694, 1),
+    (0xFE99, 6695, 1),
+    (0xFE9A, 6696, 1),
+    (0xFE9B, 6697, 1),
+    (0xFE9C, 6698, 1),
+    (0xFE9D, 6699, 1),
+    (0xFE9E, 6700, 1),
+    (0xFE9F, 6701, 1),
+    (0xFEA0, 6702, 1),
+    (0xFEA1, 6703, 1),
+    (0xFEA2, 6704, 1),
+    (0xFEA3, 6705, 1),
+    (0xFEA4, 6706, 1),
+    (0xFEA5, 6707, 1),
+    (0xFEA6, 6708, 1),
+    (0xFEA7, 6709, 1),
+    (0xFEA8, 6710, 1),
+    (0xFEA9, 6711, 1),
+    (0xFEAA, 6712, 1),
+    (0xFEAB, 6713, 1),
+    (0xFEAC, 6714, 1),
+    (0xFEAD, 6715, 1),
+    (0xFEAE, 6716, 1),
+    (0xFEAF, 6717, 1),
+    (0xFEB0, 6718, 1),
+    (0xFEB1, 6719, 1),
+    (0xFEB2, 6720, 1),
+    (0xFEB3, 6721, 1),
+    (0xFEB4, 6722, 1),
+    (0xFEB5, 6723, 1),
+    (0xFEB6, 6724, 1),
+    (0xFEB7, 6725, 1),
+    (0xFEB8, 6726, 1),
+    (0xFEB9, 6727, 1),
+    (0xFEBA, 6728, 1),
+    (0xFEBB, 6729, 1),
+    (0xFEBC, 6730, 1),
+    (0xFEBD, 6731, 1),
+    (0xFEBE, 6732, 1),
+    (0xFEBF, 6733, 1),
+    (0xFEC0, 6734, 1),
+    (0xFEC1, 6735, 1),
+    (0xFEC2, 6736, 1),
+    (0xFEC3, 6737, 1),
+    (0xFEC4, 6738, 1),
+    (0xFEC5, 6739, 1),
+    (0xFEC6, 6740, 1),
+    (0xFEC7, 6741, 1),
+    (0xFEC8, 6742, 1),
+    (0xFEC9, 6743, 1),
+    (0xFECA, 6744, 1),
+    (0xFECB, 6745, 1),
+    (0xFECC, 6746, 1),
+    (0xFECD, 6747, 1),
+    (0xFECE, 6748, 1),
+    (0xFECF, 6749, 1),
+    (0xFED0, 6750, 1),
+    (0xFED1, 6751, 1),
+    (0xFED2, 6752, 1),
+    (0xFED3, 6753, 1),
+    (0xFED4, 6754, 1),
+    (0xFED5, 6755, 1),
+    (0xFED6, 6756, 1),
+    (0xFED7, 6757, 1),
+    (0xFED8, 6758, 1),
+    (0xFED9, 6759, 1),
+    (0xFEDA, 6760, 1),
+    (0xFEDB, 6761, 1),
+    (0xFEDC, 6762, 1),
+    (0xFEDD, 6763, 1),
+    (0xFEDE, 6764, 1),
+    (0xFEDF, 6765, 1),
+    (0xFEE0, 6766, 1),
+    (0xFEE1, 6767, 1),
+    (0xFEE2, 6768, 1),
+    (0xFEE3, 6769, 1),
+    (0xFEE4, 6770, 1),
+    (0xFEE5, 6771, 1),
+    (0xFEE6, 6772, 1),
+    (0xFEE7, 6773, 1),
+    (0xFEE8, 6774, 1),
+    (0xFEE9, 6775, 1),
+    (0xFEEA, 6776, 1),
+    (0xFEEB, 6777, 1),
+    (0xFEEC, 6778, 1),
+    (0xFEED, 6779, 1),
+    (0xFEEE, 6780, 1),
+    (0xFEEF, 6781, 1),
+    (0xFEF0, 6782, 1),
+    (0xFEF1, 6783, 1),
+    (0xFEF2, 6784, 1),
+    (0xFEF3, 6785, 1),
+    (0xFEF4, 6786, 1),
+    (0xFEF5, 6787, 3),
+    (0xFEF6, 6790, 3),
+    (0xFEF7, 6793, 3),
+    (0xFEF8, 6796, 3),
+    (0xFEF9, 6799, 3),
+    (0xFEFA, 6802, 3),
+    (0xFEFB, 6805, 2),
+    (0xFEFC, 6807, 2),
+    (0xFF01, 6809, 1),
+    (0xFF02, 6810, 1),
+    (0xFF03, 6811, 1),
+    (0xFF04, 6812, 1),
+    (0xFF05, 6813, 1),
+    (0xFF06, 6814, 1),
+    (0xFF07, 6815, 1),
+    (0xFF08, 6816, 1),
+    (0xFF09, 6817, 1),
+    (0xFF0A, 6818, 1),
+    (0xFF0B, 6819, 1),
+    (0xFF0C, 6820, 1),
+    (0xFF0D, 6821, 1),
+    (0xFF0E, 6822, 1),
+    (0xFF0F, 6823, 1),
+    (0xFF10, 6824, 1),
+    (0xFF11, 6825, 1),
+    (0xFF12, 6826, 1),
+    (0xFF13, 6827, 1),
+    (0xFF14, 6828, 1),
+    (0xFF15, 6829, 1),
+    (0xFF16, 6830, 1),
+    (0xFF17, 6831, 1),
+    (0xFF18, 6832, 1),
+    (0xFF19, 6833, 1),
+    (0xFF1A, 6834, 1),
+    (0xFF1B, 6835, 1),
+    (0xFF1C, 6836, 1),
+    (0xFF1D, 6837, 1),
+    (0xFF1E, 6838, 1),
+    (0xFF1F, 6839, 1),
+    (0xFF20, 6840, 1),
+    (0xFF21, 6841, 1),
+    (0xFF22, 6842, 1),
+    (0xFF23, 6843, 1),
+    (0xFF24, 6844, 1),
+    (0xFF25, 6845, 1),
+    (0xFF26, 6846, 1),
+    (0xFF27, 6847, 1),
+    (0xFF28, 6848, 1),
+    (0xFF29, 6849, 1),
+    (0xFF2A, 6850, 1),
+    (0xFF2B, 6851, 1),
+    (0xFF2C, 6852, 1),
+    (0xFF2D, 6853, 1),
+    (0xFF2E, 6854, 1),
+    (0xFF2F, 6855, 1),
+    (0xFF30, 6856, 1),
+    (0xFF31, 6857, 1),
+    (0xFF32, 6858, 1),
+    (0xFF33, 6859, 1),
+    (0xFF34, 6860, 1),
+    (0xFF35, 6861, 1),
+    (0xFF36, 6862, 1),
+    (0xFF37, 6863, 1),
+    (0xFF38, 6864, 1),
+    (0xFF39, 6865, 1),
+    (0xFF3A, 6866, 1),
+    (0xFF3B, 6867, 1),
+    (0xFF3C, 6868, 1),
+    (0xFF3D, 6869, 1),
+    (0xFF3E, 6870, 1),
+    (0xFF3F, 6871, 1),
+    (0xFF40, 6872, 1),
+    (0xFF41, 6873, 1),
+    (0xFF42, 6874, 1),
+    (0xFF43, 6875, 1),
+    (0xFF44, 6876, 1),
+    (0xFF45, 6877, 1),
+    (0xFF46, 6878, 1),
+    (0xFF47, 6879, 1),
+    (0xFF48, 6880, 1),
+    (0xFF49, 6881, 1),
+    (0xFF4A, 6882, 1),
+    (0xFF4B, 6883, 1),
+    (0xFF4C, 6884, 1),
+    (0xFF4D, 6885, 1),
+    (0xFF4E, 6886, 1),
+    (0xFF4F, 6887, 1),
+    (0xFF50, 6888, 1),
+    (0xFF51, 6889, 1),
+    (0xFF52, 6890, 1),
+    (0xFF53, 6891, 1),
+    (0xFF54, 6892, 1),
+    (0xFF55, 6893, 1),
+    (0xFF56, 6894, 1),
+    (0xFF57, 6895, 1),
+    (0xFF58, 6896, 1),
+    (0xFF59, 6897, 1),
+    (0xFF5A, 6898, 1),
+    (0xFF5B, 6899, 1),
+    (0xFF5C, 6900, 1),
+    (0xFF5D, 6901, 1),
+    (0xFF5E, 6902, 1),
+    (0xFF5F, 6903, 1),
+    (0xFF60, 6904, 1),
+    (0xFF61, 6905, 1),
+    (0xFF62, 6906, 1),
+    (0xFF63, 6907, 1),
+    (0xFF64, 6908, 1),
+    (0xFF65, 6909, 1),
+    (0xFF66, 6910, 1),
+    (0xFF67, 6911, 1),
+    (0xFF68, 6912, 1),
+    (0xFF69, 6913, 1),
+    (0xFF6A, 6914, 1),
+    (0xFF6B, 6915, 1),
+    (0xFF6C, 6916, 1),
+    (0xFF6D, 6917, 1),
+    (0xFF6E, 6918, 1),
+    (0xFF6F, 6919, 1),
+    (0xFF70, 6920, 1),
+    (0xFF71, 6921, 1),
+    (0xFF72, 6922, 1),
+    (0xFF73, 6923, 1),
+    (0xFF74, 6924, 1),
+    (0xFF75, 6925, 1),
+    (0xFF76, 6926, 1),
+    (0xFF77, 6927, 1),
+    (0xFF78, 6928, 1),
+    (0xFF79, 6929, 1),
+    (0xFF7A, 6930, 1),
+    (0xFF7B, 6931, 1),
+    (0xFF7C, 6932, 1),
+    (0xFF7D, 6933, 1),
+    (0xFF7E, 6934, 1),
+    (0xFF7F, 6935, 1),
+    (0xFF80, 6936, 1),
+    (0xFF81, 6937, 1),
+    (0xFF82, 6938, 1),
+    (0xFF83, 6939, 1),
+    (0xFF84, 6940, 1),
+    (0xFF85, 6941, 1),
+    (0xFF86, 6942, 1),
+    (0xFF87, 6943, 1),
+    (0xFF88, 6944, 1),
+    (0xFF89, 6945, 1),
+    (0xFF8A, 6946, 1),
+    (0xFF8B, 6947, 1),
+    (0xFF8C, 6948, 1),
+    (0xFF8D, 6949, 1),
+    (0xFF8E, 6950, 1),
+    (0xFF8F, 6951, 1),
+    (0xFF90, 6952, 1),
+    (0xFF91, 6953, 1),
+    (0xFF92, 6954, 1),
+    (0xFF93, 6955, 1),
+    (0xFF94, 6956, 1),
+    (0xFF95, 6957, 1),
+    (0xFF96, 6958, 1),
+    (0xFF97, 6959, 1),
+    (0xFF98, 6960, 1),
+    (0xFF99, 6961, 1),
+    (0xFF9A, 6962, 1),
+    (0xFF9B, 6963, 1),
+    (0xFF9C, 6964, 1),
+    (0xFF9D, 6965, 1),
+    (0xFF9E, 6966, 1),
+    (0xFF9F, 6967, 1),
+    (0xFFA0, 6968, 1),
+    (0xFFA1, 6969, 1),
+    (0xFFA2, 6970, 1),
+    (0xFFA3, 6971, 1),
+    (0xFFA4, 6972, 1),
+    (0xFFA5, 6973, 1),
+    (0xFFA6, 6974, 1),
+    (0xFFA7, 6975, 1),
+    (0xFFA8, 6976, 1),
+    (0xFFA9, 6977, 1),
+    (0xFFAA, 6978, 1),
+    (0xFFAB, 6979, 1),
+    (0xFFAC, 6980, 1),
+    (0xFFAD, 6981, 1),
+    (0xFFAE, 6982, 1),
+    (0xFFAF, 6983, 1),
+    (0xFFB0, 6984, 1),
+    (0xFFB1, 6985, 1),
+    (0xFFB2, 6986, 1),
+    (0xFFB3, 6987, 1),
+    (0xFFB4, 6988, 1),
+    (0xFFB5, 6989, 1),
+    (0xFFB6, 6990, 1),
+    (0xFFB7, 6991, 1),
+    (0xFFB8, 6992, 1),
+    (0xFFB9, 6993, 1),
+    (0xFFBA, 6994, 1),
+    (0xFFBB, 6995, 1),
+    (0xFFBC, 6996, 1),
+    (0xFFBD, 6997, 1),
+    (0xFFBE, 6998, 1),
+    (0xFFC2, 6999, 1),
+    (0xFFC3, 7000, 1),
+    (0xFFC4, 7001, 1),
+    (0xFFC5, 7002, 1),
+    (0xFFC6, 7003, 1),
+    (0xFFC7, 7004, 1),
+    (0xFFCA, 7005, 1),
+    (0xFFCB, 7006, 1),
+    (0xFFCC, 7007, 1),
+    (0xFFCD, 7008, 1),
+    (0xFFCE, 7009, 1),
+    (0xFFCF, 7010, 1),
+    (0xFFD2, 7011, 1),
+    (0xFFD3, 7012, 1),
+    (0xFFD4, 7013, 1),
+    (0xFFD5, 7014, 1),
+    (0xFFD6, 7015, 1),
+    (0xFFD7, 7016, 1),
+    (0xFFDA, 7017, 1),
+    (0xFFDB, 7018, 1),
+    (0xFFDC, 7019, 1),
+    (0xFFE0, 7020, 1),
+    (0xFFE1, 7021, 1),
+    (0xFFE2, 7022, 1),
+    (0xFFE3, 7023, 2),
+    (0xFFE4, 7025, 1),
+    (0xFFE5, 7026, 1),
+    (0xFFE6, 7027, 1),
+    (0xFFE8, 7028, 1),
+    (0xFFE9, 7029, 1),
+    (0xFFEA, 7030, 1),
+    (0xFFEB, 7031, 1),
+    (0xFFEC, 7032, 1),
+    (0xFFED, 7033, 1),
+    (0xFFEE, 7034, 1),
+    (0x1D400, 7096, 1),
+    (0x1D401, 7097, 1),
+    (0x1D402, 7098, 1),
+    (0x1D403, 7099, 1),
+    (0x1D404, 7100, 1),
+    (0x1D405, 7101, 1),
+    (0x1D406, 7102, 1),
+    (0x1D407, 7103, 1),
+    (0x1D408, 7104, 1),
+    (0x1D409, 7105, 1),
+    (0x1D40A, 7106, 1),
+    (0x1D40B, 7107, 1),
+    (0x1D40C, 7108, 1),
+    (0x1D40D, 7109, 1),
+    (0x1D40E, 7110, 1),
+    (0x1D40F, 7111, 1),
+    (0x1D410, 7112, 1),
+    (0x1D411, 7113, 1),
+    (0x1D412, 7114, 1),
+    (0x1D413, 7115, 1),
+    (0x1D414, 7116, 1),
+    (0x1D415, 7117, 1),
+    (0x1D416, 7118, 1),
+    (0x1D417, 7119, 1),
+    (0x1D418, 7120, 1),
+    (0x1D419, 7121, 1),
+    (0x1D41A, 7122, 1),
+    (0x1D41B, 7123, 1),
+    (0x1D41C, 7124, 1),
+    (0x1D41D, 7125, 1),
+    (0x1D41E, 7126, 1),
+    (0x1D41F, 7127, 1),
+    (0x1D420, 7128, 1),
+    (0x1D421, 7129, 1),
+    (0x1D422, 7130, 1),
+    (0x1D423, 7131, 1),
+    (0x1D424, 7132, 1),
+    (0x1D425, 7133, 1),
+    (0x1D426, 7134, 1),
+    (0x1D427, 7135, 1),
+    (0x1D428, 7136, 1),
+    (0x1D429, 7137, 1),
+    (0x1D42A, 7138, 1),
+    (0x1D42B, 7139, 1),
+    (0x1D42C, 7140, 1),
+    (0x1D42D, 7141, 1),
+    (0x1D42E, 7142, 1),
+    (0x1D42F, 7143, 1),
+    (0x1D430, 7144, 1),
+    (0x1D431, 7145, 1),
+    (0x1D432, 7146, 1),
+    (0x1D433, 7147, 1),
+    (0x1D434, 7148, 1),
+    (0x1D435, 7149, 1),
+    (0x1D436, 7150, 1),
+    (0x1D437, 7151, 1),
+    (0x1D438, 7152, 1),
+    (0x1D439, 7153, 1),
+    (0x1D43A, 7154, 1),
+    (0x1D43B, 7155, 1),
+    (0x1D43C, 7156, 1),
+    (0x1D43D, 7157, 1),
+    (0x1D43E, 7158, 1),
+    (0x1D43F, 7159, 1),
+    (0x1D440, 7160, 1),
+    (0x1D441, 7161, 1),
+    (0x1D442, 7162, 1),
+    (0x1D443, 7163, 1),
+    (0x1D444, 7164, 1),
+    (0x1D445, 7165, 1),
+    (0x1D446, 7166, 1),
+    (0x1D447, 7167, 1),
+    (0x1D448, 7168, 1),
+    (0x1D449, 7169, 1),
+    (0x1D44A, 7170, 1),
+    (0x1D44B, 7171, 1),
+    (0x1D44C, 7172, 1),
+    (0x1D44D, 7173, 1),
+    (0x1D44E, 7174, 1),
+    (0x1D44F, 7175, 1),
+    (0x1D450, 7176, 1),
+    (0x1D451, 7177, 1),
+    (0x1D452, 7178, 1),
+    (0x1D453, 7179, 1),
+    (0x1D454, 7180, 1),
+    (0x1D456, 7181, 1),
+    (0x1D457, 7182, 1),
+    (0x1D458, 7183, 1),
+    (0x1D459, 7184, 1),
+    (0x1D45A, 7185, 1),
+    (0x1D45B, 7186, 1),
+    (0x1D45C, 7187, 1),
+    (0x1D45D, 7188, 1),
+    (0x1D45E, 7189, 1),
+    (0x1D45F, 7190, 1),
+    (0x1D460, 7191, 1),
+    (0x1D461, 7192, 1),
+    (0x1D462, 7193, 1),
+    (0x1D463, 7194, 1),
+    (0x1D464, 7195, 1),
+    (0x1D465, 7196, 1),
+    (0x1D466, 7197, 1),
+    (0x1D467, 7198, 1),
+    (0x1D468, 7199, 1),
+    (0x1D469, 7200, 1),
+    (0x1D46A, 7201, 1),
+    (0x1D46B, 7202, 1),
+    (0x1D46C, 7203, 1),
+    (0x1D46D, 7204, 1),
+    (0x1D46E, 7205, 1),
+    (0x1D46F, 7206, 1),
+    (0x1D470, 7207, 1),
+    (0x1D471, 7208, 1),
+    (0x1D472, 7209, 1),
+    (0x1D473, 7210, 1),
+    (0x1D474, 7211, 1),
+    (0x1D475, 7212, 1),
+    (0x1D476, 7213, 1),
+    (0x1D477, 7214, 1),
+    (0x1D478, 7215, 1),
+    (0x1D479, 7216, 1),
+    (0x1D47A, 7217, 1),
+    (0x1D47B, 7218, 1),
+    (0x1D47C, 7219, 1),
+    (0x1D47D, 7220, 1),
+    (0x1D47E, 7221, 1),
+    (0x1D47F, 7222, 1),
+    (0x1D480, 7223, 1),
+    (0x1D481, 7224, 1),
+    (0x1D482, 7225, 1),
+    (0x1D483, 7226, 1),
+    (0x1D484, 7227, 1),
+    (0x1D485, 7228, 1),
+    (0x1D486, 7229, 1),
+    (0x1D487, 7230, 1),
+    (0x1D488, 7231, 1),
+    (0x1D489, 7232, 1),
+    (0x1D48A, 7233, 1),
+    (0x1D48B, 7234, 1),
+    (0x1D48C, 7235, 1),
+    (0x1D48D, 7236, 1),
+    (0x1D48E, 7237, 1),
+    (0x1D48F, 7238, 1),
+    (0x1D490, 7239, 1),
+    (0x1D491, 7240, 1),
+    (0x1D492, 7241, 1),
+    (0x1D493, 7242, 1),
+    (0x1D494, 7243, 1),
+    (0x1D495, 7244, 1),
+    (0x1D496, 7245, 1),
+    (0x1D497, 7246, 1),
+    (0x1D498, 7247, 1),
+    (0x1D499, 7248, 1),
+    (0x1D49A, 7249, 1),
+    (0x1D49B, 7250, 1),
+    (0x1D49C, 7251, 1),
+    (0x1D49E, 7252, 1),
+    (0x1D49F, 7253, 1),
+    (0x1D4A2, 7254, 1),
+    (0x1D4A5, 7255, 1),
+    (0x1D4A6, 7256, 1),
+    (0x1D4A9, 7257, 1),
+    (0x1D4AA, 7258, 1),
+    (0x1D4AB, 7259, 1),
+    (0x1D4AC, 7260, 1),
+    (0x1D4AE, 7261, 1),
+    (0x1D4AF, 7262, 1),
+    (0x1D4B0, 7263, 1),
+    (0x1D4B1, 7264, 1),
+    (0x1D4B2, 7265, 1),
+    (0x1D4B3, 7266, 1),
+    (0x1D4B4, 7267, 1),
+    (0x1D4B5, 7268, 1),
+    (0x1D4B6, 7269, 1),
+    (0x1D4B7, 7270, 1),
+    (0x1D4B8, 7271, 1),
+    (0x1D4B9, 7272, 1),
+    (0x1D4BB, 7273, 1),
+    (0x1D4BD, 7274, 1),
+    (0x1D4BE, 7275, 1),
+    (0x1D4BF, 7276, 1),
+    (0x1D4C0, 7277, 1),
+    (0x1D4C1, 7278, 1),
+    (0x1D4C2, 7279, 1),
+    (0x1D4C3, 7280, 1),
+    (0x1D4C5, 7281, 1),
+    (0x1D4C6, 7282, 1),
+    (0x1D4C7, 7283, 1),
+    (0x1D4C8, 7284, 1),
+    (0x1D4C9, 7285, 1),
+    (0x1D4CA, 7286, 1),
+    (0x1D4CB, 7287, 1),
+    (0x1D4CC, 7288, 1),
+    (0x1D4CD, 7289, 1),
+    (0x1D4CE, 7290, 1),
+    (0x1D4CF, 7291, 1),
+    (0x1D4D0, 7292, 1),
+    (0x1D4D1, 7293, 1),
+    (0x1D4D2, 7294, 1),
+    (0x1D4D3, 7295, 1),
+    (0x1D4D4, 7296, 1),
+    (0x1D4D5, 7297, 1),
+    (0x1D4D6, 7298, 1),
+    (0x1D4D7, 7299, 1),
+    (0x1D4D8, 7300, 1),
+    (0x1D4D9, 7301, 1),
+    (0x1D4DA, 7302, 1),
+    (0x1D4DB, 7303, 1),
+    (0x1D4DC, 7304, 1),
+    (0x1D4DD, 7305, 1),
+    (0x1D4DE, 7306, 1),
+    (0x1D4DF, 7307, 1),
+    (0x1D4E0, 7308, 1),
+    (0x1D4E1, 7309, 1),
+    (0x1D4E2, 7310, 1),
+    (0x1D4E3, 7311, 1),
+    (0x1D4E4, 7312, 1),
+    (0x1D4E5, 7313, 1),
+    (0x1D4E6, 7314, 1),
+    (0x1D4E7, 7315, 1),
+    (0x1D4E8, 7316, 1),
+    (0x1D4E9, 7317, 1),
+    (0x1D4EA, 7318, 1),
+    (0x1D4EB, 7319, 1),
+    (0x1D4EC, 7320, 1),
+    (0x1D4ED, 7321, 1),
+    (0x1D4EE, 7322, 1),
+    (0x1D4EF, 7323, 1),
+    (0x1D4F0, 7324, 1),
+    (0x1D4F1, 7325, 1),
+    (0x1D4F2, 7326, 1),
+    (0x1D4F3, 7327, 1),
+    (0x1D4F4, 7328, 1),
+    (0x1D4F5, 7329, 1),
+    (0x1D4F6, 7330, 1),
+    (0x1D4F7, 7331, 1),
+    (0x1D4F8, 7332, 1),
+    (0x1D4F9, 7333, 1),
+    (0x1D4FA, 7334, 1),
+    (0x1D4FB, 7335, 1),
+    (0x1D4FC, 7336, 1),
+    (0x1D4FD, 7337, 1),
+    (0x1D4FE, 7338, 1),
+    (0x1D4FF, 7339, 1),
+    (0x1D500, 7340, 1),
+    (0x1D501, 7341, 1),
+    (0x1D502, 7342, 1),
+    (0x1D503, 7343, 1),
+    (0x1D504, 7344, 1),
+    (0x1D505, 7345, 1),
+    (0x1D507, 7346, 1),
+    (0x1D508, 7347, 1),
+    (0x1D509, 7348, 1),
+    (0x1D50A, 7349, 1),
+    (0x1D50D, 7350, 1),
+    (0x1D50E, 7351, 1),
+    (0x1D50F, 7352, 1),
+    (0x1D510, 7353, 1),
+    (0x1D511, 7354, 1),
+    (0x1D512, 7355, 1),
+    (0x1D513, 7356, 1),
+    (0x1D514, 7357, 1),
+    (0x1D516, 7358, 1),
+    (0x1D517, 7359, 1),
+    (0x1D518, 7360, 1),
+    (0x1D519, 7361, 1),
+    (0x1D51A, 7362, 1),
+    (0x1D51B, 7363, 1),
+    (0x1D51C, 7364, 1),
+    (0x1D51E, 7365, 1),
+    (0x1D51F, 7366, 1),
+    (0x1D520, 7367, 1),
+    (0x1D521, 7368, 1),
+    (0x1D522, 7369, 1),
+    (0x1D523, 7370, 1),
+    (0x1D524, 7371, 1),
+    (0x1D525, 7372, 1),
+    (0x1D526, 7373, 1),
+    (0x1D527, 7374, 1),
+    (0x1D528, 7375, 1),
+    (0x1D529, 7376, 1),
+    (0x1D52A, 7377, 1),
+    (0x1D52B, 7378, 1),
+    (0x1D52C, 7379, 1),
+    (0x1D52D, 7380, 1),
+    (0x1D52E, 7381, 1),
+    (0x1D52F, 7382, 1),
+    (0x1D530, 7383, 1),
+    (0x1D531, 7384, 1),
+    (0x1D532, 7385, 1),
+    (0x1D533, 7386, 1),
+    (0x1D534, 7387, 1),
+    (0x1D535, 7388, 1),
+    (0x1D536, 7389, 1),
+    (0x1D537, 7390, 1),
+    (0x1D538, 7391, 1),
+    (0x1D539, 7392, 1),
+    (0x1D53B, 7393, 1),
+    (0x1D53C, 7394, 1),
+    (0x1D53D, 7395, 1),
+    (0x1D53E, 7396, 1),
+    (0x1D540, 7397, 1),
+    (0x1D541, 7398, 1),
+    (0x1D542, 7399, 1),
+    (0x1D543, 7400, 1),
+    (0x1D544, 7401, 1),
+    (0x1D546, 7402, 1),
+    (0x1D54A, 7403, 1),
+    (0x1D54B, 7404, 1),
+    (0x1D54C, 7405, 1),
+    (0x1D54D, 7406, 1),
+    (0x1D54E, 7407, 1),
+    (0x1D54F, 7408, 1),
+    (0x1D550, 7409, 1),
+    (0x1D552, 7410, 1),
+    (0x1D553, 7411, 1),
+    (0x1D554, 7412, 1),
+    (0x1D555, 7413, 1),
+    (0x1D556, 7414, 1),
+    (0x1D557, 7415, 1),
+    (0x1D558, 7416, 1),
+    (0x1D559, 7417, 1),
+    (0x1D55A, 7418, 1),
+    (0x1D55B, 7419, 1),
+    (0x1D55C, 7420, 1),
+    (0x1D55D, 7421, 1),
+    (0x1D55E, 7422, 1),
+    (0x1D55F, 7423, 1),
+    (0x1D560, 7424, 1),
+    (0x1D561, 7425, 1),
+    (0x1D562, 7426, 1),
+    (0x1D563, 7427, 1),
+    (0x1D564, 7428, 1),
+    (0x1D565, 7429, 1),
+    (0x1D566, 7430, 1),
+    (0x1D567, 7431, 1),
+    (0x1D568, 7432, 1),
+    (0x1D569, 7433, 1),
+    (0x1D56A, 7434, 1),
+    (0x1D56B, 7435, 1),
+    (0x1D56C, 7436, 1),
+    (0x1D56D, 7437, 1),
+    (0x1D56E, 7438, 1),
+    (0x1D56F, 7439, 1),
+    (0x1D570, 7440, 1),
+    (0x1D571, 7441, 1),
+    (0x1D572, 7442, 1),
+    (0x1D573, 7443, 1),
+    (0x1D574, 7444, 1),
+    (0x1D575, 7445, 1),
+    (0x1D576, 7446, 1),
+    (0x1D577, 7447, 1),
+    (0x1D578, 7448, 1),
+    (0x1D579, 7449, 1),
+    (0x1D57A, 7450, 1),
+    (0x1D57B, 7451, 1),
+    (0x1D57C, 7452, 1),
+    (0x1D57D, 7453, 1),
+    (0x1D57E, 7454, 1),
+    (0x1D57F, 7455, 1),
+    (0x1D580, 7456, 1),
+    (0x1D581, 7457, 1),
+    (0x1D582, 7458, 1),
+    (0x1D583, 7459, 1),
+    (0x1D584, 7460, 1),
+    (0x1D585, 7461, 1),
+    (0x1D586, 7462, 1),
+    (0x1D587, 7463, 1),
+    (0x1D588, 7464, 1),
+    (0x1D589, 7465, 1),
+    (0x1D58A, 7466, 1),
+    (0x1D58B, 7467, 1),
+    (0x1D58C, 7468, 1),
+    (0x1D58D, 7469, 1),
+    (0x1D58E, 7470, 1),
+    (0x1D58F, 7471, 1),
+    (0x1D590, 7472, 1),
+    (0x1D591, 7473, 1),
+    (0x1D592, 7474, 1),
+    (0x1D593, 7475, 1),
+    (0x1D594, 7476, 1),
+    (0x1D595, 7477, 1),
+    (0x1D596, 7478, 1),
+    (0x1D597, 7479, 1),
+    (0x1D598, 7480, 1),
+    (0x1D599, 7481, 1),
+    (0x1D59A, 7482, 1),
+    (0x1D59B, 7483, 1),
+    (0x1D59C, 7484, 1),
+    (0x1D59D, 7485, 1),
+    (0x1D59E, 7486, 1),
+    (0x1D59F, 7487, 1),
+    (0x1D5A0, 7488, 1),
+    (0x1D5A1, 7489, 1),
+    (0x1D5A2, 7490, 1),
+    (0x1D5A3, 7491, 1),
+    (0x1D5A4, 7492, 1),
+    (0x1D5A5, 7493, 1),
+    (0x1D5A6, 7494, 1),
+    (0x1D5A7, 7495, 1),
+    (0x1D5A8, 7496, 1),
+    (0x1D5A9, 7497, 1),
+    (0x1D5AA, 7498, 1),
+    (0x1D5AB, 7499, 1),
+    (0x1D5AC, 7500, 1),
+    (0x1D5AD, 7501, 1),
+    (0x1D5AE, 7502, 1),
+    (0x1D5AF, 7503, 1),
+    (0x1D5B0, 7504, 1),
+    (0x1D5B1, 7505, 1),
+    (0x1D5B2, 7506, 1),
+    (0x1D5B3, 7507, 1),
+    (0x1D5B4, 7508, 1),
+    (0x1D5B5, 7509, 1),
+    (0x1D5B6, 7510, 1),
+    (0x1D5B7, 7511, 1),
+    (0x1D5B8, 7512, 1),
+    (0x1D5B9, 7513, 1),
+    (0x1D5BA, 7514, 1),
+    (0x1D5BB, 7515, 1),
+    (0x1D5BC, 7516, 1),
+    (0x1D5BD, 7517, 1),
+    (0x1D5BE, 7518, 1),
+    (0x1D5BF, 7519, 1),
+    (0x1D5C0, 7520, 1),
+    (0x1D5C1, 7521, 1),
+    (0x1D5C2, 7522, 1),
+    (0x1D5C3, 7523, 1),
+    (0x1D5C4, 7524, 1),
+    (0x1D5C5, 7525, 1),
+    (0x1D5C6, 7526, 1),
+    (0x1D5C7, 7527, 1),
+    (0x1D5C8, 7528, 1),
+    (0x1D5C9, 7529, 1),
+    (0x1D5CA, 7530, 1),
+    (0x1D5CB, 7531, 1),
+    (0x1D5CC, 7532, 1),
+    (0x1D5CD, 7533, 1),
+    (0x1D5CE, 7534, 1),
+    (0x1D5CF, 7535, 1),
+    (0x1D5D0, 7536, 1),
+    (0x1D5D1, 7537, 1),
+    (0x1D5D2, 7538, 1),
+    (0x1D5D3, 7539, 1),
+    (0x1D5D4, 7540, 1),
+    (0x1D5D5, 7541, 1),
+    (0x1D5D6, 7542, 1),
+    (0x1D5D7, 7543, 1),
+    (0x1D5D8, 7544, 1),
+    (0x1D5D9, 7545, 1),
+    (0x1D5DA, 7546, 1),
+    (0x1D5DB, 7547, 1),
+    (0x1D5DC, 7548, 1),
+    (0x1D5DD, 7549, 1),
+    (0x1D5DE, 7550, 1),
+    (0x1D5DF, 7551, 1),
+    (0x1D5E0, 7552, 1),
+    (0x1D5E1, 7553, 1),
+    (0x1D5E2, 7554, 1),
+    (0x1D5E3, 7555, 1),
+    (0x1D5E4, 7556, 1),
+    (0x1D5E5, 7557, 1),
+    (0x1D5E6, 7558, 1),
+    (0x1D5E7, 7559, 1),
+    (0x1D5E8, 7560, 1),
+    (0x1D5E9, 7561, 1),
+    (0x1D5EA, 7562, 1),
+    (0x1D5EB, 7563, 1),
+    (0x1D5EC, 7564, 1),
+    (0x1D5ED, 7565, 1),
+    (0x1D5EE, 7566, 1),
+    (0x1D5EF, 7567, 1),
+    (0x1D5F0, 7568, 1),
+    (0x1D5F1, 7569, 1),
+    (0x1D5F2, 7570, 1),
+    (0x1D5F3, 7571, 1),
+    (0x1D5F4, 7572, 1),
+    (0x1D5F5, 7573, 1),
+    (0x1D5F6, 7574, 1),
+    (0x1D5F7, 7575, 1),
+    (0x1D5F8, 7576, 1),
+    (0x1D5F9, 7577, 1),
+    (0x1D5FA, 7578, 1),
+    (0x1D5FB, 7579, 1),
+    (0x1D5FC, 7580, 1),
+    (0x1D5FD, 7581, 1),
+    (0x1D5FE, 7582, 1),
+    (0x1D5FF, 7583, 1),
+    (0x1D600, 7584, 1),
+    (0x1D601, 7585, 1),
+    (0x1D602, 7586, 1),
+    (0x1D603, 7587, 1),
+    (0x1D604, 7588, 1),
+    (0x1D605, 7589, 1),
+    (0x1D606, 7590, 1),
+    (0x1D607, 7591, 1),
+    (0x1D608, 7592, 1),
+    (0x1D609, 7593, 1),
+    (0x1D60A, 7594, 1),
+    (0x1D60B, 7595, 1),
+    (0x1D60C, 7596, 1),
+    (0x1D60D, 7597, 1),
+    (0x1D60E, 7598, 1),
+    (0x1D60F, 7599, 1),
+    (0x1D610, 7600, 1),
+    (0x1D611, 7601, 1),
+    (0x1D612, 7602, 1),
+    (0x1D613, 7603, 1),
+    (0x1D614, 7604, 1),
+    (0x1D615, 7605, 1),
+    (0x1D616, 7606, 1),
+    (0x1D617, 7607, 1),
+    (0x1D618, 7608, 1),
+    (0x1D619, 7609, 1),
+    (0x1D61A, 7610, 1),
+    (0x1D61B, 7611, 1),
+    (0x1D61C, 7612, 1),
+    (0x1D61D, 7613, 1),
+    (0x1D61E, 7614, 1),
+    (0x1D61F, 7615, 1),
+    (0x1D620, 7616, 1),
+    (0x1D621, 7617, 1),
+    (0x1D622, 7618, 1),
+    (0x1D623, 7619, 1),
+    (0x1D624, 7620, 1),
+    (0x1D625, 7621, 1),
+    (0x1D626, 7622, 1),
+    (0x1D627, 7623, 1),
+    (0x1D628, 7624, 1),
+    (0x1D629, 7625, 1),
+    (0x1D62A, 7626, 1),
+    (0x1D62B, 7627, 1),
+    (0x1D62C, 7628, 1),
+    (0x1D62D, 7629, 1),
+    (0x1D62E, 7630, 1),
+    (0x1D62F, 7631, 1),
+    (0x1D630, 7632, 1),
+    (0x1D631, 7633, 1),
+    (0x1D632, 7634, 1),
+    (0x1D633, 7635, 1),
+    (0x1D634, 7636, 1),
+    (0x1D635, 7637, 1),
+    (0x1D636, 7638, 1),
+    (0x1D637, 7639, 1),
+    (0x1D638, 7640, 1),
+    (0x1D639, 7641, 1),
+    (0x1D63A, 7642, 1),
+    (0x1D63B, 7643, 1),
+    (0x1D63C, 7644, 1),
+    (0x1D63D, 7645, 1),
+    (0x1D63E, 7646, 1),
+    (0x1D63F, 7647, 1),
+    (0x1D640, 7648, 1),
+    (0x1D641, 7649, 1),
+    (0x1D642, 7650, 1),
+    (0x1D643, 7651, 1),
+    (0x1D644, 7652, 1),
+    (0x1D645, 7653, 1),
+    (0x1D646, 7654, 1),
+    (0x1D647, 7655, 1),
+    (0x1D648, 7656, 1),
+    (0x1D649, 7657, 1),
+    (0x1D64A, 7658, 1),
+    (0x1D64B, 7659, 1),
+    (0x1D64C, 7660, 1),
+    (0x1D64D, 7661, 1),
+    (0x1D64E, 7662, 1),
+    (0x1D64F, 7663, 1),
+    (0x1D650, 7664, 1),
+    (0x1D651, 7665, 1),
+    (0x1D652, 7666, 1),
+    (0x1D653, 7667, 1),
+    (0x1D654, 7668, 1),
+    (0x1D655, 7669, 1),
+    (0x1D656, 7670, 1),
+    (0x1D657, 7671, 1),
+    (0x1D658, 7672, 1),
+    (0x1D659, 7673, 1),
+    (0x1D65A, 7674, 1),
+    (0x1D65B, 7675, 1),
+    (0x1D65C, 7676, 1),
+    (0x1D65D, 7677, 1),
+    (0x1D65E, 7678, 1),
+    (0x1D65F, 7679, 1),
+    (0x1D660, 7680, 1),
+    (0x1D661, 7681, 1),
+    (0x1D662, 7682, 1),
+    (0x1D663, 7683, 1),
+    (0x1D664, 7684, 1),
+    (0x1D665, 7685, 1),
+    (0x1D666, 7686, 1),
+    (0x1D667, 7687, 1),
+    (0x1D668, 7688, 1),
+    (0x1D669, 7689, 1),
+    (0x1D66A, 7690, 1),
+    (0x1D66B, 7691, 1),
+    (0x1D66C, 7692, 1),
+    (0x1D66D, 7693, 1),
+    (0x1D66E, 7694, 1),
+    (0x1D66F, 7695, 1),
+    (0x1D670, 7696, 1),
+    (0x1D671, 7697, 1),
+    (0x1D672, 7698, 1),
+    (0x1D673, 7699, 1),
+    (0x1D674, 7700, 1),
+    (0x1D675, 7701, 1),
+    (0x1D676, 7702, 1),
+    (0x1D677, 7703, 1),
+    (0x1D678, 7704, 1),
+    (0x1D679, 7705, 1),
+    (0x1D67A, 7706, 1),
+    (0x1D67B, 7707, 1),
+    (0x1D67C, 7708, 1),
+    (0x1D67D, 7709, 1),
+    (0x1D67E, 7710, 1),
+    (0x1D67F, 7711, 1),
+    (0x1D680, 7712, 1),
+    (0x1D681, 7713, 1),
+    (0x1D682, 7714, 1),
+    (0x1D683, 7715, 1),
+    (0x1D684, 7716, 1),
+    (0x1D685, 7717, 1),
+    (0x1D686, 7718, 1),
+    (0x1D687, 7719, 1),
+    (0x1D688, 7720, 1),
+    (0x1D689, 7721, 1),
+    (0x1D68A, 7722, 1),
+    (0x1D68B, 7723, 1),
+    (0x1D68C, 7724, 1),
+    (0x1D68D, 7725, 1),
+    (0x1D68E, 7726, 1),
+    (0x1D68F, 7727, 1),
+    (0x1D690, 7728, 1),
+    (0x1D691, 7729, 1),
+    (0x1D692, 7730, 1),
+    (0x1D693, 7731, 1),
+    (0x1D694, 7732, 1),
+    (0x1D695, 7733, 1),
+    (0x1D696, 7734, 1),
+    (0x1D697, 7735, 1),
+    (0x1D698, 7736, 1),
+    (0x1D699, 7737, 1),
+    (0x1D69A, 7738, 1),
+    (0x1D69B, 7739, 1),
+    (0x1D69C, 7740, 1),
+    (0x1D69D, 7741, 1),
+    (0x1D69E, 7742, 1),
+    (0x1D69F, 7743, 1),
+    (0x1D6A0, 7744, 1),
+    (0x1D6A1, 7745, 1),
+    (0x1D6A2, 7746, 1),
+    (0x1D6A3, 7747, 1),
+    (0x1D6A4, 7748, 1),
+    (0x1D6A5, 7749, 1),
+    (0x1D6A8, 7750, 1),
+    (0x1D6A9, 7751, 1),
+    (0x1D6AA, 7752, 1),
+    (0x1D6AB, 7753, 1),
+    (0x1D6AC, 7754, 1),
+    (0x1D6AD, 7755, 1),
+    (0x1D6AE, 7756, 1),
+    (0x1D6AF, 7757, 1),
+    (0x1D6B0, 7758, 1),
+    (0x1D6B1, 7759, 1),
+    (0x1D6B2, 7760, 1),
+    (0x1D6B3, 7761, 1),
+    (0x1D6B4, 7762, 1),
+    (0x1D6B5, 7763, 1),
+    (0x1D6B6, 7764, 1),
+    (0x1D6B7, 7765, 1),
+    (0x1D6B8, 7766, 1),
+    (0x1D6B9, 7767, 1),
+    (0x1D6BA, 7768, 1),
+    (0x1D6BB, 7769, 1),
+    (0x1D6BC, 7770, 1),
+    (0x1D6BD, 7771, 1),
+    (0x1D6BE, 7772, 1),
+    (0x1D6BF, 7773, 1),
+    (0x1D6C0, 7774, 1),
+    (0x1D6C1, 7775, 1),
+    (0x1D6C2, 7776, 1),
+    (0x1D6C3, 7777, 1),
+    (0x1D6C4, 7778, 1),
+    (0x1D6C5, 7779, 1),
+    (0x1D6C6, 7780, 1),
+    (0x1D6C7, 7781, 1),
+    (0x1D6C8, 7782, 1),
+    (0x1D6C9, 7783, 1),
+    (0x1D6CA, 7784, 1),
+    (0x1D6CB, 7785, 1),
+    (0x1D6CC, 7786, 1),
+    (0x1D6CD, 7787, 1),
+    (0x1D6CE, 7788, 1),
+    (0x1D6CF, 7789, 1),
+    (0x1D6D0, 7790, 1),
+    (0x1D6D1, 7791, 1),
+    (0x1D6D2, 7792, 1),
+    (0x1D6D3, 7793, 1),
+    (0x1D6D4, 7794, 1),
+    (0x1D6D5, 7795, 1),
+    (0x1D6D6, 7796, 1),
+    (0x1D6D7, 7797, 1),
+    (0x1D6D8, 7798, 1),
+    (0x1D6D9, 7799, 1),
+    (0x1D6DA, 7800, 1),
+    (0x1D6DB, 7801, 1),
+    (0x1D6DC, 7802, 1),
+    (0x1D6DD, 7803, 1),
+    (0x1D6DE, 7804, 1),
+    (0x1D6DF, 7805, 1),
+    (0x1D6E0, 7806, 1),
+    (0x1D6E1, 7807, 1),
+    (0x1D6E2, 7808, 1),
+    (0x1D6E3, 7809, 1),
+    (0x1D6E4, 7810, 1),
+    (0x1D6E5, 7811, 1),
+    (0x1D6E6, 7812, 1),
+    (0x1D6E7, 7813, 1),
+    (0x1D6E8, 7814, 1),
+    (0x1D6E9, 7815, 1),
+    (0x1D6EA, 7816, 1),
+    (0x1D6EB, 7817, 1),
+    (0x1D6EC, 7818, 1),
+    (0x1D6ED, 7819, 1),
+    (0x1D6EE, 7820, 1),
+    (0x1D6EF, 7821, 1),
+    (0x1D6F0, 7822, 1),
+    (0x1D6F1, 7823, 1),
+    (0x1D6F2, 7824, 1),
+    (0x1D6F3, 7825, 1),
+    (0x1D6F4, 7826, 1),
+    (0x1D6F5, 7827, 1),
+    (0x1D6F6, 7828, 1),
+    (0x1D6F7, 7829, 1),
+    (0x1D6F8, 7830, 1),
+    (0x1D6F9, 7831, 1),
+    (0x1D6FA, 7832, 1),
+    (0x1D6FB, 7833, 1),
+    (0x1D6FC, 7834, 1),
+    (0x1D6FD, 7835, 1),
+    (0x1D6FE, 7836, 1),
+    (0x1D6FF, 7837, 1),
+    (0x1D700, 7838, 1),
+    (0x1D701, 7839, 1),
+    (0x1D702, 7840, 1),
+    (0x1D703, 7841, 1),
+    (0x1D704, 7842, 1),
+    (0x1D705, 7843, 1),
+    (0x1D706, 7844, 1),
+    (0x1D707, 7845, 1),
+    (0x1D708, 7846, 1),
+    (0x1D709, 7847, 1),
+    (0x1D70A, 7848, 1),
+    (0x1D70B, 7849, 1),
+    (0x1D70C, 7850, 1),
+    (0x1D70D, 7851, 1),
+    (0x1D70E, 7852, 1),
+    (0x1D70F, 7853, 1),
+    (0x1D710, 7854, 1),
+    (0x1D711, 7855, 1),
+    (0x1D712, 7856, 1),
+    (0x1D713, 7857, 1),
+    (0x1D714, 7858, 1),
+    (0x1D715, 7859, 1),
+    (0x1D716, 7860, 1),
+    (0x1D717, 7861, 1),
+    (0x1D718, 7862, 1),
+    (0x1D719, 7863, 1),
+    (0x1D71A, 7864, 1),
+    (0x1D71B, 7865, 1),
+    (0x1D71C, 7866, 1),
+    (0x1D71D, 7867, 1),
+    (0x1D71E, 7868, 1),
+    (0x1D71F, 7869, 1),
+    (0x1D720, 7870, 1),
+    (0x1D721, 7871, 1),
+    (0x1D722, 7872, 1),
+    (0x1D723, 7873, 1),
+    (0x1D724, 7874, 1),
+    (0x1D725, 7875, 1),
+    (0x1D726, 7876, 1),
+    (0x1D727, 7877, 1),
+    (0x1D728, 7878, 1),
+    (0x1D729, 7879, 1),
+    (0x1D72A, 7880, 1),
+    (0x1D72B, 7881, 1),
+    (0x1D72C, 7882, 1),
+    (0x1D72D, 7883, 1),
+    (0x1D72E, 7884, 1),
+    (0x1D72F, 7885, 1),
+    (0x1D730, 7886, 1),
+    (0x1D731, 7887, 1),
+    (0x1D732, 7888, 1),
+    (0x1D733, 7889, 1),
+    (0x1D734, 7890, 1),
+    (0x1D735, 7891, 1),
+    (0x1D736, 7892, 1),
+    (0x1D737, 7893, 1),
+    (0x1D738, 7894, 1),
+    (0x1D739, 7895, 1),
+    (0x1D73A, 7896, 1),
+    (0x1D73B, 7897, 1),
+    (0x1D73C, 7898, 1),
+    (0x1D73D, 7899, 1),
+    (0x1D73E, 7900, 1),
+    (0x1D73F, 7901, 1),
+    (0x1D740, 7902, 1),
+    (0x1D741, 7903, 1),
+    (0x1D742, 7904, 1),
+    (0x1D743, 7905, 1),
+    (0x1D744, 7906, 1),
+    (0x1D745, 7907, 1),
+    (0x1D746, 7908, 1),
+    (0x1D747, 7909, 1),
+    (0x1D748, 7910, 1),
+    (0x1D749, 7911, 1),
+    (0x1D74A, 7912, 1),
+    (0x1D74B, 7913, 1),
+    (0x1D74C, 7914, 1),
+    (0x1D74D, 7915, 1),
+    (0x1D74E, 7916, 1),
+    (0x1D74F, 7917, 1),
+    (0x1D750, 7918, 1),
+    (0x1D751, 7919, 1),
+    (0x1D752, 7920, 1),
+    (0x1D753, 7921, 1),
+    (0x1D754, 7922, 1),
+    (0x1D755, 7923, 1),
+    (0x1D756, 7924, 1),
+    (0x1D757, 7925, 1),
+    (0x1D758, 7926, 1),
+    (0x1D759, 7927, 1),
+    (0x1D75A, 7928, 1),
+    (0x1D75B, 7929, 1),
+    (0x1D75C, 7930, 1),
+    (0x1D75D, 7931, 1),
+    (0x1D75E, 7932, 1),
+    (0x1D75F, 7933, 1),
+    (0x1D760, 7934, 1),
+    (0x1D761, 7935, 1),
+    (0x1D762, 7936, 1),
+    (0x1D763, 7937, 1),
+    (0x1D764, 7938, 1),
+    (0x1D765, 7939, 1),
+    (0x1D766, 7940, 1),
+    (0x1D767, 7941, 1),
+    (0x1D768, 7942, 1),
+    (0x1D769, 7943, 1),
+    (0x1D76A, 7944, 1),
+    (0x1D76B, 7945, 1),
+    (0x1D76C, 7946, 1),
+    (0x1D76D, 7947, 1),
+    (0x1D76E, 7948, 1),
+    (0x1D76F, 7949, 1),
+    (0x1D770, 7950, 1),
+    (0x1D771, 7951, 1),
+    (0x1D772, 7952, 1),
+    (0x1D773, 7953, 1),
+    (0x1D774, 7954, 1),
+    (0x1D775, 7955, 1),
+    (0x1D776, 7956, 1),
+    (0x1D777, 7957, 1),
+    (0x1D778, 7958, 1),
+    (0x1D779, 7959, 1),
+    (0x1D77A, 7960, 1),
+    (0x1D77B, 7961, 1),
+    (0x1D77C, 7962, 1),
+    (0x1D77D, 7963, 1),
+    (0x1D77E, 7964, 1),
+    (0x1D77F, 7965, 1),
+    (0x1D780, 7966, 1),
+    (0x1D781, 7967, 1),
+    (0x1D782, 7968, 1),
+    (0x1D783, 7969, 1),
+    (0x1D784, 7970, 1),
+    (0x1D785, 7971, 1),
+    (0x1D786, 7972, 1),
+    (0x1D787, 7973, 1),
+    (0x1D788, 7974, 1),
+    (0x1D789, 7975, 1),
+    (0x1D78A, 7976, 1),
+    (0x1D78B, 7977, 1),
+    (0x1D78C, 7978, 1),
+    (0x1D78D, 7979, 1),
+    (0x1D78E, 7980, 1),
+    (0x1D78F, 7981, 1),
+    (0x1D790, 7982, 1),
+    (0x1D791, 7983, 1),
+    (0x1D792, 7984, 1),
+    (0x1D793, 7985, 1),
+    (0x1D794, 7986, 1),
+    (0x1D795, 7987, 1),
+    (0x1D796, 7988, 1),
+    (0x1D797, 7989, 1),
+    (0x1D798, 7990, 1),
+    (0x1D799, 7991, 1),
+    (0x1D79A, 7992, 1),
+    (0x1D79B, 7993, 1),
+    (0x1D79C, 7994, 1),
+    (0x1D79D, 7995, 1),
+    (0x1D79E, 7996, 1),
+    (0x1D79F, 7997, 1),
+    (0x1D7A0, 7998, 1),
+    (0x1D7A1, 7999, 1),
+    (0x1D7A2, 8000, 1),
+    (0x1D7A3, 8001, 1),
+    (0x1D7A4, 8002, 1),
+    (0x1D7A5, 8003, 1),
+    (0x1D7A6, 8004, 1),
+    (0x1D7A7, 8005, 1),
+    (0x1D7A8, 8006, 1),
+    (0x1D7A9, 8007, 1),
+    (0x1D7AA, 8008, 1),
+    (0x1D7AB, 8009, 1),
+    (0x1D7AC, 8010, 1),
+    (0x1D7AD, 8011, 1),
+    (0x1D7AE, 8012, 1),
+    (0x1D7AF, 8013, 1),
+    (0x1D7B0, 8014, 1),
+    (0x1D7B1, 8015, 1),
+    (0x1D7B2, 8016, 1),
+    (0x1D7B3, 8017, 1),
+    (0x1D7B4, 8018, 1),
+    (0x1D7B5, 8019, 1),
+    (0x1D7B6, 8020, 1),
+    (0x1D7B7, 8021, 1),
+    (0x1D7B8, 8022, 1),
+    (0x1D7B9, 8023, 1),
+    (0x1D7BA, 8024, 1),
+    (0x1D7BB, 8025, 1),
+    (0x1D7BC, 8026, 1),
+    (0x1D7BD, 8027, 1),
+    (0x1D7BE, 8028, 1),
+    (0x1D7BF, 8029, 1),
+    (0x1D7C0, 8030, 1),
+    (0x1D7C1, 8031, 1),
+    (0x1D7C2, 8032, 1),
+    (0x1D7C3, 8033, 1),
+    (0x1D7C4, 8034, 1),
+    (0x1D7C5, 8035, 1),
+    (0x1D7C6, 8036, 1),
+    (0x1D7C7, 8037, 1),
+    (0x1D7C8, 8038, 1),
+    (0x1D7C9, 8039, 1),
+    (0x1D7CA, 8040, 1),
+    (0x1D7CB, 8041, 1),
+    (0x1D7CE, 8042, 1),
+    (0x1D7CF, 8043, 1),
+    (0x1D7D0, 8044, 1),
+    (0x1D7D1, 8045, 1),
+    (0x1D7D2, 8046, 1),
+    (0x1D7D3, 8047, 1),
+    (0x1D7D4, 8048, 1),
+    (0x1D7D5, 8049, 1),
+    (0x1D7D6, 8050, 1),
+    (0x1D7D7, 8051, 1),
+    (0x1D7D8, 8052, 1),
+    (0x1D7D9, 8053, 1),
+    (0x1D7DA, 8054, 1),
+    (0x1D7DB, 8055, 1),
+    (0x1D7DC, 8056, 1),
+    (0x1D7DD, 8057, 1),
+    (0x1D7DE, 8058, 1),
+    (0x1D7DF, 8059, 1),
+    (0x1D7E0, 8060, 1),
+    (0x1D7E1, 8061, 1),
+    (0x1D7E2, 8062, 1),
+    (0x1D7E3, 8063, 1),
+    (0x1D7E4, 8064, 1),
+    (0x1D7E5, 8065, 1),
+    (0x1D7E6, 8066, 1),
+    (0x1D7E7, 8067, 1),
+    (0x1D7E8, 8068, 1),
+    (0x1D7E9, 8069, 1),
+    (0x1D7EA, 8070, 1),
+    (0x1D7EB, 8071, 1),
+    (0x1D7EC, 8072, 1),
+    (0x1D7ED, 8073, 1),
+    (0x1D7EE, 8074, 1),
+    (0x1D7EF, 8075, 1),
+    (0x1D7F0, 8076, 1),
+    (0x1D7F1, 8077, 1),
+    (0x1D7F2, 8078, 1),
+    (0x1D7F3, 8079, 1),
+    (0x1D7F4, 8080, 1),
+    (0x1D7F5, 8081, 1),
+    (0x1D7F6, 8082, 1),
+    (0x1D7F7, 8083, 1),
+    (0x1D7F8, 8084, 1),
+    (0x1D7F9, 8085, 1),
+    (0x1D7FA, 8086, 1),
+    (0x1D7FB, 8087, 1),
+    (0x1D7FC, 8088, 1),
+    (0x1D7FD, 8089, 1),
+    (0x1D7FE, 8090, 1),
+    (0x1D7FF, 8091, 1),
+    (0x1EE00, 8092, 1),
+    (0x1EE01, 8093, 1),
+    (0x1EE02, 8094, 1),
+    (0x1EE03, 8095, 1),
+    (0x1EE05, 8096, 1),
+    (0x1EE06, 8097, 1),
+    (0x1EE07, 8098, 1),
+    (0x1EE08, 8099, 1),
+    (0x1EE09, 8100, 1),
+    (0x1EE0A, 8101, 1),
+    (0x1EE0B, 8102, 1),
+    (0x1EE0C, 8103, 1),
+    (0x1EE0D, 8104, 1),
+    (0x1EE0E, 8105, 1),
+    (0x1EE0F, 8106, 1),
+    (0x1EE10, 8107, 1),
+    (0x1EE11, 8108, 1),
+    (0x1EE12, 8109, 1),
+    (0x1EE13, 8110, 1),
+    (0x1EE14, 8111, 1),
+    (0x1EE15, 8112, 1),
+    (0x1EE16, 8113, 1),
+    (0x1EE17, 8114, 1),
+    (0x1EE18, 8115, 1),
+    (0x1EE19, 8116, 1),
+    (0x1EE1A, 8117, 1),
+    (0x1EE1B, 8118, 1),
+    (0x1EE1C, 8119, 1),
+    (0x1EE1D, 8120, 1),
+    (0x1EE1E, 8121, 1),
+    (0x1EE1F, 8122, 1),
+    (0x1EE21, 8123, 1),
+    (0x1EE22, 8124, 1),
+    (0x1EE24, 8125, 1),
+    (0x1EE27, 8126, 1),
+    (0x1EE29, 8127, 1),
+    (0x1EE2A, 8128, 1),
+    (0x1EE2B, 8129, 1),
+    (0x1EE2C, 8130, 1),
+    (0x1EE2D, 8131, 1),
+    (0x1EE2E, 8132, 1),
+    (0x1EE2F, 8133, 1),
+    (0x1EE30, 8134, 1),
+    (0x1EE31, 8135, 1),
+    (0x1EE32, 8136, 1),
+    (0x1EE34, 8137, 1),
+    (0x1EE35, 8138, 1),
+    (0x1EE36, 8139, 1),
+    (0x1EE37, 8140, 1),
+    (0x1EE39, 8141, 1),
+    (0x1EE3B, 8142, 1),
+    (0x1EE42, 8143, 1),
+    (0x1EE47, 8144, 1),
+    (0x1EE49, 8145, 1),
+    (0x1EE4B, 8146, 1),
+    (0x1EE4D, 8147, 1),
+    (0x1EE4E, 8148, 1),
+    (0x1EE4F, 8149, 1),
+    (0x1EE51, 8150, 1),
+    (0x1EE52, 8151, 1),
+    (0x1EE54, 8152, 1),
+    (0x1EE57, 8153, 1),
+    (0x1EE59, 8154, 1),
+    (0x1EE5B, 8155, 1),
+    (0x1EE5D, 8156, 1),
+    (0x1EE5F, 8157, 1),
+    (0x1EE61, 8158, 1),
+    (0x1EE62, 8159, 1),
+    (0x1EE64, 8160, 1),
+    (0x1EE67, 8161, 1),
+    (0x1EE68, 8162, 1),
+    (0x1EE69, 8163, 1),
+    (0x1EE6A, 8164, 1),
+    (0x1EE6C, 8165, 1),
+    (0x1EE6D, 8166, 1),
+    (0x1EE6E, 8167, 1),
+    (0x1EE6F, 8168, 1),
+    (0x1EE70, 8169, 1),
+    (0x1EE71, 8170, 1),
+    (0x1EE72, 8171, 1),
+    (0x1EE74, 8172, 1),
+    (0x1EE75, 8173, 1),
+    (0x1EE76, 8174, 1),
+    (0x1EE77, 8175, 1),
+    (0x1EE79, 8176, 1),
+    (0x1EE7A, 8177, 1),
+    (0x1EE7B, 8178, 1),
+    (0x1EE7C, 8179, 1),
+    (0x1EE7E, 8180, 1),
+    (0x1EE80, 8181, 1),
+    (0x1EE81, 8182, 1),
+    (0x1EE82, 8183, 1),
+    (0x1EE83, 8184, 1),
+    (0x1EE84, 8185, 1),
+    (0x1EE85, 8186, 1),
+    (0x1EE86, 8187, 1),
+    (0x1EE87, 8188, 1),
+    (0x1EE88, 8189, 1),
+    (0x1EE89, 8190, 1),
+    (0x1EE8B, 8191, 1),
+    (0x1EE8C, 8192, 1),
+    (0x1EE8D, 8193, 1),
+    (0x1EE8E, 8194, 1),
+    (0x1EE8F, 8195, 1),
+    (0x1EE90, 8196, 1),
+    (0x1EE91, 8197, 1),
+    (0x1EE92, 8198, 1),
+    (0x1EE93, 8199, 1),
+    (0x1EE94, 8200, 1),
+    (0x1EE95, 8201, 1),
+    (0x1EE96, 8202, 1),
+    (0x1EE97, 8203, 1),
+    (0x1EE98, 8204, 1),
+    (0x1EE99, 8205, 1),
+    (0x1EE9A, 8206, 1),
+    (0x1EE9B, 8207, 1),
+    (0x1EEA1, 8208, 1),
+    (0x1EEA2, 8209, 1),
+    (0x1EEA3, 8210, 1),
+    (0x1EEA5, 8211, 1),
+    (0x1EEA6, 8212, 1),
+    (0x1EEA7, 8213, 1),
+    (0x1EEA8, 8214, 1),
+    (0x1EEA9, 8215, 1),
+    (0x1EEAB, 8216, 1),
+    (0x1EEAC, 8217, 1),
+    (0x1EEAD, 8218, 1),
+    (0x1EEAE, 8219, 1),
+    (0x1EEAF, 8220, 1),
+    (0x1EEB0, 8221, 1),
+    (0x1EEB1, 8222, 1),
+    (0x1EEB2, 8223, 1),
+    (0x1EEB3, 8224, 1),
+    (0x1EEB4, 8225, 1),
+    (0x1EEB5, 8226, 1),
+    (0x1EEB6, 8227, 1),
+    (0x1EEB7, 8228, 1),
+    (0x1EEB8, 8229, 1),
+    (0x1EEB9, 8230, 1),
+    (0x1EEBA, 8231, 1),
+    (0x1EEBB, 8232, 1),
+    (0x1F100, 8233, 2),
+    (0x1F101, 8235, 2),
+    (0x1F102, 8237, 2),
+    (0x1F103, 8239, 2),
+    (0x1F104, 8241, 2),
+    (0x1F105, 8243, 2),
+    (0x1F106, 8245, 2),
+    (0x1F107, 8247, 2),
+    (0x1F108, 8249, 2),
+    (0x1F109, 8251, 2),
+    (0x1F10A, 8253, 2),
+    (0x1F110, 8255, 3),
+    (0x1F111, 8258, 3),
+    (0x1F112, 8261, 3),
+    (0x1F113, 8264, 3),
+    (0x1F114, 8267, 3),
+    (0x1F115, 8270, 3),
+    (0x1F116, 8273, 3),
+    (0x1F117, 8276, 3),
+    (0x1F118, 8279, 3),
+    (0x1F119, 8282, 3),
+    (0x1F11A, 8285, 3),
+    (0x1F11B, 8288, 3),
+    (0x1F11C, 8291, 3),
+    (0x1F11D, 8294, 3),
+    (0x1F11E, 8297, 3),
+    (0x1F11F, 8300, 3),
+    (0x1F120, 8303, 3),
+    (0x1F121, 8306, 3),
+    (0x1F122, 8309, 3),
+    (0x1F123, 8312, 3),
+    (0x1F124, 8315, 3),
+    (0x1F125, 8318, 3),
+    (0x1F126, 8321, 3),
+    (0x1F127, 8324, 3),
+    (0x1F128, 8327, 3),
+    (0x1F129, 8330, 3),
+    (0x1F12A, 8333, 3),
+    (0x1F12B, 8336, 1),
+    (0x1F12C, 8337, 1),
+    (0x1F12D, 8338, 2),
+    (0x1F12E, 8340, 2),
+    (0x1F130, 8342, 1),
+    (0x1F131, 8343, 1),
+    (0x1F132, 8344, 1),
+    (0x1F133, 8345, 1),
+    (0x1F134, 8346, 1),
+    (0x1F135, 8347, 1),
+    (0x1F136, 8348, 1),
+    (0x1F137, 8349, 1),
+    (0x1F138, 8350, 1),
+    (0x1F139, 8351, 1),
+    (0x1F13A, 8352, 1),
+    (0x1F13B, 8353, 1),
+    (0x1F13C, 8354, 1),
+    (0x1F13D, 8355, 1),
+    (0x1F13E, 8356, 1),
+    (0x1F13F, 8357, 1),
+    (0x1F140, 8358, 1),
+    (0x1F141, 8359, 1),
+    (0x1F142, 8360, 1),
+    (0x1F143, 8361, 1),
+    (0x1F144, 8362, 1),
+    (0x1F145, 8363, 1),
+    (0x1F146, 8364, 1),
+    (0x1F147, 8365, 1),
+    (0x1F148, 8366, 1),
+    (0x1F149, 8367, 1),
+    (0x1F14A, 8368, 2),
+    (0x1F14B, 8370, 2),
+    (0x1F14C, 8372, 2),
+    (0x1F14D, 8374, 2),
+    (0x1F14E, 8376, 3),
+    (0x1F14F, 8379, 2),
+    (0x1F16A, 8381, 2),
+    (0x1F16B, 8383, 2),
+    (0x1F16C, 8385, 2),
+    (0x1F190, 8387, 2),
+    (0x1F200, 8389, 2),
+    (0x1F201, 8391, 2),
+    (0x1F202, 8393, 1),
+    (0x1F210, 8394, 1),
+    (0x1F211, 8395, 1),
+    (0x1F212, 8396, 1),
+    (0x1F213, 8397, 2),
+    (0x1F214, 8399, 1),
+    (0x1F215, 8400, 1),
+    (0x1F216, 8401, 1),
+    (0x1F217, 8402, 1),
+    (0x1F218, 8403, 1),
+    (0x1F219, 8404, 1),
+    (0x1F21A, 8405, 1),
+    (0x1F21B, 8406, 1),
+    (0x1F21C, 8407, 1),
+    (0x1F21D, 8408, 1),
+    (0x1F21E, 8409, 1),
+    (0x1F21F, 8410, 1),
+    (0x1F220, 8411, 1),
+    (0x1F221, 8412, 1),
+    (0x1F222, 8413, 1),
+    (0x1F223, 8414, 1),
+    (0x1F224, 8415, 1),
+    (0x1F225, 8416, 1),
+    (0x1F226, 8417, 1),
+    (0x1F227, 8418, 1),
+    (0x1F228, 8419, 1),
+    (0x1F229, 8420, 1),
+    (0x1F22A, 8421, 1),
+    (0x1F22B, 8422, 1),
+    (0x1F22C, 8423, 1),
+    (0x1F22D, 8424, 1),
+    (0x1F22E, 8425, 1),
+    (0x1F22F, 8426, 1),
+    (0x1F230, 8427, 1),
+    (0x1F231, 8428, 1),
+    (0x1F232, 8429, 1),
+    (0x1F233, 8430, 1),
+    (0x1F234, 8431, 1),
+    (0x1F235, 8432, 1),
+    (0x1F236, 8433, 1),
+    (0x1F237, 8434, 1),
+    (0x1F238, 8435, 1),
+    (0x1F239, 8436, 1),
+    (0x1F23A, 8437, 1),
+    (0x1F23B, 8438, 1),
+    (0x1F240, 8439, 3),
+    (0x1F241, 8442, 3),
+    (0x1F242, 8445, 3),
+    (0x1F243, 8448, 3),
+    (0x1F244, 8451, 3),
+    (0x1F245, 8454, 3),
+    (0x1F246, 8457, 3),
+    (0x1F247, 8460, 3),
+    (0x1F248, 8463, 3),
+    (0x1F250, 8466, 1),
+    (0x1F251, 8467, 1),
+    (0x1FBF0, 8468, 1),
+    (0x1FBF1, 8469, 1),
+    (0x1FBF2, 8470, 1),
+    (0x1FBF3, 8471, 1),
+    (0x1FBF4, 8472, 1),
+    (0x1FBF5, 8473, 1),
+    (0x1FBF6, 8474, 1),
+    (0x1FBF7, 8475, 1),
+    (0x1FBF8, 8476, 1),
+    (0x1FBF9, 8477, 1),
+];
+
+pub(super) static DECOMPOSITION_DATA: [u32; 9020] = [
+    0x0020, 0x0020, 0x0308, 0x0061, 0x0020, 0x0304, 0x0032, 0x0033,
+    0x0020, 0x0301, 0x03BC, 0x0020, 0x0327, 0x0031, 0x006F, 0x0031,
+    0x2044, 0x0034, 0x0031, 0x2044, 0x0032, 0x0033, 0x2044, 0x0034,
+    0x0041, 0x0300, 0x0041, 0x0301, 0x0041, 0x0302, 0x0041, 0x0303,
+    0x0041, 0x0308, 0x0041, 0x030A, 0x0043, 0x0327, 0x0045, 0x0300,
+    0x0045, 0x0301, 0x0045, 0x0302, 0x0045, 0x0308, 0x0049, 0x0300,
+    0x0049, 0x0301, 0x0049, 0x0302, 0x0049, 0x0308, 0x004E, 0x0303,
+    0x004F, 0x0300, 0x004F, 0x0301, 0x004F, 0x0302, 0x004F, 0x0303,
+    0x004F, 0x0308, 0x0055, 0x0300, 0x0055, 0x0301, 0x0055, 0x0302,
+    0x0055, 0x0308, 0x0059, 0x0301, 0x0061, 0x0300, 0x0061, 0x0301,
+    0x0061, 0x0302, 0x0061, 0x0303, 0x0061, 0x0308, 0x0061, 0x030A,
+    0x0063, 0x0327, 0x0065, 0x0300, 0x0065, 0x0301, 0x0065, 0x0302,
+    0x0065, 0x0308, 0x0069, 0x0300, 0x0069, 0x0301, 0x0069, 0x0302,
+    0x0069, 0x0308, 0x006E, 0x0303, 0x006F, 0x0300, 0x006F, 0x0301,
+    0x006F, 0x0302, 0x006F, 0x0303, 0x006F, 0x0308, 0x0075, 0x0300,
+    0x0075, 0x0301, 0x0075, 0x0302, 0x0075, 0x0308, 0x0079, 0x0301,
+    0x0079, 0x0308, 0x0041, 0x0304, 0x0061, 0x0304, 0x0041, 0x0306,
+    0x0061, 0x0306, 0x0041, 0x0328, 0x0061, 0x0328, 0x0043, 0x0301,
+    0x0063, 0x0301, 0x0043, 0x0302, 0x0063, 0x0302, 0x0043, 0x0307,
+    0x0063, 0x0307, 0x0043, 0x030C, 0x0063, 0x030C, 0x0044, 0x030C,
+    0x0064, 0x030C, 0x0045, 0x0304, 0x0065, 0x0304, 0x0045, 0x0306,
+    0x0065, 0x0306, 0x0045, 0x0307, 0x0065, 0x0307, 0x0045, 0x0328,
+    0x0065, 0x0328, 0x0045, 0x030C, 0x0065, 0x030C, 0x0047, 0x0302,
+    0x0067, 0x0302, 0x0047, 0x0306, 0x0067, 0x0306, 0x0047, 0x0307,
+    0x0067, 0x0307, 0x0047, 0x0327, 0x0067, 0x0327, 0x0048, 0x0302,
+    0x0068, 0x0302, 0x0049, 0x0303, 0x0069, 0x0303, 0x0049, 0x0304,
+    0x0069, 0x0304, 0x0049, 0x0306, 0x0069, 0x0306, 0x0049, 0x0328,
+    0x0069, 0x0328, 0x0049, 0x0307, 0x0049, 0x004A, 0x0069, 0x006A,
+    0x004A, 0x0302, 0x006A, 0x0302, 0x004B, 0x0327, 0x006B, 0x0327,
+    0x004C, 0x0301, 0x006C, 0x0301, 0x004C, 0x0327, 0x006C, 0x0327,
+    0x004C, 0x030C, 0x006C, 0x030C, 0x004C, 0x00B7, 0x006C, 0x00B7,
+    0x004E, 0x0301, 0x006E, 0x0301, 0x004E, 0x0327, 0x006E, 0x0327,
+    0x004E, 0x030C, 0x006E, 0x030C, 0x02BC, 0x006E, 0x004F, 0x0304,
+    0x006F, 0x0304, 0x004F, 0x0306, 0x006F, 0x0306, 0x004F, 0x030B,
+    0x006F, 0x030B, 0x0052, 0x0301, 0x0072, 0x0301, 0x0052, 0x0327,
+    0x0072, 0x0327, 0x0052, 0x030C, 0x0072, 0x030C, 0x0053, 0x0301,
+    0x0073, 0x0301, 0x0053, 0x0302, 0x0073, 0x0302, 0x0053, 0x0327,
+    0x0073, 0x0327, 0x0053, 0x030C, 0x0073, 0x030C, 0x0054, 0x0327,
+    0x0074, 0x0327, 0x0054, 0x030C, 0x0074, 0x030C, 0x0055, 0x0303,
+    0x0075, 0x0303, 0x0055, 0x0304, 0x0075, 0x0304, 0x0055, 0x0306,
+    0x0075, 0x0306, 0x0055, 0x030A, 0x0075, 0x030A, 0x0055, 0x030B,
+    0x0075, 0x030B, 0x0055, 0x0328, 0x0075, 0x0328, 0x0057, 0x0302,
+    0x0077, 0x0302, 0x0059, 0x0302, 0x0079, 0x0302, 0x0059, 0x0308,
+    0x005A, 0x0301, 0x007A, 0x0301, 0x005A, 0x0307, 0x007A, 0x0307,
+    0x005A, 0x030C, 0x007A, 0x030C, 0x0073, 0x004F, 0x031B, 0x006F,
+    0x031B, 0x0055, 0x031B, 0x0075, 0x031B, 0x0044, 0x005A, 0x030C,
+    0x0044, 0x007A, 0x030C, 0x0064, 0x007A, 0x030C, 0x004C, 0x004A,
+    0x004C, 0x006A, 0x006C, 0x006A, 0x004E, 0x004A, 0x004E, 0x006A,
+    0x006E, 0x006A, 0x0041, 0x030C, 0x0061, 0x030C, 0x0049, 0x030C,
+    0x0069, 0x030C, 0x004F, 0x030C, 0x006F, 0x030C, 0x0055, 0x030C,
+    0x0075, 0x030C, 0x0055, 0x0308, 0x0304, 0x0075, 0x0308, 0x0304,
+    0x0055, 0x0308, 0x0301, 0x0075, 0x0308, 0x0301, 0x0055, 0x0308,
+    0x030C, 0x0075, 0x0308, 0x030C, 0x0055, 0x0308, 0x0300, 0x0075,
+    0x0308, 0x0300, 0x0041, 0x0308, 0x0304, 0x0061, 0x0308, 0x0304,
+    0x0041, 0x0307, 0x0304, 0x0061, 0x0307, 0x0304, 0x00C6, 0x0304,
+    0x00E6, 0x0304, 0x0047, 0x030C, 0x0067, 0x030C, 0x004B, 0x030C,
+    0x006B, 0x030C, 0x004F, 0x0328, 0x006F, 0x0328, 0x004F, 0x0328,
+    0x0304, 0x006F, 0x0328, 0x0304, 0x01B7, 0x030C, 0x0292, 0x030C,
+    0x006A, 0x030C, 0x0044, 0x005A, 0x0044, 0x007A, 0x0064, 0x007A,
+    0x0047, 0x0301, 0x0067, 0x0301, 0x004E, 0x0300, 0x006E, 0x0300,
+    0x0041, 0x030A, 0x0301, 0x0061, 0x030A, 0x0301, 0x00C6, 0x0301,
+    0x00E6, 0x0301, 0x00D8, 0x0301, 0x00F8, 0x0301, 0x0041, 0x030F,
+    0x0061, 0x030F, 0x0041, 0x0311, 0x0061, 0x0311, 0x0045, 0x030F,
+    0x0065, 0x030F, 0x0045, 0x0311, 0x0065, 0x0311, 0x0049, 0x030F,
+    0x0069, 0x030F, 0x0049, 0x0311, 0x0069, 0x0311, 0x004F, 0x030F,
+    0x006F, 0x030F, 0x004F, 0x0311, 0x006F, 0x0311, 0x0052, 0x030F,
+    0x0072, 0x030F, 0x0052, 0x0311, 0x0072, 0x0311, 0x0055, 0x030F,
+    0x0075, 0x030F, 0x0055, 0x0311, 0x0075, 0x0311, 0x0053, 0x0326,
+    0x0073, 0x0326, 0x0054, 0x0326, 0x0074, 0x0326, 0x0048, 0x030C,
+    0x0068, 0x030C, 0x0041, 0x0307, 0x0061, 0x0307, 0x0045, 0x0327,
+    0x0065, 0x0327, 0x004F, 0x0308, 0x0304, 0x006F, 0x0308, 0x0304,
+    0x004F, 0x0303, 0x0304, 0x006F, 0x0303, 0x0304, 0x004F, 0x0307,
+    0x006F, 0x0307, 0x004F, 0x0307, 0x0304, 0x006F, 0x0307, 0x0304,
+    0x0059, 0x0304, 0x0079, 0x0304, 0x0068, 0x0266, 0x006A, 0x0072,
+    0x0279, 0x027B, 0x0281, 0x0077, 0x0079, 0x0020, 0x0306, 0x0020,
+    0x0307, 0x0020, 0x030A, 0x0020, 0x0328, 0x0020, 0x0303, 0x0020,
+    0x030B, 0x0263, 0x006C, 0x0073, 0x0078, 0x0295, 0x0300, 0x0301,
+    0x0313, 0x0308, 0x0301, 0x02B9, 0x0020, 0x0345, 0x003B, 0x0020,
+    0x0301, 0x00A8, 0x0301, 0x0020, 0x0308, 0x0301, 0x0391, 0x0301,
+    0x00B7, 0x0395, 0x0301, 0x0397, 0x0301, 0x0399, 0x0301, 0x039F,
+    0x0301, 0x03A5, 0x0301, 0x03A9, 0x0301, 0x03B9, 0x0308, 0x0301,
+    0x0399, 0x0308, 0x03A5, 0x0308, 0x03B1, 0x0301, 0x03B5, 0x0301,
+    0x03B7, 0x0301, 0x03B9, 0x0301, 0x03C5, 0x0308, 0x0301, 0x03B9,
+    0x0308, 0x03C5, 0x0308, 0x03BF, 0x0301, 0x03C5, 0x0301, 0x03C9,
+    0x0301, 0x03B2, 0x03B8, 0x03A5, 0x03D2, 0x0301, 0x03A5, 0x0301,
+    0x03D2, 0x0308, 0x03A5, 0x0308, 0x03C6, 0x03C0, 0x03BA, 0x03C1,
+    0x03C2, 0x0398, 0x03B5, 0x03A3, 0x0415, 0x0300, 0x0415, 0x0308,
+    0x0413, 0x0301, 0x0406, 0x0308, 0x041A, 0x0301, 0x0418, 0x0300,
+    0x0423, 0x0306, 0x0418, 0x0306, 0x0438, 0x0306, 0x0435, 0x0300,
+    0x0435, 0x0308, 0x0433, 0x0301, 0x0456, 0x0308, 0x043A, 0x0301,
+    0x0438, 0x0300, 0x0443, 0x0306, 0x0474, 0x030F, 0x0475, 0x030F,
+    0x0416, 0x0306, 0x0436, 0x0306, 0x0410, 0x0306, 0x0430, 0x0306,
+    0x0410, 0x0308, 0x0430, 0x0308, 0x0415, 0x0306, 0x0435, 0x0306,
+    0x04D8, 0x0308, 0x04D9, 0x0308, 0x0416, 0x0308, 0x0436, 0x0308,
+    0x0417, 0x0308, 0x0437, 0x0308, 0x0418, 0x0304, 0x0438, 0x0304,
+    0x0418, 0x0308, 0x0438, 0x0308, 0x041E, 0x0308, 0x043E, 0x0308,
+    0x04E8, 0x0308, 0x04E9, 0x0308, 0x042D, 0x0308, 0x044D, 0x0308,
+    0x0423, 0x0304, 0x0443, 0x0304, 0x0423, 0x0308, 0x0443, 0x0308,
+    0x0423, 0x030B, 0x0443, 0x030B, 0x0427, 0x0308, 0x0447, 0x0308,
+    0x042B, 0x0308, 0x044B, 0x0308, 0x0565, 0x0582, 0x0627, 0x0653,
+    0x0627, 0x0654, 0x0648, 0x0654, 0x0627, 0x0655, 0x064A, 0x0654,
+    0x0627, 0x0674, 0x0648, 0x0674, 0x06C7, 0x0674, 0x064A, 0x0674,
+    0x06D5, 0x0654, 0x06C1, 0x0654, 0x06D2, 0x0654, 0x0928, 0x093C,
+    0x0930, 0x093C, 0x0933, 0x093C, 0x0915, 0x093C, 0x0916, 0x093C,
+    0x0917, 0x093C, 0x091C, 0x093C, 0x0921, 0x093C, 0x0922, 0x093C,
+    0x092B, 0x093C, 0x092F, 0x093C, 0x09C7, 0x09BE, 0x09C7, 0x09D7,
+    0x09A1, 0x09BC, 0x09A2, 0x09BC, 0x09AF, 0x09BC, 0x0A32, 0x0A3C,
+    0x0A38, 0x0A3C, 0x0A16, 0x0A3C, 0x0A17, 0x0A3C, 0x0A1C, 0x0A3C,
+    0x0A2B, 0x0A3C, 0x0B47, 0x0B56, 0x0B47, 0x0B3E, 0x0B47, 0x0B57,
+    0x0B21, 0x0B3C, 0x0B22, 0x0B3C, 0x0B92, 0x0BD7, 0x0BC6, 0x0BBE,
+    0x0BC7, 0x0BBE, 0x0BC6, 0x0BD7, 0x0C46, 0x0C56, 0x0CBF, 0x0CD5,
+    0x0CC6, 0x0CD5, 0x0CC6, 0x0CD6, 0x0CC6, 0x0CC2, 0x0CC6, 0x0CC2,
+    0x0CD5, 0x0D46, 0x0D3E, 0x0D47, 0x0D3E, 0x0D46, 0x0D57, 0x0DD9,
+    0x0DCA, 0x0DD9, 0x0DCF, 0x0DD9, 0x0DCF, 0x0DCA, 0x0DD9, 0x0DDF,
+    0x0E4D, 0x0E32, 0x0ECD, 0x0EB2, 0x0EAB, 0x0E99, 0x0EAB, 0x0EA1,
+    0x0F0B, 0x0F42, 0x0FB7, 0x0F4C, 0x0FB7, 0x0F51, 0x0FB7, 0x0F56,
+    0x0FB7, 0x0F5B, 0x0FB7, 0x0F40, 0x0FB5, 0x0F71, 0x0F72, 0x0F71,
+    0x0F74, 0x0FB2, 0x0F80, 0x0FB2, 0x0F71, 0x0F80, 0x0FB3, 0x0F80,
+    0x0FB3, 0x0F71, 0x0F80, 0x0F71, 0x0F80, 0x0F92, 0x0FB7, 0x0F9C,
+    0x0FB7, 0x0FA1, 0x0FB7, 0x0FA6, 0x0FB7, 0x0FAB, 0x0FB7, 0x0F90,
+    0x0FB5, 0x1025, 0x102E, 0x10DC, 0x1B05, 0x1B35, 0x1B07, 0x1B35,
+    0x1B09, 0x1B35, 0x1B0B, 0x1B35, 0x1B0D, 0x1B35, 0x1B11, 0x1B35,
+    0x1B3A, 0x1B35, 0x1B3C, 0x1B35, 0x1B3E, 0x1B35, 0x1B3F, 0x1B35,
+    0x1B42, 0x1B35, 0x0041, 0x00C6, 0x0042, 0x0044, 0x0045, 0x018E,
+    0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E,
+    0x004F, 0x0222, 0x0050, 0x0052, 0x0054, 0x0055, 0x0057, 0x0061,
+    0x0250, 0x0251, 0x1D02, 0x0062, 0x0064, 0x0065, 0x0259, 0x025B,
+    0x025C, 0x0067, 0x006B, 0x006D, 0x014B, 0x006F, 0x0254, 0x1D16,
+    0x1D17, 0x0070, 0x0074, 0x0075, 0x1D1D, 0x026F, 0x0076, 0x1D25,
+    0x03B2, 0x03B3, 0x03B4, 0x03C6, 0x03C7, 0x0069, 0x0072, 0x0075,
+    0x0076, 0x03B2, 0x03B3, 0x03C1, 0x03C6, 0x03C7, 0x043D, 0x0252,
+    0x0063, 0x0255, 0x00F0, 0x025C, 0x0066, 0x025F, 0x0261, 0x0265,
+    0x0268, 0x0269, 0x026A, 0x1D7B, 0x029D, 0x026D, 0x1D85, 0x029F,
+    0x0271, 0x0270, 0x0272, 0x0273, 0x0274, 0x0275, 0x0278, 0x0282,
+    0x0283, 0x01AB, 0x0289, 0x028A, 0x1D1C, 0x028B, 0x028C, 0x007A,
+    0x0290, 0x0291, 0x0292, 0x03B8, 0x0041, 0x0325, 0x0061, 0x0325,
+    0x0042, 0x0307, 0x0062, 0x0307, 0x0042, 0x0323, 0x0062, 0x0323,
+    0x0042, 0x0331, 0x0062, 0x0331, 0x0043, 0x0327, 0x0301, 0x0063,
+    0x0327, 0x0301, 0x0044, 0x0307, 0x0064, 0x0307, 0x0044, 0x0323,
+    0x0064, 0x0323, 0x0044, 0x0331, 0x0064, 0x0331, 0x0044, 0x0327,
+    0x0064, 0x0327, 0x0044, 0x032D, 0x0064, 0x032D, 0x0045, 0x0304,
+    0x0300, 0x0065, 0x0304, 0x0300, 0x0045, 0x0304, 0x0301, 0x0065,
+    0x0304, 0x0301, 0x0045, 0x032D, 0x0065, 0x032D, 0x0045, 0x0330,
+    0x0065, 0x0330, 0x0045, 0x0327, 0x0306, 0x0065, 0x0327, 0x0306,
+    0x0046, 0x0307, 0x0066, 0x0307, 0x0047, 0x0304, 0x0067, 0x0304,
+    0x0048, 0x0307, 0x0068, 0x0307, 0x0048, 0x0323, 0x0068, 0x0323,
+    0x0048, 0x0308, 0x0068, 0x0308, 0x0048, 0x0327, 0x0068, 0x0327,
+    0x0048, 0x032E, 0x0068, 0x032E, 0x0049, 0x0330, 0x0069, 0x0330,
+    0x0049, 0x0308, 0x0301, 0x0069, 0x0308, 0x0301, 0x004B, 0x0301,
+    0x006B, 0x0301, 0x004B, 0x0323, 0x006B, 0x0323, 0x004B, 0x0331,
+    0x006B, 0x0331, 0x004C, 0x0323, 0x006C, 0x0323, 0x004C, 0x0323,
+    0x0304, 0x006C, 0x0323, 0x0304, 0x004C, 0x0331, 0x006C, 0x0331,
+    0x004C, 0x032D, 0x006C, 0x032D, 0x004D, 0x0301, 0x006D, 0x0301,
+    0x004D, 0x0307, 0x006D, 0x0307, 0x004D, 0x0323, 0x006D, 0x0323,
+    0x004E, 0x0307, 0x006E, 0x0307, 0x004E, 0x0323, 0x006E, 0x0323,
+    0x004E, 0x0331, 0x006E, 0x0331, 0x004E, 0x032D, 0x006E, 0x032D,
+    0x004F, 0x0303, 0x0301, 0x006F, 0x0303, 0x0301, 0x004F, 0x0303,
+    0x0308, 0x006F, 0x0303, 0x0308, 0x004F, 0x0304, 0x0300, 0x006F,
+    0x0304, 0x0300, 0x004F, 0x0304, 0x0301, 0x006F, 0x0304, 0x0301,
+    0x0050, 0x0301, 0x0070, 0x0301, 0x0050, 0x0307, 0x0070, 0x0307,
+    0x0052, 0x0307, 0x0072, 0x0307, 0x0052, 0x0323, 0x0072, 0x0323,
+    0x0052, 0x0323, 0x0304, 0x0072, 0x0323, 0x0304, 0x0052, 0x0331,
+    0x0072, 0x0331, 0x0053, 0x0307, 0x0073, 0x0307, 0x0053, 0x0323,
+    0x0073, 0x0323, 0x0053, 0x0301, 0x0307, 0x0073, 0x0301, 0x0307,
+    0x0053, 0x030C, 0x0307, 0x0073, 0x030C, 0x0307, 0x0053, 0x0323,
+    0x0307, 0x0073, 0x0323, 0x0307, 0x0054, 0x0307, 0x0074, 0x0307,
+    0x0054, 0x0323, 0x0074, 0x0323, 0x0054, 0x0331, 0x0074, 0x0331,
+    0x0054, 0x032D, 0x0074, 0x032D, 0x0055, 0x0324, 0x0075, 0x0324,
+    0x0055, 0x0330, 0x0075, 0x0330, 0x0055, 0x032D, 0x0075, 0x032D,
+    0x0055, 0x0303, 0x0301, 0x0075, 0x0303, 0x0301, 0x0055, 0x0304,
+    0x0308, 0x0075, 0x0304, 0x0308, 0x0056, 0x0303, 0x0076, 0x0303,
+    0x0056, 0x0323, 0x0076, 0x0323, 0x0057, 0x0300, 0x0077, 0x0300,
+    0x0057, 0x0301, 0x0077, 0x0301, 0x0057, 0x0308, 0x0077, 0x0308,
+    0x0057, 0x0307, 0x0077, 0x0307, 0x0057, 0x0323, 0x0077, 0x0323,
+    0x0058, 0x0307, 0x0078, 0x0307, 0x0058, 0x0308, 0x0078, 0x0308,
+    0x0059, 0x0307, 0x0079, 0x0307, 0x005A, 0x0302, 0x007A, 0x0302,
+    0x005A, 0x0323, 0x007A, 0x0323, 0x005A, 0x0331, 0x007A, 0x0331,
+    0x0068, 0x0331, 0x0074, 0x0308, 0x0077, 0x030A, 0x0079, 0x030A,
+    0x0061, 0x02BE, 0x017F, 0x0307, 0x0073, 0x0307, 0x0041, 0x0323,
+    0x0061, 0x0323, 0x0041, 0x0309, 0x0061, 0x0309, 0x0041, 0x0302,
+    0x0301, 0x0061, 0x0302, 0x0301, 0x0041, 0x0302, 0x0300, 0x0061,
+    0x0302, 0x0300, 0x0041, 0x0302, 0x0309, 0x0061, 0x0302, 0x0309,
+    0x0041, 0x0302, 0x0303, 0x0061, 0x0302, 0x0303, 0x0041, 0x0323,
+    0x0302, 0x0061, 0x0323, 0x0302, 0x0041, 0x0306, 0x0301, 0x0061,
+    0x0306, 0x0301, 0x0041, 0x0306, 0x0300, 0x0061, 0x0306, 0x0300,
+    0x0041, 0x0306, 0x0309, 0x0061, 0x0306, 0x0309, 0x0041, 0x0306,
+    0x0303, 0x0061, 0x0306, 0x0303, 0x0041, 0x0323, 0x0306, 0x0061,
+    0x0323, 0x0306, 0x0045, 0x0323, 0x0065, 0x0323, 0x0045, 0x0309,
+    0x0065, 0x0309, 0x0045, 0x0303, 0x0065, 0x0303, 0x0045, 0x0302,
+    0x0301, 0x0065, 0x0302, 0x0301, 0x0045, 0x0302, 0x0300, 0x0065,
+    0x0302, 0x0300, 0x0045, 0x0302, 0x0309, 0x0065, 0x0302, 0x0309,
+    0x0045, 0x0302, 0x0303, 0x0065, 0x0302, 0x0303, 0x0045, 0x0323,
+    0x0302, 0x0065, 0x0323, 0x0302, 0x0049, 0x0309, 0x0069, 0x0309,
+    0x0049, 0x0323, 0x0069, 0x0323, 0x004F, 0x0323, 0x006F, 0x0323,
+    0x004F, 0x0309, 0x006F, 0x0309, 0x004F, 0x0302, 0x0301, 0x006F,
+    0x0302, 0x0301, 0x004F, 0x0302, 0x0300, 0x006F, 0x0302, 0x0300,
+    0x004F, 0x0302, 0x0309, 0x006F, 0x0302, 0x0309, 0x004F, 0x0302,
+    0x0303, 0x006F, 0x0302, 0x0303, 0x004F, 0x0323, 0x0302, 0x006F,
+    0x0323, 0x0302, 0x004F, 0x031B, 0x0301, 0x006F, 0x031B, 0x0301,
+    0x004F, 0x031B, 0x0300, 0x006F, 0x031B, 0x0300, 0x004F, 0x031B,
+    0x0309, 0x006F, 0x031B, 0x0309, 0x004F, 0x031B, 0x0303, 0x006F,
+    0x031B, 0x0303, 0x004F, 0x031B, 0x0323, 0x006F, 0x031B, 0x0323,
+    0x0055, 0x0323, 0x0075, 0x0323, 0x0055, 0x0309, 0x0075, 0x0309,
+    0x0055, 0x031B, 0x0301, 0x0075, 0x031B, 0x0301, 0x0055, 0x031B,
+    0x0300, 0x0075, 0x031B, 0x0300, 0x0055, 0x031B, 0x0309, 0x0075,
+    0x031B, 0x0309, 0x0055, 0x031B, 0x0303, 0x0075, 0x031B, 0x0303,
+    0x0055, 0x031B, 0x0323, 0x0075, 0x031B, 0x0323, 0x0059, 0x0300,
+    0x0079, 0x0300, 0x0059, 0x0323, 0x0079, 0x0323, 0x0059, 0x0309,
+    0x0079, 0x0309, 0x0059, 0x0303, 0x0079, 0x0303, 0x03B1, 0x0313,
+    0x03B1, 0x0314, 0x03B1, 0x0313, 0x0300, 0x03B1, 0x0314, 0x0300,
+    0x03B1, 0x0313, 0x0301, 0x03B1, 0x0314, 0x0301, 0x03B1, 0x0313,
+    0x0342, 0x03B1, 0x0314, 0x0342, 0x0391, 0x0313, 0x0391, 0x0314,
+    0x0391, 0x0313, 0x0300, 0x0391, 0x0314, 0x0300, 0x0391, 0x0313,
+    0x0301, 0x0391, 0x0314, 0x0301, 0x0391, 0x0313, 0x0342, 0x0391,
+    0x0314, 0x0342, 0x03B5, 0x0313, 0x03B5, 0x0314, 0x03B5, 0x0313,
+    0x0300, 0x03B5, 0x0314, 0x0300, 0x03B5, 0x0313, 0x0301, 0x03B5,
+    0x0314, 0x0301, 0x0395, 0x0313, 0x0395, 0x0314, 0x0395, 0x0313,
+    0x0300, 0x0395, 0x0314, 0x0300, 0x0395, 0x0313, 0x0301, 0x0395,
+    0x0314, 0x0301, 0x03B7, 0x0313, 0x03B7, 0x0314, 0x03B7, 0x0313,
+    0x0300, 0x03B7, 0x0314, 0x0300, 0x03B7, 0x0313, 0x0301, 0x03B7,
+    0x0314, 0x0301, 0x03B7, 0x0313, 0x0342, 0x03B7, 0x0314, 0x0342,
+    0x0397, 0x0313, 0x0397, 0x0314, 0x0397, 0x0313, 0x0300, 0x0397,
+    0x0314, 0x0300, 0x0397, 0x0313, 0x0301, 0x0397, 0x0314, 0x0301,
+    0x0397, 0x0313, 0x0342, 0x0397, 0x0314, 0x0342, 0x03B9, 0x0313,
+    0x03B9, 0x0314, 0x03B9, 0x0313, 0x0300, 0x03B9, 0x0314, 0x0300,
+    0x03B9, 0x0313, 0x0301, 0x03B9, 0x0314, 0x0301, 0x03B9, 0x0313,
+    0x0342, 0x03B9, 0x0314, 0x0342, 0x0399, 0x0313, 0x0399, 0x0314,
+    0x0399, 0x0313, 0x0300, 0x0399, 0x0314, 0x0300, 0x0399, 0x0313,
+    0x0301, 0x0399, 0x0314, 0x0301, 0x0399, 0x0313, 0x0342, 0x0399,
+    0x0314, 0x0342, 0x03BF, 0x0313, 0x03BF, 0x0314, 0x03BF, 0x0313,
+    0x0300, 0x03BF, 0x0314, 0x0300, 0x03BF, 0x0313, 0x0301, 0x03BF,
+    0x0314, 0x0301, 0x039F, 0x0313, 0x039F, 0x0314, 0x039F, 0x0313,
+    0x0300, 0x039F, 0x0314, 0x0300, 0x039F, 0x0313, 0x0301, 0x039F,
+    0x0314, 0x0301, 0x03C5, 0x0313, 0x03C5, 0x0314, 0x03C5, 0x0313,
+    0x0300, 0x03C5, 0x0314, 0x0300, 0x03C5, 0x0313, 0x0301, 0x03C5,
+    0x0314, 0x0301, 0x03C5, 0x0313, 0x0342, 0x03C5, 0x0314, 0x0342,
+    0x03A5, 0x0314, 0x03A5, 0x0314, 0x0300, 0x03A5, 0x0314, 0x0301,
+    0x03A5, 0x0314, 0x0342, 0x03C9, 0x0313, 0x03C9, 0x0314, 0x03C9,
+    0x0313, 0x0300, 0x03C9, 0x0314, 0x0300, 0x03C9, 0x0313, 0x0301,
+    0x03C9, 0x0314, 0x0301, 0x03C9, 0x0313, 0x0342, 0x03C9, 0x0314,
+    0x0342, 0x03A9, 0x0313, 0x03A9, 0x0314, 0x03A9, 0x0313, 0x0300,
+    0x03A9, 0x0314, 0x0300, 0x03A9, 0x0313, 0x0301, 0x03A9, 0x0314,
+    0x0301, 0x03A9, 0x0313, 0x0342, 0x03A9, 0x0314, 0x0342, 0x03B1,
+    0x0300, 0x03B1, 0x0301, 0x03B5, 0x0300, 0x03B5, 0x0301, 0x03B7,
+    0x0300, 0x03B7, 0x0301, 0x03B9, 0x0300, 0x03B9, 0x0301, 0x03BF,
+    0x0300, 0x03BF, 0x0301, 0x03C5, 0x0300, 0x03C5, 0x0301, 0x03C9,
+    0x0300, 0x03C9, 0x0301, 0x03B1, 0x0313, 0x0345, 0x03B1, 0x0314,
+    0x0345, 0x03B1, 0x0313, 0x0300, 0x0345, 0x03B1, 0x0314, 0x0300,
+    0x0345, 0x03B1, 0x0313, 0x0301, 0x0345, 0x03B1, 0x0314, 0x0301,
+    0x0345, 0x03B1, 0x0313, 0x0342, 0x0345, 0x03B1, 0x0314, 0x0342,
+    0x0345, 0x0391, 0x0313, 0x0345, 0x0391, 0x0314, 0x0345, 0x0391,
+    0x0313, 0x0300, 0x0345, 0x0391, 0x0314, 0x0300, 0x0345, 0x0391,
+    0x0313, 0x0301, 0x0345, 0x0391, 0x0314, 0x0301, 0x0345, 0x0391,
+    0x0313, 0x0342, 0x0345, 0x0391, 0x0314, 0x0342, 0x0345, 0x03B7,
+    0x0313, 0x0345, 0x03B7, 0x0314, 0x0345, 0x03B7, 0x0313, 0x0300,
+    0x0345, 0x03B7, 0x0314, 0x0300, 0x0345, 0x03B7, 0x0313, 0x0301,
+    0x0345, 0x03B7, 0x0314, 0x0301, 0x0345, 0x03B7, 0x0313, 0x0342,
+    0x0345, 0x03B7, 0x0314, 0x0342, 0x0345, 0x0397, 0x0313, 0x0345,
+    0x0397, 0x0314, 0x0345, 0x0397, 0x0313, 0x0300, 0x0345, 0x0397,
+    0x0314, 0x0300, 0x0345, 0x0397, 0x0313, 0x0301, 0x0345, 0x0397,
+    0x0314, 0x0301, 0x0345, 0x0397, 0x0313, 0x0342, 0x0345, 0x0397,
+    0x0314, 0x0342, 0x0345, 0x03C9, 0x0313, 0x0345, 0x03C9, 0x0314,
+    0x0345, 0x03C9, 0x0313, 0x0300, 0x0345, 0x03C9, 0x0314, 0x0300,
+    0x0345, 0x03C9, 0x0313, 0x0301, 0x0345, 0x03C9, 0x0314, 0x0301,
+    0x0345, 0x03C9, 0x0313, 0x0342, 0x0345, 0x03C9, 0x0314, 0x0342,
+    0x0345, 0x03A9, 0x0313, 0x0345, 0x03A9, 0x0314, 0x0345, 0x03A9,
+    0x0313, 0x0300, 0x0345, 0x03A9, 0x0314, 0x0300, 0x0345, 0x03A9,
+    0x0313, 0x0301, 0x0345, 0x03A9, 0x0314, 0x0301, 0x0345, 0x03A9,
+    0x0313, 0x0342, 0x0345, 0x03A9, 0x0314, 0x0342, 0x0345, 0x03B1,
+    0x0306, 0x03B1, 0x0304, 0x03B1, 0x0300, 0x0345, 0x03B1, 0x0345,
+    0x03B1, 0x0301, 0x0345, 0x03B1, 0x0342, 0x03B1, 0x0342, 0x0345,
+    0x0391, 0x0306, 0x0391, 0x0304, 0x0391, 0x0300, 0x0391, 0x0301,
+    0x0391, 0x0345, 0x0020, 0x0313, 0x03B9, 0x0020, 0x0313, 0x0020,
+    0x0342, 0x00A8, 0x0342, 0x0020, 0x0308, 0x0342, 0x03B7, 0x0300,
+    0x0345, 0x03B7, 0x0345, 0x03B7, 0x0301, 0x0345, 0x03B7, 0x0342,
+    0x03B7, 0x0342, 0x0345, 0x0395, 0x0300, 0x0395, 0x0301, 0x0397,
+    0x0300, 0x0397, 0x0301, 0x0397, 0x0345, 0x1FBF, 0x0300, 0x0020,
+    0x0313, 0x0300, 0x1FBF, 0x0301, 0x0020, 0x0313, 0x0301, 0x1FBF,
+    0x0342, 0x0020, 0x0313, 0x0342, 0x03B9, 0x0306, 0x03B9, 0x0304,
+    0x03B9, 0x0308, 0x0300, 0x03B9, 0x0308, 0x0301, 0x03B9, 0x0342,
+    0x03B9, 0x0308, 0x0342, 0x0399, 0x0306, 0x0399, 0x0304, 0x0399,
+    0x0300, 0x0399, 0x0301, 0x1FFE, 0x0300, 0x0020, 0x0314, 0x0300,
+    0x1FFE, 0x0301, 0x0020, 0x0314, 0x0301, 0x1FFE, 0x0342, 0x0020,
+    0x0314, 0x0342, 0x03C5, 0x0306, 0x03C5, 0x0304, 0x03C5, 0x0308,
+    0x0300, 0x03C5, 0x0308, 0x0301, 0x03C1, 0x0313, 0x03C1, 0x0314,
+    0x03C5, 0x0342, 0x03C5, 0x0308, 0x0342, 0x03A5, 0x0306, 0x03A5,
+    0x0304, 0x03A5, 0x0300, 0x03A5, 0x0301, 0x03A1, 0x0314, 0x00A8,
+    0x0300, 0x0020, 0x0308, 0x0300, 0x00A8, 0x0301, 0x0020, 0x0308,
+    0x0301, 0x0060, 0x03C9, 0x0300, 0x0345, 0x03C9, 0x0345, 0x03C9,
+    0x0301, 0x0345, 0x03C9, 0x0342, 0x03C9, 0x0342, 0x0345, 0x039F,
+    0x0300, 0x039F, 0x0301, 0x03A9, 0x0300, 0x03A9, 0x0301, 0x03A9,
+    0x0345, 0x00B4, 0x0020, 0x0301, 0x0020, 0x0314, 0x2002, 0x0020,
+    0x2003, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020,
+    0x0020, 0x0020, 0x0020, 0x2010, 0x0020, 0x0333, 0x002E, 0x002E,
+    0x002E, 0x002E, 0x002E, 0x002E, 0x0020, 0x2032, 0x2032, 0x2032,
+    0x2032, 0x2032, 0x2035, 0x2035, 0x2035, 0x2035, 0x2035, 0x0021,
+    0x0021, 0x0020, 0x0305, 0x003F, 0x003F, 0x003F, 0x0021, 0x0021,
+    0x003F, 0x2032, 0x2032, 0x2032, 0x2032, 0x0020, 0x0030, 0x0069,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x002B, 0x2212,
+    0x003D, 0x0028, 0x0029, 0x006E, 0x0030, 0x0031, 0x0032, 0x0033,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x002B, 0x2212,
+    0x003D, 0x0028, 0x0029, 0x0061, 0x0065, 0x006F, 0x0078, 0x0259,
+    0x0068, 0x006B, 0x006C, 0x006D, 0x006E, 0x0070, 0x0073, 0x0074,
+    0x0052, 0x0073, 0x0061, 0x002F, 0x0063, 0x0061, 0x002F, 0x0073,
+    0x0043, 0x00B0, 0x0043, 0x0063, 0x002F, 0x006F, 0x0063, 0x002F,
+    0x0075, 0x0190, 0x00B0, 0x0046, 0x0067, 0x0048, 0x0048, 0x0048,
+    0x0068, 0x0127, 0x0049, 0x0049, 0x004C, 0x006C, 0x004E, 0x004E,
+    0x006F, 0x0050, 0x0051, 0x0052, 0x0052, 0x0052, 0x0053, 0x004D,
+    0x0054, 0x0045, 0x004C, 0x0054, 0x004D, 0x005A, 0x03A9, 0x005A,
+    0x004B, 0x0041, 0x030A, 0x0042, 0x0043, 0x0065, 0x0045, 0x0046,
+    0x004D, 0x006F, 0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x0069, 0x0046,
+    0x0041, 0x0058, 0x03C0, 0x03B3, 0x0393, 0x03A0, 0x2211, 0x0044,
+    0x0064, 0x0065, 0x0069, 0x006A, 0x0031, 0x2044, 0x0037, 0x0031,
+    0x2044, 0x0039, 0x0031, 0x2044, 0x0031, 0x0030, 0x0031, 0x2044,
+    0x0033, 0x0032, 0x2044, 0x0033, 0x0031, 0x2044, 0x0035, 0x0032,
+    0x2044, 0x0035, 0x0033, 0x2044, 0x0035, 0x0034, 0x2044, 0x0035,
+    0x0031, 0x2044, 0x0036, 0x0035, 0x2044, 0x0036, 0x0031, 0x2044,
+    0x0038, 0x0033, 0x2044, 0x0038, 0x0035, 0x2044, 0x0038, 0x0037,
+    0x2044, 0x0038, 0x0031, 0x2044, 0x0049, 0x0049, 0x0049, 0x0049,
+    0x0049, 0x0049, 0x0049, 0x0056, 0x0056, 0x0056, 0x0049, 0x0056,
+    0x0049, 0x0049, 0x0056, 0x0049, 0x0049, 0x0049, 0x0049, 0x0058,
+    0x0058, 0x0058, 0x0049, 0x0058, 0x0049, 0x0049, 0x004C, 0x0043,
+    0x0044, 0x004D, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069,
+    0x0069, 0x0076, 0x0076, 0x0076, 0x0069, 0x0076, 0x0069, 0x0069,
+    0x0076, 0x0069, 0x0069, 0x0069, 0x0069, 0x0078, 0x0078, 0x0078,
+    0x0069, 0x0078, 0x0069, 0x0069, 0x006C, 0x0063, 0x0064, 0x006D,
+    0x0030, 0x2044, 0x0033, 0x2190, 0x0338, 0x2192, 0x0338, 0x2194,
+    0x0338, 0x21D0, 0x0338, 0x21D4, 0x0338, 0x21D2, 0x0338, 0x2203,
+    0x0338, 0x2208, 0x0338, 0x220B, 0x0338, 0x2223, 0x0338, 0x2225,
+    0x0338, 0x222B, 0x222B, 0x222B, 0x222B, 0x222B, 0x222E, 0x222E,
+    0x222E, 0x222E, 0x222E, 0x223C, 0x0338, 0x2243, 0x0338, 0x2245,
+    0x0338, 0x2248, 0x0338, 0x003D, 0x0338, 0x2261, 0x0338, 0x224D,
+    0x0338, 0x003C, 0x0338, 0x003E, 0x0338, 0x2264, 0x0338, 0x2265,
+    0x0338, 0x2272, 0x0338, 0x2273, 0x0338, 0x2276, 0x0338, 0x2277,
+    0x0338, 0x227A, 0x0338, 0x227B, 0x0338, 0x2282, 0x0338, 0x2283,
+    0x0338, 0x2286, 0x0338, 0x2287, 0x0338, 0x22A2, 0x0338, 0x22A8,
+    0x0338, 0x22A9, 0x0338, 0x22AB, 0x0338, 0x227C, 0x0338, 0x227D,
+    0x0338, 0x2291, 0x0338, 0x2292, 0x0338, 0x22B2, 0x0338, 0x22B3,
+    0x0338, 0x22B4, 0x0338, 0x22B5, 0x0338, 0x3008, 0x3009, 0x0031,
+    0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039,
+    0x0031, 0x0030, 0x0031, 0x0031, 0x0031, 0x0032, 0x0031, 0x0033,
+    0x0031, 0x0034, 0x0031, 0x0035, 0x0031, 0x0036, 0x0031, 0x0037,
+    0x0031, 0x0038, 0x0031, 0x0039, 0x0032, 0x0030, 0x0028, 0x0031,
+    0x0029, 0x0028, 0x0032, 0x0029, 0x0028, 0x0033, 0x0029, 0x0028,
+    0x0034, 0x0029, 0x0028, 0x0035, 0x0029, 0x0028, 0x0036, 0x0029,
+    0x0028, 0x0037, 0x0029, 0x0028, 0x0038, 0x0029, 0x0028, 0x0039,
+    0x0029, 0x0028, 0x0031, 0x0030, 0x0029, 0x0028, 0x0031, 0x0031,
+    0x0029, 0x0028, 0x0031, 0x0032, 0x0029, 0x0028, 0x0031, 0x0033,
+    0x0029, 0x0028, 0x0031, 0x0034, 0x0029, 0x0028, 0x0031, 0x0035,
+    0x0029, 0x0028, 0x0031, 0x0036, 0x0029, 0x0028, 0x0031, 0x0037,
+    0x0029, 0x0028, 0x0031, 0x0038, 0x0029, 0x0028, 0x0031, 0x0039,
+    0x0029, 0x0028, 0x0032, 0x0030, 0x0029, 0x0031, 0x002E, 0x0032,
+    0x002E, 0x0033, 0x002E, 0x0034, 0x002E, 0x0035, 0x002E, 0x0036,
+    0x002E, 0x0037, 0x002E, 0x0038, 0x002E, 0x0039, 0x002E, 0x0031,
+    0x0030, 0x002E, 0x0031, 0x0031, 0x002E, 0x0031, 0x0032, 0x002E,
+    0x0031, 0x0033, 0x002E, 0x0031, 0x0034, 0x002E, 0x0031, 0x0035,
+    0x002E, 0x0031, 0x0036, 0x002E, 0x0031, 0x0037, 0x002E, 0x0031,
+    0x0038, 0x002E, 0x0031, 0x0039, 0x002E, 0x0032, 0x0030, 0x002E,
+    0x0028, 0x0061, 0x0029, 0x0028, 0x0062, 0x0029, 0x0028, 0x0063,
+    0x0029, 0x0028, 0x0064, 0x0029, 0x0028, 0x0065, 0x0029, 0x0028,
+    0x0066, 0x0029, 0x0028, 0x0067, 0x0029, 0x0028, 0x0068, 0x0029,
+    0x0028, 0x0069, 0x0029, 0x0028, 0x006A, 0x0029, 0x0028, 0x006B,
+    0x0029, 0x0028, 0x006C, 0x0029, 0x0028, 0x006D, 0x0029, 0x0028,
+    0x006E, 0x0029, 0x0028, 0x006F, 0x0029, 0x0028, 0x0070, 0x0029,
+    0x0028, 0x0071, 0x0029, 0x0028, 0x0072, 0x0029, 0x0028, 0x0073,
+    0x0029, 0x0028, 0x0074, 0x0029, 0x0028, 0x0075, 0x0029, 0x0028,
+    0x0076, 0x0029, 0x0028, 0x0077, 0x0029, 0x0028, 0x0078, 0x0029,
+    0x0028, 0x0079, 0x0029, 0x0028, 0x007A, 0x0029, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A,
+    0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052,
+    0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068,
+    0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070,
+    0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007A, 0x0030, 0x222B, 0x222B, 0x222B, 0x222B, 0x003A,
+    0x003A, 0x003D, 0x003D, 0x003D, 0x003D, 0x003D, 0x003D, 0x2ADD,
+    0x0338, 0x006A, 0x0056, 0x2D61, 0x6BCD, 0x9F9F, 0x4E00, 0x4E28,
+    0x4E36, 0x4E3F, 0x4E59, 0x4E85, 0x4E8C, 0x4EA0, 0x4EBA, 0x513F,
+    0x5165, 0x516B, 0x5182, 0x5196, 0x51AB, 0x51E0, 0x51F5, 0x5200,
+    0x529B, 0x52F9, 0x5315, 0x531A, 0x5338, 0x5341, 0x535C, 0x5369,
+    0x5382, 0x53B6, 0x53C8, 0x53E3, 0x56D7, 0x571F, 0x58EB, 0x5902,
+    0x590A, 0x5915, 0x5927, 0x5973, 0x5B50, 0x5B80, 0x5BF8, 0x5C0F,
+    0x5C22, 0x5C38, 0x5C6E, 0x5C71, 0x5DDB, 0x5DE5, 0x5DF1, 0x5DFE,
+    0x5E72, 0x5E7A, 0x5E7F, 0x5EF4, 0x5EFE, 0x5F0B, 0x5F13, 0x5F50,
+    0x5F61, 0x5F73, 0x5FC3, 0x6208, 0x6236, 0x624B, 0x652F, 0x6534,
+    0x6587, 0x6597, 0x65A4, 0x65B9, 0x65E0, 0x65E5, 0x66F0, 0x6708,
+    0x6728, 0x6B20, 0x6B62, 0x6B79, 0x6BB3, 0x6BCB, 0x6BD4, 0x6BDB,
+    0x6C0F, 0x6C14, 0x6C34, 0x706B, 0x722A, 0x7236, 0x723B, 0x723F,
+    0x7247, 0x7259, 0x725B, 0x72AC, 0x7384, 0x7389, 0x74DC, 0x74E6,
+    0x7518, 0x751F, 0x7528, 0x7530, 0x758B, 0x7592, 0x7676, 0x767D,
+    0x76AE, 0x76BF, 0x76EE, 0x77DB, 0x77E2, 0x77F3, 0x793A, 0x79B8,
+    0x79BE, 0x7A74, 0x7ACB, 0x7AF9, 0x7C73, 0x7CF8, 0x7F36, 0x7F51,
+    0x7F8A, 0x7FBD, 0x8001, 0x800C, 0x8012, 0x8033, 0x807F, 0x8089,
+    0x81E3, 0x81EA, 0x81F3, 0x81FC, 0x820C, 0x821B, 0x821F, 0x826E,
+    0x8272, 0x8278, 0x864D, 0x866B, 0x8840, 0x884C, 0x8863, 0x897E,
+    0x898B, 0x89D2, 0x8A00, 0x8C37, 0x8C46, 0x8C55, 0x8C78, 0x8C9D,
+    0x8D64, 0x8D70, 0x8DB3, 0x8EAB, 0x8ECA, 0x8F9B, 0x8FB0, 0x8FB5,
+    0x9091, 0x9149, 0x91C6, 0x91CC, 0x91D1, 0x9577, 0x9580, 0x961C,
+    0x96B6, 0x96B9, 0x96E8, 0x9751, 0x975E, 0x9762, 0x9769, 0x97CB,
+    0x97ED, 0x97F3, 0x9801, 0x98A8, 0x98DB, 0x98DF, 0x9996, 0x9999,
+    0x99AC, 0x9AA8, 0x9AD8, 0x9ADF, 0x9B25, 0x9B2F, 0x9B32, 0x9B3C,
+    0x9B5A, 0x9CE5, 0x9E75, 0x9E7F, 0x9EA5, 0x9EBB, 0x9EC3, 0x9ECD,
+    0x9ED1, 0x9EF9, 0x9EFD, 0x9F0E, 0x9F13, 0x9F20, 0x9F3B, 0x9F4A,
+    0x9F52, 0x9F8D, 0x9F9C, 0x9FA0, 0x0020, 0x3012, 0x5341, 0x5344,
+    0x5345, 0x304B, 0x3099, 0x304D, 0x3099, 0x304F, 0x3099, 0x3051,
+    0x3099, 0x3053, 0x3099, 0x3055, 0x3099, 0x3057, 0x3099, 0x3059,
+    0x3099, 0x305B, 0x3099, 0x305D, 0x3099, 0x305F, 0x3099, 0x3061,
+    0x3099, 0x3064, 0x3099, 0x3066, 0x3099, 0x3068, 0x3099, 0x306F,
+    0x3099, 0x306F, 0x309A, 0x3072, 0x3099, 0x3072, 0x309A, 0x3075,
+    0x3099, 0x3075, 0x309A, 0x3078, 0x3099, 0x3078, 0x309A, 0x307B,
+    0x3099, 0x307B, 0x309A, 0x3046, 0x3099, 0x0020, 0x3099, 0x0020,
+    0x309A, 0x309D, 0x3099, 0x3088, 0x308A, 0x30AB, 0x3099, 0x30AD,
+    0x3099, 0x30AF, 0x3099, 0x30B1, 0x3099, 0x30B3, 0x3099, 0x30B5,
+    0x3099, 0x30B7, 0x3099, 0x30B9, 0x3099, 0x30BB, 0x3099, 0x30BD,
+    0x3099, 0x30BF, 0x3099, 0x30C1, 0x3099, 0x30C4, 0x3099, 0x30C6,
+    0x3099, 0x30C8, 0x3099, 0x30CF, 0x3099, 0x30CF, 0x309A, 0x30D2,
+    0x3099, 0x30D2, 0x309A, 0x30D5, 0x3099, 0x30D5, 0x309A, 0x30D8,
+    0x3099, 0x30D8, 0x309A, 0x30DB, 0x3099, 0x30DB, 0x309A, 0x30A6,
+    0x3099, 0x30EF, 0x3099, 0x30F0, 0x3099, 0x30F1, 0x3099, 0x30F2,
+    0x3099, 0x30FD, 0x3099, 0x30B3, 0x30C8, 0x1100, 0x1101, 0x11AA,
+    0x1102, 0x11AC, 0x11AD, 0x1103, 0x1104, 0x1105, 0x11B0, 0x11B1,
+    0x11B2, 0x11B3, 0x11B4, 0x11B5, 0x111A, 0x1106, 0x1107, 0x1108,
+    0x1121, 0x1109, 0x110A, 0x110B, 0x110C, 0x110D, 0x110E, 0x110F,
+    0x1110, 0x1111, 0x1112, 0x1161, 0x1162, 0x1163, 0x1164, 0x1165,
+    0x1166, 0x1167, 0x1168, 0x1169, 0x116A, 0x116B, 0x116C, 0x116D,
+    0x116E, 0x116F, 0x1170, 0x1171, 0x1172, 0x1173, 0x1174, 0x1175,
+    0x1160, 0x1114, 0x1115, 0x11C7, 0x11C8, 0x11CC, 0x11CE, 0x11D3,
+    0x11D7, 0x11D9, 0x111C, 0x11DD, 0x11DF, 0x111D, 0x111E, 0x1120,
+    0x1122, 0x1123, 0x1127, 0x1129, 0x112B, 0x112C, 0x112D, 0x112E,
+    0x112F, 0x1132, 0x1136, 0x1140, 0x1147, 0x114C, 0x11F1, 0x11F2,
+    0x1157, 0x1158, 0x1159, 0x1184, 0x1185, 0x1188, 0x1191, 0x1192,
+    0x1194, 0x119E, 0x11A1, 0x4E00, 0x4E8C, 0x4E09, 0x56DB, 0x4E0A,
+    0x4E2D, 0x4E0B, 0x7532, 0x4E59, 0x4E19, 0x4E01, 0x5929, 0x5730,
+    0x4EBA, 0x0028, 0x1100, 0x0029, 0x0028, 0x1102, 0x0029, 0x0028,
+    0x1103, 0x0029, 0x0028, 0x1105, 0x0029, 0x0028, 0x1106, 0x0029,
+    0x0028, 0x1107, 0x0029, 0x0028, 0x1109, 0x0029, 0x0028, 0x110B,
+    0x0029, 0x0028, 0x110C, 0x0029, 0x0028, 0x110E, 0x0029, 0x0028,
+    0x110F, 0x0029, 0x0028, 0x1110, 0x0029, 0x0028, 0x1111, 0x0029,
+    0x0028, 0x1112, 0x0029, 0x0028, 0x1100, 0x1161, 0x0029, 0x0028,
+    0x1102, 0x1161, 0x0029, 0x0028, 0x1103, 0x1161, 0x0029, 0x0028,
+    0x1105, 0x1161, 0x0029, 0x0028, 0x1106, 0x1161, 0x0029, 0x0028,
+    0x1107, 0x1161, 0x0029, 0x0028, 0x1109, 0x1161, 0x0029, 0x0028,
+    0x110B, 0x1161, 0x0029, 0x0028, 0x110C, 0x1161, 0x0029, 0x0028,
+    0x110E, 0x1161, 0x0029, 0x0028, 0x110F, 0x1161, 0x0029, 0x0028,
+    0x1110, 0x1161, 0x0029, 0x0028, 0x1111, 0x1161, 0x0029, 0x0028,
+    0x1112, 0x1161, 0x0029, 0x0028, 0x110C, 0x116E, 0x0029, 0x0028,
+    0x110B, 0x1169, 0x110C, 0x1165, 0x11AB, 0x0029, 0x0028, 0x110B,
+    0x1169, 0x1112, 0x116E, 0x0029, 0x0028, 0x4E00, 0x0029, 0x0028,
+    0x4E8C, 0x0029, 0x0028, 0x4E09, 0x0029, 0x0028, 0x56DB, 0x0029,
+    0x0028, 0x4E94, 0x0029, 0x0028, 0x516D, 0x0029, 0x0028, 0x4E03,
+    0x0029, 0x0028, 0x516B, 0x0029, 0x0028, 0x4E5D, 0x0029, 0x0028,
+    0x5341, 0x0029, 0x0028, 0x6708, 0x0029, 0x0028, 0x706B, 0x0029,
+    0x0028, 0x6C34, 0x0029, 0x0028, 0x6728, 0x0029, 0x0028, 0x91D1,
+    0x0029, 0x0028, 0x571F, 0x0029, 0x0028, 0x65E5, 0x0029, 0x0028,
+    0x682A, 0x0029, 0x0028, 0x6709, 0x0029, 0x0028, 0x793E, 0x0029,
+    0x0028, 0x540D, 0x0029, 0x0028, 0x7279, 0x0029, 0x0028, 0x8CA1,
+    0x0029, 0x0028, 0x795D, 0x0029, 0x0028, 0x52B4, 0x0029, 0x0028,
+    0x4EE3, 0x0029, 0x0028, 0x547C, 0x0029, 0x0028, 0x5B66, 0x0029,
+    0x0028, 0x76E3, 0x0029, 0x0028, 0x4F01, 0x0029, 0x0028, 0x8CC7,
+    0x0029, 0x0028, 0x5354, 0x0029, 0x0028, 0x796D, 0x0029, 0x0028,
+    0x4F11, 0x0029, 0x0028, 0x81EA, 0x0029, 0x0028, 0x81F3, 0x0029,
+    0x554F, 0x5E7C, 0x6587, 0x7B8F, 0x0050, 0x0054, 0x0045, 0x0032,
+    0x0031, 0x0032, 0x0032, 0x0032, 0x0033, 0x0032, 0x0034, 0x0032,
+    0x0035, 0x0032, 0x0036, 0x0032, 0x0037, 0x0032, 0x0038, 0x0032,
+    0x0039, 0x0033, 0x0030, 0x0033, 0x0031, 0x0033, 0x0032, 0x0033,
+    0x0033, 0x0033, 0x0034, 0x0033, 0x0035, 0x1100, 0x1102, 0x1103,
+    0x1105, 0x1106, 0x1107, 0x1109, 0x110B, 0x110C, 0x110E, 0x110F,
+    0x1110, 0x1111, 0x1112, 0x1100, 0x1161, 0x1102, 0x1161, 0x1103,
+    0x1161, 0x1105, 0x1161, 0x1106, 0x1161, 0x1107, 0x1161, 0x1109,
+    0x1161, 0x110B, 0x1161, 0x110C, 0x1161, 0x110E, 0x1161, 0x110F,
+    0x1161, 0x1110, 0x1161, 0x1111, 0x1161, 0x1112, 0x1161, 0x110E,
+    0x1161, 0x11B7, 0x1100, 0x1169, 0x110C, 0x116E, 0x110B, 0x1174,
+    0x110B, 0x116E, 0x4E00, 0x4E8C, 0x4E09, 0x56DB, 0x4E94, 0x516D,
+    0x4E03, 0x516B, 0x4E5D, 0x5341, 0x6708, 0x706B, 0x6C34, 0x6728,
+    0x91D1, 0x571F, 0x65E5, 0x682A, 0x6709, 0x793E, 0x540D, 0x7279,
+    0x8CA1, 0x795D, 0x52B4, 0x79D8, 0x7537, 0x5973, 0x9069, 0x512A,
+    0x5370, 0x6CE8, 0x9805, 0x4F11, 0x5199, 0x6B63, 0x4E0A, 0x4E2D,
+    0x4E0B, 0x5DE6, 0x53F3, 0x533B, 0x5B97, 0x5B66, 0x76E3, 0x4F01,
+    0x8CC7, 0x5354, 0x591C, 0x0033, 0x0036, 0x0033, 0x0037, 0x0033,
+    0x0038, 0x0033, 0x0039, 0x0034, 0x0030, 0x0034, 0x0031, 0x0034,
+    0x0032, 0x0034, 0x0033, 0x0034, 0x0034, 0x0034, 0x0035, 0x0034,
+    0x0036, 0x0034, 0x0037, 0x0034, 0x0038, 0x0034, 0x0039, 0x0035,
+    0x0030, 0x0031, 0x6708, 0x0032, 0x6708, 0x0033, 0x6708, 0x0034,
+    0x6708, 0x0035, 0x6708, 0x0036, 0x6708, 0x0037, 0x6708, 0x0038,
+    0x6708, 0x0039, 0x6708, 0x0031, 0x0030, 0x6708, 0x0031, 0x0031,
+    0x6708, 0x0031, 0x0032, 0x6708, 0x0048, 0x0067, 0x0065, 0x0072,
+    0x0067, 0x0065, 0x0056, 0x004C, 0x0054, 0x0044, 0x30A2, 0x30A4,
+    0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD, 0x30AF, 0x30B1, 0x30B3,
+    0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD, 0x30BF, 0x30C1, 0x30C4,
+    0x30C6, 0x30C8, 0x30CA, 0x30CB, 0x30CC, 0x30CD, 0x30CE, 0x30CF,
+    0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE, 0x30DF, 0x30E0, 0x30E1,
+    0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9, 0x30EA, 0x30EB, 0x30EC,
+    0x30ED, 0x30EF, 0x30F0, 0x30F1, 0x30F2, 0x4EE4, 0x548C, 0x30A2,
+    0x30CF, 0x309A, 0x30FC, 0x30C8, 0x30A2, 0x30EB, 0x30D5, 0x30A1,
+    0x30A2, 0x30F3, 0x30D8, 0x309A, 0x30A2, 0x30A2, 0x30FC, 0x30EB,
+    0x30A4, 0x30CB, 0x30F3, 0x30AF, 0x3099, 0x30A4, 0x30F3, 0x30C1,
+    0x30A6, 0x30A9, 0x30F3, 0x30A8, 0x30B9, 0x30AF, 0x30FC, 0x30C8,
+    0x3099, 0x30A8, 0x30FC, 0x30AB, 0x30FC, 0x30AA, 0x30F3, 0x30B9,
+    0x30AA, 0x30FC, 0x30E0, 0x30AB, 0x30A4, 0x30EA, 0x30AB, 0x30E9,
+    0x30C3, 0x30C8, 0x30AB, 0x30ED, 0x30EA, 0x30FC, 0x30AB, 0x3099,
+    0x30ED, 0x30F3, 0x30AB, 0x3099, 0x30F3, 0x30DE, 0x30AD, 0x3099,
+    0x30AB, 0x3099, 0x30AD, 0x3099, 0x30CB, 0x30FC, 0x30AD, 0x30E5,
+    0x30EA, 0x30FC, 0x30AD, 0x3099, 0x30EB, 0x30BF, 0x3099, 0x30FC,
+    0x30AD, 0x30ED, 0x30AD, 0x30ED, 0x30AF, 0x3099, 0x30E9, 0x30E0,
+    0x30AD, 0x30ED, 0x30E1, 0x30FC, 0x30C8, 0x30EB, 0x30AD, 0x30ED,
+    0x30EF, 0x30C3, 0x30C8, 0x30AF, 0x3099, 0x30E9, 0x30E0, 0x30AF,
+    0x3099, 0x30E9, 0x30E0, 0x30C8, 0x30F3, 0x30AF, 0x30EB, 0x30BB,
+    0x3099, 0x30A4, 0x30ED, 0x30AF, 0x30ED, 0x30FC, 0x30CD, 0x30B1,
+    0x30FC, 0x30B9, 0x30B3, 0x30EB, 0x30CA, 0x30B3, 0x30FC, 0x30DB,
+    0x309A, 0x30B5, 0x30A4, 0x30AF, 0x30EB, 0x30B5, 0x30F3, 0x30C1,
+    0x30FC, 0x30E0, 0x30B7, 0x30EA, 0x30F3, 0x30AF, 0x3099, 0x30BB,
+    0x30F3, 0x30C1, 0x30BB, 0x30F3, 0x30C8, 0x30BF, 0x3099, 0x30FC,
+    0x30B9, 0x30C6, 0x3099, 0x30B7, 0x30C8, 0x3099, 0x30EB, 0x30C8,
+    0x30F3, 0x30CA, 0x30CE, 0x30CE, 0x30C3, 0x30C8, 0x30CF, 0x30A4,
+    0x30C4, 0x30CF, 0x309A, 0x30FC, 0x30BB, 0x30F3, 0x30C8, 0x30CF,
+    0x309A, 0x30FC, 0x30C4, 0x30CF, 0x3099, 0x30FC, 0x30EC, 0x30EB,
+    0x30D2, 0x309A, 0x30A2, 0x30B9, 0x30C8, 0x30EB, 0x30D2, 0x309A,
+    0x30AF, 0x30EB, 0x30D2, 0x309A, 0x30B3, 0x30D2, 0x3099, 0x30EB,
+    0x30D5, 0x30A1, 0x30E9, 0x30C3, 0x30C8, 0x3099, 0x30D5, 0x30A3,
+    0x30FC, 0x30C8, 0x30D5, 0x3099, 0x30C3, 0x30B7, 0x30A7, 0x30EB,
+    0x30D5, 0x30E9, 0x30F3, 0x30D8, 0x30AF, 0x30BF, 0x30FC, 0x30EB,
+    0x30D8, 0x309A, 0x30BD, 0x30D8, 0x309A, 0x30CB, 0x30D2, 0x30D8,
+    0x30EB, 0x30C4, 0x30D8, 0x309A, 0x30F3, 0x30B9, 0x30D8, 0x309A,
+    0x30FC, 0x30B7, 0x3099, 0x30D8, 0x3099, 0x30FC, 0x30BF, 0x30DB,
+    0x309A, 0x30A4, 0x30F3, 0x30C8, 0x30DB, 0x3099, 0x30EB, 0x30C8,
+    0x30DB, 0x30F3, 0x30DB, 0x309A, 0x30F3, 0x30C8, 0x3099, 0x30DB,
+    0x30FC, 0x30EB, 0x30DB, 0x30FC, 0x30F3, 0x30DE, 0x30A4, 0x30AF,
+    0x30ED, 0x30DE, 0x30A4, 0x30EB, 0x30DE, 0x30C3, 0x30CF, 0x30DE,
+    0x30EB, 0x30AF, 0x30DE, 0x30F3, 0x30B7, 0x30E7, 0x30F3, 0x30DF,
+    0x30AF, 0x30ED, 0x30F3, 0x30DF, 0x30EA, 0x30DF, 0x30EA, 0x30CF,
+    0x3099, 0x30FC, 0x30EB, 0x30E1, 0x30AB, 0x3099, 0x30E1, 0x30AB,
+    0x3099, 0x30C8, 0x30F3, 0x30E1, 0x30FC, 0x30C8, 0x30EB, 0x30E4,
+    0x30FC, 0x30C8, 0x3099, 0x30E4, 0x30FC, 0x30EB, 0x30E6, 0x30A2,
+    0x30F3, 0x30EA, 0x30C3, 0x30C8, 0x30EB, 0x30EA, 0x30E9, 0x30EB,
+    0x30D2, 0x309A, 0x30FC, 0x30EB, 0x30FC, 0x30D5, 0x3099, 0x30EB,
+    0x30EC, 0x30E0, 0x30EC, 0x30F3, 0x30C8, 0x30B1, 0x3099, 0x30F3,
+    0x30EF, 0x30C3, 0x30C8, 0x0030, 0x70B9, 0x0031, 0x70B9, 0x0032,
+    0x70B9, 0x0033, 0x70B9, 0x0034, 0x70B9, 0x0035, 0x70B9, 0x0036,
+    0x70B9, 0x0037, 0x70B9, 0x0038, 0x70B9, 0x0039, 0x70B9, 0x0031,
+    0x0030, 0x70B9, 0x0031, 0x0031, 0x70B9, 0x0031, 0x0032, 0x70B9,
+    0x0031, 0x0033, 0x70B9, 0x0031, 0x0034, 0x70B9, 0x0031, 0x0035,
+    0x70B9, 0x0031, 0x0036, 0x70B9, 0x0031, 0x0037, 0x70B9, 0x0031,
+    0x0038, 0x70B9, 0x0031, 0x0039, 0x70B9, 0x0032, 0x0030, 0x70B9,
+    0x0032, 0x0031, 0x70B9, 0x0032, 0x0032, 0x70B9, 0x0032, 0x0033,
+    0x70B9, 0x0032, 0x0034, 0x70B9, 0x0068, 0x0050, 0x0061, 0x0064,
+    0x0061, 0x0041, 0x0055, 0x0062, 0x0061, 0x0072, 0x006F, 0x0056,
+    0x0070, 0x0063, 0x0064, 0x006D, 0x0064, 0x006D, 0x0032, 0x0064,
+    0x006D, 0x0033, 0x0049, 0x0055, 0x5E73, 0x6210, 0x662D, 0x548C,
+    0x5927, 0x6B63, 0x660E, 0x6CBB, 0x682A, 0x5F0F, 0x4F1A, 0x793E,
+    0x0070, 0x0041, 0x006E, 0x0041, 0x03BC, 0x0041, 0x006D, 0x0041,
+    0x006B, 0x0041, 0x004B, 0x0042, 0x004D, 0x0042, 0x0047, 0x0042,
+    0x0063, 0x0061, 0x006C, 0x006B, 0x0063, 0x0061, 0x006C, 0x0070,
+    0x0046, 0x006E, 0x0046, 0x03BC, 0x0046, 0x03BC, 0x0067, 0x006D,
+    0x0067, 0x006B, 0x0067, 0x0048, 0x007A, 0x006B, 0x0048, 0x007A,
+    0x004D, 0x0048, 0x007A, 0x0047, 0x0048, 0x007A, 0x0054, 0x0048,
+    0x007A, 0x03BC, 0x006C, 0x006D, 0x006C, 0x0064, 0x006C, 0x006B,
+    0x006C, 0x0066, 0x006D, 0x006E, 0x006D, 0x03BC, 0x006D, 0x006D,
+    0x006D, 0x0063, 0x006D, 0x006B, 0x006D, 0x006D, 0x006D, 0x0032,
+    0x0063, 0x006D, 0x0032, 0x006D, 0x0032, 0x006B, 0x006D, 0x0032,
+    0x006D, 0x006D, 0x0033, 0x0063, 0x006D, 0x0033, 0x006D, 0x0033,
+    0x006B, 0x006D, 0x0033, 0x006D, 0x2215, 0x0073, 0x006D, 0x2215,
+    0x0073, 0x0032, 0x0050, 0x0061, 0x006B, 0x0050, 0x0061, 0x004D,
+    0x0050, 0x0061, 0x0047, 0x0050, 0x0061, 0x0072, 0x0061, 0x0064,
+    0x0072, 0x0061, 0x0064, 0x2215, 0x0073, 0x0072, 0x0061, 0x0064,
+    0x2215, 0x0073, 0x0032, 0x0070, 0x0073, 0x006E, 0x0073, 0x03BC,
+    0x0073, 0x006D, 0x0073, 0x0070, 0x0056, 0x006E, 0x0056, 0x03BC,
+    0x0056, 0x006D, 0x0056, 0x006B, 0x0056, 0x004D, 0x0056, 0x0070,
+    0x0057, 0x006E, 0x0057, 0x03BC, 0x0057, 0x006D, 0x0057, 0x006B,
+    0x0057, 0x004D, 0x0057, 0x006B, 0x03A9, 0x004D, 0x03A9, 0x0061,
+    0x002E, 0x006D, 0x002E, 0x0042, 0x0071, 0x0063, 0x0063, 0x0063,
+    0x0064, 0x0043, 0x2215, 0x006B, 0x0067, 0x0043, 0x006F, 0x002E,
+    0x0064, 0x0042, 0x0047, 0x0079, 0x0068, 0x0061, 0x0048, 0x0050,
+    0x0069, 0x006E, 0x004B, 0x004B, 0x004B, 0x004D, 0x006B, 0x0074,
+    0x006C, 0x006D, 0x006C, 0x006E, 0x006C, 0x006F, 0x0067, 0x006C,
+    0x0078, 0x006D, 0x0062, 0x006D, 0x0069, 0x006C, 0x006D, 0x006F,
+    0x006C, 0x0050, 0x0048, 0x0070, 0x002E, 0x006D, 0x002E, 0x0050,
+    0x0050, 0x004D, 0x0050, 0x0052, 0x0073, 0x0072, 0x0053, 0x0076,
+    0x0057, 0x0062, 0x0056, 0x2215, 0x006D, 0x0041, 0x2215, 0x006D,
+    0x0031, 0x65E5, 0x0032, 0x65E5, 0x0033, 0x65E5, 0x0034, 0x65E5,
+    0x0035, 0x65E5, 0x0036, 0x65E5, 0x0037, 0x65E5, 0x0038, 0x65E5,
+    0x0039, 0x65E5, 0x0031, 0x0030, 0x65E5, 0x0031, 0x0031, 0x65E5,
+    0x0031, 0x0032, 0x65E5, 0x0031, 0x0033, 0x65E5, 0x0031, 0x0034,
+    0x65E5, 0x0031, 0x0035, 0x65E5, 0x0031, 0x0036, 0x65E5, 0x0031,
+    0x0037, 0x65E5, 0x0031, 0x0038, 0x65E5, 0x0031, 0x0039, 0x65E5,
+    0x0032, 0x0030, 0x65E5, 0x0032, 0x0031, 0x65E5, 0x0032, 0x0032,
+    0x65E5, 0x0032, 0x0033, 0x65E5, 0x0032, 0x0034, 0x65E5, 0x0032,
+    0x0035, 0x65E5, 0x0032, 0x0036, 0x65E5, 0x0032, 0x0037, 0x65E5,
+    0x0032, 0x0038, 0x65E5, 0x0032, 0x0039, 0x65E5, 0x0033, 0x0030,
+    0x65E5, 0x0033, 0x0031, 0x65E5, 0x0067, 0x0061, 0x006C, 0x044A,
+    0x044C, 0xA76F, 0x0126, 0x0153, 0xA727, 0xAB37, 0x026B, 0xAB52,
+    0x028D, 0x8C48, 0x66F4, 0x8ECA, 0x8CC8, 0x6ED1, 0x4E32, 0x53E5,
+    0x9F9C, 0x9F9C, 0x5951, 0x91D1, 0x5587, 0x5948, 0x61F6, 0x7669,
+    0x7F85, 0x863F, 0x87BA, 0x88F8, 0x908F, 0x6A02, 0x6D1B, 0x70D9,
+    0x73DE, 0x843D, 0x916A, 0x99F1, 0x4E82, 0x5375, 0x6B04, 0x721B,
+    0x862D, 0x9E1E, 0x5D50, 0x6FEB, 0x85CD, 0x8964, 0x62C9, 0x81D8,
+    0x881F, 0x5ECA, 0x6717, 0x6D6A, 0x72FC, 0x90CE, 0x4F86, 0x51B7,
+    0x52DE, 0x64C4, 0x6AD3, 0x7210, 0x76E7, 0x8001, 0x8606, 0x865C,
+    0x8DEF, 0x9732, 0x9B6F, 0x9DFA, 0x788C, 0x797F, 0x7DA0, 0x83C9,
+    0x9304, 0x9E7F, 0x8AD6, 0x58DF, 0x5F04, 0x7C60, 0x807E, 0x7262,
+    0x78CA, 0x8CC2, 0x96F7, 0x58D8, 0x5C62, 0x6A13, 0x6DDA, 0x6F0F,
+    0x7D2F, 0x7E37, 0x964B, 0x52D2, 0x808B, 0x51DC, 0x51CC, 0x7A1C,
+    0x7DBE, 0x83F1, 0x9675, 0x8B80, 0x62CF, 0x6A02, 0x8AFE, 0x4E39,
+    0x5BE7, 0x6012, 0x7387, 0x7570, 0x5317, 0x78FB, 0x4FBF, 0x5FA9,
+    0x4E0D, 0x6CCC, 0x6578, 0x7D22, 0x53C3, 0x585E, 0x7701, 0x8449,
+    0x8AAA, 0x6BBA, 0x8FB0, 0x6C88, 0x62FE, 0x82E5, 0x63A0, 0x7565,
+    0x4EAE, 0x5169, 0x51C9, 0x6881, 0x7CE7, 0x826F, 0x8AD2, 0x91CF,
+    0x52F5, 0x5442, 0x5973, 0x5EEC, 0x65C5, 0x6FFE, 0x792A, 0x95AD,
+    0x9A6A, 0x9E97, 0x9ECE, 0x529B, 0x66C6, 0x6B77, 0x8F62, 0x5E74,
+    0x6190, 0x6200, 0x649A, 0x6F23, 0x7149, 0x7489, 0x79CA, 0x7DF4,
+    0x806F, 0x8F26, 0x84EE, 0x9023, 0x934A, 0x5217, 0x52A3, 0x54BD,
+    0x70C8, 0x88C2, 0x8AAA, 0x5EC9, 0x5FF5, 0x637B, 0x6BAE, 0x7C3E,
+    0x7375, 0x4EE4, 0x56F9, 0x5BE7, 0x5DBA, 0x601C, 0x73B2, 0x7469,
+    0x7F9A, 0x8046, 0x9234, 0x96F6, 0x9748, 0x9818, 0x4F8B, 0x79AE,
+    0x91B4, 0x96B8, 0x60E1, 0x4E86, 0x50DA, 0x5BEE, 0x5C3F, 0x6599,
+    0x6A02, 0x71CE, 0x7642, 0x84FC, 0x907C, 0x9F8D, 0x6688, 0x962E,
+    0x5289, 0x677B, 0x67F3, 0x6D41, 0x6E9C, 0x7409, 0x7559, 0x786B,
+    0x7D10, 0x985E, 0x516D, 0x622E, 0x9678, 0x502B, 0x5D19, 0x6DEA,
+    0x8F2A, 0x5F8B, 0x6144, 0x6817, 0x7387, 0x9686, 0x5229, 0x540F,
+    0x5C65, 0x6613, 0x674E, 0x68A8, 0x6CE5, 0x7406, 0x75E2, 0x7F79,
+    0x88CF, 0x88E1, 0x91CC, 0x96E2, 0x533F, 0x6EBA, 0x541D, 0x71D0,
+    0x7498, 0x85FA, 0x96A3, 0x9C57, 0x9E9F, 0x6797, 0x6DCB, 0x81E8,
+    0x7ACB, 0x7B20, 0x7C92, 0x72C0, 0x7099, 0x8B58, 0x4EC0, 0x8336,
+    0x523A, 0x5207, 0x5EA6, 0x62D3, 0x7CD6, 0x5B85, 0x6D1E, 0x66B4,
+    0x8F3B, 0x884C, 0x964D, 0x898B, 0x5ED3, 0x5140, 0x55C0, 0x585A,
+    0x6674, 0x51DE, 0x732A, 0x76CA, 0x793C, 0x795E, 0x7965, 0x798F,
+    0x9756, 0x7CBE, 0x7FBD, 0x8612, 0x8AF8, 0x9038, 0x90FD, 0x98EF,
+    0x98FC, 0x9928, 0x9DB4, 0x90DE, 0x96B7, 0x4FAE, 0x50E7, 0x514D,
+    0x52C9, 0x52E4, 0x5351, 0x559D, 0x5606, 0x5668, 0x5840, 0x58A8,
+    0x5C64, 0x5C6E, 0x6094, 0x6168, 0x618E, 0x61F2, 0x654F, 0x65E2,
+    0x6691, 0x6885, 0x6D77, 0x6E1A, 0x6F22, 0x716E, 0x722B, 0x7422,
+    0x7891, 0x793E, 0x7949, 0x7948, 0x7950, 0x7956, 0x795D, 0x798D,
+    0x798E, 0x7A40, 0x7A81, 0x7BC0, 0x7DF4, 0x7E09, 0x7E41, 0x7F72,
+    0x8005, 0x81ED, 0x8279, 0x8279, 0x8457, 0x8910, 0x8996, 0x8B01,
+    0x8B39, 0x8CD3, 0x8D08, 0x8FB6, 0x9038, 0x96E3, 0x97FF, 0x983B,
+    0x6075, 0x242EE, 0x8218, 0x4E26, 0x51B5, 0x5168, 0x4F80, 0x5145,
+    0x5180, 0x52C7, 0x52FA, 0x559D, 0x5555, 0x5599, 0x55E2, 0x585A,
+    0x58B3, 0x5944, 0x5954, 0x5A62, 0x5B28, 0x5ED2, 0x5ED9, 0x5F69,
+    0x5FAD, 0x60D8, 0x614E, 0x6108, 0x618E, 0x6160, 0x61F2, 0x6234,
+    0x63C4, 0x641C, 0x6452, 0x6556, 0x6674, 0x6717, 0x671B, 0x6756,
+    0x6B79, 0x6BBA, 0x6D41, 0x6EDB, 0x6ECB, 0x6F22, 0x701E, 0x716E,
+    0x77A7, 0x7235, 0x72AF, 0x732A, 0x7471, 0x7506, 0x753B, 0x761D,
+    0x761F, 0x76CA, 0x76DB, 0x76F4, 0x774A, 0x7740, 0x78CC, 0x7AB1,
+    0x7BC0, 0x7C7B, 0x7D5B, 0x7DF4, 0x7F3E, 0x8005, 0x8352, 0x83EF,
+    0x8779, 0x8941, 0x8986, 0x8996, 0x8ABF, 0x8AF8, 0x8ACB, 0x8B01,
+    0x8AFE, 0x8AED, 0x8B39, 0x8B8A, 0x8D08, 0x8F38, 0x9072, 0x9199,
+    0x9276, 0x967C, 0x96E3, 0x9756, 0x97DB, 0x97FF, 0x980B, 0x983B,
+    0x9B12, 0x9F9C, 0x2284A, 0x22844, 0x233D5, 0x3B9D, 0x4018, 0x4039,
+    0x25249, 0x25CD0, 0x27ED3, 0x9F43, 0x9F8E, 0x0066, 0x0066, 0x0066,
+    0x0069, 0x0066, 0x006C, 0x0066, 0x0066, 0x0069, 0x0066, 0x0066,
+    0x006C, 0x0073, 0x0074, 0x0073, 0x0074, 0x0574, 0x0576, 0x0574,
+    0x0565, 0x0574, 0x056B, 0x057E, 0x0576, 0x0574, 0x056D, 0x05D9,
+    0x05B4, 0x05F2, 0x05B7, 0x05E2, 0x05D0, 0x05D3, 0x05D4, 0x05DB,
+    0x05DC, 0x05DD, 0x05E8, 0x05EA, 0x002B, 0x05E9, 0x05C1, 0x05E9,
+    0x05C2, 0x05E9, 0x05BC, 0x05C1, 0x05E9, 0x05BC, 0x05C2, 0x05D0,
+    0x05B7, 0x05D0, 0x05B8, 0x05D0, 0x05BC, 0x05D1, 0x05BC, 0x05D2,
+    0x05BC, 0x05D3, 0x05BC, 0x05D4, 0x05BC, 0x05D5, 0x05BC, 0x05D6,
+    0x05BC, 0x05D8, 0x05BC, 0x05D9, 0x05BC, 0x05DA, 0x05BC, 0x05DB,
+    0x05BC, 0x05DC, 0x05BC, 0x05DE, 0x05BC, 0x05E0, 0x05BC, 0x05E1,
+    0x05BC, 0x05E3, 0x05BC, 0x05E4, 0x05BC, 0x05E6, 0x05BC, 0x05E7,
+    0x05BC, 0x05E8, 0x05BC, 0x05E9, 0x05BC, 0x05EA, 0x05BC, 0x05D5,
+    0x05B9, 0x05D1, 0x05BF, 0x05DB, 0x05BF, 0x05E4, 0x05BF, 0x05D0,
+    0x05DC, 0x0671, 0x0671, 0x067B, 0x067B, 0x067B, 0x067B, 0x067E,
+    0x067E, 0x067E, 0x067E, 0x0680, 0x0680, 0x0680, 0x0680, 0x067A,
+    0x067A, 0x067A, 0x067A, 0x067F, 0x067F, 0x067F, 0x067F, 0x0679,
+    0x0679, 0x0679, 0x0679, 0x06A4, 0x06A4, 0x06A4, 0x06A4, 0x06A6,
+    0x06A6, 0x06A6, 0x06A6, 0x0684, 0x0684, 0x0684, 0x0684, 0x0683,
+    0x0683, 0x0683, 0x0683, 0x0686, 0x0686, 0x0686, 0x0686, 0x0687,
+    0x0687, 0x0687, 0x0687, 0x068D, 0x068D, 0x068C, 0x068C, 0x068E,
+    0x068E, 0x0688, 0x0688, 0x0698, 0x0698, 0x0691, 0x0691, 0x06A9,
+    0x06A9, 0x06A9, 0x06A9, 0x06AF, 0x06AF, 0x06AF, 0x06AF, 0x06B3,
+    0x06B3, 0x06B3, 0x06B3, 0x06B1, 0x06B1, 0x06B1, 0x06B1, 0x06BA,
+    0x06BA, 0x06BB, 0x06BB, 0x06BB, 0x06BB, 0x06D5, 0x0654, 0x06D5,
+    0x0654, 0x06C1, 0x06C1, 0x06C1, 0x06C1, 0x06BE, 0x06BE, 0x06BE,
+    0x06BE, 0x06D2, 0x06D2, 0x06D2, 0x0654, 0x06D2, 0x0654, 0x06AD,
+    0x06AD, 0x06AD, 0x06AD, 0x06C7, 0x06C7, 0x06C6, 0x06C6, 0x06C8,
+    0x06C8, 0x06C7, 0x0674, 0x06CB, 0x06CB, 0x06C5, 0x06C5, 0x06C9,
+    0x06C9, 0x06D0, 0x06D0, 0x06D0, 0x06D0, 0x0649, 0x0649, 0x064A,
+    0x0654, 0x0627, 0x064A, 0x0654, 0x0627, 0x064A, 0x0654, 0x06D5,
+    0x064A, 0x0654, 0x06D5, 0x064A, 0x0654, 0x0648, 0x064A, 0x0654,
+    0x0648, 0x064A, 0x0654, 0x06C7, 0x064A, 0x0654, 0x06C7, 0x064A,
+    0x0654, 0x06C6, 0x064A, 0x0654, 0x06C6, 0x064A, 0x0654, 0x06C8,
+    0x064A, 0x0654, 0x06C8, 0x064A, 0x0654, 0x06D0, 0x064A, 0x0654,
+    0x06D0, 0x064A, 0x0654, 0x06D0, 0x064A, 0x0654, 0x0649, 0x064A,
+    0x0654, 0x0649, 0x064A, 0x0654, 0x0649, 0x06CC, 0x06CC, 0x06CC,
+    0x06CC, 0x064A, 0x0654, 0x062C, 0x064A, 0x0654, 0x062D, 0x064A,
+    0x0654, 0x0645, 0x064A, 0x0654, 0x0649, 0x064A, 0x0654, 0x064A,
+    0x0628, 0x062C, 0x0628, 0x062D, 0x0628, 0x062E, 0x0628, 0x0645,
+    0x0628, 0x0649, 0x0628, 0x064A, 0x062A, 0x062C, 0x062A, 0x062D,
+    0x062A, 0x062E, 0x062A, 0x0645, 0x062A, 0x0649, 0x062A, 0x064A,
+    0x062B, 0x062C, 0x062B, 0x0645, 0x062B, 0x0649, 0x062B, 0x064A,
+    0x062C, 0x062D, 0x062C, 0x0645, 0x062D, 0x062C, 0x062D, 0x0645,
+    0x062E, 0x062C, 0x062E, 0x062D, 0x062E, 0x0645, 0x0633, 0x062C,
+    0x0633, 0x062D, 0x0633, 0x062E, 0x0633, 0x0645, 0x0635, 0x062D,
+    0x0635, 0x0645, 0x0636, 0x062C, 0x0636, 0x062D, 0x0636, 0x062E,
+    0x0636, 0x0645, 0x0637, 0x062D, 0x0637, 0x0645, 0x0638, 0x0645,
+    0x0639, 0x062C, 0x0639, 0x0645, 0x063A, 0x062C, 0x063A, 0x0645,
+    0x0641, 0x062C, 0x0641, 0x062D, 0x0641, 0x062E, 0x0641, 0x0645,
+    0x0641, 0x0649, 0x0641, 0x064A, 0x0642, 0x062D, 0x0642, 0x0645,
+    0x0642, 0x0649, 0x0642, 0x064A, 0x0643, 0x0627, 0x0643, 0x062C,
+    0x0643, 0x062D, 0x0643, 0x062E, 0x0643, 0x0644, 0x0643, 0x0645,
+    0x0643, 0x0649, 0x0643, 0x064A, 0x0644, 0x062C, 0x0644, 0x062D,
+    0x0644, 0x062E, 0x0644, 0x0645, 0x0644, 0x0649, 0x0644, 0x064A,
+    0x0645, 0x062C, 0x0645, 0x062D, 0x0645, 0x062E, 0x0645, 0x0645,
+    0x0645, 0x0649, 0x0645, 0x064A, 0x0646, 0x062C, 0x0646, 0x062D,
+    0x0646, 0x062E, 0x0646, 0x0645, 0x0646, 0x0649, 0x0646, 0x064A,
+    0x0647, 0x062C, 0x0647, 0x0645, 0x0647, 0x0649, 0x0647, 0x064A,
+    0x064A, 0x062C, 0x064A, 0x062D, 0x064A, 0x062E, 0x064A, 0x0645,
+    0x064A, 0x0649, 0x064A, 0x064A, 0x0630, 0x0670, 0x0631, 0x0670,
+    0x0649, 0x0670, 0x0020, 0x064C, 0x0651, 0x0020, 0x064D, 0x0651,
+    0x0020, 0x064E, 0x0651, 0x0020, 0x064F, 0x0651, 0x0020, 0x0650,
+    0x0651, 0x0020, 0x0651, 0x0670, 0x064A, 0x0654, 0x0631, 0x064A,
+    0x0654, 0x0632, 0x064A, 0x0654, 0x0645, 0x064A, 0x0654, 0x0646,
+    0x064A, 0x0654, 0x0649, 0x064A, 0x0654, 0x064A, 0x0628, 0x0631,
+    0x0628, 0x0632, 0x0628, 0x0645, 0x0628, 0x0646, 0x0628, 0x0649,
+    0x0628, 0x064A, 0x062A, 0x0631, 0x062A, 0x0632, 0x062A, 0x0645,
+    0x062A, 0x0646, 0x062A, 0x0649, 0x062A, 0x064A, 0x062B, 0x0631,
+    0x062B, 0x0632, 0x062B, 0x0645, 0x062B, 0x0646, 0x062B, 0x0649,
+    0x062B, 0x064A, 0x0641, 0x0649, 0x0641, 0x064A, 0x0642, 0x0649,
+    0x0642, 0x064A, 0x0643, 0x0627, 0x0643, 0x0644, 0x0643, 0x0645,
+    0x0643, 0x0649, 0x0643, 0x064A, 0x0644, 0x0645, 0x0644, 0x0649,
+    0x0644, 0x064A, 0x0645, 0x0627, 0x0645, 0x0645, 0x0646, 0x0631,
+    0x0646, 0x0632, 0x0646, 0x0645, 0x0646, 0x0646, 0x0646, 0x0649,
+    0x0646, 0x064A, 0x0649, 0x0670, 0x064A, 0x0631, 0x064A, 0x0632,
+    0x064A, 0x0645, 0x064A, 0x0646, 0x064A, 0x0649, 0x064A, 0x064A,
+    0x064A, 0x0654, 0x062C, 0x064A, 0x0654, 0x062D, 0x064A, 0x0654,
+    0x062E, 0x064A, 0x0654, 0x0645, 0x064A, 0x0654, 0x0647, 0x0628,
+    0x062C, 0x0628, 0x062D, 0x0628, 0x062E, 0x0628, 0x0645, 0x0628,
+    0x0647, 0x062A, 0x062C, 0x062A, 0x062D, 0x062A, 0x062E, 0x062A,
+    0x0645, 0x062A, 0x0647, 0x062B, 0x0645, 0x062C, 0x062D, 0x062C,
+    0x0645, 0x062D, 0x062C, 0x062D, 0x0645, 0x062E, 0x062C, 0x062E,
+    0x0645, 0x0633, 0x062C, 0x0633, 0x062D, 0x0633, 0x062E, 0x0633,
+    0x0645, 0x0635, 0x062D, 0x0635, 0x062E, 0x0635, 0x0645, 0x0636,
+    0x062C, 0x0636, 0x062D, 0x0636, 0x062E, 0x0636, 0x0645, 0x0637,
+    0x062D, 0x0638, 0x0645, 0x0639, 0x062C, 0x0639, 0x0645, 0x063A,
+    0x062C, 0x063A, 0x0645, 0x0641, 0x062C, 0x0641, 0x062D, 0x0641,
+    0x062E, 0x0641, 0x0645, 0x0642, 0x062D, 0x0642, 0x0645, 0x0643,
+    0x062C, 0x0643, 0x062D, 0x0643, 0x062E, 0x0643, 0x0644, 0x0643,
+    0x0645, 0x0644, 0x062C, 0x0644, 0x062D, 0x0644, 0x062E, 0x0644,
+    0x0645, 0x0644, 0x0647, 0x0645, 0x062C, 0x0645, 0x062D, 0x0645,
+    0x062E, 0x0645, 0x0645, 0x0646, 0x062C, 0x0646, 0x062D, 0x0646,
+    0x062E, 0x0646, 0x0645, 0x0646, 0x0647, 0x0647, 0x062C, 0x0647,
+    0x0645, 0x0647, 0x0670, 0x064A, 0x062C, 0x064A, 0x062D, 0x064A,
+    0x062E, 0x064A, 0x0645, 0x064A, 0x0647, 0x064A, 0x0654, 0x0645,
+    0x064A, 0x0654, 0x0647, 0x0628, 0x0645, 0x0628, 0x0647, 0x062A,
+    0x0645, 0x062A, 0x0647, 0x062B, 0x0645, 0x062B, 0x0647, 0x0633,
+    0x0645, 0x0633, 0x0647, 0x0634, 0x0645, 0x0634, 0x0647, 0x0643,
+    0x0644, 0x0643, 0x0645, 0x0644, 0x0645, 0x0646, 0x0645, 0x0646,
+    0x0647, 0x064A, 0x0645, 0x064A, 0x0647, 0x0640, 0x064E, 0x0651,
+    0x0640, 0x064F, 0x0651, 0x0640, 0x0650, 0x0651, 0x0637, 0x0649,
+    0x0637, 0x064A, 0x0639, 0x0649, 0x0639, 0x064A, 0x063A, 0x0649,
+    0x063A, 0x064A, 0x0633, 0x0649, 0x0633, 0x064A, 0x0634, 0x0649,
+    0x0634, 0x064A, 0x062D, 0x0649, 0x062D, 0x064A, 0x062C, 0x0649,
+    0x062C, 0x064A, 0x062E, 0x0649, 0x062E, 0x064A, 0x0635, 0x0649,
+    0x0635, 0x064A, 0x0636, 0x0649, 0x0636, 0x064A, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0637, 0x0649,
+    0x0637, 0x064A, 0x0639, 0x0649, 0x0639, 0x064A, 0x063A, 0x0649,
+    0x063A, 0x064A, 0x0633, 0x0649, 0x0633, 0x064A, 0x0634, 0x0649,
+    0x0634, 0x064A, 0x062D, 0x0649, 0x062D, 0x064A, 0x062C, 0x0649,
+    0x062C, 0x064A, 0x062E, 0x0649, 0x062E, 0x064A, 0x0635, 0x0649,
+    0x0635, 0x064A, 0x0636, 0x0649, 0x0636, 0x064A, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0634, 0x0631,
+    0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0634, 0x062C,
+    0x0634, 0x062D, 0x0634, 0x062E, 0x0634, 0x0645, 0x0633, 0x0647,
+    0x0634, 0x0647, 0x0637, 0x0645, 0x0633, 0x062C, 0x0633, 0x062D,
+    0x0633, 0x062E, 0x0634, 0x062C, 0x0634, 0x062D, 0x0634, 0x062E,
+    0x0637, 0x0645, 0x0638, 0x0645, 0x0627, 0x064B, 0x0627, 0x064B,
+    0x062A, 0x062C, 0x0645, 0x062A, 0x062D, 0x062C, 0x062A, 0x062D,
+    0x062C, 0x062A, 0x062D, 0x0645, 0x062A, 0x062E, 0x0645, 0x062A,
+    0x0645, 0x062C, 0x062A, 0x0645, 0x062D, 0x062A, 0x0645, 0x062E,
+    0x062C, 0x0645, 0x062D, 0x062C, 0x0645, 0x062D, 0x062D, 0x0645,
+    0x064A, 0x062D, 0x0645, 0x0649, 0x0633, 0x062D, 0x062C, 0x0633,
+    0x062C, 0x062D, 0x0633, 0x062C, 0x0649, 0x0633, 0x0645, 0x062D,
+    0x0633, 0x0645, 0x062D, 0x0633, 0x0645, 0x062C, 0x0633, 0x0645,
+    0x0645, 0x0633, 0x0645, 0x0645, 0x0635, 0x062D, 0x062D, 0x0635,
+    0x062D, 0x062D, 0x0635, 0x0645, 0x0645, 0x0634, 0x062D, 0x0645,
+    0x0634, 0x062D, 0x0645, 0x0634, 0x062C, 0x064A, 0x0634, 0x0645,
+    0x062E, 0x0634, 0x0645, 0x062E, 0x0634, 0x0645, 0x0645, 0x0634,
+    0x0645, 0x0645, 0x0636, 0x062D, 0x0649, 0x0636, 0x062E, 0x0645,
+    0x0636, 0x062E, 0x0645, 0x0637, 0x0645, 0x062D, 0x0637, 0x0645,
+    0x062D, 0x0637, 0x0645, 0x0645, 0x0637, 0x0645, 0x064A, 0x0639,
+    0x062C, 0x0645, 0x0639, 0x0645, 0x0645, 0x0639, 0x0645, 0x0645,
+    0x0639, 0x0645, 0x0649, 0x063A, 0x0645, 0x0645, 0x063A, 0x0645,
+    0x064A, 0x063A, 0x0645, 0x0649, 0x0641, 0x062E, 0x0645, 0x0641,
+    0x062E, 0x0645, 0x0642, 0x0645, 0x062D, 0x0642, 0x0645, 0x0645,
+    0x0644, 0x062D, 0x0645, 0x0644, 0x062D, 0x064A, 0x0644, 0x062D,
+    0x0649, 0x0644, 0x062C, 0x062C, 0x0644, 0x062C, 0x062C, 0x0644,
+    0x062E, 0x0645, 0x0644, 0x062E, 0x0645, 0x0644, 0x0645, 0x062D,
+    0x0644, 0x0645, 0x062D, 0x0645, 0x062D, 0x062C, 0x0645, 0x062D,
+    0x0645, 0x0645, 0x062D, 0x064A, 0x0645, 0x062C, 0x062D, 0x0645,
+    0x062C, 0x0645, 0x0645, 0x062E, 0x062C, 0x0645, 0x062E, 0x0645,
+    0x0645, 0x062C, 0x062E, 0x0647, 0x0645, 0x062C, 0x0647, 0x0645,
+    0x0645, 0x0646, 0x062D, 0x0645, 0x0646, 0x062D, 0x0649, 0x0646,
+    0x062C, 0x0645, 0x0646, 0x062C, 0x0645, 0x0646, 0x062C, 0x0649,
+    0x0646, 0x0645, 0x064A, 0x0646, 0x0645, 0x0649, 0x064A, 0x0645,
+    0x0645, 0x064A, 0x0645, 0x0645, 0x0628, 0x062E, 0x064A, 0x062A,
+    0x062C, 0x064A, 0x062A, 0x062C, 0x0649, 0x062A, 0x062E, 0x064A,
+    0x062A, 0x062E, 0x0649, 0x062A, 0x0645, 0x064A, 0x062A, 0x0645,
+    0x0649, 0x062C, 0x0645, 0x064A, 0x062C, 0x062D, 0x0649, 0x062C,
+    0x0645, 0x0649, 0x0633, 0x062E, 0x0649, 0x0635, 0x062D, 0x064A,
+    0x0634, 0x062D, 0x064A, 0x0636, 0x062D, 0x064A, 0x0644, 0x062C,
+    0x064A, 0x0644, 0x0645, 0x064A, 0x064A, 0x062D, 0x064A, 0x064A,
+    0x062C, 0x064A, 0x064A, 0x0645, 0x064A, 0x0645, 0x0645, 0x064A,
+    0x0642, 0x0645, 0x064A, 0x0646, 0x062D, 0x064A, 0x0642, 0x0645,
+    0x062D, 0x0644, 0x062D, 0x0645, 0x0639, 0x0645, 0x064A, 0x0643,
+    0x0645, 0x064A, 0x0646, 0x062C, 0x062D, 0x0645, 0x062E, 0x064A,
+    0x0644, 0x062C, 0x0645, 0x0643, 0x0645, 0x0645, 0x0644, 0x062C,
+    0x0645, 0x0646, 0x062C, 0x062D, 0x062C, 0x062D, 0x064A, 0x062D,
+    0x062C, 0x064A, 0x0645, 0x062C, 0x064A, 0x0641, 0x0645, 0x064A,
+    0x0628, 0x062D, 0x064A, 0x0643, 0x0645, 0x0645, 0x0639, 0x062C,
+    0x0645, 0x0635, 0x0645, 0x0645, 0x0633, 0x062E, 0x064A, 0x0646,
+    0x062C, 0x064A, 0x0635, 0x0644, 0x06D2, 0x0642, 0x0644, 0x06D2,
+    0x0627, 0x0644, 0x0644, 0x0647, 0x0627, 0x0643, 0x0628, 0x0631,
+    0x0645, 0x062D, 0x0645, 0x062F, 0x0635, 0x0644, 0x0639, 0x0645,
+    0x0631, 0x0633, 0x0648, 0x0644, 0x0639, 0x0644, 0x064A, 0x0647,
+    0x0648, 0x0633, 0x0644, 0x0645, 0x0635, 0x0644, 0x0649, 0x0635,
+    0x0644, 0x0649, 0x0020, 0x0627, 0x0644, 0x0644, 0x0647, 0x0020,
+    0x0639, 0x0644, 0x064A, 0x0647, 0x0020, 0x0648, 0x0633, 0x0644,
+    0x0645, 0x062C, 0x0644, 0x0020, 0x062C, 0x0644, 0x0627, 0x0644,
+    0x0647, 0x0631, 0x06CC, 0x0627, 0x0644, 0x002C, 0x3001, 0x3002,
+    0x003A, 0x003B, 0x0021, 0x003F, 0x3016, 0x3017, 0x002E, 0x002E,
+    0x002E, 0x002E, 0x002E, 0x2014, 0x2013, 0x005F, 0x005F, 0x0028,
+    0x0029, 0x007B, 0x007D, 0x3014, 0x3015, 0x3010, 0x3011, 0x300A,
+    0x300B, 0x3008, 0x3009, 0x300C, 0x300D, 0x300E, 0x300F, 0x005B,
+    0x005D, 0x0020, 0x0305, 0x0020, 0x0305, 0x0020, 0x0305, 0x0020,
+    0x0305, 0x005F, 0x005F, 0x005F, 0x002C, 0x3001, 0x002E, 0x003B,
+    0x003A, 0x003F, 0x0021, 0x2014, 0x0028, 0x0029, 0x007B, 0x007D,
+    0x3014, 0x3015, 0x0023, 0x0026, 0x002A, 0x002B, 0x002D, 0x003C,
+    0x003E, 0x003D, 0x005C, 0x0024, 0x0025, 0x0040, 0x0020, 0x064B,
+    0x0640, 0x064B, 0x0020, 0x064C, 0x0020, 0x064D, 0x0020, 0x064E,
+    0x0640, 0x064E, 0x0020, 0x064F, 0x0640, 0x064F, 0x0020, 0x0650,
+    0x0640, 0x0650, 0x0020, 0x0651, 0x0640, 0x0651, 0x0020, 0x0652,
+    0x0640, 0x0652, 0x0621, 0x0627, 0x0653, 0x0627, 0x0653, 0x0627,
+    0x0654, 0x0627, 0x0654, 0x0648, 0x0654, 0x0648, 0x0654, 0x0627,
+    0x0655, 0x0627, 0x0655, 0x064A, 0x0654, 0x064A, 0x0654, 0x064A,
+    0x0654, 0x064A, 0x0654, 0x0627, 0x0627, 0x0628, 0x0628, 0x0628,
+    0x0628, 0x0629, 0x0629, 0x062A, 0x062A, 0x062A, 0x062A, 0x062B,
+    0x062B, 0x062B, 0x062B, 0x062C, 0x062C, 0x062C, 0x062C, 0x062D,
+    0x062D, 0x062D, 0x062D, 0x062E, 0x062E, 0x062E, 0x062E, 0x062F,
+    0x062F, 0x0630, 0x0630, 0x0631, 0x0631, 0x0632, 0x0632, 0x0633,
+    0x0633, 0x0633, 0x0633, 0x0634, 0x0634, 0x0634, 0x0634, 0x0635,
+    0x0635, 0x0635, 0x0635, 0x0636, 0x0636, 0x0636, 0x0636, 0x0637,
+    0x0637, 0x0637, 0x0637, 0x0638, 0x0638, 0x0638, 0x0638, 0x0639,
+    0x0639, 0x0639, 0x0639, 0x063A, 0x063A, 0x063A, 0x063A, 0x0641,
+    0x0641, 0x0641, 0x0641, 0x0642, 0x0642, 0x0642, 0x0642, 0x0643,
+    0x0643, 0x0643, 0x0643, 0x0644, 0x0644, 0x0644, 0x0644, 0x0645,
+    0x0645, 0x0645, 0x0645, 0x0646, 0x0646, 0x0646, 0x0646, 0x0647,
+    0x0647, 0x0647, 0x0647, 0x0648, 0x0648, 0x0649, 0x0649, 0x064A,
+    0x064A, 0x064A, 0x064A, 0x0644, 0x0627, 0x0653, 0x0644, 0x0627,
+    0x0653, 0x0644, 0x0627, 0x0654, 0x0644, 0x0627, 0x0654, 0x0644,
+    0x0627, 0x0655, 0x0644, 0x0627, 0x0655, 0x0644, 0x0627, 0x0644,
+    0x0627, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E, 0x002F,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003A, 0x003B, 0x003C, 0x003D, 0x003E, 0x003F,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005A, 0x005B, 0x005C, 0x005D, 0x005E, 0x005F,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x007D, 0x007E, 0x2985,
+    0x2986, 0x3002, 0x300C, 0x300D, 0x3001, 0x30FB, 0x30F2, 0x30A1,
+    0x30A3, 0x30A5, 0x30A7, 0x30A9, 0x30E3, 0x30E5, 0x30E7, 0x30C3,
+    0x30FC, 0x30A2, 0x30A4, 0x30A6, 0x30A8, 0x30AA, 0x30AB, 0x30AD,
+    0x30AF, 0x30B1, 0x30B3, 0x30B5, 0x30B7, 0x30B9, 0x30BB, 0x30BD,
+    0x30BF, 0x30C1, 0x30C4, 0x30C6, 0x30C8, 0x30CA, 0x30CB, 0x30CC,
+    0x30CD, 0x30CE, 0x30CF, 0x30D2, 0x30D5, 0x30D8, 0x30DB, 0x30DE,
+    0x30DF, 0x30E0, 0x30E1, 0x30E2, 0x30E4, 0x30E6, 0x30E8, 0x30E9,
+    0x30EA, 0x30EB, 0x30EC, 0x30ED, 0x30EF, 0x30F3, 0x3099, 0x309A,
+    0x1160, 0x1100, 0x1101, 0x11AA, 0x1102, 0x11AC, 0x11AD, 0x1103,
+    0x1104, 0x1105, 0x11B0, 0x11B1, 0x11B2, 0x11B3, 0x11B4, 0x11B5,
+    0x111A, 0x1106, 0x1107, 0x1108, 0x1121, 0x1109, 0x110A, 0x110B,
+    0x110C, 0x110D, 0x110E, 0x110F, 0x1110, 0x1111, 0x1112, 0x1161,
+    0x1162, 0x1163, 0x1164, 0x1165, 0x1166, 0x1167, 0x1168, 0x1169,
+    0x116A, 0x116B, 0x116C, 0x116D, 0x116E, 0x116F, 0x1170, 0x1171,
+    0x1172, 0x1173, 0x1174, 0x1175, 0x00A2, 0x00A3, 0x00AC, 0x0020,
+    0x0304, 0x00A6, 0x00A5, 0x20A9, 0x2502, 0x2190, 0x2191, 0x2192,
+    0x2193, 0x25A0, 0x25CB, 0x11099, 0x110BA, 0x1109B, 0x110BA, 0x110A5,
+    0x110BA, 0x11131, 0x11127, 0x11132, 0x11127, 0x11347, 0x1133E, 0x11347,
+    0x11357, 0x114B9, 0x114BA, 0x114B9, 0x114B0, 0x114B9, 0x114BD, 0x115B8,
+    0x115AF, 0x115B9, 0x115AF, 0x11935, 0x11930, 0x1D157, 0x1D165, 0x1D158,
+    0x1D165, 0x1D158, 0x1D165, 0x1D16E, 0x1D158, 0x1D165, 0x1D16F, 0x1D158,
+    0x1D165, 0x1D170, 0x1D158, 0x1D165, 0x1D171, 0x1D158, 0x1D165, 0x1D172,
+    0x1D1B9, 0x1D165, 0x1D1BA, 0x1D165, 0x1D1B9, 0x1D165, 0x1D16E, 0x1D1BA,
+    0x1D165, 0x1D16E, 0x1D1B9, 0x1D165, 0x1D16F, 0x1D1BA, 0x1D165, 0x1D16F,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C,
+    0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0069, 0x006A, 0x006B,
+    0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A, 0x0041,
+    0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049,
+    0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051,
+    0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059,
+    0x005A, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007A, 0x0041, 0x0043, 0x0044, 0x0047, 0x004A,
+    0x004B, 0x004E, 0x004F, 0x0050, 0x0051, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0066, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D,
+    0x006E, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C,
+    0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A,
+    0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072,
+    0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A,
+    0x0041, 0x0042, 0x0044, 0x0045, 0x0046, 0x0047, 0x004A, 0x004B,
+    0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A, 0x006B,
+    0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072, 0x0073,
+    0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A, 0x0041,
+    0x0042, 0x0044, 0x0045, 0x0046, 0x0047, 0x0049, 0x004A, 0x004B,
+    0x004C, 0x004D, 0x004F, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C,
+    0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A,
+    0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072,
+    0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C,
+    0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A,
+    0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072,
+    0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A, 0x004B, 0x004C,
+    0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054,
+    0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006A,
+    0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070, 0x0071, 0x0072,
+    0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007A,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048,
+    0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066,
+    0x0067, 0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E,
+    0x006F, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076,
+    0x0077, 0x0078, 0x0079, 0x007A, 0x0131, 0x0237, 0x0391, 0x0392,
+    0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A,
+    0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x0398,
+    0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x2207,
+    0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8,
+    0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0,
+    0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8,
+    0x03C9, 0x2202, 0x03B5, 0x03B8, 0x03BA, 0x03C6, 0x03C1, 0x03C0,
+    0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398,
+    0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0,
+    0x03A1, 0x0398, 0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8,
+    0x03A9, 0x2207, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6,
+    0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE,
+    0x03BF, 0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6,
+    0x03C7, 0x03C8, 0x03C9, 0x2202, 0x03B5, 0x03B8, 0x03BA, 0x03C6,
+    0x03C1, 0x03C0, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396,
+    0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E,
+    0x039F, 0x03A0, 0x03A1, 0x0398, 0x03A3, 0x03A4, 0x03A5, 0x03A6,
+    0x03A7, 0x03A8, 0x03A9, 0x2207, 0x03B1, 0x03B2, 0x03B3, 0x03B4,
+    0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB, 0x03BC,
+    0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4,
+    0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0x2202, 0x03B5, 0x03B8,
+    0x03BA, 0x03C6, 0x03C1, 0x03C0, 0x0391, 0x0392, 0x0393, 0x0394,
+    0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A, 0x039B, 0x039C,
+    0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x0398, 0x03A3, 0x03A4,
+    0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x2207, 0x03B1, 0x03B2,
+    0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA,
+    0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C2,
+    0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8, 0x03C9, 0x2202,
+    0x03B5, 0x03B8, 0x03BA, 0x03C6, 0x03C1, 0x03C0, 0x0391, 0x0392,
+    0x0393, 0x0394, 0x0395, 0x0396, 0x0397, 0x0398, 0x0399, 0x039A,
+    0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x0398,
+    0x03A3, 0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x2207,
+    0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8,
+    0x03B9, 0x03BA, 0x03BB, 0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0,
+    0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7, 0x03C8,
+    0x03C9, 0x2202, 0x03B5, 0x03B8, 0x03BA, 0x03C6, 0x03C1, 0x03C0,
+    0x03DC, 0x03DD, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035,
+    0x0036, 0x0037, 0x0038, 0x0039, 0x0030, 0x0031, 0x0032, 0x0033,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x0030, 0x0031,
+    0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035,
+    0x0036, 0x0037, 0x0038, 0x0039, 0x0627, 0x0628, 0x062C, 0x062F,
+    0x0648, 0x0632, 0x062D, 0x0637, 0x064A, 0x0643, 0x0644, 0x0645,
+    0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0634,
+    0x062A, 0x062B, 0x062E, 0x0630, 0x0636, 0x0638, 0x063A, 0x066E,
+    0x06BA, 0x06A1, 0x066F, 0x0628, 0x062C, 0x0647, 0x062D, 0x064A,
+    0x0643, 0x0644, 0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635,
+    0x0642, 0x0634, 0x062A, 0x062B, 0x062E, 0x0636, 0x063A, 0x062C,
+    0x062D, 0x064A, 0x0644, 0x0646, 0x0633, 0x0639, 0x0635, 0x0642,
+    0x0634, 0x062E, 0x0636, 0x063A, 0x06BA, 0x066F, 0x0628, 0x062C,
+    0x0647, 0x062D, 0x0637, 0x064A, 0x0643, 0x0645, 0x0646, 0x0633,
+    0x0639, 0x0641, 0x0635, 0x0642, 0x0634, 0x062A, 0x062B, 0x062E,
+    0x0636, 0x0638, 0x063A, 0x066E, 0x06A1, 0x0627, 0x0628, 0x062C,
+    0x062F, 0x0647, 0x0648, 0x0632, 0x062D, 0x0637, 0x064A, 0x0644,
+    0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631,
+    0x0634, 0x062A, 0x062B, 0x062E, 0x0630, 0x0636, 0x0638, 0x063A,
+    0x0628, 0x062C, 0x062F, 0x0648, 0x0632, 0x062D, 0x0637, 0x064A,
+    0x0644, 0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642,
+    0x0631, 0x0634, 0x062A, 0x062B, 0x062E, 0x0630, 0x0636, 0x0638,
+    0x063A, 0x0030, 0x002E, 0x0030, 0x002C, 0x0031, 0x002C, 0x0032,
+    0x002C, 0x0033, 0x002C, 0x0034, 0x002C, 0x0035, 0x002C, 0x0036,
+    0x002C, 0x0037, 0x002C, 0x0038, 0x002C, 0x0039, 0x002C, 0x0028,
+    0x0041, 0x0029, 0x0028, 0x0042, 0x0029, 0x0028, 0x0043, 0x0029,
+    0x0028, 0x0044, 0x0029, 0x0028, 0x0045, 0x0029, 0x0028, 0x0046,
+    0x0029, 0x0028, 0x0047, 0x0029, 0x0028, 0x0048, 0x0029, 0x0028,
+    0x0049, 0x0029, 0x0028, 0x004A, 0x0029, 0x0028, 0x004B, 0x0029,
+    0x0028, 0x004C, 0x0029, 0x0028, 0x004D, 0x0029, 0x0028, 0x004E,
+    0x0029, 0x0028, 0x004F, 0x0029, 0x0028, 0x0050, 0x0029, 0x0028,
+    0x0051, 0x0029, 0x0028, 0x0052, 0x0029, 0x0028, 0x0053, 0x0029,
+    0x0028, 0x0054, 0x0029, 0x0028, 0x0055, 0x0029, 0x0028, 0x0056,
+    0x0029, 0x0028, 0x0057, 0x0029, 0x0028, 0x0058, 0x0029, 0x0028,
+    0x0059, 0x0029, 0x0028, 0x005A, 0x0029, 0x3014, 0x0053, 0x3015,
+    0x0043, 0x0052, 0x0043, 0x0044, 0x0057, 0x005A, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x0049, 0x004A,
+    0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050, 0x0051, 0x0052,
+    0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005A,
+    0x0048, 0x0056, 0x004D, 0x0056, 0x0053, 0x0044, 0x0053, 0x0053,
+    0x0050, 0x0050, 0x0056, 0x0057, 0x0043, 0x004D, 0x0043, 0x004D,
+    0x0044, 0x004D, 0x0052, 0x0044, 0x004A, 0x307B, 0x304B, 0x30B3,
+    0x30B3, 0x30B5, 0x624B, 0x5B57, 0x53CC, 0x30C6, 0x3099, 0x4E8C,
+    0x591A, 0x89E3, 0x5929, 0x4EA4, 0x6620, 0x7121, 0x6599, 0x524D,
+    0x5F8C, 0x518D, 0x65B0, 0x521D, 0x7D42, 0x751F, 0x8CA9, 0x58F0,
+    0x5439, 0x6F14, 0x6295, 0x6355, 0x4E00, 0x4E09, 0x904A, 0x5DE6,
+    0x4E2D, 0x53F3, 0x6307, 0x8D70, 0x6253, 0x7981, 0x7A7A, 0x5408,
+    0x6E80, 0x6709, 0x6708, 0x7533, 0x5272, 0x55B6, 0x914D, 0x3014,
+    0x672C, 0x3015, 0x3014, 0x4E09, 0x3015, 0x3014, 0x4E8C, 0x3015,
+    0x3014, 0x5B89, 0x3015, 0x3014, 0x70B9, 0x3015, 0x3014, 0x6253,
+    0x3015, 0x3014, 0x76D7, 0x3015, 0x3014, 0x52DD, 0x3015, 0x3014,
+    0x6557, 0x3015, 0x5F97, 0x53EF, 0x0030, 0x0031, 0x0032, 0x0033,
+    0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x4E3D, 0x4E38,
+    0x4E41, 0x20122, 0x4F60, 0x4FAE, 0x4FBB, 0x5002, 0x507A, 0x5099,
+    0x50E7, 0x50CF, 0x349E, 0x2063A, 0x514D, 0x5154, 0x5164, 0x5177,
+    0x2051C, 0x34B9, 0x5167, 0x518D, 0x2054B, 0x5197, 0x51A4, 0x4ECC,
+    0x51AC, 0x51B5, 0x291DF, 0x51F5, 0x5203, 0x34DF, 0x523B, 0x5246,
+    0x5272, 0x5277, 0x3515, 0x52C7, 0x52C9, 0x52E4, 0x52FA, 0x5305,
+    0x5306, 0x5317, 0x5349, 0x5351, 0x535A, 0x5373, 0x537D, 0x537F,
+    0x537F, 0x537F, 0x20A2C, 0x7070, 0x53CA, 0x53DF, 0x20B63, 0x53EB,
+    0x53F1, 0x5406, 0x549E, 0x5438, 0x5448, 0x5468, 0x54A2, 0x54F6,
+    0x5510, 0x5553, 0x5563, 0x5584, 0x5584, 0x5599, 0x55AB, 0x55B3,
+    0x55C2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58EE,
+    0x57CE, 0x57F4, 0x580D, 0x578B, 0x5832, 0x5831, 0x58AC, 0x214E4,
+    0x58F2, 0x58F7, 0x5906, 0x591A, 0x5922, 0x5962, 0x216A8, 0x216EA,
+    0x59EC, 0x5A1B, 0x5A27, 0x59D8, 0x5A66, 0x36EE, 0x36FC, 0x5B08,
+    0x5B3E, 0x5B3E, 0x219C8, 0x5BC3, 0x5BD8, 0x5BE7, 0x5BF3, 0x21B18,
+    0x5BFF, 0x5C06, 0x5F53, 0x5C22, 0x3781, 0x5C60, 0x5C6E, 0x5CC0,
+    0x5C8D, 0x21DE4, 0x5D43, 0x21DE6, 0x5D6E, 0x5D6B, 0x5D7C, 0x5DE1,
+    0x5DE2, 0x382F, 0x5DFD, 0x5E28, 0x5E3D, 0x5E69, 0x3862, 0x22183,
+    0x387C, 0x5EB0, 0x5EB3, 0x5EB6, 0x5ECA, 0x2A392, 0x5EFE, 0x22331,
+    0x22331, 0x8201, 0x5F22, 0x5F22, 0x38C7, 0x232B8, 0x261DA, 0x5F62,
+    0x5F6B, 0x38E3, 0x5F9A, 0x5FCD, 0x5FD7, 0x5FF9, 0x6081, 0x393A,
+    0x391C, 0x6094, 0x226D4, 0x60C7, 0x6148, 0x614C, 0x614E, 0x614C,
+    0x617A, 0x618E, 0x61B2, 0x61A4, 0x61AF, 0x61DE, 0x61F2, 0x61F6,
+    0x6210, 0x621B, 0x625D, 0x62B1, 0x62D4, 0x6350, 0x22B0C, 0x633D,
+    0x62FC, 0x6368, 0x6383, 0x63E4, 0x22BF1, 0x6422, 0x63C5, 0x63A9,
+    0x3A2E, 0x6469, 0x647E, 0x649D, 0x6477, 0x3A6C, 0x654F, 0x656C,
+    0x2300A, 0x65E3, 0x66F8, 0x6649, 0x3B19, 0x6691, 0x3B08, 0x3AE4,
+    0x5192, 0x5195, 0x6700, 0x669C, 0x80AD, 0x43D9, 0x6717, 0x671B,
+    0x6721, 0x675E, 0x6753, 0x233C3, 0x3B49, 0x67FA, 0x6785, 0x6852,
+    0x6885, 0x2346D, 0x688E, 0x681F, 0x6914, 0x3B9D, 0x6942, 0x69A3,
+    0x69EA, 0x6AA8, 0x236A3, 0x6ADB, 0x3C18, 0x6B21, 0x238A7, 0x6B54,
+    0x3C4E, 0x6B72, 0x6B9F, 0x6BBA, 0x6BBB, 0x23A8D, 0x21D0B, 0x23AFA,
+    0x6C4E, 0x23CBC, 0x6CBF, 0x6CCD, 0x6C67, 0x6D16, 0x6D3E, 0x6D77,
+    0x6D41, 0x6D69, 0x6D78, 0x6D85, 0x23D1E, 0x6D34, 0x6E2F, 0x6E6E,
+    0x3D33, 0x6ECB, 0x6EC7, 0x23ED1, 0x6DF9, 0x6F6E, 0x23F5E, 0x23F8E,
+    0x6FC6, 0x7039, 0x701E, 0x701B, 0x3D96, 0x704A, 0x707D, 0x7077,
+    0x70AD, 0x20525, 0x7145, 0x24263, 0x719C, 0x243AB, 0x7228, 0x7235,
+    0x7250, 0x24608, 0x7280, 0x7295, 0x24735, 0x24814, 0x737A, 0x738B,
+    0x3EAC, 0x73A5, 0x3EB8, 0x3EB8, 0x7447, 0x745C, 0x7471, 0x7485,
+    0x74CA, 0x3F1B, 0x7524, 0x24C36, 0x753E, 0x24C92, 0x7570, 0x2219F,
+    0x7610, 0x24FA1, 0x24FB8, 0x25044, 0x3FFC, 0x4008, 0x76F4, 0x250F3,
+    0x250F2, 0x25119, 0x25133, 0x771E, 0x771F, 0x771F, 0x774A, 0x4039,
+    0x778B, 0x4046, 0x4096, 0x2541D, 0x784E, 0x788C, 0x78CC, 0x40E3,
+    0x25626, 0x7956, 0x2569A, 0x256C5, 0x798F, 0x79EB, 0x412F, 0x7A40,
+    0x7A4A, 0x7A4F, 0x2597C, 0x25AA7, 0x25AA7, 0x7AEE, 0x4202, 0x25BAB,
+    0x7BC6, 0x7BC9, 0x4227, 0x25C80, 0x7CD2, 0x42A0, 0x7CE8, 0x7CE3,
+    0x7D00, 0x25F86, 0x7D63, 0x4301, 0x7DC7, 0x7E02, 0x7E45, 0x4334,
+    0x26228, 0x26247, 0x4359, 0x262D9, 0x7F7A, 0x2633E, 0x7F95, 0x7FFA,
+    0x8005, 0x264DA, 0x26523, 0x8060, 0x265A8, 0x8070, 0x2335F, 0x43D5,
+    0x80B2, 0x8103, 0x440B, 0x813E, 0x5AB5, 0x267A7, 0x267B5, 0x23393,
+    0x2339C, 0x8201, 0x8204, 0x8F9E, 0x446B, 0x8291, 0x828B, 0x829D,
+    0x52B3, 0x82B1, 0x82B3, 0x82BD, 0x82E6, 0x26B3C, 0x82E5, 0x831D,
+    0x8363, 0x83AD, 0x8323, 0x83BD, 0x83E7, 0x8457, 0x8353, 0x83CA,
+    0x83CC, 0x83DC, 0x26C36, 0x26D6B, 0x26CD5, 0x452B, 0x84F1, 0x84F3,
+    0x8516, 0x273CA, 0x8564, 0x26F2C, 0x455D, 0x4561, 0x26FB1, 0x270D2,
+    0x456B, 0x8650, 0x865C, 0x8667, 0x8669, 0x86A9, 0x8688, 0x870E,
+    0x86E2, 0x8779, 0x8728, 0x876B, 0x8786, 0x45D7, 0x87E1, 0x8801,
+    0x45F9, 0x8860, 0x8863, 0x27667, 0x88D7, 0x88DE, 0x4635, 0x88FA,
+    0x34BB, 0x278AE, 0x27966, 0x46BE, 0x46C7, 0x8AA0, 0x8AED, 0x8B8A,
+    0x8C55, 0x27CA8, 0x8CAB, 0x8CC1, 0x8D1B, 0x8D77, 0x27F2F, 0x20804,
+    0x8DCB, 0x8DBC, 0x8DF0, 0x208DE, 0x8ED4, 0x8F38, 0x285D2, 0x285ED,
+    0x9094, 0x90F1, 0x9111, 0x2872E, 0x911B, 0x9238, 0x92D7, 0x92D8,
+    0x927C, 0x93F9, 0x9415, 0x28BFA, 0x958B, 0x4995, 0x95B7, 0x28D77,
+    0x49E6, 0x96C3, 0x5DB2, 0x9723, 0x29145, 0x2921A, 0x4A6E, 0x4A76,
+    0x97E0, 0x2940A, 0x4AB2, 0x29496, 0x980B, 0x980B, 0x9829, 0x295B6,
+    0x98E2, 0x4B33, 0x9929, 0x99A7, 0x99C2, 0x99FE, 0x4BCE, 0x29B30,
+    0x9B12, 0x9C40, 0x9CFD, 0x4CCE, 0x4CED, 0x9D67, 0x2A0CE, 0x4CF8,
+    0x2A105, 0x2A20E, 0x2A291, 0x9EBB, 0x4D56, 0x9EF9, 0x9EFE, 0x9F05,
+    0x9F0F, 0x9F16, 0x9F3B, 0x2A600,
+];
+
+pub(super) static COMPOSITIONS: [(u32, u32, u32); 941] = [
+    (0x003C, 0x0338, 0x226E),
+    (0x003D, 0x0338, 0x2260),
+    (0x003E, 0x0338, 0x226F),
+    (0x0041, 0x0300, 0x00C0),
+    (0x0041, 0x0301, 0x00C1),
+    (0x0041, 0x0302, 0x00C2),
+    (0x0041, 0x0303, 0x00C3),
+    (0x0041, 0x0304, 0x0100),
+    (0x0041, 0x0306, 0x0102),
+    (0x0041, 0x0307, 0x0226),
+    (0x0041, 0x0308, 0x00C4),
+    (0x0041, 0x0309, 0x1EA2),
+    (0x0041, 0x030A, 0x00C5),
+    (0x0041, 0x030C, 0x01CD),
+    (0x0041, 0x030F, 0x0200),
+    (0x0041, 0x0311, 0x0202),
+    (0x0041, 0x0323, 0x1EA0),
+    (0x0041, 0x0325, 0x1E00),
+    (0x0041, 0x0328, 0x0104),
+    (0x0042, 0x0307, 0x1E02),
+    (0x0042, 0x0323, 0x1E04),
+    (0x0042, 0x0331, 0x1E06),
+    (0x0043, 0x0301, 0x0106),
+    (0x0043, 0x0302, 0x0108),
+    (0x0043, 0x0307, 0x010A),
+    (0x0043, 0x030C, 0x010C),
+    (0x0043, 0x0327, 0x00C7),
+    (0x0044, 0x0307, 0x1E0A),
+    (0x0044, 0x030C, 0x010E),
+    (0x0044, 0x0323, 0x1E0C),
+    (0x0044, 0x0327, 0x1E10),
+    (0x0044, 0x032D, 0x1E12),
+    (0x0044, 0x0331, 0x1E0E),
+    (0x0045, 0x0300, 0x00C8),
+    (0x0045, 0x0301, 0x00C9),
+    (0x0045, 0x0302, 0x00CA),
+    (0x0045, 0x0303, 0x1EBC),
+    (0x0045, 0x0304, 0x0112),
+    (0x0045, 0x0306, 0x0114),
+    (0x0045, 0x0307, 0x0116),
+    (0x0045, 0x0308, 0x00CB),
+    (0x0045, 0x0309, 0x1EBA),
+    (0x0045, 0x030C, 0x011A),
+    (0x0045, 0x030F, 0x0204),
+    (0x0045, 0x0311, 0x0206),
+    (0x0045, 0x0323, 0x1EB8),
+    (0x0045, 0x0327, 0x0228),
+    (0x0045, 0x0328, 0x0118),
+    (0x0045, 0x032D, 0x1E18),
+    (0x0045, 0x0330, 0x1E1A),
+    (0x0046, 0x0307, 0x1E1E),
+    (0x0047, 0x0301, 0x01F4),
+    (0x0047, 0x0302, 0x011C),
+    (0x0047, 0x0304, 0x1E20),
+    (0x0047, 0x0306, 0x011E),
+    (0x0047, 0x0307, 0x0120),
+    (0x0047, 0x030C, 0x01E6),
+    (0x0047, 0x0327, 0x0122),
+    (0x0048, 0x0302, 0x0124),
+    (0x0048, 0x0307, 0x1E22),
+    (0x0048, 0x0308, 0x1E26),
+    (0x0048, 0x030C, 0x021E),
+    (0x0048, 0x0323, 0x1E24),
+    (0x0048, 0x0327, 0x1E28),
+    (0x0048, 0x032E, 0x1E2A),
+    (0x0049, 0x0300, 0x00CC),
+    (0x0049, 0x0301, 0x00CD),
+    (0x0049, 0x0302, 0x00CE),
+    (0x0049, 0x0303, 0x0128),
+    (0x0049, 0x0304, 0x012A),
+    (0x0049, 0x0306, 0x012C),
+    (0x0049, 0x0307, 0x0130),
+    (0x0049, 0x0308, 0x00CF),
+    (0x0049, 0x0309, 0x1EC8),
+    (0x0049, 0x030C, 0x01CF),
+    (0x0049, 0x030F, 0x0208),
+    (0x0049, 0x0311, 0x020A),
+    (0x0049, 0x0323, 0x1ECA),
+    (0x0049, 0x0328, 0x012E),
+    (0x0049, 0x0330, 0x1E2C),
+    (0x004A, 0x0302, 0x0134),
+    (0x004B, 0x0301, 0x1E30),
+    (0x004B, 0x030C, 0x01E8),
+    (0x004B, 0x0323, 0x1E32),
+    (0x004B, 0x0327, 0x0136),
+    (0x004B, 0x0331, 0x1E34),
+    (0x004C, 0x0301, 0x0139),
+    (0x004C, 0x030C, 0x013D),
+    (0x004C, 0x0323, 0x1E36),
+    (0x004C, 0x0327, 0x013B),
+    (0x004C, 0x032D, 0x1E3C),
+    (0x004C, 0x0331, 0x1E3A),
+    (0x004D, 0x0301, 0x1E3E),
+    (0x004D, 0x0307, 0x1E40),
+    (0x004D, 0x0323, 0x1E42),
+    (0x004E, 0x0300, 0x01F8),
+    (0x004E, 0x0301, 0x0143),
+    (0x004E, 0x0303, 0x00D1),
+    (0x004E, 0x0307, 0x1E44),
+    (0x004E, 0x030C, 0x0147),
+    (0x004E, 0x0323, 0x1E46),
+    (0x004E, 0x0327, 0x0145),
+    (0x004E, 0x032D, 0x1E4A),
+    (0x004E, 0x0331, 0x1E48),
+    (0x004F, 0x0300, 0x00D2),
+    (0x004F, 0x0301, 0x00D3),
+    (0x004F, 0x0302, 0x00D4),
+    (0x004F, 0x0303, 0x00D5),
+    (0x004F, 0x0304, 0x014C),
+    (0x004F, 0x0306, 0x014E),
+    (0x004F, 0x0307, 0x022E),
+    (0x004F, 0x0308, 0x00D6),
+    (0x004F, 0x0309, 0x1ECE),
+    (0x004F, 0x030B, 0x0150),
+    (0x004F, 0x030C, 0x01D1),
+    (0x004F, 0x030F, 0x020C),
+    (0x004F, 0x0311, 0x020E),
+    (0x004F, 0x031B, 0x01A0),
+    (0x004F, 0x0323, 0x1ECC),
+    (0x004F, 0x0328, 0x01EA),
+    (0x0050, 0x0301, 0x1E54),
+    (0x0050, 0x0307, 0x1E56),
+    (0x0052, 0x0301, 0x0154),
+    (0x0052, 0x0307, 0x1E58),
+    (0x0052, 0x030C, 0x0158),
+    (0x0052, 0x030F, 0x0210),
+    (0x0052, 0x0311, 0x0212),
+    (0x0052, 0x0323, 0x1E5A),
+    (0x0052, 0x0327, 0x0156),
+    (0x0052, 0x0331, 0x1E5E),
+    (0x0053, 0x0301, 0x015A),
+    (0x0053, 0x0302, 0x015C),
+    (0x0053, 0x0307, 0x1E60),
+    (0x0053, 0x030C, 0x0160),
+    (0x0053, 0x0323, 0x1E62),
+    (0x0053, 0x0326, 0x0218),
+    (0x0053, 0x0327, 0x015E),
+    (0x0054, 0x0307, 0x1E6A),
+    (0x0054, 0x030C, 0x0164),
+    (0x0054, 0x0323, 0x1E6C),
+    (0x0054, 0x0326, 0x021A),
+    (0x0054, 0x0327, 0x0162),
+    (0x0054, 0x032D, 0x1E70),
+    (0x0054, 0x0331, 0x1E6E),
+    (0x0055, 0x0300, 0x00D9),
+    (0x0055, 0x0301, 0x00DA),
+    (0x0055, 0x0302, 0x00DB),
+    (0x0055, 0x0303, 0x0168),
+    (0x0055, 0x0304, 0x016A),
+    (0x0055, 0x0306, 0x016C),
+    (0x0055, 0x0308, 0x00DC),
+    (0x0055, 0x0309, 0x1EE6),
+    (0x0055, 0x030A, 0x016E),
+    (0x0055, 0x030B, 0x0170),
+    (0x0055, 0x030C, 0x01D3),
+    (0x0055, 0x030F, 0x0214),
+    (0x0055, 0x0311, 0x0216),
+    (0x0055, 0x031B, 0x01AF),
+    (0x0055, 0x0323, 0x1EE4),
+    (0x0055, 0x0324, 0x1E72),
+    (0x0055, 0x0328, 0x0172),
+    (0x0055, 0x032D, 0x1E76),
+    (0x0055, 0x0330, 0x1E74),
+    (0x0056, 0x0303, 0x1E7C),
+    (0x0056, 0x0323, 0x1E7E),
+    (0x0057, 0x0300, 0x1E80),
+    (0x0057, 0x0301, 0x1E82),
+    (0x0057, 0x0302, 0x0174),
+    (0x0057, 0x0307, 0x1E86),
+    (0x0057, 0x0308, 0x1E84),
+    (0x0057, 0x0323, 0x1E88),
+    (0x0058, 0x0307, 0x1E8A),
+    (0x0058, 0x0308, 0x1E8C),
+    (0x0059, 0x0300, 0x1EF2),
+    (0x0059, 0x0301, 0x00DD),
+    (0x0059, 0x0302, 0x0176),
+    (0x0059, 0x0303, 0x1EF8),
+    (0x0059, 0x0304, 0x0232),
+    (0x0059, 0x0307, 0x1E8E),
+    (0x0059, 0x0308, 0x0178),
+    (0x0059, 0x0309, 0x1EF6),
+    (0x0059, 0x0323, 0x1EF4),
+    (0x005A, 0x0301, 0x0179),
+    (0x005A, 0x0302, 0x1E90),
+    (0x005A, 0x0307, 0x017B),
+    (0x005A, 0x030C, 0x017D),
+    (0x005A, 0x0323, 0x1E92),
+    (0x005A, 0x0331, 0x1E94),
+    (0x0061, 0x0300, 0x00E0),
+    (0x0061, 0x0301, 0x00E1),
+    (0x0061, 0x0302, 0x00E2),
+    (0x0061, 0x0303, 0x00E3),
+    (0x0061, 0x0304, 0x0101),
+    (0x0061, 0x0306, 0x0103),
+    (0x0061, 0x0307, 0x0227),
+    (0x0061, 0x0308, 0x00E4),
+    (0x0061, 0x0309, 0x1EA3),
+    (0x0061, 0x030A, 0x00E5),
+    (0x0061, 0x030C, 0x01CE),
+    (0x0061, 0x030F, 0x0201),
+    (0x0061, 0x0311, 0x0203),
+    (0x0061, 0x0323, 0x1EA1),
+    (0x0061, 0x0325, 0x1E01),
+    (0x0061, 0x0328, 0x0105),
+    (0x0062, 0x0307, 0x1E03),
+    (0x0062, 0x0323, 0x1E05),
+    (0x0062, 0x0331, 0x1E07),
+    (0x0063, 0x0301, 0x0107),
+    (0x0063, 0x0302, 0x0109),
+    (0x0063, 0x0307, 0x010B),
+    (0x0063, 0x030C, 0x010D),
+    (0x0063, 0x0327, 0x00E7),
+    (0x0064, 0x0307, 0x1E0B),
+    (0x0064, 0x030C, 0x010F),
+    (0x0064, 0x0323, 0x1E0D),
+    (0x0064, 0x0327, 0x1E11),
+    (0x0064, 0x032D, 0x1E13),
+    (0x0064, 0x0331, 0x1E0F),
+    (0x0065, 0x0300, 0x00E8),
+    (0x0065, 0x0301, 0x00E9),
+    (0x0065, 0x0302, 0x00EA),
+    (0x0065, 0x0303, 0x1EBD),
+    (0x0065, 0x0304, 0x0113),
+    (0x0065, 0x0306, 0x0115),
+    (0x0065, 0x0307, 0x0117),
+    (0x0065, 0x0308, 0x00EB),
+    (0x0065, 0x0309, 0x1EBB),
+    (0x0065, 0x030C, 0x011B),
+    (0x0065, 0x030F, 0x0205),
+    (0x0065, 0x0311, 0x0207),
+    (0x0065, 0x0323, 0x1EB9),
+    (0x0065, 0x0327, 0x0229),
+    (0x0065, 0x0328, 0x0119),
+    (0x0065, 0x032D, 0x1E19),
+    (0x0065, 0x0330, 0x1E1B),
+    (0x0066, 0x0307, 0x1E1F),
+    (0x0067, 0x0301, 0x01F5),
+    (0x0067, 0x0302, 0x011D),
+    (0x0067, 0x0304, 0x1E21),
+    (0x0067, 0x0306, 0x011F),
+    (0x0067, 0x0307, 0x0121),
+    (0x0067, 0x030C, 0x01E7),
+    (0x0067, 0x0327, 0x0123),
+    (0x0068, 0x0302, 0x0125),
+    (0x0068, 0x0307, 0x1E23),
+    (0x0068, 0x0308, 0x1E27),
+    (0x0068, 0x030C, 0x021F),
+    (0x0068, 0x0323, 0x1E25),
+    (0x0068, 0x0327, 0x1E29),
+    (0x0068, 0x032E, 0x1E2B),
+    (0x0068, 0x0331, 0x1E96),
+    (0x0069, 0x0300, 0x00EC),
+    (0x0069, 0x0301, 0x00ED),
+    (0x0069, 0x0302, 0x00EE),
+    (0x0069, 0x0303, 0x0129),
+    (0x0069, 0x0304, 0x012B),
+    (0x0069, 0x0306, 0x012D),
+    (0x0069, 0x0308, 0x00EF),
+    (0x0069, 0x0309, 0x1EC9),
+    (0x0069, 0x030C, 0x01D0),
+    (0x0069, 0x030F, 0x0209),
+    (0x0069, 0x0311, 0x020B),
+    (0x0069, 0x0323, 0x1ECB),
+    (0x0069, 0x0328, 0x012F),
+    (0x0069, 0x0330, 0x1E2D),
+    (0x006A, 0x0302, 0x0135),
+    (0x006A, 0x030C, 0x01F0),
+    (0x006B, 0x0301, 0x1E31),
+    (0x006B, 0x030C, 0x01E9),
+    (0x006B, 0x0323, 0x1E33),
+    (0x006B, 0x0327, 0x0137),
+    (0x006B, 0x0331, 0x1E35),
+    (0x006C, 0x0301, 0x013A),
+    (0x006C, 0x030C, 0x013E),
+    (0x006C, 0x0323, 0x1E37),
+    (0x006C, 0x0327, 0x013C),
+    (0x006C, 0x032D, 0x1E3D),
+    (0x006C, 0x0331, 0x1E3B),
+    (0x006D, 0x0301, 0x1E3F),
+    (0x006D, 0x0307, 0x1E41),
+    (0x006D, 0x0323, 0x1E43),
+    (0x006E, 0x0300, 0x01F9),
+    (0x006E, 0x0301, 0x0144),
+    (0x006E, 0x0303, 0x00F1),
+    (0x006E, 0x0307, 0x1E45),
+    (0x006E, 0x030C, 0x0148),
+    (0x006E, 0x0323, 0x1E47),
+    (0x006E, 0x0327, 0x0146),
+    (0x006E, 0x032D, 0x1E4B),
+    (0x006E, 0x0331, 0x1E49),
+    (0x006F, 0x0300, 0x00F2),
+    (0x006F, 0x0301, 0x00F3),
+    (0x006F, 0x0302, 0x00F4),
+    (0x006F, 0x0303, 0x00F5),
+    (0x006F, 0x0304, 0x014D),
+    (0x006F, 0x0306, 0x014F),
+    (0x006F, 0x0307, 0x022F),
+    (0x006F, 0x0308, 0x00F6),
+    (0x006F, 0x0309, 0x1ECF),
+    (0x006F, 0x030B, 0x0151),
+    (0x006F, 0x030C, 0x01D2),
+    (0x006F, 0x030F, 0x020D),
+    (0x006F, 0x0311, 0x020F),
+    (0x006F, 0x031B, 0x01A1),
+    (0x006F, 0x0323, 0x1ECD),
+    (0x006F, 0x0328, 0x01EB),
+    (0x0070, 0x0301, 0x1E55),
+    (0x0070, 0x0307, 0x1E57),
+    (0x0072, 0x0301, 0x0155),
+    (0x0072, 0x0307, 0x1E59),
+    (0x0072, 0x030C, 0x0159),
+    (0x0072, 0x030F, 0x0211),
+    (0x0072, 0x0311, 0x0213),
+    (0x0072, 0x0323, 0x1E5B),
+    (0x0072, 0x0327, 0x0157),
+    (0x0072, 0x0331, 0x1E5F),
+    (0x0073, 0x0301, 0x015B),
+    (0x0073, 0x0302, 0x015D),
+    (0x0073, 0x0307, 0x1E61),
+    (0x0073, 0x030C, 0x0161),
+    (0x0073, 0x0323, 0x1E63),
+    (0x0073, 0x0326, 0x0219),
+    (0x0073, 0x0327, 0x015F),
+    (0x0074, 0x0307, 0x1E6B),
+    (0x0074, 0x0308, 0x1E97),
+    (0x0074, 0x030C, 0x0165),
+    (0x0074, 0x0323, 0x1E6D),
+    (0x0074, 0x0326, 0x021B),
+    (0x0074, 0x0327, 0x0163),
+    (0x0074, 0x032D, 0x1E71),
+    (0x0074, 0x0331, 0x1E6F),
+    (0x0075, 0x0300, 0x00F9),
+    (0x0075, 0x0301, 0x00FA),
+    (0x0075, 0x0302, 0x00FB),
+    (0x0075, 0x0303, 0x0169),
+    (0x0075, 0x0304, 0x016B),
+    (0x0075, 0x0306, 0x016D),
+    (0x0075, 0x0308, 0x00FC),
+    (0x0075, 0x0309, 0x1EE7),
+    (0x0075, 0x030A, 0x016F),
+    (0x0075, 0x030B, 0x0171),
+    (0x0075, 0x030C, 0x01D4),
+    (0x0075, 0x030F, 0x0215),
+    (0x0075, 0x0311, 0x0217),
+    (0x0075, 0x031B, 0x01B0),
+    (0x0075, 0x0323, 0x1EE5),
+    (0x0075, 0x0324, 0x1E73),
+    (0x0075, 0x0328, 0x0173),
+    (0x0075, 0x032D, 0x1E77),
+    (0x0075, 0x0330, 0x1E75),
+    (0x0076, 0x0303, 0x1E7D),
+    (0x0076, 0x0323, 0x1E7F),
+    (0x0077, 0x0300, 0x1E81),
+    (0x0077, 0x0301, 0x1E83),
+    (0x0077, 0x0302, 0x0175),
+    (0x0077, 0x0307, 0x1E87),
+    (0x0077, 0x0308, 0x1E85),
+    (0x0077, 0x030A, 0x1E98),
+    (0x0077, 0x0323, 0x1E89),
+    (0x0078, 0x0307, 0x1E8B),
+    (0x0078, 0x0308, 0x1E8D),
+    (0x0079, 0x0300, 0x1EF3),
+    (0x0079, 0x0301, 0x00FD),
+    (0x0079, 0x0302, 0x0177),
+    (0x0079, 0x0303, 0x1EF9),
+    (0x0079, 0x0304, 0x0233),
+    (0x0079, 0x0307, 0x1E8F),
+    (0x0079, 0x0308, 0x00FF),
+    (0x0079, 0x0309, 0x1EF7),
+    (0x0079, 0x030A, 0x1E99),
+    (0x0079, 0x0323, 0x1EF5),
+    (0x007A, 0x0301, 0x017A),
+    (0x007A, 0x0302, 0x1E91),
+    (0x007A, 0x0307, 0x017C),
+    (0x007A, 0x030C, 0x017E),
+    (0x007A, 0x0323, 0x1E93),
+    (0x007A, 0x0331, 0x1E95),
+    (0x00A8, 0x0300, 0x1FED),
+    (0x00A8, 0x0301, 0x0385),
+    (0x00A8, 0x0342, 0x1FC1),
+    (0x00C2, 0x0300, 0x1EA6),
+    (0x00C2, 0x0301, 0x1EA4),
+    (0x00C2, 0x0303, 0x1EAA),
+    (0x00C2, 0x0309, 0x1EA8),
+    (0x00C4, 0x0304, 0x01DE),
+    (0x00C5, 0x0301, 0x01FA),
+    (0x00C6, 0x0301, 0x01FC),
+    (0x00C6, 0x0304, 0x01E2),
+    (0x00C7, 0x0301, 0x1E08),
+    (0x00CA, 0x0300, 0x1EC0),
+    (0x00CA, 0x0301, 0x1EBE),
+    (0x00CA, 0x0303, 0x1EC4),
+    (0x00CA, 0x0309, 0x1EC2),
+    (0x00CF, 0x0301, 0x1E2E),
+    (0x00D4, 0x0300, 0x1ED2),
+    (0x00D4, 0x0301, 0x1ED0),
+    (0x00D4, 0x0303, 0x1ED6),
+    (0x00D4, 0x0309, 0x1ED4),
+    (0x00D5, 0x0301, 0x1E4C),
+    (0x00D5, 0x0304, 0x022C),
+    (0x00D5, 0x0308, 0x1E4E),
+    (0x00D6, 0x0304, 0x022A),
+    (0x00D8, 0x0301, 0x01FE),
+    (0x00DC, 0x0300, 0x01DB),
+    (0x00DC, 0x0301, 0x01D7),
+    (0x00DC, 0x0304, 0x01D5),
+    (0x00DC, 0x030C, 0x01D9),
+    (0x00E2, 0x0300, 0x1EA7),
+    (0x00E2, 0x0301, 0x1EA5),
+    (0x00E2, 0x0303, 0x1EAB),
+    (0x00E2, 0x0309, 0x1EA9),
+    (0x00E4, 0x0304, 0x01DF),
+    (0x00E5, 0x0301, 0x01FB),
+    (0x00E6, 0x0301, 0x01FD),
+    (0x00E6, 0x0304, 0x01E3),
+    (0x00E7, 0x0301, 0x1E09),
+    (0x00EA, 0x0300, 0x1EC1),
+    (0x00EA, 0x0301, 0x1EBF),
+    (0x00EA, 0x0303, 0x1EC5),
+    (0x00EA, 0x0309, 0x1EC3),
+    (0x00EF, 0x0301, 0x1E2F),
+    (0x00F4, 0x0300, 0x1ED3),
+    (0x00F4, 0x0301, 0x1ED1),
+    (0x00F4, 0x0303, 0x1ED7),
+    (0x00F4, 0x0309, 0x1ED5),
+    (0x00F5, 0x0301, 0x1E4D),
+    (0x00F5, 0x0304, 0x022D),
+    (0x00F5, 0x0308, 0x1E4F),
+    (0x00F6, 0x0304, 0x022B),
+    (0x00F8, 0x0301, 0x01FF),
+    (0x00FC, 0x0300, 0x01DC),
+    (0x00FC, 0x0301, 0x01D8),
+    (0x00FC, 0x0304, 0x01D6),
+    (0x00FC, 0x030C, 0x01DA),
+    (0x0102, 0x0300, 0x1EB0),
+    (0x0102, 0x0301, 0x1EAE),
+    (0x0102, 0x0303, 0x1EB4),
+    (0x0102, 0x0309, 0x1EB2),
+    (0x0103, 0x0300, 0x1EB1),
+    (0x0103, 0x0301, 0x1EAF),
+    (0x0103, 0x0303, 0x1EB5),
+    (0x0103, 0x0309, 0x1EB3),
+    (0x0112, 0x0300, 0x1E14),
+    (0x0112, 0x0301, 0x1E16),
+    (0x0113, 0x0300, 0x1E15),
+    (0x0113, 0x0301, 0x1E17),
+    (0x014C, 0x0300, 0x1E50),
+    (0x014C, 0x0301, 0x1E52),
+    (0x014D, 0x0300, 0x1E51),
+    (0x014D, 0x0301, 0x1E53),
+    (0x015A, 0x0307, 0x1E64),
+    (0x015B, 0x0307, 0x1E65),
+    (0x0160, 0x0307, 0x1E66),
+    (0x0161, 0x0307, 0x1E67),
+    (0x0168, 0x0301, 0x1E78),
+    (0x0169, 0x0301, 0x1E79),
+    (0x016A, 0x0308, 0x1E7A),
+    (0x016B, 0x0308, 0x1E7B),
+    (0x017F, 0x0307, 0x1E9B),
+    (0x01A0, 0x0300, 0x1EDC),
+    (0x01A0, 0x0301, 0x1EDA),
+    (0x01A0, 0x0303, 0x1EE0),
+    (0x01A0, 0x0309, 0x1EDE),
+    (0x01A0, 0x0323, 0x1EE2),
+    (0x01A1, 0x0300, 0x1EDD),
+    (0x01A1, 0x0301, 0x1EDB),
+    (0x01A1, 0x0303, 0x1EE1),
+    (0x01A1, 0x0309, 0x1EDF),
+    (0x01A1, 0x0323, 0x1EE3),
+    (0x01AF, 0x0300, 0x1EEA),
+    (0x01AF, 0x0301, 0x1EE8),
+    (0x01AF, 0x0303, 0x1EEE),
+    (0x01AF, 0x0309, 0x1EEC),
+    (0x01AF, 0x0323, 0x1EF0),
+    (0x01B0, 0x0300, 0x1EEB),
+    (0x01B0, 0x0301, 0x1EE9),
+    (0x01B0, 0x0303, 0x1EEF),
+    (0x01B0, 0x0309, 0x1EED),
+    (0x01B0, 0x0323, 0x1EF1),
+    (0x01B7, 0x030C, 0x01EE),
+    (0x01EA, 0x0304, 0x01EC),
+    (0x01EB, 0x0304, 0x01ED),
+    (0x0226, 0x0304, 0x01E0),
+    (0x0227, 0x0304, 0x01E1),
+    (0x0228, 0x0306, 0x1E1C),
+    (0x0229, 0x0306, 0x1E1D),
+    (0x022E, 0x0304, 0x0230),
+    (0x022F, 0x0304, 0x0231),
+    (0x0292, 0x030C, 0x01EF),
+    (0x0391, 0x0300, 0x1FBA),
+    (0x0391, 0x0301, 0x0386),
+    (0x0391, 0x0304, 0x1FB9),
+    (0x0391, 0x0306, 0x1FB8),
+    (0x0391, 0x0313, 0x1F08),
+    (0x0391, 0x0314, 0x1F09),
+    (0x0391, 0x0345, 0x1FBC),
+    (0x0395, 0x0300, 0x1FC8),
+    (0x0395, 0x0301, 0x0388),
+    (0x0395, 0x0313, 0x1F18),
+    (0x0395, 0x0314, 0x1F19),
+    (0x0397, 0x0300, 0x1FCA),
+    (0x0397, 0x0301, 0x0389),
+    (0x0397, 0x0313, 0x1F28),
+    (0x0397, 0x0314, 0x1F29),
+    (0x0397, 0x0345, 0x1FCC),
+    (0x0399, 0x0300, 0x1FDA),
+    (0x0399, 0x0301, 0x038A),
+    (0x0399, 0x0304, 0x1FD9),
+    (0x0399, 0x0306, 0x1FD8),
+    (0x0399, 0x0308, 0x03AA),
+    (0x0399, 0x0313, 0x1F38),
+    (0x0399, 0x0314, 0x1F39),
+    (0x039F, 0x0300, 0x1FF8),
+    (0x039F, 0x0301, 0x038C),
+    (0x039F, 0x0313, 0x1F48),
+    (0x039F, 0x0314, 0x1F49),
+    (0x03A1, 0x0314, 0x1FEC),
+    (0x03A5, 0x0300, 0x1FEA),
+    (0x03A5, 0x0301, 0x038E),
+    (0x03A5, 0x0304, 0x1FE9),
+    (0x03A5, 0x0306, 0x1FE8),
+    (0x03A5, 0x0308, 0x03AB),
+    (0x03A5, 0x0314, 0x1F59),
+    (0x03A9, 0x0300, 0x1FFA),
+    (0x03A9, 0x0301, 0x038F),
+    (0x03A9, 0x0313, 0x1F68),
+    (0x03A9, 0x0314, 0x1F69),
+    (0x03A9, 0x0345, 0x1FFC),
+    (0x03AC, 0x0345, 0x1FB4),
+    (0x03AE, 0x0345, 0x1FC4),
+    (0x03B1, 0x0300, 0x1F70),
+    (0x03B1, 0x0301, 0x03AC),
+    (0x03B1, 0x0304, 0x1FB1),
+    (0x03B1, 0x0306, 0x1FB0),
+    (0x03B1, 0x0313, 0x1F00),
+    (0x03B1, 0x0314, 0x1F01),
+    (0x03B1, 0x0342, 0x1FB6),
+    (0x03B1, 0x0345, 0x1FB3),
+    (0x03B5, 0x0300, 0x1F72),
+    (0x03B5, 0x0301, 0x03AD),
+    (0x03B5, 0x0313, 0x1F10),
+    (0x03B5, 0x0314, 0x1F11),
+    (0x03B7, 0x0300, 0x1F74),
+    (0x03B7, 0x0301, 0x03AE),
+    (0x03B7, 0x0313, 0x1F20),
+    (0x03B7, 0x0314, 0x1F21),
+    (0x03B7, 0x0342, 0x1FC6),
+    (0x03B7, 0x0345, 0x1FC3),
+    (0x03B9, 0x0300, 0x1F76),
+    (0x03B9, 0x0301, 0x03AF),
+    (0x03B9, 0x0304, 0x1FD1),
+    (0x03B9, 0x0306, 0x1FD0),
+    (0x03B9, 0x0308, 0x03CA),
+    (0x03B9, 0x0313, 0x1F30),
+    (0x03B9, 0x0314, 0x1F31),
+    (0x03B9, 0x0342, 0x1FD6),
+    (0x03BF, 0x0300, 0x1F78),
+    (0x03BF, 0x0301, 0x03CC),
+    (0x03BF, 0x0313, 0x1F40),
+    (0x03BF, 0x0314, 0x1F41),
+    (0x03C1, 0x0313, 0x1FE4),
+    (0x03C1, 0x0314, 0x1FE5),
+    (0x03C5, 0x0300, 0x1F7A),
+    (0x03C5, 0x0301, 0x03CD),
+    (0x03C5, 0x0304, 0x1FE1),
+    (0x03C5, 0x0306, 0x1FE0),
+    (0x03C5, 0x0308, 0x03CB),
+    (0x03C5, 0x0313, 0x1F50),
+    (0x03C5, 0x0314, 0x1F51),
+    (0x03C5, 0x0342, 0x1FE6),
+    (0x03C9, 0x0300, 0x1F7C),
+    (0x03C9, 0x0301, 0x03CE),
+    (0x03C9, 0x0313, 0x1F60),
+    (0x03C9, 0x0314, 0x1F61),
+    (0x03C9, 0x0342, 0x1FF6),
+    (0x03C9, 0x0345, 0x1FF3),
+    (0x03CA, 0x0300, 0x1FD2),
+    (0x03CA, 0x0301, 0x0390),
+    (0x03CA, 0x0342, 0x1FD7),
+    (0x03CB, 0x0300, 0x1FE2),
+    (0x03CB, 0x0301, 0x03B0),
+    (0x03CB, 0x0342, 0x1FE7),
+    (0x03CE, 0x0345, 0x1FF4),
+    (0x03D2, 0x0301, 0x03D3),
+    (0x03D2, 0x0308, 0x03D4),
+    (0x0406, 0x0308, 0x0407),
+    (0x0410, 0x0306, 0x04D0),
+    (0x0410, 0x0308, 0x04D2),
+    (0x0413, 0x0301, 0x0403),
+    (0x0415, 0x0300, 0x0400),
+    (0x0415, 0x0306, 0x04D6),
+    (0x0415, 0x0308, 0x0401),
+    (0x0416, 0x0306, 0x04C1),
+    (0x0416, 0x0308, 0x04DC),
+    (0x0417, 0x0308, 0x04DE),
+    (0x0418, 0x0300, 0x040D),
+    (0x0418, 0x0304, 0x04E2),
+    (0x0418, 0x0306, 0x0419),
+    (0x0418, 0x0308, 0x04E4),
+    (0x041A, 0x0301, 0x040C),
+    (0x041E, 0x0308, 0x04E6),
+    (0x0423, 0x0304, 0x04EE),
+    (0x0423, 0x0306, 0x040E),
+    (0x0423, 0x0308, 0x04F0),
+    (0x0423, 0x030B, 0x04F2),
+    (0x0427, 0x0308, 0x04F4),
+    (0x042B, 0x0308, 0x04F8),
+    (0x042D, 0x0308, 0x04EC),
+    (0x0430, 0x0306, 0x04D1),
+    (0x0430, 0x0308, 0x04D3),
+    (0x0433, 0x0301, 0x0453),
+    (0x0435, 0x0300, 0x0450),
+    (0x0435, 0x0306, 0x04D7),
+    (0x0435, 0x0308, 0x0451),
+    (0x0436, 0x0306, 0x04C2),
+    (0x0436, 0x0308, 0x04DD),
+    (0x0437, 0x0308, 0x04DF),
+    (0x0438, 0x0300, 0x045D),
+    (0x0438, 0x0304, 0x04E3),
+    (0x0438, 0x0306, 0x0439),
+    (0x0438, 0x0308, 0x04E5),
+    (0x043A, 0x0301, 0x045C),
+    (0x043E, 0x0308, 0x04E7),
+    (0x0443, 0x0304, 0x04EF),
+    (0x0443, 0x0306, 0x045E),
+    (0x0443, 0x0308, 0x04F1),
+    (0x0443, 0x030B, 0x04F3),
+    (0x0447, 0x0308, 0x04F5),
+    (0x044B, 0x0308, 0x04F9),
+    (0x044D, 0x0308, 0x04ED),
+    (0x0456, 0x0308, 0x0457),
+    (0x0474, 0x030F, 0x0476),
+    (0x0475, 0x030F, 0x0477),
+    (0x04D8, 0x0308, 0x04DA),
+    (0x04D9, 0x0308, 0x04DB),
+    (0x04E8, 0x0308, 0x04EA),
+    (0x04E9, 0x0308, 0x04EB),
+    (0x0627, 0x0653, 0x0622),
+    (0x0627, 0x0654, 0x0623),
+    (0x0627, 0x0655, 0x0625),
+    (0x0648, 0x0654, 0x0624),
+    (0x064A, 0x0654, 0x0626),
+    (0x06C1, 0x0654, 0x06C2),
+    (0x06D2, 0x0654, 0x06D3),
+    (0x06D5, 0x0654, 0x06C0),
+    (0x0928, 0x093C, 0x0929),
+    (0x0930, 0x093C, 0x0931),
+    (0x0933, 0x093C, 0x0934),
+    (0x09C7, 0x09BE, 0x09CB),
+    (0x09C7, 0x09D7, 0x09CC),
+    (0x0B47, 0x0B3E, 0x0B4B),
+    (0x0B47, 0x0B56, 0x0B48),
+    (0x0B47, 0x0B57, 0x0B4C),
+    (0x0B92, 0x0BD7, 0x0B94),
+    (0x0BC6, 0x0BBE, 0x0BCA),
+    (0x0BC6, 0x0BD7, 0x0BCC),
+    (0x0BC7, 0x0BBE, 0x0BCB),
+    (0x0C46, 0x0C56, 0x0C48),
+    (0x0CBF, 0x0CD5, 0x0CC0),
+    (0x0CC6, 0x0CC2, 0x0CCA),
+    (0x0CC6, 0x0CD5, 0x0CC7),
+    (0x0CC6, 0x0CD6, 0x0CC8),
+    (0x0CCA, 0x0CD5, 0x0CCB),
+    (0x0D46, 0x0D3E, 0x0D4A),
+    (0x0D46, 0x0D57, 0x0D4C),
+    (0x0D47, 0x0D3E, 0x0D4B),
+    (0x0DD9, 0x0DCA, 0x0DDA),
+    (0x0DD9, 0x0DCF, 0x0DDC),
+    (0x0DD9, 0x0DDF, 0x0DDE),
+    (0x0DDC, 0x0DCA, 0x0DDD),
+    (0x1025, 0x102E, 0x1026),
+    (0x1B05, 0x1B35, 0x1B06),
+    (0x1B07, 0x1B35, 0x1B08),
+    (0x1B09, 0x1B35, 0x1B0A),
+    (0x1B0B, 0x1B35, 0x1B0C),
+    (0x1B0D, 0x1B35, 0x1B0E),
+    (0x1B11, 0x1B35, 0x1B12),
+    (0x1B3A, 0x1B35, 0x1B3B),
+    (0x1B3C, 0x1B35, 0x1B3D),
+    (0x1B3E, 0x1B35, 0x1B40),
+    (0x1B3F, 0x1B35, 0x1B41),
+    (0x1B42, 0x1B35, 0x1B43),
+    (0x1E36, 0x0304, 0x1E38),
+    (0x1E37, 0x0304, 0x1E39),
+    (0x1E5A, 0x0304, 0x1E5C),
+    (0x1E5B, 0x0304, 0x1E5D),
+    (0x1E62, 0x0307, 0x1E68),
+    (0x1E63, 0x0307, 0x1E69),
+    (0x1EA0, 0x0302, 0x1EAC),
+    (0x1EA0, 0x0306, 0x1EB6),
+    (0x1EA1, 0x0302, 0x1EAD),
+    (0x1EA1, 0x0306, 0x1EB7),
+    (0x1EB8, 0x0302, 0x1EC6),
+    (0x1EB9, 0x0302, 0x1EC7),
+    (0x1ECC, 0x0302, 0x1ED8),
+    (0x1ECD, 0x0302, 0x1ED9),
+    (0x1F00, 0x0300, 0x1F02),
+    (0x1F00, 0x0301, 0x1F04),
+    (0x1F00, 0x0342, 0x1F06),
+    (0x1F00, 0x0345, 0x1F80),
+    (0x1F01, 0x0300, 0x1F03),
+    (0x1F01, 0x0301, 0x1F05),
+    (0x1F01, 0x0342, 0x1F07),
+    (0x1F01, 0x0345, 0x1F81),
+    (0x1F02, 0x0345, 0x1F82),
+    (0x1F03, 0x0345, 0x1F83),
+    (0x1F04, 0x0345, 0x1F84),
+    (0x1F05, 0x0345, 0x1F85),
+    (0x1F06, 0x0345, 0x1F86),
+    (0x1F07, 0x0345, 0x1F87),
+    (0x1F08, 0x0300, 0x1F0A),
+    (0x1F08, 0x0301, 0x1F0C),
+    (0x1F08, 0x0342, 0x1F0E),
+    (0x1F08, 0x0345, 0x1F88),
+    (0x1F09, 0x0300, 0x1F0B),
+    (0x1F09, 0x0301, 0x1F0D),
+    (0x1F09, 0x0342, 0x1F0F),
+    (0x1F09, 0x0345, 0x1F89),
+    (0x1F0A, 0x0345, 0x1F8A),
+    (0x1F0B, 0x0345, 0x1F8B),
+    (0x1F0C, 0x0345, 0x1F8C),
+    (0x1F0D, 0x0345, 0x1F8D),
+    (0x1F0E, 0x0345, 0x1F8E),
+    (0x1F0F, 0x0345, 0x1F8F),
+    (0x1F10, 0x0300, 0x1F12),
+    (0x1F10, 0x0301, 0x1F14),
+    (0x1F11, 0x0300, 0x1F13),
+    (0x1F11, 0x0301, 0x1F15),
+    (0x1F18, 0x0300, 0x1F1A),
+    (0x1F18, 0x0301, 0x1F1C),
+    (0x1F19, 0x0300, 0x1F1B),
+    (0x1F19, 0x0301, 0x1F1D),
+    (0x1F20, 0x0300, 0x1F22),
+    (0x1F20, 0x0301, 0x1F24),
+    (0x1F20, 0x0342, 0x1F26),
+    (0x1F20, 0x0345, 0x1F90),
+    (0x1F21, 0x0300, 0x1F23),
+    (0x1F21, 0x0301, 0x1F25),
+    (0x1F21, 0x0342, 0x1F27),
+    (0x1F21, 0x0345, 0x1F91),
+    (0x1F22, 0x0345, 0x1F92),
+    (0x1F23, 0x0345, 0x1F93),
+    (0x1F24, 0x0345, 0x1F94),
+    (0x1F25, 0x0345, 0x1F95),
+    (0x1F26, 0x0345, 0x1F96),
+    (0x1F27, 0x0345, 0x1F97),
+    (0x1F28, 0x0300, 0x1F2A),
+    (0x1F28, 0x0301, 0x1F2C),
+    (0x1F28, 0x0342, 0x1F2E),
+    (0x1F28, 0x0345, 0x1F98),
+    (0x1F29, 0x0300, 0x1F2B),
+    (0x1F29, 0x0301, 0x1F2D),
+    (0x1F29, 0x0342, 0x1F2F),
+    (0x1F29, 0x0345, 0x1F99),
+    (0x1F2A, 0x0345, 0x1F9A),
+    (0x1F2B, 0x0345, 0x1F9B),
+    (0x1F2C, 0x0345, 0x1F9C),
+    (0x1F2D, 0x0345, 0x1F9D),
+    (0x1F2E, 0x0345, 0x1F9E),
+    (0x1F2F, 0x0345, 0x1F9F),
+    (0x1F30, 0x0300, 0x1F32),
+    (0x1F30, 0x0301, 0x1F34),
+    (0x1F30, 0x0342, 0x1F36),
+    (0x1F31, 0x0300, 0x1F33),
+    (0x1F31, 0x0301, 0x1F35),
+    (0x1F31, 0x0342, 0x1F37),
+    (0x1F38, 0x0300, 0x1F3A),
+    (0x1F38, 0x0301, 0x1F3C),
+    (0x1F38, 0x0342, 0x1F3E),
+    (0x1F39, 0x0300, 0x1F3B),
+    (0x1F39, 0x0301, 0x1F3D),
+    (0x1F39, 0x0342, 0x1F3F),
+    (0x1F40, 0x0300, 0x1F42),
+    (0x1F40, 0x0301, 0x1F44),
+    (0x1F41, 0x0300, 0x1F43),
+    (0x1F41, 0x0301, 0x1F45),
+    (0x1F48, 0x0300, 0x1F4A),
+    (0x1F48, 0x0301, 0x1F4C),
+    (0x1F49, 0x0300, 0x1F4B),
+    (0x1F49, 0x0301, 0x1F4D),
+    (0x1F50, 0x0300, 0x1F52),
+    (0x1F50, 0x0301, 0x1F54),
+    (0x1F50, 0x0342, 0x1F56),
+    (0x1F51, 0x0300, 0x1F53),
+    (0x1F51, 0x0301, 0x1F55),
+    (0x1F51, 0x0342, 0x1F57),
+    (0x1F59, 0x0300, 0x1F5B),
+    (0x1F59, 0x0301, 0x1F5D),
+    (0x1F59, 0x0342, 0x1F5F),
+    (0x1F60, 0x0300, 0x1F62),
+    (0x1F60, 0x0301, 0x1F64),
+    (0x1F60, 0x0342, 0x1F66),
+    (0x1F60, 0x0345, 0x1FA0),
+    (0x1F61, 0x0300, 0x1F63),
+    (0x1F61, 0x0301, 0x1F65),
+    (0x1F61, 0x0342, 0x1F67),
+    (0x1F61, 0x0345, 0x1FA1),
+    (0x1F62, 0x0345, 0x1FA2),
+    (0x1F63, 0x0345, 0x1FA3),
+    (0x1F64, 0x0345, 0x1FA4),
+    (0x1F65, 0x0345, 0x1FA5),
+    (0x1F66, 0x0345, 0x1FA6),
+    (0x1F67, 0x0345, 0x1FA7),
+    (0x1F68, 0x0300, 0x1F6A),
+    (0x1F68, 0x0301, 0x1F6C),
+    (0x1F68, 0x0342, 0x1F6E),
+    (0x1F68, 0x0345, 0x1FA8),
+    (0x1F69, 0x0300, 0x1F6B),
+    (0x1F69, 0x0301, 0x1F6D),
+    (0x1F69, 0x0342, 0x1F6F),
+    (0x1F69, 0x0345, 0x1FA9),
+    (0x1F6A, 0x0345, 0x1FAA),
+    (0x1F6B, 0x0345, 0x1FAB),
+    (0x1F6C, 0x0345, 0x1FAC),
+    (0x1F6D, 0x0345, 0x1FAD),
+    (0x1F6E, 0x0345, 0x1FAE),
+    (0x1F6F, 0x0345, 0x1FAF),
+    (0x1F70, 0x0345, 0x1FB2),
+    (0x1F74, 0x0345, 0x1FC2),
+    (0x1F7C, 0x0345, 0x1FF2),
+    (0x1FB6, 0x0345, 0x1FB7),
+    (0x1FBF, 0x0300, 0x1FCD),
+    (0x1FBF, 0x0301, 0x1FCE),
+    (0x1FBF, 0x0342, 0x1FCF),
+    (0x1FC6, 0x0345, 0x1FC7),
+    (0x1FF6, 0x0345, 0x1FF7),
+    (0x1FFE, 0x0300, 0x1FDD),
+    (0x1FFE, 0x0301, 0x1FDE),
+    (0x1FFE, 0x0342, 0x1FDF),
+    (0x2190, 0x0338, 0x219A),
+    (0x2192, 0x0338, 0x219B),
+    (0x2194, 0x0338, 0x21AE),
+    (0x21D0, 0x0338, 0x21CD),
+    (0x21D2, 0x0338, 0x21CF),
+    (0x21D4, 0x0338, 0x21CE),
+    (0x2203, 0x0338, 0x2204),
+    (0x2208, 0x0338, 0x2209),
+    (0x220B, 0x0338, 0x220C),
+    (0x2223, 0x0338, 0x2224),
+    (0x2225, 0x0338, 0x2226),
+    (0x223C, 0x0338, 0x2241),
+    (0x2243, 0x0338, 0x2244),
+    (0x2245, 0x0338, 0x2247),
+    (0x2248, 0x0338, 0x2249),
+    (0x224D, 0x0338, 0x226D),
+    (0x2261, 0x0338, 0x2262),
+    (0x2264, 0x0338, 0x2270),
+    (0x2265, 0x0338, 0x2271),
+    (0x2272, 0x0338, 0x2274),
+    (0x2273, 0x0338, 0x2275),
+    (0x2276, 0x0338, 0x2278),
+    (0x2277, 0x0338, 0x2279),
+    (0x227A, 0x0338, 0x2280),
+    (0x227B, 0x0338, 0x2281),
+    (0x227C, 0x0338, 0x22E0),
+    (0x227D, 0x0338, 0x22E1),
+    (0x2282, 0x0338, 0x2284),
+    (0x2283, 0x0338, 0x2285),
+    (0x2286, 0x0338, 0x2288),
+    (0x2287, 0x0338, 0x2289),
+    (0x2291, 0x0338, 0x22E2),
+    (0x2292, 0x0338, 0x22E3),
+    (0x22A2, 0x0338, 0x22AC),
+    (0x22A8, 0x0338, 0x22AD),
+    (0x22A9, 0x0338, 0x22AE),
+    (0x22AB, 0x0338, 0x22AF),
+    (0x22B2, 0x0338, 0x22EA),
+    (0x22B3, 0x0338, 0x22EB),
+    (0x22B4, 0x0338, 0x22EC),
+    (0x22B5, 0x0338, 0x22ED),
+    (0x3046, 0x3099, 0x3094),
+    (0x304B, 0x3099, 0x304C),
+    (0x304D, 0x3099, 0x304E),
+    (0x304F, 0x3099, 0x3050),
+    (0x3051, 0x3099, 0x3052),
+    (0x3053, 0x3099, 0x3054),
+    (0x3055, 0x3099, 0x3056),
+    (0x3057, 0x3099, 0x3058),
+    (0x3059, 0x3099, 0x305A),
+    (0x305B, 0x3099, 0x305C),
+    (0x305D, 0x3099, 0x305E),
+    (0x305F, 0x3099, 0x3060),
+    (0x3061, 0x3099, 0x3062),
+    (0x3064, 0x3099, 0x3065),
+    (0x3066, 0x3099, 0x3067),
+    (0x3068, 0x3099, 0x3069),
+    (0x306F, 0x3099, 0x3070),
+    (0x306F, 0x309A, 0x3071),
+    (0x3072, 0x3099, 0x3073),
+    (0x3072, 0x309A, 0x3074),
+    (0x3075, 0x3099, 0x3076),
+    (0x3075, 0x309A, 0x3077),
+    (0x3078, 0x3099, 0x3079),
+    (0x3078, 0x309A, 0x307A),
+    (0x307B, 0x3099, 0x307C),
+    (0x307B, 0x309A, 0x307D),
+    (0x309D, 0x3099, 0x309E),
+    (0x30A6, 0x3099, 0x30F4),
+    (0x30AB, 0x3099, 0x30AC),
+    (0x30AD, 0x3099, 0x30AE),
+    (0x30AF, 0x3099, 0x30B0),
+    (0x30B1, 0x3099, 0x30B2),
+    (0x30B3, 0x3099, 0x30B4),
+    (0x30B5, 0x3099, 0x30B6),
+    (0x30B7, 0x3099, 0x30B8),
+    (0x30B9, 0x3099, 0x30BA),
+    (0x30BB, 0x3099, 0x30BC),
+    (0x30BD, 0x3099, 0x30BE),
+    (0x30BF, 0x3099, 0x30C0),
+    (0x30C1, 0x3099, 0x30C2),
+    (0x30C4, 0x3099, 0x30C5),
+    (0x30C6, 0x3099, 0x30C7),
+    (0x30C8, 0x3099, 0x30C9),
+    (0x30CF, 0x3099, 0x30D0),
+    (0x30CF, 0x309A, 0x30D1),
+    (0x30D2, 0x3099, 0x30D3),
+    (0x30D2, 0x309A, 0x30D4),
+    (0x30D5, 0x3099, 0x30D6),
+    (0x30D5, 0x309A, 0x30D7),
+    (0x30D8, 0x3099, 0x30D9),
+    (0x30D8, 0x309A, 0x30DA),
+    (0x30DB, 0x3099, 0x30DC),
+    (0x30DB, 0x309A, 0x30DD),
+    (0x30EF, 0x3099, 0x30F7),
+    (0x30F0, 0x3099, 0x30F8),
+    (0x30F1, 0x3099, 0x30F9),
+    (0x30F2, 0x3099, 0x30FA),
+    (0x30FD, 0x3099, 0x30FE),
+    (0x11099, 0x110BA, 0x1109A),
+    (0x1109B, 0x110BA, 0x1109C),
+    (0x110A5, 0x110BA, 0x110AB),
+    (0x11131, 0x11127, 0x1112E),
+    (0x11132, 0x11127, 0x1112F),
+    (0x11347, 0x1133E, 0x1134B),
+    (0x11347, 0x11357, 0x1134C),
+    (0x114B9, 0x114B0, 0x114BC),
+    (0x114B9, 0x114BA, 0x114BB),
+    (0x114B9, 0x114BD, 0x114BE),
+    (0x115B8, 0x115AF, 0x115BA),
+    (0x115B9, 0x115AF, 0x115BB),
+    (0x11935, 0x11930, 0x11938),
+];
+
+pub(super) static CHARACTER_CATEGORIES: [(u32, u32, i8); 705] = [
+    (0x0000, 0x0009, 0),
+    (0x000A, 0x000A, 11),
+    (0x000B, 0x000C, 0),
+    (0x000D, 0x000D, 7),
+    (0x000E, 0x001F, 0),
+    (0x0020, 0x007E, 19),
+    (0x007F, 0x009F, 0),
+    (0x00A0, 0x00AC, 19),
+    (0x00AD, 0x00AD, 0),
+    (0x00AE, 0x02FF, 19),
+    (0x0300, 0x036F, 15),
+    (0x0370, 0x0482, 19),
+    (0x0483, 0x0489, 15),
+    (0x048A, 0x0590, 19),
+    (0x0591, 0x05BD, 15),
+    (0x05BE, 0x05BE, 19),
+    (0x05BF, 0x05BF, 15),
+    (0x05C0, 0x05C0, 19),
+    (0x05C1, 0x05C2, 15),
+    (0x05C3, 0x05C3, 19),
+    (0x05C4, 0x05C5, 15),
+    (0x05C6, 0x05C6, 19),
+    (0x05C7, 0x05C7, 15),
+    (0x05C8, 0x05FF, 19),
+    (0x0600, 0x0605, 0),
+    (0x0606, 0x060F, 19),
+    (0x0610, 0x061A, 15),
+    (0x061B, 0x061B, 19),
+    (0x061C, 0x061C, 0),
+    (0x061D, 0x064A, 19),
+    (0x064B, 0x065F, 15),
+    (0x0660, 0x066F, 19),
+    (0x0670, 0x0670, 15),
+    (0x0671, 0x06D5, 19),
+    (0x06D6, 0x06DC, 15),
+    (0x06DD, 0x06DD, 0),
+    (0x06DE, 0x06DE, 19),
+    (0x06DF, 0x06E4, 15),
+    (0x06E5, 0x06E6, 19),
+    (0x06E7, 0x06E8, 15),
+    (0x06E9, 0x06E9, 19),
+    (0x06EA, 0x06ED, 15),
+    (0x06EE, 0x070E, 19),
+    (0x070F, 0x070F, 0),
+    (0x0710, 0x0710, 19),
+    (0x0711, 0x0711, 15),
+    (0x0712, 0x072F, 19),
+    (0x0730, 0x074A, 15),
+    (0x074B, 0x07A5, 19),
+    (0x07A6, 0x07B0, 15),
+    (0x07B1, 0x07EA, 19),
+    (0x07EB, 0x07F3, 15),
+    (0x07F4, 0x07FC, 19),
+    (0x07FD, 0x07FD, 15),
+    (0x07FE, 0x0815, 19),
+    (0x0816, 0x0819, 15),
+    (0x081A, 0x081A, 19),
+    (0x081B, 0x0823, 15),
+    (0x0824, 0x0824, 19),
+    (0x0825, 0x0827, 15),
+    (0x0828, 0x0828, 19),
+    (0x0829, 0x082D, 15),
+    (0x082E, 0x0858, 19),
+    (0x0859, 0x085B, 15),
+    (0x085C, 0x08D2, 19),
+    (0x08D3, 0x08E1, 15),
+    (0x08E2, 0x08E2, 0),
+    (0x08E3, 0x0901, 15),
+    (0x0902, 0x0902, 13),
+    (0x0903, 0x0903, 16),
+    (0x0904, 0x0914, 19),
+    (0x0915, 0x0939, 1),
+    (0x093A, 0x093A, 15),
+    (0x093B, 0x093B, 19),
+    (0x093C, 0x093C, 8),
+    (0x093D, 0x093D, 19),
+    (0x093E, 0x0940, 12),
+    (0x0941, 0x0948, 2),
+    (0x0949, 0x094C, 12),
+    (0x094D, 0x094D, 9),
+    (0x094E, 0x0950, 19),
+    (0x0951, 0x0954, 13),
+    (0x0955, 0x0957, 15),
+    (0x0958, 0x095F, 6),
+    (0x0960, 0x0961, 19),
+    (0x0962, 0x0963, 2),
+    (0x0964, 0x0965, 5),
+    (0x0966, 0x0980, 19),
+    (0x0981, 0x0981, 15),
+    (0x0982, 0x09BB, 19),
+    (0x09BC, 0x09BC, 15),
+    (0x09BD, 0x09C0, 19),
+    (0x09C1, 0x09C4, 15),
+    (0x09C5, 0x09CC, 19),
+    (0x09CD, 0x09CD, 15),
+    (0x09CE, 0x09E1, 19),
+    (0x09E2, 0x09E3, 15),
+    (0x09E4, 0x09FD, 19),
+    (0x09FE, 0x09FE, 15),
+    (0x09FF, 0x0A00, 19),
+    (0x0A01, 0x0A02, 15),
+    (0x0A03, 0x0A3B, 19),
+    (0x0A3C, 0x0A3C, 15),
+    (0x0A3D, 0x0A40, 19),
+    (0x0A41, 0x0A42, 15),
+    (0x0A43, 0x0A46, 19),
+    (0x0A47, 0x0A48, 15),
+    (0x0A49, 0x0A4A, 19),
+    (0x0A4B, 0x0A4D, 15),
+    (0x0A4E, 0x0A50, 19),
+    (0x0A51, 0x0A51, 15),
+    (0x0A52, 0x0A6F, 19),
+    (0x0A70, 0x0A71, 15),
+    (0x0A72, 0x0A74, 19),
+    (0x0A75, 0x0A75, 15),
+    (0x0A76, 0x0A80, 19),
+    (0x0A81, 0x0A82, 15),
+    (0x0A83, 0x0ABB, 19),
+    (0x0ABC, 0x0ABC, 15),
+    (0x0ABD, 0x0AC0, 19),
+    (0x0AC1, 0x0AC5, 15),
+    (0x0AC6, 0x0AC6, 19),
+    (0x0AC7, 0x0AC8, 15),
+    (0x0AC9, 0x0ACC, 19),
+    (0x0ACD, 0x0ACD, 15),
+    (0x0ACE, 0x0AE1, 19),
+    (0x0AE2, 0x0AE3, 15),
+    (0x0AE4, 0x0AF9, 19),
+    (0x0AFA, 0x0AFF, 15),
+    (0x0B00, 0x0B00, 19),
+    (0x0B01, 0x0B01, 15),
+    (0x0B02, 0x0B3B, 19),
+    (0x0B3C, 0x0B3C, 15),
+    (0x0B3D, 0x0B3E, 19),
+    (0x0B3F, 0x0B3F, 15),
+    (0x0B40, 0x0B40, 19),
+    (0x0B41, 0x0B44, 15),
+    (0x0B45, 0x0B4C, 19),
+    (0x0B4D, 0x0B4D, 15),
+    (0x0B4E, 0x0B54, 19),
+    (0x0B55, 0x0B56, 15),
+    (0x0B57, 0x0B61, 19),
+    (0x0B62, 0x0B63, 15),
+    (0x0B64, 0x0B81, 19),
+    (0x0B82, 0x0B82, 15),
+    (0x0B83, 0x0BBF, 19),
+    (0x0BC0, 0x0BC0, 15),
+    (0x0BC1, 0x0BCC, 19),
+    (0x0BCD, 0x0BCD, 15),
+    (0x0BCE, 0x0BFF, 19),
+    (0x0C00, 0x0C00, 15),
+    (0x0C01, 0x0C03, 19),
+    (0x0C04, 0x0C04, 15),
+    (0x0C05, 0x0C3D, 19),
+    (0x0C3E, 0x0C40, 15),
+    (0x0C41, 0x0C45, 19),
+    (0x0C46, 0x0C48, 15),
+    (0x0C49, 0x0C49, 19),
+    (0x0C4A, 0x0C4D, 15),
+    (0x0C4E, 0x0C54, 19),
+    (0x0C55, 0x0C56, 15),
+    (0x0C57, 0x0C61, 19),
+    (0x0C62, 0x0C63, 15),
+    (0x0C64, 0x0C80, 19),
+    (0x0C81, 0x0C81, 15),
+    (0x0C82, 0x0CBB, 19),
+    (0x0CBC, 0x0CBC, 15),
+    (0x0CBD, 0x0CBE, 19),
+    (0x0CBF, 0x0CBF, 15),
+    (0x0CC0, 0x0CC5, 19),
+    (0x0CC6, 0x0CC6, 15),
+    (0x0CC7, 0x0CCB, 19),
+    (0x0CCC, 0x0CCD, 15),
+    (0x0CCE, 0x0CE1, 19),
+    (0x0CE2, 0x0CE3, 15),
+    (0x0CE4, 0x0CFF, 19),
+    (0x0D00, 0x0D01, 15),
+    (0x0D02, 0x0D3A, 19),
+    (0x0D3B, 0x0D3C, 15),
+    (0x0D3D, 0x0D40, 19),
+    (0x0D41, 0x0D44, 15),
+    (0x0D45, 0x0D4C, 19),
+    (0x0D4D, 0x0D4D, 15),
+    (0x0D4E, 0x0D61, 19),
+    (0x0D62, 0x0D63, 15),
+    (0x0D64, 0x0D80, 19),
+    (0x0D81, 0x0D81, 15),
+    (0x0D82, 0x0DC9, 19),
+    (0x0DCA, 0x0DCA, 15),
+    (0x0DCB, 0x0DD1, 19),
+    (0x0DD2, 0x0DD4, 15),
+    (0x0DD5, 0x0DD5, 19),
+    (0x0DD6, 0x0DD6, 15),
+    (0x0DD7, 0x0E30, 19),
+    (0x0E31, 0x0E31, 15),
+    (0x0E32, 0x0E33, 19),
+    (0x0E34, 0x0E3A, 15),
+    (0x0E3B, 0x0E46, 19),
+    (0x0E47, 0x0E4E, 15),
+    (0x0E4F, 0x0EB0, 19),
+    (0x0EB1, 0x0EB1, 15),
+    (0x0EB2, 0x0EB3, 19),
+    (0x0EB4, 0x0EBC, 15),
+    (0x0EBD, 0x0EC7, 19),
+    (0x0EC8, 0x0ECD, 15),
+    (0x0ECE, 0x0F17, 19),
+    (0x0F18, 0x0F19, 15),
+    (0x0F1A, 0x0F34, 19),
+    (0x0F35, 0x0F35, 15),
+    (0x0F36, 0x0F36, 19),
+    (0x0F37, 0x0F37, 15),
+    (0x0F38, 0x0F38, 19),
+    (0x0F39, 0x0F39, 15),
+    (0x0F3A, 0x0F70, 19),
+    (0x0F71, 0x0F7E, 15),
+    (0x0F7F, 0x0F7F, 19),
+    (0x0F80, 0x0F84, 15),
+    (0x0F85, 0x0F85, 19),
+    (0x0F86, 0x0F87, 15),
+    (0x0F88, 0x0F8C, 19),
+    (0x0F8D, 0x0F97, 15),
+    (0x0F98, 0x0F98, 19),
+    (0x0F99, 0x0FBC, 15),
+    (0x0FBD, 0x0FC5, 19),
+    (0x0FC6, 0x0FC6, 15),
+    (0x0FC7, 0x102C, 19),
+    (0x102D, 0x1030, 15),
+    (0x1031, 0x1031, 19),
+    (0x1032, 0x1037, 15),
+    (0x1038, 0x1038, 19),
+    (0x1039, 0x103A, 15),
+    (0x103B, 0x103C, 19),
+    (0x103D, 0x103E, 15),
+    (0x103F, 0x1057, 19),
+    (0x1058, 0x1059, 15),
+    (0x105A, 0x105D, 19),
+    (0x105E, 0x1060, 15),
+    (0x1061, 0x1070, 19),
+    (0x1071, 0x1074, 15),
+    (0x1075, 0x1081, 19),
+    (0x1082, 0x1082, 15),
+    (0x1083, 0x1084, 19),
+    (0x1085, 0x1086, 15),
+    (0x1087, 0x108C, 19),
+    (0x108D, 0x108D, 15),
+    (0x108E, 0x109C, 19),
+    (0x109D, 0x109D, 15),
+    (0x109E, 0x10FF, 19),
+    (0x1100, 0x115F, 17),
+    (0x1160, 0x11A7, 14),
+    (0x11A8, 0x11FF, 3),
+    (0x1200, 0x135C, 19),
+    (0x135D, 0x135F, 15),
+    (0x1360, 0x1711, 19),
+    (0x1712, 0x1714, 15),
+    (0x1715, 0x1731, 19),
+    (0x1732, 0x1734, 15),
+    (0x1735, 0x1751, 19),
+    (0x1752, 0x1753, 15),
+    (0x1754, 0x1771, 19),
+    (0x1772, 0x1773, 15),
+    (0x1774, 0x17B3, 19),
+    (0x17B4, 0x17B5, 15),
+    (0x17B6, 0x17B6, 19),
+    (0x17B7, 0x17BD, 15),
+    (0x17BE, 0x17C5, 19),
+    (0x17C6, 0x17C6, 15),
+    (0x17C7, 0x17C8, 19),
+    (0x17C9, 0x17D3, 15),
+    (0x17D4, 0x17DC, 19),
+    (0x17DD, 0x17DD, 15),
+    (0x17DE, 0x180A, 19),
+    (0x180B, 0x180D, 15),
+    (0x180E, 0x180E, 0),
+    (0x180F, 0x1884, 19),
+    (0x1885, 0x1886, 15),
+    (0x1887, 0x18A8, 19),
+    (0x18A9, 0x18A9, 15),
+    (0x18AA, 0x191F, 19),
+    (0x1920, 0x1922, 15),
+    (0x1923, 0x1926, 19),
+    (0x1927, 0x1928, 15),
+    (0x1929, 0x1931, 19),
+    (0x1932, 0x1932, 15),
+    (0x1933, 0x1938, 19),
+    (0x1939, 0x193B, 15),
+    (0x193C, 0x1A16, 19),
+    (0x1A17, 0x1A18, 15),
+    (0x1A19, 0x1A1A, 19),
+    (0x1A1B, 0x1A1B, 15),
+    (0x1A1C, 0x1A55, 19),
+    (0x1A56, 0x1A56, 15),
+    (0x1A57, 0x1A57, 19),
+    (0x1A58, 0x1A5E, 15),
+    (0x1A5F, 0x1A5F, 19),
+    (0x1A60, 0x1A60, 15),
+    (0x1A61, 0x1A61, 19),
+    (0x1A62, 0x1A62, 15),
+    (0x1A63, 0x1A64, 19),
+    (0x1A65, 0x1A6C, 15),
+    (0x1A6D, 0x1A72, 19),
+    (0x1A73, 0x1A7C, 15),
+    (0x1A7D, 0x1A7E, 19),
+    (0x1A7F, 0x1A7F, 15),
+    (0x1A80, 0x1AAF, 19),
+    (0x1AB0, 0x1AC0, 15),
+    (0x1AC1, 0x1AFF, 19),
+    (0x1B00, 0x1B03, 15),
+    (0x1B04, 0x1B33, 19),
+    (0x1B34, 0x1B34, 15),
+    (0x1B35, 0x1B35, 19),
+    (0x1B36, 0x1B3A, 15),
+    (0x1B3B, 0x1B3B, 19),
+    (0x1B3C, 0x1B3C, 15),
+    (0x1B3D, 0x1B41, 19),
+    (0x1B42, 0x1B42, 15),
+    (0x1B43, 0x1B6A, 19),
+    (0x1B6B, 0x1B73, 15),
+    (0x1B74, 0x1B7F, 19),
+    (0x1B80, 0x1B81, 15),
+    (0x1B82, 0x1BA1, 19),
+    (0x1BA2, 0x1BA5, 15),
+    (0x1BA6, 0x1BA7, 19),
+    (0x1BA8, 0x1BA9, 15),
+    (0x1BAA, 0x1BAA, 19),
+    (0x1BAB, 0x1BAD, 15),
+    (0x1BAE, 0x1BE5, 19),
+    (0x1BE6, 0x1BE6, 15),
+    (0x1BE7, 0x1BE7, 19),
+    (0x1BE8, 0x1BE9, 15),
+    (0x1BEA, 0x1BEC, 19),
+    (0x1BED, 0x1BED, 15),
+    (0x1BEE, 0x1BEE, 19),
+    (0x1BEF, 0x1BF1, 15),
+    (0x1BF2, 0x1C2B, 19),
+    (0x1C2C, 0x1C33, 15),
+    (0x1C34, 0x1C35, 19),
+    (0x1C36, 0x1C37, 15),
+    (0x1C38, 0x1CCF, 19),
+    (0x1CD0, 0x1CD2, 15),
+    (0x1CD3, 0x1CD3, 19),
+    (0x1CD4, 0x1CE0, 15),
+    (0x1CE1, 0x1CE1, 19),
+    (0x1CE2, 0x1CE8, 15),
+    (0x1CE9, 0x1CEC, 19),
+    (0x1CED, 0x1CED, 15),
+    (0x1CEE, 0x1CF3, 19),
+    (0x1CF4, 0x1CF4, 15),
+    (0x1CF5, 0x1CF7, 19),
+    (0x1CF8, 0x1CF9, 15),
+    (0x1CFA, 0x1DBF, 19),
+    (0x1DC0, 0x1DF9, 15),
+    (0x1DFA, 0x1DFA, 19),
+    (0x1DFB, 0x1DFF, 15),
+    (0x1E00, 0x200A, 19),
+    (0x200B, 0x200C, 0),
+    (0x200D, 0x200D, 18),
+    (0x200E, 0x200F, 0),
+    (0x2010, 0x2027, 19),
+    (0x2028, 0x202E, 0),
+    (0x202F, 0x205F, 19),
+    (0x2060, 0x2064, 0),
+    (0x2065, 0x2065, 19),
+    (0x2066, 0x206F, 0),
+    (0x2070, 0x20CF, 19),
+    (0x20D0, 0x20F0, 15),
+    (0x20F1, 0x2CEE, 19),
+    (0x2CEF, 0x2CF1, 15),
+    (0x2CF2, 0x2D7E, 19),
+    (0x2D7F, 0x2D7F, 15),
+    (0x2D80, 0x2DDF, 19),
+    (0x2DE0, 0x2DFF, 15),
+    (0x2E00, 0x3029, 19),
+    (0x302A, 0x302D, 15),
+    (0x302E, 0x3098, 19),
+    (0x3099, 0x309A, 15),
+    (0x309B, 0xA66E, 19),
+    (0xA66F, 0xA672, 15),
+    (0xA673, 0xA673, 19),
+    (0xA674, 0xA67D, 15),
+    (0xA67E, 0xA69D, 19),
+    (0xA69E, 0xA69F, 15),
+    (0xA6A0, 0xA6EF, 19),
+    (0xA6F0, 0xA6F1, 15),
+    (0xA6F2, 0xA801, 19),
+    (0xA802, 0xA802, 15),
+    (0xA803, 0xA805, 19),
+    (0xA806, 0xA806, 15),
+    (0xA807, 0xA80A, 19),
+    (0xA80B, 0xA80B, 15),
+    (0xA80C, 0xA824, 19),
+    (0xA825, 0xA826, 15),
+    (0xA827, 0xA82B, 19),
+    (0xA82C, 0xA82C, 15),
+    (0xA82D, 0xA8C3, 19),
+    (0xA8C4, 0xA8C5, 15),
+    (0xA8C6, 0xA8DF, 19),
+    (0xA8E0, 0xA8F1, 15),
+    (0xA8F2, 0xA8FE, 19),
+    (0xA8FF, 0xA8FF, 15),
+    (0xA900, 0xA925, 19),
+    (0xA926, 0xA92D, 15),
+    (0xA92E, 0xA946, 19),
+    (0xA947, 0xA951, 15),
+    (0xA952, 0xA97F, 19),
+    (0xA980, 0xA982, 15),
+    (0xA983, 0xA9B2, 19),
+    (0xA9B3, 0xA9B3, 15),
+    (0xA9B4, 0xA9B5, 19),
+    (0xA9B6, 0xA9B9, 15),
+    (0xA9BA, 0xA9BB, 19),
+    (0xA9BC, 0xA9BD, 15),
+    (0xA9BE, 0xA9E4, 19),
+    (0xA9E5, 0xA9E5, 15),
+    (0xA9E6, 0xAA28, 19),
+    (0xAA29, 0xAA2E, 15),
+    (0xAA2F, 0xAA30, 19),
+    (0xAA31, 0xAA32, 15),
+    (0xAA33, 0xAA34, 19),
+    (0xAA35, 0xAA36, 15),
+    (0xAA37, 0xAA42, 19),
+    (0xAA43, 0xAA43, 15),
+    (0xAA44, 0xAA4B, 19),
+    (0xAA4C, 0xAA4C, 15),
+    (0xAA4D, 0xAA7B, 19),
+    (0xAA7C, 0xAA7C, 15),
+    (0xAA7D, 0xAAAF, 19),
+    (0xAAB0, 0xAAB0, 15),
+    (0xAAB1, 0xAAB1, 19),
+    (0xAAB2, 0xAAB4, 15),
+    (0xAAB5, 0xAAB6, 19),
+    (0xAAB7, 0xAAB8, 15),
+    (0xAAB9, 0xAABD, 19),
+    (0xAABE, 0xAABF, 15),
+    (0xAAC0, 0xAAC0, 19),
+    (0xAAC1, 0xAAC1, 15),
+    (0xAAC2, 0xAAEB, 19),
+    (0xAAEC, 0xAAED, 15),
+    (0xAAEE, 0xAAF5, 19),
+    (0xAAF6, 0xAAF6, 15),
+    (0xAAF7, 0xABE4, 19),
+    (0xABE5, 0xABE5, 15),
+    (0xABE6, 0xABE7, 19),
+    (0xABE8, 0xABE8, 15),
+    (0xABE9, 0xABEC, 19),
+    (0xABED, 0xABED, 15),
+    (0xABEE, 0xD7FF, 19),
+    (0xD800, 0xDBFF, 10),
+    (0xDC00, 0xDFFF, 4),
+    (0xE000, 0xFB1D, 19),
+    (0xFB1E, 0xFB1E, 15),
+    (0xFB1F, 0xFDFF, 19),
+    (0xFE00, 0xFE0F, 15),
+    (0xFE10, 0xFE1F, 19),
+    (0xFE20, 0xFE2F, 15),
+    (0xFE30, 0xFEFE, 19),
+    (0xFEFF, 0xFEFF, 0),
+    (0xFF00, 0xFFF8, 19),
+    (0xFFF9, 0xFFFB, 0),
+    (0xFFFC, 0x101FC, 19),
+    (0x101FD, 0x101FD, 15),
+    (0x101FE, 0x102DF, 19),
+    (0x102E0, 0x102E0, 15),
+    (0x102E1, 0x10375, 19),
+    (0x10376, 0x1037A, 15),
+    (0x1037B, 0x10A00, 19),
+    (0x10A01, 0x10A03, 15),
+    (0x10A04, 0x10A04, 19),
+    (0x10A05, 0x10A06, 15),
+    (0x10A07, 0x10A0B, 19),
+    (0x10A0C, 0x10A0F, 15),
+    (0x10A10, 0x10A37, 19),
+    (0x10A38, 0x10A3A, 15),
+    (0x10A3B, 0x10A3E, 19),
+    (0x10A3F, 0x10A3F, 15),
+    (0x10A40, 0x10AE4, 19),
+    (0x10AE5, 0x10AE6, 15),
+    (0x10AE7, 0x10D23, 19),
+    (0x10D24, 0x10D27, 15),
+    (0x10D28, 0x10EAA, 19),
+    (0x10EAB, 0x10EAC, 15),
+    (0x10EAD, 0x10F45, 19),
+    (0x10F46, 0x10F50, 15),
+    (0x10F51, 0x11000, 19),
+    (0x11001, 0x11001, 15),
+    (0x11002, 0x11037, 19),
+    (0x11038, 0x11046, 15),
+    (0x11047, 0x1107E, 19),
+    (0x1107F, 0x11081, 15),
+    (0x11082, 0x110B2, 19),
+    (0x110B3, 0x110B6, 15),
+    (0x110B7, 0x110B8, 19),
+    (0x110B9, 0x110BA, 15),
+    (0x110BB, 0x110BC, 19),
+    (0x110BD, 0x110BD, 0),
+    (0x110BE, 0x110CC, 19),
+    (0x110CD, 0x110CD, 0),
+    (0x110CE, 0x110FF, 19),
+    (0x11100, 0x11102, 15),
+    (0x11103, 0x11126, 19),
+    (0x11127, 0x1112B, 15),
+    (0x1112C, 0x1112C, 19),
+    (0x1112D, 0x11134, 15),
+    (0x11135, 0x11172, 19),
+    (0x11173, 0x11173, 15),
+    (0x11174, 0x1117F, 19),
+    (0x11180, 0x11181, 15),
+    (0x11182, 0x111B5, 19),
+    (0x111B6, 0x111BE, 15),
+    (0x111BF, 0x111C8, 19),
+    (0x111C9, 0x111CC, 15),
+    (0x111CD, 0x111CE, 19),
+    (0x111CF, 0x111CF, 15),
+    (0x111D0, 0x1122E, 19),
+    (0x1122F, 0x11231, 15),
+    (0x11232, 0x11233, 19),
+    (0x11234, 0x11234, 15),
+    (0x11235, 0x11235, 19),
+    (0x11236, 0x11237, 15),
+    (0x11238, 0x1123D, 19),
+    (0x1123E, 0x1123E, 15),
+    (0x1123F, 0x112DE, 19),
+    (0x112DF, 0x112DF, 15),
+    (0x112E0, 0x112E2, 19),
+    (0x112E3, 0x112EA, 15),
+    (0x112EB, 0x112FF, 19),
+    (0x11300, 0x11301, 15),
+    (0x11302, 0x1133A, 19),
+    (0x1133B, 0x1133C, 15),
+    (0x1133D, 0x1133F, 19),
+    (0x11340, 0x11340, 15),
+    (0x11341, 0x11365, 19),
+    (0x11366, 0x1136C, 15),
+    (0x1136D, 0x1136F, 19),
+    (0x11370, 0x11374, 15),
+    (0x11375, 0x11437, 19),
+    (0x11438, 0x1143F, 15),
+    (0x11440, 0x11441, 19),
+    (0x11442, 0x11444, 15),
+    (0x11445, 0x11445, 19),
+    (0x11446, 0x11446, 15),
+    (0x11447, 0x1145D, 19),
+    (0x1145E, 0x1145E, 15),
+    (0x1145F, 0x114B2, 19),
+    (0x114B3, 0x114B8, 15),
+    (0x114B9, 0x114B9, 19),
+    (0x114BA, 0x114BA, 15),
+    (0x114BB, 0x114BE, 19),
+    (0x114BF, 0x114C0, 15),
+    (0x114C1, 0x114C1, 19),
+    (0x114C2, 0x114C3, 15),
+    (0x114C4, 0x115B1, 19),
+    (0x115B2, 0x115B5, 15),
+    (0x115B6, 0x115BB, 19),
+    (0x115BC, 0x115BD, 15),
+    (0x115BE, 0x115BE, 19),
+    (0x115BF, 0x115C0, 15),
+    (0x115C1, 0x115DB, 19),
+    (0x115DC, 0x115DD, 15),
+    (0x115DE, 0x11632, 19),
+    (0x11633, 0x1163A, 15),
+    (0x1163B, 0x1163C, 19),
+    (0x1163D, 0x1163D, 15),
+    (0x1163E, 0x1163E, 19),
+    (0x1163F, 0x11640, 15),
+    (0x11641, 0x116AA, 19),
+    (0x116AB, 0x116AB, 15),
+    (0x116AC, 0x116AC, 19),
+    (0x116AD, 0x116AD, 15),
+    (0x116AE, 0x116AF, 19),
+    (0x116B0, 0x116B5, 15),
+    (0x116B6, 0x116B6, 19),
+    (0x116B7, 0x116B7, 15),
+    (0x116B8, 0x1171C, 19),
+    (0x1171D, 0x1171F, 15),
+    (0x11720, 0x11721, 19),
+    (0x11722, 0x11725, 15),
+    (0x11726, 0x11726, 19),
+    (0x11727, 0x1172B, 15),
+    (0x1172C, 0x1182E, 19),
+    (0x1182F, 0x11837, 15),
+    (0x11838, 0x11838, 19),
+    (0x11839, 0x1183A, 15),
+    (0x1183B, 0x1193A, 19),
+    (0x1193B, 0x1193C, 15),
+    (0x1193D, 0x1193D, 19),
+    (0x1193E, 0x1193E, 15),
+    (0x1193F, 0x11942, 19),
+    (0x11943, 0x11943, 15),
+    (0x11944, 0x119D3, 19),
+    (0x119D4, 0x119D7, 15),
+    (0x119D8, 0x119D9, 19),
+    (0x119DA, 0x119DB, 15),
+    (0x119DC, 0x119DF, 19),
+    (0x119E0, 0x119E0, 15),
+    (0x119E1, 0x11A00, 19),
+    (0x11A01, 0x11A0A, 15),
+    (0x11A0B, 0x11A32, 19),
+    (0x11A33, 0x11A38, 15),
+    (0x11A39, 0x11A3A, 19),
+    (0x11A3B, 0x11A3E, 15),
+    (0x11A3F, 0x11A46, 19),
+    (0x11A47, 0x11A47, 15),
+    (0x11A48, 0x11A50, 19),
+    (0x11A51, 0x11A56, 15),
+    (0x11A57, 0x11A58, 19),
+    (0x11A59, 0x11A5B, 15),
+    (0x11A5C, 0x11A89, 19),
+    (0x11A8A, 0x11A96, 15),
+    (0x11A97, 0x11A97, 19),
+    (0x11A98, 0x11A99, 15),
+    (0x11A9A, 0x11C2F, 19),
+    (0x11C30, 0x11C36, 15),
+    (0x11C37, 0x11C37, 19),
+    (0x11C38, 0x11C3D, 15),
+    (0x11C3E, 0x11C3E, 19),
+    (0x11C3F, 0x11C3F, 15),
+    (0x11C40, 0x11C91, 19),
+    (0x11C92, 0x11CA7, 15),
+    (0x11CA8, 0x11CA9, 19),
+    (0x11CAA, 0x11CB0, 15),
+    (0x11CB1, 0x11CB1, 19),
+    (0x11CB2, 0x11CB3, 15),
+    (0x11CB4, 0x11CB4, 19),
+    (0x11CB5, 0x11CB6, 15),
+    (0x11CB7, 0x11D30, 19),
+    (0x11D31, 0x11D36, 15),
+    (0x11D37, 0x11D39, 19),
+    (0x11D3A, 0x11D3A, 15),
+    (0x11D3B, 0x11D3B, 19),
+    (0x11D3C, 0x11D3D, 15),
+    (0x11D3E, 0x11D3E, 19),
+    (0x11D3F, 0x11D45, 15),
+    (0x11D46, 0x11D46, 19),
+    (0x11D47, 0x11D47, 15),
+    (0x11D48, 0x11D8F, 19),
+    (0x11D90, 0x11D91, 15),
+    (0x11D92, 0x11D94, 19),
+    (0x11D95, 0x11D95, 15),
+    (0x11D96, 0x11D96, 19),
+    (0x11D97, 0x11D97, 15),
+    (0x11D98, 0x11EF2, 19),
+    (0x11EF3, 0x11EF4, 15),
+    (0x11EF5, 0x1342F, 19),
+    (0x13430, 0x13438, 0),
+    (0x13439, 0x16AEF, 19),
+    (0x16AF0, 0x16AF4, 15),
+    (0x16AF5, 0x16B2F, 19),
+    (0x16B30, 0x16B36, 15),
+    (0x16B37, 0x16F4E, 19),
+    (0x16F4F, 0x16F4F, 15),
+    (0x16F50, 0x16F8E, 19),
+    (0x16F8F, 0x16F92, 15),
+    (0x16F93, 0x16FE3, 19),
+    (0x16FE4, 0x16FE4, 15),
+    (0x16FE5, 0x1BC9C, 19),
+    (0x1BC9D, 0x1BC9E, 15),
+    (0x1BC9F, 0x1D166, 19),
+    (0x1D167, 0x1D169, 15),
+    (0x1D16A, 0x1D172, 19),
+    (0x1D173, 0x1D17A, 0),
+    (0x1D17B, 0x1D182, 15),
+    (0x1D183, 0x1D184, 19),
+    (0x1D185, 0x1D18B, 15),
+    (0x1D18C, 0x1D1A9, 19),
+    (0x1D1AA, 0x1D1AD, 15),
+    (0x1D1AE, 0x1D241, 19),
+    (0x1D242, 0x1D244, 15),
+    (0x1D245, 0x1D9FF, 19),
+    (0x1DA00, 0x1DA36, 15),
+    (0x1DA37, 0x1DA3A, 19),
+    (0x1DA3B, 0x1DA6C, 15),
+    (0x1DA6D, 0x1DA74, 19),
+    (0x1DA75, 0x1DA75, 15),
+    (0x1DA76, 0x1DA83, 19),
+    (0x1DA84, 0x1DA84, 15),
+    (0x1DA85, 0x1DA9A, 19),
+    (0x1DA9B, 0x1DA9F, 15),
+    (0x1DAA0, 0x1DAA0, 19),
+    (0x1DAA1, 0x1DAAF, 15),
+    (0x1DAB0, 0x1DFFF, 19),
+    (0x1E000, 0x1E006, 15),
+    (0x1E007, 0x1E007, 19),
+    (0x1E008, 0x1E018, 15),
+    (0x1E019, 0x1E01A, 19),
+    (0x1E01B, 0x1E021, 15),
+    (0x1E022, 0x1E022, 19),
+    (0x1E023, 0x1E024, 15),
+    (0x1E025, 0x1E025, 19),
+    (0x1E026, 0x1E02A, 15),
+    (0x1E02B, 0x1E12F, 19),
+    (0x1E130, 0x1E136, 15),
+    (0x1E137, 0x1E2EB, 19),
+    (0x1E2EC, 0x1E2EF, 15),
+    (0x1E2F0, 0x1E8CF, 19),
+    (0x1E8D0, 0x1E8D6, 15),
+    (0x1E8D7, 0x1E943, 19),
+    (0x1E944, 0x1E94A, 15),
+    (0x1E94B, 0xE0000, 19),
+    (0xE0001, 0xE0001, 0),
+    (0xE0002, 0xE001F, 19),
+    (0xE0020, 0xE007F, 0),
+    (0xE0080, 0xE00FF, 19),
+    (0xE0100, 0xE01EF, 15),
+    (0xE01F0, 0x10FFFF, 19),
+];
+
+pub(super) static CHARACTER: Rules = Rules {
+    categories: &CHARACTER_CATEGORIES,
+    num_categories: 20,
+    state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 17, 2, 11, 3, 3, 15, 4, 2, 2, 5, 2, 3, 2, 9, 2, 3, 7, 2, 3,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 3, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 3, 0, 2, 0, 0, 0, 3, 3, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 6, 0, 0,
+        0, 0, 3, 10, 0, 0, 0, 0, 3, 3, 0, 0, 0, 3, 8, 3, 0, 6, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0,
+        0, 0, 3, 10, 0, 0, 0, 0, 3, 3, 0, 0, 0, 3, 8, 3, 0, 0, 0, 0,
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 3, 10, 0, 0, 0, 0, 3, 3, 0, 0, 0, 3, 0, 3, 0, 0, 0, 0,
+        0, 16, 3, 0, 0, 0, 14, 0, 3, 3, 0, 0, 0, 3, 0, 3, 0, 0, 13, 0,
+        0, 16, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 2, 0, 0, 2, 0, 0, 0,
+        0, 0, 19, 0, 0, 0, 0, 0, 3, 12, 0, 0, 18, 3, 0, 3, 2, 0, 0, 0,
+        0, 0, 18, 0, 0, 0, 0, 0, 14, 0, 0, 0, 18, 2, 0, 0, 2, 0, 0, 0,
+        0, 0, 19, 0, 0, 0, 0, 0, 15, 12, 0, 0, 18, 3, 0, 3, 2, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 2, 0, 0, 0,
+        0, 0, 3, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 3, 0, 3, 2, 0, 0, 0,
+    ],
+    backwards_state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 3, 4, 5, 6, 2, 3, 2, 4, 4, 2, 7, 8, 4, 9, 4, 10, 11, 12, 2,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0,
+        0, 3, 4, 5, 6, 2, 3, 0, 4, 4, 2, 0, 8, 4, 9, 4, 10, 11, 0, 2,
+        0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 3, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 11, 0, 0,
+        0, 3, 4, 0, 0, 0, 3, 0, 4, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    end_states: &[false, true, true, true, true, true, true, true, true, true, true, true, true, false, true, true, true, true, true, true],
+    lookahead_states: &[false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false],
+};
+
+pub(super) static WORD_CATEGORIES: [(u32, u32, i8); 2076] = [
+    (0x0000, 0x0008, 0),
+    (0x0009, 0x0009, 17),
+    (0x000A, 0x000A, 16),
+    (0x000B, 0x000B, 0),
+    (0x000C, 0x000C, 16),
+    (0x000D, 0x000D, 7),
+    (0x000E, 0x001F, 0),
+    (0x0020, 0x0020, 13),
+    (0x0021, 0x0021, 20),
+    (0x0022, 0x0022, 8),
+    (0x0023, 0x0024, 9),
+    (0x0025, 0x0026, 6),
+    (0x0027, 0x0027, 8),
+    (0x0028, 0x002B, 20),
+    (0x002C, 0x002C, 19),
+    (0x002D, 0x002D, 21),
+    (0x002E, 0x002E, 2),
+    (0x002F, 0x002F, 20),
+    (0x0030, 0x0039, 12),
+    (0x003A, 0x0040, 20),
+    (0x0041, 0x005A, 4),
+    (0x005B, 0x005E, 20),
+    (0x005F, 0x005F, 21),
+    (0x0060, 0x0060, 20),
+    (0x0061, 0x007A, 4),
+    (0x007B, 0x007E, 20),
+    (0x007F, 0x009F, 0),
+    (0x00A0, 0x00A0, 13),
+    (0x00A1, 0x00A1, 20),
+    (0x00A2, 0x00A2, 6),
+    (0x00A3, 0x00A5, 9),
+    (0x00A6, 0x00A9, 20),
+    (0x00AA, 0x00AA, 4),
+    (0x00AB, 0x00AC, 20),
+    (0x00AD, 0x00AD, 18),
+    (0x00AE, 0x00B1, 20),
+    (0x00B2, 0x00B3, 12),
+    (0x00B4, 0x00B4, 20),
+    (0x00B5, 0x00B5, 4),
+    (0x00B6, 0x00B8, 20),
+    (0x00B9, 0x00B9, 12),
+    (0x00BA, 0x00BA, 4),
+    (0x00BB, 0x00BB, 20),
+    (0x00BC, 0x00BE, 12),
+    (0x00BF, 0x00BF, 20),
+    (0x00C0, 0x00D6, 4),
+    (0x00D7, 0x00D7, 20),
+    (0x00D8, 0x00F6, 4),
+    (0x00F7, 0x00F7, 20),
+    (0x00F8, 0x02C1, 4),
+    (0x02C2, 0x02C5, 20),
+    (0x02C6, 0x02D1, 4),
+    (0x02D2, 0x02DF, 20),
+    (0x02E0, 0x02E4, 4),
+    (0x02E5, 0x02EB, 20),
+    (0x02EC, 0x02EC, 4),
+    (0x02ED, 0x02ED, 20),
+    (0x02EE, 0x02EE, 4),
+    (0x02EF, 0x02FF, 20),
+    (0x0300, 0x036F, 11),
+    (0x0370, 0x0374, 4),
+    (0x0375, 0x0375, 20),
+    (0x0376, 0x0377, 4),
+    (0x0378, 0x0379, 20),
+    (0x037A, 0x037D, 4),
+    (0x037E, 0x037E, 20),
+    (0x037F, 0x037F, 4),
+    (0x0380, 0x0385, 20),
+    (0x0386, 0x0386, 4),
+    (0x0387, 0x0387, 20),
+    (0x0388, 0x038A, 4),
+    (0x038B, 0x038B, 20),
+    (0x038C, 0x038C, 4),
+    (0x038D, 0x038D, 20),
+    (0x038E, 0x03A1, 4),
+    (0x03A2, 0x03A2, 20),
+    (0x03A3, 0x03F5, 4),
+    (0x03F6, 0x03F6, 20),
+    (0x03F7, 0x0481, 4),
+    (0x0482, 0x0482, 20),
+    (0x0483, 0x0489, 11),
+    (0x048A, 0x052F, 4),
+    (0x0530, 0x0530, 20),
+    (0x0531, 0x0556, 4),
+    (0x0557, 0x0558, 20),
+    (0x0559, 0x0559, 4),
+    (0x055A, 0x055F, 20),
+    (0x0560, 0x0588, 4),
+    (0x0589, 0x0589, 20),
+    (0x058A, 0x058A, 21),
+    (0x058B, 0x058E, 20),
+    (0x058F, 0x058F, 9),
+    (0x0590, 0x0590, 20),
+    (0x0591, 0x05BD, 11),
+    (0x05BE, 0x05BE, 21),
+    (0x05BF, 0x05BF, 11),
+    (0x05C0, 0x05C0, 20),
+    (0x05C1, 0x05C2, 11),
+    (0x05C3, 0x05C3, 20),
+    (0x05C4, 0x05C5, 11),
+    (0x05C6, 0x05C6, 20),
+    (0x05C7, 0x05C7, 11),
+    (0x05C8, 0x05CF, 20),
+    (0x05D0, 0x05EA, 4),
+    (0x05EB, 0x05EE, 20),
+    (0x05EF, 0x05F2, 4),
+    (0x05F3, 0x05FF, 20),
+    (0x0600, 0x0605, -1),
+    (0x0606, 0x060A, 20),
+    (0x060B, 0x060B, 9),
+    (0x060C, 0x060F, 20),
+    (0x0610, 0x061A, 11),
+    (0x061B, 0x061B, 20),
+    (0x061C, 0x061C, -1),
+    (0x061D, 0x061F, 20),
+    (0x0620, 0x064A, 4),
+    (0x064B, 0x065F, 11),
+    (0x0660, 0x0669, 12),
+    (0x066A, 0x066A, 6),
+    (0x066B, 0x066B, 19),
+    (0x066C, 0x066D, 20),
+    (0x066E, 0x066F, 4),
+    (0x0670, 0x0670, 11),
+    (0x0671, 0x06D3, 4),
+    (0x06D4, 0x06D4, 20),
+    (0x06D5, 0x06D5, 4),
+    (0x06D6, 0x06DC, 11),
+    (0x06DD, 0x06DD, -1),
+    (0x06DE, 0x06DE, 20),
+    (0x06DF, 0x06E4, 11),
+    (0x06E5, 0x06E6, 4),
+    (0x06E7, 0x06E8, 11),
+    (0x06E9, 0x06E9, 20),
+    (0x06EA, 0x06ED, 11),
+    (0x06EE, 0x06EF, 4),
+    (0x06F0, 0x06F9, 12),
+    (0x06FA, 0x06FC, 4),
+    (0x06FD, 0x06FE, 20),
+    (0x06FF, 0x06FF, 4),
+    (0x0700, 0x070E, 20),
+    (0x070F, 0x070F, -1),
+    (0x0710, 0x0710, 4),
+    (0x0711, 0x0711, 11),
+    (0x0712, 0x072F, 4),
+    (0x0730, 0x074A, 11),
+    (0x074B, 0x074C, 20),
+    (0x074D, 0x07A5, 4),
+    (0x07A6, 0x07B0, 11),
+    (0x07B1, 0x07B1, 4),
+    (0x07B2, 0x07BF, 20),
+    (0x07C0, 0x07C9, 12),
+    (0x07CA, 0x07EA, 4),
+    (0x07EB, 0x07F3, 11),
+    (0x07F4, 0x07F5, 4),
+    (0x07F6, 0x07F9, 20),
+    (0x07FA, 0x07FA, 4),
+    (0x07FB, 0x07FC, 20),
+    (0x07FD, 0x07FD, 11),
+    (0x07FE, 0x07FF, 9),
+    (0x0800, 0x0815, 4),
+    (0x0816, 0x0819, 11),
+    (0x081A, 0x081A, 4),
+    (0x081B, 0x0823, 11),
+    (0x0824, 0x0824, 4),
+    (0x0825, 0x0827, 11),
+    (0x0828, 0x0828, 4),
+    (0x0829, 0x082D, 11),
+    (0x082E, 0x083F, 20),
+    (0x0840, 0x0858, 4),
+    (0x0859, 0x085B, 11),
+    (0x085C, 0x085F, 20),
+    (0x0860, 0x086A, 4),
+    (0x086B, 0x089F, 20),
+    (0x08A0, 0x08B4, 4),
+    (0x08B5, 0x08B5, 20),
+    (0x08B6, 0x08C7, 4),
+    (0x08C8, 0x08D2, 20),
+    (0x08D3, 0x08E1, 11),
+    (0x08E2, 0x08E2, -1),
+    (0x08E3, 0x0902, 11),
+    (0x0903, 0x0939, 4),
+    (0x093A, 0x093A, 11),
+    (0x093B, 0x093B, 4),
+    (0x093C, 0x093C, 11),
+    (0x093D, 0x0940, 4),
+    (0x0941, 0x0948, 11),
+    (0x0949, 0x094C, 4),
+    (0x094D, 0x094D, 11),
+    (0x094E, 0x0950, 4),
+    (0x0951, 0x0957, 11),
+    (0x0958, 0x0961, 4),
+    (0x0962, 0x0963, 11),
+    (0x0964, 0x0965, 5),
+    (0x0966, 0x096F, 12),
+    (0x0970, 0x0970, 20),
+    (0x0971, 0x0980, 4),
+    (0x0981, 0x0981, 11),
+    (0x0982, 0x0983, 4),
+    (0x0984, 0x0984, 20),
+    (0x0985, 0x098C, 4),
+    (0x098D, 0x098E, 20),
+    (0x098F, 0x0990, 4),
+    (0x0991, 0x0992, 20),
+    (0x0993, 0x09A8, 4),
+    (0x09A9, 0x09A9, 20),
+    (0x09AA, 0x09B0, 4),
+    (0x09B1, 0x09B1, 20),
+    (0x09B2, 0x09B2, 4),
+    (0x09B3, 0x09B5, 20),
+    (0x09B6, 0x09B9, 4),
+    (0x09BA, 0x09BB, 20),
+    (0x09BC, 0x09BC, 11),
+    (0x09BD, 0x09C0, 4),
+    (0x09C1, 0x09C4, 11),
+    (0x09C5, 0x09C6, 20),
+    (0x09C7, 0x09C8, 4),
+    (0x09C9, 0x09CA, 20),
+    (0x09CB, 0x09CC, 4),
+    (0x09CD, 0x09CD, 11),
+    (0x09CE, 0x09CE, 4),
+    (0x09CF, 0x09D6, 20),
+    (0x09D7, 0x09D7, 4),
+    (0x09D8, 0x09DB, 20),
+    (0x09DC, 0x09DD, 4),
+    (0x09DE, 0x09DE, 20),
+    (0x09DF, 0x09E1, 4),
+    (0x09E2, 0x09E3, 11),
+    (0x09E4, 0x09E5, 20),
+    (0x09E6, 0x09EF, 12),
+    (0x09F0, 0x09F1, 4),
+    (0x09F2, 0x09F3, 9),
+    (0x09F4, 0x09F9, 12),
+    (0x09FA, 0x09FA, 20),
+    (0x09FB, 0x09FB, 9),
+    (0x09FC, 0x09FC, 4),
+    (0x09FD, 0x09FD, 20),
+    (0x09FE, 0x09FE, 11),
+    (0x09FF, 0x0A00, 20),
+    (0x0A01, 0x0A02, 11),
+    (0x0A03, 0x0A03, 4),
+    (0x0A04, 0x0A04, 20),
+    (0x0A05, 0x0A0A, 4),
+    (0x0A0B, 0x0A0E, 20),
+    (0x0A0F, 0x0A10, 4),
+    (0x0A11, 0x0A12, 20),
+    (0x0A13, 0x0A28, 4),
+    (0x0A29, 0x0A29, 20),
+    (0x0A2A, 0x0A30, 4),
+    (0x0A31, 0x0A31, 20),
+    (0x0A32, 0x0A33, 4),
+    (0x0A34, 0x0A34, 20),
+    (0x0A35, 0x0A36, 4),
+    (0x0A37, 0x0A37, 20),
+    (0x0A38, 0x0A39, 4),
+    (0x0A3A, 0x0A3B, 20),
+    (0x0A3C, 0x0A3C, 11),
+    (0x0A3D, 0x0A3D, 20),
+    (0x0A3E, 0x0A40, 4),
+    (0x0A41, 0x0A42, 11),
+    (0x0A43, 0x0A46, 20),
+    (0x0A47, 0x0A48, 11),
+    (0x0A49, 0x0A4A, 20),
+    (0x0A4B, 0x0A4D, 11),
+    (0x0A4E, 0x0A50, 20),
+    (0x0A51, 0x0A51, 11),
+    (0x0A52, 0x0A58, 20),
+    (0x0A59, 0x0A5C, 4),
+    (0x0A5D, 0x0A5D, 20),
+    (0x0A5E, 0x0A5E, 4),
+    (0x0A5F, 0x0A65, 20),
+    (0x0A66, 0x0A6F, 12),
+    (0x0A70, 0x0A71, 11),
+    (0x0A72, 0x0A74, 4),
+    (0x0A75, 0x0A75, 11),
+    (0x0A76, 0x0A80, 20),
+    (0x0A81, 0x0A82, 11),
+    (0x0A83, 0x0A83, 4),
+    (0x0A84, 0x0A84, 20),
+    (0x0A85, 0x0A8D, 4),
+    (0x0A8E, 0x0A8E, 20),
+    (0x0A8F, 0x0A91, 4),
+    (0x0A92, 0x0A92, 20),
+    (0x0A93, 0x0AA8, 4),
+    (0x0AA9, 0x0AA9, 20),
+    (0x0AAA, 0x0AB0, 4),
+    (0x0AB1, 0x0AB1, 20),
+    (0x0AB2, 0x0AB3, 4),
+    (0x0AB4, 0x0AB4, 20),
+    (0x0AB5, 0x0AB9, 4),
+    (0x0ABA, 0x0ABB, 20),
+    (0x0ABC, 0x0ABC, 11),
+    (0x0ABD, 0x0AC0, 4),
+    (0x0AC1, 0x0AC5, 11),
+    (0x0AC6, 0x0AC6, 20),
+    (0x0AC7, 0x0AC8, 11),
+    (0x0AC9, 0x0AC9, 4),
+    (0x0ACA, 0x0ACA, 20),
+    (0x0ACB, 0x0ACC, 4),
+    (0x0ACD, 0x0ACD, 11),
+    (0x0ACE, 0x0ACF, 20),
+    (0x0AD0, 0x0AD0, 4),
+    (0x0AD1, 0x0ADF, 20),
+    (0x0AE0, 0x0AE1, 4),
+    (0x0AE2, 0x0AE3, 11),
+    (0x0AE4, 0x0AE5, 20),
+    (0x0AE6, 0x0AEF, 12),
+    (0x0AF0, 0x0AF0, 20),
+    (0x0AF1, 0x0AF1, 9),
+    (0x0AF2, 0x0AF8, 20),
+    (0x0AF9, 0x0AF9, 4),
+    (0x0AFA, 0x0AFF, 11),
+    (0x0B00, 0x0B00, 20),
+    (0x0B01, 0x0B01, 11),
+    (0x0B02, 0x0B03, 4),
+    (0x0B04, 0x0B04, 20),
+    (0x0B05, 0x0B0C, 4),
+    (0x0B0D, 0x0B0E, 20),
+    (0x0B0F, 0x0B10, 4),
+    (0x0B11, 0x0B12, 20),
+    (0x0B13, 0x0B28, 4),
+    (0x0B29, 0x0B29, 20),
+    (0x0B2A, 0x0B30, 4),
+    (0x0B31, 0x0B31, 20),
+    (0x0B32, 0x0B33, 4),
+    (0x0B34, 0x0B34, 20),
+    (0x0B35, 0x0B39, 4),
+    (0x0B3A, 0x0B3B, 20),
+    (0x0B3C, 0x0B3C, 11),
+    (0x0B3D, 0x0B3E, 4),
+    (0x0B3F, 0x0B3F, 11),
+    (0x0B40, 0x0B40, 4),
+    (0x0B41, 0x0B44, 11),
+    (0x0B45, 0x0B46, 20),
+    (0x0B47, 0x0B48, 4),
+    (0x0B49, 0x0B4A, 20),
+    (0x0B4B, 0x0B4C, 4),
+    (0x0B4D, 0x0B4D, 11),
+    (0x0B4E, 0x0B54, 20),
+    (0x0B55, 0x0B56, 11),
+    (0x0B57, 0x0B57, 4),
+    (0x0B58, 0x0B5B, 20),
+    (0x0B5C, 0x0B5D, 4),
+    (0x0B5E, 0x0B5E, 20),
+    (0x0B5F, 0x0B61, 4),
+    (0x0B62, 0x0B63, 11),
+    (0x0B64, 0x0B65, 20),
+    (0x0B66, 0x0B6F, 12),
+    (0x0B70, 0x0B70, 20),
+    (0x0B71, 0x0B71, 4),
+    (0x0B72, 0x0B77, 12),
+    (0x0B78, 0x0B81, 20),
+    (0x0B82, 0x0B82, 11),
+    (0x0B83, 0x0B83, 4),
+    (0x0B84, 0x0B84, 20),
+    (0x0B85, 0x0B8A, 4),
+    (0x0B8B, 0x0B8D, 20),
+    (0x0B8E, 0x0B90, 4),
+    (0x0B91, 0x0B91, 20),
+    (0x0B92, 0x0B95, 4),
+    (0x0B96, 0x0B98, 20),
+    (0x0B99, 0x0B9A, 4),
+    (0x0B9B, 0x0B9B, 20),
+    (0x0B9C, 0x0B9C, 4),
+    (0x0B9D, 0x0B9D, 20),
+    (0x0B9E, 0x0B9F, 4),
+    (0x0BA0, 0x0BA2, 20),
+    (0x0BA3, 0x0BA4, 4),
+    (0x0BA5, 0x0BA7, 20),
+    (0x0BA8, 0x0BAA, 4),
+    (0x0BAB, 0x0BAD, 20),
+    (0x0BAE, 0x0BB9, 4),
+    (0x0BBA, 0x0BBD, 20),
+    (0x0BBE, 0x0BBF, 4),
+    (0x0BC0, 0x0BC0, 11),
+    (0x0BC1, 0x0BC2, 4),
+    (0x0BC3, 0x0BC5, 20),
+    (0x0BC6, 0x0BC8, 4),
+    (0x0BC9, 0x0BC9, 20),
+    (0x0BCA, 0x0BCC, 4),
+    (0x0BCD, 0x0BCD, 11),
+    (0x0BCE, 0x0BCF, 20),
+    (0x0BD0, 0x0BD0, 4),
+    (0x0BD1, 0x0BD6, 20),
+    (0x0BD7, 0x0BD7, 4),
+    (0x0BD8, 0x0BE5, 20),
+    (0x0BE6, 0x0BF2, 12),
+    (0x0BF3, 0x0BF8, 20),
+    (0x0BF9, 0x0BF9, 9),
+    (0x0BFA, 0x0BFF, 20),
+    (0x0C00, 0x0C00, 11),
+    (0x0C01, 0x0C03, 4),
+    (0x0C04, 0x0C04, 11),
+    (0x0C05, 0x0C0C, 4),
+    (0x0C0D, 0x0C0D, 20),
+    (0x0C0E, 0x0C10, 4),
+    (0x0C11, 0x0C11, 20),
+    (0x0C12, 0x0C28, 4),
+    (0x0C29, 0x0C29, 20),
+    (0x0C2A, 0x0C39, 4),
+    (0x0C3A, 0x0C3C, 20),
+    (0x0C3D, 0x0C3D, 4),
+    (0x0C3E, 0x0C40, 11),
+    (0x0C41, 0x0C44, 4),
+    (0x0C45, 0x0C45, 20),
+    (0x0C46, 0x0C48, 11),
+    (0x0C49, 0x0C49, 20),
+    (0x0C4A, 0x0C4D, 11),
+    (0x0C4E, 0x0C54, 20),
+    (0x0C55, 0x0C56, 11),
+    (0x0C57, 0x0C57, 20),
+    (0x0C58, 0x0C5A, 4),
+    (0x0C5B, 0x0C5F, 20),
+    (0x0C60, 0x0C61, 4),
+    (0x0C62, 0x0C63, 11),
+    (0x0C64, 0x0C65, 20),
+    (0x0C66, 0x0C6F, 12),
+    (0x0C70, 0x0C77, 20),
+    (0x0C78, 0x0C7E, 12),
+    (0x0C7F, 0x0C7F, 20),
+    (0x0C80, 0x0C80, 4),
+    (0x0C81, 0x0C81, 11),
+    (0x0C82, 0x0C83, 4),
+    (0x0C84, 0x0C84, 20),
+    (0x0C85, 0x0C8C, 4),
+    (0x0C8D, 0x0C8D, 20),
+    (0x0C8E, 0x0C90, 4),
+    (0x0C91, 0x0C91, 20),
+    (0x0C92, 0x0CA8, 4),
+    (0x0CA9, 0x0CA9, 20),
+    (0x0CAA, 0x0CB3, 4),
+    (0x0CB4, 0x0CB4, 20),
+    (0x0CB5, 0x0CB9, 4),
+    (0x0CBA, 0x0CBB, 20),
+    (0x0CBC, 0x0CBC, 11),
+    (0x0CBD, 0x0CBE, 4),
+    (0x0CBF, 0x0CBF, 11),
+    (0x0CC0, 0x0CC4, 4),
+    (0x0CC5, 0x0CC5, 20),
+    (0x0CC6, 0x0CC6, 11),
+    (0x0CC7, 0x0CC8, 4),
+    (0x0CC9, 0x0CC9, 20),
+    (0x0CCA, 0x0CCB, 4),
+    (0x0CCC, 0x0CCD, 11),
+    (0x0CCE, 0x0CD4, 20),
+    (0x0CD5, 0x0CD6, 4),
+    (0x0CD7, 0x0CDD, 20),
+    (0x0CDE, 0x0CDE, 4),
+    (0x0CDF, 0x0CDF, 20),
+    (0x0CE0, 0x0CE1, 4),
+    (0x0CE2, 0x0CE3, 11),
+    (0x0CE4, 0x0CE5, 20),
+    (0x0CE6, 0x0CEF, 12),
+    (0x0CF0, 0x0CF0, 20),
+    (0x0CF1, 0x0CF2, 4),
+    (0x0CF3, 0x0CFF, 20),
+    (0x0D00, 0x0D01, 11),
+    (0x0D02, 0x0D0C, 4),
+    (0x0D0D, 0x0D0D, 20),
+    (0x0D0E, 0x0D10, 4),
+    (0x0D11, 0x0D11, 20),
+    (0x0D12, 0x0D3A, 4),
+    (0x0D3B, 0x0D3C, 11),
+    (0x0D3D, 0x0D40, 4),
+    (0x0D41, 0x0D44, 11),
+    (0x0D45, 0x0D45, 20),
+    (0x0D46, 0x0D48, 4),
+    (0x0D49, 0x0D49, 20),
+    (0x0D4A, 0x0D4C, 4),
+    (0x0D4D, 0x0D4D, 11),
+    (0x0D4E, 0x0D4E, 4),
+    (0x0D4F, 0x0D53, 20),
+    (0x0D54, 0x0D57, 4),
+    (0x0D58, 0x0D5E, 12),
+    (0x0D5F, 0x0D61, 4),
+    (0x0D62, 0x0D63, 11),
+    (0x0D64, 0x0D65, 20),
+    (0x0D66, 0x0D78, 12),
+    (0x0D79, 0x0D79, 20),
+    (0x0D7A, 0x0D7F, 4),
+    (0x0D80, 0x0D80, 20),
+    (0x0D81, 0x0D81, 11),
+    (0x0D82, 0x0D83, 4),
+    (0x0D84, 0x0D84, 20),
+    (0x0D85, 0x0D96, 4),
+    (0x0D97, 0x0D99, 20),
+    (0x0D9A, 0x0DB1, 4),
+    (0x0DB2, 0x0DB2, 20),
+    (0x0DB3, 0x0DBB, 4),
+    (0x0DBC, 0x0DBC, 20),
+    (0x0DBD, 0x0DBD, 4),
+    (0x0DBE, 0x0DBF, 20),
+    (0x0DC0, 0x0DC6, 4),
+    (0x0DC7, 0x0DC9, 20),
+    (0x0DCA, 0x0DCA, 11),
+    (0x0DCB, 0x0DCE, 20),
+    (0x0DCF, 0x0DD1, 4),
+    (0x0DD2, 0x0DD4, 11),
+    (0x0DD5, 0x0DD5, 20),
+    (0x0DD6, 0x0DD6, 11),
+    (0x0DD7, 0x0DD7, 20),
+    (0x0DD8, 0x0DDF, 4),
+    (0x0DE0, 0x0DE5, 20),
+    (0x0DE6, 0x0DEF, 12),
+    (0x0DF0, 0x0DF1, 20),
+    (0x0DF2, 0x0DF3, 4),
+    (0x0DF4, 0x0E00, 20),
+    (0x0E01, 0x0E30, 4),
+    (0x0E31, 0x0E31, 11),
+    (0x0E32, 0x0E33, 4),
+    (0x0E34, 0x0E3A, 11),
+    (0x0E3B, 0x0E3E, 20),
+    (0x0E3F, 0x0E3F, 9),
+    (0x0E40, 0x0E46, 4),
+    (0x0E47, 0x0E4E, 11),
+    (0x0E4F, 0x0E4F, 20),
+    (0x0E50, 0x0E59, 12),
+    (0x0E5A, 0x0E80, 20),
+    (0x0E81, 0x0E82, 4),
+    (0x0E83, 0x0E83, 20),
+    (0x0E84, 0x0E84, 4),
+    (0x0E85, 0x0E85, 20),
+    (0x0E86, 0x0E8A, 4),
+    (0x0E8B, 0x0E8B, 20),
+    (0x0E8C, 0x0EA3, 4),
+    (0x0EA4, 0x0EA4, 20),
+    (0x0EA5, 0x0EA5, 4),
+    (0x0EA6, 0x0EA6, 20),
+    (0x0EA7, 0x0EB0, 4),
+    (0x0EB1, 0x0EB1, 11),
+    (0x0EB2, 0x0EB3, 4),
+    (0x0EB4, 0x0EBC, 11),
+    (0x0EBD, 0x0EBD, 4),
+    (0x0EBE, 0x0EBF, 20),
+    (0x0EC0, 0x0EC4, 4),
+    (0x0EC5, 0x0EC5, 20),
+    (0x0EC6, 0x0EC6, 4),
+    (0x0EC7, 0x0EC7, 20),
+    (0x0EC8, 0x0ECD, 11),
+    (0x0ECE, 0x0ECF, 20),
+    (0x0ED0, 0x0ED9, 12),
+    (0x0EDA, 0x0EDB, 20),
+    (0x0EDC, 0x0EDF, 4),
+    (0x0EE0, 0x0EFF, 20),
+    (0x0F00, 0x0F00, 4),
+    (0x0F01, 0x0F17, 20),
+    (0x0F18, 0x0F19, 11),
+    (0x0F1A, 0x0F1F, 20),
+    (0x0F20, 0x0F33, 12),
+    (0x0F34, 0x0F34, 20),
+    (0x0F35, 0x0F35, 11),
+    (0x0F36, 0x0F36, 20),
+    (0x0F37, 0x0F37, 11),
+    (0x0F38, 0x0F38, 20),
+    (0x0F39, 0x0F39, 11),
+    (0x0F3A, 0x0F3D, 20),
+    (0x0F3E, 0x0F47, 4),
+    (0x0F48, 0x0F48, 20),
+    (0x0F49, 0x0F6C, 4),
+    (0x0F6D, 0x0F70, 20),
+    (0x0F71, 0x0F7E, 11),
+    (0x0F7F, 0x0F7F, 4),
+    (0x0F80, 0x0F84, 11),
+    (0x0F85, 0x0F85, 20),
+    (0x0F86, 0x0F87, 11),
+    (0x0F88, 0x0F8C, 4),
+    (0x0F8D, 0x0F97, 11),
+    (0x0F98, 0x0F98, 20),
+    (0x0F99, 0x0FBC, 11),
+    (0x0FBD, 0x0FC5, 20),
+    (0x0FC6, 0x0FC6, 11),
+    (0x0FC7, 0x0FFF, 20),
+    (0x1000, 0x102C, 4),
+    (0x102D, 0x1030, 11),
+    (0x1031, 0x1031, 4),
+    (0x1032, 0x1037, 11),
+    (0x1038, 0x1038, 4),
+    (0x1039, 0x103A, 11),
+    (0x103B, 0x103C, 4),
+    (0x103D, 0x103E, 11),
+    (0x103F, 0x103F, 4),
+    (0x1040, 0x1049, 12),
+    (0x104A, 0x104F, 20),
+    (0x1050, 0x1057, 4),
+    (0x1058, 0x1059, 11),
+    (0x105A, 0x105D, 4),
+    (0x105E, 0x1060, 11),
+    (0x1061, 0x1070, 4),
+    (0x1071, 0x1074, 11),
+    (0x1075, 0x1081, 4),
+    (0x1082, 0x1082, 11),
+    (0x1083, 0x1084, 4),
+    (0x1085, 0x1086, 11),
+    (0x1087, 0x108C, 4),
+    (0x108D, 0x108D, 11),
+    (0x108E, 0x108F, 4),
+    (0x1090, 0x1099, 12),
+    (0x109A, 0x109C, 4),
+    (0x109D, 0x109D, 11),
+    (0x109E, 0x109F, 20),
+    (0x10A0, 0x10C5, 4),
+    (0x10C6, 0x10C6, 20),
+    (0x10C7, 0x10C7, 4),
+    (0x10C8, 0x10CC, 20),
+    (0x10CD, 0x10CD, 4),
+    (0x10CE, 0x10CF, 20),
+    (0x10D0, 0x10FA, 4),
+    (0x10FB, 0x10FB, 20),
+    (0x10FC, 0x1248, 4),
+    (0x1249, 0x1249, 20),
+    (0x124A, 0x124D, 4),
+    (0x124E, 0x124F, 20),
+    (0x1250, 0x1256, 4),
+    (0x1257, 0x1257, 20),
+    (0x1258, 0x1258, 4),
+    (0x1259, 0x1259, 20),
+    (0x125A, 0x125D, 4),
+    (0x125E, 0x125F, 20),
+    (0x1260, 0x1288, 4),
+    (0x1289, 0x1289, 20),
+    (0x128A, 0x128D, 4),
+    (0x128E, 0x128F, 20),
+    (0x1290, 0x12B0, 4),
+    (0x12B1, 0x12B1, 20),
+    (0x12B2, 0x12B5, 4),
+    (0x12B6, 0x12B7, 20),
+    (0x12B8, 0x12BE, 4),
+    (0x12BF, 0x12BF, 20),
+    (0x12C0, 0x12C0, 4),
+    (0x12C1, 0x12C1, 20),
+    (0x12C2, 0x12C5, 4),
+    (0x12C6, 0x12C7, 20),
+    (0x12C8, 0x12D6, 4),
+    (0x12D7, 0x12D7, 20),
+    (0x12D8, 0x1310, 4),
+    (0x1311, 0x1311, 20),
+    (0x1312, 0x1315, 4),
+    (0x1316, 0x1317, 20),
+    (0x1318, 0x135A, 4),
+    (0x135B, 0x135C, 20),
+    (0x135D, 0x135F, 11),
+    (0x1360, 0x1368, 20),
+    (0x1369, 0x137C, 12),
+    (0x137D, 0x137F, 20),
+    (0x1380, 0x138F, 4),
+    (0x1390, 0x139F, 20),
+    (0x13A0, 0x13F5, 4),
+    (0x13F6, 0x13F7, 20),
+    (0x13F8, 0x13FD, 4),
+    (0x13FE, 0x13FF, 20),
+    (0x1400, 0x1400, 21),
+    (0x1401, 0x166C, 4),
+    (0x166D, 0x166E, 20),
+    (0x166F, 0x167F, 4),
+    (0x1680, 0x1680, 13),
+    (0x1681, 0x169A, 4),
+    (0x169B, 0x169F, 20),
+    (0x16A0, 0x16EA, 4),
+    (0x16EB, 0x16ED, 20),
+    (0x16EE, 0x16F0, 12),
+    (0x16F1, 0x16F8, 4),
+    (0x16F9, 0x16FF, 20),
+    (0x1700, 0x170C, 4),
+    (0x170D, 0x170D, 20),
+    (0x170E, 0x1711, 4),
+    (0x1712, 0x1714, 11),
+    (0x1715, 0x171F, 20),
+    (0x1720, 0x1731, 4),
+    (0x1732, 0x1734, 11),
+    (0x1735, 0x173F, 20),
+    (0x1740, 0x1751, 4),
+    (0x1752, 0x1753, 11),
+    (0x1754, 0x175F, 20),
+    (0x1760, 0x176C, 4),
+    (0x176D, 0x176D, 20),
+    (0x176E, 0x1770, 4),
+    (0x1771, 0x1771, 20),
+    (0x1772, 0x1773, 11),
+    (0x1774, 0x177F, 20),
+    (0x1780, 0x17B3, 4),
+    (0x17B4, 0x17B5, 11),
+    (0x17B6, 0x17B6, 4),
+    (0x17B7, 0x17BD, 11),
+    (0x17BE, 0x17C5, 4),
+    (0x17C6, 0x17C6, 11),
+    (0x17C7, 0x17C8, 4),
+    (0x17C9, 0x17D3, 11),
+    (0x17D4, 0x17D6, 20),
+    (0x17D7, 0x17D7, 4),
+    (0x17D8, 0x17DA, 20),
+    (0x17DB, 0x17DB, 9),
+    (0x17DC, 0x17DC, 4),
+    (0x17DD, 0x17DD, 11),
+    (0x17DE, 0x17DF, 20),
+    (0x17E0, 0x17E9, 12),
+    (0x17EA, 0x17EF, 20),
+    (0x17F0, 0x17F9, 12),
+    (0x17FA, 0x1805, 20),
+    (0x1806, 0x1806, 21),
+    (0x1807, 0x180A, 20),
+    (0x180B, 0x180D, 11),
+    (0x180E, 0x180E, -1),
+    (0x180F, 0x180F, 20),
+    (0x1810, 0x1819, 12),
+    (0x181A, 0x181F, 20),
+    (0x1820, 0x1878, 4),
+    (0x1879, 0x187F, 20),
+    (0x1880, 0x1884, 4),
+    (0x1885, 0x1886, 11),
+    (0x1887, 0x18A8, 4),
+    (0x18A9, 0x18A9, 11),
+    (0x18AA, 0x18AA, 4),
+    (0x18AB, 0x18AF, 20),
+    (0x18B0, 0x18F5, 4),
+    (0x18F6, 0x18FF, 20),
+    (0x1900, 0x191E, 4),
+    (0x191F, 0x191F, 20),
+    (0x1920, 0x1922, 11),
+    (0x1923, 0x1926, 4),
+    (0x1927, 0x1928, 11),
+    (0x1929, 0x192B, 4),
+    (0x192C, 0x192F, 20),
+    (0x1930, 0x1931, 4),
+    (0x1932, 0x1932, 11),
+    (0x1933, 0x1938, 4),
+    (0x1939, 0x193B, 11),
+    (0x193C, 0x1945, 20),
+    (0x1946, 0x194F, 12),
+    (0x1950, 0x196D, 4),
+    (0x196E, 0x196F, 20),
+    (0x1970, 0x1974, 4),
+    (0x1975, 0x197F, 20),
+    (0x1980, 0x19AB, 4),
+    (0x19AC, 0x19AF, 20),
+    (0x19B0, 0x19C9, 4),
+    (0x19CA, 0x19CF, 20),
+    (0x19D0, 0x19DA, 12),
+    (0x19DB, 0x19FF, 20),
+    (0x1A00, 0x1A16, 4),
+    (0x1A17, 0x1A18, 11),
+    (0x1A19, 0x1A1A, 4),
+    (0x1A1B, 0x1A1B, 11),
+    (0x1A1C, 0x1A1F, 20),
+    (0x1A20, 0x1A55, 4),
+    (0x1A56, 0x1A56, 11),
+    (0x1A57, 0x1A57, 4),
+    (0x1A58, 0x1A5E, 11),
+    (0x1A5F, 0x1A5F, 20),
+    (0x1A60, 0x1A60, 11),
+    (0x1A61, 0x1A61, 4),
+    (0x1A62, 0x1A62, 11),
+    (0x1A63, 0x1A64, 4),
+    (0x1A65, 0x1A6C, 11),
+    (0x1A6D, 0x1A72, 4),
+    (0x1A73, 0x1A7C, 11),
+    (0x1A7D, 0x1A7E, 20),
+    (0x1A7F, 0x1A7F, 11),
+    (0x1A80, 0x1A89, 12),
+    (0x1A8A, 0x1A8F, 20),
+    (0x1A90, 0x1A99, 12),
+    (0x1A9A, 0x1AA6, 20),
+    (0x1AA7, 0x1AA7, 4),
+    (0x1AA8, 0x1AAF, 20),
+    (0x1AB0, 0x1AC0, 11),
+    (0x1AC1, 0x1AFF, 20),
+    (0x1B00, 0x1B03, 11),
+    (0x1B04, 0x1B33, 4),
+    (0x1B34, 0x1B34, 11),
+    (0x1B35, 0x1B35, 4),
+    (0x1B36, 0x1B3A, 11),
+    (0x1B3B, 0x1B3B, 4),
+    (0x1B3C, 0x1B3C, 11),
+    (0x1B3D, 0x1B41, 4),
+    (0x1B42, 0x1B42, 11),
+    (0x1B43, 0x1B4B, 4),
+    (0x1B4C, 0x1B4F, 20),
+    (0x1B50, 0x1B59, 12),
+    (0x1B5A, 0x1B6A, 20),
+    (0x1B6B, 0x1B73, 11),
+    (0x1B74, 0x1B7F, 20),
+    (0x1B80, 0x1B81, 11),
+    (0x1B82, 0x1BA1, 4),
+    (0x1BA2, 0x1BA5, 11),
+    (0x1BA6, 0x1BA7, 4),
+    (0x1BA8, 0x1BA9, 11),
+    (0x1BAA, 0x1BAA, 4),
+    (0x1BAB, 0x1BAD, 11),
+    (0x1BAE, 0x1BAF, 4),
+    (0x1BB0, 0x1BB9, 12),
+    (0x1BBA, 0x1BE5, 4),
+    (0x1BE6, 0x1BE6, 11),
+    (0x1BE7, 0x1BE7, 4),
+    (0x1BE8, 0x1BE9, 11),
+    (0x1BEA, 0x1BEC, 4),
+    (0x1BED, 0x1BED, 11),
+    (0x1BEE, 0x1BEE, 4),
+    (0x1BEF, 0x1BF1, 11),
+    (0x1BF2, 0x1BF3, 4),
+    (0x1BF4, 0x1BFF, 20),
+    (0x1C00, 0x1C2B, 4),
+    (0x1C2C, 0x1C33, 11),
+    (0x1C34, 0x1C35, 4),
+    (0x1C36, 0x1C37, 11),
+    (0x1C38, 0x1C3F, 20),
+    (0x1C40, 0x1C49, 12),
+    (0x1C4A, 0x1C4C, 20),
+    (0x1C4D, 0x1C4F, 4),
+    (0x1C50, 0x1C59, 12),
+    (0x1C5A, 0x1C7D, 4),
+    (0x1C7E, 0x1C7F, 20),
+    (0x1C80, 0x1C88, 4),
+    (0x1C89, 0x1C8F, 20),
+    (0x1C90, 0x1CBA, 4),
+    (0x1CBB, 0x1CBC, 20),
+    (0x1CBD, 0x1CBF, 4),
+    (0x1CC0, 0x1CCF, 20),
+    (0x1CD0, 0x1CD2, 11),
+    (0x1CD3, 0x1CD3, 20),
+    (0x1CD4, 0x1CE0, 11),
+    (0x1CE1, 0x1CE1, 4),
+    (0x1CE2, 0x1CE8, 11),
+    (0x1CE9, 0x1CEC, 4),
+    (0x1CED, 0x1CED, 11),
+    (0x1CEE, 0x1CF3, 4),
+    (0x1CF4, 0x1CF4, 11),
+    (0x1CF5, 0x1CF7, 4),
+    (0x1CF8, 0x1CF9, 11),
+    (0x1CFA, 0x1CFA, 4),
+    (0x1CFB, 0x1CFF, 20),
+    (0x1D00, 0x1DBF, 4),
+    (0x1DC0, 0x1DF9, 11),
+    (0x1DFA, 0x1DFA, 20),
+    (0x1DFB, 0x1DFF, 11),
+    (0x1E00, 0x1F15, 4),
+    (0x1F16, 0x1F17, 20),
+    (0x1F18, 0x1F1D, 4),
+    (0x1F1E, 0x1F1F, 20),
+    (0x1F20, 0x1F45, 4),
+    (0x1F46, 0x1F47, 20),
+    (0x1F48, 0x1F4D, 4),
+    (0x1F4E, 0x1F4F, 20),
+    (0x1F50, 0x1F57, 4),
+    (0x1F58, 0x1F58, 20),
+    (0x1F59, 0x1F59, 4),
+    (0x1F5A, 0x1F5A, 20),
+    (0x1F5B, 0x1F5B, 4),
+    (0x1F5C, 0x1F5C, 20),
+    (0x1F5D, 0x1F5D, 4),
+    (0x1F5E, 0x1F5E, 20),
+    (0x1F5F, 0x1F7D, 4),
+    (0x1F7E, 0x1F7F, 20),
+    (0x1F80, 0x1FB4, 4),
+    (0x1FB5, 0x1FB5, 20),
+    (0x1FB6, 0x1FBC, 4),
+    (0x1FBD, 0x1FBD, 20),
+    (0x1FBE, 0x1FBE, 4),
+    (0x1FBF, 0x1FC1, 20),
+    (0x1FC2, 0x1FC4, 4),
+    (0x1FC5, 0x1FC5, 20),
+    (0x1FC6, 0x1FCC, 4),
+    (0x1FCD, 0x1FCF, 20),
+    (0x1FD0, 0x1FD3, 4),
+    (0x1FD4, 0x1FD5, 20),
+    (0x1FD6, 0x1FDB, 4),
+    (0x1FDC, 0x1FDF, 20),
+    (0x1FE0, 0x1FEC, 4),
+    (0x1FED, 0x1FF1, 20),
+    (0x1FF2, 0x1FF4, 4),
+    (0x1FF5, 0x1FF5, 20),
+    (0x1FF6, 0x1FFC, 4),
+    (0x1FFD, 0x1FFF, 20),
+    (0x2000, 0x200A, 13),
+    (0x200B, 0x200F, -1),
+    (0x2010, 0x2015, 21),
+    (0x2016, 0x2026, 20),
+    (0x2027, 0x2027, 21),
+    (0x2028, 0x2029, 16),
+    (0x202A, 0x202E, -1),
+    (0x202F, 0x202F, 13),
+    (0x2030, 0x2031, 6),
+    (0x2032, 0x203E, 20),
+    (0x203F, 0x2040, 21),
+    (0x2041, 0x2053, 20),
+    (0x2054, 0x2054, 21),
+    (0x2055, 0x205E, 20),
+    (0x205F, 0x205F, 13),
+    (0x2060, 0x2064, -1),
+    (0x2065, 0x2065, 20),
+    (0x2066, 0x206F, -1),
+    (0x2070, 0x2070, 12),
+    (0x2071, 0x2071, 4),
+    (0x2072, 0x2073, 20),
+    (0x2074, 0x2079, 12),
+    (0x207A, 0x207E, 20),
+    (0x207F, 0x207F, 4),
+    (0x2080, 0x2089, 12),
+    (0x208A, 0x208F, 20),
+    (0x2090, 0x209C, 4),
+    (0x209D, 0x209F, 20),
+    (0x20A0, 0x20BF, 9),
+    (0x20C0, 0x20CF, 20),
+    (0x20D0, 0x20F0, 11),
+    (0x20F1, 0x2101, 20),
+    (0x2102, 0x2102, 4),
+    (0x2103, 0x2106, 20),
+    (0x2107, 0x2107, 4),
+    (0x2108, 0x2109, 20),
+    (0x210A, 0x2113, 4),
+    (0x2114, 0x2114, 20),
+    (0x2115, 0x2115, 4),
+    (0x2116, 0x2118, 20),
+    (0x2119, 0x211D, 4),
+    (0x211E, 0x2123, 20),
+    (0x2124, 0x2124, 4),
+    (0x2125, 0x2125, 20),
+    (0x2126, 0x2126, 4),
+    (0x2127, 0x2127, 20),
+    (0x2128, 0x2128, 4),
+    (0x2129, 0x2129, 20),
+    (0x212A, 0x212D, 4),
+    (0x212E, 0x212E, 20),
+    (0x212F, 0x2139, 4),
+    (0x213A, 0x213B, 20),
+    (0x213C, 0x213F, 4),
+    (0x2140, 0x2144, 20),
+    (0x2145, 0x2149, 4),
+    (0x214A, 0x214D, 20),
+    (0x214E, 0x214E, 4),
+    (0x214F, 0x214F, 20),
+    (0x2150, 0x2182, 12),
+    (0x2183, 0x2184, 4),
+    (0x2185, 0x2189, 12),
+    (0x218A, 0x245F, 20),
+    (0x2460, 0x249B, 12),
+    (0x249C, 0x24E9, 20),
+    (0x24EA, 0x24FF, 12),
+    (0x2500, 0x2775, 20),
+    (0x2776, 0x2793, 12),
+    (0x2794, 0x2BFF, 20),
+    (0x2C00, 0x2C2E, 4),
+    (0x2C2F, 0x2C2F, 20),
+    (0x2C30, 0x2C5E, 4),
+    (0x2C5F, 0x2C5F, 20),
+    (0x2C60, 0x2CE4, 4),
+    (0x2CE5, 0x2CEA, 20),
+    (0x2CEB, 0x2CEE, 4),
+    (0x2CEF, 0x2CF1, 11),
+    (0x2CF2, 0x2CF3, 4),
+    (0x2CF4, 0x2CFC, 20),
+    (0x2CFD, 0x2CFD, 12),
+    (0x2CFE, 0x2CFF, 20),
+    (0x2D00, 0x2D25, 4),
+    (0x2D26, 0x2D26, 20),
+    (0x2D27, 0x2D27, 4),
+    (0x2D28, 0x2D2C, 20),
+    (0x2D2D, 0x2D2D, 4),
+    (0x2D2E, 0x2D2F, 20),
+    (0x2D30, 0x2D67, 4),
+    (0x2D68, 0x2D6E, 20),
+    (0x2D6F, 0x2D6F, 4),
+    (0x2D70, 0x2D7E, 20),
+    (0x2D7F, 0x2D7F, 11),
+    (0x2D80, 0x2D96, 4),
+    (0x2D97, 0x2D9F, 20),
+    (0x2DA0, 0x2DA6, 4),
+    (0x2DA7, 0x2DA7, 20),
+    (0x2DA8, 0x2DAE, 4),
+    (0x2DAF, 0x2DAF, 20),
+    (0x2DB0, 0x2DB6, 4),
+    (0x2DB7, 0x2DB7, 20),
+    (0x2DB8, 0x2DBE, 4),
+    (0x2DBF, 0x2DBF, 20),
+    (0x2DC0, 0x2DC6, 4),
+    (0x2DC7, 0x2DC7, 20),
+    (0x2DC8, 0x2DCE, 4),
+    (0x2DCF, 0x2DCF, 20),
+    (0x2DD0, 0x2DD6, 4),
+    (0x2DD7, 0x2DD7, 20),
+    (0x2DD8, 0x2DDE, 4),
+    (0x2DDF, 0x2DDF, 20),
+    (0x2DE0, 0x2DFF, 11),
+    (0x2E00, 0x2E16, 20),
+    (0x2E17, 0x2E17, 21),
+    (0x2E18, 0x2E19, 20),
+    (0x2E1A, 0x2E1A, 21),
+    (0x2E1B, 0x2E2E, 20),
+    (0x2E2F, 0x2E2F, 4),
+    (0x2E30, 0x2E39, 20),
+    (0x2E3A, 0x2E3B, 21),
+    (0x2E3C, 0x2E3F, 20),
+    (0x2E40, 0x2E40, 21),
+    (0x2E41, 0x2FFF, 20),
+    (0x3000, 0x3000, 13),
+    (0x3001, 0x3004, 20),
+    (0x3005, 0x3005, 3),
+    (0x3006, 0x3006, 4),
+    (0x3007, 0x3007, 12),
+    (0x3008, 0x301B, 20),
+    (0x301C, 0x301C, 21),
+    (0x301D, 0x3020, 20),
+    (0x3021, 0x3029, 12),
+    (0x302A, 0x302D, 11),
+    (0x302E, 0x302F, 4),
+    (0x3030, 0x3030, 21),
+    (0x3031, 0x3035, 4),
+    (0x3036, 0x3037, 20),
+    (0x3038, 0x303A, 12),
+    (0x303B, 0x303C, 4),
+    (0x303D, 0x3040, 20),
+    (0x3041, 0x3094, 14),
+    (0x3095, 0x3096, 4),
+    (0x3097, 0x3098, 20),
+    (0x3099, 0x309A, 1),
+    (0x309B, 0x309C, 10),
+    (0x309D, 0x309E, 14),
+    (0x309F, 0x309F, 4),
+    (0x30A0, 0x30A0, 21),
+    (0x30A1, 0x30FA, 15),
+    (0x30FB, 0x30FC, 10),
+    (0x30FD, 0x30FE, 15),
+    (0x30FF, 0x30FF, 4),
+    (0x3100, 0x3104, 20),
+    (0x3105, 0x312F, 4),
+    (0x3130, 0x3130, 20),
+    (0x3131, 0x318E, 4),
+    (0x318F, 0x3191, 20),
+    (0x3192, 0x3195, 12),
+    (0x3196, 0x319F, 20),
+    (0x31A0, 0x31BF, 4),
+    (0x31C0, 0x31EF, 20),
+    (0x31F0, 0x31FF, 4),
+    (0x3200, 0x321F, 20),
+    (0x3220, 0x3229, 12),
+    (0x322A, 0x3247, 20),
+    (0x3248, 0x324F, 12),
+    (0x3250, 0x3250, 20),
+    (0x3251, 0x325F, 12),
+    (0x3260, 0x327F, 20),
+    (0x3280, 0x3289, 12),
+    (0x328A, 0x32B0, 20),
+    (0x32B1, 0x32BF, 12),
+    (0x32C0, 0x33FF, 20),
+    (0x3400, 0x4DBF, 4),
+    (0x4DC0, 0x4DFF, 20),
+    (0x4E00, 0x9FA5, 3),
+    (0x9FA6, 0x9FFC, 4),
+    (0x9FFD, 0x9FFF, 20),
+    (0xA000, 0xA48C, 4),
+    (0xA48D, 0xA4CF, 20),
+    (0xA4D0, 0xA4FD, 4),
+    (0xA4FE, 0xA4FF, 20),
+    (0xA500, 0xA60C, 4),
+    (0xA60D, 0xA60F, 20),
+    (0xA610, 0xA61F, 4),
+    (0xA620, 0xA629, 12),
+    (0xA62A, 0xA62B, 4),
+    (0xA62C, 0xA63F, 20),
+    (0xA640, 0xA66E, 4),
+    (0xA66F, 0xA672, 11),
+    (0xA673, 0xA673, 20),
+    (0xA674, 0xA67D, 11),
+    (0xA67E, 0xA67E, 20),
+    (0xA67F, 0xA69D, 4),
+    (0xA69E, 0xA69F, 11),
+    (0xA6A0, 0xA6E5, 4),
+    (0xA6E6, 0xA6EF, 12),
+    (0xA6F0, 0xA6F1, 11),
+    (0xA6F2, 0xA716, 20),
+    (0xA717, 0xA71F, 4),
+    (0xA720, 0xA721, 20),
+    (0xA722, 0xA788, 4),
+    (0xA789, 0xA78A, 20),
+    (0xA78B, 0xA7BF, 4),
+    (0xA7C0, 0xA7C1, 20),
+    (0xA7C2, 0xA7CA, 4),
+    (0xA7CB, 0xA7F4, 20),
+    (0xA7F5, 0xA801, 4),
+    (0xA802, 0xA802, 11),
+    (0xA803, 0xA805, 4),
+    (0xA806, 0xA806, 11),
+    (0xA807, 0xA80A, 4),
+    (0xA80B, 0xA80B, 11),
+    (0xA80C, 0xA824, 4),
+    (0xA825, 0xA826, 11),
+    (0xA827, 0xA827, 4),
+    (0xA828, 0xA82B, 20),
+    (0xA82C, 0xA82C, 11),
+    (0xA82D, 0xA82F, 20),
+    (0xA830, 0xA835, 12),
+    (0xA836, 0xA837, 20),
+    (0xA838, 0xA838, 9),
+    (0xA839, 0xA83F, 20),
+    (0xA840, 0xA873, 4),
+    (0xA874, 0xA87F, 20),
+    (0xA880, 0xA8C3, 4),
+    (0xA8C4, 0xA8C5, 11),
+    (0xA8C6, 0xA8CF, 20),
+    (0xA8D0, 0xA8D9, 12),
+    (0xA8DA, 0xA8DF, 20),
+    (0xA8E0, 0xA8F1, 11),
+    (0xA8F2, 0xA8F7, 4),
+    (0xA8F8, 0xA8FA, 20),
+    (0xA8FB, 0xA8FB, 4),
+    (0xA8FC, 0xA8FC, 20),
+    (0xA8FD, 0xA8FE, 4),
+    (0xA8FF, 0xA8FF, 11),
+    (0xA900, 0xA909, 12),
+    (0xA90A, 0xA925, 4),
+    (0xA926, 0xA92D, 11),
+    (0xA92E, 0xA92F, 20),
+    (0xA930, 0xA946, 4),
+    (0xA947, 0xA951, 11),
+    (0xA952, 0xA953, 4),
+    (0xA954, 0xA95F, 20),
+    (0xA960, 0xA97C, 4),
+    (0xA97D, 0xA97F, 20),
+    (0xA980, 0xA982, 11),
+    (0xA983, 0xA9B2, 4),
+    (0xA9B3, 0xA9B3, 11),
+    (0xA9B4, 0xA9B5, 4),
+    (0xA9B6, 0xA9B9, 11),
+    (0xA9BA, 0xA9BB, 4),
+    (0xA9BC, 0xA9BD, 11),
+    (0xA9BE, 0xA9C0, 4),
+    (0xA9C1, 0xA9CE, 20),
+    (0xA9CF, 0xA9CF, 4),
+    (0xA9D0, 0xA9D9, 12),
+    (0xA9DA, 0xA9DF, 20),
+    (0xA9E0, 0xA9E4, 4),
+    (0xA9E5, 0xA9E5, 11),
+    (0xA9E6, 0xA9EF, 4),
+    (0xA9F0, 0xA9F9, 12),
+    (0xA9FA, 0xA9FE, 4),
+    (0xA9FF, 0xA9FF, 20),
+    (0xAA00, 0xAA28, 4),
+    (0xAA29, 0xAA2E, 11),
+    (0xAA2F, 0xAA30, 4),
+    (0xAA31, 0xAA32, 11),
+    (0xAA33, 0xAA34, 4),
+    (0xAA35, 0xAA36, 11),
+    (0xAA37, 0xAA3F, 20),
+    (0xAA40, 0xAA42, 4),
+    (0xAA43, 0xAA43, 11),
+    (0xAA44, 0xAA4B, 4),
+    (0xAA4C, 0xAA4C, 11),
+    (0xAA4D, 0xAA4D, 4),
+    (0xAA4E, 0xAA4F, 20),
+    (0xAA50, 0xAA59, 12),
+    (0xAA5A, 0xAA5F, 20),
+    (0xAA60, 0xAA76, 4),
+    (0xAA77, 0xAA79, 20),
+    (0xAA7A, 0xAA7B, 4),
+    (0xAA7C, 0xAA7C, 11),
+    (0xAA7D, 0xAAAF, 4),
+    (0xAAB0, 0xAAB0, 11),
+    (0xAAB1, 0xAAB1, 4),
+    (0xAAB2, 0xAAB4, 11),
+    (0xAAB5, 0xAAB6, 4),
+    (0xAAB7, 0xAAB8, 11),
+    (0xAAB9, 0xAABD, 4),
+    (0xAABE, 0xAABF, 11),
+    (0xAAC0, 0xAAC0, 4),
+    (0xAAC1, 0xAAC1, 11),
+    (0xAAC2, 0xAAC2, 4),
+    (0xAAC3, 0xAADA, 20),
+    (0xAADB, 0xAADD, 4),
+    (0xAADE, 0xAADF, 20),
+    (0xAAE0, 0xAAEB, 4),
+    (0xAAEC, 0xAAED, 11),
+    (0xAAEE, 0xAAEF, 4),
+    (0xAAF0, 0xAAF1, 20),
+    (0xAAF2, 0xAAF5, 4),
+    (0xAAF6, 0xAAF6, 11),
+    (0xAAF7, 0xAB00, 20),
+    (0xAB01, 0xAB06, 4),
+    (0xAB07, 0xAB08, 20),
+    (0xAB09, 0xAB0E, 4),
+    (0xAB0F, 0xAB10, 20),
+    (0xAB11, 0xAB16, 4),
+    (0xAB17, 0xAB1F, 20),
+    (0xAB20, 0xAB26, 4),
+    (0xAB27, 0xAB27, 20),
+    (0xAB28, 0xAB2E, 4),
+    (0xAB2F, 0xAB2F, 20),
+    (0xAB30, 0xAB5A, 4),
+    (0xAB5B, 0xAB5B, 20),
+    (0xAB5C, 0xAB69, 4),
+    (0xAB6A, 0xAB6F, 20),
+    (0xAB70, 0xABE4, 4),
+    (0xABE5, 0xABE5, 11),
+    (0xABE6, 0xABE7, 4),
+    (0xABE8, 0xABE8, 11),
+    (0xABE9, 0xABEA, 4),
+    (0xABEB, 0xABEB, 20),
+    (0xABEC, 0xABEC, 4),
+    (0xABED, 0xABED, 11),
+    (0xABEE, 0xABEF, 20),
+    (0xABF0, 0xABF9, 12),
+    (0xABFA, 0xABFF, 20),
+    (0xAC00, 0xD7A3, 4),
+    (0xD7A4, 0xD7AF, 20),
+    (0xD7B0, 0xD7C6, 4),
+    (0xD7C7, 0xD7CA, 20),
+    (0xD7CB, 0xD7FB, 4),
+    (0xD7FC, 0xF8FF, 20),
+    (0xF900, 0xFA2D, 3),
+    (0xFA2E, 0xFA6D, 4),
+    (0xFA6E, 0xFA6F, 20),
+    (0xFA70, 0xFAD9, 4),
+    (0xFADA, 0xFAFF, 20),
+    (0xFB00, 0xFB06, 4),
+    (0xFB07, 0xFB12, 20),
+    (0xFB13, 0xFB17, 4),
+    (0xFB18, 0xFB1C, 20),
+    (0xFB1D, 0xFB1D, 4),
+    (0xFB1E, 0xFB1E, 11),
+    (0xFB1F, 0xFB28, 4),
+    (0xFB29, 0xFB29, 20),
+    (0xFB2A, 0xFB36, 4),
+    (0xFB37, 0xFB37, 20),
+    (0xFB38, 0xFB3C, 4),
+    (0xFB3D, 0xFB3D, 20),
+    (0xFB3E, 0xFB3E, 4),
+    (0xFB3F, 0xFB3F, 20),
+    (0xFB40, 0xFB41, 4),
+    (0xFB42, 0xFB42, 20),
+    (0xFB43, 0xFB44, 4),
+    (0xFB45, 0xFB45, 20),
+    (0xFB46, 0xFBB1, 4),
+    (0xFBB2, 0xFBD2, 20),
+    (0xFBD3, 0xFD3D, 4),
+    (0xFD3E, 0xFD4F, 20),
+    (0xFD50, 0xFD8F, 4),
+    (0xFD90, 0xFD91, 20),
+    (0xFD92, 0xFDC7, 4),
+    (0xFDC8, 0xFDEF, 20),
+    (0xFDF0, 0xFDFB, 4),
+    (0xFDFC, 0xFDFC, 9),
+    (0xFDFD, 0xFDFF, 20),
+    (0xFE00, 0xFE0F, 11),
+    (0xFE10, 0xFE1F, 20),
+    (0xFE20, 0xFE2F, 11),
+    (0xFE30, 0xFE30, 20),
+    (0xFE31, 0xFE34, 21),
+    (0xFE35, 0xFE4C, 20),
+    (0xFE4D, 0xFE4F, 21),
+    (0xFE50, 0xFE57, 20),
+    (0xFE58, 0xFE58, 21),
+    (0xFE59, 0xFE62, 20),
+    (0xFE63, 0xFE63, 21),
+    (0xFE64, 0xFE68, 20),
+    (0xFE69, 0xFE69, 9),
+    (0xFE6A, 0xFE6F, 20),
+    (0xFE70, 0xFE74, 4),
+    (0xFE75, 0xFE75, 20),
+    (0xFE76, 0xFEFC, 4),
+    (0xFEFD, 0xFEFE, 20),
+    (0xFEFF, 0xFEFF, -1),
+    (0xFF00, 0xFF03, 20),
+    (0xFF04, 0xFF04, 9),
+    (0xFF05, 0xFF0C, 20),
+    (0xFF0D, 0xFF0D, 21),
+    (0xFF0E, 0xFF0F, 20),
+    (0xFF10, 0xFF19, 12),
+    (0xFF1A, 0xFF20, 20),
+    (0xFF21, 0xFF3A, 4),
+    (0xFF3B, 0xFF3E, 20),
+    (0xFF3F, 0xFF3F, 21),
+    (0xFF40, 0xFF40, 20),
+    (0xFF41, 0xFF5A, 4),
+    (0xFF5B, 0xFF65, 20),
+    (0xFF66, 0xFFBE, 4),
+    (0xFFBF, 0xFFC1, 20),
+    (0xFFC2, 0xFFC7, 4),
+    (0xFFC8, 0xFFC9, 20),
+    (0xFFCA, 0xFFCF, 4),
+    (0xFFD0, 0xFFD1, 20),
+    (0xFFD2, 0xFFD7, 4),
+    (0xFFD8, 0xFFD9, 20),
+    (0xFFDA, 0xFFDC, 4),
+    (0xFFDD, 0xFFDF, 20),
+    (0xFFE0, 0xFFE1, 9),
+    (0xFFE2, 0xFFE4, 20),
+    (0xFFE5, 0xFFE6, 9),
+    (0xFFE7, 0xFFF8, 20),
+    (0xFFF9, 0xFFFB, -1),
+    (0xFFFC, 0xFFFF, 20),
+    (0x10000, 0x1000B, 4),
+    (0x1000C, 0x1000C, 20),
+    (0x1000D, 0x10026, 4),
+    (0x10027, 0x10027, 20),
+    (0x10028, 0x1003A, 4),
+    (0x1003B, 0x1003B, 20),
+    (0x1003C, 0x1003D, 4),
+    (0x1003E, 0x1003E, 20),
+    (0x1003F, 0x1004D, 4),
+    (0x1004E, 0x1004F, 20),
+    (0x10050, 0x1005D, 4),
+    (0x1005E, 0x1007F, 20),
+    (0x10080, 0x100FA, 4),
+    (0x100FB, 0x10106, 20),
+    (0x10107, 0x10133, 12),
+    (0x10134, 0x1013F, 20),
+    (0x10140, 0x10178, 12),
+    (0x10179, 0x10189, 20),
+    (0x1018A, 0x1018B, 12),
+    (0x1018C, 0x101FC, 20),
+    (0x101FD, 0x101FD, 11),
+    (0x101FE, 0x1027F, 20),
+    (0x10280, 0x1029C, 4),
+    (0x1029D, 0x1029F, 20),
+    (0x102A0, 0x102D0, 4),
+    (0x102D1, 0x102DF, 20),
+    (0x102E0, 0x102E0, 11),
+    (0x102E1, 0x102FB, 12),
+    (0x102FC, 0x102FF, 20),
+    (0x10300, 0x1031F, 4),
+    (0x10320, 0x10323, 12),
+    (0x10324, 0x1032C, 20),
+    (0x1032D, 0x10340, 4),
+    (0x10341, 0x10341, 12),
+    (0x10342, 0x10349, 4),
+    (0x1034A, 0x1034A, 12),
+    (0x1034B, 0x1034F, 20),
+    (0x10350, 0x10375, 4),
+    (0x10376, 0x1037A, 11),
+    (0x1037B, 0x1037F, 20),
+    (0x10380, 0x1039D, 4),
+    (0x1039E, 0x1039F, 20),
+    (0x103A0, 0x103C3, 4),
+    (0x103C4, 0x103C7, 20),
+    (0x103C8, 0x103CF, 4),
+    (0x103D0, 0x103D0, 20),
+    (0x103D1, 0x103D5, 12),
+    (0x103D6, 0x103FF, 20),
+    (0x10400, 0x1049D, 4),
+    (0x1049E, 0x1049F, 20),
+    (0x104A0, 0x104A9, 12),
+    (0x104AA, 0x104AF, 20),
+    (0x104B0, 0x104D3, 4),
+    (0x104D4, 0x104D7, 20),
+    (0x104D8, 0x104FB, 4),
+    (0x104FC, 0x104FF, 20),
+    (0x10500, 0x10527, 4),
+    (0x10528, 0x1052F, 20),
+    (0x10530, 0x10563, 4),
+    (0x10564, 0x105FF, 20),
+    (0x10600, 0x10736, 4),
+    (0x10737, 0x1073F, 20),
+    (0x10740, 0x10755, 4),
+    (0x10756, 0x1075F, 20),
+    (0x10760, 0x10767, 4),
+    (0x10768, 0x107FF, 20),
+    (0x10800, 0x10805, 4),
+    (0x10806, 0x10807, 20),
+    (0x10808, 0x10808, 4),
+    (0x10809, 0x10809, 20),
+    (0x1080A, 0x10835, 4),
+    (0x10836, 0x10836, 20),
+    (0x10837, 0x10838, 4),
+    (0x10839, 0x1083B, 20),
+    (0x1083C, 0x1083C, 4),
+    (0x1083D, 0x1083E, 20),
+    (0x1083F, 0x10855, 4),
+    (0x10856, 0x10857, 20),
+    (0x10858, 0x1085F, 12),
+    (0x10860, 0x10876, 4),
+    (0x10877, 0x10878, 20),
+    (0x10879, 0x1087F, 12),
+    (0x10880, 0x1089E, 4),
+    (0x1089F, 0x108A6, 20),
+    (0x108A7, 0x108AF, 12),
+    (0x108B0, 0x108DF, 20),
+    (0x108E0, 0x108F2, 4),
+    (0x108F3, 0x108F3, 20),
+    (0x108F4, 0x108F5, 4),
+    (0x108F6, 0x108FA, 20),
+    (0x108FB, 0x108FF, 12),
+    (0x10900, 0x10915, 4),
+    (0x10916, 0x1091B, 12),
+    (0x1091C, 0x1091F, 20),
+    (0x10920, 0x10939, 4),
+    (0x1093A, 0x1097F, 20),
+    (0x10980, 0x109B7, 4),
+    (0x109B8, 0x109BB, 20),
+    (0x109BC, 0x109BD, 12),
+    (0x109BE, 0x109BF, 4),
+    (0x109C0, 0x109CF, 12),
+    (0x109D0, 0x109D1, 20),
+    (0x109D2, 0x109FF, 12),
+    (0x10A00, 0x10A00, 4),
+    (0x10A01, 0x10A03, 11),
+    (0x10A04, 0x10A04, 20),
+    (0x10A05, 0x10A06, 11),
+    (0x10A07, 0x10A0B, 20),
+    (0x10A0C, 0x10A0F, 11),
+    (0x10A10, 0x10A13, 4),
+    (0x10A14, 0x10A14, 20),
+    (0x10A15, 0x10A17, 4),
+    (0x10A18, 0x10A18, 20),
+    (0x10A19, 0x10A35, 4),
+    (0x10A36, 0x10A37, 20),
+    (0x10A38, 0x10A3A, 11),
+    (0x10A3B, 0x10A3E, 20),
+    (0x10A3F, 0x10A3F, 11),
+    (0x10A40, 0x10A48, 12),
+    (0x10A49, 0x10A5F, 20),
+    (0x10A60, 0x10A7C, 4),
+    (0x10A7D, 0x10A7E, 12),
+    (0x10A7F, 0x10A7F, 20),
+    (0x10A80, 0x10A9C, 4),
+    (0x10A9D, 0x10A9F, 12),
+    (0x10AA0, 0x10ABF, 20),
+    (0x10AC0, 0x10AC7, 4),
+    (0x10AC8, 0x10AC8, 20),
+    (0x10AC9, 0x10AE4, 4),
+    (0x10AE5, 0x10AE6, 11),
+    (0x10AE7, 0x10AEA, 20),
+    (0x10AEB, 0x10AEF, 12),
+    (0x10AF0, 0x10AFF, 20),
+    (0x10B00, 0x10B35, 4),
+    (0x10B36, 0x10B3F, 20),
+    (0x10B40, 0x10B55, 4),
+    (0x10B56, 0x10B57, 20),
+    (0x10B58, 0x10B5F, 12),
+    (0x10B60, 0x10B72, 4),
+    (0x10B73, 0x10B77, 20),
+    (0x10B78, 0x10B7F, 12),
+    (0x10B80, 0x10B91, 4),
+    (0x10B92, 0x10BA8, 20),
+    (0x10BA9, 0x10BAF, 12),
+    (0x10BB0, 0x10BFF, 20),
+    (0x10C00, 0x10C48, 4),
+    (0x10C49, 0x10C7F, 20),
+    (0x10C80, 0x10CB2, 4),
+    (0x10CB3, 0x10CBF, 20),
+    (0x10CC0, 0x10CF2, 4),
+    (0x10CF3, 0x10CF9, 20),
+    (0x10CFA, 0x10CFF, 12),
+    (0x10D00, 0x10D23, 4),
+    (0x10D24, 0x10D27, 11),
+    (0x10D28, 0x10D2F, 20),
+    (0x10D30, 0x10D39, 12),
+    (0x10D3A, 0x10E5F, 20),
+    (0x10E60, 0x10E7E, 12),
+    (0x10E7F, 0x10E7F, 20),
+    (0x10E80, 0x10EA9, 4),
+    (0x10EAA, 0x10EAA, 20),
+    (0x10EAB, 0x10EAC, 11),
+    (0x10EAD, 0x10EAD, 21),
+    (0x10EAE, 0x10EAF, 20),
+    (0x10EB0, 0x10EB1, 4),
+    (0x10EB2, 0x10EFF, 20),
+    (0x10F00, 0x10F1C, 4),
+    (0x10F1D, 0x10F26, 12),
+    (0x10F27, 0x10F27, 4),
+    (0x10F28, 0x10F2F, 20),
+    (0x10F30, 0x10F45, 4),
+    (0x10F46, 0x10F50, 11),
+    (0x10F51, 0x10F54, 12),
+    (0x10F55, 0x10FAF, 20),
+    (0x10FB0, 0x10FC4, 4),
+    (0x10FC5, 0x10FCB, 12),
+    (0x10FCC, 0x10FDF, 20),
+    (0x10FE0, 0x10FF6, 4),
+    (0x10FF7, 0x10FFF, 20),
+    (0x11000, 0x11000, 4),
+    (0x11001, 0x11001, 11),
+    (0x11002, 0x11037, 4),
+    (0x11038, 0x11046, 11),
+    (0x11047, 0x11051, 20),
+    (0x11052, 0x1106F, 12),
+    (0x11070, 0x1107E, 20),
+    (0x1107F, 0x11081, 11),
+    (0x11082, 0x110B2, 4),
+    (0x110B3, 0x110B6, 11),
+    (0x110B7, 0x110B8, 4),
+    (0x110B9, 0x110BA, 11),
+    (0x110BB, 0x110BC, 20),
+    (0x110BD, 0x110BD, 0),
+    (0x110BE, 0x110CC, 20),
+    (0x110CD, 0x110CD, 0),
+    (0x110CE, 0x110CF, 20),
+    (0x110D0, 0x110E8, 4),
+    (0x110E9, 0x110EF, 20),
+    (0x110F0, 0x110F9, 12),
+    (0x110FA, 0x110FF, 20),
+    (0x11100, 0x11102, 11),
+    (0x11103, 0x11126, 4),
+    (0x11127, 0x1112B, 11),
+    (0x1112C, 0x1112C, 4),
+    (0x1112D, 0x11134, 11),
+    (0x11135, 0x11135, 20),
+    (0x11136, 0x1113F, 12),
+    (0x11140, 0x11143, 20),
+    (0x11144, 0x11147, 4),
+    (0x11148, 0x1114F, 20),
+    (0x11150, 0x11172, 4),
+    (0x11173, 0x11173, 11),
+    (0x11174, 0x11175, 20),
+    (0x11176, 0x11176, 4),
+    (0x11177, 0x1117F, 20),
+    (0x11180, 0x11181, 11),
+    (0x11182, 0x111B5, 4),
+    (0x111B6, 0x111BE, 11),
+    (0x111BF, 0x111C4, 4),
+    (0x111C5, 0x111C8, 20),
+    (0x111C9, 0x111CC, 11),
+    (0x111CD, 0x111CD, 20),
+    (0x111CE, 0x111CE, 4),
+    (0x111CF, 0x111CF, 11),
+    (0x111D0, 0x111D9, 12),
+    (0x111DA, 0x111DA, 4),
+    (0x111DB, 0x111DB, 20),
+    (0x111DC, 0x111DC, 4),
+    (0x111DD, 0x111E0, 20),
+    (0x111E1, 0x111F4, 12),
+    (0x111F5, 0x111FF, 20),
+    (0x11200, 0x11211, 4),
+    (0x11212, 0x11212, 20),
+    (0x11213, 0x1122E, 4),
+    (0x1122F, 0x11231, 11),
+    (0x11232, 0x11233, 4),
+    (0x11234, 0x11234, 11),
+    (0x11235, 0x11235, 4),
+    (0x11236, 0x11237, 11),
+    (0x11238, 0x1123D, 20),
+    (0x1123E, 0x1123E, 11),
+    (0x1123F, 0x1127F, 20),
+    (0x11280, 0x11286, 4),
+    (0x11287, 0x11287, 20),
+    (0x11288, 0x11288, 4),
+    (0x11289, 0x11289, 20),
+    (0x1128A, 0x1128D, 4),
+    (0x1128E, 0x1128E, 20),
+    (0x1128F, 0x1129D, 4),
+    (0x1129E, 0x1129E, 20),
+    (0x1129F, 0x112A8, 4),
+    (0x112A9, 0x112AF, 20),
+    (0x112B0, 0x112DE, 4),
+    (0x112DF, 0x112DF, 11),
+    (0x112E0, 0x112E2, 4),
+    (0x112E3, 0x112EA, 11),
+    (0x112EB, 0x112EF, 20),
+    (0x112F0, 0x112F9, 12),
+    (0x112FA, 0x112FF, 20),
+    (0x11300, 0x11301, 11),
+    (0x11302, 0x11303, 4),
+    (0x11304, 0x11304, 20),
+    (0x11305, 0x1130C, 4),
+    (0x1130D, 0x1130E, 20),
+    (0x1130F, 0x11310, 4),
+    (0x11311, 0x11312, 20),
+    (0x11313, 0x11328, 4),
+    (0x11329, 0x11329, 20),
+    (0x1132A, 0x11330, 4),
+    (0x11331, 0x11331, 20),
+    (0x11332, 0x11333, 4),
+    (0x11334, 0x11334, 20),
+    (0x11335, 0x11339, 4),
+    (0x1133A, 0x1133A, 20),
+    (0x1133B, 0x1133C, 11),
+    (0x1133D, 0x1133F, 4),
+    (0x11340, 0x11340, 11),
+    (0x11341, 0x11344, 4),
+    (0x11345, 0x11346, 20),
+    (0x11347, 0x11348, 4),
+    (0x11349, 0x1134A, 20),
+    (0x1134B, 0x1134D, 4),
+    (0x1134E, 0x1134F, 20),
+    (0x11350, 0x11350, 4),
+    (0x11351, 0x11356, 20),
+    (0x11357, 0x11357, 4),
+    (0x11358, 0x1135C, 20),
+    (0x1135D, 0x11363, 4),
+    (0x11364, 0x11365, 20),
+    (0x11366, 0x1136C, 11),
+    (0x1136D, 0x1136F, 20),
+    (0x11370, 0x11374, 11),
+    (0x11375, 0x113FF, 20),
+    (0x11400, 0x11437, 4),
+    (0x11438, 0x1143F, 11),
+    (0x11440, 0x11441, 4),
+    (0x11442, 0x11444, 11),
+    (0x11445, 0x11445, 4),
+    (0x11446, 0x11446, 11),
+    (0x11447, 0x1144A, 4),
+    (0x1144B, 0x1144F, 20),
+    (0x11450, 0x11459, 12),
+    (0x1145A, 0x1145D, 20),
+    (0x1145E, 0x1145E, 11),
+    (0x1145F, 0x11461, 4),
+    (0x11462, 0x1147F, 20),
+    (0x11480, 0x114B2, 4),
+    (0x114B3, 0x114B8, 11),
+    (0x114B9, 0x114B9, 4),
+    (0x114BA, 0x114BA, 11),
+    (0x114BB, 0x114BE, 4),
+    (0x114BF, 0x114C0, 11),
+    (0x114C1, 0x114C1, 4),
+    (0x114C2, 0x114C3, 11),
+    (0x114C4, 0x114C5, 4),
+    (0x114C6, 0x114C6, 20),
+    (0x114C7, 0x114C7, 4),
+    (0x114C8, 0x114CF, 20),
+    (0x114D0, 0x114D9, 12),
+    (0x114DA, 0x1157F, 20),
+    (0x11580, 0x115B1, 4),
+    (0x115B2, 0x115B5, 11),
+    (0x115B6, 0x115B7, 20),
+    (0x115B8, 0x115BB, 4),
+    (0x115BC, 0x115BD, 11),
+    (0x115BE, 0x115BE, 4),
+    (0x115BF, 0x115C0, 11),
+    (0x115C1, 0x115D7, 20),
+    (0x115D8, 0x115DB, 4),
+    (0x115DC, 0x115DD, 11),
+    (0x115DE, 0x115FF, 20),
+    (0x11600, 0x11632, 4),
+    (0x11633, 0x1163A, 11),
+    (0x1163B, 0x1163C, 4),
+    (0x1163D, 0x1163D, 11),
+    (0x1163E, 0x1163E, 4),
+    (0x1163F, 0x11640, 11),
+    (0x11641, 0x11643, 20),
+    (0x11644, 0x11644, 4),
+    (0x11645, 0x1164F, 20),
+    (0x11650, 0x11659, 12),
+    (0x1165A, 0x1167F, 20),
+    (0x11680, 0x116AA, 4),
+    (0x116AB, 0x116AB, 11),
+    (0x116AC, 0x116AC, 4),
+    (0x116AD, 0x116AD, 11),
+    (0x116AE, 0x116AF, 4),
+    (0x116B0, 0x116B5, 11),
+    (0x116B6, 0x116B6, 4),
+    (0x116B7, 0x116B7, 11),
+    (0x116B8, 0x116B8, 4),
+    (0x116B9, 0x116BF, 20),
+    (0x116C0, 0x116C9, 12),
+    (0x116CA, 0x116FF, 20),
+    (0x11700, 0x1171A, 4),
+    (0x1171B, 0x1171C, 20),
+    (0x1171D, 0x1171F, 11),
+    (0x11720, 0x11721, 4),
+    (0x11722, 0x11725, 11),
+    (0x11726, 0x11726, 4),
+    (0x11727, 0x1172B, 11),
+    (0x1172C, 0x1172F, 20),
+    (0x11730, 0x1173B, 12),
+    (0x1173C, 0x117FF, 20),
+    (0x11800, 0x1182E, 4),
+    (0x1182F, 0x11837, 11),
+    (0x11838, 0x11838, 4),
+    (0x11839, 0x1183A, 11),
+    (0x1183B, 0x1189F, 20),
+    (0x118A0, 0x118DF, 4),
+    (0x118E0, 0x118F2, 12),
+    (0x118F3, 0x118FE, 20),
+    (0x118FF, 0x11906, 4),
+    (0x11907, 0x11908, 20),
+    (0x11909, 0x11909, 4),
+    (0x1190A, 0x1190B, 20),
+    (0x1190C, 0x11913, 4),
+    (0x11914, 0x11914, 20),
+    (0x11915, 0x11916, 4),
+    (0x11917, 0x11917, 20),
+    (0x11918, 0x11935, 4),
+    (0x11936, 0x11936, 20),
+    (0x11937, 0x11938, 4),
+    (0x11939, 0x1193A, 20),
+    (0x1193B, 0x1193C, 11),
+    (0x1193D, 0x1193D, 4),
+    (0x1193E, 0x1193E, 11),
+    (0x1193F, 0x11942, 4),
+    (0x11943, 0x11943, 11),
+    (0x11944, 0x1194F, 20),
+    (0x11950, 0x11959, 12),
+    (0x1195A, 0x1199F, 20),
+    (0x119A0, 0x119A7, 4),
+    (0x119A8, 0x119A9, 20),
+    (0x119AA, 0x119D3, 4),
+    (0x119D4, 0x119D7, 11),
+    (0x119D8, 0x119D9, 20),
+    (0x119DA, 0x119DB, 11),
+    (0x119DC, 0x119DF, 4),
+    (0x119E0, 0x119E0, 11),
+    (0x119E1, 0x119E1, 4),
+    (0x119E2, 0x119E2, 20),
+    (0x119E3, 0x119E4, 4),
+    (0x119E5, 0x119FF, 20),
+    (0x11A00, 0x11A00, 4),
+    (0x11A01, 0x11A0A, 11),
+    (0x11A0B, 0x11A32, 4),
+    (0x11A33, 0x11A38, 11),
+    (0x11A39, 0x11A3A, 4),
+    (0x11A3B, 0x11A3E, 11),
+    (0x11A3F, 0x11A46, 20),
+    (0x11A47, 0x11A47, 11),
+    (0x11A48, 0x11A4F, 20),
+    (0x11A50, 0x11A50, 4),
+    (0x11A51, 0x11A56, 11),
+    (0x11A57, 0x11A58, 4),
+    (0x11A59, 0x11A5B, 11),
+    (0x11A5C, 0x11A89, 4),
+    (0x11A8A, 0x11A96, 11),
+    (0x11A97, 0x11A97, 4),
+    (0x11A98, 0x11A99, 11),
+    (0x11A9A, 0x11A9C, 20),
+    (0x11A9D, 0x11A9D, 4),
+    (0x11A9E, 0x11ABF, 20),
+    (0x11AC0, 0x11AF8, 4),
+    (0x11AF9, 0x11BFF, 20),
+    (0x11C00, 0x11C08, 4),
+    (0x11C09, 0x11C09, 20),
+    (0x11C0A, 0x11C2F, 4),
+    (0x11C30, 0x11C36, 11),
+    (0x11C37, 0x11C37, 20),
+    (0x11C38, 0x11C3D, 11),
+    (0x11C3E, 0x11C3E, 4),
+    (0x11C3F, 0x11C3F, 11),
+    (0x11C40, 0x11C40, 4),
+    (0x11C41, 0x11C4F, 20),
+    (0x11C50, 0x11C6C, 12),
+    (0x11C6D, 0x11C71, 20),
+    (0x11C72, 0x11C8F, 4),
+    (0x11C90, 0x11C91, 20),
+    (0x11C92, 0x11CA7, 11),
+    (0x11CA8, 0x11CA8, 20),
+    (0x11CA9, 0x11CA9, 4),
+    (0x11CAA, 0x11CB0, 11),
+    (0x11CB1, 0x11CB1, 4),
+    (0x11CB2, 0x11CB3, 11),
+    (0x11CB4, 0x11CB4, 4),
+    (0x11CB5, 0x11CB6, 11),
+    (0x11CB7, 0x11CFF, 20),
+    (0x11D00, 0x11D06, 4),
+    (0x11D07, 0x11D07, 20),
+    (0x11D08, 0x11D09, 4),
+    (0x11D0A, 0x11D0A, 20),
+    (0x11D0B, 0x11D30, 4),
+    (0x11D31, 0x11D36, 11),
+    (0x11D37, 0x11D39, 20),
+    (0x11D3A, 0x11D3A, 11),
+    (0x11D3B, 0x11D3B, 20),
+    (0x11D3C, 0x11D3D, 11),
+    (0x11D3E, 0x11D3E, 20),
+    (0x11D3F, 0x11D45, 11),
+    (0x11D46, 0x11D46, 4),
+    (0x11D47, 0x11D47, 11),
+    (0x11D48, 0x11D4F, 20),
+    (0x11D50, 0x11D59, 12),
+    (0x11D5A, 0x11D5F, 20),
+    (0x11D60, 0x11D65, 4),
+    (0x11D66, 0x11D66, 20),
+    (0x11D67, 0x11D68, 4),
+    (0x11D69, 0x11D69, 20),
+    (0x11D6A, 0x11D8E, 4),
+    (0x11D8F, 0x11D8F, 20),
+    (0x11D90, 0x11D91, 11),
+    (0x11D92, 0x11D92, 20),
+    (0x11D93, 0x11D94, 4),
+    (0x11D95, 0x11D95, 11),
+    (0x11D96, 0x11D96, 4),
+    (0x11D97, 0x11D97, 11),
+    (0x11D98, 0x11D98, 4),
+    (0x11D99, 0x11D9F, 20),
+    (0x11DA0, 0x11DA9, 12),
+    (0x11DAA, 0x11EDF, 20),
+    (0x11EE0, 0x11EF2, 4),
+    (0x11EF3, 0x11EF4, 11),
+    (0x11EF5, 0x11EF6, 4),
+    (0x11EF7, 0x11FAF, 20),
+    (0x11FB0, 0x11FB0, 4),
+    (0x11FB1, 0x11FBF, 20),
+    (0x11FC0, 0x11FD4, 12),
+    (0x11FD5, 0x11FDC, 20),
+    (0x11FDD, 0x11FE0, 9),
+    (0x11FE1, 0x11FFF, 20),
+    (0x12000, 0x12399, 4),
+    (0x1239A, 0x123FF, 20),
+    (0x12400, 0x1246E, 12),
+    (0x1246F, 0x1247F, 20),
+    (0x12480, 0x12543, 4),
+    (0x12544, 0x12FFF, 20),
+    (0x13000, 0x1342E, 4),
+    (0x1342F, 0x1342F, 20),
+    (0x13430, 0x13437, -1),
+    (0x13438, 0x13438, 0),
+    (0x13439, 0x143FF, 20),
+    (0x14400, 0x14646, 4),
+    (0x14647, 0x167FF, 20),
+    (0x16800, 0x16A38, 4),
+    (0x16A39, 0x16A3F, 20),
+    (0x16A40, 0x16A5E, 4),
+    (0x16A5F, 0x16A5F, 20),
+    (0x16A60, 0x16A69, 12),
+    (0x16A6A, 0x16ACF, 20),
+    (0x16AD0, 0x16AED, 4),
+    (0x16AEE, 0x16AEF, 20),
+    (0x16AF0, 0x16AF4, 11),
+    (0x16AF5, 0x16AFF, 20),
+    (0x16B00, 0x16B2F, 4),
+    (0x16B30, 0x16B36, 11),
+    (0x16B37, 0x16B3F, 20),
+    (0x16B40, 0x16B43, 4),
+    (0x16B44, 0x16B4F, 20),
+    (0x16B50, 0x16B59, 12),
+    (0x16B5A, 0x16B5A, 20),
+    (0x16B5B, 0x16B61, 12),
+    (0x16B62, 0x16B62, 20),
+    (0x16B63, 0x16B77, 4),
+    (0x16B78, 0x16B7C, 20),
+    (0x16B7D, 0x16B8F, 4),
+    (0x16B90, 0x16E3F, 20),
+    (0x16E40, 0x16E7F, 4),
+    (0x16E80, 0x16E96, 12),
+    (0x16E97, 0x16EFF, 20),
+    (0x16F00, 0x16F4A, 4),
+    (0x16F4B, 0x16F4E, 20),
+    (0x16F4F, 0x16F4F, 11),
+    (0x16F50, 0x16F87, 4),
+    (0x16F88, 0x16F8E, 20),
+    (0x16F8F, 0x16F92, 11),
+    (0x16F93, 0x16F9F, 4),
+    (0x16FA0, 0x16FDF, 20),
+    (0x16FE0, 0x16FE1, 4),
+    (0x16FE2, 0x16FE2, 20),
+    (0x16FE3, 0x16FE3, 4),
+    (0x16FE4, 0x16FE4, 11),
+    (0x16FE5, 0x16FEF, 20),
+    (0x16FF0, 0x16FF1, 4),
+    (0x16FF2, 0x16FFF, 20),
+    (0x17000, 0x187F7, 4),
+    (0x187F8, 0x187FF, 20),
+    (0x18800, 0x18D08, 4),
+    (0x18D09, 0x1AFFF, 20),
+    (0x1B000, 0x1B11E, 4),
+    (0x1B11F, 0x1B14F, 20),
+    (0x1B150, 0x1B152, 4),
+    (0x1B153, 0x1B163, 20),
+    (0x1B164, 0x1B167, 4),
+    (0x1B168, 0x1B16F, 20),
+    (0x1B170, 0x1B2FB, 4),
+    (0x1B2FC, 0x1BBFF, 20),
+    (0x1BC00, 0x1BC6A, 4),
+    (0x1BC6B, 0x1BC6F, 20),
+    (0x1BC70, 0x1BC7C, 4),
+    (0x1BC7D, 0x1BC7F, 20),
+    (0x1BC80, 0x1BC88, 4),
+    (0x1BC89, 0x1BC8F, 20),
+    (0x1BC90, 0x1BC99, 4),
+    (0x1BC9A, 0x1BC9C, 20),
+    (0x1BC9D, 0x1BC9E, 11),
+    (0x1BC9F, 0x1BC9F, 20),
+    (0x1BCA0, 0x1BCA2, -1),
+    (0x1BCA3, 0x1BCA3, 0),
+    (0x1BCA4, 0x1D164, 20),
+    (0x1D165, 0x1D166, 4),
+    (0x1D167, 0x1D169, 11),
+    (0x1D16A, 0x1D16C, 20),
+    (0x1D16D, 0x1D172, 4),
+    (0x1D173, 0x1D179, -1),
+    (0x1D17A, 0x1D17A, 0),
+    (0x1D17B, 0x1D182, 11),
+    (0x1D183, 0x1D184, 20),
+    (0x1D185, 0x1D18B, 11),
+    (0x1D18C, 0x1D1A9, 20),
+    (0x1D1AA, 0x1D1AD, 11),
+    (0x1D1AE, 0x1D241, 20),
+    (0x1D242, 0x1D244, 11),
+    (0x1D245, 0x1D2DF, 20),
+    (0x1D2E0, 0x1D2F3, 12),
+    (0x1D2F4, 0x1D35F, 20),
+    (0x1D360, 0x1D378, 12),
+    (0x1D379, 0x1D3FF, 20),
+    (0x1D400, 0x1D454, 4),
+    (0x1D455, 0x1D455, 20),
+    (0x1D456, 0x1D49C, 4),
+    (0x1D49D, 0x1D49D, 20),
+    (0x1D49E, 0x1D49F, 4),
+    (0x1D4A0, 0x1D4A1, 20),
+    (0x1D4A2, 0x1D4A2, 4),
+    (0x1D4A3, 0x1D4A4, 20),
+    (0x1D4A5, 0x1D4A6, 4),
+    (0x1D4A7, 0x1D4A8, 20),
+    (0x1D4A9, 0x1D4AC, 4),
+    (0x1D4AD, 0x1D4AD, 20),
+    (0x1D4AE, 0x1D4B9, 4),
+    (0x1D4BA, 0x1D4BA, 20),
+    (0x1D4BB, 0x1D4BB, 4),
+    (0x1D4BC, 0x1D4BC, 20),
+    (0x1D4BD, 0x1D4C3, 4),
+    (0x1D4C4, 0x1D4C4, 20),
+    (0x1D4C5, 0x1D505, 4),
+    (0x1D506, 0x1D506, 20),
+    (0x1D507, 0x1D50A, 4),
+    (0x1D50B, 0x1D50C, 20),
+    (0x1D50D, 0x1D514, 4),
+    (0x1D515, 0x1D515, 20),
+    (0x1D516, 0x1D51C, 4),
+    (0x1D51D, 0x1D51D, 20),
+    (0x1D51E, 0x1D539, 4),
+    (0x1D53A, 0x1D53A, 20),
+    (0x1D53B, 0x1D53E, 4),
+    (0x1D53F, 0x1D53F, 20),
+    (0x1D540, 0x1D544, 4),
+    (0x1D545, 0x1D545, 20),
+    (0x1D546, 0x1D546, 4),
+    (0x1D547, 0x1D549, 20),
+    (0x1D54A, 0x1D550, 4),
+    (0x1D551, 0x1D551, 20),
+    (0x1D552, 0x1D6A5, 4),
+    (0x1D6A6, 0x1D6A7, 20),
+    (0x1D6A8, 0x1D6C0, 4),
+    (0x1D6C1, 0x1D6C1, 20),
+    (0x1D6C2, 0x1D6DA, 4),
+    (0x1D6DB, 0x1D6DB, 20),
+    (0x1D6DC, 0x1D6FA, 4),
+    (0x1D6FB, 0x1D6FB, 20),
+    (0x1D6FC, 0x1D714, 4),
+    (0x1D715, 0x1D715, 20),
+    (0x1D716, 0x1D734, 4),
+    (0x1D735, 0x1D735, 20),
+    (0x1D736, 0x1D74E, 4),
+    (0x1D74F, 0x1D74F, 20),
+    (0x1D750, 0x1D76E, 4),
+    (0x1D76F, 0x1D76F, 20),
+    (0x1D770, 0x1D788, 4),
+    (0x1D789, 0x1D789, 20),
+    (0x1D78A, 0x1D7A8, 4),
+    (0x1D7A9, 0x1D7A9, 20),
+    (0x1D7AA, 0x1D7C2, 4),
+    (0x1D7C3, 0x1D7C3, 20),
+    (0x1D7C4, 0x1D7CB, 4),
+    (0x1D7CC, 0x1D7CD, 20),
+    (0x1D7CE, 0x1D7FF, 12),
+    (0x1D800, 0x1D9FF, 20),
+    (0x1DA00, 0x1DA36, 11),
+    (0x1DA37, 0x1DA3A, 20),
+    (0x1DA3B, 0x1DA6C, 11),
+    (0x1DA6D, 0x1DA74, 20),
+    (0x1DA75, 0x1DA75, 11),
+    (0x1DA76, 0x1DA83, 20),
+    (0x1DA84, 0x1DA84, 11),
+    (0x1DA85, 0x1DA9A, 20),
+    (0x1DA9B, 0x1DA9F, 11),
+    (0x1DAA0, 0x1DAA0, 20),
+    (0x1DAA1, 0x1DAAF, 11),
+    (0x1DAB0, 0x1DFFF, 20),
+    (0x1E000, 0x1E006, 11),
+    (0x1E007, 0x1E007, 20),
+    (0x1E008, 0x1E018, 11),
+    (0x1E019, 0x1E01A, 20),
+    (0x1E01B, 0x1E021, 11),
+    (0x1E022, 0x1E022, 20),
+    (0x1E023, 0x1E024, 11),
+    (0x1E025, 0x1E025, 20),
+    (0x1E026, 0x1E02A, 11),
+    (0x1E02B, 0x1E0FF, 20),
+    (0x1E100, 0x1E12C, 4),
+    (0x1E12D, 0x1E12F, 20),
+    (0x1E130, 0x1E136, 11),
+    (0x1E137, 0x1E13D, 4),
+    (0x1E13E, 0x1E13F, 20),
+    (0x1E140, 0x1E149, 12),
+    (0x1E14A, 0x1E14D, 20),
+    (0x1E14E, 0x1E14E, 4),
+    (0x1E14F, 0x1E2BF, 20),
+    (0x1E2C0, 0x1E2EB, 4),
+    (0x1E2EC, 0x1E2EF, 11),
+    (0x1E2F0, 0x1E2F9, 12),
+    (0x1E2FA, 0x1E2FE, 20),
+    (0x1E2FF, 0x1E2FF, 9),
+    (0x1E300, 0x1E7FF, 20),
+    (0x1E800, 0x1E8C4, 4),
+    (0x1E8C5, 0x1E8C6, 20),
+    (0x1E8C7, 0x1E8CF, 12),
+    (0x1E8D0, 0x1E8D6, 11),
+    (0x1E8D7, 0x1E8FF, 20),
+    (0x1E900, 0x1E943, 4),
+    (0x1E944, 0x1E94A, 11),
+    (0x1E94B, 0x1E94B, 4),
+    (0x1E94C, 0x1E94F, 20),
+    (0x1E950, 0x1E959, 12),
+    (0x1E95A, 0x1EC70, 20),
+    (0x1EC71, 0x1ECAB, 12),
+    (0x1ECAC, 0x1ECAC, 20),
+    (0x1ECAD, 0x1ECAF, 12),
+    (0x1ECB0, 0x1ECB0, 9),
+    (0x1ECB1, 0x1ECB4, 12),
+    (0x1ECB5, 0x1ED00, 20),
+    (0x1ED01, 0x1ED2D, 12),
+    (0x1ED2E, 0x1ED2E, 20),
+    (0x1ED2F, 0x1ED3D, 12),
+    (0x1ED3E, 0x1EDFF, 20),
+    (0x1EE00, 0x1EE03, 4),
+    (0x1EE04, 0x1EE04, 20),
+    (0x1EE05, 0x1EE1F, 4),
+    (0x1EE20, 0x1EE20, 20),
+    (0x1EE21, 0x1EE22, 4),
+    (0x1EE23, 0x1EE23, 20),
+    (0x1EE24, 0x1EE24, 4),
+    (0x1EE25, 0x1EE26, 20),
+    (0x1EE27, 0x1EE27, 4),
+    (0x1EE28, 0x1EE28, 20),
+    (0x1EE29, 0x1EE32, 4),
+    (0x1EE33, 0x1EE33, 20),
+    (0x1EE34, 0x1EE37, 4),
+    (0x1EE38, 0x1EE38, 20),
+    (0x1EE39, 0x1EE39, 4),
+    (0x1EE3A, 0x1EE3A, 20),
+    (0x1EE3B, 0x1EE3B, 4),
+    (0x1EE3C, 0x1EE41, 20),
+    (0x1EE42, 0x1EE42, 4),
+    (0x1EE43, 0x1EE46, 20),
+    (0x1EE47, 0x1EE47, 4),
+    (0x1EE48, 0x1EE48, 20),
+    (0x1EE49, 0x1EE49, 4),
+    (0x1EE4A, 0x1EE4A, 20),
+    (0x1EE4B, 0x1EE4B, 4),
+    (0x1EE4C, 0x1EE4C, 20),
+    (0x1EE4D, 0x1EE4F, 4),
+    (0x1EE50, 0x1EE50, 20),
+    (0x1EE51, 0x1EE52, 4),
+    (0x1EE53, 0x1EE53, 20),
+    (0x1EE54, 0x1EE54, 4),
+    (0x1EE55, 0x1EE56, 20),
+    (0x1EE57, 0x1EE57, 4),
+    (0x1EE58, 0x1EE58, 20),
+    (0x1EE59, 0x1EE59, 4),
+    (0x1EE5A, 0x1EE5A, 20),
+    (0x1EE5B, 0x1EE5B, 4),
+    (0x1EE5C, 0x1EE5C, 20),
+    (0x1EE5D, 0x1EE5D, 4),
+    (0x1EE5E, 0x1EE5E, 20),
+    (0x1EE5F, 0x1EE5F, 4),
+    (0x1EE60, 0x1EE60, 20),
+    (0x1EE61, 0x1EE62, 4),
+    (0x1EE63, 0x1EE63, 20),
+    (0x1EE64, 0x1EE64, 4),
+    (0x1EE65, 0x1EE66, 20),
+    (0x1EE67, 0x1EE6A, 4),
+    (0x1EE6B, 0x1EE6B, 20),
+    (0x1EE6C, 0x1EE72, 4),
+    (0x1EE73, 0x1EE73, 20),
+    (0x1EE74, 0x1EE77, 4),
+    (0x1EE78, 0x1EE78, 20),
+    (0x1EE79, 0x1EE7C, 4),
+    (0x1EE7D, 0x1EE7D, 20),
+    (0x1EE7E, 0x1EE7E, 4),
+    (0x1EE7F, 0x1EE7F, 20),
+    (0x1EE80, 0x1EE89, 4),
+    (0x1EE8A, 0x1EE8A, 20),
+    (0x1EE8B, 0x1EE9B, 4),
+    (0x1EE9C, 0x1EEA0, 20),
+    (0x1EEA1, 0x1EEA3, 4),
+    (0x1EEA4, 0x1EEA4, 20),
+    (0x1EEA5, 0x1EEA9, 4),
+    (0x1EEAA, 0x1EEAA, 20),
+    (0x1EEAB, 0x1EEBB, 4),
+    (0x1EEBC, 0x1F0FF, 20),
+    (0x1F100, 0x1F10C, 12),
+    (0x1F10D, 0x1FBEF, 20),
+    (0x1FBF0, 0x1FBF9, 12),
+    (0x1FBFA, 0x1FFFF, 20),
+    (0x20000, 0x2EBE0, 4),
+    (0x2EBE1, 0x2F7FF, 20),
+    (0x2F800, 0x3134A, 4),
+    (0x3134B, 0xE0000, 20),
+    (0xE0001, 0xE0001, 0),
+    (0xE0002, 0xE001F, 20),
+    (0xE0020, 0xE007E, -1),
+    (0xE007F, 0xE007F, 0),
+    (0xE0080, 0xE00FF, 20),
+    (0xE0100, 0xE01EF, 11),
+    (0xE01F0, 0x10FFFF, 20),
+];
+
+pub(super) static WORD: Rules = Rules {
+    categories: &WORD_CATEGORIES,
+    num_categories: 22,
+    state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 12, 14, 15, 3, 16, 16, 9, 16, 14, 17, 2, 6, 8, 18, 19, 2, 8, 2, 16, 16, 16,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 4, 0, 3, 5, 0, 0, 4, 0, 0, 3, 6, 0, 0, 0, 0, 0, 4, 0, 0, 4,
+        0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 6, 7, 0, 3, 0, 2, 0, 7, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 8, 0, 0, 0, 0, 0, 9, 0, 0, 0, 8, 0, 8, 0, 0, 2, 8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+        0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+        0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 11, 10, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 16, 0, 13, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 12, 16, 0, 0, 11, 10, 0, 0, 0, 0, 0, 0,
+        0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 11, 16, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 10, 16, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0,
+    ],
+    backwards_state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 3, 4, 5, 6, 7, 8, 9, 4, 2, 10, 3, 11, 9, 12, 13, 14, 9, 7, 8, 2, 7,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 4, 5, 6, 7, 8, 0, 4, 2, 10, 3, 11, 9, 12, 13, 0, 9, 0, 8, 2, 7,
+        0, 3, 0, 0, 6, 0, 0, 0, 0, 0, 0, 3, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 4, 0, 6, 0, 0, 0, 4, 0, 0, 3, 11, 0, 0, 0, 0, 0, 7, 0, 0, 7,
+        0, 3, 0, 0, 6, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 9, 0, 0, 0, 9, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 12, 13, 0, 0, 0, 0, 0, 0,
+        0, 3, 4, 0, 6, 7, 0, 0, 4, 2, 0, 3, 11, 0, 0, 0, 0, 0, 0, 8, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0,
+        0, 3, 0, 0, 0, 0, 0, 9, 0, 0, 0, 3, 0, 9, 0, 0, 0, 9, 0, 0, 0, 0,
+    ],
+    end_states: &[false, true, true, true, false, true, true, false, true, true, true, true, true, true, true, true, true, true, true, true],
+    lookahead_states: &[false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false],
+};
+
+pub(super) static LINE_CATEGORIES: [(u32, u32, i8); 1308] = [
+    (0x0000, 0x0002, 12),
+    (0x0003, 0x0003, 14),
+    (0x0004, 0x0008, 12),
+    (0x0009, 0x000A, 14),
+    (0x000B, 0x000B, 12),
+    (0x000C, 0x000C, 14),
+    (0x000D, 0x000D, 4),
+    (0x000E, 0x0020, 12),
+    (0x0021, 0x0021, 15),
+    (0x0022, 0x0022, 3),
+    (0x0023, 0x0023, 8),
+    (0x0024, 0x0024, 10),
+    (0x0025, 0x0025, 15),
+    (0x0026, 0x0026, 8),
+    (0x0027, 0x0027, 10),
+    (0x0028, 0x0028, 2),
+    (0x0029, 0x0029, 11),
+    (0x002A, 0x002B, 8),
+    (0x002C, 0x002C, 6),
+    (0x002D, 0x002D, 5),
+    (0x002E, 0x002E, 6),
+    (0x002F, 0x002F, 8),
+    (0x0030, 0x0039, 1),
+    (0x003A, 0x003B, 15),
+    (0x003C, 0x003E, 8),
+    (0x003F, 0x003F, 15),
+    (0x0040, 0x005A, 8),
+    (0x005B, 0x005B, 16),
+    (0x005C, 0x005C, 8),
+    (0x005D, 0x005D, 15),
+    (0x005E, 0x007A, 8),
+    (0x007B, 0x007B, 16),
+    (0x007C, 0x007C, 8),
+    (0x007D, 0x007D, 15),
+    (0x007E, 0x007E, 8),
+    (0x007F, 0x009F, 12),
+    (0x00A0, 0x00A0, 13),
+    (0x00A1, 0x00A1, 8),
+    (0x00A2, 0x00A2, 15),
+    (0x00A3, 0x00A5, 16),
+    (0x00A6, 0x00AA, 8),
+    (0x00AB, 0x00AB, 16),
+    (0x00AC, 0x00AC, 8),
+    (0x00AD, 0x00AD, 5),
+    (0x00AE, 0x00AF, 8),
+    (0x00B0, 0x00B0, 15),
+    (0x00B1, 0x00B1, 8),
+    (0x00B2, 0x00B3, 1),
+    (0x00B4, 0x00B8, 8),
+    (0x00B9, 0x00B9, 1),
+    (0x00BA, 0x00BA, 8),
+    (0x00BB, 0x00BB, 15),
+    (0x00BC, 0x00BE, 1),
+    (0x00BF, 0x02FF, 8),
+    (0x0300, 0x036F, 7),
+    (0x0370, 0x0482, 8),
+    (0x0483, 0x0489, 7),
+    (0x048A, 0x0589, 8),
+    (0x058A, 0x058A, 5),
+    (0x058B, 0x058E, 8),
+    (0x058F, 0x058F, 16),
+    (0x0590, 0x0590, 8),
+    (0x0591, 0x05BD, 7),
+    (0x05BE, 0x05BE, 5),
+    (0x05BF, 0x05BF, 7),
+    (0x05C0, 0x05C0, 8),
+    (0x05C1, 0x05C2, 7),
+    (0x05C3, 0x05C3, 8),
+    (0x05C4, 0x05C5, 7),
+    (0x05C6, 0x05C6, 8),
+    (0x05C7, 0x05C7, 7),
+    (0x05C8, 0x05FF, 8),
+    (0x0600, 0x0605, -1),
+    (0x0606, 0x060A, 8),
+    (0x060B, 0x060B, 16),
+    (0x060C, 0x060F, 8),
+    (0x0610, 0x061A, 7),
+    (0x061B, 0x061B, 8),
+    (0x061C, 0x061C, -1),
+    (0x061D, 0x064A, 8),
+    (0x064B, 0x065F, 7),
+    (0x0660, 0x0669, 1),
+    (0x066A, 0x066A, 15),
+    (0x066B, 0x066F, 8),
+    (0x0670, 0x0670, 7),
+    (0x0671, 0x06D5, 8),
+    (0x06D6, 0x06DC, 7),
+    (0x06DD, 0x06DD, -1),
+    (0x06DE, 0x06DE, 8),
+    (0x06DF, 0x06E4, 7),
+    (0x06E5, 0x06E6, 8),
+    (0x06E7, 0x06E8, 7),
+    (0x06E9, 0x06E9, 8),
+    (0x06EA, 0x06ED, 7),
+    (0x06EE, 0x06EF, 8),
+    (0x06F0, 0x06F9, 1),
+    (0x06FA, 0x070E, 8),
+    (0x070F, 0x070F, -1),
+    (0x0710, 0x0710, 8),
+    (0x0711, 0x0711, 7),
+    (0x0712, 0x072F, 8),
+    (0x0730, 0x074A, 7),
+    (0x074B, 0x07A5, 8),
+    (0x07A6, 0x07B0, 7),
+    (0x07B1, 0x07BF, 8),
+    (0x07C0, 0x07C9, 1),
+    (0x07CA, 0x07EA, 8),
+    (0x07EB, 0x07F3, 7),
+    (0x07F4, 0x07FC, 8),
+    (0x07FD, 0x07FD, 7),
+    (0x07FE, 0x07FF, 16),
+    (0x0800, 0x0815, 8),
+    (0x0816, 0x0819, 7),
+    (0x081A, 0x081A, 8),
+    (0x081B, 0x0823, 7),
+    (0x0824, 0x0824, 8),
+    (0x0825, 0x0827, 7),
+    (0x0828, 0x0828, 8),
+    (0x0829, 0x082D, 7),
+    (0x082E, 0x0858, 8),
+    (0x0859, 0x085B, 7),
+    (0x085C, 0x08D2, 8),
+    (0x08D3, 0x08E1, 7),
+    (0x08E2, 0x08E2, -1),
+    (0x08E3, 0x0902, 7),
+    (0x0903, 0x0939, 8),
+    (0x093A, 0x093A, 7),
+    (0x093B, 0x093B, 8),
+    (0x093C, 0x093C, 7),
+    (0x093D, 0x0940, 8),
+    (0x0941, 0x0948, 7),
+    (0x0949, 0x094C, 8),
+    (0x094D, 0x094D, 7),
+    (0x094E, 0x0950, 8),
+    (0x0951, 0x0957, 7),
+    (0x0958, 0x0961, 8),
+    (0x0962, 0x0963, 7),
+    (0x0964, 0x0965, 0),
+    (0x0966, 0x096F, 1),
+    (0x0970, 0x0980, 8),
+    (0x0981, 0x0981, 7),
+    (0x0982, 0x09BB, 8),
+    (0x09BC, 0x09BC, 7),
+    (0x09BD, 0x09C0, 8),
+    (0x09C1, 0x09C4, 7),
+    (0x09C5, 0x09CC, 8),
+    (0x09CD, 0x09CD, 7),
+    (0x09CE, 0x09E1, 8),
+    (0x09E2, 0x09E3, 7),
+    (0x09E4, 0x09E5, 8),
+    (0x09E6, 0x09EF, 1),
+    (0x09F0, 0x09F1, 8),
+    (0x09F2, 0x09F3, 16),
+    (0x09F4, 0x09F9, 1),
+    (0x09FA, 0x09FA, 8),
+    (0x09FB, 0x09FB, 16),
+    (0x09FC, 0x09FD, 8),
+    (0x09FE, 0x09FE, 7),
+    (0x09FF, 0x0A00, 8),
+    (0x0A01, 0x0A02, 7),
+    (0x0A03, 0x0A3B, 8),
+    (0x0A3C, 0x0A3C, 7),
+    (0x0A3D, 0x0A40, 8),
+    (0x0A41, 0x0A42, 7),
+    (0x0A43, 0x0A46, 8),
+    (0x0A47, 0x0A48, 7),
+    (0x0A49, 0x0A4A, 8),
+    (0x0A4B, 0x0A4D, 7),
+    (0x0A4E, 0x0A50, 8),
+    (0x0A51, 0x0A51, 7),
+    (0x0A52, 0x0A65, 8),
+    (0x0A66, 0x0A6F, 1),
+    (0x0A70, 0x0A71, 7),
+    (0x0A72, 0x0A74, 8),
+    (0x0A75, 0x0A75, 7),
+    (0x0A76, 0x0A80, 8),
+    (0x0A81, 0x0A82, 7),
+    (0x0A83, 0x0ABB, 8),
+    (0x0ABC, 0x0ABC, 7),
+    (0x0ABD, 0x0AC0, 8),
+    (0x0AC1, 0x0AC5, 7),
+    (0x0AC6, 0x0AC6, 8),
+    (0x0AC7, 0x0AC8, 7),
+    (0x0AC9, 0x0ACC, 8),
+    (0x0ACD, 0x0ACD, 7),
+    (0x0ACE, 0x0AE1, 8),
+    (0x0AE2, 0x0AE3, 7),
+    (0x0AE4, 0x0AE5, 8),
+    (0x0AE6, 0x0AEF, 1),
+    (0x0AF0, 0x0AF0, 8),
+    (0x0AF1, 0x0AF1, 16),
+    (0x0AF2, 0x0AF9, 8),
+    (0x0AFA, 0x0AFF, 7),
+    (0x0B00, 0x0B00, 8),
+    (0x0B01, 0x0B01, 7),
+    (0x0B02, 0x0B3B, 8),
+    (0x0B3C, 0x0B3C, 7),
+    (0x0B3D, 0x0B3E, 8),
+    (0x0B3F, 0x0B3F, 7),
+    (0x0B40, 0x0B40, 8),
+    (0x0B41, 0x0B44, 7),
+    (0x0B45, 0x0B4C, 8),
+    (0x0B4D, 0x0B4D, 7),
+    (0x0B4E, 0x0B54, 8),
+    (0x0B55, 0x0B56, 7),
+    (0x0B57, 0x0B61, 8),
+    (0x0B62, 0x0B63, 7),
+    (0x0B64, 0x0B65, 8),
+    (0x0B66, 0x0B6F, 1),
+    (0x0B70, 0x0B71, 8),
+    (0x0B72, 0x0B77, 1),
+    (0x0B78, 0x0B81, 8),
+    (0x0B82, 0x0B82, 7),
+    (0x0B83, 0x0BBF, 8),
+    (0x0BC0, 0x0BC0, 7),
+    (0x0BC1, 0x0BCC, 8),
+    (0x0BCD, 0x0BCD, 7),
+    (0x0BCE, 0x0BE5, 8),
+    (0x0BE6, 0x0BF2, 1),
+    (0x0BF3, 0x0BF8, 8),
+    (0x0BF9, 0x0BF9, 16),
+    (0x0BFA, 0x0BFF, 8),
+    (0x0C00, 0x0C00, 7),
+    (0x0C01, 0x0C03, 8),
+    (0x0C04, 0x0C04, 7),
+    (0x0C05, 0x0C3D, 8),
+    (0x0C3E, 0x0C40, 7),
+    (0x0C41, 0x0C45, 8),
+    (0x0C46, 0x0C48, 7),
+    (0x0C49, 0x0C49, 8),
+    (0x0C4A, 0x0C4D, 7),
+    (0x0C4E, 0x0C54, 8),
+    (0x0C55, 0x0C56, 7),
+    (0x0C57, 0x0C61, 8),
+    (0x0C62, 0x0C63, 7),
+    (0x0C64, 0x0C65, 8),
+    (0x0C66, 0x0C6F, 1),
+    (0x0C70, 0x0C77, 8),
+    (0x0C78, 0x0C7E, 1),
+    (0x0C7F, 0x0C80, 8),
+    (0x0C81, 0x0C81, 7),
+    (0x0C82, 0x0CBB, 8),
+    (0x0CBC, 0x0CBC, 7),
+    (0x0CBD, 0x0CBE, 8),
+    (0x0CBF, 0x0CBF, 7),
+    (0x0CC0, 0x0CC5, 8),
+    (0x0CC6, 0x0CC6, 7),
+    (0x0CC7, 0x0CCB, 8),
+    (0x0CCC, 0x0CCD, 7),
+    (0x0CCE, 0x0CE1, 8),
+    (0x0CE2, 0x0CE3, 7),
+    (0x0CE4, 0x0CE5, 8),
+    (0x0CE6, 0x0CEF, 1),
+    (0x0CF0, 0x0CFF, 8),
+    (0x0D00, 0x0D01, 7),
+    (0x0D02, 0x0D3A, 8),
+    (0x0D3B, 0x0D3C, 7),
+    (0x0D3D, 0x0D40, 8),
+    (0x0D41, 0x0D44, 7),
+    (0x0D45, 0x0D4C, 8),
+    (0x0D4D, 0x0D4D, 7),
+    (0x0D4E, 0x0D57, 8),
+    (0x0D58, 0x0D5E, 1),
+    (0x0D5F, 0x0D61, 8),
+    (0x0D62, 0x0D63, 7),
+    (0x0D64, 0x0D65, 8),
+    (0x0D66, 0x0D78, 1),
+    (0x0D79, 0x0D80, 8),
+    (0x0D81, 0x0D81, 7),
+    (0x0D82, 0x0DC9, 8),
+    (0x0DCA, 0x0DCA, 7),
+    (0x0DCB, 0x0DD1, 8),
+    (0x0DD2, 0x0DD4, 7),
+    (0x0DD5, 0x0DD5, 8),
+    (0x0DD6, 0x0DD6, 7),
+    (0x0DD7, 0x0DE5, 8),
+    (0x0DE6, 0x0DEF, 1),
+    (0x0DF0, 0x0E30, 8),
+    (0x0E31, 0x0E31, 7),
+    (0x0E32, 0x0E33, 8),
+    (0x0E34, 0x0E3A, 7),
+    (0x0E3B, 0x0E3E, 8),
+    (0x0E3F, 0x0E3F, 16),
+    (0x0E40, 0x0E46, 8),
+    (0x0E47, 0x0E4E, 7),
+    (0x0E4F, 0x0E4F, 8),
+    (0x0E50, 0x0E59, 1),
+    (0x0E5A, 0x0EB0, 8),
+    (0x0EB1, 0x0EB1, 7),
+    (0x0EB2, 0x0EB3, 8),
+    (0x0EB4, 0x0EBC, 7),
+    (0x0EBD, 0x0EC7, 8),
+    (0x0EC8, 0x0ECD, 7),
+    (0x0ECE, 0x0ECF, 8),
+    (0x0ED0, 0x0ED9, 1),
+    (0x0EDA, 0x0F0B, 8),
+    (0x0F0C, 0x0F0C, 13),
+    (0x0F0D, 0x0F17, 8),
+    (0x0F18, 0x0F19, 7),
+    (0x0F1A, 0x0F1F, 8),
+    (0x0F20, 0x0F33, 1),
+    (0x0F34, 0x0F34, 8),
+    (0x0F35, 0x0F35, 7),
+    (0x0F36, 0x0F36, 8),
+    (0x0F37, 0x0F37, 7),
+    (0x0F38, 0x0F38, 8),
+    (0x0F39, 0x0F39, 7),
+    (0x0F3A, 0x0F3A, 16),
+    (0x0F3B, 0x0F3B, 15),
+    (0x0F3C, 0x0F3C, 16),
+    (0x0F3D, 0x0F3D, 15),
+    (0x0F3E, 0x0F70, 8),
+    (0x0F71, 0x0F7E, 7),
+    (0x0F7F, 0x0F7F, 8),
+    (0x0F80, 0x0F84, 7),
+    (0x0F85, 0x0F85, 8),
+    (0x0F86, 0x0F87, 7),
+    (0x0F88, 0x0F8C, 8),
+    (0x0F8D, 0x0F97, 7),
+    (0x0F98, 0x0F98, 8),
+    (0x0F99, 0x0FBC, 7),
+    (0x0FBD, 0x0FC5, 8),
+    (0x0FC6, 0x0FC6, 7),
+    (0x0FC7, 0x102C, 8),
+    (0x102D, 0x1030, 7),
+    (0x1031, 0x1031, 8),
+    (0x1032, 0x1037, 7),
+    (0x1038, 0x1038, 8),
+    (0x1039, 0x103A, 7),
+    (0x103B, 0x103C, 8),
+    (0x103D, 0x103E, 7),
+    (0x103F, 0x103F, 8),
+    (0x1040, 0x1049, 1),
+    (0x104A, 0x1057, 8),
+    (0x1058, 0x1059, 7),
+    (0x105A, 0x105D, 8),
+    (0x105E, 0x1060, 7),
+    (0x1061, 0x1070, 8),
+    (0x1071, 0x1074, 7),
+    (0x1075, 0x1081, 8),
+    (0x1082, 0x1082, 7),
+    (0x1083, 0x1084, 8),
+    (0x1085, 0x1086, 7),
+    (0x1087, 0x108C, 8),
+    (0x108D, 0x108D, 7),
+    (0x108E, 0x108F, 8),
+    (0x1090, 0x1099, 1),
+    (0x109A, 0x109C, 8),
+    (0x109D, 0x109D, 7),
+    (0x109E, 0x135C, 8),
+    (0x135D, 0x135F, 7),
+    (0x1360, 0x1368, 8),
+    (0x1369, 0x137C, 1),
+    (0x137D, 0x13FF, 8),
+    (0x1400, 0x1400, 5),
+    (0x1401, 0x167F, 8),
+    (0x1680, 0x1680, 12),
+    (0x1681, 0x169A, 8),
+    (0x169B, 0x169B, 16),
+    (0x169C, 0x169C, 15),
+    (0x169D, 0x1711, 8),
+    (0x1712, 0x1714, 7),
+    (0x1715, 0x1731, 8),
+    (0x1732, 0x1734, 7),
+    (0x1735, 0x1751, 8),
+    (0x1752, 0x1753, 7),
+    (0x1754, 0x1771, 8),
+    (0x1772, 0x1773, 7),
+    (0x1774, 0x17B3, 8),
+    (0x17B4, 0x17B5, 7),
+    (0x17B6, 0x17B6, 8),
+    (0x17B7, 0x17BD, 7),
+    (0x17BE, 0x17C5, 8),
+    (0x17C6, 0x17C6, 7),
+    (0x17C7, 0x17C8, 8),
+    (0x17C9, 0x17D3, 7),
+    (0x17D4, 0x17DA, 8),
+    (0x17DB, 0x17DB, 16),
+    (0x17DC, 0x17DC, 8),
+    (0x17DD, 0x17DD, 7),
+    (0x17DE, 0x17DF, 8),
+    (0x17E0, 0x17E9, 1),
+    (0x17EA, 0x17EF, 8),
+    (0x17F0, 0x17F9, 1),
+    (0x17FA, 0x1805, 8),
+    (0x1806, 0x1806, 5),
+    (0x1807, 0x180A, 8),
+    (0x180B, 0x180D, 7),
+    (0x180E, 0x180E, -1),
+    (0x180F, 0x180F, 8),
+    (0x1810, 0x1819, 1),
+    (0x181A, 0x1884, 8),
+    (0x1885, 0x1886, 7),
+    (0x1887, 0x18A8, 8),
+    (0x18A9, 0x18A9, 7),
+    (0x18AA, 0x191F, 8),
+    (0x1920, 0x1922, 7),
+    (0x1923, 0x1926, 8),
+    (0x1927, 0x1928, 7),
+    (0x1929, 0x1931, 8),
+    (0x1932, 0x1932, 7),
+    (0x1933, 0x1938, 8),
+    (0x1939, 0x193B, 7),
+    (0x193C, 0x1945, 8),
+    (0x1946, 0x194F, 1),
+    (0x1950, 0x19CF, 8),
+    (0x19D0, 0x19DA, 1),
+    (0x19DB, 0x1A16, 8),
+    (0x1A17, 0x1A18, 7),
+    (0x1A19, 0x1A1A, 8),
+    (0x1A1B, 0x1A1B, 7),
+    (0x1A1C, 0x1A55, 8),
+    (0x1A56, 0x1A56, 7),
+    (0x1A57, 0x1A57, 8),
+    (0x1A58, 0x1A5E, 7),
+    (0x1A5F, 0x1A5F, 8),
+    (0x1A60, 0x1A60, 7),
+    (0x1A61, 0x1A61, 8),
+    (0x1A62, 0x1A62, 7),
+    (0x1A63, 0x1A64, 8),
+    (0x1A65, 0x1A6C, 7),
+    (0x1A6D, 0x1A72, 8),
+    (0x1A73, 0x1A7C, 7),
+    (0x1A7D, 0x1A7E, 8),
+    (0x1A7F, 0x1A7F, 7),
+    (0x1A80, 0x1A89, 1),
+    (0x1A8A, 0x1A8F, 8),
+    (0x1A90, 0x1A99, 1),
+    (0x1A9A, 0x1AAF, 8),
+    (0x1AB0, 0x1AC0, 7),
+    (0x1AC1, 0x1AFF, 8),
+    (0x1B00, 0x1B03, 7),
+    (0x1B04, 0x1B33, 8),
+    (0x1B34, 0x1B34, 7),
+    (0x1B35, 0x1B35, 8),
+    (0x1B36, 0x1B3A, 7),
+    (0x1B3B, 0x1B3B, 8),
+    (0x1B3C, 0x1B3C, 7),
+    (0x1B3D, 0x1B41, 8),
+    (0x1B42, 0x1B42, 7),
+    (0x1B43, 0x1B4F, 8),
+    (0x1B50, 0x1B59, 1),
+    (0x1B5A, 0x1B6A, 8),
+    (0x1B6B, 0x1B73, 7),
+    (0x1B74, 0x1B7F, 8),
+    (0x1B80, 0x1B81, 7),
+    (0x1B82, 0x1BA1, 8),
+    (0x1BA2, 0x1BA5, 7),
+    (0x1BA6, 0x1BA7, 8),
+    (0x1BA8, 0x1BA9, 7),
+    (0x1BAA, 0x1BAA, 8),
+    (0x1BAB, 0x1BAD, 7),
+    (0x1BAE, 0x1BAF, 8),
+    (0x1BB0, 0x1BB9, 1),
+    (0x1BBA, 0x1BE5, 8),
+    (0x1BE6, 0x1BE6, 7),
+    (0x1BE7, 0x1BE7, 8),
+    (0x1BE8, 0x1BE9, 7),
+    (0x1BEA, 0x1BEC, 8),
+    (0x1BED, 0x1BED, 7),
+    (0x1BEE, 0x1BEE, 8),
+    (0x1BEF, 0x1BF1, 7),
+    (0x1BF2, 0x1C2B, 8),
+    (0x1C2C, 0x1C33, 7),
+    (0x1C34, 0x1C35, 8),
+    (0x1C36, 0x1C37, 7),
+    (0x1C38, 0x1C3F, 8),
+    (0x1C40, 0x1C49, 1),
+    (0x1C4A, 0x1C4F, 8),
+    (0x1C50, 0x1C59, 1),
+    (0x1C5A, 0x1CCF, 8),
+    (0x1CD0, 0x1CD2, 7),
+    (0x1CD3, 0x1CD3, 8),
+    (0x1CD4, 0x1CE0, 7),
+    (0x1CE1, 0x1CE1, 8),
+    (0x1CE2, 0x1CE8, 7),
+    (0x1CE9, 0x1CEC, 8),
+    (0x1CED, 0x1CED, 7),
+    (0x1CEE, 0x1CF3, 8),
+    (0x1CF4, 0x1CF4, 7),
+    (0x1CF5, 0x1CF7, 8),
+    (0x1CF8, 0x1CF9, 7),
+    (0x1CFA, 0x1DBF, 8),
+    (0x1DC0, 0x1DF9, 7),
+    (0x1DFA, 0x1DFA, 8),
+    (0x1DFB, 0x1DFF, 7),
+    (0x1E00, 0x1FFF, 8),
+    (0x2000, 0x2006, 12),
+    (0x2007, 0x2007, 13),
+    (0x2008, 0x200A, 12),
+    (0x200B, 0x200F, -1),
+    (0x2010, 0x2010, 5),
+    (0x2011, 0x2011, 13),
+    (0x2012, 0x2015, 5),
+    (0x2016, 0x2017, 8),
+    (0x2018, 0x2018, 16),
+    (0x2019, 0x2019, 15),
+    (0x201A, 0x201C, 16),
+    (0x201D, 0x201D, 15),
+    (0x201E, 0x201F, 16),
+    (0x2020, 0x2027, 8),
+    (0x2028, 0x2029, 14),
+    (0x202A, 0x202E, -1),
+    (0x202F, 0x202F, 13),
+    (0x2030, 0x2034, 15),
+    (0x2035, 0x2038, 8),
+    (0x2039, 0x2039, 16),
+    (0x203A, 0x203A, 15),
+    (0x203B, 0x2044, 8),
+    (0x2045, 0x2045, 16),
+    (0x2046, 0x2046, 15),
+    (0x2047, 0x205E, 8),
+    (0x205F, 0x205F, 12),
+    (0x2060, 0x2064, -1),
+    (0x2065, 0x2065, 8),
+    (0x2066, 0x206F, -1),
+    (0x2070, 0x2070, 1),
+    (0x2071, 0x2073, 8),
+    (0x2074, 0x2079, 1),
+    (0x207A, 0x207C, 8),
+    (0x207D, 0x207D, 16),
+    (0x207E, 0x207E, 15),
+    (0x207F, 0x207F, 8),
+    (0x2080, 0x2089, 1),
+    (0x208A, 0x208C, 8),
+    (0x208D, 0x208D, 16),
+    (0x208E, 0x208E, 15),
+    (0x208F, 0x209F, 8),
+    (0x20A0, 0x20BF, 16),
+    (0x20C0, 0x20CF, 8),
+    (0x20D0, 0x20F0, 7),
+    (0x20F1, 0x2102, 8),
+    (0x2103, 0x2103, 15),
+    (0x2104, 0x2104, 8),
+    (0x2105, 0x2105, 15),
+    (0x2106, 0x2108, 8),
+    (0x2109, 0x2109, 15),
+    (0x210A, 0x214F, 8),
+    (0x2150, 0x215F, 1),
+    (0x2160, 0x2188, 8),
+    (0x2189, 0x2189, 1),
+    (0x218A, 0x2307, 8),
+    (0x2308, 0x2308, 16),
+    (0x2309, 0x2309, 15),
+    (0x230A, 0x230A, 16),
+    (0x230B, 0x230B, 15),
+    (0x230C, 0x2328, 8),
+    (0x2329, 0x2329, 16),
+    (0x232A, 0x232A, 15),
+    (0x232B, 0x245F, 8),
+    (0x2460, 0x249B, 1),
+    (0x249C, 0x24E9, 8),
+    (0x24EA, 0x24FF, 1),
+    (0x2500, 0x2767, 8),
+    (0x2768, 0x2768, 16),
+    (0x2769, 0x2769, 15),
+    (0x276A, 0x276A, 16),
+    (0x276B, 0x276B, 15),
+    (0x276C, 0x276C, 16),
+    (0x276D, 0x276D, 15),
+    (0x276E, 0x276E, 16),
+    (0x276F, 0x276F, 15),
+    (0x2770, 0x2770, 16),
+    (0x2771, 0x2771, 15),
+    (0x2772, 0x2772, 16),
+    (0x2773, 0x2773, 15),
+    (0x2774, 0x2774, 16),
+    (0x2775, 0x2775, 15),
+    (0x2776, 0x2793, 1),
+    (0x2794, 0x27C4, 8),
+    (0x27C5, 0x27C5, 16),
+    (0x27C6, 0x27C6, 15),
+    (0x27C7, 0x27E5, 8),
+    (0x27E6, 0x27E6, 16),
+    (0x27E7, 0x27E7, 15),
+    (0x27E8, 0x27E8, 16),
+    (0x27E9, 0x27E9, 15),
+    (0x27EA, 0x27EA, 16),
+    (0x27EB, 0x27EB, 15),
+    (0x27EC, 0x27EC, 16),
+    (0x27ED, 0x27ED, 15),
+    (0x27EE, 0x27EE, 16),
+    (0x27EF, 0x27EF, 15),
+    (0x27F0, 0x2982, 8),
+    (0x2983, 0x2983, 16),
+    (0x2984, 0x2984, 15),
+    (0x2985, 0x2985, 16),
+    (0x2986, 0x2986, 15),
+    (0x2987, 0x2987, 16),
+    (0x2988, 0x2988, 15),
+    (0x2989, 0x2989, 16),
+    (0x298A, 0x298A, 15),
+    (0x298B, 0x298B, 16),
+    (0x298C, 0x298C, 15),
+    (0x298D, 0x298D, 16),
+    (0x298E, 0x298E, 15),
+    (0x298F, 0x298F, 16),
+    (0x2990, 0x2990, 15),
+    (0x2991, 0x2991, 16),
+    (0x2992, 0x2992, 15),
+    (0x2993, 0x2993, 16),
+    (0x2994, 0x2994, 15),
+    (0x2995, 0x2995, 16),
+    (0x2996, 0x2996, 15),
+    (0x2997, 0x2997, 16),
+    (0x2998, 0x2998, 15),
+    (0x2999, 0x29D7, 8),
+    (0x29D8, 0x29D8, 16),
+    (0x29D9, 0x29D9, 15),
+    (0x29DA, 0x29DA, 16),
+    (0x29DB, 0x29DB, 15),
+    (0x29DC, 0x29FB, 8),
+    (0x29FC, 0x29FC, 16),
+    (0x29FD, 0x29FD, 15),
+    (0x29FE, 0x2CEE, 8),
+    (0x2CEF, 0x2CF1, 7),
+    (0x2CF2, 0x2CFC, 8),
+    (0x2CFD, 0x2CFD, 1),
+    (0x2CFE, 0x2D7E, 8),
+    (0x2D7F, 0x2D7F, 7),
+    (0x2D80, 0x2DDF, 8),
+    (0x2DE0, 0x2DFF, 7),
+    (0x2E00, 0x2E01, 8),
+    (0x2E02, 0x2E02, 16),
+    (0x2E03, 0x2E03, 15),
+    (0x2E04, 0x2E04, 16),
+    (0x2E05, 0x2E05, 15),
+    (0x2E06, 0x2E08, 8),
+    (0x2E09, 0x2E09, 16),
+    (0x2E0A, 0x2E0A, 15),
+    (0x2E0B, 0x2E0B, 8),
+    (0x2E0C, 0x2E0C, 16),
+    (0x2E0D, 0x2E0D, 15),
+    (0x2E0E, 0x2E16, 8),
+    (0x2E17, 0x2E17, 5),
+    (0x2E18, 0x2E19, 8),
+    (0x2E1A, 0x2E1A, 5),
+    (0x2E1B, 0x2E1B, 8),
+    (0x2E1C, 0x2E1C, 16),
+    (0x2E1D, 0x2E1D, 15),
+    (0x2E1E, 0x2E1F, 8),
+    (0x2E20, 0x2E20, 16),
+    (0x2E21, 0x2E21, 15),
+    (0x2E22, 0x2E22, 16),
+    (0x2E23, 0x2E23, 15),
+    (0x2E24, 0x2E24, 16),
+    (0x2E25, 0x2E25, 15),
+    (0x2E26, 0x2E26, 16),
+    (0x2E27, 0x2E27, 15),
+    (0x2E28, 0x2E28, 16),
+    (0x2E29, 0x2E29, 15),
+    (0x2E2A, 0x2E39, 8),
+    (0x2E3A, 0x2E3B, 5),
+    (0x2E3C, 0x2E3F, 8),
+    (0x2E40, 0x2E40, 5),
+    (0x2E41, 0x2E41, 8),
+    (0x2E42, 0x2E42, 16),
+    (0x2E43, 0x2FFF, 8),
+    (0x3000, 0x3000, 12),
+    (0x3001, 0x3002, 15),
+    (0x3003, 0x3004, 8),
+    (0x3005, 0x3005, 15),
+    (0x3006, 0x3007, 8),
+    (0x3008, 0x3008, 16),
+    (0x3009, 0x3009, 15),
+    (0x300A, 0x300A, 16),
+    (0x300B, 0x300B, 15),
+    (0x300C, 0x300C, 16),
+    (0x300D, 0x300D, 15),
+    (0x300E, 0x300E, 16),
+    (0x300F, 0x300F, 15),
+    (0x3010, 0x3010, 16),
+    (0x3011, 0x3011, 15),
+    (0x3012, 0x3013, 8),
+    (0x3014, 0x3014, 16),
+    (0x3015, 0x3015, 15),
+    (0x3016, 0x3016, 16),
+    (0x3017, 0x3017, 15),
+    (0x3018, 0x3018, 16),
+    (0x3019, 0x3019, 15),
+    (0x301A, 0x301A, 16),
+    (0x301B, 0x301B, 15),
+    (0x301C, 0x301C, 5),
+    (0x301D, 0x301D, 16),
+    (0x301E, 0x301F, 15),
+    (0x3020, 0x3029, 8),
+    (0x302A, 0x302D, 7),
+    (0x302E, 0x302F, 8),
+    (0x3030, 0x3030, 5),
+    (0x3031, 0x3040, 8),
+    (0x3041, 0x3041, 15),
+    (0x3042, 0x3042, 17),
+    (0x3043, 0x3043, 15),
+    (0x3044, 0x3044, 17),
+    (0x3045, 0x3045, 15),
+    (0x3046, 0x3046, 17),
+    (0x3047, 0x3047, 15),
+    (0x3048, 0x3048, 17),
+    (0x3049, 0x3049, 15),
+    (0x304A, 0x3062, 17),
+    (0x3063, 0x3063, 15),
+    (0x3064, 0x3082, 17),
+    (0x3083, 0x3083, 15),
+    (0x3084, 0x3084, 17),
+    (0x3085, 0x3085, 15),
+    (0x3086, 0x3086, 17),
+    (0x3087, 0x3087, 15),
+    (0x3088, 0x308D, 17),
+    (0x308E, 0x308E, 15),
+    (0x308F, 0x3094, 17),
+    (0x3095, 0x3098, 8),
+    (0x3099, 0x309A, 9),
+    (0x309B, 0x309E, 15),
+    (0x309F, 0x309F, 8),
+    (0x30A0, 0x30A0, 5),
+    (0x30A1, 0x30A1, 15),
+    (0x30A2, 0x30A2, 17),
+    (0x30A3, 0x30A3, 15),
+    (0x30A4, 0x30A4, 17),
+    (0x30A5, 0x30A5, 15),
+    (0x30A6, 0x30A6, 17),
+    (0x30A7, 0x30A7, 15),
+    (0x30A8, 0x30A8, 17),
+    (0x30A9, 0x30A9, 15),
+    (0x30AA, 0x30C2, 17),
+    (0x30C3, 0x30C3, 15),
+    (0x30C4, 0x30E2, 17),
+    (0x30E3, 0x30E3, 15),
+    (0x30E4, 0x30E4, 17),
+    (0x30E5, 0x30E5, 15),
+    (0x30E6, 0x30E6, 17),
+    (0x30E7, 0x30E7, 15),
+    (0x30E8, 0x30ED, 17),
+    (0x30EE, 0x30EE, 15),
+    (0x30EF, 0x30F4, 17),
+    (0x30F5, 0x30F6, 15),
+    (0x30F7, 0x30FA, 17),
+    (0x30FB, 0x30FB, 8),
+    (0x30FC, 0x30FE, 15),
+    (0x30FF, 0x3191, 8),
+    (0x3192, 0x3195, 1),
+    (0x3196, 0x321F, 8),
+    (0x3220, 0x3229, 1),
+    (0x322A, 0x3247, 8),
+    (0x3248, 0x324F, 1),
+    (0x3250, 0x3250, 8),
+    (0x3251, 0x325F, 1),
+    (0x3260, 0x327F, 8),
+    (0x3280, 0x3289, 1),
+    (0x328A, 0x32B0, 8),
+    (0x32B1, 0x32BF, 1),
+    (0x32C0, 0x4DFF, 8),
+    (0x4E00, 0x9FA5, 17),
+    (0x9FA6, 0xA61F, 8),
+    (0xA620, 0xA629, 1),
+    (0xA62A, 0xA66E, 8),
+    (0xA66F, 0xA672, 7),
+    (0xA673, 0xA673, 8),
+    (0xA674, 0xA67D, 7),
+    (0xA67E, 0xA69D, 8),
+    (0xA69E, 0xA69F, 7),
+    (0xA6A0, 0xA6EF, 8),
+    (0xA6F0, 0xA6F1, 7),
+    (0xA6F2, 0xA801, 8),
+    (0xA802, 0xA802, 7),
+    (0xA803, 0xA805, 8),
+    (0xA806, 0xA806, 7),
+    (0xA807, 0xA80A, 8),
+    (0xA80B, 0xA80B, 7),
+    (0xA80C, 0xA824, 8),
+    (0xA825, 0xA826, 7),
+    (0xA827, 0xA82B, 8),
+    (0xA82C, 0xA82C, 7),
+    (0xA82D, 0xA82F, 8),
+    (0xA830, 0xA835, 1),
+    (0xA836, 0xA837, 8),
+    (0xA838, 0xA838, 16),
+    (0xA839, 0xA8C3, 8),
+    (0xA8C4, 0xA8C5, 7),
+    (0xA8C6, 0xA8CF, 8),
+    (0xA8D0, 0xA8D9, 1),
+    (0xA8DA, 0xA8DF, 8),
+    (0xA8E0, 0xA8F1, 7),
+    (0xA8F2, 0xA8FE, 8),
+    (0xA8FF, 0xA8FF, 7),
+    (0xA900, 0xA909, 1),
+    (0xA90A, 0xA925, 8),
+    (0xA926, 0xA92D, 7),
+    (0xA92E, 0xA946, 8),
+    (0xA947, 0xA951, 7),
+    (0xA952, 0xA97F, 8),
+    (0xA980, 0xA982, 7),
+    (0xA983, 0xA9B2, 8),
+    (0xA9B3, 0xA9B3, 7),
+    (0xA9B4, 0xA9B5, 8),
+    (0xA9B6, 0xA9B9, 7),
+    (0xA9BA, 0xA9BB, 8),
+    (0xA9BC, 0xA9BD, 7),
+    (0xA9BE, 0xA9CF, 8),
+    (0xA9D0, 0xA9D9, 1),
+    (0xA9DA, 0xA9E4, 8),
+    (0xA9E5, 0xA9E5, 7),
+    (0xA9E6, 0xA9EF, 8),
+    (0xA9F0, 0xA9F9, 1),
+    (0xA9FA, 0xAA28, 8),
+    (0xAA29, 0xAA2E, 7),
+    (0xAA2F, 0xAA30, 8),
+    (0xAA31, 0xAA32, 7),
+    (0xAA33, 0xAA34, 8),
+    (0xAA35, 0xAA36, 7),
+    (0xAA37, 0xAA42, 8),
+    (0xAA43, 0xAA43, 7),
+    (0xAA44, 0xAA4B, 8),
+    (0xAA4C, 0xAA4C, 7),
+    (0xAA4D, 0xAA4F, 8),
+    (0xAA50, 0xAA59, 1),
+    (0xAA5A, 0xAA7B, 8),
+    (0xAA7C, 0xAA7C, 7),
+    (0xAA7D, 0xAAAF, 8),
+    (0xAAB0, 0xAAB0, 7),
+    (0xAAB1, 0xAAB1, 8),
+    (0xAAB2, 0xAAB4, 7),
+    (0xAAB5, 0xAAB6, 8),
+    (0xAAB7, 0xAAB8, 7),
+    (0xAAB9, 0xAABD, 8),
+    (0xAABE, 0xAABF, 7),
+    (0xAAC0, 0xAAC0, 8),
+    (0xAAC1, 0xAAC1, 7),
+    (0xAAC2, 0xAAEB, 8),
+    (0xAAEC, 0xAAED, 7),
+    (0xAAEE, 0xAAF5, 8),
+    (0xAAF6, 0xAAF6, 7),
+    (0xAAF7, 0xABE4, 8),
+    (0xABE5, 0xABE5, 7),
+    (0xABE6, 0xABE7, 8),
+    (0xABE8, 0xABE8, 7),
+    (0xABE9, 0xABEC, 8),
+    (0xABED, 0xABED, 7),
+    (0xABEE, 0xABEF, 8),
+    (0xABF0, 0xABF9, 1),
+    (0xABFA, 0xABFF, 8),
+    (0xAC00, 0xD7A3, 17),
+    (0xD7A4, 0xF8FF, 8),
+    (0xF900, 0xFA2D, 17),
+    (0xFA2E, 0xFA2F, 8),
+    (0xFA30, 0xFA6A, 17),
+    (0xFA6B, 0xFB1D, 8),
+    (0xFB1E, 0xFB1E, 7),
+    (0xFB1F, 0xFD3D, 8),
+    (0xFD3E, 0xFD3E, 15),
+    (0xFD3F, 0xFD3F, 16),
+    (0xFD40, 0xFDFB, 8),
+    (0xFDFC, 0xFDFC, 16),
+    (0xFDFD, 0xFDFF, 8),
+    (0xFE00, 0xFE0F, 7),
+    (0xFE10, 0xFE16, 8),
+    (0xFE17, 0xFE17, 16),
+    (0xFE18, 0xFE18, 15),
+    (0xFE19, 0xFE1F, 8),
+    (0xFE20, 0xFE2F, 7),
+    (0xFE30, 0xFE30, 8),
+    (0xFE31, 0xFE32, 5),
+    (0xFE33, 0xFE34, 8),
+    (0xFE35, 0xFE35, 16),
+    (0xFE36, 0xFE36, 15),
+    (0xFE37, 0xFE37, 16),
+    (0xFE38, 0xFE38, 15),
+    (0xFE39, 0xFE39, 16),
+    (0xFE3A, 0xFE3A, 15),
+    (0xFE3B, 0xFE3B, 16),
+    (0xFE3C, 0xFE3C, 15),
+    (0xFE3D, 0xFE3D, 16),
+    (0xFE3E, 0xFE3E, 15),
+    (0xFE3F, 0xFE3F, 16),
+    (0xFE40, 0xFE40, 15),
+    (0xFE41, 0xFE41, 16),
+    (0xFE42, 0xFE42, 15),
+    (0xFE43, 0xFE43, 16),
+    (0xFE44, 0xFE44, 15),
+    (0xFE45, 0xFE46, 8),
+    (0xFE47, 0xFE47, 16),
+    (0xFE48, 0xFE48, 15),
+    (0xFE49, 0xFE57, 8),
+    (0xFE58, 0xFE58, 5),
+    (0xFE59, 0xFE59, 16),
+    (0xFE5A, 0xFE5A, 15),
+    (0xFE5B, 0xFE5B, 16),
+    (0xFE5C, 0xFE5C, 15),
+    (0xFE5D, 0xFE5D, 16),
+    (0xFE5E, 0xFE5E, 15),
+    (0xFE5F, 0xFE62, 8),
+    (0xFE63, 0xFE63, 5),
+    (0xFE64, 0xFE68, 8),
+    (0xFE69, 0xFE69, 16),
+    (0xFE6A, 0xFEFE, 8),
+    (0xFEFF, 0xFEFF, 13),
+    (0xFF00, 0xFF00, 8),
+    (0xFF01, 0xFF01, 15),
+    (0xFF02, 0xFF03, 8),
+    (0xFF04, 0xFF04, 16),
+    (0xFF05, 0xFF05, 15),
+    (0xFF06, 0xFF07, 8),
+    (0xFF08, 0xFF08, 16),
+    (0xFF09, 0xFF09, 15),
+    (0xFF0A, 0xFF0B, 8),
+    (0xFF0C, 0xFF0C, 15),
+    (0xFF0D, 0xFF0D, 5),
+    (0xFF0E, 0xFF0E, 15),
+    (0xFF0F, 0xFF0F, 8),
+    (0xFF10, 0xFF19, 1),
+    (0xFF1A, 0xFF1B, 15),
+    (0xFF1C, 0xFF1E, 8),
+    (0xFF1F, 0xFF1F, 15),
+    (0xFF20, 0xFF3A, 8),
+    (0xFF3B, 0xFF3B, 16),
+    (0xFF3C, 0xFF3C, 8),
+    (0xFF3D, 0xFF3D, 15),
+    (0xFF3E, 0xFF5A, 8),
+    (0xFF5B, 0xFF5B, 16),
+    (0xFF5C, 0xFF5C, 8),
+    (0xFF5D, 0xFF5D, 15),
+    (0xFF5E, 0xFF5E, 8),
+    (0xFF5F, 0xFF5F, 16),
+    (0xFF60, 0xFF60, 15),
+    (0xFF61, 0xFF61, 8),
+    (0xFF62, 0xFF62, 16),
+    (0xFF63, 0xFF63, 15),
+    (0xFF64, 0xFFDF, 8),
+    (0xFFE0, 0xFFE1, 16),
+    (0xFFE2, 0xFFE4, 8),
+    (0xFFE5, 0xFFE6, 16),
+    (0xFFE7, 0xFFF8, 8),
+    (0xFFF9, 0xFFFB, -1),
+    (0xFFFC, 0x10106, 8),
+    (0x10107, 0x10133, 1),
+    (0x10134, 0x10174, 8),
+    (0x10175, 0x10178, 1),
+    (0x10179, 0x10189, 8),
+    (0x1018A, 0x1018B, 1),
+    (0x1018C, 0x101FC, 8),
+    (0x101FD, 0x101FD, 7),
+    (0x101FE, 0x102DF, 8),
+    (0x102E0, 0x102E0, 7),
+    (0x102E1, 0x102FB, 1),
+    (0x102FC, 0x1031F, 8),
+    (0x10320, 0x10323, 1),
+    (0x10324, 0x10375, 8),
+    (0x10376, 0x1037A, 7),
+    (0x1037B, 0x1049F, 8),
+    (0x104A0, 0x104A9, 1),
+    (0x104AA, 0x10857, 8),
+    (0x10858, 0x1085F, 1),
+    (0x10860, 0x10878, 8),
+    (0x10879, 0x1087F, 1),
+    (0x10880, 0x108A6, 8),
+    (0x108A7, 0x108AF, 1),
+    (0x108B0, 0x108FA, 8),
+    (0x108FB, 0x108FF, 1),
+    (0x10900, 0x10915, 8),
+    (0x10916, 0x1091B, 1),
+    (0x1091C, 0x109BB, 8),
+    (0x109BC, 0x109BD, 1),
+    (0x109BE, 0x109BF, 8),
+    (0x109C0, 0x109CF, 1),
+    (0x109D0, 0x109D1, 8),
+    (0x109D2, 0x109FF, 1),
+    (0x10A00, 0x10A00, 8),
+    (0x10A01, 0x10A03, 7),
+    (0x10A04, 0x10A04, 8),
+    (0x10A05, 0x10A06, 7),
+    (0x10A07, 0x10A0B, 8),
+    (0x10A0C, 0x10A0F, 7),
+    (0x10A10, 0x10A37, 8),
+    (0x10A38, 0x10A3A, 7),
+    (0x10A3B, 0x10A3E, 8),
+    (0x10A3F, 0x10A3F, 7),
+    (0x10A40, 0x10A48, 1),
+    (0x10A49, 0x10A7C, 8),
+    (0x10A7D, 0x10A7E, 1),
+    (0x10A7F, 0x10A9C, 8),
+    (0x10A9D, 0x10A9F, 1),
+    (0x10AA0, 0x10AE4, 8),
+    (0x10AE5, 0x10AE6, 7),
+    (0x10AE7, 0x10AEA, 8),
+    (0x10AEB, 0x10AEF, 1),
+    (0x10AF0, 0x10B57, 8),
+    (0x10B58, 0x10B5F, 1),
+    (0x10B60, 0x10B77, 8),
+    (0x10B78, 0x10B7F, 1),
+    (0x10B80, 0x10BA8, 8),
+    (0x10BA9, 0x10BAF, 1),
+    (0x10BB0, 0x10CF9, 8),
+    (0x10CFA, 0x10CFF, 1),
+    (0x10D00, 0x10D23, 8),
+    (0x10D24, 0x10D27, 7),
+    (0x10D28, 0x10D2F, 8),
+    (0x10D30, 0x10D39, 1),
+    (0x10D3A, 0x10E5F, 8),
+    (0x10E60, 0x10E7E, 1),
+    (0x10E7F, 0x10EAA, 8),
+    (0x10EAB, 0x10EAC, 7),
+    (0x10EAD, 0x10EAD, 5),
+    (0x10EAE, 0x10F1C, 8),
+    (0x10F1D, 0x10F26, 1),
+    (0x10F27, 0x10F45, 8),
+    (0x10F46, 0x10F50, 7),
+    (0x10F51, 0x10F54, 1),
+    (0x10F55, 0x10FC4, 8),
+    (0x10FC5, 0x10FCB, 1),
+    (0x10FCC, 0x11000, 8),
+    (0x11001, 0x11001, 7),
+    (0x11002, 0x11037, 8),
+    (0x11038, 0x11046, 7),
+    (0x11047, 0x11051, 8),
+    (0x11052, 0x1106F, 1),
+    (0x11070, 0x1107E, 8),
+    (0x1107F, 0x11081, 7),
+    (0x11082, 0x110B2, 8),
+    (0x110B3, 0x110B6, 7),
+    (0x110B7, 0x110B8, 8),
+    (0x110B9, 0x110BA, 7),
+    (0x110BB, 0x110BC, 8),
+    (0x110BD, 0x110BD, 0),
+    (0x110BE, 0x110CC, 8),
+    (0x110CD, 0x110CD, 0),
+    (0x110CE, 0x110EF, 8),
+    (0x110F0, 0x110F9, 1),
+    (0x110FA, 0x110FF, 8),
+    (0x11100, 0x11102, 7),
+    (0x11103, 0x11126, 8),
+    (0x11127, 0x1112B, 7),
+    (0x1112C, 0x1112C, 8),
+    (0x1112D, 0x11134, 7),
+    (0x11135, 0x11135, 8),
+    (0x11136, 0x1113F, 1),
+    (0x11140, 0x11172, 8),
+    (0x11173, 0x11173, 7),
+    (0x11174, 0x1117F, 8),
+    (0x11180, 0x11181, 7),
+    (0x11182, 0x111B5, 8),
+    (0x111B6, 0x111BE, 7),
+    (0x111BF, 0x111C8, 8),
+    (0x111C9, 0x111CC, 7),
+    (0x111CD, 0x111CE, 8),
+    (0x111CF, 0x111CF, 7),
+    (0x111D0, 0x111D9, 1),
+    (0x111DA, 0x111E0, 8),
+    (0x111E1, 0x111F4, 1),
+    (0x111F5, 0x1122E, 8),
+    (0x1122F, 0x11231, 7),
+    (0x11232, 0x11233, 8),
+    (0x11234, 0x11234, 7),
+    (0x11235, 0x11235, 8),
+    (0x11236, 0x11237, 7),
+    (0x11238, 0x1123D, 8),
+    (0x1123E, 0x1123E, 7),
+    (0x1123F, 0x112DE, 8),
+    (0x112DF, 0x112DF, 7),
+    (0x112E0, 0x112E2, 8),
+    (0x112E3, 0x112EA, 7),
+    (0x112EB, 0x112EF, 8),
+    (0x112F0, 0x112F9, 1),
+    (0x112FA, 0x112FF, 8),
+    (0x11300, 0x11301, 7),
+    (0x11302, 0x1133A, 8),
+    (0x1133B, 0x1133C, 7),
+    (0x1133D, 0x1133F, 8),
+    (0x11340, 0x11340, 7),
+    (0x11341, 0x11365, 8),
+    (0x11366, 0x1136C, 7),
+    (0x1136D, 0x1136F, 8),
+    (0x11370, 0x11374, 7),
+    (0x11375, 0x11437, 8),
+    (0x11438, 0x1143F, 7),
+    (0x11440, 0x11441, 8),
+    (0x11442, 0x11444, 7),
+    (0x11445, 0x11445, 8),
+    (0x11446, 0x11446, 7),
+    (0x11447, 0x1144F, 8),
+    (0x11450, 0x11459, 1),
+    (0x1145A, 0x1145D, 8),
+    (0x1145E, 0x1145E, 7),
+    (0x1145F, 0x114B2, 8),
+    (0x114B3, 0x114B8, 7),
+    (0x114B9, 0x114B9, 8),
+    (0x114BA, 0x114BA, 7),
+    (0x114BB, 0x114BE, 8),
+    (0x114BF, 0x114C0, 7),
+    (0x114C1, 0x114C1, 8),
+    (0x114C2, 0x114C3, 7),
+    (0x114C4, 0x114CF, 8),
+    (0x114D0, 0x114D9, 1),
+    (0x114DA, 0x115B1, 8),
+    (0x115B2, 0x115B5, 7),
+    (0x115B6, 0x115BB, 8),
+    (0x115BC, 0x115BD, 7),
+    (0x115BE, 0x115BE, 8),
+    (0x115BF, 0x115C0, 7),
+    (0x115C1, 0x115DB, 8),
+    (0x115DC, 0x115DD, 7),
+    (0x115DE, 0x11632, 8),
+    (0x11633, 0x1163A, 7),
+    (0x1163B, 0x1163C, 8),
+    (0x1163D, 0x1163D, 7),
+    (0x1163E, 0x1163E, 8),
+    (0x1163F, 0x11640, 7),
+    (0x11641, 0x1164F, 8),
+    (0x11650, 0x11659, 1),
+    (0x1165A, 0x116AA, 8),
+    (0x116AB, 0x116AB, 7),
+    (0x116AC, 0x116AC, 8),
+    (0x116AD, 0x116AD, 7),
+    (0x116AE, 0x116AF, 8),
+    (0x116B0, 0x116B5, 7),
+    (0x116B6, 0x116B6, 8),
+    (0x116B7, 0x116B7, 7),
+    (0x116B8, 0x116BF, 8),
+    (0x116C0, 0x116C9, 1),
+    (0x116CA, 0x1171C, 8),
+    (0x1171D, 0x1171F, 7),
+    (0x11720, 0x11721, 8),
+    (0x11722, 0x11725, 7),
+    (0x11726, 0x11726, 8),
+    (0x11727, 0x1172B, 7),
+    (0x1172C, 0x1172F, 8),
+    (0x11730, 0x1173B, 1),
+    (0x1173C, 0x1182E, 8),
+    (0x1182F, 0x11837, 7),
+    (0x11838, 0x11838, 8),
+    (0x11839, 0x1183A, 7),
+    (0x1183B, 0x118DF, 8),
+    (0x118E0, 0x118F2, 1),
+    (0x118F3, 0x1193A, 8),
+    (0x1193B, 0x1193C, 7),
+    (0x1193D, 0x1193D, 8),
+    (0x1193E, 0x1193E, 7),
+    (0x1193F, 0x11942, 8),
+    (0x11943, 0x11943, 7),
+    (0x11944, 0x1194F, 8),
+    (0x11950, 0x11959, 1),
+    (0x1195A, 0x119D3, 8),
+    (0x119D4, 0x119D7, 7),
+    (0x119D8, 0x119D9, 8),
+    (0x119DA, 0x119DB, 7),
+    (0x119DC, 0x119DF, 8),
+    (0x119E0, 0x119E0, 7),
+    (0x119E1, 0x11A00, 8),
+    (0x11A01, 0x11A0A, 7),
+    (0x11A0B, 0x11A32, 8),
+    (0x11A33, 0x11A38, 7),
+    (0x11A39, 0x11A3A, 8),
+    (0x11A3B, 0x11A3E, 7),
+    (0x11A3F, 0x11A46, 8),
+    (0x11A47, 0x11A47, 7),
+    (0x11A48, 0x11A50, 8),
+    (0x11A51, 0x11A56, 7),
+    (0x11A57, 0x11A58, 8),
+    (0x11A59, 0x11A5B, 7),
+    (0x11A5C, 0x11A89, 8),
+    (0x11A8A, 0x11A96, 7),
+    (0x11A97, 0x11A97, 8),
+    (0x11A98, 0x11A99, 7),
+    (0x11A9A, 0x11C2F, 8),
+    (0x11C30, 0x11C36, 7),
+    (0x11C37, 0x11C37, 8),
+    (0x11C38, 0x11C3D, 7),
+    (0x11C3E, 0x11C3E, 8),
+    (0x11C3F, 0x11C3F, 7),
+    (0x11C40, 0x11C4F, 8),
+    (0x11C50, 0x11C6C, 1),
+    (0x11C6D, 0x11C91, 8),
+    (0x11C92, 0x11CA7, 7),
+    (0x11CA8, 0x11CA9, 8),
+    (0x11CAA, 0x11CB0, 7),
+    (0x11CB1, 0x11CB1, 8),
+    (0x11CB2, 0x11CB3, 7),
+    (0x11CB4, 0x11CB4, 8),
+    (0x11CB5, 0x11CB6, 7),
+    (0x11CB7, 0x11D30, 8),
+    (0x11D31, 0x11D36, 7),
+    (0x11D37, 0x11D39, 8),
+    (0x11D3A, 0x11D3A, 7),
+    (0x11D3B, 0x11D3B, 8),
+    (0x11D3C, 0x11D3D, 7),
+    (0x11D3E, 0x11D3E, 8),
+    (0x11D3F, 0x11D45, 7),
+    (0x11D46, 0x11D46, 8),
+    (0x11D47, 0x11D47, 7),
+    (0x11D48, 0x11D4F, 8),
+    (0x11D50, 0x11D59, 1),
+    (0x11D5A, 0x11D8F, 8),
+    (0x11D90, 0x11D91, 7),
+    (0x11D92, 0x11D94, 8),
+    (0x11D95, 0x11D95, 7),
+    (0x11D96, 0x11D96, 8),
+    (0x11D97, 0x11D97, 7),
+    (0x11D98, 0x11D9F, 8),
+    (0x11DA0, 0x11DA9, 1),
+    (0x11DAA, 0x11EF2, 8),
+    (0x11EF3, 0x11EF4, 7),
+    (0x11EF5, 0x11FBF, 8),
+    (0x11FC0, 0x11FD4, 1),
+    (0x11FD5, 0x11FDC, 8),
+    (0x11FDD, 0x11FE0, 16),
+    (0x11FE1, 0x1342F, 8),
+    (0x13430, 0x13437, -1),
+    (0x13438, 0x13438, 0),
+    (0x13439, 0x16A5F, 8),
+    (0x16A60, 0x16A69, 1),
+    (0x16A6A, 0x16AEF, 8),
+    (0x16AF0, 0x16AF4, 7),
+    (0x16AF5, 0x16B2F, 8),
+    (0x16B30, 0x16B36, 7),
+    (0x16B37, 0x16B4F, 8),
+    (0x16B50, 0x16B59, 1),
+    (0x16B5A, 0x16B5A, 8),
+    (0x16B5B, 0x16B61, 1),
+    (0x16B62, 0x16E7F, 8),
+    (0x16E80, 0x16E96, 1),
+    (0x16E97, 0x16F4E, 8),
+    (0x16F4F, 0x16F4F, 7),
+    (0x16F50, 0x16F8E, 8),
+    (0x16F8F, 0x16F92, 7),
+    (0x16F93, 0x16FE3, 8),
+    (0x16FE4, 0x16FE4, 7),
+    (0x16FE5, 0x1BC9C, 8),
+    (0x1BC9D, 0x1BC9E, 7),
+    (0x1BC9F, 0x1BC9F, 8),
+    (0x1BCA0, 0x1BCA2, -1),
+    (0x1BCA3, 0x1BCA3, 0),
+    (0x1BCA4, 0x1D166, 8),
+    (0x1D167, 0x1D169, 7),
+    (0x1D16A, 0x1D172, 8),
+    (0x1D173, 0x1D179, -1),
+    (0x1D17A, 0x1D17A, 0),
+    (0x1D17B, 0x1D182, 7),
+    (0x1D183, 0x1D184, 8),
+    (0x1D185, 0x1D18B, 7),
+    (0x1D18C, 0x1D1A9, 8),
+    (0x1D1AA, 0x1D1AD, 7),
+    (0x1D1AE, 0x1D241, 8),
+    (0x1D242, 0x1D244, 7),
+    (0x1D245, 0x1D2DF, 8),
+    (0x1D2E0, 0x1D2F3, 1),
+    (0x1D2F4, 0x1D35F, 8),
+    (0x1D360, 0x1D378, 1),
+    (0x1D379, 0x1D7CD, 8),
+    (0x1D7CE, 0x1D7FF, 1),
+    (0x1D800, 0x1D9FF, 8),
+    (0x1DA00, 0x1DA36, 7),
+    (0x1DA37, 0x1DA3A, 8),
+    (0x1DA3B, 0x1DA6C, 7),
+    (0x1DA6D, 0x1DA74, 8),
+    (0x1DA75, 0x1DA75, 7),
+    (0x1DA76, 0x1DA83, 8),
+    (0x1DA84, 0x1DA84, 7),
+    (0x1DA85, 0x1DA9A, 8),
+    (0x1DA9B, 0x1DA9F, 7),
+    (0x1DAA0, 0x1DAA0, 8),
+    (0x1DAA1, 0x1DAAF, 7),
+    (0x1DAB0, 0x1DFFF, 8),
+    (0x1E000, 0x1E006, 7),
+    (0x1E007, 0x1E007, 8),
+    (0x1E008, 0x1E018, 7),
+    (0x1E019, 0x1E01A, 8),
+    (0x1E01B, 0x1E021, 7),
+    (0x1E022, 0x1E022, 8),
+    (0x1E023, 0x1E024, 7),
+    (0x1E025, 0x1E025, 8),
+    (0x1E026, 0x1E02A, 7),
+    (0x1E02B, 0x1E12F, 8),
+    (0x1E130, 0x1E136, 7),
+    (0x1E137, 0x1E13F, 8),
+    (0x1E140, 0x1E149, 1),
+    (0x1E14A, 0x1E2EB, 8),
+    (0x1E2EC, 0x1E2EF, 7),
+    (0x1E2F0, 0x1E2F9, 1),
+    (0x1E2FA, 0x1E2FE, 8),
+    (0x1E2FF, 0x1E2FF, 16),
+    (0x1E300, 0x1E8C6, 8),
+    (0x1E8C7, 0x1E8CF, 1),
+    (0x1E8D0, 0x1E8D6, 7),
+    (0x1E8D7, 0x1E943, 8),
+    (0x1E944, 0x1E94A, 7),
+    (0x1E94B, 0x1E94F, 8),
+    (0x1E950, 0x1E959, 1),
+    (0x1E95A, 0x1EC70, 8),
+    (0x1EC71, 0x1ECAB, 1),
+    (0x1ECAC, 0x1ECAC, 8),
+    (0x1ECAD, 0x1ECAF, 1),
+    (0x1ECB0, 0x1ECB0, 16),
+    (0x1ECB1, 0x1ECB4, 1),
+    (0x1ECB5, 0x1ED00, 8),
+    (0x1ED01, 0x1ED2D, 1),
+    (0x1ED2E, 0x1ED2E, 8),
+    (0x1ED2F, 0x1ED3D, 1),
+    (0x1ED3E, 0x1F0FF, 8),
+    (0x1F100, 0x1F10C, 1),
+    (0x1F10D, 0x1FBEF, 8),
+    (0x1FBF0, 0x1FBF9, 1),
+    (0x1FBFA, 0xE0000, 8),
+    (0xE0001, 0xE0001, 0),
+    (0xE0002, 0xE001F, 8),
+    (0xE0020, 0xE007E, -1),
+    (0xE007F, 0xE007F, 0),
+    (0xE0080, 0xE00FF, 8),
+    (0xE0100, 0xE01EF, 7),
+    (0xE01F0, 0x10FFFF, 8),
+];
+
+pub(super) static LINE: Rules = Rules {
+    categories: &LINE_CATEGORIES,
+    num_categories: 18,
+    state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 6, 1, 1, 40, 8, 9, 2, 2, 9, 14, 9, 34, 11, 41, 9, 1, 3,
+        0, 2, 35, 9, 40, 7, 9, 2, 2, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 0, 35, 9, 40, 7, 9, 39, 0, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 5, 4, 4, 0, 4, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 4, 0,
+        0, 5, 35, 9, 40, 7, 10, 39, 0, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 6, 35, 9, 40, 7, 10, 2, 2, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 0, 35, 0, 40, 7, 0, 39, 0, 39, 12, 0, 34, 11, 41, 0, 0, 0,
+        0, 5, 36, 4, 40, 8, 0, 39, 0, 39, 13, 0, 34, 11, 41, 0, 4, 0,
+        0, 0, 35, 9, 40, 0, 9, 39, 0, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 5, 35, 9, 40, 0, 9, 39, 0, 9, 12, 9, 34, 11, 41, 9, 0, 0,
+        0, 6, 12, 12, 40, 15, 9, 2, 2, 9, 27, 9, 12, 11, 41, 9, 12, 3,
+        0, 6, 12, 12, 40, 15, 9, 2, 2, 9, 27, 9, 34, 11, 41, 9, 12, 3,
+        0, 6, 13, 13, 40, 16, 9, 2, 2, 9, 25, 9, 34, 11, 41, 9, 13, 3,
+        0, 6, 14, 14, 40, 17, 9, 2, 2, 9, 28, 9, 34, 11, 41, 9, 14, 3,
+        0, 5, 36, 4, 40, 15, 0, 39, 0, 39, 20, 0, 34, 11, 41, 0, 4, 0,
+        0, 5, 36, 4, 40, 16, 0, 39, 0, 39, 21, 0, 34, 11, 41, 0, 4, 0,
+        0, 5, 36, 4, 40, 18, 0, 39, 0, 39, 24, 0, 34, 11, 41, 0, 4, 0,
+        0, 5, 36, 19, 40, 18, 10, 39, 0, 9, 24, 9, 34, 11, 41, 9, 4, 0,
+        0, 5, 36, 19, 40, 4, 9, 39, 0, 9, 21, 9, 34, 11, 41, 9, 4, 0,
+        0, 6, 12, 20, 40, 15, 9, 2, 2, 9, 12, 9, 33, 0, 41, 9, 12, 3,
+        0, 6, 21, 21, 40, 22, 9, 2, 2, 9, 21, 9, 33, 0, 41, 9, 21, 3,
+        0, 5, 36, 4, 40, 23, 0, 39, 0, 39, 21, 0, 33, 0, 41, 0, 4, 0,
+        0, 5, 36, 4, 40, 23, 0, 39, 0, 39, 4, 0, 33, 0, 41, 0, 4, 0,
+        0, 6, 25, 25, 40, 26, 10, 2, 2, 9, 25, 9, 33, 0, 41, 9, 25, 3,
+        0, 6, 25, 25, 40, 26, 9, 2, 2, 9, 25, 9, 33, 0, 41, 9, 25, 3,
+        0, 5, 36, 4, 40, 23, 0, 39, 0, 39, 21, 0, 33, 0, 41, 0, 4, 0,
+        0, 6, 12, 12, 40, 15, 9, 2, 2, 9, 12, 9, 33, 0, 41, 9, 12, 3,
+        0, 6, 28, 28, 40, 29, 9, 2, 2, 9, 28, 9, 34, 11, 41, 9, 28, 3,
+        0, 5, 36, 4, 40, 30, 0, 39, 0, 39, 32, 0, 34, 11, 41, 0, 4, 0,
+        0, 5, 36, 31, 40, 30, 10, 39, 0, 9, 32, 9, 34, 11, 41, 9, 4, 0,
+        0, 5, 36, 31, 40, 4, 9, 39, 0, 9, 4, 9, 33, 0, 41, 9, 4, 0,
+        0, 6, 25, 25, 40, 26, 10, 2, 2, 9, 25, 9, 33, 0, 41, 9, 25, 3,
+        0, 0, 35, 0, 40, 0, 0, 39, 0, 39, 0, 0, 33, 0, 41, 0, 0, 0,
+        0, 0, 35, 0, 40, 0, 0, 39, 0, 39, 0, 0, 34, 11, 41, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0,
+        0, 5, 4, 4, 0, 4, 0, 0, 0, 0, 4, 37, 0, 0, 0, 0, 4, 0,
+        0, 0, 0, 37, 40, 0, 37, 38, 0, 37, 0, 37, 0, 0, 41, 37, 0, 0,
+        0, 0, 0, 0, 40, 0, 0, 38, 0, 38, 0, 0, 0, 0, 41, 0, 0, 0,
+        0, 0, 35, 0, 40, 0, 0, 39, 0, 39, 0, 0, 0, 0, 41, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    backwards_state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 3, 4, 4, 4, 5, 4, 4, 6, 4, 4, 4, 4, 4, 7, 4, 8, 9,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 3, 4, 4, 0, 5, 4, 4, 6, 0, 4, 0, 4, 4, 0, 0, 8, 0,
+        0, 3, 4, 4, 0, 5, 4, 4, 6, 4, 4, 4, 4, 4, 0, 4, 8, 9,
+        0, 3, 4, 4, 0, 5, 0, 4, 6, 0, 4, 0, 4, 4, 0, 0, 8, 9,
+        0, 3, 4, 4, 0, 0, 0, 4, 6, 0, 4, 0, 4, 4, 0, 0, 8, 0,
+        0, 3, 4, 4, 4, 5, 4, 4, 6, 4, 4, 4, 4, 4, 0, 4, 8, 9,
+        0, 0, 4, 4, 0, 5, 0, 0, 0, 0, 4, 0, 4, 4, 0, 0, 8, 0,
+        0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 4, 0, 4, 4, 0, 0, 8, 0,
+    ],
+    end_states: &[false, true, true, true, false, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, true, false, false, true, true, true, true, true],
+    lookahead_states: &[false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false, false],
+};
+
+pub(super) static SENTENCE_CATEGORIES: [(u32, u32, i8); 3425] = [
+    (0x0000, 0x0008, 8),
+    (0x0009, 0x000A, 12),
+    (0x000B, 0x000B, 8),
+    (0x000C, 0x000D, 12),
+    (0x000E, 0x001F, 8),
+    (0x0020, 0x0020, 12),
+    (0x0021, 0x0021, 4),
+    (0x0022, 0x0022, 3),
+    (0x0023, 0x0026, 8),
+    (0x0027, 0x0027, 3),
+    (0x0028, 0x0028, 11),
+    (0x0029, 0x0029, 10),
+    (0x002A, 0x002B, 8),
+    (0x002C, 0x002C, 0),
+    (0x002D, 0x002D, 8),
+    (0x002E, 0x002E, 2),
+    (0x002F, 0x002F, 8),
+    (0x0030, 0x0039, 9),
+    (0x003A, 0x003E, 8),
+    (0x003F, 0x003F, 4),
+    (0x0040, 0x0040, 8),
+    (0x0041, 0x005A, 5),
+    (0x005B, 0x005B, 11),
+    (0x005C, 0x005C, 8),
+    (0x005D, 0x005D, 10),
+    (0x005E, 0x0060, 8),
+    (0x0061, 0x007A, 6),
+    (0x007B, 0x007B, 11),
+    (0x007C, 0x007C, 8),
+    (0x007D, 0x007D, 10),
+    (0x007E, 0x009F, 8),
+    (0x00A0, 0x00A0, 12),
+    (0x00A1, 0x00A9, 8),
+    (0x00AA, 0x00AA, 5),
+    (0x00AB, 0x00AB, 11),
+    (0x00AC, 0x00AC, 8),
+    (0x00AD, 0x00AD, -1),
+    (0x00AE, 0x00B1, 8),
+    (0x00B2, 0x00B3, 9),
+    (0x00B4, 0x00B4, 8),
+    (0x00B5, 0x00B5, 6),
+    (0x00B6, 0x00B8, 8),
+    (0x00B9, 0x00B9, 9),
+    (0x00BA, 0x00BA, 5),
+    (0x00BB, 0x00BB, 10),
+    (0x00BC, 0x00BE, 9),
+    (0x00BF, 0x00BF, 8),
+    (0x00C0, 0x00D6, 5),
+    (0x00D7, 0x00D7, 8),
+    (0x00D8, 0x00DE, 5),
+    (0x00DF, 0x00F6, 6),
+    (0x00F7, 0x00F7, 8),
+    (0x00F8, 0x00FF, 6),
+    (0x0100, 0x0100, 5),
+    (0x0101, 0x0101, 6),
+    (0x0102, 0x0102, 5),
+    (0x0103, 0x0103, 6),
+    (0x0104, 0x0104, 5),
+    (0x0105, 0x0105, 6),
+    (0x0106, 0x0106, 5),
+    (0x0107, 0x0107, 6),
+    (0x0108, 0x0108, 5),
+    (0x0109, 0x0109, 6),
+    (0x010A, 0x010A, 5),
+    (0x010B, 0x010B, 6),
+    (0x010C, 0x010C, 5),
+    (0x010D, 0x010D, 6),
+    (0x010E, 0x010E, 5),
+    (0x010F, 0x010F, 6),
+    (0x0110, 0x0110, 5),
+    (0x0111, 0x0111, 6),
+    (0x0112, 0x0112, 5),
+    (0x0113, 0x0113, 6),
+    (0x0114, 0x0114, 5),
+    (0x0115, 0x0115, 6),
+    (0x0116, 0x0116, 5),
+    (0x0117, 0x0117, 6),
+    (0x0118, 0x0118, 5),
+    (0x0119, 0x0119, 6),
+    (0x011A, 0x011A, 5),
+    (0x011B, 0x011B, 6),
+    (0x011C, 0x011C, 5),
+    (0x011D, 0x011D, 6),
+    (0x011E, 0x011E, 5),
+    (0x011F, 0x011F, 6),
+    (0x0120, 0x0120, 5),
+    (0x0121, 0x0121, 6),
+    (0x0122, 0x0122, 5),
+    (0x0123, 0x0123, 6),
+    (0x0124, 0x0124, 5),
+    (0x0125, 0x0125, 6),
+    (0x0126, 0x0126, 5),
+    (0x0127, 0x0127, 6),
+    (0x0128, 0x0128, 5),
+    (0x0129, 0x0129, 6),
+    (0x012A, 0x012A, 5),
+    (0x012B, 0x012B, 6),
+    (0x012C, 0x012C, 5),
+    (0x012D, 0x012D, 6),
+    (0x012E, 0x012E, 5),
+    (0x012F, 0x012F, 6),
+    (0x0130, 0x0130, 5),
+    (0x0131, 0x0131, 6),
+    (0x0132, 0x0132, 5),
+    (0x0133, 0x0133, 6),
+    (0x0134, 0x0134, 5),
+    (0x0135, 0x0135, 6),
+    (0x0136, 0x0136, 5),
+    (0x0137, 0x0138, 6),
+    (0x0139, 0x0139, 5),
+    (0x013A, 0x013A, 6),
+    (0x013B, 0x013B, 5),
+    (0x013C, 0x013C, 6),
+    (0x013D, 0x013D, 5),
+    (0x013E, 0x013E, 6),
+    (0x013F, 0x013F, 5),
+    (0x0140, 0x0140, 6),
+    (0x0141, 0x0141, 5),
+    (0x0142, 0x0142, 6),
+    (0x0143, 0x0143, 5),
+    (0x0144, 0x0144, 6),
+    (0x0145, 0x0145, 5),
+    (0x0146, 0x0146, 6),
+    (0x0147, 0x0147, 5),
+    (0x0148, 0x0149, 6),
+    (0x014A, 0x014A, 5),
+    (0x014B, 0x014B, 6),
+    (0x014C, 0x014C, 5),
+    (0x014D, 0x014D, 6),
+    (0x014E, 0x014E, 5),
+    (0x014F, 0x014F, 6),
+    (0x0150, 0x0150, 5),
+    (0x0151, 0x0151, 6),
+    (0x0152, 0x0152, 5),
+    (0x0153, 0x0153, 6),
+    (0x0154, 0x0154, 5),
+    (0x0155, 0x0155, 6),
+    (0x0156, 0x0156, 5),
+    (0x0157, 0x0157, 6),
+    (0x0158, 0x0158, 5),
+    (0x0159, 0x0159, 6),
+    (0x015A, 0x015A, 5),
+    (0x015B, 0x015B, 6),
+    (0x015C, 0x015C, 5),
+    (0x015D, 0x015D, 6),
+    (0x015E, 0x015E, 5),
+    (0x015F, 0x015F, 6),
+    (0x0160, 0x0160, 5),
+    (0x0161, 0x0161, 6),
+    (0x0162, 0x0162, 5),
+    (0x0163, 0x0163, 6),
+    (0x0164, 0x0164, 5),
+    (0x0165, 0x0165, 6),
+    (0x0166, 0x0166, 5),
+    (0x0167, 0x0167, 6),
+    (0x0168, 0x0168, 5),
+    (0x0169, 0x0169, 6),
+    (0x016A, 0x016A, 5),
+    (0x016B, 0x016B, 6),
+    (0x016C, 0x016C, 5),
+    (0x016D, 0x016D, 6),
+    (0x016E, 0x016E, 5),
+    (0x016F, 0x016F, 6),
+    (0x0170, 0x0170, 5),
+    (0x0171, 0x0171, 6),
+    (0x0172, 0x0172, 5),
+    (0x0173, 0x0173, 6),
+    (0x0174, 0x0174, 5),
+    (0x0175, 0x0175, 6),
+    (0x0176, 0x0176, 5),
+    (0x0177, 0x0177, 6),
+    (0x0178, 0x0179, 5),
+    (0x017A, 0x017A, 6),
+    (0x017B, 0x017B, 5),
+    (0x017C, 0x017C, 6),
+    (0x017D, 0x017D, 5),
+    (0x017E, 0x0180, 6),
+    (0x0181, 0x0182, 5),
+    (0x0183, 0x0183, 6),
+    (0x0184, 0x0184, 5),
+    (0x0185, 0x0185, 6),
+    (0x0186, 0x0187, 5),
+    (0x0188, 0x0188, 6),
+    (0x0189, 0x018B, 5),
+    (0x018C, 0x018D, 6),
+    (0x018E, 0x0191, 5),
+    (0x0192, 0x0192, 6),
+    (0x0193, 0x0194, 5),
+    (0x0195, 0x0195, 6),
+    (0x0196, 0x0198, 5),
+    (0x0199, 0x019B, 6),
+    (0x019C, 0x019D, 5),
+    (0x019E, 0x019E, 6),
+    (0x019F, 0x01A0, 5),
+    (0x01A1, 0x01A1, 6),
+    (0x01A2, 0x01A2, 5),
+    (0x01A3, 0x01A3, 6),
+    (0x01A4, 0x01A4, 5),
+    (0x01A5, 0x01A5, 6),
+    (0x01A6, 0x01A7, 5),
+    (0x01A8, 0x01A8, 6),
+    (0x01A9, 0x01A9, 5),
+    (0x01AA, 0x01AB, 6),
+    (0x01AC, 0x01AC, 5),
+    (0x01AD, 0x01AD, 6),
+    (0x01AE, 0x01AF, 5),
+    (0x01B0, 0x01B0, 6),
+    (0x01B1, 0x01B3, 5),
+    (0x01B4, 0x01B4, 6),
+    (0x01B5, 0x01B5, 5),
+    (0x01B6, 0x01B6, 6),
+    (0x01B7, 0x01B8, 5),
+    (0x01B9, 0x01BA, 6),
+    (0x01BB, 0x01BC, 5),
+    (0x01BD, 0x01BF, 6),
+    (0x01C0, 0x01C5, 5),
+    (0x01C6, 0x01C6, 6),
+    (0x01C7, 0x01C8, 5),
+    (0x01C9, 0x01C9, 6),
+    (0x01CA, 0x01CB, 5),
+    (0x01CC, 0x01CC, 6),
+    (0x01CD, 0x01CD, 5),
+    (0x01CE, 0x01CE, 6),
+    (0x01CF, 0x01CF, 5),
+    (0x01D0, 0x01D0, 6),
+    (0x01D1, 0x01D1, 5),
+    (0x01D2, 0x01D2, 6),
+    (0x01D3, 0x01D3, 5),
+    (0x01D4, 0x01D4, 6),
+    (0x01D5, 0x01D5, 5),
+    (0x01D6, 0x01D6, 6),
+    (0x01D7, 0x01D7, 5),
+    (0x01D8, 0x01D8, 6),
+    (0x01D9, 0x01D9, 5),
+    (0x01DA, 0x01DA, 6),
+    (0x01DB, 0x01DB, 5),
+    (0x01DC, 0x01DD, 6),
+    (0x01DE, 0x01DE, 5),
+    (0x01DF, 0x01DF, 6),
+    (0x01E0, 0x01E0, 5),
+    (0x01E1, 0x01E1, 6),
+    (0x01E2, 0x01E2, 5),
+    (0x01E3, 0x01E3, 6),
+    (0x01E4, 0x01E4, 5),
+    (0x01E5, 0x01E5, 6),
+    (0x01E6, 0x01E6, 5),
+    (0x01E7, 0x01E7, 6),
+    (0x01E8, 0x01E8, 5),
+    (0x01E9, 0x01E9, 6),
+    (0x01EA, 0x01EA, 5),
+    (0x01EB, 0x01EB, 6),
+    (0x01EC, 0x01EC, 5),
+    (0x01ED, 0x01ED, 6),
+    (0x01EE, 0x01EE, 5),
+    (0x01EF, 0x01F0, 6),
+    (0x01F1, 0x01F2, 5),
+    (0x01F3, 0x01F3, 6),
+    (0x01F4, 0x01F4, 5),
+    (0x01F5, 0x01F5, 6),
+    (0x01F6, 0x01F8, 5),
+    (0x01F9, 0x01F9, 6),
+    (0x01FA, 0x01FA, 5),
+    (0x01FB, 0x01FB, 6),
+    (0x01FC, 0x01FC, 5),
+    (0x01FD, 0x01FD, 6),
+    (0x01FE, 0x01FE, 5),
+    (0x01FF, 0x01FF, 6),
+    (0x0200, 0x0200, 5),
+    (0x0201, 0x0201, 6),
+    (0x0202, 0x0202, 5),
+    (0x0203, 0x0203, 6),
+    (0x0204, 0x0204, 5),
+    (0x0205, 0x0205, 6),
+    (0x0206, 0x0206, 5),
+    (0x0207, 0x0207, 6),
+    (0x0208, 0x0208, 5),
+    (0x0209, 0x0209, 6),
+    (0x020A, 0x020A, 5),
+    (0x020B, 0x020B, 6),
+    (0x020C, 0x020C, 5),
+    (0x020D, 0x020D, 6),
+    (0x020E, 0x020E, 5),
+    (0x020F, 0x020F, 6),
+    (0x0210, 0x0210, 5),
+    (0x0211, 0x0211, 6),
+    (0x0212, 0x0212, 5),
+    (0x0213, 0x0213, 6),
+    (0x0214, 0x0214, 5),
+    (0x0215, 0x0215, 6),
+    (0x0216, 0x0216, 5),
+    (0x0217, 0x0217, 6),
+    (0x0218, 0x0218, 5),
+    (0x0219, 0x0219, 6),
+    (0x021A, 0x021A, 5),
+    (0x021B, 0x021B, 6),
+    (0x021C, 0x021C, 5),
+    (0x021D, 0x021D, 6),
+    (0x021E, 0x021E, 5),
+    (0x021F, 0x021F, 6),
+    (0x0220, 0x0220, 5),
+    (0x0221, 0x0221, 6),
+    (0x0222, 0x0222, 5),
+    (0x0223, 0x0223, 6),
+    (0x0224, 0x0224, 5),
+    (0x0225, 0x0225, 6),
+    (0x0226, 0x0226, 5),
+    (0x0227, 0x0227, 6),
+    (0x0228, 0x0228, 5),
+    (0x0229, 0x0229, 6),
+    (0x022A, 0x022A, 5),
+    (0x022B, 0x022B, 6),
+    (0x022C, 0x022C, 5),
+    (0x022D, 0x022D, 6),
+    (0x022E, 0x022E, 5),
+    (0x022F, 0x022F, 6),
+    (0x0230, 0x0230, 5),
+    (0x0231, 0x0231, 6),
+    (0x0232, 0x0232, 5),
+    (0x0233, 0x0239, 6),
+    (0x023A, 0x023B, 5),
+    (0x023C, 0x023C, 6),
+    (0x023D, 0x023E, 5),
+    (0x023F, 0x0240, 6),
+    (0x0241, 0x0241, 5),
+    (0x0242, 0x0242, 6),
+    (0x0243, 0x0246, 5),
+    (0x0247, 0x0247, 6),
+    (0x0248, 0x0248, 5),
+    (0x0249, 0x0249, 6),
+    (0x024A, 0x024A, 5),
+    (0x024B, 0x024B, 6),
+    (0x024C, 0x024C, 5),
+    (0x024D, 0x024D, 6),
+    (0x024E, 0x024E, 5),
+    (0x024F, 0x0293, 6),
+    (0x0294, 0x0294, 5),
+    (0x0295, 0x02AF, 6),
+    (0x02B0, 0x02C1, 5),
+    (0x02C2, 0x02C5, 8),
+    (0x02C6, 0x02D1, 5),
+    (0x02D2, 0x02DF, 8),
+    (0x02E0, 0x02E4, 5),
+    (0x02E5, 0x02EB, 8),
+    (0x02EC, 0x02EC, 5),
+    (0x02ED, 0x02ED, 8),
+    (0x02EE, 0x02EE, 5),
+    (0x02EF, 0x02FF, 8),
+    (0x0300, 0x036F, -1),
+    (0x0370, 0x0370, 5),
+    (0x0371, 0x0371, 6),
+    (0x0372, 0x0372, 5),
+    (0x0373, 0x0373, 6),
+    (0x0374, 0x0374, 5),
+    (0x0375, 0x0375, 8),
+    (0x0376, 0x0376, 5),
+    (0x0377, 0x0377, 6),
+    (0x0378, 0x0379, 8),
+    (0x037A, 0x037A, 5),
+    (0x037B, 0x037D, 6),
+    (0x037E, 0x037E, 8),
+    (0x037F, 0x037F, 5),
+    (0x0380, 0x0385, 8),
+    (0x0386, 0x0386, 5),
+    (0x0387, 0x0387, 8),
+    (0x0388, 0x038A, 5),
+    (0x038B, 0x038B, 8),
+    (0x038C, 0x038C, 5),
+    (0x038D, 0x038D, 8),
+    (0x038E, 0x038F, 5),
+    (0x0390, 0x0390, 6),
+    (0x0391, 0x03A1, 5),
+    (0x03A2, 0x03A2, 8),
+    (0x03A3, 0x03AB, 5),
+    (0x03AC, 0x03CE, 6),
+    (0x03CF, 0x03CF, 5),
+    (0x03D0, 0x03D1, 6),
+    (0x03D2, 0x03D4, 5),
+    (0x03D5, 0x03D7, 6),
+    (0x03D8, 0x03D8, 5),
+    (0x03D9, 0x03D9, 6),
+    (0x03DA, 0x03DA, 5),
+    (0x03DB, 0x03DB, 6),
+    (0x03DC, 0x03DC, 5),
+    (0x03DD, 0x03DD, 6),
+    (0x03DE, 0x03DE, 5),
+    (0x03DF, 0x03DF, 6),
+    (0x03E0, 0x03E0, 5),
+    (0x03E1, 0x03E1, 6),
+    (0x03E2, 0x03E2, 5),
+    (0x03E3, 0x03E3, 6),
+    (0x03E4, 0x03E4, 5),
+    (0x03E5, 0x03E5, 6),
+    (0x03E6, 0x03E6, 5),
+    (0x03E7, 0x03E7, 6),
+    (0x03E8, 0x03E8, 5),
+    (0x03E9, 0x03E9, 6),
+    (0x03EA, 0x03EA, 5),
+    (0x03EB, 0x03EB, 6),
+    (0x03EC, 0x03EC, 5),
+    (0x03ED, 0x03ED, 6),
+    (0x03EE, 0x03EE, 5),
+    (0x03EF, 0x03F3, 6),
+    (0x03F4, 0x03F4, 5),
+    (0x03F5, 0x03F5, 6),
+    (0x03F6, 0x03F6, 8),
+    (0x03F7, 0x03F7, 5),
+    (0x03F8, 0x03F8, 6),
+    (0x03F9, 0x03FA, 5),
+    (0x03FB, 0x03FC, 6),
+    (0x03FD, 0x042F, 5),
+    (0x0430, 0x045F, 6),
+    (0x0460, 0x0460, 5),
+    (0x0461, 0x0461, 6),
+    (0x0462, 0x0462, 5),
+    (0x0463, 0x0463, 6),
+    (0x0464, 0x0464, 5),
+    (0x0465, 0x0465, 6),
+    (0x0466, 0x0466, 5),
+    (0x0467, 0x0467, 6),
+    (0x0468, 0x0468, 5),
+    (0x0469, 0x0469, 6),
+    (0x046A, 0x046A, 5),
+    (0x046B, 0x046B, 6),
+    (0x046C, 0x046C, 5),
+    (0x046D, 0x046D, 6),
+    (0x046E, 0x046E, 5),
+    (0x046F, 0x046F, 6),
+    (0x0470, 0x0470, 5),
+    (0x0471, 0x0471, 6),
+    (0x0472, 0x0472, 5),
+    (0x0473, 0x0473, 6),
+    (0x0474, 0x0474, 5),
+    (0x0475, 0x0475, 6),
+    (0x0476, 0x0476, 5),
+    (0x0477, 0x0477, 6),
+    (0x0478, 0x0478, 5),
+    (0x0479, 0x0479, 6),
+    (0x047A, 0x047A, 5),
+    (0x047B, 0x047B, 6),
+    (0x047C, 0x047C, 5),
+    (0x047D, 0x047D, 6),
+    (0x047E, 0x047E, 5),
+    (0x047F, 0x047F, 6),
+    (0x0480, 0x0480, 5),
+    (0x0481, 0x0481, 6),
+    (0x0482, 0x0482, 8),
+    (0x0483, 0x0489, -1),
+    (0x048A, 0x048A, 5),
+    (0x048B, 0x048B, 6),
+    (0x048C, 0x048C, 5),
+    (0x048D, 0x048D, 6),
+    (0x048E, 0x048E, 5),
+    (0x048F, 0x048F, 6),
+    (0x0490, 0x0490, 5),
+    (0x0491, 0x0491, 6),
+    (0x0492, 0x0492, 5),
+    (0x0493, 0x0493, 6),
+    (0x0494, 0x0494, 5),
+    (0x0495, 0x0495, 6),
+    (0x0496, 0x0496, 5),
+    (0x0497, 0x0497, 6),
+    (0x0498, 0x0498, 5),
+    (0x0499, 0x0499, 6),
+    (0x049A, 0x049A, 5),
+    (0x049B, 0x049B, 6),
+    (0x049C, 0x049C, 5),
+    (0x049D, 0x049D, 6),
+    (0x049E, 0x049E, 5),
+    (0x049F, 0x049F, 6),
+    (0x04A0, 0x04A0, 5),
+    (0x04A1, 0x04A1, 6),
+    (0x04A2, 0x04A2, 5),
+    (0x04A3, 0x04A3, 6),
+    (0x04A4, 0x04A4, 5),
+    (0x04A5, 0x04A5, 6),
+    (0x04A6, 0x04A6, 5),
+    (0x04A7, 0x04A7, 6),
+    (0x04A8, 0x04A8, 5),
+    (0x04A9, 0x04A9, 6),
+    (0x04AA, 0x04AA, 5),
+    (0x04AB, 0x04AB, 6),
+    (0x04AC, 0x04AC, 5),
+    (0x04AD, 0x04AD, 6),
+    (0x04AE, 0x04AE, 5),
+    (0x04AF, 0x04AF, 6),
+    (0x04B0, 0x04B0, 5),
+    (0x04B1, 0x04B1, 6),
+    (0x04B2, 0x04B2, 5),
+    (0x04B3, 0x04B3, 6),
+    (0x04B4, 0x04B4, 5),
+    (0x04B5, 0x04B5, 6),
+    (0x04B6, 0x04B6, 5),
+    (0x04B7, 0x04B7, 6),
+    (0x04B8, 0x04B8, 5),
+    (0x04B9, 0x04B9, 6),
+    (0x04BA, 0x04BA, 5),
+    (0x04BB, 0x04BB, 6),
+    (0x04BC, 0x04BC, 5),
+    (0x04BD, 0x04BD, 6),
+    (0x04BE, 0x04BE, 5),
+    (0x04BF, 0x04BF, 6),
+    (0x04C0, 0x04C1, 5),
+    (0x04C2, 0x04C2, 6),
+    (0x04C3, 0x04C3, 5),
+    (0x04C4, 0x04C4, 6),
+    (0x04C5, 0x04C5, 5),
+    (0x04C6, 0x04C6, 6),
+    (0x04C7, 0x04C7, 5),
+    (0x04C8, 0x04C8, 6),
+    (0x04C9, 0x04C9, 5),
+    (0x04CA, 0x04CA, 6),
+    (0x04CB, 0x04CB, 5),
+    (0x04CC, 0x04CC, 6),
+    (0x04CD, 0x04CD, 5),
+    (0x04CE, 0x04CF, 6),
+    (0x04D0, 0x04D0, 5),
+    (0x04D1, 0x04D1, 6),
+    (0x04D2, 0x04D2, 5),
+    (0x04D3, 0x04D3, 6),
+    (0x04D4, 0x04D4, 5),
+    (0x04D5, 0x04D5, 6),
+    (0x04D6, 0x04D6, 5),
+    (0x04D7, 0x04D7, 6),
+    (0x04D8, 0x04D8, 5),
+    (0x04D9, 0x04D9, 6),
+    (0x04DA, 0x04DA, 5),
+    (0x04DB, 0x04DB, 6),
+    (0x04DC, 0x04DC, 5),
+    (0x04DD, 0x04DD, 6),
+    (0x04DE, 0x04DE, 5),
+    (0x04DF, 0x04DF, 6),
+    (0x04E0, 0x04E0, 5),
+    (0x04E1, 0x04E1, 6),
+    (0x04E2, 0x04E2, 5),
+    (0x04E3, 0x04E3, 6),
+    (0x04E4, 0x04E4, 5),
+    (0x04E5, 0x04E5, 6),
+    (0x04E6, 0x04E6, 5),
+    (0x04E7, 0x04E7, 6),
+    (0x04E8, 0x04E8, 5),
+    (0x04E9, 0x04E9, 6),
+    (0x04EA, 0x04EA, 5),
+    (0x04EB, 0x04EB, 6),
+    (0x04EC, 0x04EC, 5),
+    (0x04ED, 0x04ED, 6),
+    (0x04EE, 0x04EE, 5),
+    (0x04EF, 0x04EF, 6),
+    (0x04F0, 0x04F0, 5),
+    (0x04F1, 0x04F1, 6),
+    (0x04F2, 0x04F2, 5),
+    (0x04F3, 0x04F3, 6),
+    (0x04F4, 0x04F4, 5),
+    (0x04F5, 0x04F5, 6),
+    (0x04F6, 0x04F6, 5),
+    (0x04F7, 0x04F7, 6),
+    (0x04F8, 0x04F8, 5),
+    (0x04F9, 0x04F9, 6),
+    (0x04FA, 0x04FA, 5),
+    (0x04FB, 0x04FB, 6),
+    (0x04FC, 0x04FC, 5),
+    (0x04FD, 0x04FD, 6),
+    (0x04FE, 0x04FE, 5),
+    (0x04FF, 0x04FF, 6),
+    (0x0500, 0x0500, 5),
+    (0x0501, 0x0501, 6),
+    (0x0502, 0x0502, 5),
+    (0x0503, 0x0503, 6),
+    (0x0504, 0x0504, 5),
+    (0x0505, 0x0505, 6),
+    (0x0506, 0x0506, 5),
+    (0x0507, 0x0507, 6),
+    (0x0508, 0x0508, 5),
+    (0x0509, 0x0509, 6),
+    (0x050A, 0x050A, 5),
+    (0x050B, 0x050B, 6),
+    (0x050C, 0x050C, 5),
+    (0x050D, 0x050D, 6),
+    (0x050E, 0x050E, 5),
+    (0x050F, 0x050F, 6),
+    (0x0510, 0x0510, 5),
+    (0x0511, 0x0511, 6),
+    (0x0512, 0x0512, 5),
+    (0x0513, 0x0513, 6),
+    (0x0514, 0x0514, 5),
+    (0x0515, 0x0515, 6),
+    (0x0516, 0x0516, 5),
+    (0x0517, 0x0517, 6),
+    (0x0518, 0x0518, 5),
+    (0x0519, 0x0519, 6),
+    (0x051A, 0x051A, 5),
+    (0x051B, 0x051B, 6),
+    (0x051C, 0x051C, 5),
+    (0x051D, 0x051D, 6),
+    (0x051E, 0x051E, 5),
+    (0x051F, 0x051F, 6),
+    (0x0520, 0x0520, 5),
+    (0x0521, 0x0521, 6),
+    (0x0522, 0x0522, 5),
+    (0x0523, 0x0523, 6),
+    (0x0524, 0x0524, 5),
+    (0x0525, 0x0525, 6),
+    (0x0526, 0x0526, 5),
+    (0x0527, 0x0527, 6),
+    (0x0528, 0x0528, 5),
+    (0x0529, 0x0529, 6),
+    (0x052A, 0x052A, 5),
+    (0x052B, 0x052B, 6),
+    (0x052C, 0x052C, 5),
+    (0x052D, 0x052D, 6),
+    (0x052E, 0x052E, 5),
+    (0x052F, 0x052F, 6),
+    (0x0530, 0x0530, 8),
+    (0x0531, 0x0556, 5),
+    (0x0557, 0x0558, 8),
+    (0x0559, 0x0559, 5),
+    (0x055A, 0x055F, 8),
+    (0x0560, 0x0588, 6),
+    (0x0589, 0x0590, 8),
+    (0x0591, 0x05BD, -1),
+    (0x05BE, 0x05BE, 8),
+    (0x05BF, 0x05BF, -1),
+    (0x05C0, 0x05C0, 8),
+    (0x05C1, 0x05C2, -1),
+    (0x05C3, 0x05C3, 8),
+    (0x05C4, 0x05C5, -1),
+    (0x05C6, 0x05C6, 8),
+    (0x05C7, 0x05C7, -1),
+    (0x05C8, 0x05CF, 8),
+    (0x05D0, 0x05EA, 5),
+    (0x05EB, 0x05EE, 8),
+    (0x05EF, 0x05F2, 5),
+    (0x05F3, 0x05FF, 8),
+    (0x0600, 0x0605, -1),
+    (0x0606, 0x060F, 8),
+    (0x0610, 0x061A, -1),
+    (0x061B, 0x061B, 8),
+    (0x061C, 0x061C, -1),
+    (0x061D, 0x061F, 8),
+    (0x0620, 0x064A, 5),
+    (0x064B, 0x065F, -1),
+    (0x0660, 0x0669, 9),
+    (0x066A, 0x066D, 8),
+    (0x066E, 0x066F, 5),
+    (0x0670, 0x0670, -1),
+    (0x0671, 0x06D3, 5),
+    (0x06D4, 0x06D4, 8),
+    (0x06D5, 0x06D5, 5),
+    (0x06D6, 0x06DD, -1),
+    (0x06DE, 0x06DE, 8),
+    (0x06DF, 0x06E4, -1),
+    (0x06E5, 0x06E6, 5),
+    (0x06E7, 0x06E8, -1),
+    (0x06E9, 0x06E9, 8),
+    (0x06EA, 0x06ED, -1),
+    (0x06EE, 0x06EF, 5),
+    (0x06F0, 0x06F9, 9),
+    (0x06FA, 0x06FC, 5),
+    (0x06FD, 0x06FE, 8),
+    (0x06FF, 0x06FF, 5),
+    (0x0700, 0x070E, 8),
+    (0x070F, 0x070F, -1),
+    (0x0710, 0x0710, 5),
+    (0x0711, 0x0711, -1),
+    (0x0712, 0x072F, 5),
+    (0x0730, 0x074A, -1),
+    (0x074B, 0x074C, 8),
+    (0x074D, 0x07A5, 5),
+    (0x07A6, 0x07B0, -1),
+    (0x07B1, 0x07B1, 5),
+    (0x07B2, 0x07BF, 8),
+    (0x07C0, 0x07C9, 9),
+    (0x07CA, 0x07EA, 5),
+    (0x07EB, 0x07F3, -1),
+    (0x07F4, 0x07F5, 5),
+    (0x07F6, 0x07F9, 8),
+    (0x07FA, 0x07FA, 5),
+    (0x07FB, 0x07FC, 8),
+    (0x07FD, 0x07FD, -1),
+    (0x07FE, 0x07FF, 8),
+    (0x0800, 0x0815, 5),
+    (0x0816, 0x0819, -1),
+    (0x081A, 0x081A, 5),
+    (0x081B, 0x0823, -1),
+    (0x0824, 0x0824, 5),
+    (0x0825, 0x0827, -1),
+    (0x0828, 0x0828, 5),
+    (0x0829, 0x082D, -1),
+    (0x082E, 0x083F, 8),
+    (0x0840, 0x0858, 5),
+    (0x0859, 0x085B, -1),
+    (0x085C, 0x085F, 8),
+    (0x0860, 0x086A, 5),
+    (0x086B, 0x089F, 8),
+    (0x08A0, 0x08B4, 5),
+    (0x08B5, 0x08B5, 8),
+    (0x08B6, 0x08C7, 5),
+    (0x08C8, 0x08D2, 8),
+    (0x08D3, 0x0902, -1),
+    (0x0903, 0x0903, 8),
+    (0x0904, 0x0939, 5),
+    (0x093A, 0x093A, -1),
+    (0x093B, 0x093B, 8),
+    (0x093C, 0x093C, -1),
+    (0x093D, 0x093D, 5),
+    (0x093E, 0x0940, 8),
+    (0x0941, 0x0948, -1),
+    (0x0949, 0x094C, 8),
+    (0x094D, 0x094D, -1),
+    (0x094E, 0x094F, 8),
+    (0x0950, 0x0950, 5),
+    (0x0951, 0x0957, -1),
+    (0x0958, 0x0961, 5),
+    (0x0962, 0x0963, -1),
+    (0x0964, 0x0965, 7),
+    (0x0966, 0x096F, 9),
+    (0x0970, 0x0970, 8),
+    (0x0971, 0x0980, 5),
+    (0x0981, 0x0981, -1),
+    (0x0982, 0x0984, 8),
+    (0x0985, 0x098C, 5),
+    (0x098D, 0x098E, 8),
+    (0x098F, 0x0990, 5),
+    (0x0991, 0x0992, 8),
+    (0x0993, 0x09A8, 5),
+    (0x09A9, 0x09A9, 8),
+    (0x09AA, 0x09B0, 5),
+    (0x09B1, 0x09B1, 8),
+    (0x09B2, 0x09B2, 5),
+    (0x09B3, 0x09B5, 8),
+    (0x09B6, 0x09B9, 5),
+    (0x09BA, 0x09BB, 8),
+    (0x09BC, 0x09BC, -1),
+    (0x09BD, 0x09BD, 5),
+    (0x09BE, 0x09C0, 8),
+    (0x09C1, 0x09C4, -1),
+    (0x09C5, 0x09CC, 8),
+    (0x09CD, 0x09CD, -1),
+    (0x09CE, 0x09CE, 5),
+    (0x09CF, 0x09DB, 8),
+    (0x09DC, 0x09DD, 5),
+    (0x09DE, 0x09DE, 8),
+    (0x09DF, 0x09E1, 5),
+    (0x09E2, 0x09E3, -1),
+    (0x09E4, 0x09E5, 8),
+    (0x09E6, 0x09EF, 9),
+    (0x09F0, 0x09F1, 5),
+    (0x09F2, 0x09F3, 8),
+    (0x09F4, 0x09F9, 9),
+    (0x09FA, 0x09FB, 8),
+    (0x09FC, 0x09FC, 5),
+    (0x09FD, 0x09FD, 8),
+    (0x09FE, 0x09FE, -1),
+    (0x09FF, 0x0A00, 8),
+    (0x0A01, 0x0A02, -1),
+    (0x0A03, 0x0A04, 8),
+    (0x0A05, 0x0A0A, 5),
+    (0x0A0B, 0x0A0E, 8),
+    (0x0A0F, 0x0A10, 5),
+    (0x0A11, 0x0A12, 8),
+    (0x0A13, 0x0A28, 5),
+    (0x0A29, 0x0A29, 8),
+    (0x0A2A, 0x0A30, 5),
+    (0x0A31, 0x0A31, 8),
+    (0x0A32, 0x0A33, 5),
+    (0x0A34, 0x0A34, 8),
+    (0x0A35, 0x0A36, 5),
+    (0x0A37, 0x0A37, 8),
+    (0x0A38, 0x0A39, 5),
+    (0x0A3A, 0x0A3B, 8),
+    (0x0A3C, 0x0A3C, -1),
+    (0x0A3D, 0x0A40, 8),
+    (0x0A41, 0x0A42, -1),
+    (0x0A43, 0x0A46, 8),
+    (0x0A47, 0x0A48, -1),
+    (0x0A49, 0x0A4A, 8),
+    (0x0A4B, 0x0A4D, -1),
+    (0x0A4E, 0x0A50, 8),
+    (0x0A51, 0x0A51, -1),
+    (0x0A52, 0x0A58, 8),
+    (0x0A59, 0x0A5C, 5),
+    (0x0A5D, 0x0A5D, 8),
+    (0x0A5E, 0x0A5E, 5),
+    (0x0A5F, 0x0A65, 8),
+    (0x0A66, 0x0A6F, 9),
+    (0x0A70, 0x0A71, -1),
+    (0x0A72, 0x0A74, 5),
+    (0x0A75, 0x0A75, -1),
+    (0x0A76, 0x0A80, 8),
+    (0x0A81, 0x0A82, -1),
+    (0x0A83, 0x0A84, 8),
+    (0x0A85, 0x0A8D, 5),
+    (0x0A8E, 0x0A8E, 8),
+    (0x0A8F, 0x0A91, 5),
+    (0x0A92, 0x0A92, 8),
+    (0x0A93, 0x0AA8, 5),
+    (0x0AA9, 0x0AA9, 8),
+    (0x0AAA, 0x0AB0, 5),
+    (0x0AB1, 0x0AB1, 8),
+    (0x0AB2, 0x0AB3, 5),
+    (0x0AB4, 0x0AB4, 8),
+    (0x0AB5, 0x0AB9, 5),
+    (0x0ABA, 0x0ABB, 8),
+    (0x0ABC, 0x0ABC, -1),
+    (0x0ABD, 0x0ABD, 5),
+    (0x0ABE, 0x0AC0, 8),
+    (0x0AC1, 0x0AC5, -1),
+    (0x0AC6, 0x0AC6, 8),
+    (0x0AC7, 0x0AC8, -1),
+    (0x0AC9, 0x0ACC, 8),
+    (0x0ACD, 0x0ACD, -1),
+    (0x0ACE, 0x0ACF, 8),
+    (0x0AD0, 0x0AD0, 5),
+    (0x0AD1, 0x0ADF, 8),
+    (0x0AE0, 0x0AE1, 5),
+    (0x0AE2, 0x0AE3, -1),
+    (0x0AE4, 0x0AE5, 8),
+    (0x0AE6, 0x0AEF, 9),
+    (0x0AF0, 0x0AF8, 8),
+    (0x0AF9, 0x0AF9, 5),
+    (0x0AFA, 0x0AFF, -1),
+    (0x0B00, 0x0B00, 8),
+    (0x0B01, 0x0B01, -1),
+    (0x0B02, 0x0B04, 8),
+    (0x0B05, 0x0B0C, 5),
+    (0x0B0D, 0x0B0E, 8),
+    (0x0B0F, 0x0B10, 5),
+    (0x0B11, 0x0B12, 8),
+    (0x0B13, 0x0B28, 5),
+    (0x0B29, 0x0B29, 8),
+    (0x0B2A, 0x0B30, 5),
+    (0x0B31, 0x0B31, 8),
+    (0x0B32, 0x0B33, 5),
+    (0x0B34, 0x0B34, 8),
+    (0x0B35, 0x0B39, 5),
+    (0x0B3A, 0x0B3B, 8),
+    (0x0B3C, 0x0B3C, -1),
+    (0x0B3D, 0x0B3D, 5),
+    (0x0B3E, 0x0B3E, 8),
+    (0x0B3F, 0x0B3F, -1),
+    (0x0B40, 0x0B40, 8),
+    (0x0B41, 0x0B44, -1),
+    (0x0B45, 0x0B4C, 8),
+    (0x0B4D, 0x0B4D, -1),
+    (0x0B4E, 0x0B54, 8),
+    (0x0B55, 0x0B56, -1),
+    (0x0B57, 0x0B5B, 8),
+    (0x0B5C, 0x0B5D, 5),
+    (0x0B5E, 0x0B5E, 8),
+    (0x0B5F, 0x0B61, 5),
+    (0x0B62, 0x0B63, -1),
+    (0x0B64, 0x0B65, 8),
+    (0x0B66, 0x0B6F, 9),
+    (0x0B70, 0x0B70, 8),
+    (0x0B71, 0x0B71, 5),
+    (0x0B72, 0x0B77, 9),
+    (0x0B78, 0x0B81, 8),
+    (0x0B82, 0x0B82, -1),
+    (0x0B83, 0x0B83, 5),
+    (0x0B84, 0x0B84, 8),
+    (0x0B85, 0x0B8A, 5),
+    (0x0B8B, 0x0B8D, 8),
+    (0x0B8E, 0x0B90, 5),
+    (0x0B91, 0x0B91, 8),
+    (0x0B92, 0x0B95, 5),
+    (0x0B96, 0x0B98, 8),
+    (0x0B99, 0x0B9A, 5),
+    (0x0B9B, 0x0B9B, 8),
+    (0x0B9C, 0x0B9C, 5),
+    (0x0B9D, 0x0B9D, 8),
+    (0x0B9E, 0x0B9F, 5),
+    (0x0BA0, 0x0BA2, 8),
+    (0x0BA3, 0x0BA4, 5),
+    (0x0BA5, 0x0BA7, 8),
+    (0x0BA8, 0x0BAA, 5),
+    (0x0BAB, 0x0BAD, 8),
+    (0x0BAE, 0x0BB9, 5),
+    (0x0BBA, 0x0BBF, 8),
+    (0x0BC0, 0x0BC0, -1),
+    (0x0BC1, 0x0BCC, 8),
+    (0x0BCD, 0x0BCD, -1),
+    (0x0BCE, 0x0BCF, 8),
+    (0x0BD0, 0x0BD0, 5),
+    (0x0BD1, 0x0BE5, 8),
+    (0x0BE6, 0x0BF2, 9),
+    (0x0BF3, 0x0BFF, 8),
+    (0x0C00, 0x0C00, -1),
+    (0x0C01, 0x0C03, 8),
+    (0x0C04, 0x0C04, -1),
+    (0x0C05, 0x0C0C, 5),
+    (0x0C0D, 0x0C0D, 8),
+    (0x0C0E, 0x0C10, 5),
+    (0x0C11, 0x0C11, 8),
+    (0x0C12, 0x0C28, 5),
+    (0x0C29, 0x0C29, 8),
+    (0x0C2A, 0x0C39, 5),
+    (0x0C3A, 0x0C3C, 8),
+    (0x0C3D, 0x0C3D, 5),
+    (0x0C3E, 0x0C40, -1),
+    (0x0C41, 0x0C45, 8),
+    (0x0C46, 0x0C48, -1),
+    (0x0C49, 0x0C49, 8),
+    (0x0C4A, 0x0C4D, -1),
+    (0x0C4E, 0x0C54, 8),
+    (0x0C55, 0x0C56, -1),
+    (0x0C57, 0x0C57, 8),
+    (0x0C58, 0x0C5A, 5),
+    (0x0C5B, 0x0C5F, 8),
+    (0x0C60, 0x0C61, 5),
+    (0x0C62, 0x0C63, -1),
+    (0x0C64, 0x0C65, 8),
+    (0x0C66, 0x0C6F, 9),
+    (0x0C70, 0x0C77, 8),
+    (0x0C78, 0x0C7E, 9),
+    (0x0C7F, 0x0C7F, 8),
+    (0x0C80, 0x0C80, 5),
+    (0x0C81, 0x0C81, -1),
+    (0x0C82, 0x0C84, 8),
+    (0x0C85, 0x0C8C, 5),
+    (0x0C8D, 0x0C8D, 8),
+    (0x0C8E, 0x0C90, 5),
+    (0x0C91, 0x0C91, 8),
+    (0x0C92, 0x0CA8, 5),
+    (0x0CA9, 0x0CA9, 8),
+    (0x0CAA, 0x0CB3, 5),
+    (0x0CB4, 0x0CB4, 8),
+    (0x0CB5, 0x0CB9, 5),
+    (0x0CBA, 0x0CBB, 8),
+    (0x0CBC, 0x0CBC, -1),
+    (0x0CBD, 0x0CBD, 5),
+    (0x0CBE, 0x0CBE, 8),
+    (0x0CBF, 0x0CBF, -1),
+    (0x0CC0, 0x0CC5, 8),
+    (0x0CC6, 0x0CC6, -1),
+    (0x0CC7, 0x0CCB, 8),
+    (0x0CCC, 0x0CCD, -1),
+    (0x0CCE, 0x0CDD, 8),
+    (0x0CDE, 0x0CDE, 5),
+    (0x0CDF, 0x0CDF, 8),
+    (0x0CE0, 0x0CE1, 5),
+    (0x0CE2, 0x0CE3, -1),
+    (0x0CE4, 0x0CE5, 8),
+    (0x0CE6, 0x0CEF, 9),
+    (0x0CF0, 0x0CF0, 8),
+    (0x0CF1, 0x0CF2, 5),
+    (0x0CF3, 0x0CFF, 8),
+    (0x0D00, 0x0D01, -1),
+    (0x0D02, 0x0D03, 8),
+    (0x0D04, 0x0D0C, 5),
+    (0x0D0D, 0x0D0D, 8),
+    (0x0D0E, 0x0D10, 5),
+    (0x0D11, 0x0D11, 8),
+    (0x0D12, 0x0D3A, 5),
+    (0x0D3B, 0x0D3C, -1),
+    (0x0D3D, 0x0D3D, 5),
+    (0x0D3E, 0x0D40, 8),
+    (0x0D41, 0x0D44, -1),
+    (0x0D45, 0x0D4C, 8),
+    (0x0D4D, 0x0D4D, -1),
+    (0x0D4E, 0x0D4E, 5),
+    (0x0D4F, 0x0D53, 8),
+    (0x0D54, 0x0D56, 5),
+    (0x0D57, 0x0D57, 8),
+    (0x0D58, 0x0D5E, 9),
+    (0x0D5F, 0x0D61, 5),
+    (0x0D62, 0x0D63, -1),
+    (0x0D64, 0x0D65, 8),
+    (0x0D66, 0x0D78, 9),
+    (0x0D79, 0x0D79, 8),
+    (0x0D7A, 0x0D7F, 5),
+    (0x0D80, 0x0D80, 8),
+    (0x0D81, 0x0D81, -1),
+    (0x0D82, 0x0D84, 8),
+    (0x0D85, 0x0D96, 5),
+    (0x0D97, 0x0D99, 8),
+    (0x0D9A, 0x0DB1, 5),
+    (0x0DB2, 0x0DB2, 8),
+    (0x0DB3, 0x0DBB, 5),
+    (0x0DBC, 0x0DBC, 8),
+    (0x0DBD, 0x0DBD, 5),
+    (0x0DBE, 0x0DBF, 8),
+    (0x0DC0, 0x0DC6, 5),
+    (0x0DC7, 0x0DC9, 8),
+    (0x0DCA, 0x0DCA, -1),
+    (0x0DCB, 0x0DD1, 8),
+    (0x0DD2, 0x0DD4, -1),
+    (0x0DD5, 0x0DD5, 8),
+    (0x0DD6, 0x0DD6, -1),
+    (0x0DD7, 0x0DE5, 8),
+    (0x0DE6, 0x0DEF, 9),
+    (0x0DF0, 0x0E00, 8),
+    (0x0E01, 0x0E30, 5),
+    (0x0E31, 0x0E31, -1),
+    (0x0E32, 0x0E33, 5),
+    (0x0E34, 0x0E3A, -1),
+    (0x0E3B, 0x0E3F, 8),
+    (0x0E40, 0x0E46, 5),
+    (0x0E47, 0x0E4E, -1),
+    (0x0E4F, 0x0E4F, 8),
+    (0x0E50, 0x0E59, 9),
+    (0x0E5A, 0x0E80, 8),
+    (0x0E81, 0x0E82, 5),
+    (0x0E83, 0x0E83, 8),
+    (0x0E84, 0x0E84, 5),
+    (0x0E85, 0x0E85, 8),
+    (0x0E86, 0x0E8A, 5),
+    (0x0E8B, 0x0E8B, 8),
+    (0x0E8C, 0x0EA3, 5),
+    (0x0EA4, 0x0EA4, 8),
+    (0x0EA5, 0x0EA5, 5),
+    (0x0EA6, 0x0EA6, 8),
+    (0x0EA7, 0x0EB0, 5),
+    (0x0EB1, 0x0EB1, -1),
+    (0x0EB2, 0x0EB3, 5),
+    (0x0EB4, 0x0EBC, -1),
+    (0x0EBD, 0x0EBD, 5),
+    (0x0EBE, 0x0EBF, 8),
+    (0x0EC0, 0x0EC4, 5),
+    (0x0EC5, 0x0EC5, 8),
+    (0x0EC6, 0x0EC6, 5),
+    (0x0EC7, 0x0EC7, 8),
+    (0x0EC8, 0x0ECD, -1),
+    (0x0ECE, 0x0ECF, 8),
+    (0x0ED0, 0x0ED9, 9),
+    (0x0EDA, 0x0EDB, 8),
+    (0x0EDC, 0x0EDF, 5),
+    (0x0EE0, 0x0EFF, 8),
+    (0x0F00, 0x0F00, 5),
+    (0x0F01, 0x0F17, 8),
+    (0x0F18, 0x0F19, -1),
+    (0x0F1A, 0x0F1F, 8),
+    (0x0F20, 0x0F33, 9),
+    (0x0F34, 0x0F34, 8),
+    (0x0F35, 0x0F35, -1),
+    (0x0F36, 0x0F36, 8),
+    (0x0F37, 0x0F37, -1),
+    (0x0F38, 0x0F38, 8),
+    (0x0F39, 0x0F39, -1),
+    (0x0F3A, 0x0F3A, 11),
+    (0x0F3B, 0x0F3B, 10),
+    (0x0F3C, 0x0F3C, 11),
+    (0x0F3D, 0x0F3D, 10),
+    (0x0F3E, 0x0F3F, 8),
+    (0x0F40, 0x0F47, 5),
+    (0x0F48, 0x0F48, 8),
+    (0x0F49, 0x0F6C, 5),
+    (0x0F6D, 0x0F70, 8),
+    (0x0F71, 0x0F7E, -1),
+    (0x0F7F, 0x0F7F, 8),
+    (0x0F80, 0x0F84, -1),
+    (0x0F85, 0x0F85, 8),
+    (0x0F86, 0x0F87, -1),
+    (0x0F88, 0x0F8C, 5),
+    (0x0F8D, 0x0F97, -1),
+    (0x0F98, 0x0F98, 8),
+    (0x0F99, 0x0FBC, -1),
+    (0x0FBD, 0x0FC5, 8),
+    (0x0FC6, 0x0FC6, -1),
+    (0x0FC7, 0x0FFF, 8),
+    (0x1000, 0x102A, 5),
+    (0x102B, 0x102C, 8),
+    (0x102D, 0x1030, -1),
+    (0x1031, 0x1031, 8),
+    (0x1032, 0x1037, -1),
+    (0x1038, 0x1038, 8),
+    (0x1039, 0x103A, -1),
+    (0x103B, 0x103C, 8),
+    (0x103D, 0x103E, -1),
+    (0x103F, 0x103F, 5),
+    (0x1040, 0x1049, 9),
+    (0x104A, 0x104F, 8),
+    (0x1050, 0x1055, 5),
+    (0x1056, 0x1057, 8),
+    (0x1058, 0x1059, -1),
+    (0x105A, 0x105D, 5),
+    (0x105E, 0x1060, -1),
+    (0x1061, 0x1061, 5),
+    (0x1062, 0x1064, 8),
+    (0x1065, 0x1066, 5),
+    (0x1067, 0x106D, 8),
+    (0x106E, 0x1070, 5),
+    (0x1071, 0x1074, -1),
+    (0x1075, 0x1081, 5),
+    (0x1082, 0x1082, -1),
+    (0x1083, 0x1084, 8),
+    (0x1085, 0x1086, -1),
+    (0x1087, 0x108C, 8),
+    (0x108D, 0x108D, -1),
+    (0x108E, 0x108E, 5),
+    (0x108F, 0x108F, 8),
+    (0x1090, 0x1099, 9),
+    (0x109A, 0x109C, 8),
+    (0x109D, 0x109D, -1),
+    (0x109E, 0x109F, 8),
+    (0x10A0, 0x10C5, 5),
+    (0x10C6, 0x10C6, 8),
+    (0x10C7, 0x10C7, 5),
+    (0x10C8, 0x10CC, 8),
+    (0x10CD, 0x10CD, 5),
+    (0x10CE, 0x10CF, 8),
+    (0x10D0, 0x10FA, 6),
+    (0x10FB, 0x10FB, 8),
+    (0x10FC, 0x10FC, 5),
+    (0x10FD, 0x10FF, 6),
+    (0x1100, 0x1248, 5),
+    (0x1249, 0x1249, 8),
+    (0x124A, 0x124D, 5),
+    (0x124E, 0x124F, 8),
+    (0x1250, 0x1256, 5),
+    (0x1257, 0x1257, 8),
+    (0x1258, 0x1258, 5),
+    (0x1259, 0x1259, 8),
+    (0x125A, 0x125D, 5),
+    (0x125E, 0x125F, 8),
+    (0x1260, 0x1288, 5),
+    (0x1289, 0x1289, 8),
+    (0x128A, 0x128D, 5),
+    (0x128E, 0x128F, 8),
+    (0x1290, 0x12B0, 5),
+    (0x12B1, 0x12B1, 8),
+    (0x12B2, 0x12B5, 5),
+    (0x12B6, 0x12B7, 8),
+    (0x12B8, 0x12BE, 5),
+    (0x12BF, 0x12BF, 8),
+    (0x12C0, 0x12C0, 5),
+    (0x12C1, 0x12C1, 8),
+    (0x12C2, 0x12C5, 5),
+    (0x12C6, 0x12C7, 8),
+    (0x12C8, 0x12D6, 5),
+    (0x12D7, 0x12D7, 8),
+    (0x12D8, 0x1310, 5),
+    (0x1311, 0x1311, 8),
+    (0x1312, 0x1315, 5),
+    (0x1316, 0x1317, 8),
+    (0x1318, 0x135A, 5),
+    (0x135B, 0x135C, 8),
+    (0x135D, 0x135F, -1),
+    (0x1360, 0x1368, 8),
+    (0x1369, 0x137C, 9),
+    (0x137D, 0x137F, 8),
+    (0x1380, 0x138F, 5),
+    (0x1390, 0x139F, 8),
+    (0x13A0, 0x13F5, 5),
+    (0x13F6, 0x13F7, 8),
+    (0x13F8, 0x13FD, 6),
+    (0x13FE, 0x1400, 8),
+    (0x1401, 0x166C, 5),
+    (0x166D, 0x166E, 8),
+    (0x166F, 0x167F, 5),
+    (0x1680, 0x1680, 12),
+    (0x1681, 0x169A, 5),
+    (0x169B, 0x169B, 11),
+    (0x169C, 0x169C, 10),
+    (0x169D, 0x169F, 8),
+    (0x16A0, 0x16EA, 5),
+    (0x16EB, 0x16ED, 8),
+    (0x16EE, 0x16F0, 9),
+    (0x16F1, 0x16F8, 5),
+    (0x16F9, 0x16FF, 8),
+    (0x1700, 0x170C, 5),
+    (0x170D, 0x170D, 8),
+    (0x170E, 0x1711, 5),
+    (0x1712, 0x1714, -1),
+    (0x1715, 0x171F, 8),
+    (0x1720, 0x1731, 5),
+    (0x1732, 0x1734, -1),
+    (0x1735, 0x173F, 8),
+    (0x1740, 0x1751, 5),
+    (0x1752, 0x1753, -1),
+    (0x1754, 0x175F, 8),
+    (0x1760, 0x176C, 5),
+    (0x176D, 0x176D, 8),
+    (0x176E, 0x1770, 5),
+    (0x1771, 0x1771, 8),
+    (0x1772, 0x1773, -1),
+    (0x1774, 0x177F, 8),
+    (0x1780, 0x17B3, 5),
+    (0x17B4, 0x17B5, -1),
+    (0x17B6, 0x17B6, 8),
+    (0x17B7, 0x17BD, -1),
+    (0x17BE, 0x17C5, 8),
+    (0x17C6, 0x17C6, -1),
+    (0x17C7, 0x17C8, 8),
+    (0x17C9, 0x17D3, -1),
+    (0x17D4, 0x17D6, 8),
+    (0x17D7, 0x17D7, 5),
+    (0x17D8, 0x17DB, 8),
+    (0x17DC, 0x17DC, 5),
+    (0x17DD, 0x17DD, -1),
+    (0x17DE, 0x17DF, 8),
+    (0x17E0, 0x17E9, 9),
+    (0x17EA, 0x17EF, 8),
+    (0x17F0, 0x17F9, 9),
+    (0x17FA, 0x180A, 8),
+    (0x180B, 0x180E, -1),
+    (0x180F, 0x180F, 8),
+    (0x1810, 0x1819, 9),
+    (0x181A, 0x181F, 8),
+    (0x1820, 0x1878, 5),
+    (0x1879, 0x187F, 8),
+    (0x1880, 0x1884, 5),
+    (0x1885, 0x1886, -1),
+    (0x1887, 0x18A8, 5),
+    (0x18A9, 0x18A9, -1),
+    (0x18AA, 0x18AA, 5),
+    (0x18AB, 0x18AF, 8),
+    (0x18B0, 0x18F5, 5),
+    (0x18F6, 0x18FF, 8),
+    (0x1900, 0x191E, 5),
+    (0x191F, 0x191F, 8),
+    (0x1920, 0x1922, -1),
+    (0x1923, 0x1926, 8),
+    (0x1927, 0x1928, -1),
+    (0x1929, 0x1931, 8),
+    (0x1932, 0x1932, -1),
+    (0x1933, 0x1938, 8),
+    (0x1939, 0x193B, -1),
+    (0x193C, 0x1945, 8),
+    (0x1946, 0x194F, 9),
+    (0x1950, 0x196D, 5),
+    (0x196E, 0x196F, 8),
+    (0x1970, 0x1974, 5),
+    (0x1975, 0x197F, 8),
+    (0x1980, 0x19AB, 5),
+    (0x19AC, 0x19AF, 8),
+    (0x19B0, 0x19C9, 5),
+    (0x19CA, 0x19CF, 8),
+    (0x19D0, 0x19DA, 9),
+    (0x19DB, 0x19FF, 8),
+    (0x1A00, 0x1A16, 5),
+    (0x1A17, 0x1A18, -1),
+    (0x1A19, 0x1A1A, 8),
+    (0x1A1B, 0x1A1B, -1),
+    (0x1A1C, 0x1A1F, 8),
+    (0x1A20, 0x1A54, 5),
+    (0x1A55, 0x1A55, 8),
+    (0x1A56, 0x1A56, -1),
+    (0x1A57, 0x1A57, 8),
+    (0x1A58, 0x1A5E, -1),
+    (0x1A5F, 0x1A5F, 8),
+    (0x1A60, 0x1A60, -1),
+    (0x1A61, 0x1A61, 8),
+    (0x1A62, 0x1A62, -1),
+    (0x1A63, 0x1A64, 8),
+    (0x1A65, 0x1A6C, -1),
+    (0x1A6D, 0x1A72, 8),
+    (0x1A73, 0x1A7C, -1),
+    (0x1A7D, 0x1A7E, 8),
+    (0x1A7F, 0x1A7F, -1),
+    (0x1A80, 0x1A89, 9),
+    (0x1A8A, 0x1A8F, 8),
+    (0x1A90, 0x1A99, 9),
+    (0x1A9A, 0x1AA6, 8),
+    (0x1AA7, 0x1AA7, 5),
+    (0x1AA8, 0x1AAF, 8),
+    (0x1AB0, 0x1AC0, -1),
+    (0x1AC1, 0x1AFF, 8),
+    (0x1B00, 0x1B03, -1),
+    (0x1B04, 0x1B04, 8),
+    (0x1B05, 0x1B33, 5),
+    (0x1B34, 0x1B34, -1),
+    (0x1B35, 0x1B35, 8),
+    (0x1B36, 0x1B3A, -1),
+    (0x1B3B, 0x1B3B, 8),
+    (0x1B3C, 0x1B3C, -1),
+    (0x1B3D, 0x1B41, 8),
+    (0x1B42, 0x1B42, -1),
+    (0x1B43, 0x1B44, 8),
+    (0x1B45, 0x1B4B, 5),
+    (0x1B4C, 0x1B4F, 8),
+    (0x1B50, 0x1B59, 9),
+    (0x1B5A, 0x1B6A, 8),
+    (0x1B6B, 0x1B73, -1),
+    (0x1B74, 0x1B7F, 8),
+    (0x1B80, 0x1B81, -1),
+    (0x1B82, 0x1B82, 8),
+    (0x1B83, 0x1BA0, 5),
+    (0x1BA1, 0x1BA1, 8),
+    (0x1BA2, 0x1BA5, -1),
+    (0x1BA6, 0x1BA7, 8),
+    (0x1BA8, 0x1BA9, -1),
+    (0x1BAA, 0x1BAA, 8),
+    (0x1BAB, 0x1BAD, -1),
+    (0x1BAE, 0x1BAF, 5),
+    (0x1BB0, 0x1BB9, 9),
+    (0x1BBA, 0x1BE5, 5),
+    (0x1BE6, 0x1BE6, -1),
+    (0x1BE7, 0x1BE7, 8),
+    (0x1BE8, 0x1BE9, -1),
+    (0x1BEA, 0x1BEC, 8),
+    (0x1BED, 0x1BED, -1),
+    (0x1BEE, 0x1BEE, 8),
+    (0x1BEF, 0x1BF1, -1),
+    (0x1BF2, 0x1BFF, 8),
+    (0x1C00, 0x1C23, 5),
+    (0x1C24, 0x1C2B, 8),
+    (0x1C2C, 0x1C33, -1),
+    (0x1C34, 0x1C35, 8),
+    (0x1C36, 0x1C37, -1),
+    (0x1C38, 0x1C3F, 8),
+    (0x1C40, 0x1C49, 9),
+    (0x1C4A, 0x1C4C, 8),
+    (0x1C4D, 0x1C4F, 5),
+    (0x1C50, 0x1C59, 9),
+    (0x1C5A, 0x1C7D, 5),
+    (0x1C7E, 0x1C7F, 8),
+    (0x1C80, 0x1C88, 6),
+    (0x1C89, 0x1C8F, 8),
+    (0x1C90, 0x1CBA, 5),
+    (0x1CBB, 0x1CBC, 8),
+    (0x1CBD, 0x1CBF, 5),
+    (0x1CC0, 0x1CCF, 8),
+    (0x1CD0, 0x1CD2, -1),
+    (0x1CD3, 0x1CD3, 8),
+    (0x1CD4, 0x1CE0, -1),
+    (0x1CE1, 0x1CE1, 8),
+    (0x1CE2, 0x1CE8, -1),
+    (0x1CE9, 0x1CEC, 5),
+    (0x1CED, 0x1CED, -1),
+    (0x1CEE, 0x1CF3, 5),
+    (0x1CF4, 0x1CF4, -1),
+    (0x1CF5, 0x1CF6, 5),
+    (0x1CF7, 0x1CF7, 8),
+    (0x1CF8, 0x1CF9, -1),
+    (0x1CFA, 0x1CFA, 5),
+    (0x1CFB, 0x1CFF, 8),
+    (0x1D00, 0x1D2B, 6),
+    (0x1D2C, 0x1D6A, 5),
+    (0x1D6B, 0x1D77, 6),
+    (0x1D78, 0x1D78, 5),
+    (0x1D79, 0x1D9A, 6),
+    (0x1D9B, 0x1DBF, 5),
+    (0x1DC0, 0x1DF9, -1),
+    (0x1DFA, 0x1DFA, 8),
+    (0x1DFB, 0x1DFF, -1),
+    (0x1E00, 0x1E00, 5),
+    (0x1E01, 0x1E01, 6),
+    (0x1E02, 0x1E02, 5),
+    (0x1E03, 0x1E03, 6),
+    (0x1E04, 0x1E04, 5),
+    (0x1E05, 0x1E05, 6),
+    (0x1E06, 0x1E06, 5),
+    (0x1E07, 0x1E07, 6),
+    (0x1E08, 0x1E08, 5),
+    (0x1E09, 0x1E09, 6),
+    (0x1E0A, 0x1E0A, 5),
+    (0x1E0B, 0x1E0B, 6),
+    (0x1E0C, 0x1E0C, 5),
+    (0x1E0D, 0x1E0D, 6),
+    (0x1E0E, 0x1E0E, 5),
+    (0x1E0F, 0x1E0F, 6),
+    (0x1E10, 0x1E10, 5),
+    (0x1E11, 0x1E11, 6),
+    (0x1E12, 0x1E12, 5),
+    (0x1E13, 0x1E13, 6),
+    (0x1E14, 0x1E14, 5),
+    (0x1E15, 0x1E15, 6),
+    (0x1E16, 0x1E16, 5),
+    (0x1E17, 0x1E17, 6),
+    (0x1E18, 0x1E18, 5),
+    (0x1E19, 0x1E19, 6),
+    (0x1E1A, 0x1E1A, 5),
+    (0x1E1B, 0x1E1B, 6),
+    (0x1E1C, 0x1E1C, 5),
+    (0x1E1D, 0x1E1D, 6),
+    (0x1E1E, 0x1E1E, 5),
+    (0x1E1F, 0x1E1F, 6),
+    (0x1E20, 0x1E20, 5),
+    (0x1E21, 0x1E21, 6),
+    (0x1E22, 0x1E22, 5),
+    (0x1E23, 0x1E23, 6),
+    (0x1E24, 0x1E24, 5),
+    (0x1E25, 0x1E25, 6),
+    (0x1E26, 0x1E26, 5),
+    (0x1E27, 0x1E27, 6),
+    (0x1E28, 0x1E28, 5),
+    (0x1E29, 0x1E29, 6),
+    (0x1E2A, 0x1E2A, 5),
+    (0x1E2B, 0x1E2B, 6),
+    (0x1E2C, 0x1E2C, 5),
+    (0x1E2D, 0x1E2D, 6),
+    (0x1E2E, 0x1E2E, 5),
+    (0x1E2F, 0x1E2F, 6),
+    (0x1E30, 0x1E30, 5),
+    (0x1E31, 0x1E31, 6),
+    (0x1E32, 0x1E32, 5),
+    (0x1E33, 0x1E33, 6),
+    (0x1E34, 0x1E34, 5),
+    (0x1E35, 0x1E35, 6),
+    (0x1E36, 0x1E36, 5),
+    (0x1E37, 0x1E37, 6),
+    (0x1E38, 0x1E38, 5),
+    (0x1E39, 0x1E39, 6),
+    (0x1E3A, 0x1E3A, 5),
+    (0x1E3B, 0x1E3B, 6),
+    (0x1E3C, 0x1E3C, 5),
+    (0x1E3D, 0x1E3D, 6),
+    (0x1E3E, 0x1E3E, 5),
+    (0x1E3F, 0x1E3F, 6),
+    (0x1E40, 0x1E40, 5),
+    (0x1E41, 0x1E41, 6),
+    (0x1E42, 0x1E42, 5),
+    (0x1E43, 0x1E43, 6),
+    (0x1E44, 0x1E44, 5),
+    (0x1E45, 0x1E45, 6),
+    (0x1E46, 0x1E46, 5),
+    (0x1E47, 0x1E47, 6),
+    (0x1E48, 0x1E48, 5),
+    (0x1E49, 0x1E49, 6),
+    (0x1E4A, 0x1E4A, 5),
+    (0x1E4B, 0x1E4B, 6),
+    (0x1E4C, 0x1E4C, 5),
+    (0x1E4D, 0x1E4D, 6),
+    (0x1E4E, 0x1E4E, 5),
+    (0x1E4F, 0x1E4F, 6),
+    (0x1E50, 0x1E50, 5),
+    (0x1E51, 0x1E51, 6),
+    (0x1E52, 0x1E52, 5),
+    (0x1E53, 0x1E53, 6),
+    (0x1E54, 0x1E54, 5),
+    (0x1E55, 0x1E55, 6),
+    (0x1E56, 0x1E56, 5),
+    (0x1E57, 0x1E57, 6),
+    (0x1E58, 0x1E58, 5),
+    (0x1E59, 0x1E59, 6),
+    (0x1E5A, 0x1E5A, 5),
+    (0x1E5B, 0x1E5B, 6),
+    (0x1E5C, 0x1E5C, 5),
+    (0x1E5D, 0x1E5D, 6),
+    (0x1E5E, 0x1E5E, 5),
+    (0x1E5F, 0x1E5F, 6),
+    (0x1E60, 0x1E60, 5),
+    (0x1E61, 0x1E61, 6),
+    (0x1E62, 0x1E62, 5),
+    (0x1E63, 0x1E63, 6),
+    (0x1E64, 0x1E64, 5),
+    (0x1E65, 0x1E65, 6),
+    (0x1E66, 0x1E66, 5),
+    (0x1E67, 0x1E67, 6),
+    (0x1E68, 0x1E68, 5),
+    (0x1E69, 0x1E69, 6),
+    (0x1E6A, 0x1E6A, 5),
+    (0x1E6B, 0x1E6B, 6),
+    (0x1E6C, 0x1E6C, 5),
+    (0x1E6D, 0x1E6D, 6),
+    (0x1E6E, 0x1E6E, 5),
+    (0x1E6F, 0x1E6F, 6),
+    (0x1E70, 0x1E70, 5),
+    (0x1E71, 0x1E71, 6),
+    (0x1E72, 0x1E72, 5),
+    (0x1E73, 0x1E73, 6),
+    (0x1E74, 0x1E74, 5),
+    (0x1E75, 0x1E75, 6),
+    (0x1E76, 0x1E76, 5),
+    (0x1E77, 0x1E77, 6),
+    (0x1E78, 0x1E78, 5),
+    (0x1E79, 0x1E79, 6),
+    (0x1E7A, 0x1E7A, 5),
+    (0x1E7B, 0x1E7B, 6),
+    (0x1E7C, 0x1E7C, 5),
+    (0x1E7D, 0x1E7D, 6),
+    (0x1E7E, 0x1E7E, 5),
+    (0x1E7F, 0x1E7F, 6),
+    (0x1E80, 0x1E80, 5),
+    (0x1E81, 0x1E81, 6),
+    (0x1E82, 0x1E82, 5),
+    (0x1E83, 0x1E83, 6),
+    (0x1E84, 0x1E84, 5),
+    (0x1E85, 0x1E85, 6),
+    (0x1E86, 0x1E86, 5),
+    (0x1E87, 0x1E87, 6),
+    (0x1E88, 0x1E88, 5),
+    (0x1E89, 0x1E89, 6),
+    (0x1E8A, 0x1E8A, 5),
+    (0x1E8B, 0x1E8B, 6),
+    (0x1E8C, 0x1E8C, 5),
+    (0x1E8D, 0x1E8D, 6),
+    (0x1E8E, 0x1E8E, 5),
+    (0x1E8F, 0x1E8F, 6),
+    (0x1E90, 0x1E90, 5),
+    (0x1E91, 0x1E91, 6),
+    (0x1E92, 0x1E92, 5),
+    (0x1E93, 0x1E93, 6),
+    (0x1E94, 0x1E94, 5),
+    (0x1E95, 0x1E9D, 6),
+    (0x1E9E, 0x1E9E, 5),
+    (0x1E9F, 0x1E9F, 6),
+    (0x1EA0, 0x1EA0, 5),
+    (0x1EA1, 0x1EA1, 6),
+    (0x1EA2, 0x1EA2, 5),
+    (0x1EA3, 0x1EA3, 6),
+    (0x1EA4, 0x1EA4, 5),
+    (0x1EA5, 0x1EA5, 6),
+    (0x1EA6, 0x1EA6, 5),
+    (0x1EA7, 0x1EA7, 6),
+    (0x1EA8, 0x1EA8, 5),
+    (0x1EA9, 0x1EA9, 6),
+    (0x1EAA, 0x1EAA, 5),
+    (0x1EAB, 0x1EAB, 6),
+    (0x1EAC, 0x1EAC, 5),
+    (0x1EAD, 0x1EAD, 6),
+    (0x1EAE, 0x1EAE, 5),
+    (0x1EAF, 0x1EAF, 6),
+    (0x1EB0, 0x1EB0, 5),
+    (0x1EB1, 0x1EB1, 6),
+    (0x1EB2, 0x1EB2, 5),
+    (0x1EB3, 0x1EB3, 6),
+    (0x1EB4, 0x1EB4, 5),
+    (0x1EB5, 0x1EB5, 6),
+    (0x1EB6, 0x1EB6, 5),
+    (0x1EB7, 0x1EB7, 6),
+    (0x1EB8, 0x1EB8, 5),
+    (0x1EB9, 0x1EB9, 6),
+    (0x1EBA, 0x1EBA, 5),
+    (0x1EBB, 0x1EBB, 6),
+    (0x1EBC, 0x1EBC, 5),
+    (0x1EBD, 0x1EBD, 6),
+    (0x1EBE, 0x1EBE, 5),
+    (0x1EBF, 0x1EBF, 6),
+    (0x1EC0, 0x1EC0, 5),
+    (0x1EC1, 0x1EC1, 6),
+    (0x1EC2, 0x1EC2, 5),
+    (0x1EC3, 0x1EC3, 6),
+    (0x1EC4, 0x1EC4, 5),
+    (0x1EC5, 0x1EC5, 6),
+    (0x1EC6, 0x1EC6, 5),
+    (0x1EC7, 0x1EC7, 6),
+    (0x1EC8, 0x1EC8, 5),
+    (0x1EC9, 0x1EC9, 6),
+    (0x1ECA, 0x1ECA, 5),
+    (0x1ECB, 0x1ECB, 6),
+    (0x1ECC, 0x1ECC, 5),
+    (0x1ECD, 0x1ECD, 6),
+    (0x1ECE, 0x1ECE, 5),
+    (0x1ECF, 0x1ECF, 6),
+    (0x1ED0, 0x1ED0, 5),
+    (0x1ED1, 0x1ED1, 6),
+    (0x1ED2, 0x1ED2, 5),
+    (0x1ED3, 0x1ED3, 6),
+    (0x1ED4, 0x1ED4, 5),
+    (0x1ED5, 0x1ED5, 6),
+    (0x1ED6, 0x1ED6, 5),
+    (0x1ED7, 0x1ED7, 6),
+    (0x1ED8, 0x1ED8, 5),
+    (0x1ED9, 0x1ED9, 6),
+    (0x1EDA, 0x1EDA, 5),
+    (0x1EDB, 0x1EDB, 6),
+    (0x1EDC, 0x1EDC, 5),
+    (0x1EDD, 0x1EDD, 6),
+    (0x1EDE, 0x1EDE, 5),
+    (0x1EDF, 0x1EDF, 6),
+    (0x1EE0, 0x1EE0, 5),
+    (0x1EE1, 0x1EE1, 6),
+    (0x1EE2, 0x1EE2, 5),
+    (0x1EE3, 0x1EE3, 6),
+    (0x1EE4, 0x1EE4, 5),
+    (0x1EE5, 0x1EE5, 6),
+    (0x1EE6, 0x1EE6, 5),
+    (0x1EE7, 0x1EE7, 6),
+    (0x1EE8, 0x1EE8, 5),
+    (0x1EE9, 0x1EE9, 6),
+    (0x1EEA, 0x1EEA, 5),
+    (0x1EEB, 0x1EEB, 6),
+    (0x1EEC, 0x1EEC, 5),
+    (0x1EED, 0x1EED, 6),
+    (0x1EEE, 0x1EEE, 5),
+    (0x1EEF, 0x1EEF, 6),
+    (0x1EF0, 0x1EF0, 5),
+    (0x1EF1, 0x1EF1, 6),
+    (0x1EF2, 0x1EF2, 5),
+    (0x1EF3, 0x1EF3, 6),
+    (0x1EF4, 0x1EF4, 5),
+    (0x1EF5, 0x1EF5, 6),
+    (0x1EF6, 0x1EF6, 5),
+    (0x1EF7, 0x1EF7, 6),
+    (0x1EF8, 0x1EF8, 5),
+    (0x1EF9, 0x1EF9, 6),
+    (0x1EFA, 0x1EFA, 5),
+    (0x1EFB, 0x1EFB, 6),
+    (0x1EFC, 0x1EFC, 5),
+    (0x1EFD, 0x1EFD, 6),
+    (0x1EFE, 0x1EFE, 5),
+    (0x1EFF, 0x1F07, 6),
+    (0x1F08, 0x1F0F, 5),
+    (0x1F10, 0x1F15, 6),
+    (0x1F16, 0x1F17, 8),
+    (0x1F18, 0x1F1D, 5),
+    (0x1F1E, 0x1F1F, 8),
+    (0x1F20, 0x1F27, 6),
+    (0x1F28, 0x1F2F, 5),
+    (0x1F30, 0x1F37, 6),
+    (0x1F38, 0x1F3F, 5),
+    (0x1F40, 0x1F45, 6),
+    (0x1F46, 0x1F47, 8),
+    (0x1F48, 0x1F4D, 5),
+    (0x1F4E, 0x1F4F, 8),
+    (0x1F50, 0x1F57, 6),
+    (0x1F58, 0x1F58, 8),
+    (0x1F59, 0x1F59, 5),
+    (0x1F5A, 0x1F5A, 8),
+    (0x1F5B, 0x1F5B, 5),
+    (0x1F5C, 0x1F5C, 8),
+    (0x1F5D, 0x1F5D, 5),
+    (0x1F5E, 0x1F5E, 8),
+    (0x1F5F, 0x1F5F, 5),
+    (0x1F60, 0x1F67, 6),
+    (0x1F68, 0x1F6F, 5),
+    (0x1F70, 0x1F7D, 6),
+    (0x1F7E, 0x1F7F, 8),
+    (0x1F80, 0x1F87, 6),
+    (0x1F88, 0x1F8F, 5),
+    (0x1F90, 0x1F97, 6),
+    (0x1F98, 0x1F9F, 5),
+    (0x1FA0, 0x1FA7, 6),
+    (0x1FA8, 0x1FAF, 5),
+    (0x1FB0, 0x1FB4, 6),
+    (0x1FB5, 0x1FB5, 8),
+    (0x1FB6, 0x1FB7, 6),
+    (0x1FB8, 0x1FBC, 5),
+    (0x1FBD, 0x1FBD, 8),
+    (0x1FBE, 0x1FBE, 6),
+    (0x1FBF, 0x1FC1, 8),
+    (0x1FC2, 0x1FC4, 6),
+    (0x1FC5, 0x1FC5, 8),
+    (0x1FC6, 0x1FC7, 6),
+    (0x1FC8, 0x1FCC, 5),
+    (0x1FCD, 0x1FCF, 8),
+    (0x1FD0, 0x1FD3, 6),
+    (0x1FD4, 0x1FD5, 8),
+    (0x1FD6, 0x1FD7, 6),
+    (0x1FD8, 0x1FDB, 5),
+    (0x1FDC, 0x1FDF, 8),
+    (0x1FE0, 0x1FE7, 6),
+    (0x1FE8, 0x1FEC, 5),
+    (0x1FED, 0x1FF1, 8),
+    (0x1FF2, 0x1FF4, 6),
+    (0x1FF5, 0x1FF5, 8),
+    (0x1FF6, 0x1FF7, 6),
+    (0x1FF8, 0x1FFC, 5),
+    (0x1FFD, 0x1FFF, 8),
+    (0x2000, 0x200A, 12),
+    (0x200B, 0x200F, -1),
+    (0x2010, 0x2017, 8),
+    (0x2018, 0x2018, 11),
+    (0x2019, 0x2019, 10),
+    (0x201A, 0x201C, 11),
+    (0x201D, 0x201D, 10),
+    (0x201E, 0x201F, 11),
+    (0x2020, 0x2027, 8),
+    (0x2028, 0x2028, 12),
+    (0x2029, 0x2029, 1),
+    (0x202A, 0x202E, -1),
+    (0x202F, 0x202F, 12),
+    (0x2030, 0x2038, 8),
+    (0x2039, 0x2039, 11),
+    (0x203A, 0x203A, 10),
+    (0x203B, 0x2044, 8),
+    (0x2045, 0x2045, 11),
+    (0x2046, 0x2046, 10),
+    (0x2047, 0x205E, 8),
+    (0x205F, 0x205F, 12),
+    (0x2060, 0x2064, -1),
+    (0x2065, 0x2065, 8),
+    (0x2066, 0x206F, -1),
+    (0x2070, 0x2070, 9),
+    (0x2071, 0x2071, 5),
+    (0x2072, 0x2073, 8),
+    (0x2074, 0x2079, 9),
+    (0x207A, 0x207C, 8),
+    (0x207D, 0x207D, 11),
+    (0x207E, 0x207E, 10),
+    (0x207F, 0x207F, 5),
+    (0x2080, 0x2089, 9),
+    (0x208A, 0x208C, 8),
+    (0x208D, 0x208D, 11),
+    (0x208E, 0x208E, 10),
+    (0x208F, 0x208F, 8),
+    (0x2090, 0x209C, 5),
+    (0x209D, 0x20CF, 8),
+    (0x20D0, 0x20F0, -1),
+    (0x20F1, 0x2101, 8),
+    (0x2102, 0x2102, 5),
+    (0x2103, 0x2106, 8),
+    (0x2107, 0x2107, 5),
+    (0x2108, 0x2109, 8),
+    (0x210A, 0x210A, 6),
+    (0x210B, 0x210D, 5),
+    (0x210E, 0x210F, 6),
+    (0x2110, 0x2112, 5),
+    (0x2113, 0x2113, 6),
+    (0x2114, 0x2114, 8),
+    (0x2115, 0x2115, 5),
+    (0x2116, 0x2118, 8),
+    (0x2119, 0x211D, 5),
+    (0x211E, 0x2123, 8),
+    (0x2124, 0x2124, 5),
+    (0x2125, 0x2125, 8),
+    (0x2126, 0x2126, 5),
+    (0x2127, 0x2127, 8),
+    (0x2128, 0x2128, 5),
+    (0x2129, 0x2129, 8),
+    (0x212A, 0x212D, 5),
+    (0x212E, 0x212E, 8),
+    (0x212F, 0x212F, 6),
+    (0x2130, 0x2133, 5),
+    (0x2134, 0x2134, 6),
+    (0x2135, 0x2138, 5),
+    (0x2139, 0x2139, 6),
+    (0x213A, 0x213B, 8),
+    (0x213C, 0x213D, 6),
+    (0x213E, 0x213F, 5),
+    (0x2140, 0x2144, 8),
+    (0x2145, 0x2145, 5),
+    (0x2146, 0x2149, 6),
+    (0x214A, 0x214D, 8),
+    (0x214E, 0x214E, 6),
+    (0x214F, 0x214F, 8),
+    (0x2150, 0x2182, 9),
+    (0x2183, 0x2183, 5),
+    (0x2184, 0x2184, 6),
+    (0x2185, 0x2189, 9),
+    (0x218A, 0x2307, 8),
+    (0x2308, 0x2308, 11),
+    (0x2309, 0x2309, 10),
+    (0x230A, 0x230A, 11),
+    (0x230B, 0x230B, 10),
+    (0x230C, 0x2328, 8),
+    (0x2329, 0x2329, 11),
+    (0x232A, 0x232A, 10),
+    (0x232B, 0x245F, 8),
+    (0x2460, 0x249B, 9),
+    (0x249C, 0x24E9, 8),
+    (0x24EA, 0x24FF, 9),
+    (0x2500, 0x2767, 8),
+    (0x2768, 0x2768, 11),
+    (0x2769, 0x2769, 10),
+    (0x276A, 0x276A, 11),
+    (0x276B, 0x276B, 10),
+    (0x276C, 0x276C, 11),
+    (0x276D, 0x276D, 10),
+    (0x276E, 0x276E, 11),
+    (0x276F, 0x276F, 10),
+    (0x2770, 0x2770, 11),
+    (0x2771, 0x2771, 10),
+    (0x2772, 0x2772, 11),
+    (0x2773, 0x2773, 10),
+    (0x2774, 0x2774, 11),
+    (0x2775, 0x2775, 10),
+    (0x2776, 0x2793, 9),
+    (0x2794, 0x27C4, 8),
+    (0x27C5, 0x27C5, 11),
+    (0x27C6, 0x27C6, 10),
+    (0x27C7, 0x27E5, 8),
+    (0x27E6, 0x27E6, 11),
+    (0x27E7, 0x27E7, 10),
+    (0x27E8, 0x27E8, 11),
+    (0x27E9, 0x27E9, 10),
+    (0x27EA, 0x27EA, 11),
+    (0x27EB, 0x27EB, 10),
+    (0x27EC, 0x27EC, 11),
+    (0x27ED, 0x27ED, 10),
+    (0x27EE, 0x27EE, 11),
+    (0x27EF, 0x27EF, 10),
+    (0x27F0, 0x2982, 8),
+    (0x2983, 0x2983, 11),
+    (0x2984, 0x2984, 10),
+    (0x2985, 0x2985, 11),
+    (0x2986, 0x2986, 10),
+    (0x2987, 0x2987, 11),
+    (0x2988, 0x2988, 10),
+    (0x2989, 0x2989, 11),
+    (0x298A, 0x298A, 10),
+    (0x298B, 0x298B, 11),
+    (0x298C, 0x298C, 10),
+    (0x298D, 0x298D, 11),
+    (0x298E, 0x298E, 10),
+    (0x298F, 0x298F, 11),
+    (0x2990, 0x2990, 10),
+    (0x2991, 0x2991, 11),
+    (0x2992, 0x2992, 10),
+    (0x2993, 0x2993, 11),
+    (0x2994, 0x2994, 10),
+    (0x2995, 0x2995, 11),
+    (0x2996, 0x2996, 10),
+    (0x2997, 0x2997, 11),
+    (0x2998, 0x2998, 10),
+    (0x2999, 0x29D7, 8),
+    (0x29D8, 0x29D8, 11),
+    (0x29D9, 0x29D9, 10),
+    (0x29DA, 0x29DA, 11),
+    (0x29DB, 0x29DB, 10),
+    (0x29DC, 0x29FB, 8),
+    (0x29FC, 0x29FC, 11),
+    (0x29FD, 0x29FD, 10),
+    (0x29FE, 0x2BFF, 8),
+    (0x2C00, 0x2C2E, 5),
+    (0x2C2F, 0x2C2F, 8),
+    (0x2C30, 0x2C5E, 6),
+    (0x2C5F, 0x2C5F, 8),
+    (0x2C60, 0x2C60, 5),
+    (0x2C61, 0x2C61, 6),
+    (0x2C62, 0x2C64, 5),
+    (0x2C65, 0x2C66, 6),
+    (0x2C67, 0x2C67, 5),
+    (0x2C68, 0x2C68, 6),
+    (0x2C69, 0x2C69, 5),
+    (0x2C6A, 0x2C6A, 6),
+    (0x2C6B, 0x2C6B, 5),
+    (0x2C6C, 0x2C6C, 6),
+    (0x2C6D, 0x2C70, 5),
+    (0x2C71, 0x2C71, 6),
+    (0x2C72, 0x2C72, 5),
+    (0x2C73, 0x2C74, 6),
+    (0x2C75, 0x2C75, 5),
+    (0x2C76, 0x2C7B, 6),
+    (0x2C7C, 0x2C80, 5),
+    (0x2C81, 0x2C81, 6),
+    (0x2C82, 0x2C82, 5),
+    (0x2C83, 0x2C83, 6),
+    (0x2C84, 0x2C84, 5),
+    (0x2C85, 0x2C85, 6),
+    (0x2C86, 0x2C86, 5),
+    (0x2C87, 0x2C87, 6),
+    (0x2C88, 0x2C88, 5),
+    (0x2C89, 0x2C89, 6),
+    (0x2C8A, 0x2C8A, 5),
+    (0x2C8B, 0x2C8B, 6),
+    (0x2C8C, 0x2C8C, 5),
+    (0x2C8D, 0x2C8D, 6),
+    (0x2C8E, 0x2C8E, 5),
+    (0x2C8F, 0x2C8F, 6),
+    (0x2C90, 0x2C90, 5),
+    (0x2C91, 0x2C91, 6),
+    (0x2C92, 0x2C92, 5),
+    (0x2C93, 0x2C93, 6),
+    (0x2C94, 0x2C94, 5),
+    (0x2C95, 0x2C95, 6),
+    (0x2C96, 0x2C96, 5),
+    (0x2C97, 0x2C97, 6),
+    (0x2C98, 0x2C98, 5),
+    (0x2C99, 0x2C99, 6),
+    (0x2C9A, 0x2C9A, 5),
+    (0x2C9B, 0x2C9B, 6),
+    (0x2C9C, 0x2C9C, 5),
+    (0x2C9D, 0x2C9D, 6),
+    (0x2C9E, 0x2C9E, 5),
+    (0x2C9F, 0x2C9F, 6),
+    (0x2CA0, 0x2CA0, 5),
+    (0x2CA1, 0x2CA1, 6),
+    (0x2CA2, 0x2CA2, 5),
+    (0x2CA3, 0x2CA3, 6),
+    (0x2CA4, 0x2CA4, 5),
+    (0x2CA5, 0x2CA5, 6),
+    (0x2CA6, 0x2CA6, 5),
+    (0x2CA7, 0x2CA7, 6),
+    (0x2CA8, 0x2CA8, 5),
+    (0x2CA9, 0x2CA9, 6),
+    (0x2CAA, 0x2CAA, 5),
+    (0x2CAB, 0x2CAB, 6),
+    (0x2CAC, 0x2CAC, 5),
+    (0x2CAD, 0x2CAD, 6),
+    (0x2CAE, 0x2CAE, 5),
+    (0x2CAF, 0x2CAF, 6),
+    (0x2CB0, 0x2CB0, 5),
+    (0x2CB1, 0x2CB1, 6),
+    (0x2CB2, 0x2CB2, 5),
+    (0x2CB3, 0x2CB3, 6),
+    (0x2CB4, 0x2CB4, 5),
+    (0x2CB5, 0x2CB5, 6),
+    (0x2CB6, 0x2CB6, 5),
+    (0x2CB7, 0x2CB7, 6),
+    (0x2CB8, 0x2CB8, 5),
+    (0x2CB9, 0x2CB9, 6),
+    (0x2CBA, 0x2CBA, 5),
+    (0x2CBB, 0x2CBB, 6),
+    (0x2CBC, 0x2CBC, 5),
+    (0x2CBD, 0x2CBD, 6),
+    (0x2CBE, 0x2CBE, 5),
+    (0x2CBF, 0x2CBF, 6),
+    (0x2CC0, 0x2CC0, 5),
+    (0x2CC1, 0x2CC1, 6),
+    (0x2CC2, 0x2CC2, 5),
+    (0x2CC3, 0x2CC3, 6),
+    (0x2CC4, 0x2CC4, 5),
+    (0x2CC5, 0x2CC5, 6),
+    (0x2CC6, 0x2CC6, 5),
+    (0x2CC7, 0x2CC7, 6),
+    (0x2CC8, 0x2CC8, 5),
+    (0x2CC9, 0x2CC9, 6),
+    (0x2CCA, 0x2CCA, 5),
+    (0x2CCB, 0x2CCB, 6),
+    (0x2CCC, 0x2CCC, 5),
+    (0x2CCD, 0x2CCD, 6),
+    (0x2CCE, 0x2CCE, 5),
+    (0x2CCF, 0x2CCF, 6),
+    (0x2CD0, 0x2CD0, 5),
+    (0x2CD1, 0x2CD1, 6),
+    (0x2CD2, 0x2CD2, 5),
+    (0x2CD3, 0x2CD3, 6),
+    (0x2CD4, 0x2CD4, 5),
+    (0x2CD5, 0x2CD5, 6),
+    (0x2CD6, 0x2CD6, 5),
+    (0x2CD7, 0x2CD7, 6),
+    (0x2CD8, 0x2CD8, 5),
+    (0x2CD9, 0x2CD9, 6),
+    (0x2CDA, 0x2CDA, 5),
+    (0x2CDB, 0x2CDB, 6),
+    (0x2CDC, 0x2CDC, 5),
+    (0x2CDD, 0x2CDD, 6),
+    (0x2CDE, 0x2CDE, 5),
+    (0x2CDF, 0x2CDF, 6),
+    (0x2CE0, 0x2CE0, 5),
+    (0x2CE1, 0x2CE1, 6),
+    (0x2CE2, 0x2CE2, 5),
+    (0x2CE3, 0x2CE4, 6),
+    (0x2CE5, 0x2CEA, 8),
+    (0x2CEB, 0x2CEB, 5),
+    (0x2CEC, 0x2CEC, 6),
+    (0x2CED, 0x2CED, 5),
+    (0x2CEE, 0x2CEE, 6),
+    (0x2CEF, 0x2CF1, -1),
+    (0x2CF2, 0x2CF2, 5),
+    (0x2CF3, 0x2CF3, 6),
+    (0x2CF4, 0x2CFC, 8),
+    (0x2CFD, 0x2CFD, 9),
+    (0x2CFE, 0x2CFF, 8),
+    (0x2D00, 0x2D25, 6),
+    (0x2D26, 0x2D26, 8),
+    (0x2D27, 0x2D27, 6),
+    (0x2D28, 0x2D2C, 8),
+    (0x2D2D, 0x2D2D, 6),
+    (0x2D2E, 0x2D2F, 8),
+    (0x2D30, 0x2D67, 5),
+    (0x2D68, 0x2D6E, 8),
+    (0x2D6F, 0x2D6F, 5),
+    (0x2D70, 0x2D7E, 8),
+    (0x2D7F, 0x2D7F, -1),
+    (0x2D80, 0x2D96, 5),
+    (0x2D97, 0x2D9F, 8),
+    (0x2DA0, 0x2DA6, 5),
+    (0x2DA7, 0x2DA7, 8),
+    (0x2DA8, 0x2DAE, 5),
+    (0x2DAF, 0x2DAF, 8),
+    (0x2DB0, 0x2DB6, 5),
+    (0x2DB7, 0x2DB7, 8),
+    (0x2DB8, 0x2DBE, 5),
+    (0x2DBF, 0x2DBF, 8),
+    (0x2DC0, 0x2DC6, 5),
+    (0x2DC7, 0x2DC7, 8),
+    (0x2DC8, 0x2DCE, 5),
+    (0x2DCF, 0x2DCF, 8),
+    (0x2DD0, 0x2DD6, 5),
+    (0x2DD7, 0x2DD7, 8),
+    (0x2DD8, 0x2DDE, 5),
+    (0x2DDF, 0x2DDF, 8),
+    (0x2DE0, 0x2DFF, -1),
+    (0x2E00, 0x2E01, 8),
+    (0x2E02, 0x2E02, 11),
+    (0x2E03, 0x2E03, 10),
+    (0x2E04, 0x2E04, 11),
+    (0x2E05, 0x2E05, 10),
+    (0x2E06, 0x2E08, 8),
+    (0x2E09, 0x2E09, 11),
+    (0x2E0A, 0x2E0A, 10),
+    (0x2E0B, 0x2E0B, 8),
+    (0x2E0C, 0x2E0C, 11),
+    (0x2E0D, 0x2E0D, 10),
+    (0x2E0E, 0x2E1B, 8),
+    (0x2E1C, 0x2E1C, 11),
+    (0x2E1D, 0x2E1D, 10),
+    (0x2E1E, 0x2E1F, 8),
+    (0x2E20, 0x2E20, 11),
+    (0x2E21, 0x2E21, 10),
+    (0x2E22, 0x2E22, 11),
+    (0x2E23, 0x2E23, 10),
+    (0x2E24, 0x2E24, 11),
+    (0x2E25, 0x2E25, 10),
+    (0x2E26, 0x2E26, 11),
+    (0x2E27, 0x2E27, 10),
+    (0x2E28, 0x2E28, 11),
+    (0x2E29, 0x2E29, 10),
+    (0x2E2A, 0x2E2E, 8),
+    (0x2E2F, 0x2E2F, 5),
+    (0x2E30, 0x2E41, 8),
+    (0x2E42, 0x2E42, 11),
+    (0x2E43, 0x2FFF, 8),
+    (0x3000, 0x3000, 12),
+    (0x3001, 0x3001, 8),
+    (0x3002, 0x3002, 4),
+    (0x3003, 0x3004, 8),
+    (0x3005, 0x3006, 5),
+    (0x3007, 0x3007, 9),
+    (0x3008, 0x3008, 11),
+    (0x3009, 0x3009, 10),
+    (0x300A, 0x300A, 11),
+    (0x300B, 0x300B, 10),
+    (0x300C, 0x300C, 11),
+    (0x300D, 0x300D, 10),
+    (0x300E, 0x300E, 11),
+    (0x300F, 0x300F, 10),
+    (0x3010, 0x3010, 11),
+    (0x3011, 0x3011, 10),
+    (0x3012, 0x3013, 8),
+    (0x3014, 0x3014, 11),
+    (0x3015, 0x3015, 10),
+    (0x3016, 0x3016, 11),
+    (0x3017, 0x3017, 10),
+    (0x3018, 0x3018, 11),
+    (0x3019, 0x3019, 10),
+    (0x301A, 0x301A, 11),
+    (0x301B, 0x301B, 10),
+    (0x301C, 0x301C, 8),
+    (0x301D, 0x301D, 11),
+    (0x301E, 0x301F, 10),
+    (0x3020, 0x3020, 8),
+    (0x3021, 0x3029, 9),
+    (0x302A, 0x302D, -1),
+    (0x302E, 0x3030, 8),
+    (0x3031, 0x3035, 5),
+    (0x3036, 0x3037, 8),
+    (0x3038, 0x303A, 9),
+    (0x303B, 0x303C, 5),
+    (0x303D, 0x3040, 8),
+    (0x3041, 0x3096, 5),
+    (0x3097, 0x3098, 8),
+    (0x3099, 0x309A, -1),
+    (0x309B, 0x309C, 8),
+    (0x309D, 0x309F, 5),
+    (0x30A0, 0x30A0, 8),
+    (0x30A1, 0x30FA, 5),
+    (0x30FB, 0x30FB, 8),
+    (0x30FC, 0x30FF, 5),
+    (0x3100, 0x3104, 8),
+    (0x3105, 0x312F, 5),
+    (0x3130, 0x3130, 8),
+    (0x3131, 0x318E, 5),
+    (0x318F, 0x3191, 8),
+    (0x3192, 0x3195, 9),
+    (0x3196, 0x319F, 8),
+    (0x31A0, 0x31BF, 5),
+    (0x31C0, 0x31EF, 8),
+    (0x31F0, 0x31FF, 5),
+    (0x3200, 0x321F, 8),
+    (0x3220, 0x3229, 9),
+    (0x322A, 0x3247, 8),
+    (0x3248, 0x324F, 9),
+    (0x3250, 0x3250, 8),
+    (0x3251, 0x325F, 9),
+    (0x3260, 0x327F, 8),
+    (0x3280, 0x3289, 9),
+    (0x328A, 0x32B0, 8),
+    (0x32B1, 0x32BF, 9),
+    (0x32C0, 0x33FF, 8),
+    (0x3400, 0x4DBF, 5),
+    (0x4DC0, 0x4DFF, 8),
+    (0x4E00, 0x9FFC, 5),
+    (0x9FFD, 0x9FFF, 8),
+    (0xA000, 0xA48C, 5),
+    (0xA48D, 0xA4CF, 8),
+    (0xA4D0, 0xA4FD, 5),
+    (0xA4FE, 0xA4FF, 8),
+    (0xA500, 0xA60C, 5),
+    (0xA60D, 0xA60F, 8),
+    (0xA610, 0xA61F, 5),
+    (0xA620, 0xA629, 9),
+    (0xA62A, 0xA62B, 5),
+    (0xA62C, 0xA63F, 8),
+    (0xA640, 0xA640, 5),
+    (0xA641, 0xA641, 6),
+    (0xA642, 0xA642, 5),
+    (0xA643, 0xA643, 6),
+    (0xA644, 0xA644, 5),
+    (0xA645, 0xA645, 6),
+    (0xA646, 0xA646, 5),
+    (0xA647, 0xA647, 6),
+    (0xA648, 0xA648, 5),
+    (0xA649, 0xA649, 6),
+    (0xA64A, 0xA64A, 5),
+    (0xA64B, 0xA64B, 6),
+    (0xA64C, 0xA64C, 5),
+    (0xA64D, 0xA64D, 6),
+    (0xA64E, 0xA64E, 5),
+    (0xA64F, 0xA64F, 6),
+    (0xA650, 0xA650, 5),
+    (0xA651, 0xA651, 6),
+    (0xA652, 0xA652, 5),
+    (0xA653, 0xA653, 6),
+    (0xA654, 0xA654, 5),
+    (0xA655, 0xA655, 6),
+    (0xA656, 0xA656, 5),
+    (0xA657, 0xA657, 6),
+    (0xA658, 0xA658, 5),
+    (0xA659, 0xA659, 6),
+    (0xA65A, 0xA65A, 5),
+    (0xA65B, 0xA65B, 6),
+    (0xA65C, 0xA65C, 5),
+    (0xA65D, 0xA65D, 6),
+    (0xA65E, 0xA65E, 5),
+    (0xA65F, 0xA65F, 6),
+    (0xA660, 0xA660, 5),
+    (0xA661, 0xA661, 6),
+    (0xA662, 0xA662, 5),
+    (0xA663, 0xA663, 6),
+    (0xA664, 0xA664, 5),
+    (0xA665, 0xA665, 6),
+    (0xA666, 0xA666, 5),
+    (0xA667, 0xA667, 6),
+    (0xA668, 0xA668, 5),
+    (0xA669, 0xA669, 6),
+    (0xA66A, 0xA66A, 5),
+    (0xA66B, 0xA66B, 6),
+    (0xA66C, 0xA66C, 5),
+    (0xA66D, 0xA66D, 6),
+    (0xA66E, 0xA66E, 5),
+    (0xA66F, 0xA672, -1),
+    (0xA673, 0xA673, 8),
+    (0xA674, 0xA67D, -1),
+    (0xA67E, 0xA67E, 8),
+    (0xA67F, 0xA680, 5),
+    (0xA681, 0xA681, 6),
+    (0xA682, 0xA682, 5),
+    (0xA683, 0xA683, 6),
+    (0xA684, 0xA684, 5),
+    (0xA685, 0xA685, 6),
+    (0xA686, 0xA686, 5),
+    (0xA687, 0xA687, 6),
+    (0xA688, 0xA688, 5),
+    (0xA689, 0xA689, 6),
+    (0xA68A, 0xA68A, 5),
+    (0xA68B, 0xA68B, 6),
+    (0xA68C, 0xA68C, 5),
+    (0xA68D, 0xA68D, 6),
+    (0xA68E, 0xA68E, 5),
+    (0xA68F, 0xA68F, 6),
+    (0xA690, 0xA690, 5),
+    (0xA691, 0xA691, 6),
+    (0xA692, 0xA692, 5),
+    (0xA693, 0xA693, 6),
+    (0xA694, 0xA694, 5),
+    (0xA695, 0xA695, 6),
+    (0xA696, 0xA696, 5),
+    (0xA697, 0xA697, 6),
+    (0xA698, 0xA698, 5),
+    (0xA699, 0xA699, 6),
+    (0xA69A, 0xA69A, 5),
+    (0xA69B, 0xA69B, 6),
+    (0xA69C, 0xA69D, 5),
+    (0xA69E, 0xA69F, -1),
+    (0xA6A0, 0xA6E5, 5),
+    (0xA6E6, 0xA6EF, 9),
+    (0xA6F0, 0xA6F1, -1),
+    (0xA6F2, 0xA716, 8),
+    (0xA717, 0xA71F, 5),
+    (0xA720, 0xA721, 8),
+    (0xA722, 0xA722, 5),
+    (0xA723, 0xA723, 6),
+    (0xA724, 0xA724, 5),
+    (0xA725, 0xA725, 6),
+    (0xA726, 0xA726, 5),
+    (0xA727, 0xA727, 6),
+    (0xA728, 0xA728, 5),
+    (0xA729, 0xA729, 6),
+    (0xA72A, 0xA72A, 5),
+    (0xA72B, 0xA72B, 6),
+    (0xA72C, 0xA72C, 5),
+    (0xA72D, 0xA72D, 6),
+    (0xA72E, 0xA72E, 5),
+    (0xA72F, 0xA731, 6),
+    (0xA732, 0xA732, 5),
+    (0xA733, 0xA733, 6),
+    (0xA734, 0xA734, 5),
+    (0xA735, 0xA735, 6),
+    (0xA736, 0xA736, 5),
+    (0xA737, 0xA737, 6),
+    (0xA738, 0xA738, 5),
+    (0xA739, 0xA739, 6),
+    (0xA73A, 0xA73A, 5),
+    (0xA73B, 0xA73B, 6),
+    (0xA73C, 0xA73C, 5),
+    (0xA73D, 0xA73D, 6),
+    (0xA73E, 0xA73E, 5),
+    (0xA73F, 0xA73F, 6),
+    (0xA740, 0xA740, 5),
+    (0xA741, 0xA741, 6),
+    (0xA742, 0xA742, 5),
+    (0xA743, 0xA743, 6),
+    (0xA744, 0xA744, 5),
+    (0xA745, 0xA745, 6),
+    (0xA746, 0xA746, 5),
+    (0xA747, 0xA747, 6),
+    (0xA748, 0xA748, 5),
+    (0xA749, 0xA749, 6),
+    (0xA74A, 0xA74A, 5),
+    (0xA74B, 0xA74B, 6),
+    (0xA74C, 0xA74C, 5),
+    (0xA74D, 0xA74D, 6),
+    (0xA74E, 0xA74E, 5),
+    (0xA74F, 0xA74F, 6),
+    (0xA750, 0xA750, 5),
+    (0xA751, 0xA751, 6),
+    (0xA752, 0xA752, 5),
+    (0xA753, 0xA753, 6),
+    (0xA754, 0xA754, 5),
+    (0xA755, 0xA755, 6),
+    (0xA756, 0xA756, 5),
+    (0xA757, 0xA757, 6),
+    (0xA758, 0xA758, 5),
+    (0xA759, 0xA759, 6),
+    (0xA75A, 0xA75A, 5),
+    (0xA75B, 0xA75B, 6),
+    (0xA75C, 0xA75C, 5),
+    (0xA75D, 0xA75D, 6),
+    (0xA75E, 0xA75E, 5),
+    (0xA75F, 0xA75F, 6),
+    (0xA760, 0xA760, 5),
+    (0xA761, 0xA761, 6),
+    (0xA762, 0xA762, 5),
+    (0xA763, 0xA763, 6),
+    (0xA764, 0xA764, 5),
+    (0xA765, 0xA765, 6),
+    (0xA766, 0xA766, 5),
+    (0xA767, 0xA767, 6),
+    (0xA768, 0xA768, 5),
+    (0xA769, 0xA769, 6),
+    (0xA76A, 0xA76A, 5),
+    (0xA76B, 0xA76B, 6),
+    (0xA76C, 0xA76C, 5),
+    (0xA76D, 0xA76D, 6),
+    (0xA76E, 0xA76E, 5),
+    (0xA76F, 0xA76F, 6),
+    (0xA770, 0xA770, 5),
+    (0xA771, 0xA778, 6),
+    (0xA779, 0xA779, 5),
+    (0xA77A, 0xA77A, 6),
+    (0xA77B, 0xA77B, 5),
+    (0xA77C, 0xA77C, 6),
+    (0xA77D, 0xA77E, 5),
+    (0xA77F, 0xA77F, 6),
+    (0xA780, 0xA780, 5),
+    (0xA781, 0xA781, 6),
+    (0xA782, 0xA782, 5),
+    (0xA783, 0xA783, 6),
+    (0xA784, 0xA784, 5),
+    (0xA785, 0xA785, 6),
+    (0xA786, 0xA786, 5),
+    (0xA787, 0xA787, 6),
+    (0xA788, 0xA788, 5),
+    (0xA789, 0xA78A, 8),
+    (0xA78B, 0xA78B, 5),
+    (0xA78C, 0xA78C, 6),
+    (0xA78D, 0xA78D, 5),
+    (0xA78E, 0xA78E, 6),
+    (0xA78F, 0xA790, 5),
+    (0xA791, 0xA791, 6),
+    (0xA792, 0xA792, 5),
+    (0xA793, 0xA795, 6),
+    (0xA796, 0xA796, 5),
+    (0xA797, 0xA797, 6),
+    (0xA798, 0xA798, 5),
+    (0xA799, 0xA799, 6),
+    (0xA79A, 0xA79A, 5),
+    (0xA79B, 0xA79B, 6),
+    (0xA79C, 0xA79C, 5),
+    (0xA79D, 0xA79D, 6),
+    (0xA79E, 0xA79E, 5),
+    (0xA79F, 0xA79F, 6),
+    (0xA7A0, 0xA7A0, 5),
+    (0xA7A1, 0xA7A1, 6),
+    (0xA7A2, 0xA7A2, 5),
+    (0xA7A3, 0xA7A3, 6),
+    (0xA7A4, 0xA7A4, 5),
+    (0xA7A5, 0xA7A5, 6),
+    (0xA7A6, 0xA7A6, 5),
+    (0xA7A7, 0xA7A7, 6),
+    (0xA7A8, 0xA7A8, 5),
+    (0xA7A9, 0xA7A9, 6),
+    (0xA7AA, 0xA7AE, 5),
+    (0xA7AF, 0xA7AF, 6),
+    (0xA7B0, 0xA7B4, 5),
+    (0xA7B5, 0xA7B5, 6),
+    (0xA7B6, 0xA7B6, 5),
+    (0xA7B7, 0xA7B7, 6),
+    (0xA7B8, 0xA7B8, 5),
+    (0xA7B9, 0xA7B9, 6),
+    (0xA7BA, 0xA7BA, 5),
+    (0xA7BB, 0xA7BB, 6),
+    (0xA7BC, 0xA7BC, 5),
+    (0xA7BD, 0xA7BD, 6),
+    (0xA7BE, 0xA7BE, 5),
+    (0xA7BF, 0xA7BF, 6),
+    (0xA7C0, 0xA7C1, 8),
+    (0xA7C2, 0xA7C2, 5),
+    (0xA7C3, 0xA7C3, 6),
+    (0xA7C4, 0xA7C7, 5),
+    (0xA7C8, 0xA7C8, 6),
+    (0xA7C9, 0xA7C9, 5),
+    (0xA7CA, 0xA7CA, 6),
+    (0xA7CB, 0xA7F4, 8),
+    (0xA7F5, 0xA7F5, 5),
+    (0xA7F6, 0xA7F6, 6),
+    (0xA7F7, 0xA7F9, 5),
+    (0xA7FA, 0xA7FA, 6),
+    (0xA7FB, 0xA801, 5),
+    (0xA802, 0xA802, -1),
+    (0xA803, 0xA805, 5),
+    (0xA806, 0xA806, -1),
+    (0xA807, 0xA80A, 5),
+    (0xA80B, 0xA80B, -1),
+    (0xA80C, 0xA822, 5),
+    (0xA823, 0xA824, 8),
+    (0xA825, 0xA826, -1),
+    (0xA827, 0xA82B, 8),
+    (0xA82C, 0xA82C, -1),
+    (0xA82D, 0xA82F, 8),
+    (0xA830, 0xA835, 9),
+    (0xA836, 0xA83F, 8),
+    (0xA840, 0xA873, 5),
+    (0xA874, 0xA881, 8),
+    (0xA882, 0xA8B3, 5),
+    (0xA8B4, 0xA8C3, 8),
+    (0xA8C4, 0xA8C5, -1),
+    (0xA8C6, 0xA8CF, 8),
+    (0xA8D0, 0xA8D9, 9),
+    (0xA8DA, 0xA8DF, 8),
+    (0xA8E0, 0xA8F1, -1),
+    (0xA8F2, 0xA8F7, 5),
+    (0xA8F8, 0xA8FA, 8),
+    (0xA8FB, 0xA8FB, 5),
+    (0xA8FC, 0xA8FC, 8),
+    (0xA8FD, 0xA8FE, 5),
+    (0xA8FF, 0xA8FF, -1),
+    (0xA900, 0xA909, 9),
+    (0xA90A, 0xA925, 5),
+    (0xA926, 0xA92D, -1),
+    (0xA92E, 0xA92F, 8),
+    (0xA930, 0xA946, 5),
+    (0xA947, 0xA951, -1),
+    (0xA952, 0xA95F, 8),
+    (0xA960, 0xA97C, 5),
+    (0xA97D, 0xA97F, 8),
+    (0xA980, 0xA982, -1),
+    (0xA983, 0xA983, 8),
+    (0xA984, 0xA9B2, 5),
+    (0xA9B3, 0xA9B3, -1),
+    (0xA9B4, 0xA9B5, 8),
+    (0xA9B6, 0xA9B9, -1),
+    (0xA9BA, 0xA9BB, 8),
+    (0xA9BC, 0xA9BD, -1),
+    (0xA9BE, 0xA9CE, 8),
+    (0xA9CF, 0xA9CF, 5),
+    (0xA9D0, 0xA9D9, 9),
+    (0xA9DA, 0xA9DF, 8),
+    (0xA9E0, 0xA9E4, 5),
+    (0xA9E5, 0xA9E5, -1),
+    (0xA9E6, 0xA9EF, 5),
+    (0xA9F0, 0xA9F9, 9),
+    (0xA9FA, 0xA9FE, 5),
+    (0xA9FF, 0xA9FF, 8),
+    (0xAA00, 0xAA28, 5),
+    (0xAA29, 0xAA2E, -1),
+    (0xAA2F, 0xAA30, 8),
+    (0xAA31, 0xAA32, -1),
+    (0xAA33, 0xAA34, 8),
+    (0xAA35, 0xAA36, -1),
+    (0xAA37, 0xAA3F, 8),
+    (0xAA40, 0xAA42, 5),
+    (0xAA43, 0xAA43, -1),
+    (0xAA44, 0xAA4B, 5),
+    (0xAA4C, 0xAA4C, -1),
+    (0xAA4D, 0xAA4F, 8),
+    (0xAA50, 0xAA59, 9),
+    (0xAA5A, 0xAA5F, 8),
+    (0xAA60, 0xAA76, 5),
+    (0xAA77, 0xAA79, 8),
+    (0xAA7A, 0xAA7A, 5),
+    (0xAA7B, 0xAA7B, 8),
+    (0xAA7C, 0xAA7C, -1),
+    (0xAA7D, 0xAA7D, 8),
+    (0xAA7E, 0xAAAF, 5),
+    (0xAAB0, 0xAAB0, -1),
+    (0xAAB1, 0xAAB1, 5),
+    (0xAAB2, 0xAAB4, -1),
+    (0xAAB5, 0xAAB6, 5),
+    (0xAAB7, 0xAAB8, -1),
+    (0xAAB9, 0xAABD, 5),
+    (0xAABE, 0xAABF, -1),
+    (0xAAC0, 0xAAC0, 5),
+    (0xAAC1, 0xAAC1, -1),
+    (0xAAC2, 0xAAC2, 5),
+    (0xAAC3, 0xAADA, 8),
+    (0xAADB, 0xAADD, 5),
+    (0xAADE, 0xAADF, 8),
+    (0xAAE0, 0xAAEA, 5),
+    (0xAAEB, 0xAAEB, 8),
+    (0xAAEC, 0xAAED, -1),
+    (0xAAEE, 0xAAF1, 8),
+    (0xAAF2, 0xAAF4, 5),
+    (0xAAF5, 0xAAF5, 8),
+    (0xAAF6, 0xAAF6, -1),
+    (0xAAF7, 0xAB00, 8),
+    (0xAB01, 0xAB06, 5),
+    (0xAB07, 0xAB08, 8),
+    (0xAB09, 0xAB0E, 5),
+    (0xAB0F, 0xAB10, 8),
+    (0xAB11, 0xAB16, 5),
+    (0xAB17, 0xAB1F, 8),
+    (0xAB20, 0xAB26, 5),
+    (0xAB27, 0xAB27, 8),
+    (0xAB28, 0xAB2E, 5),
+    (0xAB2F, 0xAB2F, 8),
+    (0xAB30, 0xAB5A, 6),
+    (0xAB5B, 0xAB5B, 8),
+    (0xAB5C, 0xAB5F, 5),
+    (0xAB60, 0xAB68, 6),
+    (0xAB69, 0xAB69, 5),
+    (0xAB6A, 0xAB6F, 8),
+    (0xAB70, 0xABBF, 6),
+    (0xABC0, 0xABE2, 5),
+    (0xABE3, 0xABE4, 8),
+    (0xABE5, 0xABE5, -1),
+    (0xABE6, 0xABE7, 8),
+    (0xABE8, 0xABE8, -1),
+    (0xABE9, 0xABEC, 8),
+    (0xABED, 0xABED, -1),
+    (0xABEE, 0xABEF, 8),
+    (0xABF0, 0xABF9, 9),
+    (0xABFA, 0xABFF, 8),
+    (0xAC00, 0xD7A3, 5),
+    (0xD7A4, 0xD7AF, 8),
+    (0xD7B0, 0xD7C6, 5),
+    (0xD7C7, 0xD7CA, 8),
+    (0xD7CB, 0xD7FB, 5),
+    (0xD7FC, 0xF8FF, 8),
+    (0xF900, 0xFA6D, 5),
+    (0xFA6E, 0xFA6F, 8),
+    (0xFA70, 0xFAD9, 5),
+    (0xFADA, 0xFAFF, 8),
+    (0xFB00, 0xFB06, 6),
+    (0xFB07, 0xFB12, 8),
+    (0xFB13, 0xFB17, 6),
+    (0xFB18, 0xFB1C, 8),
+    (0xFB1D, 0xFB1D, 5),
+    (0xFB1E, 0xFB1E, -1),
+    (0xFB1F, 0xFB28, 5),
+    (0xFB29, 0xFB29, 8),
+    (0xFB2A, 0xFB36, 5),
+    (0xFB37, 0xFB37, 8),
+    (0xFB38, 0xFB3C, 5),
+    (0xFB3D, 0xFB3D, 8),
+    (0xFB3E, 0xFB3E, 5),
+    (0xFB3F, 0xFB3F, 8),
+    (0xFB40, 0xFB41, 5),
+    (0xFB42, 0xFB42, 8),
+    (0xFB43, 0xFB44, 5),
+    (0xFB45, 0xFB45, 8),
+    (0xFB46, 0xFBB1, 5),
+    (0xFBB2, 0xFBD2, 8),
+    (0xFBD3, 0xFD3D, 5),
+    (0xFD3E, 0xFD3E, 10),
+    (0xFD3F, 0xFD3F, 11),
+    (0xFD40, 0xFD4F, 8),
+    (0xFD50, 0xFD8F, 5),
+    (0xFD90, 0xFD91, 8),
+    (0xFD92, 0xFDC7, 5),
+    (0xFDC8, 0xFDEF, 8),
+    (0xFDF0, 0xFDFB, 5),
+    (0xFDFC, 0xFDFF, 8),
+    (0xFE00, 0xFE0F, -1),
+    (0xFE10, 0xFE16, 8),
+    (0xFE17, 0xFE17, 11),
+    (0xFE18, 0xFE18, 10),
+    (0xFE19, 0xFE1F, 8),
+    (0xFE20, 0xFE2F, -1),
+    (0xFE30, 0xFE34, 8),
+    (0xFE35, 0xFE35, 11),
+    (0xFE36, 0xFE36, 10),
+    (0xFE37, 0xFE37, 11),
+    (0xFE38, 0xFE38, 10),
+    (0xFE39, 0xFE39, 11),
+    (0xFE3A, 0xFE3A, 10),
+    (0xFE3B, 0xFE3B, 11),
+    (0xFE3C, 0xFE3C, 10),
+    (0xFE3D, 0xFE3D, 11),
+    (0xFE3E, 0xFE3E, 10),
+    (0xFE3F, 0xFE3F, 11),
+    (0xFE40, 0xFE40, 10),
+    (0xFE41, 0xFE41, 11),
+    (0xFE42, 0xFE42, 10),
+    (0xFE43, 0xFE43, 11),
+    (0xFE44, 0xFE44, 10),
+    (0xFE45, 0xFE46, 8),
+    (0xFE47, 0xFE47, 11),
+    (0xFE48, 0xFE48, 10),
+    (0xFE49, 0xFE58, 8),
+    (0xFE59, 0xFE59, 11),
+    (0xFE5A, 0xFE5A, 10),
+    (0xFE5B, 0xFE5B, 11),
+    (0xFE5C, 0xFE5C, 10),
+    (0xFE5D, 0xFE5D, 11),
+    (0xFE5E, 0xFE5E, 10),
+    (0xFE5F, 0xFE6F, 8),
+    (0xFE70, 0xFE74, 5),
+    (0xFE75, 0xFE75, 8),
+    (0xFE76, 0xFEFC, 5),
+    (0xFEFD, 0xFEFE, 8),
+    (0xFEFF, 0xFEFF, -1),
+    (0xFF00, 0xFF00, 8),
+    (0xFF01, 0xFF01, 4),
+    (0xFF02, 0xFF07, 8),
+    (0xFF08, 0xFF08, 11),
+    (0xFF09, 0xFF09, 10),
+    (0xFF0A, 0xFF0D, 8),
+    (0xFF0E, 0xFF0E, 2),
+    (0xFF0F, 0xFF0F, 8),
+    (0xFF10, 0xFF19, 9),
+    (0xFF1A, 0xFF1E, 8),
+    (0xFF1F, 0xFF1F, 4),
+    (0xFF20, 0xFF20, 8),
+    (0xFF21, 0xFF3A, 5),
+    (0xFF3B, 0xFF3B, 11),
+    (0xFF3C, 0xFF3C, 8),
+    (0xFF3D, 0xFF3D, 10),
+    (0xFF3E, 0xFF40, 8),
+    (0xFF41, 0xFF5A, 6),
+    (0xFF5B, 0xFF5B, 11),
+    (0xFF5C, 0xFF5C, 8),
+    (0xFF5D, 0xFF5D, 10),
+    (0xFF5E, 0xFF5E, 8),
+    (0xFF5F, 0xFF5F, 11),
+    (0xFF60, 0xFF60, 10),
+    (0xFF61, 0xFF61, 8),
+    (0xFF62, 0xFF62, 11),
+    (0xFF63, 0xFF63, 10),
+    (0xFF64, 0xFF65, 8),
+    (0xFF66, 0xFFBE, 5),
+    (0xFFBF, 0xFFC1, 8),
+    (0xFFC2, 0xFFC7, 5),
+    (0xFFC8, 0xFFC9, 8),
+    (0xFFCA, 0xFFCF, 5),
+    (0xFFD0, 0xFFD1, 8),
+    (0xFFD2, 0xFFD7, 5),
+    (0xFFD8, 0xFFD9, 8),
+    (0xFFDA, 0xFFDC, 5),
+    (0xFFDD, 0xFFF8, 8),
+    (0xFFF9, 0xFFFB, -1),
+    (0xFFFC, 0xFFFF, 8),
+    (0x10000, 0x1000B, 5),
+    (0x1000C, 0x1000C, 8),
+    (0x1000D, 0x10026, 5),
+    (0x10027, 0x10027, 8),
+    (0x10028, 0x1003A, 5),
+    (0x1003B, 0x1003B, 8),
+    (0x1003C, 0x1003D, 5),
+    (0x1003E, 0x1003E, 8),
+    (0x1003F, 0x1004D, 5),
+    (0x1004E, 0x1004F, 8),
+    (0x10050, 0x1005D, 5),
+    (0x1005E, 0x1007F, 8),
+    (0x10080, 0x100FA, 5),
+    (0x100FB, 0x10106, 8),
+    (0x10107, 0x10133, 9),
+    (0x10134, 0x1013F, 8),
+    (0x10140, 0x10178, 9),
+    (0x10179, 0x10189, 8),
+    (0x1018A, 0x1018B, 9),
+    (0x1018C, 0x101FC, 8),
+    (0x101FD, 0x101FD, 0),
+    (0x101FE, 0x1027F, 8),
+    (0x10280, 0x1029C, 5),
+    (0x1029D, 0x1029F, 8),
+    (0x102A0, 0x102D0, 5),
+    (0x102D1, 0x102DF, 8),
+    (0x102E0, 0x102E0, 0),
+    (0x102E1, 0x102FB, 9),
+    (0x102FC, 0x102FF, 8),
+    (0x10300, 0x1031F, 5),
+    (0x10320, 0x10323, 9),
+    (0x10324, 0x1032C, 8),
+    (0x1032D, 0x10340, 5),
+    (0x10341, 0x10341, 9),
+    (0x10342, 0x10349, 5),
+    (0x1034A, 0x1034A, 9),
+    (0x1034B, 0x1034F, 8),
+    (0x10350, 0x10375, 5),
+    (0x10376, 0x10379, -1),
+    (0x1037A, 0x1037A, 0),
+    (0x1037B, 0x1037F, 8),
+    (0x10380, 0x1039D, 5),
+    (0x1039E, 0x1039F, 8),
+    (0x103A0, 0x103C3, 5),
+    (0x103C4, 0x103C7, 8),
+    (0x103C8, 0x103CF, 5),
+    (0x103D0, 0x103D0, 8),
+    (0x103D1, 0x103D5, 9),
+    (0x103D6, 0x103FF, 8),
+    (0x10400, 0x10427, 5),
+    (0x10428, 0x1044F, 6),
+    (0x10450, 0x1049D, 5),
+    (0x1049E, 0x1049F, 8),
+    (0x104A0, 0x104A9, 9),
+    (0x104AA, 0x104AF, 8),
+    (0x104B0, 0x104D3, 5),
+    (0x104D4, 0x104D7, 8),
+    (0x104D8, 0x104FB, 6),
+    (0x104FC, 0x104FF, 8),
+    (0x10500, 0x10527, 5),
+    (0x10528, 0x1052F, 8),
+    (0x10530, 0x10563, 5),
+    (0x10564, 0x105FF, 8),
+    (0x10600, 0x10736, 5),
+    (0x10737, 0x1073F, 8),
+    (0x10740, 0x10755, 5),
+    (0x10756, 0x1075F, 8),
+    (0x10760, 0x10767, 5),
+    (0x10768, 0x107FF, 8),
+    (0x10800, 0x10805, 5),
+    (0x10806, 0x10807, 8),
+    (0x10808, 0x10808, 5),
+    (0x10809, 0x10809, 8),
+    (0x1080A, 0x10835, 5),
+    (0x10836, 0x10836, 8),
+    (0x10837, 0x10838, 5),
+    (0x10839, 0x1083B, 8),
+    (0x1083C, 0x1083C, 5),
+    (0x1083D, 0x1083E, 8),
+    (0x1083F, 0x10855, 5),
+    (0x10856, 0x10857, 8),
+    (0x10858, 0x1085F, 9),
+    (0x10860, 0x10876, 5),
+    (0x10877, 0x10878, 8),
+    (0x10879, 0x1087F, 9),
+    (0x10880, 0x1089E, 5),
+    (0x1089F, 0x108A6, 8),
+    (0x108A7, 0x108AF, 9),
+    (0x108B0, 0x108DF, 8),
+    (0x108E0, 0x108F2, 5),
+    (0x108F3, 0x108F3, 8),
+    (0x108F4, 0x108F5, 5),
+    (0x108F6, 0x108FA, 8),
+    (0x108FB, 0x108FF, 9),
+    (0x10900, 0x10915, 5),
+    (0x10916, 0x1091B, 9),
+    (0x1091C, 0x1091F, 8),
+    (0x10920, 0x10939, 5),
+    (0x1093A, 0x1097F, 8),
+    (0x10980, 0x109B7, 5),
+    (0x109B8, 0x109BB, 8),
+    (0x109BC, 0x109BD, 9),
+    (0x109BE, 0x109BF, 5),
+    (0x109C0, 0x109CF, 9),
+    (0x109D0, 0x109D1, 8),
+    (0x109D2, 0x109FF, 9),
+    (0x10A00, 0x10A00, 5),
+    (0x10A01, 0x10A02, -1),
+    (0x10A03, 0x10A03, 0),
+    (0x10A04, 0x10A04, 8),
+    (0x10A05, 0x10A06, -1),
+    (0x10A07, 0x10A0B, 8),
+    (0x10A0C, 0x10A0E, -1),
+    (0x10A0F, 0x10A0F, 0),
+    (0x10A10, 0x10A13, 5),
+    (0x10A14, 0x10A14, 8),
+    (0x10A15, 0x10A17, 5),
+    (0x10A18, 0x10A18, 8),
+    (0x10A19, 0x10A35, 5),
+    (0x10A36, 0x10A37, 8),
+    (0x10A38, 0x10A39, -1),
+    (0x10A3A, 0x10A3A, 0),
+    (0x10A3B, 0x10A3E, 8),
+    (0x10A3F, 0x10A3F, 0),
+    (0x10A40, 0x10A48, 9),
+    (0x10A49, 0x10A5F, 8),
+    (0x10A60, 0x10A7C, 5),
+    (0x10A7D, 0x10A7E, 9),
+    (0x10A7F, 0x10A7F, 8),
+    (0x10A80, 0x10A9C, 5),
+    (0x10A9D, 0x10A9F, 9),
+    (0x10AA0, 0x10ABF, 8),
+    (0x10AC0, 0x10AC7, 5),
+    (0x10AC8, 0x10AC8, 8),
+    (0x10AC9, 0x10AE4, 5),
+    (0x10AE5, 0x10AE6, -1),
+    (0x10AE7, 0x10AEA, 8),
+    (0x10AEB, 0x10AEF, 9),
+    (0x10AF0, 0x10AFF, 8),
+    (0x10B00, 0x10B35, 5),
+    (0x10B36, 0x10B3F, 8),
+    (0x10B40, 0x10B55, 5),
+    (0x10B56, 0x10B57, 8),
+    (0x10B58, 0x10B5F, 9),
+    (0x10B60, 0x10B72, 5),
+    (0x10B73, 0x10B77, 8),
+    (0x10B78, 0x10B7F, 9),
+    (0x10B80, 0x10B91, 5),
+    (0x10B92, 0x10BA8, 8),
+    (0x10BA9, 0x10BAF, 9),
+    (0x10BB0, 0x10BFF, 8),
+    (0x10C00, 0x10C48, 5),
+    (0x10C49, 0x10C7F, 8),
+    (0x10C80, 0x10CB2, 5),
+    (0x10CB3, 0x10CBF, 8),
+    (0x10CC0, 0x10CF2, 6),
+    (0x10CF3, 0x10CF9, 8),
+    (0x10CFA, 0x10CFF, 9),
+    (0x10D00, 0x10D23, 5),
+    (0x10D24, 0x10D26, -1),
+    (0x10D27, 0x10D27, 0),
+    (0x10D28, 0x10D2F, 8),
+    (0x10D30, 0x10D39, 9),
+    (0x10D3A, 0x10E5F, 8),
+    (0x10E60, 0x10E7E, 9),
+    (0x10E7F, 0x10E7F, 8),
+    (0x10E80, 0x10EA9, 5),
+    (0x10EAA, 0x10EAA, 8),
+    (0x10EAB, 0x10EAC, -1),
+    (0x10EAD, 0x10EAF, 8),
+    (0x10EB0, 0x10EB1, 5),
+    (0x10EB2, 0x10EFF, 8),
+    (0x10F00, 0x10F1C, 5),
+    (0x10F1D, 0x10F26, 9),
+    (0x10F27, 0x10F27, 5),
+    (0x10F28, 0x10F2F, 8),
+    (0x10F30, 0x10F45, 5),
+    (0x10F46, 0x10F4F, -1),
+    (0x10F50, 0x10F50, 0),
+    (0x10F51, 0x10F54, 9),
+    (0x10F55, 0x10FAF, 8),
+    (0x10FB0, 0x10FC4, 5),
+    (0x10FC5, 0x10FCB, 9),
+    (0x10FCC, 0x10FDF, 8),
+    (0x10FE0, 0x10FF6, 5),
+    (0x10FF7, 0x11000, 8),
+    (0x11001, 0x11001, 0),
+    (0x11002, 0x11002, 8),
+    (0x11003, 0x11037, 5),
+    (0x11038, 0x11045, -1),
+    (0x11046, 0x11046, 0),
+    (0x11047, 0x11051, 8),
+    (0x11052, 0x1106F, 9),
+    (0x11070, 0x1107E, 8),
+    (0x1107F, 0x11080, -1),
+    (0x11081, 0x11081, 0),
+    (0x11082, 0x11082, 8),
+    (0x11083, 0x110AF, 5),
+    (0x110B0, 0x110B2, 8),
+    (0x110B3, 0x110B5, -1),
+    (0x110B6, 0x110B6, 0),
+    (0x110B7, 0x110B8, 8),
+    (0x110B9, 0x110BA, -1),
+    (0x110BB, 0x110BC, 8),
+    (0x110BD, 0x110BD, 0),
+    (0x110BE, 0x110CC, 8),
+    (0x110CD, 0x110CD, 0),
+    (0x110CE, 0x110CF, 8),
+    (0x110D0, 0x110E8, 5),
+    (0x110E9, 0x110EF, 8),
+    (0x110F0, 0x110F9, 9),
+    (0x110FA, 0x110FF, 8),
+    (0x11100, 0x11101, -1),
+    (0x11102, 0x11102, 0),
+    (0x11103, 0x11126, 5),
+    (0x11127, 0x1112A, -1),
+    (0x1112B, 0x1112B, 0),
+    (0x1112C, 0x1112C, 8),
+    (0x1112D, 0x11133, -1),
+    (0x11134, 0x11134, 0),
+    (0x11135, 0x11135, 8),
+    (0x11136, 0x1113F, 9),
+    (0x11140, 0x11143, 8),
+    (0x11144, 0x11144, 5),
+    (0x11145, 0x11146, 8),
+    (0x11147, 0x11147, 5),
+    (0x11148, 0x1114F, 8),
+    (0x11150, 0x11172, 5),
+    (0x11173, 0x11173, 0),
+    (0x11174, 0x11175, 8),
+    (0x11176, 0x11176, 5),
+    (0x11177, 0x1117F, 8),
+    (0x11180, 0x11181, -1),
+    (0x11182, 0x11182, 8),
+    (0x11183, 0x111B2, 5),
+    (0x111B3, 0x111B5, 8),
+    (0x111B6, 0x111BD, -1),
+    (0x111BE, 0x111BE, 0),
+    (0x111BF, 0x111C0, 8),
+    (0x111C1, 0x111C4, 5),
+    (0x111C5, 0x111C8, 8),
+    (0x111C9, 0x111CB, -1),
+    (0x111CC, 0x111CC, 0),
+    (0x111CD, 0x111CE, 8),
+    (0x111CF, 0x111CF, 0),
+    (0x111D0, 0x111D9, 9),
+    (0x111DA, 0x111DA, 5),
+    (0x111DB, 0x111DB, 8),
+    (0x111DC, 0x111DC, 5),
+    (0x111DD, 0x111E0, 8),
+    (0x111E1, 0x111F4, 9),
+    (0x111F5, 0x111FF, 8),
+    (0x11200, 0x11211, 5),
+    (0x11212, 0x11212, 8),
+    (0x11213, 0x1122B, 5),
+    (0x1122C, 0x1122E, 8),
+    (0x1122F, 0x11230, -1),
+    (0x11231, 0x11231, 0),
+    (0x11232, 0x11233, 8),
+    (0x11234, 0x11234, 0),
+    (0x11235, 0x11235, 8),
+    (0x11236, 0x11237, -1),
+    (0x11238, 0x1123D, 8),
+    (0x1123E, 0x1123E, 0),
+    (0x1123F, 0x1127F, 8),
+    (0x11280, 0x11286, 5),
+    (0x11287, 0x11287, 8),
+    (0x11288, 0x11288, 5),
+    (0x11289, 0x11289, 8),
+    (0x1128A, 0x1128D, 5),
+    (0x1128E, 0x1128E, 8),
+    (0x1128F, 0x1129D, 5),
+    (0x1129E, 0x1129E, 8),
+    (0x1129F, 0x112A8, 5),
+    (0x112A9, 0x112AF, 8),
+    (0x112B0, 0x112DE, 5),
+    (0x112DF, 0x112DF, 0),
+    (0x112E0, 0x112E2, 8),
+    (0x112E3, 0x112E9, -1),
+    (0x112EA, 0x112EA, 0),
+    (0x112EB, 0x112EF, 8),
+    (0x112F0, 0x112F9, 9),
+    (0x112FA, 0x112FF, 8),
+    (0x11300, 0x11301, -1),
+    (0x11302, 0x11304, 8),
+    (0x11305, 0x1130C, 5),
+    (0x1130D, 0x1130E, 8),
+    (0x1130F, 0x11310, 5),
+    (0x11311, 0x11312, 8),
+    (0x11313, 0x11328, 5),
+    (0x11329, 0x11329, 8),
+    (0x1132A, 0x11330, 5),
+    (0x11331, 0x11331, 8),
+    (0x11332, 0x11333, 5),
+    (0x11334, 0x11334, 8),
+    (0x11335, 0x11339, 5),
+    (0x1133A, 0x1133A, 8),
+    (0x1133B, 0x1133C, -1),
+    (0x1133D, 0x1133D, 5),
+    (0x1133E, 0x1133F, 8),
+    (0x11340, 0x11340, 0),
+    (0x11341, 0x1134F, 8),
+    (0x11350, 0x11350, 5),
+    (0x11351, 0x1135C, 8),
+    (0x1135D, 0x11361, 5),
+    (0x11362, 0x11365, 8),
+    (0x11366, 0x1136B, -1),
+    (0x1136C, 0x1136C, 0),
+    (0x1136D, 0x1136F, 8),
+    (0x11370, 0x11373, -1),
+    (0x11374, 0x11374, 0),
+    (0x11375, 0x113FF, 8),
+    (0x11400, 0x11434, 5),
+    (0x11435, 0x11437, 8),
+    (0x11438, 0x1143E, -1),
+    (0x1143F, 0x1143F, 0),
+    (0x11440, 0x11441, 8),
+    (0x11442, 0x11443, -1),
+    (0x11444, 0x11444, 0),
+    (0x11445, 0x11445, 8),
+    (0x11446, 0x11446, 0),
+    (0x11447, 0x1144A, 5),
+    (0x1144B, 0x1144F, 8),
+    (0x11450, 0x11459, 9),
+    (0x1145A, 0x1145D, 8),
+    (0x1145E, 0x1145E, 0),
+    (0x1145F, 0x11461, 5),
+    (0x11462, 0x1147F, 8),
+    (0x11480, 0x114AF, 5),
+    (0x114B0, 0x114B2, 8),
+    (0x114B3, 0x114B7, -1),
+    (0x114B8, 0x114B8, 0),
+    (0x114B9, 0x114B9, 8),
+    (0x114BA, 0x114BA, 0),
+    (0x114BB, 0x114BE, 8),
+    (0x114BF, 0x114C0, -1),
+    (0x114C1, 0x114C1, 8),
+    (0x114C2, 0x114C3, -1),
+    (0x114C4, 0x114C5, 5),
+    (0x114C6, 0x114C6, 8),
+    (0x114C7, 0x114C7, 5),
+    (0x114C8, 0x114CF, 8),
+    (0x114D0, 0x114D9, 9),
+    (0x114DA, 0x1157F, 8),
+    (0x11580, 0x115AE, 5),
+    (0x115AF, 0x115B1, 8),
+    (0x115B2, 0x115B4, -1),
+    (0x115B5, 0x115B5, 0),
+    (0x115B6, 0x115BB, 8),
+    (0x115BC, 0x115BD, -1),
+    (0x115BE, 0x115BE, 8),
+    (0x115BF, 0x115C0, -1),
+    (0x115C1, 0x115D7, 8),
+    (0x115D8, 0x115DB, 5),
+    (0x115DC, 0x115DD, -1),
+    (0x115DE, 0x115FF, 8),
+    (0x11600, 0x1162F, 5),
+    (0x11630, 0x11632, 8),
+    (0x11633, 0x11639, -1),
+    (0x1163A, 0x1163A, 0),
+    (0x1163B, 0x1163C, 8),
+    (0x1163D, 0x1163D, 0),
+    (0x1163E, 0x1163E, 8),
+    (0x1163F, 0x11640, -1),
+    (0x11641, 0x11643, 8),
+    (0x11644, 0x11644, 5),
+    (0x11645, 0x1164F, 8),
+    (0x11650, 0x11659, 9),
+    (0x1165A, 0x1167F, 8),
+    (0x11680, 0x116AA, 5),
+    (0x116AB, 0x116AB, 0),
+    (0x116AC, 0x116AC, 8),
+    (0x116AD, 0x116AD, 0),
+    (0x116AE, 0x116AF, 8),
+    (0x116B0, 0x116B4, -1),
+    (0x116B5, 0x116B5, 0),
+    (0x116B6, 0x116B6, 8),
+    (0x116B7, 0x116B7, 0),
+    (0x116B8, 0x116B8, 5),
+    (0x116B9, 0x116BF, 8),
+    (0x116C0, 0x116C9, 9),
+    (0x116CA, 0x116FF, 8),
+    (0x11700, 0x1171A, 5),
+    (0x1171B, 0x1171C, 8),
+    (0x1171D, 0x1171E, -1),
+    (0x1171F, 0x1171F, 0),
+    (0x11720, 0x11721, 8),
+    (0x11722, 0x11724, -1),
+    (0x11725, 0x11725, 0),
+    (0x11726, 0x11726, 8),
+    (0x11727, 0x1172A, -1),
+    (0x1172B, 0x1172B, 0),
+    (0x1172C, 0x1172F, 8),
+    (0x11730, 0x1173B, 9),
+    (0x1173C, 0x117FF, 8),
+    (0x11800, 0x1182B, 5),
+    (0x1182C, 0x1182E, 8),
+    (0x1182F, 0x11836, -1),
+    (0x11837, 0x11837, 0),
+    (0x11838, 0x11838, 8),
+    (0x11839, 0x1183A, -1),
+    (0x1183B, 0x1189F, 8),
+    (0x118A0, 0x118BF, 5),
+    (0x118C0, 0x118DF, 6),
+    (0x118E0, 0x118F2, 9),
+    (0x118F3, 0x118FE, 8),
+    (0x118FF, 0x11906, 5),
+    (0x11907, 0x11908, 8),
+    (0x11909, 0x11909, 5),
+    (0x1190A, 0x1190B, 8),
+    (0x1190C, 0x11913, 5),
+    (0x11914, 0x11914, 8),
+    (0x11915, 0x11916, 5),
+    (0x11917, 0x11917, 8),
+    (0x11918, 0x1192F, 5),
+    (0x11930, 0x1193A, 8),
+    (0x1193B, 0x1193C, -1),
+    (0x1193D, 0x1193D, 8),
+    (0x1193E, 0x1193E, 0),
+    (0x1193F, 0x1193F, 5),
+    (0x11940, 0x11940, 8),
+    (0x11941, 0x11941, 5),
+    (0x11942, 0x11942, 8),
+    (0x11943, 0x11943, 0),
+    (0x11944, 0x1194F, 8),
+    (0x11950, 0x11959, 9),
+    (0x1195A, 0x1199F, 8),
+    (0x119A0, 0x119A7, 5),
+    (0x119A8, 0x119A9, 8),
+    (0x119AA, 0x119D0, 5),
+    (0x119D1, 0x119D3, 8),
+    (0x119D4, 0x119D6, -1),
+    (0x119D7, 0x119D7, 0),
+    (0x119D8, 0x119D9, 8),
+    (0x119DA, 0x119DB, -1),
+    (0x119DC, 0x119DF, 8),
+    (0x119E0, 0x119E0, 0),
+    (0x119E1, 0x119E1, 5),
+    (0x119E2, 0x119E2, 8),
+    (0x119E3, 0x119E3, 5),
+    (0x119E4, 0x119FF, 8),
+    (0x11A00, 0x11A00, 5),
+    (0x11A01, 0x11A09, -1),
+    (0x11A0A, 0x11A0A, 0),
+    (0x11A0B, 0x11A32, 5),
+    (0x11A33, 0x11A37, -1),
+    (0x11A38, 0x11A38, 0),
+    (0x11A39, 0x11A39, 8),
+    (0x11A3A, 0x11A3A, 5),
+    (0x11A3B, 0x11A3D, -1),
+    (0x11A3E, 0x11A3E, 0),
+    (0x11A3F, 0x11A46, 8),
+    (0x11A47, 0x11A47, 0),
+    (0x11A48, 0x11A4F, 8),
+    (0x11A50, 0x11A50, 5),
+    (0x11A51, 0x11A55, -1),
+    (0x11A56, 0x11A56, 0),
+    (0x11A57, 0x11A58, 8),
+    (0x11A59, 0x11A5A, -1),
+    (0x11A5B, 0x11A5B, 0),
+    (0x11A5C, 0x11A89, 5),
+    (0x11A8A, 0x11A95, -1),
+    (0x11A96, 0x11A96, 0),
+    (0x11A97, 0x11A97, 8),
+    (0x11A98, 0x11A99, -1),
+    (0x11A9A, 0x11A9C, 8),
+    (0x11A9D, 0x11A9D, 5),
+    (0x11A9E, 0x11ABF, 8),
+    (0x11AC0, 0x11AF8, 5),
+    (0x11AF9, 0x11BFF, 8),
+    (0x11C00, 0x11C08, 5),
+    (0x11C09, 0x11C09, 8),
+    (0x11C0A, 0x11C2E, 5),
+    (0x11C2F, 0x11C2F, 8),
+    (0x11C30, 0x11C35, -1),
+    (0x11C36, 0x11C36, 0),
+    (0x11C37, 0x11C37, 8),
+    (0x11C38, 0x11C3C, -1),
+    (0x11C3D, 0x11C3D, 0),
+    (0x11C3E, 0x11C3E, 8),
+    (0x11C3F, 0x11C3F, 0),
+    (0x11C40, 0x11C40, 5),
+    (0x11C41, 0x11C4F, 8),
+    (0x11C50, 0x11C6C, 9),
+    (0x11C6D, 0x11C71, 8),
+    (0x11C72, 0x11C8F, 5),
+    (0x11C90, 0x11C91, 8),
+    (0x11C92, 0x11CA6, -1),
+    (0x11CA7, 0x11CA7, 0),
+    (0x11CA8, 0x11CA9, 8),
+    (0x11CAA, 0x11CAF, -1),
+    (0x11CB0, 0x11CB0, 0),
+    (0x11CB1, 0x11CB1, 8),
+    (0x11CB2, 0x11CB3, -1),
+    (0x11CB4, 0x11CB4, 8),
+    (0x11CB5, 0x11CB6, -1),
+    (0x11CB7, 0x11CFF, 8),
+    (0x11D00, 0x11D06, 5),
+    (0x11D07, 0x11D07, 8),
+    (0x11D08, 0x11D09, 5),
+    (0x11D0A, 0x11D0A, 8),
+    (0x11D0B, 0x11D30, 5),
+    (0x11D31, 0x11D35, -1),
+    (0x11D36, 0x11D36, 0),
+    (0x11D37, 0x11D39, 8),
+    (0x11D3A, 0x11D3A, 0),
+    (0x11D3B, 0x11D3B, 8),
+    (0x11D3C, 0x11D3D, -1),
+    (0x11D3E, 0x11D3E, 8),
+    (0x11D3F, 0x11D44, -1),
+    (0x11D45, 0x11D45, 0),
+    (0x11D46, 0x11D46, 5),
+    (0x11D47, 0x11D47, 0),
+    (0x11D48, 0x11D4F, 8),
+    (0x11D50, 0x11D59, 9),
+    (0x11D5A, 0x11D5F, 8),
+    (0x11D60, 0x11D65, 5),
+    (0x11D66, 0x11D66, 8),
+    (0x11D67, 0x11D68, 5),
+    (0x11D69, 0x11D69, 8),
+    (0x11D6A, 0x11D89, 5),
+    (0x11D8A, 0x11D8F, 8),
+    (0x11D90, 0x11D91, -1),
+    (0x11D92, 0x11D94, 8),
+    (0x11D95, 0x11D95, 0),
+    (0x11D96, 0x11D96, 8),
+    (0x11D97, 0x11D97, 0),
+    (0x11D98, 0x11D98, 5),
+    (0x11D99, 0x11D9F, 8),
+    (0x11DA0, 0x11DA9, 9),
+    (0x11DAA, 0x11EDF, 8),
+    (0x11EE0, 0x11EF2, 5),
+    (0x11EF3, 0x11EF4, -1),
+    (0x11EF5, 0x11FAF, 8),
+    (0x11FB0, 0x11FB0, 5),
+    (0x11FB1, 0x11FBF, 8),
+    (0x11FC0, 0x11FD4, 9),
+    (0x11FD5, 0x11FFF, 8),
+    (0x12000, 0x12399, 5),
+    (0x1239A, 0x123FF, 8),
+    (0x12400, 0x1246E, 9),
+    (0x1246F, 0x1247F, 8),
+    (0x12480, 0x12543, 5),
+    (0x12544, 0x12FFF, 8),
+    (0x13000, 0x1342E, 5),
+    (0x1342F, 0x1342F, 8),
+    (0x13430, 0x13437, -1),
+    (0x13438, 0x13438, 0),
+    (0x13439, 0x143FF, 8),
+    (0x14400, 0x14646, 5),
+    (0x14647, 0x167FF, 8),
+    (0x16800, 0x16A38, 5),
+    (0x16A39, 0x16A3F, 8),
+    (0x16A40, 0x16A5E, 5),
+    (0x16A5F, 0x16A5F, 8),
+    (0x16A60, 0x16A69, 9),
+    (0x16A6A, 0x16ACF, 8),
+    (0x16AD0, 0x16AED, 5),
+    (0x16AEE, 0x16AEF, 8),
+    (0x16AF0, 0x16AF3, -1),
+    (0x16AF4, 0x16AF4, 0),
+    (0x16AF5, 0x16AFF, 8),
+    (0x16B00, 0x16B2F, 5),
+    (0x16B30, 0x16B35, -1),
+    (0x16B36, 0x16B36, 0),
+    (0x16B37, 0x16B3F, 8),
+    (0x16B40, 0x16B43, 5),
+    (0x16B44, 0x16B4F, 8),
+    (0x16B50, 0x16B59, 9),
+    (0x16B5A, 0x16B5A, 8),
+    (0x16B5B, 0x16B61, 9),
+    (0x16B62, 0x16B62, 8),
+    (0x16B63, 0x16B77, 5),
+    (0x16B78, 0x16B7C, 8),
+    (0x16B7D, 0x16B8F, 5),
+    (0x16B90, 0x16E3F, 8),
+    (0x16E40, 0x16E5F, 5),
+    (0x16E60, 0x16E7F, 6),
+    (0x16E80, 0x16E96, 9),
+    (0x16E97, 0x16EFF, 8),
+    (0x16F00, 0x16F4A, 5),
+    (0x16F4B, 0x16F4E, 8),
+    (0x16F4F, 0x16F4F, 0),
+    (0x16F50, 0x16F50, 5),
+    (0x16F51, 0x16F8E, 8),
+    (0x16F8F, 0x16F91, -1),
+    (0x16F92, 0x16F92, 0),
+    (0x16F93, 0x16F9F, 5),
+    (0x16FA0, 0x16FDF, 8),
+    (0x16FE0, 0x16FE1, 5),
+    (0x16FE2, 0x16FE2, 8),
+    (0x16FE3, 0x16FE3, 5),
+    (0x16FE4, 0x16FE4, 0),
+    (0x16FE5, 0x16FFF, 8),
+    (0x17000, 0x187F7, 5),
+    (0x187F8, 0x187FF, 8),
+    (0x18800, 0x18D08, 5),
+    (0x18D09, 0x1AFFF, 8),
+    (0x1B000, 0x1B11E, 5),
+    (0x1B11F, 0x1B14F, 8),
+    (0x1B150, 0x1B152, 5),
+    (0x1B153, 0x1B163, 8),
+    (0x1B164, 0x1B167, 5),
+    (0x1B168, 0x1B16F, 8),
+    (0x1B170, 0x1B2FB, 5),
+    (0x1B2FC, 0x1BBFF, 8),
+    (0x1BC00, 0x1BC6A, 5),
+    (0x1BC6B, 0x1BC6F, 8),
+    (0x1BC70, 0x1BC7C, 5),
+    (0x1BC7D, 0x1BC7F, 8),
+    (0x1BC80, 0x1BC88, 5),
+    (0x1BC89, 0x1BC8F, 8),
+    (0x1BC90, 0x1BC99, 5),
+    (0x1BC9A, 0x1BC9C, 8),
+    (0x1BC9D, 0x1BC9E, -1),
+    (0x1BC9F, 0x1BC9F, 8),
+    (0x1BCA0, 0x1BCA2, -1),
+    (0x1BCA3, 0x1BCA3, 0),
+    (0x1BCA4, 0x1D166, 8),
+    (0x1D167, 0x1D168, -1),
+    (0x1D169, 0x1D169, 0),
+    (0x1D16A, 0x1D172, 8),
+    (0x1D173, 0x1D181, -1),
+    (0x1D182, 0x1D182, 0),
+    (0x1D183, 0x1D184, 8),
+    (0x1D185, 0x1D18A, -1),
+    (0x1D18B, 0x1D18B, 0),
+    (0x1D18C, 0x1D1A9, 8),
+    (0x1D1AA, 0x1D1AC, -1),
+    (0x1D1AD, 0x1D1AD, 0),
+    (0x1D1AE, 0x1D241, 8),
+    (0x1D242, 0x1D243, -1),
+    (0x1D244, 0x1D244, 0),
+    (0x1D245, 0x1D2DF, 8),
+    (0x1D2E0, 0x1D2F3, 9),
+    (0x1D2F4, 0x1D35F, 8),
+    (0x1D360, 0x1D378, 9),
+    (0x1D379, 0x1D3FF, 8),
+    (0x1D400, 0x1D419, 5),
+    (0x1D41A, 0x1D433, 6),
+    (0x1D434, 0x1D44D, 5),
+    (0x1D44E, 0x1D454, 6),
+    (0x1D455, 0x1D455, 8),
+    (0x1D456, 0x1D467, 6),
+    (0x1D468, 0x1D481, 5),
+    (0x1D482, 0x1D49B, 6),
+    (0x1D49C, 0x1D49C, 5),
+    (0x1D49D, 0x1D49D, 8),
+    (0x1D49E, 0x1D49F, 5),
+    (0x1D4A0, 0x1D4A1, 8),
+    (0x1D4A2, 0x1D4A2, 5),
+    (0x1D4A3, 0x1D4A4, 8),
+    (0x1D4A5, 0x1D4A6, 5),
+    (0x1D4A7, 0x1D4A8, 8),
+    (0x1D4A9, 0x1D4AC, 5),
+    (0x1D4AD, 0x1D4AD, 8),
+    (0x1D4AE, 0x1D4B5, 5),
+    (0x1D4B6, 0x1D4B9, 6),
+    (0x1D4BA, 0x1D4BA, 8),
+    (0x1D4BB, 0x1D4BB, 6),
+    (0x1D4BC, 0x1D4BC, 8),
+    (0x1D4BD, 0x1D4C3, 6),
+    (0x1D4C4, 0x1D4C4, 8),
+    (0x1D4C5, 0x1D4CF, 6),
+    (0x1D4D0, 0x1D4E9, 5),
+    (0x1D4EA, 0x1D503, 6),
+    (0x1D504, 0x1D505, 5),
+    (0x1D506, 0x1D506, 8),
+    (0x1D507, 0x1D50A, 5),
+    (0x1D50B, 0x1D50C, 8),
+    (0x1D50D, 0x1D514, 5),
+    (0x1D515, 0x1D515, 8),
+    (0x1D516, 0x1D51C, 5),
+    (0x1D51D, 0x1D51D, 8),
+    (0x1D51E, 0x1D537, 6),
+    (0x1D538, 0x1D539, 5),
+    (0x1D53A, 0x1D53A, 8),
+    (0x1D53B, 0x1D53E, 5),
+    (0x1D53F, 0x1D53F, 8),
+    (0x1D540, 0x1D544, 5),
+    (0x1D545, 0x1D545, 8),
+    (0x1D546, 0x1D546, 5),
+    (0x1D547, 0x1D549, 8),
+    (0x1D54A, 0x1D550, 5),
+    (0x1D551, 0x1D551, 8),
+    (0x1D552, 0x1D56B, 6),
+    (0x1D56C, 0x1D585, 5),
+    (0x1D586, 0x1D59F, 6),
+    (0x1D5A0, 0x1D5B9, 5),
+    (0x1D5BA, 0x1D5D3, 6),
+    (0x1D5D4, 0x1D5ED, 5),
+    (0x1D5EE, 0x1D607, 6),
+    (0x1D608, 0x1D621, 5),
+    (0x1D622, 0x1D63B, 6),
+    (0x1D63C, 0x1D655, 5),
+    (0x1D656, 0x1D66F, 6),
+    (0x1D670, 0x1D689, 5),
+    (0x1D68A, 0x1D6A5, 6),
+    (0x1D6A6, 0x1D6A7, 8),
+    (0x1D6A8, 0x1D6C0, 5),
+    (0x1D6C1, 0x1D6C1, 8),
+    (0x1D6C2, 0x1D6DA, 6),
+    (0x1D6DB, 0x1D6DB, 8),
+    (0x1D6DC, 0x1D6E1, 6),
+    (0x1D6E2, 0x1D6FA, 5),
+    (0x1D6FB, 0x1D6FB, 8),
+    (0x1D6FC, 0x1D714, 6),
+    (0x1D715, 0x1D715, 8),
+    (0x1D716, 0x1D71B, 6),
+    (0x1D71C, 0x1D734, 5),
+    (0x1D735, 0x1D735, 8),
+    (0x1D736, 0x1D74E, 6),
+    (0x1D74F, 0x1D74F, 8),
+    (0x1D750, 0x1D755, 6),
+    (0x1D756, 0x1D76E, 5),
+    (0x1D76F, 0x1D76F, 8),
+    (0x1D770, 0x1D788, 6),
+    (0x1D789, 0x1D789, 8),
+    (0x1D78A, 0x1D78F, 6),
+    (0x1D790, 0x1D7A8, 5),
+    (0x1D7A9, 0x1D7A9, 8),
+    (0x1D7AA, 0x1D7C2, 6),
+    (0x1D7C3, 0x1D7C3, 8),
+    (0x1D7C4, 0x1D7C9, 6),
+    (0x1D7CA, 0x1D7CA, 5),
+    (0x1D7CB, 0x1D7CB, 6),
+    (0x1D7CC, 0x1D7CD, 8),
+    (0x1D7CE, 0x1D7FF, 9),
+    (0x1D800, 0x1D9FF, 8),
+    (0x1DA00, 0x1DA35, -1),
+    (0x1DA36, 0x1DA36, 0),
+    (0x1DA37, 0x1DA3A, 8),
+    (0x1DA3B, 0x1DA6B, -1),
+    (0x1DA6C, 0x1DA6C, 0),
+    (0x1DA6D, 0x1DA74, 8),
+    (0x1DA75, 0x1DA75, 0),
+    (0x1DA76, 0x1DA83, 8),
+    (0x1DA84, 0x1DA84, 0),
+    (0x1DA85, 0x1DA9A, 8),
+    (0x1DA9B, 0x1DA9E, -1),
+    (0x1DA9F, 0x1DA9F, 0),
+    (0x1DAA0, 0x1DAA0, 8),
+    (0x1DAA1, 0x1DAAE, -1),
+    (0x1DAAF, 0x1DAAF, 0),
+    (0x1DAB0, 0x1DFFF, 8),
+    (0x1E000, 0x1E005, -1),
+    (0x1E006, 0x1E006, 0),
+    (0x1E007, 0x1E007, 8),
+    (0x1E008, 0x1E017, -1),
+    (0x1E018, 0x1E018, 0),
+    (0x1E019, 0x1E01A, 8),
+    (0x1E01B, 0x1E020, -1),
+    (0x1E021, 0x1E021, 0),
+    (0x1E022, 0x1E022, 8),
+    (0x1E023, 0x1E024, -1),
+    (0x1E025, 0x1E025, 8),
+    (0x1E026, 0x1E029, -1),
+    (0x1E02A, 0x1E02A, 0),
+    (0x1E02B, 0x1E0FF, 8),
+    (0x1E100, 0x1E12C, 5),
+    (0x1E12D, 0x1E12F, 8),
+    (0x1E130, 0x1E135, -1),
+    (0x1E136, 0x1E136, 0),
+    (0x1E137, 0x1E13D, 5),
+    (0x1E13E, 0x1E13F, 8),
+    (0x1E140, 0x1E149, 9),
+    (0x1E14A, 0x1E14D, 8),
+    (0x1E14E, 0x1E14E, 5),
+    (0x1E14F, 0x1E2BF, 8),
+    (0x1E2C0, 0x1E2EB, 5),
+    (0x1E2EC, 0x1E2EE, -1),
+    (0x1E2EF, 0x1E2EF, 0),
+    (0x1E2F0, 0x1E2F9, 9),
+    (0x1E2FA, 0x1E7FF, 8),
+    (0x1E800, 0x1E8C4, 5),
+    (0x1E8C5, 0x1E8C6, 8),
+    (0x1E8C7, 0x1E8CF, 9),
+    (0x1E8D0, 0x1E8D5, -1),
+    (0x1E8D6, 0x1E8D6, 0),
+    (0x1E8D7, 0x1E8FF, 8),
+    (0x1E900, 0x1E921, 5),
+    (0x1E922, 0x1E943, 6),
+    (0x1E944, 0x1E949, -1),
+    (0x1E94A, 0x1E94A, 0),
+    (0x1E94B, 0x1E94B, 5),
+    (0x1E94C, 0x1E94F, 8),
+    (0x1E950, 0x1E959, 9),
+    (0x1E95A, 0x1EC70, 8),
+    (0x1EC71, 0x1ECAB, 9),
+    (0x1ECAC, 0x1ECAC, 8),
+    (0x1ECAD, 0x1ECAF, 9),
+    (0x1ECB0, 0x1ECB0, 8),
+    (0x1ECB1, 0x1ECB4, 9),
+    (0x1ECB5, 0x1ED00, 8),
+    (0x1ED01, 0x1ED2D, 9),
+    (0x1ED2E, 0x1ED2E, 8),
+    (0x1ED2F, 0x1ED3D, 9),
+    (0x1ED3E, 0x1EDFF, 8),
+    (0x1EE00, 0x1EE03, 5),
+    (0x1EE04, 0x1EE04, 8),
+    (0x1EE05, 0x1EE1F, 5),
+    (0x1EE20, 0x1EE20, 8),
+    (0x1EE21, 0x1EE22, 5),
+    (0x1EE23, 0x1EE23, 8),
+    (0x1EE24, 0x1EE24, 5),
+    (0x1EE25, 0x1EE26, 8),
+    (0x1EE27, 0x1EE27, 5),
+    (0x1EE28, 0x1EE28, 8),
+    (0x1EE29, 0x1EE32, 5),
+    (0x1EE33, 0x1EE33, 8),
+    (0x1EE34, 0x1EE37, 5),
+    (0x1EE38, 0x1EE38, 8),
+    (0x1EE39, 0x1EE39, 5),
+    (0x1EE3A, 0x1EE3A, 8),
+    (0x1EE3B, 0x1EE3B, 5),
+    (0x1EE3C, 0x1EE41, 8),
+    (0x1EE42, 0x1EE42, 5),
+    (0x1EE43, 0x1EE46, 8),
+    (0x1EE47, 0x1EE47, 5),
+    (0x1EE48, 0x1EE48, 8),
+    (0x1EE49, 0x1EE49, 5),
+    (0x1EE4A, 0x1EE4A, 8),
+    (0x1EE4B, 0x1EE4B, 5),
+    (0x1EE4C, 0x1EE4C, 8),
+    (0x1EE4D, 0x1EE4F, 5),
+    (0x1EE50, 0x1EE50, 8),
+    (0x1EE51, 0x1EE52, 5),
+    (0x1EE53, 0x1EE53, 8),
+    (0x1EE54, 0x1EE54, 5),
+    (0x1EE55, 0x1EE56, 8),
+    (0x1EE57, 0x1EE57, 5),
+    (0x1EE58, 0x1EE58, 8),
+    (0x1EE59, 0x1EE59, 5),
+    (0x1EE5A, 0x1EE5A, 8),
+    (0x1EE5B, 0x1EE5B, 5),
+    (0x1EE5C, 0x1EE5C, 8),
+    (0x1EE5D, 0x1EE5D, 5),
+    (0x1EE5E, 0x1EE5E, 8),
+    (0x1EE5F, 0x1EE5F, 5),
+    (0x1EE60, 0x1EE60, 8),
+    (0x1EE61, 0x1EE62, 5),
+    (0x1EE63, 0x1EE63, 8),
+    (0x1EE64, 0x1EE64, 5),
+    (0x1EE65, 0x1EE66, 8),
+    (0x1EE67, 0x1EE6A, 5),
+    (0x1EE6B, 0x1EE6B, 8),
+    (0x1EE6C, 0x1EE72, 5),
+    (0x1EE73, 0x1EE73, 8),
+    (0x1EE74, 0x1EE77, 5),
+    (0x1EE78, 0x1EE78, 8),
+    (0x1EE79, 0x1EE7C, 5),
+    (0x1EE7D, 0x1EE7D, 8),
+    (0x1EE7E, 0x1EE7E, 5),
+    (0x1EE7F, 0x1EE7F, 8),
+    (0x1EE80, 0x1EE89, 5),
+    (0x1EE8A, 0x1EE8A, 8),
+    (0x1EE8B, 0x1EE9B, 5),
+    (0x1EE9C, 0x1EEA0, 8),
+    (0x1EEA1, 0x1EEA3, 5),
+    (0x1EEA4, 0x1EEA4, 8),
+    (0x1EEA5, 0x1EEA9, 5),
+    (0x1EEAA, 0x1EEAA, 8),
+    (0x1EEAB, 0x1EEBB, 5),
+    (0x1EEBC, 0x1F0FF, 8),
+    (0x1F100, 0x1F10C, 9),
+    (0x1F10D, 0x1FBEF, 8),
+    (0x1FBF0, 0x1FBF9, 9),
+    (0x1FBFA, 0x1FFFF, 8),
+    (0x20000, 0x2EBE0, 5),
+    (0x2EBE1, 0x2F7FF, 8),
+    (0x2F800, 0x3134A, 5),
+    (0x3134B, 0xE0000, 8),
+    (0xE0001, 0xE0001, 0),
+    (0xE0002, 0xE001F, 8),
+    (0xE0020, 0xE007E, -1),
+    (0xE007F, 0xE007F, 0),
+    (0xE0080, 0xE00FF, 8),
+    (0xE0100, 0xE01EE, -1),
+    (0xE01EF, 0xE01EF, 0),
+    (0xE01F0, 0x10FFFF, 8),
+];
+
+pub(super) static SENTENCE: Rules = Rules {
+    categories: &SENTENCE_CATEGORIES,
+    num_categories: 13,
+    state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 2, 5, 1, 11, 1, 1, 3, 1, 1, 1, 1, 1,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        1, 2, 5, 9, 11, 1, 1, 8, 8, 1, 5, 8, 6,
+        1, 2, 5, 8, 11, 4, 1, 8, 8, 1, 1, 8, 7,
+        1, 2, 5, 10, 11, 4, 4, 10, 10, 1, 1, 10, 7,
+        1, 2, 5, 8, 11, 4, 4, 8, 8, 1, 1, 8, 1,
+        1, 2, 5, 9, 11, 4, 4, 10, 10, 1, 5, 10, 6,
+        0, 0, 0, 8, 0, 4, 4, 8, 8, 0, 0, 8, 0,
+        0, 2, 11, 11, 11, 0, 0, 0, 0, 0, 11, 0, 12,
+        0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12,
+    ],
+    backwards_state_table: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        9, 10, 10, 10, 10, 9, 3, 4, 4, 3, 10, 9, 10,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        9, 10, 10, 3, 2, 9, 3, 4, 4, 3, 7, 3, 5,
+        9, 10, 2, 4, 2, 9, 3, 4, 4, 3, 8, 4, 6,
+        9, 10, 10, 7, 2, 9, 3, 4, 4, 3, 7, 9, 5,
+        9, 10, 2, 8, 2, 9, 3, 4, 4, 3, 8, 9, 6,
+        9, 10, 10, 7, 2, 9, 3, 4, 4, 3, 7, 9, 10,
+        9, 10, 2, 8, 2, 9, 3, 4, 4, 3, 8, 9, 10,
+        9, 0, 10, 10, 0, 9, 3, 4, 4, 3, 10, 9, 10,
+        9, 0, 10, 10, 10, 9, 3, 4, 4, 3, 10, 9, 10,
+    ],
+    end_states: &[false, true, true, true, true, false, false, false, false, false, false, true, true],
+    lookahead_states: &[false, false, false, false, true, true, true, true, false, true, false, false, false],
+};
+
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::errors::time::DateTimeException;
+use crate::errors::{ArithmeticException, Throwable};
+use crate::util::random::current_time_millis;
+
+use super::local_time::write_fraction;
+use super::LocalDateTime;
+
+const MIN_SECOND: i64 = -31_557_014_167_219_200;
+const MAX_SECOND: i64 = 31_556_889_864_403_199;
+
+/// `java.time.Instant`: a point on the time line, in seconds and nanoseconds since
+/// 1970-01-01T00:00:00Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    seconds: i64,
+    nanos: i32,
+}
+
+impl Instant {
+    pub const EPOCH: Instant = Instant {
+        seconds: 0,
+        nanos: 0,
+    };
+
+    /// The current time, to the millisecond.
+    pub fn now() -> Self {
+        Self::of_epoch_milli(current_time_millis())
+    }
+
+    pub fn of_epoch_second(epoch_second: i64) -> Self {
+        Self::create(epoch_second, 0)
+    }
+
+    /// The instant of a number of seconds plus an adjustment in nanoseconds, which may be
+    /// negative or more than a second.
+    pub fn of_epoch_second_adjusted(epoch_second: i64, nano_adjustment: i64) -> Self {
+        let seconds = epoch_second
+            .checked_add(nano_adjustment.div_euclid(1_000_000_000))
+            .unwrap_or_else(|| out_of_range());
+        Self::create(seconds, nano_adjustment.rem_euclid(1_000_000_000) as i32)
+    }
+
+    pub fn of_epoch_milli(epoch_milli: i64) -> Self {
+        Self::create(
+            epoch_milli.div_euclid(1000),
+            epoch_milli.rem_euclid(1000) as i32 * 1_000_000,
+        )
+    }
+
+    fn create(seconds: i64, nanos: i32) -> Self {
+        if !(MIN_SECOND..=MAX_SECOND).contains(&seconds) {
+            out_of_range();
+        }
+        Self { seconds, nanos }
+    }
+
+    pub fn get_epoch_second(&self) -> i64 {
+        self.seconds
+    }
+
+    /// The nanoseconds within the second.
+    pub fn get_nano(&self) -> i32 {
+        self.nanos
+    }
+
+    /// The number of milliseconds since the epoch. Panics with `ArithmeticException` on
+    /// overflow, like Java.
+    pub fn to_epoch_milli(&self) -> i64 {
+        self.seconds
+            .checked_mul(1000)
+            .and_then(|millis| millis.checked_add(self.nanos as i64 / 1_000_000))
+            .unwrap_or_else(|| ArithmeticException::new("long overflow".to_string()).throw())
+    }
+
+    /// Java's `Instant.hashCode`.
+    pub fn hash_code(&self) -> i32 {
+        ((self.seconds ^ (self.seconds >> 32)) as i32).wrapping_add(51i32.wrapping_mul(self.nanos))
+    }
+}
+
+fn out_of_range() -> ! {
+    DateTimeException::new(
+        "Instant exceeds minimum or maximum instant".to_string(),
+        None,
+    )
+    .throw()
+}
+
+/// ISO 8601 in UTC, always with seconds, e.g. `2007-12-03T10:15:30Z` or
+/// `1969-12-31T23:59:59.999Z`.
+impl fmt::Display for Instant {
+    /// Like the JDK, this formats the date within a cycle of 10000 years, and the cycles
+    /// separately, to reach years beyond the range of `LocalDate`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SECONDS_PER_10000_YEARS: i64 = 146_097 * 25 * 86_400;
+        const SECONDS_0000_TO_1970: i64 = (146_097 * 5 - (30 * 365 + 7)) * 86_400;
+        let mut text;
+        if self.seconds >= -SECONDS_0000_TO_1970 {
+            let zero_seconds = self.seconds - SECONDS_PER_10000_YEARS + SECONDS_0000_TO_1970;
+            let high = zero_seconds.div_euclid(SECONDS_PER_10000_YEARS) + 1;
+            let low = zero_seconds.rem_euclid(SECONDS_PER_10000_YEARS);
+            let date_time = LocalDateTime::of_epoch_second(low - SECONDS_0000_TO_1970, 0, 0);
+            text = if high > 0 {
+                format!("+{high}{date_time}")
+            } else {
+                date_time.to_string()
+            };
+            if date_time.get_second() == 0 {
+                text.push_str(":00");
+            }
+        } else {
+            let zero_seconds = self.seconds + SECONDS_0000_TO_1970;
+            let high = zero_seconds / SECONDS_PER_10000_YEARS;
+            let low = zero_seconds % SECONDS_PER_10000_YEARS;
+            let date_time = LocalDateTime::of_epoch_second(low - SECONDS_0000_TO_1970, 0, 0);
+            text = date_time.to_string();
+            if date_time.get_second() == 0 {
+                text.push_str(":00");
+            }
+            if high < 0 {
+                if date_time.get_year() == -10_000 {
+                    text.replace_range(..2, &(high - 1).to_string());
+                } else if low == 0 {
+                    text.insert_str(0, &high.to_string());
+                } else {
+                    text.insert_str(1, &high.abs().to_string());
+                }
+            }
+        }
+        f.write_str(&text)?;
+        write_fraction(f, self.nanos)?;
+        f.write_str("Z")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_like_jdk() {
+        assert_eq!(Instant::EPOCH.to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            Instant::of_epoch_milli(-1).to_string(),
+            "1969-12-31T23:59:59.999Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second(253_402_300_800).to_string(),
+            "+10000-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second_adjusted(1, 1000).to_string(),
+            "1970-01-01T00:00:01.000001Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second(-100_000_000_000).to_string(),
+            "-1199-02-15T14:13:20Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second_adjusted(MAX_SECOND, 999_999_999).to_string(),
+            "+1000000000-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second(MIN_SECOND).to_string(),
+            "-1000000000-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            Instant::of_epoch_second_adjusted(12345, 678).hash_code(),
+            46_923
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Instant exceeds minimum or maximum instant")]
+    fn rejects_out_of_range() {
+        Instant::of_epoch_second(i64::MAX);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt;
+
+use crate::errors::time::DateTimeException;
+use crate::errors::Throwable;
+
+use super::{check_valid_value, LocalDateTime, LocalTime};
+
+const DAYS_PER_CYCLE: i64 = 146_097;
+const DAYS_0000_TO_1970: i64 = DAYS_PER_CYCLE * 5 - (30 * 365 + 7);
+const MONTH_NAMES: [&str; 12] = [
+    "JANUARY",
+    "FEBRUARY",
+    "MARCH",
+    "APRIL",
+    "MAY",
+    "JUNE",
+    "JULY",
+    "AUGUST",
+    "SEPTEMBER",
+    "OCTOBER",
+    "NOVEMBER",
+    "DECEMBER",
+];
+
+/// `java.time.LocalDate`: a date without a time or zone, in the proleptic Gregorian
+/// calendar, from year -999999999 to 999999999.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalDate {
+    year: i32,
+    month: i32,
+    day: i32,
+}
+
+impl LocalDate {
+    /// The date of a year, month from 1 to 12 and day of month. Throws `DateTimeException`
+    /// if any of them is out of range or the day does not exist in the month.
+    pub fn of(year: i32, month: i32, day_of_month: i32) -> Self {
+        check_valid_value("Year", -999_999_999, 999_999_999, year as i64);
+        check_valid_value("MonthOfYear", 1, 12, month as i64);
+        check_valid_value("DayOfMonth", 1, 31, day_of_month as i64);
+        if day_of_month > month_length(month, is_leap(year)) {
+            let message = if day_of_month == 29 {
+                format!("Invalid date 'February 29' as '{year}' is not a leap year")
+            } else {
+                format!(
+                    "Invalid date '{} {day_of_month}'",
+                    MONTH_NAMES[month as usize - 1]
+                )
+            };
+            DateTimeException::new(message, None).throw()
+        }
+        Self {
+            year,
+            month,
+            day: day_of_month,
+        }
+    }
+
+    /// The date a number of days after 1970-01-01.
+    pub fn of_epoch_day(epoch_day: i64) -> Self {
+        check_valid_value("EpochDay", -365_243_219_162, 365_241_780_471, epoch_day);
+        let mut zero_day = epoch_day + DAYS_0000_TO_1970 - 60;
+        let mut adjust = 0;
+        if zero_day < 0 {
+            let adjust_cycles = (zero_day + 1) / DAYS_PER_CYCLE - 1;
+            adjust = adjust_cycles * 400;
+            zero_day -= adjust_cycles * DAYS_PER_CYCLE;
+        }
+        let mut year_est = (400 * zero_day + 591) / DAYS_PER_CYCLE;
+        let days_before = |year: i64| 365 * year + year / 4 - year / 100 + year / 400;
+        let mut doy_est = zero_day - days_before(year_est);
+        if doy_est < 0 {
+            year_est -= 1;
+            doy_est = zero_day - days_before(year_est);
+        }
+        year_est += adjust;
+        // Months counted from March, so that February comes last.
+        let march_doy0 = doy_est as i32;
+        let march_month0 = (march_doy0 * 5 + 2) / 153;
+        Self {
+            year: (year_est + march_month0 as i64 / 10) as i32,
+            month: (march_month0 + 2) % 12 + 1,
+            day: march_doy0 - (march_month0 * 306 + 5) / 10 + 1,
+        }
+    }
+
+    pub fn get_year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 for January.
+    pub fn get_month_value(&self) -> i32 {
+        self.month
+    }
+
+    pub fn get_day_of_month(&self) -> i32 {
+        self.day
+    }
+
+    pub fn get_day_of_year(&self) -> i32 {
+        const FIRST_DAYS: [i32; 12] = [1, 32, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335];
+        let leap = (self.month > 2 && self.is_leap_year()) as i32;
+        FIRST_DAYS[self.month as usize - 1] + leap + self.day - 1
+    }
+
+    /// The ISO day of the week, from 1 for Monday to 7 for Sunday.
+    pub(crate) fn day_of_week_value(&self) -> i32 {
+        (self.to_epoch_day() + 3).rem_euclid(7) as i32 + 1
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        is_leap(self.year)
+    }
+
+    pub fn length_of_month(&self) -> i32 {
+        month_length(self.month, self.is_leap_year())
+    }
+
+    /// The number of days since 1970-01-01.
+    pub fn to_epoch_day(&self) -> i64 {
+        let y = self.year as i64;
+        let m = self.month as i64;
+        let mut total = 365 * y;
+        if y >= 0 {
+            total += (y + 3) / 4 - (y + 99) / 100 + (y + 399) / 400;
+        } else {
+            total -= y / -4 - y / -100 + y / -400;
+        }
+        total += (367 * m - 362) / 12 + self.day as i64 - 1;
+        if m > 2 {
+            total -= if self.is_leap_year() { 1 } else { 2 };
+        }
+        total - DAYS_0000_TO_1970
+    }
+
+    pub fn at_time(&self, time: LocalTime) -> LocalDateTime {
+        LocalDateTime::of(*self, time)
+    }
+
+    /// Java's `LocalDate.hashCode`.
+    pub fn hash_code(&self) -> i32 {
+        (self.year & !0x7FF) ^ (self.year << 11).wrapping_add((self.month << 6) + self.day)
+    }
+}
+
+fn is_leap(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn month_length(month: i32, leap: bool) -> i32 {
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// ISO 8601 `uuuu-MM-dd`, with a sign before years beyond 9999 and before negative ones,
+/// e.g. `2007-12-03`, `+12345-03-01` or `-0005-03-01`.
+impl fmt::Display for LocalDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.year > 9999 {
+            write!(f, "+{}", self.year)?;
+        } else if self.year < 0 {
+            write!(f, "-{:04}", -(self.year as i64))?;
+        } else {
+            write!(f, "{:04}", self.year)?;
+        }
+        write!(f, "-{:02}-{:02}", self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_epoch_days() {
+        for epoch_day in [-800_000, -719_529, -1, 0, 59, 11_016, 19_782, 2_932_896] {
+            assert_eq!(LocalDate::of_epoch_day(epoch_day).to_epoch_day(), epoch_day);
+        }
+        let date = LocalDate::of_epoch_day(19_782);
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(date.get_day_of_year(), 60);
+        assert_eq!(date.day_of_week_value(), 4);
+        assert_eq!(date.hash_code(), 4_145_309);
+        assert_eq!(LocalDate::of(-5, 3, 1).to_string(), "-0005-03-01");
+        assert_eq!(LocalDate::of(12345, 3, 1).to_string(), "+12345-03-01");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid date 'February 29' as '2023' is not a leap year")]
+    fn rejects_february_29() {
+        LocalDate::of(2023, 2, 29);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for MonthOfYear (valid values 1 - 12): 13")]
+    fn rejects_month_13() {
+        LocalDate::of(2023, 13, 1);
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+
+use super::{check_valid_value, LocalDate, LocalTime};
+
+/// `java.time.LocalDateTime`: a date and time without a zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalDateTime {
+    date: LocalDate,
+    time: LocalTime,
+}
+
+impl LocalDateTime {
+    pub fn of(date: LocalDate, time: LocalTime) -> Self {
+        Self { date, time }
+    }
+
+    /// The date and time at an offset from UTC, in seconds, of a number of seconds since the
+    /// epoch plus nanoseconds.
+    pub fn of_epoch_second(epoch_second: i64, nano_of_second: i32, offset_seconds: i32) -> Self {
+        check_valid_value("NanoOfSecond", 0, 999_999_999, nano_of_second as i64);
+        let local = epoch_second + offset_seconds as i64;
+        let time = LocalTime::of_nano_of_day(
+            local.rem_euclid(86_400) * 1_000_000_000 + nano_of_second as i64,
+        );
+        Self {
+            date: LocalDate::of_epoch_day(local.div_euclid(86_400)),
+            time,
+        }
+    }
+
+    pub fn to_local_date(&self) -> LocalDate {
+        self.date
+    }
+
+    pub fn to_local_time(&self) -> LocalTime {
+        self.time
+    }
+
+    pub fn get_year(&self) -> i32 {
+        self.date.get_year()
+    }
+
+    pub fn get_month_value(&self) -> i32 {
+        self.date.get_month_value()
+    }
+
+    pub fn get_day_of_month(&self) -> i32 {
+        self.date.get_day_of_month()
+    }
+
+    pub fn get_hour(&self) -> i32 {
+        self.time.get_hour()
+    }
+
+    pub fn get_minute(&self) -> i32 {
+        self.time.get_minute()
+    }
+
+    pub fn get_second(&self) -> i32 {
+        self.time.get_second()
+    }
+
+    pub fn get_nano(&self) -> i32 {
+        self.time.get_nano()
+    }
+
+    /// The number of seconds since the epoch of this date and time at an offset from UTC.
+    pub fn to_epoch_second(&self, offset_seconds: i32) -> i64 {
+        self.date.to_epoch_day() * 86_400 + self.time.to_second_of_day() as i64
+            - offset_seconds as i64
+    }
+
+    /// Java's `LocalDateTime.hashCode`.
+    pub fn hash_code(&self) -> i32 {
+        self.date.hash_code() ^ self.time.hash_code()
+    }
+}
+
+/// The date and the time separated by `T`, e.g. `2007-12-03T10:15:30`.
+impl fmt::Display for LocalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+use super::check_valid_value;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// `java.time.LocalTime`: a time of day without a date or zone, to the nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocalTime {
+    hour: i32,
+    minute: i32,
+    second: i32,
+    nano: i32,
+}
+
+impl LocalTime {
+    /// Midnight, 00:00.
+    pub const MIDNIGHT: LocalTime = LocalTime {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        nano: 0,
+    };
+
+    /// The time of an hour, minute, second and nanosecond. Throws `DateTimeException` if
+    /// any of them is out of range.
+    pub fn of(hour: i32, minute: i32, second: i32, nano_of_second: i32) -> Self {
+        check_valid_value("HourOfDay", 0, 23, hour as i64);
+        check_valid_value("MinuteOfHour", 0, 59, minute as i64);
+        check_valid_value("SecondOfMinute", 0, 59, second as i64);
+        check_valid_value("NanoOfSecond", 0, 999_999_999, nano_of_second as i64);
+        Self {
+            hour,
+            minute,
+            second,
+            nano: nano_of_second,
+        }
+    }
+
+    /// The time a number of nanoseconds after midnight.
+    pub fn of_nano_of_day(nano_of_day: i64) -> Self {
+        check_valid_value("NanoOfDay", 0, 86_400 * NANOS_PER_SECOND - 1, nano_of_day);
+        let seconds = nano_of_day / NANOS_PER_SECOND;
+        Self {
+            hour: (seconds / 3600) as i32,
+            minute: (seconds / 60 % 60) as i32,
+            second: (seconds % 60) as i32,
+            nano: (nano_of_day % NANOS_PER_SECOND) as i32,
+        }
+    }
+
+    pub fn get_hour(&self) -> i32 {
+        self.hour
+    }
+
+    pub fn get_minute(&self) -> i32 {
+        self.minute
+    }
+
+    pub fn get_second(&self) -> i32 {
+        self.second
+    }
+
+    pub fn get_nano(&self) -> i32 {
+        self.nano
+    }
+
+    pub fn to_second_of_day(&self) -> i32 {
+        self.hour * 3600 + self.minute * 60 + self.second
+    }
+
+    pub fn to_nano_of_day(&self) -> i64 {
+        self.to_second_of_day() as i64 * NANOS_PER_SECOND + self.nano as i64
+    }
+
+    /// Java's `LocalTime.hashCode`.
+    pub fn hash_code(&self) -> i32 {
+        let nano_of_day = self.to_nano_of_day();
+        (nano_of_day ^ (nano_of_day >> 32)) as i32
+    }
+}
+
+/// `HH:mm`, followed by `:ss` unless the seconds and nanoseconds are zero, and by as many
+/// groups of three fraction digits as the nanoseconds need, e.g. `10:15`, `10:15:30` or
+/// `10:15:30.120`.
+impl fmt::Display for LocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)?;
+        if self.second > 0 || self.nano > 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        write_fraction(f, self.nano)
+    }
+}
+
+/// Nanoseconds as a fraction of a second in groups of three digits, if they are not zero.
+pub(super) fn write_fraction(f: &mut fmt::Formatter<'_>, nano: i32) -> fmt::Result {
+    if nano == 0 {
+        Ok(())
+    } else if nano % 1_000_000 == 0 {
+        write!(f, ".{:03}", nano / 1_000_000)
+    } else if nano % 1000 == 0 {
+        write!(f, ".{:06}", nano / 1000)
+    } else {
+        write!(f, ".{nano:09}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_like_jdk() {
+        assert_eq!(LocalTime::of(1, 2, 0, 0).to_string(), "01:02");
+        assert_eq!(LocalTime::of(1, 2, 3, 0).to_string(), "01:02:03");
+        assert_eq!(
+            LocalTime::of(1, 2, 0, 1_000_000).to_string(),
+            "01:02:00.001"
+        );
+        assert_eq!(LocalTime::of(1, 2, 0, 1000).to_string(), "01:02:00.000001");
+        assert_eq!(LocalTime::of(1, 2, 0, 1).to_string(), "01:02:00.000000001");
+        assert_eq!(LocalTime::of(1, 2, 3, 4).hash_code(), -736_645_786);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for HourOfDay (valid values 0 - 23): 24")]
+    fn rejects_hour_24() {
+        LocalTime::of(24, 0, 0, 0);
+    }
+}
@@ -0,0 +1,28 @@
+//! `java.time`: dates, times and instants in the ISO calendar, with the `toString` forms and
+//! validation messages of the JDK.
+
+mod instant;
+mod local_date;
+mod local_date_time;
+mod local_time;
+mod zoned_date_time;
+
+pub use instant::Instant;
+pub use local_date::LocalDate;
+pub use local_date_time::LocalDateTime;
+pub use local_time::LocalTime;
+pub use zoned_date_time::ZonedDateTime;
+
+use crate::errors::time::DateTimeException;
+use crate::errors::Throwable;
+
+/// `ChronoField.checkValidValue`: throws `DateTimeException` if a field is out of range.
+fn check_valid_value(field: &str, min: i64, max: i64, value: i64) {
+    if !(min..=max).contains(&value) {
+        DateTimeException::new(
+            format!("Invalid value for {field} (valid values {min} - {max}): {value}"),
+            None,
+        )
+        .throw()
+    }
+}
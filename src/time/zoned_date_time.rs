@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::util::TimeZone;
+
+use super::{Instant, LocalDateTime};
+
+/// `java.time.ZonedDateTime`: a date and time in a time zone, with the zone's offset from
+/// UTC at that time.
+///
+/// Zones are `java.util.TimeZone`s, read from the same IANA database as `ZoneId`s are, and
+/// have the offsets of `ZoneRules`: local mean time before a zone's first transition, with
+/// offsets to the second.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZonedDateTime {
+    date_time: LocalDateTime,
+    offset_seconds: i32,
+    zone: TimeZone,
+}
+
+impl ZonedDateTime {
+    /// A local date and time in a zone. Like the JDK, a time skipped by a forward
+    /// transition is moved later by the length of the gap, and a time repeated by a
+    /// backward transition takes the earlier offset.
+    pub fn of(date_time: LocalDateTime, zone: TimeZone) -> Self {
+        let local = date_time.to_epoch_second(0);
+        let offset_at = |epoch_second: i64| zone.get_zone_rules_offset(epoch_second);
+        // The offsets in use a day either side cover any transition at this time.
+        let before = offset_at(local - 86_400);
+        let after = offset_at(local + 86_400);
+        let valid = [before.max(after), before.min(after)]
+            .into_iter()
+            .find(|&offset| offset_at(local - offset as i64) == offset);
+        match valid {
+            Some(offset_seconds) => Self {
+                date_time,
+                offset_seconds,
+                zone,
+            },
+            None => {
+                // In a gap: the transition is at local - before, UTC.
+                let epoch_second = local - before as i64;
+                Self::of_instant(
+                    Instant::of_epoch_second_adjusted(epoch_second, date_time.get_nano() as i64),
+                    zone,
+                )
+            }
+        }
+    }
+
+    pub fn of_instant(instant: Instant, zone: TimeZone) -> Self {
+        let seconds = instant.get_epoch_second();
+        let offset_seconds = zone.get_zone_rules_offset(seconds);
+        Self {
+            date_time: LocalDateTime::of_epoch_second(seconds, instant.get_nano(), offset_seconds),
+            offset_seconds,
+            zone,
+        }
+    }
+
+    pub fn to_local_date_time(&self) -> LocalDateTime {
+        self.date_time
+    }
+
+    /// The offset from UTC in seconds.
+    pub fn get_offset(&self) -> i32 {
+        self.offset_seconds
+    }
+
+    pub fn get_zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    pub fn to_epoch_second(&self) -> i64 {
+        self.date_time.to_epoch_second(self.offset_seconds)
+    }
+
+    pub fn to_instant(&self) -> Instant {
+        Instant::of_epoch_second_adjusted(self.to_epoch_second(), self.date_time.get_nano() as i64)
+    }
+
+    /// Java's `ZonedDateTime.hashCode`, with the zone hashed as a region ID.
+    pub fn hash_code(&self) -> i32 {
+        let zone_hash = self.zone.get_id().encode_utf16().fold(0i32, |hash, unit| {
+            hash.wrapping_mul(31).wrapping_add(unit as i32)
+        });
+        self.date_time.hash_code() ^ self.offset_seconds ^ zone_hash.rotate_left(3)
+    }
+}
+
+/// The local date and time, the offset like `+01:00`, or `Z` for UTC, and the zone ID in
+/// brackets, e.g. `2007-12-03T10:15:30+01:00[Europe/Paris]`.
+impl fmt::Display for ZonedDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.date_time)?;
+        let offset = self.offset_seconds;
+        if offset == 0 {
+            f.write_str("Z")?;
+        } else {
+            let total = offset.abs();
+            let sign = if offset < 0 { '-' } else { '+' };
+            write!(f, "{sign}{:02}:{:02}", total / 3600, total / 60 % 60)?;
+            if total % 60 != 0 {
+                write!(f, ":{:02}", total % 60)?;
+            }
+        }
+        write!(f, "[{}]", self.zone.get_id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::{LocalDate, LocalTime};
+
+    fn new_york(hour: i32, month: i32, day: i32) -> ZonedDateTime {
+        let date_time = LocalDate::of(2021, month, day).at_time(LocalTime::of(hour, 30, 0, 0));
+        ZonedDateTime::of(date_time, TimeZone::get_time_zone("America/New_York"))
+    }
+
+    #[test]
+    fn resolves_like_jdk() {
+        assert_eq!(
+            new_york(2, 3, 14).to_string(),
+            "2021-03-14T03:30-04:00[America/New_York]"
+        );
+        assert_eq!(
+            new_york(1, 11, 7).to_string(),
+            "2021-11-07T01:30-04:00[America/New_York]"
+        );
+        let noon = LocalDate::of(2021, 6, 1).at_time(LocalTime::of(12, 0, 0, 0));
+        let zone = TimeZone::get_time_zone("America/New_York");
+        assert_eq!(ZonedDateTime::of(noon, zone).hash_code(), 408_542_070);
+        let kolkata = TimeZone::get_time_zone("Asia/Kolkata");
+        assert_eq!(
+            ZonedDateTime::of_instant(Instant::EPOCH, kolkata).to_string(),
+            "1970-01-01T05:30+05:30[Asia/Kolkata]"
+        );
+    }
+
+    #[test]
+    fn takes_local_mean_time_before_the_first_transition() {
+        let los_angeles = TimeZone::get_time_zone("America/Los_Angeles");
+        let instant = Instant::of_epoch_second_adjusted(-3_957_770_453, 0);
+        let zoned = ZonedDateTime::of_instant(instant, los_angeles.clone());
+        assert_eq!(
+            zoned.to_string(),
+            "1844-08-01T03:26:09-07:52:58[America/Los_Angeles]"
+        );
+        let date_time = LocalDate::of(1850, 3, 1).at_time(LocalTime::of(12, 0, 0, 0));
+        let zoned = ZonedDateTime::of(date_time, los_angeles.clone());
+        assert_eq!(zoned.to_epoch_second(), -3_781_656_422);
+        // The switch to standard time turned the clocks back 7:02.
+        let date_time = LocalDate::of(1883, 11, 18).at_time(LocalTime::of(12, 5, 0, 0));
+        assert_eq!(
+            ZonedDateTime::of(date_time, los_angeles).get_offset(),
+            -28_378
+        );
+    }
+}
@@ -0,0 +1,787 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use crate::errors::util::{ConcurrentModificationException, NoSuchElementException};
+use crate::errors::{
+    IllegalArgumentException, IllegalStateException, IndexOutOfBoundsException,
+    NullPointerException, Throwable,
+};
+use crate::util::collection::unsupported;
+use crate::util::{tim_sort, Comparator};
+
+/// Which JDK class a list behaves like. They differ in their exception messages, in when
+/// they count a modification, and in when their iterators stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Kind {
+    Array,
+    Linked,
+    /// `List.of`'s `ListN`, which is never modified.
+    Immutable,
+    /// `List.of`'s `List12`, the immutable list of one or two elements.
+    ImmutablePair,
+}
+
+pub(crate) struct ListData<E> {
+    elements: VecDeque<E>,
+    /// Java's `modCount`: the number of structural modifications, which iterators and
+    /// sublists compare with the count they expect to fail fast.
+    mod_count: usize,
+}
+
+/// Where a sublist lies in the root list. Sublists of a sublist point to its range, so
+/// that a structural modification through them resizes the sublists they lie in.
+struct Range {
+    parent: Option<Rc<RefCell<Range>>>,
+    offset: usize,
+    size: usize,
+    mod_count: usize,
+}
+
+/// A list or a sublist of it, sharing the elements with its iterators and sublists like
+/// Java's `AbstractList` does. The lists delegate to it.
+pub(crate) struct ListView<E> {
+    data: Rc<RefCell<ListData<E>>>,
+    range: Option<Rc<RefCell<Range>>>,
+    kind: Kind,
+    /// Whether the view is `Collections.unmodifiableList`'s, which throws
+    /// `UnsupportedOperationException` from every method that would change the list.
+    read_only: bool,
+    /// For `List.of`'s lists, which throw `NullPointerException` when searched for `null`:
+    /// whether an element is `null`.
+    is_null: Option<fn(&E) -> bool>,
+}
+
+impl<E> Clone for ListView<E> {
+    /// Another view of the same elements.
+    fn clone(&self) -> Self {
+        Self {
+            data: Rc::clone(&self.data),
+            range: self.range.clone(),
+            kind: self.kind,
+            read_only: self.read_only,
+            is_null: self.is_null,
+        }
+    }
+}
+
+fn concurrent_modification() -> ! {
+    ConcurrentModificationException::new(String::new()).throw()
+}
+
+impl<E: Clone> ListView<E> {
+    pub(crate) fn new(kind: Kind, elements: VecDeque<E>) -> Self {
+        Self {
+            data: Rc::new(RefCell::new(ListData {
+                elements,
+                mod_count: 0,
+            })),
+            range: None,
+            kind,
+            read_only: false,
+            is_null: None,
+        }
+    }
+
+    /// A view of the same elements that throws `NullPointerException` when searched for an
+    /// element for which `is_null` is true.
+    pub(crate) fn rejecting_null(&self, is_null: fn(&E) -> bool) -> Self {
+        Self {
+            is_null: Some(is_null),
+            ..self.clone()
+        }
+    }
+
+    fn check_not_null(&self, o: &E) {
+        if self.is_null.is_some_and(|is_null| is_null(o)) {
+            NullPointerException::new(String::new()).throw()
+        }
+    }
+
+    /// A view of the same elements that can't change them.
+    pub(crate) fn read_only(&self) -> Self {
+        Self {
+            read_only: true,
+            ..self.clone()
+        }
+    }
+
+    fn check_modifiable(&self) {
+        if self.read_only {
+            unsupported()
+        }
+    }
+
+    fn data(&self) -> Ref<'_, ListData<E>> {
+        self.data.borrow()
+    }
+
+    fn data_mut(&self) -> RefMut<'_, ListData<E>> {
+        self.data.borrow_mut()
+    }
+
+    pub(crate) fn increment_mod_count(&self) {
+        let mut data = self.data_mut();
+        data.mod_count = data.mod_count.wrapping_add(1);
+    }
+
+    pub(crate) fn with_elements<R>(&self, f: impl FnOnce(&mut VecDeque<E>) -> R) -> R {
+        f(&mut self.data_mut().elements)
+    }
+
+    fn mod_count(&self) -> usize {
+        self.data().mod_count
+    }
+
+    /// Throws `ConcurrentModificationException` if the root list was structurally
+    /// modified other than through this sublist.
+    fn check_for_comodification(&self) {
+        if let Some(range) = &self.range {
+            if range.borrow().mod_count != self.mod_count() {
+                concurrent_modification();
+            }
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.range.as_ref().map_or(0, |range| range.borrow().offset)
+    }
+
+    /// The size without checking for comodification.
+    fn raw_size(&self) -> usize {
+        match &self.range {
+            Some(range) => range.borrow().size,
+            None => self.data().elements.len(),
+        }
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        self.check_for_comodification();
+        self.raw_size()
+    }
+
+    /// Checks the index of an existing element.
+    fn check_element_index(&self, index: usize, size: usize) {
+        if index < size {
+            return;
+        }
+        let message = match self.kind {
+            Kind::Linked if self.range.is_none() => format!("Index: {index}, Size: {size}"),
+            Kind::ImmutablePair if self.range.is_none() => {
+                format!("Index: {index} Size: {size}")
+            }
+            _ => format!("Index {index} out of bounds for length {size}"),
+        };
+        IndexOutOfBoundsException::new(message, index as i32).throw()
+    }
+
+    /// Checks an index to insert at.
+    fn check_position_index(&self, index: usize, size: usize) {
+        if index > size {
+            let message = if matches!(self.kind, Kind::Immutable | Kind::ImmutablePair) {
+                format!("Index: {index} Size: {size}")
+            } else {
+                format!("Index: {index}, Size: {size}")
+            };
+            IndexOutOfBoundsException::new(message, index as i32).throw()
+        }
+    }
+
+    /// Records a structural modification that changed the size by `delta` in this view's
+    /// range and the ranges it lies in.
+    fn update_size_and_mod_count(&self, delta: isize) {
+        let mod_count = self.mod_count();
+        let mut range = self.range.clone();
+        while let Some(current) = range {
+            let mut current = current.borrow_mut();
+            current.size = current.size.wrapping_add_signed(delta);
+            current.mod_count = mod_count;
+            range = current.parent.clone();
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> E {
+        self.check_element_index(index, self.raw_size());
+        self.check_for_comodification();
+        self.data().elements[self.offset() + index].clone()
+    }
+
+    pub(crate) fn set(&self, index: usize, e: E) -> E {
+        self.check_modifiable();
+        self.check_element_index(index, self.raw_size());
+        self.check_for_comodification();
+        let offset = self.offset();
+        std::mem::replace(&mut self.data_mut().elements[offset + index], e)
+    }
+
+    pub(crate) fn add_at(&self, index: usize, e: E) {
+        self.check_modifiable();
+        self.check_position_index(index, self.raw_size());
+        self.check_for_comodification();
+        let offset = self.offset();
+        self.data_mut().elements.insert(offset + index, e);
+        self.increment_mod_count();
+        self.update_size_and_mod_count(1);
+    }
+
+    pub(crate) fn add(&self, e: E) {
+        self.add_at(self.raw_size(), e);
+    }
+
+    pub(crate) fn remove_at(&self, index: usize) -> E {
+        self.check_modifiable();
+        self.check_element_index(index, self.raw_size());
+        self.check_for_comodification();
+        let offset = self.offset();
+        let removed = self.data_mut().elements.remove(offset + index).unwrap();
+        self.increment_mod_count();
+        self.update_size_and_mod_count(-1);
+        removed
+    }
+
+    pub(crate) fn add_all_at(&self, index: usize, elements: Vec<E>) -> bool {
+        self.check_modifiable();
+        self.check_position_index(index, self.raw_size());
+        if elements.is_empty() {
+            // `ArrayList` counts even adding nothing as a modification.
+            if self.kind == Kind::Array && self.range.is_none() {
+                self.increment_mod_count();
+            }
+            return false;
+        }
+        self.check_for_comodification();
+        let count = elements.len();
+        let at = self.offset() + index;
+        {
+            let mut data = self.data_mut();
+            let tail = data.elements.split_off(at);
+            data.elements.extend(elements);
+            data.elements.extend(tail);
+        }
+        self.increment_mod_count();
+        self.update_size_and_mod_count(count as isize);
+        true
+    }
+
+    pub(crate) fn clear(&self) {
+        self.check_modifiable();
+        self.check_for_comodification();
+        let offset = self.offset();
+        let size = self.raw_size();
+        self.data_mut().elements.drain(offset..offset + size);
+        // A `LinkedList` sublist removes element by element, counting nothing if empty.
+        if self.kind == Kind::Array || self.range.is_none() || size > 0 {
+            self.increment_mod_count();
+        }
+        self.update_size_and_mod_count(-(size as isize));
+    }
+
+    /// The elements of the view.
+    pub(crate) fn to_vec(&self) -> Vec<E> {
+        self.check_for_comodification();
+        let offset = self.offset();
+        let size = self.raw_size();
+        self.data()
+            .elements
+            .range(offset..offset + size)
+            .cloned()
+            .collect()
+    }
+
+    pub(crate) fn index_of(&self, o: &E) -> Option<usize>
+    where
+        E: PartialEq,
+    {
+        self.check_not_null(o);
+        let offset = self.offset();
+        let size = self.raw_size();
+        let index = self
+            .data()
+            .elements
+            .range(offset..offset + size)
+            .position(|e| e == o);
+        self.check_for_comodification();
+        index
+    }
+
+    pub(crate) fn last_index_of(&self, o: &E) -> Option<usize>
+    where
+        E: PartialEq,
+    {
+        self.check_not_null(o);
+        let offset = self.offset();
+        let size = self.raw_size();
+        let index = self
+            .data()
+            .elements
+            .range(offset..offset + size)
+            .rposition(|e| e == o);
+        self.check_for_comodification();
+        index
+    }
+
+    pub(crate) fn remove_object(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.check_modifiable();
+        match self.index_of(o) {
+            Some(index) => {
+                self.remove_at(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn remove_if<F: FnMut(&E) -> bool>(&self, mut filter: F) -> bool {
+        self.check_modifiable();
+        let elements = self.to_vec();
+        let expected_mod_count = self.mod_count();
+        // The filter sees every element before any is removed, and must not change the
+        // list. No borrow is held while it runs, so if it does, that is detected.
+        let removed: Vec<bool> = elements.iter().map(&mut filter).collect();
+        if self.mod_count() != expected_mod_count {
+            concurrent_modification();
+        }
+        let count = removed.iter().filter(|&&removed| removed).count();
+        if count == 0 {
+            return false;
+        }
+        let offset = self.offset();
+        let mut index = 0;
+        self.data_mut().elements.retain(|_| {
+            let keep =
+                index < offset || index >= offset + removed.len() || !removed[index - offset];
+            index += 1;
+            keep
+        });
+        self.increment_mod_count();
+        self.update_size_and_mod_count(-(count as isize));
+        true
+    }
+
+    pub(crate) fn replace_all<F: FnMut(&E) -> E>(&self, mut operator: F) {
+        self.check_modifiable();
+        self.check_for_comodification();
+        let expected_mod_count = self.mod_count();
+        let offset = self.offset();
+        for index in 0..self.raw_size() {
+            if self.mod_count() != expected_mod_count {
+                break;
+            }
+            let e = self.data().elements[offset + index].clone();
+            let replacement = operator(&e);
+            self.data_mut().elements[offset + index] = replacement;
+        }
+        if self.mod_count() != expected_mod_count {
+            concurrent_modification();
+        }
+        // Like `sort`, JDK 17's `ArrayList.replaceAll` counts as a modification.
+        if self.kind == Kind::Array && self.range.is_none() {
+            self.increment_mod_count();
+        }
+    }
+
+    pub(crate) fn sort<C: Comparator<E>>(&self, c: C) {
+        self.check_modifiable();
+        let mut elements = self.to_vec();
+        let expected_mod_count = self.mod_count();
+        let sorted = catch_unwind(AssertUnwindSafe(|| {
+            tim_sort::sort(&mut elements, &|a: &E, b: &E| c.compare(a, b))
+        }));
+        if let Err(error) = sorted {
+            // `ArrayList` sorts its array in place, so a comparator that throws leaves it
+            // partly sorted. The others sort a copy.
+            if self.kind == Kind::Array
+                && self.range.is_none()
+                && self.mod_count() == expected_mod_count
+            {
+                self.write_back(elements);
+            }
+            resume_unwind(error)
+        }
+        if self.mod_count() != expected_mod_count {
+            concurrent_modification();
+        }
+        self.write_back(elements);
+        if self.kind == Kind::Array && self.range.is_none() {
+            self.increment_mod_count();
+        }
+    }
+
+    fn write_back(&self, elements: Vec<E>) {
+        let offset = self.offset();
+        let mut data = self.data_mut();
+        for (index, e) in elements.into_iter().enumerate() {
+            data.elements[offset + index] = e;
+        }
+    }
+
+    pub(crate) fn for_each<F: FnMut(&E)>(&self, mut action: F) {
+        if self.kind == Kind::Array && self.range.is_none() {
+            // `ArrayList.forEach` stops at a modification, then throws.
+            let expected_mod_count = self.mod_count();
+            let mut index = 0;
+            while self.mod_count() == expected_mod_count && index < self.raw_size() {
+                let e = self.data().elements[index].clone();
+                action(&e);
+                index += 1;
+            }
+            if self.mod_count() != expected_mod_count {
+                concurrent_modification();
+            }
+        } else {
+            for e in self.list_iterator(0) {
+                action(&e);
+            }
+        }
+    }
+
+    pub(crate) fn list_iterator(&self, index: usize) -> ListIter<E> {
+        self.check_for_comodification();
+        self.check_position_index(index, self.raw_size());
+        ListIter {
+            view: self.clone(),
+            cursor: index,
+            last_returned: None,
+            expected_mod_count: self.mod_count(),
+        }
+    }
+
+    pub(crate) fn sub_list(&self, from_index: usize, to_index: usize) -> SubList<E> {
+        let size = self.size();
+        if to_index > size {
+            IndexOutOfBoundsException::new(format!("toIndex = {to_index}"), to_index as i32).throw()
+        }
+        if from_index > to_index {
+            IllegalArgumentException::new(
+                format!("fromIndex({from_index}) > toIndex({to_index})"),
+                None,
+            )
+            .throw()
+        }
+        let range = Range {
+            parent: self.range.clone(),
+            offset: self.offset() + from_index,
+            size: to_index - from_index,
+            mod_count: self.mod_count(),
+        };
+        SubList {
+            view: ListView {
+                data: Rc::clone(&self.data),
+                range: Some(Rc::new(RefCell::new(range))),
+                kind: self.kind,
+                read_only: self.read_only,
+                is_null: self.is_null,
+            },
+        }
+    }
+
+    /// A copy of the list, with its own elements.
+    pub(crate) fn deep_clone(&self) -> Self {
+        Self::new(self.kind, self.to_vec().into())
+    }
+}
+
+/// `java.util.ListIterator`: iterates over a list in either direction, and can change it
+/// along the way.
+///
+/// As a Rust iterator it yields the elements, which is Java's `hasNext` and `next`: a
+/// structural modification of the list other than through the iterator makes the next
+/// element throw `ConcurrentModificationException`. Like Java's, it stops without
+/// throwing if the modification leaves the cursor at the end, which for an `ArrayList`
+/// means exactly the end but for a `LinkedList` anywhere past it.
+pub struct ListIter<E> {
+    view: ListView<E>,
+    cursor: usize,
+    last_returned: Option<usize>,
+    expected_mod_count: usize,
+}
+
+impl<E: Clone> ListIter<E> {
+    fn check_for_comodification(&self) {
+        if self.view.mod_count() != self.expected_mod_count {
+            concurrent_modification();
+        }
+    }
+
+    pub fn has_next(&self) -> bool {
+        match self.view.kind {
+            Kind::Array | Kind::Immutable | Kind::ImmutablePair => {
+                self.cursor != self.view.raw_size()
+            }
+            Kind::Linked => self.cursor < self.view.raw_size(),
+        }
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.cursor != 0
+    }
+
+    /// The element before the cursor, moving the cursor back, or `None` at the start.
+    pub fn previous(&mut self) -> Option<E> {
+        if !self.has_previous() {
+            return None;
+        }
+        self.check_for_comodification();
+        self.cursor -= 1;
+        self.last_returned = Some(self.cursor);
+        Some(self.element(self.cursor))
+    }
+
+    /// The index of the element `next` would return.
+    pub fn next_index(&self) -> usize {
+        self.cursor
+    }
+
+    /// The index of the element `previous` would return, or `None` at the start.
+    pub fn previous_index(&self) -> Option<usize> {
+        self.cursor.checked_sub(1)
+    }
+
+    /// Removes the element last returned by `next` or `previous`. Throws
+    /// `IllegalStateException` if there is none, or if `add` was called since.
+    pub fn remove(&mut self) {
+        self.view.check_modifiable();
+        if self.view.kind == Kind::Linked {
+            self.check_for_comodification();
+        }
+        let Some(last_returned) = self.last_returned else {
+            IllegalStateException::new(String::new(), None).throw()
+        };
+        self.check_for_comodification();
+        self.view.remove_at(last_returned);
+        self.cursor = last_returned;
+        self.last_returned = None;
+        self.expected_mod_count = self.view.mod_count();
+    }
+
+    /// Replaces the element last returned by `next` or `previous`. Throws
+    /// `IllegalStateException` if there is none, or if `remove` or `add` was called since.
+    pub fn set(&mut self, e: E) {
+        self.view.check_modifiable();
+        let Some(last_returned) = self.last_returned else {
+            IllegalStateException::new(String::new(), None).throw()
+        };
+        self.check_for_comodification();
+        self.view.set(last_returned, e);
+    }
+
+    /// Inserts an element before the cursor.
+    pub fn add(&mut self, e: E) {
+        self.view.check_modifiable();
+        self.check_for_comodification();
+        self.view.add_at(self.cursor, e);
+        self.cursor += 1;
+        self.last_returned = None;
+        self.expected_mod_count = self.view.mod_count();
+    }
+
+    fn element(&self, index: usize) -> E {
+        let offset = self.view.offset();
+        match self.view.data().elements.get(offset + index) {
+            Some(e) => e.clone(),
+            None => concurrent_modification(),
+        }
+    }
+}
+
+impl<E: Clone> Iterator for ListIter<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        if !self.has_next() {
+            return None;
+        }
+        self.check_for_comodification();
+        if self.cursor >= self.view.raw_size() {
+            NoSuchElementException::new(String::new()).throw()
+        }
+        let e = self.element(self.cursor);
+        self.last_returned = Some(self.cursor);
+        self.cursor += 1;
+        Some(e)
+    }
+}
+
+/// A view of a range of a list, returned by `List::sub_list`.
+pub struct SubList<E> {
+    pub(crate) view: ListView<E>,
+}
+
+/// Implements `Collection`, `List` and the standard traits for a type whose `view` field
+/// is a `ListView`.
+macro_rules! list_impls {
+    ($type: ident) => {
+        impl<E: Clone> $crate::util::Collection<E> for $type<E> {
+            type Iter = $crate::util::ListIter<E>;
+
+            fn size(&self) -> usize {
+                self.view.size()
+            }
+
+            fn contains(&self, o: &E) -> bool
+            where
+                E: PartialEq,
+            {
+                self.view.index_of(o).is_some()
+            }
+
+            fn iterator(&self) -> $crate::util::ListIter<E> {
+                self.view.list_iterator(0)
+            }
+
+            fn to_array(&self) -> Vec<E> {
+                self.view.to_vec()
+            }
+
+            fn add(&mut self, e: E) -> bool {
+                self.view.add(e);
+                true
+            }
+
+            fn remove(&mut self, o: &E) -> bool
+            where
+                E: PartialEq,
+            {
+                self.view.remove_object(o)
+            }
+
+            fn add_all<C: $crate::util::Collection<E>>(&mut self, c: &C) -> bool {
+                self.view.add_all_at(self.view.size(), c.to_array())
+            }
+
+            fn remove_if<F: FnMut(&E) -> bool>(&mut self, filter: F) -> bool {
+                self.view.remove_if(filter)
+            }
+
+            fn clear(&mut self) {
+                self.view.clear()
+            }
+
+            fn for_each<F: FnMut(&E)>(&self, action: F) {
+                self.view.for_each(action)
+            }
+        }
+
+        impl<E: Clone> $crate::util::List<E> for $type<E> {
+            fn get(&self, index: usize) -> E {
+                self.view.get(index)
+            }
+
+            fn set(&mut self, index: usize, e: E) -> E {
+                self.view.set(index, e)
+            }
+
+            fn add_at(&mut self, index: usize, e: E) {
+                self.view.add_at(index, e)
+            }
+
+            fn remove_at(&mut self, index: usize) -> E {
+                self.view.remove_at(index)
+            }
+
+            fn add_all_at<C: $crate::util::Collection<E>>(&mut self, index: usize, c: &C) -> bool {
+                self.view.add_all_at(index, c.to_array())
+            }
+
+            fn index_of(&self, o: &E) -> Option<usize>
+            where
+                E: PartialEq,
+            {
+                self.view.index_of(o)
+            }
+
+            fn last_index_of(&self, o: &E) -> Option<usize>
+            where
+                E: PartialEq,
+            {
+                self.view.last_index_of(o)
+            }
+
+            fn list_iterator_at(&self, index: usize) -> $crate::util::ListIter<E> {
+                self.view.list_iterator(index)
+            }
+
+            fn sub_list(&self, from_index: usize, to_index: usize) -> $crate::util::SubList<E> {
+                self.view.sub_list(from_index, to_index)
+            }
+
+            fn replace_all<F: FnMut(&E) -> E>(&mut self, operator: F) {
+                self.view.replace_all(operator)
+            }
+
+            fn sort<C: $crate::util::Comparator<E>>(&mut self, c: C) {
+                self.view.sort(c)
+            }
+        }
+
+        /// Like Java's `toString`: the elements in brackets, separated by `, `.
+        impl<E: Clone + std::fmt::Display> std::fmt::Display for $type<E> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("[")?;
+                for (i, e) in self.view.to_vec().iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{e}")?;
+                }
+                f.write_str("]")
+            }
+        }
+
+        impl<E: Clone + std::fmt::Debug> std::fmt::Debug for $type<E> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.view.to_vec()).finish()
+            }
+        }
+
+        /// Like Java's `equals`, any two lists with equal elements in the same order are
+        /// equal.
+        impl<E: Clone + PartialEq, L: $crate::util::List<E>> PartialEq<L> for $type<E> {
+            fn eq(&self, other: &L) -> bool {
+                self.view.to_vec() == other.to_array()
+            }
+        }
+
+        /// Java's `List.hashCode`: `31 * hash + e.hashCode()` over the elements, from 1.
+        impl<E: Clone + $crate::HashCode> $crate::HashCode for $type<E> {
+            fn hash_code(&self) -> i32 {
+                self.view.to_vec().iter().fold(1i32, |hash, e| {
+                    hash.wrapping_mul(31).wrapping_add(e.hash_code())
+                })
+            }
+        }
+
+        impl<E: Clone> IntoIterator for &$type<E> {
+            type Item = E;
+            type IntoIter = $crate::util::ListIter<E>;
+
+            fn into_iter(self) -> $crate::util::ListIter<E> {
+                self.view.list_iterator(0)
+            }
+        }
+
+        impl<E: Clone> From<&$type<E>> for $crate::util::collections::UnmodifiableList<E> {
+            fn from(list: &$type<E>) -> Self {
+                Self {
+                    view: list.view.read_only(),
+                }
+            }
+        }
+
+        impl<E: Clone> Extend<E> for $type<E> {
+            fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+                for e in iter {
+                    self.view.add(e);
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use list_impls;
+
+list_impls!(SubList);
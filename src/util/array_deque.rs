@@ -0,0 +1,616 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+use crate::errors::util::ConcurrentModificationException;
+use crate::errors::{IllegalStateException, NullPointerException, Throwable};
+use crate::util::collection;
+use crate::util::{Collection, Deque, Queue};
+use crate::HashCode;
+
+fn concurrent_modification() -> ! {
+    ConcurrentModificationException::new(String::new()).throw()
+}
+
+fn inc(i: usize, capacity: usize) -> usize {
+    if i + 1 >= capacity {
+        0
+    } else {
+        i + 1
+    }
+}
+
+fn dec(i: usize, capacity: usize) -> usize {
+    if i == 0 {
+        capacity - 1
+    } else {
+        i - 1
+    }
+}
+
+/// The distance from `j` to `i` going forwards around the array.
+fn sub(i: usize, j: usize, capacity: usize) -> usize {
+    if i >= j {
+        i - j
+    } else {
+        i + capacity - j
+    }
+}
+
+/// The circular array of an `ArrayDeque`, laid out like the JDK's: the elements run from
+/// `head` up to `tail`, wrapping around, and there is always at least one empty slot.
+struct Elements<E> {
+    es: Vec<Option<E>>,
+    head: usize,
+    tail: usize,
+    /// `HashCode::is_null` of the elements, which are checked as they are added.
+    is_null: fn(&E) -> bool,
+}
+
+impl<E: Clone> Elements<E> {
+    fn capacity(&self) -> usize {
+        self.es.len()
+    }
+
+    fn size(&self) -> usize {
+        sub(self.tail, self.head, self.capacity())
+    }
+
+    /// The indexes of the elements from head to tail.
+    fn indexes(&self) -> impl Iterator<Item = usize> {
+        let (head, capacity) = (self.head, self.capacity());
+        (0..self.size()).map(move |i| (head + i) % capacity)
+    }
+
+    /// Java's `grow`: half again as big, or twice as big plus two while small, moving the
+    /// elements from head to the end of the array up into the new room.
+    fn grow(&mut self, needed: usize) {
+        let old_capacity = self.capacity();
+        let jump = if old_capacity < 64 {
+            old_capacity + 2
+        } else {
+            old_capacity >> 1
+        };
+        let new_capacity = old_capacity + jump.max(needed);
+        self.es.resize(new_capacity, None);
+        if self.tail < self.head || (self.tail == self.head && self.es[self.head].is_some()) {
+            let new_space = new_capacity - old_capacity;
+            self.es[self.head..].rotate_right(new_space);
+            self.head += new_space;
+        }
+    }
+
+    fn require_non_null(&self, e: &E) {
+        if (self.is_null)(e) {
+            NullPointerException::new(String::new()).throw()
+        }
+    }
+
+    fn add_first(&mut self, e: E) {
+        self.require_non_null(&e);
+        self.head = dec(self.head, self.capacity());
+        self.es[self.head] = Some(e);
+        if self.head == self.tail {
+            self.grow(1);
+        }
+    }
+
+    fn add_last(&mut self, e: E) {
+        self.require_non_null(&e);
+        self.es[self.tail] = Some(e);
+        self.tail = inc(self.tail, self.capacity());
+        if self.head == self.tail {
+            self.grow(1);
+        }
+    }
+
+    fn poll_first(&mut self) -> Option<E> {
+        let e = self.es[self.head].take()?;
+        self.head = inc(self.head, self.capacity());
+        Some(e)
+    }
+
+    fn poll_last(&mut self) -> Option<E> {
+        let t = dec(self.tail, self.capacity());
+        let e = self.es[t].take()?;
+        self.tail = t;
+        Some(e)
+    }
+
+    /// Java's `delete`: removes the element at `i`, closing the gap from whichever end is
+    /// nearer. Returns whether the elements after it moved back.
+    fn delete(&mut self, i: usize) -> bool {
+        let capacity = self.capacity();
+        let (h, t) = (self.head, self.tail);
+        let front = sub(i, h, capacity);
+        let back = sub(t, i, capacity) - 1;
+        if front < back {
+            if h <= i {
+                self.es[h..=i].rotate_right(1);
+            } else {
+                self.es[..=i].rotate_right(1);
+                self.es.swap(0, capacity - 1);
+                self.es[h..].rotate_right(1);
+            }
+            self.es[h] = None;
+            self.head = inc(h, capacity);
+            false
+        } else {
+            self.tail = dec(t, capacity);
+            if i <= self.tail {
+                self.es[i..=self.tail].rotate_left(1);
+            } else {
+                self.es[i..].rotate_left(1);
+                self.es.swap(capacity - 1, 0);
+                self.es[..t].rotate_left(1);
+            }
+            self.es[self.tail] = None;
+            true
+        }
+    }
+
+    fn clear(&mut self) {
+        for slot in &mut self.es {
+            *slot = None;
+        }
+        self.head = 0;
+        self.tail = 0;
+    }
+}
+
+/// `java.util.ArrayDeque`: a resizable circular array implementing `Deque`, which is
+/// faster than `LinkedList` as a queue and than `Stack` as a stack.
+///
+/// Like Java's, it can't hold `null`: adding `None` throws `NullPointerException`, and
+/// where Java's `poll` and `peek` methods return `null` on an empty deque, these return
+/// `None`. Like Java's, the deque is shared with its
+/// iterators, which aren't fail-fast: they throw `ConcurrentModificationException` only
+/// when a modification leaves an empty slot where they expected an element. `clone`
+/// copies the elements into a new deque.
+pub struct ArrayDeque<E> {
+    elements: Rc<RefCell<Elements<E>>>,
+}
+
+impl<E: Clone + HashCode> ArrayDeque<E> {
+    /// An empty deque with room for 16 elements.
+    pub fn new() -> Self {
+        Self::with_array(16 + 1)
+    }
+
+    pub fn with_capacity(num_elements: usize) -> Self {
+        Self::with_array(if num_elements < 1 {
+            1
+        } else {
+            num_elements.saturating_add(1)
+        })
+    }
+
+    /// Java's `new ArrayDeque<>(c)`: the elements of a collection in its iteration order.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        let mut deque = Self::with_capacity(c.size());
+        for e in c.iterator() {
+            deque.add_last(e);
+        }
+        deque
+    }
+
+    fn with_array(capacity: usize) -> Self {
+        Self {
+            elements: Rc::new(RefCell::new(Elements {
+                es: vec![None; capacity],
+                head: 0,
+                tail: 0,
+                is_null: E::is_null,
+            })),
+        }
+    }
+}
+
+impl<E: Clone> ArrayDeque<E> {
+    fn elements(&self) -> Ref<'_, Elements<E>> {
+        self.elements.borrow()
+    }
+
+    fn elements_mut(&self) -> RefMut<'_, Elements<E>> {
+        self.elements.borrow_mut()
+    }
+}
+
+impl<E: Clone> Collection<E> for ArrayDeque<E> {
+    type Iter = Iter<E>;
+
+    fn size(&self) -> usize {
+        self.elements().size()
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        let elements = self.elements();
+        elements
+            .indexes()
+            .any(|i| elements.es[i].as_ref() == Some(o))
+    }
+
+    fn iterator(&self) -> Iter<E> {
+        let elements = self.elements();
+        Iter {
+            elements: Rc::clone(&self.elements),
+            cursor: elements.head,
+            remaining: elements.size(),
+            last_returned: None,
+            descending: false,
+        }
+    }
+
+    fn to_array(&self) -> Vec<E> {
+        let elements = self.elements();
+        elements
+            .indexes()
+            .map(|i| elements.es[i].clone().unwrap())
+            .collect()
+    }
+
+    fn add(&mut self, e: E) -> bool {
+        self.add_last(e);
+        true
+    }
+
+    fn remove(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.remove_first_occurrence(o)
+    }
+
+    /// Makes room for the elements of the collection first, like Java's.
+    fn add_all<C: Collection<E>>(&mut self, c: &C) -> bool {
+        let (size, capacity) = {
+            let elements = self.elements();
+            (elements.size(), elements.capacity())
+        };
+        let needed = (size + c.size() + 1).saturating_sub(capacity);
+        if needed > 0 {
+            self.elements_mut().grow(needed);
+        }
+        for e in c.iterator() {
+            self.add_last(e);
+        }
+        self.size() > size
+    }
+
+    /// Like Java's, the filter sees every element before any is removed, and the deque
+    /// throws `ConcurrentModificationException` if the filter added to its end.
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, mut filter: F) -> bool {
+        let (indexes, end) = {
+            let elements = self.elements();
+            (elements.indexes().collect::<Vec<_>>(), elements.tail)
+        };
+        let mut removed = Vec::with_capacity(indexes.len());
+        for &i in &indexes {
+            let e = self.elements().es[i].clone();
+            removed.push(e.is_some_and(|e| filter(&e)));
+        }
+        let mut elements = self.elements_mut();
+        if elements.tail != end {
+            concurrent_modification();
+        }
+        if !removed.contains(&true) {
+            return false;
+        }
+        let survivors: Vec<E> = indexes
+            .iter()
+            .zip(&removed)
+            .filter(|(_, &removed)| !removed)
+            .filter_map(|(&i, _)| elements.es[i].take())
+            .collect();
+        for &i in &indexes {
+            elements.es[i] = None;
+        }
+        let capacity = elements.capacity();
+        let mut w = elements.head;
+        for e in survivors {
+            elements.es[w] = Some(e);
+            w = inc(w, capacity);
+        }
+        elements.tail = w;
+        true
+    }
+
+    fn clear(&mut self) {
+        self.elements_mut().clear();
+    }
+
+    /// Like Java's, throws `ConcurrentModificationException` if the action added to the
+    /// end of the deque or left an empty slot where an element was.
+    fn for_each<F: FnMut(&E)>(&self, mut action: F) {
+        let (indexes, end) = {
+            let elements = self.elements();
+            (elements.indexes().collect::<Vec<_>>(), elements.tail)
+        };
+        for i in indexes {
+            let e = self.elements().es.get(i).cloned().flatten();
+            match e {
+                Some(e) => action(&e),
+                None => concurrent_modification(),
+            }
+        }
+        if self.elements().tail != end {
+            concurrent_modification();
+        }
+    }
+}
+
+impl<E: Clone> Queue<E> for ArrayDeque<E> {
+    fn offer(&mut self, e: E) -> bool {
+        self.add_last(e);
+        true
+    }
+
+    fn poll(&mut self) -> Option<E> {
+        self.poll_first()
+    }
+
+    fn peek(&self) -> Option<E> {
+        self.peek_first()
+    }
+}
+
+impl<E: Clone> Deque<E> for ArrayDeque<E> {
+    type DescendingIter = Iter<E>;
+
+    fn add_first(&mut self, e: E) {
+        self.elements_mut().add_first(e);
+    }
+
+    fn add_last(&mut self, e: E) {
+        self.elements_mut().add_last(e);
+    }
+
+    fn poll_first(&mut self) -> Option<E> {
+        self.elements_mut().poll_first()
+    }
+
+    fn poll_last(&mut self) -> Option<E> {
+        self.elements_mut().poll_last()
+    }
+
+    fn peek_first(&self) -> Option<E> {
+        let elements = self.elements();
+        elements.es[elements.head].clone()
+    }
+
+    fn peek_last(&self) -> Option<E> {
+        let elements = self.elements();
+        elements.es[dec(elements.tail, elements.capacity())].clone()
+    }
+
+    fn remove_first_occurrence(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        let mut elements = self.elements_mut();
+        match elements
+            .indexes()
+            .find(|&i| elements.es[i].as_ref() == Some(o))
+        {
+            Some(i) => {
+                elements.delete(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn remove_last_occurrence(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        let mut elements = self.elements_mut();
+        let indexes: Vec<usize> = elements.indexes().collect();
+        match indexes
+            .into_iter()
+            .rev()
+            .find(|&i| elements.es[i].as_ref() == Some(o))
+        {
+            Some(i) => {
+                elements.delete(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn descending_iterator(&self) -> Iter<E> {
+        let elements = self.elements();
+        Iter {
+            elements: Rc::clone(&self.elements),
+            cursor: dec(elements.tail, elements.capacity()),
+            remaining: elements.size(),
+            last_returned: None,
+            descending: true,
+        }
+    }
+}
+
+impl<E: Clone + HashCode> Default for ArrayDeque<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone> Clone for ArrayDeque<E> {
+    fn clone(&self) -> Self {
+        let elements = self.elements();
+        Self {
+            elements: Rc::new(RefCell::new(Elements {
+                es: elements.es.clone(),
+                head: elements.head,
+                tail: elements.tail,
+                is_null: elements.is_null,
+            })),
+        }
+    }
+}
+
+impl<E: Clone + HashCode> FromIterator<E> for ArrayDeque<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<E: Clone> Extend<E> for ArrayDeque<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for e in iter {
+            self.add_last(e);
+        }
+    }
+}
+
+impl<E: Clone> IntoIterator for &ArrayDeque<E> {
+    type Item = E;
+    type IntoIter = Iter<E>;
+
+    fn into_iter(self) -> Iter<E> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the elements in brackets, separated by `, `.
+impl<E: Clone + std::fmt::Display> std::fmt::Display for ArrayDeque<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, e| write!(f, "{e}"))
+    }
+}
+
+impl<E: Clone + std::fmt::Debug> std::fmt::Debug for ArrayDeque<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.to_array()).finish()
+    }
+}
+
+/// Iterates over an `ArrayDeque` from the first element to the last, or from the last to
+/// the first for `Deque::descending_iterator`.
+///
+/// Like Java's, it returns as many elements as the deque had when it was created, throwing
+/// `ConcurrentModificationException` if it finds an empty slot.
+pub struct Iter<E> {
+    elements: Rc<RefCell<Elements<E>>>,
+    cursor: usize,
+    remaining: usize,
+    last_returned: Option<usize>,
+    descending: bool,
+}
+
+impl<E: Clone> Iter<E> {
+    pub fn has_next(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Removes the element last returned by `next`. Throws `IllegalStateException` if there
+    /// is none.
+    pub fn remove(&mut self) {
+        let Some(last_returned) = self.last_returned.take() else {
+            IllegalStateException::new(String::new(), None).throw()
+        };
+        let mut elements = self.elements.borrow_mut();
+        let left_shifted = elements.delete(last_returned);
+        // The elements after the removed one moved back, or those before it forwards.
+        if left_shifted != self.descending {
+            self.cursor = if self.descending {
+                inc(self.cursor, elements.capacity())
+            } else {
+                dec(self.cursor, elements.capacity())
+            };
+        }
+    }
+}
+
+impl<E: Clone> Iterator for Iter<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let elements = self.elements.borrow();
+        let e = elements.es[self.cursor]
+            .clone()
+            .unwrap_or_else(|| concurrent_modification());
+        self.last_returned = Some(self.cursor);
+        self.cursor = if self.descending {
+            dec(self.cursor, elements.capacity())
+        } else {
+            inc(self.cursor, elements.capacity())
+        };
+        self.remaining -= 1;
+        Some(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn works_as_a_deque() {
+        let mut deque = ArrayDeque::with_capacity(2);
+        for i in 0..5 {
+            deque.add_first(i);
+            deque.offer_last(10 + i);
+        }
+        assert_eq!(deque.to_string(), "[4, 3, 2, 1, 0, 10, 11, 12, 13, 14]");
+        let mut each = deque.descending_iterator();
+        each.next();
+        each.next();
+        each.remove();
+        assert_eq!(deque.to_string(), "[4, 3, 2, 1, 0, 10, 11, 12, 14]");
+        assert_eq!((deque.peek_first(), deque.peek_last()), (Some(4), Some(14)));
+        deque.push(5);
+        assert_eq!(deque.pop(), 5);
+        assert!(deque.remove_last_occurrence(&0));
+        assert_eq!(deque.poll(), Some(4));
+        deque.clear();
+        assert_eq!((deque.poll_first(), deque.peek_last()), (None, None));
+        assert_eq!(message(|| deque.remove_first()), "");
+        assert_eq!(message(|| deque.element()), "");
+    }
+
+    #[test]
+    fn rejects_null() {
+        let mut deque = ArrayDeque::new();
+        deque.add_last(Some(1));
+        assert_eq!(message(|| deque.add_first(None)), "");
+        assert_eq!(message(|| deque.offer_last(None)), "");
+        assert_eq!(message(|| deque.push(None)), "");
+        assert_eq!(deque.to_array(), [Some(1)]);
+    }
+
+    #[test]
+    fn iterators_are_weakly_consistent() {
+        let mut deque: ArrayDeque<i32> = [4, 3, 2, 1, 0, 10, 11, 12, 14].into_iter().collect();
+        let mut each = deque.iterator();
+        each.next();
+        deque.poll_last();
+        deque.poll_last();
+        let mut returned = Vec::new();
+        let thrown = message(|| {
+            while each.has_next() {
+                returned.push(each.next().unwrap());
+            }
+        });
+        assert_eq!(thrown, "");
+        assert_eq!(returned, [3, 2, 1, 0, 10, 11]);
+    }
+}
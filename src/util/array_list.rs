@@ -0,0 +1,204 @@
+use std::collections::VecDeque;
+
+use crate::util::abstract_list::{list_impls, Kind, ListView};
+use crate::util::Collection;
+
+/// `java.util.ArrayList`: a resizable array implementing `List`.
+///
+/// Like Java's, the list is shared with its iterators and sublists: they see each other's
+/// changes, and a structural modification (one that changes the size) other than through
+/// an iterator or sublist makes it throw `ConcurrentModificationException`. `clone` copies
+/// the elements into a new list.
+pub struct ArrayList<E> {
+    view: ListView<E>,
+}
+
+impl<E: Clone> ArrayList<E> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(initial_capacity: usize) -> Self {
+        Self {
+            view: ListView::new(Kind::Array, VecDeque::with_capacity(initial_capacity)),
+        }
+    }
+
+    /// Java's `new ArrayList<>(c)`: a list of the elements of a collection, in its
+    /// iteration order.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        c.to_array().into()
+    }
+
+    pub fn ensure_capacity(&mut self, min_capacity: usize) {
+        let grown = self.view.with_elements(|elements| {
+            let grown = min_capacity > elements.capacity();
+            elements.reserve(min_capacity.saturating_sub(elements.len()));
+            grown
+        });
+        if grown {
+            self.view.increment_mod_count();
+        }
+    }
+
+    pub fn trim_to_size(&mut self) {
+        self.view.increment_mod_count();
+        self.view.with_elements(|elements| elements.shrink_to_fit());
+    }
+}
+
+impl<E: Clone> Default for ArrayList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone> Clone for ArrayList<E> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.deep_clone(),
+        }
+    }
+}
+
+impl<E: Clone> From<Vec<E>> for ArrayList<E> {
+    fn from(elements: Vec<E>) -> Self {
+        Self {
+            view: ListView::new(Kind::Array, elements.into()),
+        }
+    }
+}
+
+impl<E: Clone> FromIterator<E> for ArrayList<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<E>>().into()
+    }
+}
+
+list_impls!(ArrayList);
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::util::{natural_order, ArrayList, Collection, List};
+    use crate::HashCode;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    fn list() -> ArrayList<i32> {
+        ArrayList::from(vec![3, 1, 2])
+    }
+
+    #[test]
+    fn edits_like_jdk() {
+        let mut list = list();
+        list.add_at(1, 7);
+        assert_eq!(list.remove_at(0), 3);
+        assert!(list.remove(&2));
+        assert!(!list.remove(&9));
+        assert_eq!(list.set(1, 5), 1);
+        list.add_all_at(0, &ArrayList::from(vec![4, 4]));
+        assert_eq!(list.to_string(), "[4, 4, 7, 5]");
+        assert_eq!(
+            (list.index_of(&4), list.last_index_of(&4)),
+            (Some(0), Some(1))
+        );
+        list.replace_all(|e| e * 10);
+        list.sort(natural_order());
+        assert!(list.remove_if(|e| *e == 40));
+        assert_eq!(list, vec![50, 70].into_iter().collect::<ArrayList<_>>());
+        assert_eq!(ArrayList::from(vec![1, 2, 3]).hash_code(), 30817);
+
+        let mut iterator = list.list_iterator();
+        iterator.next();
+        iterator.add(7);
+        iterator.previous();
+        iterator.remove();
+        iterator.next();
+        iterator.set(8);
+        assert_eq!(
+            (iterator.next_index(), iterator.previous_index()),
+            (2, Some(1))
+        );
+        assert_eq!(list.to_string(), "[50, 8]");
+    }
+
+    #[test]
+    fn sub_lists_write_through() {
+        let list = list();
+        let sub_list = list.sub_list(0, 3);
+        let mut nested = sub_list.sub_list(1, 2);
+        nested.add(9);
+        assert_eq!(
+            (list.to_string(), sub_list.to_string(), nested.to_string()),
+            (
+                "[3, 1, 9, 2]".to_string(),
+                "[3, 1, 9, 2]".to_string(),
+                "[1, 9]".to_string()
+            )
+        );
+        sub_list.sub_list(0, 2).clear();
+        assert_eq!(list.to_string(), "[9, 2]");
+        // A sibling sublist's change is a concurrent modification.
+        let sub_list = list.sub_list(0, 2);
+        list.sub_list(0, 1).add(5);
+        assert_eq!(message(|| sub_list.size()), "");
+    }
+
+    #[test]
+    fn fails_fast() {
+        let mut list = list();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            for e in &list {
+                list.add(e);
+            }
+        }));
+        assert_eq!(*result.unwrap_err().downcast::<String>().unwrap(), "");
+        // Removing the second to last element ends the loop before the check, and removing
+        // the last one does not.
+        let mut list = self::list();
+        for e in &list {
+            if e == 1 {
+                list.remove(&e);
+            }
+        }
+        assert_eq!(list.to_string(), "[3, 2]");
+        let error = message(|| {
+            for e in &list {
+                if e == 2 {
+                    list.remove(&e);
+                }
+            }
+        });
+        assert_eq!(error, "");
+        // `ArrayList` counts even these as modifications.
+        let list = self::list();
+        let mut iterator = list.iterator();
+        iterator.next();
+        list.sub_list(1, 1).clear();
+        assert_eq!(message(|| iterator.next()), "");
+    }
+
+    #[test]
+    fn throws_jdk_messages() {
+        let mut list = list();
+        assert_eq!(
+            message(|| list.get(5)),
+            "Index 5 out of bounds for length 3"
+        );
+        assert_eq!(message(|| list.add_at(5, 1)), "Index: 5, Size: 3");
+        assert_eq!(message(|| list.sub_list(0, 5)), "toIndex = 5");
+        assert_eq!(message(|| list.sub_list(2, 1)), "fromIndex(2) > toIndex(1)");
+        assert_eq!(
+            message(|| list.sub_list(1, 3).remove_at(5)),
+            "Index 5 out of bounds for length 2"
+        );
+        assert_eq!(message(|| list.list_iterator().remove()), "");
+    }
+}
@@ -0,0 +1,458 @@
+//! `java.util.Arrays`: sorting, searching, comparing and printing slices, which stand in
+//! for Java arrays.
+//!
+//! Objects are sorted by the JDK's TimSort, which calls the comparator on the same pairs
+//! as Java's, and primitives by its dual-pivot quicksort. The sorts of objects take a
+//! comparator: `natural_order()` sorts them like `Arrays.sort(Object[])`.
+
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::errors::{ArrayIndexOutOfBoundsException, IllegalArgumentException, Throwable};
+use crate::util::{dual_pivot_quicksort, tim_sort, Comparator};
+use crate::{floating_decimal, HashCode, JString};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The Java primitive types that `sort` and `binary_search` take: `byte`, `short`, `char`,
+/// `int` and `long` as `i8`, `i16`, `u16` (or `char`), `i32` and `i64`, and `float` and
+/// `double`.
+///
+/// Floats are ordered like `Double.compare` orders them, which `<` doesn't do: `-0.0`
+/// comes before `0.0`, and NaN after everything.
+pub trait Primitive: Copy + PartialOrd + sealed::Sealed {
+    #[doc(hidden)]
+    const FLOATING: bool = false;
+
+    #[doc(hidden)]
+    fn is_nan(self) -> bool {
+        false
+    }
+
+    /// Java's `doubleToLongBits`, or the value itself for an integer.
+    #[doc(hidden)]
+    fn bits(self) -> i64;
+
+    /// Whether the sign bit is set: for a float that compares equal to 0, whether it is
+    /// `-0.0`.
+    #[doc(hidden)]
+    fn sign_bit(self) -> bool;
+
+    #[doc(hidden)]
+    fn zero(negative: bool) -> Self;
+}
+
+macro_rules! integer_primitive {
+    ($($type: ty),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl Primitive for $type {
+                fn bits(self) -> i64 {
+                    self as i64
+                }
+
+                fn sign_bit(self) -> bool {
+                    false
+                }
+
+                fn zero(_: bool) -> Self {
+                    Self::default()
+                }
+            }
+        )*
+    };
+}
+
+integer_primitive!(i8, i16, u16, i32, i64, char);
+
+macro_rules! float_primitive {
+    ($($type: ty => $bits: ty),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl Primitive for $type {
+                const FLOATING: bool = true;
+
+                fn is_nan(self) -> bool {
+                    <$type>::is_nan(self)
+                }
+
+                fn bits(self) -> i64 {
+                    // All NaNs are the same NaN to `doubleToLongBits`.
+                    let value = if self.is_nan() { <$type>::NAN } else { self };
+                    value.to_bits() as $bits as i64
+                }
+
+                fn sign_bit(self) -> bool {
+                    self.is_sign_negative()
+                }
+
+                fn zero(negative: bool) -> Self {
+                    if negative {
+                        -0.0
+                    } else {
+                        0.0
+                    }
+                }
+            }
+        )*
+    };
+}
+
+float_primitive!(f32 => i32, f64 => i64);
+
+/// Sorts the values into ascending order, like `Arrays.sort` of a primitive array.
+pub fn sort<T: Primitive>(a: &mut [T]) {
+    if !T::FLOATING {
+        dual_pivot_quicksort::sort(a);
+        return;
+    }
+    // NaNs go to the end, where they belong, so that the rest can be sorted by `<`.
+    let mut right = a.len();
+    while right > 0 && a[right - 1].is_nan() {
+        right -= 1;
+    }
+    for k in (0..right).rev() {
+        if a[k].is_nan() {
+            right -= 1;
+            a.swap(k, right);
+        }
+    }
+    let a = &mut a[..right];
+    dual_pivot_quicksort::sort(a);
+
+    // `<` doesn't tell the zeros apart, so the negative ones are moved before the rest.
+    let first_zero = a.partition_point(|&e| e < T::zero(false));
+    let negative_zeros = a[first_zero..]
+        .iter()
+        .take_while(|&&e| e == T::zero(false))
+        .filter(|e| e.sign_bit())
+        .count();
+    let zeros = a[first_zero..]
+        .iter()
+        .take_while(|&&e| e == T::zero(false))
+        .count();
+    for (i, e) in a[first_zero..first_zero + zeros].iter_mut().enumerate() {
+        *e = T::zero(i < negative_zeros);
+    }
+}
+
+/// Sorts the elements stably by the comparator, like `Arrays.sort` of an object array.
+/// Throws `IllegalArgumentException` if the sort finds that the comparator contradicts
+/// itself, with `"Comparison method violates its general contract!"`.
+///
+/// If the comparator throws, the slice is left in whatever state Java's sort leaves the
+/// array in, which can have an element twice and another not at all.
+pub fn sort_with<T: Clone, C: Comparator<T>>(a: &mut [T], c: C) {
+    tim_sort::sort(a, &|x: &T, y: &T| c.compare(x, y));
+}
+
+/// Searches the sorted values for the key, returning its index, or `-(insertion point) - 1`
+/// if it isn't there. With duplicates, any of their indexes may be returned.
+pub fn binary_search<T: Primitive>(a: &[T], key: T) -> i32 {
+    let mut low = 0i32;
+    let mut high = a.len() as i32 - 1;
+    while low <= high {
+        let mid = ((low + high) as u32 >> 1) as i32;
+        let mid_val = a[mid as usize];
+        if mid_val < key {
+            low = mid + 1;
+        } else if mid_val > key {
+            high = mid - 1;
+        } else if mid_val.bits() == key.bits() {
+            return mid;
+        } else if mid_val.bits() < key.bits() {
+            // `-0.0` and `0.0`, or NaNs.
+            low = mid + 1;
+        } else {
+            high = mid - 1;
+        }
+    }
+    -(low + 1)
+}
+
+/// Searches elements sorted by the comparator for the key, like `binary_search`.
+pub fn binary_search_with<T, C: Comparator<T>>(a: &[T], key: &T, c: C) -> i32 {
+    let mut low = 0i32;
+    let mut high = a.len() as i32 - 1;
+    while low <= high {
+        let mid = ((low + high) as u32 >> 1) as i32;
+        let cmp = c.compare(&a[mid as usize], key);
+        if cmp < 0 {
+            low = mid + 1;
+        } else if cmp > 0 {
+            high = mid - 1;
+        } else {
+            return mid;
+        }
+    }
+    -(low + 1)
+}
+
+/// Whether the slices have equal elements in the same order. Like Java's, floats are
+/// compared by their bits, so NaN equals NaN and `0.0` doesn't equal `-0.0`.
+pub fn equals<T: PartialEq + HashCode>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(x, y)| x.java_equals(y).unwrap_or_else(|| x == y))
+}
+
+/// The hash code of a `List` of the elements.
+pub fn hash_code<T: HashCode>(a: &[T]) -> i32 {
+    a.iter().fold(1i32, |hash, e| {
+        hash.wrapping_mul(31).wrapping_add(e.hash_code())
+    })
+}
+
+/// Sets every element to `val`.
+pub fn fill<T: Clone>(a: &mut [T], val: T) {
+    a.fill(val);
+}
+
+/// A copy of `original[from..to]`, padded with default values (Java's zeros and nulls)
+/// where `to` is past the end. Throws `IllegalArgumentException` if `from > to`, and
+/// `ArrayIndexOutOfBoundsException` if `from` is past the end.
+pub fn copy_of_range<T: Clone + Default>(original: &[T], from: usize, to: usize) -> Vec<T> {
+    if from > to {
+        IllegalArgumentException::new(format!("{from} > {to}"), None).throw()
+    }
+    if from > original.len() {
+        let length = original.len() as i64 - from as i64;
+        ArrayIndexOutOfBoundsException::new(
+            format!("arraycopy: length {length} is negative"),
+            length as i32,
+        )
+        .throw()
+    }
+    let mut copy = original[from..to.min(original.len())].to_vec();
+    copy.resize(to - from, T::default());
+    copy
+}
+
+/// `"[a, b, c]"`, with the elements written by `Display`, except that floats are written
+/// like `Double.toString` writes them.
+pub fn to_string<T: Display>(a: &[T]) -> String {
+    let mut out = String::from("[");
+    for (i, e) in a.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&floating_decimal::display_to_string(e));
+    }
+    out.push(']');
+    out
+}
+
+/// Like `to_string`, but writing nested slices, `Vec`s and arrays as their elements too,
+/// and `None` as `null`.
+pub fn deep_to_string<T: DeepToString>(a: &[T]) -> String {
+    let mut out = String::new();
+    a.deep_to_string(&mut out);
+    out
+}
+
+/// How `deep_to_string` writes an element. Slices, `Vec`s and arrays are written as their
+/// elements in brackets, `None` as `null`, and the other implementations by `Display`.
+pub trait DeepToString {
+    fn deep_to_string(&self, out: &mut String);
+}
+
+macro_rules! display_deep_to_string {
+    ($($type: ty),*) => {
+        $(
+            impl DeepToString for $type {
+                fn deep_to_string(&self, out: &mut String) {
+                    out.push_str(&self.to_string());
+                }
+            }
+        )*
+    };
+}
+
+display_deep_to_string!(
+    bool, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, char, str, String, JString
+);
+
+impl DeepToString for f32 {
+    fn deep_to_string(&self, out: &mut String) {
+        out.push_str(&floating_decimal::float_to_string(*self));
+    }
+}
+
+impl DeepToString for f64 {
+    fn deep_to_string(&self, out: &mut String) {
+        out.push_str(&floating_decimal::double_to_string(*self));
+    }
+}
+
+impl<T: DeepToString> DeepToString for [T] {
+    fn deep_to_string(&self, out: &mut String) {
+        out.push('[');
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            e.deep_to_string(out);
+        }
+        out.push(']');
+    }
+}
+
+impl<T: DeepToString, const N: usize> DeepToString for [T; N] {
+    fn deep_to_string(&self, out: &mut String) {
+        self.as_slice().deep_to_string(out);
+    }
+}
+
+impl<T: DeepToString> DeepToString for Vec<T> {
+    fn deep_to_string(&self, out: &mut String) {
+        self.as_slice().deep_to_string(out);
+    }
+}
+
+impl<T: DeepToString> DeepToString for Option<T> {
+    fn deep_to_string(&self, out: &mut String) {
+        match self {
+            Some(e) => e.deep_to_string(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+macro_rules! pointer_deep_to_string {
+    ($($type: ty),*) => {
+        $(
+            impl<T: DeepToString + ?Sized> DeepToString for $type {
+                fn deep_to_string(&self, out: &mut String) {
+                    (**self).deep_to_string(out);
+                }
+            }
+        )*
+    };
+}
+
+pointer_deep_to_string!(&T, Box<T>, Rc<T>);
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::random::RandomGenerator;
+    use crate::util::{natural_order, reverse_order};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn sorts_primitives() {
+        let mut random = crate::util::Random::with_seed(42);
+        for length in [0, 1, 2, 10, 46, 47, 100, 1000, 5000] {
+            for bound in [3, 1000] {
+                let mut a: Vec<i32> = (0..length)
+                    .map(|_| random.next_int_bounded(bound))
+                    .collect();
+                let mut expected = a.clone();
+                expected.sort();
+                sort(&mut a);
+                assert_eq!(a, expected);
+            }
+        }
+
+        let mut a = [3.0, f64::NAN, 0.0, -1.5, -0.0, 0.0, f64::INFINITY, -0.0];
+        sort(&mut a);
+        assert_eq!(
+            to_string(&a[..7].iter().map(|e| format!("{e:?}")).collect::<Vec<_>>()),
+            "[-1.5, -0.0, -0.0, 0.0, 0.0, 3.0, inf]"
+        );
+        assert!(a[7].is_nan());
+        assert_eq!(binary_search(&a, -0.0), 1);
+        assert_eq!(binary_search(&a, 0.0), 3);
+        assert_eq!(binary_search(&a, f64::NAN), 7);
+        assert_eq!(binary_search(&[1, 3, 5], 4), -3);
+        assert_eq!(binary_search(&[1, 3, 5], 0), -1);
+        assert_eq!(binary_search::<i32>(&[], 0), -1);
+    }
+
+    #[test]
+    fn sorts_objects_stably() {
+        let mut a: Vec<(i32, usize)> = (0..500).map(|i| ((i * 7919 % 13) as i32, i)).collect();
+        sort_with(&mut a, |x: &(i32, usize), y: &(i32, usize)| x.0 - y.0);
+        assert!(a.windows(2).all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+
+        let mut words = ["pear", "apple", "fig"];
+        sort_with(&mut words, natural_order());
+        assert_eq!(to_string(&words), "[apple, fig, pear]");
+        assert_eq!(binary_search_with(&words, &"fig", natural_order()), 1);
+        assert_eq!(binary_search_with(&words, &"kiwi", natural_order()), -3);
+        sort_with(&mut words, reverse_order());
+        assert_eq!(to_string(&words), "[pear, fig, apple]");
+    }
+
+    #[test]
+    fn detects_broken_comparators() {
+        // Java 17 throws for the same array.
+        let mut random = crate::util::Random::with_seed(18);
+        let length = random.next_int_bounded(3000);
+        let bound = 1 + random.next_int_bounded(5);
+        let mut a: Vec<i32> = (0..length)
+            .map(|_| random.next_int_bounded(bound))
+            .collect();
+        assert_eq!(
+            message(|| sort_with(&mut a, |x: &i32, y: &i32| if x < y { -1 } else { 1 })),
+            "Comparison method violates its general contract!"
+        );
+    }
+
+    #[test]
+    fn helpers() {
+        assert!(equals(&[1, 2], &[1, 2]));
+        assert!(!equals(&[1, 2], &[2, 1]));
+        assert!(equals(&[f64::NAN, 0.0], &[f64::NAN, 0.0]));
+        assert!(!equals(&[0.0], &[-0.0]));
+        assert!(!equals(&[0.0f32], &[-0.0]));
+        assert!(equals(&[Some(f32::NAN), None], &[Some(f32::NAN), None]));
+        assert_eq!(hash_code(&[1, 2]), 994);
+        assert_eq!(hash_code(&[Some("a".to_string()), None]), 3968);
+        assert_eq!(hash_code::<i32>(&[]), 1);
+
+        let mut a = [0; 3];
+        fill(&mut a, 7);
+        assert_eq!(a, [7, 7, 7]);
+
+        let a = [1, 2, 3, 4, 5];
+        assert_eq!(copy_of_range(&a, 3, 7), [4, 5, 0, 0]);
+        assert_eq!(copy_of_range(&a, 5, 7), [0, 0]);
+        assert_eq!(message(|| copy_of_range(&a, 4, 2)), "4 > 2");
+        assert_eq!(
+            message(|| copy_of_range(&a, 7, 9)),
+            "arraycopy: length -2 is negative"
+        );
+
+        assert_eq!(to_string::<i32>(&[]), "[]");
+        assert_eq!(
+            to_string(&[1.0, 1e10, f64::INFINITY, -0.0]),
+            "[1.0, 1.0E10, Infinity, -0.0]"
+        );
+        assert_eq!(to_string(&[&0.1f32, &f32::NAN]), "[0.1, NaN]");
+        assert_eq!(deep_to_string(&[vec![1e21], vec![]]), "[[1.0E21], []]");
+        let nested: [Option<Box<dyn DeepToString>>; 4] = [
+            Some(Box::new(1)),
+            None,
+            Some(Box::new([1, 2])),
+            Some(Box::new(vec![
+                Box::new("a") as Box<dyn DeepToString>,
+                Box::new(Vec::<i32>::new()),
+            ])),
+        ];
+        assert_eq!(deep_to_string(&nested), "[1, null, [1, 2], [a, []]]");
+    }
+}
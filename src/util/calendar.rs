@@ -0,0 +1,201 @@
+use std::fmt;
+
+use crate::errors::{ArrayIndexOutOfBoundsException, Throwable};
+use crate::text::{calendar_fields, calendar_rules};
+use crate::util::random::current_time_millis;
+use crate::util::{Date, Locale, TimeZone};
+
+const BUDDHIST_YEAR_OFFSET: i32 = 543;
+const FIELD_NAMES: [&str; Calendar::FIELD_COUNT as usize] = [
+    "ERA",
+    "YEAR",
+    "MONTH",
+    "WEEK_OF_YEAR",
+    "WEEK_OF_MONTH",
+    "DAY_OF_MONTH",
+    "DAY_OF_YEAR",
+    "DAY_OF_WEEK",
+    "DAY_OF_WEEK_IN_MONTH",
+    "AM_PM",
+    "HOUR",
+    "HOUR_OF_DAY",
+    "MINUTE",
+    "SECOND",
+    "MILLISECOND",
+    "ZONE_OFFSET",
+    "DST_OFFSET",
+];
+
+/// `java.util.Calendar`: an instant with the calendar fields it has in a time zone.
+///
+/// Fields are read only, computed like those of `SimpleDateFormat`: in the locale's
+/// calendar, which is Gregorian switching to Julian before 1582-10-15, or Buddhist for Thai.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calendar {
+    time: i64,
+    zone: TimeZone,
+    locale: Locale,
+}
+
+impl Calendar {
+    pub const ERA: i32 = 0;
+    pub const YEAR: i32 = 1;
+    /// From 0 for January.
+    pub const MONTH: i32 = 2;
+    pub const WEEK_OF_YEAR: i32 = 3;
+    pub const WEEK_OF_MONTH: i32 = 4;
+    pub const DATE: i32 = 5;
+    pub const DAY_OF_MONTH: i32 = 5;
+    pub const DAY_OF_YEAR: i32 = 6;
+    /// From 1 for Sunday.
+    pub const DAY_OF_WEEK: i32 = 7;
+    pub const DAY_OF_WEEK_IN_MONTH: i32 = 8;
+    pub const AM_PM: i32 = 9;
+    pub const HOUR: i32 = 10;
+    pub const HOUR_OF_DAY: i32 = 11;
+    pub const MINUTE: i32 = 12;
+    pub const SECOND: i32 = 13;
+    pub const MILLISECOND: i32 = 14;
+    /// The zone's standard offset from UTC in milliseconds.
+    pub const ZONE_OFFSET: i32 = 15;
+    /// The daylight saving time in effect, in milliseconds.
+    pub const DST_OFFSET: i32 = 16;
+    pub const FIELD_COUNT: i32 = 17;
+
+    /// The current time in the default zone and locale.
+    pub fn get_instance() -> Self {
+        Self::get_instance_with(TimeZone::get_default(), &Locale::get_default())
+    }
+
+    /// The current time in a zone, in the calendar of a locale.
+    pub fn get_instance_with(zone: TimeZone, locale: &Locale) -> Self {
+        Self {
+            time: current_time_millis(),
+            zone,
+            locale: locale.clone(),
+        }
+    }
+
+    /// The value of a field from `ERA` to `DST_OFFSET`. Throws
+    /// `ArrayIndexOutOfBoundsException` for any other field number.
+    pub fn get(&self, field: i32) -> i32 {
+        match usize::try_from(field) {
+            Ok(index) if field < Self::FIELD_COUNT => self.fields()[index],
+            _ => ArrayIndexOutOfBoundsException::new(
+                format!(
+                    "Index {field} out of bounds for length {}",
+                    Self::FIELD_COUNT
+                ),
+                field,
+            )
+            .throw(),
+        }
+    }
+
+    fn fields(&self) -> [i32; Self::FIELD_COUNT as usize] {
+        calendar_fields(self.time, &self.zone, &self.locale)
+    }
+
+    pub fn get_time(&self) -> Date {
+        Date::with_time(self.time)
+    }
+
+    pub fn set_time(&mut self, date: &Date) {
+        self.time = date.get_time();
+    }
+
+    pub fn get_time_in_millis(&self) -> i64 {
+        self.time
+    }
+
+    pub fn set_time_in_millis(&mut self, millis: i64) {
+        self.time = millis;
+    }
+
+    pub fn get_time_zone(&self) -> &TimeZone {
+        &self.zone
+    }
+
+    pub fn set_time_zone(&mut self, zone: TimeZone) {
+        self.zone = zone;
+    }
+
+    /// Like Java's `Calendar.hashCode`, except that the zone is left out: the JDK hashes
+    /// its own compiled zone data, which only comes to 0 for UTC and GMT.
+    pub fn hash_code(&self) -> i32 {
+        const GREGORIAN_CUTOVER_FIXED_DATE: i32 = 577_736;
+        let (buddhist, first_day_of_week, minimal_days) = calendar_rules(&self.locale);
+        // Lenient, with the locale's week rules.
+        let other_items = 1 | (first_day_of_week << 1) | (minimal_days << 4);
+        let hash = (self.time as i32) ^ ((self.time >> 32) as i32) ^ other_items;
+        let hash = hash ^ GREGORIAN_CUTOVER_FIXED_DATE;
+        if buddhist {
+            hash ^ BUDDHIST_YEAR_OFFSET
+        } else {
+            hash
+        }
+    }
+
+    /// The first day of the week in the locale, from 1 for Sunday.
+    pub fn get_first_day_of_week(&self) -> i32 {
+        calendar_rules(&self.locale).1
+    }
+
+    /// How many days of the year's first week must fall in the year, in the locale.
+    pub fn get_minimal_days_in_first_week(&self) -> i32 {
+        calendar_rules(&self.locale).2
+    }
+}
+
+/// The time, zone ID and field values, in the manner of Java's `toString`.
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Calendar[time={},zone={}", self.time, self.zone.get_id())?;
+        for (name, value) in FIELD_NAMES.iter().zip(self.fields()) {
+            write!(f, ",{name}={value}")?;
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gets_fields_like_jdk() {
+        let mut calendar =
+            Calendar::get_instance_with(TimeZone::get_time_zone("America/New_York"), &Locale::US);
+        calendar.set_time_in_millis(1_625_158_245_123);
+        let fields: Vec<i32> = (0..Calendar::FIELD_COUNT)
+            .map(|i| calendar.get(i))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                1,
+                2021,
+                6,
+                27,
+                1,
+                1,
+                182,
+                5,
+                1,
+                1,
+                0,
+                12,
+                50,
+                45,
+                123,
+                -18_000_000,
+                3_600_000
+            ]
+        );
+        calendar.set_time_zone(TimeZone::get_time_zone("UTC"));
+        assert_eq!(calendar.hash_code(), 1_660_029_602);
+        let germany = Calendar::get_instance_with(TimeZone::get_time_zone("UTC"), &Locale::GERMANY);
+        assert_eq!(germany.get_first_day_of_week(), 2);
+        assert_eq!(germany.get_minimal_days_in_first_week(), 4);
+    }
+}
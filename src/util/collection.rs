@@ -0,0 +1,357 @@
+use crate::errors::util::NoSuchElementException;
+use crate::errors::{Throwable, UnsupportedOperationException};
+use crate::util::spliterator::{self, ArraySpliterator};
+use crate::util::stream::{ParallelStream, Stream};
+use crate::util::{Comparator, ListIter, SubList};
+
+/// `java.util.Collection`: a group of elements that can be iterated over.
+///
+/// Elements are returned by value, so collections need `E: Clone`; cloning an element is
+/// the equivalent of copying a Java reference, so use `Rc<T>` for elements that are costly
+/// to clone. Java's `equals` is `PartialEq`.
+pub trait Collection<E> {
+    type Iter: Iterator<Item = E>;
+
+    fn size(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq;
+
+    fn iterator(&self) -> Self::Iter;
+
+    fn to_array(&self) -> Vec<E> {
+        self.iterator().collect()
+    }
+
+    /// Adds an element, returning whether the collection changed.
+    fn add(&mut self, e: E) -> bool;
+
+    /// Java's `remove(Object)`: removes one element equal to `o`, returning whether there
+    /// was one.
+    fn remove(&mut self, o: &E) -> bool
+    where
+        E: PartialEq;
+
+    fn contains_all<C: Collection<E>>(&self, c: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        c.iterator().all(|e| self.contains(&e))
+    }
+
+    fn add_all<C: Collection<E>>(&mut self, c: &C) -> bool {
+        let mut modified = false;
+        for e in c.to_array() {
+            modified |= self.add(e);
+        }
+        modified
+    }
+
+    fn remove_all<C: Collection<E>>(&mut self, c: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        self.remove_if(|e| c.contains(e))
+    }
+
+    fn retain_all<C: Collection<E>>(&mut self, c: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        self.remove_if(|e| !c.contains(e))
+    }
+
+    /// Removes the elements matching `filter`, returning whether there were any.
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, filter: F) -> bool;
+
+    fn clear(&mut self);
+
+    fn for_each<F: FnMut(&E)>(&self, mut action: F) {
+        for e in self.iterator() {
+            action(&e);
+        }
+    }
+
+    /// A `Stream` of the elements, in iteration order.
+    fn stream<'a>(&self) -> Stream<'a, E>
+    where
+        E: 'a,
+        Self::Iter: 'a,
+    {
+        Stream::of(self.iterator())
+    }
+
+    /// A spliterator over a copy of the elements, in iteration order.
+    fn spliterator(&self) -> ArraySpliterator<E> {
+        ArraySpliterator::new(self.iterator().collect(), spliterator::ORDERED)
+    }
+
+    /// A `ParallelStream` of the elements, in iteration order. They are copied out of the
+    /// collection first, for the pool's threads to share.
+    fn parallel_stream<'a>(&self) -> ParallelStream<'a, E>
+    where
+        E: Send + 'static,
+    {
+        ParallelStream::from_spliterator(self.spliterator())
+    }
+}
+
+/// `java.util.Set`: a collection without duplicate elements. Like Java's `equals`, two sets
+/// are equal when they have the same elements, in whatever order.
+pub trait Set<E>: Collection<E> {}
+
+/// `java.util.NavigableSet`: a set sorted by its elements, which can be searched for the
+/// elements closest to a given one and viewed in ranges. The views share the set, and
+/// adding an element out of a view's range throws `IllegalArgumentException`.
+pub trait NavigableSet<E>: Set<E> {
+    /// The lowest element, throwing `NoSuchElementException` if the set is empty.
+    fn first(&self) -> E;
+
+    /// The highest element, throwing `NoSuchElementException` if the set is empty.
+    fn last(&self) -> E;
+
+    /// The highest element less than `e`.
+    fn lower(&self, e: &E) -> Option<E>;
+
+    /// The highest element less than or equal to `e`.
+    fn floor(&self, e: &E) -> Option<E>;
+
+    /// The lowest element greater than or equal to `e`.
+    fn ceiling(&self, e: &E) -> Option<E>;
+
+    /// The lowest element greater than `e`.
+    fn higher(&self, e: &E) -> Option<E>;
+
+    fn poll_first(&mut self) -> Option<E>;
+
+    fn poll_last(&mut self) -> Option<E>;
+
+    /// A view of the set in the opposite order.
+    fn descending_set(&self) -> Self
+    where
+        Self: Sized;
+
+    fn descending_iterator(&self) -> Self::Iter
+    where
+        Self: Sized,
+    {
+        self.descending_set().iterator()
+    }
+
+    /// A view of the elements less than `to_element`, or equal to it if `inclusive`.
+    fn head_set(&self, to_element: E, inclusive: bool) -> Self
+    where
+        Self: Sized;
+
+    /// A view of the elements greater than `from_element`, or equal to it if `inclusive`.
+    fn tail_set(&self, from_element: E, inclusive: bool) -> Self
+    where
+        Self: Sized;
+
+    /// A view of the elements from `from_element` to `to_element`. Throws
+    /// `IllegalArgumentException` if `from_element` is greater than `to_element`.
+    fn sub_set(
+        &self,
+        from_element: E,
+        from_inclusive: bool,
+        to_element: E,
+        to_inclusive: bool,
+    ) -> Self
+    where
+        Self: Sized;
+}
+
+/// `AbstractSet.removeAll`, which removes the elements of `c` one by one if the set is the
+/// bigger of the two and otherwise filters the set by them.
+pub(crate) fn remove_all<E, S, C>(set: &mut S, c: &C) -> bool
+where
+    E: PartialEq,
+    S: Set<E>,
+    C: Collection<E>,
+{
+    if set.size() > c.size() {
+        let mut modified = false;
+        for e in c.iterator() {
+            modified |= set.remove(&e);
+        }
+        modified
+    } else {
+        set.remove_if(|e| c.contains(e))
+    }
+}
+
+/// Java's `Collection.removeIf`, which removes through the iterator.
+pub(crate) fn remove_if<E, I: Iterator<Item = E>>(
+    mut each: I,
+    mut filter: impl FnMut(&E) -> bool,
+    mut remove: impl FnMut(&mut I),
+) -> bool {
+    let mut removed = false;
+    while let Some(e) = each.next() {
+        if filter(&e) {
+            remove(&mut each);
+            removed = true;
+        }
+    }
+    removed
+}
+
+/// Writes the elements in brackets, separated by `, `, like Java's `toString`.
+pub(crate) fn fmt_elements<T>(
+    f: &mut std::fmt::Formatter<'_>,
+    elements: impl Iterator<Item = T>,
+    mut fmt: impl FnMut(&mut std::fmt::Formatter<'_>, T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    f.write_str("[")?;
+    for (i, e) in elements.enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        fmt(f, e)?;
+    }
+    f.write_str("]")
+}
+
+pub(crate) fn unsupported() -> ! {
+    UnsupportedOperationException::new(String::new(), None).throw()
+}
+
+/// `java.util.List`: an ordered collection with positional access.
+///
+/// Java overloads `add` and `remove` for indexes; here the positional versions are
+/// `add_at` and `remove_at`. Indexes out of range throw `IndexOutOfBoundsException` with
+/// the implementation's JDK message.
+pub trait List<E>: Collection<E> {
+    fn get(&self, index: usize) -> E;
+
+    /// Replaces an element, returning the one it replaced.
+    fn set(&mut self, index: usize, e: E) -> E;
+
+    fn add_at(&mut self, index: usize, e: E);
+
+    fn remove_at(&mut self, index: usize) -> E;
+
+    fn add_all_at<C: Collection<E>>(&mut self, index: usize, c: &C) -> bool;
+
+    fn index_of(&self, o: &E) -> Option<usize>
+    where
+        E: PartialEq;
+
+    fn last_index_of(&self, o: &E) -> Option<usize>
+    where
+        E: PartialEq;
+
+    fn list_iterator(&self) -> ListIter<E> {
+        self.list_iterator_at(0)
+    }
+
+    /// An iterator whose first `next` returns the element at `index`.
+    fn list_iterator_at(&self, index: usize) -> ListIter<E>;
+
+    /// A view of the elements from `from_index` up to `to_index`, through which the list
+    /// can be read and changed. Changing the list other than through the view makes the
+    /// view throw `ConcurrentModificationException`.
+    fn sub_list(&self, from_index: usize, to_index: usize) -> SubList<E>;
+
+    fn replace_all<F: FnMut(&E) -> E>(&mut self, operator: F);
+
+    /// Sorts the list stably by the comparator.
+    fn sort<C: Comparator<E>>(&mut self, c: C);
+}
+
+/// `java.util.Queue`: a collection for holding elements before processing. Java's
+/// `remove()` is `Deque::remove_first` here, as `remove` removes an element by value.
+pub trait Queue<E>: Collection<E> {
+    /// Adds an element if there is room, returning whether it was added.
+    fn offer(&mut self, e: E) -> bool;
+
+    /// Removes and returns the head, or `None` if the queue is empty.
+    fn poll(&mut self) -> Option<E>;
+
+    fn peek(&self) -> Option<E>;
+
+    /// The head, throwing `NoSuchElementException` if the queue is empty.
+    fn element(&self) -> E {
+        self.peek()
+            .unwrap_or_else(|| NoSuchElementException::new(String::new()).throw())
+    }
+}
+
+/// `java.util.Deque`: a queue that can be added to and removed from at both ends. The
+/// `get`, `remove` and `pop` methods throw `NoSuchElementException` on an empty deque,
+/// where the `peek` and `poll` ones return `None`.
+pub trait Deque<E>: Queue<E> {
+    type DescendingIter: Iterator<Item = E>;
+
+    fn add_first(&mut self, e: E);
+
+    fn add_last(&mut self, e: E);
+
+    fn offer_first(&mut self, e: E) -> bool {
+        self.add_first(e);
+        true
+    }
+
+    fn offer_last(&mut self, e: E) -> bool {
+        self.add_last(e);
+        true
+    }
+
+    fn poll_first(&mut self) -> Option<E>;
+
+    fn poll_last(&mut self) -> Option<E>;
+
+    fn peek_first(&self) -> Option<E>;
+
+    fn peek_last(&self) -> Option<E>;
+
+    fn remove_first(&mut self) -> E {
+        self.poll_first()
+            .unwrap_or_else(|| NoSuchElementException::new(String::new()).throw())
+    }
+
+    fn remove_last(&mut self) -> E {
+        self.poll_last()
+            .unwrap_or_else(|| NoSuchElementException::new(String::new()).throw())
+    }
+
+    fn get_first(&self) -> E {
+        self.peek_first()
+            .unwrap_or_else(|| NoSuchElementException::new(String::new()).throw())
+    }
+
+    fn get_last(&self) -> E {
+        self.peek_last()
+            .unwrap_or_else(|| NoSuchElementException::new(String::new()).throw())
+    }
+
+    /// Removes the first element equal to `o`, returning whether there was one.
+    fn remove_first_occurrence(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.remove(o)
+    }
+
+    fn remove_last_occurrence(&mut self, o: &E) -> bool
+    where
+        E: PartialEq;
+
+    /// Adds an element at the front, like a stack.
+    fn push(&mut self, e: E) {
+        self.add_first(e);
+    }
+
+    /// Removes the element at the front, like a stack.
+    fn pop(&mut self) -> E {
+        self.remove_first()
+    }
+
+    /// Iterates from the last element to the first.
+    fn descending_iterator(&self) -> Self::DescendingIter;
+}
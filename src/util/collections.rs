@@ -0,0 +1,210 @@
+//! `java.util.Collections`: algorithms over lists and collections, and the unmodifiable
+//! lists.
+//!
+//! The algorithms are the JDK's, so `shuffle_with` a `Random` seeded like Java's gives the
+//! same order, and `sort` calls the comparator on the same pairs.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use crate::util::abstract_list::{list_impls, Kind, ListView};
+use crate::util::random::RandomGenerator;
+use crate::util::{natural_order, Collection, Comparator, List, Random};
+
+thread_local! {
+    static RANDOM: RefCell<Random> = RefCell::new(Random::new());
+}
+
+/// Sorts the list by the elements' natural order, stably.
+pub fn sort<E: Ord, L: List<E>>(list: &mut L) {
+    list.sort(natural_order());
+}
+
+/// Sorts the list by the comparator, stably. Throws `IllegalArgumentException` if the sort
+/// finds that the comparator contradicts itself.
+pub fn sort_with<E, L: List<E>, C: Comparator<E>>(list: &mut L, c: C) {
+    list.sort(c);
+}
+
+/// Shuffles the list with a random generator shared by the thread.
+pub fn shuffle<E: Clone, L: List<E>>(list: &mut L) {
+    RANDOM.with(|random| shuffle_with(list, &mut *random.borrow_mut()));
+}
+
+/// Shuffles the list, drawing from `rnd` exactly like Java does, so that a generator in
+/// the same state leaves the list in the same order.
+pub fn shuffle_with<E: Clone, L: List<E>, R: RandomGenerator>(list: &mut L, rnd: &mut R) {
+    // Java shuffles a copy, unless the list has fast random access, and swaps the same
+    // elements either way.
+    let mut elements = list.to_array();
+    for i in (2..=elements.len()).rev() {
+        elements.swap(i - 1, rnd.next_int_bounded(i as i32) as usize);
+    }
+    let mut each = list.list_iterator();
+    for e in elements {
+        each.next();
+        each.set(e);
+    }
+}
+
+/// Swaps the elements at two indexes.
+pub fn swap<E, L: List<E>>(list: &mut L, i: usize, j: usize) {
+    let e = list.get(i);
+    let e = list.set(j, e);
+    list.set(i, e);
+}
+
+/// Searches the list, sorted by the natural order, for the key, returning its index, or
+/// `-(insertion point) - 1` if it isn't there.
+pub fn binary_search<E: Ord, L: List<E>>(list: &L, key: &E) -> i32 {
+    binary_search_with(list, key, natural_order())
+}
+
+/// Searches the list, sorted by the comparator, for the key, like `binary_search`.
+pub fn binary_search_with<E, L: List<E>, C: Comparator<E>>(list: &L, key: &E, c: C) -> i32 {
+    let mut low = 0i32;
+    let mut high = list.size() as i32 - 1;
+    while low <= high {
+        let mid = ((low + high) as u32 >> 1) as i32;
+        let cmp = c.compare(&list.get(mid as usize), key);
+        if cmp < 0 {
+            low = mid + 1;
+        } else if cmp > 0 {
+            high = mid - 1;
+        } else {
+            return mid;
+        }
+    }
+    -(low + 1)
+}
+
+/// How many elements of the collection are equal to `o`.
+pub fn frequency<E: PartialEq, C: Collection<E>>(c: &C, o: &E) -> usize {
+    c.iterator().filter(|e| e == o).count()
+}
+
+/// Rotates the list by `distance`: the element at `i` moves to `(i + distance) % size`, so
+/// a negative distance rotates it the other way.
+pub fn rotate<E, L: List<E>>(list: &mut L, distance: i32) {
+    let size = list.size() as i32;
+    if size == 0 {
+        return;
+    }
+    let distance = distance.rem_euclid(size) as usize;
+    if distance == 0 {
+        return;
+    }
+    let size = size as usize;
+    let mut cycle_start = 0;
+    let mut moved = 0;
+    while moved != size {
+        let mut displaced = list.get(cycle_start);
+        let mut i = cycle_start;
+        loop {
+            i += distance;
+            if i >= size {
+                i -= size;
+            }
+            displaced = list.set(i, displaced);
+            moved += 1;
+            if i == cycle_start {
+                break;
+            }
+        }
+        cycle_start += 1;
+    }
+}
+
+/// A view of the list that throws `UnsupportedOperationException` from every method that
+/// would change it, including those of its iterators and sublists. Changes made to the
+/// list itself show through.
+pub fn unmodifiable_list<E: Clone>(list: impl Into<UnmodifiableList<E>>) -> UnmodifiableList<E> {
+    list.into()
+}
+
+/// An immutable list of `n` copies of `o`.
+pub fn n_copies<E: Clone>(n: usize, o: E) -> UnmodifiableList<E> {
+    // `CopiesList` throws with the messages `LinkedList` throws with.
+    let elements = VecDeque::from(vec![o; n]);
+    UnmodifiableList {
+        view: ListView::new(Kind::Linked, elements).read_only(),
+    }
+}
+
+/// The list returned by `unmodifiable_list` and `n_copies`.
+pub struct UnmodifiableList<E> {
+    pub(crate) view: ListView<E>,
+}
+
+impl<E> Clone for UnmodifiableList<E> {
+    /// Another view of the same list.
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+        }
+    }
+}
+
+list_impls!(UnmodifiableList);
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::{reverse_order, ArrayList, LinkedList};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn algorithms() {
+        let mut list: ArrayList<i32> = (0..10).collect();
+        shuffle_with(&mut list, &mut Random::with_seed(42));
+        assert_eq!(list.to_string(), "[4, 6, 2, 1, 7, 9, 8, 5, 3, 0]");
+        let mut linked: LinkedList<i32> = (0..10).collect();
+        shuffle_with(&mut linked, &mut Random::with_seed(42));
+        assert_eq!(linked, list);
+
+        sort(&mut list);
+        assert_eq!(binary_search(&list, &7), 7);
+        assert_eq!(binary_search(&list, &10), -11);
+        sort_with(&mut list, reverse_order());
+        assert_eq!(list.to_string(), "[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]");
+        assert_eq!(binary_search_with(&list, &7, reverse_order()), 2);
+
+        rotate(&mut list, -3);
+        assert_eq!(list.to_string(), "[6, 5, 4, 3, 2, 1, 0, 9, 8, 7]");
+        rotate(&mut list, 13);
+        assert_eq!(list.to_string(), "[9, 8, 7, 6, 5, 4, 3, 2, 1, 0]");
+        swap(&mut list, 0, 9);
+        assert_eq!(list.get(0), 0);
+
+        let words: ArrayList<&str> = ["a", "b", "a", "c", "a"].into_iter().collect();
+        assert_eq!(frequency(&words, &"a"), 3);
+        assert_eq!(frequency(&words, &"z"), 0);
+    }
+
+    #[test]
+    fn unmodifiable_lists() {
+        let mut list: ArrayList<i32> = [3, 1, 2].into_iter().collect();
+        let mut view = unmodifiable_list(&list);
+        list.add(4);
+        assert_eq!(view.to_string(), "[3, 1, 2, 4]");
+        assert_eq!(message(|| view.set(5, 1)), "");
+        assert_eq!(message(|| view.remove(&9)), "");
+        assert_eq!(message(|| view.sort(natural_order())), "");
+        assert_eq!(message(|| view.list_iterator().remove()), "");
+        assert_eq!(message(|| view.sub_list(0, 1).clear()), "");
+        assert_eq!(message(|| view.sub_list(0, 5)), "toIndex = 5");
+        assert_eq!(view.get(0), 3);
+
+        let copies = n_copies(3, "x");
+        assert_eq!(copies.to_string(), "[x, x, x]");
+        assert_eq!(message(|| copies.get(3)), "Index: 3, Size: 3");
+    }
+}
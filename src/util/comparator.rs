@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+
+/// `java.util.Comparator`: a total order over `T` that returns a negative number, zero or a
+/// positive number like Java's `compare`.
+///
+/// Any `Fn(&T, &T) -> i32` closure is a comparator. Use `as_fn` to sort with one:
+/// `list.sort_by(comparator.as_fn())`.
+pub trait Comparator<T: ?Sized> {
+    fn compare(&self, a: &T, b: &T) -> i32;
+
+    fn ordering(&self, a: &T, b: &T) -> Ordering {
+        self.compare(a, b).cmp(&0)
+    }
+
+    /// Adapts the comparator to the closures taken by `sort_by`, `max_by` and friends.
+    fn as_fn(&self) -> impl Fn(&T, &T) -> Ordering + '_
+    where
+        Self: Sized,
+    {
+        move |a, b| self.ordering(a, b)
+    }
+
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed(self)
+    }
+
+    /// Breaks ties of this comparator with `other`.
+    fn then_comparing<C: Comparator<T>>(self, other: C) -> ThenComparing<Self, C>
+    where
+        Self: Sized,
+    {
+        ThenComparing(self, other)
+    }
+
+    /// Breaks ties of this comparator by the natural order of the extracted key.
+    fn then_comparing_by<K: Ord, F: Fn(&T) -> K>(
+        self,
+        key_extractor: F,
+    ) -> ThenComparing<Self, Comparing<F>>
+    where
+        Self: Sized,
+    {
+        ThenComparing(self, Comparing(key_extractor))
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> i32> Comparator<T> for F {
+    fn compare(&self, a: &T, b: &T) -> i32 {
+        self(a, b)
+    }
+}
+
+fn to_int(ordering: Ordering) -> i32 {
+    match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+pub struct NaturalOrder<T: ?Sized>(PhantomData<fn(&T, &T)>);
+
+impl<T: ?Sized> Clone for NaturalOrder<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for NaturalOrder<T> {}
+
+impl<T: ?Sized> std::fmt::Debug for NaturalOrder<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NaturalOrder")
+    }
+}
+
+impl<T: Ord + ?Sized> Comparator<T> for NaturalOrder<T> {
+    fn compare(&self, a: &T, b: &T) -> i32 {
+        to_int(a.cmp(b))
+    }
+}
+
+/// Orders values by their `Ord` implementation, Rust's equivalent of `Comparable`.
+pub fn natural_order<T: Ord + ?Sized>() -> NaturalOrder<T> {
+    NaturalOrder(PhantomData)
+}
+
+/// The reverse of `natural_order`.
+pub fn reverse_order<T: Ord + ?Sized>() -> Reversed<NaturalOrder<T>> {
+    Reversed(natural_order())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reversed<C>(C);
+
+impl<T: ?Sized, C: Comparator<T>> Comparator<T> for Reversed<C> {
+    fn compare(&self, a: &T, b: &T) -> i32 {
+        self.0.compare(b, a)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThenComparing<A, B>(A, B);
+
+impl<T: ?Sized, A: Comparator<T>, B: Comparator<T>> Comparator<T> for ThenComparing<A, B> {
+    fn compare(&self, a: &T, b: &T) -> i32 {
+        match self.0.compare(a, b) {
+            0 => self.1.compare(a, b),
+            result => result,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparing<F>(F);
+
+impl<T: ?Sized, K: Ord, F: Fn(&T) -> K> Comparator<T> for Comparing<F> {
+    fn compare(&self, a: &T, b: &T) -> i32 {
+        to_int((self.0)(a).cmp(&(self.0)(b)))
+    }
+}
+
+/// Orders values by the natural order of a key extracted from them.
+pub fn comparing<T: ?Sized, K: Ord, F: Fn(&T) -> K>(key_extractor: F) -> Comparing<F> {
+    Comparing(key_extractor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullsFirst<C>(C);
+
+impl<T, C: Comparator<T>> Comparator<Option<T>> for NullsFirst<C> {
+    fn compare(&self, a: &Option<T>, b: &Option<T>) -> i32 {
+        match (a, b) {
+            (None, None) => 0,
+            (None, Some(_)) => -1,
+            (Some(_), None) => 1,
+            (Some(a), Some(b)) => self.0.compare(a, b),
+        }
+    }
+}
+
+/// Extends `comparator` to `Option`s, ordering `None` (Java's `null`) before everything.
+pub fn nulls_first<T, C: Comparator<T>>(comparator: C) -> NullsFirst<C> {
+    NullsFirst(comparator)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NullsLast<C>(C);
+
+impl<T, C: Comparator<T>> Comparator<Option<T>> for NullsLast<C> {
+    fn compare(&self, a: &Option<T>, b: &Option<T>) -> i32 {
+        match (a, b) {
+            (None, None) => 0,
+            (None, Some(_)) => 1,
+            (Some(_), None) => -1,
+            (Some(a), Some(b)) => self.0.compare(a, b),
+        }
+    }
+}
+
+/// Extends `comparator` to `Option`s, ordering `None` (Java's `null`) after everything.
+pub fn nulls_last<T, C: Comparator<T>>(comparator: C) -> NullsLast<C> {
+    NullsLast(comparator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composition() {
+        let mut words = vec!["pear", "fig", "apple", "kiwi"];
+        words.sort_by(
+            comparing(|word: &&str| word.len())
+                .then_comparing(natural_order())
+                .as_fn(),
+        );
+        assert_eq!(words, ["fig", "kiwi", "pear", "apple"]);
+
+        words.sort_by(reverse_order().as_fn());
+        assert_eq!(words, ["pear", "kiwi", "fig", "apple"]);
+
+        let mut values = vec![Some(2), None, Some(1)];
+        values.sort_by(nulls_last(natural_order()).as_fn());
+        assert_eq!(values, [Some(1), Some(2), None]);
+    }
+}
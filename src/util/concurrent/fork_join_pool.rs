@@ -0,0 +1,420 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use crate::errors::util::concurrent::RejectedExecutionException;
+use crate::errors::{IllegalArgumentException, Throwable};
+
+/// The most threads a pool can have, like the JDK's `MAX_CAP`.
+const MAX_CAP: usize = 0x7fff;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+static POOL_IDS: AtomicUsize = AtomicUsize::new(1);
+static COMMON_POOL: OnceLock<ForkJoinPool> = OnceLock::new();
+
+thread_local! {
+    /// The pool the current thread works for, and the index of its queue there.
+    static WORKER: RefCell<Option<(Arc<Pool>, usize)>> = const { RefCell::new(None) };
+}
+
+fn current_worker() -> Option<(Arc<Pool>, usize)> {
+    WORKER.with(|worker| worker.borrow().clone())
+}
+
+/// The queues and threads of a pool, which the threads share with its handle.
+struct Pool {
+    /// Each worker's tasks. It pushes and pops its own at the back, and others steal them
+    /// from the front, the oldest and so usually largest first.
+    queues: Vec<Mutex<VecDeque<Job>>>,
+    /// The tasks submitted from outside the pool, taken in order.
+    submissions: Mutex<VecDeque<Job>>,
+    /// Counts the tasks pushed, so that a worker about to sleep notices one pushed after it
+    /// last looked.
+    pushed: Mutex<u64>,
+    work: Condvar,
+    shutdown: AtomicBool,
+}
+
+impl Pool {
+    fn push(&self, worker: Option<usize>, job: Job) {
+        match worker {
+            Some(index) => self.queues[index].lock().unwrap().push_back(job),
+            None => self.submissions.lock().unwrap().push_back(job),
+        }
+        *self.pushed.lock().unwrap() += 1;
+        self.work.notify_one();
+    }
+
+    /// The worker's newest task, or else the oldest submission, or else the oldest task
+    /// stolen from another worker.
+    fn find_job(&self, index: usize) -> Option<Job> {
+        if let Some(job) = self.queues[index].lock().unwrap().pop_back() {
+            return Some(job);
+        }
+        if let Some(job) = self.submissions.lock().unwrap().pop_front() {
+            return Some(job);
+        }
+        let n = self.queues.len();
+        (1..n).find_map(|k| self.queues[(index + k) % n].lock().unwrap().pop_front())
+    }
+
+    fn run_worker(self: Arc<Self>, index: usize) {
+        WORKER.with(|worker| *worker.borrow_mut() = Some((Arc::clone(&self), index)));
+        loop {
+            let pushed = *self.pushed.lock().unwrap();
+            if let Some(job) = self.find_job(index) {
+                job();
+                continue;
+            }
+            if self.shutdown.load(Ordering::Acquire) {
+                break;
+            }
+            let guard = self.pushed.lock().unwrap();
+            if *guard == pushed {
+                drop(self.work.wait(guard).unwrap());
+            }
+        }
+        WORKER.with(|worker| worker.borrow_mut().take());
+    }
+}
+
+/// `java.util.concurrent.ForkJoinPool`: a fixed set of threads that run `RecursiveTask`s
+/// and `RecursiveAction`s and the tasks they fork, each thread stealing work from the
+/// others when it runs out of its own.
+///
+/// Dropping a pool shuts it down: its threads finish the tasks already submitted, then
+/// exit. The `common_pool` is never shut down.
+pub struct ForkJoinPool {
+    pool: Arc<Pool>,
+}
+
+impl ForkJoinPool {
+    /// A pool with a thread for each available processor.
+    pub fn new() -> Self {
+        Self::with_parallelism(available_processors())
+    }
+
+    /// A pool of `parallelism` threads. Throws `IllegalArgumentException` unless it is
+    /// between 1 and 32767.
+    pub fn with_parallelism(parallelism: usize) -> Self {
+        if parallelism == 0 || parallelism > MAX_CAP {
+            IllegalArgumentException::new(String::new(), None).throw()
+        }
+        let id = POOL_IDS.fetch_add(1, Ordering::Relaxed);
+        Self::start(parallelism, |i| format!("ForkJoinPool-{id}-worker-{i}"))
+    }
+
+    fn start(parallelism: usize, name: impl Fn(usize) -> String) -> Self {
+        let pool = Arc::new(Pool {
+            queues: (0..parallelism).map(|_| Mutex::default()).collect(),
+            submissions: Mutex::default(),
+            pushed: Mutex::new(0),
+            work: Condvar::new(),
+            shutdown: AtomicBool::new(false),
+        });
+        for index in 0..parallelism {
+            let worker = Arc::clone(&pool);
+            thread::Builder::new()
+                .name(name(index + 1))
+                .spawn(move || worker.run_worker(index))
+                .unwrap();
+        }
+        Self { pool }
+    }
+
+    /// The pool that tasks forked outside any pool, and parallel streams, run in. Like the
+    /// JDK's, it has a thread for each available processor but one, the thread that waits
+    /// for a task's result helping to run it, and at least one.
+    pub fn common_pool() -> &'static ForkJoinPool {
+        COMMON_POOL.get_or_init(|| {
+            Self::start(Self::get_common_pool_parallelism(), |i| {
+                format!("ForkJoinPool.commonPool-worker-{i}")
+            })
+        })
+    }
+
+    pub fn get_common_pool_parallelism() -> usize {
+        (available_processors() - 1).max(1)
+    }
+
+    /// The number of threads in the pool.
+    pub fn get_parallelism(&self) -> usize {
+        self.pool.queues.len()
+    }
+
+    /// Runs the task in the pool and waits for its result, rethrowing what it throws.
+    pub fn invoke<T: RecursiveTask>(&self, task: T) -> T::Output {
+        self.push(move || task.compute()).join()
+    }
+
+    /// Like `invoke`, for an action.
+    pub fn invoke_action<A: RecursiveAction>(&self, action: A) {
+        self.push(move || action.compute()).join()
+    }
+
+    /// Starts the task in the pool, returning a handle that `join` waits on.
+    pub fn submit<T: RecursiveTask>(&self, task: T) -> ForkJoinTask<T::Output> {
+        self.push(move || task.compute())
+    }
+
+    fn push<V: Send + 'static>(&self, f: impl FnOnce() -> V + Send + 'static) -> ForkJoinTask<V> {
+        if self.is_shutdown() {
+            RejectedExecutionException::new(String::new()).throw()
+        }
+        let mut task = ForkJoinTask::new(f);
+        let worker = current_worker()
+            .filter(|(pool, _)| Arc::ptr_eq(pool, &self.pool))
+            .map(|(_, index)| index);
+        task.submitted = worker.is_none();
+        self.pool.push(worker, task.job());
+        task
+    }
+
+    /// Stops the pool taking new work, so that submitting a task throws
+    /// `RejectedExecutionException`. Its threads exit once they have run the tasks already
+    /// queued.
+    pub fn shutdown(&self) {
+        self.pool.shutdown.store(true, Ordering::Release);
+        let _pushed = self.pool.pushed.lock().unwrap();
+        self.pool.work.notify_all();
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.pool.shutdown.load(Ordering::Acquire)
+    }
+}
+
+impl Default for ForkJoinPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ForkJoinPool {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+fn available_processors() -> usize {
+    thread::available_parallelism().map_or(1, usize::from)
+}
+
+/// The state a task shares with the queue entry that runs it.
+struct TaskState<V> {
+    /// The computation, until a thread takes it to run.
+    job: Mutex<Option<Box<dyn FnOnce() -> V + Send>>>,
+    /// What the computation returned or threw, until `join` takes it.
+    result: Mutex<Option<thread::Result<V>>>,
+    done: Condvar,
+}
+
+impl<V> TaskState<V> {
+    /// Runs the computation, unless another thread has taken it already.
+    fn run(&self) {
+        let job = self.job.lock().unwrap().take();
+        if let Some(job) = job {
+            let result = catch_unwind(AssertUnwindSafe(job));
+            *self.result.lock().unwrap() = Some(result);
+            self.done.notify_all();
+        }
+    }
+}
+
+/// `java.util.concurrent.ForkJoinTask`: a forked or submitted task whose result `join`
+/// waits for.
+pub struct ForkJoinTask<V> {
+    state: Arc<TaskState<V>>,
+    /// Whether the task was submitted to a pool from outside it, and so must run there
+    /// rather than in the thread that joins it.
+    submitted: bool,
+}
+
+impl<V: Send + 'static> ForkJoinTask<V> {
+    fn new(f: impl FnOnce() -> V + Send + 'static) -> Self {
+        Self {
+            state: Arc::new(TaskState {
+                job: Mutex::new(Some(Box::new(f))),
+                result: Mutex::new(None),
+                done: Condvar::new(),
+            }),
+            submitted: false,
+        }
+    }
+
+    /// The queue entry that runs the task, which does nothing if `join` ran it first.
+    fn job(&self) -> Job {
+        let state = Arc::clone(&self.state);
+        Box::new(move || state.run())
+    }
+
+    /// Queues the computation in the current thread's pool, or the common pool outside
+    /// any.
+    fn fork(f: impl FnOnce() -> V + Send + 'static) -> Self {
+        let task = Self::new(f);
+        match current_worker() {
+            Some((pool, index)) => pool.push(Some(index), task.job()),
+            None => ForkJoinPool::common_pool().pool.push(None, task.job()),
+        }
+        task
+    }
+
+    /// The task's result, rethrowing what it threw. If no thread has started a forked task,
+    /// it runs in this one; while another runs it, a thread of a pool runs other tasks.
+    pub fn join(self) -> V {
+        let worker = current_worker();
+        loop {
+            if let Some(result) = self.state.result.lock().unwrap().take() {
+                return result.unwrap_or_else(|error| resume_unwind(error));
+            }
+            if !self.submitted {
+                self.state.run();
+            }
+            let helped = worker
+                .as_ref()
+                .is_some_and(|(pool, index)| pool.find_job(*index).map(|job| job()).is_some());
+            if !helped {
+                let result = self.state.result.lock().unwrap();
+                if result.is_none() {
+                    let timeout = Duration::from_millis(if worker.is_some() { 1 } else { 100 });
+                    drop(self.state.done.wait_timeout(result, timeout).unwrap());
+                }
+            }
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.state.result.lock().unwrap().is_some()
+    }
+}
+
+/// `java.util.concurrent.RecursiveTask`: a computation that returns a result, usually by
+/// forking smaller tasks and joining their results.
+pub trait RecursiveTask: Send + Sized + 'static {
+    type Output: Send + 'static;
+
+    fn compute(self) -> Self::Output;
+
+    /// Queues the task to run in the current thread's pool, or the common pool outside
+    /// any, returning a handle to `join` for its result.
+    fn fork(self) -> ForkJoinTask<Self::Output> {
+        ForkJoinTask::fork(move || self.compute())
+    }
+
+    /// Computes the task in the current thread.
+    fn invoke(self) -> Self::Output {
+        self.compute()
+    }
+}
+
+/// `java.util.concurrent.RecursiveAction`: a computation run for its effects, usually by
+/// forking smaller actions.
+pub trait RecursiveAction: Send + Sized + 'static {
+    fn compute(self);
+
+    /// Queues the action to run in the current thread's pool, or the common pool outside
+    /// any, returning a handle to `join` for it to finish.
+    fn fork(self) -> ForkJoinTask<()> {
+        ForkJoinTask::fork(move || self.compute())
+    }
+
+    /// Computes the action in the current thread.
+    fn invoke(self) {
+        self.compute()
+    }
+}
+
+/// Like Java's `ForkJoinTask.invokeAll(t1, t2)`: forks `b`, computes `a` and joins `b`,
+/// rethrowing what either threw once both have finished.
+pub fn invoke_all<A: RecursiveAction, B: RecursiveAction>(a: A, b: B) {
+    let b = b.fork();
+    let a = catch_unwind(AssertUnwindSafe(|| a.compute()));
+    let b = catch_unwind(AssertUnwindSafe(|| b.join()));
+    if let Err(error) = a.and(b) {
+        resume_unwind(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicI64;
+
+    use super::*;
+    use crate::errors::IllegalStateException;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    struct Sum(Arc<[i64]>, usize, usize);
+
+    impl RecursiveTask for Sum {
+        type Output = i64;
+
+        fn compute(self) -> i64 {
+            let Sum(values, lo, hi) = self;
+            if hi - lo <= 100 {
+                if values[lo..hi].contains(&-1) {
+                    IllegalStateException::new(format!("-1 at {lo}"), None).throw()
+                }
+                return values[lo..hi].iter().sum();
+            }
+            let mid = (lo + hi) / 2;
+            let left = Sum(Arc::clone(&values), lo, mid).fork();
+            Sum(values, mid, hi).compute() + left.join()
+        }
+    }
+
+    struct Count(Arc<AtomicI64>, i64);
+
+    impl RecursiveAction for Count {
+        fn compute(self) {
+            let Count(total, n) = self;
+            if n == 1 {
+                total.fetch_add(1, Ordering::Relaxed);
+            } else {
+                invoke_all(Count(Arc::clone(&total), n / 2), Count(total, n - n / 2));
+            }
+        }
+    }
+
+    #[test]
+    fn forks_and_joins() {
+        let pool = ForkJoinPool::with_parallelism(4);
+        assert_eq!(pool.get_parallelism(), 4);
+        let values: Arc<[i64]> = (1..=100_000).collect();
+        assert_eq!(
+            pool.invoke(Sum(Arc::clone(&values), 0, 100_000)),
+            5_000_050_000
+        );
+        assert_eq!(Sum(values, 0, 1_000).fork().join(), 500_500);
+
+        let total = Arc::new(AtomicI64::new(0));
+        pool.invoke_action(Count(Arc::clone(&total), 10_000));
+        assert_eq!(total.load(Ordering::Relaxed), 10_000);
+
+        let task = pool.submit(Sum((0..1_000).collect(), 0, 1_000));
+        assert_eq!(task.join(), 499_500);
+        assert!(ForkJoinPool::get_common_pool_parallelism() >= 1);
+    }
+
+    #[test]
+    fn rethrows_and_rejects() {
+        let pool = ForkJoinPool::with_parallelism(2);
+        let values: Arc<[i64]> = (0..1_000).map(|i| if i == 567 { -1 } else { i }).collect();
+        assert_eq!(message(|| pool.invoke(Sum(values, 0, 1_000))), "-1 at 562");
+
+        pool.shutdown();
+        assert!(pool.is_shutdown());
+        assert_eq!(message(|| pool.invoke(Sum((0..10).collect(), 0, 10))), "");
+        assert_eq!(message(|| ForkJoinPool::with_parallelism(0)), "");
+    }
+}
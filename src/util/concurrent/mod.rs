@@ -0,0 +1,85 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use crate::errors::{Throwable, UnsupportedOperationException};
+use crate::util::random::{current_time_millis, nano_time, RandomGenerator};
+
+mod fork_join_pool;
+
+pub use fork_join_pool::{invoke_all, ForkJoinPool, ForkJoinTask, RecursiveAction, RecursiveTask};
+
+const GAMMA: i64 = 0x9e37_79b9_7f4a_7c15_u64 as i64;
+const SEEDER_INCREMENT: i64 = 0xbb67_ae85_84ca_a73b_u64 as i64;
+
+static SEEDER: AtomicI64 = AtomicI64::new(0);
+static THREAD_IDS: AtomicI64 = AtomicI64::new(1);
+
+thread_local! {
+    static SEED: Cell<Option<(i64, i64)>> = const { Cell::new(None) };
+}
+
+fn mix_murmur64(mut z: i64) -> i64 {
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xff51_afd7_ed55_8ccd_u64 as i64);
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xc4ce_b9fe_1a85_ec53_u64 as i64);
+    z ^ ((z as u64) >> 33) as i64
+}
+
+fn mix_murmur32(mut z: i64) -> i32 {
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xff51_afd7_ed55_8ccd_u64 as i64);
+    (((z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xc4ce_b9fe_1a85_ec53_u64 as i64) as u64) >> 32)
+        as i32
+}
+
+/// `java.util.concurrent.ThreadLocalRandom`: a generator whose state lives in the current
+/// thread, so it can be used from anywhere without locking or passing an instance around.
+///
+/// Like the JDK, it cannot be seeded.
+#[derive(Debug, Clone, Copy)]
+pub struct ThreadLocalRandom {
+    _private: (),
+}
+
+impl ThreadLocalRandom {
+    /// Returns the current thread's generator. Its state is created on first use in each
+    /// thread.
+    pub fn current() -> Self {
+        Self { _private: () }
+    }
+
+    /// Always throws `UnsupportedOperationException`: the JDK does not allow reseeding a
+    /// `ThreadLocalRandom`.
+    pub fn set_seed(&mut self, _seed: i64) {
+        UnsupportedOperationException::new(String::new(), None).throw()
+    }
+
+    fn next_seed(&mut self) -> i64 {
+        SEED.with(|state| {
+            let (seed, increment) = state.get().unwrap_or_else(local_init);
+            let seed = seed.wrapping_add(increment);
+            state.set(Some((seed, increment)));
+            seed
+        })
+    }
+}
+
+fn local_init() -> (i64, i64) {
+    let _ = SEEDER.compare_exchange(
+        0,
+        mix_murmur64(current_time_millis()) ^ mix_murmur64(nano_time()),
+        Ordering::Relaxed,
+        Ordering::Relaxed,
+    );
+    let seed = mix_murmur64(SEEDER.fetch_add(SEEDER_INCREMENT, Ordering::Relaxed));
+    let increment = (THREAD_IDS.fetch_add(1, Ordering::Relaxed) << 1).wrapping_add(GAMMA);
+    (seed, increment)
+}
+
+impl RandomGenerator for ThreadLocalRandom {
+    fn next_long(&mut self) -> i64 {
+        mix_murmur64(self.next_seed())
+    }
+
+    fn next_int(&mut self) -> i32 {
+        mix_murmur32(self.next_seed())
+    }
+}
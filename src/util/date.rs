@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::text::SimpleDateFormat;
+use crate::util::random::current_time_millis;
+use crate::util::{Locale, TimeZone};
+
+/// `java.util.Date`: an instant in time, in milliseconds since the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    time: i64,
+}
+
+impl Date {
+    /// The current time.
+    pub fn new() -> Self {
+        Self::with_time(current_time_millis())
+    }
+
+    pub fn with_time(time: i64) -> Self {
+        Self { time }
+    }
+
+    pub fn get_time(&self) -> i64 {
+        self.time
+    }
+
+    pub fn set_time(&mut self, time: i64) {
+        self.time = time;
+    }
+
+    pub fn before(&self, when: &Date) -> bool {
+        self.time < when.time
+    }
+
+    pub fn after(&self, when: &Date) -> bool {
+        self.time > when.time
+    }
+
+    pub fn compare_to(&self, another: &Date) -> i32 {
+        self.time.cmp(&another.time) as i32
+    }
+
+    pub fn hash_code(&self) -> i32 {
+        (self.time ^ (self.time >> 32)) as i32
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like `Date.toString`, the time in the default time zone as `EEE MMM dd HH:mm:ss zzz yyyy`
+/// in US English, e.g. `Thu Jan 01 00:00:00 UTC 1970`.
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut format = SimpleDateFormat::with_locale("EEE MMM dd HH:mm:ss zzz yyyy", &Locale::US);
+        format.set_time_zone(TimeZone::get_default());
+        f.write_str(&format.format(self))
+    }
+}
@@ -0,0 +1,243 @@
+//! The dual-pivot quicksort `Arrays.sort` uses for primitives: insertion sort for short
+//! ranges, otherwise partitioning around two pivots picked from five samples.
+//!
+//! Sorting primitives isn't observable the way sorting objects is, so this is the
+//! recursive core only, without the JDK's merge of presorted runs. The values must be
+//! totally ordered by `<`, so NaNs are for the caller to deal with.
+
+/// Below this length a range is sorted by insertion sort.
+const INSERTION_SORT_THRESHOLD: isize = 47;
+
+pub(crate) fn sort<T: Copy + PartialOrd>(a: &mut [T]) {
+    if a.len() > 1 {
+        sort_range(a, 0, a.len() as isize - 1, true);
+    }
+}
+
+/// Sorts `a[left..=right]`. Unless the range is `leftmost`, the element before it is no
+/// greater than any in it, which the pair insertion sort uses as a sentinel.
+fn sort_range<T: Copy + PartialOrd>(
+    a: &mut [T],
+    mut left: isize,
+    mut right: isize,
+    leftmost: bool,
+) {
+    macro_rules! a {
+        ($i:expr) => {
+            a[($i) as usize]
+        };
+    }
+    let length = right - left + 1;
+
+    if length < INSERTION_SORT_THRESHOLD {
+        if leftmost {
+            let mut i = left;
+            while i < right {
+                let ai = a![i + 1];
+                let mut j = i;
+                while ai < a![j] {
+                    a![j + 1] = a![j];
+                    if j == left {
+                        j -= 1;
+                        break;
+                    }
+                    j -= 1;
+                }
+                a![j + 1] = ai;
+                i += 1;
+            }
+        } else {
+            // Skip the longest ascending prefix.
+            loop {
+                if left >= right {
+                    return;
+                }
+                left += 1;
+                if a![left] < a![left - 1] {
+                    break;
+                }
+            }
+            // Insert two elements at a time, the greater first.
+            let mut k = left;
+            left += 1;
+            while left <= right {
+                let (mut a1, mut a2) = (a![k], a![left]);
+                if a1 < a2 {
+                    a2 = a1;
+                    a1 = a![left];
+                }
+                k -= 1;
+                while a1 < a![k] {
+                    a![k + 2] = a![k];
+                    k -= 1;
+                }
+                k += 1;
+                a![k + 1] = a1;
+                k -= 1;
+                while a2 < a![k] {
+                    a![k + 1] = a![k];
+                    k -= 1;
+                }
+                a![k + 1] = a2;
+                left += 1;
+                k = left;
+                left += 1;
+            }
+            let last = a![right];
+            right -= 1;
+            while last < a![right] {
+                a![right + 1] = a![right];
+                right -= 1;
+            }
+            a![right + 1] = last;
+        }
+        return;
+    }
+
+    // Five evenly spaced samples around the middle, sorted in place.
+    let seventh = (length >> 3) + (length >> 6) + 1;
+    let e3 = (left + right) >> 1;
+    let e2 = e3 - seventh;
+    let e1 = e2 - seventh;
+    let e4 = e3 + seventh;
+    let e5 = e4 + seventh;
+    for e in [e2, e3, e4, e5] {
+        let t = a![e];
+        let mut j = e;
+        while j > e1 && t < a![j - seventh] {
+            a![j] = a![j - seventh];
+            j -= seventh;
+        }
+        a![j] = t;
+    }
+
+    let mut less = left;
+    let mut great = right;
+
+    if a![e1] != a![e2] && a![e2] != a![e3] && a![e3] != a![e4] && a![e4] != a![e5] {
+        let pivot1 = a![e2];
+        let pivot2 = a![e4];
+        // The pivots go to the ends, to be swapped into place after partitioning.
+        a![e2] = a![left];
+        a![e4] = a![right];
+        less += 1;
+        while a![less] < pivot1 {
+            less += 1;
+        }
+        great -= 1;
+        while a![great] > pivot2 {
+            great -= 1;
+        }
+
+        // Partition into less than pivot1, between them, and greater than pivot2.
+        let mut k = less;
+        'outer: while k <= great {
+            let ak = a![k];
+            if ak < pivot1 {
+                a![k] = a![less];
+                a![less] = ak;
+                less += 1;
+            } else if ak > pivot2 {
+                while a![great] > pivot2 {
+                    if great == k {
+                        great -= 1;
+                        break 'outer;
+                    }
+                    great -= 1;
+                }
+                if a![great] < pivot1 {
+                    a![k] = a![less];
+                    a![less] = a![great];
+                    less += 1;
+                } else {
+                    a![k] = a![great];
+                }
+                a![great] = ak;
+                great -= 1;
+            }
+            k += 1;
+        }
+
+        a![left] = a![less - 1];
+        a![less - 1] = pivot1;
+        a![right] = a![great + 1];
+        a![great + 1] = pivot2;
+
+        sort_range(a, left, less - 2, leftmost);
+        sort_range(a, great + 2, right, false);
+
+        // If the middle part is large, move the elements equal to the pivots out of it.
+        if less < e1 && e5 < great {
+            while a![less] == pivot1 {
+                less += 1;
+            }
+            while a![great] == pivot2 {
+                great -= 1;
+            }
+            let mut k = less;
+            'outer: while k <= great {
+                let ak = a![k];
+                if ak == pivot1 {
+                    a![k] = a![less];
+                    a![less] = ak;
+                    less += 1;
+                } else if ak == pivot2 {
+                    while a![great] == pivot2 {
+                        if great == k {
+                            great -= 1;
+                            break 'outer;
+                        }
+                        great -= 1;
+                    }
+                    if a![great] == pivot1 {
+                        a![k] = a![less];
+                        // Not `pivot1`, which may be a zero of the other sign.
+                        a![less] = a![great];
+                        less += 1;
+                    } else {
+                        a![k] = a![great];
+                    }
+                    a![great] = ak;
+                    great -= 1;
+                }
+                k += 1;
+            }
+        }
+
+        sort_range(a, less, great, false);
+    } else {
+        // With repeated samples, partition around one pivot into less, equal and greater.
+        let pivot = a![e3];
+        let mut k = less;
+        while k <= great {
+            if a![k] == pivot {
+                k += 1;
+                continue;
+            }
+            let ak = a![k];
+            if ak < pivot {
+                a![k] = a![less];
+                a![less] = ak;
+                less += 1;
+            } else {
+                while a![great] > pivot {
+                    great -= 1;
+                }
+                if a![great] < pivot {
+                    a![k] = a![less];
+                    a![less] = a![great];
+                    less += 1;
+                } else {
+                    // Not `pivot`, which may be a zero of the other sign.
+                    a![k] = a![great];
+                }
+                a![great] = ak;
+                great -= 1;
+            }
+            k += 1;
+        }
+
+        sort_range(a, left, less - 1, leftmost);
+        sort_range(a, great + 1, right, false);
+    }
+}
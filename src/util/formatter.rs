@@ -0,0 +1,1602 @@
+use std::fmt;
+
+use crate::errors::util::{
+    DuplicateFormatFlagsException, FormatFlagsConversionMismatchException,
+    IllegalFormatArgumentIndexException, IllegalFormatCodePointException,
+    IllegalFormatConversionException, IllegalFormatFlagsException, IllegalFormatPrecisionException,
+    IllegalFormatWidthException, MissingFormatArgumentException, MissingFormatWidthException,
+    UnknownFormatConversionException,
+};
+use crate::errors::Throwable;
+use crate::floating_decimal::{self, Form};
+use crate::text::{zone_display_name, DateFormatSymbols, DecimalFormat, DecimalFormatSymbols};
+use crate::time::{Instant, LocalDate, LocalDateTime, LocalTime, ZonedDateTime};
+use crate::util::{Calendar, Date, Locale, TimeZone};
+use crate::{HashCode, JavaStringOwned};
+
+const LEFT_JUSTIFY: u32 = 1 << 0;
+const UPPERCASE: u32 = 1 << 1;
+const ALTERNATE: u32 = 1 << 2;
+const PLUS: u32 = 1 << 3;
+const LEADING_SPACE: u32 = 1 << 4;
+const ZERO_PAD: u32 = 1 << 5;
+const GROUP: u32 = 1 << 6;
+const PARENTHESES: u32 = 1 << 7;
+const PREVIOUS: u32 = 1 << 8;
+
+/// The flags in the order Java lists them in exception messages.
+const FLAG_CHARS: [(u32, char); 9] = [
+    (LEFT_JUSTIFY, '-'),
+    (UPPERCASE, '^'),
+    (ALTERNATE, '#'),
+    (PLUS, '+'),
+    (LEADING_SPACE, ' '),
+    (ZERO_PAD, '0'),
+    (GROUP, ','),
+    (PARENTHESES, '('),
+    (PREVIOUS, '<'),
+];
+
+/// An argument of a `Formatter`, standing in for Java's boxed `Object`s. The integer
+/// variants are Java's `Byte`, `Short`, `Integer` and `Long`, which format differently in
+/// hexadecimal and octal; of them only a `Long` is taken as milliseconds since the epoch by
+/// the date/time conversions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatArgument {
+    /// Java's `null`, formatted as `null` by most conversions.
+    Null,
+    Boolean(bool),
+    Char(char),
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Date(Date),
+    Calendar(Calendar),
+    Instant(Instant),
+    LocalDate(LocalDate),
+    LocalTime(LocalTime),
+    LocalDateTime(LocalDateTime),
+    ZonedDateTime(ZonedDateTime),
+}
+
+macro_rules! format_argument_from {
+    ($($type: ty => $variant: ident),+) => {
+        $(
+            impl From<$type> for FormatArgument {
+                fn from(value: $type) -> Self {
+                    FormatArgument::$variant(value)
+                }
+            }
+        )+
+    };
+}
+
+format_argument_from!(
+    bool => Boolean,
+    char => Char,
+    i8 => Byte,
+    i16 => Short,
+    i32 => Int,
+    i64 => Long,
+    f32 => Float,
+    f64 => Double,
+    String => String,
+    Date => Date,
+    Calendar => Calendar,
+    Instant => Instant,
+    LocalDate => LocalDate,
+    LocalTime => LocalTime,
+    LocalDateTime => LocalDateTime,
+    ZonedDateTime => ZonedDateTime
+);
+
+impl From<&str> for FormatArgument {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl<T: Into<FormatArgument>> From<Option<T>> for FormatArgument {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+impl FormatArgument {
+    /// The name of the Java class of the argument, for exception messages.
+    fn class_name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean(_) => "java.lang.Boolean",
+            Self::Char(_) => "java.lang.Character",
+            Self::Byte(_) => "java.lang.Byte",
+            Self::Short(_) => "java.lang.Short",
+            Self::Int(_) => "java.lang.Integer",
+            Self::Long(_) => "java.lang.Long",
+            Self::Float(_) => "java.lang.Float",
+            Self::Double(_) => "java.lang.Double",
+            Self::String(_) => "java.lang.String",
+            Self::Date(_) => "java.util.Date",
+            Self::Calendar(_) => "java.util.GregorianCalendar",
+            Self::Instant(_) => "java.time.Instant",
+            Self::LocalDate(_) => "java.time.LocalDate",
+            Self::LocalTime(_) => "java.time.LocalTime",
+            Self::LocalDateTime(_) => "java.time.LocalDateTime",
+            Self::ZonedDateTime(_) => "java.time.ZonedDateTime",
+        }
+    }
+
+    /// Java's `toString` of the argument.
+    pub(crate) fn to_java_string(&self) -> String {
+        match self {
+            Self::Null => "null".to_string(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Char(value) => value.to_string(),
+            Self::Byte(value) => value.to_string(),
+            Self::Short(value) => value.to_string(),
+            Self::Int(value) => value.to_string(),
+            Self::Long(value) => value.to_string(),
+            Self::Float(value) => floating_decimal::float_to_string(*value),
+            Self::Double(value) => floating_decimal::double_to_string(*value),
+            Self::String(value) => value.clone(),
+            Self::Date(value) => value.to_string(),
+            Self::Calendar(value) => value.to_string(),
+            Self::Instant(value) => value.to_string(),
+            Self::LocalDate(value) => value.to_string(),
+            Self::LocalTime(value) => value.to_string(),
+            Self::LocalDateTime(value) => value.to_string(),
+            Self::ZonedDateTime(value) => value.to_string(),
+        }
+    }
+
+    /// Java's `hashCode` of a non-null argument.
+    fn hash_code(&self) -> i32 {
+        match self {
+            Self::Null => 0,
+            Self::Boolean(value) => value.hash_code(),
+            Self::Char(value) => value.hash_code(),
+            Self::Byte(value) => value.hash_code(),
+            Self::Short(value) => value.hash_code(),
+            Self::Int(value) => value.hash_code(),
+            Self::Long(value) => value.hash_code(),
+            Self::Float(value) => value.hash_code(),
+            Self::Double(value) => value.hash_code(),
+            Self::String(value) => HashCode::hash_code(value),
+            Self::Date(value) => value.hash_code(),
+            Self::Calendar(value) => value.hash_code(),
+            Self::Instant(value) => value.hash_code(),
+            Self::LocalDate(value) => value.hash_code(),
+            Self::LocalTime(value) => value.hash_code(),
+            Self::LocalDateTime(value) => value.hash_code(),
+            Self::ZonedDateTime(value) => value.hash_code(),
+        }
+    }
+}
+
+/// `java.util.Formatter`: printf-style formatting into a string, with Java's conversions,
+/// flags, argument indexes and exceptions.
+///
+/// The format specifiers are `%[argument_index$][flags][width][.precision]conversion`,
+/// where the conversion is one of the general `b`, `h` and `s`, the character `c`, the
+/// integral `d`, `o` and `x`, the floating-point `e`, `f`, `g` and `a`, the date/time
+/// prefix `t` followed by one of its own conversions, `%` or `n`. An upper-case conversion
+/// upper-cases its result. Like Java, an invalid format or an argument of the wrong type
+/// throws one of the `IllegalFormatException`s in `errors::util`, leaving what was
+/// formatted before it in the output.
+///
+/// Numbers are localized with the digits, separators and grouping size of the locale.
+#[derive(Debug, Clone)]
+pub struct Formatter {
+    out: String,
+    locale: Locale,
+}
+
+impl Formatter {
+    /// A formatter in the default locale.
+    pub fn new() -> Self {
+        Self::with_locale(&Locale::get_default())
+    }
+
+    pub fn with_locale(locale: &Locale) -> Self {
+        Self {
+            out: String::new(),
+            locale: locale.clone(),
+        }
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// The text formatted so far.
+    pub fn out(&self) -> &str {
+        &self.out
+    }
+
+    /// Formats the arguments by the format string in the formatter's locale, appending the
+    /// result to the output.
+    pub fn format(&mut self, format: &str, args: &[FormatArgument]) -> &mut Self {
+        let locale = self.locale.clone();
+        self.format_with_locale(&locale, format, args)
+    }
+
+    /// Formats the arguments in another locale.
+    pub fn format_with_locale(
+        &mut self,
+        locale: &Locale,
+        format: &str,
+        args: &[FormatArgument],
+    ) -> &mut Self {
+        // The last argument used, and the last one used by an ordinary index.
+        let mut last: Option<usize> = None;
+        let mut last_ordinary: Option<usize> = None;
+        for part in parse(format) {
+            let spec = match part {
+                FormatString::Fixed(text) => {
+                    self.out.push_str(text);
+                    continue;
+                }
+                FormatString::Specifier(spec) => spec,
+            };
+            let arg = match spec.index {
+                Index::Text => None,
+                Index::Previous => last,
+                Index::Ordinary => {
+                    let next = last_ordinary.map_or(0, |index| index + 1);
+                    last_ordinary = Some(next);
+                    last = Some(next);
+                    last
+                }
+                Index::Explicit(index) => {
+                    last = Some(index as usize - 1);
+                    last
+                }
+            };
+            let arg = match (spec.index, arg) {
+                (Index::Text, _) => None,
+                (_, Some(index)) if index < args.len() => Some(&args[index]),
+                _ => MissingFormatArgumentException::new(format!("Format specifier '{spec}'"))
+                    .throw(),
+            };
+            spec.print(&mut self.out, arg, locale);
+        }
+        self
+    }
+}
+
+impl Default for Formatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Formatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.out)
+    }
+}
+
+enum FormatString<'a> {
+    Fixed(&'a str),
+    Specifier(FormatSpecifier),
+}
+
+/// Which argument a specifier formats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Index {
+    /// `%%` and `%n`, which take none.
+    Text,
+    /// `<`: the one the previous specifier formatted.
+    Previous,
+    /// The one after the last one an ordinary specifier formatted.
+    Ordinary,
+    /// `n$`, from 1.
+    Explicit(i32),
+}
+
+#[derive(Debug, Clone)]
+struct FormatSpecifier {
+    index: Index,
+    flags: u32,
+    width: i32,
+    precision: i32,
+    date_time: bool,
+    /// The conversion, in lower case unless it is a date/time one.
+    conversion: char,
+}
+
+/// Splits a format string into fixed text and specifiers, throwing the exception of the
+/// first invalid specifier before anything is formatted.
+fn parse(format: &str) -> Vec<FormatString<'_>> {
+    let mut parts = Vec::new();
+    let mut i = 0;
+    while i < format.len() {
+        let Some(n) = format[i..].find('%').map(|n| i + n) else {
+            parts.push(FormatString::Fixed(&format[i..]));
+            break;
+        };
+        if i != n {
+            parts.push(FormatString::Fixed(&format[i..n]));
+        }
+        i = n + 1;
+        let Some(c) = format[i..].chars().next() else {
+            UnknownFormatConversionException::new("Conversion = '%'".to_string()).throw()
+        };
+        if is_valid_conversion(c) {
+            parts.push(FormatString::Specifier(FormatSpecifier::of(c)));
+            i += 1;
+        } else {
+            match match_specifier(format.as_bytes(), i) {
+                Some((groups, end)) => {
+                    parts.push(FormatString::Specifier(FormatSpecifier::parse(
+                        format, groups,
+                    )));
+                    i = end;
+                }
+                None => unknown_conversion(&c.to_string()),
+            }
+        }
+    }
+    parts
+}
+
+/// The byte ranges of the groups of Java's specifier regex
+/// `%(\d+\$)?([-#+ 0,(\<]*)?(\d+)?(\.\d+)?([tT])?([a-zA-Z%])`, matched after the `%` at
+/// `start - 1`, with the end of the match.
+type Groups = [Option<(usize, usize)>; 6];
+
+fn match_specifier(s: &[u8], start: usize) -> Option<(Groups, usize)> {
+    let digits_from = |i: usize| i + s[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    // The argument index only matches if its digits are followed by `$`.
+    let index_end = digits_from(start);
+    if index_end > start && s.get(index_end) == Some(&b'$') {
+        if let Some(matched) = match_after_index(s, start, Some((start, index_end + 1))) {
+            return Some(matched);
+        }
+    }
+    match_after_index(s, start, None)
+}
+
+fn match_after_index(
+    s: &[u8],
+    start: usize,
+    index: Option<(usize, usize)>,
+) -> Option<(Groups, usize)> {
+    let digits_from = |i: usize| i + s[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+    let mut i = index.map_or(start, |(_, end)| end);
+    let flags_start = i;
+    while s.get(i).is_some_and(|b| b"-#+ 0,(<".contains(b)) {
+        i += 1;
+    }
+    let flags = Some((flags_start, i));
+    let width = Some((i, digits_from(i))).filter(|(start, end)| end > start);
+    i = width.map_or(i, |(_, end)| end);
+    let mut precision = None;
+    if s.get(i) == Some(&b'.') && digits_from(i + 1) > i + 1 {
+        precision = Some((i, digits_from(i + 1)));
+        i = digits_from(i + 1);
+    }
+    let is_conversion = |b: Option<&u8>| b.is_some_and(|b| b.is_ascii_alphabetic() || *b == b'%');
+    // `t` or `T` is only the date/time prefix if a conversion follows it.
+    let date_time = Some((i, i + 1))
+        .filter(|_| matches!(s.get(i), Some(b't' | b'T')) && is_conversion(s.get(i + 1)));
+    i = date_time.map_or(i, |(_, end)| end);
+    if !is_conversion(s.get(i)) {
+        return None;
+    }
+    Some((
+        [index, flags, width, precision, date_time, Some((i, i + 1))],
+        i + 1,
+    ))
+}
+
+fn is_valid_conversion(c: char) -> bool {
+    "bBhHsScCdoxXeEfgGaA%n".contains(c)
+}
+
+fn is_valid_date_time(c: char) -> bool {
+    "HIklMSLNpzZsQBbhAaCYyjmdeRTrDFc".contains(c)
+}
+
+fn unknown_conversion(conversion: &str) -> ! {
+    UnknownFormatConversionException::new(format!("Conversion = '{conversion}'")).throw()
+}
+
+fn flags_to_string(flags: u32) -> String {
+    FLAG_CHARS
+        .iter()
+        .filter(|(flag, _)| flags & flag != 0)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+fn fail_mismatch(flag: u32, conversion: char) -> ! {
+    FormatFlagsConversionMismatchException::new(format!(
+        "Conversion = {conversion}, Flags = {}",
+        flags_to_string(flag)
+    ))
+    .throw()
+}
+
+fn fail_conversion(conversion: char, arg: &FormatArgument) -> ! {
+    IllegalFormatConversionException::new(format!("{conversion} != {}", arg.class_name())).throw()
+}
+
+impl FormatSpecifier {
+    /// A specifier of just a conversion, like `%s`.
+    fn of(conversion: char) -> Self {
+        let mut spec = Self {
+            index: Index::Ordinary,
+            flags: 0,
+            width: -1,
+            precision: -1,
+            date_time: false,
+            conversion,
+        };
+        if conversion.is_ascii_uppercase() {
+            spec.flags = UPPERCASE;
+            spec.conversion = conversion.to_ascii_lowercase();
+        }
+        if "%n".contains(conversion) {
+            spec.index = Index::Text;
+        }
+        spec
+    }
+
+    fn parse(s: &str, groups: Groups) -> Self {
+        let group = |index: usize| groups[index].map(|(start, end)| &s[start..end]);
+        let mut spec = Self::of('s');
+        spec.flags = 0;
+        if let Some(index) = group(0) {
+            // Skip the trailing `$`.
+            match index[..index.len() - 1].parse::<i32>() {
+                Ok(index) if index > 0 => spec.index = Index::Explicit(index),
+                Ok(index) => IllegalFormatArgumentIndexException::new(format!(
+                    "Illegal format argument index = {index}"
+                ))
+                .throw(),
+                Err(_) => IllegalFormatArgumentIndexException::new(
+                    "Format argument index: (not representable as int)".to_string(),
+                )
+                .throw(),
+            }
+        }
+        for c in group(1).unwrap_or_default().chars() {
+            let flag = FLAG_CHARS
+                .iter()
+                .find(|&&(_, flag_char)| flag_char == c)
+                .map_or(0, |&(flag, _)| flag);
+            if spec.flags & flag != 0 {
+                DuplicateFormatFlagsException::new(format!("Flags = '{c}'")).throw();
+            }
+            spec.flags |= flag;
+        }
+        if spec.flags & PREVIOUS != 0 {
+            spec.index = Index::Previous;
+        }
+        if let Some(width) = group(2) {
+            spec.width = width.parse().unwrap_or(i32::MIN);
+            if spec.width < 0 {
+                IllegalFormatWidthException::new(spec.width.to_string()).throw();
+            }
+        }
+        if let Some(precision) = group(3) {
+            spec.precision = precision[1..].parse().unwrap_or(i32::MIN);
+            if spec.precision < 0 {
+                IllegalFormatPrecisionException::new(spec.precision.to_string()).throw();
+            }
+        }
+        if let Some(prefix) = group(4) {
+            spec.date_time = true;
+            if prefix == "T" {
+                spec.flags |= UPPERCASE;
+            }
+        }
+        let conversion = s[groups[5].unwrap().0..].chars().next().unwrap();
+        spec.conversion = conversion;
+        if !spec.date_time {
+            if !is_valid_conversion(conversion) {
+                unknown_conversion(&conversion.to_string());
+            }
+            if conversion.is_ascii_uppercase() {
+                spec.flags |= UPPERCASE;
+                spec.conversion = conversion.to_ascii_lowercase();
+            }
+            if "%n".contains(conversion) {
+                spec.index = Index::Text;
+            }
+        }
+        spec.check();
+        spec
+    }
+
+    fn has(&self, flag: u32) -> bool {
+        self.flags & flag != 0
+    }
+
+    fn check(&self) {
+        match self.conversion {
+            _ if self.date_time => self.check_date_time(),
+            'b' | 'h' | 's' => self.check_general(),
+            'c' => self.check_character(),
+            'd' | 'o' | 'x' => self.check_integer(),
+            'e' | 'f' | 'g' | 'a' => self.check_float(),
+            _ => self.check_text(),
+        }
+    }
+
+    fn check_bad_flags(&self, bad_flags: u32) {
+        for &(flag, _) in &FLAG_CHARS {
+            if bad_flags & flag != 0 && self.has(flag) {
+                fail_mismatch(flag, self.conversion);
+            }
+        }
+    }
+
+    fn check_precision_absent(&self) {
+        if self.precision != -1 {
+            IllegalFormatPrecisionException::new(self.precision.to_string()).throw();
+        }
+    }
+
+    /// `-` and the flags in `needing_width` require a width.
+    fn check_width_present(&self, needing_width: u32) {
+        if self.width == -1 && self.has(needing_width) {
+            MissingFormatWidthException::new(self.to_string()).throw();
+        }
+    }
+
+    fn check_date_time(&self) {
+        self.check_precision_absent();
+        if !is_valid_date_time(self.conversion) {
+            unknown_conversion(&format!("t{}", self.conversion));
+        }
+        self.check_bad_flags(ALTERNATE | PLUS | LEADING_SPACE | ZERO_PAD | GROUP | PARENTHESES);
+        self.check_width_present(LEFT_JUSTIFY);
+    }
+
+    fn check_general(&self) {
+        if self.conversion != 's' && self.has(ALTERNATE) {
+            fail_mismatch(ALTERNATE, self.conversion);
+        }
+        self.check_width_present(LEFT_JUSTIFY);
+        self.check_bad_flags(PLUS | LEADING_SPACE | ZERO_PAD | GROUP | PARENTHESES);
+    }
+
+    fn check_character(&self) {
+        self.check_precision_absent();
+        self.check_bad_flags(ALTERNATE | PLUS | LEADING_SPACE | ZERO_PAD | GROUP | PARENTHESES);
+        self.check_width_present(LEFT_JUSTIFY);
+    }
+
+    fn check_numeric(&self) {
+        self.check_width_present(LEFT_JUSTIFY | ZERO_PAD);
+        if (self.has(PLUS) && self.has(LEADING_SPACE))
+            || (self.has(LEFT_JUSTIFY) && self.has(ZERO_PAD))
+        {
+            IllegalFormatFlagsException::new(format!("Flags = '{}'", flags_to_string(self.flags)))
+                .throw();
+        }
+    }
+
+    fn check_integer(&self) {
+        self.check_numeric();
+        self.check_precision_absent();
+        match self.conversion {
+            'd' => self.check_bad_flags(ALTERNATE),
+            _ => self.check_bad_flags(GROUP),
+        }
+    }
+
+    fn check_float(&self) {
+        self.check_numeric();
+        match self.conversion {
+            'a' => self.check_bad_flags(PARENTHESES | GROUP),
+            'e' => self.check_bad_flags(GROUP),
+            'g' => self.check_bad_flags(ALTERNATE),
+            _ => {}
+        }
+    }
+
+    fn check_text(&self) {
+        self.check_precision_absent();
+        if self.conversion == '%' {
+            if self.flags & !LEFT_JUSTIFY != 0 {
+                IllegalFormatFlagsException::new(format!(
+                    "Flags = '{}'",
+                    flags_to_string(self.flags)
+                ))
+                .throw();
+            }
+            self.check_width_present(LEFT_JUSTIFY);
+        } else {
+            if self.width != -1 {
+                IllegalFormatWidthException::new(self.width.to_string()).throw();
+            }
+            if self.flags != 0 {
+                IllegalFormatFlagsException::new(format!(
+                    "Flags = '{}'",
+                    flags_to_string(self.flags)
+                ))
+                .throw();
+            }
+        }
+    }
+
+    fn print(&self, out: &mut String, arg: Option<&FormatArgument>, locale: &Locale) {
+        let arg = arg.unwrap_or(&FormatArgument::Null);
+        if self.date_time {
+            return self.print_date_time(out, arg, locale);
+        }
+        match self.conversion {
+            'd' | 'o' | 'x' => self.print_integer(out, arg, locale),
+            'e' | 'f' | 'g' | 'a' => self.print_float(out, arg, locale),
+            'c' => self.print_character(out, arg, locale),
+            'b' => {
+                let value = match arg {
+                    FormatArgument::Null => false,
+                    FormatArgument::Boolean(value) => *value,
+                    _ => true,
+                };
+                self.print_string(out, &value.to_string(), locale);
+            }
+            's' => {
+                if self.has(ALTERNATE) {
+                    fail_mismatch(ALTERNATE, 's');
+                }
+                self.print_string(out, &arg.to_java_string(), locale);
+            }
+            'h' => {
+                let hash = match arg {
+                    FormatArgument::Null => "null".to_string(),
+                    _ => format!("{:x}", arg.hash_code()),
+                };
+                self.print_string(out, &hash, locale);
+            }
+            'n' => out.push('\n'),
+            _ => self.print_string(out, "%", locale),
+        }
+    }
+
+    /// Truncates a string to the precision and upper-cases it if asked to, then justifies
+    /// it.
+    fn print_string(&self, out: &mut String, s: &str, locale: &Locale) {
+        let mut s = s.to_string();
+        if self.precision != -1 {
+            let units: Vec<u16> = s.encode_utf16().collect();
+            if (self.precision as usize) < units.len() {
+                s = String::from_utf16_lossy(&units[..self.precision as usize]);
+            }
+        }
+        if self.has(UPPERCASE) {
+            s = s.to_upper_case(locale);
+        }
+        self.append_justified(out, &s);
+    }
+
+    /// Pads to the width with spaces, on the right if left-justified.
+    fn append_justified(&self, out: &mut String, s: &str) {
+        let padding = (self.width.max(0) as usize).saturating_sub(s.encode_utf16().count());
+        if self.has(LEFT_JUSTIFY) {
+            out.push_str(s);
+            out.extend(std::iter::repeat_n(' ', padding));
+        } else {
+            out.extend(std::iter::repeat_n(' ', padding));
+            out.push_str(s);
+        }
+    }
+
+    fn print_character(&self, out: &mut String, arg: &FormatArgument, locale: &Locale) {
+        let code_point = match *arg {
+            FormatArgument::Null => return self.print_string(out, "null", locale),
+            FormatArgument::Char(c) => return self.print_string(out, &c.to_string(), locale),
+            FormatArgument::Byte(value) => value as i32,
+            FormatArgument::Short(value) => value as i32,
+            FormatArgument::Int(value) => value,
+            _ => fail_conversion(self.conversion, arg),
+        };
+        match u32::try_from(code_point).ok().and_then(char::from_u32) {
+            Some(c) => self.print_string(out, &c.to_string(), locale),
+            // A lone surrogate, which a Rust string cannot hold.
+            None if (0xD800..0xE000).contains(&code_point) => {
+                self.print_string(out, "\u{FFFD}", locale)
+            }
+            None => IllegalFormatCodePointException::new(format!("Code point = {:#x}", code_point))
+                .throw(),
+        }
+    }
+
+    fn print_integer(&self, out: &mut String, arg: &FormatArgument, locale: &Locale) {
+        // Negative bytes, shorts and ints are unsigned in octal and hexadecimal.
+        let (value, bits) = match *arg {
+            FormatArgument::Null => return self.print_string(out, "null", locale),
+            FormatArgument::Byte(value) => (value as i64, 8),
+            FormatArgument::Short(value) => (value as i64, 16),
+            FormatArgument::Int(value) => (value as i64, 32),
+            FormatArgument::Long(value) => (value, 64),
+            _ => fail_conversion(self.conversion, arg),
+        };
+        let mut sb = String::new();
+        if self.conversion == 'd' {
+            let negative = value < 0;
+            self.leading_sign(&mut sb, negative);
+            let width = self.adjust_width(self.width, negative);
+            let digits = value.to_string();
+            self.localized_magnitude(
+                &mut sb,
+                &digits,
+                negative as usize,
+                self.flags,
+                width,
+                locale,
+            );
+            self.trailing_sign(&mut sb, negative);
+        } else {
+            self.check_bad_flags(PARENTHESES | LEADING_SPACE | PLUS);
+            let unsigned = if value < 0 && bits < 64 {
+                (value + (1 << bits)) as u64
+            } else {
+                value as u64
+            };
+            let (mut digits, prefix) = if self.conversion == 'o' {
+                (format!("{unsigned:o}"), "0")
+            } else if self.has(UPPERCASE) {
+                (format!("{unsigned:x}"), "0X")
+            } else {
+                (format!("{unsigned:x}"), "0x")
+            };
+            let mut length = digits.len() as i32;
+            if self.has(ALTERNATE) {
+                sb.push_str(prefix);
+                length += prefix.len() as i32;
+            }
+            if self.has(ZERO_PAD) {
+                sb.extend(std::iter::repeat_n(
+                    '0',
+                    (self.width - length).max(0) as usize,
+                ));
+            }
+            if self.has(UPPERCASE) {
+                digits = digits.to_upper_case(locale);
+            }
+            sb.push_str(&digits);
+        }
+        self.append_justified(out, &sb);
+    }
+
+    fn leading_sign(&self, sb: &mut String, negative: bool) {
+        if !negative {
+            if self.has(PLUS) {
+                sb.push('+');
+            } else if self.has(LEADING_SPACE) {
+                sb.push(' ');
+            }
+        } else if self.has(PARENTHESES) {
+            sb.push('(');
+        } else {
+            sb.push('-');
+        }
+    }
+
+    fn trailing_sign(&self, sb: &mut String, negative: bool) {
+        if negative && self.has(PARENTHESES) {
+            sb.push(')');
+        }
+    }
+
+    /// The width left for the number when parentheses enclose it.
+    fn adjust_width(&self, width: i32, negative: bool) -> i32 {
+        if width != -1 && negative && self.has(PARENTHESES) {
+            width - 1
+        } else {
+            width
+        }
+    }
+
+    /// Appends ASCII digits from `offset`, with at most one `.`, in the locale's digits,
+    /// decimal separator and, if `GROUP` is set, grouping. With `ZERO_PAD` the whole of
+    /// `sb` is padded to the width with zeros inserted where the digits start.
+    fn localized_magnitude(
+        &self,
+        sb: &mut String,
+        value: &str,
+        offset: usize,
+        flags: u32,
+        width: i32,
+        locale: &Locale,
+    ) {
+        let begin = sb.len();
+        let us = *locale == Locale::US;
+        let symbols = (!us).then(|| DecimalFormatSymbols::get_instance(locale));
+        let zero = symbols
+            .as_ref()
+            .map_or('0', |symbols| symbols.get_zero_digit());
+        let value = value.as_bytes();
+        let dot = value[offset..]
+            .iter()
+            .position(|&b| b == b'.')
+            .map_or(value.len(), |dot| offset + dot);
+        let decimal_separator = symbols
+            .as_ref()
+            .map_or('.', |symbols| symbols.get_decimal_separator());
+        let mut grouping = None;
+        if flags & GROUP != 0 {
+            grouping = match &symbols {
+                None => Some((',', 3)),
+                Some(symbols) => {
+                    let format = DecimalFormat::get_number_instance(locale);
+                    let size = format.get_grouping_size();
+                    (format.is_grouping_used() && size != 0)
+                        .then(|| (symbols.get_grouping_separator(), size as usize))
+                }
+            };
+        }
+        for (j, &c) in value.iter().enumerate().skip(offset) {
+            if j == dot {
+                sb.push(decimal_separator);
+                grouping = None;
+                continue;
+            }
+            sb.push(localized_digit(c, zero));
+            if let Some((separator, size)) = grouping {
+                if j != dot - 1 && (dot - j) % size == 1 {
+                    sb.push(separator);
+                }
+            }
+        }
+        if width != -1 && flags & ZERO_PAD != 0 {
+            let length = sb.encode_utf16().count() as i32;
+            let zeros: String =
+                std::iter::repeat_n(zero, (width - length).max(0) as usize).collect();
+            sb.insert_str(begin, &zeros);
+        }
+    }
+
+    fn print_float(&self, out: &mut String, arg: &FormatArgument, locale: &Locale) {
+        let value = match *arg {
+            FormatArgument::Null => return self.print_string(out, "null", locale),
+            FormatArgument::Float(value) => value as f64,
+            FormatArgument::Double(value) => value,
+            _ => fail_conversion(self.conversion, arg),
+        };
+        let mut sb = String::new();
+        // -0.0 is negative too.
+        let negative = value.is_sign_negative() && !value.is_nan();
+        if value.is_nan() {
+            sb.push_str(if self.has(UPPERCASE) { "NAN" } else { "NaN" });
+        } else {
+            let magnitude = value.abs();
+            self.leading_sign(&mut sb, negative);
+            if magnitude.is_infinite() {
+                sb.push_str(if self.has(UPPERCASE) {
+                    "INFINITY"
+                } else {
+                    "Infinity"
+                });
+            } else {
+                self.print_finite(&mut sb, magnitude, locale, negative);
+            }
+            self.trailing_sign(&mut sb, negative);
+        }
+        self.append_justified(out, &sb);
+    }
+
+    fn print_finite(&self, sb: &mut String, value: f64, locale: &Locale, negative: bool) {
+        let exponent_char = if self.has(UPPERCASE) { 'E' } else { 'e' };
+        match self.conversion {
+            'e' => {
+                let precision = if self.precision == -1 {
+                    6
+                } else {
+                    self.precision
+                };
+                let (mantissa, exponent, _) =
+                    floating_decimal::formatted(value, precision, Form::Scientific);
+                let mut mantissa = add_zeros(mantissa, precision);
+                if self.has(ALTERNATE) && precision == 0 {
+                    mantissa.push('.');
+                }
+                let exponent = if value == 0.0 {
+                    "+00".to_string()
+                } else {
+                    exponent.unwrap_or_default()
+                };
+                let width = self.exponent_width(&exponent, negative);
+                self.localized_magnitude(sb, &mantissa, 0, self.flags, width, locale);
+                sb.push(exponent_char);
+                self.localized_exponent(sb, &exponent, locale);
+            }
+            'f' => {
+                let precision = if self.precision == -1 {
+                    6
+                } else {
+                    self.precision
+                };
+                let (mantissa, _, _) =
+                    floating_decimal::formatted(value, precision, Form::DecimalFloat);
+                let mut mantissa = add_zeros(mantissa, precision);
+                if self.has(ALTERNATE) && precision == 0 {
+                    mantissa.push('.');
+                }
+                let width = self.adjust_width(self.width, negative);
+                self.localized_magnitude(sb, &mantissa, 0, self.flags, width, locale);
+            }
+            'g' => {
+                let mut precision = match self.precision {
+                    -1 => 6,
+                    0 => 1,
+                    precision => precision,
+                };
+                let (mantissa, exponent, exponent_rounded) = if value == 0.0 {
+                    ("0".to_string(), None, 0)
+                } else {
+                    floating_decimal::formatted(value, precision, Form::General)
+                };
+                precision -= match exponent {
+                    Some(_) => 1,
+                    None => exponent_rounded + 1,
+                };
+                let mantissa = add_zeros(mantissa, precision);
+                match exponent {
+                    Some(exponent) => {
+                        let width = self.exponent_width(&exponent, negative);
+                        self.localized_magnitude(sb, &mantissa, 0, self.flags, width, locale);
+                        sb.push(exponent_char);
+                        self.localized_exponent(sb, &exponent, locale);
+                    }
+                    None => {
+                        let width = self.adjust_width(self.width, negative);
+                        self.localized_magnitude(sb, &mantissa, 0, self.flags, width, locale);
+                    }
+                }
+            }
+            _ => {
+                let precision = match self.precision {
+                    -1 => 0,
+                    0 => 1,
+                    precision => precision,
+                };
+                let s = hex_double(value, precision);
+                let upper = self.has(UPPERCASE);
+                sb.push_str(if upper { "0X" } else { "0x" });
+                if self.has(ZERO_PAD) {
+                    let leading = if self.has(LEADING_SPACE) || self.has(PLUS) || negative {
+                        3
+                    } else {
+                        2
+                    };
+                    let zeros = self.width - s.len() as i32 - leading;
+                    sb.extend(std::iter::repeat_n('0', zeros.max(0) as usize));
+                }
+                let (significand, exponent) = s.split_once('p').unwrap();
+                let mut significand = if upper {
+                    significand.to_ascii_uppercase()
+                } else {
+                    significand.to_string()
+                };
+                if precision != 0 {
+                    significand = add_zeros(significand, precision);
+                }
+                sb.push_str(&significand);
+                sb.push(if upper { 'P' } else { 'p' });
+                sb.push_str(exponent);
+            }
+        }
+    }
+
+    /// The width left for the mantissa of scientific notation.
+    fn exponent_width(&self, exponent: &str, negative: bool) -> i32 {
+        if self.width == -1 {
+            -1
+        } else {
+            self.adjust_width(self.width - exponent.len() as i32 - 1, negative)
+        }
+    }
+
+    /// Appends the sign of an exponent, then its digits in the locale's digits.
+    fn localized_exponent(&self, sb: &mut String, exponent: &str, locale: &Locale) {
+        let zero = zero_digit(locale);
+        let (sign, digits) = exponent.split_at(1);
+        sb.push_str(sign);
+        sb.extend(digits.bytes().map(|digit| localized_digit(digit, zero)));
+    }
+
+    fn print_date_time(&self, out: &mut String, arg: &FormatArgument, locale: &Locale) {
+        let time_in_locale = |millis: i64| {
+            let mut calendar = Calendar::get_instance_with(TimeZone::get_default(), locale);
+            calendar.set_time_in_millis(millis);
+            calendar
+        };
+        let source = match arg {
+            FormatArgument::Null => return self.print_string(out, "null", locale),
+            FormatArgument::Long(millis) => DateTimeSource::Calendar(time_in_locale(*millis)),
+            FormatArgument::Date(date) => DateTimeSource::Calendar(time_in_locale(date.get_time())),
+            FormatArgument::Calendar(calendar) => DateTimeSource::Calendar(calendar.clone()),
+            FormatArgument::Instant(_)
+            | FormatArgument::LocalDate(_)
+            | FormatArgument::LocalTime(_)
+            | FormatArgument::LocalDateTime(_)
+            | FormatArgument::ZonedDateTime(_) => DateTimeSource::Temporal(arg),
+            _ => fail_conversion(self.conversion, arg),
+        };
+        let mut sb = String::new();
+        if let Err(conversion) = print_date_time_field(&mut sb, &source, self.conversion, locale) {
+            fail_conversion(conversion, arg);
+        }
+        if self.has(UPPERCASE) {
+            sb = sb.to_upper_case(locale);
+        }
+        self.append_justified(out, &sb);
+    }
+}
+
+/// Java's `toString` of a specifier, as in exception messages.
+impl fmt::Display for FormatSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "%{}", flags_to_string(self.flags & !UPPERCASE))?;
+        if let Index::Explicit(index) = self.index {
+            write!(f, "{index}$")?;
+        }
+        if self.width != -1 {
+            write!(f, "{}", self.width)?;
+        }
+        if self.precision != -1 {
+            write!(f, ".{}", self.precision)?;
+        }
+        let upper = self.has(UPPERCASE);
+        if self.date_time {
+            f.write_str(if upper { "T" } else { "t" })?;
+        }
+        if upper {
+            write!(f, "{}", self.conversion.to_ascii_uppercase())
+        } else {
+            write!(f, "{}", self.conversion)
+        }
+    }
+}
+
+fn zero_digit(locale: &Locale) -> char {
+    if *locale == Locale::US {
+        '0'
+    } else {
+        DecimalFormatSymbols::get_instance(locale).get_zero_digit()
+    }
+}
+
+/// An ASCII character as the locale's digit, offset from its zero like Java does for any
+/// character.
+fn localized_digit(ascii: u8, zero: char) -> char {
+    char::from_u32(
+        (ascii as u32)
+            .wrapping_sub('0' as u32)
+            .wrapping_add(zero as u32),
+    )
+    .unwrap_or(ascii as char)
+}
+
+/// Pads the digits after the point with zeros up to the precision, adding the point if
+/// need be.
+fn add_zeros(mut mantissa: String, precision: i32) -> String {
+    let dot = mantissa.find('.');
+    let existing = dot.map_or(0, |dot| (mantissa.len() - dot - 1) as i32);
+    if existing >= precision {
+        return mantissa;
+    }
+    if dot.is_none() {
+        mantissa.push('.');
+    }
+    mantissa.extend(std::iter::repeat_n('0', (precision - existing) as usize));
+    mantissa
+}
+
+/// Java's `Double.toHexString` without the sign and `0x`.
+fn double_to_hex_string(value: f64) -> String {
+    if value == 0.0 {
+        return "0.0p0".to_string();
+    }
+    let bits = value.to_bits();
+    let subnormal = bits >> 52 & 0x7FF == 0;
+    let significand = format!("{:013x}", bits & ((1 << 52) - 1));
+    let significand = significand.trim_end_matches('0');
+    let exponent = if subnormal {
+        -1022
+    } else {
+        (bits >> 52 & 0x7FF) as i32 - 1023
+    };
+    format!(
+        "{}.{}p{exponent}",
+        if subnormal { 0 } else { 1 },
+        if significand.is_empty() {
+            "0"
+        } else {
+            significand
+        }
+    )
+}
+
+/// `Formatter.hexDouble`: the hexadecimal significand and exponent of a non-negative
+/// finite value, rounded half even to `precision` hexadecimal digits if that is from 1 to
+/// 12, with subnormal values normalized.
+fn hex_double(value: f64, precision: i32) -> String {
+    if value == 0.0 || precision == 0 || precision >= 13 {
+        return double_to_hex_string(value);
+    }
+    let subnormal = value.to_bits() >> 52 == 0;
+    let value = if subnormal {
+        value * 2f64.powi(54)
+    } else {
+        value
+    };
+    let shift = 53 - (1 + precision * 4);
+    let bits = value.to_bits();
+    let mut significand = bits >> shift;
+    let rounding_bits = bits & !(!0u64 << shift);
+    let least_zero = significand & 1 == 0;
+    let round = (1u64 << (shift - 1)) & rounding_bits != 0;
+    let sticky = shift > 1 && !(1u64 << (shift - 1)) & rounding_bits != 0;
+    if round && (!least_zero || sticky) {
+        significand += 1;
+    }
+    let result = f64::from_bits(significand << shift);
+    if result.is_infinite() {
+        return "1.0p1024".to_string();
+    }
+    let hex = double_to_hex_string(result);
+    if !subnormal {
+        return hex;
+    }
+    let (significand, exponent) = hex.split_once('p').unwrap();
+    format!("{significand}p{}", exponent.parse::<i32>().unwrap() - 54)
+}
+
+/// What a date/time conversion reads its fields from.
+enum DateTimeSource<'a> {
+    Calendar(Calendar),
+    /// One of the `java.time` arguments.
+    Temporal(&'a FormatArgument),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ChronoField {
+    NanoOfSecond,
+    MilliOfSecond,
+    SecondOfMinute,
+    MinuteOfHour,
+    HourOfDay,
+    ClockHourOfAmPm,
+    AmPmOfDay,
+    /// From 1 for Sunday, as `Calendar` numbers them.
+    DayOfWeek,
+    DayOfMonth,
+    DayOfYear,
+    MonthOfYear,
+    YearOfEra,
+    InstantSeconds,
+    OffsetSeconds,
+}
+
+/// A field the temporal does not support.
+struct Unsupported;
+
+impl DateTimeSource<'_> {
+    fn get(&self, field: ChronoField) -> Result<i64, Unsupported> {
+        use ChronoField::*;
+        let temporal = match self {
+            DateTimeSource::Calendar(calendar) => {
+                let value = match field {
+                    NanoOfSecond => calendar.get(Calendar::MILLISECOND) as i64 * 1_000_000,
+                    MilliOfSecond => calendar.get(Calendar::MILLISECOND) as i64,
+                    SecondOfMinute => calendar.get(Calendar::SECOND) as i64,
+                    MinuteOfHour => calendar.get(Calendar::MINUTE) as i64,
+                    HourOfDay => calendar.get(Calendar::HOUR_OF_DAY) as i64,
+                    ClockHourOfAmPm => match calendar.get(Calendar::HOUR) {
+                        0 => 12,
+                        hour => hour as i64,
+                    },
+                    AmPmOfDay => calendar.get(Calendar::AM_PM) as i64,
+                    DayOfWeek => calendar.get(Calendar::DAY_OF_WEEK) as i64,
+                    DayOfMonth => calendar.get(Calendar::DAY_OF_MONTH) as i64,
+                    DayOfYear => calendar.get(Calendar::DAY_OF_YEAR) as i64,
+                    MonthOfYear => calendar.get(Calendar::MONTH) as i64 + 1,
+                    YearOfEra => calendar.get(Calendar::YEAR) as i64,
+                    // Truncated, not floored, like Java.
+                    InstantSeconds => calendar.get_time_in_millis() / 1000,
+                    OffsetSeconds => {
+                        (calendar.get(Calendar::ZONE_OFFSET) + calendar.get(Calendar::DST_OFFSET))
+                            as i64
+                            / 1000
+                    }
+                };
+                return Ok(value);
+            }
+            DateTimeSource::Temporal(temporal) => temporal,
+        };
+        let (date, time, instant, offset) = match temporal {
+            FormatArgument::LocalDate(date) => (Some(*date), None, None, None),
+            FormatArgument::LocalTime(time) => (None, Some(*time), None, None),
+            FormatArgument::LocalDateTime(date_time) => (
+                Some(date_time.to_local_date()),
+                Some(date_time.to_local_time()),
+                None,
+                None,
+            ),
+            FormatArgument::ZonedDateTime(zoned) => {
+                let date_time = zoned.to_local_date_time();
+                (
+                    Some(date_time.to_local_date()),
+                    Some(date_time.to_local_time()),
+                    Some(zoned.to_instant()),
+                    Some(zoned.get_offset()),
+                )
+            }
+            FormatArgument::Instant(instant) => (None, None, Some(*instant), None),
+            _ => return Err(Unsupported),
+        };
+        let date = || date.ok_or(Unsupported);
+        let nano = || match (time, instant) {
+            (Some(time), _) => Ok(time.get_nano() as i64),
+            (None, Some(instant)) => Ok(instant.get_nano() as i64),
+            _ => Err(Unsupported),
+        };
+        let time = || time.ok_or(Unsupported);
+        Ok(match field {
+            NanoOfSecond => nano()?,
+            MilliOfSecond => nano()? / 1_000_000,
+            SecondOfMinute => time()?.get_second() as i64,
+            MinuteOfHour => time()?.get_minute() as i64,
+            HourOfDay => time()?.get_hour() as i64,
+            ClockHourOfAmPm => match time()?.get_hour() % 12 {
+                0 => 12,
+                hour => hour as i64,
+            },
+            AmPmOfDay => (time()?.get_hour() / 12) as i64,
+            DayOfWeek => (date()?.day_of_week_value() % 7 + 1) as i64,
+            DayOfMonth => date()?.get_day_of_month() as i64,
+            DayOfYear => date()?.get_day_of_year() as i64,
+            MonthOfYear => date()?.get_month_value() as i64,
+            YearOfEra => match date()?.get_year() {
+                year if year >= 1 => year as i64,
+                year => 1 - year as i64,
+            },
+            InstantSeconds => instant.ok_or(Unsupported)?.get_epoch_second(),
+            OffsetSeconds => offset.ok_or(Unsupported)? as i64,
+        })
+    }
+}
+
+/// Appends a number in the locale's digits, zero padded to `size` digits if it is given.
+fn localized_number(sb: &mut String, value: i64, size: Option<usize>, locale: &Locale) {
+    let zero = zero_digit(locale);
+    let digits = value.to_string();
+    if let Some(size) = size {
+        sb.extend(std::iter::repeat_n(zero, size.saturating_sub(digits.len())));
+    }
+    sb.extend(digits.bytes().map(|digit| localized_digit(digit, zero)));
+}
+
+/// Appends the field or fields of a date/time conversion, `Formatter.print(Calendar)` and
+/// `print(TemporalAccessor)` in one. If the temporal lacks a field, the error is the
+/// conversion of the field, which is what Java reports for composites like `%tc`.
+fn print_date_time_field(
+    sb: &mut String,
+    source: &DateTimeSource,
+    conversion: char,
+    locale: &Locale,
+) -> Result<(), char> {
+    match conversion {
+        'T' | 'R' => {
+            print_date_time_field(sb, source, 'H', locale)?;
+            sb.push(':');
+            print_date_time_field(sb, source, 'M', locale)?;
+            if conversion == 'T' {
+                sb.push(':');
+                print_date_time_field(sb, source, 'S', locale)?;
+            }
+        }
+        'r' => {
+            print_date_time_field(sb, source, 'I', locale)?;
+            sb.push(':');
+            print_date_time_field(sb, source, 'M', locale)?;
+            sb.push(':');
+            print_date_time_field(sb, source, 'S', locale)?;
+            sb.push(' ');
+            let mut am_pm = String::new();
+            print_date_time_field(&mut am_pm, source, 'p', locale)?;
+            sb.push_str(&am_pm.to_upper_case(locale));
+        }
+        'c' => {
+            for (i, field) in ['a', 'b', 'd', 'T', 'Z', 'Y'].into_iter().enumerate() {
+                if i > 0 {
+                    sb.push(' ');
+                }
+                print_date_time_field(sb, source, field, locale)?;
+            }
+        }
+        'D' => {
+            print_date_time_field(sb, source, 'm', locale)?;
+            sb.push('/');
+            print_date_time_field(sb, source, 'd', locale)?;
+            sb.push('/');
+            print_date_time_field(sb, source, 'y', locale)?;
+        }
+        'F' => {
+            // ISO 8601.
+            print_date_time_field(sb, source, 'Y', locale)?;
+            sb.push('-');
+            print_date_time_field(sb, source, 'm', locale)?;
+            sb.push('-');
+            print_date_time_field(sb, source, 'd', locale)?;
+        }
+        _ => print_field(sb, source, conversion, locale).map_err(|Unsupported| conversion)?,
+    }
+    Ok(())
+}
+
+/// Appends the field of a date/time conversion that is not a composite of others.
+fn print_field(
+    sb: &mut String,
+    source: &DateTimeSource,
+    conversion: char,
+    locale: &Locale,
+) -> Result<(), Unsupported> {
+    use ChronoField::*;
+    match conversion {
+        'H' | 'k' => {
+            let size = (conversion == 'H').then_some(2);
+            localized_number(sb, source.get(HourOfDay)?, size, locale);
+        }
+        'I' | 'l' => {
+            let size = (conversion == 'I').then_some(2);
+            localized_number(sb, source.get(ClockHourOfAmPm)?, size, locale);
+        }
+        'M' => localized_number(sb, source.get(MinuteOfHour)?, Some(2), locale),
+        'N' => localized_number(sb, source.get(NanoOfSecond)?, Some(9), locale),
+        'L' => localized_number(sb, source.get(MilliOfSecond)?, Some(3), locale),
+        'Q' => {
+            let millis = match source {
+                DateTimeSource::Calendar(calendar) => calendar.get_time_in_millis(),
+                DateTimeSource::Temporal(_) => {
+                    source.get(InstantSeconds)? * 1000 + source.get(MilliOfSecond)?
+                }
+            };
+            localized_number(sb, millis, None, locale);
+        }
+        'p' => {
+            let am_pm = source.get(AmPmOfDay)? as usize;
+            let name = if *locale == Locale::US {
+                ["AM", "PM"][am_pm].to_string()
+            } else {
+                DateFormatSymbols::get_instance(locale).get_am_pm_strings()[am_pm].clone()
+            };
+            sb.push_str(&name.to_lower_case(locale));
+        }
+        's' => localized_number(sb, source.get(InstantSeconds)?, None, locale),
+        'S' => localized_number(sb, source.get(SecondOfMinute)?, Some(2), locale),
+        'z' => {
+            let offset = source.get(OffsetSeconds)?;
+            sb.push(if offset < 0 { '-' } else { '+' });
+            let minutes = offset.abs() / 60;
+            localized_number(sb, minutes / 60 * 100 + minutes % 60, Some(4), locale);
+        }
+        'Z' => match source {
+            DateTimeSource::Calendar(calendar) => {
+                let daylight = calendar.get(Calendar::DST_OFFSET) != 0;
+                sb.push_str(&zone_display_name(
+                    calendar.get_time_zone(),
+                    daylight,
+                    false,
+                    locale,
+                ));
+            }
+            DateTimeSource::Temporal(FormatArgument::ZonedDateTime(zoned)) => {
+                let zone = zoned.get_zone();
+                let daylight = zone.in_daylight_time(zoned.to_instant().to_epoch_milli());
+                sb.push_str(&zone_display_name(zone, daylight, false, locale));
+            }
+            DateTimeSource::Temporal(_) => return Err(Unsupported),
+        },
+        'a' | 'A' => {
+            let day = source.get(DayOfWeek)? as usize;
+            let symbols = DateFormatSymbols::get_instance(locale);
+            if conversion == 'A' {
+                sb.push_str(&symbols.get_weekdays()[day]);
+            } else {
+                sb.push_str(&symbols.get_short_weekdays()[day]);
+            }
+        }
+        'b' | 'h' | 'B' => {
+            let month = source.get(MonthOfYear)? as usize - 1;
+            let symbols = DateFormatSymbols::get_instance(locale);
+            if conversion == 'B' {
+                sb.push_str(&symbols.get_months()[month]);
+            } else {
+                sb.push_str(&symbols.get_short_months()[month]);
+            }
+        }
+        'C' | 'y' | 'Y' => {
+            let year = source.get(YearOfEra)?;
+            match conversion {
+                'C' => localized_number(sb, year / 100, Some(2), locale),
+                'y' => localized_number(sb, year % 100, Some(2), locale),
+                _ => localized_number(sb, year, Some(4), locale),
+            }
+        }
+        'd' | 'e' => {
+            let size = (conversion == 'd').then_some(2);
+            localized_number(sb, source.get(DayOfMonth)?, size, locale);
+        }
+        'j' => localized_number(sb, source.get(DayOfYear)?, Some(3), locale),
+        'm' => localized_number(sb, source.get(MonthOfYear)?, Some(2), locale),
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(locale: &Locale, format: &str, args: &[FormatArgument]) -> String {
+        Formatter::with_locale(locale)
+            .format(format, args)
+            .to_string()
+    }
+
+    fn us(format_string: &str, args: &[FormatArgument]) -> String {
+        format(&Locale::US, format_string, args)
+    }
+
+    fn calendar(millis: i64, zone: &str) -> FormatArgument {
+        let mut calendar = Calendar::get_instance_with(TimeZone::get_time_zone(zone), &Locale::US);
+        calendar.set_time_in_millis(millis);
+        calendar.into()
+    }
+
+    #[test]
+    fn formats_integers() {
+        assert_eq!(us("%,d", &[1_000_000.into()]), "1,000,000");
+        assert_eq!(us("%(,012d", &[i32::MIN.into()]), "(2,147,483,648)");
+        assert_eq!(
+            us("%#x %08X", &[(-1i8).into(), (-300i16).into()]),
+            "0xff 0000FED4"
+        );
+        assert_eq!(
+            us("%-5d|%+d|% d", &[7.into(), 7i64.into(), 7.into()]),
+            "7    |+7| 7"
+        );
+        let germany = format(&Locale::GERMANY, "%,d", &[1_000_000.into()]);
+        assert_eq!(germany, "1.000.000");
+        let egypt = Locale::for_language_tag("ar-EG");
+        assert_eq!(format(&egypt, "%,d", &[1_000_000.into()]), "١٬٠٠٠٬٠٠٠");
+    }
+
+    #[test]
+    fn formats_floating_point() {
+        assert_eq!(us("%,.3f", &[(-1234567.891).into()]), "-1,234,567.891");
+        assert_eq!(us("%g", &[0.00012345.into()]), "0.000123450");
+        assert_eq!(us("%.3g", &[123456789e20.into()]), "1.23e+28");
+        assert_eq!(
+            us("%a %.1a", &[4.9e-324.into(), 1.5.into()]),
+            "0x0.0000000000001p-1022 0x1.8p0"
+        );
+        let germany = format(&Locale::GERMANY, "%,.3f", &[(-1234567.891).into()]);
+        assert_eq!(germany, "-1.234.567,891");
+        assert_eq!(
+            us("%e %E", &[f64::NAN.into(), f64::NEG_INFINITY.into()]),
+            "NaN -INFINITY"
+        );
+    }
+
+    #[test]
+    fn formats_general_conversions() {
+        assert_eq!(
+            us(
+                "%s %b %h",
+                &[1.25f32.into(), FormatArgument::Null, "hello".into()]
+            ),
+            "1.25 false 5e918d2"
+        );
+        assert_eq!(us("%S", &["straße".into()]), "STRASSE");
+        let turkey = Locale::for_language_tag("tr-TR");
+        assert_eq!(format(&turkey, "%S", &["istanbul".into()]), "İSTANBUL");
+        assert_eq!(
+            us("%-6.3s|%c%n%%", &["hello".into(), 0x1F600.into()]),
+            "hel   |\u{1F600}\n%"
+        );
+        assert_eq!(
+            us("%s %<S %s %2$s %s", &["a".into(), "b".into(), "c".into()]),
+            "a A b b c"
+        );
+    }
+
+    #[test]
+    fn formats_dates_and_times() {
+        let time = calendar(1_600_000_000_000, "UTC");
+        assert_eq!(
+            us("%tY-%<tm-%<td %<tH:%<tM", std::slice::from_ref(&time)),
+            "2020-09-13 12:26"
+        );
+        assert_eq!(
+            us("%Tc", std::slice::from_ref(&time)),
+            "SUN SEP 13 12:26:40 UTC 2020"
+        );
+        assert_eq!(
+            us("%tr %<tQ %<ts", &[time]),
+            "12:26:40 PM 1600000000000 1600000000"
+        );
+        assert_eq!(us("%tQ", &[1_600_000_000_123i64.into()]), "1600000000123");
+        let date_time = LocalDateTime::of(LocalDate::of(2021, 11, 7), LocalTime::of(1, 30, 0, 0));
+        let zoned = ZonedDateTime::of(date_time, TimeZone::get_time_zone("America/New_York"));
+        assert_eq!(
+            us("%tc %<tz", &[zoned.into()]),
+            "Sun Nov 07 01:30:00 EDT 2021 -0400"
+        );
+        let los_angeles = TimeZone::get_time_zone("America/Los_Angeles");
+        let zoned = ZonedDateTime::of_instant(
+            Instant::of_epoch_second_adjusted(-3_957_770_453, 0),
+            los_angeles,
+        );
+        assert_eq!(us("%tT %<tz", &[zoned.into()]), "03:26:09 -0752");
+        assert_eq!(us("%tF", &[LocalDate::of(-5, 1, 1).into()]), "0006-01-01");
+        assert_eq!(
+            us("%-12tR|", &[LocalTime::of(0, 5, 9, 0).into()]),
+            "00:05       |"
+        );
+        let instant = Instant::of_epoch_second_adjusted(-1, 999_000_000);
+        assert_eq!(us("%tQ %<tN", &[instant.into()]), "-1 999000000");
+        let germany = calendar(1_000_000_000_123, "Europe/Berlin");
+        assert_eq!(
+            format(
+                &Locale::GERMANY,
+                "%tA, %<te. %<tB %<tL",
+                std::slice::from_ref(&germany)
+            ),
+            "Sonntag, 9. September 123"
+        );
+        let winter = calendar(1_610_000_000_000, "Europe/Berlin");
+        assert_eq!(
+            format(&Locale::GERMANY, "%tZ %tZ", &[germany, winter]),
+            "MESZ MEZ"
+        );
+        let date_time = LocalDateTime::of(LocalDate::of(2021, 1, 7), LocalTime::of(1, 0, 0, 0));
+        let zoned = ZonedDateTime::of(date_time, TimeZone::get_time_zone("Europe/Berlin"));
+        assert_eq!(format(&Locale::GERMANY, "%tZ", &[zoned.into()]), "MEZ");
+    }
+
+    #[test]
+    fn rejects_bad_formats() {
+        let message = |format_string: &'static str, args: Vec<FormatArgument>| {
+            let result = std::panic::catch_unwind(|| us(format_string, &args));
+            *result.unwrap_err().downcast::<String>().unwrap()
+        };
+        assert_eq!(message("%q", vec![]), "Conversion = 'q'");
+        assert_eq!(message("%tv", vec![]), "Conversion = 'tv'");
+        assert_eq!(message("%-d", vec![1.into()]), "%-d");
+        assert_eq!(message("%+ d", vec![1.into()]), "Flags = '+ '");
+        assert_eq!(message("%--5d", vec![1.into()]), "Flags = '-'");
+        assert_eq!(
+            message("%,(#o", vec![1.into()]),
+            "Conversion = o, Flags = ,"
+        );
+        assert_eq!(message("%.2c", vec![1.into()]), "2");
+        assert_eq!(
+            message("%0$d", vec![1.into()]),
+            "Illegal format argument index = 0"
+        );
+        assert_eq!(message("%3$s", vec![1.into()]), "Format specifier '%3$s'");
+        assert_eq!(
+            message("%c", vec![0x110000.into()]),
+            "Code point = 0x110000"
+        );
+        assert_eq!(message("%d", vec!["x".into()]), "d != java.lang.String");
+        assert_eq!(message("%tY", vec![1.into()]), "Y != java.lang.Integer");
+        assert_eq!(
+            message("%tc", vec![LocalDate::of(2021, 3, 4).into()]),
+            "H != java.time.LocalDate"
+        );
+    }
+}
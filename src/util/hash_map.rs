@@ -0,0 +1,2310 @@
+//! `java.util.HashMap`, with the JDK's table layout so that it iterates in the same order.
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use crate::errors::util::ConcurrentModificationException;
+use crate::errors::{IllegalArgumentException, IllegalStateException, Throwable};
+use crate::floating_decimal;
+use crate::util::collection;
+use crate::util::{Collection, LinkedHashMap, Map, Set};
+use crate::HashCode;
+
+const DEFAULT_INITIAL_CAPACITY: usize = 1 << 4;
+const MAXIMUM_CAPACITY: usize = 1 << 30;
+const DEFAULT_LOAD_FACTOR: f32 = 0.75;
+/// The number of nodes in a bin at which it is turned into a tree.
+const TREEIFY_THRESHOLD: usize = 8;
+/// The number of nodes at or below which a tree split by a resize is turned back into a
+/// list.
+const UNTREEIFY_THRESHOLD: usize = 6;
+/// The smallest table whose bins are turned into trees; smaller tables are resized
+/// instead.
+const MIN_TREEIFY_CAPACITY: usize = 64;
+const INTEGER_MAX_VALUE: usize = i32::MAX as usize;
+
+fn concurrent_modification() -> ! {
+    ConcurrentModificationException::new(String::new()).throw()
+}
+
+/// Java's `HashMap.hash`: spreads the higher bits of the hash code downwards, as the table
+/// index only uses the lower ones.
+fn hash<K: HashCode>(key: &K) -> i32 {
+    let h = key.hash_code();
+    h ^ ((h as u32) >> 16) as i32
+}
+
+fn index_for(hash: i32, capacity: usize) -> usize {
+    hash as u32 as usize & (capacity - 1)
+}
+
+/// The smallest power of two at least `capacity`.
+fn table_size_for(capacity: usize) -> usize {
+    capacity.max(1).next_power_of_two().min(MAXIMUM_CAPACITY)
+}
+
+/// Java's `tieBreakOrder` orders keys that have equal hashes and can't be compared by
+/// their identity hash codes, which differ from run to run; any fixed order is as good.
+fn tie_break_order() -> Ordering {
+    Ordering::Less
+}
+
+/// The red-black tree links of a `TreeNode`, the node of a bin that has been turned into
+/// a tree.
+#[derive(Clone, Copy, Default)]
+struct TreeLinks {
+    parent: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+    prev: Option<usize>,
+    red: bool,
+}
+
+struct Node<K, V> {
+    hash: i32,
+    key: K,
+    value: V,
+    next: Option<usize>,
+    tree: Option<TreeLinks>,
+    /// The links of `LinkedHashMap.Entry`, in the order of a `LinkedHashMap`.
+    before: Option<usize>,
+    after: Option<usize>,
+}
+
+/// How `LinkedHashMap::put_first` and `put_last` make `put` order the key.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PutMode {
+    Normal,
+    First,
+    Last,
+}
+
+/// Java's `removeEldestEntry`, given the map and its eldest mapping after a new one is put.
+pub(crate) type RemoveEldestEntry<K, V> = Rc<dyn Fn(&LinkedHashMap<K, V>, &K, &V) -> bool>;
+
+/// The state `LinkedHashMap` adds to the table: a list of the nodes in insertion or access
+/// order.
+pub(crate) struct Linked<K, V> {
+    head: Option<usize>,
+    tail: Option<usize>,
+    pub(crate) access_order: bool,
+    pub(crate) put_mode: PutMode,
+    pub(crate) remove_eldest_entry: Option<RemoveEldestEntry<K, V>>,
+}
+
+impl<K, V> Linked<K, V> {
+    pub(crate) fn new(access_order: bool) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            access_order,
+            put_mode: PutMode::Normal,
+            remove_eldest_entry: None,
+        }
+    }
+}
+
+/// Where `compute`, `compute_if_absent` and `merge` found a key, or would put it.
+struct ComputeSlot {
+    index: usize,
+    first: Option<usize>,
+    old: Option<usize>,
+    bin_count: usize,
+}
+
+/// What `put_tree_val` found in a tree: the key's node, or the node to attach it to.
+enum TreeSlot {
+    Found(usize),
+    Vacant {
+        root: usize,
+        parent: usize,
+        left: bool,
+    },
+}
+
+/// The state of a `HashMap`, shared with its views and iterators. The nodes live in an
+/// arena and link to each other by index; `bins` is Java's `table`, empty until the first
+/// mapping is put.
+pub(crate) struct Table<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    free: Vec<usize>,
+    bins: Vec<Option<usize>>,
+    size: usize,
+    threshold: usize,
+    load_factor: f32,
+    /// Java's `modCount`: the number of structural modifications, which iterators compare
+    /// with the count they expect to fail fast.
+    mod_count: usize,
+    pub(crate) linked: Option<Linked<K, V>>,
+}
+
+impl<K: HashCode, V> Table<K, V> {
+    fn new(threshold: usize, load_factor: f32) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            bins: Vec::new(),
+            size: 0,
+            threshold,
+            load_factor,
+            mod_count: 0,
+            linked: None,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<K, V> {
+        self.nodes[i].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, i: usize) -> &mut Node<K, V> {
+        self.nodes[i].as_mut().unwrap()
+    }
+
+    fn new_node(&mut self, hash: i32, key: K, value: V, tree: bool) -> usize {
+        let node = Node {
+            hash,
+            key,
+            value,
+            next: None,
+            tree: tree.then(TreeLinks::default),
+            before: None,
+            after: None,
+        };
+        let i = match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = Some(node);
+                i
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        self.link_node_at_end(i);
+        i
+    }
+
+    /// Appends a new node to the `LinkedHashMap` order, or prepends it for `put_first`.
+    fn link_node_at_end(&mut self, p: usize) {
+        let Some(linked) = &self.linked else { return };
+        if linked.put_mode == PutMode::First {
+            let first = linked.head;
+            self.linked.as_mut().unwrap().head = Some(p);
+            match first {
+                None => self.linked.as_mut().unwrap().tail = Some(p),
+                Some(first) => {
+                    self.node_mut(p).after = Some(first);
+                    self.node_mut(first).before = Some(p);
+                }
+            }
+        } else {
+            let last = linked.tail;
+            self.linked.as_mut().unwrap().tail = Some(p);
+            match last {
+                None => self.linked.as_mut().unwrap().head = Some(p),
+                Some(last) => {
+                    self.node_mut(p).before = Some(last);
+                    self.node_mut(last).after = Some(p);
+                }
+            }
+        }
+    }
+
+    /// Java's `afterNodeAccess`: moves a node that was read or replaced to the end of an
+    /// access-ordered `LinkedHashMap`, or to whichever end `put_first` or `put_last` puts
+    /// it, which is a structural modification.
+    pub(crate) fn after_node_access(&mut self, p: usize) {
+        let Some(linked) = &self.linked else { return };
+        let (b, a) = (self.node(p).before, self.node(p).after);
+        let to_last = linked.put_mode == PutMode::Last
+            || (linked.put_mode == PutMode::Normal && linked.access_order);
+        if to_last && linked.tail != Some(p) {
+            let mut last = linked.tail;
+            self.node_mut(p).after = None;
+            match b {
+                None => self.linked.as_mut().unwrap().head = a,
+                Some(b) => self.node_mut(b).after = a,
+            }
+            match a {
+                Some(a) => self.node_mut(a).before = b,
+                None => last = b,
+            }
+            match last {
+                None => self.linked.as_mut().unwrap().head = Some(p),
+                Some(last) => {
+                    self.node_mut(p).before = Some(last);
+                    self.node_mut(last).after = Some(p);
+                }
+            }
+            self.linked.as_mut().unwrap().tail = Some(p);
+            self.mod_count += 1;
+        } else if linked.put_mode == PutMode::First && linked.head != Some(p) {
+            let mut first = linked.head;
+            self.node_mut(p).before = None;
+            match a {
+                None => self.linked.as_mut().unwrap().tail = b,
+                Some(a) => self.node_mut(a).before = b,
+            }
+            match b {
+                Some(b) => self.node_mut(b).after = a,
+                None => first = a,
+            }
+            match first {
+                None => self.linked.as_mut().unwrap().tail = Some(p),
+                Some(first) => {
+                    self.node_mut(p).after = Some(first);
+                    self.node_mut(first).before = Some(p);
+                }
+            }
+            self.linked.as_mut().unwrap().head = Some(p);
+            self.mod_count += 1;
+        }
+    }
+
+    /// Java's `afterNodeRemoval`: unlinks a removed node from the `LinkedHashMap` order.
+    fn after_node_removal(&mut self, p: usize) {
+        if self.linked.is_none() {
+            return;
+        }
+        let (b, a) = (self.node(p).before, self.node(p).after);
+        match b {
+            None => self.linked.as_mut().unwrap().head = a,
+            Some(b) => self.node_mut(b).after = a,
+        }
+        match a {
+            None => self.linked.as_mut().unwrap().tail = b,
+            Some(a) => self.node_mut(a).before = b,
+        }
+    }
+
+    /// The first node in iteration order, or the last if `reversed`.
+    fn first_node(&self, reversed: bool) -> Option<usize> {
+        match &self.linked {
+            Some(linked) if reversed => linked.tail,
+            Some(linked) => linked.head,
+            None => self.first_from(0).0,
+        }
+    }
+
+    /// The eldest mapping of a `LinkedHashMap`, if it evicts any.
+    pub(crate) fn eldest(&self) -> Option<(RemoveEldestEntry<K, V>, K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let linked = self.linked.as_ref()?;
+        let remove_eldest_entry = linked.remove_eldest_entry.clone()?;
+        let node = self.node(linked.head?);
+        Some((remove_eldest_entry, node.key.clone(), node.value.clone()))
+    }
+
+    fn free_node(&mut self, i: usize) -> Node<K, V> {
+        self.free.push(i);
+        self.nodes[i].take().unwrap()
+    }
+
+    fn hash_of(&self, i: usize) -> i32 {
+        self.node(i).hash
+    }
+
+    fn next(&self, i: usize) -> Option<usize> {
+        self.node(i).next
+    }
+
+    fn set_next(&mut self, i: usize, next: Option<usize>) {
+        self.node_mut(i).next = next;
+    }
+
+    fn is_tree(&self, i: usize) -> bool {
+        self.node(i).tree.is_some()
+    }
+
+    fn links(&self, i: usize) -> &TreeLinks {
+        self.node(i).tree.as_ref().unwrap()
+    }
+
+    fn links_mut(&mut self, i: usize) -> &mut TreeLinks {
+        self.node_mut(i).tree.as_mut().unwrap()
+    }
+
+    fn parent(&self, i: usize) -> Option<usize> {
+        self.links(i).parent
+    }
+
+    fn left(&self, i: usize) -> Option<usize> {
+        self.links(i).left
+    }
+
+    fn right(&self, i: usize) -> Option<usize> {
+        self.links(i).right
+    }
+
+    fn prev(&self, i: usize) -> Option<usize> {
+        self.links(i).prev
+    }
+
+    fn red(&self, i: usize) -> bool {
+        self.links(i).red
+    }
+
+    fn is_red(&self, i: Option<usize>) -> bool {
+        i.is_some_and(|i| self.red(i))
+    }
+
+    fn set_parent(&mut self, i: usize, parent: Option<usize>) {
+        self.links_mut(i).parent = parent;
+    }
+
+    fn set_left(&mut self, i: usize, left: Option<usize>) {
+        self.links_mut(i).left = left;
+    }
+
+    fn set_right(&mut self, i: usize, right: Option<usize>) {
+        self.links_mut(i).right = right;
+    }
+
+    fn set_prev(&mut self, i: usize, prev: Option<usize>) {
+        self.links_mut(i).prev = prev;
+    }
+
+    fn set_red(&mut self, i: usize, red: bool) {
+        self.links_mut(i).red = red;
+    }
+
+    /// Replaces whichever child of `parent` is `child` by `replacement`.
+    fn replace_child(&mut self, parent: usize, child: usize, replacement: Option<usize>) {
+        if self.left(parent) == Some(child) {
+            self.set_left(parent, replacement);
+        } else {
+            self.set_right(parent, replacement);
+        }
+    }
+
+    /// The first node of the first non-empty bin from `index` on, and the index after it.
+    fn first_from(&self, mut index: usize) -> (Option<usize>, usize) {
+        while index < self.bins.len() {
+            index += 1;
+            if let Some(first) = self.bins[index - 1] {
+                return (Some(first), index);
+            }
+        }
+        (None, index)
+    }
+
+    /// The nodes in iteration order, which for a `LinkedHashMap` can be `reversed`.
+    fn node_indexes(&self, reversed: bool) -> Vec<usize> {
+        let mut indexes = Vec::with_capacity(self.size);
+        if self.linked.is_some() {
+            let mut e = self.first_node(reversed);
+            while let Some(i) = e {
+                indexes.push(i);
+                e = if reversed {
+                    self.node(i).before
+                } else {
+                    self.node(i).after
+                };
+            }
+            return indexes;
+        }
+        for &first in &self.bins {
+            let mut e = first;
+            while let Some(i) = e {
+                indexes.push(i);
+                e = self.next(i);
+            }
+        }
+        indexes
+    }
+
+    /// Initializes or doubles the table, splitting each bin into the nodes that stay at
+    /// its index and those that move up by the old capacity, in their order.
+    fn resize(&mut self) {
+        let old_cap = self.bins.len();
+        let old_thr = self.threshold;
+        let new_cap;
+        let mut new_thr = 0;
+        if old_cap > 0 {
+            if old_cap >= MAXIMUM_CAPACITY {
+                self.threshold = INTEGER_MAX_VALUE;
+                return;
+            }
+            new_cap = old_cap << 1;
+            if new_cap < MAXIMUM_CAPACITY && old_cap >= DEFAULT_INITIAL_CAPACITY {
+                new_thr = old_thr << 1;
+            }
+        } else if old_thr > 0 {
+            new_cap = old_thr;
+        } else {
+            new_cap = DEFAULT_INITIAL_CAPACITY;
+            new_thr = (DEFAULT_LOAD_FACTOR * DEFAULT_INITIAL_CAPACITY as f32) as usize;
+        }
+        if new_thr == 0 {
+            let ft = new_cap as f32 * self.load_factor;
+            new_thr = if new_cap < MAXIMUM_CAPACITY && ft < MAXIMUM_CAPACITY as f32 {
+                ft as usize
+            } else {
+                INTEGER_MAX_VALUE
+            };
+        }
+        self.threshold = new_thr;
+        let old_bins = std::mem::replace(&mut self.bins, vec![None; new_cap]);
+        for (j, first) in old_bins.into_iter().enumerate() {
+            let Some(first) = first else { continue };
+            if self.next(first).is_none() {
+                let index = index_for(self.hash_of(first), new_cap);
+                self.bins[index] = Some(first);
+            } else if self.is_tree(first) {
+                self.split(first, j, old_cap);
+            } else {
+                let (mut lo_head, mut lo_tail) = (None, None);
+                let (mut hi_head, mut hi_tail) = (None, None);
+                let mut e = Some(first);
+                while let Some(i) = e {
+                    e = self.next(i);
+                    let (head, tail) = if self.hash_of(i) as u32 as usize & old_cap == 0 {
+                        (&mut lo_head, &mut lo_tail)
+                    } else {
+                        (&mut hi_head, &mut hi_tail)
+                    };
+                    match *tail {
+                        None => *head = Some(i),
+                        Some(t) => self.node_mut(t).next = Some(i),
+                    }
+                    *tail = Some(i);
+                }
+                if let Some(tail) = lo_tail {
+                    self.set_next(tail, None);
+                    self.bins[j] = lo_head;
+                }
+                if let Some(tail) = hi_tail {
+                    self.set_next(tail, None);
+                    self.bins[j + old_cap] = hi_head;
+                }
+            }
+        }
+    }
+
+    /// Turns the bin of `hash` into a tree, or resizes a table too small for trees.
+    fn treeify_bin(&mut self, hash: i32) {
+        let n = self.bins.len();
+        if n < MIN_TREEIFY_CAPACITY {
+            self.resize();
+        } else if let Some(first) = self.bins[index_for(hash, n)] {
+            let mut tail = None;
+            let mut e = Some(first);
+            while let Some(i) = e {
+                self.node_mut(i).tree = Some(TreeLinks {
+                    prev: tail,
+                    ..TreeLinks::default()
+                });
+                tail = Some(i);
+                e = self.next(i);
+            }
+            self.treeify(first);
+        }
+    }
+
+    /// Builds a tree of the nodes linked from `head`, in their order, and moves its root
+    /// to the front of the bin.
+    fn treeify(&mut self, head: usize)
+    where
+        K: HashCode,
+    {
+        let mut root: Option<usize> = None;
+        let mut x = Some(head);
+        while let Some(xi) = x {
+            x = self.next(xi);
+            self.set_left(xi, None);
+            self.set_right(xi, None);
+            let Some(r) = root else {
+                self.set_parent(xi, None);
+                self.set_red(xi, false);
+                root = Some(xi);
+                continue;
+            };
+            let h = self.hash_of(xi);
+            let mut p = r;
+            loop {
+                let ph = self.hash_of(p);
+                let dir = if ph > h {
+                    Ordering::Less
+                } else if ph < h {
+                    Ordering::Greater
+                } else {
+                    self.node(xi)
+                        .key
+                        .comparable_order(&self.node(p).key)
+                        .filter(|dir| dir.is_ne())
+                        .unwrap_or_else(tie_break_order)
+                };
+                let child = if dir.is_le() {
+                    self.left(p)
+                } else {
+                    self.right(p)
+                };
+                match child {
+                    Some(child) => p = child,
+                    None => {
+                        self.set_parent(xi, Some(p));
+                        if dir.is_le() {
+                            self.set_left(p, Some(xi));
+                        } else {
+                            self.set_right(p, Some(xi));
+                        }
+                        root = Some(self.balance_insertion(r, xi));
+                        break;
+                    }
+                }
+            }
+        }
+        self.move_root_to_front(root.unwrap());
+    }
+
+    /// Turns the nodes linked from `head` back into plain ones.
+    fn untreeify(&mut self, head: usize) {
+        let mut e = Some(head);
+        while let Some(i) = e {
+            self.node_mut(i).tree = None;
+            e = self.next(i);
+        }
+    }
+
+    /// Splits a tree bin on resize like a list bin, keeping each half a tree only if it is
+    /// big enough.
+    fn split(&mut self, first: usize, index: usize, bit: usize)
+    where
+        K: HashCode,
+    {
+        let (mut lo_head, mut lo_tail, mut lc) = (None, None, 0);
+        let (mut hi_head, mut hi_tail, mut hc) = (None, None, 0);
+        let mut e = Some(first);
+        while let Some(i) = e {
+            e = self.next(i);
+            self.set_next(i, None);
+            let (head, tail, count) = if self.hash_of(i) as u32 as usize & bit == 0 {
+                (&mut lo_head, &mut lo_tail, &mut lc)
+            } else {
+                (&mut hi_head, &mut hi_tail, &mut hc)
+            };
+            let prev = *tail;
+            match prev {
+                None => *head = Some(i),
+                Some(t) => self.node_mut(t).next = Some(i),
+            }
+            *tail = Some(i);
+            *count += 1;
+            self.set_prev(i, prev);
+        }
+        if let Some(lo_head) = lo_head {
+            self.bins[index] = Some(lo_head);
+            if lc <= UNTREEIFY_THRESHOLD {
+                self.untreeify(lo_head);
+            } else if hi_head.is_some() {
+                self.treeify(lo_head);
+            }
+        }
+        if let Some(hi_head) = hi_head {
+            self.bins[index + bit] = Some(hi_head);
+            if hc <= UNTREEIFY_THRESHOLD {
+                self.untreeify(hi_head);
+            } else if lo_head.is_some() {
+                self.treeify(hi_head);
+            }
+        }
+    }
+
+    fn root(&self, mut i: usize) -> usize {
+        while let Some(parent) = self.parent(i) {
+            i = parent;
+        }
+        i
+    }
+
+    /// Makes the root of a tree the first node of its bin, which is where lookups start.
+    fn move_root_to_front(&mut self, root: usize) {
+        let index = index_for(self.hash_of(root), self.bins.len());
+        let first = self.bins[index];
+        if first != Some(root) {
+            self.bins[index] = Some(root);
+            let rp = self.prev(root);
+            let rn = self.next(root);
+            if let Some(rn) = rn {
+                self.set_prev(rn, rp);
+            }
+            if let Some(rp) = rp {
+                self.set_next(rp, rn);
+            }
+            if let Some(first) = first {
+                self.set_prev(first, Some(root));
+            }
+            self.set_next(root, first);
+            self.set_prev(root, None);
+        }
+    }
+
+    fn rotate_left(&mut self, mut root: usize, p: usize) -> usize {
+        if let Some(r) = self.right(p) {
+            let rl = self.left(r);
+            self.set_right(p, rl);
+            if let Some(rl) = rl {
+                self.set_parent(rl, Some(p));
+            }
+            let pp = self.parent(p);
+            self.set_parent(r, pp);
+            match pp {
+                None => {
+                    root = r;
+                    self.set_red(r, false);
+                }
+                Some(pp) => self.replace_child(pp, p, Some(r)),
+            }
+            self.set_left(r, Some(p));
+            self.set_parent(p, Some(r));
+        }
+        root
+    }
+
+    fn rotate_right(&mut self, mut root: usize, p: usize) -> usize {
+        if let Some(l) = self.left(p) {
+            let lr = self.right(l);
+            self.set_left(p, lr);
+            if let Some(lr) = lr {
+                self.set_parent(lr, Some(p));
+            }
+            let pp = self.parent(p);
+            self.set_parent(l, pp);
+            match pp {
+                None => {
+                    root = l;
+                    self.set_red(l, false);
+                }
+                Some(pp) => {
+                    if self.right(pp) == Some(p) {
+                        self.set_right(pp, Some(l));
+                    } else {
+                        self.set_left(pp, Some(l));
+                    }
+                }
+            }
+            self.set_right(l, Some(p));
+            self.set_parent(p, Some(l));
+        }
+        root
+    }
+
+    fn balance_insertion(&mut self, mut root: usize, mut x: usize) -> usize {
+        self.set_red(x, true);
+        loop {
+            let Some(xp) = self.parent(x) else {
+                self.set_red(x, false);
+                return x;
+            };
+            if !self.red(xp) {
+                return root;
+            }
+            let Some(xpp) = self.parent(xp) else {
+                return root;
+            };
+            let xppl = self.left(xpp);
+            if xppl == Some(xp) {
+                let xppr = self.right(xpp);
+                if let Some(xppr) = xppr.filter(|&i| self.red(i)) {
+                    self.set_red(xppr, false);
+                    self.set_red(xp, false);
+                    self.set_red(xpp, true);
+                    x = xpp;
+                } else {
+                    let (mut xp, mut xpp) = (Some(xp), Some(xpp));
+                    if self.right(xp.unwrap()) == Some(x) {
+                        x = xp.unwrap();
+                        root = self.rotate_left(root, x);
+                        xp = self.parent(x);
+                        xpp = xp.and_then(|xp| self.parent(xp));
+                    }
+                    if let Some(xp) = xp {
+                        self.set_red(xp, false);
+                        if let Some(xpp) = xpp {
+                            self.set_red(xpp, true);
+                            root = self.rotate_right(root, xpp);
+                        }
+                    }
+                }
+            } else if let Some(xppl) = xppl.filter(|&i| self.red(i)) {
+                self.set_red(xppl, false);
+                self.set_red(xp, false);
+                self.set_red(xpp, true);
+                x = xpp;
+            } else {
+                let (mut xp, mut xpp) = (Some(xp), Some(xpp));
+                if self.left(xp.unwrap()) == Some(x) {
+                    x = xp.unwrap();
+                    root = self.rotate_right(root, x);
+                    xp = self.parent(x);
+                    xpp = xp.and_then(|xp| self.parent(xp));
+                }
+                if let Some(xp) = xp {
+                    self.set_red(xp, false);
+                    if let Some(xpp) = xpp {
+                        self.set_red(xpp, true);
+                        root = self.rotate_left(root, xpp);
+                    }
+                }
+            }
+        }
+    }
+
+    fn balance_deletion(&mut self, mut root: usize, mut x: Option<usize>) -> usize {
+        loop {
+            let Some(xi) = x else { return root };
+            if xi == root {
+                return root;
+            }
+            let Some(xp) = self.parent(xi) else {
+                self.set_red(xi, false);
+                return xi;
+            };
+            if self.red(xi) {
+                self.set_red(xi, false);
+                return root;
+            }
+            let mut xp = Some(xp);
+            if self.left(xp.unwrap()) == Some(xi) {
+                let mut xpr = self.right(xp.unwrap());
+                if let Some(r) = xpr.filter(|&i| self.red(i)) {
+                    self.set_red(r, false);
+                    self.set_red(xp.unwrap(), true);
+                    root = self.rotate_left(root, xp.unwrap());
+                    xp = self.parent(xi);
+                    xpr = xp.and_then(|xp| self.right(xp));
+                }
+                match xpr {
+                    None => x = xp,
+                    Some(s) => {
+                        let (sl, sr) = (self.left(s), self.right(s));
+                        if !self.is_red(sr) && !self.is_red(sl) {
+                            self.set_red(s, true);
+                            x = xp;
+                        } else {
+                            if !self.is_red(sr) {
+                                if let Some(sl) = sl {
+                                    self.set_red(sl, false);
+                                }
+                                self.set_red(s, true);
+                                root = self.rotate_right(root, s);
+                                xp = self.parent(xi);
+                                xpr = xp.and_then(|xp| self.right(xp));
+                            }
+                            if let Some(s) = xpr {
+                                self.set_red(s, self.is_red(xp));
+                                if let Some(sr) = self.right(s) {
+                                    self.set_red(sr, false);
+                                }
+                            }
+                            if let Some(xp) = xp {
+                                self.set_red(xp, false);
+                                root = self.rotate_left(root, xp);
+                            }
+                            x = Some(root);
+                        }
+                    }
+                }
+            } else {
+                let mut xpl = self.left(xp.unwrap());
+                if let Some(l) = xpl.filter(|&i| self.red(i)) {
+                    self.set_red(l, false);
+                    self.set_red(xp.unwrap(), true);
+                    root = self.rotate_right(root, xp.unwrap());
+                    xp = self.parent(xi);
+                    xpl = xp.and_then(|xp| self.left(xp));
+                }
+                match xpl {
+                    None => x = xp,
+                    Some(s) => {
+                        let (sl, sr) = (self.left(s), self.right(s));
+                        if !self.is_red(sl) && !self.is_red(sr) {
+                            self.set_red(s, true);
+                            x = xp;
+                        } else {
+                            if !self.is_red(sl) {
+                                if let Some(sr) = sr {
+                                    self.set_red(sr, false);
+                                }
+                                self.set_red(s, true);
+                                root = self.rotate_left(root, s);
+                                xp = self.parent(xi);
+                                xpl = xp.and_then(|xp| self.left(xp));
+                            }
+                            if let Some(s) = xpl {
+                                self.set_red(s, self.is_red(xp));
+                                if let Some(sl) = self.left(s) {
+                                    self.set_red(sl, false);
+                                }
+                            }
+                            if let Some(xp) = xp {
+                                self.set_red(xp, false);
+                                root = self.rotate_right(root, xp);
+                            }
+                            x = Some(root);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Unlinks a node from its tree bin. Unless `movable`, which only removal through an
+    /// iterator isn't, the root is then moved to the front, and a tree that has become too
+    /// small is turned back into a list.
+    fn remove_tree_node(&mut self, node: usize, movable: bool) {
+        let index = index_for(self.hash_of(node), self.bins.len());
+        let mut root = self.bins[index].unwrap();
+        let succ = self.next(node);
+        let pred = self.prev(node);
+        let first = match pred {
+            None => {
+                self.bins[index] = succ;
+                succ
+            }
+            Some(pred) => {
+                self.set_next(pred, succ);
+                self.bins[index]
+            }
+        };
+        if let Some(succ) = succ {
+            self.set_prev(succ, pred);
+        }
+        let Some(first) = first else { return };
+        if self.parent(root).is_some() {
+            root = self.root(root);
+        }
+        if movable
+            && (self.right(root).is_none()
+                || self.left(root).is_none_or(|rl| self.left(rl).is_none()))
+        {
+            self.untreeify(first);
+            return;
+        }
+        let p = node;
+        let (pl, pr) = (self.left(p), self.right(p));
+        let replacement = match (pl, pr) {
+            (Some(pl), Some(pr)) => {
+                let mut s = pr;
+                while let Some(sl) = self.left(s) {
+                    s = sl;
+                }
+                let c = self.red(s);
+                self.set_red(s, self.red(p));
+                self.set_red(p, c);
+                let sr = self.right(s);
+                let pp = self.parent(p);
+                if s == pr {
+                    self.set_parent(p, Some(s));
+                    self.set_right(s, Some(p));
+                } else {
+                    let sp = self.parent(s);
+                    self.set_parent(p, sp);
+                    if let Some(sp) = sp {
+                        if self.left(sp) == Some(s) {
+                            self.set_left(sp, Some(p));
+                        } else {
+                            self.set_right(sp, Some(p));
+                        }
+                    }
+                    self.set_right(s, Some(pr));
+                    self.set_parent(pr, Some(s));
+                }
+                self.set_left(p, None);
+                self.set_right(p, sr);
+                if let Some(sr) = sr {
+                    self.set_parent(sr, Some(p));
+                }
+                self.set_left(s, Some(pl));
+                self.set_parent(pl, Some(s));
+                self.set_parent(s, pp);
+                match pp {
+                    None => root = s,
+                    Some(pp) => self.replace_child(pp, p, Some(s)),
+                }
+                sr.unwrap_or(p)
+            }
+            (Some(pl), None) => pl,
+            (None, Some(pr)) => pr,
+            (None, None) => p,
+        };
+        if replacement != p {
+            let pp = self.parent(p);
+            self.set_parent(replacement, pp);
+            match pp {
+                None => {
+                    root = replacement;
+                    self.set_red(replacement, false);
+                }
+                Some(pp) => self.replace_child(pp, p, Some(replacement)),
+            }
+            self.set_left(p, None);
+            self.set_right(p, None);
+            self.set_parent(p, None);
+        }
+        let r = if self.red(p) {
+            root
+        } else {
+            self.balance_deletion(root, Some(replacement))
+        };
+        if replacement == p {
+            let pp = self.parent(p);
+            self.set_parent(p, None);
+            if let Some(pp) = pp {
+                if self.left(pp) == Some(p) {
+                    self.set_left(pp, None);
+                } else if self.right(pp) == Some(p) {
+                    self.set_right(pp, None);
+                }
+            }
+        }
+        if movable {
+            self.move_root_to_front(r);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.mod_count += 1;
+        if self.size > 0 {
+            self.size = 0;
+            self.bins.fill(None);
+            self.nodes.clear();
+            self.free.clear();
+        }
+        if let Some(linked) = &mut self.linked {
+            linked.head = None;
+            linked.tail = None;
+        }
+    }
+}
+
+impl<K: HashCode + PartialEq, V> Table<K, V> {
+    fn matches(&self, i: usize, hash: i32, key: &K) -> bool {
+        let node = self.node(i);
+        node.hash == hash && node.key == *key
+    }
+
+    fn get_node(&self, hash: i32, key: &K) -> Option<usize> {
+        if self.bins.is_empty() {
+            return None;
+        }
+        let first = self.bins[index_for(hash, self.bins.len())]?;
+        if self.matches(first, hash, key) {
+            return Some(first);
+        }
+        let mut e = self.next(first)?;
+        if self.is_tree(first) {
+            return self.get_tree_node(first, hash, key);
+        }
+        loop {
+            if self.matches(e, hash, key) {
+                return Some(e);
+            }
+            e = self.next(e)?;
+        }
+    }
+
+    fn get_tree_node(&self, first: usize, hash: i32, key: &K) -> Option<usize> {
+        let root = if self.parent(first).is_some() {
+            self.root(first)
+        } else {
+            first
+        };
+        self.find(root, hash, key)
+    }
+
+    /// Finds a key in the subtree of `p`, searching both sides where the order of keys
+    /// with equal hashes doesn't tell.
+    fn find(&self, mut p: usize, h: i32, k: &K) -> Option<usize> {
+        loop {
+            let (pl, pr) = (self.left(p), self.right(p));
+            let ph = self.hash_of(p);
+            let next = if ph > h {
+                pl
+            } else if ph < h {
+                pr
+            } else if self.node(p).key == *k {
+                return Some(p);
+            } else {
+                match (pl, pr) {
+                    (None, _) => pr,
+                    (_, None) => pl,
+                    (Some(_), Some(right)) => match k.comparable_order(&self.node(p).key) {
+                        Some(Ordering::Less) => pl,
+                        Some(Ordering::Greater) => pr,
+                        _ => match self.find(right, h, k) {
+                            Some(q) => return Some(q),
+                            None => pl,
+                        },
+                    },
+                }
+            };
+            p = next?;
+        }
+    }
+
+    /// The search half of Java's `putTreeVal`.
+    fn find_tree_slot(&self, first: usize, h: i32, k: &K) -> TreeSlot {
+        let root = if self.parent(first).is_some() {
+            self.root(first)
+        } else {
+            first
+        };
+        let mut searched = false;
+        let mut p = root;
+        loop {
+            let ph = self.hash_of(p);
+            let dir = if ph > h {
+                Ordering::Less
+            } else if ph < h {
+                Ordering::Greater
+            } else if self.node(p).key == *k {
+                return TreeSlot::Found(p);
+            } else if let Some(dir) = k
+                .comparable_order(&self.node(p).key)
+                .filter(|dir| dir.is_ne())
+            {
+                dir
+            } else {
+                if !searched {
+                    searched = true;
+                    let found = self
+                        .left(p)
+                        .and_then(|child| self.find(child, h, k))
+                        .or_else(|| self.right(p).and_then(|child| self.find(child, h, k)));
+                    if let Some(q) = found {
+                        return TreeSlot::Found(q);
+                    }
+                }
+                tie_break_order()
+            };
+            let child = if dir.is_le() {
+                self.left(p)
+            } else {
+                self.right(p)
+            };
+            match child {
+                Some(child) => p = child,
+                None => {
+                    return TreeSlot::Vacant {
+                        root,
+                        parent: p,
+                        left: dir.is_le(),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Java's `putTreeVal` for a key that isn't in the tree: the new node follows its
+    /// parent in the bin, and the root is moved to the front.
+    fn put_tree_val(&mut self, first: usize, hash: i32, key: K, value: V) {
+        let TreeSlot::Vacant { root, parent, left } = self.find_tree_slot(first, hash, &key) else {
+            unreachable!()
+        };
+        let x = self.new_node(hash, key, value, true);
+        let xpn = self.next(parent);
+        self.set_next(x, xpn);
+        if left {
+            self.set_left(parent, Some(x));
+        } else {
+            self.set_right(parent, Some(x));
+        }
+        self.set_next(parent, Some(x));
+        self.set_parent(x, Some(parent));
+        self.set_prev(x, Some(parent));
+        if let Some(xpn) = xpn {
+            self.set_prev(xpn, Some(x));
+        }
+        let root = self.balance_insertion(root, x);
+        self.move_root_to_front(root);
+    }
+
+    /// Java's `putVal`: a new key is appended to its bin, which is turned into a tree if
+    /// it gets too long, and the table is resized once it holds more than the threshold.
+    fn put_val(&mut self, hash: i32, key: K, value: V, only_if_absent: bool) -> Option<V>
+    where
+        V: Clone,
+    {
+        if self.bins.is_empty() {
+            self.resize();
+        }
+        let i = index_for(hash, self.bins.len());
+        match self.bins[i] {
+            None => {
+                let x = self.new_node(hash, key, value, false);
+                self.bins[i] = Some(x);
+            }
+            Some(p) if self.matches(p, hash, &key) => {
+                return Some(self.replace_value(p, value, only_if_absent));
+            }
+            Some(p) if self.is_tree(p) => {
+                if let TreeSlot::Found(e) = self.find_tree_slot(p, hash, &key) {
+                    return Some(self.replace_value(e, value, only_if_absent));
+                }
+                self.put_tree_val(p, hash, key, value);
+            }
+            Some(mut p) => {
+                let mut bin_count = 0;
+                loop {
+                    match self.next(p) {
+                        None => {
+                            let x = self.new_node(hash, key, value, false);
+                            self.set_next(p, Some(x));
+                            if bin_count >= TREEIFY_THRESHOLD - 1 {
+                                self.treeify_bin(hash);
+                            }
+                            break;
+                        }
+                        Some(e) if self.matches(e, hash, &key) => {
+                            return Some(self.replace_value(e, value, only_if_absent));
+                        }
+                        Some(e) => p = e,
+                    }
+                    bin_count += 1;
+                }
+            }
+        }
+        self.mod_count += 1;
+        self.size += 1;
+        if self.size > self.threshold {
+            self.resize();
+        }
+        None
+    }
+
+    fn replace_value(&mut self, e: usize, value: V, only_if_absent: bool) -> V
+    where
+        V: Clone,
+    {
+        let node = self.node_mut(e);
+        let old_value = if only_if_absent {
+            node.value.clone()
+        } else {
+            std::mem::replace(&mut node.value, value)
+        };
+        self.after_node_access(e);
+        old_value
+    }
+
+    /// Sizes the table for `s` more mappings, like Java's `putMapEntries` does before
+    /// putting them.
+    fn presize(&mut self, s: usize) {
+        if s == 0 {
+            return;
+        }
+        if self.bins.is_empty() {
+            let ft = s as f32 / self.load_factor + 1.0;
+            let t = if ft < MAXIMUM_CAPACITY as f32 {
+                ft as usize
+            } else {
+                MAXIMUM_CAPACITY
+            };
+            if t > self.threshold {
+                self.threshold = table_size_for(t);
+            }
+        } else {
+            while s > self.threshold && self.bins.len() < MAXIMUM_CAPACITY {
+                self.resize();
+            }
+        }
+    }
+
+    /// Java's `putMapEntries` without eviction, as a constructor or `clone` does it.
+    pub(crate) fn put_map_entries(&mut self, entries: Vec<(K, V)>)
+    where
+        V: Clone,
+    {
+        self.presize(entries.len());
+        for (key, value) in entries {
+            self.put_val(hash(&key), key, value, false);
+        }
+    }
+
+    /// Java's `removeNode`: removes the key's node if its value matches.
+    fn remove_node(
+        &mut self,
+        hash: i32,
+        key: &K,
+        matches_value: impl FnOnce(&V) -> bool,
+        movable: bool,
+    ) -> Option<(K, V)> {
+        if self.bins.is_empty() {
+            return None;
+        }
+        let index = index_for(hash, self.bins.len());
+        let mut p = self.bins[index]?;
+        let node = if self.matches(p, hash, key) {
+            p
+        } else {
+            let mut e = self.next(p)?;
+            if self.is_tree(p) {
+                self.get_tree_node(p, hash, key)?
+            } else {
+                loop {
+                    if self.matches(e, hash, key) {
+                        break e;
+                    }
+                    p = e;
+                    e = self.next(e)?;
+                }
+            }
+        };
+        if !matches_value(&self.node(node).value) {
+            return None;
+        }
+        if self.is_tree(node) {
+            self.remove_tree_node(node, movable);
+        } else if node == p {
+            self.bins[index] = self.next(node);
+        } else {
+            let next = self.next(node);
+            self.set_next(p, next);
+        }
+        self.mod_count += 1;
+        self.size -= 1;
+        self.after_node_removal(node);
+        let node = self.free_node(node);
+        Some((node.key, node.value))
+    }
+
+    /// The lookup `compute`, `compute_if_absent` and `merge` do before calling their
+    /// function, which resizes a full table first since a new key goes in directly.
+    fn compute_slot(&mut self, hash: i32, key: &K) -> ComputeSlot {
+        if self.size > self.threshold || self.bins.is_empty() {
+            self.resize();
+        }
+        let index = index_for(hash, self.bins.len());
+        let first = self.bins[index];
+        let mut old = None;
+        let mut bin_count = 0;
+        if let Some(first) = first {
+            if self.is_tree(first) {
+                old = self.get_tree_node(first, hash, key);
+            } else {
+                let mut e = Some(first);
+                while let Some(i) = e {
+                    if self.matches(i, hash, key) {
+                        old = Some(i);
+                        break;
+                    }
+                    bin_count += 1;
+                    e = self.next(i);
+                }
+            }
+        }
+        ComputeSlot {
+            index,
+            first,
+            old,
+            bin_count,
+        }
+    }
+
+    /// Puts a key that `compute_slot` didn't find. Unlike `put_val`, this puts it at the
+    /// front of a list bin.
+    fn put_computed(&mut self, slot: &ComputeSlot, hash: i32, key: K, value: V) {
+        match slot.first {
+            Some(first) if self.is_tree(first) => self.put_tree_val(first, hash, key, value),
+            _ => {
+                let x = self.new_node(hash, key, value, false);
+                self.set_next(x, slot.first);
+                self.bins[slot.index] = Some(x);
+                if slot.bin_count >= TREEIFY_THRESHOLD - 1 {
+                    self.treeify_bin(hash);
+                }
+            }
+        }
+        self.size += 1;
+    }
+}
+
+/// `java.util.HashMap`: a map that places its keys in a table by their Java `hashCode`.
+///
+/// The table is the JDK's: its capacity is a power of two, doubled once the size exceeds
+/// the load factor's share of it; a key's bin is picked by the lower bits of
+/// `h ^ (h >>> 16)` of its hash code; new keys are appended to their bin; a resize splits
+/// each bin in order; and a bin of more than 8 keys in a table of at least 64 bins becomes
+/// a tree. With keys that implement `HashCode` like their Java classes, iteration order and
+/// `to_string` are therefore the same as the JDK's.
+///
+/// Like Java's, the map is shared with its views and their iterators, and a structural
+/// modification (one that adds or removes a mapping) other than through an iterator makes
+/// the iterator throw `ConcurrentModificationException`. `clone` copies the mappings into
+/// a new map.
+pub struct HashMap<K, V> {
+    pub(crate) table: Rc<RefCell<Table<K, V>>>,
+    /// Whether this is the `reversed` view of a `LinkedHashMap`.
+    pub(crate) reversed: bool,
+}
+
+impl<K, V> HashMap<K, V> {
+    fn table(&self) -> Ref<'_, Table<K, V>> {
+        self.table.borrow()
+    }
+
+    fn table_mut(&self) -> RefMut<'_, Table<K, V>> {
+        self.table.borrow_mut()
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> HashMap<K, V> {
+    /// An empty map with the default capacity of 16 and load factor of 0.75.
+    pub fn new() -> Self {
+        Self::from_table(Table::new(0, DEFAULT_LOAD_FACTOR))
+    }
+
+    /// An empty map whose table will have room for `initial_capacity` keys, rounded up to
+    /// a power of two.
+    pub fn with_capacity(initial_capacity: usize) -> Self {
+        Self::with_capacity_and_load_factor(initial_capacity, DEFAULT_LOAD_FACTOR)
+    }
+
+    /// Throws `IllegalArgumentException` if the load factor isn't positive.
+    pub fn with_capacity_and_load_factor(initial_capacity: usize, load_factor: f32) -> Self {
+        if load_factor.is_nan() || load_factor <= 0.0 {
+            IllegalArgumentException::new(
+                format!(
+                    "Illegal load factor: {}",
+                    floating_decimal::float_to_string(load_factor)
+                ),
+                None,
+            )
+            .throw()
+        }
+        let initial_capacity = initial_capacity.min(MAXIMUM_CAPACITY);
+        Self::from_table(Table::new(table_size_for(initial_capacity), load_factor))
+    }
+
+    fn from_table(table: Table<K, V>) -> Self {
+        Self {
+            table: Rc::new(RefCell::new(table)),
+            reversed: false,
+        }
+    }
+
+    /// Java's `new HashMap<>(m)`: a map of the mappings of another, in its iteration order.
+    pub fn from_map<M: Map<K, V>>(m: &M) -> Self {
+        let map = Self::new();
+        map.table_mut().put_map_entries(m.iterator().collect());
+        map
+    }
+
+    pub(crate) fn entries(&self) -> Vec<(K, V)> {
+        let table = self.table();
+        table
+            .node_indexes(self.reversed)
+            .into_iter()
+            .map(|i| {
+                let node = table.node(i);
+                (node.key.clone(), node.value.clone())
+            })
+            .collect()
+    }
+
+    fn check_mod_count(&self, expected_mod_count: usize) {
+        if self.table().mod_count != expected_mod_count {
+            concurrent_modification();
+        }
+    }
+
+    /// Java's `afterNodeInsertion`: lets a `LinkedHashMap` evict its eldest mapping.
+    fn after_node_insertion(&self) {
+        let Some((remove_eldest_entry, key, value)) = self.table().eldest() else {
+            return;
+        };
+        let map = LinkedHashMap {
+            map: HashMap {
+                table: Rc::clone(&self.table),
+                reversed: false,
+            },
+        };
+        if remove_eldest_entry(&map, &key, &value) {
+            self.table_mut()
+                .remove_node(hash(&key), &key, |_| true, true);
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Map<K, V> for HashMap<K, V> {
+    type Iter = EntryIterator<K, V>;
+    type KeySet = KeySet<K, V>;
+    type Values = Values<K, V>;
+    type EntrySet = EntrySet<K, V>;
+
+    fn size(&self) -> usize {
+        self.table().size
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.table().get_node(hash(key), key).is_some()
+    }
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        let table = self.table();
+        table
+            .node_indexes(false)
+            .into_iter()
+            .any(|i| table.node(i).value == *value)
+    }
+
+    /// In an access-ordered `LinkedHashMap`, this moves the mapping to the end.
+    fn get(&self, key: &K) -> Option<V> {
+        let mut table = self.table_mut();
+        let node = table.get_node(hash(key), key)?;
+        table.after_node_access(node);
+        Some(table.node(node).value.clone())
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        let old_value = self.table_mut().put_val(hash(&key), key, value, false);
+        if old_value.is_none() {
+            self.after_node_insertion();
+        }
+        old_value
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.table_mut()
+            .remove_node(hash(key), key, |_| true, true)
+            .map(|(_, value)| value)
+    }
+
+    fn put_all<M: Map<K, V>>(&mut self, m: &M) {
+        let entries: Vec<(K, V)> = m.iterator().collect();
+        self.table_mut().presize(entries.len());
+        for (key, value) in entries {
+            self.put(key, value);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.table_mut().clear();
+    }
+
+    fn iterator(&self) -> EntryIterator<K, V> {
+        EntryIterator(HashIterator::new(&self.table, self.reversed))
+    }
+
+    fn key_set(&self) -> KeySet<K, V> {
+        KeySet {
+            table: Rc::clone(&self.table),
+            reversed: self.reversed,
+        }
+    }
+
+    fn values(&self) -> Values<K, V> {
+        Values {
+            table: Rc::clone(&self.table),
+            reversed: self.reversed,
+        }
+    }
+
+    fn entry_set(&self) -> EntrySet<K, V> {
+        EntrySet {
+            table: Rc::clone(&self.table),
+            reversed: self.reversed,
+        }
+    }
+
+    fn get_or_default(&self, key: &K, default_value: V) -> V {
+        self.get(key).unwrap_or(default_value)
+    }
+
+    /// Calls the action on each mapping, throwing `ConcurrentModificationException`
+    /// afterwards if it changed the map structurally.
+    fn for_each<F: FnMut(&K, &V)>(&self, mut action: F) {
+        let mod_count = self.table().mod_count;
+        for (key, value) in self.entries() {
+            action(&key, &value);
+        }
+        self.check_mod_count(mod_count);
+    }
+
+    fn replace_all<F: FnMut(&K, &V) -> V>(&mut self, mut function: F) {
+        let mod_count = self.table().mod_count;
+        let indexes = self.table().node_indexes(self.reversed);
+        for i in indexes {
+            if self.table().mod_count != mod_count {
+                break;
+            }
+            let (key, value) = {
+                let table = self.table();
+                let node = table.node(i);
+                (node.key.clone(), node.value.clone())
+            };
+            let value = function(&key, &value);
+            if self.table().mod_count == mod_count {
+                self.table_mut().node_mut(i).value = value;
+            }
+        }
+        self.check_mod_count(mod_count);
+    }
+
+    fn put_if_absent(&mut self, key: K, value: V) -> Option<V> {
+        let current = self.table_mut().put_val(hash(&key), key, value, true);
+        if current.is_none() {
+            self.after_node_insertion();
+        }
+        current
+    }
+
+    fn remove_entry(&mut self, key: &K, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.table_mut()
+            .remove_node(hash(key), key, |v| v == value, true)
+            .is_some()
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<V> {
+        let mut table = self.table_mut();
+        let node = table.get_node(hash(&key), &key)?;
+        let old_value = std::mem::replace(&mut table.node_mut(node).value, value);
+        table.after_node_access(node);
+        Some(old_value)
+    }
+
+    fn replace_entry(&mut self, key: K, old_value: &V, new_value: V) -> bool
+    where
+        V: PartialEq,
+    {
+        let mut table = self.table_mut();
+        match table.get_node(hash(&key), &key) {
+            Some(node) if table.node(node).value == *old_value => {
+                table.node_mut(node).value = new_value;
+                table.after_node_access(node);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Like Java's, a new key goes at the front of its bin, and the function throws
+    /// `ConcurrentModificationException` if it changes the map structurally.
+    fn compute_if_absent<F: FnOnce(&K) -> V>(&mut self, key: K, mapping_function: F) -> V
+    where
+        V: Clone,
+    {
+        let hash = hash(&key);
+        let (slot, mod_count) = {
+            let mut table = self.table_mut();
+            let slot = table.compute_slot(hash, &key);
+            if let Some(old) = slot.old {
+                table.after_node_access(old);
+                return table.node(old).value.clone();
+            }
+            (slot, table.mod_count)
+        };
+        let value = mapping_function(&key);
+        {
+            let mut table = self.table_mut();
+            if table.mod_count != mod_count {
+                concurrent_modification();
+            }
+            table.put_computed(&slot, hash, key, value.clone());
+            table.mod_count = mod_count + 1;
+        }
+        self.after_node_insertion();
+        value
+    }
+
+    fn compute_if_present<F: FnOnce(&K, &V) -> Option<V>>(
+        &mut self,
+        key: &K,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let hash = hash(key);
+        let (node, old_value, mod_count) = {
+            let table = self.table();
+            let node = table.get_node(hash, key)?;
+            (node, table.node(node).value.clone(), table.mod_count)
+        };
+        let value = remapping_function(key, &old_value);
+        let mut table = self.table_mut();
+        if table.mod_count != mod_count {
+            concurrent_modification();
+        }
+        match value {
+            Some(value) => {
+                table.node_mut(node).value = value.clone();
+                table.after_node_access(node);
+                Some(value)
+            }
+            None => {
+                table.remove_node(hash, key, |_| true, true);
+                None
+            }
+        }
+    }
+
+    /// Like Java's, a new key goes at the front of its bin, and the function throws
+    /// `ConcurrentModificationException` if it changes the map structurally.
+    fn compute<F: FnOnce(&K, Option<&V>) -> Option<V>>(
+        &mut self,
+        key: K,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        V: Clone,
+    {
+        let hash = hash(&key);
+        let (slot, old_value, mod_count) = {
+            let mut table = self.table_mut();
+            let slot = table.compute_slot(hash, &key);
+            let old_value = slot.old.map(|old| table.node(old).value.clone());
+            (slot, old_value, table.mod_count)
+        };
+        let value = remapping_function(&key, old_value.as_ref());
+        let mut table = self.table_mut();
+        if table.mod_count != mod_count {
+            concurrent_modification();
+        }
+        match (slot.old, value) {
+            (Some(old), Some(value)) => {
+                table.node_mut(old).value = value.clone();
+                table.after_node_access(old);
+                Some(value)
+            }
+            (Some(_), None) => {
+                table.remove_node(hash, &key, |_| true, true);
+                None
+            }
+            (None, Some(value)) => {
+                table.put_computed(&slot, hash, key, value.clone());
+                table.mod_count = mod_count + 1;
+                drop(table);
+                self.after_node_insertion();
+                Some(value)
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Like Java's, a new key goes at the front of its bin, and the function throws
+    /// `ConcurrentModificationException` if it changes the map structurally.
+    fn merge<F: FnOnce(&V, &V) -> Option<V>>(
+        &mut self,
+        key: K,
+        value: V,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        V: Clone,
+    {
+        let hash = hash(&key);
+        let (slot, old_value, mod_count) = {
+            let mut table = self.table_mut();
+            let slot = table.compute_slot(hash, &key);
+            let old_value = slot.old.map(|old| table.node(old).value.clone());
+            (slot, old_value, table.mod_count)
+        };
+        let Some(old_value) = old_value else {
+            {
+                let mut table = self.table_mut();
+                table.put_computed(&slot, hash, key, value.clone());
+                table.mod_count += 1;
+            }
+            self.after_node_insertion();
+            return Some(value);
+        };
+        let value = remapping_function(&old_value, &value);
+        let mut table = self.table_mut();
+        if table.mod_count != mod_count {
+            concurrent_modification();
+        }
+        match value {
+            Some(value) => {
+                let old = slot.old.unwrap();
+                table.node_mut(old).value = value.clone();
+                table.after_node_access(old);
+                Some(value)
+            }
+            None => {
+                table.remove_node(hash, &key, |_| true, true);
+                None
+            }
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Default for HashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Clone for HashMap<K, V> {
+    /// Like Java's `clone`, a map with the same load factor sized for the mappings, which
+    /// can therefore have a smaller table than this one.
+    fn clone(&self) -> Self {
+        let mut table = Table::new(0, self.table().load_factor);
+        if let Some(linked) = &self.table().linked {
+            table.linked = Some(Linked {
+                remove_eldest_entry: linked.remove_eldest_entry.clone(),
+                ..Linked::new(linked.access_order)
+            });
+        }
+        let map = Self::from_table(table);
+        map.table_mut().put_map_entries(self.entries());
+        map
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> FromIterator<(K, V)> for HashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Extend<(K, V)> for HashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.put(key, value);
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> IntoIterator for &HashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = EntryIterator<K, V>;
+
+    fn into_iter(self) -> EntryIterator<K, V> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the mappings as `key=value` in braces, separated by `, `.
+impl<K, V> std::fmt::Display for HashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Display,
+    V: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (i, (key, value)) in self.entries().iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{key}={value}")?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl<K, V> std::fmt::Debug for HashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Debug,
+    V: Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two maps with the same mappings are equal.
+impl<K, V, M> PartialEq<M> for HashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq,
+    V: Clone + PartialEq,
+    M: Map<K, V>,
+{
+    fn eq(&self, other: &M) -> bool {
+        self.size() == other.size()
+            && self
+                .entries()
+                .into_iter()
+                .all(|(key, value)| other.get(&key) == Some(value))
+    }
+}
+
+/// Java's `Map.hashCode`: the sum of the hash codes of the keys xor'ed with their values'.
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> HashCode for HashMap<K, V> {
+    fn hash_code(&self) -> i32 {
+        self.entries().iter().fold(0i32, |hash, (key, value)| {
+            hash.wrapping_add(key.hash_code() ^ value.hash_code())
+        })
+    }
+}
+
+/// Java's `HashIterator`, which walks the table bin by bin, or its `LinkedHashIterator`,
+/// which follows the order of a `LinkedHashMap`.
+struct HashIterator<K, V> {
+    table: Rc<RefCell<Table<K, V>>>,
+    next: Option<usize>,
+    current: Option<usize>,
+    index: usize,
+    expected_mod_count: usize,
+    reversed: bool,
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> HashIterator<K, V> {
+    fn new(table: &Rc<RefCell<Table<K, V>>>, reversed: bool) -> Self {
+        let (next, index, expected_mod_count) = {
+            let table = table.borrow();
+            let (next, index) = if table.linked.is_some() {
+                (table.first_node(reversed), 0)
+            } else if table.size > 0 {
+                table.first_from(0)
+            } else {
+                (None, 0)
+            };
+            (next, index, table.mod_count)
+        };
+        Self {
+            table: Rc::clone(table),
+            next,
+            current: None,
+            index,
+            expected_mod_count,
+            reversed,
+        }
+    }
+
+    fn next_node<R>(&mut self, f: impl FnOnce(&Node<K, V>) -> R) -> Option<R> {
+        let e = self.next?;
+        let table = self.table.borrow();
+        if table.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        self.current = Some(e);
+        if table.linked.is_some() {
+            let node = table.node(e);
+            self.next = if self.reversed {
+                node.before
+            } else {
+                node.after
+            };
+        } else {
+            self.next = table.next(e);
+            if self.next.is_none() {
+                (self.next, self.index) = table.first_from(self.index);
+            }
+        }
+        Some(f(table.node(e)))
+    }
+
+    fn remove(&mut self) {
+        let Some(current) = self.current.take() else {
+            IllegalStateException::new(String::new(), None).throw()
+        };
+        let mut table = self.table.borrow_mut();
+        if table.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        let node = table.node(current);
+        let (hash, key) = (node.hash, node.key.clone());
+        table.remove_node(hash, &key, |_| true, false);
+        self.expected_mod_count = table.mod_count;
+    }
+}
+
+macro_rules! hash_iterator {
+    ($(#[$attr: meta])* $type: ident, $item: ty, $node: ident => $value: expr) => {
+        $(#[$attr])*
+        ///
+        /// As a Rust iterator it is Java's `hasNext` and `next`: a structural modification
+        /// of the map other than through the iterator makes the next call throw
+        /// `ConcurrentModificationException`.
+        pub struct $type<K, V>(HashIterator<K, V>);
+
+        impl<K: Clone + HashCode + PartialEq, V: Clone> $type<K, V> {
+            pub fn has_next(&self) -> bool {
+                self.0.next.is_some()
+            }
+
+            /// Removes the mapping last returned by `next`. Throws `IllegalStateException`
+            /// if there is none.
+            pub fn remove(&mut self) {
+                self.0.remove()
+            }
+        }
+
+        impl<K: Clone + HashCode + PartialEq, V: Clone> Iterator for $type<K, V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                self.0.next_node(|$node| $value)
+            }
+        }
+    };
+}
+
+hash_iterator!(
+    /// Iterates over the mappings of a `HashMap`.
+    EntryIterator, (K, V), node => (node.key.clone(), node.value.clone())
+);
+hash_iterator!(
+    /// Iterates over the keys of a `HashMap` or the elements of a `HashSet`.
+    KeyIterator, K, node => node.key.clone()
+);
+hash_iterator!(
+    /// Iterates over the values of a `HashMap`.
+    ValueIterator, V, node => node.value.clone()
+);
+
+/// The keys of a `HashMap`, returned by `Map::key_set`. Removing a key removes its
+/// mapping; adding one throws `UnsupportedOperationException`.
+pub struct KeySet<K, V> {
+    table: Rc<RefCell<Table<K, V>>>,
+    reversed: bool,
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Collection<K> for KeySet<K, V> {
+    type Iter = KeyIterator<K, V>;
+
+    fn size(&self) -> usize {
+        self.table.borrow().size
+    }
+
+    fn contains(&self, o: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.table.borrow().get_node(hash(o), o).is_some()
+    }
+
+    fn iterator(&self) -> KeyIterator<K, V> {
+        KeyIterator(HashIterator::new(&self.table, self.reversed))
+    }
+
+    fn add(&mut self, _: K) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.table
+            .borrow_mut()
+            .remove_node(hash(o), o, |_| true, true)
+            .is_some()
+    }
+
+    fn remove_all<C: Collection<K>>(&mut self, c: &C) -> bool
+    where
+        K: PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&K) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, KeyIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.table.borrow_mut().clear();
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Set<K> for KeySet<K, V> {}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> IntoIterator for &KeySet<K, V> {
+    type Item = K;
+    type IntoIter = KeyIterator<K, V>;
+
+    fn into_iter(self) -> KeyIterator<K, V> {
+        self.iterator()
+    }
+}
+
+impl<K, V> std::fmt::Display for KeySet<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Display,
+    V: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, key| write!(f, "{key}"))
+    }
+}
+
+/// The values of a `HashMap`, returned by `Map::values`. Removing a value removes the
+/// first mapping to it; adding one throws `UnsupportedOperationException`.
+pub struct Values<K, V> {
+    table: Rc<RefCell<Table<K, V>>>,
+    reversed: bool,
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Collection<V> for Values<K, V> {
+    type Iter = ValueIterator<K, V>;
+
+    fn size(&self) -> usize {
+        self.table.borrow().size
+    }
+
+    fn contains(&self, o: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.iterator().any(|value| value == *o)
+    }
+
+    fn iterator(&self) -> ValueIterator<K, V> {
+        ValueIterator(HashIterator::new(&self.table, self.reversed))
+    }
+
+    fn add(&mut self, _: V) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        let mut each = self.iterator();
+        while let Some(value) = each.next() {
+            if value == *o {
+                each.remove();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn remove_if<F: FnMut(&V) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, ValueIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.table.borrow_mut().clear();
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> IntoIterator for &Values<K, V> {
+    type Item = V;
+    type IntoIter = ValueIterator<K, V>;
+
+    fn into_iter(self) -> ValueIterator<K, V> {
+        self.iterator()
+    }
+}
+
+impl<K, V> std::fmt::Display for Values<K, V>
+where
+    K: Clone + HashCode + PartialEq,
+    V: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, value| write!(f, "{value}"))
+    }
+}
+
+/// The mappings of a `HashMap` as `(key, value)` pairs, returned by `Map::entry_set`.
+/// Removing a pair removes the mapping; adding one throws
+/// `UnsupportedOperationException`.
+pub struct EntrySet<K, V> {
+    table: Rc<RefCell<Table<K, V>>>,
+    reversed: bool,
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Collection<(K, V)> for EntrySet<K, V> {
+    type Iter = EntryIterator<K, V>;
+
+    fn size(&self) -> usize {
+        self.table.borrow().size
+    }
+
+    fn contains(&self, o: &(K, V)) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        let table = self.table.borrow();
+        table.get_node(hash(&o.0), &o.0).is_some_and(|i| {
+            let node = table.node(i);
+            (node.key.clone(), node.value.clone()) == *o
+        })
+    }
+
+    fn iterator(&self) -> EntryIterator<K, V> {
+        EntryIterator(HashIterator::new(&self.table, self.reversed))
+    }
+
+    fn add(&mut self, _: (K, V)) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &(K, V)) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        let key = &o.0;
+        self.table
+            .borrow_mut()
+            .remove_node(hash(key), key, |v| (key.clone(), v.clone()) == *o, true)
+            .is_some()
+    }
+
+    fn remove_all<C: Collection<(K, V)>>(&mut self, c: &C) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&(K, V)) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, EntryIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.table.borrow_mut().clear();
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Set<(K, V)> for EntrySet<K, V> {}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> IntoIterator for &EntrySet<K, V> {
+    type Item = (K, V);
+    type IntoIter = EntryIterator<K, V>;
+
+    fn into_iter(self) -> EntryIterator<K, V> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`, with each mapping as `key=value`.
+impl<K, V> std::fmt::Display for EntrySet<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Display,
+    V: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, (key, value)| {
+            write!(f, "{key}={value}")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn iteration_order() {
+        let mut map = HashMap::new();
+        for key in ["one", "two", "three", "four", "five", "six", "seven"] {
+            map.put(key, key.len() as i32);
+        }
+        assert_eq!(
+            map.to_string(),
+            "{six=3, four=4, one=3, seven=5, two=3, three=5, five=4}"
+        );
+        assert_eq!(map.hash_code(), 226301712);
+        assert_eq!(
+            map.key_set().to_string(),
+            "[six, four, one, seven, two, three, five]"
+        );
+        assert_eq!(map.values().to_string(), "[3, 4, 3, 5, 3, 5, 4]");
+        assert_eq!(
+            map.entry_set().to_string(),
+            "[six=3, four=4, one=3, seven=5, two=3, three=5, five=4]"
+        );
+
+        let map: HashMap<i32, i32> = (0..13).map(|i| (i * 7, i)).collect();
+        assert_eq!(
+            map.key_set().to_string(),
+            "[0, 35, 70, 7, 42, 77, 14, 49, 84, 21, 56, 28, 63]"
+        );
+    }
+
+    #[test]
+    fn tree_bins() {
+        let keys: Vec<String> = (0..16)
+            .rev()
+            .map(|i| {
+                (0..4)
+                    .rev()
+                    .map(|j| if i >> j & 1 == 0 { "Aa" } else { "BB" })
+                    .collect()
+            })
+            .collect();
+        let mut map = HashMap::with_capacity(64);
+        for (i, key) in keys.iter().take(12).enumerate() {
+            map.put(key.clone(), i);
+        }
+        assert_eq!(
+            map.key_set().to_string(),
+            "[BBBBAaAa, BBBBBBBB, BBBBBBAa, BBBBAaBB, BBAaBBBB, BBAaBBAa, BBAaAaBB, \
+             BBAaAaAa, AaBBBBBB, AaBBBBAa, AaBBAaBB, AaBBAaAa]"
+        );
+        for key in ["BBBBBBBB", "BBAaBBAa", "BBBBAaBB"] {
+            map.remove(&key.to_string());
+        }
+        assert_eq!(
+            map.key_set().to_string(),
+            "[BBAaAaAa, BBBBAaAa, BBBBBBAa, BBAaBBBB, BBAaAaBB, AaBBBBBB, AaBBBBAa, \
+             AaBBAaBB, AaBBAaAa]"
+        );
+        assert_eq!(map.get(&"AaBBAaBB".to_string()), Some(10));
+    }
+
+    #[test]
+    fn compute() {
+        let mut map = HashMap::new();
+        for key in ["Aa", "BB", "AaAa", "BBBB", "AaBB", "BBAa"] {
+            map.compute_if_absent(key, |key| key.len());
+        }
+        assert_eq!(
+            map.to_string(),
+            "{BB=2, Aa=2, BBAa=4, AaBB=4, BBBB=4, AaAa=4}"
+        );
+        assert_eq!(map.merge("Aa", 3, |a, b| Some(a + b)), Some(5));
+        assert_eq!(map.merge("BB", 3, |_, _| None), None);
+        assert_eq!(
+            map.compute_if_present(&"AaAa", |_, v| Some(v * 10)),
+            Some(40)
+        );
+        assert_eq!(map.compute("Ab", |_, v| v.map(|v| v + 1)), None);
+        assert_eq!(map.to_string(), "{Aa=5, BBAa=4, AaBB=4, BBBB=4, AaAa=40}");
+
+        let copy = map.clone();
+        let mut keys = map.key_set();
+        assert_eq!(
+            message(|| map.compute_if_absent("Ab", |_| keys.remove(&"Aa") as usize)),
+            ""
+        );
+        assert!(!(copy == map));
+        assert_eq!(copy.get(&"Aa"), Some(5));
+    }
+
+    #[test]
+    fn iterators_fail_fast() {
+        let mut map: HashMap<i32, i32> = (0..4).map(|i| (i, i)).collect();
+        let mut iterator = map.key_set().iterator();
+        assert_eq!(message(|| iterator.remove()), "");
+        iterator.next();
+        iterator.remove();
+        assert!(!map.contains_key(&0));
+        map.put(8, 8);
+        assert_eq!(message(|| iterator.next()), "");
+        assert!(map.values().remove(&8));
+        assert_eq!(map.to_string(), "{1=1, 2=2, 3=3}");
+        assert_eq!(
+            message(|| HashMap::<i32, i32>::with_capacity_and_load_factor(4, 0.0)),
+            "Illegal load factor: 0.0"
+        );
+    }
+}
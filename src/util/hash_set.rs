@@ -0,0 +1,184 @@
+use crate::util::collection;
+use crate::util::hash_map::KeyIterator;
+use crate::util::{Collection, HashMap, Map, Set};
+use crate::HashCode;
+
+/// `java.util.HashSet`: a set backed by a `HashMap`, so that it iterates in the JDK's order
+/// for elements that implement `HashCode` like their Java classes.
+///
+/// Like Java's, the set is shared with its iterators, which throw
+/// `ConcurrentModificationException` after a structural modification other than through
+/// them. `clone` copies the elements into a new set.
+pub struct HashSet<E> {
+    map: HashMap<E, ()>,
+}
+
+impl<E: Clone + HashCode + PartialEq> HashSet<E> {
+    /// An empty set with the default capacity of 16 and load factor of 0.75.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+
+    pub fn with_capacity(initial_capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(initial_capacity),
+        }
+    }
+
+    /// Throws `IllegalArgumentException` if the load factor isn't positive.
+    pub fn with_capacity_and_load_factor(initial_capacity: usize, load_factor: f32) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_load_factor(initial_capacity, load_factor),
+        }
+    }
+
+    /// Java's `new HashSet<>(c)`: a set of the elements of a collection, with room for a
+    /// third more of them and at least 16.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        let mut set = Self::with_capacity(((c.size() as f32 / 0.75) as usize + 1).max(16));
+        set.add_all(c);
+        set
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Collection<E> for HashSet<E> {
+    type Iter = KeyIterator<E, ()>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.map.contains_key(o)
+    }
+
+    fn iterator(&self) -> KeyIterator<E, ()> {
+        self.map.key_set().iterator()
+    }
+
+    fn add(&mut self, e: E) -> bool {
+        self.map.put(e, ()).is_none()
+    }
+
+    fn remove(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.map.remove(o).is_some()
+    }
+
+    fn remove_all<C: Collection<E>>(&mut self, c: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, filter: F) -> bool {
+        self.map.key_set().remove_if(filter)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Set<E> for HashSet<E> {}
+
+impl<E: Clone + HashCode + PartialEq> Default for HashSet<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Clone for HashSet<E> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> FromIterator<E> for HashSet<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Extend<E> for HashSet<E> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for e in iter {
+            self.add(e);
+        }
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> IntoIterator for &HashSet<E> {
+    type Item = E;
+    type IntoIter = KeyIterator<E, ()>;
+
+    fn into_iter(self) -> KeyIterator<E, ()> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the elements in brackets, separated by `, `.
+impl<E: Clone + HashCode + PartialEq + std::fmt::Display> std::fmt::Display for HashSet<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map.key_set().fmt(f)
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq + std::fmt::Debug> std::fmt::Debug for HashSet<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iterator()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two sets with the same elements are equal.
+impl<E: Clone + HashCode + PartialEq, S: Set<E>> PartialEq<S> for HashSet<E> {
+    fn eq(&self, other: &S) -> bool {
+        self.size() == other.size() && self.contains_all(other)
+    }
+}
+
+/// Java's `Set.hashCode`: the sum of the hash codes of the elements.
+impl<E: Clone + HashCode + PartialEq> HashCode for HashSet<E> {
+    fn hash_code(&self) -> i32 {
+        self.iterator()
+            .fold(0i32, |hash, e| hash.wrapping_add(e.hash_code()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iteration_order() {
+        let set: HashSet<&str> = ["banana", "apple", "cherry", "date", "elderberry", "fig"]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            set.to_string(),
+            "[banana, date, apple, cherry, fig, elderberry]"
+        );
+        let numbers: HashSet<i32> = [100, 33, 17, 3, 64, -1].into_iter().collect();
+        assert_eq!(numbers.to_string(), "[64, -1, 33, 17, 3, 100]");
+    }
+
+    #[test]
+    fn from_collection() {
+        let source: HashSet<i32> = (0..13).rev().collect();
+        let copy = HashSet::from_collection(&source);
+        assert!(copy == source);
+        assert_eq!(copy.hash_code(), 78);
+    }
+}
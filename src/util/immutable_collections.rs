@@ -0,0 +1,1035 @@
+//! The immutable collections of Java 9's `List.of`, `Set.of` and `Map.of` and their
+//! `copyOf`s, which reject `null`, throw `UnsupportedOperationException` on any change,
+//! and lay out their sets and maps like the JDK's.
+//!
+//! Java starts iterating over those sets and maps at a point picked by a salt that is
+//! random for each run of the JVM, so that no one relies on their order. The salt here is
+//! random for each run too, unless set for a thread by `with_salt`: with the salt of a
+//! Java run, read from `ImmutableCollections.SALT32L`, they iterate in that run's order.
+//!
+//! `None` is Java's `null`, as it is for `HashCode`.
+
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+use crate::errors::{
+    IllegalArgumentException, NullPointerException, Throwable, UnsupportedOperationException,
+};
+use crate::util::abstract_list::{list_impls, Kind, ListView};
+use crate::util::arrays::DeepToString;
+use crate::util::collection::{self, unsupported};
+use crate::util::random::nano_time;
+use crate::util::{Collection, HashSet, Map, Set};
+use crate::HashCode;
+
+/// How much larger than the number of elements the tables of sets and maps are.
+const EXPAND_FACTOR: usize = 2;
+
+static SALT: OnceLock<u32> = OnceLock::new();
+
+thread_local! {
+    static SALT_OVERRIDE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+fn salt() -> u32 {
+    SALT_OVERRIDE.get().unwrap_or_else(|| {
+        *SALT.get_or_init(|| {
+            let color = 0x243F_6A88_85A3_08D3_i64;
+            (color.wrapping_mul(nano_time()) >> 16) as u32
+        })
+    })
+}
+
+/// Runs `f` with `salt` as the salt of the sets and maps it iterates over on this thread,
+/// so that they iterate in the same order as Java's with `SALT32L` set to it.
+pub fn with_salt<R>(salt: u32, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<u32>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SALT_OVERRIDE.set(self.0);
+        }
+    }
+
+    let _restore = Restore(SALT_OVERRIDE.replace(Some(salt)));
+    f()
+}
+
+/// Where an iterator over a table of `len` slots starts, and which way it goes.
+fn start(len: usize) -> (usize, bool) {
+    let salt = salt();
+    (((salt as u64 * len as u64) >> 32) as usize, salt & 1 == 0)
+}
+
+fn require_non_null<T: HashCode>(o: &T) {
+    if o.is_null() {
+        NullPointerException::new(String::new()).throw()
+    }
+}
+
+/// The message of the `NullPointerException` the JVM throws when a method is called on
+/// `null`.
+fn null_receiver<T: HashCode>(o: &T, method: &str, variable: &str) {
+    if o.is_null() {
+        NullPointerException::new(format!(
+            "Cannot invoke \"Object.{method}\" because \"{variable}\" is null"
+        ))
+        .throw()
+    }
+}
+
+/// `Iterator.remove`, which Java's views of the maps remove through.
+fn unsupported_remove() -> ! {
+    UnsupportedOperationException::new("remove".to_string(), None).throw()
+}
+
+/// `Map.entry`: the mapping of a key to a value. Throws `NullPointerException` if either
+/// is `None`.
+pub fn entry<K: HashCode, V: HashCode>(k: K, v: V) -> (K, V) {
+    require_non_null(&k);
+    require_non_null(&v);
+    (k, v)
+}
+
+/// The list of `List.of` and `List.copyOf`. Its sublists and iterators can't change it
+/// either, and searching it for `None` throws `NullPointerException`. `clone` returns the
+/// same list.
+pub struct ImmutableList<E> {
+    view: ListView<E>,
+}
+
+impl<E: Clone + HashCode> ImmutableList<E> {
+    /// `List.of`: the list of the elements, in order. Throws `NullPointerException` if one
+    /// is `None`.
+    pub fn of<I: IntoIterator<Item = E>>(elements: I) -> Self {
+        let elements: VecDeque<E> = elements.into_iter().collect();
+        elements.iter().for_each(require_non_null);
+        let kind = match elements.len() {
+            1 | 2 => Kind::ImmutablePair,
+            _ => Kind::Immutable,
+        };
+        Self {
+            view: ListView::new(kind, elements)
+                .read_only()
+                .rejecting_null(E::is_null),
+        }
+    }
+
+    /// `List.copyOf`: the list of the elements of a collection, in its order.
+    pub fn copy_of<C: Collection<E>>(c: &C) -> Self {
+        Self::of(c.to_array())
+    }
+}
+
+impl<E: Clone> ImmutableList<E> {
+    /// The list of `Stream.toList`, which is always a `ListN` and may hold `None`.
+    pub(crate) fn allowing_nulls(elements: Vec<E>) -> Self {
+        Self {
+            view: ListView::new(Kind::Immutable, elements.into()).read_only(),
+        }
+    }
+}
+
+impl<E> Clone for ImmutableList<E> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+        }
+    }
+}
+
+list_impls!(ImmutableList);
+
+/// The elements of an `ImmutableSet`.
+enum Elements<E> {
+    /// `Set12`: one or two elements.
+    Pair(E, Option<E>),
+    /// `SetN`: a table twice as long as the number of elements, in which an element is in
+    /// the first free slot from its hash.
+    Table(Box<[Option<E>]>, usize),
+}
+
+impl<E: HashCode + PartialEq> Elements<E> {
+    /// The slot of the element, or the slot it would go in.
+    fn probe(table: &[Option<E>], pe: &E) -> Result<usize, usize> {
+        null_receiver(pe, "hashCode()", "pe");
+        let mut idx = pe.hash_code().rem_euclid(table.len() as i32) as usize;
+        loop {
+            match &table[idx] {
+                None => return Err(idx),
+                Some(ee) if pe == ee => return Ok(idx),
+                Some(_) => idx = (idx + 1) % table.len(),
+            }
+        }
+    }
+}
+
+/// The set of `Set.of` and `Set.copyOf`, which iterates in the order Java's does for the
+/// same salt. Searching it for `None` throws `NullPointerException`. `clone` returns the
+/// same set.
+pub struct ImmutableSet<E> {
+    elements: Rc<Elements<E>>,
+}
+
+impl<E: Clone + HashCode + PartialEq + DeepToString> ImmutableSet<E> {
+    /// `Set.of`: the set of the elements. Throws `NullPointerException` if one is `None`,
+    /// and `IllegalArgumentException` if two are equal.
+    pub fn of<I: IntoIterator<Item = E>>(elements: I) -> Self {
+        let duplicate = |e: &E| -> ! {
+            let mut message = "duplicate element: ".to_string();
+            e.deep_to_string(&mut message);
+            IllegalArgumentException::new(message, None).throw()
+        };
+        let mut elements: Vec<E> = elements.into_iter().collect();
+        let elements = match elements.len() {
+            1 => {
+                let e0 = elements.pop().unwrap();
+                require_non_null(&e0);
+                Elements::Pair(e0, None)
+            }
+            2 => {
+                let e1 = elements.pop().unwrap();
+                let e0 = elements.pop().unwrap();
+                require_non_null(&e1);
+                null_receiver(&e0, "equals(Object)", "e0");
+                if e0 == e1 {
+                    duplicate(&e0)
+                }
+                Elements::Pair(e0, Some(e1))
+            }
+            size => {
+                let mut table: Box<[Option<E>]> = (0..EXPAND_FACTOR * size).map(|_| None).collect();
+                for e in elements {
+                    match Elements::probe(&table, &e) {
+                        Ok(_) => duplicate(&e),
+                        Err(idx) => table[idx] = Some(e),
+                    }
+                }
+                Elements::Table(table, size)
+            }
+        };
+        Self {
+            elements: Rc::new(elements),
+        }
+    }
+
+    /// `Set.copyOf`: the set of the elements of a collection, of which it may have
+    /// duplicates. Throws `NullPointerException` if one is `None`.
+    pub fn copy_of<C: Collection<E>>(c: &C) -> Self {
+        Self::of(HashSet::from_collection(c).to_array())
+    }
+}
+
+impl<E> Clone for ImmutableSet<E> {
+    fn clone(&self) -> Self {
+        Self {
+            elements: Rc::clone(&self.elements),
+        }
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Collection<E> for ImmutableSet<E> {
+    type Iter = SetIterator<E>;
+
+    fn size(&self) -> usize {
+        match &*self.elements {
+            Elements::Pair(_, e1) => 1 + e1.is_some() as usize,
+            Elements::Table(_, size) => *size,
+        }
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        match &*self.elements {
+            Elements::Pair(e0, e1) => {
+                null_receiver(o, "equals(Object)", "o");
+                o == e0 || e1.as_ref() == Some(o)
+            }
+            Elements::Table(table, size) => {
+                require_non_null(o);
+                *size > 0 && Elements::probe(table, o).is_ok()
+            }
+        }
+    }
+
+    fn iterator(&self) -> SetIterator<E> {
+        let (idx, reverse) = match &*self.elements {
+            Elements::Pair(_, e1) => (1 + e1.is_some() as usize, salt() & 1 == 0),
+            Elements::Table(table, _) => start(table.len()),
+        };
+        SetIterator {
+            elements: Rc::clone(&self.elements),
+            idx,
+            remaining: self.size(),
+            reverse,
+        }
+    }
+
+    fn add(&mut self, _: E) -> bool {
+        unsupported()
+    }
+
+    fn remove(&mut self, _: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        unsupported()
+    }
+
+    fn add_all<C: Collection<E>>(&mut self, _: &C) -> bool {
+        unsupported()
+    }
+
+    fn remove_all<C: Collection<E>>(&mut self, _: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        unsupported()
+    }
+
+    fn retain_all<C: Collection<E>>(&mut self, _: &C) -> bool
+    where
+        E: PartialEq,
+    {
+        unsupported()
+    }
+
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, _: F) -> bool {
+        unsupported()
+    }
+
+    fn clear(&mut self) {
+        unsupported()
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq> Set<E> for ImmutableSet<E> {}
+
+impl<E: Clone + HashCode + PartialEq> IntoIterator for &ImmutableSet<E> {
+    type Item = E;
+    type IntoIter = SetIterator<E>;
+
+    fn into_iter(self) -> SetIterator<E> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the elements in brackets, separated by `, `.
+impl<E: Clone + HashCode + PartialEq + std::fmt::Display> std::fmt::Display for ImmutableSet<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, e| write!(f, "{e}"))
+    }
+}
+
+impl<E: Clone + HashCode + PartialEq + std::fmt::Debug> std::fmt::Debug for ImmutableSet<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iterator()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two sets with the same elements are equal.
+impl<E: Clone + HashCode + PartialEq, S: Set<E>> PartialEq<S> for ImmutableSet<E> {
+    fn eq(&self, other: &S) -> bool {
+        self.size() == other.size() && self.contains_all(other)
+    }
+}
+
+/// Java's `Set.hashCode`: the sum of the hash codes of the elements.
+impl<E: Clone + HashCode + PartialEq> HashCode for ImmutableSet<E> {
+    fn hash_code(&self) -> i32 {
+        self.iterator()
+            .fold(0i32, |hash, e| hash.wrapping_add(e.hash_code()))
+    }
+}
+
+/// Iterates over an `ImmutableSet`, from the slot picked by the salt.
+pub struct SetIterator<E> {
+    elements: Rc<Elements<E>>,
+    idx: usize,
+    remaining: usize,
+    reverse: bool,
+}
+
+impl<E: Clone> SetIterator<E> {
+    pub fn has_next(&self) -> bool {
+        self.remaining > 0
+    }
+}
+
+impl<E: Clone> Iterator for SetIterator<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        if !self.has_next() {
+            return None;
+        }
+        self.remaining -= 1;
+        match &*self.elements {
+            Elements::Pair(e0, e1) => {
+                let second = self.idx == 2;
+                self.idx -= 1;
+                // Without a salt that reverses it, the order is the order given.
+                match (second, self.reverse, e1) {
+                    (true, true, Some(e1)) | (false, false, Some(e1)) => Some(e1.clone()),
+                    _ => Some(e0.clone()),
+                }
+            }
+            Elements::Table(table, _) => loop {
+                self.idx = if self.reverse {
+                    (self.idx + 1) % table.len()
+                } else {
+                    self.idx.checked_sub(1).unwrap_or(table.len() - 1)
+                };
+                if let Some(e) = &table[self.idx] {
+                    return Some(e.clone());
+                }
+            },
+        }
+    }
+}
+
+/// The mappings of an `ImmutableMap`.
+enum Mappings<K, V> {
+    /// `Map1`: a single mapping.
+    Single(K, V),
+    /// `MapN`: a table twice as long as the number of mappings, in which a mapping is in
+    /// the first free slot from its key's hash.
+    Table(Box<[Option<(K, V)>]>, usize),
+}
+
+impl<K: HashCode + PartialEq, V> Mappings<K, V> {
+    /// The slot of the key, or the slot it would go in.
+    fn probe(table: &[Option<(K, V)>], pk: &K) -> Result<usize, usize> {
+        null_receiver(pk, "hashCode()", "pk");
+        let mut idx = pk.hash_code().rem_euclid(table.len() as i32) as usize;
+        loop {
+            match &table[idx] {
+                None => return Err(idx),
+                Some((ek, _)) if pk == ek => return Ok(idx),
+                Some(_) => idx = (idx + 1) % table.len(),
+            }
+        }
+    }
+}
+
+/// The map of `Map.of`, `Map.ofEntries` and `Map.copyOf`, which iterates in the order
+/// Java's does for the same salt. Looking up `None` throws `NullPointerException`, and
+/// its views throw `UnsupportedOperationException` on any change. `clone` returns the same
+/// map.
+pub struct ImmutableMap<K, V> {
+    mappings: Rc<Mappings<K, V>>,
+}
+
+impl<K, V> ImmutableMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + DeepToString,
+    V: Clone + HashCode,
+{
+    /// `Map.of` and `Map.ofEntries`: the map of the mappings. Throws
+    /// `NullPointerException` if a key or value is `None`, and `IllegalArgumentException`
+    /// if two keys are equal.
+    pub fn of<I: IntoIterator<Item = (K, V)>>(mappings: I) -> Self {
+        let mut mappings: Vec<(K, V)> = mappings.into_iter().collect();
+        let mappings = if mappings.len() == 1 {
+            let (k, v) = mappings.pop().unwrap();
+            require_non_null(&k);
+            require_non_null(&v);
+            Mappings::Single(k, v)
+        } else {
+            let size = mappings.len();
+            let mut table: Box<[Option<(K, V)>]> =
+                (0..EXPAND_FACTOR * size).map(|_| None).collect();
+            for (k, v) in mappings {
+                require_non_null(&k);
+                require_non_null(&v);
+                match Mappings::probe(&table, &k) {
+                    Ok(_) => {
+                        let mut message = "duplicate key: ".to_string();
+                        k.deep_to_string(&mut message);
+                        IllegalArgumentException::new(message, None).throw()
+                    }
+                    Err(idx) => table[idx] = Some((k, v)),
+                }
+            }
+            Mappings::Table(table, size)
+        };
+        Self {
+            mappings: Rc::new(mappings),
+        }
+    }
+
+    /// `Map.copyOf`: the map of the mappings of another. Throws `NullPointerException` if a
+    /// key or value is `None`.
+    pub fn copy_of<M: Map<K, V>>(m: &M) -> Self {
+        Self::of(m.iterator().collect::<Vec<_>>())
+    }
+}
+
+impl<K, V> Clone for ImmutableMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            mappings: Rc::clone(&self.mappings),
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> Map<K, V> for ImmutableMap<K, V> {
+    type Iter = MapIterator<K, V>;
+    type KeySet = KeySet<K, V>;
+    type Values = Values<K, V>;
+    type EntrySet = EntrySet<K, V>;
+
+    fn size(&self) -> usize {
+        match &*self.mappings {
+            Mappings::Single(..) => 1,
+            Mappings::Table(_, size) => *size,
+        }
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        match &*self.mappings {
+            Mappings::Single(k0, _) => {
+                null_receiver(key, "equals(Object)", "o");
+                key == k0
+            }
+            Mappings::Table(table, size) => {
+                require_non_null(key);
+                *size > 0 && Mappings::probe(table, key).is_ok()
+            }
+        }
+    }
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        match &*self.mappings {
+            Mappings::Single(_, v0) => {
+                null_receiver(value, "equals(Object)", "o");
+                value == v0
+            }
+            Mappings::Table(table, _) => {
+                require_non_null(value);
+                table.iter().flatten().any(|(_, v)| v == value)
+            }
+        }
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        match &*self.mappings {
+            Mappings::Single(k0, v0) => {
+                null_receiver(key, "equals(Object)", "o");
+                (key == k0).then(|| v0.clone())
+            }
+            Mappings::Table(_, 0) => {
+                require_non_null(key);
+                None
+            }
+            Mappings::Table(table, _) => Mappings::probe(table, key)
+                .ok()
+                .and_then(|idx| table[idx].as_ref())
+                .map(|(_, v)| v.clone()),
+        }
+    }
+
+    fn put(&mut self, _: K, _: V) -> Option<V> {
+        unsupported()
+    }
+
+    fn remove(&mut self, _: &K) -> Option<V> {
+        unsupported()
+    }
+
+    fn put_all<M: Map<K, V>>(&mut self, _: &M) {
+        unsupported()
+    }
+
+    fn clear(&mut self) {
+        unsupported()
+    }
+
+    fn iterator(&self) -> MapIterator<K, V> {
+        let (idx, reverse) = match &*self.mappings {
+            Mappings::Single(..) => (0, false),
+            Mappings::Table(table, _) => start(table.len()),
+        };
+        MapIterator {
+            mappings: Rc::clone(&self.mappings),
+            idx,
+            remaining: self.size(),
+            reverse,
+        }
+    }
+
+    fn key_set(&self) -> KeySet<K, V> {
+        KeySet { map: self.clone() }
+    }
+
+    fn values(&self) -> Values<K, V> {
+        Values { map: self.clone() }
+    }
+
+    fn entry_set(&self) -> EntrySet<K, V> {
+        EntrySet { map: self.clone() }
+    }
+
+    fn replace_all<F: FnMut(&K, &V) -> V>(&mut self, _: F) {
+        unsupported()
+    }
+
+    fn put_if_absent(&mut self, _: K, _: V) -> Option<V> {
+        unsupported()
+    }
+
+    fn remove_entry(&mut self, _: &K, _: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        unsupported()
+    }
+
+    fn replace(&mut self, _: K, _: V) -> Option<V> {
+        unsupported()
+    }
+
+    fn replace_entry(&mut self, _: K, _: &V, _: V) -> bool
+    where
+        V: PartialEq,
+    {
+        unsupported()
+    }
+
+    fn compute_if_absent<F: FnOnce(&K) -> V>(&mut self, _: K, _: F) -> V
+    where
+        V: Clone,
+    {
+        unsupported()
+    }
+
+    fn compute_if_present<F: FnOnce(&K, &V) -> Option<V>>(&mut self, _: &K, _: F) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        unsupported()
+    }
+
+    fn compute<F: FnOnce(&K, Option<&V>) -> Option<V>>(&mut self, _: K, _: F) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsupported()
+    }
+
+    fn merge<F: FnOnce(&V, &V) -> Option<V>>(&mut self, _: K, _: V, _: F) -> Option<V>
+    where
+        V: Clone,
+    {
+        unsupported()
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> IntoIterator for &ImmutableMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = MapIterator<K, V>;
+
+    fn into_iter(self) -> MapIterator<K, V> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the mappings as `key=value` in braces, separated by `, `.
+impl<K, V> std::fmt::Display for ImmutableMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Display,
+    V: Clone + HashCode + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (i, (key, value)) in self.iterator().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{key}={value}")?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl<K, V> std::fmt::Debug for ImmutableMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Debug,
+    V: Clone + HashCode + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iterator()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two maps with the same mappings are equal.
+impl<K, V, M> PartialEq<M> for ImmutableMap<K, V>
+where
+    K: Clone + HashCode + PartialEq,
+    V: Clone + HashCode + PartialEq,
+    M: Map<K, V>,
+{
+    fn eq(&self, other: &M) -> bool {
+        self.size() == other.size()
+            && self
+                .iterator()
+                .all(|(key, value)| other.get(&key) == Some(value))
+    }
+}
+
+/// Java's `Map.hashCode`: the sum of the hash codes of the keys xor'ed with their values'.
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> HashCode for ImmutableMap<K, V> {
+    fn hash_code(&self) -> i32 {
+        self.iterator().fold(0i32, |hash, (key, value)| {
+            hash.wrapping_add(key.hash_code() ^ value.hash_code())
+        })
+    }
+}
+
+/// Iterates over the mappings of an `ImmutableMap`, from the slot picked by the salt.
+pub struct MapIterator<K, V> {
+    mappings: Rc<Mappings<K, V>>,
+    idx: usize,
+    remaining: usize,
+    reverse: bool,
+}
+
+impl<K: Clone, V: Clone> MapIterator<K, V> {
+    pub fn has_next(&self) -> bool {
+        self.remaining > 0
+    }
+
+    /// Throws `UnsupportedOperationException`, like `Iterator.remove` does by default.
+    pub fn remove(&mut self) {
+        unsupported_remove()
+    }
+}
+
+impl<K: Clone, V: Clone> Iterator for MapIterator<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        if !self.has_next() {
+            return None;
+        }
+        self.remaining -= 1;
+        match &*self.mappings {
+            Mappings::Single(k, v) => Some((k.clone(), v.clone())),
+            Mappings::Table(table, _) => loop {
+                self.idx = if self.reverse {
+                    (self.idx + 1) % table.len()
+                } else {
+                    self.idx.checked_sub(1).unwrap_or(table.len() - 1)
+                };
+                if let Some(mapping) = &table[self.idx] {
+                    return Some(mapping.clone());
+                }
+            },
+        }
+    }
+}
+
+/// Implements `Collection` for a view of an `ImmutableMap`, like Java's `AbstractMap`
+/// views: removing an element that is there throws `UnsupportedOperationException`, but
+/// removing one that isn't does nothing.
+macro_rules! view_impls {
+    ($view: ident, $element: ty, $iter: ident, $wrap: expr, $contains: expr) => {
+        impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> Collection<$element>
+            for $view<K, V>
+        {
+            type Iter = $iter<K, V>;
+
+            fn size(&self) -> usize {
+                self.map.size()
+            }
+
+            fn contains(&self, o: &$element) -> bool
+            where
+                $element: PartialEq,
+            {
+                $contains(&self.map, o)
+            }
+
+            fn iterator(&self) -> $iter<K, V> {
+                $wrap(self.map.iterator())
+            }
+
+            fn add(&mut self, _: $element) -> bool {
+                unsupported()
+            }
+
+            fn remove(&mut self, o: &$element) -> bool
+            where
+                $element: PartialEq,
+            {
+                self.remove_if(|e| e == o)
+            }
+
+            fn remove_if<F: FnMut(&$element) -> bool>(&mut self, filter: F) -> bool {
+                self.check_removable();
+                collection::remove_if(self.iterator(), filter, |_| unsupported_remove())
+            }
+
+            fn clear(&mut self) {
+                unsupported()
+            }
+        }
+
+        impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> IntoIterator for &$view<K, V> {
+            type Item = $element;
+            type IntoIter = $iter<K, V>;
+
+            fn into_iter(self) -> $iter<K, V> {
+                self.iterator()
+            }
+        }
+    };
+}
+
+/// The keys of an `ImmutableMap`, returned by `Map::key_set`.
+pub struct KeySet<K, V> {
+    map: ImmutableMap<K, V>,
+}
+
+/// The values of an `ImmutableMap`, returned by `Map::values`.
+pub struct Values<K, V> {
+    map: ImmutableMap<K, V>,
+}
+
+/// The mappings of an `ImmutableMap`, returned by `Map::entry_set`.
+pub struct EntrySet<K, V> {
+    map: ImmutableMap<K, V>,
+}
+
+view_impls!(
+    KeySet,
+    K,
+    KeyIterator,
+    KeyIterator,
+    |map: &ImmutableMap<K, V>, o| { map.contains_key(o) }
+);
+view_impls!(
+    Values,
+    V,
+    ValueIterator,
+    ValueIterator,
+    |map: &ImmutableMap<K, V>, o| { map.contains_value(o) }
+);
+view_impls!(
+    EntrySet,
+    (K, V),
+    MapIterator,
+    |each| each,
+    |map: &ImmutableMap<K, V>, o: &(K, V)| {
+        map.get(&o.0).map(|value| (o.0.clone(), value)).as_ref() == Some(o)
+    }
+);
+
+impl<K, V> KeySet<K, V> {
+    fn check_removable(&self) {}
+}
+
+impl<K, V> Values<K, V> {
+    fn check_removable(&self) {}
+}
+
+impl<K, V> EntrySet<K, V> {
+    /// The mappings of `Map1` are an immutable set, which throws even when there is
+    /// nothing to remove.
+    fn check_removable(&self) {
+        if let Mappings::Single(..) = *self.map.mappings {
+            unsupported()
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> Set<K> for KeySet<K, V> {}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> Set<(K, V)> for EntrySet<K, V> {}
+
+/// Iterates over the keys of an `ImmutableMap`.
+pub struct KeyIterator<K, V>(MapIterator<K, V>);
+
+impl<K: Clone, V: Clone> Iterator for KeyIterator<K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        self.0.next().map(|(key, _)| key)
+    }
+}
+
+/// Iterates over the values of an `ImmutableMap`.
+pub struct ValueIterator<K, V>(MapIterator<K, V>);
+
+impl<K: Clone, V: Clone> Iterator for ValueIterator<K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<V> {
+        self.0.next().map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::{natural_order, ArrayList, HashMap, List};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn lists() {
+        let mut list = ImmutableList::of([1, 2, 3]);
+        assert_eq!(list.to_string(), "[1, 2, 3]");
+        assert_eq!(list.hash_code(), 30817);
+        assert_eq!(list, [1, 2, 3].into_iter().collect::<ArrayList<i32>>());
+        assert_eq!(
+            message(|| list.get(5)),
+            "Index 5 out of bounds for length 3"
+        );
+        assert_eq!(
+            message(|| ImmutableList::of([1, 2]).get(5)),
+            "Index: 5 Size: 2"
+        );
+        assert_eq!(message(|| list.list_iterator_at(4)), "Index: 4 Size: 3");
+        assert_eq!(message(|| list.sub_list(0, 5)), "toIndex = 5");
+        assert_eq!(
+            message(|| list.sub_list(1, 3).get(2)),
+            "Index 2 out of bounds for length 2"
+        );
+        assert_eq!(message(|| list.add(4)), "");
+        assert_eq!(message(|| list.add_all(&ArrayList::<i32>::new())), "");
+        assert_eq!(message(|| list.sort(natural_order())), "");
+        assert_eq!(message(|| list.list_iterator().remove()), "");
+
+        let nullable = ImmutableList::of([Some(1), Some(2)]);
+        assert_eq!(message(|| nullable.index_of(&None)), "");
+        assert_eq!(message(|| nullable.sub_list(0, 1).contains(&None)), "");
+        assert_eq!(message(|| ImmutableList::of([Some(1), None])), "");
+        assert_eq!(ImmutableList::copy_of(&nullable), nullable);
+    }
+
+    #[test]
+    fn sets() {
+        let set = ImmutableSet::of([1, 2, 3, 17, 33, 100, -5]);
+        assert_eq!(
+            with_salt(1030553677, || set.to_string()),
+            "[2, 1, -5, 100, 33, 17, 3]"
+        );
+        assert_eq!(
+            with_salt(1333003058, || set.to_string()),
+            "[33, 100, -5, 1, 2, 3, 17]"
+        );
+        let pair = ImmutableSet::of([1, 2]);
+        assert_eq!(with_salt(1030553677, || pair.to_string()), "[1, 2]");
+        assert_eq!(with_salt(1333003058, || pair.to_string()), "[2, 1]");
+        assert_eq!(ImmutableSet::of([1, 2, 3]).hash_code(), 6);
+        assert!(set.contains(&-5) && !set.contains(&4));
+        assert_eq!(
+            ImmutableSet::copy_of(&ArrayList::from_iter([3, 1, 3])),
+            ImmutableSet::of([1, 3])
+        );
+
+        assert_eq!(
+            message(|| ImmutableSet::of([1, 2, 1])),
+            "duplicate element: 1"
+        );
+        assert_eq!(message(|| ImmutableSet::of([1, 1])), "duplicate element: 1");
+        assert_eq!(message(|| ImmutableSet::of([None::<i32>])), "");
+        assert_eq!(
+            message(|| ImmutableSet::of([None, Some(1)])),
+            "Cannot invoke \"Object.equals(Object)\" because \"e0\" is null"
+        );
+        assert_eq!(message(|| ImmutableSet::of([Some(1), None])), "");
+        assert_eq!(
+            message(|| ImmutableSet::of([Some(1), None, Some(2)])),
+            "Cannot invoke \"Object.hashCode()\" because \"pe\" is null"
+        );
+        assert_eq!(
+            message(|| ImmutableSet::of([Some(1), Some(1), None])),
+            "duplicate element: 1"
+        );
+        assert_eq!(
+            message(|| ImmutableSet::of([Some(1)]).contains(&None)),
+            "Cannot invoke \"Object.equals(Object)\" because \"o\" is null"
+        );
+        assert_eq!(
+            message(|| ImmutableSet::of([Some(1), Some(2), Some(3)]).contains(&None)),
+            ""
+        );
+        assert_eq!(message(|| ImmutableSet::of([1]).clone().remove(&2)), "");
+    }
+
+    #[test]
+    fn maps() {
+        let map = ImmutableMap::of([("a", 1), ("b", 2), ("c", 3), ("zz", 4)]);
+        assert_eq!(
+            with_salt(1030553677, || map.to_string()),
+            "{zz=4, c=3, b=2, a=1}"
+        );
+        assert_eq!(
+            with_salt(1333003058, || map.to_string()),
+            "{c=3, zz=4, a=1, b=2}"
+        );
+        assert_eq!(ImmutableMap::of([("a", 1), ("b", 2)]).hash_code(), 192);
+        assert_eq!(map.get(&"c"), Some(3));
+        assert_eq!(map.get(&"d"), None);
+        let mut copy = HashMap::new();
+        copy.put_all(&map);
+        assert_eq!(map, copy);
+        assert_eq!(ImmutableMap::copy_of(&copy), map);
+
+        assert_eq!(
+            message(|| ImmutableMap::of([("a", 1), ("a", 2)])),
+            "duplicate key: a"
+        );
+        assert_eq!(
+            message(|| ImmutableMap::of([(Some(1.0), 1), (Some(2.0), 2), (Some(1.0), 3)])),
+            "duplicate key: 1.0"
+        );
+        assert_eq!(
+            message(|| ImmutableMap::of([("a", Some(1)), ("b", None)])),
+            ""
+        );
+        assert_eq!(message(|| entry(Some(1), None::<i32>)), "");
+        let nullable = ImmutableMap::of([(Some(1), 1), (Some(2), 2)]);
+        assert_eq!(
+            message(|| nullable.get(&None)),
+            "Cannot invoke \"Object.hashCode()\" because \"pk\" is null"
+        );
+        assert_eq!(message(|| nullable.contains_key(&None)), "");
+        assert_eq!(
+            message(|| ImmutableMap::of([(Some(1), 1)]).get(&None)),
+            "Cannot invoke \"Object.equals(Object)\" because \"o\" is null"
+        );
+
+        let mut map = map;
+        assert_eq!(message(|| map.put("d", 4)), "");
+        assert_eq!(message(|| map.compute_if_absent("a", |_| 0)), "");
+        let mut keys = map.key_set();
+        assert_eq!(message(|| keys.remove(&"a")), "remove");
+        assert!(!keys.remove(&"q"));
+        assert!(!keys.remove_if(|k| k.is_empty()));
+        assert_eq!(message(|| keys.clear()), "");
+        assert_eq!(message(|| map.values().remove(&1)), "remove");
+        assert!(!map.entry_set().remove(&("a", 2)));
+        assert_eq!(
+            message(|| ImmutableMap::of([("a", 1)]).entry_set().remove(&("a", 2))),
+            ""
+        );
+    }
+}
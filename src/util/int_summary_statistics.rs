@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use crate::util::FormatArgument;
+use crate::JavaStringOwned;
+
+/// `java.util.IntSummaryStatistics`: the count, sum, minimum, maximum and average of the
+/// `int`s it has accepted. The sum is a `long`, so it doesn't overflow where an `int`
+/// would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntSummaryStatistics {
+    count: i64,
+    sum: i64,
+    min: i32,
+    max: i32,
+}
+
+impl IntSummaryStatistics {
+    /// Statistics of no values, whose minimum is `i32::MAX` and maximum `i32::MIN`.
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0,
+            min: i32::MAX,
+            max: i32::MIN,
+        }
+    }
+
+    pub fn accept(&mut self, value: i32) {
+        self.count += 1;
+        self.sum += value as i64;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Adds the values of `other` to these.
+    pub fn combine(&mut self, other: &IntSummaryStatistics) {
+        self.count += other.count;
+        self.sum += other.sum;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn get_count(&self) -> i64 {
+        self.count
+    }
+
+    pub fn get_sum(&self) -> i64 {
+        self.sum
+    }
+
+    pub fn get_min(&self) -> i32 {
+        self.min
+    }
+
+    pub fn get_max(&self) -> i32 {
+        self.max
+    }
+
+    /// The mean of the values, or 0 if there are none.
+    pub fn get_average(&self) -> f64 {
+        if self.count > 0 {
+            self.sum as f64 / self.count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for IntSummaryStatistics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like Java's `toString`, with the average formatted by `%f` in the default locale.
+impl Display for IntSummaryStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&String::format(
+            "%s{count=%d, sum=%d, min=%d, average=%f, max=%d}",
+            &[
+                FormatArgument::from("IntSummaryStatistics"),
+                FormatArgument::from(self.count),
+                FormatArgument::from(self.sum),
+                FormatArgument::from(self.min),
+                FormatArgument::from(self.get_average()),
+                FormatArgument::from(self.max),
+            ],
+        ))
+    }
+}
@@ -0,0 +1,404 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+use crate::util::hash_map::{EntryIterator, EntrySet, HashMap, KeySet, Linked, PutMode, Values};
+use crate::util::{Map, SequencedMap};
+use crate::HashCode;
+
+/// `java.util.LinkedHashMap`: a `HashMap` that also links its mappings in the order they
+/// were put, or in access order, where reading or replacing a mapping moves it to the end.
+///
+/// Java's `removeEldestEntry` is a function given to `set_remove_eldest_entry`, which is
+/// asked after each new mapping whether to remove the eldest, so that an access-ordered map
+/// is an LRU cache. Like a `HashMap`, the map is shared with its views, iterators and
+/// `reversed` view, and `clone` copies the mappings into a new map.
+pub struct LinkedHashMap<K, V> {
+    pub(crate) map: HashMap<K, V>,
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> LinkedHashMap<K, V> {
+    /// An empty insertion-ordered map with the default capacity of 16 and load factor of
+    /// 0.75.
+    pub fn new() -> Self {
+        Self::linked(HashMap::new(), false)
+    }
+
+    pub fn with_capacity(initial_capacity: usize) -> Self {
+        Self::linked(HashMap::with_capacity(initial_capacity), false)
+    }
+
+    /// Throws `IllegalArgumentException` if the load factor isn't positive.
+    pub fn with_capacity_and_load_factor(initial_capacity: usize, load_factor: f32) -> Self {
+        Self::linked(
+            HashMap::with_capacity_and_load_factor(initial_capacity, load_factor),
+            false,
+        )
+    }
+
+    /// Java's `new LinkedHashMap<>(initialCapacity, loadFactor, accessOrder)`, which orders
+    /// the mappings by access if `access_order`, and otherwise by insertion.
+    pub fn with_access_order(
+        initial_capacity: usize,
+        load_factor: f32,
+        access_order: bool,
+    ) -> Self {
+        Self::linked(
+            HashMap::with_capacity_and_load_factor(initial_capacity, load_factor),
+            access_order,
+        )
+    }
+
+    /// Java's `new LinkedHashMap<>(m)`: an insertion-ordered map of the mappings of another,
+    /// in its iteration order.
+    pub fn from_map<M: Map<K, V>>(m: &M) -> Self {
+        let map = Self::new();
+        map.map
+            .table
+            .borrow_mut()
+            .put_map_entries(m.iterator().collect());
+        map
+    }
+
+    fn linked(map: HashMap<K, V>, access_order: bool) -> Self {
+        map.table.borrow_mut().linked = Some(Linked::new(access_order));
+        Self { map }
+    }
+
+    /// Sets Java's `removeEldestEntry`, which is called with the map and its eldest mapping
+    /// after a new one is put, and removes the eldest mapping if it returns `true`.
+    pub fn set_remove_eldest_entry<F>(&mut self, remove_eldest_entry: F)
+    where
+        F: Fn(&LinkedHashMap<K, V>, &K, &V) -> bool + 'static,
+    {
+        self.linked_mut(|linked| linked.remove_eldest_entry = Some(Rc::new(remove_eldest_entry)));
+    }
+
+    fn linked_mut<R>(&self, f: impl FnOnce(&mut Linked<K, V>) -> R) -> R {
+        f(self.map.table.borrow_mut().linked.as_mut().unwrap())
+    }
+
+    /// Java's `put` with `putMode` set, which it resets like a `finally` block.
+    fn put_with_mode(&mut self, put_mode: PutMode, key: K, value: V) -> Option<V> {
+        self.linked_mut(|linked| linked.put_mode = put_mode);
+        let old_value = panic::catch_unwind(AssertUnwindSafe(|| self.map.put(key, value)));
+        self.linked_mut(|linked| linked.put_mode = PutMode::Normal);
+        old_value.unwrap_or_else(|error| panic::resume_unwind(error))
+    }
+}
+
+/// A reversed view puts first where the map puts last, and the other way around.
+impl<K: Clone + HashCode + PartialEq, V: Clone> SequencedMap<K, V> for LinkedHashMap<K, V> {
+    type Reversed = LinkedHashMap<K, V>;
+
+    /// A view that is `reversed` again is in the map's order.
+    fn reversed(&self) -> Self {
+        Self {
+            map: HashMap {
+                table: Rc::clone(&self.map.table),
+                reversed: !self.map.reversed,
+            },
+        }
+    }
+
+    /// Like Java's, moves an existing mapping to the front, unlike `put`.
+    fn put_first(&mut self, key: K, value: V) -> Option<V> {
+        let put_mode = if self.map.reversed {
+            PutMode::Last
+        } else {
+            PutMode::First
+        };
+        self.put_with_mode(put_mode, key, value)
+    }
+
+    /// Like Java's, moves an existing mapping to the end, unlike `put`.
+    fn put_last(&mut self, key: K, value: V) -> Option<V> {
+        let put_mode = if self.map.reversed {
+            PutMode::First
+        } else {
+            PutMode::Last
+        };
+        self.put_with_mode(put_mode, key, value)
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Map<K, V> for LinkedHashMap<K, V> {
+    type Iter = EntryIterator<K, V>;
+    type KeySet = KeySet<K, V>;
+    type Values = Values<K, V>;
+    type EntrySet = EntrySet<K, V>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.map.contains_value(value)
+    }
+
+    /// In an access-ordered map, this moves the mapping to the end.
+    fn get(&self, key: &K) -> Option<V> {
+        self.map.get(key)
+    }
+
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.map.put(key, value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.map.remove(key)
+    }
+
+    fn put_all<M: Map<K, V>>(&mut self, m: &M) {
+        self.map.put_all(m);
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn iterator(&self) -> EntryIterator<K, V> {
+        self.map.iterator()
+    }
+
+    fn key_set(&self) -> KeySet<K, V> {
+        self.map.key_set()
+    }
+
+    fn values(&self) -> Values<K, V> {
+        self.map.values()
+    }
+
+    fn entry_set(&self) -> EntrySet<K, V> {
+        self.map.entry_set()
+    }
+
+    fn get_or_default(&self, key: &K, default_value: V) -> V {
+        self.map.get_or_default(key, default_value)
+    }
+
+    fn for_each<F: FnMut(&K, &V)>(&self, action: F) {
+        self.map.for_each(action);
+    }
+
+    fn replace_all<F: FnMut(&K, &V) -> V>(&mut self, function: F) {
+        self.map.replace_all(function);
+    }
+
+    fn put_if_absent(&mut self, key: K, value: V) -> Option<V> {
+        self.map.put_if_absent(key, value)
+    }
+
+    fn remove_entry(&mut self, key: &K, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.map.remove_entry(key, value)
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<V> {
+        self.map.replace(key, value)
+    }
+
+    fn replace_entry(&mut self, key: K, old_value: &V, new_value: V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.map.replace_entry(key, old_value, new_value)
+    }
+
+    fn compute_if_absent<F: FnOnce(&K) -> V>(&mut self, key: K, mapping_function: F) -> V {
+        self.map.compute_if_absent(key, mapping_function)
+    }
+
+    fn compute_if_present<F: FnOnce(&K, &V) -> Option<V>>(
+        &mut self,
+        key: &K,
+        remapping_function: F,
+    ) -> Option<V> {
+        self.map.compute_if_present(key, remapping_function)
+    }
+
+    fn compute<F: FnOnce(&K, Option<&V>) -> Option<V>>(
+        &mut self,
+        key: K,
+        remapping_function: F,
+    ) -> Option<V> {
+        self.map.compute(key, remapping_function)
+    }
+
+    fn merge<F: FnOnce(&V, &V) -> Option<V>>(
+        &mut self,
+        key: K,
+        value: V,
+        remapping_function: F,
+    ) -> Option<V> {
+        self.map.merge(key, value, remapping_function)
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Default for LinkedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Clone for LinkedHashMap<K, V> {
+    /// Like Java's `clone`, a map with the same ordering and `removeEldestEntry`, with the
+    /// mappings in this one's order.
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> FromIterator<(K, V)> for LinkedHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> Extend<(K, V)> for LinkedHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map.extend(iter);
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone> IntoIterator for &LinkedHashMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = EntryIterator<K, V>;
+
+    fn into_iter(self) -> EntryIterator<K, V> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the mappings as `key=value` in braces, separated by `, `.
+impl<K, V> std::fmt::Display for LinkedHashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Display,
+    V: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+impl<K, V> std::fmt::Debug for LinkedHashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq + std::fmt::Debug,
+    V: Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map.fmt(f)
+    }
+}
+
+/// Like Java's `equals`, any two maps with the same mappings are equal, whatever their
+/// order.
+impl<K, V, M> PartialEq<M> for LinkedHashMap<K, V>
+where
+    K: Clone + HashCode + PartialEq,
+    V: Clone + PartialEq,
+    M: Map<K, V>,
+{
+    fn eq(&self, other: &M) -> bool {
+        self.map == *other
+    }
+}
+
+impl<K: Clone + HashCode + PartialEq, V: Clone + HashCode> HashCode for LinkedHashMap<K, V> {
+    fn hash_code(&self) -> i32 {
+        self.map.hash_code()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::Collection;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn insertion_order() {
+        let mut map = LinkedHashMap::new();
+        map.put("b", 1);
+        map.put("a", 2);
+        map.put("c", 3);
+        map.put("b", 4);
+        assert_eq!(map.to_string(), "{b=4, a=2, c=3}");
+        assert_eq!(map.hash_code(), 297);
+        assert!(map == HashMap::from_map(&map));
+    }
+
+    #[test]
+    fn lru_cache() {
+        let mut lru = LinkedHashMap::with_access_order(16, 0.75, true);
+        lru.set_remove_eldest_entry(|map, _, _| map.size() > 3);
+        lru.put("a", 1);
+        lru.put("b", 2);
+        lru.put("c", 3);
+        lru.get(&"a");
+        lru.put("d", 4);
+        assert_eq!(lru.to_string(), "{c=3, a=1, d=4}");
+        lru.merge("b", 10, |a, b| Some(a + b));
+        lru.compute_if_absent("c", |_| 0);
+        lru.put("e", 5);
+        assert_eq!(lru.to_string(), "{b=10, c=0, e=5}");
+        assert_eq!(lru.clone().to_string(), "{b=10, c=0, e=5}");
+
+        let mut keys = lru.key_set().iterator();
+        keys.next();
+        lru.get(&"c");
+        assert_eq!(message(|| keys.next()), "");
+
+        let mut copy = LinkedHashMap::from_map(&lru);
+        copy.put("f", 6);
+        copy.put("g", 7);
+        copy.get(&"a");
+        assert_eq!(copy.to_string(), "{b=10, e=5, c=0, f=6, g=7}");
+    }
+
+    #[test]
+    fn sequenced() {
+        let mut map: LinkedHashMap<&str, i32> =
+            [("a", 1), ("b", 2), ("c", 3)].into_iter().collect();
+        assert_eq!(map.put_first("c", 30), Some(3));
+        assert_eq!(map.put_last("a", 10), Some(1));
+        assert_eq!(map.put_first("z", 0), None);
+        assert_eq!(map.to_string(), "{z=0, c=30, b=2, a=10}");
+
+        let mut reversed = map.reversed();
+        assert_eq!(reversed.to_string(), "{a=10, b=2, c=30, z=0}");
+        reversed.put_first("y", 9);
+        assert_eq!(map.to_string(), "{z=0, c=30, b=2, a=10, y=9}");
+        assert_eq!(reversed.first_entry(), Some(("y", 9)));
+        assert_eq!(reversed.last_entry(), Some(("z", 0)));
+        assert_eq!(reversed.poll_last_entry(), Some(("z", 0)));
+        assert_eq!(map.poll_last_entry(), Some(("y", 9)));
+        reversed.put("x", 1);
+        assert_eq!(reversed.to_string(), "{x=1, a=10, b=2, c=30}");
+        assert_eq!(reversed.reversed().to_string(), map.to_string());
+        assert_eq!(
+            reversed.values().iterator().collect::<Vec<_>>(),
+            [1, 10, 2, 30]
+        );
+    }
+}
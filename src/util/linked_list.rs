@@ -0,0 +1,213 @@
+use std::collections::VecDeque;
+
+use crate::util::abstract_list::{list_impls, Kind, ListView};
+use crate::util::{Collection, Deque, ListIter, Queue};
+
+/// `java.util.LinkedList`: a doubly-linked list implementing `List` and `Deque`.
+///
+/// It is shared with its iterators and sublists and fails fast like `ArrayList`, but with
+/// `LinkedList`'s exception messages, and its iterators stop without throwing whenever a
+/// modification leaves them past the end.
+pub struct LinkedList<E> {
+    view: ListView<E>,
+}
+
+impl<E: Clone> LinkedList<E> {
+    pub fn new() -> Self {
+        Self {
+            view: ListView::new(Kind::Linked, VecDeque::new()),
+        }
+    }
+
+    /// Java's `new LinkedList<>(c)`.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        c.to_array().into()
+    }
+}
+
+impl<E: Clone> Default for LinkedList<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone> Clone for LinkedList<E> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.deep_clone(),
+        }
+    }
+}
+
+impl<E: Clone> From<Vec<E>> for LinkedList<E> {
+    fn from(elements: Vec<E>) -> Self {
+        Self {
+            view: ListView::new(Kind::Linked, elements.into()),
+        }
+    }
+}
+
+impl<E: Clone> FromIterator<E> for LinkedList<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<E>>().into()
+    }
+}
+
+list_impls!(LinkedList);
+
+impl<E: Clone> Queue<E> for LinkedList<E> {
+    fn offer(&mut self, e: E) -> bool {
+        self.view.add(e);
+        true
+    }
+
+    fn poll(&mut self) -> Option<E> {
+        self.poll_first()
+    }
+
+    fn peek(&self) -> Option<E> {
+        self.peek_first()
+    }
+}
+
+impl<E: Clone> Deque<E> for LinkedList<E> {
+    type DescendingIter = DescendingIter<E>;
+
+    fn add_first(&mut self, e: E) {
+        self.view.add_at(0, e);
+    }
+
+    fn add_last(&mut self, e: E) {
+        self.view.add(e);
+    }
+
+    fn poll_first(&mut self) -> Option<E> {
+        (self.view.size() > 0).then(|| self.view.remove_at(0))
+    }
+
+    fn poll_last(&mut self) -> Option<E> {
+        let size = self.view.size();
+        (size > 0).then(|| self.view.remove_at(size - 1))
+    }
+
+    fn peek_first(&self) -> Option<E> {
+        (self.view.size() > 0).then(|| self.view.get(0))
+    }
+
+    fn peek_last(&self) -> Option<E> {
+        let size = self.view.size();
+        (size > 0).then(|| self.view.get(size - 1))
+    }
+
+    fn remove_last_occurrence(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        match self.view.last_index_of(o) {
+            Some(index) => {
+                self.view.remove_at(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn descending_iterator(&self) -> DescendingIter<E> {
+        DescendingIter(self.view.list_iterator(self.view.size()))
+    }
+}
+
+/// Iterates over a `LinkedList` from the last element to the first.
+pub struct DescendingIter<E>(ListIter<E>);
+
+impl<E: Clone> DescendingIter<E> {
+    pub fn has_next(&self) -> bool {
+        self.0.has_previous()
+    }
+
+    /// Removes the element last returned.
+    pub fn remove(&mut self) {
+        self.0.remove();
+    }
+}
+
+impl<E: Clone> Iterator for DescendingIter<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        self.0.previous()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::util::{Collection, Deque, LinkedList, List, Queue};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn works_as_a_deque() {
+        let mut deque = LinkedList::new();
+        deque.push(2);
+        deque.offer_first(1);
+        deque.add_last(3);
+        deque.offer(4);
+        assert_eq!(
+            deque.descending_iterator().collect::<Vec<_>>(),
+            [4, 3, 2, 1]
+        );
+        assert_eq!(
+            (deque.pop(), deque.poll_last(), deque.peek()),
+            (1, Some(4), Some(2))
+        );
+        assert_eq!((deque.get_first(), deque.get_last()), (2, 3));
+        deque.clear();
+        assert_eq!(deque.poll(), None);
+        assert_eq!(message(|| deque.element()), "");
+        assert_eq!(message(|| deque.remove_last()), "");
+    }
+
+    #[test]
+    fn fails_fast_like_jdk() {
+        // Unlike an `ArrayList`, removing the last element ends the loop: the cursor is
+        // then past the end.
+        let mut list = LinkedList::from(vec![1, 2, 3]);
+        for e in &list {
+            if e == 3 {
+                list.remove(&e);
+            }
+        }
+        assert_eq!(list.to_string(), "[1, 2]");
+        let error = message(|| {
+            for e in &list {
+                list.add(e);
+            }
+        });
+        assert_eq!(error, "");
+        // Nor does it count sorting or replacing elements as modifications.
+        let mut list = LinkedList::from(vec![3, 1, 2]);
+        let mut iterator = list.iterator();
+        iterator.next();
+        list.sort(crate::util::natural_order());
+        list.replace_all(|e| e + 1);
+        assert_eq!(iterator.collect::<Vec<_>>(), [3, 4]);
+    }
+
+    #[test]
+    fn throws_jdk_messages() {
+        let list = LinkedList::from(vec![1, 2, 3]);
+        assert_eq!(message(|| list.get(5)), "Index: 5, Size: 3");
+        assert_eq!(message(|| list.list_iterator_at(5)), "Index: 5, Size: 3");
+        assert_eq!(
+            message(|| list.sub_list(1, 3).get(5)),
+            "Index 5 out of bounds for length 2"
+        );
+    }
+}
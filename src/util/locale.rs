@@ -0,0 +1,746 @@
+use std::borrow::Cow;
+use std::env;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+use crate::errors::{IllegalArgumentException, Throwable};
+
+/// `java.util.Locale`: a language, script, country and variant, each possibly empty, and
+/// the extensions of a BCP 47 language tag.
+///
+/// Like Java's, the extensions are kept in a canonical form: lowercased and ordered by
+/// their singletons, with the attributes and keywords of the Unicode extension sorted.
+/// The private use goes last, without the `lvariant` part that carries variants that are
+/// not valid BCP 47 variants.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    language: Cow<'static, str>,
+    script: Cow<'static, str>,
+    country: Cow<'static, str>,
+    variant: Cow<'static, str>,
+    /// The extensions as Java's `LocaleExtensions` writes them, like `u-ca-japanese-x-foo`.
+    extensions: Cow<'static, str>,
+}
+
+static DEFAULT: RwLock<Option<Locale>> = RwLock::new(None);
+
+/// Grandfathered and irregular tags with their replacements, as in `LanguageTag`.
+const GRANDFATHERED: [(&str, &str); 26] = [
+    ("art-lojban", "jbo"),
+    ("cel-gaulish", "xtg-x-cel-gaulish"),
+    ("en-GB-oed", "en-GB-x-oed"),
+    ("i-ami", "ami"),
+    ("i-bnn", "bnn"),
+    ("i-default", "en-x-i-default"),
+    ("i-enochian", "und-x-i-enochian"),
+    ("i-hak", "hak"),
+    ("i-klingon", "tlh"),
+    ("i-lux", "lb"),
+    ("i-mingo", "und-x-i-mingo"),
+    ("i-navajo", "nv"),
+    ("i-pwn", "pwn"),
+    ("i-tao", "tao"),
+    ("i-tay", "tay"),
+    ("i-tsu", "tsu"),
+    ("no-bok", "nb"),
+    ("no-nyn", "nn"),
+    ("sgn-BE-FR", "sfb"),
+    ("sgn-BE-NL", "vgt"),
+    ("sgn-CH-DE", "sgg"),
+    ("zh-guoyu", "cmn"),
+    ("zh-hakka", "hak"),
+    ("zh-min", "nan-x-zh-min"),
+    ("zh-min-nan", "nan"),
+    ("zh-xiang", "hsn"),
+];
+
+fn is_alpha(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn is_language(s: &str) -> bool {
+    (2..=8).contains(&s.len()) && is_alpha(s)
+}
+
+fn is_extlang(s: &str) -> bool {
+    s.len() == 3 && is_alpha(s)
+}
+
+fn is_script(s: &str) -> bool {
+    s.len() == 4 && is_alpha(s)
+}
+
+fn is_region(s: &str) -> bool {
+    (s.len() == 2 && is_alpha(s)) || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn is_variant(s: &str) -> bool {
+    match s.len() {
+        5..=8 => is_alphanumeric(s),
+        4 => s.as_bytes()[0].is_ascii_digit() && is_alphanumeric(s),
+        _ => false,
+    }
+}
+
+fn is_extension_singleton(s: &str) -> bool {
+    s.len() == 1 && is_alphanumeric(s) && !s.eq_ignore_ascii_case("x")
+}
+
+fn is_extension_subtag(s: &str) -> bool {
+    (2..=8).contains(&s.len()) && is_alphanumeric(s)
+}
+
+fn is_private_use_subtag(s: &str) -> bool {
+    (1..=8).contains(&s.len()) && is_alphanumeric(s)
+}
+
+/// Maps the ISO 639 codes that were withdrawn and reassigned to their current form.
+fn convert_old_iso_code(language: String) -> String {
+    match language.as_str() {
+        "iw" => "he".to_string(),
+        "ji" => "yi".to_string(),
+        "in" => "id".to_string(),
+        _ => language,
+    }
+}
+
+/// The Unicode locale extension's subtags in Java's canonical form: the attributes, sorted,
+/// then the keywords sorted by key, each with its type unless that is empty. Of keywords
+/// with the same key, the first is kept.
+fn canonical_unicode_extension(subtags: &[String]) -> String {
+    let is_key = |subtag: &str| {
+        let bytes = subtag.as_bytes();
+        bytes.len() == 2 && bytes[0].is_ascii_alphanumeric() && bytes[1].is_ascii_alphabetic()
+    };
+    let mut attributes: Vec<&str> = subtags
+        .iter()
+        .map(String::as_str)
+        .take_while(|subtag| !is_key(subtag))
+        .collect();
+    attributes.sort_unstable();
+    attributes.dedup();
+    let mut keywords: Vec<(&str, Vec<&str>)> = Vec::new();
+    let mut duplicate = false;
+    for subtag in &subtags[attributes.len()..] {
+        if is_key(subtag) {
+            duplicate = keywords.iter().any(|(key, _)| key == subtag);
+            if !duplicate {
+                keywords.push((subtag, Vec::new()));
+            }
+        } else if !duplicate {
+            keywords.last_mut().unwrap().1.push(subtag);
+        }
+    }
+    keywords.sort_by_key(|&(key, _)| key);
+    let mut canonical = attributes;
+    for (key, types) in keywords {
+        canonical.push(key);
+        canonical.extend(types);
+    }
+    canonical.join("-")
+}
+
+/// The extensions of a `LocaleExtensions` ID with their singletons. Every subtag after the
+/// private use's `x` belongs to it.
+fn split_extensions(id: &str) -> Vec<(char, &str)> {
+    let mut extensions = Vec::new();
+    let mut rest = id;
+    while let Some((singleton, value)) = rest.split_once('-') {
+        let key = singleton.chars().next().unwrap_or_default();
+        let end = if key == 'x' {
+            value.len()
+        } else {
+            value
+                .match_indices('-')
+                .map(|(i, _)| i)
+                .find(|&i| {
+                    value[i + 1..]
+                        .split('-')
+                        .next()
+                        .is_some_and(|s| s.len() == 1)
+                })
+                .unwrap_or(value.len())
+        };
+        extensions.push((key, &value[..end]));
+        rest = value.get(end + 1..).unwrap_or("");
+    }
+    extensions
+}
+
+/// The extensions Java gives the locales that stood for a calendar or digits before there
+/// were extensions: `ja_JP_JP` for the Japanese imperial calendar and `th_TH_TH` for Thai
+/// digits.
+fn compatibility_extensions(
+    language: &str,
+    script: &str,
+    country: &str,
+    variant: &str,
+) -> &'static str {
+    match (language, script, country, variant) {
+        ("ja", "", "JP", "JP") => "u-ca-japanese",
+        ("th", "", "TH", "TH") => "u-nu-thai",
+        _ => "",
+    }
+}
+
+fn title_case(s: &str) -> String {
+    let mut title = s.to_ascii_lowercase();
+    if let Some(first) = title.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    title
+}
+
+impl Locale {
+    pub const ROOT: Locale = Locale::constant("", "");
+    pub const ENGLISH: Locale = Locale::constant("en", "");
+    pub const FRENCH: Locale = Locale::constant("fr", "");
+    pub const GERMAN: Locale = Locale::constant("de", "");
+    pub const ITALIAN: Locale = Locale::constant("it", "");
+    pub const JAPANESE: Locale = Locale::constant("ja", "");
+    pub const KOREAN: Locale = Locale::constant("ko", "");
+    pub const CHINESE: Locale = Locale::constant("zh", "");
+    pub const SIMPLIFIED_CHINESE: Locale = Locale::constant("zh", "CN");
+    pub const TRADITIONAL_CHINESE: Locale = Locale::constant("zh", "TW");
+    pub const FRANCE: Locale = Locale::constant("fr", "FR");
+    pub const GERMANY: Locale = Locale::constant("de", "DE");
+    pub const ITALY: Locale = Locale::constant("it", "IT");
+    pub const JAPAN: Locale = Locale::constant("ja", "JP");
+    pub const KOREA: Locale = Locale::constant("ko", "KR");
+    pub const UK: Locale = Locale::constant("en", "GB");
+    pub const US: Locale = Locale::constant("en", "US");
+    pub const CANADA: Locale = Locale::constant("en", "CA");
+    pub const CANADA_FRENCH: Locale = Locale::constant("fr", "CA");
+    pub const CHINA: Locale = Locale::constant("zh", "CN");
+    pub const PRC: Locale = Locale::constant("zh", "CN");
+    pub const TAIWAN: Locale = Locale::constant("zh", "TW");
+
+    const fn constant(language: &'static str, country: &'static str) -> Self {
+        Self {
+            language: Cow::Borrowed(language),
+            script: Cow::Borrowed(""),
+            country: Cow::Borrowed(country),
+            variant: Cow::Borrowed(""),
+            extensions: Cow::Borrowed(""),
+        }
+    }
+
+    fn from_parts(language: String, script: String, country: String, variant: String) -> Self {
+        Self {
+            language: Cow::Owned(convert_old_iso_code(language.to_ascii_lowercase())),
+            script: Cow::Owned(script),
+            country: Cow::Owned(country.to_ascii_uppercase()),
+            variant: Cow::Owned(variant),
+            extensions: Cow::Borrowed(""),
+        }
+    }
+
+    fn with_extensions(mut self, extensions: String) -> Self {
+        self.extensions = Cow::Owned(extensions);
+        self
+    }
+
+    fn with_compatibility_extensions(self) -> Self {
+        let extensions =
+            compatibility_extensions(&self.language, &self.script, &self.country, &self.variant);
+        self.with_extensions(extensions.to_string())
+    }
+
+    /// A locale for a language, which is lowercased; `new Locale(language)`.
+    pub fn new(language: &str) -> Self {
+        Self::with_variant(language, "", "")
+    }
+
+    /// `new Locale(language, country)`; the country is uppercased.
+    pub fn with_country(language: &str, country: &str) -> Self {
+        Self::with_variant(language, country, "")
+    }
+
+    /// `new Locale(language, country, variant)`. As in Java the fields are not validated,
+    /// only case-normalized, and the variant is kept as given. `ja_JP_JP` and `th_TH_TH`
+    /// get the extensions `u-ca-japanese` and `u-nu-thai` that they used to stand for.
+    pub fn with_variant(language: &str, country: &str, variant: &str) -> Self {
+        Self::from_parts(
+            language.to_string(),
+            String::new(),
+            country.to_string(),
+            variant.to_string(),
+        )
+        .with_compatibility_extensions()
+    }
+
+    /// Parses a BCP 47 language tag like `Locale.forLanguageTag`. Parsing stops at the
+    /// first ill-formed subtag and keeps what came before it, so this never fails; a tag
+    /// that is ill-formed from the start gives `Locale::ROOT`. Of extensions with the same
+    /// singleton, the first is kept.
+    pub fn for_language_tag(language_tag: &str) -> Self {
+        let tag = GRANDFATHERED
+            .iter()
+            .find(|(grandfathered, _)| grandfathered.eq_ignore_ascii_case(language_tag))
+            .map_or(language_tag, |&(_, preferred)| preferred);
+        let mut subtags = tag.split('-').peekable();
+
+        let (mut language, mut script, mut country) = (String::new(), String::new(), "");
+        let mut variants = Vec::new();
+        let mut extensions: Vec<(char, Vec<String>)> = Vec::new();
+        let mut complete = true;
+        if let Some(subtag) = subtags.next_if(|subtag| is_language(subtag)) {
+            language = subtag.to_ascii_lowercase();
+            // The first extended language subtag is the language, as in "zh-yue".
+            let mut extlangs = 0;
+            while extlangs < 3 {
+                let Some(extlang) = subtags.next_if(|subtag| is_extlang(subtag)) else {
+                    break;
+                };
+                if extlangs == 0 {
+                    language = extlang.to_ascii_lowercase();
+                }
+                extlangs += 1;
+            }
+            if let Some(subtag) = subtags.next_if(|subtag| is_script(subtag)) {
+                script = title_case(subtag);
+            }
+            if let Some(subtag) = subtags.next_if(|subtag| is_region(subtag)) {
+                country = subtag;
+            }
+            while let Some(subtag) = subtags.next_if(|subtag| is_variant(subtag)) {
+                variants.push(subtag.to_string());
+            }
+            while let Some(singleton) = subtags.next_if(|subtag| is_extension_singleton(subtag)) {
+                let values: Vec<String> =
+                    std::iter::from_fn(|| subtags.next_if(|subtag| is_extension_subtag(subtag)))
+                        .map(|subtag| subtag.to_ascii_lowercase())
+                        .collect();
+                if values.is_empty() {
+                    complete = false;
+                    break;
+                }
+                let key = singleton.to_ascii_lowercase().chars().next().unwrap();
+                if extensions.iter().all(|&(other, _)| other != key) {
+                    extensions.push((key, values));
+                }
+            }
+        }
+        let mut private_use = Vec::new();
+        if complete
+            && subtags
+                .next_if(|subtag| subtag.eq_ignore_ascii_case("x"))
+                .is_some()
+        {
+            private_use =
+                std::iter::from_fn(|| subtags.next_if(|subtag| is_private_use_subtag(subtag)))
+                    .collect();
+            if let Some(lvariant) = private_use
+                .iter()
+                .position(|subtag| subtag.eq_ignore_ascii_case("lvariant"))
+            {
+                variants.extend(private_use[lvariant + 1..].iter().map(|s| s.to_string()));
+                private_use.truncate(lvariant);
+            }
+        }
+
+        extensions.sort_by_key(|&(key, _)| key);
+        let mut canonical: Vec<String> = extensions
+            .iter()
+            .map(|(key, values)| match key {
+                'u' => format!("u-{}", canonical_unicode_extension(values)),
+                _ => format!("{key}-{}", values.join("-")),
+            })
+            .collect();
+        if !private_use.is_empty() {
+            canonical.push(format!("x-{}", private_use.join("-").to_ascii_lowercase()));
+        }
+
+        if language == "und" {
+            language.clear();
+        }
+        let locale = Self::from_parts(language, script, country.to_string(), variants.join("_"));
+        if canonical.is_empty() && !locale.variant.is_empty() {
+            locale.with_compatibility_extensions()
+        } else {
+            locale.with_extensions(canonical.join("-"))
+        }
+    }
+
+    /// The locale's BCP 47 language tag, like `Locale.toLanguageTag`: `"und"` stands for a
+    /// missing language, the extensions follow the variants, and variants that are not
+    /// valid BCP 47 variants are carried in an `lvariant` part of the private use as long as
+    /// they are alphanumeric.
+    pub fn to_language_tag(&self) -> String {
+        let (mut language, mut variant) = (&*self.language, &*self.variant);
+        if language == "no" && self.country == "NO" && variant == "NY" {
+            (language, variant) = ("nn", "");
+        }
+
+        let mut subtags = Vec::new();
+        let mut has_subtag = false;
+        if !is_language(language) {
+            language = "";
+        }
+        if is_script(&self.script) {
+            subtags.push(title_case(&self.script));
+            has_subtag = true;
+        }
+        if is_region(&self.country) {
+            subtags.push(self.country.to_ascii_uppercase());
+            has_subtag = true;
+        }
+
+        let mut variants = variant
+            .split('_')
+            .filter(|_| !variant.is_empty())
+            .peekable();
+        while let Some(subtag) = variants.next_if(|subtag| is_variant(subtag)) {
+            subtags.push(subtag.to_string());
+            has_subtag = true;
+        }
+        let lvariant: Vec<&str> =
+            std::iter::from_fn(|| variants.next_if(|subtag| is_private_use_subtag(subtag)))
+                .collect();
+
+        let mut private_use = None;
+        for (key, value) in split_extensions(&self.extensions) {
+            if key == 'x' {
+                private_use = Some(format!("x-{value}"));
+            } else {
+                subtags.push(format!("{key}-{value}"));
+                has_subtag = true;
+            }
+        }
+        if !lvariant.is_empty() {
+            let lvariant = format!("lvariant-{}", lvariant.join("-"));
+            private_use = Some(match private_use {
+                Some(private_use) => format!("{private_use}-{lvariant}"),
+                None => format!("x-{lvariant}"),
+            });
+        }
+
+        if language.is_empty() && (has_subtag || private_use.is_none()) {
+            language = "und";
+        }
+        if !language.is_empty() {
+            subtags.insert(0, language.to_string());
+        }
+        subtags.extend(private_use);
+        subtags.join("-")
+    }
+
+    pub fn get_language(&self) -> &str {
+        &self.language
+    }
+
+    pub fn get_script(&self) -> &str {
+        &self.script
+    }
+
+    pub fn get_country(&self) -> &str {
+        &self.country
+    }
+
+    pub fn get_variant(&self) -> &str {
+        &self.variant
+    }
+
+    /// The value of the extension with a singleton, like `ca-japanese` for `u`, or `None`
+    /// if the locale has no such extension. Throws `IllegalArgumentException` if the key is
+    /// not a singleton or `x`.
+    pub fn get_extension(&self, key: char) -> Option<&str> {
+        if !key.is_ascii_alphanumeric() {
+            IllegalArgumentException::new(format!("Ill-formed extension key: {key}"), None).throw()
+        }
+        let key = key.to_ascii_lowercase();
+        split_extensions(&self.extensions)
+            .into_iter()
+            .find_map(|(singleton, value)| (singleton == key).then_some(value))
+    }
+
+    /// The type of a Unicode locale keyword, like `japanese` for `ca` in `u-ca-japanese`:
+    /// empty if the keyword has none, and `None` if the locale has no such keyword. Throws
+    /// `IllegalArgumentException` if the key is ill-formed.
+    pub fn get_unicode_locale_type(&self, key: &str) -> Option<&str> {
+        let bytes = key.as_bytes();
+        if !(bytes.len() == 2 && bytes.iter().all(u8::is_ascii_alphanumeric)) {
+            IllegalArgumentException::new(format!("Ill-formed Unicode locale key: {key}"), None)
+                .throw()
+        }
+        let unicode = self.get_extension('u')?;
+        // Keys are the only two-character subtags; attributes and types are longer.
+        let mut offset = 0;
+        let mut subtags = unicode.split('-').map(|subtag| {
+            offset += subtag.len() + 1;
+            (offset, subtag)
+        });
+        let (start, _) = subtags.find(|(_, subtag)| subtag.eq_ignore_ascii_case(key))?;
+        let end = subtags
+            .take_while(|(_, subtag)| subtag.len() > 2)
+            .last()
+            .map_or(start, |(end, _)| end);
+        Some(unicode.get(start..end - 1).unwrap_or(""))
+    }
+
+    pub fn has_extensions(&self) -> bool {
+        !self.extensions.is_empty()
+    }
+
+    /// The locale without its extensions.
+    pub fn strip_extensions(&self) -> Locale {
+        Locale {
+            extensions: Cow::Borrowed(""),
+            ..self.clone()
+        }
+    }
+
+    /// The default locale: the one last passed to `set_default`, or else the one named by
+    /// the `LC_ALL`, `LC_MESSAGES` or `LANG` environment variable, in that order of
+    /// precedence. Unset, `C` and `POSIX` mean `en_US`, as in the JDK.
+    pub fn get_default() -> Locale {
+        if let Some(locale) = DEFAULT.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            return locale.clone();
+        }
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .map_or(Locale::US, |value| Locale::from_posix(&value));
+        DEFAULT
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert(locale)
+            .clone()
+    }
+
+    pub fn set_default(new_locale: Locale) {
+        *DEFAULT.write().unwrap_or_else(|e| e.into_inner()) = Some(new_locale);
+    }
+
+    /// Parses a POSIX locale name like `sr_RS.UTF-8@latin`. The encoding is ignored, and of
+    /// the modifiers only `@latin` is understood, as the Latin script.
+    fn from_posix(name: &str) -> Locale {
+        let (name, modifier) = name.split_once('@').unwrap_or((name, ""));
+        let name = name.split_once('.').map_or(name, |(name, _)| name);
+        if name.is_empty() || name == "C" || name == "POSIX" {
+            return Locale::US;
+        }
+        let (language, country) = name.split_once('_').unwrap_or((name, ""));
+        let script = if modifier == "latin" { "Latn" } else { "" };
+        Self::from_parts(
+            language.to_string(),
+            script.to_string(),
+            country.to_string(),
+            String::new(),
+        )
+    }
+}
+
+impl Display for Locale {
+    /// Java's `Locale.toString`, such as `en_US`, `sr_RS_#Latn` or `ja_JP_#u-ca-japanese`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (language, script, country, variant, extensions) = (
+            !self.language.is_empty(),
+            !self.script.is_empty(),
+            !self.country.is_empty(),
+            !self.variant.is_empty(),
+            !self.extensions.is_empty(),
+        );
+        write!(f, "{}", self.language)?;
+        if country || (language && (variant || script || extensions)) {
+            write!(f, "_{}", self.country)?;
+        }
+        if variant && (language || country) {
+            write!(f, "_{}", self.variant)?;
+        }
+        if script && (language || country) {
+            write!(f, "_#{}", self.script)?;
+        }
+        if extensions && (language || country) {
+            f.write_str(if script { "_" } else { "_#" })?;
+            f.write_str(&self.extensions)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Locale;
+
+    #[test]
+    fn language_tags_match_jdk() {
+        for (tag, display, canonical) in [
+            ("en-US", "en_US", "en-US"),
+            ("zh-Hant-TW", "zh_TW_#Hant", "zh-Hant-TW"),
+            ("iw-IL", "he_IL", "he-IL"),
+            ("EN-latn-gb", "en_GB_#Latn", "en-Latn-GB"),
+            ("en-US-x-lvariant-POSIX", "en_US_POSIX", "en-US-POSIX"),
+            ("zh-yue-HK", "yue_HK", "yue-HK"),
+            ("i-klingon", "tlh", "tlh"),
+            ("en_US", "", "und"),
+            ("en--US", "en", "en"),
+            ("es-419", "es_419", "es-419"),
+            (
+                "en-US-u-ca-japanese",
+                "en_US_#u-ca-japanese",
+                "en-US-u-ca-japanese",
+            ),
+            ("en-x-foo", "en__#x-foo", "en-x-foo"),
+            ("x-foo", "", "x-foo"),
+            ("und-x-foo", "", "x-foo"),
+            (
+                "sr-Latn-RS-u-nu-latn",
+                "sr_RS_#Latn_u-nu-latn",
+                "sr-Latn-RS-u-nu-latn",
+            ),
+            (
+                "en-U-CA-Japanese-NU-thai",
+                "en__#u-ca-japanese-nu-thai",
+                "en-u-ca-japanese-nu-thai",
+            ),
+            (
+                "en-u-nu-thai-ca-japanese",
+                "en__#u-ca-japanese-nu-thai",
+                "en-u-ca-japanese-nu-thai",
+            ),
+            (
+                "en-u-foo-bar-ca-japanese",
+                "en__#u-bar-foo-ca-japanese",
+                "en-u-bar-foo-ca-japanese",
+            ),
+            ("en-z-zz-b-bb", "en__#b-bb-z-zz", "en-b-bb-z-zz"),
+            ("en-a-bb-a-cc", "en__#a-bb", "en-a-bb"),
+            ("en-a-x-foo", "en", "en"),
+            (
+                "en-a-bcd-u-ca-buddhist-x-PRIV-lvariant-var",
+                "en__var_#a-bcd-u-ca-buddhist-x-priv",
+                "en-a-bcd-u-ca-buddhist-x-priv-lvariant-var",
+            ),
+            (
+                "de-CH-1901-x-foo-lvariant-bar",
+                "de_CH_1901_bar_#x-foo",
+                "de-CH-1901-x-foo-lvariant-bar",
+            ),
+            (
+                "ja-JP-x-lvariant-JP",
+                "ja_JP_JP_#u-ca-japanese",
+                "ja-JP-u-ca-japanese-x-lvariant-JP",
+            ),
+            (
+                "th-TH-x-lvariant-TH",
+                "th_TH_TH_#u-nu-thai",
+                "th-TH-u-nu-thai-x-lvariant-TH",
+            ),
+            ("ja-JP-JP-u-ca-gregory", "ja_JP", "ja-JP"),
+            (
+                "en-Latn-US-boont-fonipa",
+                "en_US_boont_fonipa_#Latn",
+                "en-Latn-US-boont-fonipa",
+            ),
+            (
+                "de-CH-1901-x-lvariant-foo",
+                "de_CH_1901_foo",
+                "de-CH-1901-x-lvariant-foo",
+            ),
+        ] {
+            let locale = Locale::for_language_tag(tag);
+            assert_eq!(locale.to_string(), display, "{tag}");
+            assert_eq!(locale.to_language_tag(), canonical, "{tag}");
+        }
+
+        for (language, country, variant, display, canonical) in [
+            ("EN", "us", "", "en_US", "en-US"),
+            ("", "US", "", "_US", "und-US"),
+            ("", "", "v", "", "x-lvariant-v"),
+            (
+                "en",
+                "US",
+                "my_var",
+                "en_US_my_var",
+                "en-US-x-lvariant-my-var",
+            ),
+            ("de", "DE", "a b", "de_DE_a b", "de-DE"),
+            ("no", "NO", "NY", "no_NO_NY", "nn-NO"),
+            (
+                "ja",
+                "JP",
+                "JP",
+                "ja_JP_JP_#u-ca-japanese",
+                "ja-JP-u-ca-japanese-x-lvariant-JP",
+            ),
+            (
+                "JA",
+                "jp",
+                "JP",
+                "ja_JP_JP_#u-ca-japanese",
+                "ja-JP-u-ca-japanese-x-lvariant-JP",
+            ),
+            ("ja", "JP", "jp", "ja_JP_jp", "ja-JP-x-lvariant-jp"),
+            (
+                "th",
+                "TH",
+                "TH",
+                "th_TH_TH_#u-nu-thai",
+                "th-TH-u-nu-thai-x-lvariant-TH",
+            ),
+            ("th", "TH", "TH_x", "th_TH_TH_x", "th-TH-x-lvariant-TH-x"),
+        ] {
+            let locale = Locale::with_variant(language, country, variant);
+            assert_eq!(locale.to_string(), display);
+            assert_eq!(locale.to_language_tag(), canonical);
+        }
+        assert_eq!(Locale::ROOT.to_language_tag(), "und");
+    }
+
+    #[test]
+    fn language_tags_round_trip() {
+        for tag in [
+            "en-US-u-ca-japanese",
+            "en-x-foo",
+            "sr-Latn-RS-u-nu-latn",
+            "en-a-bcd-u-ca-buddhist-x-priv-lvariant-var",
+            "ja-JP-u-ca-japanese-x-lvariant-JP",
+            "th-TH-u-nu-thai-x-lvariant-TH",
+        ] {
+            let locale = Locale::for_language_tag(tag);
+            assert_eq!(locale.to_language_tag(), tag);
+            assert_eq!(Locale::for_language_tag(&locale.to_language_tag()), locale);
+        }
+        for (language, country, variant) in [("ja", "JP", "JP"), ("th", "TH", "TH")] {
+            let locale = Locale::with_variant(language, country, variant);
+            assert_eq!(Locale::for_language_tag(&locale.to_language_tag()), locale);
+            assert_eq!(
+                locale.strip_extensions().to_string(),
+                format!("{language}_{country}_{variant}")
+            );
+        }
+    }
+
+    #[test]
+    fn extensions() {
+        let locale = Locale::for_language_tag("en-u-ca-islamic-civil-nu-thai-x-foo");
+        assert!(locale.has_extensions());
+        assert_eq!(locale.get_extension('U'), Some("ca-islamic-civil-nu-thai"));
+        assert_eq!(locale.get_extension('x'), Some("foo"));
+        assert_eq!(locale.get_extension('a'), None);
+        assert_eq!(locale.get_unicode_locale_type("ca"), Some("islamic-civil"));
+        assert_eq!(locale.get_unicode_locale_type("NU"), Some("thai"));
+        assert_eq!(locale.get_unicode_locale_type("co"), None);
+        assert_eq!(
+            Locale::for_language_tag("en-u-ca").get_unicode_locale_type("ca"),
+            Some("")
+        );
+        assert!(!Locale::US.has_extensions());
+    }
+
+    #[test]
+    fn posix_names() {
+        assert_eq!(
+            Locale::from_posix("tr_TR.UTF-8"),
+            Locale::with_country("tr", "TR")
+        );
+        assert_eq!(Locale::from_posix("C.UTF-8"), Locale::US);
+        assert_eq!(Locale::from_posix("sr_RS@latin").to_string(), "sr_RS_#Latn");
+    }
+}
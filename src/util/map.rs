@@ -0,0 +1,310 @@
+use crate::util::collection::{self, Collection, Set};
+
+/// `java.util.Map`: an object that maps keys to values, with at most one value per key.
+///
+/// Keys and values are returned by value like the elements of a `Collection`, and since
+/// they can't be `null`, the methods that return `null` in Java for a missing mapping
+/// return `None` here. The functions passed to `compute`, `compute_if_present` and `merge`
+/// return `None` to remove the mapping, where Java's return `null`.
+pub trait Map<K, V> {
+    /// Iterates over the mappings, like Java's `entrySet().iterator()`.
+    type Iter: Iterator<Item = (K, V)>;
+    type KeySet: Set<K>;
+    type Values: Collection<V>;
+    type EntrySet: Set<(K, V)>;
+
+    fn size(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    fn contains_key(&self, key: &K) -> bool;
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.iterator().any(|(_, v)| v == *value)
+    }
+
+    fn get(&self, key: &K) -> Option<V>;
+
+    /// Maps a key to a value, returning the value it replaced.
+    fn put(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Removes a key's mapping, returning its value.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    fn put_all<M: Map<K, V>>(&mut self, m: &M) {
+        for (key, value) in m.iterator() {
+            self.put(key, value);
+        }
+    }
+
+    fn clear(&mut self);
+
+    fn iterator(&self) -> Self::Iter;
+
+    /// A view of the keys, through which mappings can be removed.
+    fn key_set(&self) -> Self::KeySet;
+
+    /// A view of the values, through which mappings can be removed.
+    fn values(&self) -> Self::Values;
+
+    /// A view of the mappings, through which they can be removed.
+    fn entry_set(&self) -> Self::EntrySet;
+
+    fn get_or_default(&self, key: &K, default_value: V) -> V {
+        self.get(key).unwrap_or(default_value)
+    }
+
+    fn for_each<F: FnMut(&K, &V)>(&self, mut action: F) {
+        for (key, value) in self.iterator() {
+            action(&key, &value);
+        }
+    }
+
+    /// Replaces each value by the function of its mapping.
+    fn replace_all<F: FnMut(&K, &V) -> V>(&mut self, function: F);
+
+    /// Maps the key to the value unless it is already mapped, returning the current value.
+    fn put_if_absent(&mut self, key: K, value: V) -> Option<V> {
+        match self.get(&key) {
+            None => self.put(key, value),
+            current => current,
+        }
+    }
+
+    /// Java's `remove(key, value)`: removes the key's mapping if it is to the value,
+    /// returning whether it was.
+    fn remove_entry(&mut self, key: &K, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        if self.get(key).as_ref() != Some(value) {
+            return false;
+        }
+        self.remove(key);
+        true
+    }
+
+    /// Replaces the key's value if it is mapped, returning the value it replaced.
+    fn replace(&mut self, key: K, value: V) -> Option<V> {
+        if self.contains_key(&key) {
+            self.put(key, value)
+        } else {
+            None
+        }
+    }
+
+    /// Java's `replace(key, oldValue, newValue)`: replaces the key's value if it is
+    /// `old_value`, returning whether it was.
+    fn replace_entry(&mut self, key: K, old_value: &V, new_value: V) -> bool
+    where
+        V: PartialEq,
+    {
+        if self.get(&key).as_ref() != Some(old_value) {
+            return false;
+        }
+        self.put(key, new_value);
+        true
+    }
+
+    /// Maps the key to the function of it unless it is already mapped, returning the
+    /// current value.
+    fn compute_if_absent<F: FnOnce(&K) -> V>(&mut self, key: K, mapping_function: F) -> V
+    where
+        V: Clone,
+    {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = mapping_function(&key);
+        self.put(key, value.clone());
+        value
+    }
+
+    /// Remaps the key if it is mapped, returning the new value.
+    fn compute_if_present<F: FnOnce(&K, &V) -> Option<V>>(
+        &mut self,
+        key: &K,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let old_value = self.get(key)?;
+        match remapping_function(key, &old_value) {
+            Some(value) => {
+                self.put(key.clone(), value.clone());
+                Some(value)
+            }
+            None => {
+                self.remove(key);
+                None
+            }
+        }
+    }
+
+    /// Maps the key to the function of it and its current value, if any, returning the
+    /// new value.
+    fn compute<F: FnOnce(&K, Option<&V>) -> Option<V>>(
+        &mut self,
+        key: K,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        V: Clone,
+    {
+        let old_value = self.get(&key);
+        match remapping_function(&key, old_value.as_ref()) {
+            Some(value) => {
+                self.put(key, value.clone());
+                Some(value)
+            }
+            None => {
+                if old_value.is_some() {
+                    self.remove(&key);
+                }
+                None
+            }
+        }
+    }
+
+    /// Maps the key to the value if it isn't mapped, and otherwise to the function of its
+    /// current value and the value, returning the new value.
+    fn merge<F: FnOnce(&V, &V) -> Option<V>>(
+        &mut self,
+        key: K,
+        value: V,
+        remapping_function: F,
+    ) -> Option<V>
+    where
+        V: Clone,
+    {
+        let new_value = match self.get(&key) {
+            Some(old_value) => remapping_function(&old_value, &value),
+            None => Some(value),
+        };
+        match new_value {
+            Some(value) => {
+                self.put(key, value.clone());
+                Some(value)
+            }
+            None => {
+                self.remove(&key);
+                None
+            }
+        }
+    }
+}
+
+/// Java 21's `SequencedMap`: a map whose mappings have an order, with access to both ends.
+pub trait SequencedMap<K, V>: Map<K, V> {
+    type Reversed: SequencedMap<K, V>;
+
+    /// A view of the map in the opposite order.
+    fn reversed(&self) -> Self::Reversed;
+
+    fn first_entry(&self) -> Option<(K, V)> {
+        self.iterator().next()
+    }
+
+    fn last_entry(&self) -> Option<(K, V)> {
+        self.reversed().iterator().next()
+    }
+
+    /// Removes the first mapping, returning it.
+    fn poll_first_entry(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.first_entry()?;
+        self.remove(&key);
+        Some((key, value))
+    }
+
+    /// Removes the last mapping, returning it.
+    fn poll_last_entry(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.last_entry()?;
+        self.remove(&key);
+        Some((key, value))
+    }
+
+    /// Maps the key to the value and makes the mapping the first, returning the value it
+    /// replaced. Throws `UnsupportedOperationException` for a map that orders its mappings
+    /// itself.
+    fn put_first(&mut self, key: K, value: V) -> Option<V> {
+        let _ = (key, value);
+        collection::unsupported()
+    }
+
+    /// Maps the key to the value and makes the mapping the last, returning the value it
+    /// replaced. Throws `UnsupportedOperationException` for a map that orders its mappings
+    /// itself.
+    fn put_last(&mut self, key: K, value: V) -> Option<V> {
+        let _ = (key, value);
+        collection::unsupported()
+    }
+}
+
+/// `java.util.NavigableMap`: a map sorted by its keys, which can be searched for the keys
+/// closest to a given one and viewed in ranges. The views share the map, and putting a key
+/// out of a view's range throws `IllegalArgumentException`.
+pub trait NavigableMap<K, V>: SequencedMap<K, V> {
+    /// The lowest key, throwing `NoSuchElementException` if the map is empty.
+    fn first_key(&self) -> K;
+
+    /// The highest key, throwing `NoSuchElementException` if the map is empty.
+    fn last_key(&self) -> K;
+
+    /// The mapping of the highest key less than `key`.
+    fn lower_entry(&self, key: &K) -> Option<(K, V)>;
+
+    fn lower_key(&self, key: &K) -> Option<K> {
+        self.lower_entry(key).map(|(key, _)| key)
+    }
+
+    /// The mapping of the highest key less than or equal to `key`.
+    fn floor_entry(&self, key: &K) -> Option<(K, V)>;
+
+    fn floor_key(&self, key: &K) -> Option<K> {
+        self.floor_entry(key).map(|(key, _)| key)
+    }
+
+    /// The mapping of the lowest key greater than or equal to `key`.
+    fn ceiling_entry(&self, key: &K) -> Option<(K, V)>;
+
+    fn ceiling_key(&self, key: &K) -> Option<K> {
+        self.ceiling_entry(key).map(|(key, _)| key)
+    }
+
+    /// The mapping of the lowest key greater than `key`.
+    fn higher_entry(&self, key: &K) -> Option<(K, V)>;
+
+    fn higher_key(&self, key: &K) -> Option<K> {
+        self.higher_entry(key).map(|(key, _)| key)
+    }
+
+    /// A view of the map in the opposite order, the same as `reversed`.
+    fn descending_map(&self) -> Self::Reversed {
+        self.reversed()
+    }
+
+    /// A view of the mappings of the keys less than `to_key`, or equal to it if
+    /// `inclusive`.
+    fn head_map(&self, to_key: K, inclusive: bool) -> Self
+    where
+        Self: Sized;
+
+    /// A view of the mappings of the keys greater than `from_key`, or equal to it if
+    /// `inclusive`.
+    fn tail_map(&self, from_key: K, inclusive: bool) -> Self
+    where
+        Self: Sized;
+
+    /// A view of the mappings of the keys from `from_key` to `to_key`. Throws
+    /// `IllegalArgumentException` if `from_key` is greater than `to_key`.
+    fn sub_map(&self, from_key: K, from_inclusive: bool, to_key: K, to_inclusive: bool) -> Self
+    where
+        Self: Sized;
+}
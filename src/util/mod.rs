@@ -0,0 +1,64 @@
+mod abstract_list;
+pub mod array_deque;
+mod array_list;
+pub mod arrays;
+mod calendar;
+mod collection;
+pub mod collections;
+mod comparator;
+pub mod concurrent;
+mod date;
+mod dual_pivot_quicksort;
+mod formatter;
+pub mod hash_map;
+mod hash_set;
+pub mod immutable_collections;
+mod int_summary_statistics;
+mod linked_hash_map;
+mod linked_list;
+mod locale;
+mod map;
+mod optional;
+pub mod priority_queue;
+pub mod random;
+pub mod regex;
+pub mod spliterator;
+mod stack;
+pub mod stream;
+mod string_joiner;
+mod tim_sort;
+mod time_zone;
+pub mod tree_map;
+mod tree_set;
+mod uuid;
+
+pub use abstract_list::{ListIter, SubList};
+pub use array_deque::ArrayDeque;
+pub use array_list::ArrayList;
+pub use calendar::Calendar;
+pub use collection::{Collection, Deque, List, NavigableSet, Queue, Set};
+pub use comparator::{
+    comparing, natural_order, nulls_first, nulls_last, reverse_order, Comparator, Comparing,
+    NaturalOrder, NullsFirst, NullsLast, Reversed, ThenComparing,
+};
+pub use date::Date;
+pub use formatter::{FormatArgument, Formatter};
+pub use hash_map::HashMap;
+pub use hash_set::HashSet;
+pub use immutable_collections::{ImmutableList, ImmutableMap, ImmutableSet};
+pub use int_summary_statistics::IntSummaryStatistics;
+pub use linked_hash_map::LinkedHashMap;
+pub use linked_list::{DescendingIter, LinkedList};
+pub use locale::Locale;
+pub use map::{Map, NavigableMap, SequencedMap};
+pub use optional::{Optional, OptionalDouble, OptionalInt, OptionalLong};
+pub use priority_queue::PriorityQueue;
+pub use random::{Random, SplittableRandom};
+pub use spliterator::Spliterator;
+pub use stack::Stack;
+pub use string_joiner::StringJoiner;
+pub(crate) use time_zone::custom_id;
+pub use time_zone::TimeZone;
+pub use tree_map::TreeMap;
+pub use tree_set::TreeSet;
+pub use uuid::UUID;
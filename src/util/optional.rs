@@ -0,0 +1,379 @@
+use std::fmt::Display;
+
+use crate::errors::util::NoSuchElementException;
+use crate::errors::Throwable;
+use crate::floating_decimal::{display_to_string, double_to_string};
+use crate::util::stream::Stream;
+use crate::HashCode;
+
+fn no_value() -> ! {
+    NoSuchElementException::new("No value present".to_string()).throw()
+}
+
+/// `java.util.Optional`: a value that may be absent, converting to and from `Option`.
+///
+/// `None` stands for Java's `null`, so `of_nullable` takes an `Option`. Java's mappers
+/// return `null` for an empty result; those here return an `Optional` when they can empty
+/// it, so `map`'s can't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Optional<T> {
+    value: Option<T>,
+}
+
+impl<T> Optional<T> {
+    pub fn empty() -> Self {
+        Self { value: None }
+    }
+
+    pub fn of(value: T) -> Self {
+        Self { value: Some(value) }
+    }
+
+    pub fn of_nullable(value: Option<T>) -> Self {
+        Self { value }
+    }
+
+    /// The value, throwing `NoSuchElementException` if there is none.
+    pub fn get(self) -> T {
+        self.or_else_throw()
+    }
+
+    pub fn is_present(&self) -> bool {
+        self.value.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+
+    pub fn if_present<F: FnOnce(&T)>(&self, action: F) {
+        if let Some(value) = &self.value {
+            action(value);
+        }
+    }
+
+    pub fn if_present_or_else<F: FnOnce(&T), G: FnOnce()>(&self, action: F, empty_action: G) {
+        match &self.value {
+            Some(value) => action(value),
+            None => empty_action(),
+        }
+    }
+
+    /// This if its value matches `predicate`, and otherwise an empty `Optional`.
+    pub fn filter<P: FnOnce(&T) -> bool>(self, predicate: P) -> Self {
+        Self {
+            value: self.value.filter(predicate),
+        }
+    }
+
+    pub fn map<U, F: FnOnce(T) -> U>(self, mapper: F) -> Optional<U> {
+        Optional {
+            value: self.value.map(mapper),
+        }
+    }
+
+    pub fn flat_map<U, F: FnOnce(T) -> Optional<U>>(self, mapper: F) -> Optional<U> {
+        self.value.map_or_else(Optional::empty, mapper)
+    }
+
+    /// This if it has a value, and otherwise the `Optional` from `supplier`.
+    pub fn or<F: FnOnce() -> Self>(self, supplier: F) -> Self {
+        if self.is_present() {
+            self
+        } else {
+            supplier()
+        }
+    }
+
+    /// A `Stream` of the value, if there is one.
+    pub fn stream<'a>(self) -> Stream<'a, T>
+    where
+        T: 'a,
+    {
+        Stream::of(self.value)
+    }
+
+    pub fn or_else(self, other: T) -> T {
+        self.value.unwrap_or(other)
+    }
+
+    pub fn or_else_get<F: FnOnce() -> T>(self, supplier: F) -> T {
+        self.value.unwrap_or_else(supplier)
+    }
+
+    /// The value, throwing `NoSuchElementException` if there is none.
+    pub fn or_else_throw(self) -> T {
+        self.value.unwrap_or_else(|| no_value())
+    }
+
+    /// The value, throwing the exception from `exception_supplier` if there is none.
+    pub fn or_else_throw_with<X: Throwable, F: FnOnce() -> X>(self, exception_supplier: F) -> T {
+        self.value.unwrap_or_else(|| exception_supplier().throw())
+    }
+}
+
+impl<T> Default for Optional<T> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> From<Option<T>> for Optional<T> {
+    fn from(value: Option<T>) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> From<Optional<T>> for Option<T> {
+    fn from(optional: Optional<T>) -> Self {
+        optional.value
+    }
+}
+
+/// Like Java's `toString`: `Optional[value]`, or `Optional.empty`. A float value is written
+/// as `Double.toString` writes it, like `OptionalDouble`'s.
+impl<T: Display> Display for Optional<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "Optional[{}]", display_to_string(value)),
+            None => f.write_str("Optional.empty"),
+        }
+    }
+}
+
+/// The value's hash, or 0 if there is none.
+impl<T: HashCode> HashCode for Optional<T> {
+    fn hash_code(&self) -> i32 {
+        self.value.hash_code()
+    }
+}
+
+/// Defines one of the `Optional`s of a primitive, whose value is got by `$get`.
+macro_rules! primitive_optional {
+    ($(#[$doc: meta])* $name: ident, $type: ty, $get: ident, $to_string: expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name {
+            value: Option<$type>,
+        }
+
+        impl $name {
+            pub fn empty() -> Self {
+                Self { value: None }
+            }
+
+            pub fn of(value: $type) -> Self {
+                Self { value: Some(value) }
+            }
+
+            /// The value, throwing `NoSuchElementException` if there is none.
+            pub fn $get(&self) -> $type {
+                self.or_else_throw()
+            }
+
+            pub fn is_present(&self) -> bool {
+                self.value.is_some()
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.value.is_none()
+            }
+
+            pub fn if_present<F: FnOnce($type)>(&self, action: F) {
+                if let Some(value) = self.value {
+                    action(value);
+                }
+            }
+
+            pub fn if_present_or_else<F: FnOnce($type), G: FnOnce()>(
+                &self,
+                action: F,
+                empty_action: G,
+            ) {
+                match self.value {
+                    Some(value) => action(value),
+                    None => empty_action(),
+                }
+            }
+
+            /// A `Stream` of the value, if there is one.
+            pub fn stream(&self) -> Stream<'static, $type> {
+                Stream::of(self.value)
+            }
+
+            pub fn or_else(&self, other: $type) -> $type {
+                self.value.unwrap_or(other)
+            }
+
+            pub fn or_else_get<F: FnOnce() -> $type>(&self, supplier: F) -> $type {
+                self.value.unwrap_or_else(supplier)
+            }
+
+            /// The value, throwing `NoSuchElementException` if there is none.
+            pub fn or_else_throw(&self) -> $type {
+                self.value.unwrap_or_else(|| no_value())
+            }
+
+            /// The value, throwing the exception from `exception_supplier` if there is none.
+            pub fn or_else_throw_with<X: Throwable, F: FnOnce() -> X>(
+                &self,
+                exception_supplier: F,
+            ) -> $type {
+                self.value
+                    .unwrap_or_else(|| exception_supplier().throw())
+            }
+        }
+
+        impl From<Option<$type>> for $name {
+            fn from(value: Option<$type>) -> Self {
+                Self { value }
+            }
+        }
+
+        impl From<$name> for Option<$type> {
+            fn from(optional: $name) -> Self {
+                optional.value
+            }
+        }
+
+        /// Like Java's `toString`.
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self.value {
+                    #[allow(clippy::redundant_closure_call)]
+                    Some(value) => write!(
+                        f,
+                        "{}[{}]",
+                        stringify!($name),
+                        ($to_string)(value)
+                    ),
+                    None => write!(f, "{}.empty", stringify!($name)),
+                }
+            }
+        }
+
+        /// Like the boxed primitive's `hashCode`, or 0 if there is no value.
+        impl HashCode for $name {
+            fn hash_code(&self) -> i32 {
+                self.value.hash_code()
+            }
+        }
+    };
+}
+
+primitive_optional!(
+    /// `java.util.OptionalInt`: an `int` that may be absent.
+    OptionalInt,
+    i32,
+    get_as_int,
+    |value: i32| value.to_string()
+);
+primitive_optional!(
+    /// `java.util.OptionalLong`: a `long` that may be absent.
+    OptionalLong,
+    i64,
+    get_as_long,
+    |value: i64| value.to_string()
+);
+primitive_optional!(
+    /// `java.util.OptionalDouble`: a `double` that may be absent.
+    OptionalDouble,
+    f64,
+    get_as_double,
+    double_to_string
+);
+
+impl PartialEq for OptionalInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for OptionalInt {}
+
+impl PartialEq for OptionalLong {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for OptionalLong {}
+
+/// Like Java's `equals`, by `Double.compare`: NaN equals NaN, but 0.0 doesn't equal -0.0.
+impl PartialEq for OptionalDouble {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.value, other.value) {
+            (Some(a), Some(b)) => a.to_bits() == b.to_bits() || a.is_nan() && b.is_nan(),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::errors::IllegalStateException;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn optionals() {
+        let name = Optional::of("java");
+        assert_eq!(name.to_string(), "Optional[java]");
+        assert_eq!(Optional::of(1.0f64).to_string(), "Optional[1.0]");
+        assert_eq!(Optional::of(1e-5f32).to_string(), "Optional[1.0E-5]");
+        assert_eq!(
+            Optional::of(&f64::INFINITY).to_string(),
+            "Optional[Infinity]"
+        );
+        assert_eq!(name.map(str::len).get(), 4);
+        assert_eq!(name.filter(|s| s.is_empty()), Optional::empty());
+        assert_eq!(
+            name.flat_map(|s| Optional::of_nullable(s.find('v'))),
+            Optional::of(2)
+        );
+        assert_eq!(Optional::empty().or(|| name), name);
+        assert_eq!(Optional::<&str>::empty().to_string(), "Optional.empty");
+        assert_eq!(Optional::<&str>::empty().or_else("none"), "none");
+        assert_eq!(name.stream().count(), 1);
+        assert_eq!(name.hash_code(), "java".hash_code());
+        assert_eq!(Option::from(Optional::from(Some(1))), Some(1));
+
+        let mut seen = None;
+        name.if_present_or_else(|s| seen = Some(*s), || unreachable!());
+        assert_eq!(seen, Some("java"));
+
+        assert_eq!(
+            message(|| Optional::<i32>::empty().get()),
+            "No value present"
+        );
+        assert_eq!(
+            message(|| Optional::<i32>::empty().or_else_throw_with(|| {
+                IllegalStateException::new("missing".to_string(), None)
+            })),
+            "missing"
+        );
+    }
+
+    #[test]
+    fn primitive_optionals() {
+        assert_eq!(OptionalInt::of(5).to_string(), "OptionalInt[5]");
+        assert_eq!(OptionalLong::empty().to_string(), "OptionalLong.empty");
+        assert_eq!(OptionalDouble::of(1e7).to_string(), "OptionalDouble[1.0E7]");
+        assert_eq!(OptionalDouble::of(f64::NAN), OptionalDouble::of(f64::NAN));
+        assert_ne!(OptionalDouble::of(0.0), OptionalDouble::of(-0.0));
+        assert_eq!(OptionalLong::of(1 << 32).hash_code(), 1);
+        assert_eq!(OptionalInt::empty().or_else_get(|| 3), 3);
+        assert_eq!(
+            message(|| OptionalDouble::empty().get_as_double()),
+            "No value present"
+        );
+    }
+}
@@ -0,0 +1,491 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use crate::errors::util::ConcurrentModificationException;
+use crate::errors::{
+    IllegalArgumentException, IllegalStateException, NullPointerException, Throwable,
+};
+use crate::util::collection;
+use crate::util::{Collection, Comparator, NaturalOrder, Queue};
+use crate::HashCode;
+
+fn concurrent_modification() -> ! {
+    ConcurrentModificationException::new(String::new()).throw()
+}
+
+/// The binary heap of a `PriorityQueue`, laid out like the JDK's. Each element has an id,
+/// which stands in for Java's reference equality when an iterator removes an element it
+/// returned after the heap moved it.
+struct Heap<E, C> {
+    queue: Vec<(u64, E)>,
+    next_id: u64,
+    /// Java's `modCount`: the number of structural modifications, which iterators compare
+    /// with the count they expect to fail fast.
+    mod_count: usize,
+    comparator: C,
+    /// `HashCode::is_null` of the elements, which are checked as they are added.
+    is_null: fn(&E) -> bool,
+}
+
+impl<E: Clone, C: Comparator<E>> Heap<E, C> {
+    fn compare(&self, a: &E, b: &E) -> i32 {
+        self.comparator.compare(a, b)
+    }
+
+    fn require_non_null(&self, e: &E) {
+        if (self.is_null)(e) {
+            NullPointerException::new(String::new()).throw()
+        }
+    }
+
+    fn push(&mut self, e: E) {
+        self.require_non_null(&e);
+        self.mod_count += 1;
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue.push((id, e));
+        self.sift_up(self.queue.len() - 1);
+    }
+
+    /// Moves the element at `k` up until it is no less than its parent.
+    fn sift_up(&mut self, mut k: usize) {
+        while k > 0 {
+            let parent = (k - 1) >> 1;
+            if self.compare(&self.queue[k].1, &self.queue[parent].1) >= 0 {
+                break;
+            }
+            self.queue.swap(k, parent);
+            k = parent;
+        }
+    }
+
+    /// Moves the element at `k` down until it is no greater than its children, preferring
+    /// the left child when they are equal.
+    fn sift_down(&mut self, mut k: usize) {
+        let n = self.queue.len();
+        let half = n >> 1;
+        while k < half {
+            let mut child = (k << 1) + 1;
+            let right = child + 1;
+            if right < n && self.compare(&self.queue[child].1, &self.queue[right].1) > 0 {
+                child = right;
+            }
+            if self.compare(&self.queue[k].1, &self.queue[child].1) <= 0 {
+                break;
+            }
+            self.queue.swap(k, child);
+            k = child;
+        }
+    }
+
+    fn heapify(&mut self) {
+        for i in (0..self.queue.len() >> 1).rev() {
+            self.sift_down(i);
+        }
+    }
+
+    fn poll(&mut self) -> Option<E> {
+        if self.queue.is_empty() {
+            return None;
+        }
+        self.mod_count += 1;
+        let (_, result) = self.queue.swap_remove(0);
+        if !self.queue.is_empty() {
+            self.sift_down(0);
+        }
+        Some(result)
+    }
+
+    /// Java's `removeAt`: replaces the element at `i` by the last one and sifts it down, or
+    /// up if it didn't move. Returns it if it moved up, and so before `i`.
+    fn remove_at(&mut self, i: usize) -> Option<(u64, E)> {
+        self.mod_count += 1;
+        let s = self.queue.len() - 1;
+        if s == i {
+            self.queue.pop();
+            return None;
+        }
+        self.queue.swap_remove(i);
+        let moved = self.queue[i].0;
+        self.sift_down(i);
+        if self.queue[i].0 == moved {
+            self.sift_up(i);
+            if self.queue[i].0 != moved {
+                return Some(self.queue.iter().find(|(id, _)| *id == moved)?.clone());
+            }
+        }
+        None
+    }
+}
+
+/// `java.util.PriorityQueue`: a queue ordered by a `Comparator` of its elements, their
+/// natural order by default, kept in a binary heap.
+///
+/// The heap moves elements exactly like the JDK's, so iterating over the queue, and its
+/// `Display`, give the elements in the same order as Java's. Like Java's, it can't hold
+/// `null`: adding `None` throws `NullPointerException`, and `poll` and `peek` return `None`
+/// on an empty queue. Like Java's, the queue is shared
+/// with its iterators, which throw `ConcurrentModificationException` after a structural
+/// modification other than through them. `clone` copies the elements into a new queue.
+pub struct PriorityQueue<E, C = NaturalOrder<E>> {
+    heap: Rc<RefCell<Heap<E, C>>>,
+}
+
+impl<E: Clone + Ord + HashCode> PriorityQueue<E> {
+    /// An empty queue ordered by the natural order of the elements.
+    pub fn new() -> Self {
+        Self::with_comparator(crate::util::natural_order())
+    }
+
+    /// Throws `IllegalArgumentException` if the initial capacity is less than 1.
+    pub fn with_capacity(initial_capacity: usize) -> Self {
+        Self::with_capacity_and_comparator(initial_capacity, crate::util::natural_order())
+    }
+
+    /// Java's `new PriorityQueue<>(c)`: the elements of a collection ordered by their
+    /// natural order, heapified in its iteration order.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        let queue = Self::new();
+        {
+            let mut heap = queue.heap_mut();
+            for e in c.iterator() {
+                heap.require_non_null(&e);
+                let id = heap.next_id;
+                heap.next_id += 1;
+                heap.queue.push((id, e));
+            }
+            heap.heapify();
+        }
+        queue
+    }
+}
+
+impl<E: Clone + HashCode, C: Comparator<E>> PriorityQueue<E, C> {
+    /// An empty queue ordered by the comparator.
+    pub fn with_comparator(comparator: C) -> Self {
+        Self::with_capacity_and_comparator(11, comparator)
+    }
+
+    /// Throws `IllegalArgumentException` if the initial capacity is less than 1.
+    pub fn with_capacity_and_comparator(initial_capacity: usize, comparator: C) -> Self {
+        if initial_capacity < 1 {
+            IllegalArgumentException::new(String::new(), None).throw()
+        }
+        Self {
+            heap: Rc::new(RefCell::new(Heap {
+                queue: Vec::with_capacity(initial_capacity),
+                next_id: 0,
+                mod_count: 0,
+                comparator,
+                is_null: E::is_null,
+            })),
+        }
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> PriorityQueue<E, C> {
+    fn heap(&self) -> Ref<'_, Heap<E, C>> {
+        self.heap.borrow()
+    }
+
+    fn heap_mut(&self) -> RefMut<'_, Heap<E, C>> {
+        self.heap.borrow_mut()
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Collection<E> for PriorityQueue<E, C> {
+    type Iter = Iter<E, C>;
+
+    fn size(&self) -> usize {
+        self.heap().queue.len()
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.heap().queue.iter().any(|(_, e)| e == o)
+    }
+
+    /// Iterates over the elements in the order of the heap, not of the queue.
+    fn iterator(&self) -> Iter<E, C> {
+        Iter {
+            heap: Rc::clone(&self.heap),
+            cursor: 0,
+            last_returned: None,
+            forget_me_not: VecDeque::new(),
+            last_returned_element: None,
+            expected_mod_count: self.heap().mod_count,
+        }
+    }
+
+    fn to_array(&self) -> Vec<E> {
+        self.heap().queue.iter().map(|(_, e)| e.clone()).collect()
+    }
+
+    fn add(&mut self, e: E) -> bool {
+        self.offer(e)
+    }
+
+    fn remove(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        let mut heap = self.heap_mut();
+        match heap.queue.iter().position(|(_, e)| e == o) {
+            Some(i) => {
+                heap.remove_at(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Like Java's, the filter sees every element before any is removed, and throws
+    /// `ConcurrentModificationException` if it changes the queue. This counts as a
+    /// modification even if it removes nothing.
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, mut filter: F) -> bool {
+        let (elements, expected_mod_count) = {
+            let mut heap = self.heap_mut();
+            heap.mod_count += 1;
+            (heap.queue.clone(), heap.mod_count)
+        };
+        let removed: Vec<bool> = elements.iter().map(|(_, e)| filter(e)).collect();
+        let mut heap = self.heap_mut();
+        if heap.mod_count != expected_mod_count {
+            concurrent_modification();
+        }
+        if !removed.contains(&true) {
+            return false;
+        }
+        let mut removed = removed.into_iter();
+        heap.queue.retain(|_| !removed.next().unwrap());
+        heap.heapify();
+        true
+    }
+
+    fn clear(&mut self) {
+        let mut heap = self.heap_mut();
+        heap.mod_count += 1;
+        heap.queue.clear();
+    }
+
+    /// Throws `ConcurrentModificationException` after calling the action on every element
+    /// if it changed the queue structurally.
+    fn for_each<F: FnMut(&E)>(&self, mut action: F) {
+        let (elements, expected_mod_count) = {
+            let heap = self.heap();
+            (heap.queue.clone(), heap.mod_count)
+        };
+        for (_, e) in &elements {
+            action(e);
+        }
+        if self.heap().mod_count != expected_mod_count {
+            concurrent_modification();
+        }
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Queue<E> for PriorityQueue<E, C> {
+    fn offer(&mut self, e: E) -> bool {
+        self.heap_mut().push(e);
+        true
+    }
+
+    fn poll(&mut self) -> Option<E> {
+        self.heap_mut().poll()
+    }
+
+    fn peek(&self) -> Option<E> {
+        self.heap().queue.first().map(|(_, e)| e.clone())
+    }
+}
+
+impl<E: Clone + Ord + HashCode> Default for PriorityQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone, C: Comparator<E> + Clone> Clone for PriorityQueue<E, C> {
+    fn clone(&self) -> Self {
+        let heap = self.heap();
+        Self {
+            heap: Rc::new(RefCell::new(Heap {
+                queue: heap.queue.clone(),
+                next_id: heap.next_id,
+                mod_count: 0,
+                comparator: heap.comparator.clone(),
+                is_null: heap.is_null,
+            })),
+        }
+    }
+}
+
+impl<E: Clone + Ord + HashCode> FromIterator<E> for PriorityQueue<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Extend<E> for PriorityQueue<E, C> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for e in iter {
+            self.offer(e);
+        }
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> IntoIterator for &PriorityQueue<E, C> {
+    type Item = E;
+    type IntoIter = Iter<E, C>;
+
+    fn into_iter(self) -> Iter<E, C> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the elements in the order of the heap, in brackets, separated
+/// by `, `.
+impl<E: Clone + std::fmt::Display, C: Comparator<E>> std::fmt::Display for PriorityQueue<E, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, e| write!(f, "{e}"))
+    }
+}
+
+impl<E: Clone + std::fmt::Debug, C: Comparator<E>> std::fmt::Debug for PriorityQueue<E, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.to_array()).finish()
+    }
+}
+
+/// Iterates over a `PriorityQueue` in the order of its heap.
+///
+/// Like Java's, removing an element through it can move a later element before the
+/// cursor; the iterator returns those at the end.
+pub struct Iter<E, C> {
+    heap: Rc<RefCell<Heap<E, C>>>,
+    cursor: usize,
+    last_returned: Option<usize>,
+    /// Elements moved before the cursor by `remove`, which the iterator still has to
+    /// return.
+    forget_me_not: VecDeque<(u64, E)>,
+    /// The id of the element last returned from `forget_me_not`.
+    last_returned_element: Option<u64>,
+    expected_mod_count: usize,
+}
+
+impl<E: Clone, C: Comparator<E>> Iter<E, C> {
+    pub fn has_next(&self) -> bool {
+        self.cursor < self.heap.borrow().queue.len() || !self.forget_me_not.is_empty()
+    }
+
+    /// Removes the element last returned by `next`. Throws `IllegalStateException` if there
+    /// is none.
+    pub fn remove(&mut self) {
+        let mut heap = self.heap.borrow_mut();
+        if heap.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        if let Some(last_returned) = self.last_returned.take() {
+            match heap.remove_at(last_returned) {
+                None => self.cursor -= 1,
+                Some(moved) => self.forget_me_not.push_back(moved),
+            }
+        } else if let Some(id) = self.last_returned_element.take() {
+            if let Some(i) = heap.queue.iter().position(|(e, _)| *e == id) {
+                heap.remove_at(i);
+            }
+        } else {
+            IllegalStateException::new(String::new(), None).throw()
+        }
+        self.expected_mod_count = heap.mod_count;
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Iterator for Iter<E, C> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        if !self.has_next() {
+            return None;
+        }
+        let heap = self.heap.borrow();
+        if heap.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        if self.cursor < heap.queue.len() {
+            self.last_returned = Some(self.cursor);
+            self.cursor += 1;
+            return Some(heap.queue[self.cursor - 1].1.clone());
+        }
+        self.last_returned = None;
+        let (id, e) = self.forget_me_not.pop_front()?;
+        self.last_returned_element = Some(id);
+        Some(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::{reverse_order, ArrayList};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn heap_order() {
+        let mut queue: PriorityQueue<i32> = [5, 3, 8, 1, 9, 2, 7].into_iter().collect();
+        assert_eq!(queue.to_string(), "[1, 3, 2, 5, 9, 8, 7]");
+        let mut reversed = PriorityQueue::with_comparator(reverse_order());
+        reversed.add_all(&queue);
+        assert_eq!(reversed.to_string(), "[9, 5, 8, 1, 3, 2, 7]");
+        assert_eq!(reversed.peek(), Some(9));
+
+        // Removing 3 moves 7 up before the cursor, so the iterator returns it later.
+        let mut each = queue.iterator();
+        let mut returned = Vec::new();
+        while let Some(e) = each.next() {
+            returned.push(e);
+            if e == 3 || e == 5 {
+                each.remove();
+            }
+        }
+        assert_eq!(returned, [1, 3, 5, 7, 2, 8, 9]);
+        assert_eq!(queue.to_string(), "[1, 7, 2, 8, 9]");
+        let polled: Vec<i32> = std::iter::from_fn(|| queue.poll()).collect();
+        assert_eq!(polled, [1, 2, 7, 8, 9]);
+        assert_eq!(queue.poll(), None);
+    }
+
+    #[test]
+    fn fails_fast() {
+        let mut queue: PriorityQueue<i32> = (0..5).collect();
+        let mut each = queue.iterator();
+        each.next();
+        queue.offer(-1);
+        assert_eq!(message(|| each.next()), "");
+        assert_eq!(message(|| PriorityQueue::<i32>::with_capacity(0)), "");
+    }
+
+    #[test]
+    fn rejects_null() {
+        let mut queue = PriorityQueue::new();
+        queue.offer(Some(2));
+        assert_eq!(message(|| queue.offer(None)), "");
+        assert_eq!(message(|| queue.add(None)), "");
+        assert_eq!(queue.to_array(), [Some(2)]);
+        let with_null: ArrayList<Option<i32>> = [Some(1), None].into_iter().collect();
+        assert_eq!(message(|| PriorityQueue::from_collection(&with_null)), "");
+    }
+}
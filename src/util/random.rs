@@ -0,0 +1,626 @@
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::{IllegalArgumentException, Throwable};
+
+const BAD_BOUND: &str = "bound must be positive";
+const BAD_FLOATING_BOUND: &str = "bound must be finite and positive";
+const BAD_RANGE: &str = "bound must be greater than origin";
+
+const DOUBLE_UNIT: f64 = 1.0 / (1u64 << 53) as f64;
+const FLOAT_UNIT: f32 = 1.0 / (1u32 << 24) as f32;
+
+fn illegal_argument(message: &str) -> ! {
+    IllegalArgumentException::new(message.to_string(), None).throw()
+}
+
+fn check_bound_int(bound: i32) {
+    if bound <= 0 {
+        illegal_argument(BAD_BOUND)
+    }
+}
+
+fn check_bound_long(bound: i64) {
+    if bound <= 0 {
+        illegal_argument(BAD_BOUND)
+    }
+}
+
+fn check_bound_double(bound: f64) {
+    if !(bound > 0.0 && bound < f64::INFINITY) {
+        illegal_argument(BAD_FLOATING_BOUND)
+    }
+}
+
+fn check_range_int(origin: i32, bound: i32) {
+    if origin >= bound {
+        illegal_argument(BAD_RANGE)
+    }
+}
+
+fn check_range_long(origin: i64, bound: i64) {
+    if origin >= bound {
+        illegal_argument(BAD_RANGE)
+    }
+}
+
+fn check_range_double(origin: f64, bound: f64) {
+    if !(origin < bound && (bound - origin) < f64::INFINITY) {
+        illegal_argument(BAD_RANGE)
+    }
+}
+
+/// Equivalent of Java's `Math.nextDown` for the finite values produced here.
+fn next_down(x: f64) -> f64 {
+    if x.is_nan() || x == f64::NEG_INFINITY {
+        x
+    } else if x == 0.0 {
+        -f64::from_bits(1)
+    } else if x > 0.0 {
+        f64::from_bits(x.to_bits() - 1)
+    } else {
+        f64::from_bits(x.to_bits() + 1)
+    }
+}
+
+/// The common interface of all generators, mirroring `java.util.random.RandomGenerator`.
+///
+/// Only `next_long` is required; the bounded variants use the same algorithms as the JDK's
+/// `RandomSupport`, so any implementor that matches the JDK for `next_int`/`next_long` also
+/// matches it for the derived methods.
+pub trait RandomGenerator {
+    fn next_long(&mut self) -> i64;
+
+    fn next_int(&mut self) -> i32 {
+        ((self.next_long() as u64) >> 32) as i32
+    }
+
+    fn next_boolean(&mut self) -> bool {
+        self.next_int() < 0
+    }
+
+    fn next_float(&mut self) -> f32 {
+        ((self.next_int() as u32) >> 8) as f32 * FLOAT_UNIT
+    }
+
+    fn next_double(&mut self) -> f64 {
+        ((self.next_long() as u64) >> 11) as f64 * DOUBLE_UNIT
+    }
+
+    fn next_int_bounded(&mut self, bound: i32) -> i32 {
+        check_bound_int(bound);
+        let mut r = self.next_int();
+        let m = bound - 1;
+        if bound & m == 0 {
+            r &= m;
+        } else {
+            let mut u = ((r as u32) >> 1) as i32;
+            loop {
+                r = u % bound;
+                if u.wrapping_add(m).wrapping_sub(r) >= 0 {
+                    break;
+                }
+                u = ((self.next_int() as u32) >> 1) as i32;
+            }
+        }
+        r
+    }
+
+    fn next_int_between(&mut self, origin: i32, bound: i32) -> i32 {
+        check_range_int(origin, bound);
+        bounded_next_int(self, origin, bound)
+    }
+
+    fn next_long_bounded(&mut self, bound: i64) -> i64 {
+        check_bound_long(bound);
+        let mut r = self.next_long();
+        let m = bound - 1;
+        if bound & m == 0 {
+            r &= m;
+        } else {
+            let mut u = ((r as u64) >> 1) as i64;
+            loop {
+                r = u % bound;
+                if u.wrapping_add(m).wrapping_sub(r) >= 0 {
+                    break;
+                }
+                u = ((self.next_long() as u64) >> 1) as i64;
+            }
+        }
+        r
+    }
+
+    fn next_long_between(&mut self, origin: i64, bound: i64) -> i64 {
+        check_range_long(origin, bound);
+        bounded_next_long(self, origin, bound)
+    }
+
+    fn next_double_bounded(&mut self, bound: f64) -> f64 {
+        check_bound_double(bound);
+        let r = self.next_double() * bound;
+        if r >= bound {
+            next_down(bound)
+        } else {
+            r
+        }
+    }
+
+    fn next_double_between(&mut self, origin: f64, bound: f64) -> f64 {
+        check_range_double(origin, bound);
+        bounded_next_double(self, origin, bound)
+    }
+
+    /// An endless stream of `next_int` values; use `take` for Java's sized `ints(n)`.
+    fn ints(&mut self) -> impl Iterator<Item = i32> + '_
+    where
+        Self: Sized,
+    {
+        std::iter::repeat_with(move || self.next_int())
+    }
+
+    fn ints_between(&mut self, origin: i32, bound: i32) -> impl Iterator<Item = i32> + '_
+    where
+        Self: Sized,
+    {
+        check_range_int(origin, bound);
+        std::iter::repeat_with(move || bounded_next_int(self, origin, bound))
+    }
+
+    fn longs(&mut self) -> impl Iterator<Item = i64> + '_
+    where
+        Self: Sized,
+    {
+        std::iter::repeat_with(move || self.next_long())
+    }
+
+    fn longs_between(&mut self, origin: i64, bound: i64) -> impl Iterator<Item = i64> + '_
+    where
+        Self: Sized,
+    {
+        check_range_long(origin, bound);
+        std::iter::repeat_with(move || bounded_next_long(self, origin, bound))
+    }
+
+    fn doubles(&mut self) -> impl Iterator<Item = f64> + '_
+    where
+        Self: Sized,
+    {
+        std::iter::repeat_with(move || self.next_double())
+    }
+
+    fn doubles_between(&mut self, origin: f64, bound: f64) -> impl Iterator<Item = f64> + '_
+    where
+        Self: Sized,
+    {
+        check_range_double(origin, bound);
+        std::iter::repeat_with(move || bounded_next_double(self, origin, bound))
+    }
+}
+
+fn bounded_next_int<R: RandomGenerator + ?Sized>(rng: &mut R, origin: i32, bound: i32) -> i32 {
+    let mut r = rng.next_int();
+    if origin < bound {
+        let n = bound.wrapping_sub(origin);
+        let m = n.wrapping_sub(1);
+        if n & m == 0 {
+            r = (r & m).wrapping_add(origin);
+        } else if n > 0 {
+            let mut u = ((r as u32) >> 1) as i32;
+            loop {
+                r = u % n;
+                if u.wrapping_add(m).wrapping_sub(r) >= 0 {
+                    break;
+                }
+                u = ((rng.next_int() as u32) >> 1) as i32;
+            }
+            r += origin;
+        } else {
+            while r < origin || r >= bound {
+                r = rng.next_int();
+            }
+        }
+    }
+    r
+}
+
+fn bounded_next_long<R: RandomGenerator + ?Sized>(rng: &mut R, origin: i64, bound: i64) -> i64 {
+    let mut r = rng.next_long();
+    if origin < bound {
+        let n = bound.wrapping_sub(origin);
+        let m = n.wrapping_sub(1);
+        if n & m == 0 {
+            r = (r & m).wrapping_add(origin);
+        } else if n > 0 {
+            let mut u = ((r as u64) >> 1) as i64;
+            loop {
+                r = u % n;
+                if u.wrapping_add(m).wrapping_sub(r) >= 0 {
+                    break;
+                }
+                u = ((rng.next_long() as u64) >> 1) as i64;
+            }
+            r += origin;
+        } else {
+            while r < origin || r >= bound {
+                r = rng.next_long();
+            }
+        }
+    }
+    r
+}
+
+fn bounded_next_double<R: RandomGenerator + ?Sized>(rng: &mut R, origin: f64, bound: f64) -> f64 {
+    let mut r = rng.next_double();
+    if origin < bound {
+        if bound - origin < f64::INFINITY {
+            r = r * (bound - origin) + origin;
+        } else {
+            let half_origin = 0.5 * origin;
+            r = (r * (0.5 * bound - half_origin) + half_origin) * 2.0;
+        }
+        if r >= bound {
+            r = next_down(bound);
+        }
+    }
+    r
+}
+
+fn since_epoch() -> std::time::Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+pub(crate) fn nano_time() -> i64 {
+    since_epoch().as_nanos() as i64
+}
+
+pub(crate) fn current_time_millis() -> i64 {
+    since_epoch().as_millis() as i64
+}
+
+/// Port of fdlibm's `__ieee754_log`, which `StrictMath.log` is specified to match.
+///
+/// `Random::next_gaussian` depends on it to reproduce the JDK's sequence bit for bit.
+fn strict_log(mut x: f64) -> f64 {
+    const LN2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+    const LN2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+    const TWO54: f64 = f64::from_bits(0x4350_0000_0000_0000);
+    const LG1: f64 = f64::from_bits(0x3fe5_5555_5555_5593);
+    const LG2: f64 = f64::from_bits(0x3fd9_9999_9997_fa04);
+    const LG3: f64 = f64::from_bits(0x3fd2_4924_9422_9359);
+    const LG4: f64 = f64::from_bits(0x3fcc_71c5_1d8e_78af);
+    const LG5: f64 = f64::from_bits(0x3fc7_4664_96cb_03de);
+    const LG6: f64 = f64::from_bits(0x3fc3_9a09_d078_c69f);
+    const LG7: f64 = f64::from_bits(0x3fc2_f112_df3e_5244);
+
+    let high = |x: f64| (x.to_bits() >> 32) as i32;
+    let low = |x: f64| x.to_bits() as u32;
+    let with_high = |x: f64, hi: i32| f64::from_bits(((hi as u32 as u64) << 32) | low(x) as u64);
+
+    let mut hx = high(x);
+    let lx = low(x);
+    let mut k = 0;
+
+    if hx < 0x0010_0000 {
+        if ((hx & 0x7fff_ffff) as u32 | lx) == 0 {
+            return f64::NEG_INFINITY;
+        }
+        if hx < 0 {
+            return f64::NAN;
+        }
+        k -= 54;
+        x *= TWO54;
+        hx = high(x);
+    }
+    if hx >= 0x7ff0_0000 {
+        return x + x;
+    }
+    k += (hx >> 20) - 1023;
+    hx &= 0x000f_ffff;
+    let i = (hx + 0x95f64) & 0x10_0000;
+    x = with_high(x, hx | (i ^ 0x3ff0_0000));
+    k += i >> 20;
+    let f = x - 1.0;
+    if (0x000f_ffff & (2 + hx)) < 3 {
+        if f == 0.0 {
+            if k == 0 {
+                return 0.0;
+            }
+            let dk = k as f64;
+            return dk * LN2_HI + dk * LN2_LO;
+        }
+        let r = f * f * (0.5 - f64::from_bits(0x3fd5_5555_5555_5555) * f);
+        if k == 0 {
+            return f - r;
+        }
+        let dk = k as f64;
+        return dk * LN2_HI - ((r - dk * LN2_LO) - f);
+    }
+    let s = f / (2.0 + f);
+    let dk = k as f64;
+    let z = s * s;
+    let mut i = hx - 0x6147a;
+    let w = z * z;
+    let j = 0x6b851 - hx;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    i |= j;
+    let r = t2 + t1;
+    if i > 0 {
+        let hfsq = 0.5 * f * f;
+        if k == 0 {
+            f - (hfsq - s * (hfsq + r))
+        } else {
+            dk * LN2_HI - ((hfsq - (s * (hfsq + r) + dk * LN2_LO)) - f)
+        }
+    } else if k == 0 {
+        f - s * (f - r)
+    } else {
+        dk * LN2_HI - ((s * (f - r) - dk * LN2_LO) - f)
+    }
+}
+
+const MULTIPLIER: i64 = 0x5_DEEC_E66D;
+const ADDEND: i64 = 0xB;
+const MASK: i64 = (1 << 48) - 1;
+
+static SEED_UNIQUIFIER: AtomicI64 = AtomicI64::new(8_682_522_807_148_012);
+
+/// `java.util.Random`: the 48-bit linear congruential generator, producing exactly the same
+/// sequences as the JDK for the same seed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Random {
+    seed: i64,
+    next_next_gaussian: Option<f64>,
+}
+
+impl Random {
+    pub fn new() -> Self {
+        let uniquifier = SEED_UNIQUIFIER
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(current.wrapping_mul(1_181_783_497_276_652_981))
+            })
+            .unwrap()
+            .wrapping_mul(1_181_783_497_276_652_981);
+        Self::with_seed(uniquifier ^ nano_time())
+    }
+
+    pub fn with_seed(seed: i64) -> Self {
+        Self {
+            seed: Self::initial_scramble(seed),
+            next_next_gaussian: None,
+        }
+    }
+
+    fn initial_scramble(seed: i64) -> i64 {
+        (seed ^ MULTIPLIER) & MASK
+    }
+
+    pub fn set_seed(&mut self, seed: i64) {
+        self.seed = Self::initial_scramble(seed);
+        self.next_next_gaussian = None;
+    }
+
+    /// Advances the generator and returns its top `bits` bits, like `Random.next(int)`.
+    pub fn next(&mut self, bits: u32) -> i32 {
+        self.seed = self.seed.wrapping_mul(MULTIPLIER).wrapping_add(ADDEND) & MASK;
+        ((self.seed as u64) >> (48 - bits)) as i32
+    }
+
+    pub fn next_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(4) {
+            let mut rnd = self.next_int();
+            for byte in chunk {
+                *byte = rnd as u8;
+                rnd >>= 8;
+            }
+        }
+    }
+
+    /// Gaussian values from Marsaglia's polar method; every second call returns the value
+    /// cached by the previous one, as in the JDK.
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(gaussian) = self.next_next_gaussian.take() {
+            return gaussian;
+        }
+        loop {
+            let v1 = 2.0 * self.next_double() - 1.0;
+            let v2 = 2.0 * self.next_double() - 1.0;
+            let s = v1 * v1 + v2 * v2;
+            if s < 1.0 && s != 0.0 {
+                let multiplier = (-2.0 * strict_log(s) / s).sqrt();
+                self.next_next_gaussian = Some(v2 * multiplier);
+                return v1 * multiplier;
+            }
+        }
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomGenerator for Random {
+    fn next_long(&mut self) -> i64 {
+        ((self.next(32) as i64) << 32).wrapping_add(self.next(32) as i64)
+    }
+
+    fn next_int(&mut self) -> i32 {
+        self.next(32)
+    }
+
+    fn next_boolean(&mut self) -> bool {
+        self.next(1) != 0
+    }
+
+    fn next_float(&mut self) -> f32 {
+        self.next(24) as f32 * FLOAT_UNIT
+    }
+
+    fn next_double(&mut self) -> f64 {
+        (((self.next(26) as i64) << 27) + self.next(27) as i64) as f64 * DOUBLE_UNIT
+    }
+
+    fn next_int_bounded(&mut self, bound: i32) -> i32 {
+        check_bound_int(bound);
+        let mut r = self.next(31);
+        let m = bound - 1;
+        if bound & m == 0 {
+            r = ((bound as i64 * r as i64) >> 31) as i32;
+        } else {
+            let mut u = r;
+            loop {
+                r = u % bound;
+                if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                    break;
+                }
+                u = self.next(31);
+            }
+        }
+        r
+    }
+}
+
+const GOLDEN_GAMMA: i64 = 0x9e37_79b9_7f4a_7c15_u64 as i64;
+
+pub(crate) fn mix64(mut z: i64) -> i64 {
+    z = (z ^ ((z as u64) >> 30) as i64).wrapping_mul(0xbf58_476d_1ce4_e5b9_u64 as i64);
+    z = (z ^ ((z as u64) >> 27) as i64).wrapping_mul(0x94d0_49bb_1331_11eb_u64 as i64);
+    z ^ ((z as u64) >> 31) as i64
+}
+
+fn mix32(mut z: i64) -> i32 {
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0x62a9_d9ed_7997_05f5_u64 as i64);
+    (((z ^ ((z as u64) >> 28) as i64).wrapping_mul(0xcb24_d0a5_c88c_35b3_u64 as i64) as u64) >> 32)
+        as i32
+}
+
+fn mix_gamma(mut z: i64) -> i64 {
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xff51_afd7_ed55_8ccd_u64 as i64);
+    z = (z ^ ((z as u64) >> 33) as i64).wrapping_mul(0xc4ce_b9fe_1a85_ec53_u64 as i64);
+    z = (z ^ ((z as u64) >> 33) as i64) | 1;
+    let n = (z ^ ((z as u64) >> 1) as i64).count_ones();
+    if n < 24 {
+        z ^ 0xaaaa_aaaa_aaaa_aaaa_u64 as i64
+    } else {
+        z
+    }
+}
+
+static DEFAULT_GEN: AtomicI64 = AtomicI64::new(0);
+
+/// `java.util.SplittableRandom`: the SplitMix64 generator, with `split` producing an
+/// independent generator exactly as the JDK does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplittableRandom {
+    seed: i64,
+    gamma: i64,
+}
+
+impl SplittableRandom {
+    pub fn new() -> Self {
+        let _ = DEFAULT_GEN.compare_exchange(
+            0,
+            mix64(current_time_millis()) ^ mix64(nano_time()),
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+        let s = DEFAULT_GEN.fetch_add(GOLDEN_GAMMA.wrapping_mul(2), Ordering::Relaxed);
+        Self {
+            seed: mix64(s),
+            gamma: mix_gamma(s.wrapping_add(GOLDEN_GAMMA)),
+        }
+    }
+
+    pub fn with_seed(seed: i64) -> Self {
+        Self {
+            seed,
+            gamma: GOLDEN_GAMMA,
+        }
+    }
+
+    fn next_seed(&mut self) -> i64 {
+        self.seed = self.seed.wrapping_add(self.gamma);
+        self.seed
+    }
+
+    pub fn split(&mut self) -> Self {
+        let seed = self.next_long();
+        let gamma = mix_gamma(self.next_seed());
+        Self { seed, gamma }
+    }
+}
+
+impl Default for SplittableRandom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomGenerator for SplittableRandom {
+    fn next_long(&mut self) -> i64 {
+        mix64(self.next_seed())
+    }
+
+    fn next_int(&mut self) -> i32 {
+        mix32(self.next_seed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn random_matches_jdk() {
+        let mut random = Random::with_seed(42);
+        assert_eq!(random.next_int(), -1170105035);
+        assert_eq!(random.next_int_bounded(10), 3);
+        assert_eq!(random.next_int_bounded(16), 10);
+        assert_eq!(random.next_long(), 884324181205335268);
+        assert_eq!(random.next_double(), 0.9420735430282128);
+        assert_eq!(random.next_float(), 0.70771056);
+        assert!(random.next_boolean());
+        assert_eq!(random.next_gaussian(), -0.8761154839986063);
+        assert_eq!(random.next_gaussian(), -0.10449443722947346);
+        assert_eq!(random.next_int_between(5, 100), 77);
+        assert_eq!(random.next_long_bounded(1000), 280);
+        assert_eq!(random.next_double_between(1.0, 2.0), 1.4636535758091533);
+
+        let ints: Vec<i32> = Random::with_seed(7).ints_between(0, 100).take(5).collect();
+        assert_eq!(ints, [36, 64, 85, 44, 80]);
+        let ints: Vec<i32> = Random::with_seed(7).ints_between(0, 64).take(5).collect();
+        assert_eq!(ints, [25, 24, 26, 57, 9]);
+
+        let mut bytes = [0u8; 6];
+        Random::with_seed(1).next_bytes(&mut bytes);
+        assert_eq!(bytes.map(|b| b as i8), [115, -43, 26, -69, -40, -100]);
+
+        let mut random = Random::with_seed(123);
+        let sum: f64 = (0..100_000).map(|_| random.next_gaussian()).sum();
+        assert_eq!(sum, 233.0653033102171);
+    }
+
+    #[test]
+    fn splittable_random_matches_jdk() {
+        let mut random = SplittableRandom::with_seed(42);
+        assert_eq!(random.next_int(), -491277234);
+        assert_eq!(random.next_long(), 2949826092126892291);
+        assert_eq!(random.next_int_bounded(10), 1);
+        assert_eq!(random.next_double(), 0.34419071652363753);
+        assert!(random.next_boolean());
+
+        let mut split = random.split();
+        assert_eq!(split.next_long(), 3683887314927460773);
+        assert_eq!(random.next_long(), -3677692746721775708);
+    }
+
+    #[test]
+    #[should_panic(expected = "bound must be positive")]
+    fn non_positive_bound() {
+        Random::with_seed(0).next_int_bounded(0);
+    }
+}
@@ -0,0 +1,2223 @@
+//! `java.util.regex`: a backtracking implementation of Java's regular expressions.
+//!
+//! The syntax is Java's, including possessive quantifiers, atomic groups, lookbehind,
+//! named groups, `\Q...\E` quoting, Unicode properties and the inline flags `idmsuxU`.
+//! Canonical equivalence (`CANON_EQ`) and grapheme clusters (`\X`, `\b{g}`) are not
+//! supported. Matching works on `char`s, so a match can never split a surrogate pair the
+//! way a Java match on UTF-16 can; all indices are byte offsets into the input.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::character::{self, UnicodeBlock, UnicodeScript};
+use crate::errors::util::regex::PatternSyntaxException;
+use crate::errors::{
+    IllegalArgumentException, IllegalStateException, IndexOutOfBoundsException, Throwable,
+};
+
+const INFINITY: usize = usize::MAX;
+/// The greatest width of a node, which like a Java `int` may not exceed `i32::MAX`.
+const MAX_WIDTH: usize = i32::MAX as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quantifier {
+    Greedy,
+    Lazy,
+    Possessive,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Property {
+    /// A set of general categories, one bit per `character::get_type` value.
+    Categories(u32),
+    Test(fn(i32) -> bool),
+    Script(UnicodeScript),
+    Block(UnicodeBlock),
+}
+
+impl Property {
+    fn test(self, ch: char) -> bool {
+        let code_point = ch as i32;
+        match self {
+            Property::Categories(mask) => mask & (1 << character::get_type(code_point)) != 0,
+            Property::Test(test) => test(code_point),
+            Property::Script(script) => UnicodeScript::of(code_point) == script,
+            Property::Block(block) => UnicodeBlock::of(code_point) == Some(block),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ClassItem {
+    Char(char),
+    Range(char, char),
+    Property(Property, bool),
+    Class(CharClass),
+}
+
+/// A character class: the intersection of unions of items, possibly negated as a whole.
+#[derive(Debug, Clone)]
+struct CharClass {
+    operands: Vec<Vec<ClassItem>>,
+    negated: bool,
+    flags: i32,
+}
+
+impl CharClass {
+    fn contains(&self, ch: char) -> bool {
+        let in_item = |item: &ClassItem| match *item {
+            ClassItem::Char(c) => matches_ignoring_case(self.flags, ch, |ch| ch == c),
+            ClassItem::Range(low, high) => {
+                matches_ignoring_case(self.flags, ch, |ch| (low..=high).contains(&ch))
+            }
+            ClassItem::Property(property, negated) => property.test(ch) != negated,
+            ClassItem::Class(ref class) => class.contains(ch),
+        };
+        let contained = self
+            .operands
+            .iter()
+            .filter(|operand| !operand.is_empty())
+            .all(|operand| operand.iter().any(in_item));
+        contained != self.negated
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Empty,
+    Char(char, i32),
+    Any(i32),
+    Class(Box<CharClass>),
+    /// `^` without `MULTILINE`, and `\A`.
+    Begin,
+    /// `^` with `MULTILINE`.
+    Caret(i32),
+    /// `$` and `\Z`; the flags say whether it is multiline and which line terminators count.
+    Dollar(i32),
+    /// `\z`.
+    End,
+    /// `\G`.
+    LastMatchEnd,
+    WordBoundary(bool, i32),
+    Group(Box<Node>, Option<usize>),
+    Concat(Vec<Node>),
+    Alternation(Vec<Node>),
+    /// A repetition, with the index of its record of failed positions if it keeps one.
+    Repeat(Box<Node>, usize, usize, Quantifier, Option<usize>),
+    /// A lookahead, which may be negated.
+    Look(Box<Node>, bool),
+    /// A lookbehind, with the least and greatest number of characters it looks back over,
+    /// which may be negated.
+    Behind(Box<Node>, usize, usize, bool),
+    Atomic(Box<Node>),
+    BackReference(usize, i32),
+}
+
+impl Node {
+    /// Whether the node always matches exactly one character, so repeating it needs no
+    /// stack of iterations.
+    fn is_single_char(&self) -> bool {
+        matches!(self, Node::Char(..) | Node::Any(_) | Node::Class(_))
+    }
+
+    /// How many characters the node can match, worked out as Java's `TreeInfo` does it.
+    fn width(&self) -> Width {
+        let fixed = |width| Width {
+            min: width,
+            max: Some(width),
+            deterministic: true,
+        };
+        match self {
+            Node::Char(..) | Node::Any(_) | Node::Class(_) => fixed(1),
+            Node::Empty
+            | Node::Begin
+            | Node::Caret(_)
+            | Node::Dollar(_)
+            | Node::End
+            | Node::LastMatchEnd
+            | Node::WordBoundary(..)
+            | Node::Look(..)
+            | Node::Behind(..) => fixed(0),
+            Node::Group(inner, _) | Node::Atomic(inner) => inner.width(),
+            Node::Concat(nodes) => nodes.iter().fold(fixed(0), |width, node| {
+                let next = node.width();
+                Width {
+                    min: (width.min + next.min).min(MAX_WIDTH),
+                    max: width
+                        .max
+                        .zip(next.max)
+                        .map(|(max, next)| (max + next).min(MAX_WIDTH)),
+                    deterministic: width.deterministic && next.deterministic,
+                }
+            }),
+            Node::Alternation(alternatives) => {
+                let widths: Vec<Width> = alternatives.iter().map(Node::width).collect();
+                Width {
+                    min: widths.iter().map(|width| width.min).min().unwrap_or(0),
+                    max: widths
+                        .iter()
+                        .map(|width| width.max)
+                        .collect::<Option<Vec<_>>>()
+                        .map(|maxes| maxes.into_iter().max().unwrap_or(0)),
+                    deterministic: false,
+                }
+            }
+            Node::Repeat(inner, min, max, ..) => {
+                let width = inner.width();
+                // As in Java, only a repetition of something that always matches the same
+                // way, or a `?`, has an obvious maximum.
+                let max_width = if width.deterministic || (*min, *max) == (0, 1) {
+                    width
+                        .max
+                        .and_then(|width| width.checked_mul((*max).min(MAX_WIDTH)))
+                        .filter(|&width| width <= MAX_WIDTH)
+                } else {
+                    None
+                };
+                Width {
+                    min: width.min.saturating_mul(*min).min(MAX_WIDTH),
+                    max: max_width,
+                    deterministic: width.deterministic && min == max,
+                }
+            }
+            Node::BackReference(..) => Width {
+                min: 0,
+                max: None,
+                deterministic: false,
+            },
+        }
+    }
+
+    /// Numbers the repetitions that record where they failed, which like Java's `Loop`
+    /// nodes with a `posIndex` are the greedy, unbounded repetitions of groups that can
+    /// match in more than one way and aren't inside another repetition. Unlike Java, none
+    /// inside a lookbehind does, as whether it fails there depends on where it looks back
+    /// from.
+    fn number_loops(&mut self, nested: bool, count: &mut usize) {
+        match self {
+            Node::Repeat(inner, _, max, quantifier, index) => {
+                if !nested
+                    && *quantifier == Quantifier::Greedy
+                    && *max == INFINITY
+                    && matches!(**inner, Node::Group(..))
+                    && !inner.width().deterministic
+                {
+                    *index = Some(*count);
+                    *count += 1;
+                }
+                inner.number_loops(true, count);
+            }
+            Node::Group(inner, _) | Node::Look(inner, _) | Node::Atomic(inner) => {
+                inner.number_loops(nested, count)
+            }
+            Node::Behind(inner, ..) => inner.number_loops(true, count),
+            Node::Concat(nodes) | Node::Alternation(nodes) => nodes
+                .iter_mut()
+                .for_each(|node| node.number_loops(nested, count)),
+            _ => {}
+        }
+    }
+}
+
+/// The least and greatest number of characters a node matches.
+#[derive(Debug, Clone, Copy)]
+struct Width {
+    min: usize,
+    /// `None` if there is no obvious maximum, as for a back reference.
+    max: Option<usize>,
+    /// Whether the node always matches the same way, so that a repetition of it can be
+    /// measured.
+    deterministic: bool,
+}
+
+fn matches_ignoring_case(flags: i32, ch: char, test: impl Fn(char) -> bool) -> bool {
+    if test(ch) {
+        return true;
+    }
+    if flags & Pattern::CASE_INSENSITIVE == 0 {
+        return false;
+    }
+    if flags & Pattern::UNICODE_CASE != 0 {
+        let to_char = |code_point: i32| char::from_u32(code_point as u32);
+        let upper = to_char(character::to_upper_case(ch as i32));
+        let lower = to_char(character::to_lower_case(ch as i32));
+        upper.is_some_and(&test) || lower.is_some_and(&test)
+    } else {
+        ch.is_ascii() && (test(ch.to_ascii_uppercase()) || test(ch.to_ascii_lowercase()))
+    }
+}
+
+fn chars_match(flags: i32, pattern: char, ch: char) -> bool {
+    if pattern == ch {
+        return true;
+    }
+    if flags & Pattern::CASE_INSENSITIVE == 0 {
+        return false;
+    }
+    if flags & Pattern::UNICODE_CASE != 0 {
+        let upper = (
+            character::to_upper_case(pattern as i32),
+            character::to_upper_case(ch as i32),
+        );
+        upper.0 == upper.1 || character::to_lower_case(upper.0) == character::to_lower_case(upper.1)
+    } else {
+        pattern.eq_ignore_ascii_case(&ch)
+    }
+}
+
+fn is_line_terminator(flags: i32, ch: char) -> bool {
+    if flags & Pattern::UNIX_LINES != 0 {
+        ch == '\n'
+    } else {
+        matches!(ch, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')
+    }
+}
+
+fn is_ascii_space(code_point: i32) -> bool {
+    matches!(code_point, 0x20 | 0x09..=0x0D)
+}
+
+fn is_ascii_word(code_point: i32) -> bool {
+    code_point == '_' as i32 || is_ascii_alnum(code_point)
+}
+
+fn is_ascii_alnum(code_point: i32) -> bool {
+    char::from_u32(code_point as u32).is_some_and(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn is_ascii_digit(code_point: i32) -> bool {
+    ('0' as i32..='9' as i32).contains(&code_point)
+}
+
+fn is_ascii_punct(code_point: i32) -> bool {
+    char::from_u32(code_point as u32).is_some_and(|ch| ch.is_ascii_punctuation())
+}
+
+fn is_horizontal_space(code_point: i32) -> bool {
+    matches!(
+        code_point,
+        0x20 | 0x09 | 0xA0 | 0x1680 | 0x180E | 0x2000..=0x200A | 0x202F | 0x205F | 0x3000
+    )
+}
+
+fn is_vertical_space(code_point: i32) -> bool {
+    matches!(code_point, 0x0A..=0x0D | 0x85 | 0x2028 | 0x2029)
+}
+
+fn is_white_space(code_point: i32) -> bool {
+    matches!(code_point, 0x09..=0x0D | 0x85) || character::is_space_char(code_point)
+}
+
+fn is_join_control(code_point: i32) -> bool {
+    code_point == 0x200C || code_point == 0x200D
+}
+
+fn is_hex_digit(code_point: i32) -> bool {
+    character::is_digit(code_point)
+        || matches!(
+            code_point,
+            0x30..=0x39 | 0x41..=0x46 | 0x61..=0x66 | 0xFF10..=0xFF19 | 0xFF21..=0xFF26
+                | 0xFF41..=0xFF46
+        )
+}
+
+fn is_unicode_word(code_point: i32) -> bool {
+    character::is_alphabetic(code_point)
+        || matches!(
+            character::get_type(code_point),
+            character::NON_SPACING_MARK
+                | character::ENCLOSING_MARK
+                | character::COMBINING_SPACING_MARK
+                | character::DECIMAL_DIGIT_NUMBER
+                | character::CONNECTOR_PUNCTUATION
+        )
+        || is_join_control(code_point)
+}
+
+fn is_punctuation(code_point: i32) -> bool {
+    categories(&[20, 21, 22, 23, 24, 29, 30]) & (1 << character::get_type(code_point)) != 0
+}
+
+fn is_graph(code_point: i32) -> bool {
+    let excluded = categories(&[
+        character::SPACE_SEPARATOR,
+        character::LINE_SEPARATOR,
+        character::PARAGRAPH_SEPARATOR,
+        character::CONTROL,
+        character::SURROGATE,
+        character::UNASSIGNED,
+    ]);
+    excluded & (1 << character::get_type(code_point)) == 0
+}
+
+const fn categories(types: &[i8]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < types.len() {
+        mask |= 1 << types[i];
+        i += 1;
+    }
+    mask
+}
+
+const LETTERS: u32 = categories(&[1, 2, 3, 4, 5]);
+const CASED_LETTERS: u32 = categories(&[1, 2, 3]);
+
+/// General category names and their groups, as in `\p{Lu}` or `\p{IsL}`.
+fn category(name: &str, case_insensitive: bool) -> Option<u32> {
+    let mask = match name {
+        "Cn" => categories(&[0]),
+        "Lu" | "Ll" | "Lt" if case_insensitive => CASED_LETTERS,
+        "Lu" => categories(&[1]),
+        "Ll" => categories(&[2]),
+        "Lt" => categories(&[3]),
+        "Lm" => categories(&[4]),
+        "Lo" => categories(&[5]),
+        "Mn" => categories(&[6]),
+        "Me" => categories(&[7]),
+        "Mc" => categories(&[8]),
+        "Nd" => categories(&[9]),
+        "Nl" => categories(&[10]),
+        "No" => categories(&[11]),
+        "Zs" => categories(&[12]),
+        "Zl" => categories(&[13]),
+        "Zp" => categories(&[14]),
+        "Cc" => categories(&[15]),
+        "Cf" => categories(&[16]),
+        "Co" => categories(&[18]),
+        "Cs" => categories(&[19]),
+        "Pd" => categories(&[20]),
+        "Ps" => categories(&[21]),
+        "Pe" => categories(&[22]),
+        "Pc" => categories(&[23]),
+        "Po" => categories(&[24]),
+        "Sm" => categories(&[25]),
+        "Sc" => categories(&[26]),
+        "Sk" => categories(&[27]),
+        "So" => categories(&[28]),
+        "Pi" => categories(&[29]),
+        "Pf" => categories(&[30]),
+        "L" => LETTERS,
+        "M" => categories(&[6, 7, 8]),
+        "N" => categories(&[9, 10, 11]),
+        "Z" => categories(&[12, 13, 14]),
+        "C" => categories(&[0, 15, 16, 18, 19]),
+        "P" => categories(&[20, 21, 22, 23, 24, 29, 30]),
+        "S" => categories(&[25, 26, 27, 28]),
+        "LC" => CASED_LETTERS,
+        "LD" => LETTERS | categories(&[9]),
+        _ => return None,
+    };
+    Some(mask)
+}
+
+/// The names `CharPredicates.forProperty` knows besides general categories: POSIX classes
+/// (ASCII-only) and the `java*` methods of `Character`.
+fn named_property(name: &str, case_insensitive: bool) -> Option<Property> {
+    if let Some(mask) = category(name, case_insensitive) {
+        return Some(Property::Categories(mask));
+    }
+    let test: fn(i32) -> bool = match name {
+        "all" => |_| true,
+        "L1" => |cp| (0..=0xFF).contains(&cp),
+        "ASCII" => |cp| (0..=0x7F).contains(&cp),
+        "Alnum" => is_ascii_alnum,
+        "Alpha" => |cp| is_ascii_alnum(cp) && !is_ascii_digit(cp),
+        "Blank" => |cp| cp == 0x20 || cp == 0x09,
+        "Cntrl" => |cp| (0..0x20).contains(&cp) || cp == 0x7F,
+        "Digit" => is_ascii_digit,
+        "Graph" => |cp| is_ascii_alnum(cp) || is_ascii_punct(cp),
+        "Lower" | "Upper" if case_insensitive => |cp| is_ascii_alnum(cp) && !is_ascii_digit(cp),
+        "Lower" => |cp| ('a' as i32..='z' as i32).contains(&cp),
+        "Upper" => |cp| ('A' as i32..='Z' as i32).contains(&cp),
+        "Print" => |cp| (0x20..0x7F).contains(&cp),
+        "Punct" => is_ascii_punct,
+        "Space" => is_ascii_space,
+        "XDigit" => |cp| is_ascii_digit(cp) || matches!(cp, 0x41..=0x46 | 0x61..=0x66),
+        "javaLowerCase" | "javaUpperCase" | "javaTitleCase" if case_insensitive => |cp| {
+            character::is_lower_case(cp)
+                || character::is_upper_case(cp)
+                || character::is_title_case(cp)
+        },
+        "javaLowerCase" => character::is_lower_case,
+        "javaUpperCase" => character::is_upper_case,
+        "javaTitleCase" => character::is_title_case,
+        "javaAlphabetic" => character::is_alphabetic,
+        "javaIdeographic" => character::is_ideographic,
+        "javaDigit" => character::is_digit,
+        "javaDefined" => character::is_defined,
+        "javaLetter" => character::is_letter,
+        "javaLetterOrDigit" => character::is_letter_or_digit,
+        "javaJavaIdentifierStart" => character::is_java_identifier_start,
+        "javaJavaIdentifierPart" => character::is_java_identifier_part,
+        "javaIdentifierIgnorable" => character::is_identifier_ignorable,
+        "javaSpaceChar" => character::is_space_char,
+        "javaWhitespace" => character::is_whitespace,
+        "javaISOControl" => character::is_iso_control,
+        "javaMirrored" => character::is_mirrored,
+        _ => return None,
+    };
+    Some(Property::Test(test))
+}
+
+/// Binary Unicode properties, as in `\p{IsAlphabetic}`; names are case-insensitive and
+/// may use underscores.
+fn binary_property(name: &str, case_insensitive: bool) -> Option<Property> {
+    let test: fn(i32) -> bool = match name.to_ascii_uppercase().as_str() {
+        "ALPHABETIC" => character::is_alphabetic,
+        "ASSIGNED" => character::is_defined,
+        "CONTROL" => |cp| character::get_type(cp) == character::CONTROL,
+        "HEXDIGIT" | "HEX_DIGIT" => is_hex_digit,
+        "IDEOGRAPHIC" => character::is_ideographic,
+        "JOINCONTROL" | "JOIN_CONTROL" => is_join_control,
+        "LETTER" => character::is_letter,
+        "LOWERCASE" | "UPPERCASE" | "TITLECASE" if case_insensitive => |cp| {
+            character::is_lower_case(cp)
+                || character::is_upper_case(cp)
+                || character::is_title_case(cp)
+        },
+        "LOWERCASE" => character::is_lower_case,
+        "UPPERCASE" => character::is_upper_case,
+        "TITLECASE" => character::is_title_case,
+        "NONCHARACTERCODEPOINT" | "NONCHARACTER_CODE_POINT" => {
+            |cp| (cp & 0xFFFE) == 0xFFFE || (0xFDD0..=0xFDEF).contains(&cp)
+        }
+        "PUNCTUATION" => is_punctuation,
+        "WHITESPACE" | "WHITE_SPACE" => is_white_space,
+        "WORD" => is_unicode_word,
+        "DIGIT" => character::is_digit,
+        "ALNUM" => |cp| character::is_alphabetic(cp) || character::is_digit(cp),
+        "BLANK" => |cp| cp == 0x09 || character::get_type(cp) == character::SPACE_SEPARATOR,
+        "GRAPH" => is_graph,
+        "PRINT" => |cp| {
+            (is_graph(cp) || cp == 0x09 || character::get_type(cp) == character::SPACE_SEPARATOR)
+                && character::get_type(cp) != character::CONTROL
+        },
+        _ => return None,
+    };
+    Some(Property::Test(test))
+}
+
+/// The POSIX classes under `UNICODE_CHARACTER_CLASS`, which use Unicode properties.
+fn unicode_posix_property(name: &str) -> Option<Property> {
+    let test: fn(i32) -> bool = match name {
+        "ASCII" => |cp| (0..=0x7F).contains(&cp),
+        "Alnum" => |cp| character::is_alphabetic(cp) || character::is_digit(cp),
+        "Alpha" => character::is_alphabetic,
+        "Blank" => |cp| cp == 0x09 || character::get_type(cp) == character::SPACE_SEPARATOR,
+        "Cntrl" => |cp| character::get_type(cp) == character::CONTROL,
+        "Digit" => character::is_digit,
+        "Graph" => is_graph,
+        "Lower" => character::is_lower_case,
+        "Print" => |cp| {
+            (is_graph(cp) || cp == 0x09 || character::get_type(cp) == character::SPACE_SEPARATOR)
+                && character::get_type(cp) != character::CONTROL
+        },
+        "Punct" => is_punctuation,
+        "Space" => is_white_space,
+        "Upper" => character::is_upper_case,
+        "XDigit" => is_hex_digit,
+        _ => return None,
+    };
+    Some(Property::Test(test))
+}
+
+/// The classes behind `\d`, `\s`, `\w` and the rest, which follow Unicode only with
+/// `UNICODE_CHARACTER_CLASS`.
+fn predefined_class(escape: char, flags: i32) -> Option<(Property, bool)> {
+    let unicode = flags & Pattern::UNICODE_CHARACTER_CLASS != 0;
+    let test: fn(i32) -> bool = match escape.to_ascii_lowercase() {
+        'd' if unicode => character::is_digit,
+        'd' => is_ascii_digit,
+        's' if unicode => is_white_space,
+        's' => is_ascii_space,
+        'w' if unicode => is_unicode_word,
+        'w' => is_ascii_word,
+        'h' => is_horizontal_space,
+        'v' => is_vertical_space,
+        _ => return None,
+    };
+    Some((Property::Test(test), escape.is_ascii_uppercase()))
+}
+
+enum Escape {
+    Char(char),
+    Property(Property, bool),
+    Node(Node),
+    Quote(Vec<char>),
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+    flags: i32,
+    group_count: usize,
+    group_names: HashMap<String, usize>,
+    has_back_references: bool,
+}
+
+type ParseResult<T> = Result<T, PatternSyntaxException>;
+
+impl Parser<'_> {
+    fn error(&self, description: impl Into<String>, index: usize) -> PatternSyntaxException {
+        let utf16_index: usize = self.chars[..index.min(self.chars.len())]
+            .iter()
+            .map(|ch| ch.len_utf16())
+            .sum();
+        PatternSyntaxException::new(
+            description.into(),
+            self.pattern.to_string(),
+            utf16_index as i32,
+        )
+    }
+
+    /// Skips whitespace and `#` comments in `COMMENTS` mode.
+    fn skip_comments(&mut self) {
+        if self.flags & Pattern::COMMENTS == 0 {
+            return;
+        }
+        while let Some(&ch) = self.chars.get(self.pos) {
+            if ch == '#' {
+                while self
+                    .chars
+                    .get(self.pos)
+                    .is_some_and(|&ch| !is_line_terminator(0, ch))
+                {
+                    self.pos += 1;
+                }
+            } else if matches!(ch, ' ' | '\t' | '\n' | '\x0B' | '\x0C' | '\r') {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_comments();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next_raw(&mut self) -> Option<char> {
+        let ch = self.chars.get(self.pos).copied();
+        self.pos += 1;
+        ch
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_expression(&mut self) -> ParseResult<Node> {
+        let mut alternatives = vec![self.parse_sequence()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_sequence()?);
+        }
+        Ok(if alternatives.len() == 1 {
+            alternatives.pop().unwrap()
+        } else {
+            Node::Alternation(alternatives)
+        })
+    }
+
+    fn parse_sequence(&mut self) -> ParseResult<Node> {
+        let mut nodes = Vec::new();
+        while let Some(ch) = self.peek() {
+            let start = self.pos;
+            let atom = match ch {
+                '|' | ')' => break,
+                '(' => {
+                    self.pos += 1;
+                    match self.parse_group()? {
+                        Some(group) => group,
+                        None => continue,
+                    }
+                }
+                '[' => {
+                    self.pos += 1;
+                    Node::Class(Box::new(self.parse_class()?))
+                }
+                '.' => {
+                    self.pos += 1;
+                    Node::Any(self.flags)
+                }
+                '^' => {
+                    self.pos += 1;
+                    if self.flags & Pattern::MULTILINE != 0 {
+                        Node::Caret(self.flags)
+                    } else {
+                        Node::Begin
+                    }
+                }
+                '$' => {
+                    self.pos += 1;
+                    Node::Dollar(self.flags)
+                }
+                '*' | '+' | '?' => {
+                    return Err(self.error(format!("Dangling meta character '{ch}'"), start))
+                }
+                '\\' => {
+                    self.pos += 1;
+                    match self.parse_escape(false)? {
+                        Escape::Char(ch) => Node::Char(ch, self.flags),
+                        Escape::Property(property, negated) => Node::Class(Box::new(CharClass {
+                            operands: vec![vec![ClassItem::Property(property, negated)]],
+                            negated: false,
+                            flags: self.flags,
+                        })),
+                        Escape::Node(node) => node,
+                        Escape::Quote(quoted) => {
+                            let flags = self.flags;
+                            let mut quoted: Vec<Node> =
+                                quoted.into_iter().map(|ch| Node::Char(ch, flags)).collect();
+                            // A quantifier applies to the last quoted character only.
+                            match quoted.pop() {
+                                Some(last) => {
+                                    nodes.extend(quoted);
+                                    last
+                                }
+                                None => continue,
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    self.pos += 1;
+                    Node::Char(ch, self.flags)
+                }
+            };
+            nodes.push(self.parse_quantifier(atom)?);
+        }
+        Ok(match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.pop().unwrap(),
+            _ => Node::Concat(nodes),
+        })
+    }
+
+    /// Parses a repetition count, which like a Java `int` may not exceed `i32::MAX`.
+    fn parse_number(&mut self) -> ParseResult<Option<usize>> {
+        let mut number = None;
+        while let Some(digit) = self.chars.get(self.pos).and_then(|ch| ch.to_digit(10)) {
+            let value = number.unwrap_or(0) * 10 + digit as usize;
+            if value > i32::MAX as usize {
+                return Err(self.error("Illegal repetition range", self.pos));
+            }
+            number = Some(value);
+            self.pos += 1;
+        }
+        Ok(number)
+    }
+
+    fn parse_quantifier(&mut self, atom: Node) -> ParseResult<Node> {
+        let Some((min, max, quantifier)) = self.parse_closure(true)? else {
+            return Ok(atom);
+        };
+        // Java accepts counted closures right after a quantifier, but ignores them.
+        while self.parse_closure(false)?.is_some() {}
+        // Java repeats a group that can only match the empty string just the least
+        // number of times, so an extra empty iteration doesn't set its groups.
+        let width = atom.width();
+        let max = if width.deterministic && width.max == Some(0) {
+            min
+        } else {
+            max
+        };
+        Ok(Node::Repeat(Box::new(atom), min, max, quantifier, None))
+    }
+
+    /// Parses a quantifier, or if not `any`, only a counted closure like `{2,3}`.
+    fn parse_closure(&mut self, any: bool) -> ParseResult<Option<(usize, usize, Quantifier)>> {
+        let (min, max) = match self.peek() {
+            Some('?') if any => (0, 1),
+            Some('*') if any => (0, INFINITY),
+            Some('+') if any => (1, INFINITY),
+            Some('{') => {
+                self.pos += 1;
+                if !self
+                    .chars
+                    .get(self.pos)
+                    .is_some_and(|ch| ch.is_ascii_digit())
+                {
+                    return Err(self.error("Illegal repetition", self.pos));
+                }
+                let min = self.parse_number()?.unwrap();
+                let max = if self.chars.get(self.pos) == Some(&',') {
+                    self.pos += 1;
+                    if self.chars.get(self.pos) == Some(&'}') {
+                        INFINITY
+                    } else {
+                        self.parse_number()?
+                            .ok_or_else(|| self.error("Unclosed counted closure", self.pos))?
+                    }
+                } else {
+                    min
+                };
+                if self.chars.get(self.pos) != Some(&'}') {
+                    return Err(self.error("Unclosed counted closure", self.pos));
+                }
+                if max < min {
+                    return Err(self.error("Illegal repetition range", self.pos));
+                }
+                (min, max)
+            }
+            _ => return Ok(None),
+        };
+        self.pos += 1;
+        let quantifier = match self.chars.get(self.pos) {
+            Some('?') => Quantifier::Lazy,
+            Some('+') => Quantifier::Possessive,
+            _ => Quantifier::Greedy,
+        };
+        if quantifier != Quantifier::Greedy {
+            self.pos += 1;
+        }
+        Ok(Some((min, max, quantifier)))
+    }
+
+    fn parse_flags(&mut self) -> ParseResult<bool> {
+        let mut enable = true;
+        loop {
+            let Some(ch) = self.chars.get(self.pos).copied() else {
+                return Err(self.error("Unknown inline modifier", self.pos));
+            };
+            let flag = match ch {
+                'i' => Pattern::CASE_INSENSITIVE,
+                'd' => Pattern::UNIX_LINES,
+                'm' => Pattern::MULTILINE,
+                's' => Pattern::DOTALL,
+                'u' => Pattern::UNICODE_CASE,
+                'x' => Pattern::COMMENTS,
+                'U' => Pattern::UNICODE_CHARACTER_CLASS | Pattern::UNICODE_CASE,
+                'c' => Pattern::CANON_EQ,
+                '-' if enable => {
+                    enable = false;
+                    self.pos += 1;
+                    continue;
+                }
+                ')' => {
+                    self.pos += 1;
+                    return Ok(false);
+                }
+                ':' => {
+                    self.pos += 1;
+                    return Ok(true);
+                }
+                _ => return Err(self.error("Unknown inline modifier", self.pos)),
+            };
+            if enable {
+                self.flags |= flag;
+            } else {
+                self.flags &= !flag;
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn parse_group_name(&mut self) -> ParseResult<String> {
+        let start = self.pos;
+        if !self
+            .chars
+            .get(self.pos)
+            .is_some_and(|ch| ch.is_ascii_alphabetic())
+        {
+            return Err(self.error(
+                "capturing group name does not start with a Latin letter",
+                self.pos,
+            ));
+        }
+        while self
+            .chars
+            .get(self.pos)
+            .is_some_and(|ch| ch.is_ascii_alphanumeric())
+        {
+            self.pos += 1;
+        }
+        if self.chars.get(self.pos) != Some(&'>') {
+            return Err(self.error("named capturing group is missing trailing '>'", self.pos));
+        }
+        let name = self.chars[start..self.pos].iter().collect();
+        self.pos += 1;
+        Ok(name)
+    }
+
+    /// Parses a group after its `(`. Returns `None` for a flag setting like `(?i)`, which
+    /// changes the flags of the rest of the enclosing group instead.
+    fn parse_group(&mut self) -> ParseResult<Option<Node>> {
+        let saved_flags = self.flags;
+        // Whether this is a negated lookbehind, if it is one.
+        let mut behind = None;
+        let mut wrap: Box<dyn FnOnce(Node) -> Node> =
+            Box::new(|node| Node::Group(Box::new(node), None));
+        if self.chars.get(self.pos) == Some(&'?') {
+            self.pos += 1;
+            let ch = self.next_raw();
+            match ch {
+                Some(':') => {}
+                Some('=') => wrap = Box::new(|node| Node::Look(Box::new(node), false)),
+                Some('!') => wrap = Box::new(|node| Node::Look(Box::new(node), true)),
+                Some('>') => wrap = Box::new(|node| Node::Atomic(Box::new(node))),
+                Some('<') if matches!(self.chars.get(self.pos), Some('=' | '!')) => {
+                    behind = Some(self.chars[self.pos] == '!');
+                    self.pos += 1;
+                }
+                Some('<') => {
+                    let name = self.parse_group_name()?;
+                    if self.group_names.contains_key(&name) {
+                        return Err(self.error(
+                            format!("Named capturing group <{name}> is already defined"),
+                            self.pos - 1,
+                        ));
+                    }
+                    self.group_count += 1;
+                    let index = self.group_count;
+                    self.group_names.insert(name, index);
+                    wrap = Box::new(move |node| Node::Group(Box::new(node), Some(index)));
+                }
+                _ => {
+                    self.pos -= 1;
+                    if !self.parse_flags()? {
+                        // (?i) lasts until the end of the enclosing group.
+                        return Ok(None);
+                    }
+                }
+            }
+        } else {
+            self.group_count += 1;
+            let index = self.group_count;
+            wrap = Box::new(move |node| Node::Group(Box::new(node), Some(index)));
+        }
+
+        let node = self.parse_expression()?;
+        if !self.eat(')') {
+            return Err(self.error("Unclosed group", self.chars.len()));
+        }
+        self.flags = saved_flags;
+        if let Some(negated) = behind {
+            // A lookbehind tries only the starts its pattern's width allows.
+            let width = node.width();
+            let Some(max) = width.max else {
+                return Err(self.error(
+                    "Look-behind group does not have an obvious maximum length",
+                    self.pos - 2,
+                ));
+            };
+            return Ok(Some(Node::Behind(Box::new(node), width.min, max, negated)));
+        }
+        Ok(Some(wrap(node)))
+    }
+
+    fn parse_class(&mut self) -> ParseResult<CharClass> {
+        let flags = self.flags;
+        let negated = self.chars.get(self.pos) == Some(&'^');
+        if negated {
+            self.pos += 1;
+        }
+        let mut operands = vec![Vec::new()];
+        loop {
+            let Some(ch) = self.peek() else {
+                return Err(self.error("Unclosed character class", self.chars.len() - 1));
+            };
+            let empty = operands
+                .iter()
+                .all(|operand: &Vec<ClassItem>| operand.is_empty());
+            match ch {
+                ']' if !empty => {
+                    self.pos += 1;
+                    return Ok(CharClass {
+                        operands,
+                        negated,
+                        flags,
+                    });
+                }
+                '[' => {
+                    self.pos += 1;
+                    let nested = self.parse_class()?;
+                    operands.last_mut().unwrap().push(ClassItem::Class(nested));
+                    continue;
+                }
+                '&' if self.chars.get(self.pos + 1) == Some(&'&') => {
+                    self.pos += 2;
+                    operands.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+
+            let low = match self.parse_class_char()? {
+                Ok(ch) => ch,
+                Err(item) => {
+                    operands.last_mut().unwrap().extend(item);
+                    continue;
+                }
+            };
+            let range = self.chars.get(self.pos) == Some(&'-')
+                && self
+                    .chars
+                    .get(self.pos + 1)
+                    .is_some_and(|&ch| ch != ']' && ch != '[');
+            if range {
+                self.pos += 1;
+                match self.parse_class_char()? {
+                    Ok(high) if high >= low => {
+                        operands
+                            .last_mut()
+                            .unwrap()
+                            .push(ClassItem::Range(low, high));
+                    }
+                    _ => return Err(self.error("Illegal character range", self.pos - 1)),
+                }
+            } else {
+                operands.last_mut().unwrap().push(ClassItem::Char(low));
+            }
+        }
+    }
+
+    /// A single character of a class, or the items of an escape that is not one.
+    fn parse_class_char(&mut self) -> ParseResult<Result<char, Vec<ClassItem>>> {
+        self.skip_comments();
+        let Some(ch) = self.next_raw() else {
+            return Err(self.error("Unclosed character class", self.chars.len() - 1));
+        };
+        if ch != '\\' {
+            return Ok(Ok(ch));
+        }
+        Ok(match self.parse_escape(true)? {
+            Escape::Char(ch) => Ok(ch),
+            Escape::Property(property, negated) => {
+                Err(vec![ClassItem::Property(property, negated)])
+            }
+            Escape::Quote(quoted) => Err(quoted.into_iter().map(ClassItem::Char).collect()),
+            Escape::Node(_) => unreachable!("anchors are not parsed in classes"),
+        })
+    }
+
+    fn parse_hex(&mut self, digits: usize) -> ParseResult<u32> {
+        let mut value = 0;
+        for _ in 0..digits {
+            match self.chars.get(self.pos).and_then(|ch| ch.to_digit(16)) {
+                Some(digit) => value = value * 16 + digit,
+                None => {
+                    let description = if digits == 4 {
+                        "Illegal Unicode escape sequence"
+                    } else {
+                        "Illegal hexadecimal escape sequence"
+                    };
+                    return Err(self.error(description, self.pos));
+                }
+            }
+            self.pos += 1;
+        }
+        Ok(value)
+    }
+
+    /// Parses an escape after its `\`.
+    fn parse_escape(&mut self, in_class: bool) -> ParseResult<Escape> {
+        let Some(ch) = self.next_raw() else {
+            return Err(self.error("Unexpected internal error", self.chars.len()));
+        };
+        let escape_index = self.pos - 1;
+        let flags = self.flags;
+        let node = |node| Ok(Escape::Node(node));
+        match ch {
+            '0' => {
+                let mut value = 0;
+                let mut digits = 0;
+                while digits < 3 {
+                    let Some(digit) = self.chars.get(self.pos).and_then(|ch| ch.to_digit(8)) else {
+                        break;
+                    };
+                    if digits == 2 && value > 0o37 {
+                        break;
+                    }
+                    value = value * 8 + digit;
+                    digits += 1;
+                    self.pos += 1;
+                }
+                if digits == 0 {
+                    return Err(self.error("Illegal octal escape sequence", self.pos));
+                }
+                Ok(Escape::Char(char::from_u32(value).unwrap()))
+            }
+            '1'..='9' if !in_class => {
+                let mut group = ch.to_digit(10).unwrap() as usize;
+                while let Some(digit) = self.chars.get(self.pos).and_then(|ch| ch.to_digit(10)) {
+                    let longer = group * 10 + digit as usize;
+                    if longer > self.group_count {
+                        break;
+                    }
+                    group = longer;
+                    self.pos += 1;
+                }
+                self.has_back_references = true;
+                node(Node::BackReference(group, flags))
+            }
+            'a' => Ok(Escape::Char('\x07')),
+            'e' => Ok(Escape::Char('\x1B')),
+            'f' => Ok(Escape::Char('\x0C')),
+            'n' => Ok(Escape::Char('\n')),
+            'r' => Ok(Escape::Char('\r')),
+            't' => Ok(Escape::Char('\t')),
+            'c' => match self.next_raw() {
+                Some(control) => Ok(Escape::Char(char::from_u32(control as u32 ^ 64).unwrap())),
+                None => Err(self.error("Illegal control escape sequence", escape_index)),
+            },
+            'x' if self.chars.get(self.pos) == Some(&'{') => {
+                self.pos += 1;
+                if !self
+                    .chars
+                    .get(self.pos)
+                    .is_some_and(|ch| ch.is_ascii_hexdigit())
+                {
+                    return Err(self.error("Illegal hexadecimal escape sequence", self.pos - 1));
+                }
+                let mut value = 0;
+                while let Some(digit) = self.chars.get(self.pos).and_then(|ch| ch.to_digit(16)) {
+                    value = value * 16 + digit;
+                    if value > char::MAX as u32 {
+                        return Err(self.error("Hexadecimal codepoint is too big", self.pos));
+                    }
+                    self.pos += 1;
+                }
+                if self.chars.get(self.pos) != Some(&'}') {
+                    return Err(self.error("Unclosed hexadecimal escape sequence", self.pos));
+                }
+                self.pos += 1;
+                // Surrogates can never match a `str`, so they become an empty class.
+                Ok(match char::from_u32(value) {
+                    Some(ch) => Escape::Char(ch),
+                    None => Escape::Property(Property::Test(|_| false), false),
+                })
+            }
+            'x' => {
+                let value = self.parse_hex(2)?;
+                Ok(Escape::Char(char::from_u32(value).unwrap()))
+            }
+            'u' => {
+                let high = self.parse_hex(4)?;
+                if (0xD800..0xDC00).contains(&high)
+                    && self.chars.get(self.pos..self.pos + 2) == Some(&['\\', 'u'])
+                {
+                    let resume = self.pos;
+                    self.pos += 2;
+                    match self.parse_hex(4) {
+                        Ok(low) if (0xDC00..0xE000).contains(&low) => {
+                            let code_point = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                            return Ok(Escape::Char(char::from_u32(code_point).unwrap()));
+                        }
+                        _ => self.pos = resume,
+                    }
+                }
+                // A lone surrogate can never match a `str`, so it becomes an empty class.
+                Ok(match char::from_u32(high) {
+                    Some(ch) => Escape::Char(ch),
+                    None => Escape::Property(Property::Test(|_| false), false),
+                })
+            }
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' | 'h' | 'H' | 'v' | 'V' => {
+                let (property, negated) = predefined_class(ch, flags).unwrap();
+                Ok(Escape::Property(property, negated))
+            }
+            'p' | 'P' => {
+                let property = self.parse_property()?;
+                Ok(Escape::Property(property, ch == 'P'))
+            }
+            'Q' => {
+                let start = self.pos;
+                while self.pos < self.chars.len()
+                    && self.chars.get(self.pos..self.pos + 2) != Some(&['\\', 'E'])
+                {
+                    self.pos += 1;
+                }
+                let quoted = self.chars[start..self.pos].to_vec();
+                self.pos = (self.pos + 2).min(self.chars.len());
+                Ok(Escape::Quote(quoted))
+            }
+            'E' => Ok(Escape::Quote(Vec::new())),
+            'b' if !in_class => node(Node::WordBoundary(false, flags)),
+            'B' if !in_class => node(Node::WordBoundary(true, flags)),
+            'A' if !in_class => node(Node::Begin),
+            'z' if !in_class => node(Node::End),
+            'Z' if !in_class => node(Node::Dollar(flags & !Pattern::MULTILINE)),
+            'G' if !in_class => node(Node::LastMatchEnd),
+            'R' if !in_class => {
+                let line_break = CharClass {
+                    operands: vec![vec![ClassItem::Property(
+                        Property::Test(is_vertical_space),
+                        false,
+                    )]],
+                    negated: false,
+                    flags: 0,
+                };
+                node(Node::Alternation(vec![
+                    Node::Concat(vec![Node::Char('\r', 0), Node::Char('\n', 0)]),
+                    Node::Class(Box::new(line_break)),
+                ]))
+            }
+            'k' if !in_class => {
+                if self.next_raw() != Some('<') {
+                    return Err(self.error(
+                        "\\k is not followed by '<' for named capturing group",
+                        self.pos - 1,
+                    ));
+                }
+                let name = self.parse_group_name()?;
+                match self.group_names.get(&name) {
+                    Some(&group) => {
+                        self.has_back_references = true;
+                        node(Node::BackReference(group, flags))
+                    }
+                    None => Err(self.error(
+                        format!("named capturing group <{name}> does not exist"),
+                        self.pos - 1,
+                    )),
+                }
+            }
+            ch if ch.is_ascii_alphanumeric() => {
+                Err(self.error("Illegal/unsupported escape sequence", escape_index))
+            }
+            ch => Ok(Escape::Char(ch)),
+        }
+    }
+
+    /// Parses the name after `\p` or `\P`: one letter, or a name in braces.
+    fn parse_property(&mut self) -> ParseResult<Property> {
+        let name: String = match self.next_raw() {
+            Some('{') => {
+                let start = self.pos;
+                while self.chars.get(self.pos).is_some_and(|&ch| ch != '}') {
+                    self.pos += 1;
+                }
+                if self.pos >= self.chars.len() {
+                    return Err(self.error("Unclosed character family", self.pos));
+                }
+                self.pos += 1;
+                self.chars[start..self.pos - 1].iter().collect()
+            }
+            Some(ch) => ch.to_string(),
+            None => return Err(self.error("Illegal character family", self.pos)),
+        };
+        let name = name.as_str();
+        let case_insensitive = self.flags & Pattern::CASE_INSENSITIVE != 0;
+
+        let property = if let Some((key, value)) = name.split_once('=') {
+            let property = match key.to_ascii_lowercase().as_str() {
+                "sc" | "script" => UnicodeScript::for_name(value).ok().map(Property::Script),
+                "blk" | "block" => UnicodeBlock::for_name(value).ok().map(Property::Block),
+                "gc" | "general_category" => named_property(value, case_insensitive),
+                _ => None,
+            };
+            property.ok_or_else(|| {
+                self.error(
+                    format!(
+                        "Unknown Unicode property {{name=<{}>, value=<{value}>}}",
+                        key.to_ascii_lowercase()
+                    ),
+                    self.pos - 1,
+                )
+            })?
+        } else {
+            let property = if let Some(block) = name.strip_prefix("In") {
+                UnicodeBlock::for_name(block).ok().map(Property::Block)
+            } else if let Some(short_name) = name.strip_prefix("Is") {
+                binary_property(short_name, case_insensitive)
+                    .or_else(|| named_property(short_name, case_insensitive))
+                    .or_else(|| {
+                        UnicodeScript::for_name(short_name)
+                            .ok()
+                            .map(Property::Script)
+                    })
+            } else {
+                let unicode = self.flags & Pattern::UNICODE_CHARACTER_CLASS != 0;
+                unicode
+                    .then(|| unicode_posix_property(name))
+                    .flatten()
+                    .or_else(|| named_property(name, case_insensitive))
+            };
+            property.ok_or_else(|| {
+                self.error(
+                    format!("Unknown character property name {{{name}}}"),
+                    self.pos - 1,
+                )
+            })?
+        };
+        Ok(property)
+    }
+}
+
+/// `java.util.regex.Pattern`: a compiled regular expression.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pattern: String,
+    flags: i32,
+    root: Node,
+    group_count: usize,
+    group_names: HashMap<String, usize>,
+    /// How many repetitions record where they failed.
+    loop_count: usize,
+}
+
+impl Pattern {
+    pub const UNIX_LINES: i32 = 0x01;
+    pub const CASE_INSENSITIVE: i32 = 0x02;
+    pub const COMMENTS: i32 = 0x04;
+    pub const MULTILINE: i32 = 0x08;
+    pub const LITERAL: i32 = 0x10;
+    pub const DOTALL: i32 = 0x20;
+    pub const UNICODE_CASE: i32 = 0x40;
+    /// Accepted for compatibility, but canonical equivalence is not implemented.
+    pub const CANON_EQ: i32 = 0x80;
+    pub const UNICODE_CHARACTER_CLASS: i32 = 0x100;
+
+    pub fn compile(regex: &str) -> Result<Self, PatternSyntaxException> {
+        Self::compile_with_flags(regex, 0)
+    }
+
+    /// Compiles with a combination of the flag constants. Flags that are not defined
+    /// are an `IllegalArgumentException` in Java, and likewise panic here.
+    pub fn compile_with_flags(regex: &str, flags: i32) -> Result<Self, PatternSyntaxException> {
+        let all_flags = 0x1FF;
+        if flags & !all_flags != 0 {
+            IllegalArgumentException::new(format!("Unknown flag 0x{flags:x}"), None).throw();
+        }
+        let flags = if flags & Self::UNICODE_CHARACTER_CLASS != 0 {
+            flags | Self::UNICODE_CASE
+        } else {
+            flags
+        };
+
+        let mut parser = Parser {
+            pattern: regex,
+            chars: regex.chars().collect(),
+            pos: 0,
+            flags,
+            group_count: 0,
+            group_names: HashMap::new(),
+            has_back_references: false,
+        };
+        let mut root = if flags & Self::LITERAL != 0 {
+            Node::Concat(regex.chars().map(|ch| Node::Char(ch, flags)).collect())
+        } else {
+            let root = parser.parse_expression()?;
+            if parser.pos < parser.chars.len() {
+                // Only an unmatched ')' can stop the top-level expression early.
+                return Err(parser.error("Unmatched closing ')'", parser.pos.saturating_sub(1)));
+            }
+            root
+        };
+        // As in Java, a failed position can only be skipped when what follows the
+        // repetition doesn't depend on what the groups captured.
+        let mut loop_count = 0;
+        if !parser.has_back_references {
+            root.number_loops(false, &mut loop_count);
+        }
+
+        Ok(Self {
+            pattern: regex.to_string(),
+            flags,
+            root,
+            group_count: parser.group_count,
+            group_names: parser.group_names,
+            loop_count,
+        })
+    }
+
+    /// Compiles `regex` and tells whether it matches all of `input`.
+    pub fn matches(regex: &str, input: &str) -> Result<bool, PatternSyntaxException> {
+        Ok(Self::compile(regex)?.matcher(input).matches())
+    }
+
+    /// A regular expression matching `s` literally, using `\Q...\E` like Java.
+    pub fn quote(s: &str) -> String {
+        let mut quoted = String::from("\\Q");
+        let mut rest = s;
+        while let Some(index) = rest.find("\\E") {
+            quoted.push_str(&rest[..index]);
+            quoted.push_str("\\E\\\\E\\Q");
+            rest = &rest[index + 2..];
+        }
+        quoted.push_str(rest);
+        quoted.push_str("\\E");
+        quoted
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn flags(&self) -> i32 {
+        self.flags
+    }
+
+    pub fn matcher<'t>(&self, input: &'t str) -> Matcher<'_, 't> {
+        Matcher {
+            pattern: self,
+            text: input,
+            groups: vec![None; self.group_count + 1],
+            first: None,
+            last: 0,
+            last_append: 0,
+        }
+    }
+
+    /// Splits `input` around matches, like `Pattern.split`. A positive `limit` caps the
+    /// number of parts, with the last part holding the rest of the input; zero removes
+    /// trailing empty parts; a negative limit keeps them. A zero-width match at the start
+    /// of the input never produces a leading empty part.
+    pub fn split<'t>(&self, input: &'t str, limit: i32) -> Vec<&'t str> {
+        let mut index = 0;
+        let limited = limit > 0;
+        let mut parts = Vec::new();
+        let mut matcher = self.matcher(input);
+        while matcher.find() {
+            let (start, end) = matcher.groups[0].unwrap();
+            if !limited || parts.len() < limit as usize - 1 {
+                if index == 0 && start == 0 && start == end {
+                    continue;
+                }
+                parts.push(&input[index..start]);
+                index = end;
+            } else if parts.len() == limit as usize - 1 {
+                parts.push(&input[index..]);
+                index = end;
+            }
+        }
+        if index == 0 {
+            return vec![input];
+        }
+        if !limited || parts.len() < limit as usize {
+            parts.push(&input[index..]);
+        }
+        if limit == 0 {
+            while parts.last().is_some_and(|part| part.is_empty()) {
+                parts.pop();
+            }
+        }
+        parts
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+type Groups = Vec<Option<(usize, usize)>>;
+type Continuation<'k> = dyn FnMut(usize, &mut Groups) -> bool + 'k;
+
+/// A backtracking matcher over one input: each node calls its continuation with every
+/// position it can end at, in order of preference, until the continuation succeeds.
+struct Engine<'a> {
+    text: &'a str,
+    last_match_end: usize,
+    /// For each repetition that keeps one, the positions it has already gone on from and
+    /// failed, which are skipped rather than tried again as Java's `Loop` does.
+    failed: &'a [RefCell<HashSet<usize>>],
+}
+
+impl Engine<'_> {
+    fn char_at(&self, pos: usize) -> Option<char> {
+        self.text[pos..].chars().next()
+    }
+
+    fn char_before(&self, pos: usize) -> Option<char> {
+        self.text[..pos].chars().next_back()
+    }
+
+    /// Where a single-character node ends if it matches at `pos`.
+    fn single(&self, node: &Node, pos: usize) -> Option<usize> {
+        let ch = self.char_at(pos)?;
+        let matched = match node {
+            Node::Char(expected, flags) => chars_match(*flags, *expected, ch),
+            Node::Any(flags) => flags & Pattern::DOTALL != 0 || !is_line_terminator(*flags, ch),
+            Node::Class(class) => class.contains(ch),
+            _ => unreachable!(),
+        };
+        matched.then(|| pos + ch.len_utf8())
+    }
+
+    fn is_word(&self, ch: char, flags: i32) -> bool {
+        if flags & Pattern::UNICODE_CHARACTER_CLASS != 0 {
+            is_unicode_word(ch as i32)
+        } else {
+            ch == '_' || character::is_letter_or_digit(ch as i32)
+        }
+    }
+
+    /// Whether a non-spacing mark ending before `pos` follows a letter or digit.
+    fn has_base_character(&self, pos: usize) -> bool {
+        for ch in self.text[..pos].chars().rev() {
+            if character::is_letter_or_digit(ch as i32) {
+                return true;
+            }
+            if character::get_type(ch as i32) != character::NON_SPACING_MARK {
+                return false;
+            }
+        }
+        false
+    }
+
+    fn is_word_boundary(&self, pos: usize, flags: i32) -> bool {
+        let is_word_at = |ch: char, end: usize| {
+            self.is_word(ch, flags)
+                || (character::get_type(ch as i32) == character::NON_SPACING_MARK
+                    && self.has_base_character(end))
+        };
+        let left = self
+            .char_before(pos)
+            .is_some_and(|ch| is_word_at(ch, pos - ch.len_utf8()));
+        let right = self.char_at(pos).is_some_and(|ch| is_word_at(ch, pos));
+        left != right
+    }
+
+    fn is_dollar(&self, pos: usize, flags: i32) -> bool {
+        let len = self.text.len();
+        let multiline = flags & Pattern::MULTILINE != 0;
+        if pos == len {
+            return true;
+        }
+        let rest = &self.text[pos..];
+        let ch = self.char_at(pos).unwrap();
+        let unix_lines = flags & Pattern::UNIX_LINES != 0;
+        let inside_crlf = !unix_lines && ch == '\n' && self.char_before(pos) == Some('\r');
+        if !is_line_terminator(flags, ch) || inside_crlf {
+            return false;
+        }
+        multiline || rest.len() == ch.len_utf8() || (!unix_lines && rest == "\r\n")
+    }
+
+    fn is_caret(&self, pos: usize, flags: i32) -> bool {
+        // As in Perl, ^ does not match at the end of input, even after a line terminator.
+        if pos == self.text.len() {
+            return false;
+        }
+        match self.char_before(pos) {
+            None => true,
+            Some('\r') if flags & Pattern::UNIX_LINES == 0 => self.char_at(pos) != Some('\n'),
+            Some(ch) => is_line_terminator(flags, ch),
+        }
+    }
+
+    fn match_node(
+        &self,
+        node: &Node,
+        pos: usize,
+        groups: &mut Groups,
+        k: &mut Continuation<'_>,
+    ) -> bool {
+        match node {
+            Node::Empty => k(pos, groups),
+            Node::Char(..) | Node::Any(_) | Node::Class(_) => match self.single(node, pos) {
+                Some(end) => k(end, groups),
+                None => false,
+            },
+            Node::Begin => pos == 0 && k(pos, groups),
+            Node::Caret(flags) => self.is_caret(pos, *flags) && k(pos, groups),
+            Node::Dollar(flags) => self.is_dollar(pos, *flags) && k(pos, groups),
+            Node::End => pos == self.text.len() && k(pos, groups),
+            Node::LastMatchEnd => pos == self.last_match_end && k(pos, groups),
+            Node::WordBoundary(negated, flags) => {
+                self.is_word_boundary(pos, *flags) != *negated && k(pos, groups)
+            }
+            Node::Group(inner, None) => self.match_node(inner, pos, groups, k),
+            Node::Group(inner, Some(index)) => {
+                let index = *index;
+                self.match_node(inner, pos, groups, &mut |end, groups: &mut Groups| {
+                    let saved = groups[index];
+                    groups[index] = Some((pos, end));
+                    if k(end, groups) {
+                        return true;
+                    }
+                    groups[index] = saved;
+                    false
+                })
+            }
+            Node::Concat(nodes) => self.match_sequence(nodes, pos, groups, k),
+            Node::Alternation(alternatives) => alternatives
+                .iter()
+                .any(|alternative| self.match_node(alternative, pos, groups, k)),
+            Node::Repeat(inner, min, max, Quantifier::Possessive, _) => {
+                let mut found = None;
+                let mut last = |end, _: &mut Groups| {
+                    found = Some(end);
+                    true
+                };
+                self.match_repeat(inner, *min, *max, false, None, pos, groups, &mut last);
+                found.is_some_and(|end| k(end, groups))
+            }
+            Node::Repeat(inner, min, max, quantifier, index) => {
+                let lazy = *quantifier == Quantifier::Lazy;
+                self.match_repeat(inner, *min, *max, lazy, *index, pos, groups, k)
+            }
+            // As in Java, the groups captured inside a lookaround that matched stay set
+            // even if what follows fails.
+            Node::Look(inner, negated) => {
+                let matched = self.match_node(inner, pos, groups, &mut |_, _| true);
+                matched != *negated && k(pos, groups)
+            }
+            Node::Behind(inner, min, max, negated) => {
+                // The shortest lookbehind is tried first.
+                let matched = std::iter::once(pos)
+                    .chain(self.text[..pos].char_indices().rev().map(|(i, _)| i))
+                    .skip(*min)
+                    .take(max - min + 1)
+                    .any(|start| self.match_node(inner, start, groups, &mut |end, _| end == pos));
+                matched != *negated && k(pos, groups)
+            }
+            Node::Atomic(inner) => {
+                let mut found = None;
+                self.match_node(inner, pos, groups, &mut |end, _| {
+                    found = Some(end);
+                    true
+                });
+                found.is_some_and(|end| k(end, groups))
+            }
+            Node::BackReference(group, flags) => {
+                let Some((start, end)) = groups.get(*group).copied().flatten() else {
+                    return false;
+                };
+                let mut at = pos;
+                for expected in self.text[start..end].chars() {
+                    match self.char_at(at) {
+                        Some(ch) if chars_match(*flags, expected, ch) => at += ch.len_utf8(),
+                        _ => return false,
+                    }
+                }
+                k(at, groups)
+            }
+        }
+    }
+
+    fn match_sequence(
+        &self,
+        nodes: &[Node],
+        pos: usize,
+        groups: &mut Groups,
+        k: &mut Continuation<'_>,
+    ) -> bool {
+        match nodes.split_first() {
+            None => k(pos, groups),
+            Some((first, rest)) => {
+                self.match_node(first, pos, groups, &mut |end, groups: &mut Groups| {
+                    self.match_sequence(rest, end, groups, k)
+                })
+            }
+        }
+    }
+
+    /// Repeats `inner` from `pos`. A repeated group keeps the iterations it can still
+    /// backtrack into on a stack of its own rather than recursing, so that long inputs
+    /// don't overflow the call stack. With the index of a record of failed positions,
+    /// going on from a position that already failed goes straight to what follows, so that
+    /// nested repetitions like `(a*)*b` don't backtrack exponentially.
+    #[allow(clippy::too_many_arguments)]
+    fn match_repeat(
+        &self,
+        inner: &Node,
+        min: usize,
+        max: usize,
+        lazy: bool,
+        index: Option<usize>,
+        pos: usize,
+        groups: &mut Groups,
+        k: &mut Continuation<'_>,
+    ) -> bool {
+        if inner.is_single_char() {
+            let mut ends = vec![pos];
+            while ends.len() <= max {
+                match self.single(inner, *ends.last().unwrap()) {
+                    Some(end) => ends.push(end),
+                    None => break,
+                }
+            }
+            if ends.len() <= min {
+                return false;
+            }
+            let ends = &ends[min..];
+            return if lazy {
+                ends.iter().any(|&end| k(end, groups))
+            } else {
+                ends.iter().rev().any(|&end| k(end, groups))
+            };
+        }
+
+        // The continuation gets the groups of the iteration it follows, which become the
+        // caller's groups if it succeeds.
+        let mut succeed = |pos: usize, mut captured: Groups, groups: &mut Groups| {
+            let matched = k(pos, &mut captured);
+            if matched {
+                *groups = captured;
+            }
+            matched
+        };
+        // Like Java, the record is only kept once the loop has matched at least once and
+        // enough times.
+        let failed = index.map(|index| &self.failed[index]);
+        let remembers = |count: usize| count > 0 && count >= min;
+        let mut stack: Vec<Iteration> = Vec::new();
+        let mut next = Some((0, pos, groups.clone()));
+        loop {
+            if let Some((count, pos, mut captured)) = next.take() {
+                if lazy && count >= min && succeed(pos, captured.clone(), groups) {
+                    return true;
+                }
+                let skip =
+                    remembers(count) && failed.is_some_and(|failed| failed.borrow().contains(&pos));
+                let mut ends = Vec::new();
+                if count < max && !skip {
+                    self.match_node(inner, pos, &mut captured, &mut |end, captured| {
+                        ends.push((end, captured.clone()));
+                        false
+                    });
+                }
+                stack.push(Iteration {
+                    count,
+                    pos,
+                    groups: captured,
+                    ends: ends.into_iter(),
+                });
+            }
+            let Some(iteration) = stack.last_mut() else {
+                return false;
+            };
+            match iteration.ends.next() {
+                // As in Java, an empty iteration ends the loop, whatever the count.
+                Some((end, captured)) if end == iteration.pos => {
+                    if succeed(end, captured, groups) {
+                        return true;
+                    }
+                }
+                Some((end, captured)) => next = Some((iteration.count + 1, end, captured)),
+                None => {
+                    let iteration = stack.pop().unwrap();
+                    if let Some(failed) = failed.filter(|_| remembers(iteration.count)) {
+                        failed.borrow_mut().insert(iteration.pos);
+                    }
+                    if !lazy
+                        && iteration.count >= min
+                        && succeed(iteration.pos, iteration.groups, groups)
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An iteration of a repeated group: how many times the group has matched, where, the
+/// groups captured so far and the ends of the next iteration that are left to try.
+struct Iteration {
+    count: usize,
+    pos: usize,
+    groups: Groups,
+    ends: std::vec::IntoIter<(usize, Groups)>,
+}
+
+/// `java.util.regex.Matcher`: the state of matching a `Pattern` against one input.
+pub struct Matcher<'p, 't> {
+    pattern: &'p Pattern,
+    text: &'t str,
+    groups: Groups,
+    /// The start of the last match, or `None` if the last attempt failed.
+    first: Option<usize>,
+    /// The end of the last match.
+    last: usize,
+    last_append: usize,
+}
+
+impl<'t> Matcher<'_, 't> {
+    /// A record of failed positions for each repetition that keeps one. Like Java, a
+    /// search keeps them from one start to the next.
+    fn failed_positions(&self) -> Vec<RefCell<HashSet<usize>>> {
+        (0..self.pattern.loop_count)
+            .map(|_| RefCell::default())
+            .collect()
+    }
+
+    /// Tries to match at exactly `start`, ending anywhere or, if `to_end`, at the end,
+    /// capturing into `scratch`, which a failed attempt leaves as it was. The groups of a
+    /// match are copied to `self.groups`.
+    fn try_at(
+        &mut self,
+        start: usize,
+        to_end: bool,
+        scratch: &mut Groups,
+        failed: &[RefCell<HashSet<usize>>],
+    ) -> Option<usize> {
+        let engine = Engine {
+            text: self.text,
+            last_match_end: self.last,
+            failed,
+        };
+        let pattern = self.pattern;
+        let len = self.text.len();
+        let found = &mut self.groups;
+        let mut result = None;
+        engine.match_node(
+            &pattern.root,
+            start,
+            scratch,
+            &mut |end, groups: &mut Groups| {
+                if to_end && end != len {
+                    return false;
+                }
+                found.clone_from(groups);
+                result = Some(end);
+                true
+            },
+        );
+        result
+    }
+
+    fn match_at(&mut self, start: usize, to_end: bool) -> bool {
+        let mut scratch = vec![None; self.groups.len()];
+        let failed = self.failed_positions();
+        let result = self.try_at(start, to_end, &mut scratch, &failed);
+        self.record(start, result)
+    }
+
+    fn record(&mut self, start: usize, end: Option<usize>) -> bool {
+        match end {
+            Some(end) => {
+                self.groups[0] = Some((start, end));
+                self.first = Some(start);
+                self.last = end;
+                true
+            }
+            None => {
+                self.groups.iter_mut().for_each(|group| *group = None);
+                self.first = None;
+                false
+            }
+        }
+    }
+
+    fn search(&mut self, from: usize) -> bool {
+        let mut scratch = vec![None; self.groups.len()];
+        let failed = self.failed_positions();
+        let mut start = from;
+        loop {
+            if let Some(end) = self.try_at(start, false, &mut scratch, &failed) {
+                return self.record(start, Some(end));
+            }
+            match self.text[start..].chars().next() {
+                Some(ch) => start += ch.len_utf8(),
+                None => return self.record(from, None),
+            }
+        }
+    }
+
+    /// Finds the next match, starting after the previous one; an empty match moves the
+    /// search on by one character so that it is not found again.
+    pub fn find(&mut self) -> bool {
+        let mut next = self.last;
+        if Some(next) == self.first {
+            match self.text[next..].chars().next() {
+                Some(ch) => next += ch.len_utf8(),
+                None => {
+                    self.record(next, None);
+                    return false;
+                }
+            }
+        }
+        self.search(next)
+    }
+
+    /// Resets the matcher and finds the first match at or after byte offset `start`.
+    pub fn find_from(&mut self, start: usize) -> bool {
+        if start > self.text.len() {
+            IndexOutOfBoundsException::new(format!("Illegal start index: {start}"), start as i32)
+                .throw();
+        }
+        self.reset();
+        self.search(start)
+    }
+
+    /// Whether the whole input matches.
+    pub fn matches(&mut self) -> bool {
+        self.match_at(0, true)
+    }
+
+    /// Whether a prefix of the input matches.
+    pub fn looking_at(&mut self) -> bool {
+        self.match_at(0, false)
+    }
+
+    pub fn reset(&mut self) {
+        self.groups.iter_mut().for_each(|group| *group = None);
+        self.first = None;
+        self.last = 0;
+        self.last_append = 0;
+    }
+
+    fn check_match(&self) -> (usize, usize) {
+        match self.groups[0] {
+            Some(range) if self.first.is_some() => range,
+            _ => IllegalStateException::new("No match available".to_string(), None).throw(),
+        }
+    }
+
+    fn check_group(&self, group: usize) -> Option<(usize, usize)> {
+        self.check_match();
+        if group > self.pattern.group_count {
+            IndexOutOfBoundsException::new(format!("No group {group}"), group as i32).throw();
+        }
+        self.groups[group]
+    }
+
+    /// The byte offset where the last match started.
+    pub fn start(&self) -> usize {
+        self.check_match().0
+    }
+
+    /// The byte offset just after the last match.
+    pub fn end(&self) -> usize {
+        self.check_match().1
+    }
+
+    /// Where `group` started in the last match, or `None` if it did not take part.
+    pub fn start_of(&self, group: usize) -> Option<usize> {
+        self.check_group(group).map(|(start, _)| start)
+    }
+
+    pub fn end_of(&self, group: usize) -> Option<usize> {
+        self.check_group(group).map(|(_, end)| end)
+    }
+
+    /// The text of `group` in the last match, where group 0 is the whole match, or `None`
+    /// if the group did not take part in it.
+    pub fn group(&self, group: usize) -> Option<&'t str> {
+        self.check_group(group)
+            .map(|(start, end)| &self.text[start..end])
+    }
+
+    pub fn group_named(&self, name: &str) -> Option<&'t str> {
+        match self.pattern.group_names.get(name) {
+            Some(&group) => self.group(group),
+            None => {
+                IllegalArgumentException::new(format!("No group with name <{name}>"), None).throw()
+            }
+        }
+    }
+
+    pub fn group_count(&self) -> usize {
+        self.pattern.group_count
+    }
+
+    /// Appends the input since the last append and then `replacement`, in which `$n` and
+    /// `${name}` stand for groups and a backslash escapes the next character.
+    pub fn append_replacement(&mut self, sb: &mut String, replacement: &str) {
+        let (start, end) = self.check_match();
+        let mut result = String::new();
+        let mut chars = replacement.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => match chars.next() {
+                    Some(escaped) => result.push(escaped),
+                    None => IllegalArgumentException::new(
+                        "character to be escaped is missing".to_string(),
+                        None,
+                    )
+                    .throw(),
+                },
+                '$' => {
+                    let group = match chars.next() {
+                        Some('{') => {
+                            let name: String =
+                                std::iter::from_fn(|| chars.next_if(|&ch| ch != '}')).collect();
+                            if chars.next() != Some('}') {
+                                IllegalArgumentException::new(
+                                    "named capturing group is missing trailing '}'".to_string(),
+                                    None,
+                                )
+                                .throw();
+                            }
+                            match self.pattern.group_names.get(&name) {
+                                Some(&group) => group,
+                                None => IllegalArgumentException::new(
+                                    format!("No group with name {{{name}}}"),
+                                    None,
+                                )
+                                .throw(),
+                            }
+                        }
+                        Some(digit) if digit.is_ascii_digit() => {
+                            let mut group = digit.to_digit(10).unwrap() as usize;
+                            while let Some(next) = chars.peek().and_then(|ch| ch.to_digit(10)) {
+                                let longer = group * 10 + next as usize;
+                                if longer > self.pattern.group_count {
+                                    break;
+                                }
+                                group = longer;
+                                chars.next();
+                            }
+                            group
+                        }
+                        _ => IllegalArgumentException::new(
+                            "Illegal group reference".to_string(),
+                            None,
+                        )
+                        .throw(),
+                    };
+                    result.push_str(self.group(group).unwrap_or(""));
+                }
+                ch => result.push(ch),
+            }
+        }
+        sb.push_str(&self.text[self.last_append..start]);
+        sb.push_str(&result);
+        self.last_append = end;
+    }
+
+    /// Appends the input after the last append.
+    pub fn append_tail(&mut self, sb: &mut String) {
+        sb.push_str(&self.text[self.last_append..]);
+    }
+
+    /// Replaces every match, with the replacement syntax of `append_replacement`.
+    pub fn replace_all(&mut self, replacement: &str) -> String {
+        self.reset();
+        let mut sb = String::new();
+        while self.find() {
+            self.append_replacement(&mut sb, replacement);
+        }
+        self.append_tail(&mut sb);
+        sb
+    }
+
+    /// Replaces the first match, with the replacement syntax of `append_replacement`.
+    pub fn replace_first(&mut self, replacement: &str) -> String {
+        self.reset();
+        let mut sb = String::new();
+        if self.find() {
+            self.append_replacement(&mut sb, replacement);
+        }
+        self.append_tail(&mut sb);
+        sb
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Pattern;
+
+    #[test]
+    fn matcher_matches_jdk() {
+        let pattern = Pattern::compile(r"(\w+)@(\w+)\.com").unwrap();
+        let mut matcher = pattern.matcher("mail bob@example.com and al@x.com");
+        let mut found = Vec::new();
+        while matcher.find() {
+            found.push((
+                matcher.start(),
+                matcher.end(),
+                matcher.group(1).unwrap(),
+                matcher.group(2).unwrap(),
+            ));
+        }
+        assert_eq!(found, [(5, 20, "bob", "example"), (25, 33, "al", "x")]);
+
+        let replace = |regex: &str, input: &str, replacement: &str| {
+            Pattern::compile(regex)
+                .unwrap()
+                .matcher(input)
+                .replace_all(replacement)
+        };
+        assert_eq!(replace(r"(\d)", "a1b2", "<$1>"), "a<1>b<2>");
+        assert_eq!(replace("x", "x", "\\$"), "$");
+        assert_eq!(replace("(?m)^", "ab\ncd\n", "> "), "> ab\n> cd\n");
+        assert_eq!(replace("$", "ab\ncd\n", "!"), "ab\ncd!\n!");
+        assert_eq!(replace("(?m)$", "ab\ncd\n", "!"), "ab!\ncd!\n!");
+        assert_eq!(
+            replace("(?<y>\\d{4})-(?<m>\\d\\d)", "2024-01", "${m}/${y}"),
+            "01/2024"
+        );
+        assert_eq!(replace("(?i)straSSe", "STRASSE strasse", "-"), "- -");
+        assert_eq!(replace("a++b|a", "aaa", "x"), "xxx");
+        assert_eq!(
+            replace(r"(a|ab)(c|bcd)(d*)", "abcd", "[$1,$2,$3]"),
+            "[a,bcd,]"
+        );
+        assert_eq!(replace(r"(\w)\1", "aabbcd", "=$1"), "=a=bcd");
+        assert_eq!(replace(r"(?<=\$)\d+", "cost $42", "N"), "cost $N");
+        assert_eq!(replace(r"\p{Lu}\p{Ll}+", "Hello World", "W"), "W W");
+        assert_eq!(replace(r"[a-z&&[^aeiou]]", "regex", "_"), "_e_e_");
+
+        assert_eq!(Pattern::matches("a*b", "aaab"), Ok(true));
+        assert_eq!(Pattern::quote("a\\Eb"), "\\Qa\\E\\\\E\\Qb\\E");
+        assert!(Pattern::matches(&Pattern::quote("a.b\\E*"), "a.b\\E*").unwrap());
+    }
+
+    /// The matches `find` gives one after another, with their groups.
+    fn find_all(regex: &str, input: &str) -> String {
+        let pattern = Pattern::compile(regex).unwrap();
+        let mut matcher = pattern.matcher(input);
+        let mut found = Vec::new();
+        while matcher.find() {
+            let mut found_match = format!("[{},{}", matcher.start(), matcher.end());
+            for group in 1..=matcher.group_count() {
+                match (matcher.start_of(group), matcher.end_of(group)) {
+                    (Some(start), Some(end)) => found_match += &format!(" g{group}={start}-{end}"),
+                    _ => found_match += &format!(" g{group}=null"),
+                }
+            }
+            found.push(found_match + "]");
+        }
+        found.join(" ")
+    }
+
+    #[test]
+    fn repetitions_and_lookarounds_match_jdk() {
+        for (regex, input, expected) in [
+            // A group that can only match the empty string isn't repeated beyond its minimum.
+            (
+                "($)*",
+                "cab",
+                "[0,0 g1=null] [1,1 g1=null] [2,2 g1=null] [3,3 g1=null]",
+            ),
+            ("(\\b)*", "ab", "[0,0 g1=null] [1,1 g1=null] [2,2 g1=null]"),
+            (
+                "((?=a))*",
+                "ab",
+                "[0,0 g1=null] [1,1 g1=null] [2,2 g1=null]",
+            ),
+            ("($)+", "a", "[1,1 g1=1-1]"),
+            // An empty iteration of another group ends the loop but keeps its groups.
+            ("(a?)*", "aa", "[0,2 g1=2-2] [2,2 g1=2-2]"),
+            ("(a|)*", "ab", "[0,1 g1=1-1] [1,1 g1=1-1] [2,2 g1=2-2]"),
+            ("(a?){3}", "a", "[0,1 g1=1-1] [1,1 g1=1-1]"),
+            ("(a*)*", "aab", "[0,2 g1=2-2] [2,2 g1=2-2] [3,3 g1=3-3]"),
+            ("((a)|$)*", "a", "[0,1 g1=1-1 g2=0-1] [1,1 g1=1-1 g2=null]"),
+            ("($|b)*", "ab", "[0,0 g1=null] [1,2 g1=2-2] [2,2 g1=2-2]"),
+            ("(a?)*?x", "x", "[0,1 g1=null]"),
+            ("(?:(a)|b)*", "ab", "[0,2 g1=0-1] [2,2 g1=null]"),
+            ("(a|b)*?b", "aab", "[0,3 g1=1-2]"),
+            ("(a(?<=a))*", "aaa", "[0,3 g1=2-3] [3,3 g1=null]"),
+            // Counted closures after a quantifier are ignored.
+            ("x?{2}", "xxx", "[0,1] [1,2] [2,3] [3,3]"),
+            ("a{2}{3}", "aaaaaaa", "[0,2] [2,4] [4,6]"),
+            ("a+{2}", "aaa", "[0,3]"),
+            ("a{2}?{3}", "aaaaaa", "[0,2] [2,4] [4,6]"),
+            // The shortest lookbehind is tried first.
+            ("(?<=(a+))b", "aab", "[2,3 g1=1-2]"),
+            ("(?<=(ab)?)c", "abc", "[2,3 g1=null]"),
+            ("(?<=(ab){1,2})c", "ababc", "[4,5 g1=2-4]"),
+            ("(?<=x.*)b", "xab", "[2,3]"),
+            ("(?<=(a)+)b", "ab", "[1,2 g1=0-1]"),
+            ("(?<=(?:ab){2})c", "ababc", "[4,5]"),
+            ("(?<=a|b+)c", "bbc", "[2,3]"),
+            ("(?<=(a)|(b))c", "bc", "[1,2 g1=null g2=0-1]"),
+            ("(?<=a)(?<=(?=a)a)b", "ab", "[1,2]"),
+            ("(?<!a{2})b", "aab", ""),
+            // Groups set inside a lookaround or atomic group that matched stay set.
+            ("(?=(a))x|b", "ab", "[1,2 g1=0-1]"),
+            ("(?>(a))x|b", "ab", "[1,2 g1=0-1]"),
+            ("(?!(a))x|a", "a", "[0,1 g1=0-1]"),
+            ("(?=(a)x)|a", "a", "[0,1 g1=null]"),
+            ("(?:(a)(?=(b))x|ab)", "ab", "[0,2 g1=null g2=1-2]"),
+            ("(?>(?:(a)|b)*)c", "abc", "[0,3 g1=0-1]"),
+        ] {
+            assert_eq!(find_all(regex, input), expected, "{regex} on {input:?}");
+        }
+    }
+
+    #[test]
+    fn long_inputs_match_without_deep_recursion() {
+        let words = "ab  cd\t".repeat(60_000);
+        let pattern = Pattern::compile("\\s+").unwrap();
+        assert_eq!(pattern.split(&words, 0).len(), 120_000);
+
+        let pairs = "ab".repeat(100_000);
+        let pattern = Pattern::compile("(?<=a)b").unwrap();
+        let mut matcher = pattern.matcher(&pairs);
+        let mut count = 0;
+        while matcher.find() {
+            count += 1;
+        }
+        assert_eq!(count, 100_000);
+        for regex in ["(ab)*", "(?:ab)*", "(?:ab)*?", "(?:ab)*+", "(?:a|b)*"] {
+            assert_eq!(Pattern::matches(regex, &pairs), Ok(true), "{regex}");
+        }
+    }
+
+    #[test]
+    fn nested_repetitions_fail_in_polynomial_time() {
+        let started = Instant::now();
+        let run = "a".repeat(500);
+        for regex in ["(a*)*b", "(.*)*x", "(?:a|aa)+b", "(a+)+b"] {
+            let pattern = Pattern::compile(regex).unwrap();
+            assert!(!pattern.matcher(&run).find(), "{regex}");
+            assert!(!pattern.matcher(&run).matches(), "{regex}");
+        }
+        assert_eq!(find_all("(a*)*b", "aaab"), "[0,4 g1=3-3]");
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn syntax_errors_match_jdk() {
+        for (regex, description, index) in [
+            ("*a", "Dangling meta character '*'", 0),
+            ("a(b", "Unclosed group", 3),
+            ("a)b", "Unmatched closing ')'", 0),
+            ("[abc", "Unclosed character class", 3),
+            ("a{x", "Illegal repetition", 2),
+            ("ab\\", "Unexpected internal error", 3),
+            ("\\y", "Illegal/unsupported escape sequence", 1),
+            ("(?z)", "Unknown inline modifier", 2),
+            ("[z-a]", "Illegal character range", 3),
+            ("a**", "Dangling meta character '*'", 2),
+            ("x{2,1}", "Illegal repetition range", 5),
+            ("\\p{Foo}", "Unknown character property name {Foo}", 6),
+            (
+                "(?<n>a)(?<n>b)",
+                "Named capturing group <n> is already defined",
+                11,
+            ),
+            ("\\k<q>", "named capturing group <q> does not exist", 4),
+            ("[]", "Unclosed character class", 1),
+            ("x{2}*", "Dangling meta character '*'", 4),
+            ("a{2}{x", "Illegal repetition", 5),
+            ("(?<=ab", "Unclosed group", 6),
+        ] {
+            let error = Pattern::compile(regex).unwrap_err();
+            assert_eq!(
+                (error.get_description(), error.get_index()),
+                (description, index),
+                "{regex}"
+            );
+        }
+        for (regex, index) in [
+            ("(?<=(a)\\1)b", 8),
+            ("(?<=(ab)*)c", 8),
+            ("(?<=(?:ab)+)c", 10),
+            ("(?<=x(?:ab)*)c", 11),
+            ("(?<=(?:a|b){2})c", 13),
+            ("(?<=(?:a|bc){1,2})d", 16),
+            ("(?<=(?:a*)*)b", 10),
+        ] {
+            let error = Pattern::compile(regex).unwrap_err();
+            assert_eq!(
+                (error.get_description(), error.get_index()),
+                (
+                    "Look-behind group does not have an obvious maximum length",
+                    index
+                ),
+                "{regex}"
+            );
+        }
+        assert_eq!(
+            Pattern::compile("[abc").unwrap_err().to_string(),
+            "Unclosed character class near index 3\n[abc\n   ^"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! `java.util.Spliterator`: iterating over the elements of a source and splitting them into
+//! parts that can be gone through in parallel, with the characteristics of the source that
+//! parallel streams take into account.
+
+use std::collections::VecDeque;
+
+/// The elements have an encounter order, which splitting keeps: a split-off part holds
+/// elements before those left.
+pub const ORDERED: i32 = 0x0000_0010;
+/// No two elements are equal.
+pub const DISTINCT: i32 = 0x0000_0001;
+/// The elements are in sorted order.
+pub const SORTED: i32 = 0x0000_0004;
+/// `estimate_size` is the exact number of elements left.
+pub const SIZED: i32 = 0x0000_0040;
+/// No element is `None`.
+pub const NONNULL: i32 = 0x0000_0100;
+/// The source can't be changed while it is gone through.
+pub const IMMUTABLE: i32 = 0x0000_0400;
+/// The source can be changed safely while it is gone through.
+pub const CONCURRENT: i32 = 0x0000_1000;
+/// The parts split off are `SIZED` and `SUBSIZED` too.
+pub const SUBSIZED: i32 = 0x0000_4000;
+
+/// `java.util.Spliterator`: goes through the elements one at a time with `try_advance` or
+/// all together with `for_each_remaining`, and splits off a part of them with `try_split`.
+pub trait Spliterator<T> {
+    /// Passes the next element to `action`, returning whether there was one.
+    fn try_advance(&mut self, action: &mut dyn FnMut(T)) -> bool;
+
+    /// Passes each remaining element to `action`, in order.
+    fn for_each_remaining(&mut self, action: &mut dyn FnMut(T)) {
+        while self.try_advance(action) {}
+    }
+
+    /// Splits off a part of the elements, which this stops covering, or returns `None` if
+    /// they can't be split. If this is `ORDERED`, the part is a prefix of the elements.
+    fn try_split(&mut self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// The number of elements left, exactly if `SIZED`, or `i64::MAX` if it is unknown.
+    fn estimate_size(&self) -> i64;
+
+    /// `estimate_size` if this is `SIZED`, and otherwise -1.
+    fn get_exact_size_if_known(&self) -> i64 {
+        if self.has_characteristics(SIZED) {
+            self.estimate_size()
+        } else {
+            -1
+        }
+    }
+
+    /// The characteristics of this and its elements, such as `ORDERED` and `SIZED`, or'ed
+    /// together.
+    fn characteristics(&self) -> i32;
+
+    fn has_characteristics(&self, characteristics: i32) -> bool {
+        self.characteristics() & characteristics == characteristics
+    }
+}
+
+/// The spliterator of `Spliterators.spliterator(array, characteristics)`: it goes through
+/// the elements in order and splits off the first half of them.
+#[derive(Debug, Clone)]
+pub struct ArraySpliterator<T> {
+    elements: VecDeque<T>,
+    characteristics: i32,
+}
+
+impl<T> ArraySpliterator<T> {
+    /// A spliterator of the elements with `additional_characteristics`, which is always
+    /// `SIZED` and `SUBSIZED`.
+    pub fn new(elements: Vec<T>, additional_characteristics: i32) -> Self {
+        Self {
+            elements: elements.into(),
+            characteristics: additional_characteristics | SIZED | SUBSIZED,
+        }
+    }
+}
+
+impl<T> Spliterator<T> for ArraySpliterator<T> {
+    fn try_advance(&mut self, action: &mut dyn FnMut(T)) -> bool {
+        self.elements.pop_front().map(action).is_some()
+    }
+
+    fn for_each_remaining(&mut self, action: &mut dyn FnMut(T)) {
+        self.elements.drain(..).for_each(action)
+    }
+
+    fn try_split(&mut self) -> Option<Self> {
+        let mid = self.elements.len() / 2;
+        if mid == 0 {
+            return None;
+        }
+        let rest = self.elements.split_off(mid);
+        Some(Self {
+            elements: std::mem::replace(&mut self.elements, rest),
+            characteristics: self.characteristics,
+        })
+    }
+
+    fn estimate_size(&self) -> i64 {
+        self.elements.len() as i64
+    }
+
+    fn characteristics(&self) -> i32 {
+        self.characteristics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_spliterators() {
+        let mut rest = ArraySpliterator::new((1..=5).collect(), ORDERED | IMMUTABLE);
+        assert_eq!(rest.characteristics(), 0x4450);
+        assert!(rest.has_characteristics(ORDERED | SIZED));
+        assert!(!rest.has_characteristics(SORTED));
+
+        let mut prefix = rest.try_split().unwrap();
+        assert_eq!(prefix.get_exact_size_if_known(), 2);
+        assert_eq!(rest.estimate_size(), 3);
+        assert_eq!(prefix.characteristics(), rest.characteristics());
+
+        let mut seen = Vec::new();
+        assert!(rest.try_advance(&mut |e| seen.push(e)));
+        prefix.for_each_remaining(&mut |e| seen.push(e));
+        rest.for_each_remaining(&mut |e| seen.push(e));
+        assert_eq!(seen, [3, 1, 2, 4, 5]);
+        assert!(!rest.try_advance(&mut |_| unreachable!()));
+        assert!(rest.try_split().is_none());
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::VecDeque;
+
+use crate::errors::util::EmptyStackException;
+use crate::errors::Throwable;
+use crate::util::abstract_list::{list_impls, Kind, ListView};
+
+/// `java.util.Stack`: the legacy last-in-first-out stack, a `List` whose top is its last
+/// element. `ArrayDeque` is the better stack, but ported code uses this one.
+///
+/// Its `List` methods behave like `ArrayList`'s, throwing with its messages rather than
+/// `Vector`'s. `clone` copies the elements into a new stack.
+pub struct Stack<E> {
+    view: ListView<E>,
+}
+
+impl<E: Clone> Stack<E> {
+    pub fn new() -> Self {
+        Self {
+            view: ListView::new(Kind::Array, VecDeque::new()),
+        }
+    }
+
+    /// Pushes an element onto the top of the stack, returning it.
+    pub fn push(&mut self, item: E) -> E {
+        self.view.add(item.clone());
+        item
+    }
+
+    /// Removes the element at the top of the stack. Throws `EmptyStackException` if the
+    /// stack is empty.
+    pub fn pop(&mut self) -> E {
+        let size = self.view.size();
+        if size == 0 {
+            EmptyStackException::new(String::new()).throw()
+        }
+        self.view.remove_at(size - 1)
+    }
+
+    /// The element at the top of the stack. Throws `EmptyStackException` if the stack is
+    /// empty.
+    pub fn peek(&self) -> E {
+        let size = self.view.size();
+        if size == 0 {
+            EmptyStackException::new(String::new()).throw()
+        }
+        self.view.get(size - 1)
+    }
+
+    pub fn empty(&self) -> bool {
+        self.view.size() == 0
+    }
+
+    /// How far an element is from the top of the stack, 1 for the top, or -1 if it isn't
+    /// in the stack.
+    pub fn search(&self, o: &E) -> i32
+    where
+        E: PartialEq,
+    {
+        match self.view.last_index_of(o) {
+            Some(i) => (self.view.size() - i) as i32,
+            None => -1,
+        }
+    }
+}
+
+impl<E: Clone> Default for Stack<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone> Clone for Stack<E> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.deep_clone(),
+        }
+    }
+}
+
+impl<E: Clone> FromIterator<E> for Stack<E> {
+    /// The elements pushed in order, so that the last is at the top.
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        Self {
+            view: ListView::new(Kind::Array, iter.into_iter().collect()),
+        }
+    }
+}
+
+list_impls!(Stack);
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::{Collection, List};
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn works_as_a_stack() {
+        let mut stack = Stack::new();
+        assert!(stack.empty());
+        assert_eq!(stack.push("a"), "a");
+        stack.push("b");
+        stack.push("c");
+        stack.push("b");
+        assert_eq!(stack.to_string(), "[a, b, c, b]");
+        assert_eq!(stack.search(&"b"), 1);
+        assert_eq!(stack.search(&"a"), 4);
+        assert_eq!(stack.search(&"z"), -1);
+        assert_eq!(stack.pop(), "b");
+        assert_eq!(stack.peek(), "c");
+        assert_eq!(stack.get(0), "a");
+        stack.clear();
+        assert_eq!(message(|| stack.pop()), "");
+        assert_eq!(message(|| stack.peek()), "");
+    }
+}
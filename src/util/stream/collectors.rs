@@ -0,0 +1,572 @@
+//! `java.util.stream.Collectors`: the common `Collector`s, into collections, maps of
+//! groups, strings and summaries, and the ones that adapt others.
+
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::errors::{IllegalStateException, NullPointerException, Throwable};
+use crate::util::{
+    ArrayList, Collection, HashMap, HashSet, IntSummaryStatistics, Map, StringJoiner,
+};
+use crate::HashCode;
+
+type Accumulator<A, T> = Rc<dyn Fn(&mut A, T)>;
+
+/// `java.util.stream.Collector`: gathers elements into a mutable container of type `A`
+/// made by its supplier, and finishes it into the result `R`. The combiner merges two
+/// containers, for collecting parts of a stream separately.
+pub struct Collector<T, A, R> {
+    supplier: Rc<dyn Fn() -> A>,
+    accumulator: Accumulator<A, T>,
+    combiner: Rc<dyn Fn(A, A) -> A>,
+    finisher: Rc<dyn Fn(A) -> R>,
+}
+
+impl<T, A> Collector<T, A, A> {
+    /// A collector whose result is its container.
+    pub fn of(
+        supplier: impl Fn() -> A + 'static,
+        accumulator: impl Fn(&mut A, T) + 'static,
+        combiner: impl Fn(A, A) -> A + 'static,
+    ) -> Self {
+        Self::of_with_finisher(supplier, accumulator, combiner, |container| container)
+    }
+}
+
+impl<T, A, R> Collector<T, A, R> {
+    pub fn of_with_finisher(
+        supplier: impl Fn() -> A + 'static,
+        accumulator: impl Fn(&mut A, T) + 'static,
+        combiner: impl Fn(A, A) -> A + 'static,
+        finisher: impl Fn(A) -> R + 'static,
+    ) -> Self {
+        Self {
+            supplier: Rc::new(supplier),
+            accumulator: Rc::new(accumulator),
+            combiner: Rc::new(combiner),
+            finisher: Rc::new(finisher),
+        }
+    }
+
+    /// A new, empty container.
+    pub fn supply(&self) -> A {
+        (self.supplier)()
+    }
+
+    /// Adds an element to a container.
+    pub fn accumulate(&self, container: &mut A, t: T) {
+        (self.accumulator)(container, t)
+    }
+
+    /// Merges two containers into one, the elements of `left` before those of `right`.
+    pub fn combine(&self, left: A, right: A) -> A {
+        (self.combiner)(left, right)
+    }
+
+    pub fn finish(&self, container: A) -> R {
+        (self.finisher)(container)
+    }
+
+    pub(super) fn collect(&self, elements: impl Iterator<Item = T>) -> R {
+        let mut container = self.supply();
+        for t in elements {
+            self.accumulate(&mut container, t);
+        }
+        self.finish(container)
+    }
+}
+
+impl<T, A, R> Clone for Collector<T, A, R> {
+    fn clone(&self) -> Self {
+        Self {
+            supplier: Rc::clone(&self.supplier),
+            accumulator: Rc::clone(&self.accumulator),
+            combiner: Rc::clone(&self.combiner),
+            finisher: Rc::clone(&self.finisher),
+        }
+    }
+}
+
+/// The elements in an `ArrayList`, in encounter order.
+pub fn to_list<T: Clone + 'static>() -> Collector<T, ArrayList<T>, ArrayList<T>> {
+    Collector::of(
+        ArrayList::new,
+        |list, t| {
+            list.add(t);
+        },
+        |mut left, right| {
+            left.add_all(&right);
+            left
+        },
+    )
+}
+
+/// The elements in a `HashSet`.
+pub fn to_set<T: Clone + HashCode + PartialEq + 'static>() -> Collector<T, HashSet<T>, HashSet<T>> {
+    Collector::of(
+        HashSet::new,
+        |set, t| {
+            set.add(t);
+        },
+        |mut left, mut right| {
+            if left.size() < right.size() {
+                right.add_all(&left);
+                right
+            } else {
+                left.add_all(&right);
+                left
+            }
+        },
+    )
+}
+
+fn duplicate_key<K: Display, V: Display>(k: &K, u: &V, v: &V) -> ! {
+    IllegalStateException::new(
+        format!("Duplicate key {k} (attempted merging values {u} and {v})"),
+        None,
+    )
+    .throw()
+}
+
+/// The elements in a `HashMap`, each mapped from the key and to the value of its mappers.
+/// Throws `IllegalStateException` if two elements map to the same key.
+pub fn to_map<T, K, U>(
+    key_mapper: impl Fn(&T) -> K + 'static,
+    value_mapper: impl Fn(&T) -> U + 'static,
+) -> Collector<T, HashMap<K, U>, HashMap<K, U>>
+where
+    T: 'static,
+    K: Clone + HashCode + PartialEq + Display + 'static,
+    U: Clone + Display + 'static,
+{
+    Collector::of(
+        HashMap::new,
+        move |map, t| {
+            let k = key_mapper(&t);
+            let v = value_mapper(&t);
+            if let Some(u) = map.put_if_absent(k.clone(), v.clone()) {
+                duplicate_key(&k, &u, &v)
+            }
+        },
+        |mut left, right| {
+            for (k, v) in right.iterator() {
+                if let Some(u) = left.put_if_absent(k.clone(), v.clone()) {
+                    duplicate_key(&k, &u, &v)
+                }
+            }
+            left
+        },
+    )
+}
+
+/// Like `to_map`, but merging the values of elements that map to the same key by
+/// `merge_function`.
+pub fn to_map_merging<T, K, U>(
+    key_mapper: impl Fn(&T) -> K + 'static,
+    value_mapper: impl Fn(&T) -> U + 'static,
+    merge_function: impl Fn(&U, &U) -> U + 'static,
+) -> Collector<T, HashMap<K, U>, HashMap<K, U>>
+where
+    T: 'static,
+    K: Clone + HashCode + PartialEq + 'static,
+    U: Clone + 'static,
+{
+    let merge_function = Rc::new(merge_function);
+    let merge = Rc::clone(&merge_function);
+    Collector::of(
+        HashMap::new,
+        move |map, t| {
+            map.merge(key_mapper(&t), value_mapper(&t), |a, b| Some(merge(a, b)));
+        },
+        move |mut left, right| {
+            for (k, v) in right.iterator() {
+                left.merge(k, v, |a, b| Some(merge_function(a, b)));
+            }
+            left
+        },
+    )
+}
+
+/// The container of `grouping_by`: the key and container of each group, in the order of
+/// their first elements.
+struct Groups<K, A> {
+    indexes: HashMap<K, usize>,
+    groups: Vec<(K, A)>,
+}
+
+impl<K: Clone + HashCode + PartialEq, A> Groups<K, A> {
+    fn new() -> Self {
+        Self {
+            indexes: HashMap::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, key: K, container: A) {
+        self.indexes.put(key.clone(), self.groups.len());
+        self.groups.push((key, container));
+    }
+
+    /// The container of the key's group, which `supplier` makes if it is new.
+    fn group(&mut self, key: K, supplier: impl FnOnce() -> A) -> &mut A {
+        let index = match self.indexes.get(&key) {
+            Some(index) => index,
+            None => {
+                self.insert(key, supplier());
+                self.groups.len() - 1
+            }
+        };
+        &mut self.groups[index].1
+    }
+}
+
+/// The elements in lists in a `HashMap`, by the key `classifier` maps them to. Throws
+/// `NullPointerException` if it maps one to `None`.
+pub fn grouping_by<T, K>(
+    classifier: impl Fn(&T) -> K + 'static,
+) -> Collector<T, impl Sized, HashMap<K, ArrayList<T>>>
+where
+    T: Clone + 'static,
+    K: Clone + HashCode + PartialEq + 'static,
+{
+    grouping_by_with(classifier, to_list())
+}
+
+/// Like `grouping_by`, but collecting each group by `downstream`. The map has its keys in
+/// the same order as Java's.
+pub fn grouping_by_with<T, K, A, D>(
+    classifier: impl Fn(&T) -> K + 'static,
+    downstream: Collector<T, A, D>,
+) -> Collector<T, impl Sized, HashMap<K, D>>
+where
+    T: 'static,
+    K: Clone + HashCode + PartialEq + 'static,
+    A: 'static,
+    D: Clone + 'static,
+{
+    let (accumulating, combining) = (downstream.clone(), downstream.clone());
+    Collector::of_with_finisher(
+        Groups::new,
+        move |groups: &mut Groups<K, A>, t| {
+            let key = classifier(&t);
+            if key.is_null() {
+                NullPointerException::new("element cannot be mapped to a null key".to_string())
+                    .throw()
+            }
+            let container = groups.group(key, || accumulating.supply());
+            accumulating.accumulate(container, t);
+        },
+        move |left, right| {
+            let mut rest: Vec<_> = right.groups.into_iter().map(Some).collect();
+            let mut merged = Groups::new();
+            for (key, container) in left.groups {
+                let container = match right.indexes.get(&key) {
+                    Some(index) => {
+                        let (_, other) = rest[index].take().unwrap();
+                        combining.combine(container, other)
+                    }
+                    None => container,
+                };
+                merged.insert(key, container);
+            }
+            for (key, container) in rest.into_iter().flatten() {
+                merged.insert(key, container);
+            }
+            merged
+        },
+        move |groups| {
+            // Java's map gets its keys in the same order, so it has the same table.
+            let mut map = HashMap::new();
+            for (key, container) in groups.groups {
+                map.put(key, downstream.finish(container));
+            }
+            map
+        },
+    )
+}
+
+/// The elements in lists in a `HashMap` from `false` and `true`, by whether they match
+/// `predicate`. Both keys are always there.
+pub fn partitioning_by<T: Clone + 'static>(
+    predicate: impl Fn(&T) -> bool + 'static,
+) -> Collector<T, impl Sized, HashMap<bool, ArrayList<T>>> {
+    partitioning_by_with(predicate, to_list())
+}
+
+/// Like `partitioning_by`, but collecting each part by `downstream`.
+pub fn partitioning_by_with<T, A, D>(
+    predicate: impl Fn(&T) -> bool + 'static,
+    downstream: Collector<T, A, D>,
+) -> Collector<T, impl Sized, HashMap<bool, D>>
+where
+    T: 'static,
+    A: 'static,
+    D: Clone + 'static,
+{
+    let (supplying, accumulating, combining) =
+        (downstream.clone(), downstream.clone(), downstream.clone());
+    Collector::of_with_finisher(
+        move || (supplying.supply(), supplying.supply()),
+        move |(rejected, matched): &mut (A, A), t| {
+            if predicate(&t) {
+                accumulating.accumulate(matched, t);
+            } else {
+                accumulating.accumulate(rejected, t);
+            }
+        },
+        move |left, right| {
+            (
+                combining.combine(left.0, right.0),
+                combining.combine(left.1, right.1),
+            )
+        },
+        move |(rejected, matched)| {
+            let mut map = HashMap::new();
+            map.put(false, downstream.finish(rejected));
+            map.put(true, downstream.finish(matched));
+            map
+        },
+    )
+}
+
+/// The elements mapped by `mapper`, collected by `downstream`.
+pub fn mapping<T, U, A, R>(
+    mapper: impl Fn(T) -> U + 'static,
+    downstream: Collector<U, A, R>,
+) -> Collector<T, A, R>
+where
+    U: 'static,
+    A: 'static,
+    R: 'static,
+{
+    let (accumulating, combining, finishing) =
+        (downstream.clone(), downstream.clone(), downstream.clone());
+    Collector::of_with_finisher(
+        move || downstream.supply(),
+        move |container, t| accumulating.accumulate(container, mapper(t)),
+        move |left, right| combining.combine(left, right),
+        move |container| finishing.finish(container),
+    )
+}
+
+/// The strings concatenated.
+pub fn joining<T: AsRef<str>>() -> Collector<T, impl Sized, String> {
+    joining_with_prefix_suffix("", "", "")
+}
+
+/// The strings separated by `delimiter`.
+pub fn joining_with<T: AsRef<str>>(delimiter: &str) -> Collector<T, impl Sized, String> {
+    joining_with_prefix_suffix(delimiter, "", "")
+}
+
+/// The strings separated by `delimiter`, between `prefix` and `suffix`.
+pub fn joining_with_prefix_suffix<T: AsRef<str>>(
+    delimiter: &str,
+    prefix: &str,
+    suffix: &str,
+) -> Collector<T, impl Sized, String> {
+    let (delimiter, prefix, suffix) = (
+        delimiter.to_string(),
+        prefix.to_string(),
+        suffix.to_string(),
+    );
+    Collector::of_with_finisher(
+        move || StringJoiner::with_prefix_suffix(&delimiter, &prefix, &suffix),
+        |joiner: &mut StringJoiner, t: T| {
+            joiner.add(t.as_ref());
+        },
+        |mut left, right| {
+            left.merge(&right);
+            left
+        },
+        |joiner| joiner.to_string(),
+    )
+}
+
+/// The number of elements.
+pub fn counting<T>() -> Collector<T, i64, i64> {
+    Collector::of(|| 0, |count, _| *count += 1, |left, right| left + right)
+}
+
+/// The sum of the `int`s `mapper` maps the elements to, which overflows like Java's.
+pub fn summing_int<T>(mapper: impl Fn(&T) -> i32 + 'static) -> Collector<T, i32, i32> {
+    Collector::of(
+        || 0,
+        move |sum: &mut i32, t| *sum = sum.wrapping_add(mapper(&t)),
+        i32::wrapping_add,
+    )
+}
+
+/// The statistics of the `int`s `mapper` maps the elements to.
+pub fn summarizing_int<T>(
+    mapper: impl Fn(&T) -> i32 + 'static,
+) -> Collector<T, IntSummaryStatistics, IntSummaryStatistics> {
+    Collector::of(
+        IntSummaryStatistics::new,
+        move |statistics, t| statistics.accept(mapper(&t)),
+        |mut left, right| {
+            left.combine(&right);
+            left
+        },
+    )
+}
+
+/// Collects each element by both `downstream1` and `downstream2`, and merges their results
+/// by `merger`.
+pub fn teeing<T, A1, R1, A2, R2, R>(
+    downstream1: Collector<T, A1, R1>,
+    downstream2: Collector<T, A2, R2>,
+    merger: impl Fn(R1, R2) -> R + 'static,
+) -> Collector<T, impl Sized, R>
+where
+    T: Clone + 'static,
+    A1: 'static,
+    R1: 'static,
+    A2: 'static,
+    R2: 'static,
+{
+    let (supplying1, accumulating1, combining1) = (
+        downstream1.clone(),
+        downstream1.clone(),
+        downstream1.clone(),
+    );
+    let (supplying2, accumulating2, combining2) = (
+        downstream2.clone(),
+        downstream2.clone(),
+        downstream2.clone(),
+    );
+    Collector::of_with_finisher(
+        move || (supplying1.supply(), supplying2.supply()),
+        move |(container1, container2): &mut (A1, A2), t: T| {
+            accumulating1.accumulate(container1, t.clone());
+            accumulating2.accumulate(container2, t);
+        },
+        move |left, right| {
+            (
+                combining1.combine(left.0, right.0),
+                combining2.combine(left.1, right.1),
+            )
+        },
+        move |(container1, container2)| {
+            merger(
+                downstream1.finish(container1),
+                downstream2.finish(container2),
+            )
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::stream::Stream;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    fn fruit() -> Stream<'static, &'static str> {
+        Stream::of(["apple", "fig", "kiwi", "banana", "pear", "plum"])
+    }
+
+    #[test]
+    fn collects_like_jdk() {
+        assert_eq!(
+            fruit()
+                .collect(grouping_by(|s: &&str| s.len() as i32))
+                .to_string(),
+            "{3=[fig], 4=[kiwi, pear, plum], 5=[apple], 6=[banana]}"
+        );
+        assert_eq!(
+            fruit()
+                .collect(grouping_by_with(|s: &&str| s.len() as i32, counting()))
+                .to_string(),
+            "{3=1, 4=3, 5=1, 6=1}"
+        );
+        let numbers = || Stream::of([1, 2, 3, 4, 5]);
+        assert_eq!(
+            numbers()
+                .collect(partitioning_by(|x| x % 2 == 0))
+                .to_string(),
+            "{false=[1, 3, 5], true=[2, 4]}"
+        );
+        assert_eq!(
+            numbers()
+                .collect(partitioning_by_with(|&x| x > 9, counting()))
+                .to_string(),
+            "{false=5, true=0}"
+        );
+        assert_eq!(
+            Stream::of(["a", "b", "c"]).collect(joining_with_prefix_suffix(", ", "[", "]")),
+            "[a, b, c]"
+        );
+        assert_eq!(fruit().limit(2).collect(joining()), "applefig");
+        assert_eq!(
+            Stream::of([1, 2, 3]).collect(teeing(counting(), summing_int(|&x| x), |c, s| {
+                format!("{s}/{c}")
+            })),
+            "6/3"
+        );
+        assert_eq!(
+            numbers().collect(summarizing_int(|&x| x)).to_string(),
+            "IntSummaryStatistics{count=5, sum=15, min=1, average=3.000000, max=5}"
+        );
+        assert_eq!(
+            IntSummaryStatistics::new().to_string(),
+            "IntSummaryStatistics{count=0, sum=0, min=2147483647, average=0.000000, \
+             max=-2147483648}"
+        );
+        assert_eq!(
+            fruit()
+                .collect(mapping(|s: &str| s.len() as i32, to_set()))
+                .to_string(),
+            "[3, 4, 5, 6]"
+        );
+        assert_eq!(numbers().collect(to_list()).to_string(), "[1, 2, 3, 4, 5]");
+        assert_eq!(
+            Stream::of(["x", "yy", "zz"])
+                .collect(to_map_merging(
+                    |s: &&str| s.len() as i32,
+                    |_| 1,
+                    |a, b| a + b
+                ))
+                .to_string(),
+            "{1=1, 2=2}"
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_keys() {
+        assert_eq!(
+            message(|| Stream::of(["a", "b", "a"]).collect(to_map(|s: &&str| *s, |s| s.len()))),
+            "Duplicate key a (attempted merging values 1 and 1)"
+        );
+        assert_eq!(
+            message(|| Stream::of([1]).collect(grouping_by(|_| None::<i32>))),
+            "element cannot be mapped to a null key"
+        );
+    }
+
+    #[test]
+    fn combines_containers() {
+        let collector = grouping_by_with(|x: &i32| x % 3, to_list());
+        let mut left = collector.supply();
+        let mut right = collector.supply();
+        for x in [1, 2, 4] {
+            collector.accumulate(&mut left, x);
+        }
+        for x in [3, 5, 7] {
+            collector.accumulate(&mut right, x);
+        }
+        let merged = collector.combine(left, right);
+        assert_eq!(
+            collector.finish(merged).to_string(),
+            "{0=[3], 1=[1, 4, 7], 2=[2, 5]}"
+        );
+    }
+}
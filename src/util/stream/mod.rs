@@ -0,0 +1,560 @@
+//! `java.util.stream`: pipelines of operations over the elements of a source, which run
+//! when a terminal operation asks for their results, and the `Collector`s that gather the
+//! elements into a result.
+
+use std::cell::RefCell;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use crate::errors::{IllegalArgumentException, IllegalStateException, Throwable};
+use crate::util::immutable_collections::ImmutableList;
+use crate::util::{natural_order, tim_sort, Collection, Comparator, HashSet, Optional};
+use crate::HashCode;
+
+pub mod collectors;
+mod parallel;
+
+pub use collectors::Collector;
+pub use parallel::ParallelStream;
+
+type Source<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
+
+/// The actions registered by `on_close`, which all the stages of a pipeline share.
+type CloseActions<'a> = Rc<RefCell<Vec<Box<dyn FnOnce() + 'a>>>>;
+
+/// `java.util.stream.Stream`: a sequence of elements that intermediate operations such as
+/// `filter` and `map` lazily transform, and a terminal operation such as `collect` or
+/// `for_each` consumes, pulling each element through the whole pipeline in turn.
+///
+/// Like a Java stream, each stage can be operated upon only once. The operations take the
+/// stream by value, but `clone` returns the same stream, like copying a Java reference, and
+/// operating on a stream that has already been operated upon or closed throws
+/// `IllegalStateException`.
+pub struct Stream<'a, T> {
+    /// The elements, until an operation links this stage to the next or consumes it.
+    source: Rc<RefCell<Option<Source<'a, T>>>>,
+    close_actions: CloseActions<'a>,
+}
+
+fn operated_upon() -> ! {
+    IllegalStateException::new(
+        "stream has already been operated upon or closed".to_string(),
+        None,
+    )
+    .throw()
+}
+
+/// Runs the actions once each, rethrowing the first exception thrown once they have all
+/// run, like Java's `close` does.
+fn run_close_actions(close_actions: &CloseActions<'_>) {
+    let actions = std::mem::take(&mut *close_actions.borrow_mut());
+    let mut thrown = None;
+    for action in actions {
+        if let Err(error) = catch_unwind(AssertUnwindSafe(action)) {
+            thrown.get_or_insert(error);
+        }
+    }
+    if let Some(error) = thrown {
+        resume_unwind(error)
+    }
+}
+
+impl<'a, T: 'a> Stream<'a, T> {
+    /// A stream of the elements, in order.
+    pub fn of<I>(elements: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'a,
+    {
+        Self::from_source(Box::new(elements.into_iter()), Rc::default())
+    }
+
+    pub fn empty() -> Self {
+        Self::of(std::iter::empty())
+    }
+
+    /// The infinite stream of `seed`, `f(seed)`, `f(f(seed))` and so on. Each element is
+    /// computed only when it is needed.
+    pub fn iterate<F: FnMut(&T) -> T + 'a>(seed: T, mut f: F) -> Self
+    where
+        T: Clone,
+    {
+        let mut seed = Some(seed);
+        let mut previous: Option<T> = None;
+        Self::of(std::iter::from_fn(move || {
+            let t = match previous.take() {
+                Some(previous) => f(&previous),
+                None => seed.take()?,
+            };
+            previous = Some(t.clone());
+            Some(t)
+        }))
+    }
+
+    /// Like `iterate`, but ending before the first element that doesn't match `has_next`.
+    pub fn iterate_while<P, F>(seed: T, mut has_next: P, mut next: F) -> Self
+    where
+        T: Clone,
+        P: FnMut(&T) -> bool + 'a,
+        F: FnMut(&T) -> T + 'a,
+    {
+        let mut seed = Some(seed);
+        let mut previous: Option<T> = None;
+        let mut finished = false;
+        Self::of(std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+            let t = match previous.take() {
+                Some(previous) => next(&previous),
+                None => seed.take()?,
+            };
+            if !has_next(&t) {
+                finished = true;
+                return None;
+            }
+            previous = Some(t.clone());
+            Some(t)
+        }))
+    }
+
+    /// The infinite stream of the elements `supplier` returns.
+    pub fn generate<F: FnMut() -> T + 'a>(supplier: F) -> Self {
+        Self::of(std::iter::repeat_with(supplier))
+    }
+
+    /// The elements of `a` followed by those of `b`. Closing the stream closes both.
+    pub fn concat(a: Self, b: Self) -> Self {
+        let source = Box::new(a.link().chain(b.link()));
+        let close_actions: CloseActions<'a> = Rc::default();
+        close_actions.borrow_mut().push(Box::new(move || {
+            let a = catch_unwind(AssertUnwindSafe(|| run_close_actions(&a.close_actions)));
+            run_close_actions(&b.close_actions);
+            if let Err(error) = a {
+                resume_unwind(error)
+            }
+        }));
+        Self::from_source(source, close_actions)
+    }
+
+    fn from_source(source: Source<'a, T>, close_actions: CloseActions<'a>) -> Self {
+        Self {
+            source: Rc::new(RefCell::new(Some(source))),
+            close_actions,
+        }
+    }
+
+    /// Takes the elements, leaving this stage operated upon.
+    fn link(&self) -> Source<'a, T> {
+        self.source
+            .borrow_mut()
+            .take()
+            .unwrap_or_else(|| operated_upon())
+    }
+
+    /// The next stage of the pipeline, with the elements of `source`.
+    fn chain<U: 'a>(self, source: impl Iterator<Item = U> + 'a) -> Stream<'a, U> {
+        Stream::from_source(Box::new(source), self.close_actions)
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        false
+    }
+
+    /// Registers an action to run when the stream is closed, returning the same stream.
+    pub fn on_close<F: FnOnce() + 'a>(self, close_handler: F) -> Self {
+        if self.source.borrow().is_none() {
+            operated_upon()
+        }
+        self.close_actions
+            .borrow_mut()
+            .push(Box::new(close_handler));
+        self
+    }
+
+    /// Runs the actions registered by `on_close` on any stage of the pipeline, once each
+    /// and in order. If any throw, the first exception is rethrown after they have all run.
+    pub fn close(&self) {
+        self.source.borrow_mut().take();
+        run_close_actions(&self.close_actions);
+    }
+
+    pub fn filter<P: FnMut(&T) -> bool + 'a>(self, predicate: P) -> Self {
+        let source = self.link();
+        self.chain(source.filter(predicate))
+    }
+
+    pub fn map<U: 'a, F: FnMut(T) -> U + 'a>(self, mapper: F) -> Stream<'a, U> {
+        let source = self.link();
+        self.chain(source.map(mapper))
+    }
+
+    /// Replaces each element by the elements of the stream `mapper` maps it to, closing
+    /// that stream once they have been gone through.
+    pub fn flat_map<U: 'a, F: FnMut(T) -> Stream<'a, U> + 'a>(
+        self,
+        mut mapper: F,
+    ) -> Stream<'a, U> {
+        let source = self.link();
+        self.chain(source.flat_map(move |e| {
+            let stream = mapper(e);
+            Flattened {
+                source: stream.link(),
+                stream,
+            }
+        }))
+    }
+
+    /// Replaces each element by the elements `mapper` passes to the consumer it is given.
+    pub fn map_multi<U: 'a, F: FnMut(T, &mut dyn FnMut(U)) + 'a>(
+        self,
+        mut mapper: F,
+    ) -> Stream<'a, U> {
+        let source = self.link();
+        self.chain(source.flat_map(move |e| {
+            let mut buffer = Vec::new();
+            mapper(e, &mut |u| buffer.push(u));
+            buffer
+        }))
+    }
+
+    /// Drops the elements equal to one before them, by `equals` and `hashCode`.
+    pub fn distinct(self) -> Self
+    where
+        T: Clone + HashCode + PartialEq,
+    {
+        let source = self.link();
+        let mut seen = HashSet::new();
+        self.chain(source.filter(move |e| seen.add(e.clone())))
+    }
+
+    /// Sorts the elements by their natural order, stably.
+    pub fn sorted(self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.sorted_with(natural_order())
+    }
+
+    /// Sorts the elements by the comparator, stably. Like Java, the first element that
+    /// comes out of the stage waits for every element to go in.
+    pub fn sorted_with<C: Comparator<T> + 'a>(self, comparator: C) -> Self
+    where
+        T: Clone,
+    {
+        let mut source = Some(self.link());
+        let mut sorted: Option<std::vec::IntoIter<T>> = None;
+        self.chain(std::iter::from_fn(move || {
+            sorted
+                .get_or_insert_with(|| {
+                    let mut elements: Vec<T> = source.take().unwrap().collect();
+                    tim_sort::sort(&mut elements, &|a, b| comparator.compare(a, b));
+                    elements.into_iter()
+                })
+                .next()
+        }))
+    }
+
+    /// Passes each element to `action` as it goes through.
+    pub fn peek<F: FnMut(&T) + 'a>(self, action: F) -> Self {
+        let source = self.link();
+        self.chain(source.inspect(action))
+    }
+
+    /// Only the first `max_size` elements. Throws `IllegalArgumentException` if it is
+    /// negative.
+    pub fn limit(self, max_size: i64) -> Self {
+        if max_size < 0 {
+            IllegalArgumentException::new(max_size.to_string(), None).throw()
+        }
+        let source = self.link();
+        self.chain(source.take(max_size as usize))
+    }
+
+    /// All but the first `n` elements. Throws `IllegalArgumentException` if it is
+    /// negative.
+    pub fn skip(self, n: i64) -> Self {
+        if n < 0 {
+            IllegalArgumentException::new(n.to_string(), None).throw()
+        }
+        let source = self.link();
+        self.chain(source.skip(n as usize))
+    }
+
+    /// The elements before the first that doesn't match `predicate`.
+    pub fn take_while<P: FnMut(&T) -> bool + 'a>(self, predicate: P) -> Self {
+        let source = self.link();
+        self.chain(source.take_while(predicate))
+    }
+
+    /// The elements from the first that doesn't match `predicate`.
+    pub fn drop_while<P: FnMut(&T) -> bool + 'a>(self, predicate: P) -> Self {
+        let source = self.link();
+        self.chain(source.skip_while(predicate))
+    }
+
+    pub fn for_each<F: FnMut(T)>(self, action: F) {
+        self.link().for_each(action)
+    }
+
+    pub fn for_each_ordered<F: FnMut(T)>(self, action: F) {
+        self.link().for_each(action)
+    }
+
+    /// Folds the elements into `identity` by `accumulator`, from the first.
+    pub fn reduce<F: FnMut(T, T) -> T>(self, identity: T, accumulator: F) -> T {
+        self.link().fold(identity, accumulator)
+    }
+
+    /// Folds the elements by `accumulator` into the first, if there is one.
+    pub fn reduce_optional<F: FnMut(T, T) -> T>(self, accumulator: F) -> Optional<T> {
+        self.link().reduce(accumulator).into()
+    }
+
+    /// Gathers the elements into the collector's result.
+    pub fn collect<A, R>(self, collector: Collector<T, A, R>) -> R {
+        collector.collect(self.link())
+    }
+
+    /// The elements in a list that can't be changed, and which unlike `List.of`'s may hold
+    /// `None`.
+    pub fn to_list(self) -> ImmutableList<T>
+    where
+        T: Clone,
+    {
+        ImmutableList::allowing_nulls(self.link().collect())
+    }
+
+    pub fn to_array(self) -> Vec<T> {
+        self.link().collect()
+    }
+
+    /// The least element by the comparator, the first of those that are least.
+    pub fn min<C: Comparator<T>>(self, comparator: C) -> Optional<T> {
+        self.reduce_optional(|a, b| {
+            if comparator.compare(&a, &b) <= 0 {
+                a
+            } else {
+                b
+            }
+        })
+    }
+
+    /// The greatest element by the comparator, the first of those that are greatest.
+    pub fn max<C: Comparator<T>>(self, comparator: C) -> Optional<T> {
+        self.reduce_optional(|a, b| {
+            if comparator.compare(&a, &b) >= 0 {
+                a
+            } else {
+                b
+            }
+        })
+    }
+
+    pub fn count(self) -> i64 {
+        self.link().count() as i64
+    }
+
+    pub fn any_match<P: FnMut(&T) -> bool>(self, mut predicate: P) -> bool {
+        self.link().any(|e| predicate(&e))
+    }
+
+    pub fn all_match<P: FnMut(&T) -> bool>(self, mut predicate: P) -> bool {
+        self.link().all(|e| predicate(&e))
+    }
+
+    pub fn none_match<P: FnMut(&T) -> bool>(self, predicate: P) -> bool {
+        !self.any_match(predicate)
+    }
+
+    pub fn find_first(self) -> Optional<T> {
+        self.link().next().into()
+    }
+
+    /// Any element, which for a sequential stream is the first.
+    pub fn find_any(self) -> Optional<T> {
+        self.find_first()
+    }
+
+    /// The elements, pulled through the pipeline one by one.
+    pub fn iterator(self) -> Box<dyn Iterator<Item = T> + 'a> {
+        self.link()
+    }
+}
+
+impl<T> Clone for Stream<'_, T> {
+    /// The same stream.
+    fn clone(&self) -> Self {
+        Self {
+            source: Rc::clone(&self.source),
+            close_actions: Rc::clone(&self.close_actions),
+        }
+    }
+}
+
+/// The elements of a stream `flat_map` mapped an element to, which closes the stream once
+/// they have been gone through or dropped.
+struct Flattened<'a, T> {
+    stream: Stream<'a, T>,
+    source: Source<'a, T>,
+}
+
+impl<T> Iterator for Flattened<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.source.next()
+    }
+}
+
+impl<T> Drop for Flattened<'_, T> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            run_close_actions(&self.stream.close_actions);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::util::{reverse_order, ArrayList, List};
+    use crate::JavaString;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn pipelines() {
+        let list: ArrayList<i32> = [5, 3, 1, 3, 5, 2].into_iter().collect();
+        assert_eq!(
+            list.stream().distinct().sorted().to_list().to_string(),
+            "[1, 2, 3, 5]"
+        );
+        assert_eq!(
+            Stream::iterate(1, |x| x * 2)
+                .limit(10)
+                .to_list()
+                .to_string(),
+            "[1, 2, 4, 8, 16, 32, 64, 128, 256, 512]"
+        );
+        assert_eq!(
+            Stream::iterate_while(1, |&x| x < 100, |x| x * 3).to_array(),
+            [1, 3, 9, 27, 81]
+        );
+        let numbers = || Stream::of([1, 2, 3, 4, 5, 1]);
+        assert_eq!(numbers().take_while(|&x| x < 4).to_array(), [1, 2, 3]);
+        assert_eq!(numbers().drop_while(|&x| x < 4).to_array(), [4, 5, 1]);
+        assert_eq!(numbers().skip(4).to_array(), [5, 1]);
+        assert_eq!(
+            Stream::of([1, 2, 3])
+                .map_multi(|x, sink| (0..x).for_each(|_| sink(x)))
+                .to_array(),
+            [1, 2, 2, 3, 3, 3]
+        );
+        assert_eq!(
+            Stream::of(["a b", "c"])
+                .flat_map(|s| Stream::of(s.split(' ')))
+                .map(str::to_uppercase)
+                .to_array(),
+            ["A", "B", "C"]
+        );
+        assert_eq!(Stream::of([1, 2, 3, 4]).reduce(0, |a, b| a + b), 10);
+        assert_eq!(
+            Stream::<i32>::empty().reduce_optional(|a, b| a + b),
+            Optional::empty()
+        );
+        assert_eq!(Stream::of([3, 1, 2]).max(natural_order()), Optional::of(3));
+        assert_eq!(Stream::of([3, 1, 2]).min(reverse_order()), Optional::of(3));
+        assert_eq!(Stream::of(["b", "a", "c"]).find_first(), Optional::of("b"));
+        assert!(Stream::generate(|| 7).limit(3).all_match(|&x| x == 7));
+        assert_eq!(Stream::concat(numbers(), Stream::of([9])).count(), 7);
+
+        let text = String::from("ab\r\ncd\ref\n");
+        assert_eq!(text.java_lines().to_array(), ["ab", "cd", "ef"]);
+        assert_eq!(
+            Stream::of(text.java_chars()).filter(|&c| c == 13).count(),
+            2
+        );
+    }
+
+    #[test]
+    fn peeks_in_encounter_order() {
+        let seen = RefCell::new(Vec::new());
+        let first = Stream::of([3, 1, 2])
+            .peek(|&x| seen.borrow_mut().push(x))
+            .sorted()
+            .find_first();
+        assert_eq!(first, Optional::of(1));
+        assert_eq!(*seen.borrow(), [3, 1, 2]);
+
+        seen.borrow_mut().clear();
+        Stream::of([1, 2, 3])
+            .peek(|&x| seen.borrow_mut().push(x))
+            .limit(2)
+            .for_each(|_| {});
+        assert_eq!(*seen.borrow(), [1, 2]);
+    }
+
+    #[test]
+    fn streams_are_single_use() {
+        let stream = Stream::of([1, 2, 3]);
+        let _ = stream.clone().map(|x| x);
+        assert_eq!(
+            message(|| stream.filter(|_| true)),
+            "stream has already been operated upon or closed"
+        );
+        assert_eq!(message(|| Stream::of([1]).limit(-1)), "-1");
+        assert_eq!(message(|| Stream::of([1]).skip(-3)), "-3");
+
+        let closed = Stream::of([1]);
+        closed.close();
+        assert_eq!(
+            message(|| closed.count()),
+            "stream has already been operated upon or closed"
+        );
+        let consumed = Stream::of([1, 2]);
+        consumed.clone().count();
+        consumed.close();
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let (first, second) = (Rc::clone(&log), Rc::clone(&log));
+        let stream = Stream::of([1, 2])
+            .on_close(move || first.borrow_mut().push("closed"))
+            .on_close(|| IllegalStateException::new("x".to_string(), None).throw())
+            .on_close(move || second.borrow_mut().push("after"));
+        let mapped = stream.map(|x| x + 1);
+        assert_eq!(message(|| mapped.close()), "x");
+        assert_eq!(*log.borrow(), ["closed", "after"]);
+
+        let flattened = Rc::new(RefCell::new(0));
+        let count = Rc::clone(&flattened);
+        Stream::of([1, 2])
+            .flat_map(move |x| {
+                let count = Rc::clone(&count);
+                Stream::of([x]).on_close(move || *count.borrow_mut() += 1)
+            })
+            .for_each(|_| {});
+        assert_eq!(*flattened.borrow(), 2);
+    }
+
+    #[test]
+    fn to_list() {
+        let list = Stream::of([1, 2]).to_list();
+        assert_eq!(
+            message(|| list.get(5)),
+            "Index 5 out of bounds for length 2"
+        );
+        let nullable = Stream::of([Some(1), None]).to_list();
+        assert_eq!(nullable.index_of(&None), Some(1));
+        assert_eq!(message(|| nullable.list_iterator_at(4)), "Index: 4 Size: 2");
+        assert_eq!(message(|| Stream::of([1, 2]).to_list().add(3)), "");
+    }
+}
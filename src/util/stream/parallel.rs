@@ -0,0 +1,834 @@
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex};
+
+use super::{run_close_actions, CloseActions, Collector, Stream};
+use crate::errors::{IllegalArgumentException, Throwable};
+use crate::util::concurrent::{ForkJoinPool, RecursiveTask};
+use crate::util::immutable_collections::ImmutableList;
+use crate::util::spliterator::{self, ArraySpliterator, Spliterator};
+use crate::util::{natural_order, tim_sort, Collection, Comparator, HashSet, Optional};
+use crate::HashCode;
+
+/// How many more elements each batch `Stream::parallel` takes from a sequential stream has
+/// than the one before, like the JDK's `IteratorSpliterator`.
+const BATCH_UNIT: usize = 1 << 10;
+const MAX_BATCH: usize = 1 << 25;
+
+/// A part of a pipeline's elements that can go to another thread: a spliterator over the
+/// source's, wrapped by each stage after it.
+trait Part<T>: Send {
+    /// Passes to `action` the elements the source's next element turns into, returning
+    /// whether there was one.
+    fn advance(&mut self, action: &mut dyn FnMut(T)) -> bool;
+
+    fn for_each_remaining(&mut self, action: &mut dyn FnMut(T));
+
+    fn try_split(&mut self) -> Option<Box<dyn Part<T>>>;
+
+    fn estimate_size(&self) -> i64;
+
+    fn characteristics(&self) -> i32;
+}
+
+/// A part of the source's elements, covered by a spliterator.
+struct Source<S>(S);
+
+impl<T, S: Spliterator<T> + Send + 'static> Part<T> for Source<S> {
+    fn advance(&mut self, action: &mut dyn FnMut(T)) -> bool {
+        self.0.try_advance(action)
+    }
+
+    fn for_each_remaining(&mut self, action: &mut dyn FnMut(T)) {
+        self.0.for_each_remaining(action)
+    }
+
+    fn try_split(&mut self) -> Option<Box<dyn Part<T>>> {
+        let part = self.0.try_split()?;
+        Some(Box::new(Source(part)))
+    }
+
+    fn estimate_size(&self) -> i64 {
+        self.0.estimate_size()
+    }
+
+    fn characteristics(&self) -> i32 {
+        self.0.characteristics()
+    }
+}
+
+/// What a stage does with each element: it passes the elements it turns into to the sink.
+type Stage<T, U> = Arc<dyn Fn(T, &mut dyn FnMut(U)) + Send + Sync>;
+
+/// A part of the elements of a stage, which clears the characteristics it doesn't keep.
+struct Staged<T, U> {
+    part: Box<dyn Part<T>>,
+    stage: Stage<T, U>,
+    cleared: i32,
+}
+
+impl<T: 'static, U: 'static> Part<U> for Staged<T, U> {
+    fn advance(&mut self, action: &mut dyn FnMut(U)) -> bool {
+        let stage = &self.stage;
+        self.part.advance(&mut |t| stage(t, action))
+    }
+
+    fn for_each_remaining(&mut self, action: &mut dyn FnMut(U)) {
+        let stage = &self.stage;
+        self.part.for_each_remaining(&mut |t| stage(t, action))
+    }
+
+    fn try_split(&mut self) -> Option<Box<dyn Part<U>>> {
+        let part = self.part.try_split()?;
+        Some(Box::new(Staged {
+            part,
+            stage: Arc::clone(&self.stage),
+            cleared: self.cleared,
+        }))
+    }
+
+    fn estimate_size(&self) -> i64 {
+        self.part.estimate_size()
+    }
+
+    fn characteristics(&self) -> i32 {
+        self.part.characteristics() & !self.cleared
+    }
+}
+
+/// The parts of a pipeline's elements, in encounter order. It runs in the thread that made
+/// the stream; only the parts go to the pool.
+type Parts<'a, T> = Box<dyn FnMut() -> Option<Box<dyn Part<T>>> + 'a>;
+
+type Leaf<T, R> = Arc<dyn Fn(&mut dyn Part<T>) -> R + Send + Sync>;
+type Combine<R> = Arc<dyn Fn(R, R) -> R + Send + Sync>;
+
+/// A terminal operation over a part: it splits off prefixes for other threads to work on
+/// while the part is larger than `threshold`, computes `leaf` of what is left, and
+/// combines the results of adjacent parts, the earlier first.
+struct Evaluation<T, R> {
+    part: Box<dyn Part<T>>,
+    threshold: i64,
+    leaf: Leaf<T, R>,
+    combine: Combine<R>,
+}
+
+impl<T: 'static, R: Send + 'static> RecursiveTask for Evaluation<T, R> {
+    type Output = R;
+
+    fn compute(mut self) -> R {
+        if self.part.estimate_size() > self.threshold {
+            if let Some(prefix) = self.part.try_split() {
+                let prefix = Evaluation {
+                    part: prefix,
+                    threshold: self.threshold,
+                    leaf: Arc::clone(&self.leaf),
+                    combine: Arc::clone(&self.combine),
+                }
+                .fork();
+                let combine = Arc::clone(&self.combine);
+                let rest = self.compute();
+                return combine(prefix.join(), rest);
+            }
+        }
+        (self.leaf)(&mut *self.part)
+    }
+}
+
+/// Evaluates the part in the common pool, or the pool of the current thread, splitting it
+/// into about four parts for each of the pool's threads like the JDK does.
+fn evaluate<T: 'static, R: Send + 'static>(
+    part: Box<dyn Part<T>>,
+    leaf: &Leaf<T, R>,
+    combine: &Combine<R>,
+) -> R {
+    let leaf_target = (ForkJoinPool::get_common_pool_parallelism() << 2) as i64;
+    Evaluation {
+        threshold: (part.estimate_size() / leaf_target).max(1),
+        part,
+        leaf: Arc::clone(leaf),
+        combine: Arc::clone(combine),
+    }
+    .invoke()
+}
+
+fn array_part<T: Send + 'static>(elements: Vec<T>) -> Box<dyn Part<T>> {
+    Box::new(Source(ArraySpliterator::new(
+        elements,
+        spliterator::ORDERED,
+    )))
+}
+
+/// A `Stream` whose operations run in the threads of a `ForkJoinPool`: the common pool, or
+/// the pool of the thread the terminal operation is called from.
+///
+/// The source's spliterator is split into parts that the pool's threads go through, so the
+/// functions passed to the operations must be `Send` and `Sync`, and the elements `Send`.
+/// `Stream::parallel` and `Collection::parallel_stream` make one, and `sequential` turns it
+/// back into a `Stream`.
+///
+/// Like Java's, `for_each` passes the elements to its action in whatever order the threads
+/// get to them, but `for_each_ordered`, `find_first`, `collect` and the other operations
+/// keep the encounter order. `collect` runs the collector in the calling thread, over the
+/// elements the pool has computed. Stateful operations such as `sorted` and `limit` wait
+/// for the elements before them, a batch at a time.
+pub struct ParallelStream<'a, T> {
+    parts: Parts<'a, T>,
+    close_actions: CloseActions<'a>,
+}
+
+impl<'a, T: Send + 'static> ParallelStream<'a, T> {
+    /// A stream of the elements of the spliterator, which its `try_split` splits among the
+    /// pool's threads, like `StreamSupport.stream(spliterator, true)`.
+    pub fn from_spliterator<S: Spliterator<T> + Send + 'static>(spliterator: S) -> Self {
+        let mut part: Option<Box<dyn Part<T>>> = Some(Box::new(Source(spliterator)));
+        Self::from_parts(Box::new(move || part.take()), CloseActions::default())
+    }
+
+    /// A stream of the elements of a sequential stream, taken from it in batches of 1024,
+    /// 2048, 3072 elements and so on, each of which is split among the pool's threads.
+    pub(super) fn from_stream(stream: Stream<'a, T>) -> Self {
+        let mut source = stream.link();
+        let mut batch = 0;
+        Self::from_parts(
+            Box::new(move || {
+                batch = (batch + BATCH_UNIT).min(MAX_BATCH);
+                let elements: Vec<T> = source.by_ref().take(batch).collect();
+                (!elements.is_empty()).then(|| array_part(elements))
+            }),
+            stream.close_actions,
+        )
+    }
+
+    fn from_parts(parts: Parts<'a, T>, close_actions: CloseActions<'a>) -> Self {
+        Self {
+            parts,
+            close_actions,
+        }
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        true
+    }
+
+    /// The same elements in a sequential stream, computed a batch at a time in the pool.
+    pub fn sequential(self) -> Stream<'a, T> {
+        let close_actions = self.close_actions.clone();
+        Stream::from_source(self.iterator(), close_actions)
+    }
+
+    /// Registers an action to run when the stream is closed, returning the same stream.
+    pub fn on_close<F: FnOnce() + 'a>(self, close_handler: F) -> Self {
+        self.close_actions
+            .borrow_mut()
+            .push(Box::new(close_handler));
+        self
+    }
+
+    /// Runs the actions registered by `on_close`, like `Stream::close`.
+    pub fn close(&self) {
+        run_close_actions(&self.close_actions);
+    }
+
+    /// The next stage, whose parts pass the elements of these through `stage`.
+    fn stage<U: Send + 'static>(self, cleared: i32, stage: Stage<T, U>) -> ParallelStream<'a, U> {
+        let mut parts = self.parts;
+        ParallelStream::from_parts(
+            Box::new(move || {
+                let part = parts()?;
+                Some(Box::new(Staged {
+                    part,
+                    stage: Arc::clone(&stage),
+                    cleared,
+                }) as Box<dyn Part<U>>)
+            }),
+            self.close_actions,
+        )
+    }
+
+    /// Each of the parts, computed in the pool into a list, in encounter order.
+    fn batches(self) -> impl Iterator<Item = Vec<T>> + 'a {
+        let leaf: Leaf<T, Vec<T>> = Arc::new(|part| {
+            let mut elements = Vec::new();
+            part.for_each_remaining(&mut |e| elements.push(e));
+            elements
+        });
+        let combine: Combine<Vec<T>> = Arc::new(|mut prefix, rest| {
+            prefix.extend(rest);
+            prefix
+        });
+        std::iter::from_fn(self.parts).map(move |part| evaluate(part, &leaf, &combine))
+    }
+
+    /// The next stage, whose elements `f` makes from each batch of these in turn, until it
+    /// breaks with the last of them.
+    fn rebatch<F>(self, mut f: F) -> Self
+    where
+        F: FnMut(Vec<T>) -> ControlFlow<Vec<T>, Vec<T>> + 'a,
+    {
+        let close_actions = self.close_actions.clone();
+        let mut batches = Some(self.batches());
+        Self::from_parts(
+            Box::new(move || loop {
+                let elements = match f(batches.as_mut()?.next()?) {
+                    ControlFlow::Continue(elements) => elements,
+                    ControlFlow::Break(elements) => {
+                        batches = None;
+                        elements
+                    }
+                };
+                if !elements.is_empty() {
+                    return Some(array_part(elements));
+                }
+            }),
+            close_actions,
+        )
+    }
+
+    /// Evaluates the whole pipeline, combining the results of the batches like those of
+    /// their parts.
+    fn reduce_parts<R: Send + 'static>(self, leaf: Leaf<T, R>, combine: Combine<R>) -> Option<R> {
+        std::iter::from_fn(self.parts)
+            .map(|part| evaluate(part, &leaf, &combine))
+            .reduce(|prefix, rest| combine(prefix, rest))
+    }
+
+    /// The first element of the pipeline that `found` isn't `None` for, evaluating it a
+    /// batch at a time until there is one.
+    fn find<R: Send + 'static, F>(self, found: F) -> Option<R>
+    where
+        F: Fn(T) -> Option<R> + Send + Sync + 'static,
+    {
+        let leaf: Leaf<T, Option<R>> = Arc::new(move |part| {
+            let mut result = None;
+            while result.is_none()
+                && part.advance(&mut |e| {
+                    if result.is_none() {
+                        result = found(e);
+                    }
+                })
+            {}
+            result
+        });
+        let combine: Combine<Option<R>> = Arc::new(|prefix, rest| prefix.or(rest));
+        std::iter::from_fn(self.parts).find_map(|part| evaluate(part, &leaf, &combine))
+    }
+
+    pub fn filter<P: Fn(&T) -> bool + Send + Sync + 'static>(self, predicate: P) -> Self {
+        self.stage(
+            spliterator::SIZED | spliterator::SUBSIZED,
+            Arc::new(move |e, sink| {
+                if predicate(&e) {
+                    sink(e)
+                }
+            }),
+        )
+    }
+
+    pub fn map<U: Send + 'static, F: Fn(T) -> U + Send + Sync + 'static>(
+        self,
+        mapper: F,
+    ) -> ParallelStream<'a, U> {
+        self.stage(
+            spliterator::SORTED | spliterator::DISTINCT,
+            Arc::new(move |e, sink| sink(mapper(e))),
+        )
+    }
+
+    /// Replaces each element by the elements of the stream `mapper` maps it to, closing
+    /// that stream once they have been gone through. The streams are made and gone through
+    /// in the pool's threads.
+    pub fn flat_map<U: Send + 'static, F: Fn(T) -> Stream<'static, U> + Send + Sync + 'static>(
+        self,
+        mapper: F,
+    ) -> ParallelStream<'a, U> {
+        self.stage(
+            spliterator::SORTED
+                | spliterator::DISTINCT
+                | spliterator::SIZED
+                | spliterator::SUBSIZED,
+            Arc::new(move |e, sink| {
+                let stream = mapper(e);
+                stream.link().for_each(&mut *sink);
+                run_close_actions(&stream.close_actions);
+            }),
+        )
+    }
+
+    /// Replaces each element by the elements `mapper` passes to the consumer it is given.
+    pub fn map_multi<U: Send + 'static, F: Fn(T, &mut dyn FnMut(U)) + Send + Sync + 'static>(
+        self,
+        mapper: F,
+    ) -> ParallelStream<'a, U> {
+        self.stage(
+            spliterator::SORTED
+                | spliterator::DISTINCT
+                | spliterator::SIZED
+                | spliterator::SUBSIZED,
+            Arc::new(mapper),
+        )
+    }
+
+    /// Passes each element to `action` as it goes through, in whichever thread it is in.
+    pub fn peek<F: Fn(&T) + Send + Sync + 'static>(self, action: F) -> Self {
+        self.stage(
+            0,
+            Arc::new(move |e, sink| {
+                action(&e);
+                sink(e)
+            }),
+        )
+    }
+
+    /// Drops the elements equal to one before them in encounter order, by `equals` and
+    /// `hashCode`.
+    pub fn distinct(self) -> Self
+    where
+        T: Clone + HashCode + PartialEq,
+    {
+        let mut seen = HashSet::new();
+        self.rebatch(move |elements| {
+            ControlFlow::Continue(
+                elements
+                    .into_iter()
+                    .filter(|e| seen.add(e.clone()))
+                    .collect(),
+            )
+        })
+    }
+
+    /// Sorts the elements by their natural order, stably.
+    pub fn sorted(self) -> Self
+    where
+        T: Clone + Ord,
+    {
+        self.sorted_with(natural_order())
+    }
+
+    /// Sorts the elements by the comparator, stably, once the pool has computed them all.
+    pub fn sorted_with<C: Comparator<T> + 'a>(self, comparator: C) -> Self
+    where
+        T: Clone,
+    {
+        let close_actions = self.close_actions.clone();
+        let mut unsorted = Some(self);
+        Self::from_parts(
+            Box::new(move || {
+                let mut elements: Vec<T> = unsorted.take()?.batches().flatten().collect();
+                tim_sort::sort(&mut elements, &|a, b| comparator.compare(a, b));
+                Some(array_part(elements))
+            }),
+            close_actions,
+        )
+    }
+
+    /// Only the first `max_size` elements in encounter order. Throws
+    /// `IllegalArgumentException` if it is negative.
+    pub fn limit(self, max_size: i64) -> Self {
+        if max_size < 0 {
+            IllegalArgumentException::new(max_size.to_string(), None).throw()
+        }
+        let mut remaining = max_size as usize;
+        self.rebatch(move |mut elements| {
+            if elements.len() < remaining {
+                remaining -= elements.len();
+                ControlFlow::Continue(elements)
+            } else {
+                elements.truncate(remaining);
+                ControlFlow::Break(elements)
+            }
+        })
+    }
+
+    /// All but the first `n` elements in encounter order. Throws
+    /// `IllegalArgumentException` if it is negative.
+    pub fn skip(self, n: i64) -> Self {
+        if n < 0 {
+            IllegalArgumentException::new(n.to_string(), None).throw()
+        }
+        let mut remaining = n as usize;
+        self.rebatch(move |mut elements| {
+            let skipped = remaining.min(elements.len());
+            remaining -= skipped;
+            elements.drain(..skipped);
+            ControlFlow::Continue(elements)
+        })
+    }
+
+    /// The elements before the first in encounter order that doesn't match `predicate`.
+    pub fn take_while<P: FnMut(&T) -> bool + 'a>(self, mut predicate: P) -> Self {
+        self.rebatch(
+            move |mut elements| match elements.iter().position(|e| !predicate(e)) {
+                Some(end) => {
+                    elements.truncate(end);
+                    ControlFlow::Break(elements)
+                }
+                None => ControlFlow::Continue(elements),
+            },
+        )
+    }
+
+    /// The elements from the first in encounter order that doesn't match `predicate`.
+    pub fn drop_while<P: FnMut(&T) -> bool + 'a>(self, mut predicate: P) -> Self {
+        let mut dropping = true;
+        self.rebatch(move |mut elements| {
+            if dropping {
+                let start = elements
+                    .iter()
+                    .position(|e| !predicate(e))
+                    .unwrap_or(elements.len());
+                dropping = start == elements.len();
+                elements.drain(..start);
+            }
+            ControlFlow::Continue(elements)
+        })
+    }
+
+    /// Passes each element to `action` in whichever thread it is computed in, in no
+    /// particular order.
+    pub fn for_each<F: Fn(T) + Send + Sync + 'static>(self, action: F) {
+        self.reduce_parts(
+            Arc::new(move |part| part.for_each_remaining(&mut |e| action(e))),
+            Arc::new(|(), ()| ()),
+        );
+    }
+
+    /// Passes each element to `action` in encounter order, in the calling thread.
+    pub fn for_each_ordered<F: FnMut(T)>(self, action: F) {
+        self.iterator().for_each(action)
+    }
+
+    /// Folds the elements of each part into `identity` by `accumulator`, and then the
+    /// results of the parts, in encounter order. Like Java, the result is only the same as
+    /// a sequential stream's if `identity` is an identity of the accumulator and the
+    /// accumulator is associative.
+    pub fn reduce<F: Fn(T, T) -> T + Send + Sync + 'static>(self, identity: T, accumulator: F) -> T
+    where
+        T: Clone,
+    {
+        let accumulator = Arc::new(accumulator);
+        let fold = Arc::clone(&accumulator);
+        let seed = Mutex::new(identity.clone());
+        self.reduce_parts(
+            Arc::new(move |part| {
+                let mut result = Some(seed.lock().unwrap().clone());
+                part.for_each_remaining(&mut |e| result = Some(fold(result.take().unwrap(), e)));
+                result.unwrap()
+            }),
+            Arc::new(move |prefix, rest| accumulator(prefix, rest)),
+        )
+        .unwrap_or(identity)
+    }
+
+    /// Folds the elements by `accumulator`, in encounter order, if there are any.
+    pub fn reduce_optional<F: Fn(T, T) -> T + Send + Sync + 'static>(
+        self,
+        accumulator: F,
+    ) -> Optional<T> {
+        let accumulator = Arc::new(accumulator);
+        let fold = Arc::clone(&accumulator);
+        self.reduce_parts(
+            Arc::new(move |part| {
+                let mut result = None;
+                part.for_each_remaining(&mut |e| {
+                    result = Some(match result.take() {
+                        Some(result) => fold(result, e),
+                        None => e,
+                    })
+                });
+                result
+            }),
+            Arc::new(move |prefix, rest| match (prefix, rest) {
+                (Some(prefix), Some(rest)) => Some(accumulator(prefix, rest)),
+                (prefix, rest) => prefix.or(rest),
+            }),
+        )
+        .flatten()
+        .into()
+    }
+
+    /// Gathers the elements into the collector's result in the calling thread, in
+    /// encounter order, as the pool computes them.
+    pub fn collect<A, R>(self, collector: Collector<T, A, R>) -> R {
+        collector.collect(self.iterator())
+    }
+
+    /// The elements in a list that can't be changed, and which may hold `None`.
+    pub fn to_list(self) -> ImmutableList<T>
+    where
+        T: Clone,
+    {
+        ImmutableList::allowing_nulls(self.to_array())
+    }
+
+    pub fn to_array(self) -> Vec<T> {
+        self.iterator().collect()
+    }
+
+    /// The least element by the comparator, the first in encounter order of those that
+    /// are least.
+    pub fn min<C: Comparator<T> + Send + Sync + 'static>(self, comparator: C) -> Optional<T> {
+        self.reduce_optional(move |a, b| {
+            if comparator.compare(&a, &b) <= 0 {
+                a
+            } else {
+                b
+            }
+        })
+    }
+
+    /// The greatest element by the comparator, the first in encounter order of those that
+    /// are greatest.
+    pub fn max<C: Comparator<T> + Send + Sync + 'static>(self, comparator: C) -> Optional<T> {
+        self.reduce_optional(move |a, b| {
+            if comparator.compare(&a, &b) >= 0 {
+                a
+            } else {
+                b
+            }
+        })
+    }
+
+    /// The number of elements. Like Java's, it takes the size of each part that is `SIZED`
+    /// without going through its elements, so `peek`'s action isn't run for them.
+    pub fn count(self) -> i64 {
+        self.reduce_parts(
+            Arc::new(|part| {
+                if part.characteristics() & spliterator::SIZED != 0 {
+                    return part.estimate_size();
+                }
+                let mut count = 0;
+                part.for_each_remaining(&mut |_| count += 1);
+                count
+            }),
+            Arc::new(|prefix, rest| prefix + rest),
+        )
+        .unwrap_or(0)
+    }
+
+    pub fn any_match<P: Fn(&T) -> bool + Send + Sync + 'static>(self, predicate: P) -> bool {
+        self.find(move |e| predicate(&e).then_some(())).is_some()
+    }
+
+    pub fn all_match<P: Fn(&T) -> bool + Send + Sync + 'static>(self, predicate: P) -> bool {
+        !self.any_match(move |e| !predicate(e))
+    }
+
+    pub fn none_match<P: Fn(&T) -> bool + Send + Sync + 'static>(self, predicate: P) -> bool {
+        !self.any_match(predicate)
+    }
+
+    /// The first element in encounter order, if there is one.
+    pub fn find_first(self) -> Optional<T> {
+        self.find(Some).into()
+    }
+
+    /// Any element. This returns the first, but like Java's that isn't guaranteed.
+    pub fn find_any(self) -> Optional<T> {
+        self.find_first()
+    }
+
+    /// The elements in encounter order, computed a batch at a time in the pool.
+    pub fn iterator(self) -> Box<dyn Iterator<Item = T> + 'a> {
+        Box::new(self.batches().flatten())
+    }
+}
+
+impl<'a, T: Send + 'static> Stream<'a, T> {
+    /// The same elements in a `ParallelStream`. Like the JDK's spliterator of a stream
+    /// whose size is unknown, it takes them in ever larger batches for the pool to split
+    /// among its threads.
+    pub fn parallel(self) -> ParallelStream<'a, T> {
+        ParallelStream::from_stream(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::thread;
+
+    use super::*;
+    use crate::util::concurrent::RecursiveAction;
+    use crate::util::stream::collectors;
+    use crate::util::{reverse_order, ArrayList, Collection};
+
+    fn numbers(n: i32) -> ParallelStream<'static, i32> {
+        ParallelStream::from_spliterator(ArraySpliterator::new(
+            (1..=n).collect(),
+            spliterator::ORDERED,
+        ))
+    }
+
+    #[test]
+    fn keeps_encounter_order() {
+        assert_eq!(
+            numbers(10_000)
+                .map(|i| i * 2)
+                .filter(|i| i % 3 == 0)
+                .to_array(),
+            (1..=10_000)
+                .map(|i| i * 2)
+                .filter(|i| i % 3 == 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            numbers(10_000).filter(|i| i % 997 == 0).find_first(),
+            Optional::of(997)
+        );
+        assert_eq!(
+            numbers(100)
+                .map(|i| i.to_string())
+                .collect(collectors::joining_with(","))
+                .to_string(),
+            (1..=100)
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        let mut seen = Vec::new();
+        numbers(5_000).for_each_ordered(|i| seen.push(i));
+        assert_eq!(seen, (1..=5_000).collect::<Vec<_>>());
+
+        let mut list = ArrayList::new();
+        for word in ["pear", "fig", "kiwi", "apple", "fig", "plum"] {
+            list.add(word.to_string());
+        }
+        assert_eq!(
+            list.parallel_stream()
+                .distinct()
+                .sorted_with(reverse_order())
+                .to_list()
+                .to_string(),
+            "[plum, pear, kiwi, fig, apple]"
+        );
+    }
+
+    #[test]
+    fn reduces_and_matches() {
+        assert_eq!(
+            numbers(100_000).map(i64::from).reduce(0, |a, b| a + b),
+            5_000_050_000
+        );
+        assert_eq!(numbers(0).reduce_optional(|a, b| a + b), Optional::empty());
+        assert_eq!(numbers(1_000).count(), 1_000);
+        assert_eq!(numbers(1_000).filter(|i| i % 3 == 0).count(), 333);
+        assert_eq!(
+            Stream::of(0..5_000)
+                .parallel()
+                .peek(|_| unreachable!())
+                .count(),
+            5_000
+        );
+        assert_eq!(
+            numbers(1_000).max(|a: &i32, b: &i32| (a % 10).cmp(&(b % 10)) as i32),
+            Optional::of(9)
+        );
+        assert!(numbers(1_000).any_match(|&i| i == 1_000));
+        assert!(numbers(1_000).all_match(|&i| i > 0));
+        assert!(numbers(1_000).none_match(|&i| i > 1_000));
+
+        let sum = Arc::new(AtomicI64::new(0));
+        let total = Arc::clone(&sum);
+        numbers(1_000).for_each(move |i| {
+            total.fetch_add(i64::from(i), Ordering::Relaxed);
+        });
+        assert_eq!(sum.load(Ordering::Relaxed), 500_500);
+    }
+
+    #[test]
+    fn runs_stateful_stages_in_order() {
+        assert_eq!(
+            Stream::iterate(1, |i| i + 1)
+                .parallel()
+                .filter(|i| i % 2 == 1)
+                .skip(2_000)
+                .limit(3)
+                .to_array(),
+            [4_001, 4_003, 4_005]
+        );
+        assert_eq!(
+            Stream::of(0..5_000)
+                .parallel()
+                .take_while(|&i| i < 3_000)
+                .drop_while(|&i| i < 2_998)
+                .to_array(),
+            [2_998, 2_999]
+        );
+        assert_eq!(
+            numbers(3)
+                .flat_map(|i| Stream::of(0..i))
+                .map_multi(|i, sink| sink(i * 10))
+                .sequential()
+                .to_array(),
+            [0, 0, 10, 0, 10, 20]
+        );
+        assert_eq!(
+            numbers(5).sorted_with(reverse_order()).limit(2).to_array(),
+            [5, 4]
+        );
+    }
+
+    #[test]
+    fn runs_in_the_pool() {
+        let threads = Arc::new(Mutex::new(BTreeSet::new()));
+        let seen = Arc::clone(&threads);
+        numbers(10_000)
+            .peek(move |_| {
+                seen.lock()
+                    .unwrap()
+                    .insert(thread::current().name().map(str::to_string));
+            })
+            .to_array();
+        let threads = threads.lock().unwrap();
+        let caller = thread::current().name().map(str::to_string);
+        assert!(threads.contains(&caller));
+        assert!(threads.iter().all(|name| *name == caller
+            || name
+                .as_ref()
+                .unwrap()
+                .starts_with("ForkJoinPool.commonPool-worker-")));
+
+        struct Check;
+
+        impl RecursiveAction for Check {
+            fn compute(self) {
+                let name = thread::current().name().unwrap().to_string();
+                assert!(name.starts_with("ForkJoinPool-"), "{name}");
+                assert_eq!(numbers(1_000).to_array().len(), 1_000);
+            }
+        }
+
+        ForkJoinPool::with_parallelism(2).invoke_action(Check);
+    }
+
+    #[test]
+    fn rethrows_and_closes() {
+        let error = std::panic::catch_unwind(|| {
+            numbers(10_000)
+                .map(|i| {
+                    if i == 7_777 {
+                        IllegalArgumentException::new("7777".to_string(), None).throw()
+                    }
+                    i
+                })
+                .to_array()
+        })
+        .unwrap_err();
+        assert_eq!(*error.downcast::<String>().unwrap(), "7777");
+
+        let closed = Arc::new(AtomicI64::new(0));
+        let counter = Arc::clone(&closed);
+        let stream = Stream::of(0..3)
+            .on_close(move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+            .parallel();
+        assert!(stream.is_parallel());
+        let stream = stream.sequential();
+        assert!(!stream.is_parallel());
+        stream.close();
+        assert_eq!(closed.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Display;
+
+/// `java.util.StringJoiner`: builds a delimited sequence of strings, wrapped in a prefix
+/// and suffix, with a replaceable value for when nothing has been added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StringJoiner {
+    prefix: String,
+    delimiter: String,
+    suffix: String,
+    /// The elements joined so far, without the prefix and suffix; `None` until the first
+    /// `add`, so that an added empty string still counts.
+    value: Option<String>,
+    empty_value: Option<String>,
+}
+
+impl StringJoiner {
+    pub fn new(delimiter: &str) -> Self {
+        Self::with_prefix_suffix(delimiter, "", "")
+    }
+
+    pub fn with_prefix_suffix(delimiter: &str, prefix: &str, suffix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            delimiter: delimiter.to_string(),
+            suffix: suffix.to_string(),
+            value: None,
+            empty_value: None,
+        }
+    }
+
+    /// Sets what `to_string` gives before anything is added, instead of the prefix and
+    /// suffix alone.
+    pub fn set_empty_value(&mut self, empty_value: &str) -> &mut Self {
+        self.empty_value = Some(empty_value.to_string());
+        self
+    }
+
+    pub fn add(&mut self, new_element: &str) -> &mut Self {
+        match &mut self.value {
+            Some(value) => {
+                value.push_str(&self.delimiter);
+                value.push_str(new_element);
+            }
+            None => self.value = Some(new_element.to_string()),
+        }
+        self
+    }
+
+    /// Adds the contents of `other`, without its prefix and suffix, as a single element.
+    /// Nothing is added if `other` is empty.
+    pub fn merge(&mut self, other: &StringJoiner) -> &mut Self {
+        if let Some(other_value) = &other.value {
+            let other_value = other_value.clone();
+            self.add(&other_value);
+        }
+        self
+    }
+
+    /// The length of `to_string`, in the same units as `JavaString::length`.
+    pub fn length(&self) -> usize {
+        match (&self.value, &self.empty_value) {
+            (None, Some(empty_value)) => empty_value.len(),
+            (value, _) => {
+                self.prefix.len() + value.as_ref().map_or(0, String::len) + self.suffix.len()
+            }
+        }
+    }
+}
+
+impl Display for StringJoiner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.value, &self.empty_value) {
+            (None, Some(empty_value)) => write!(f, "{empty_value}"),
+            (value, _) => write!(
+                f,
+                "{}{}{}",
+                self.prefix,
+                value.as_deref().unwrap_or(""),
+                self.suffix
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StringJoiner;
+
+    #[test]
+    fn joins_like_jdk() {
+        let mut joiner = StringJoiner::with_prefix_suffix(", ", "[", "]");
+        assert_eq!((joiner.to_string(), joiner.length()), ("[]".to_string(), 2));
+        joiner.set_empty_value("EMPTY");
+        assert_eq!(
+            (joiner.to_string(), joiner.length()),
+            ("EMPTY".to_string(), 5)
+        );
+        joiner.add("a").add("b");
+        assert_eq!(
+            (joiner.to_string(), joiner.length()),
+            ("[a, b]".to_string(), 6)
+        );
+
+        let mut other = StringJoiner::with_prefix_suffix("-", "{", "}");
+        joiner.merge(&other);
+        other.add("x").add("y");
+        joiner.merge(&other);
+        assert_eq!(joiner.to_string(), "[a, b, x-y]");
+
+        let mut empty_element = StringJoiner::new("/");
+        empty_element.set_empty_value("EMPTY").add("");
+        assert_eq!(empty_element.to_string(), "");
+    }
+}
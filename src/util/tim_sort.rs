@@ -0,0 +1,540 @@
+//! `java.util.TimSort`, the stable merge sort behind `Arrays.sort` of objects and
+//! `List.sort`, ported so that it calls the comparator on the same pairs as Java's and
+//! detects the same broken comparators.
+//!
+//! Like Java's, it copies elements rather than moving them, so a comparator that throws
+//! leaves the slice in the same state as Java leaves the array.
+
+use crate::errors::{IllegalArgumentException, Throwable};
+
+/// Below this length the slice is sorted by a binary insertion sort, without merges.
+const MIN_MERGE: usize = 32;
+
+/// How many times in a row one run has to win before the merge starts galloping.
+const MIN_GALLOP: usize = 7;
+
+fn contract_violation() -> ! {
+    IllegalArgumentException::new(
+        "Comparison method violates its general contract!".to_string(),
+        None,
+    )
+    .throw()
+}
+
+/// Sorts the slice stably, throwing `IllegalArgumentException` if it finds that the
+/// comparator isn't a total order.
+pub(crate) fn sort<T: Clone>(a: &mut [T], c: &impl Fn(&T, &T) -> i32) {
+    let hi = a.len();
+    let mut n_remaining = hi;
+    if n_remaining < 2 {
+        return;
+    }
+    if n_remaining < MIN_MERGE {
+        let init_run_len = count_run_and_make_ascending(a, 0, hi, c);
+        binary_sort(a, 0, hi, init_run_len, c);
+        return;
+    }
+    let mut ts = TimSort {
+        a,
+        c,
+        min_gallop: MIN_GALLOP,
+        tmp: Vec::new(),
+        runs: Vec::new(),
+    };
+    let min_run = min_run_length(n_remaining);
+    let mut lo = 0;
+    loop {
+        let mut run_len = count_run_and_make_ascending(ts.a, lo, hi, c);
+        if run_len < min_run {
+            let force = n_remaining.min(min_run);
+            binary_sort(ts.a, lo, lo + force, lo + run_len, c);
+            run_len = force;
+        }
+        ts.runs.push((lo, run_len));
+        ts.merge_collapse();
+        lo += run_len;
+        n_remaining -= run_len;
+        if n_remaining == 0 {
+            break;
+        }
+    }
+    ts.merge_force_collapse();
+}
+
+/// Sorts `a[lo..hi]`, of which `a[lo..start]` is already sorted, by inserting each
+/// element where a binary search puts it, after the elements equal to it.
+fn binary_sort<T: Clone>(
+    a: &mut [T],
+    lo: usize,
+    hi: usize,
+    start: usize,
+    c: &impl Fn(&T, &T) -> i32,
+) {
+    let mut start = if start == lo { start + 1 } else { start };
+    while start < hi {
+        let pivot = a[start].clone();
+        let mut left = lo;
+        let mut right = start;
+        while left < right {
+            let mid = (left + right) >> 1;
+            if c(&pivot, &a[mid]) < 0 {
+                right = mid;
+            } else {
+                left = mid + 1;
+            }
+        }
+        for i in (left..start).rev() {
+            a[i + 1] = a[i].clone();
+        }
+        a[left] = pivot;
+        start += 1;
+    }
+}
+
+/// The length of the run starting at `lo`, which is reversed if it is strictly
+/// descending.
+fn count_run_and_make_ascending<T>(
+    a: &mut [T],
+    lo: usize,
+    hi: usize,
+    c: &impl Fn(&T, &T) -> i32,
+) -> usize {
+    let mut run_hi = lo + 1;
+    if run_hi == hi {
+        return 1;
+    }
+    run_hi += 1;
+    if c(&a[run_hi - 1], &a[lo]) < 0 {
+        while run_hi < hi && c(&a[run_hi], &a[run_hi - 1]) < 0 {
+            run_hi += 1;
+        }
+        a[lo..run_hi].reverse();
+    } else {
+        while run_hi < hi && c(&a[run_hi], &a[run_hi - 1]) >= 0 {
+            run_hi += 1;
+        }
+    }
+    run_hi - lo
+}
+
+/// The shortest run to make, between 16 and 32, such that the number of runs is a power
+/// of two or a little less.
+fn min_run_length(mut n: usize) -> usize {
+    let mut r = 0;
+    while n >= MIN_MERGE {
+        r |= n & 1;
+        n >>= 1;
+    }
+    n + r
+}
+
+/// Where `key` goes in the sorted `a[base..base + len]`, before the elements equal to it,
+/// galloping from `hint`.
+fn gallop_left<T>(
+    key: &T,
+    a: &[T],
+    base: usize,
+    len: usize,
+    hint: usize,
+    c: &impl Fn(&T, &T) -> i32,
+) -> usize {
+    let mut last_ofs;
+    let mut ofs;
+    if c(key, &a[base + hint]) > 0 {
+        let max_ofs = len - hint;
+        last_ofs = 0;
+        ofs = 1;
+        while ofs < max_ofs && c(key, &a[base + hint + ofs]) > 0 {
+            last_ofs = ofs;
+            ofs = (ofs << 1) + 1;
+        }
+        ofs = ofs.min(max_ofs);
+        last_ofs += hint + 1;
+        ofs += hint;
+    } else {
+        let max_ofs = hint + 1;
+        last_ofs = 0;
+        ofs = 1;
+        while ofs < max_ofs && c(key, &a[base + hint - ofs]) <= 0 {
+            last_ofs = ofs;
+            ofs = (ofs << 1) + 1;
+        }
+        ofs = ofs.min(max_ofs);
+        // Java's `lastOfs = hint - ofs` can be -1, which the `lastOfs++` below undoes.
+        (last_ofs, ofs) = (hint + 1 - ofs, hint - last_ofs);
+    }
+    while last_ofs < ofs {
+        let m = last_ofs + ((ofs - last_ofs) >> 1);
+        if c(key, &a[base + m]) > 0 {
+            last_ofs = m + 1;
+        } else {
+            ofs = m;
+        }
+    }
+    ofs
+}
+
+/// Where `key` goes in the sorted `a[base..base + len]`, after the elements equal to it,
+/// galloping from `hint`.
+fn gallop_right<T>(
+    key: &T,
+    a: &[T],
+    base: usize,
+    len: usize,
+    hint: usize,
+    c: &impl Fn(&T, &T) -> i32,
+) -> usize {
+    let mut last_ofs;
+    let mut ofs;
+    if c(key, &a[base + hint]) < 0 {
+        let max_ofs = hint + 1;
+        last_ofs = 0;
+        ofs = 1;
+        while ofs < max_ofs && c(key, &a[base + hint - ofs]) < 0 {
+            last_ofs = ofs;
+            ofs = (ofs << 1) + 1;
+        }
+        ofs = ofs.min(max_ofs);
+        (last_ofs, ofs) = (hint + 1 - ofs, hint - last_ofs);
+    } else {
+        let max_ofs = len - hint;
+        last_ofs = 0;
+        ofs = 1;
+        while ofs < max_ofs && c(key, &a[base + hint + ofs]) >= 0 {
+            last_ofs = ofs;
+            ofs = (ofs << 1) + 1;
+        }
+        ofs = ofs.min(max_ofs);
+        last_ofs += hint + 1;
+        ofs += hint;
+    }
+    while last_ofs < ofs {
+        let m = last_ofs + ((ofs - last_ofs) >> 1);
+        if c(key, &a[base + m]) < 0 {
+            ofs = m;
+        } else {
+            last_ofs = m + 1;
+        }
+    }
+    ofs
+}
+
+/// Copies `src[from..from + len]` to `dest[to..]`, like `System.arraycopy` between two
+/// arrays.
+fn copy<T: Clone>(src: &[T], from: usize, dest: &mut [T], to: usize, len: usize) {
+    dest[to..to + len].clone_from_slice(&src[from..from + len]);
+}
+
+/// Copies `a[from..from + len]` to `a[to..]` within the slice, like `System.arraycopy`
+/// when the ranges overlap.
+fn copy_within<T: Clone>(a: &mut [T], from: usize, to: usize, len: usize) {
+    if to < from {
+        for i in 0..len {
+            a[to + i] = a[from + i].clone();
+        }
+    } else {
+        for i in (0..len).rev() {
+            a[to + i] = a[from + i].clone();
+        }
+    }
+}
+
+struct TimSort<'a, T, F> {
+    a: &'a mut [T],
+    c: &'a F,
+    min_gallop: usize,
+    tmp: Vec<T>,
+    /// The runs waiting to be merged, as their base and length.
+    runs: Vec<(usize, usize)>,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> i32> TimSort<'_, T, F> {
+    fn run_len(&self, i: usize) -> usize {
+        self.runs[i].1
+    }
+
+    /// Merges runs until each is longer than the next two together, and than the next.
+    fn merge_collapse(&mut self) {
+        while self.runs.len() > 1 {
+            let mut n = self.runs.len() - 2;
+            if (n > 0 && self.run_len(n - 1) <= self.run_len(n) + self.run_len(n + 1))
+                || (n > 1 && self.run_len(n - 2) <= self.run_len(n) + self.run_len(n - 1))
+            {
+                if self.run_len(n - 1) < self.run_len(n + 1) {
+                    n -= 1;
+                }
+            } else if self.run_len(n) > self.run_len(n + 1) {
+                break;
+            }
+            self.merge_at(n);
+        }
+    }
+
+    fn merge_force_collapse(&mut self) {
+        while self.runs.len() > 1 {
+            let mut n = self.runs.len() - 2;
+            if n > 0 && self.run_len(n - 1) < self.run_len(n + 1) {
+                n -= 1;
+            }
+            self.merge_at(n);
+        }
+    }
+
+    /// Merges the runs at `i` and `i + 1`.
+    fn merge_at(&mut self, i: usize) {
+        let (mut base1, mut len1) = self.runs[i];
+        let (base2, mut len2) = self.runs[i + 1];
+        self.runs[i] = (base1, len1 + len2);
+        self.runs.remove(i + 1);
+
+        let c = self.c;
+        // Where the first element of run 2 goes in run 1: the elements before it are in
+        // place already.
+        let k = gallop_right(&self.a[base2], self.a, base1, len1, 0, c);
+        base1 += k;
+        len1 -= k;
+        if len1 == 0 {
+            return;
+        }
+        // Where the last element of run 1 goes in run 2: the elements after it are in
+        // place already.
+        len2 = gallop_left(&self.a[base1 + len1 - 1], self.a, base2, len2, len2 - 1, c);
+        if len2 == 0 {
+            return;
+        }
+        if len1 <= len2 {
+            self.merge_lo(base1, len1, base2, len2);
+        } else {
+            self.merge_hi(base1, len1, base2, len2);
+        }
+    }
+
+    /// Merges two adjacent runs from the front, copying the first, shorter one out of the
+    /// way.
+    fn merge_lo(&mut self, base1: usize, mut len1: usize, base2: usize, mut len2: usize) {
+        let c = self.c;
+        self.tmp.clear();
+        self.tmp.extend_from_slice(&self.a[base1..base1 + len1]);
+        let (a, tmp) = (&mut *self.a, &self.tmp);
+        let mut cursor1 = 0;
+        let mut cursor2 = base2;
+        let mut dest = base1;
+
+        a[dest] = a[cursor2].clone();
+        dest += 1;
+        cursor2 += 1;
+        len2 -= 1;
+        if len2 == 0 {
+            copy(tmp, cursor1, a, dest, len1);
+            return;
+        }
+        if len1 == 1 {
+            copy_within(a, cursor2, dest, len2);
+            a[dest + len2] = tmp[cursor1].clone();
+            return;
+        }
+
+        let mut min_gallop = self.min_gallop;
+        'outer: loop {
+            let mut count1 = 0;
+            let mut count2 = 0;
+            loop {
+                if c(&a[cursor2], &tmp[cursor1]) < 0 {
+                    a[dest] = a[cursor2].clone();
+                    dest += 1;
+                    cursor2 += 1;
+                    count2 += 1;
+                    count1 = 0;
+                    len2 -= 1;
+                    if len2 == 0 {
+                        break 'outer;
+                    }
+                } else {
+                    a[dest] = tmp[cursor1].clone();
+                    dest += 1;
+                    cursor1 += 1;
+                    count1 += 1;
+                    count2 = 0;
+                    len1 -= 1;
+                    if len1 == 1 {
+                        break 'outer;
+                    }
+                }
+                if (count1 | count2) >= min_gallop {
+                    break;
+                }
+            }
+
+            loop {
+                count1 = gallop_right(&a[cursor2], tmp, cursor1, len1, 0, c);
+                if count1 != 0 {
+                    copy(tmp, cursor1, a, dest, count1);
+                    dest += count1;
+                    cursor1 += count1;
+                    len1 -= count1;
+                    if len1 <= 1 {
+                        break 'outer;
+                    }
+                }
+                a[dest] = a[cursor2].clone();
+                dest += 1;
+                cursor2 += 1;
+                len2 -= 1;
+                if len2 == 0 {
+                    break 'outer;
+                }
+
+                count2 = gallop_left(&tmp[cursor1], a, cursor2, len2, 0, c);
+                if count2 != 0 {
+                    copy_within(a, cursor2, dest, count2);
+                    dest += count2;
+                    cursor2 += count2;
+                    len2 -= count2;
+                    if len2 == 0 {
+                        break 'outer;
+                    }
+                }
+                a[dest] = tmp[cursor1].clone();
+                dest += 1;
+                cursor1 += 1;
+                len1 -= 1;
+                if len1 == 1 {
+                    break 'outer;
+                }
+                min_gallop = min_gallop.saturating_sub(1);
+                if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
+                    break;
+                }
+            }
+            // Java's `minGallop` can go negative in the loop, and is then reset to 0.
+            min_gallop += 2;
+        }
+        self.min_gallop = min_gallop.max(1);
+
+        if len1 == 1 {
+            copy_within(a, cursor2, dest, len2);
+            a[dest + len2] = tmp[cursor1].clone();
+        } else if len1 == 0 {
+            contract_violation();
+        } else {
+            copy(tmp, cursor1, a, dest, len1);
+        }
+    }
+
+    /// Merges two adjacent runs from the back, copying the second, shorter one out of the
+    /// way.
+    fn merge_hi(&mut self, base1: usize, mut len1: usize, base2: usize, mut len2: usize) {
+        let c = self.c;
+        self.tmp.clear();
+        self.tmp.extend_from_slice(&self.a[base2..base2 + len2]);
+        let (a, tmp) = (&mut *self.a, &self.tmp);
+        // The cursors are one past the elements they point at, as the first run can
+        // start at 0.
+        let mut cursor1 = base1 + len1;
+        let mut cursor2 = len2;
+        let mut dest = base2 + len2;
+
+        dest -= 1;
+        cursor1 -= 1;
+        a[dest] = a[cursor1].clone();
+        len1 -= 1;
+        if len1 == 0 {
+            copy(tmp, 0, a, dest - len2, len2);
+            return;
+        }
+        if len2 == 1 {
+            dest -= len1;
+            cursor1 -= len1;
+            copy_within(a, cursor1, dest, len1);
+            a[dest - 1] = tmp[cursor2 - 1].clone();
+            return;
+        }
+
+        let mut min_gallop = self.min_gallop;
+        'outer: loop {
+            let mut count1 = 0;
+            let mut count2 = 0;
+            loop {
+                if c(&tmp[cursor2 - 1], &a[cursor1 - 1]) < 0 {
+                    dest -= 1;
+                    cursor1 -= 1;
+                    a[dest] = a[cursor1].clone();
+                    count1 += 1;
+                    count2 = 0;
+                    len1 -= 1;
+                    if len1 == 0 {
+                        break 'outer;
+                    }
+                } else {
+                    dest -= 1;
+                    cursor2 -= 1;
+                    a[dest] = tmp[cursor2].clone();
+                    count2 += 1;
+                    count1 = 0;
+                    len2 -= 1;
+                    if len2 == 1 {
+                        break 'outer;
+                    }
+                }
+                if (count1 | count2) >= min_gallop {
+                    break;
+                }
+            }
+
+            loop {
+                count1 = len1 - gallop_right(&tmp[cursor2 - 1], a, base1, len1, len1 - 1, c);
+                if count1 != 0 {
+                    dest -= count1;
+                    cursor1 -= count1;
+                    len1 -= count1;
+                    copy_within(a, cursor1, dest, count1);
+                    if len1 == 0 {
+                        break 'outer;
+                    }
+                }
+                dest -= 1;
+                cursor2 -= 1;
+                a[dest] = tmp[cursor2].clone();
+                len2 -= 1;
+                if len2 == 1 {
+                    break 'outer;
+                }
+
+                count2 = len2 - gallop_left(&a[cursor1 - 1], tmp, 0, len2, len2 - 1, c);
+                if count2 != 0 {
+                    dest -= count2;
+                    cursor2 -= count2;
+                    len2 -= count2;
+                    copy(tmp, cursor2, a, dest, count2);
+                    if len2 <= 1 {
+                        break 'outer;
+                    }
+                }
+                dest -= 1;
+                cursor1 -= 1;
+                a[dest] = a[cursor1].clone();
+                len1 -= 1;
+                if len1 == 0 {
+                    break 'outer;
+                }
+                min_gallop = min_gallop.saturating_sub(1);
+                if count1 < MIN_GALLOP && count2 < MIN_GALLOP {
+                    break;
+                }
+            }
+            min_gallop += 2;
+        }
+        self.min_gallop = min_gallop.max(1);
+
+        if len2 == 1 {
+            dest -= len1;
+            cursor1 -= len1;
+            copy_within(a, cursor1, dest, len1);
+            a[dest - 1] = tmp[cursor2 - 1].clone();
+        } else if len2 == 0 {
+            contract_violation();
+        } else {
+            copy(tmp, 0, a, dest - len2, len2);
+        }
+    }
+}
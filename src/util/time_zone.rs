@@ -0,0 +1,623 @@
+use std::fs;
+use std::sync::{Arc, RwLock};
+use std::{env, fmt};
+
+/// Where the IANA time zone database is read from, as compiled by `zic`.
+const ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+const GMT_ID: &str = "GMT";
+/// 1900-01-01T00:00:00Z in seconds since the epoch.
+const UTC_1900: i64 = -2_208_988_800;
+
+static DEFAULT: RwLock<Option<TimeZone>> = RwLock::new(None);
+
+/// `java.util.TimeZone`: a region's offsets from UTC over time, including daylight saving
+/// time.
+///
+/// Region IDs like `Europe/Paris` are loaded from the system's IANA time zone database, the
+/// same data the JDK ships in its own format. As in Java, an unknown ID gives GMT.
+#[derive(Clone, PartialEq)]
+pub struct TimeZone {
+    id: String,
+    rules: Arc<ZoneRules>,
+}
+
+/// A zone's offsets as `ZoneInfo` has them, from 1900 on, and as `java.time`'s `ZoneRules`
+/// has them, with local mean time before the zone's first transition.
+#[derive(Debug, PartialEq)]
+struct ZoneRules {
+    info: Rules,
+    tzdb: Rules,
+}
+
+/// The offsets of a zone: the compiled transitions, and the POSIX rule that continues them
+/// past the last one.
+#[derive(Debug, Clone, PartialEq)]
+struct Rules {
+    /// UTC seconds of each transition, with the index of the offset that starts there.
+    transitions: Vec<(i64, usize)>,
+    /// Offsets in seconds east of UTC, and whether they are daylight saving time. The first
+    /// applies before the first transition.
+    offsets: Vec<(i32, bool)>,
+    tail: Option<PosixRule>,
+}
+
+/// A POSIX `TZ` rule such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Debug, Clone, PartialEq)]
+struct PosixRule {
+    std_offset: i32,
+    dst: Option<DstRule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DstRule {
+    offset: i32,
+    start: (RuleDate, i32),
+    end: (RuleDate, i32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RuleDate {
+    /// `Jn`: day 1 to 365, never counting February 29.
+    Julian(i32),
+    /// `n`: day 0 to 365, counting February 29 in leap years.
+    ZeroBased(i32),
+    /// `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` (5 is the last) of month `m`.
+    MonthWeekDay(u32, u32, u32),
+}
+
+impl fmt::Debug for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeZone").field("id", &self.id).finish()
+    }
+}
+
+impl TimeZone {
+    /// The zone for an ID: a region such as `America/New_York`, `UTC`, `GMT` or a custom
+    /// offset such as `GMT+5:30`, which is normalized to `GMT+05:30`. Any other ID gives
+    /// GMT.
+    pub fn get_time_zone(id: &str) -> TimeZone {
+        Self::find(id).unwrap_or_else(|| Self::fixed(GMT_ID.to_string(), 0))
+    }
+
+    fn find(id: &str) -> Option<TimeZone> {
+        if id == "UTC" || id == GMT_ID {
+            return Some(Self::fixed(id.to_string(), 0));
+        }
+        if let Some(custom) = id.strip_prefix(GMT_ID) {
+            return parse_custom(custom).map(|offset| Self::fixed(custom_id(offset), offset));
+        }
+        let valid = !id.is_empty()
+            && !id.starts_with('/')
+            && !id.split('/').any(|part| part == "." || part == "..")
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"/_+-".contains(&b));
+        if !valid {
+            return None;
+        }
+        let data = fs::read(format!("{ZONEINFO_DIR}/{id}")).ok()?;
+        let tzdb = parse_tzif(&data)?;
+        Some(TimeZone {
+            id: id.to_string(),
+            rules: Arc::new(ZoneRules {
+                info: tzdb.clone().since_1900(),
+                tzdb,
+            }),
+        })
+    }
+
+    fn fixed(id: String, offset: i32) -> TimeZone {
+        let rules = Rules {
+            transitions: Vec::new(),
+            offsets: vec![(offset, false)],
+            tail: None,
+        };
+        TimeZone {
+            id,
+            rules: Arc::new(ZoneRules {
+                info: rules.clone(),
+                tzdb: rules,
+            }),
+        }
+    }
+
+    /// The default zone: the one last passed to `set_default`, or else the one named by the
+    /// `TZ` environment variable, `/etc/timezone` or the `/etc/localtime` link, in that order
+    /// of precedence, as in the JDK. GMT if none of them names a zone.
+    pub fn get_default() -> TimeZone {
+        if let Some(zone) = DEFAULT.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            return zone.clone();
+        }
+        let id = env::var("TZ")
+            .ok()
+            .map(|tz| tz.trim_start_matches(':').to_string())
+            .filter(|tz| !tz.is_empty())
+            .or_else(|| {
+                fs::read_to_string("/etc/timezone")
+                    .ok()
+                    .map(|id| id.trim().to_string())
+                    .filter(|id| !id.is_empty())
+            })
+            .or_else(|| {
+                let target = fs::read_link("/etc/localtime").ok()?;
+                let target = target.to_str()?;
+                Some(target[target.find("zoneinfo/")? + "zoneinfo/".len()..].to_string())
+            });
+        let zone = id.map_or_else(
+            || Self::get_time_zone(GMT_ID),
+            |id| Self::get_time_zone(&id),
+        );
+        DEFAULT
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .get_or_insert(zone)
+            .clone()
+    }
+
+    pub fn set_default(zone: TimeZone) {
+        *DEFAULT.write().unwrap_or_else(|e| e.into_inner()) = Some(zone);
+    }
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    /// The offset from UTC in milliseconds at the given time, in milliseconds since the
+    /// epoch, including any daylight saving time.
+    pub fn get_offset(&self, date: i64) -> i32 {
+        self.rules.info.offset_at(date.div_euclid(1000)).0 * 1000
+    }
+
+    /// The offset from UTC in seconds at the given time, in seconds since the epoch, as
+    /// `ZoneRules.getOffset` has it: before 1900 this is local mean time, to the second,
+    /// where `get_offset` takes the zone's current standard offset.
+    pub(crate) fn get_zone_rules_offset(&self, epoch_second: i64) -> i32 {
+        self.rules.tzdb.offset_at(epoch_second).0
+    }
+
+    /// The current offset from UTC in milliseconds of the zone's standard time.
+    pub fn get_raw_offset(&self) -> i32 {
+        self.rules.info.raw_offset() * 1000
+    }
+
+    /// How many milliseconds the zone's daylight saving time currently adds to its standard
+    /// time, or 0 if it no longer observes it.
+    pub fn get_dst_savings(&self) -> i32 {
+        match &self.rules.info.tail {
+            Some(PosixRule {
+                std_offset,
+                dst: Some(dst),
+            }) => (dst.offset - std_offset) * 1000,
+            _ => 0,
+        }
+    }
+
+    /// Whether daylight saving time is in effect at the given time, in milliseconds since
+    /// the epoch.
+    pub fn in_daylight_time(&self, date: i64) -> bool {
+        self.rules.info.offset_at(date.div_euclid(1000)).1
+    }
+
+    /// The offset in milliseconds to subtract from a local wall time, in milliseconds since
+    /// the epoch as if it were UTC, to get the time it stands for. Like `GregorianCalendar`,
+    /// a time skipped by a forward transition is taken with the offset before it, and a time
+    /// repeated by a backward transition with the offset after it.
+    pub(crate) fn get_offset_by_wall(&self, wall: i64) -> i32 {
+        self.rules.info.offset_by_wall(wall.div_euclid(1000)) * 1000
+    }
+}
+
+/// Parses the part of a custom ID after `GMT`, like `java.util.TimeZone`: a sign, then
+/// `h`, `hh`, `hmm`, `hhmm` or `h[h]:mm`, up to 23:59. Returns the offset in seconds.
+fn parse_custom(custom: &str) -> Option<i32> {
+    let (negative, digits) = match custom.as_bytes().first()? {
+        b'+' => (false, &custom[1..]),
+        b'-' => (true, &custom[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match digits.split_once(':') {
+        Some((hours, minutes)) if (1..=2).contains(&hours.len()) && minutes.len() == 2 => {
+            (hours, minutes)
+        }
+        Some(_) => return None,
+        None if (1..=2).contains(&digits.len()) => (digits, "0"),
+        None if (3..=4).contains(&digits.len()) => digits.split_at(digits.len() - 2),
+        None => return None,
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let (hours, minutes): (i32, i32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    let offset = (hours * 60 + minutes) * 60;
+    Some(if negative { -offset } else { offset })
+}
+
+pub(crate) fn custom_id(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let minutes = offset.abs() / 60;
+    format!("{GMT_ID}{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Parses a compiled TZif file, using its 64-bit data when there is any.
+fn parse_tzif(data: &[u8]) -> Option<Rules> {
+    fn counts(data: &[u8]) -> Option<[usize; 6]> {
+        if data.get(..4)? != b"TZif" {
+            return None;
+        }
+        let mut counts = [0; 6];
+        for (i, count) in counts.iter_mut().enumerate() {
+            let start = 20 + i * 4;
+            *count = u32::from_be_bytes(data.get(start..start + 4)?.try_into().ok()?) as usize;
+        }
+        Some(counts)
+    }
+
+    let [is_ut, is_std, leap, time, kind, chars] = counts(data)?;
+    let (data, time_size) = if data[4] >= b'2' {
+        let v1_length = time * 5 + kind * 6 + chars + leap * 8 + is_std + is_ut;
+        (&data[44 + v1_length..], 8)
+    } else {
+        (data, 4)
+    };
+    let [is_ut, is_std, leap, time, kind, chars] = counts(data)?;
+
+    let mut pos = 44;
+    let mut times = Vec::with_capacity(time);
+    for _ in 0..time {
+        let bytes = data.get(pos..pos + time_size)?;
+        times.push(if time_size == 8 {
+            i64::from_be_bytes(bytes.try_into().ok()?)
+        } else {
+            i32::from_be_bytes(bytes.try_into().ok()?) as i64
+        });
+        pos += time_size;
+    }
+    let indices = data.get(pos..pos + time)?;
+    pos += time;
+    let mut offsets = Vec::with_capacity(kind);
+    for _ in 0..kind {
+        let record = data.get(pos..pos + 6)?;
+        offsets.push((
+            i32::from_be_bytes(record[..4].try_into().ok()?),
+            record[4] != 0,
+        ));
+        pos += 6;
+    }
+    if offsets.is_empty() || indices.iter().any(|&index| index as usize >= kind) {
+        return None;
+    }
+    pos += chars + leap * (time_size + 4) + is_std + is_ut;
+
+    let tail = if time_size == 8 {
+        let footer = std::str::from_utf8(data.get(pos..)?).ok()?;
+        parse_posix(footer.trim_matches('\n'))
+    } else {
+        None
+    };
+    let rules = Rules {
+        transitions: times
+            .into_iter()
+            .zip(indices.iter().map(|&index| index as usize))
+            .collect(),
+        offsets,
+        tail,
+    };
+    Some(rules)
+}
+
+/// Parses a POSIX `TZ` rule; `None` if it is empty or malformed.
+fn parse_posix(rule: &str) -> Option<PosixRule> {
+    let mut rest = rule;
+    skip_name(&mut rest)?;
+    let std_offset = -parse_time(&mut rest)?;
+    if rest.is_empty() {
+        return Some(PosixRule {
+            std_offset,
+            dst: None,
+        });
+    }
+    skip_name(&mut rest)?;
+    let offset = if rest.starts_with(',') {
+        std_offset + 3600
+    } else {
+        -parse_time(&mut rest)?
+    };
+    let transition = |rest: &mut &str| -> Option<(RuleDate, i32)> {
+        *rest = rest.strip_prefix(',')?;
+        let date = if let Some(month) = rest.strip_prefix('M') {
+            let end = month.find([',', '/']).unwrap_or(month.len());
+            let mut fields = month[..end].split('.').map(str::parse::<u32>);
+            let date = RuleDate::MonthWeekDay(
+                fields.next()?.ok()?,
+                fields.next()?.ok()?,
+                fields.next()?.ok()?,
+            );
+            *rest = &month[end..];
+            date
+        } else {
+            let julian = rest.starts_with('J');
+            let digits = rest.trim_start_matches('J');
+            let end = digits.find([',', '/']).unwrap_or(digits.len());
+            let day = digits[..end].parse().ok()?;
+            *rest = &digits[end..];
+            if julian {
+                RuleDate::Julian(day)
+            } else {
+                RuleDate::ZeroBased(day)
+            }
+        };
+        let time = match rest.strip_prefix('/') {
+            Some(time) => {
+                *rest = time;
+                parse_time(rest)?
+            }
+            None => 7200,
+        };
+        Some((date, time))
+    };
+    let start = transition(&mut rest)?;
+    let end = transition(&mut rest)?;
+    Some(PosixRule {
+        std_offset,
+        dst: Some(DstRule { offset, start, end }),
+    })
+}
+
+fn skip_name(rest: &mut &str) -> Option<()> {
+    let length = if let Some(quoted) = rest.strip_prefix('<') {
+        quoted.find('>')? + 2
+    } else {
+        rest.find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len())
+    };
+    if length < 3 {
+        return None;
+    }
+    *rest = &rest[length..];
+    Some(())
+}
+
+/// Parses `[+-]hh[:mm[:ss]]` into seconds.
+fn parse_time(rest: &mut &str) -> Option<i32> {
+    let negative = rest.starts_with('-');
+    let digits = rest.trim_start_matches(['+', '-']);
+    let end = digits
+        .find(|c: char| !c.is_ascii_digit() && c != ':')
+        .unwrap_or(digits.len());
+    let mut seconds = 0;
+    let mut parts = 0;
+    for (i, part) in digits[..end].split(':').enumerate() {
+        if i > 2 {
+            return None;
+        }
+        seconds += part.parse::<i32>().ok()? * [3600, 60, 1][i];
+        parts += 1;
+    }
+    if parts == 0 {
+        return None;
+    }
+    *rest = &digits[end..];
+    Some(if negative { -seconds } else { seconds })
+}
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Days since the epoch of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn year_of(seconds: i64) -> i64 {
+    // 400 Gregorian years have exactly 146097 days.
+    let days = seconds.div_euclid(SECONDS_PER_DAY) + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let year = year_of_era + era * 400;
+    if day_of_year >= 306 {
+        year + 1
+    } else {
+        year
+    }
+}
+
+impl RuleDate {
+    /// The day of the year's rule date, in days since the epoch.
+    fn day_in(&self, year: i64) -> i64 {
+        match *self {
+            RuleDate::Julian(day) => {
+                let day = day as i64;
+                days_from_civil(year, 1, 1) + day - 1 + i64::from(is_leap_year(year) && day >= 60)
+            }
+            RuleDate::ZeroBased(day) => days_from_civil(year, 1, 1) + day as i64,
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day =
+                    first + (weekday as i64 - first_weekday).rem_euclid(7) + (week as i64 - 1) * 7;
+                let next_month = if month == 12 {
+                    days_from_civil(year + 1, 1, 1)
+                } else {
+                    days_from_civil(year, month + 1, 1)
+                };
+                while day >= next_month {
+                    day -= 7;
+                }
+                day
+            }
+        }
+    }
+}
+
+impl PosixRule {
+    /// The rule's transitions in a year, as UTC seconds with the offset and daylight flag
+    /// they start.
+    fn transitions(&self, year: i64) -> Vec<(i64, i32, bool)> {
+        let Some(dst) = &self.dst else {
+            return Vec::new();
+        };
+        let (start_date, start_time) = &dst.start;
+        let (end_date, end_time) = &dst.end;
+        let start =
+            start_date.day_in(year) * SECONDS_PER_DAY + *start_time as i64 - self.std_offset as i64;
+        let end = end_date.day_in(year) * SECONDS_PER_DAY + *end_time as i64 - dst.offset as i64;
+        let mut transitions = vec![(start, dst.offset, true), (end, self.std_offset, false)];
+        transitions.sort_by_key(|&(time, _, _)| time);
+        transitions
+    }
+
+    fn offset_at(&self, seconds: i64) -> (i32, bool) {
+        let year = year_of(seconds + self.std_offset as i64);
+        let mut current = (self.std_offset, false);
+        for previous_year in [year - 1, year] {
+            for (time, offset, dst) in self.transitions(previous_year) {
+                if time <= seconds {
+                    current = (offset, dst);
+                }
+            }
+        }
+        current
+    }
+}
+
+impl Rules {
+    /// Like the JDK's `ZoneInfo`, which has no transitions before 1900: before then the
+    /// zone's current standard offset applies, rather than local mean time.
+    fn since_1900(mut self) -> Rules {
+        if self.transitions.is_empty() {
+            return self;
+        }
+        let raw = self.raw_offset();
+        let start = self
+            .transitions
+            .partition_point(|&(time, _)| time < UTC_1900);
+        let at_1900 = match start {
+            0 => 0,
+            _ => self.transitions[start - 1].1,
+        };
+        self.transitions.drain(..start);
+        self.transitions.insert(0, (UTC_1900, at_1900));
+        // The first offset is the one before the first transition, so move it to the end.
+        let moved = self.offsets.len();
+        self.offsets.push(self.offsets[0]);
+        self.offsets[0] = (raw, false);
+        for (_, index) in &mut self.transitions {
+            if *index == 0 {
+                *index = moved;
+            }
+        }
+        self
+    }
+
+    /// The current offset in seconds of the zone's standard time.
+    fn raw_offset(&self) -> i32 {
+        if let Some(tail) = &self.tail {
+            return tail.std_offset;
+        }
+        self.transitions
+            .iter()
+            .rev()
+            .map(|&(_, index)| self.offsets[index])
+            .find(|&(_, dst)| !dst)
+            .unwrap_or(self.offsets[0])
+            .0
+    }
+
+    fn offset_at(&self, seconds: i64) -> (i32, bool) {
+        let index = self
+            .transitions
+            .partition_point(|&(time, _)| time <= seconds);
+        if index == self.transitions.len() {
+            if let Some(tail) = &self.tail {
+                return tail.offset_at(seconds);
+            }
+        }
+        match index {
+            0 => self.offsets[0],
+            _ => self.offsets[self.transitions[index - 1].1],
+        }
+    }
+
+    /// Like `ZoneInfo.getOffsetsByWall`: the offset of the last transition whose wall time,
+    /// in the offset it starts, is not after `wall`.
+    fn offset_by_wall(&self, wall: i64) -> i32 {
+        let index = self
+            .transitions
+            .partition_point(|&(time, index)| time + self.offsets[index].0 as i64 <= wall);
+        let mut best = match index {
+            0 => (i64::MIN, self.offsets[0].0),
+            _ => {
+                let (time, index) = self.transitions[index - 1];
+                (time, self.offsets[index].0)
+            }
+        };
+        if index == self.transitions.len() {
+            if let Some(tail) = &self.tail {
+                let year = year_of(wall);
+                for year in year - 1..=year {
+                    for (time, offset, _) in tail.transitions(year) {
+                        if time + offset as i64 <= wall && time > best.0 {
+                            best = (time, offset);
+                        }
+                    }
+                }
+                if best.0 == i64::MIN {
+                    best.1 = tail.std_offset;
+                }
+            }
+        }
+        best.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeZone;
+
+    #[test]
+    fn custom_ids_like_jdk() {
+        assert_eq!(TimeZone::get_time_zone("GMT+5:30").get_id(), "GMT+05:30");
+        assert_eq!(
+            TimeZone::get_time_zone("GMT-0800").get_offset(0),
+            -8 * 3_600_000
+        );
+        assert_eq!(TimeZone::get_time_zone("GMT+24").get_id(), "GMT");
+        assert_eq!(TimeZone::get_time_zone("Nowhere/Special").get_id(), "GMT");
+        assert_eq!(TimeZone::get_time_zone("../etc/passwd").get_id(), "GMT");
+    }
+
+    #[test]
+    fn follows_daylight_saving_time() {
+        let paris = TimeZone::get_time_zone("Europe/Paris");
+        assert_eq!(paris.get_raw_offset(), 3_600_000);
+        // 2023-07-01 and 2060-01-01, past the compiled transitions.
+        assert_eq!(paris.get_offset(1_688_169_600_000), 7_200_000);
+        assert!(paris.in_daylight_time(1_688_169_600_000));
+        assert_eq!(paris.get_offset(2_840_140_800_000), 3_600_000);
+        // 02:30 on 2023-03-26 does not exist and 02:30 on 2023-10-29 happens twice.
+        assert_eq!(paris.get_offset_by_wall(1_679_797_800_000), 3_600_000);
+        assert_eq!(paris.get_offset_by_wall(1_698_546_600_000), 3_600_000);
+        assert_eq!(paris.get_offset_by_wall(1_688_169_600_000), 7_200_000);
+    }
+}
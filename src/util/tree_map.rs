@@ -0,0 +1,1904 @@
+//! `java.util.TreeMap`, the JDK's red-black tree, so that it calls its comparator on the
+//! same keys as Java's.
+
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+use crate::errors::util::{ConcurrentModificationException, NoSuchElementException};
+use crate::errors::{IllegalArgumentException, IllegalStateException, Throwable};
+use crate::util::collection;
+use crate::util::{
+    Collection, Comparator, Map, NaturalOrder, NavigableMap, NavigableSet, SequencedMap, Set,
+};
+use crate::HashCode;
+
+const RED: bool = false;
+const BLACK: bool = true;
+
+fn concurrent_modification() -> ! {
+    ConcurrentModificationException::new(String::new()).throw()
+}
+
+fn no_such_element() -> ! {
+    NoSuchElementException::new(String::new()).throw()
+}
+
+fn illegal_argument(message: &str) -> ! {
+    IllegalArgumentException::new(message.to_string(), None).throw()
+}
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    left: Option<usize>,
+    right: Option<usize>,
+    parent: Option<usize>,
+    color: bool,
+}
+
+/// Where a key is in the tree, or the node to attach it to and on which side; `Vacant(None)`
+/// is the root of an empty tree.
+enum Slot {
+    Found(usize),
+    Vacant(Option<(usize, bool)>),
+}
+
+/// The state of a `TreeMap`, shared with its views and iterators. The entries live in an
+/// arena and link to each other by index.
+struct Tree<K, V, C> {
+    entries: Vec<Option<Entry<K, V>>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    size: usize,
+    /// Java's `modCount`: the number of structural modifications, which iterators compare
+    /// with the count they expect to fail fast.
+    mod_count: usize,
+    comparator: C,
+}
+
+impl<K, V, C: Comparator<K>> Tree<K, V, C> {
+    fn new(comparator: C) -> Self {
+        Self {
+            entries: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            size: 0,
+            mod_count: 0,
+            comparator,
+        }
+    }
+
+    fn compare(&self, a: &K, b: &K) -> i32 {
+        self.comparator.compare(a, b)
+    }
+
+    fn entry(&self, i: usize) -> &Entry<K, V> {
+        self.entries[i].as_ref().unwrap()
+    }
+
+    fn entry_mut(&mut self, i: usize) -> &mut Entry<K, V> {
+        self.entries[i].as_mut().unwrap()
+    }
+
+    fn new_entry(&mut self, key: K, value: V, parent: Option<usize>) -> usize {
+        let entry = Entry {
+            key,
+            value,
+            left: None,
+            right: None,
+            parent,
+            color: BLACK,
+        };
+        match self.free.pop() {
+            Some(i) => {
+                self.entries[i] = Some(entry);
+                i
+            }
+            None => {
+                self.entries.push(Some(entry));
+                self.entries.len() - 1
+            }
+        }
+    }
+
+    fn free_entry(&mut self, i: usize) -> Entry<K, V> {
+        self.free.push(i);
+        self.entries[i].take().unwrap()
+    }
+
+    fn parent_of(&self, p: Option<usize>) -> Option<usize> {
+        p.and_then(|p| self.entry(p).parent)
+    }
+
+    fn left_of(&self, p: Option<usize>) -> Option<usize> {
+        p.and_then(|p| self.entry(p).left)
+    }
+
+    fn right_of(&self, p: Option<usize>) -> Option<usize> {
+        p.and_then(|p| self.entry(p).right)
+    }
+
+    fn color_of(&self, p: Option<usize>) -> bool {
+        p.map_or(BLACK, |p| self.entry(p).color)
+    }
+
+    fn set_color(&mut self, p: Option<usize>, color: bool) {
+        if let Some(p) = p {
+            self.entry_mut(p).color = color;
+        }
+    }
+
+    fn get_entry(&self, key: &K) -> Option<usize> {
+        let mut p = self.root;
+        while let Some(i) = p {
+            let entry = self.entry(i);
+            let cmp = self.compare(key, &entry.key);
+            if cmp < 0 {
+                p = entry.left;
+            } else if cmp > 0 {
+                p = entry.right;
+            } else {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    /// The parent of the first ancestor of `p` that `p` is left of, or right of if `left`.
+    fn climb(&self, p: usize, left: bool) -> Option<usize> {
+        let mut ch = p;
+        let mut parent = self.entry(p).parent;
+        while let Some(pa) = parent {
+            let side = if left {
+                self.entry(pa).left
+            } else {
+                self.entry(pa).right
+            };
+            if side != Some(ch) {
+                break;
+            }
+            ch = pa;
+            parent = self.entry(pa).parent;
+        }
+        parent
+    }
+
+    /// The entry of the lowest key greater than `key`, or equal to it if `inclusive`.
+    fn get_ceiling_entry(&self, key: &K, inclusive: bool) -> Option<usize> {
+        let mut p = self.root?;
+        loop {
+            let entry = self.entry(p);
+            let cmp = self.compare(key, &entry.key);
+            if cmp < 0 {
+                match entry.left {
+                    Some(left) => p = left,
+                    None => return Some(p),
+                }
+            } else if cmp > 0 || !inclusive {
+                match entry.right {
+                    Some(right) => p = right,
+                    None => return self.climb(p, false),
+                }
+            } else {
+                return Some(p);
+            }
+        }
+    }
+
+    /// The entry of the highest key less than `key`, or equal to it if `inclusive`.
+    fn get_floor_entry(&self, key: &K, inclusive: bool) -> Option<usize> {
+        let mut p = self.root?;
+        loop {
+            let entry = self.entry(p);
+            let cmp = self.compare(key, &entry.key);
+            if cmp > 0 {
+                match entry.right {
+                    Some(right) => p = right,
+                    None => return Some(p),
+                }
+            } else if cmp < 0 || !inclusive {
+                match entry.left {
+                    Some(left) => p = left,
+                    None => return self.climb(p, true),
+                }
+            } else {
+                return Some(p);
+            }
+        }
+    }
+
+    fn first_entry(&self) -> Option<usize> {
+        let mut p = self.root?;
+        while let Some(left) = self.entry(p).left {
+            p = left;
+        }
+        Some(p)
+    }
+
+    fn last_entry(&self) -> Option<usize> {
+        let mut p = self.root?;
+        while let Some(right) = self.entry(p).right {
+            p = right;
+        }
+        Some(p)
+    }
+
+    fn successor(&self, t: usize) -> Option<usize> {
+        match self.entry(t).right {
+            Some(mut p) => {
+                while let Some(left) = self.entry(p).left {
+                    p = left;
+                }
+                Some(p)
+            }
+            None => self.climb(t, false),
+        }
+    }
+
+    fn predecessor(&self, t: usize) -> Option<usize> {
+        match self.entry(t).left {
+            Some(mut p) => {
+                while let Some(right) = self.entry(p).right {
+                    p = right;
+                }
+                Some(p)
+            }
+            None => self.climb(t, true),
+        }
+    }
+
+    fn find_slot(&self, key: &K) -> Slot {
+        let Some(mut t) = self.root else {
+            return Slot::Vacant(None);
+        };
+        loop {
+            let entry = self.entry(t);
+            let cmp = self.compare(key, &entry.key);
+            let next = if cmp < 0 {
+                entry.left
+            } else if cmp > 0 {
+                entry.right
+            } else {
+                return Slot::Found(t);
+            };
+            match next {
+                Some(next) => t = next,
+                None => return Slot::Vacant(Some((t, cmp < 0))),
+            }
+        }
+    }
+
+    /// Java's `addEntry`, or `addEntryToEmptyMap` without its type check.
+    fn add_entry(&mut self, key: K, value: V, parent: Option<(usize, bool)>) {
+        match parent {
+            None => {
+                self.root = Some(self.new_entry(key, value, None));
+            }
+            Some((parent, add_to_left)) => {
+                let e = self.new_entry(key, value, Some(parent));
+                if add_to_left {
+                    self.entry_mut(parent).left = Some(e);
+                } else {
+                    self.entry_mut(parent).right = Some(e);
+                }
+                self.fix_after_insertion(e);
+            }
+        }
+        self.size += 1;
+        self.mod_count += 1;
+    }
+
+    fn rotate_left(&mut self, p: Option<usize>) {
+        let Some(p) = p else { return };
+        let r = self.entry(p).right.unwrap();
+        let r_left = self.entry(r).left;
+        self.entry_mut(p).right = r_left;
+        if let Some(r_left) = r_left {
+            self.entry_mut(r_left).parent = Some(p);
+        }
+        let parent = self.entry(p).parent;
+        self.entry_mut(r).parent = parent;
+        match parent {
+            None => self.root = Some(r),
+            Some(parent) if self.entry(parent).left == Some(p) => {
+                self.entry_mut(parent).left = Some(r)
+            }
+            Some(parent) => self.entry_mut(parent).right = Some(r),
+        }
+        self.entry_mut(r).left = Some(p);
+        self.entry_mut(p).parent = Some(r);
+    }
+
+    fn rotate_right(&mut self, p: Option<usize>) {
+        let Some(p) = p else { return };
+        let l = self.entry(p).left.unwrap();
+        let l_right = self.entry(l).right;
+        self.entry_mut(p).left = l_right;
+        if let Some(l_right) = l_right {
+            self.entry_mut(l_right).parent = Some(p);
+        }
+        let parent = self.entry(p).parent;
+        self.entry_mut(l).parent = parent;
+        match parent {
+            None => self.root = Some(l),
+            Some(parent) if self.entry(parent).right == Some(p) => {
+                self.entry_mut(parent).right = Some(l)
+            }
+            Some(parent) => self.entry_mut(parent).left = Some(l),
+        }
+        self.entry_mut(l).right = Some(p);
+        self.entry_mut(p).parent = Some(l);
+    }
+
+    fn fix_after_insertion(&mut self, x: usize) {
+        let mut x = Some(x);
+        self.set_color(x, RED);
+        while x.is_some() && x != self.root && self.color_of(self.parent_of(x)) == RED {
+            let grandparent = self.parent_of(self.parent_of(x));
+            if self.parent_of(x) == self.left_of(grandparent) {
+                let y = self.right_of(grandparent);
+                if self.color_of(y) == RED {
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(y, BLACK);
+                    self.set_color(grandparent, RED);
+                    x = grandparent;
+                } else {
+                    if x == self.right_of(self.parent_of(x)) {
+                        x = self.parent_of(x);
+                        self.rotate_left(x);
+                    }
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(self.parent_of(self.parent_of(x)), RED);
+                    self.rotate_right(self.parent_of(self.parent_of(x)));
+                }
+            } else {
+                let y = self.left_of(grandparent);
+                if self.color_of(y) == RED {
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(y, BLACK);
+                    self.set_color(grandparent, RED);
+                    x = grandparent;
+                } else {
+                    if x == self.left_of(self.parent_of(x)) {
+                        x = self.parent_of(x);
+                        self.rotate_right(x);
+                    }
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(self.parent_of(self.parent_of(x)), RED);
+                    self.rotate_left(self.parent_of(self.parent_of(x)));
+                }
+            }
+        }
+        self.set_color(self.root, BLACK);
+    }
+
+    /// Removes an entry, returning its mapping. Like Java's, an entry with two children
+    /// takes the mapping of its successor, whose entry is removed instead.
+    fn delete_entry(&mut self, p: usize) -> (K, V) {
+        self.mod_count += 1;
+        self.size -= 1;
+        let mut p = p;
+        if self.entry(p).left.is_some() && self.entry(p).right.is_some() {
+            let s = self.successor(p).unwrap();
+            let mut successor = self.entries[s].take().unwrap();
+            let entry = self.entry_mut(p);
+            std::mem::swap(&mut entry.key, &mut successor.key);
+            std::mem::swap(&mut entry.value, &mut successor.value);
+            self.entries[s] = Some(successor);
+            p = s;
+        }
+        let entry = self.entry(p);
+        let (parent, color) = (entry.parent, entry.color);
+        if let Some(replacement) = entry.left.or(entry.right) {
+            self.entry_mut(replacement).parent = parent;
+            match parent {
+                None => self.root = Some(replacement),
+                Some(parent) if self.entry(parent).left == Some(p) => {
+                    self.entry_mut(parent).left = Some(replacement)
+                }
+                Some(parent) => self.entry_mut(parent).right = Some(replacement),
+            }
+            if color == BLACK {
+                self.fix_after_deletion(Some(replacement));
+            }
+        } else if parent.is_none() {
+            self.root = None;
+        } else {
+            if color == BLACK {
+                self.fix_after_deletion(Some(p));
+            }
+            if let Some(parent) = self.entry(p).parent {
+                if self.entry(parent).left == Some(p) {
+                    self.entry_mut(parent).left = None;
+                } else if self.entry(parent).right == Some(p) {
+                    self.entry_mut(parent).right = None;
+                }
+            }
+        }
+        let entry = self.free_entry(p);
+        (entry.key, entry.value)
+    }
+
+    fn fix_after_deletion(&mut self, mut x: Option<usize>) {
+        while x != self.root && self.color_of(x) == BLACK {
+            if x == self.left_of(self.parent_of(x)) {
+                let mut sib = self.right_of(self.parent_of(x));
+                if self.color_of(sib) == RED {
+                    self.set_color(sib, BLACK);
+                    self.set_color(self.parent_of(x), RED);
+                    self.rotate_left(self.parent_of(x));
+                    sib = self.right_of(self.parent_of(x));
+                }
+                if self.color_of(self.left_of(sib)) == BLACK
+                    && self.color_of(self.right_of(sib)) == BLACK
+                {
+                    self.set_color(sib, RED);
+                    x = self.parent_of(x);
+                } else {
+                    if self.color_of(self.right_of(sib)) == BLACK {
+                        self.set_color(self.left_of(sib), BLACK);
+                        self.set_color(sib, RED);
+                        self.rotate_right(sib);
+                        sib = self.right_of(self.parent_of(x));
+                    }
+                    self.set_color(sib, self.color_of(self.parent_of(x)));
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(self.right_of(sib), BLACK);
+                    self.rotate_left(self.parent_of(x));
+                    x = self.root;
+                }
+            } else {
+                let mut sib = self.left_of(self.parent_of(x));
+                if self.color_of(sib) == RED {
+                    self.set_color(sib, BLACK);
+                    self.set_color(self.parent_of(x), RED);
+                    self.rotate_right(self.parent_of(x));
+                    sib = self.left_of(self.parent_of(x));
+                }
+                if self.color_of(self.right_of(sib)) == BLACK
+                    && self.color_of(self.left_of(sib)) == BLACK
+                {
+                    self.set_color(sib, RED);
+                    x = self.parent_of(x);
+                } else {
+                    if self.color_of(self.left_of(sib)) == BLACK {
+                        self.set_color(self.right_of(sib), BLACK);
+                        self.set_color(sib, RED);
+                        self.rotate_left(sib);
+                        sib = self.left_of(self.parent_of(x));
+                    }
+                    self.set_color(sib, self.color_of(self.parent_of(x)));
+                    self.set_color(self.parent_of(x), BLACK);
+                    self.set_color(self.left_of(sib), BLACK);
+                    self.rotate_right(self.parent_of(x));
+                    x = self.root;
+                }
+            }
+        }
+        self.set_color(x, BLACK);
+    }
+
+    /// Java's `buildFromSorted`: a balanced tree of mappings in ascending order, whose
+    /// nodes are black but for the bottom level if it isn't full.
+    fn build_from_sorted(&mut self, mappings: Vec<(K, V)>) {
+        let size = mappings.len();
+        let red_level = 31 - (size as u32 + 1).leading_zeros();
+        let mut mappings = mappings.into_iter();
+        self.root = self.build(0, 0, size as isize - 1, red_level, &mut mappings);
+        self.size = size;
+    }
+
+    fn build(
+        &mut self,
+        level: u32,
+        lo: isize,
+        hi: isize,
+        red_level: u32,
+        mappings: &mut impl Iterator<Item = (K, V)>,
+    ) -> Option<usize> {
+        if hi < lo {
+            return None;
+        }
+        let mid = (lo + hi) / 2;
+        let left = if lo < mid {
+            self.build(level + 1, lo, mid - 1, red_level, mappings)
+        } else {
+            None
+        };
+        let (key, value) = mappings.next().unwrap();
+        let middle = self.new_entry(key, value, None);
+        if level == red_level {
+            self.entry_mut(middle).color = RED;
+        }
+        if let Some(left) = left {
+            self.entry_mut(middle).left = Some(left);
+            self.entry_mut(left).parent = Some(middle);
+        }
+        if mid < hi {
+            let right = self
+                .build(level + 1, mid + 1, hi, red_level, mappings)
+                .unwrap();
+            self.entry_mut(middle).right = Some(right);
+            self.entry_mut(right).parent = Some(middle);
+        }
+        Some(middle)
+    }
+
+    fn clear(&mut self) {
+        self.mod_count += 1;
+        self.size = 0;
+        self.root = None;
+        self.entries.clear();
+        self.free.clear();
+    }
+}
+
+/// The range of a view, Java's `NavigableSubMap`: each bound is a key and whether it is
+/// inclusive, or `None` for Java's `fromStart` or `toEnd`.
+#[derive(Clone)]
+struct Bounds<K> {
+    lo: Option<(K, bool)>,
+    hi: Option<(K, bool)>,
+    descending: bool,
+}
+
+/// `java.util.TreeMap`: a map sorted by a `Comparator` of its keys, their natural order by
+/// default.
+///
+/// A comparator that can't compare two keys throws, like Java's `ClassCastException`,
+/// before the map changes; the first key put into an empty map is compared with itself to
+/// check it. The `NavigableMap` views, `head_map`, `tail_map`, `sub_map` and
+/// `descending_map`, are `TreeMap`s that share the map, like its iterators, and fail fast
+/// like `HashMap`'s. `clone` copies the mappings into a new map.
+pub struct TreeMap<K, V, C = NaturalOrder<K>> {
+    tree: Rc<RefCell<Tree<K, V, C>>>,
+    bounds: Bounds<K>,
+}
+
+impl<K: Clone + Ord, V: Clone> TreeMap<K, V> {
+    /// An empty map sorted by the natural order of the keys.
+    pub fn new() -> Self {
+        Self::with_comparator(crate::util::natural_order())
+    }
+
+    /// Java's `new TreeMap<>(m)`: the mappings of another map, sorted by the natural order
+    /// of the keys.
+    pub fn from_map<M: Map<K, V>>(m: &M) -> Self {
+        let mut map = Self::new();
+        map.put_all(m);
+        map
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> TreeMap<K, V, C> {
+    /// An empty map sorted by the comparator.
+    pub fn with_comparator(comparator: C) -> Self {
+        Self {
+            tree: Rc::new(RefCell::new(Tree::new(comparator))),
+            bounds: Bounds {
+                lo: None,
+                hi: None,
+                descending: false,
+            },
+        }
+    }
+
+    fn tree(&self) -> Ref<'_, Tree<K, V, C>> {
+        self.tree.borrow()
+    }
+
+    fn tree_mut(&self) -> RefMut<'_, Tree<K, V, C>> {
+        self.tree.borrow_mut()
+    }
+
+    /// Whether this is the map rather than a view with bounds.
+    fn is_whole(&self) -> bool {
+        self.bounds.lo.is_none() && self.bounds.hi.is_none()
+    }
+
+    fn mapping(tree: &Tree<K, V, C>, i: usize) -> (K, V) {
+        let entry = tree.entry(i);
+        (entry.key.clone(), entry.value.clone())
+    }
+
+    fn check_mod_count(&self, expected_mod_count: usize) {
+        if self.tree().mod_count != expected_mod_count {
+            concurrent_modification();
+        }
+    }
+
+    fn too_low(&self, tree: &Tree<K, V, C>, key: &K) -> bool {
+        self.bounds.lo.as_ref().is_some_and(|(lo, inclusive)| {
+            let c = tree.compare(key, lo);
+            c < 0 || (c == 0 && !inclusive)
+        })
+    }
+
+    fn too_high(&self, tree: &Tree<K, V, C>, key: &K) -> bool {
+        self.bounds.hi.as_ref().is_some_and(|(hi, inclusive)| {
+            let c = tree.compare(key, hi);
+            c > 0 || (c == 0 && !inclusive)
+        })
+    }
+
+    fn in_range(&self, tree: &Tree<K, V, C>, key: &K) -> bool {
+        !self.too_low(tree, key) && !self.too_high(tree, key)
+    }
+
+    /// Whether a key is in the range or one of its exclusive bounds, which is the test
+    /// for the exclusive bound of a view of this one.
+    fn in_range_or_bound(&self, tree: &Tree<K, V, C>, key: &K, inclusive: bool) -> bool {
+        if inclusive {
+            return self.in_range(tree, key);
+        }
+        self.bounds
+            .lo
+            .as_ref()
+            .is_none_or(|(lo, _)| tree.compare(key, lo) >= 0)
+            && self
+                .bounds
+                .hi
+                .as_ref()
+                .is_none_or(|(hi, _)| tree.compare(hi, key) >= 0)
+    }
+
+    fn check_in_range(&self, key: &K) {
+        if !self.in_range(&self.tree(), key) {
+            illegal_argument("key out of range");
+        }
+    }
+
+    fn abs_lowest(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        let e = match &self.bounds.lo {
+            None => tree.first_entry(),
+            Some((lo, inclusive)) => tree.get_ceiling_entry(lo, *inclusive),
+        };
+        e.filter(|&e| !self.too_high(tree, &tree.entry(e).key))
+    }
+
+    fn abs_highest(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        let e = match &self.bounds.hi {
+            None => tree.last_entry(),
+            Some((hi, inclusive)) => tree.get_floor_entry(hi, *inclusive),
+        };
+        e.filter(|&e| !self.too_low(tree, &tree.entry(e).key))
+    }
+
+    /// Java's `absCeiling`, or `absHigher` unless `inclusive`.
+    fn abs_ceiling(&self, tree: &Tree<K, V, C>, key: &K, inclusive: bool) -> Option<usize> {
+        if self.too_low(tree, key) {
+            return self.abs_lowest(tree);
+        }
+        tree.get_ceiling_entry(key, inclusive)
+            .filter(|&e| !self.too_high(tree, &tree.entry(e).key))
+    }
+
+    /// Java's `absFloor`, or `absLower` unless `inclusive`.
+    fn abs_floor(&self, tree: &Tree<K, V, C>, key: &K, inclusive: bool) -> Option<usize> {
+        if self.too_high(tree, key) {
+            return self.abs_highest(tree);
+        }
+        tree.get_floor_entry(key, inclusive)
+            .filter(|&e| !self.too_low(tree, &tree.entry(e).key))
+    }
+
+    /// The entry after the range, where an ascending iterator stops.
+    fn abs_high_fence(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        let (hi, inclusive) = self.bounds.hi.as_ref()?;
+        tree.get_ceiling_entry(hi, !inclusive)
+    }
+
+    /// The entry before the range, where a descending iterator stops.
+    fn abs_low_fence(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        let (lo, inclusive) = self.bounds.lo.as_ref()?;
+        tree.get_floor_entry(lo, !inclusive)
+    }
+
+    fn sub_lowest(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        if self.bounds.descending {
+            self.abs_highest(tree)
+        } else {
+            self.abs_lowest(tree)
+        }
+    }
+
+    fn sub_highest(&self, tree: &Tree<K, V, C>) -> Option<usize> {
+        if self.bounds.descending {
+            self.abs_lowest(tree)
+        } else {
+            self.abs_highest(tree)
+        }
+    }
+
+    /// Java's `subCeiling`, or `subHigher` unless `inclusive`.
+    fn sub_ceiling(&self, tree: &Tree<K, V, C>, key: &K, inclusive: bool) -> Option<usize> {
+        if self.bounds.descending {
+            self.abs_floor(tree, key, inclusive)
+        } else {
+            self.abs_ceiling(tree, key, inclusive)
+        }
+    }
+
+    /// Java's `subFloor`, or `subLower` unless `inclusive`.
+    fn sub_floor(&self, tree: &Tree<K, V, C>, key: &K, inclusive: bool) -> Option<usize> {
+        if self.bounds.descending {
+            self.abs_ceiling(tree, key, inclusive)
+        } else {
+            self.abs_floor(tree, key, inclusive)
+        }
+    }
+
+    fn export(&self, e: Option<usize>) -> Option<(K, V)> {
+        let tree = self.tree();
+        e.map(|e| Self::mapping(&tree, e))
+    }
+
+    fn poll(&mut self, e: Option<usize>) -> Option<(K, V)> {
+        e.map(|e| self.tree_mut().delete_entry(e))
+    }
+
+    /// The entries of the map or view in its order.
+    fn indexes(&self) -> Vec<usize> {
+        let mut each = TreeIterator::new(self);
+        let mut indexes = Vec::new();
+        while let Some(e) = each.next_entry() {
+            indexes.push(e);
+        }
+        indexes
+    }
+
+    fn entries(&self) -> Vec<(K, V)> {
+        let tree = self.tree();
+        self.indexes()
+            .into_iter()
+            .map(|e| Self::mapping(&tree, e))
+            .collect()
+    }
+
+    /// A view with other bounds, which Java's `NavigableSubMap` constructor checks by
+    /// comparing them.
+    fn view(&self, bounds: Bounds<K>) -> Self {
+        {
+            let tree = self.tree();
+            match (&bounds.lo, &bounds.hi) {
+                (Some((lo, _)), Some((hi, _))) => {
+                    if tree.compare(lo, hi) > 0 {
+                        illegal_argument("fromKey > toKey");
+                    }
+                }
+                (Some((key, _)), None) | (None, Some((key, _))) => {
+                    tree.compare(key, key);
+                }
+                (None, None) => {}
+            }
+        }
+        Self {
+            tree: Rc::clone(&self.tree),
+            bounds,
+        }
+    }
+
+    /// Puts a key where `find_slot` said it goes, after `addEntryToEmptyMap`'s check of
+    /// the first key.
+    fn add_entry(&self, key: K, value: V, parent: Option<(usize, bool)>) {
+        if parent.is_none() {
+            self.tree().compare(&key, &key);
+        }
+        self.tree_mut().add_entry(key, value, parent);
+    }
+
+    fn put_val(&mut self, key: K, value: V, replace_old: bool) -> Option<V> {
+        self.check_in_range(&key);
+        let slot = self.tree().find_slot(&key);
+        match slot {
+            Slot::Found(t) => {
+                let mut tree = self.tree_mut();
+                let entry = tree.entry_mut(t);
+                if replace_old {
+                    Some(std::mem::replace(&mut entry.value, value))
+                } else {
+                    Some(entry.value.clone())
+                }
+            }
+            Slot::Vacant(parent) => {
+                self.add_entry(key, value, parent);
+                None
+            }
+        }
+    }
+
+    /// A view of the keys in the map's order, through which mappings can be removed and
+    /// which can be searched like the map.
+    pub fn navigable_key_set(&self) -> KeySet<K, V, C> {
+        KeySet {
+            map: self.view(self.bounds.clone()),
+        }
+    }
+
+    pub fn descending_key_set(&self) -> KeySet<K, V, C> {
+        KeySet {
+            map: self.descending_map(),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Map<K, V> for TreeMap<K, V, C> {
+    type Iter = EntryIterator<K, V, C>;
+    type KeySet = KeySet<K, V, C>;
+    type Values = Values<K, V, C>;
+    type EntrySet = EntrySet<K, V, C>;
+
+    /// For a view, this counts the mappings in its range.
+    fn size(&self) -> usize {
+        if self.is_whole() {
+            self.tree().size
+        } else {
+            self.indexes().len()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.sub_lowest(&self.tree()).is_none()
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        let tree = self.tree();
+        self.in_range(&tree, key) && tree.get_entry(key).is_some()
+    }
+
+    fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        let tree = self.tree();
+        self.indexes()
+            .into_iter()
+            .any(|e| tree.entry(e).value == *value)
+    }
+
+    fn get(&self, key: &K) -> Option<V> {
+        let tree = self.tree();
+        if !self.in_range(&tree, key) {
+            return None;
+        }
+        let e = tree.get_entry(key)?;
+        Some(tree.entry(e).value.clone())
+    }
+
+    /// Throws `IllegalArgumentException` if the key is out of a view's range.
+    fn put(&mut self, key: K, value: V) -> Option<V> {
+        self.put_val(key, value, true)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let e = {
+            let tree = self.tree();
+            if !self.in_range(&tree, key) {
+                return None;
+            }
+            tree.get_entry(key)?
+        };
+        Some(self.tree_mut().delete_entry(e).1)
+    }
+
+    fn clear(&mut self) {
+        if self.is_whole() {
+            self.tree_mut().clear();
+        } else {
+            let mut each = TreeIterator::new(self);
+            while each.next_entry().is_some() {
+                each.remove();
+            }
+        }
+    }
+
+    fn iterator(&self) -> EntryIterator<K, V, C> {
+        EntryIterator(TreeIterator::new(self))
+    }
+
+    fn key_set(&self) -> KeySet<K, V, C> {
+        self.navigable_key_set()
+    }
+
+    fn values(&self) -> Values<K, V, C> {
+        Values {
+            map: self.view(self.bounds.clone()),
+        }
+    }
+
+    fn entry_set(&self) -> EntrySet<K, V, C> {
+        EntrySet {
+            map: self.view(self.bounds.clone()),
+        }
+    }
+
+    /// Calls the action on each mapping, throwing `ConcurrentModificationException` if it
+    /// changes the map structurally.
+    fn for_each<F: FnMut(&K, &V)>(&self, mut action: F) {
+        let mut each = TreeIterator::new(self);
+        while let Some(e) = each.next_entry() {
+            let (key, value) = Self::mapping(&self.tree(), e);
+            action(&key, &value);
+            if self.is_whole() {
+                self.check_mod_count(each.expected_mod_count);
+            }
+        }
+    }
+
+    fn replace_all<F: FnMut(&K, &V) -> V>(&mut self, mut function: F) {
+        let mut each = TreeIterator::new(self);
+        while let Some(e) = each.next_entry() {
+            let (key, value) = Self::mapping(&self.tree(), e);
+            let value = function(&key, &value);
+            self.check_mod_count(each.expected_mod_count);
+            self.tree_mut().entry_mut(e).value = value;
+        }
+    }
+
+    fn put_if_absent(&mut self, key: K, value: V) -> Option<V> {
+        self.put_val(key, value, false)
+    }
+
+    fn replace(&mut self, key: K, value: V) -> Option<V> {
+        let mut tree = self.tree_mut();
+        if !self.in_range(&tree, &key) {
+            return None;
+        }
+        let e = tree.get_entry(&key)?;
+        Some(std::mem::replace(&mut tree.entry_mut(e).value, value))
+    }
+
+    fn replace_entry(&mut self, key: K, old_value: &V, new_value: V) -> bool
+    where
+        V: PartialEq,
+    {
+        let mut tree = self.tree_mut();
+        if !self.in_range(&tree, &key) {
+            return false;
+        }
+        match tree.get_entry(&key) {
+            Some(e) if tree.entry(e).value == *old_value => {
+                tree.entry_mut(e).value = new_value;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Like Java's, the function throws `ConcurrentModificationException` if it changes
+    /// the map structurally, and a view throws `IllegalArgumentException` after calling it
+    /// for a key out of its range.
+    fn compute_if_absent<F: FnOnce(&K) -> V>(&mut self, key: K, mapping_function: F) -> V {
+        if !self.in_range(&self.tree(), &key) {
+            mapping_function(&key);
+            illegal_argument("key out of range");
+        }
+        let (slot, mod_count) = {
+            let tree = self.tree();
+            (tree.find_slot(&key), tree.mod_count)
+        };
+        let parent = match slot {
+            Slot::Found(t) => return self.tree().entry(t).value.clone(),
+            Slot::Vacant(parent) => parent,
+        };
+        let value = mapping_function(&key);
+        self.check_mod_count(mod_count);
+        self.add_entry(key, value.clone(), parent);
+        value
+    }
+
+    fn compute_if_present<F: FnOnce(&K, &V) -> Option<V>>(
+        &mut self,
+        key: &K,
+        remapping_function: F,
+    ) -> Option<V> {
+        let (t, old_value, mod_count) = {
+            let tree = self.tree();
+            if !self.in_range(&tree, key) {
+                return None;
+            }
+            let t = tree.get_entry(key)?;
+            (t, tree.entry(t).value.clone(), tree.mod_count)
+        };
+        let value = remapping_function(key, &old_value);
+        self.check_mod_count(mod_count);
+        self.remap_value(t, value)
+    }
+
+    /// Like Java's, the function throws `ConcurrentModificationException` if it changes
+    /// the map structurally, and a view throws `IllegalArgumentException` after calling it
+    /// for a key out of its range unless it returns `None`.
+    fn compute<F: FnOnce(&K, Option<&V>) -> Option<V>>(
+        &mut self,
+        key: K,
+        remapping_function: F,
+    ) -> Option<V> {
+        if !self.in_range(&self.tree(), &key) {
+            remapping_function(&key, None)?;
+            illegal_argument("key out of range");
+        }
+        let (slot, old_value, mod_count) = {
+            let tree = self.tree();
+            let slot = tree.find_slot(&key);
+            let old_value = match slot {
+                Slot::Found(t) => Some(tree.entry(t).value.clone()),
+                Slot::Vacant(_) => None,
+            };
+            (slot, old_value, tree.mod_count)
+        };
+        let value = remapping_function(&key, old_value.as_ref());
+        self.check_mod_count(mod_count);
+        match slot {
+            Slot::Found(t) => self.remap_value(t, value),
+            Slot::Vacant(parent) => {
+                let value = value?;
+                self.add_entry(key, value.clone(), parent);
+                Some(value)
+            }
+        }
+    }
+
+    /// Like Java's, the function throws `ConcurrentModificationException` if it changes
+    /// the map structurally.
+    fn merge<F: FnOnce(&V, &V) -> Option<V>>(
+        &mut self,
+        key: K,
+        value: V,
+        remapping_function: F,
+    ) -> Option<V> {
+        self.check_in_range(&key);
+        let (slot, mod_count) = {
+            let tree = self.tree();
+            (tree.find_slot(&key), tree.mod_count)
+        };
+        let t = match slot {
+            Slot::Found(t) => t,
+            Slot::Vacant(parent) => {
+                self.add_entry(key, value.clone(), parent);
+                return Some(value);
+            }
+        };
+        let old_value = self.tree().entry(t).value.clone();
+        let value = remapping_function(&old_value, &value);
+        self.check_mod_count(mod_count);
+        self.remap_value(t, value)
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> TreeMap<K, V, C> {
+    /// Java's `remapValue`: replaces an entry's value by the function's, or removes it.
+    fn remap_value(&self, t: usize, value: Option<V>) -> Option<V> {
+        let mut tree = self.tree_mut();
+        match value {
+            Some(value) => {
+                tree.entry_mut(t).value = value.clone();
+                Some(value)
+            }
+            None => {
+                tree.delete_entry(t);
+                None
+            }
+        }
+    }
+}
+
+/// Java 21's `SequencedMap` methods of a `TreeMap`, whose `put_first` and `put_last` throw
+/// `UnsupportedOperationException`.
+impl<K: Clone, V: Clone, C: Comparator<K>> SequencedMap<K, V> for TreeMap<K, V, C> {
+    type Reversed = TreeMap<K, V, C>;
+
+    fn reversed(&self) -> Self {
+        self.view(Bounds {
+            descending: !self.bounds.descending,
+            ..self.bounds.clone()
+        })
+    }
+
+    fn first_entry(&self) -> Option<(K, V)> {
+        self.export(self.sub_lowest(&self.tree()))
+    }
+
+    fn last_entry(&self) -> Option<(K, V)> {
+        self.export(self.sub_highest(&self.tree()))
+    }
+
+    fn poll_first_entry(&mut self) -> Option<(K, V)> {
+        let e = self.sub_lowest(&self.tree());
+        self.poll(e)
+    }
+
+    fn poll_last_entry(&mut self) -> Option<(K, V)> {
+        let e = self.sub_highest(&self.tree());
+        self.poll(e)
+    }
+}
+
+/// The views check their keys against the range of this map, throwing
+/// `IllegalArgumentException` with Java's messages if they are out of it.
+impl<K: Clone, V: Clone, C: Comparator<K>> NavigableMap<K, V> for TreeMap<K, V, C> {
+    fn first_key(&self) -> K {
+        self.first_entry().unwrap_or_else(|| no_such_element()).0
+    }
+
+    fn last_key(&self) -> K {
+        self.last_entry().unwrap_or_else(|| no_such_element()).0
+    }
+
+    fn lower_entry(&self, key: &K) -> Option<(K, V)> {
+        self.export(self.sub_floor(&self.tree(), key, false))
+    }
+
+    fn floor_entry(&self, key: &K) -> Option<(K, V)> {
+        self.export(self.sub_floor(&self.tree(), key, true))
+    }
+
+    fn ceiling_entry(&self, key: &K) -> Option<(K, V)> {
+        self.export(self.sub_ceiling(&self.tree(), key, true))
+    }
+
+    fn higher_entry(&self, key: &K) -> Option<(K, V)> {
+        self.export(self.sub_ceiling(&self.tree(), key, false))
+    }
+
+    fn head_map(&self, to_key: K, inclusive: bool) -> Self {
+        if !self.in_range_or_bound(&self.tree(), &to_key, inclusive) {
+            illegal_argument("toKey out of range");
+        }
+        let bound = Some((to_key, inclusive));
+        self.view(if self.bounds.descending {
+            Bounds {
+                lo: bound,
+                ..self.bounds.clone()
+            }
+        } else {
+            Bounds {
+                hi: bound,
+                ..self.bounds.clone()
+            }
+        })
+    }
+
+    fn tail_map(&self, from_key: K, inclusive: bool) -> Self {
+        if !self.in_range_or_bound(&self.tree(), &from_key, inclusive) {
+            illegal_argument("fromKey out of range");
+        }
+        let bound = Some((from_key, inclusive));
+        self.view(if self.bounds.descending {
+            Bounds {
+                hi: bound,
+                ..self.bounds.clone()
+            }
+        } else {
+            Bounds {
+                lo: bound,
+                ..self.bounds.clone()
+            }
+        })
+    }
+
+    fn sub_map(&self, from_key: K, from_inclusive: bool, to_key: K, to_inclusive: bool) -> Self {
+        {
+            let tree = self.tree();
+            if !self.in_range_or_bound(&tree, &from_key, from_inclusive) {
+                illegal_argument("fromKey out of range");
+            }
+            if !self.in_range_or_bound(&tree, &to_key, to_inclusive) {
+                illegal_argument("toKey out of range");
+            }
+        }
+        let (from, to) = (
+            Some((from_key, from_inclusive)),
+            Some((to_key, to_inclusive)),
+        );
+        let descending = self.bounds.descending;
+        self.view(if descending {
+            Bounds {
+                lo: to,
+                hi: from,
+                descending,
+            }
+        } else {
+            Bounds {
+                lo: from,
+                hi: to,
+                descending,
+            }
+        })
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> Default for TreeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K> + Clone> Clone for TreeMap<K, V, C> {
+    /// Like Java's `clone`, a balanced tree of the mappings with the same comparator, or
+    /// for a view, a map of the mappings in its range with the same bounds.
+    fn clone(&self) -> Self {
+        let mut tree = Tree::new(self.tree().comparator.clone());
+        let mut mappings = self.entries();
+        if self.bounds.descending {
+            mappings.reverse();
+        }
+        tree.build_from_sorted(mappings);
+        Self {
+            tree: Rc::new(RefCell::new(tree)),
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> FromIterator<(K, V)> for TreeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Extend<(K, V)> for TreeMap<K, V, C> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.put(key, value);
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> IntoIterator for &TreeMap<K, V, C> {
+    type Item = (K, V);
+    type IntoIter = EntryIterator<K, V, C>;
+
+    fn into_iter(self) -> EntryIterator<K, V, C> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the mappings as `key=value` in braces, separated by `, `.
+impl<K, V, C> std::fmt::Display for TreeMap<K, V, C>
+where
+    K: Clone + std::fmt::Display,
+    V: Clone + std::fmt::Display,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (i, (key, value)) in self.entries().iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{key}={value}")?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl<K, V, C> std::fmt::Debug for TreeMap<K, V, C>
+where
+    K: Clone + std::fmt::Debug,
+    V: Clone + std::fmt::Debug,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.entries()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two maps with the same mappings are equal.
+impl<K, V, C, M> PartialEq<M> for TreeMap<K, V, C>
+where
+    K: Clone,
+    V: Clone + PartialEq,
+    C: Comparator<K>,
+    M: Map<K, V>,
+{
+    fn eq(&self, other: &M) -> bool {
+        self.size() == other.size()
+            && self
+                .entries()
+                .into_iter()
+                .all(|(key, value)| other.get(&key) == Some(value))
+    }
+}
+
+/// Java's `Map.hashCode`: the sum of the hash codes of the keys xor'ed with their values'.
+impl<K, V, C> HashCode for TreeMap<K, V, C>
+where
+    K: Clone + HashCode,
+    V: Clone + HashCode,
+    C: Comparator<K>,
+{
+    fn hash_code(&self) -> i32 {
+        self.entries().iter().fold(0i32, |hash, (key, value)| {
+            hash.wrapping_add(key.hash_code() ^ value.hash_code())
+        })
+    }
+}
+
+/// Java's `SubMapIterator`, which walks a map or view in its order up to the entry past
+/// its range.
+struct TreeIterator<K, V, C> {
+    tree: Rc<RefCell<Tree<K, V, C>>>,
+    next: Option<usize>,
+    fence: Option<usize>,
+    last_returned: Option<usize>,
+    expected_mod_count: usize,
+    descending: bool,
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> TreeIterator<K, V, C> {
+    fn new(map: &TreeMap<K, V, C>) -> Self {
+        let tree = map.tree();
+        let descending = map.bounds.descending;
+        let (next, fence) = if descending {
+            (map.abs_highest(&tree), map.abs_low_fence(&tree))
+        } else {
+            (map.abs_lowest(&tree), map.abs_high_fence(&tree))
+        };
+        Self {
+            tree: Rc::clone(&map.tree),
+            next,
+            fence,
+            last_returned: None,
+            expected_mod_count: tree.mod_count,
+            descending,
+        }
+    }
+
+    fn has_next(&self) -> bool {
+        self.next.is_some() && self.next != self.fence
+    }
+
+    fn next_entry(&mut self) -> Option<usize> {
+        if !self.has_next() {
+            return None;
+        }
+        let e = self.next?;
+        let tree = self.tree.borrow();
+        if tree.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        self.next = if self.descending {
+            tree.predecessor(e)
+        } else {
+            tree.successor(e)
+        };
+        self.last_returned = Some(e);
+        Some(e)
+    }
+
+    fn next_mapping<R>(&mut self, f: impl FnOnce(&Entry<K, V>) -> R) -> Option<R> {
+        let e = self.next_entry()?;
+        Some(f(self.tree.borrow().entry(e)))
+    }
+
+    fn remove(&mut self) {
+        let Some(last_returned) = self.last_returned.take() else {
+            IllegalStateException::new(String::new(), None).throw()
+        };
+        let mut tree = self.tree.borrow_mut();
+        if tree.mod_count != self.expected_mod_count {
+            concurrent_modification();
+        }
+        let entry = tree.entry(last_returned);
+        if !self.descending && entry.left.is_some() && entry.right.is_some() {
+            // The entry takes the mapping of its successor, which was next, and may have
+            // been the fence.
+            if self.fence == self.next {
+                self.fence = Some(last_returned);
+            }
+            self.next = Some(last_returned);
+        }
+        tree.delete_entry(last_returned);
+        self.expected_mod_count = tree.mod_count;
+    }
+}
+
+macro_rules! tree_iterator {
+    ($(#[$attr: meta])* $type: ident, $item: ty, $entry: ident => $value: expr) => {
+        $(#[$attr])*
+        ///
+        /// As a Rust iterator it is Java's `hasNext` and `next`: a structural modification
+        /// of the map other than through the iterator makes the next call throw
+        /// `ConcurrentModificationException`.
+        pub struct $type<K, V, C>(TreeIterator<K, V, C>);
+
+        impl<K: Clone, V: Clone, C: Comparator<K>> $type<K, V, C> {
+            pub fn has_next(&self) -> bool {
+                self.0.has_next()
+            }
+
+            /// Removes the mapping last returned by `next`. Throws `IllegalStateException`
+            /// if there is none.
+            pub fn remove(&mut self) {
+                self.0.remove()
+            }
+        }
+
+        impl<K: Clone, V: Clone, C: Comparator<K>> Iterator for $type<K, V, C> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                self.0.next_mapping(|$entry| $value)
+            }
+        }
+    };
+}
+
+tree_iterator!(
+    /// Iterates over the mappings of a `TreeMap`.
+    EntryIterator, (K, V), entry => (entry.key.clone(), entry.value.clone())
+);
+tree_iterator!(
+    /// Iterates over the keys of a `TreeMap` or the elements of a `TreeSet`.
+    KeyIterator, K, entry => entry.key.clone()
+);
+tree_iterator!(
+    /// Iterates over the values of a `TreeMap`.
+    ValueIterator, V, entry => entry.value.clone()
+);
+
+/// The keys of a `TreeMap` in its order, returned by `Map::key_set`, which is Java's
+/// `navigableKeySet`. Removing a key removes its mapping; adding one throws
+/// `UnsupportedOperationException`.
+pub struct KeySet<K, V, C> {
+    map: TreeMap<K, V, C>,
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Collection<K> for KeySet<K, V, C> {
+    type Iter = KeyIterator<K, V, C>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    fn contains(&self, o: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.map.contains_key(o)
+    }
+
+    fn iterator(&self) -> KeyIterator<K, V, C> {
+        KeyIterator(TreeIterator::new(&self.map))
+    }
+
+    fn add(&mut self, _: K) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &K) -> bool
+    where
+        K: PartialEq,
+    {
+        self.map.remove(o).is_some()
+    }
+
+    fn remove_all<C2: Collection<K>>(&mut self, c: &C2) -> bool
+    where
+        K: PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&K) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, KeyIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Set<K> for KeySet<K, V, C> {}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> NavigableSet<K> for KeySet<K, V, C> {
+    fn first(&self) -> K {
+        self.map.first_key()
+    }
+
+    fn last(&self) -> K {
+        self.map.last_key()
+    }
+
+    fn lower(&self, e: &K) -> Option<K> {
+        self.map.lower_key(e)
+    }
+
+    fn floor(&self, e: &K) -> Option<K> {
+        self.map.floor_key(e)
+    }
+
+    fn ceiling(&self, e: &K) -> Option<K> {
+        self.map.ceiling_key(e)
+    }
+
+    fn higher(&self, e: &K) -> Option<K> {
+        self.map.higher_key(e)
+    }
+
+    fn poll_first(&mut self) -> Option<K> {
+        self.map.poll_first_entry().map(|(key, _)| key)
+    }
+
+    fn poll_last(&mut self) -> Option<K> {
+        self.map.poll_last_entry().map(|(key, _)| key)
+    }
+
+    fn descending_set(&self) -> Self {
+        Self {
+            map: self.map.descending_map(),
+        }
+    }
+
+    fn head_set(&self, to_element: K, inclusive: bool) -> Self {
+        Self {
+            map: self.map.head_map(to_element, inclusive),
+        }
+    }
+
+    fn tail_set(&self, from_element: K, inclusive: bool) -> Self {
+        Self {
+            map: self.map.tail_map(from_element, inclusive),
+        }
+    }
+
+    fn sub_set(
+        &self,
+        from_element: K,
+        from_inclusive: bool,
+        to_element: K,
+        to_inclusive: bool,
+    ) -> Self {
+        Self {
+            map: self
+                .map
+                .sub_map(from_element, from_inclusive, to_element, to_inclusive),
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> IntoIterator for &KeySet<K, V, C> {
+    type Item = K;
+    type IntoIter = KeyIterator<K, V, C>;
+
+    fn into_iter(self) -> KeyIterator<K, V, C> {
+        self.iterator()
+    }
+}
+
+impl<K, V, C> std::fmt::Display for KeySet<K, V, C>
+where
+    K: Clone + std::fmt::Display,
+    V: Clone,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, key| write!(f, "{key}"))
+    }
+}
+
+/// The values of a `TreeMap` in the order of their keys, returned by `Map::values`.
+/// Removing a value removes the first mapping to it; adding one throws
+/// `UnsupportedOperationException`.
+pub struct Values<K, V, C> {
+    map: TreeMap<K, V, C>,
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Collection<V> for Values<K, V, C> {
+    type Iter = ValueIterator<K, V, C>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn contains(&self, o: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.map.contains_value(o)
+    }
+
+    fn iterator(&self) -> ValueIterator<K, V, C> {
+        ValueIterator(TreeIterator::new(&self.map))
+    }
+
+    fn add(&mut self, _: V) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        let mut each = self.iterator();
+        while let Some(value) = each.next() {
+            if value == *o {
+                each.remove();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn remove_if<F: FnMut(&V) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, ValueIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> IntoIterator for &Values<K, V, C> {
+    type Item = V;
+    type IntoIter = ValueIterator<K, V, C>;
+
+    fn into_iter(self) -> ValueIterator<K, V, C> {
+        self.iterator()
+    }
+}
+
+impl<K, V, C> std::fmt::Display for Values<K, V, C>
+where
+    K: Clone,
+    V: Clone + std::fmt::Display,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, value| write!(f, "{value}"))
+    }
+}
+
+/// The mappings of a `TreeMap` as `(key, value)` pairs, returned by `Map::entry_set`.
+/// Removing a pair removes the mapping; adding one throws
+/// `UnsupportedOperationException`.
+pub struct EntrySet<K, V, C> {
+    map: TreeMap<K, V, C>,
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> EntrySet<K, V, C> {
+    /// The entry of a pair's key if it maps to the pair's value.
+    fn find(&self, o: &(K, V)) -> Option<usize>
+    where
+        (K, V): PartialEq,
+    {
+        let tree = self.map.tree();
+        if !self.map.in_range(&tree, &o.0) {
+            return None;
+        }
+        let e = tree.get_entry(&o.0)?;
+        ((o.0.clone(), tree.entry(e).value.clone()) == *o).then_some(e)
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Collection<(K, V)> for EntrySet<K, V, C> {
+    type Iter = EntryIterator<K, V, C>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn contains(&self, o: &(K, V)) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        self.find(o).is_some()
+    }
+
+    fn iterator(&self) -> EntryIterator<K, V, C> {
+        self.map.iterator()
+    }
+
+    fn add(&mut self, _: (K, V)) -> bool {
+        collection::unsupported()
+    }
+
+    fn remove(&mut self, o: &(K, V)) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        let Some(e) = self.find(o) else {
+            return false;
+        };
+        self.map.tree_mut().delete_entry(e);
+        true
+    }
+
+    fn remove_all<C2: Collection<(K, V)>>(&mut self, c: &C2) -> bool
+    where
+        (K, V): PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&(K, V)) -> bool>(&mut self, filter: F) -> bool {
+        collection::remove_if(self.iterator(), filter, EntryIterator::remove)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> Set<(K, V)> for EntrySet<K, V, C> {}
+
+impl<K: Clone, V: Clone, C: Comparator<K>> IntoIterator for &EntrySet<K, V, C> {
+    type Item = (K, V);
+    type IntoIter = EntryIterator<K, V, C>;
+
+    fn into_iter(self) -> EntryIterator<K, V, C> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`, with each mapping as `key=value`.
+impl<K, V, C> std::fmt::Display for EntrySet<K, V, C>
+where
+    K: Clone + std::fmt::Display,
+    V: Clone + std::fmt::Display,
+    C: Comparator<K>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        collection::fmt_elements(f, self.iterator(), |f, (key, value)| {
+            write!(f, "{key}={value}")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::errors::ClassCastException;
+
+    fn message<R>(f: impl FnOnce() -> R) -> String {
+        match catch_unwind(AssertUnwindSafe(f)) {
+            Ok(_) => panic!("nothing was thrown"),
+            Err(error) => *error.downcast::<String>().unwrap(),
+        }
+    }
+
+    fn squares() -> TreeMap<i32, i32> {
+        (1..=9).map(|i| (i * 10, i * i)).collect()
+    }
+
+    #[test]
+    fn navigation() {
+        let mut map = squares();
+        assert_eq!((map.first_key(), map.last_key()), (10, 90));
+        assert_eq!(map.lower_entry(&50), Some((40, 16)));
+        assert_eq!(map.floor_entry(&55), Some((50, 25)));
+        assert_eq!(map.ceiling_key(&55), Some(60));
+        assert_eq!(map.higher_key(&90), None);
+        assert_eq!(map.poll_first_entry(), Some((10, 1)));
+        assert_eq!(map.poll_last_entry(), Some((90, 81)));
+        assert_eq!(
+            map.to_string(),
+            "{20=4, 30=9, 40=16, 50=25, 60=36, 70=49, 80=64}"
+        );
+        assert_eq!(message(|| TreeMap::<i32, i32>::new().first_key()), "");
+    }
+
+    #[test]
+    fn views() {
+        let map = squares();
+        let mut sub = map.sub_map(20, true, 50, false);
+        assert_eq!(sub.to_string(), "{20=4, 30=9, 40=16}");
+        assert_eq!(sub.put(25, 0), None);
+        assert_eq!(message(|| sub.put(50, 0)), "key out of range");
+        assert_eq!(message(|| sub.merge(5, 0, |_, _| None)), "key out of range");
+        assert_eq!(sub.get(&60), None);
+        assert_eq!(sub.remove(&90), None);
+        assert_eq!(message(|| sub.tail_map(10, true)), "fromKey out of range");
+        assert_eq!(message(|| sub.head_map(50, true)), "toKey out of range");
+        assert_eq!(sub.head_map(50, false).size(), 4);
+        assert_eq!(
+            message(|| map.sub_map(50, true, 20, true)),
+            "fromKey > toKey"
+        );
+
+        let mut head = map.head_map(30, true);
+        head.clear();
+        assert_eq!(sub.to_string(), "{40=16}");
+        assert_eq!(map.first_key(), 40);
+        assert_eq!(
+            map.tail_map(60, false).key_set().to_string(),
+            "[70, 80, 90]"
+        );
+    }
+
+    #[test]
+    fn descending() {
+        let map = squares();
+        let descending = map.descending_map();
+        assert_eq!(descending.first_key(), 90);
+        assert_eq!(descending.higher_key(&50), Some(40));
+        let head = descending.head_map(70, true);
+        assert_eq!(head.to_string(), "{90=81, 80=64, 70=49}");
+        assert_eq!(head.descending_map().to_string(), "{70=49, 80=64, 90=81}");
+        assert_eq!(
+            message(|| descending.sub_map(20, true, 50, true)),
+            "fromKey > toKey"
+        );
+        assert_eq!(
+            map.descending_key_set().tail_set(30, false).to_string(),
+            "[20, 10]"
+        );
+    }
+
+    #[test]
+    fn iterator() {
+        let mut map = squares();
+        let mut each = map.key_set().iterator();
+        while let Some(key) = each.next() {
+            if key % 20 == 0 {
+                each.remove();
+            }
+        }
+        assert_eq!(map.key_set().to_string(), "[10, 30, 50, 70, 90]");
+        assert_eq!(message(|| map.iterator().remove()), "");
+
+        let mut each = map.iterator();
+        each.next();
+        map.put(100, 100);
+        assert!(each.has_next());
+        assert_eq!(message(|| each.next()), "");
+        let mut view = map.descending_map();
+        assert_eq!(
+            message(|| map.compute_if_absent(0, |_| view.put(60, 0).unwrap_or(0))),
+            ""
+        );
+        assert_eq!(map.get(&0), None);
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Key {
+        Number(i32),
+        Text(&'static str),
+    }
+
+    /// Compares keys like Java's natural order of `Integer` and `String` keys in a raw map:
+    /// a number and a text throw `ClassCastException`.
+    fn compare_keys(a: &Key, b: &Key) -> i32 {
+        match (a, b) {
+            (Key::Number(a), Key::Number(b)) => a.cmp(b) as i32,
+            (Key::Text(a), Key::Text(b)) => a.cmp(b) as i32,
+            _ => ClassCastException::new("incomparable keys".to_string()).throw(),
+        }
+    }
+
+    #[test]
+    fn incomparable_keys() {
+        let mut map = TreeMap::with_comparator(compare_keys);
+        map.put(Key::Number(2), "two");
+        map.put(Key::Number(1), "one");
+        assert_eq!(
+            message(|| map.put(Key::Text("three"), "three")),
+            "incomparable keys"
+        );
+        assert_eq!(message(|| map.get(&Key::Text("one"))), "incomparable keys");
+        assert_eq!(map.size(), 2);
+        map.put(Key::Number(3), "three");
+        assert_eq!(
+            map.values().iterator().collect::<Vec<_>>(),
+            ["one", "two", "three"]
+        );
+
+        let mut map = TreeMap::with_comparator(|a: &Key, b: &Key| match (a, b) {
+            (Key::Text(_), _) | (_, Key::Text(_)) => {
+                ClassCastException::new("not comparable".to_string()).throw()
+            }
+            _ => compare_keys(a, b),
+        });
+        assert_eq!(message(|| map.put(Key::Text("one"), 1)), "not comparable");
+        assert!(map.is_empty());
+        assert_eq!(map.put(Key::Number(1), 1), None);
+    }
+}
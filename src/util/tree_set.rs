@@ -0,0 +1,253 @@
+use crate::util::collection;
+use crate::util::tree_map::KeyIterator;
+use crate::util::{
+    Collection, Comparator, Map, NaturalOrder, NavigableMap, NavigableSet, SequencedMap, Set,
+    TreeMap,
+};
+use crate::HashCode;
+
+/// `java.util.TreeSet`: a set backed by a `TreeMap`, sorted by a `Comparator` of its
+/// elements, their natural order by default.
+///
+/// Like the map's, the `NavigableSet` views share the set and throw
+/// `IllegalArgumentException` for elements out of their range, and the iterators fail
+/// fast. `clone` copies the elements into a new set.
+pub struct TreeSet<E, C = NaturalOrder<E>> {
+    map: TreeMap<E, (), C>,
+}
+
+impl<E: Clone + Ord> TreeSet<E> {
+    /// An empty set sorted by the natural order of the elements.
+    pub fn new() -> Self {
+        Self {
+            map: TreeMap::new(),
+        }
+    }
+
+    /// Java's `new TreeSet<>(c)`: the elements of a collection, sorted by their natural
+    /// order.
+    pub fn from_collection<C: Collection<E>>(c: &C) -> Self {
+        let mut set = Self::new();
+        set.add_all(c);
+        set
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> TreeSet<E, C> {
+    /// An empty set sorted by the comparator.
+    pub fn with_comparator(comparator: C) -> Self {
+        Self {
+            map: TreeMap::with_comparator(comparator),
+        }
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Collection<E> for TreeSet<E, C> {
+    type Iter = KeyIterator<E, (), C>;
+
+    fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    fn contains(&self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.map.contains_key(o)
+    }
+
+    fn iterator(&self) -> KeyIterator<E, (), C> {
+        self.map.key_set().iterator()
+    }
+
+    /// Throws `IllegalArgumentException` if the element is out of a view's range.
+    fn add(&mut self, e: E) -> bool {
+        self.map.put(e, ()).is_none()
+    }
+
+    fn remove(&mut self, o: &E) -> bool
+    where
+        E: PartialEq,
+    {
+        self.map.remove(o).is_some()
+    }
+
+    fn remove_all<C2: Collection<E>>(&mut self, c: &C2) -> bool
+    where
+        E: PartialEq,
+    {
+        collection::remove_all(self, c)
+    }
+
+    fn remove_if<F: FnMut(&E) -> bool>(&mut self, filter: F) -> bool {
+        self.map.key_set().remove_if(filter)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Set<E> for TreeSet<E, C> {}
+
+impl<E: Clone, C: Comparator<E>> NavigableSet<E> for TreeSet<E, C> {
+    fn first(&self) -> E {
+        self.map.first_key()
+    }
+
+    fn last(&self) -> E {
+        self.map.last_key()
+    }
+
+    fn lower(&self, e: &E) -> Option<E> {
+        self.map.lower_key(e)
+    }
+
+    fn floor(&self, e: &E) -> Option<E> {
+        self.map.floor_key(e)
+    }
+
+    fn ceiling(&self, e: &E) -> Option<E> {
+        self.map.ceiling_key(e)
+    }
+
+    fn higher(&self, e: &E) -> Option<E> {
+        self.map.higher_key(e)
+    }
+
+    fn poll_first(&mut self) -> Option<E> {
+        self.map.poll_first_entry().map(|(e, _)| e)
+    }
+
+    fn poll_last(&mut self) -> Option<E> {
+        self.map.poll_last_entry().map(|(e, _)| e)
+    }
+
+    fn descending_set(&self) -> Self {
+        Self {
+            map: self.map.descending_map(),
+        }
+    }
+
+    fn head_set(&self, to_element: E, inclusive: bool) -> Self {
+        Self {
+            map: self.map.head_map(to_element, inclusive),
+        }
+    }
+
+    fn tail_set(&self, from_element: E, inclusive: bool) -> Self {
+        Self {
+            map: self.map.tail_map(from_element, inclusive),
+        }
+    }
+
+    fn sub_set(
+        &self,
+        from_element: E,
+        from_inclusive: bool,
+        to_element: E,
+        to_inclusive: bool,
+    ) -> Self {
+        Self {
+            map: self
+                .map
+                .sub_map(from_element, from_inclusive, to_element, to_inclusive),
+        }
+    }
+}
+
+impl<E: Clone + Ord> Default for TreeSet<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Clone, C: Comparator<E> + Clone> Clone for TreeSet<E, C> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<E: Clone + Ord> FromIterator<E> for TreeSet<E> {
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> Extend<E> for TreeSet<E, C> {
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for e in iter {
+            self.add(e);
+        }
+    }
+}
+
+impl<E: Clone, C: Comparator<E>> IntoIterator for &TreeSet<E, C> {
+    type Item = E;
+    type IntoIter = KeyIterator<E, (), C>;
+
+    fn into_iter(self) -> KeyIterator<E, (), C> {
+        self.iterator()
+    }
+}
+
+/// Like Java's `toString`: the elements in brackets, separated by `, `.
+impl<E: Clone + std::fmt::Display, C: Comparator<E>> std::fmt::Display for TreeSet<E, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.map.key_set().fmt(f)
+    }
+}
+
+impl<E: Clone + std::fmt::Debug, C: Comparator<E>> std::fmt::Debug for TreeSet<E, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iterator()).finish()
+    }
+}
+
+/// Like Java's `equals`, any two sets with the same elements are equal.
+impl<E: Clone + PartialEq, C: Comparator<E>, S: Set<E>> PartialEq<S> for TreeSet<E, C> {
+    fn eq(&self, other: &S) -> bool {
+        self.size() == other.size() && self.contains_all(other)
+    }
+}
+
+/// Java's `Set.hashCode`: the sum of the hash codes of the elements.
+impl<E: Clone + HashCode, C: Comparator<E>> HashCode for TreeSet<E, C> {
+    fn hash_code(&self) -> i32 {
+        self.iterator()
+            .fold(0i32, |hash, e| hash.wrapping_add(e.hash_code()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn navigation() {
+        let set: TreeSet<i32> = [50, 10, 40, 20, 30].into_iter().collect();
+        assert_eq!(set.to_string(), "[10, 20, 30, 40, 50]");
+        assert_eq!((set.first(), set.last()), (10, 50));
+        assert_eq!(set.lower(&30), Some(20));
+        assert_eq!(set.floor(&35), Some(30));
+        assert_eq!(set.ceiling(&35), Some(40));
+        assert_eq!(set.higher(&50), None);
+        assert_eq!(set.descending_set().to_string(), "[50, 40, 30, 20, 10]");
+        assert_eq!(
+            set.descending_iterator().collect::<Vec<_>>(),
+            [50, 40, 30, 20, 10]
+        );
+        let mut sub = set.sub_set(20, true, 40, false);
+        assert_eq!(sub.to_string(), "[20, 30]");
+        assert_eq!(sub.poll_first(), Some(20));
+        assert_eq!(set.to_string(), "[10, 30, 40, 50]");
+    }
+}
@@ -0,0 +1,481 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::RandomState;
+use std::fmt::Display;
+use std::fs::File;
+use std::hash::BuildHasher;
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use crate::errors::{IllegalArgumentException, Throwable, UnsupportedOperationException};
+
+/// `java.util.UUID`: an immutable 128-bit value stored as two signed longs, like the JDK.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UUID {
+    most_sig_bits: i64,
+    least_sig_bits: i64,
+}
+
+static RANDOM_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 16 random bytes from `/dev/urandom`, the source of the JDK's default `SecureRandom` on
+/// Unix.
+///
+/// Where it can't be read, as on Windows, the bytes come from the OS-seeded keys behind
+/// `RandomState` instead. Those are unpredictable but not from a cryptographic generator,
+/// so such UUIDs must not be used as secrets.
+fn random_bytes() -> [u8; 16] {
+    let mut bytes = [0; 16];
+    if File::open("/dev/urandom")
+        .and_then(|mut urandom| urandom.read_exact(&mut bytes))
+        .is_ok()
+    {
+        return bytes;
+    }
+
+    let state = RandomState::new();
+    let counter = RANDOM_COUNTER.fetch_add(1, AtomicOrdering::Relaxed);
+    let high = state.hash_one((counter, 0u8));
+    let low = state.hash_one((counter, 1u8));
+    bytes[..8].copy_from_slice(&high.to_be_bytes());
+    bytes[8..].copy_from_slice(&low.to_be_bytes());
+    bytes
+}
+
+fn invalid(name: &str) -> IllegalArgumentException {
+    IllegalArgumentException::new(format!("Invalid UUID string: {name}"), None)
+}
+
+/// `Long.parseLong(s, begin, end, 16)`, including its optional sign and its error messages.
+///
+/// `NumberFormatException` is a subclass of `IllegalArgumentException` in Java, so failures
+/// are reported as the latter with the former's message.
+fn parse_hex(units: &[u16], begin: usize, end: usize) -> Result<i64, IllegalArgumentException> {
+    let error_at = |index: usize| {
+        IllegalArgumentException::new(
+            format!(
+                "Error at index {} in: \"{}\"",
+                index - begin,
+                String::from_utf16_lossy(&units[begin..end])
+            ),
+            None,
+        )
+    };
+
+    if begin >= end {
+        return Err(IllegalArgumentException::new(String::new(), None));
+    }
+
+    let mut i = begin;
+    let mut negative = false;
+    let mut limit = -i64::MAX;
+    if units[i] < b'0' as u16 {
+        if units[i] == b'-' as u16 {
+            negative = true;
+            limit = i64::MIN;
+        } else if units[i] != b'+' as u16 {
+            return Err(error_at(i));
+        }
+        i += 1;
+        if i >= end {
+            return Err(error_at(i));
+        }
+    }
+
+    let mult_min = limit / 16;
+    let mut result: i64 = 0;
+    while i < end {
+        let digit = char::from_u32(units[i] as u32)
+            .and_then(|c| c.to_digit(16))
+            .map(|d| d as i64);
+        match digit {
+            Some(digit) if result >= mult_min && result * 16 >= limit + digit => {
+                result = result * 16 - digit;
+            }
+            _ => return Err(error_at(i)),
+        }
+        i += 1;
+    }
+    Ok(if negative { result } else { -result })
+}
+
+impl UUID {
+    pub fn new(most_sig_bits: i64, least_sig_bits: i64) -> Self {
+        Self {
+            most_sig_bits,
+            least_sig_bits,
+        }
+    }
+
+    fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self::new(
+            i64::from_be_bytes(bytes[..8].try_into().unwrap()),
+            i64::from_be_bytes(bytes[8..].try_into().unwrap()),
+        )
+    }
+
+    /// A version 4 (random) UUID.
+    pub fn random_uuid() -> Self {
+        let mut bytes = random_bytes();
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Self::from_bytes(bytes)
+    }
+
+    /// A version 3 (MD5 name-based) UUID, identical to the JDK's for the same bytes.
+    pub fn name_uuid_from_bytes(name: &[u8]) -> Self {
+        let mut bytes = md5(name);
+        bytes[6] = (bytes[6] & 0x0f) | 0x30;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Self::from_bytes(bytes)
+    }
+
+    /// Parses a UUID exactly as `UUID.fromString` does.
+    ///
+    /// Java accepts anything with five dash-separated hex fields, padding short fields and
+    /// truncating long ones, so `"1-2-3-4-5"` is `00000001-0002-0003-0004-000000000005`.
+    /// Use `from_string_strict` to only accept the canonical form.
+    pub fn from_string(name: &str) -> Result<Self, IllegalArgumentException> {
+        let units: Vec<u16> = name.encode_utf16().collect();
+        let len = units.len();
+        if len > 36 {
+            return Err(IllegalArgumentException::new(
+                "UUID string too large".to_string(),
+                None,
+            ));
+        }
+
+        let dash = |from: usize| {
+            units
+                .iter()
+                .skip(from)
+                .position(|&unit| unit == b'-' as u16)
+                .map(|index| index + from)
+        };
+        let dashes = dash(0).and_then(|dash1| {
+            let dash2 = dash(dash1 + 1)?;
+            let dash3 = dash(dash2 + 1)?;
+            let dash4 = dash(dash3 + 1)?;
+            Some((dash1, dash2, dash3, dash4))
+        });
+        let Some((dash1, dash2, dash3, dash4)) = dashes else {
+            return Err(invalid(name));
+        };
+        if dash(dash4 + 1).is_some() {
+            return Err(invalid(name));
+        }
+
+        let mut most_sig_bits = parse_hex(&units, 0, dash1)? & 0xffff_ffff;
+        most_sig_bits <<= 16;
+        most_sig_bits |= parse_hex(&units, dash1 + 1, dash2)? & 0xffff;
+        most_sig_bits <<= 16;
+        most_sig_bits |= parse_hex(&units, dash2 + 1, dash3)? & 0xffff;
+        let mut least_sig_bits = parse_hex(&units, dash3 + 1, dash4)? & 0xffff;
+        least_sig_bits <<= 48;
+        least_sig_bits |= parse_hex(&units, dash4 + 1, len)? & 0xffff_ffff_ffff;
+
+        Ok(Self::new(most_sig_bits, least_sig_bits))
+    }
+
+    /// Parses only the canonical `8-4-4-4-12` hex form (either case).
+    pub fn from_string_strict(name: &str) -> Result<Self, IllegalArgumentException> {
+        let bytes = name.as_bytes();
+        let well_formed = bytes.len() == 36
+            && bytes.iter().enumerate().all(|(i, &b)| match i {
+                8 | 13 | 18 | 23 => b == b'-',
+                _ => b.is_ascii_hexdigit(),
+            });
+        if !well_formed {
+            return Err(invalid(name));
+        }
+
+        let hex: String = name.chars().filter(|&c| c != '-').collect();
+        let value = u128::from_str_radix(&hex, 16).map_err(|_| invalid(name))?;
+        Ok(Self::new((value >> 64) as i64, value as i64))
+    }
+
+    pub fn get_most_significant_bits(&self) -> i64 {
+        self.most_sig_bits
+    }
+
+    pub fn get_least_significant_bits(&self) -> i64 {
+        self.least_sig_bits
+    }
+
+    /// 1 = time-based, 2 = DCE security, 3 = MD5 name-based, 4 = random.
+    pub fn version(&self) -> i32 {
+        ((self.most_sig_bits >> 12) & 0x0f) as i32
+    }
+
+    /// 0 = NCS, 2 = IETF (Leach-Salz), 6 = Microsoft, 7 = reserved.
+    pub fn variant(&self) -> i32 {
+        let lsb = self.least_sig_bits;
+        let shift = 64 - ((lsb as u64) >> 62) as u32;
+        ((lsb as u64).wrapping_shr(shift) as i64 & (lsb >> 63)) as i32
+    }
+
+    fn check_time_based(&self) {
+        if self.version() != 1 {
+            UnsupportedOperationException::new("Not a time-based UUID".to_string(), None).throw()
+        }
+    }
+
+    /// The 60-bit timestamp of a version 1 UUID; throws `UnsupportedOperationException`
+    /// for any other version.
+    pub fn timestamp(&self) -> i64 {
+        self.check_time_based();
+        let msb = self.most_sig_bits;
+        ((msb & 0x0fff) << 48) | (((msb >> 16) & 0xffff) << 32) | ((msb as u64) >> 32) as i64
+    }
+
+    /// The clock sequence of a version 1 UUID; throws `UnsupportedOperationException`
+    /// for any other version.
+    pub fn clock_sequence(&self) -> i32 {
+        self.check_time_based();
+        (((self.least_sig_bits & 0x3fff_0000_0000_0000) as u64) >> 48) as i32
+    }
+
+    /// The node of a version 1 UUID; throws `UnsupportedOperationException` for any other
+    /// version.
+    pub fn node(&self) -> i64 {
+        self.check_time_based();
+        self.least_sig_bits & 0x0000_ffff_ffff_ffff
+    }
+
+    pub fn hash_code(&self) -> i32 {
+        let hilo = self.most_sig_bits ^ self.least_sig_bits;
+        ((hilo >> 32) as i32) ^ (hilo as i32)
+    }
+
+    /// Compares the two halves as *signed* longs, like the JDK. This does not match the
+    /// unsigned lexical order of the string forms: `80000000-...` sorts before `00000001-...`.
+    pub fn compare_to(&self, other: &Self) -> i32 {
+        match self.cmp(other) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+}
+
+impl PartialOrd for UUID {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UUID {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.most_sig_bits
+            .cmp(&other.most_sig_bits)
+            .then(self.least_sig_bits.cmp(&other.least_sig_bits))
+    }
+}
+
+impl Display for UUID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msb = self.most_sig_bits as u64;
+        let lsb = self.least_sig_bits as u64;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            msb >> 32,
+            (msb >> 16) & 0xffff,
+            msb & 0xffff,
+            lsb >> 48,
+            lsb & 0xffff_ffff_ffff
+        )
+    }
+}
+
+impl FromStr for UUID {
+    type Err = IllegalArgumentException;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s)
+    }
+}
+
+/// RFC 1321 MD5, as used by `name_uuid_from_bytes`.
+fn md5(input: &[u8]) -> [u8; 16] {
+    const S: [u32; 64] = [
+        7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5,
+        9, 14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10,
+        15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+    ];
+
+    const K: [u32; 64] = [
+        0xd76a_a478,
+        0xe8c7_b756,
+        0x2420_70db,
+        0xc1bd_ceee,
+        0xf57c_0faf,
+        0x4787_c62a,
+        0xa830_4613,
+        0xfd46_9501,
+        0x6980_98d8,
+        0x8b44_f7af,
+        0xffff_5bb1,
+        0x895c_d7be,
+        0x6b90_1122,
+        0xfd98_7193,
+        0xa679_438e,
+        0x49b4_0821,
+        0xf61e_2562,
+        0xc040_b340,
+        0x265e_5a51,
+        0xe9b6_c7aa,
+        0xd62f_105d,
+        0x0244_1453,
+        0xd8a1_e681,
+        0xe7d3_fbc8,
+        0x21e1_cde6,
+        0xc337_07d6,
+        0xf4d5_0d87,
+        0x455a_14ed,
+        0xa9e3_e905,
+        0xfcef_a3f8,
+        0x676f_02d9,
+        0x8d2a_4c8a,
+        0xfffa_3942,
+        0x8771_f681,
+        0x6d9d_6122,
+        0xfde5_380c,
+        0xa4be_ea44,
+        0x4bde_cfa9,
+        0xf6bb_4b60,
+        0xbebf_bc70,
+        0x289b_7ec6,
+        0xeaa1_27fa,
+        0xd4ef_3085,
+        0x0488_1d05,
+        0xd9d4_d039,
+        0xe6db_99e5,
+        0x1fa2_7cf8,
+        0xc4ac_5665,
+        0xf429_2244,
+        0x432a_ff97,
+        0xab94_23a7,
+        0xfc93_a039,
+        0x655b_59c3,
+        0x8f0c_cc92,
+        0xffef_f47d,
+        0x8584_5dd1,
+        0x6fa8_7e4f,
+        0xfe2c_e6e0,
+        0xa301_4314,
+        0x4e08_11a1,
+        0xf753_7e82,
+        0xbd3a_f235,
+        0x2ad7_d2bb,
+        0xeb86_d391,
+    ];
+
+    let mut message = input.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((input.len() as u64).wrapping_mul(8)).to_le_bytes());
+
+    let mut state: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+    for chunk in message.chunks(64) {
+        let m: Vec<u32> = chunk
+            .chunks(4)
+            .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+            .collect();
+        let [mut a, mut b, mut c, mut d] = state;
+        for i in 0..64 {
+            let (f, g) = match i / 16 {
+                0 => ((b & c) | (!b & d), i),
+                1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                2 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16),
+            };
+            let rotated = a
+                .wrapping_add(f)
+                .wrapping_add(K[i])
+                .wrapping_add(m[g])
+                .rotate_left(S[i]);
+            a = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(rotated);
+        }
+        for (word, value) in state.iter_mut().zip([a, b, c, d]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0; 16];
+    for (bytes, word) in digest.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_matches_jdk() {
+        let uuid = UUID::from_string("1-2-3-4-5").unwrap();
+        assert_eq!(uuid.to_string(), "00000001-0002-0003-0004-000000000005");
+        assert_eq!(UUID::from_string("+1-2-3-4-5").unwrap(), uuid);
+        assert!(UUID::from_string_strict("1-2-3-4-5").is_err());
+
+        let canonical = "123e4567-e89b-12d3-a456-426614174000";
+        assert_eq!(
+            UUID::from_string(canonical).unwrap(),
+            UUID::from_string_strict(canonical).unwrap()
+        );
+
+        for (input, message) in [
+            ("-1-2-3-4-5", "Invalid UUID string: -1-2-3-4-5"),
+            ("1--3-4-5", ""),
+            ("1-2-3-4-5-6", "Invalid UUID string: 1-2-3-4-5-6"),
+            ("g-2-3-4-5", "Error at index 0 in: \"g\""),
+            (
+                "1-2-3-4-12345678901234567",
+                "Error at index 16 in: \"12345678901234567\"",
+            ),
+            (
+                "0000000000000000000000000000000000000",
+                "UUID string too large",
+            ),
+        ] {
+            assert_eq!(UUID::from_string(input).unwrap_err().message(), message);
+        }
+    }
+
+    #[test]
+    fn fields_match_jdk() {
+        let uuid = UUID::name_uuid_from_bytes(b"hello");
+        assert_eq!(uuid.to_string(), "5d41402a-bc4b-3a76-b971-9d911017c592");
+        assert_eq!((uuid.version(), uuid.variant()), (3, 2));
+        assert_eq!(uuid.hash_code(), 1215046239);
+        assert_eq!(
+            UUID::name_uuid_from_bytes(b"").to_string(),
+            "d41d8cd9-8f00-3204-a980-0998ecf8427e"
+        );
+
+        let time_based = UUID::from_string("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+        assert_eq!(time_based.timestamp(), 138648505420000000);
+        assert_eq!(time_based.clock_sequence(), 13256);
+        assert_eq!(time_based.node(), 175285648414790);
+
+        let random = UUID::random_uuid();
+        assert_eq!((random.version(), random.variant()), (4, 2));
+        assert_ne!(random, UUID::random_uuid());
+
+        assert_eq!(UUID::new(i64::MIN, 0).compare_to(&UUID::new(1, 0)), -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a time-based UUID")]
+    fn timestamp_of_random_uuid() {
+        UUID::random_uuid().timestamp();
+    }
+}
@@ -0,0 +1,298 @@
+import java.io.*;
+import java.nio.charset.StandardCharsets;
+import java.util.*;
+
+/**
+ * Regenerates src/character/tables.rs and src/character/names.dat from the running JDK's
+ * java.lang.Character data, so the Rust implementation matches that JDK's Unicode version.
+ *
+ * Run from the repository root with:
+ * java --add-exports java.base/sun.text=ALL-UNNAMED tools/GenCharacterTables.java
+ * (the export is for the canonical combining classes, which Character does not expose).
+ */
+public class GenCharacterTables {
+    static final int MAX = Character.MAX_CODE_POINT;
+
+    interface Property {
+        boolean test(int cp);
+    }
+
+    public static void main(String[] args) throws IOException {
+        StringBuilder out = new StringBuilder();
+        out.append("// Generated by tools/GenCharacterTables.java from JDK ")
+            .append(Runtime.version().feature())
+            .append(". Do not edit by hand.\n\n");
+        out.append("pub(super) const UNICODE_VERSION: &str = \"").append(unicodeVersion()).append("\";\n\n");
+
+        categories(out);
+        numericValues(out);
+        ranges(out, "OTHER_UPPERCASE", cp -> Character.isUpperCase(cp) && Character.getType(cp) != Character.UPPERCASE_LETTER);
+        ranges(out, "OTHER_LOWERCASE", cp -> Character.isLowerCase(cp) && Character.getType(cp) != Character.LOWERCASE_LETTER);
+        ranges(out, "OTHER_ALPHABETIC", cp -> Character.isAlphabetic(cp) && !Character.isLetter(cp)
+            && Character.getType(cp) != Character.LETTER_NUMBER);
+        ranges(out, "IDEOGRAPHIC", Character::isIdeographic);
+        ranges(out, "MIRRORED", Character::isMirrored);
+        caseMapping(out, "UPPER_CASE", cp -> Character.toUpperCase(cp) != cp, Character::toUpperCase);
+        caseMapping(out, "LOWER_CASE", cp -> Character.toLowerCase(cp) != cp, Character::toLowerCase);
+        // Only where title case differs from upper case, which is the fallback.
+        caseMapping(out, "TITLE_CASE", cp -> Character.toTitleCase(cp) != Character.toUpperCase(cp),
+            Character::toTitleCase);
+        upperCaseExpansions(out);
+        combiningClasses(out);
+        blocks(out);
+        scripts(out);
+
+        try (Writer writer = new OutputStreamWriter(new FileOutputStream("src/character/tables.rs"), StandardCharsets.UTF_8)) {
+            writer.write(out.toString());
+        }
+        try (OutputStream stream = new BufferedOutputStream(new FileOutputStream("src/character/names.dat"))) {
+            names(new DataOutputStream(stream));
+        }
+    }
+
+    static String unicodeVersion() {
+        // Characters first assigned in each Unicode version, newest first.
+        int[][] probes = {{0x1FAE8, 15}, {0x1FAE0, 14}, {0x1FAD0, 13}, {0x1FA70, 12}};
+        for (int[] probe : probes) {
+            if (Character.isDefined(probe[0])) {
+                return probe[1] + ".0";
+            }
+        }
+        return "11.0";
+    }
+
+    static String hex(int cp) {
+        return String.format("0x%04X", cp);
+    }
+
+    static void categories(StringBuilder out) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; ) {
+            int type = Character.getType(cp);
+            int end = cp;
+            while (end < MAX && Character.getType(end + 1) == type) {
+                end++;
+            }
+            if (type != Character.UNASSIGNED) {
+                rows.add("(" + hex(cp) + ", " + hex(end) + ", " + type + ")");
+            }
+            cp = end + 1;
+        }
+        table(out, "CATEGORIES", "(u32, u32, i8)", rows);
+    }
+
+    static void numericValues(StringBuilder out) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            int value = Character.getNumericValue(cp);
+            if (value == -1) {
+                continue;
+            }
+            int step = cp < MAX && Character.getNumericValue(cp + 1) == value + 1 && value >= 0 ? 1 : 0;
+            int end = cp;
+            while (end < MAX && Character.getNumericValue(end + 1) == Character.getNumericValue(end) + step
+                    && Character.getNumericValue(end + 1) != -1) {
+                end++;
+            }
+            rows.add("(" + hex(cp) + ", " + hex(end) + ", " + value + ", " + step + ")");
+            cp = end;
+        }
+        table(out, "NUMERIC_VALUES", "(u32, u32, i32, i32)", rows);
+    }
+
+    static void ranges(StringBuilder out, String name, Property property) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            if (!property.test(cp)) {
+                continue;
+            }
+            int end = cp;
+            while (end < MAX && property.test(end + 1)) {
+                end++;
+            }
+            rows.add("(" + hex(cp) + ", " + hex(end) + ")");
+            cp = end;
+        }
+        table(out, name, "(u32, u32)", rows);
+    }
+
+    interface Mapping {
+        int map(int cp);
+    }
+
+    static void caseMapping(StringBuilder out, String name, Property include, Mapping mapping) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            if (include.test(cp)) {
+                rows.add("(" + hex(cp) + ", " + hex(mapping.map(cp)) + ")");
+            }
+        }
+        table(out, name, "(u32, u32)", rows);
+    }
+
+    /** Upper cases of more than one char, from SpecialCasing.txt via String.toUpperCase. */
+    static void upperCaseExpansions(StringBuilder out) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            String upper = new String(Character.toChars(cp)).toUpperCase(Locale.ROOT);
+            if (!upper.equals(new String(Character.toChars(Character.toUpperCase(cp))))) {
+                StringJoiner units = new StringJoiner(", ", "&[", "]");
+                for (char unit : upper.toCharArray()) {
+                    units.add(hex(unit));
+                }
+                rows.add("(" + hex(cp) + ", " + units + ")");
+            }
+        }
+        table(out, "UPPER_CASE_EXPANSIONS", "(u32, &[u16])", rows);
+    }
+
+    static void combiningClasses(StringBuilder out) {
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; ) {
+            int combiningClass = sun.text.Normalizer.getCombiningClass(cp);
+            int end = cp;
+            while (end < MAX && sun.text.Normalizer.getCombiningClass(end + 1) == combiningClass) {
+                end++;
+            }
+            if (combiningClass != 0) {
+                rows.add("(" + hex(cp) + ", " + hex(end) + ", " + combiningClass + ")");
+            }
+            cp = end + 1;
+        }
+        table(out, "COMBINING_CLASSES", "(u32, u32, u8)", rows);
+    }
+
+    static String camel(String javaName) {
+        StringBuilder camel = new StringBuilder();
+        for (String part : javaName.split("_")) {
+            camel.append(part.charAt(0)).append(part.substring(1).toLowerCase(Locale.ROOT));
+        }
+        return camel.toString();
+    }
+
+    static void enumeration(StringBuilder out, String doc, String name, List<String> javaNames) {
+        out.append(doc);
+        out.append("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]\n");
+        out.append("pub enum ").append(name).append(" {\n");
+        for (String javaName : javaNames) {
+            out.append("    ").append(camel(javaName)).append(",\n");
+        }
+        out.append("}\n\n");
+        List<String> variants = new ArrayList<>();
+        List<String> names = new ArrayList<>();
+        for (String javaName : javaNames) {
+            variants.add(name + "::" + camel(javaName));
+            names.add("\"" + javaName + "\"");
+        }
+        String constant = name.equals("UnicodeBlock") ? "BLOCK" : "SCRIPT";
+        table(out, "ALL_" + constant + "S", name, variants);
+        table(out, constant + "_NAMES", "&str", names);
+    }
+
+    static void blocks(StringBuilder out) {
+        List<String> javaNames = new ArrayList<>();
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; ) {
+            Character.UnicodeBlock block = Character.UnicodeBlock.of(cp);
+            int end = cp;
+            while (end < MAX && Character.UnicodeBlock.of(end + 1) == block) {
+                end++;
+            }
+            if (block != null) {
+                if (!javaNames.contains(block.toString())) {
+                    javaNames.add(block.toString());
+                }
+                rows.add("(" + hex(cp) + ", " + hex(end) + ", UnicodeBlock::" + camel(block.toString()) + ")");
+            }
+            cp = end + 1;
+        }
+        enumeration(out, "/// A Unicode block, in code point order; `java.lang.Character.UnicodeBlock`.\n",
+            "UnicodeBlock", javaNames);
+        table(out, "BLOCKS", "(u32, u32, UnicodeBlock)", rows);
+    }
+
+    static void scripts(StringBuilder out) {
+        List<String> javaNames = new ArrayList<>();
+        for (Character.UnicodeScript script : Character.UnicodeScript.values()) {
+            javaNames.add(script.name());
+        }
+        List<String> rows = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; ) {
+            Character.UnicodeScript script = Character.UnicodeScript.of(cp);
+            int end = cp;
+            while (end < MAX && Character.UnicodeScript.of(end + 1) == script) {
+                end++;
+            }
+            if (script != Character.UnicodeScript.UNKNOWN) {
+                rows.add("(" + hex(cp) + ", " + hex(end) + ", UnicodeScript::" + camel(script.name()) + ")");
+            }
+            cp = end + 1;
+        }
+        enumeration(out, "/// A Unicode script; `java.lang.Character.UnicodeScript`.\n", "UnicodeScript", javaNames);
+        table(out, "SCRIPTS", "(u32, u32, UnicodeScript)", rows);
+    }
+
+    static void table(StringBuilder out, String name, String type, List<String> rows) {
+        out.append("pub(super) static ").append(name).append(": [").append(type).append("; ")
+            .append(rows.size()).append("] = [\n");
+        for (String row : rows) {
+            out.append("    ").append(row).append(",\n");
+        }
+        out.append("];\n\n");
+    }
+
+    /**
+     * names.dat layout, all integers big-endian:
+     * u16 word count, then each word as u8 length + ASCII bytes;
+     * u32 name count, then each name as u24 code point, u8 word count and its words, each
+     * a single byte below 0x80 or two bytes with the high bit set.
+     * Names equal to Character.getName's block-name fallback are left out.
+     */
+    static void names(DataOutputStream out) throws IOException {
+        Map<String, Integer> frequency = new HashMap<>();
+        TreeMap<Integer, String[]> names = new TreeMap<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            String name = Character.getName(cp);
+            if (name == null) {
+                continue;
+            }
+            Character.UnicodeBlock block = Character.UnicodeBlock.of(cp);
+            String fallback = block == null ? null
+                : block.toString().replace('_', ' ') + " " + Integer.toHexString(cp).toUpperCase(Locale.ROOT);
+            if (name.equals(fallback)) {
+                continue;
+            }
+            String[] words = name.split(" ");
+            names.put(cp, words);
+            for (String word : words) {
+                frequency.merge(word, 1, Integer::sum);
+            }
+        }
+
+        List<String> words = new ArrayList<>(frequency.keySet());
+        words.sort(Comparator.comparing((String word) -> -frequency.get(word)).thenComparing(word -> word));
+        Map<String, Integer> index = new HashMap<>();
+        out.writeShort(words.size());
+        for (String word : words) {
+            index.put(word, index.size());
+            out.writeByte(word.length());
+            out.writeBytes(word);
+        }
+
+        out.writeInt(names.size());
+        for (Map.Entry<Integer, String[]> entry : names.entrySet()) {
+            int cp = entry.getKey();
+            out.writeByte(cp >> 16);
+            out.writeShort(cp & 0xFFFF);
+            out.writeByte(entry.getValue().length);
+            for (String word : entry.getValue()) {
+                int i = index.get(word);
+                if (i < 0x80) {
+                    out.writeByte(i);
+                } else {
+                    out.writeShort(0x8000 | i);
+                }
+            }
+        }
+    }
+}
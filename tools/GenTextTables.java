@@ -0,0 +1,525 @@
+import java.io.*;
+import java.lang.reflect.*;
+import java.nio.charset.StandardCharsets;
+import java.text.*;
+import java.util.*;
+
+/**
+ * Regenerates src/text/tables.rs, src/text/collation.dat and src/text/locales.dat from the
+ * running JDK's java.text.Normalizer, Collator, BreakIterator and locale data, so the Rust
+ * implementation matches that JDK's data.
+ *
+ * Run from the repository root with:
+ * java --add-opens java.base/java.text=ALL-UNNAMED --add-opens java.base/sun.text=ALL-UNNAMED tools/GenTextTables.java
+ * (the opens are for the built collation and break iterator tables, which are not exposed).
+ */
+public class GenTextTables {
+    static final int MAX = Character.MAX_CODE_POINT;
+    static final int HANGUL_FIRST = 0xAC00;
+    static final int HANGUL_LAST = 0xD7A3;
+
+    public static void main(String[] args) throws Exception {
+        StringBuilder out = new StringBuilder();
+        out.append("// Generated by tools/GenTextTables.java from JDK ")
+            .append(Runtime.version().feature())
+            .append(". Do not edit by hand.\n\n");
+        out.append("use super::break_iterator::Rules;\n\n");
+
+        decompositions(out);
+        compositions(out);
+        breakRules(out, "CHARACTER", BreakIterator.getCharacterInstance());
+        breakRules(out, "WORD", BreakIterator.getWordInstance());
+        breakRules(out, "LINE", BreakIterator.getLineInstance());
+        breakRules(out, "SENTENCE", BreakIterator.getSentenceInstance());
+
+        try (Writer writer = new OutputStreamWriter(new FileOutputStream("src/text/tables.rs"), StandardCharsets.UTF_8)) {
+            writer.write(out.toString());
+        }
+        try (OutputStream stream = new BufferedOutputStream(new FileOutputStream("src/text/collation.dat"))) {
+            collation(new DataOutputStream(stream));
+        }
+        try (OutputStream stream = new BufferedOutputStream(new FileOutputStream("src/text/locales.dat"))) {
+            locales(new DataOutputStream(stream));
+        }
+    }
+
+    static String string(int cp) {
+        return new String(Character.toChars(cp));
+    }
+
+    static String hex(int cp) {
+        return String.format("0x%04X", cp);
+    }
+
+    static void table(StringBuilder out, String name, String type, List<String> rows) {
+        out.append("pub(super) static ").append(name).append(": [").append(type).append("; ")
+            .append(rows.size()).append("] = [\n");
+        for (String row : rows) {
+            out.append("    ").append(row).append(",\n");
+        }
+        out.append("];\n\n");
+    }
+
+    /**
+     * The full canonical decomposition of every code point that has one, and the full
+     * compatibility decomposition where it differs, as offsets into one shared array.
+     * Hangul syllables are decomposed algorithmically and left out.
+     */
+    static void decompositions(StringBuilder out) {
+        List<Integer> data = new ArrayList<>();
+        List<String> canonical = new ArrayList<>();
+        List<String> compatibility = new ArrayList<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            if (cp >= HANGUL_FIRST && cp <= HANGUL_LAST || Character.getType(cp) == Character.SURROGATE) {
+                continue;
+            }
+            String s = string(cp);
+            String nfd = Normalizer.normalize(s, Normalizer.Form.NFD);
+            String nfkd = Normalizer.normalize(s, Normalizer.Form.NFKD);
+            if (!nfd.equals(s)) {
+                canonical.add("(" + hex(cp) + ", " + append(data, nfd) + ")");
+            }
+            if (!nfkd.equals(nfd)) {
+                compatibility.add("(" + hex(cp) + ", " + append(data, nfkd) + ")");
+            }
+        }
+        table(out, "CANONICAL_DECOMPOSITIONS", "(u32, u16, u8)", canonical);
+        table(out, "COMPATIBILITY_DECOMPOSITIONS", "(u32, u16, u8)", compatibility);
+
+        out.append("pub(super) static DECOMPOSITION_DATA: [u32; ").append(data.size()).append("] = [\n");
+        for (int i = 0; i < data.size(); i += 8) {
+            out.append("   ");
+            for (int j = i; j < Math.min(i + 8, data.size()); j++) {
+                out.append(' ').append(hex(data.get(j))).append(',');
+            }
+            out.append('\n');
+        }
+        out.append("];\n\n");
+    }
+
+    static String append(List<Integer> data, String decomposition) {
+        int offset = data.size();
+        decomposition.codePoints().forEach(data::add);
+        return offset + ", " + (data.size() - offset);
+    }
+
+    /**
+     * The primary composites as (first, second, composite), sorted by the pair. The second
+     * character of a canonical pair never decomposes further, so each composite is found by
+     * taking one character off its full decomposition and recomposing the rest.
+     */
+    static void compositions(StringBuilder out) {
+        TreeMap<Long, Integer> pairs = new TreeMap<>();
+        for (int cp = 0; cp <= MAX; cp++) {
+            if (cp >= HANGUL_FIRST && cp <= HANGUL_LAST || Character.getType(cp) == Character.SURROGATE) {
+                continue;
+            }
+            String s = string(cp);
+            String nfd = Normalizer.normalize(s, Normalizer.Form.NFD);
+            if (nfd.equals(s) || !Normalizer.normalize(nfd, Normalizer.Form.NFC).equals(s)) {
+                continue;
+            }
+            int[] cps = nfd.codePoints().toArray();
+            boolean found = false;
+            for (int i = cps.length - 1; i > 0 && !found; i--) {
+                StringBuilder rest = new StringBuilder();
+                for (int j = 0; j < cps.length; j++) {
+                    if (j != i) {
+                        rest.appendCodePoint(cps[j]);
+                    }
+                }
+                String first = Normalizer.normalize(rest, Normalizer.Form.NFC);
+                if (first.codePointCount(0, first.length()) == 1
+                    && Normalizer.normalize(first + string(cps[i]), Normalizer.Form.NFC).equals(s)) {
+                    pairs.put(((long) first.codePointAt(0) << 32) | cps[i], cp);
+                    found = true;
+                }
+            }
+            if (!found) {
+                throw new IllegalStateException("no canonical pair for " + hex(cp));
+            }
+        }
+        List<String> rows = new ArrayList<>();
+        pairs.forEach((pair, cp) -> rows.add("(" + hex((int) (pair >>> 32)) + ", " + hex((int) (long) pair) + ", " + hex(cp) + ")"));
+        table(out, "COMPOSITIONS", "(u32, u32, u32)", rows);
+    }
+
+    static Object field(Object object, String name) throws ReflectiveOperationException {
+        for (Class<?> c = object.getClass(); c != null; c = c.getSuperclass()) {
+            try {
+                Field field = c.getDeclaredField(name);
+                field.setAccessible(true);
+                return field.get(object);
+            } catch (NoSuchFieldException e) {
+                // Try the superclass.
+            }
+        }
+        throw new NoSuchFieldException(name);
+    }
+
+    /** The tables of a sun.text.RuleBasedBreakIterator, with its categories as ranges. */
+    static void breakRules(StringBuilder out, String name, BreakIterator iterator) throws Exception {
+        Method lookupCategory = Class.forName("sun.text.RuleBasedBreakIterator").getDeclaredMethod("lookupCategory", int.class);
+        lookupCategory.setAccessible(true);
+        List<String> categories = new ArrayList<>();
+        int start = 0;
+        int category = (int) lookupCategory.invoke(iterator, 0);
+        for (int cp = 1; cp <= MAX + 1; cp++) {
+            int next = cp <= MAX ? (int) lookupCategory.invoke(iterator, cp) : Integer.MIN_VALUE;
+            if (next != category) {
+                categories.add("(" + hex(start) + ", " + hex(cp - 1) + ", " + category + ")");
+                start = cp;
+                category = next;
+            }
+        }
+        table(out, name + "_CATEGORIES", "(u32, u32, i8)", categories);
+
+        out.append("pub(super) static ").append(name).append(": Rules = Rules {\n");
+        out.append("    categories: &").append(name).append("_CATEGORIES,\n");
+        out.append("    num_categories: ").append(field(iterator, "numCategories")).append(",\n");
+        int numCategories = (int) field(iterator, "numCategories");
+        stateTable(out, "state_table", (short[]) field(iterator, "stateTable"), numCategories);
+        stateTable(out, "backwards_state_table", (short[]) field(iterator, "backwardsStateTable"), numCategories);
+        out.append("    end_states: &").append(Arrays.toString((boolean[]) field(iterator, "endStates"))).append(",\n");
+        out.append("    lookahead_states: &").append(Arrays.toString((boolean[]) field(iterator, "lookaheadStates"))).append(",\n");
+        out.append("};\n\n");
+    }
+
+    /** A state table with one row of next states, indexed by category, per line. */
+    static void stateTable(StringBuilder out, String name, short[] table, int numCategories) {
+        out.append("    ").append(name).append(": &[\n");
+        for (int i = 0; i < table.length; i += numCategories) {
+            out.append("       ");
+            for (int j = i; j < i + numCategories; j++) {
+                out.append(' ').append(table[j]).append(',');
+            }
+            out.append('\n');
+        }
+        out.append("    ],\n");
+    }
+
+    /**
+     * collation.dat layout, fixed-size integers big-endian, varints little-endian base 128
+     * with signed ones zigzag encoded:
+     * u8 table count, then each table, starting with the root rules, as
+     * u8 flags (1 French secondary, 2 Thai/Lao vowel swapping), i16 max secondary order,
+     * i16 max tertiary order;
+     * u16 run count, then each run of consecutive code points whose orders step evenly as
+     * varint gap from the previous run, varint length, signed varint order delta from the
+     * previous run's last order and, for runs longer than one, signed varint step;
+     * u16 contraction count, then each as u8 entry count and entries of u8 UTF-16 length,
+     * the u16 units, i32 order and u8 forward flag;
+     * u16 expansion count, then each as u8 length and i32 orders.
+     * Then u8 locale count, each as u8 length + ASCII language tag and u8 table index.
+     *
+     * The JDK's order mapping only keys on the low 18 bits of a code point, so only
+     * code points below 0x40000 are stored.
+     */
+    static void collation(DataOutputStream out) throws Exception {
+        Map<String, Integer> tables = new LinkedHashMap<>();
+        Map<String, Integer> locales = new TreeMap<>();
+        List<RuleBasedCollator> collators = new ArrayList<>();
+        // The root rules come first, as table 0.
+        Locale[] available = Collator.getAvailableLocales();
+        Arrays.sort(available, Comparator.comparing((Locale locale) -> !locale.equals(Locale.ROOT))
+            .thenComparing(Locale::toLanguageTag));
+        for (Locale locale : available) {
+            if (!locale.getVariant().isEmpty() || locale.hasExtensions()) {
+                continue;
+            }
+            RuleBasedCollator collator = (RuleBasedCollator) Collator.getInstance(locale);
+            Integer index = tables.get(collator.getRules());
+            if (index == null) {
+                index = tables.size();
+                tables.put(collator.getRules(), index);
+                collators.add(collator);
+            }
+            locales.put(locale.toLanguageTag(), index);
+        }
+
+        out.writeByte(collators.size());
+        for (RuleBasedCollator collator : collators) {
+            Object built = field(collator, "tables");
+            out.writeByte(((boolean) field(built, "frenchSec") ? 1 : 0) | ((boolean) field(built, "seAsianSwapping") ? 2 : 0));
+            out.writeShort((short) field(built, "maxSecOrder"));
+            out.writeShort((short) field(built, "maxTerOrder"));
+
+            Object mapping = field(built, "mapping");
+            Method elementAt = mapping.getClass().getDeclaredMethod("elementAt", int.class);
+            elementAt.setAccessible(true);
+            List<int[]> entries = new ArrayList<>();
+            for (int cp = 0; cp < 0x40000; cp++) {
+                int order = (int) elementAt.invoke(mapping, cp);
+                if (order != -1) {
+                    entries.add(new int[] {cp, order});
+                }
+            }
+            ByteArrayOutputStream runs = new ByteArrayOutputStream();
+            int runCount = 0;
+            int previousCp = -1;
+            int previousOrder = 0;
+            for (int i = 0; i < entries.size(); ) {
+                int j = i + 1;
+                int step = j < entries.size() ? entries.get(j)[1] - entries.get(i)[1] : 0;
+                while (j < entries.size() && entries.get(j)[0] == entries.get(j - 1)[0] + 1
+                    && entries.get(j)[1] - entries.get(j - 1)[1] == step) {
+                    j++;
+                }
+                varint(runs, entries.get(i)[0] - previousCp - 1);
+                varint(runs, j - i);
+                varint(runs, zigzag(entries.get(i)[1] - previousOrder));
+                if (j - i > 1) {
+                    varint(runs, zigzag(step));
+                }
+                previousCp = entries.get(j - 1)[0];
+                previousOrder = entries.get(j - 1)[1];
+                runCount++;
+                i = j;
+            }
+            out.writeShort(runCount);
+            runs.writeTo(out);
+
+            Vector<?> contractTable = (Vector<?>) field(built, "contractTable");
+            out.writeShort(contractTable == null ? 0 : contractTable.size());
+            if (contractTable != null) {
+                for (Object list : contractTable) {
+                    out.writeByte(((Vector<?>) list).size());
+                    for (Object pair : (Vector<?>) list) {
+                        String entryName = (String) field(pair, "entryName");
+                        out.writeByte(entryName.length());
+                        out.writeChars(entryName);
+                        out.writeInt((int) field(pair, "value"));
+                        out.writeByte((boolean) field(pair, "fwd") ? 1 : 0);
+                    }
+                }
+            }
+
+            Vector<?> expandTable = (Vector<?>) field(built, "expandTable");
+            out.writeShort(expandTable == null ? 0 : expandTable.size());
+            if (expandTable != null) {
+                for (Object list : expandTable) {
+                    int[] orders = (int[]) list;
+                    out.writeByte(orders.length);
+                    for (int order : orders) {
+                        out.writeInt(order);
+                    }
+                }
+            }
+        }
+
+        out.writeByte(locales.size());
+        for (Map.Entry<String, Integer> entry : locales.entrySet()) {
+            out.writeByte(entry.getKey().length());
+            out.writeBytes(entry.getKey());
+            out.writeByte(entry.getValue());
+        }
+    }
+
+    /** Interns the strings of locales.dat; null is written as index 0xFFFF. */
+    static class Strings {
+        final Map<String, Integer> indices = new LinkedHashMap<>();
+
+        int index(String string) {
+            return string == null ? 0xFFFF : indices.computeIfAbsent(string, s -> indices.size());
+        }
+
+        void indices(List<Integer> record, String... strings) {
+            for (String string : strings) {
+                record.add(index(string));
+            }
+        }
+    }
+
+    static final int[] NAME_STYLES = {
+        Calendar.SHORT_FORMAT, Calendar.LONG_FORMAT, Calendar.SHORT_STANDALONE, Calendar.LONG_STANDALONE,
+    };
+
+    /**
+     * Writes the date and number formatting data of every available locale without a
+     * variant or extensions, all strings interned in one table and identical records shared:
+     * u16 string count, then each string as u16 length + UTF-8;
+     * u16 date record count, then each record as u8 flags (1 Buddhist calendar), u8 first
+     * day of week, u8 minimal days in first week and u16 string indices of
+     * the era, month, day of week and AM/PM display names of the locale's calendar, each
+     * in the short, long, short standalone and long standalone styles, then
+     * the FULL, LONG, MEDIUM and SHORT date patterns, the same time patterns and the
+     * date-time patterns for every date style with every time style;
+     * u16 number record count, then each record as i8 currency fraction digits (-1 if unknown) and u16
+     * string indices of the DecimalFormatSymbols fields zeroDigit, groupingSeparator,
+     * decimalSeparator, perMill, percent, digit, patternSeparator, infinity, NaN,
+     * minusSign, currencySymbol, intlCurrencySymbol, monetarySeparator, exponentialSeparator,
+     * perMillText, percentText, minusSignText, monetaryGroupingSeparator, then the
+     * number, integer, percent and currency patterns, the compact number decimal pattern
+     * and plural rules and, for the SHORT and LONG compact styles, u16 pattern count and
+     * the compact patterns;
+     * u16 zone name record count, then each record as u16 entry count and, for each time
+     * zone whose short names in the locale aren't the English ones, u16 index of the zone
+     * and string indices of its short standard and short daylight names;
+     * u16 locale count, each as u16 tag, date record, number record and zone name record
+     * indices, sorted by tag;
+     * u16 time zone count, each as u16 indices of the ID and its English short standard, long
+     * standard, short daylight and long daylight names, sorted by ID.
+     */
+    static void locales(DataOutputStream out) throws Exception {
+        Strings strings = new Strings();
+        Map<List<Integer>, Integer> dateRecords = new LinkedHashMap<>();
+        Map<List<Integer>, Integer> numberRecords = new LinkedHashMap<>();
+        Map<List<Integer>, Integer> zoneRecords = new LinkedHashMap<>();
+        Map<String, int[]> locales = new TreeMap<>();
+        List<String> zoneIds = new ArrayList<>(new TreeSet<>(Arrays.asList(TimeZone.getAvailableIDs())));
+        Set<Locale> available = new HashSet<>(Arrays.asList(DateFormat.getAvailableLocales()));
+        available.addAll(Arrays.asList(NumberFormat.getAvailableLocales()));
+        for (Locale locale : available) {
+            if (!locale.getVariant().isEmpty() || locale.hasExtensions()) {
+                continue;
+            }
+            Calendar calendar = Calendar.getInstance(locale);
+            List<Integer> date = new ArrayList<>();
+            date.add(calendar.getCalendarType().equals("buddhist") ? 1 : 0);
+            date.add(calendar.getFirstDayOfWeek());
+            date.add(calendar.getMinimalDaysInFirstWeek());
+            int[][] fields = {
+                {Calendar.ERA, 0, 2}, {Calendar.MONTH, 0, 12}, {Calendar.DAY_OF_WEEK, 1, 8}, {Calendar.AM_PM, 0, 2},
+            };
+            for (int[] field : fields) {
+                for (int style : NAME_STYLES) {
+                    for (int value = field[1]; value < field[2]; value++) {
+                        calendar.clear();
+                        calendar.set(field[0], value);
+                        strings.indices(date, calendar.getDisplayName(field[0], style, locale));
+                    }
+                }
+            }
+            for (int style = DateFormat.FULL; style <= DateFormat.SHORT; style++) {
+                strings.indices(date, ((SimpleDateFormat) DateFormat.getDateInstance(style, locale)).toPattern());
+            }
+            for (int style = DateFormat.FULL; style <= DateFormat.SHORT; style++) {
+                strings.indices(date, ((SimpleDateFormat) DateFormat.getTimeInstance(style, locale)).toPattern());
+            }
+            for (int dateStyle = DateFormat.FULL; dateStyle <= DateFormat.SHORT; dateStyle++) {
+                for (int timeStyle = DateFormat.FULL; timeStyle <= DateFormat.SHORT; timeStyle++) {
+                    strings.indices(date,
+                        ((SimpleDateFormat) DateFormat.getDateTimeInstance(dateStyle, timeStyle, locale)).toPattern());
+                }
+            }
+
+            DecimalFormatSymbols symbols = DecimalFormatSymbols.getInstance(locale);
+            List<Integer> number = new ArrayList<>();
+            Currency currency = symbols.getCurrency();
+            if (currency == null) {
+                try {
+                    currency = Currency.getInstance(symbols.getInternationalCurrencySymbol());
+                } catch (IllegalArgumentException e) {
+                    // Like DecimalFormat, leave the digits of an unknown currency alone.
+                }
+            }
+            number.add(currency == null ? -1 : currency.getDefaultFractionDigits());
+            for (String name : new String[] {
+                "zeroDigit", "groupingSeparator", "decimalSeparator", "perMill", "percent", "digit",
+                "patternSeparator", "infinity", "NaN", "minusSign", "currencySymbol", "intlCurrencySymbol",
+                "monetarySeparator", "exponentialSeparator", "perMillText", "percentText", "minusSignText",
+                "monetaryGroupingSeparator",
+            }) {
+                strings.indices(number, String.valueOf(field(symbols, name)));
+            }
+            strings.indices(number,
+                ((DecimalFormat) NumberFormat.getInstance(locale)).toPattern(),
+                ((DecimalFormat) NumberFormat.getIntegerInstance(locale)).toPattern(),
+                ((DecimalFormat) NumberFormat.getPercentInstance(locale)).toPattern(),
+                ((DecimalFormat) NumberFormat.getCurrencyInstance(locale)).toPattern());
+            Object compact = NumberFormat.getCompactNumberInstance(locale, NumberFormat.Style.SHORT);
+            strings.indices(number, (String) field(compact, "decimalPattern"), (String) field(compact, "pluralRules"));
+            for (NumberFormat.Style style : NumberFormat.Style.values()) {
+                String[] compactPatterns = (String[]) field(
+                    NumberFormat.getCompactNumberInstance(locale, style), "compactPatterns");
+                number.add(compactPatterns.length);
+                strings.indices(number, compactPatterns);
+            }
+
+            List<Integer> zoneNames = new ArrayList<>();
+            for (int i = 0; i < zoneIds.size(); i++) {
+                TimeZone zone = TimeZone.getTimeZone(zoneIds.get(i));
+                String standard = zone.getDisplayName(false, TimeZone.SHORT, locale);
+                String daylight = zone.getDisplayName(true, TimeZone.SHORT, locale);
+                if (!standard.equals(zone.getDisplayName(false, TimeZone.SHORT, Locale.ENGLISH))
+                    || !daylight.equals(zone.getDisplayName(true, TimeZone.SHORT, Locale.ENGLISH))) {
+                    zoneNames.add(i);
+                    strings.indices(zoneNames, standard, daylight);
+                }
+            }
+
+            int dateRecord = dateRecords.computeIfAbsent(date, record -> dateRecords.size());
+            int numberRecord = numberRecords.computeIfAbsent(number, record -> numberRecords.size());
+            int zoneRecord = zoneRecords.computeIfAbsent(zoneNames, record -> zoneRecords.size());
+            locales.put(locale.toLanguageTag(),
+                new int[] {strings.index(locale.toLanguageTag()), dateRecord, numberRecord, zoneRecord});
+        }
+
+        Map<String, int[]> zones = new TreeMap<>();
+        for (String id : zoneIds) {
+            TimeZone zone = TimeZone.getTimeZone(id);
+            List<Integer> names = new ArrayList<>();
+            strings.indices(names, id,
+                zone.getDisplayName(false, TimeZone.SHORT, Locale.ENGLISH),
+                zone.getDisplayName(false, TimeZone.LONG, Locale.ENGLISH),
+                zone.getDisplayName(true, TimeZone.SHORT, Locale.ENGLISH),
+                zone.getDisplayName(true, TimeZone.LONG, Locale.ENGLISH));
+            zones.put(id, names.stream().mapToInt(Integer::intValue).toArray());
+        }
+
+        out.writeShort(strings.indices.size());
+        for (String string : strings.indices.keySet()) {
+            byte[] bytes = string.getBytes(StandardCharsets.UTF_8);
+            out.writeShort(bytes.length);
+            out.write(bytes);
+        }
+        out.writeShort(dateRecords.size());
+        for (List<Integer> record : dateRecords.keySet()) {
+            for (int i = 0; i < record.size(); i++) {
+                if (i < 3) {
+                    out.writeByte(record.get(i));
+                } else {
+                    out.writeShort(record.get(i));
+                }
+            }
+        }
+        out.writeShort(numberRecords.size());
+        for (List<Integer> record : numberRecords.keySet()) {
+            out.writeByte(record.get(0));
+            for (int index : record.subList(1, record.size())) {
+                out.writeShort(index);
+            }
+        }
+        out.writeShort(zoneRecords.size());
+        for (List<Integer> record : zoneRecords.keySet()) {
+            out.writeShort(record.size() / 3);
+            for (int index : record) {
+                out.writeShort(index);
+            }
+        }
+        out.writeShort(locales.size());
+        for (int[] entry : locales.values()) {
+            for (int index : entry) {
+                out.writeShort(index);
+            }
+        }
+        out.writeShort(zones.size());
+        for (int[] names : zones.values()) {
+            for (int index : names) {
+                out.writeShort(index);
+            }
+        }
+    }
+
+    static int zigzag(int value) {
+        return (value << 1) ^ (value >> 31);
+    }
+
+    static void varint(OutputStream out, int value) throws IOException {
+        while ((value & ~0x7F) != 0) {
+            out.write((value & 0x7F) | 0x80);
+            value >>>= 7;
+        }
+        out.write(value);
+    }
+}